@@ -36,6 +36,8 @@ pub enum AuditEventType {
     SimulationSaved,
     /// Simulation deleted
     SimulationDeleted,
+    /// Decision rendered for an entity
+    DecisionRendered,
     /// Permission granted
     PermissionGranted,
     /// Permission revoked
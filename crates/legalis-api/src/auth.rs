@@ -2,6 +2,7 @@
 //!
 //! Provides JWT-based authentication, API key authentication, and role-based access control.
 
+use crate::jwt::JwtVerifier;
 use axum::{
     extract::{FromRequestParts, Request},
     http::{StatusCode, header, request::Parts},
@@ -10,6 +11,8 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use std::str::FromStr;
+use std::sync::Arc;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -25,6 +28,9 @@ pub enum AuthError {
     #[error("Token expired")]
     TokenExpired,
 
+    #[error("Token not yet valid")]
+    TokenNotYetValid,
+
     #[error("Insufficient permissions")]
     InsufficientPermissions,
 
@@ -41,6 +47,7 @@ impl IntoResponse for AuthError {
             AuthError::MissingCredentials => (StatusCode::UNAUTHORIZED, self.to_string()),
             AuthError::InvalidToken => (StatusCode::UNAUTHORIZED, self.to_string()),
             AuthError::TokenExpired => (StatusCode::UNAUTHORIZED, self.to_string()),
+            AuthError::TokenNotYetValid => (StatusCode::UNAUTHORIZED, self.to_string()),
             AuthError::InsufficientPermissions => (StatusCode::FORBIDDEN, self.to_string()),
             AuthError::InvalidApiKey => (StatusCode::UNAUTHORIZED, self.to_string()),
             AuthError::UserNotFound => (StatusCode::UNAUTHORIZED, self.to_string()),
@@ -73,6 +80,27 @@ pub enum Role {
     ApiClient,
 }
 
+impl FromStr for Role {
+    type Err = String;
+
+    /// Parses a role name, ignoring case and `_`/`-` separators (`super_admin`, `SuperAdmin`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized: String = s
+            .chars()
+            .filter(|c| !matches!(c, '_' | '-' | ' '))
+            .collect::<String>()
+            .to_ascii_lowercase();
+        match normalized.as_str() {
+            "superadmin" => Ok(Role::SuperAdmin),
+            "admin" => Ok(Role::Admin),
+            "analyst" => Ok(Role::Analyst),
+            "viewer" => Ok(Role::Viewer),
+            "apiclient" => Ok(Role::ApiClient),
+            _ => Err(format!("Unknown role: {}", s)),
+        }
+    }
+}
+
 impl Role {
     /// Returns the permissions granted by this role.
     pub fn permissions(&self) -> HashSet<Permission> {
@@ -342,7 +370,8 @@ impl ApiKey {
 /// Authentication extractor for Axum.
 ///
 /// This allows endpoints to receive `AuthUser` as a parameter,
-/// which will automatically validate authentication.
+/// which will automatically validate authentication. Bearer tokens are
+/// verified by the `Arc<JwtVerifier>` request extension installed by the router.
 impl<S> FromRequestParts<S> for AuthUser
 where
     S: Send + Sync,
@@ -356,7 +385,8 @@ where
 
             // Check for Bearer token (JWT)
             if let Some(token) = auth_str.strip_prefix("Bearer ") {
-                return extract_jwt_user(token);
+                let verifier = parts.extensions.get::<Arc<JwtVerifier>>();
+                return extract_jwt_user(token, verifier.map(Arc::as_ref));
             }
 
             // Check for API key
@@ -381,28 +411,10 @@ where
 
 /// Extracts user from JWT token.
 ///
-/// NOTE: This is a simplified implementation for demonstration.
-/// In production, use a proper JWT library like `jsonwebtoken` with proper signature verification.
-fn extract_jwt_user(token: &str) -> Result<AuthUser, AuthError> {
-    // In a real implementation, this would:
-    // 1. Verify the JWT signature with a secret key
-    // 2. Check expiration
-    // 3. Validate issuer and audience
-    // 4. Look up user in database
-
-    // For now, we'll simulate validation
-    if token.is_empty() || token.len() < 10 {
-        return Err(AuthError::InvalidToken);
-    }
-
-    // Simulate extracting claims (in production, use proper JWT decoding)
-    // This is a placeholder - real implementation would decode and verify JWT
-    Ok(AuthUser::new(
-        Uuid::new_v4(),
-        "jwt_user".to_string(),
-        Role::Admin,
-        AuthMethod::Jwt,
-    ))
+/// Bearer tokens are rejected outright when no verifier is configured.
+fn extract_jwt_user(token: &str, verifier: Option<&JwtVerifier>) -> Result<AuthUser, AuthError> {
+    let verifier = verifier.ok_or(AuthError::InvalidToken)?;
+    verifier.authenticate(token)
 }
 
 /// Extracts user from API key.
@@ -527,13 +539,34 @@ mod tests {
 
     #[test]
     fn test_jwt_extraction() {
-        // Valid token format (simplified)
-        let result = extract_jwt_user("valid_jwt_token_placeholder");
-        assert!(result.is_ok());
+        use crate::jwt::{JwtSigner, JwtValidation};
+
+        let signer = JwtSigner::hs256(b"test-secret");
+        let verifier =
+            JwtVerifier::new(JwtValidation::default()).with_key(signer.verification_key());
+        let token = signer
+            .sign(&serde_json::json!({
+                "sub": Uuid::new_v4(),
+                "role": "viewer",
+                "exp": chrono::Utc::now().timestamp() + 60
+            }))
+            .unwrap();
+
+        let user = extract_jwt_user(&token, Some(&verifier)).unwrap();
+        assert_eq!(user.role, Role::Viewer);
+
+        // Unverifiable tokens are rejected rather than trusted
+        assert!(extract_jwt_user("valid_jwt_token_placeholder", Some(&verifier)).is_err());
+        assert!(extract_jwt_user(&token, None).is_err());
+        assert!(extract_jwt_user("", Some(&verifier)).is_err());
+    }
 
-        // Invalid token
-        let result = extract_jwt_user("");
-        assert!(result.is_err());
+    #[test]
+    fn test_role_from_str() {
+        assert_eq!("super_admin".parse::<Role>(), Ok(Role::SuperAdmin));
+        assert_eq!("Analyst".parse::<Role>(), Ok(Role::Analyst));
+        assert_eq!("api-client".parse::<Role>(), Ok(Role::ApiClient));
+        assert!("root".parse::<Role>().is_err());
     }
 
     #[test]
@@ -2,7 +2,12 @@
 //!
 //! Standalone HTTP server for the Legalis REST API.
 
-use legalis_api::{AppState, config::Config, create_router, logging};
+use legalis_api::{
+    AppState, config::Config, create_router, federation::FederationIdentity, jwt::JwtVerifier,
+    logging, store::StatuteStore,
+};
+use legalis_registry::vector_search::{Embedder, HashedTfIdfEmbedder, SemanticIndex};
+use std::path::Path;
 use std::sync::Arc;
 use tokio::signal;
 use tracing::info;
@@ -10,7 +15,7 @@ use tracing::info;
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Load configuration from environment
-    let config = Config::from_env();
+    let config = Config::from_env()?;
 
     // Initialize logging
     logging::init_logging();
@@ -24,8 +29,45 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("  Max body size: {} bytes", config.max_body_size);
     info!("  Request timeout: {}s", config.request_timeout_secs);
 
+    // Connect statute storage
+    let statutes = StatuteStore::connect(&config.storage).await?;
+    info!("  Storage: {:?}", config.storage.kind);
+    info!("  Statutes loaded: {}", statutes.count().await?);
+
     // Create application state
-    let state = Arc::new(AppState::new());
+    let mut state = AppState::new().with_statute_store(statutes);
+    if config.jwt.is_enabled() {
+        let verifier = JwtVerifier::from_config(&config.jwt)?;
+        info!("  JWT authentication: {} key(s)", verifier.key_count());
+        state = state.with_jwt_verifier(verifier);
+    } else {
+        info!("  JWT authentication: disabled (no keys configured)");
+    }
+    if config.federation.is_enabled() {
+        let identity = FederationIdentity::from_config(&config.federation)?;
+        let published = identity.identity();
+        info!(
+            "  Federation: {} ({}), key {}",
+            published.name, published.registry_id, published.public_key
+        );
+        state = state.with_federation(identity);
+    } else {
+        info!("  Federation: disabled (no key configured)");
+    }
+
+    // Open the semantic search index
+    let index_path = config.semantic.index_path(&config.storage);
+    let index = open_semantic_index(
+        index_path.as_deref(),
+        config.semantic.embedding_model.as_deref(),
+    )?;
+    info!(
+        "  Semantic search: {} ({} statutes indexed)",
+        index.embedder().model(),
+        index.len()
+    );
+    state = state.with_semantic_index(index, index_path);
+    let state = Arc::new(state);
 
     // Create router
     let app = create_router(state);
@@ -46,6 +88,31 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Loads the persisted semantic index, or starts an empty one.
+fn open_semantic_index(
+    path: Option<&Path>,
+    model: Option<&Path>,
+) -> Result<SemanticIndex, Box<dyn std::error::Error>> {
+    let embedder = embedder(model)?;
+    match path {
+        Some(path) if path.exists() => Ok(SemanticIndex::load(path, embedder)?),
+        _ => Ok(SemanticIndex::new(embedder)),
+    }
+}
+/// Builds the embedder: a local sentence encoder when a model directory is
+/// configured, the offline hashed embedder otherwise.
+fn embedder(model: Option<&Path>) -> Result<Box<dyn Embedder>, Box<dyn std::error::Error>> {
+    match model {
+        #[cfg(feature = "onnx")]
+        Some(dir) => Ok(Box::new(
+            legalis_registry::vector_search::OnnxEmbedder::from_dir(dir)?,
+        )),
+        #[cfg(not(feature = "onnx"))]
+        Some(_) => Err("LEGALIS_API_EMBEDDING_MODEL requires the onnx feature".into()),
+        None => Ok(Box::new(HashedTfIdfEmbedder::default())),
+    }
+}
+
 /// Wait for shutdown signal (Ctrl+C or SIGTERM).
 async fn shutdown_signal() {
     let ctrl_c = async {
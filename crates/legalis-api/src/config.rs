@@ -1,6 +1,11 @@
 //! Configuration management for the API server.
 
+use crate::auth::Role;
+use crate::jwt::JwtAlgorithm;
+use legalis_registry::{RegistryError, RegistryResult};
+use std::collections::HashMap;
 use std::env;
+use std::path::PathBuf;
 
 /// API server configuration.
 #[derive(Debug, Clone)]
@@ -27,6 +32,207 @@ pub struct Config {
     pub cache_default_ttl: u64,
     /// Enable cache compression
     pub cache_compression: bool,
+    /// Bearer token verification
+    pub jwt: JwtConfig,
+    /// Statute storage backend
+    pub storage: StorageConfig,
+    /// Federation identity
+    pub federation: FederationConfig,
+    /// Semantic statute search
+    pub semantic: SemanticSearchConfig,
+}
+
+/// Statute storage backend type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageKind {
+    /// In-memory storage, lost on restart (default)
+    Memory,
+    /// SQLite database (requires sqlite feature)
+    Sqlite,
+    /// PostgreSQL database (requires postgres feature)
+    Postgres,
+    /// Embedded single-file registry; the URL is the file path
+    File,
+}
+
+impl std::str::FromStr for StorageKind {
+    type Err = RegistryError;
+
+    /// Parses a backend name, ignoring case; unknown names are an error so a
+    /// typo never falls back to in-memory storage.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "memory" => Ok(StorageKind::Memory),
+            "sqlite" => Ok(StorageKind::Sqlite),
+            "postgres" | "postgresql" => Ok(StorageKind::Postgres),
+            "file" => Ok(StorageKind::File),
+            other => Err(RegistryError::InvalidOperation(format!(
+                "Unknown storage backend '{}' (expected memory, sqlite, postgres or file)",
+                other
+            ))),
+        }
+    }
+}
+
+/// Statute storage configuration.
+#[derive(Clone, PartialEq)]
+pub struct StorageConfig {
+    /// Backend type
+    pub kind: StorageKind,
+    /// Database connection URL (e.g. `sqlite://legalis.db?mode=rwc`), or the
+    /// registry file path for file storage
+    pub url: Option<String>,
+}
+
+impl StorageConfig {
+    /// Returns the database URL, or an error if none is configured.
+    pub fn database_url(&self) -> RegistryResult<&str> {
+        self.url.as_deref().ok_or_else(|| {
+            RegistryError::InvalidOperation(format!(
+                "{:?} storage requires LEGALIS_API_DATABASE_URL",
+                self.kind
+            ))
+        })
+    }
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        Self {
+            kind: StorageKind::Memory,
+            url: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for StorageConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Connection URLs may embed credentials
+        f.debug_struct("StorageConfig")
+            .field("kind", &self.kind)
+            .field("url", &self.url.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+/// Semantic search configuration.
+///
+/// With file storage the index is kept next to the registry file unless
+/// another path is set. Other backends keep it in memory only.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SemanticSearchConfig {
+    /// File the semantic index is persisted to
+    pub index_path: Option<PathBuf>,
+    /// Directory holding `model.onnx` and `tokenizer.json` of a local
+    /// sentence encoder (requires the onnx feature)
+    pub embedding_model: Option<PathBuf>,
+}
+
+impl SemanticSearchConfig {
+    /// Returns the index file for the given storage configuration.
+    pub fn index_path(&self, storage: &StorageConfig) -> Option<PathBuf> {
+        self.index_path
+            .clone()
+            .or_else(|| match (storage.kind, &storage.url) {
+                (StorageKind::File, Some(url)) => Some(PathBuf::from(format!("{url}.semantic"))),
+                _ => None,
+            })
+    }
+}
+
+/// Federation configuration.
+///
+/// Federation is disabled unless a signing key file is set. The key is
+/// created on first start when the file does not exist yet.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FederationConfig {
+    /// PKCS#8 Ed25519 key that search responses are signed with
+    pub key_path: Option<PathBuf>,
+    /// Registry ID announced to peers (random per start when unset)
+    pub registry_id: Option<uuid::Uuid>,
+    /// Registry name announced to peers
+    pub name: Option<String>,
+    /// Jurisdictions covered by this registry
+    pub jurisdictions: Vec<String>,
+}
+
+impl FederationConfig {
+    /// Returns true when a signing key is configured.
+    pub fn is_enabled(&self) -> bool {
+        self.key_path.is_some()
+    }
+}
+
+/// JWT verification configuration.
+///
+/// Bearer authentication is disabled unless a secret, public key or JWKS file is set.
+#[derive(Clone)]
+pub struct JwtConfig {
+    /// Shared secret for HS256 tokens
+    pub secret: Option<String>,
+    /// Algorithm of the PEM public key (RS256 or ES256)
+    pub algorithm: JwtAlgorithm,
+    /// PEM public key file
+    pub public_key_path: Option<PathBuf>,
+    /// Local JWKS file, reloaded to rotate keys
+    pub jwks_path: Option<PathBuf>,
+    /// Required issuer
+    pub issuer: Option<String>,
+    /// Required audience
+    pub audience: Option<String>,
+    /// Clock skew tolerated for `exp` and `nbf`, in seconds
+    pub leeway_secs: i64,
+    /// Claim holding roles (dotted paths allowed)
+    pub role_claim: String,
+    /// Claim value to role table
+    pub role_map: HashMap<String, Role>,
+    /// Role for tokens without a mappable role claim
+    pub default_role: Option<Role>,
+    /// Claim identifying the ReBAC subject (defaults to `sub`)
+    pub subject_claim: Option<String>,
+}
+
+impl JwtConfig {
+    /// Returns true when any verification key source is configured.
+    pub fn is_enabled(&self) -> bool {
+        self.secret.is_some() || self.public_key_path.is_some() || self.jwks_path.is_some()
+    }
+}
+
+impl Default for JwtConfig {
+    fn default() -> Self {
+        Self {
+            secret: None,
+            algorithm: JwtAlgorithm::RS256,
+            public_key_path: None,
+            jwks_path: None,
+            issuer: None,
+            audience: None,
+            leeway_secs: 60,
+            role_claim: "role".to_string(),
+            role_map: HashMap::new(),
+            default_role: None,
+            subject_claim: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for JwtConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtConfig")
+            .field("secret", &self.secret.as_ref().map(|_| "<redacted>"))
+            .field("algorithm", &self.algorithm)
+            .field("public_key_path", &self.public_key_path)
+            .field("jwks_path", &self.jwks_path)
+            .field("issuer", &self.issuer)
+            .field("audience", &self.audience)
+            .field("leeway_secs", &self.leeway_secs)
+            .field("role_claim", &self.role_claim)
+            .field("role_map", &self.role_map)
+            .field("default_role", &self.default_role)
+            .field("subject_claim", &self.subject_claim)
+            .finish()
+    }
 }
 
 /// Cache backend type.
@@ -52,13 +258,19 @@ impl Default for Config {
             redis_url: None,
             cache_default_ttl: 300, // 5 minutes
             cache_compression: false,
+            jwt: JwtConfig::default(),
+            storage: StorageConfig::default(),
+            federation: FederationConfig::default(),
+            semantic: SemanticSearchConfig::default(),
         }
     }
 }
 
 impl Config {
     /// Load configuration from environment variables.
-    pub fn from_env() -> Self {
+    ///
+    /// Fails if `LEGALIS_API_STORAGE` names an unknown backend.
+    pub fn from_env() -> RegistryResult<Self> {
         let mut config = Self::default();
 
         if let Ok(host) = env::var("LEGALIS_API_HOST") {
@@ -121,7 +333,80 @@ impl Config {
             config.cache_compression = compression.to_lowercase() == "true" || compression == "1";
         }
 
-        config
+        // Storage configuration
+        if let Ok(backend) = env::var("LEGALIS_API_STORAGE") {
+            config.storage.kind = backend.parse()?;
+        }
+
+        if let Ok(url) = env::var("LEGALIS_API_DATABASE_URL") {
+            config.storage.url = Some(url);
+        } else if let Ok(url) = env::var("DATABASE_URL") {
+            config.storage.url = Some(url);
+        }
+
+        // JWT configuration
+        config.jwt.secret = env::var("LEGALIS_API_JWT_SECRET").ok();
+        config.jwt.public_key_path = env::var("LEGALIS_API_JWT_PUBLIC_KEY")
+            .ok()
+            .map(PathBuf::from);
+        config.jwt.jwks_path = env::var("LEGALIS_API_JWT_JWKS").ok().map(PathBuf::from);
+        config.jwt.issuer = env::var("LEGALIS_API_JWT_ISSUER").ok();
+        config.jwt.audience = env::var("LEGALIS_API_JWT_AUDIENCE").ok();
+        config.jwt.subject_claim = env::var("LEGALIS_API_JWT_SUBJECT_CLAIM").ok();
+
+        if let Ok(alg) = env::var("LEGALIS_API_JWT_ALGORITHM")
+            && let Ok(a) = alg.parse::<JwtAlgorithm>()
+        {
+            config.jwt.algorithm = a;
+        }
+
+        if let Ok(leeway) = env::var("LEGALIS_API_JWT_LEEWAY")
+            && let Ok(l) = leeway.parse::<i64>()
+        {
+            config.jwt.leeway_secs = l;
+        }
+
+        if let Ok(claim) = env::var("LEGALIS_API_JWT_ROLE_CLAIM") {
+            config.jwt.role_claim = claim;
+        }
+
+        // Comma-separated `claim-value=Role` pairs, e.g. "legal-team=analyst,ops=admin"
+        if let Ok(map) = env::var("LEGALIS_API_JWT_ROLE_MAP") {
+            config.jwt.role_map = parse_role_map(&map);
+        }
+
+        if let Ok(role) = env::var("LEGALIS_API_JWT_DEFAULT_ROLE") {
+            config.jwt.default_role = role.parse().ok();
+        }
+
+        // Federation configuration
+        config.federation.key_path = env::var("LEGALIS_API_FEDERATION_KEY")
+            .ok()
+            .map(PathBuf::from);
+        config.federation.registry_id = env::var("LEGALIS_API_FEDERATION_ID")
+            .ok()
+            .and_then(|id| id.parse().ok());
+        config.federation.name = env::var("LEGALIS_API_FEDERATION_NAME").ok();
+
+        // Comma-separated jurisdiction codes, e.g. "JP,KR"
+        if let Ok(jurisdictions) = env::var("LEGALIS_API_FEDERATION_JURISDICTIONS") {
+            config.federation.jurisdictions = jurisdictions
+                .split(',')
+                .map(str::trim)
+                .filter(|j| !j.is_empty())
+                .map(str::to_string)
+                .collect();
+        }
+
+        // Semantic search configuration
+        config.semantic.index_path = env::var("LEGALIS_API_SEMANTIC_INDEX")
+            .ok()
+            .map(PathBuf::from);
+        config.semantic.embedding_model = env::var("LEGALIS_API_EMBEDDING_MODEL")
+            .ok()
+            .map(PathBuf::from);
+
+        Ok(config)
     }
 
     /// Returns the bind address.
@@ -129,3 +414,93 @@ impl Config {
         format!("{}:{}", self.host, self.port)
     }
 }
+
+/// Parses `value=Role` pairs, skipping malformed entries.
+fn parse_role_map(map: &str) -> HashMap<String, Role> {
+    map.split(',')
+        .filter_map(|pair| {
+            let (value, role) = pair.split_once('=')?;
+            Some((value.trim().to_string(), role.trim().parse().ok()?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_role_map() {
+        let map = parse_role_map("legal-team=analyst, ops = Admin,broken,x=root");
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["legal-team"], Role::Analyst);
+        assert_eq!(map["ops"], Role::Admin);
+    }
+
+    #[test]
+    fn test_storage_config_requires_url() {
+        let mut storage = StorageConfig {
+            kind: StorageKind::Sqlite,
+            url: None,
+        };
+        assert!(storage.database_url().is_err());
+
+        storage.url = Some("postgres://user:secret@db/legalis".to_string());
+        assert_eq!(
+            storage.database_url().unwrap(),
+            "postgres://user:secret@db/legalis"
+        );
+        assert!(!format!("{:?}", storage).contains("secret"));
+    }
+
+    #[test]
+    fn test_parse_storage_kind() {
+        assert_eq!(
+            "SQLite".parse::<StorageKind>().unwrap(),
+            StorageKind::Sqlite
+        );
+        assert_eq!(
+            "postgresql".parse::<StorageKind>().unwrap(),
+            StorageKind::Postgres
+        );
+        assert_eq!(
+            "memory".parse::<StorageKind>().unwrap(),
+            StorageKind::Memory
+        );
+        let err = "sqlit".parse::<StorageKind>().unwrap_err();
+        assert!(err.to_string().contains("sqlit"), "{}", err);
+    }
+
+    #[test]
+    fn test_jwt_config_debug_redacts_secret() {
+        let config = JwtConfig {
+            secret: Some("hunter2".to_string()),
+            ..Default::default()
+        };
+        assert!(config.is_enabled());
+        assert!(!format!("{:?}", config).contains("hunter2"));
+    }
+
+    #[test]
+    fn test_semantic_index_path() {
+        let file = StorageConfig {
+            kind: StorageKind::File,
+            url: Some("/var/lib/legalis/registry.db".to_string()),
+        };
+        let semantic = SemanticSearchConfig::default();
+        assert_eq!(
+            semantic.index_path(&file),
+            Some(PathBuf::from("/var/lib/legalis/registry.db.semantic"))
+        );
+        assert_eq!(semantic.index_path(&StorageConfig::default()), None);
+
+        let explicit = SemanticSearchConfig {
+            index_path: Some(PathBuf::from("/tmp/index.json")),
+            ..Default::default()
+        };
+        assert_eq!(
+            explicit.index_path(&StorageConfig::default()),
+            Some(PathBuf::from("/tmp/index.json"))
+        );
+    }
+}
@@ -180,6 +180,7 @@ mod tests {
                 effect_type: EffectType::Obligation,
                 description: "Test effect".to_string(),
                 parameters: HashMap::new(),
+                outputs: Vec::new(),
             },
             preconditions: vec![],
             discretion_logic: None,
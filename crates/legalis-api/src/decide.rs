@@ -0,0 +1,651 @@
+//! Entity decisions.
+//!
+//! A decision evaluates the facts known about one entity (an applicant, a
+//! case) against a set of statutes. For every statute it reports the
+//! [`LegalResult`], the computed effect outputs, the reasoning chain, the
+//! individual condition evaluations and the facts whose absence left a
+//! condition [`PartialBool::Unknown`]. The REST (`POST /api/v1/decide`),
+//! GraphQL (`decide`) and gRPC (`Decide`) endpoints all go through [`decide`].
+
+use crate::audit::{AuditEventType, AuditLog};
+use crate::store::StatuteStore;
+use chrono::{DateTime, NaiveDate, Utc};
+use legalis_core::{
+    AbductiveReasoner, BasicEntity, Condition, Effect, EntityContext, EvaluationAuditTrail,
+    EvaluationContext, EvaluationRecord, LegalEntity, LegalExplanation, LegalResult, OutputValue,
+    PartialBool, Statute,
+};
+use legalis_registry::{AsOf, RegistryError};
+use legalis_sim::{ApplicationReason, SimEngine};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::time::Instant;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors raised while preparing a decision.
+#[derive(Debug, Error)]
+pub enum DecisionError {
+    #[error("No statutes to decide against")]
+    NoStatutes,
+
+    #[error("Unknown statutes: {}", .0.join(", "))]
+    UnknownStatutes(Vec<String>),
+
+    #[error(transparent)]
+    Storage(#[from] RegistryError),
+}
+
+/// Request to decide an entity's case against a set of statutes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DecisionRequest {
+    /// IDs of statutes to evaluate (empty = all statutes)
+    #[serde(default)]
+    pub statute_ids: Vec<String>,
+    /// Known facts about the entity; numbers and booleans are accepted as well as strings
+    #[serde(default)]
+    pub facts: HashMap<String, serde_json::Value>,
+    /// Evaluation date (defaults to the `current_date` fact; statutes with
+    /// effective or expiry dates need review when neither is given)
+    #[serde(default)]
+    pub date: Option<NaiveDate>,
+    /// Identifier of the entity or case; a random one is assigned when absent
+    #[serde(default)]
+    pub entity_id: Option<Uuid>,
+    /// Only consider statute versions recorded by this moment, to re-run a
+    /// decision as the registry stood at the time
+    #[serde(default)]
+    pub known_at: Option<DateTime<Utc>>,
+}
+
+impl DecisionRequest {
+    /// Creates an empty request that evaluates all statutes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts the decision to the given statute (may be called repeatedly).
+    pub fn with_statute(mut self, statute_id: impl Into<String>) -> Self {
+        self.statute_ids.push(statute_id.into());
+        self
+    }
+
+    /// Adds a fact about the entity.
+    pub fn with_fact(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.facts.insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets the evaluation date.
+    pub fn with_date(mut self, date: NaiveDate) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Sets the entity identifier recorded in the decision.
+    pub fn with_entity_id(mut self, entity_id: Uuid) -> Self {
+        self.entity_id = Some(entity_id);
+        self
+    }
+
+    /// Restricts the statutes to the versions recorded by the given moment.
+    pub fn with_known_at(mut self, known_at: DateTime<Utc>) -> Self {
+        self.known_at = Some(known_at);
+        self
+    }
+
+    /// Returns the point in time whose law governs the decision.
+    ///
+    /// `None` when neither a date nor `known_at` is given, in which case the
+    /// latest version of every statute is used. Without a date the law in
+    /// force today applies.
+    pub fn as_of(&self) -> Option<AsOf> {
+        AsOf::from_parts(self.date, self.known_at)
+    }
+
+    /// Returns the facts as entity attributes.
+    ///
+    /// Strings are used verbatim, other values are rendered as JSON, and
+    /// `null` facts are treated as unknown.
+    pub fn attributes(&self) -> HashMap<String, String> {
+        self.facts
+            .iter()
+            .filter_map(|(key, value)| {
+                let value = match value {
+                    serde_json::Value::Null => return None,
+                    serde_json::Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                };
+                Some((key.clone(), value))
+            })
+            .collect()
+    }
+}
+
+/// Summary of a statute's outcome for an entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionOutcome {
+    /// The statute applies and its effect is granted
+    Applies,
+    /// The statute does not apply
+    NotApplicable,
+    /// The outcome requires a caseworker's judgment
+    Discretion,
+}
+
+impl DecisionOutcome {
+    /// Returns the outcome as a lowercase identifier.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Applies => "applies",
+            Self::NotApplicable => "not_applicable",
+            Self::Discretion => "discretion",
+        }
+    }
+}
+
+impl<T> From<&LegalResult<T>> for DecisionOutcome {
+    fn from(result: &LegalResult<T>) -> Self {
+        match result {
+            LegalResult::Deterministic(_) => Self::Applies,
+            LegalResult::JudicialDiscretion { .. } => Self::Discretion,
+            LegalResult::Void { .. } => Self::NotApplicable,
+        }
+    }
+}
+
+/// A fact whose absence left a condition undetermined.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MissingFact {
+    /// Statute whose condition needs the fact
+    pub statute_id: String,
+    /// Name of the missing fact, or the condition itself when no single fact is named
+    pub fact: String,
+    /// The undetermined condition
+    pub condition: String,
+    /// Why the condition could not be evaluated
+    pub reason: String,
+}
+
+/// Outcome of a single statute for the entity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatuteDecision {
+    pub statute_id: String,
+    pub title: String,
+    pub outcome: DecisionOutcome,
+    /// Full evaluation result, including discretion issues and void reasons
+    pub result: LegalResult<Effect>,
+    /// Why the statute did or did not apply
+    pub reason: ApplicationReason,
+    /// Computed effect outputs (empty unless the statute applies)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub outputs: BTreeMap<String, OutputValue>,
+    /// Step-by-step reasoning over the statute's preconditions
+    pub explanation: Option<LegalExplanation>,
+    /// Each precondition and exception evaluated, in order
+    pub audit_trail: Vec<EvaluationRecord>,
+    /// Facts needed to settle conditions that could not be evaluated
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing_facts: Vec<MissingFact>,
+}
+
+/// An effect granted to the entity by an applicable statute.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecidedEffect {
+    pub statute_id: String,
+    pub effect: Effect,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub outputs: BTreeMap<String, OutputValue>,
+}
+
+/// Decision for an entity across a set of statutes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Decision {
+    pub decision_id: String,
+    pub entity_id: Uuid,
+    pub decided_at: DateTime<Utc>,
+    /// Evaluation date requested by the caller, if any
+    pub date: Option<NaiveDate>,
+    /// Registry knowledge cut-off requested by the caller, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub known_at: Option<DateTime<Utc>>,
+    /// Per-statute outcomes, in the order the statutes were given
+    pub results: Vec<StatuteDecision>,
+    /// Effects of the statutes that apply
+    pub effects: Vec<DecidedEffect>,
+    /// Distinct missing facts across all statutes, sorted
+    pub missing_facts: Vec<String>,
+}
+
+impl Decision {
+    /// Returns the statute decisions with the given outcome.
+    pub fn with_outcome(&self, outcome: DecisionOutcome) -> impl Iterator<Item = &StatuteDecision> {
+        self.results.iter().filter(move |r| r.outcome == outcome)
+    }
+
+    /// Returns true if any statute needs a caseworker's judgment.
+    pub fn requires_discretion(&self) -> bool {
+        self.with_outcome(DecisionOutcome::Discretion)
+            .next()
+            .is_some()
+    }
+
+    /// Returns the details recorded in the audit log for this decision.
+    ///
+    /// The outcome and reason of every statute are kept, so the decision can
+    /// be reconstructed from the audit log without the reasoning chains.
+    pub fn audit_details(&self) -> serde_json::Value {
+        serde_json::json!({
+            "entity_id": self.entity_id,
+            "date": self.date,
+            "known_at": self.known_at,
+            "statutes": self.results.iter().map(|r| serde_json::json!({
+                "statute_id": r.statute_id,
+                "outcome": r.outcome,
+                "reason": r.reason.to_string(),
+                "outputs": r.outputs,
+            })).collect::<Vec<_>>(),
+            "effects": self.effects.iter().map(|e| &e.statute_id).collect::<Vec<_>>(),
+            "missing_facts": self.missing_facts,
+        })
+    }
+}
+
+/// Decides an entity's case against the given statutes.
+///
+/// # Example
+///
+/// ```
+/// use legalis_api::decide::{DecisionOutcome, DecisionRequest, decide};
+/// use legalis_core::{ComparisonOp, Condition, Effect, EffectType, Statute};
+///
+/// let pension = Statute::new("pension", "Old-age pension", Effect::new(EffectType::Grant, "Pension"))
+///     .with_precondition(Condition::age(ComparisonOp::GreaterOrEqual, 65));
+/// let relief = Statute::new("relief", "Low-income relief", Effect::new(EffectType::Grant, "Relief"))
+///     .with_precondition(Condition::income(ComparisonOp::LessThan, 20_000));
+///
+/// let request = DecisionRequest::new().with_fact("age", 70);
+/// let decision = decide(&[pension, relief], &request);
+///
+/// assert_eq!(decision.results[0].outcome, DecisionOutcome::Applies);
+/// assert_eq!(decision.effects.len(), 1);
+/// assert_eq!(decision.missing_facts, vec!["income".to_string()]);
+/// ```
+pub fn decide(statutes: &[Statute], request: &DecisionRequest) -> Decision {
+    let entity_id = request.entity_id.unwrap_or_else(Uuid::new_v4);
+    let mut entity = BasicEntity::with_id(entity_id);
+    for (key, value) in request.attributes() {
+        entity.set_attribute(&key, value);
+    }
+
+    let results: Vec<StatuteDecision> = statutes
+        .iter()
+        .map(|statute| decide_statute(&entity, statute, request.date))
+        .collect();
+
+    let effects = results
+        .iter()
+        .filter_map(|r| match &r.result {
+            LegalResult::Deterministic(effect) => Some(DecidedEffect {
+                statute_id: r.statute_id.clone(),
+                effect: effect.clone(),
+                outputs: r.outputs.clone(),
+            }),
+            _ => None,
+        })
+        .collect();
+
+    let missing_facts = results
+        .iter()
+        .flat_map(|r| r.missing_facts.iter().map(|m| m.fact.clone()))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    Decision {
+        decision_id: Uuid::new_v4().to_string(),
+        entity_id,
+        decided_at: Utc::now(),
+        date: request.date,
+        known_at: request.known_at,
+        results,
+        effects,
+        missing_facts,
+    }
+}
+
+/// Loads the statutes selected by a request from the store.
+///
+/// An empty selection loads every statute; naming a statute that does not
+/// exist is an error rather than being silently skipped.
+///
+/// When the request has a point in time (see [`DecisionRequest::as_of`]), the
+/// version in force at that time is loaded instead of the latest one. An empty
+/// selection then only covers statutes in force; a named statute with no
+/// version in force falls back to its latest known version, so the decision
+/// reports it as not applicable.
+pub async fn load_statutes(
+    store: &StatuteStore,
+    request: &DecisionRequest,
+) -> Result<Vec<Statute>, DecisionError> {
+    let as_of = request.as_of();
+    let statutes = if request.statute_ids.is_empty() {
+        match &as_of {
+            Some(as_of) => store.list_as_of(as_of).await?,
+            None => store.list().await?,
+        }
+    } else {
+        let mut statutes = Vec::with_capacity(request.statute_ids.len());
+        let mut unknown = Vec::new();
+        for id in &request.statute_ids {
+            let statute = match &as_of {
+                Some(as_of) => {
+                    let history = store.history(id).await?;
+                    as_of
+                        .select(&history)
+                        .or_else(|| as_of.latest_known(&history))
+                        .map(|entry| entry.statute.clone())
+                }
+                None => store.get(id).await?,
+            };
+            match statute {
+                Some(statute) => statutes.push(statute),
+                None => unknown.push(id.clone()),
+            }
+        }
+        if !unknown.is_empty() {
+            return Err(DecisionError::UnknownStatutes(unknown));
+        }
+        statutes
+    };
+
+    if statutes.is_empty() {
+        return Err(DecisionError::NoStatutes);
+    }
+    Ok(statutes)
+}
+
+/// Writes a decision into the audit log.
+pub async fn record_decision(
+    audit_log: &AuditLog,
+    user_id: String,
+    username: String,
+    decision: &Decision,
+) {
+    audit_log
+        .log_success(
+            AuditEventType::DecisionRendered,
+            user_id,
+            username,
+            "decide".to_string(),
+            Some(decision.decision_id.clone()),
+            Some("decision".to_string()),
+            decision.audit_details(),
+        )
+        .await;
+}
+
+fn decide_statute(
+    entity: &BasicEntity,
+    statute: &Statute,
+    date: Option<NaiveDate>,
+) -> StatuteDecision {
+    let (application, context) = match date {
+        Some(date) => (
+            SimEngine::apply_law_at(entity, statute, date),
+            EntityContext::new(entity).with_date(date),
+        ),
+        None => (
+            SimEngine::apply_law_with_outputs(entity, statute),
+            EntityContext::new(entity),
+        ),
+    };
+
+    let mut trail = EvaluationAuditTrail::new();
+    let mut missing_facts = Vec::new();
+    let conditions = statute
+        .preconditions
+        .iter()
+        .chain(statute.exceptions.iter().map(|e| &e.condition));
+    for condition in conditions {
+        let started = Instant::now();
+        let holds = matches!(condition.evaluate(&context), Ok(true));
+        trail.record(
+            condition.to_string(),
+            holds,
+            started.elapsed().as_micros() as u64,
+        );
+        collect_missing_facts(condition, &context, &statute.id, &mut missing_facts);
+    }
+
+    StatuteDecision {
+        statute_id: statute.id.clone(),
+        title: statute.title.clone(),
+        outcome: DecisionOutcome::from(&application.result),
+        result: application.result,
+        reason: application.reason,
+        outputs: application.outputs,
+        explanation: AbductiveReasoner::new(Vec::new()).explain_statute(statute, &context),
+        audit_trail: trail.records().to_vec(),
+        missing_facts,
+    }
+}
+
+/// Collects the leaf conditions that make `condition` undetermined.
+///
+/// Only branches that are themselves unknown are followed, so a fact is not
+/// reported as missing when the rest of the condition already settles it.
+fn collect_missing_facts<C: EvaluationContext>(
+    condition: &Condition,
+    context: &C,
+    statute_id: &str,
+    missing: &mut Vec<MissingFact>,
+) {
+    let PartialBool::Unknown { reason, .. } = condition.partial_evaluate(context) else {
+        return;
+    };
+    match condition {
+        Condition::And(left, right) | Condition::Or(left, right) => {
+            collect_missing_facts(left, context, statute_id, missing);
+            collect_missing_facts(right, context, statute_id, missing);
+        }
+        Condition::Not(inner) => collect_missing_facts(inner, context, statute_id, missing),
+        leaf => missing.push(MissingFact {
+            statute_id: statute_id.to_string(),
+            fact: fact_name(leaf).unwrap_or_else(|| leaf.to_string()),
+            condition: leaf.to_string(),
+            reason,
+        }),
+    }
+}
+
+/// Returns the entity attribute a leaf condition reads, if it reads exactly one.
+fn fact_name(condition: &Condition) -> Option<String> {
+    match condition {
+        Condition::Age { .. } => Some("age".to_string()),
+        Condition::Income { .. } => Some("income".to_string()),
+        Condition::HasAttribute { key } | Condition::AttributeEquals { key, .. } => {
+            Some(key.clone())
+        }
+        Condition::DateRange { .. } => Some("current_date".to_string()),
+        Condition::ResidencyDuration { .. } => Some("residency_months".to_string()),
+        Condition::Duration { unit, .. } => Some(format!("duration_{:?}", unit).to_lowercase()),
+        Condition::Percentage { context, .. } => Some(format!("percentage_{}", context)),
+        Condition::SetMembership { attribute, .. }
+        | Condition::Pattern { attribute, .. }
+        | Condition::Fuzzy { attribute, .. } => Some(attribute.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use legalis_core::{ComparisonOp, EffectType, StatuteException, TemporalValidity};
+
+    fn benefit() -> Statute {
+        Statute::new(
+            "benefit",
+            "Family benefit",
+            Effect::new(EffectType::Grant, "Benefit"),
+        )
+        .with_precondition(
+            Condition::age(ComparisonOp::GreaterOrEqual, 18)
+                .and(Condition::attribute_equals("resident", "true")),
+        )
+        .with_exception(StatuteException::new(
+            "abroad",
+            "Residents abroad are excluded",
+            Condition::has_attribute("abroad"),
+        ))
+    }
+
+    #[test]
+    fn test_decide_applies_with_reasoning() {
+        let request = DecisionRequest::new()
+            .with_fact("age", 30)
+            .with_fact("resident", true);
+        let decision = decide(&[benefit()], &request);
+
+        let result = &decision.results[0];
+        assert_eq!(result.outcome, DecisionOutcome::Applies);
+        assert_eq!(result.reason, ApplicationReason::Applied);
+        assert!(result.missing_facts.is_empty());
+        // One precondition and one exception
+        assert_eq!(result.audit_trail.len(), 2);
+        assert!(result.audit_trail[0].result);
+        assert!(!result.audit_trail[1].result);
+
+        let explanation = result.explanation.as_ref().unwrap();
+        assert_eq!(explanation.applicable_statutes, vec!["benefit".to_string()]);
+        assert!(!explanation.reasoning_chain.is_empty());
+        assert_eq!(decision.effects.len(), 1);
+        assert!(!decision.requires_discretion());
+    }
+
+    #[test]
+    fn test_decide_reports_missing_facts() {
+        let request = DecisionRequest::new()
+            .with_fact("age", 30)
+            .with_fact("resident", serde_json::Value::Null);
+        let decision = decide(&[benefit()], &request);
+
+        let result = &decision.results[0];
+        assert_ne!(result.outcome, DecisionOutcome::Applies);
+        assert_eq!(result.missing_facts.len(), 1);
+        assert_eq!(result.missing_facts[0].fact, "resident");
+        assert_eq!(decision.missing_facts, vec!["resident".to_string()]);
+        assert!(decision.effects.is_empty());
+    }
+
+    #[test]
+    fn test_decide_short_circuits_missing_facts() {
+        // Age alone settles the OR, so income is not needed
+        let statute = Statute::new("s", "S", Effect::new(EffectType::Grant, "G"))
+            .with_precondition(
+                Condition::age(ComparisonOp::GreaterOrEqual, 65)
+                    .or(Condition::income(ComparisonOp::LessThan, 10_000)),
+            );
+        let decision = decide(&[statute], &DecisionRequest::new().with_fact("age", 70));
+        assert!(decision.missing_facts.is_empty());
+        assert_eq!(decision.results[0].outcome, DecisionOutcome::Applies);
+    }
+
+    #[test]
+    fn test_decide_as_of_date() {
+        let statute = benefit().with_temporal_validity(
+            TemporalValidity::new().with_expiry_date(NaiveDate::from_ymd_opt(2030, 3, 31).unwrap()),
+        );
+        let entity_id = Uuid::new_v4();
+        let request = DecisionRequest::new()
+            .with_fact("age", 30)
+            .with_fact("resident", "true")
+            .with_entity_id(entity_id)
+            .with_date(NaiveDate::from_ymd_opt(2031, 1, 1).unwrap());
+        let decision = decide(&[statute], &request);
+
+        assert_eq!(decision.entity_id, entity_id);
+        assert_eq!(decision.results[0].outcome, DecisionOutcome::NotApplicable);
+        assert!(matches!(
+            decision.results[0].reason,
+            ApplicationReason::NotInForce { .. }
+        ));
+
+        let details = decision.audit_details();
+        assert_eq!(details["statutes"][0]["outcome"], "not_applicable");
+        assert_eq!(details["entity_id"], entity_id.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_load_statutes_as_of() {
+        let store = StatuteStore::in_memory();
+        let original = benefit().with_temporal_validity(
+            TemporalValidity::new()
+                .with_effective_date(NaiveDate::from_ymd_opt(2015, 1, 1).unwrap()),
+        );
+        store.insert(original.clone()).await.unwrap();
+        let recorded = store.entry("benefit").await.unwrap().unwrap().modified_at;
+
+        let mut amended = original;
+        amended.preconditions = vec![Condition::age(ComparisonOp::GreaterOrEqual, 20)];
+        amended.temporal_validity.effective_date = NaiveDate::from_ymd_opt(2020, 1, 1);
+        store.update(amended).await.unwrap();
+
+        let date = NaiveDate::from_ymd_opt(2019, 4, 1).unwrap();
+        let request = DecisionRequest::new().with_date(date);
+        let statutes = load_statutes(&store, &request).await.unwrap();
+        assert_eq!(statutes[0].preconditions.len(), 1);
+        assert!(matches!(statutes[0].preconditions[0], Condition::And(..)));
+
+        // Re-running today's law as known before the amendment
+        let request = DecisionRequest::new()
+            .with_date(NaiveDate::from_ymd_opt(2021, 1, 1).unwrap())
+            .with_known_at(recorded);
+        let statutes = load_statutes(&store, &request).await.unwrap();
+        assert!(matches!(statutes[0].preconditions[0], Condition::And(..)));
+
+        // Named statutes not yet in force are still decided, as not applicable
+        let before = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap();
+        let request = DecisionRequest::new()
+            .with_statute("benefit")
+            .with_date(before)
+            .with_fact("age", 30);
+        let statutes = load_statutes(&store, &request).await.unwrap();
+        let decision = decide(&statutes, &request);
+        assert_eq!(decision.results[0].outcome, DecisionOutcome::NotApplicable);
+        assert!(matches!(
+            load_statutes(&store, &DecisionRequest::new().with_date(before)).await,
+            Err(DecisionError::NoStatutes)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_load_statutes_rejects_unknown_ids() {
+        let store = StatuteStore::in_memory();
+        assert!(matches!(
+            load_statutes(&store, &DecisionRequest::new()).await,
+            Err(DecisionError::NoStatutes)
+        ));
+
+        store.insert(benefit()).await.unwrap();
+        assert_eq!(
+            load_statutes(&store, &DecisionRequest::new())
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        let request = DecisionRequest::new()
+            .with_statute("benefit")
+            .with_statute("nope");
+        match load_statutes(&store, &request).await {
+            Err(DecisionError::UnknownStatutes(ids)) => assert_eq!(ids, vec!["nope".to_string()]),
+            other => panic!("unexpected result: {:?}", other.map(|s| s.len())),
+        }
+    }
+}
@@ -0,0 +1,273 @@
+//! Federation endpoints: signed statute search for peer registries.
+//!
+//! Regional registries answer each other's searches over plain HTTP. Every
+//! answer is signed with the registry's Ed25519 key, so a peer that pinned
+//! the key can tell a genuine response from a forged or replayed one.
+//!
+//! | Method | Path                          | Purpose                                         |
+//! |--------|-------------------------------|-------------------------------------------------|
+//! | `GET`  | `/api/v1/federation/identity` | Returns the [`PeerIdentity`] peers pin          |
+//! | `GET`  | `/api/v1/federation/search`   | Answers a search with a [`PeerSearchResponse`]  |
+//!
+//! Both return `404` unless the server was started with a federation key.
+//! Only public statutes are shared; the fan-out side lives in
+//! `legalis_registry::federation::FederatedQueryEngine`.
+
+use crate::config::FederationConfig;
+use crate::registry_protocol::Visibility;
+use crate::store::StatuteStore;
+use legalis_registry::RankingConfig;
+use legalis_registry::RegistryError;
+use legalis_registry::federation::{
+    FederationError, FederationKeyPair, PeerIdentity, PeerSearchRequest, PeerSearchResponse,
+    SearchHit,
+};
+use serde::Deserialize;
+use std::path::Path;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Largest number of hits a peer may ask for.
+pub const MAX_SEARCH_LIMIT: usize = 1000;
+
+/// Errors raised while setting up the federation identity.
+#[derive(Debug, Error)]
+pub enum IdentityError {
+    #[error("Failed to access federation key {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[error(transparent)]
+    Key(#[from] FederationError),
+}
+
+/// This server's identity within a federation.
+#[derive(Debug)]
+pub struct FederationIdentity {
+    registry_id: Uuid,
+    name: String,
+    jurisdictions: Vec<String>,
+    key: FederationKeyPair,
+    ranking: RankingConfig,
+}
+
+impl FederationIdentity {
+    /// Creates an identity with a random registry ID.
+    pub fn new(name: impl Into<String>, key: FederationKeyPair) -> Self {
+        Self {
+            registry_id: Uuid::new_v4(),
+            name: name.into(),
+            jurisdictions: Vec::new(),
+            key,
+            ranking: RankingConfig::default(),
+        }
+    }
+
+    /// Loads the identity described by the configuration.
+    ///
+    /// The key file is created with a fresh key when it does not exist.
+    pub fn from_config(config: &FederationConfig) -> Result<Self, IdentityError> {
+        let path = config
+            .key_path
+            .as_deref()
+            .ok_or_else(|| FederationError::InvalidKey("no key file configured".to_string()))?;
+        let key = load_or_create_key(path)?;
+
+        let mut identity = Self::new(
+            config.name.clone().unwrap_or_else(|| "legalis".to_string()),
+            key,
+        )
+        .with_jurisdictions(config.jurisdictions.clone());
+        if let Some(registry_id) = config.registry_id {
+            identity = identity.with_registry_id(registry_id);
+        }
+        Ok(identity)
+    }
+
+    /// Sets the registry ID.
+    pub fn with_registry_id(mut self, registry_id: Uuid) -> Self {
+        self.registry_id = registry_id;
+        self
+    }
+
+    /// Sets the covered jurisdictions.
+    pub fn with_jurisdictions(mut self, jurisdictions: Vec<String>) -> Self {
+        self.jurisdictions = jurisdictions;
+        self
+    }
+
+    /// Sets the field weights used to rank hits.
+    pub fn with_ranking_config(mut self, ranking: RankingConfig) -> Self {
+        self.ranking = ranking;
+        self
+    }
+
+    /// Returns the registry ID.
+    pub fn registry_id(&self) -> Uuid {
+        self.registry_id
+    }
+
+    /// Returns the identity published to peers.
+    pub fn identity(&self) -> PeerIdentity {
+        PeerIdentity {
+            registry_id: self.registry_id,
+            name: self.name.clone(),
+            jurisdictions: self.jurisdictions.clone(),
+            public_key: self.key.public_key(),
+        }
+    }
+
+    /// Runs a peer search against the public statutes and signs the answer.
+    pub async fn search(
+        &self,
+        store: &StatuteStore,
+        request: &PeerSearchRequest,
+    ) -> Result<PeerSearchResponse, RegistryError> {
+        let entries = store.entries().await?;
+        let candidates = entries
+            .iter()
+            .filter(|entry| Visibility::of(entry) == Visibility::Public)
+            .map(SearchHit::from);
+        let hits = request.rank(candidates, &self.ranking);
+
+        let mut response = PeerSearchResponse::new(self.registry_id, &self.name, request, hits);
+        self.key.sign(&mut response);
+        Ok(response)
+    }
+}
+
+/// Reads a PKCS#8 key file, generating it first if it is missing.
+fn load_or_create_key(path: &Path) -> Result<FederationKeyPair, IdentityError> {
+    let io_error = |source| IdentityError::Io {
+        path: path.display().to_string(),
+        source,
+    };
+
+    if !path.exists() {
+        let pkcs8 = FederationKeyPair::generate_pkcs8()?;
+        write_private(path, &pkcs8).map_err(io_error)?;
+    }
+
+    let pkcs8 = std::fs::read(path).map_err(io_error)?;
+    Ok(FederationKeyPair::from_pkcs8(&pkcs8)?)
+}
+
+/// Writes a file readable only by its owner where the platform allows it.
+fn write_private(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)?.write_all(contents)
+}
+
+/// Query parameters of `/api/v1/federation/search`.
+#[derive(Debug, Deserialize)]
+pub struct FederationSearchQuery {
+    /// Query text
+    #[serde(default)]
+    pub q: String,
+    /// Maximum number of hits (default 50)
+    pub limit: Option<usize>,
+    /// Nonce echoed in the signed response
+    pub nonce: String,
+    /// Comma-separated jurisdiction codes
+    pub jurisdiction: Option<String>,
+}
+
+impl FederationSearchQuery {
+    /// Converts the query parameters into a search request.
+    pub fn into_request(self) -> PeerSearchRequest {
+        let jurisdictions = self
+            .jurisdiction
+            .map(|list| {
+                list.split(',')
+                    .map(str::trim)
+                    .filter(|j| !j.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        PeerSearchRequest {
+            query: self.q,
+            jurisdictions,
+            limit: self.limit.unwrap_or(50).min(MAX_SEARCH_LIMIT),
+            nonce: self.nonce,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use legalis_core::{Effect, EffectType, Statute};
+
+    fn identity() -> FederationIdentity {
+        let pkcs8 = FederationKeyPair::generate_pkcs8().unwrap();
+        FederationIdentity::new("Tokyo", FederationKeyPair::from_pkcs8(&pkcs8).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_search_is_signed_and_public_only() {
+        let store = StatuteStore::in_memory();
+        let effect = Effect::new(EffectType::Grant, "Benefit");
+        store
+            .insert(
+                Statute::new("tax-relief", "Tax Relief", effect.clone()).with_jurisdiction("JP"),
+            )
+            .await
+            .unwrap();
+        let mut private = store
+            .insert(Statute::new("tax-draft", "Tax Draft", effect))
+            .await
+            .unwrap();
+        private.metadata.insert(
+            crate::registry_protocol::VISIBILITY_KEY.to_string(),
+            "private".to_string(),
+        );
+        store.store_entry(&private).await.unwrap();
+
+        let identity = identity();
+        let request = PeerSearchRequest::new("tax");
+        let response = identity.search(&store, &request).await.unwrap();
+
+        let ids: Vec<_> = response
+            .hits
+            .iter()
+            .map(|h| h.statute_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["tax-relief"]);
+        assert_eq!(response.nonce, request.nonce);
+        response.verify(&identity.identity().public_key).unwrap();
+
+        let mut tampered = response.clone();
+        tampered.hits.clear();
+        assert_eq!(
+            tampered.verify(&identity.identity().public_key),
+            Err(FederationError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_key_file_is_created_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = FederationConfig {
+            key_path: Some(dir.path().join("federation.key")),
+            name: Some("Osaka".to_string()),
+            ..Default::default()
+        };
+
+        let first = FederationIdentity::from_config(&config).unwrap();
+        let second = FederationIdentity::from_config(&config).unwrap();
+        assert_eq!(first.identity().public_key, second.identity().public_key);
+        assert_eq!(first.identity().name, "Osaka");
+    }
+}
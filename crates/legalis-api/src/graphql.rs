@@ -8,12 +8,15 @@ use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
 use futures::{Stream, StreamExt};
 use legalis_core::{Effect, EffectType, Statute};
 use legalis_dsl::LegalDslParser;
+use legalis_registry::AsOf;
 use legalis_verifier::StatuteVerifier;
 use std::sync::Arc;
-use tokio::sync::RwLock;
 use tokio_stream::wrappers::BroadcastStream;
 
+use crate::audit::AuditLog;
 use crate::auth::{AuthUser, Permission};
+use crate::decide::{self, DecisionRequest};
+use crate::store::StatuteStore;
 use crate::websocket::{WsBroadcaster, WsNotification};
 
 /// GraphQL schema type.
@@ -22,24 +25,42 @@ pub type LegalisSchema = Schema<QueryRoot, MutationRoot, SubscriptionRoot>;
 /// Application state for GraphQL context.
 #[derive(Clone)]
 pub struct GraphQLState {
-    pub statutes: Arc<RwLock<Vec<Statute>>>,
+    pub statutes: StatuteStore,
     pub ws_broadcaster: WsBroadcaster,
+    pub audit_log: Arc<AuditLog>,
 }
 
 impl GraphQLState {
     pub fn new() -> Self {
         Self {
-            statutes: Arc::new(RwLock::new(Vec::new())),
+            statutes: StatuteStore::in_memory(),
             ws_broadcaster: WsBroadcaster::new(),
+            audit_log: Arc::new(AuditLog::new()),
         }
     }
 
     pub fn with_broadcaster(ws_broadcaster: WsBroadcaster) -> Self {
         Self {
-            statutes: Arc::new(RwLock::new(Vec::new())),
+            statutes: StatuteStore::in_memory(),
             ws_broadcaster,
+            audit_log: Arc::new(AuditLog::new()),
         }
     }
+
+    /// Creates a state that reads and writes the given shared statute store.
+    pub fn with_store(statutes: StatuteStore, ws_broadcaster: WsBroadcaster) -> Self {
+        Self {
+            statutes,
+            ws_broadcaster,
+            audit_log: Arc::new(AuditLog::new()),
+        }
+    }
+
+    /// Records decisions in the given audit log.
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLog>) -> Self {
+        self.audit_log = audit_log;
+        self
+    }
 }
 
 impl Default for GraphQLState {
@@ -146,33 +167,53 @@ pub struct QueryRoot;
 
 #[Object]
 impl QueryRoot {
-    /// Get all statutes.
+    /// Get all statutes, optionally as in force on `asOf` (YYYY-MM-DD) and
+    /// as recorded at `knownAt` (RFC 3339).
     #[graphql(complexity = 10)]
-    async fn statutes(&self, ctx: &Context<'_>) -> FieldResult<Vec<StatuteObject>> {
+    async fn statutes(
+        &self,
+        ctx: &Context<'_>,
+        as_of: Option<String>,
+        known_at: Option<String>,
+    ) -> FieldResult<Vec<StatuteObject>> {
         let state = ctx.data::<GraphQLState>()?;
-        let statutes = state.statutes.read().await;
+        let statutes = list_statutes(state, parse_as_of(as_of, known_at)?).await?;
         Ok(statutes.iter().map(StatuteObject::from).collect())
     }
 
-    /// Get a statute by ID.
-    async fn statute(&self, ctx: &Context<'_>, id: String) -> FieldResult<Option<StatuteObject>> {
+    /// Get a statute by ID, optionally the version in force on `asOf` as
+    /// recorded at `knownAt`.
+    async fn statute(
+        &self,
+        ctx: &Context<'_>,
+        id: String,
+        as_of: Option<String>,
+        known_at: Option<String>,
+    ) -> FieldResult<Option<StatuteObject>> {
         let state = ctx.data::<GraphQLState>()?;
-        let statutes = state.statutes.read().await;
-        Ok(statutes
-            .iter()
-            .find(|s| s.id == id)
-            .map(StatuteObject::from))
+        let statute = match parse_as_of(as_of, known_at)? {
+            Some(as_of) => state
+                .statutes
+                .get_as_of(&id, &as_of)
+                .await?
+                .map(|entry| entry.statute),
+            None => state.statutes.get(&id).await?,
+        };
+        Ok(statute.as_ref().map(StatuteObject::from))
     }
 
-    /// Search statutes by title.
+    /// Search statutes by title, optionally among those in force on `asOf`
+    /// as recorded at `knownAt`.
     #[graphql(complexity = 15)]
     async fn search_statutes(
         &self,
         ctx: &Context<'_>,
         query: String,
+        as_of: Option<String>,
+        known_at: Option<String>,
     ) -> FieldResult<Vec<StatuteObject>> {
         let state = ctx.data::<GraphQLState>()?;
-        let statutes = state.statutes.read().await;
+        let statutes = list_statutes(state, parse_as_of(as_of, known_at)?).await?;
         Ok(statutes
             .iter()
             .filter(|s| {
@@ -190,11 +231,10 @@ impl QueryRoot {
         jurisdiction: String,
     ) -> FieldResult<Vec<StatuteObject>> {
         let state = ctx.data::<GraphQLState>()?;
-        let statutes = state.statutes.read().await;
-        Ok(statutes
+        let entries = state.statutes.find_by_jurisdiction(&jurisdiction).await?;
+        Ok(entries
             .iter()
-            .filter(|s| s.jurisdiction.as_ref() == Some(&jurisdiction))
-            .map(StatuteObject::from)
+            .map(|entry| StatuteObject::from(&entry.statute))
             .collect())
     }
 
@@ -205,12 +245,12 @@ impl QueryRoot {
         statute_ids: Vec<String>,
     ) -> FieldResult<VerificationResult> {
         let state = ctx.data::<GraphQLState>()?;
-        let statutes_lock = state.statutes.read().await;
-
-        let statutes_to_verify: Vec<_> = statutes_lock
-            .iter()
+        let statutes_to_verify: Vec<_> = state
+            .statutes
+            .list()
+            .await?
+            .into_iter()
             .filter(|s| statute_ids.contains(&s.id))
-            .cloned()
             .collect();
 
         if statutes_to_verify.is_empty() {
@@ -231,8 +271,7 @@ impl QueryRoot {
     /// Get statute count.
     async fn statute_count(&self, ctx: &Context<'_>) -> FieldResult<i32> {
         let state = ctx.data::<GraphQLState>()?;
-        let statutes = state.statutes.read().await;
-        Ok(statutes.len() as i32)
+        Ok(state.statutes.count().await? as i32)
     }
 
     /// Get statutes with relay-style cursor pagination.
@@ -246,7 +285,7 @@ impl QueryRoot {
         before: Option<String>,
     ) -> FieldResult<StatuteConnection> {
         let state = ctx.data::<GraphQLState>()?;
-        let statutes = state.statutes.read().await;
+        let statutes = state.statutes.list().await?;
 
         // Convert statutes to a vec for easier processing
         let all_statutes: Vec<_> = statutes.iter().collect();
@@ -340,6 +379,260 @@ pub struct UpdateStatuteInput {
     pub version: Option<i32>,
 }
 
+/// A known fact about an entity.
+#[derive(async_graphql::InputObject)]
+pub struct FactInput {
+    /// Fact name (e.g. "age", "income")
+    pub key: String,
+    /// Fact value
+    pub value: String,
+}
+
+/// Input type for deciding an entity's case.
+#[derive(async_graphql::InputObject)]
+pub struct DecideInput {
+    /// Statutes to evaluate (all statutes when omitted)
+    pub statute_ids: Option<Vec<String>>,
+    /// Known facts about the entity
+    pub facts: Vec<FactInput>,
+    /// Evaluation date (YYYY-MM-DD)
+    pub date: Option<String>,
+    /// Entity or case identifier (UUID)
+    pub entity_id: Option<String>,
+    /// Only use statute versions recorded by this moment (RFC 3339)
+    pub known_at: Option<String>,
+}
+
+impl TryFrom<DecideInput> for DecisionRequest {
+    type Error = async_graphql::Error;
+
+    fn try_from(input: DecideInput) -> Result<Self, Self::Error> {
+        let mut request = DecisionRequest {
+            statute_ids: input.statute_ids.unwrap_or_default(),
+            ..DecisionRequest::default()
+        };
+        for fact in input.facts {
+            request = request.with_fact(fact.key, fact.value);
+        }
+        if let Some(date) = input.date {
+            let date = chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+                .map_err(|e| format!("Invalid date '{}': {}", date, e))?;
+            request = request.with_date(date);
+        }
+        if let Some(entity_id) = input.entity_id {
+            let entity_id = uuid::Uuid::parse_str(&entity_id)
+                .map_err(|e| format!("Invalid entity ID '{}': {}", entity_id, e))?;
+            request = request.with_entity_id(entity_id);
+        }
+        if let Some(known_at) = input.known_at {
+            request = request.with_known_at(parse_known_at(&known_at)?);
+        }
+        Ok(request)
+    }
+}
+
+/// Parses an RFC 3339 `knownAt` argument.
+fn parse_known_at(known_at: &str) -> FieldResult<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(known_at)
+        .map(|t| t.with_timezone(&chrono::Utc))
+        .map_err(|e| format!("Invalid knownAt '{}': {}", known_at, e).into())
+}
+
+/// Parses the optional `asOf` and `knownAt` arguments of statute queries.
+fn parse_as_of(as_of: Option<String>, known_at: Option<String>) -> FieldResult<Option<AsOf>> {
+    let as_of = as_of
+        .map(|date| {
+            chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+                .map_err(|e| format!("Invalid asOf '{}': {}", date, e))
+        })
+        .transpose()?;
+    let known_at = known_at.as_deref().map(parse_known_at).transpose()?;
+    Ok(AsOf::from_parts(as_of, known_at))
+}
+
+/// Lists the latest statutes, or those in force at a point in time.
+async fn list_statutes(
+    state: &GraphQLState,
+    as_of: Option<AsOf>,
+) -> legalis_registry::RegistryResult<Vec<Statute>> {
+    match as_of {
+        Some(as_of) => state.statutes.list_as_of(&as_of).await,
+        None => state.statutes.list().await,
+    }
+}
+
+/// Decision for an entity across a set of statutes.
+#[derive(SimpleObject)]
+pub struct DecisionObject {
+    /// Decision identifier, as recorded in the audit log
+    pub decision_id: String,
+    /// Entity or case identifier
+    pub entity_id: String,
+    /// When the decision was made (RFC 3339)
+    pub decided_at: String,
+    /// Per-statute outcomes
+    pub results: Vec<StatuteDecisionObject>,
+    /// IDs of the statutes whose effects apply
+    pub applied_statute_ids: Vec<String>,
+    /// Distinct facts still needed to settle undetermined conditions
+    pub missing_facts: Vec<String>,
+    /// Whether any statute needs a caseworker's judgment
+    pub requires_discretion: bool,
+}
+
+/// Outcome of a single statute in a decision.
+#[derive(SimpleObject)]
+pub struct StatuteDecisionObject {
+    /// Statute ID
+    pub statute_id: String,
+    /// Statute title
+    pub title: String,
+    /// Outcome (applies, not_applicable, discretion)
+    pub outcome: String,
+    /// Why the statute did or did not apply
+    pub reason: String,
+    /// Effect description, when the statute applies
+    pub effect: Option<String>,
+    /// Issue to resolve, when the outcome needs discretion
+    pub discretion_issue: Option<String>,
+    /// Computed effect outputs
+    pub outputs: Vec<DecisionOutputObject>,
+    /// Reasoning steps over the statute's preconditions
+    pub reasoning: Vec<ReasoningStepObject>,
+    /// Conditions evaluated, in order
+    pub evaluations: Vec<EvaluationObject>,
+    /// Facts needed to settle undetermined conditions
+    pub missing_facts: Vec<MissingFactObject>,
+}
+
+/// A computed effect output.
+#[derive(SimpleObject)]
+pub struct DecisionOutputObject {
+    /// Output name
+    pub name: String,
+    /// Formatted value
+    pub value: String,
+    /// Currency or duration unit
+    pub unit: Option<String>,
+}
+
+/// A step in a statute's reasoning chain.
+#[derive(SimpleObject)]
+pub struct ReasoningStepObject {
+    /// Step number
+    pub step: i32,
+    /// Description of the step
+    pub description: String,
+    /// Condition evaluated in this step
+    pub condition: Option<String>,
+    /// Result of the step
+    pub result: String,
+}
+
+/// A single condition evaluation.
+#[derive(SimpleObject)]
+pub struct EvaluationObject {
+    /// Condition evaluated
+    pub condition: String,
+    /// Whether the condition held
+    pub result: bool,
+    /// Evaluation time in microseconds
+    pub duration_micros: i64,
+}
+
+/// A fact whose absence left a condition undetermined.
+#[derive(SimpleObject)]
+pub struct MissingFactObject {
+    /// Fact name
+    pub fact: String,
+    /// Undetermined condition
+    pub condition: String,
+    /// Why the condition could not be evaluated
+    pub reason: String,
+}
+
+impl From<&decide::Decision> for DecisionObject {
+    fn from(decision: &decide::Decision) -> Self {
+        Self {
+            decision_id: decision.decision_id.clone(),
+            entity_id: decision.entity_id.to_string(),
+            decided_at: decision.decided_at.to_rfc3339(),
+            results: decision
+                .results
+                .iter()
+                .map(StatuteDecisionObject::from)
+                .collect(),
+            applied_statute_ids: decision
+                .effects
+                .iter()
+                .map(|e| e.statute_id.clone())
+                .collect(),
+            missing_facts: decision.missing_facts.clone(),
+            requires_discretion: decision.requires_discretion(),
+        }
+    }
+}
+
+impl From<&decide::StatuteDecision> for StatuteDecisionObject {
+    fn from(result: &decide::StatuteDecision) -> Self {
+        let (effect, discretion_issue) = match &result.result {
+            legalis_core::LegalResult::Deterministic(effect) => {
+                (Some(effect.description.clone()), None)
+            }
+            legalis_core::LegalResult::JudicialDiscretion { issue, .. } => {
+                (None, Some(issue.clone()))
+            }
+            legalis_core::LegalResult::Void { .. } => (None, None),
+        };
+        Self {
+            statute_id: result.statute_id.clone(),
+            title: result.title.clone(),
+            outcome: result.outcome.as_str().to_string(),
+            reason: result.reason.to_string(),
+            effect,
+            discretion_issue,
+            outputs: result
+                .outputs
+                .iter()
+                .map(|(name, value)| DecisionOutputObject {
+                    name: name.clone(),
+                    value: value.to_string(),
+                    unit: value.unit(),
+                })
+                .collect(),
+            reasoning: result
+                .explanation
+                .iter()
+                .flat_map(|e| &e.reasoning_chain)
+                .map(|step| ReasoningStepObject {
+                    step: step.step as i32,
+                    description: step.description.clone(),
+                    condition: step.condition.clone(),
+                    result: format!("{:?}", step.result),
+                })
+                .collect(),
+            evaluations: result
+                .audit_trail
+                .iter()
+                .map(|record| EvaluationObject {
+                    condition: record.condition.clone(),
+                    result: record.result,
+                    duration_micros: record.duration_micros as i64,
+                })
+                .collect(),
+            missing_facts: result
+                .missing_facts
+                .iter()
+                .map(|m| MissingFactObject {
+                    fact: m.fact.clone(),
+                    condition: m.condition.clone(),
+                    reason: m.reason.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
 /// Mutation root.
 pub struct MutationRoot;
 
@@ -356,10 +649,9 @@ impl MutationRoot {
         check_permission(ctx, Permission::CreateStatutes)?;
 
         let state = ctx.data::<GraphQLState>()?;
-        let mut statutes = state.statutes.write().await;
 
         // Check if statute already exists
-        if statutes.iter().any(|s| s.id == input.id) {
+        if state.statutes.contains(&input.id).await? {
             return Err(format!("Statute with ID '{}' already exists", input.id).into());
         }
 
@@ -392,8 +684,7 @@ impl MutationRoot {
         let statute_obj = StatuteObject::from(&statute);
         let statute_id = statute.id.clone();
         let statute_title = statute.title.clone();
-        statutes.push(statute);
-        drop(statutes);
+        state.statutes.insert(statute).await?;
 
         // Broadcast WebSocket notification
         let user_id = ctx
@@ -422,12 +713,12 @@ impl MutationRoot {
         check_permission(ctx, Permission::UpdateStatutes)?;
 
         let state = ctx.data::<GraphQLState>()?;
-        let mut statutes = state.statutes.write().await;
 
         // Find statute
-        let statute = statutes
-            .iter_mut()
-            .find(|s| s.id == input.id)
+        let mut statute = state
+            .statutes
+            .get(&input.id)
+            .await?
             .ok_or_else(|| format!("Statute with ID '{}' not found", input.id))?;
 
         // Update fields
@@ -443,10 +734,12 @@ impl MutationRoot {
             statute.version = version as u32;
         }
 
-        let statute_obj = StatuteObject::from(&*statute);
+        let statute_obj = StatuteObject::from(&statute);
         let statute_id = statute.id.clone();
         let statute_title = statute.title.clone();
-        drop(statutes);
+
+        // Stored as the next registry version of the statute
+        state.statutes.update(statute).await?;
 
         // Broadcast WebSocket notification
         let user_id = ctx
@@ -471,12 +764,7 @@ impl MutationRoot {
         check_permission(ctx, Permission::DeleteStatutes)?;
 
         let state = ctx.data::<GraphQLState>()?;
-        let mut statutes = state.statutes.write().await;
-
-        let initial_len = statutes.len();
-        statutes.retain(|s| s.id != id);
-        let deleted = statutes.len() < initial_len;
-        drop(statutes);
+        let deleted = state.statutes.delete(&id).await?;
 
         // Broadcast WebSocket notification if deleted
         if deleted {
@@ -502,7 +790,6 @@ impl MutationRoot {
         dsl: String,
     ) -> FieldResult<StatuteObject> {
         let state = ctx.data::<GraphQLState>()?;
-        let mut statutes = state.statutes.write().await;
 
         let parser = LegalDslParser::new();
         let statute = parser
@@ -510,25 +797,48 @@ impl MutationRoot {
             .map_err(|e| format!("Parse error: {}", e))?;
 
         // Check if statute already exists
-        if statutes.iter().any(|s| s.id == statute.id) {
+        if state.statutes.contains(&statute.id).await? {
             return Err(format!("Statute with ID '{}' already exists", statute.id).into());
         }
 
         let statute_obj = StatuteObject::from(&statute);
-        statutes.push(statute);
+        state.statutes.insert(statute).await?;
 
         Ok(statute_obj)
     }
 
+    /// Decide an entity's case against a set of statutes.
+    /// Requires VerifyStatutes permission; the decision is written to the audit log.
+    async fn decide(&self, ctx: &Context<'_>, input: DecideInput) -> FieldResult<DecisionObject> {
+        check_permission(ctx, Permission::VerifyStatutes)?;
+
+        let state = ctx.data::<GraphQLState>()?;
+        let request = DecisionRequest::try_from(input)?;
+        let statutes = decide::load_statutes(&state.statutes, &request).await?;
+        let decision = decide::decide(&statutes, &request);
+
+        let user = ctx.data::<AuthUser>()?;
+        decide::record_decision(
+            &state.audit_log,
+            user.id.to_string(),
+            user.username.clone(),
+            &decision,
+        )
+        .await;
+
+        Ok(DecisionObject::from(&decision))
+    }
+
     /// Clear all statutes (use with caution!).
     async fn clear_statutes(&self, ctx: &Context<'_>) -> FieldResult<i32> {
         let state = ctx.data::<GraphQLState>()?;
-        let mut statutes = state.statutes.write().await;
+        let statutes = state.statutes.list().await?;
 
-        let count = statutes.len() as i32;
-        statutes.clear();
+        for statute in &statutes {
+            state.statutes.delete(&statute.id).await?;
+        }
 
-        Ok(count)
+        Ok(statutes.len() as i32)
     }
 }
 
@@ -778,17 +1088,18 @@ mod tests {
         let state = GraphQLState::new();
 
         // Add a test statute
-        {
-            let mut statutes = state.statutes.write().await;
-            statutes.push(
+        state
+            .statutes
+            .insert(
                 Statute::new(
                     "test-1",
                     "Test Statute 1",
                     Effect::new(EffectType::Grant, "Test benefit"),
                 )
                 .with_jurisdiction("US"),
-            );
-        }
+            )
+            .await
+            .unwrap();
 
         let schema = create_schema(state.clone());
 
@@ -805,4 +1116,100 @@ mod tests {
         let result = schema.execute(query).await;
         assert!(result.errors.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_decide_mutation() {
+        use crate::auth::{AuthMethod, Role};
+        use legalis_core::{ComparisonOp, Condition};
+
+        let state = GraphQLState::new();
+        state
+            .statutes
+            .insert(
+                Statute::new(
+                    "pension",
+                    "Pension",
+                    Effect::new(EffectType::Grant, "Pension"),
+                )
+                .with_precondition(
+                    Condition::age(ComparisonOp::GreaterOrEqual, 65)
+                        .and(Condition::income(ComparisonOp::LessThan, 30_000)),
+                ),
+            )
+            .await
+            .unwrap();
+        let schema = create_schema(state.clone());
+
+        let query = r#"
+            mutation {
+                decide(input: { facts: [{ key: "age", value: "70" }] }) {
+                    decisionId
+                    missingFacts
+                    results { statuteId outcome evaluations { condition result } }
+                }
+            }
+        "#;
+        let user = AuthUser::new(
+            uuid::Uuid::new_v4(),
+            "caseworker".to_string(),
+            Role::Analyst,
+            AuthMethod::Jwt,
+        );
+        let result = schema
+            .execute(async_graphql::Request::new(query).data(user))
+            .await;
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+        let data = result.data.into_json().unwrap();
+        assert_eq!(data["decide"]["missingFacts"][0], "income");
+        assert_eq!(data["decide"]["results"][0]["statuteId"], "pension");
+        assert_eq!(state.audit_log.count().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_statutes_as_of() {
+        use legalis_core::TemporalValidity;
+
+        let state = GraphQLState::new();
+        let expiring = TemporalValidity::new()
+            .with_expiry_date(chrono::NaiveDate::from_ymd_opt(2018, 12, 31).unwrap());
+        state
+            .statutes
+            .insert(
+                Statute::new("old", "Old", Effect::new(EffectType::Grant, "Old"))
+                    .with_temporal_validity(expiring),
+            )
+            .await
+            .unwrap();
+        state
+            .statutes
+            .insert(Statute::new(
+                "new",
+                "New",
+                Effect::new(EffectType::Grant, "New"),
+            ))
+            .await
+            .unwrap();
+        let schema = create_schema(state);
+
+        let result = schema
+            .execute(
+                r#"{
+                    then: statutes(asOf: "2018-06-01") { id }
+                    now: statutes(asOf: "2019-04-01") { id }
+                    old: statute(id: "old", asOf: "2019-04-01") { id }
+                }"#,
+            )
+            .await;
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        let data = result.data.into_json().unwrap();
+        assert_eq!(data["then"].as_array().unwrap().len(), 2);
+        assert_eq!(data["now"], serde_json::json!([{ "id": "new" }]));
+        assert!(data["old"].is_null());
+
+        let result = schema
+            .execute(r#"{ statutes(asOf: "2019-04-01", knownAt: "yesterday") { id } }"#)
+            .await;
+        assert_eq!(result.errors.len(), 1);
+    }
 }
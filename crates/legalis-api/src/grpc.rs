@@ -12,13 +12,16 @@ pub mod service {
     use futures::FutureExt;
     use std::collections::HashMap;
     use std::pin::Pin;
-    use std::sync::Arc;
-    use tokio::sync::RwLock;
     use tokio_stream::{Stream, wrappers::ReceiverStream};
     use tonic::{Request, Response, Status}; // For now_or_never()
 
-    use legalis_core::{Effect, EffectType, Statute};
+    use crate::audit::AuditLog;
+    use crate::decide::{self, Decision, DecisionError, DecisionOutcome, DecisionRequest};
+    use crate::store::StatuteStore;
+    use legalis_core::{Effect, EffectType, LegalResult, Statute};
+    use legalis_registry::RegistryError;
     use legalis_verifier::StatuteVerifier;
+    use std::sync::Arc;
 
     // Include the generated protobuf code
     pub mod pb {
@@ -35,15 +38,40 @@ pub mod service {
     /// gRPC service state
     #[derive(Clone)]
     pub struct GrpcServiceState {
-        pub statutes: Arc<RwLock<Vec<Statute>>>,
+        pub statutes: StatuteStore,
+        pub audit_log: Arc<AuditLog>,
     }
 
     impl GrpcServiceState {
         pub fn new() -> Self {
+            Self::with_store(StatuteStore::in_memory())
+        }
+
+        /// Creates a state that reads and writes the given shared statute store.
+        pub fn with_store(statutes: StatuteStore) -> Self {
             Self {
-                statutes: Arc::new(RwLock::new(Vec::new())),
+                statutes,
+                audit_log: Arc::new(AuditLog::new()),
             }
         }
+
+        /// Records decisions in the given audit log.
+        pub fn with_audit_log(mut self, audit_log: Arc<AuditLog>) -> Self {
+            self.audit_log = audit_log;
+            self
+        }
+    }
+
+    /// Maps a storage failure to a gRPC status.
+    fn storage_status(err: RegistryError) -> Status {
+        match err {
+            RegistryError::StatuteNotFound(_) | RegistryError::VersionNotFound { .. } => {
+                Status::not_found(err.to_string())
+            }
+            RegistryError::DuplicateId(_) => Status::already_exists(err.to_string()),
+            RegistryError::ConcurrentModification { .. } => Status::aborted(err.to_string()),
+            _ => Status::internal(err.to_string()),
+        }
     }
 
     impl Default for GrpcServiceState {
@@ -62,6 +90,134 @@ pub mod service {
             Self { state }
         }
 
+        /// Convert internal Effect to protobuf Effect
+        fn effect_to_proto(effect: &Effect) -> pb::Effect {
+            pb::Effect {
+                effect_type: match effect.effect_type {
+                    EffectType::Grant => pb::EffectType::Grant as i32,
+                    EffectType::Revoke => pb::EffectType::Revoke as i32,
+                    EffectType::Obligation => pb::EffectType::Obligation as i32,
+                    EffectType::Prohibition => pb::EffectType::Prohibition as i32,
+                    // Map additional effect types to closest equivalent or obligation
+                    EffectType::MonetaryTransfer => pb::EffectType::Obligation as i32,
+                    EffectType::StatusChange => pb::EffectType::Obligation as i32,
+                    EffectType::Custom => pb::EffectType::Obligation as i32,
+                },
+                description: effect.description.clone(),
+                parameters: effect.parameters.clone(),
+            }
+        }
+
+        /// Convert a decision request from protobuf
+        fn decision_request_from_proto(req: DecideRequest) -> Result<DecisionRequest, Status> {
+            let mut request = DecisionRequest {
+                statute_ids: req.statute_ids,
+                ..DecisionRequest::default()
+            };
+            for (key, value) in req.facts {
+                request = request.with_fact(key, value);
+            }
+            if !req.date.is_empty() {
+                let date = chrono::NaiveDate::parse_from_str(&req.date, "%Y-%m-%d")
+                    .map_err(|e| Status::invalid_argument(format!("Invalid date: {}", e)))?;
+                request = request.with_date(date);
+            }
+            if !req.entity_id.is_empty() {
+                let entity_id = uuid::Uuid::parse_str(&req.entity_id)
+                    .map_err(|e| Status::invalid_argument(format!("Invalid entity ID: {}", e)))?;
+                request = request.with_entity_id(entity_id);
+            }
+            if !req.known_at.is_empty() {
+                let known_at = chrono::DateTime::parse_from_rfc3339(&req.known_at)
+                    .map_err(|e| Status::invalid_argument(format!("Invalid known_at: {}", e)))?;
+                request = request.with_known_at(known_at.with_timezone(&chrono::Utc));
+            }
+            Ok(request)
+        }
+
+        /// Convert a decision to protobuf
+        fn decision_to_proto(decision: &Decision) -> DecideResponse {
+            let results = decision
+                .results
+                .iter()
+                .map(|r| {
+                    let (effect, discretion_issue) = match &r.result {
+                        LegalResult::Deterministic(effect) => {
+                            (Some(Self::effect_to_proto(effect)), String::new())
+                        }
+                        LegalResult::JudicialDiscretion { issue, .. } => (None, issue.clone()),
+                        LegalResult::Void { .. } => (None, String::new()),
+                    };
+                    pb::StatuteDecision {
+                        statute_id: r.statute_id.clone(),
+                        title: r.title.clone(),
+                        outcome: match r.outcome {
+                            DecisionOutcome::Applies => pb::DecisionOutcome::Applies as i32,
+                            DecisionOutcome::NotApplicable => {
+                                pb::DecisionOutcome::NotApplicable as i32
+                            }
+                            DecisionOutcome::Discretion => pb::DecisionOutcome::Discretion as i32,
+                        },
+                        reason: r.reason.to_string(),
+                        effect,
+                        discretion_issue,
+                        outputs: r
+                            .outputs
+                            .iter()
+                            .map(|(name, value)| pb::DecisionOutput {
+                                name: name.clone(),
+                                value: value.to_string(),
+                                unit: value.unit().unwrap_or_default(),
+                            })
+                            .collect(),
+                        reasoning: r
+                            .explanation
+                            .iter()
+                            .flat_map(|e| &e.reasoning_chain)
+                            .map(|step| pb::ReasoningStep {
+                                step: step.step as i32,
+                                description: step.description.clone(),
+                                condition: step.condition.clone().unwrap_or_default(),
+                                result: format!("{:?}", step.result),
+                            })
+                            .collect(),
+                        evaluations: r
+                            .audit_trail
+                            .iter()
+                            .map(|record| pb::ConditionEvaluation {
+                                condition: record.condition.clone(),
+                                result: record.result,
+                                duration_micros: record.duration_micros,
+                            })
+                            .collect(),
+                        missing_facts: r
+                            .missing_facts
+                            .iter()
+                            .map(|m| pb::MissingFact {
+                                fact: m.fact.clone(),
+                                condition: m.condition.clone(),
+                                reason: m.reason.clone(),
+                            })
+                            .collect(),
+                    }
+                })
+                .collect();
+
+            DecideResponse {
+                decision_id: decision.decision_id.clone(),
+                entity_id: decision.entity_id.to_string(),
+                decided_at: decision.decided_at.timestamp(),
+                results,
+                applied_statute_ids: decision
+                    .effects
+                    .iter()
+                    .map(|e| e.statute_id.clone())
+                    .collect(),
+                missing_facts: decision.missing_facts.clone(),
+                requires_discretion: decision.requires_discretion(),
+            }
+        }
+
         /// Convert internal Statute to protobuf Statute
         fn statute_to_proto(statute: &Statute) -> pb::Statute {
             pb::Statute {
@@ -69,20 +225,7 @@ pub mod service {
                 title: statute.title.clone(),
                 version: statute.version as i32,
                 jurisdiction: statute.jurisdiction.clone().unwrap_or_default(),
-                effect: Some(pb::Effect {
-                    effect_type: match statute.effect.effect_type {
-                        EffectType::Grant => pb::EffectType::Grant as i32,
-                        EffectType::Revoke => pb::EffectType::Revoke as i32,
-                        EffectType::Obligation => pb::EffectType::Obligation as i32,
-                        EffectType::Prohibition => pb::EffectType::Prohibition as i32,
-                        // Map additional effect types to closest equivalent or obligation
-                        EffectType::MonetaryTransfer => pb::EffectType::Obligation as i32,
-                        EffectType::StatusChange => pb::EffectType::Obligation as i32,
-                        EffectType::Custom => pb::EffectType::Obligation as i32,
-                    },
-                    description: statute.effect.description.clone(),
-                    parameters: statute.effect.parameters.clone(),
-                }),
+                effect: Some(Self::effect_to_proto(&statute.effect)),
                 preconditions: statute
                     .preconditions
                     .iter()
@@ -126,16 +269,19 @@ pub mod service {
             request: Request<ListStatutesRequest>,
         ) -> Result<Response<ListStatutesResponse>, Status> {
             let req = request.into_inner();
-            let statutes = self.state.statutes.read().await;
 
             // Filter by jurisdiction if provided
-            let filtered: Vec<_> = if !req.jurisdiction.is_empty() {
-                statutes
-                    .iter()
-                    .filter(|s| s.jurisdiction.as_ref() == Some(&req.jurisdiction))
+            let filtered: Vec<Statute> = if !req.jurisdiction.is_empty() {
+                self.state
+                    .statutes
+                    .find_by_jurisdiction(&req.jurisdiction)
+                    .await
+                    .map_err(storage_status)?
+                    .into_iter()
+                    .map(|entry| entry.statute)
                     .collect()
             } else {
-                statutes.iter().collect()
+                self.state.statutes.list().await.map_err(storage_status)?
             };
 
             let total_count = filtered.len() as i32;
@@ -156,11 +302,13 @@ pub mod service {
             request: Request<GetStatuteRequest>,
         ) -> Result<Response<GetStatuteResponse>, Status> {
             let req = request.into_inner();
-            let statutes = self.state.statutes.read().await;
 
-            let statute = statutes
-                .iter()
-                .find(|s| s.id == req.statute_id)
+            let statute = self
+                .state
+                .statutes
+                .get(&req.statute_id)
+                .await
+                .map_err(storage_status)?
                 .ok_or_else(|| {
                     Status::not_found(format!("Statute not found: {}", req.statute_id))
                 })?;
@@ -177,10 +325,15 @@ pub mod service {
             request: Request<CreateStatuteRequest>,
         ) -> Result<Response<pb::Statute>, Status> {
             let req = request.into_inner();
-            let mut statutes = self.state.statutes.write().await;
 
             // Check if statute already exists
-            if statutes.iter().any(|s| s.id == req.id) {
+            if self
+                .state
+                .statutes
+                .contains(&req.id)
+                .await
+                .map_err(storage_status)?
+            {
                 return Err(Status::already_exists(format!(
                     "Statute already exists: {}",
                     req.id
@@ -217,7 +370,11 @@ pub mod service {
             }
 
             let proto_statute = Self::statute_to_proto(&statute);
-            statutes.push(statute);
+            self.state
+                .statutes
+                .insert(statute)
+                .await
+                .map_err(storage_status)?;
 
             Ok(Response::new(proto_statute))
         }
@@ -227,11 +384,13 @@ pub mod service {
             request: Request<UpdateStatuteRequest>,
         ) -> Result<Response<pb::Statute>, Status> {
             let req = request.into_inner();
-            let mut statutes = self.state.statutes.write().await;
 
-            let statute = statutes
-                .iter_mut()
-                .find(|s| s.id == req.id)
+            let mut statute = self
+                .state
+                .statutes
+                .get(&req.id)
+                .await
+                .map_err(storage_status)?
                 .ok_or_else(|| Status::not_found(format!("Statute not found: {}", req.id)))?;
 
             if !req.title.is_empty() {
@@ -246,7 +405,14 @@ pub mod service {
                 statute.version = req.version as u32;
             }
 
-            Ok(Response::new(Self::statute_to_proto(statute)))
+            let proto_statute = Self::statute_to_proto(&statute);
+            self.state
+                .statutes
+                .update(statute)
+                .await
+                .map_err(storage_status)?;
+
+            Ok(Response::new(proto_statute))
         }
 
         async fn delete_statute(
@@ -254,12 +420,15 @@ pub mod service {
             request: Request<DeleteStatuteRequest>,
         ) -> Result<Response<DeleteStatuteResponse>, Status> {
             let req = request.into_inner();
-            let mut statutes = self.state.statutes.write().await;
 
-            let initial_len = statutes.len();
-            statutes.retain(|s| s.id != req.id);
+            let deleted = self
+                .state
+                .statutes
+                .delete(&req.id)
+                .await
+                .map_err(storage_status)?;
 
-            if statutes.len() == initial_len {
+            if !deleted {
                 return Err(Status::not_found(format!("Statute not found: {}", req.id)));
             }
 
@@ -274,14 +443,19 @@ pub mod service {
             request: Request<BatchCreateStatutesRequest>,
         ) -> Result<Response<BatchCreateStatutesResponse>, Status> {
             let req = request.into_inner();
-            let mut statutes = self.state.statutes.write().await;
 
             let mut created_statutes = Vec::new();
             let mut errors = Vec::new();
 
             for create_req in req.statutes {
                 // Check if statute already exists
-                if statutes.iter().any(|s| s.id == create_req.id) {
+                if self
+                    .state
+                    .statutes
+                    .contains(&create_req.id)
+                    .await
+                    .map_err(storage_status)?
+                {
                     errors.push(format!("Statute already exists: {}", create_req.id));
                     continue;
                 }
@@ -324,7 +498,11 @@ pub mod service {
                 }
 
                 created_statutes.push(Self::statute_to_proto(&statute));
-                statutes.push(statute);
+                self.state
+                    .statutes
+                    .insert(statute)
+                    .await
+                    .map_err(storage_status)?;
             }
 
             Ok(Response::new(BatchCreateStatutesResponse {
@@ -338,12 +516,11 @@ pub mod service {
             request: Request<VerifyStatutesRequest>,
         ) -> Result<Response<VerificationResult>, Status> {
             let req = request.into_inner();
-            let statutes = self.state.statutes.read().await;
+            let statutes = self.state.statutes.list().await.map_err(storage_status)?;
 
             let statutes_to_verify: Vec<_> = statutes
-                .iter()
+                .into_iter()
                 .filter(|s| req.statute_ids.contains(&s.id))
-                .cloned()
                 .collect();
 
             if statutes_to_verify.is_empty() {
@@ -369,12 +546,11 @@ pub mod service {
             request: Request<StreamVerifyStatutesRequest>,
         ) -> Result<Response<Self::StreamVerifyStatutesStream>, Status> {
             let req = request.into_inner();
-            let statutes = self.state.statutes.read().await;
+            let statutes = self.state.statutes.list().await.map_err(storage_status)?;
 
             let statutes_to_verify: Vec<_> = statutes
-                .iter()
+                .into_iter()
                 .filter(|s| req.statute_ids.contains(&s.id))
-                .cloned()
                 .collect();
 
             let (tx, rx) = tokio::sync::mpsc::channel(4);
@@ -487,7 +663,7 @@ pub mod service {
             request: Request<SearchStatutesRequest>,
         ) -> Result<Response<SearchStatutesResponse>, Status> {
             let req = request.into_inner();
-            let statutes = self.state.statutes.read().await;
+            let statutes = self.state.statutes.list().await.map_err(storage_status)?;
 
             let filtered: Vec<_> = statutes
                 .iter()
@@ -533,6 +709,31 @@ pub mod service {
             Ok(Response::new(VerifyConditionResponse { is_valid, message }))
         }
 
+        async fn decide(
+            &self,
+            request: Request<DecideRequest>,
+        ) -> Result<Response<DecideResponse>, Status> {
+            let request = Self::decision_request_from_proto(request.into_inner())?;
+            let statutes = decide::load_statutes(&self.state.statutes, &request)
+                .await
+                .map_err(|e| match e {
+                    DecisionError::NoStatutes => Status::failed_precondition(e.to_string()),
+                    DecisionError::UnknownStatutes(_) => Status::not_found(e.to_string()),
+                    DecisionError::Storage(e) => storage_status(e),
+                })?;
+            let decision = decide::decide(&statutes, &request);
+
+            decide::record_decision(
+                &self.state.audit_log,
+                "grpc".to_string(),
+                "grpc".to_string(),
+                &decision,
+            )
+            .await;
+
+            Ok(Response::new(Self::decision_to_proto(&decision)))
+        }
+
         async fn health_check(
             &self,
             _request: Request<HealthCheckRequest>,
@@ -655,9 +856,52 @@ pub mod service {
 mod tests {
     use super::service::*;
 
-    #[test]
-    fn test_grpc_service_state_creation() {
+    #[tokio::test]
+    async fn test_grpc_service_state_creation() {
+        let state = GrpcServiceState::new();
+        assert_eq!(state.statutes.count().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_grpc_decide() {
+        use legalis_core::{ComparisonOp, Condition, Effect, EffectType, Statute};
+        use pb::legalis_service_server::LegalisService;
+        use tonic::Request;
+
         let state = GrpcServiceState::new();
-        assert_eq!(state.statutes.try_read().unwrap().len(), 0);
+        state
+            .statutes
+            .insert(
+                Statute::new("adult", "Adult", Effect::new(EffectType::Grant, "Vote"))
+                    .with_precondition(Condition::age(ComparisonOp::GreaterOrEqual, 18)),
+            )
+            .await
+            .unwrap();
+        let service = LegalisGrpcService::new(state.clone());
+
+        let response = service
+            .decide(Request::new(pb::DecideRequest {
+                facts: [("age".to_string(), "20".to_string())].into(),
+                ..Default::default()
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.applied_statute_ids, vec!["adult".to_string()]);
+        assert_eq!(
+            response.results[0].outcome,
+            pb::DecisionOutcome::Applies as i32
+        );
+        assert_eq!(response.results[0].evaluations.len(), 1);
+        assert_eq!(state.audit_log.count().await, 1);
+
+        let status = service
+            .decide(Request::new(pb::DecideRequest {
+                statute_ids: vec!["missing".to_string()],
+                ..Default::default()
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
     }
 }
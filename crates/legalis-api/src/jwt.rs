@@ -0,0 +1,1183 @@
+//! JWT signing and verification for the Legalis API.
+//!
+//! Supports HS256, RS256 and ES256 tokens with:
+//! - Keys from shared secrets, PEM files or a local JWKS file
+//! - `exp`/`nbf`/`iss`/`aud` validation with configurable leeway
+//! - Key rotation (several active keys, selected by `kid`)
+//! - Claim-to-[`Role`] mapping and stable ReBAC subject IDs
+
+use crate::auth::{AuthError, AuthMethod, AuthUser, Role};
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use ring::hmac;
+use ring::rand::SystemRandom;
+use ring::signature::{self, EcdsaKeyPair, KeyPair, RsaKeyPair, RsaPublicKeyComponents};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::RwLock;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors raised while loading keys or signing tokens.
+#[derive(Debug, Error)]
+pub enum JwtError {
+    #[error("Invalid key: {0}")]
+    InvalidKey(String),
+
+    #[error("Unsupported algorithm: {0}")]
+    UnsupportedAlgorithm(String),
+
+    #[error("Signing failed")]
+    SigningFailed,
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Supported JWS algorithms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum JwtAlgorithm {
+    /// HMAC with SHA-256
+    HS256,
+    /// RSASSA-PKCS1-v1_5 with SHA-256
+    RS256,
+    /// ECDSA on P-256 with SHA-256
+    ES256,
+}
+
+impl JwtAlgorithm {
+    /// Returns the `alg` header value.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::HS256 => "HS256",
+            Self::RS256 => "RS256",
+            Self::ES256 => "ES256",
+        }
+    }
+}
+
+impl std::str::FromStr for JwtAlgorithm {
+    type Err = JwtError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "HS256" => Ok(Self::HS256),
+            "RS256" => Ok(Self::RS256),
+            "ES256" => Ok(Self::ES256),
+            _ => Err(JwtError::UnsupportedAlgorithm(s.to_string())),
+        }
+    }
+}
+
+enum KeyMaterial {
+    Hmac(hmac::Key),
+    /// DER-encoded `RSAPublicKey`
+    RsaDer(Vec<u8>),
+    /// Big-endian modulus and exponent, as found in a JWK
+    RsaComponents {
+        n: Vec<u8>,
+        e: Vec<u8>,
+    },
+    /// Uncompressed P-256 point
+    EcPoint(Vec<u8>),
+}
+
+/// A key that can verify token signatures.
+pub struct VerificationKey {
+    kid: Option<String>,
+    algorithm: JwtAlgorithm,
+    material: KeyMaterial,
+}
+
+impl std::fmt::Debug for VerificationKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VerificationKey")
+            .field("kid", &self.kid)
+            .field("algorithm", &self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+impl VerificationKey {
+    /// Creates an HS256 key from a shared secret.
+    pub fn hmac(secret: &[u8]) -> Self {
+        Self {
+            kid: None,
+            algorithm: JwtAlgorithm::HS256,
+            material: KeyMaterial::Hmac(hmac::Key::new(hmac::HMAC_SHA256, secret)),
+        }
+    }
+
+    /// Loads an RS256 or ES256 public key from PEM.
+    ///
+    /// Accepts `PUBLIC KEY` (SubjectPublicKeyInfo) and, for RSA, `RSA PUBLIC KEY`.
+    pub fn from_pem(algorithm: JwtAlgorithm, pem: &str) -> Result<Self, JwtError> {
+        let (label, der) = decode_pem(pem)?;
+        let key = match label.as_str() {
+            "RSA PUBLIC KEY" => der,
+            "PUBLIC KEY" => spki_public_key(&der)?,
+            other => {
+                return Err(JwtError::InvalidKey(format!(
+                    "Expected a public key, found '{}'",
+                    other
+                )));
+            }
+        };
+        let material = match algorithm {
+            JwtAlgorithm::RS256 => KeyMaterial::RsaDer(key),
+            JwtAlgorithm::ES256 => KeyMaterial::EcPoint(key),
+            JwtAlgorithm::HS256 => {
+                return Err(JwtError::InvalidKey(
+                    "HS256 uses a shared secret, not a public key".to_string(),
+                ));
+            }
+        };
+        Ok(Self {
+            kid: None,
+            algorithm,
+            material,
+        })
+    }
+
+    /// Sets the key ID used to match the token `kid` header.
+    pub fn with_kid(mut self, kid: impl Into<String>) -> Self {
+        self.kid = Some(kid.into());
+        self
+    }
+
+    /// Returns the key ID.
+    pub fn kid(&self) -> Option<&str> {
+        self.kid.as_deref()
+    }
+
+    /// Returns the algorithm this key verifies.
+    pub fn algorithm(&self) -> JwtAlgorithm {
+        self.algorithm
+    }
+
+    fn verify(&self, message: &[u8], sig: &[u8]) -> bool {
+        match &self.material {
+            KeyMaterial::Hmac(key) => hmac::verify(key, message, sig).is_ok(),
+            KeyMaterial::RsaDer(der) => {
+                signature::UnparsedPublicKey::new(&signature::RSA_PKCS1_2048_8192_SHA256, der)
+                    .verify(message, sig)
+                    .is_ok()
+            }
+            KeyMaterial::RsaComponents { n, e } => RsaPublicKeyComponents { n, e }
+                .verify(&signature::RSA_PKCS1_2048_8192_SHA256, message, sig)
+                .is_ok(),
+            KeyMaterial::EcPoint(point) => {
+                signature::UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_FIXED, point)
+                    .verify(message, sig)
+                    .is_ok()
+            }
+        }
+    }
+}
+
+/// A JSON Web Key as found in a JWKS document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Jwk {
+    /// Key type (`RSA`, `EC` or `oct`)
+    pub kty: String,
+    /// Key ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+    /// Intended algorithm
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+    /// Intended use (`sig` or `enc`)
+    #[serde(default, rename = "use", skip_serializing_if = "Option::is_none")]
+    pub key_use: Option<String>,
+    /// RSA modulus
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub n: Option<String>,
+    /// RSA exponent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub e: Option<String>,
+    /// EC curve
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crv: Option<String>,
+    /// EC x coordinate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+    /// EC y coordinate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
+    /// Symmetric key value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub k: Option<String>,
+}
+
+impl Jwk {
+    /// Converts the JWK into a verification key.
+    pub fn to_key(&self) -> Result<VerificationKey, JwtError> {
+        let field = |value: &Option<String>, name: &str| -> Result<Vec<u8>, JwtError> {
+            let value = value
+                .as_deref()
+                .ok_or_else(|| JwtError::InvalidKey(format!("JWK is missing '{}'", name)))?;
+            URL_SAFE_NO_PAD
+                .decode(value)
+                .map_err(|e| JwtError::InvalidKey(format!("JWK '{}': {}", name, e)))
+        };
+
+        let (algorithm, material) = match self.kty.as_str() {
+            "RSA" => (
+                JwtAlgorithm::RS256,
+                KeyMaterial::RsaComponents {
+                    n: field(&self.n, "n")?,
+                    e: field(&self.e, "e")?,
+                },
+            ),
+            "EC" => {
+                if self.crv.as_deref() != Some("P-256") {
+                    return Err(JwtError::UnsupportedAlgorithm(format!(
+                        "EC curve {:?}",
+                        self.crv
+                    )));
+                }
+                let mut point = vec![0x04];
+                point.extend(field(&self.x, "x")?);
+                point.extend(field(&self.y, "y")?);
+                (JwtAlgorithm::ES256, KeyMaterial::EcPoint(point))
+            }
+            "oct" => (
+                JwtAlgorithm::HS256,
+                KeyMaterial::Hmac(hmac::Key::new(hmac::HMAC_SHA256, &field(&self.k, "k")?)),
+            ),
+            other => return Err(JwtError::UnsupportedAlgorithm(format!("kty {}", other))),
+        };
+
+        if let Some(alg) = &self.alg
+            && alg.parse::<JwtAlgorithm>()? != algorithm
+        {
+            return Err(JwtError::InvalidKey(format!(
+                "JWK alg {} does not match key type {}",
+                alg, self.kty
+            )));
+        }
+
+        Ok(VerificationKey {
+            kid: self.kid.clone(),
+            algorithm,
+            material,
+        })
+    }
+}
+
+/// A JSON Web Key Set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Jwks {
+    /// Keys in the set
+    pub keys: Vec<Jwk>,
+}
+
+impl Jwks {
+    /// Parses a JWKS document.
+    pub fn from_json(json: &str) -> Result<Self, JwtError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Loads a JWKS document from a local file.
+    pub fn load(path: &Path) -> Result<Self, JwtError> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Converts the signing keys in the set, skipping encryption keys.
+    pub fn verification_keys(&self) -> Result<Vec<VerificationKey>, JwtError> {
+        self.keys
+            .iter()
+            .filter(|jwk| jwk.key_use.as_deref() != Some("enc"))
+            .map(Jwk::to_key)
+            .collect()
+    }
+}
+
+/// Registered-claim validation rules.
+#[derive(Debug, Clone)]
+pub struct JwtValidation {
+    /// Accepted issuers; empty accepts any
+    pub issuers: Vec<String>,
+    /// Accepted audiences; empty accepts any
+    pub audiences: Vec<String>,
+    /// Clock skew tolerated for `exp` and `nbf`, in seconds
+    pub leeway_secs: i64,
+    /// Reject tokens without an `exp` claim
+    pub require_exp: bool,
+}
+
+impl Default for JwtValidation {
+    fn default() -> Self {
+        Self {
+            issuers: Vec::new(),
+            audiences: Vec::new(),
+            leeway_secs: 60,
+            require_exp: true,
+        }
+    }
+}
+
+impl JwtValidation {
+    /// Requires the given issuer.
+    pub fn with_issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.issuers.push(issuer.into());
+        self
+    }
+
+    /// Requires the given audience.
+    pub fn with_audience(mut self, audience: impl Into<String>) -> Self {
+        self.audiences.push(audience.into());
+        self
+    }
+
+    /// Sets the tolerated clock skew.
+    pub fn with_leeway(mut self, seconds: i64) -> Self {
+        self.leeway_secs = seconds;
+        self
+    }
+
+    fn validate(&self, claims: &Map<String, Value>, now: i64) -> Result<(), AuthError> {
+        let leeway = self.leeway_secs as f64;
+        match numeric_date(claims, "exp")? {
+            Some(exp) if now as f64 > exp + leeway => return Err(AuthError::TokenExpired),
+            None if self.require_exp => return Err(AuthError::InvalidToken),
+            _ => {}
+        }
+        if let Some(nbf) = numeric_date(claims, "nbf")?
+            && now as f64 + leeway < nbf
+        {
+            return Err(AuthError::TokenNotYetValid);
+        }
+
+        if !self.issuers.is_empty() {
+            let issuer = claims.get("iss").and_then(Value::as_str);
+            if !issuer.is_some_and(|iss| self.issuers.iter().any(|i| i == iss)) {
+                return Err(AuthError::InvalidToken);
+            }
+        }
+
+        if !self.audiences.is_empty() {
+            let matches = |aud: &str| self.audiences.iter().any(|a| a == aud);
+            let accepted = match claims.get("aud") {
+                Some(Value::String(aud)) => matches(aud),
+                Some(Value::Array(auds)) => auds.iter().filter_map(Value::as_str).any(matches),
+                _ => false,
+            };
+            if !accepted {
+                return Err(AuthError::InvalidToken);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads a NumericDate claim, which may be fractional (RFC 7519 §2).
+///
+/// Returns `None` if the claim is absent and fails if it is not a number.
+fn numeric_date(claims: &Map<String, Value>, name: &str) -> Result<Option<f64>, AuthError> {
+    match claims.get(name) {
+        None => Ok(None),
+        Some(value) => value
+            .as_f64()
+            .filter(|date| date.is_finite())
+            .map(Some)
+            .ok_or(AuthError::InvalidToken),
+    }
+}
+
+/// Maps token claims to a [`Role`], username and ReBAC subject.
+#[derive(Debug, Clone)]
+pub struct ClaimMapping {
+    /// Claim holding the role(s); dotted paths reach nested objects (`realm_access.roles`)
+    pub role_claim: String,
+    /// Claim value to role table; when empty, values are parsed as role names
+    pub roles: HashMap<String, Role>,
+    /// Role for tokens without a mappable role claim; `None` rejects them
+    pub default_role: Option<Role>,
+    /// Claim identifying the ReBAC subject
+    pub subject_claim: String,
+    /// Explicit subject overrides, keyed by claim value
+    pub subjects: HashMap<String, Uuid>,
+    /// Claims tried in order for the display username
+    pub username_claims: Vec<String>,
+}
+
+impl Default for ClaimMapping {
+    fn default() -> Self {
+        Self {
+            role_claim: "role".to_string(),
+            roles: HashMap::new(),
+            default_role: None,
+            subject_claim: "sub".to_string(),
+            subjects: HashMap::new(),
+            username_claims: vec![
+                "username".to_string(),
+                "preferred_username".to_string(),
+                "email".to_string(),
+                "sub".to_string(),
+            ],
+        }
+    }
+}
+
+impl ClaimMapping {
+    /// Reads roles from the given claim path.
+    pub fn with_role_claim(mut self, claim: impl Into<String>) -> Self {
+        self.role_claim = claim.into();
+        self
+    }
+
+    /// Maps a claim value to a role.
+    pub fn with_role(mut self, value: impl Into<String>, role: Role) -> Self {
+        self.roles.insert(value.into(), role);
+        self
+    }
+
+    /// Assigns a role to tokens without a mappable role claim.
+    pub fn with_default_role(mut self, role: Role) -> Self {
+        self.default_role = Some(role);
+        self
+    }
+
+    /// Reads the ReBAC subject from the given claim.
+    pub fn with_subject_claim(mut self, claim: impl Into<String>) -> Self {
+        self.subject_claim = claim.into();
+        self
+    }
+
+    /// Maps a subject claim value to an existing ReBAC user ID.
+    pub fn with_subject(mut self, value: impl Into<String>, user_id: Uuid) -> Self {
+        self.subjects.insert(value.into(), user_id);
+        self
+    }
+
+    /// Resolves the most privileged role named by the claims.
+    fn role(&self, claims: &Map<String, Value>) -> Option<Role> {
+        let values: Vec<&str> = match claim_path(claims, &self.role_claim) {
+            Some(Value::String(value)) => vec![value.as_str()],
+            Some(Value::Array(values)) => values.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+
+        values
+            .into_iter()
+            .filter_map(|value| {
+                if self.roles.is_empty() {
+                    value.parse().ok()
+                } else {
+                    self.roles.get(value).copied()
+                }
+            })
+            .max_by_key(|role| role_rank(*role))
+            .or(self.default_role)
+    }
+
+    /// Resolves the ReBAC subject ID.
+    ///
+    /// UUID subjects are used as-is; other values map to a stable UUID derived
+    /// from the issuer and subject so the same identity always gets the same ID.
+    fn subject(&self, claims: &Map<String, Value>) -> Option<Uuid> {
+        let subject = claim_string(claim_path(claims, &self.subject_claim)?)?;
+        if let Some(id) = self.subjects.get(&subject) {
+            return Some(*id);
+        }
+        if let Ok(id) = Uuid::parse_str(&subject) {
+            return Some(id);
+        }
+
+        let issuer = claims
+            .get("iss")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let digest = Sha256::digest(format!("{}|{}", issuer, subject).as_bytes());
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&digest[..16]);
+        Some(uuid::Builder::from_custom_bytes(bytes).into_uuid())
+    }
+
+    fn username(&self, claims: &Map<String, Value>) -> String {
+        self.username_claims
+            .iter()
+            .find_map(|claim| claims.get(claim).and_then(claim_string))
+            .unwrap_or_default()
+    }
+}
+
+/// Verifies bearer tokens and maps them to authenticated users.
+///
+/// # Example
+///
+/// ```
+/// use legalis_api::auth::Role;
+/// use legalis_api::jwt::{ClaimMapping, JwtSigner, JwtValidation, JwtVerifier};
+///
+/// let signer = JwtSigner::hs256(b"change-me").with_kid("2026-01");
+/// let verifier = JwtVerifier::new(JwtValidation::default().with_issuer("legalis"))
+///     .with_key(signer.verification_key())
+///     .with_claim_mapping(ClaimMapping::default().with_role("legal-team", Role::Analyst));
+///
+/// let now = chrono::Utc::now().timestamp();
+/// let token = signer
+///     .sign(&serde_json::json!({
+///         "sub": "alice", "iss": "legalis", "exp": now + 300, "role": ["legal-team"]
+///     }))
+///     .unwrap();
+///
+/// let user = verifier.authenticate(&token).unwrap();
+/// assert_eq!(user.role, Role::Analyst);
+/// assert_eq!(user.username, "alice");
+/// ```
+pub struct JwtVerifier {
+    keys: RwLock<Vec<VerificationKey>>,
+    validation: JwtValidation,
+    mapping: ClaimMapping,
+}
+
+impl JwtVerifier {
+    /// Creates a verifier with no keys.
+    pub fn new(validation: JwtValidation) -> Self {
+        Self {
+            keys: RwLock::new(Vec::new()),
+            validation,
+            mapping: ClaimMapping::default(),
+        }
+    }
+
+    /// Adds a verification key.
+    pub fn with_key(self, key: VerificationKey) -> Self {
+        self.add_key(key);
+        self
+    }
+
+    /// Sets the claim mapping.
+    pub fn with_claim_mapping(mut self, mapping: ClaimMapping) -> Self {
+        self.mapping = mapping;
+        self
+    }
+
+    /// Builds a verifier from server configuration.
+    pub fn from_config(config: &crate::config::JwtConfig) -> Result<Self, JwtError> {
+        let mut validation = JwtValidation::default().with_leeway(config.leeway_secs);
+        validation.issuers.extend(config.issuer.clone());
+        validation.audiences.extend(config.audience.clone());
+
+        let mut mapping = ClaimMapping::default().with_role_claim(config.role_claim.clone());
+        mapping.roles = config.role_map.clone();
+        mapping.default_role = config.default_role;
+        if let Some(claim) = &config.subject_claim {
+            mapping.subject_claim = claim.clone();
+        }
+
+        let verifier = Self::new(validation).with_claim_mapping(mapping);
+        if let Some(secret) = &config.secret {
+            verifier.add_key(VerificationKey::hmac(secret.as_bytes()));
+        }
+        if let Some(path) = &config.public_key_path {
+            let pem = std::fs::read_to_string(path)?;
+            verifier.add_key(VerificationKey::from_pem(config.algorithm, &pem)?);
+        }
+        if let Some(path) = &config.jwks_path {
+            verifier.reload_jwks(path)?;
+        }
+        Ok(verifier)
+    }
+
+    /// Adds a key; tokens signed by any active key verify.
+    pub fn add_key(&self, key: VerificationKey) {
+        self.write_keys().push(key);
+    }
+
+    /// Removes every key with the given ID, returning how many were removed.
+    pub fn remove_key(&self, kid: &str) -> usize {
+        let mut keys = self.write_keys();
+        let before = keys.len();
+        keys.retain(|key| key.kid() != Some(kid));
+        before - keys.len()
+    }
+
+    /// Replaces the JWKS-provided keys with the current contents of a file.
+    ///
+    /// Keys without a `kid` (configured secrets and PEM keys) are kept.
+    pub fn reload_jwks(&self, path: &Path) -> Result<usize, JwtError> {
+        let new_keys = Jwks::load(path)?.verification_keys()?;
+        let count = new_keys.len();
+        let mut keys = self.write_keys();
+        keys.retain(|key| key.kid.is_none());
+        keys.extend(new_keys);
+        Ok(count)
+    }
+
+    /// Returns the number of active keys.
+    pub fn key_count(&self) -> usize {
+        self.keys.read().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    fn write_keys(&self) -> std::sync::RwLockWriteGuard<'_, Vec<VerificationKey>> {
+        self.keys.write().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Verifies the signature and registered claims, returning all claims.
+    pub fn verify(&self, token: &str) -> Result<Map<String, Value>, AuthError> {
+        let mut parts = token.split('.');
+        let (Some(header), Some(payload), Some(sig), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(AuthError::InvalidToken);
+        };
+
+        let header: JwtHeader = decode_segment(header)?;
+        let algorithm: JwtAlgorithm = header.alg.parse().map_err(|_| AuthError::InvalidToken)?;
+        let sig = URL_SAFE_NO_PAD
+            .decode(sig)
+            .map_err(|_| AuthError::InvalidToken)?;
+        let message = &token[..token.len() - token.rsplit('.').next().map_or(0, str::len) - 1];
+
+        let keys = self.keys.read().unwrap_or_else(|e| e.into_inner());
+        let verified = keys
+            .iter()
+            .filter(|key| key.algorithm == algorithm)
+            .filter(|key| match (&header.kid, &key.kid) {
+                (Some(token_kid), Some(key_kid)) => token_kid == key_kid,
+                (Some(_), None) => true,
+                (None, _) => true,
+            })
+            .any(|key| key.verify(message.as_bytes(), &sig));
+        if !verified {
+            return Err(AuthError::InvalidToken);
+        }
+
+        let claims: Map<String, Value> = decode_segment(payload)?;
+        self.validation
+            .validate(&claims, chrono::Utc::now().timestamp())?;
+        Ok(claims)
+    }
+
+    /// Verifies a token and maps its claims to an [`AuthUser`].
+    pub fn authenticate(&self, token: &str) -> Result<AuthUser, AuthError> {
+        let claims = self.verify(token)?;
+        let role = self
+            .mapping
+            .role(&claims)
+            .ok_or(AuthError::InsufficientPermissions)?;
+        let id = self
+            .mapping
+            .subject(&claims)
+            .ok_or(AuthError::InvalidToken)?;
+
+        Ok(AuthUser::new(
+            id,
+            self.mapping.username(&claims),
+            role,
+            AuthMethod::Jwt,
+        ))
+    }
+}
+
+impl std::fmt::Debug for JwtVerifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtVerifier")
+            .field("keys", &self.key_count())
+            .field("validation", &self.validation)
+            .field("mapping", &self.mapping)
+            .finish()
+    }
+}
+
+enum SigningMaterial {
+    Hmac(hmac::Key, Vec<u8>),
+    Rsa(RsaKeyPair),
+    Ec(EcdsaKeyPair),
+}
+
+/// Signs tokens with the API's own key.
+pub struct JwtSigner {
+    kid: Option<String>,
+    material: SigningMaterial,
+    rng: SystemRandom,
+}
+
+impl JwtSigner {
+    /// Creates an HS256 signer from a shared secret.
+    pub fn hs256(secret: &[u8]) -> Self {
+        Self {
+            kid: None,
+            material: SigningMaterial::Hmac(
+                hmac::Key::new(hmac::HMAC_SHA256, secret),
+                secret.to_vec(),
+            ),
+            rng: SystemRandom::new(),
+        }
+    }
+
+    /// Loads an RS256 or ES256 signer from a PKCS#8 `PRIVATE KEY` PEM.
+    pub fn from_pem(algorithm: JwtAlgorithm, pem: &str) -> Result<Self, JwtError> {
+        let (label, der) = decode_pem(pem)?;
+        if label != "PRIVATE KEY" {
+            return Err(JwtError::InvalidKey(format!(
+                "Expected a PKCS#8 private key, found '{}'",
+                label
+            )));
+        }
+        Self::from_pkcs8(algorithm, &der)
+    }
+
+    /// Creates an RS256 or ES256 signer from a DER-encoded PKCS#8 key.
+    pub fn from_pkcs8(algorithm: JwtAlgorithm, der: &[u8]) -> Result<Self, JwtError> {
+        let rng = SystemRandom::new();
+        let rejected = |e: ring::error::KeyRejected| JwtError::InvalidKey(e.to_string());
+        let material = match algorithm {
+            JwtAlgorithm::RS256 => {
+                SigningMaterial::Rsa(RsaKeyPair::from_pkcs8(der).map_err(rejected)?)
+            }
+            JwtAlgorithm::ES256 => SigningMaterial::Ec(
+                EcdsaKeyPair::from_pkcs8(&signature::ECDSA_P256_SHA256_FIXED_SIGNING, der, &rng)
+                    .map_err(rejected)?,
+            ),
+            JwtAlgorithm::HS256 => {
+                return Err(JwtError::InvalidKey(
+                    "HS256 signers are created from a shared secret".to_string(),
+                ));
+            }
+        };
+        Ok(Self {
+            kid: None,
+            material,
+            rng,
+        })
+    }
+
+    /// Sets the `kid` header written into tokens.
+    pub fn with_kid(mut self, kid: impl Into<String>) -> Self {
+        self.kid = Some(kid.into());
+        self
+    }
+
+    /// Returns the signing algorithm.
+    pub fn algorithm(&self) -> JwtAlgorithm {
+        match self.material {
+            SigningMaterial::Hmac(..) => JwtAlgorithm::HS256,
+            SigningMaterial::Rsa(_) => JwtAlgorithm::RS256,
+            SigningMaterial::Ec(_) => JwtAlgorithm::ES256,
+        }
+    }
+
+    /// Returns the key that verifies this signer's tokens.
+    pub fn verification_key(&self) -> VerificationKey {
+        let key = match &self.material {
+            SigningMaterial::Hmac(_, secret) => VerificationKey::hmac(secret),
+            SigningMaterial::Rsa(pair) => VerificationKey {
+                kid: None,
+                algorithm: JwtAlgorithm::RS256,
+                material: KeyMaterial::RsaDer(pair.public_key().as_ref().to_vec()),
+            },
+            SigningMaterial::Ec(pair) => VerificationKey {
+                kid: None,
+                algorithm: JwtAlgorithm::ES256,
+                material: KeyMaterial::EcPoint(pair.public_key().as_ref().to_vec()),
+            },
+        };
+        match &self.kid {
+            Some(kid) => key.with_kid(kid.clone()),
+            None => key,
+        }
+    }
+
+    /// Signs the claims and returns a compact JWS.
+    pub fn sign<T: Serialize>(&self, claims: &T) -> Result<String, JwtError> {
+        let header = JwtHeader {
+            alg: self.algorithm().as_str().to_string(),
+            typ: Some("JWT".to_string()),
+            kid: self.kid.clone(),
+        };
+        let message = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header)?),
+            URL_SAFE_NO_PAD.encode(serde_json::to_vec(claims)?)
+        );
+
+        let sig = match &self.material {
+            SigningMaterial::Hmac(key, _) => hmac::sign(key, message.as_bytes()).as_ref().to_vec(),
+            SigningMaterial::Rsa(pair) => {
+                let mut sig = vec![0; pair.public().modulus_len()];
+                pair.sign(
+                    &signature::RSA_PKCS1_SHA256,
+                    &self.rng,
+                    message.as_bytes(),
+                    &mut sig,
+                )
+                .map_err(|_| JwtError::SigningFailed)?;
+                sig
+            }
+            SigningMaterial::Ec(pair) => pair
+                .sign(&self.rng, message.as_bytes())
+                .map_err(|_| JwtError::SigningFailed)?
+                .as_ref()
+                .to_vec(),
+        };
+
+        Ok(format!("{}.{}", message, URL_SAFE_NO_PAD.encode(sig)))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct JwtHeader {
+    alg: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    typ: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    kid: Option<String>,
+}
+
+fn decode_segment<T: serde::de::DeserializeOwned>(segment: &str) -> Result<T, AuthError> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|_| AuthError::InvalidToken)?;
+    serde_json::from_slice(&bytes).map_err(|_| AuthError::InvalidToken)
+}
+
+/// Follows a dotted claim path such as `realm_access.roles`.
+fn claim_path<'a>(claims: &'a Map<String, Value>, path: &str) -> Option<&'a Value> {
+    let mut segments = path.split('.');
+    let mut value = claims.get(segments.next()?)?;
+    for segment in segments {
+        value = value.get(segment)?;
+    }
+    Some(value)
+}
+
+fn claim_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Orders roles by privilege so the strongest of several claims wins.
+fn role_rank(role: Role) -> u8 {
+    match role {
+        Role::Viewer => 0,
+        Role::ApiClient => 1,
+        Role::Analyst => 2,
+        Role::Admin => 3,
+        Role::SuperAdmin => 4,
+    }
+}
+
+/// Decodes a PEM block into its label and DER contents.
+fn decode_pem(pem: &str) -> Result<(String, Vec<u8>), JwtError> {
+    let pem = pem.trim();
+    let label = pem
+        .strip_prefix("-----BEGIN ")
+        .and_then(|rest| rest.split_once("-----"))
+        .map(|(label, _)| label.to_string())
+        .ok_or_else(|| JwtError::InvalidKey("Missing PEM header".to_string()))?;
+    let body: String = pem
+        .lines()
+        .filter(|line| !line.starts_with("-----"))
+        .map(str::trim)
+        .collect();
+    let der = STANDARD
+        .decode(body)
+        .map_err(|e| JwtError::InvalidKey(format!("Invalid PEM body: {}", e)))?;
+    Ok((label, der))
+}
+
+/// Extracts the key bytes from a DER SubjectPublicKeyInfo.
+fn spki_public_key(der: &[u8]) -> Result<Vec<u8>, JwtError> {
+    let invalid = || JwtError::InvalidKey("Malformed SubjectPublicKeyInfo".to_string());
+    let (spki, _) = der_element(der, 0x30).ok_or_else(invalid)?;
+    let (_, rest) = der_element(spki, 0x30).ok_or_else(invalid)?;
+    let (bits, _) = der_element(rest, 0x03).ok_or_else(invalid)?;
+    match bits.split_first() {
+        Some((0, key)) => Ok(key.to_vec()),
+        _ => Err(invalid()),
+    }
+}
+
+/// Reads one DER element with the expected tag, returning its contents and the remainder.
+fn der_element(input: &[u8], tag: u8) -> Option<(&[u8], &[u8])> {
+    let (&actual, rest) = input.split_first()?;
+    if actual != tag {
+        return None;
+    }
+    let (&first, mut rest) = rest.split_first()?;
+    let len = if first < 0x80 {
+        usize::from(first)
+    } else {
+        let count = usize::from(first & 0x7f);
+        if count == 0 || count > 4 || rest.len() < count {
+            return None;
+        }
+        let len = rest[..count]
+            .iter()
+            .fold(0usize, |acc, b| (acc << 8) | usize::from(*b));
+        rest = &rest[count..];
+        len
+    };
+    (rest.len() >= len).then(|| rest.split_at(len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const RSA_PRIVATE_KEY: &str = include_str!("../tests/fixtures/jwt_rsa_private.pem");
+    const RSA_PUBLIC_KEY: &str = include_str!("../tests/fixtures/jwt_rsa_public.pem");
+
+    fn now() -> i64 {
+        chrono::Utc::now().timestamp()
+    }
+
+    fn es256_signer() -> JwtSigner {
+        let rng = SystemRandom::new();
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&signature::ECDSA_P256_SHA256_FIXED_SIGNING, &rng)
+            .unwrap();
+        JwtSigner::from_pkcs8(JwtAlgorithm::ES256, pkcs8.as_ref()).unwrap()
+    }
+
+    #[test]
+    fn test_hs256_round_trip_and_tampering() {
+        let signer = JwtSigner::hs256(b"secret");
+        let verifier = JwtVerifier::new(JwtValidation::default())
+            .with_key(signer.verification_key())
+            .with_claim_mapping(ClaimMapping::default());
+        let id = Uuid::new_v4();
+        let token = signer
+            .sign(&json!({"sub": id, "username": "bob", "role": "Analyst", "exp": now() + 60}))
+            .unwrap();
+
+        let user = verifier.authenticate(&token).unwrap();
+        assert_eq!(user.id, id);
+        assert_eq!(user.username, "bob");
+        assert_eq!(user.role, Role::Analyst);
+        assert_eq!(user.auth_method, AuthMethod::Jwt);
+
+        // Swap in a payload claiming a stronger role.
+        let forged_payload = URL_SAFE_NO_PAD
+            .encode(json!({"sub": id, "role": "SuperAdmin", "exp": now() + 60}).to_string());
+        let parts: Vec<_> = token.split('.').collect();
+        let forged = format!("{}.{}.{}", parts[0], forged_payload, parts[2]);
+        assert!(matches!(
+            verifier.authenticate(&forged),
+            Err(AuthError::InvalidToken)
+        ));
+
+        let other = JwtSigner::hs256(b"other").sign(&json!({"exp": now() + 60}));
+        assert!(verifier.verify(&other.unwrap()).is_err());
+        assert!(verifier.verify("not-a-jwt").is_err());
+    }
+
+    #[test]
+    fn test_rejects_none_and_algorithm_confusion() {
+        let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"none"}"#);
+        let payload = URL_SAFE_NO_PAD.encode(json!({"exp": now() + 60}).to_string());
+        let verifier =
+            JwtVerifier::new(JwtValidation::default()).with_key(VerificationKey::hmac(b"secret"));
+        assert!(
+            verifier
+                .verify(&format!("{}.{}.", header, payload))
+                .is_err()
+        );
+
+        // An RS256 public key must never be usable as an HMAC secret.
+        let rsa = VerificationKey::from_pem(JwtAlgorithm::RS256, RSA_PUBLIC_KEY).unwrap();
+        let verifier = JwtVerifier::new(JwtValidation::default()).with_key(rsa);
+        let forged = JwtSigner::hs256(RSA_PUBLIC_KEY.as_bytes())
+            .sign(&json!({"exp": now() + 60}))
+            .unwrap();
+        assert!(verifier.verify(&forged).is_err());
+    }
+
+    #[test]
+    fn test_rs256_with_pem_keys() {
+        let signer = JwtSigner::from_pem(JwtAlgorithm::RS256, RSA_PRIVATE_KEY).unwrap();
+        let verifier = JwtVerifier::new(JwtValidation::default())
+            .with_key(VerificationKey::from_pem(JwtAlgorithm::RS256, RSA_PUBLIC_KEY).unwrap());
+        let token = signer
+            .sign(&json!({"sub": "x", "exp": now() + 60}))
+            .unwrap();
+        assert_eq!(verifier.verify(&token).unwrap()["sub"], "x");
+
+        let from_signer =
+            JwtVerifier::new(JwtValidation::default()).with_key(signer.verification_key());
+        assert!(from_signer.verify(&token).is_ok());
+    }
+
+    #[test]
+    fn test_es256_with_jwks_and_rotation() {
+        let old = es256_signer().with_kid("k1");
+        let new = es256_signer().with_kid("k2");
+
+        let jwk = |signer: &JwtSigner, kid: &str| {
+            let SigningMaterial::Ec(pair) = &signer.material else {
+                unreachable!()
+            };
+            let point = pair.public_key().as_ref();
+            json!({
+                "kty": "EC", "crv": "P-256", "alg": "ES256", "use": "sig", "kid": kid,
+                "x": URL_SAFE_NO_PAD.encode(&point[1..33]),
+                "y": URL_SAFE_NO_PAD.encode(&point[33..]),
+            })
+        };
+        let dir = std::env::temp_dir().join(format!("legalis-jwks-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("jwks.json");
+
+        std::fs::write(&path, json!({"keys": [jwk(&old, "k1")]}).to_string()).unwrap();
+        let verifier = JwtVerifier::new(JwtValidation::default());
+        assert_eq!(verifier.reload_jwks(&path).unwrap(), 1);
+
+        let claims = json!({"sub": "s", "exp": now() + 60});
+        let old_token = old.sign(&claims).unwrap();
+        let new_token = new.sign(&claims).unwrap();
+        assert!(verifier.verify(&old_token).is_ok());
+        assert!(verifier.verify(&new_token).is_err());
+
+        // Rotate: publish both keys, then retire the old one.
+        std::fs::write(
+            &path,
+            json!({"keys": [jwk(&old, "k1"), jwk(&new, "k2")]}).to_string(),
+        )
+        .unwrap();
+        verifier.reload_jwks(&path).unwrap();
+        assert!(verifier.verify(&old_token).is_ok());
+        assert!(verifier.verify(&new_token).is_ok());
+
+        assert_eq!(verifier.remove_key("k1"), 1);
+        assert!(verifier.verify(&old_token).is_err());
+        assert!(verifier.verify(&new_token).is_ok());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_registered_claim_validation() {
+        let signer = JwtSigner::hs256(b"secret");
+        let verifier = JwtVerifier::new(
+            JwtValidation::default()
+                .with_issuer("https://idp.example")
+                .with_audience("legalis-api")
+                .with_leeway(0),
+        )
+        .with_key(signer.verification_key());
+        let sign = |claims: Value| signer.sign(&claims).unwrap();
+        let t = now();
+
+        let valid =
+            json!({"iss": "https://idp.example", "aud": ["other", "legalis-api"], "exp": t + 60});
+        assert!(verifier.verify(&sign(valid)).is_ok());
+
+        let expired = json!({"iss": "https://idp.example", "aud": "legalis-api", "exp": t - 10});
+        assert!(matches!(
+            verifier.verify(&sign(expired)),
+            Err(AuthError::TokenExpired)
+        ));
+
+        let early = json!({"iss": "https://idp.example", "aud": "legalis-api", "exp": t + 60, "nbf": t + 30});
+        assert!(matches!(
+            verifier.verify(&sign(early)),
+            Err(AuthError::TokenNotYetValid)
+        ));
+
+        let wrong_iss = json!({"iss": "https://evil.example", "aud": "legalis-api", "exp": t + 60});
+        assert!(verifier.verify(&sign(wrong_iss)).is_err());
+
+        let wrong_aud = json!({"iss": "https://idp.example", "aud": "other", "exp": t + 60});
+        assert!(verifier.verify(&sign(wrong_aud)).is_err());
+
+        let no_exp = json!({"iss": "https://idp.example", "aud": "legalis-api"});
+        assert!(verifier.verify(&sign(no_exp)).is_err());
+
+        // Fractional NumericDates are checked, not skipped
+        let expired =
+            json!({"iss": "https://idp.example", "aud": "legalis-api", "exp": t as f64 - 10.5});
+        assert!(matches!(
+            verifier.verify(&sign(expired)),
+            Err(AuthError::TokenExpired)
+        ));
+        let early = json!({"iss": "https://idp.example", "aud": "legalis-api", "exp": t + 60, "nbf": t as f64 + 30.5});
+        assert!(matches!(
+            verifier.verify(&sign(early)),
+            Err(AuthError::TokenNotYetValid)
+        ));
+        let valid = json!({"iss": "https://idp.example", "aud": "legalis-api", "exp": t as f64 + 60.5, "nbf": t as f64 - 0.5});
+        assert!(verifier.verify(&sign(valid)).is_ok());
+
+        // Present but non-numeric dates are malformed
+        for claims in [
+            json!({"iss": "https://idp.example", "aud": "legalis-api", "exp": (t + 60).to_string()}),
+            json!({"iss": "https://idp.example", "aud": "legalis-api", "exp": t + 60, "nbf": null}),
+        ] {
+            assert!(matches!(
+                verifier.verify(&sign(claims)),
+                Err(AuthError::InvalidToken)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_claim_mapping() {
+        let signer = JwtSigner::hs256(b"secret");
+        let mapping = ClaimMapping::default()
+            .with_role_claim("realm_access.roles")
+            .with_role("legalis-viewer", Role::Viewer)
+            .with_role("legalis-admin", Role::Admin);
+        let verifier = JwtVerifier::new(JwtValidation::default())
+            .with_key(signer.verification_key())
+            .with_claim_mapping(mapping);
+
+        let claims = json!({
+            "iss": "https://idp.example",
+            "sub": "user-42",
+            "preferred_username": "carol",
+            "exp": now() + 60,
+            "realm_access": {"roles": ["offline_access", "legalis-viewer", "legalis-admin"]}
+        });
+        let user = verifier
+            .authenticate(&signer.sign(&claims).unwrap())
+            .unwrap();
+        assert_eq!(user.role, Role::Admin);
+        assert_eq!(user.username, "carol");
+
+        // Non-UUID subjects map to a stable ID.
+        let again = verifier
+            .authenticate(&signer.sign(&claims).unwrap())
+            .unwrap();
+        assert_eq!(user.id, again.id);
+
+        let unmapped =
+            json!({"sub": "user-42", "exp": now() + 60, "realm_access": {"roles": ["other"]}});
+        assert!(matches!(
+            verifier.authenticate(&signer.sign(&unmapped).unwrap()),
+            Err(AuthError::InsufficientPermissions)
+        ));
+
+        let pinned = Uuid::new_v4();
+        let verifier = JwtVerifier::new(JwtValidation::default())
+            .with_key(signer.verification_key())
+            .with_claim_mapping(
+                ClaimMapping::default()
+                    .with_default_role(Role::Viewer)
+                    .with_subject_claim("email")
+                    .with_subject("carol@example.com", pinned),
+            );
+        let user = verifier
+            .authenticate(
+                &signer
+                    .sign(&json!({"email": "carol@example.com", "exp": now() + 60}))
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(user.id, pinned);
+        assert_eq!(user.role, Role::Viewer);
+    }
+}
@@ -17,13 +17,16 @@ pub mod cache;
 pub mod collaborative;
 pub mod config;
 pub mod contract_test;
+pub mod decide;
 // pub mod dataloader; // TODO: Re-enable when Loader trait signature issues are resolved
 pub mod edge_cache;
+pub mod federation;
 pub mod field_selection;
 pub mod gateway;
 pub mod graphql;
 #[cfg(feature = "grpc")]
 pub mod grpc;
+pub mod jwt;
 pub mod live_queries;
 pub mod load_test;
 pub mod logging;
@@ -38,10 +41,12 @@ pub mod query_batch;
 pub mod query_cost;
 pub mod rate_limit;
 pub mod rebac;
+pub mod registry_protocol;
 pub mod sampling;
 pub mod schema_stitching;
 pub mod security;
 pub mod slo;
+pub mod store;
 pub mod telemetry;
 pub mod versioning;
 pub mod websocket;
@@ -99,8 +104,36 @@ pub enum ApiError {
     #[error("Validation failed: {0}")]
     ValidationFailed(String),
 
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
     #[error(transparent)]
     Auth(#[from] auth::AuthError),
+
+    #[error("Storage error: {0}")]
+    Storage(#[from] legalis_registry::RegistryError),
+}
+
+impl From<decide::DecisionError> for ApiError {
+    fn from(err: decide::DecisionError) -> Self {
+        match err {
+            decide::DecisionError::NoStatutes => ApiError::BadRequest(err.to_string()),
+            decide::DecisionError::UnknownStatutes(_) => ApiError::NotFound(err.to_string()),
+            decide::DecisionError::Storage(e) => ApiError::Storage(e),
+        }
+    }
+}
+
+impl From<registry_protocol::PublishError> for ApiError {
+    fn from(err: registry_protocol::PublishError) -> Self {
+        use registry_protocol::PublishError;
+        match err {
+            PublishError::IdMismatch { .. } => ApiError::BadRequest(err.to_string()),
+            PublishError::Conflict { .. } => ApiError::Conflict(err.to_string()),
+            PublishError::Auth(e) => ApiError::Auth(e),
+            PublishError::Storage(e) => ApiError::Storage(e),
+        }
+    }
 }
 
 impl IntoResponse for ApiError {
@@ -110,7 +143,20 @@ impl IntoResponse for ApiError {
             ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
             ApiError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
             ApiError::ValidationFailed(msg) => (StatusCode::UNPROCESSABLE_ENTITY, msg),
+            ApiError::Conflict(msg) => (StatusCode::CONFLICT, msg),
             ApiError::Auth(err) => return err.into_response(),
+            ApiError::Storage(err) => {
+                use legalis_registry::RegistryError;
+                let status = match err {
+                    RegistryError::StatuteNotFound(_) | RegistryError::VersionNotFound { .. } => {
+                        StatusCode::NOT_FOUND
+                    }
+                    RegistryError::DuplicateId(_) => StatusCode::BAD_REQUEST,
+                    RegistryError::ConcurrentModification { .. } => StatusCode::CONFLICT,
+                    _ => StatusCode::INTERNAL_SERVER_ERROR,
+                };
+                (status, err.to_string())
+            }
         };
 
         let body = Json(ErrorResponse { error: message });
@@ -182,13 +228,13 @@ pub struct SavedSimulation {
 
 /// Application state.
 pub struct AppState {
-    /// In-memory statute storage
-    pub statutes: RwLock<Vec<Statute>>,
+    /// Statute storage, shared with the GraphQL and gRPC services
+    pub statutes: store::StatuteStore,
     /// ReBAC authorization engine
     pub rebac: RwLock<rebac::ReBACEngine>,
     /// Async verification job manager
     pub verification_jobs: async_jobs::JobManager<VerificationJobResult>,
-    /// Saved simulations
+    /// Saved simulations, kept in memory only (not yet in the statute storage backend)
     pub saved_simulations: RwLock<Vec<SavedSimulation>>,
     /// Response cache
     pub cache: Arc<cache::CacheStore>,
@@ -196,18 +242,26 @@ pub struct AppState {
     pub ws_broadcaster: websocket::WsBroadcaster,
     /// Audit log for tracking all mutations
     pub audit_log: Arc<audit::AuditLog>,
-    /// API keys storage
+    /// API keys, kept in memory only (not yet in the statute storage backend)
     pub api_keys: RwLock<Vec<auth::ApiKey>>,
     /// Collaborative editor for real-time editing
     pub collaborative_editor: Arc<collaborative::CollaborativeEditor>,
     /// Presence manager for tracking active users
     pub presence_manager: Arc<presence::PresenceManager>,
+    /// Bearer token verifier; JWT authentication is rejected when unset
+    pub jwt_verifier: Option<Arc<jwt::JwtVerifier>>,
+    /// Federation identity; peer search is disabled when unset
+    pub federation: Option<Arc<federation::FederationIdentity>>,
+    /// Statute embeddings for semantic and hybrid search
+    pub semantic_index: tokio::sync::Mutex<legalis_registry::vector_search::SemanticIndex>,
+    /// File the semantic index is saved to after it changes
+    pub semantic_index_path: Option<std::path::PathBuf>,
 }
 
 impl AppState {
     pub fn new() -> Self {
         Self {
-            statutes: RwLock::new(Vec::new()),
+            statutes: store::StatuteStore::in_memory(),
             rebac: RwLock::new(rebac::ReBACEngine::new()),
             verification_jobs: async_jobs::JobManager::new(),
             saved_simulations: RwLock::new(Vec::new()),
@@ -217,8 +271,49 @@ impl AppState {
             api_keys: RwLock::new(Vec::new()),
             collaborative_editor: Arc::new(collaborative::CollaborativeEditor::new()),
             presence_manager: Arc::new(presence::PresenceManager::new(30)),
+            jwt_verifier: None,
+            federation: None,
+            semantic_index: tokio::sync::Mutex::new(Default::default()),
+            semantic_index_path: None,
         }
     }
+
+    /// Uses the given statute store instead of the in-memory default.
+    pub fn with_statute_store(mut self, statutes: store::StatuteStore) -> Self {
+        self.statutes = statutes;
+        self
+    }
+
+    /// Returns gRPC service state backed by this server's statute store.
+    #[cfg(feature = "grpc")]
+    pub fn grpc_state(&self) -> grpc::service::GrpcServiceState {
+        grpc::service::GrpcServiceState::with_store(self.statutes.clone())
+            .with_audit_log(self.audit_log.clone())
+    }
+
+    /// Enables bearer token authentication with the given verifier.
+    pub fn with_jwt_verifier(mut self, verifier: jwt::JwtVerifier) -> Self {
+        self.jwt_verifier = Some(Arc::new(verifier));
+        self
+    }
+
+    /// Answers peer registry searches, signed with the identity's key.
+    pub fn with_federation(mut self, identity: federation::FederationIdentity) -> Self {
+        self.federation = Some(Arc::new(identity));
+        self
+    }
+
+    /// Uses the given semantic index, saving it to `path` whenever a search
+    /// brings it up to date with the statute store.
+    pub fn with_semantic_index(
+        mut self,
+        index: legalis_registry::vector_search::SemanticIndex,
+        path: Option<std::path::PathBuf>,
+    ) -> Self {
+        self.semantic_index = tokio::sync::Mutex::new(index);
+        self.semantic_index_path = path;
+        self
+    }
 }
 
 impl Default for AppState {
@@ -240,6 +335,9 @@ pub struct StatuteSummary {
     pub title: String,
     pub has_discretion: bool,
     pub precondition_count: usize,
+    /// Relevance to the free-text query, when one was given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
 }
 
 /// Statute permission update request.
@@ -272,6 +370,7 @@ impl From<&Statute> for StatuteSummary {
             title: s.title.clone(),
             has_discretion: s.discretion_logic.is_some(),
             precondition_count: s.preconditions.len(),
+            score: None,
         }
     }
 }
@@ -387,6 +486,12 @@ pub struct ComplexityResponse {
 /// Search/filter parameters for statutes.
 #[derive(Deserialize)]
 pub struct StatuteSearchQuery {
+    /// Free-text query; results are ranked by relevance
+    pub q: Option<String>,
+    /// Ranking of `q`: lexical, semantic or hybrid (default)
+    pub mode: Option<String>,
+    /// Weight of the semantic similarity in hybrid mode (0.0-1.0)
+    pub vector_weight: Option<f64>,
     /// Search by title (case-insensitive substring match)
     pub title: Option<String>,
     /// Filter by whether statute has discretion
@@ -403,6 +508,10 @@ pub struct StatuteSearchQuery {
     pub cursor: Option<String>,
     /// Field selection (comma-separated list of fields)
     pub fields: Option<String>,
+    /// Only statutes in force on this date, in the version then applicable
+    pub as_of: Option<chrono::NaiveDate>,
+    /// Only statute versions recorded by this moment (RFC 3339)
+    pub known_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 /// Statute comparison request.
@@ -594,6 +703,20 @@ pub struct SimulationResponse {
     pub discretionary_rate: f64,
     pub void_rate: f64,
     pub completed_at: String,
+    /// Totals of computed effect outputs (e.g. benefit amounts) per statute
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub output_totals: Vec<OutputTotalInfo>,
+}
+
+/// Total of a computed effect output across a simulated population.
+#[derive(Serialize, Deserialize)]
+pub struct OutputTotalInfo {
+    pub statute_id: String,
+    pub output: String,
+    pub unit: Option<String>,
+    /// Exact sum, serialized as a decimal string
+    pub total: legalis_core::Decimal,
+    pub count: usize,
 }
 
 /// Simulation comparison request.
@@ -724,14 +847,12 @@ async fn get_statute_permissions(
     user.require_permission(auth::Permission::ReadStatutes)?;
 
     // Check if statute exists
-    let statutes = state.statutes.read().await;
-    if !statutes.iter().any(|s| s.id == statute_id) {
+    if !state.statutes.contains(&statute_id).await? {
         return Err(ApiError::NotFound(format!(
             "Statute not found: {}",
             statute_id
         )));
     }
-    drop(statutes);
 
     // Get all users who have access to this statute
     // This is a simplified version - in production, you'd have a way to iterate
@@ -758,14 +879,12 @@ async fn grant_statute_permission(
     user.require_permission(auth::Permission::ManageUsers)?;
 
     // Check if statute exists
-    let statutes = state.statutes.read().await;
-    if !statutes.iter().any(|s| s.id == statute_id) {
+    if !state.statutes.contains(&statute_id).await? {
         return Err(ApiError::NotFound(format!(
             "Statute not found: {}",
             statute_id
         )));
     }
-    drop(statutes);
 
     // Parse user ID
     let target_user_id = uuid::Uuid::parse_str(&req.user_id)
@@ -848,14 +967,12 @@ async fn revoke_statute_permission(
     user.require_permission(auth::Permission::ManageUsers)?;
 
     // Check if statute exists
-    let statutes = state.statutes.read().await;
-    if !statutes.iter().any(|s| s.id == statute_id) {
+    if !state.statutes.contains(&statute_id).await? {
         return Err(ApiError::NotFound(format!(
             "Statute not found: {}",
             statute_id
         )));
     }
-    drop(statutes);
 
     // Parse user ID
     let target_user_id = uuid::Uuid::parse_str(&req.user_id)
@@ -1315,11 +1432,13 @@ pub fn create_router(state: Arc<AppState>) -> Router {
     // Initialize metrics
     metrics::init();
 
-    // Create GraphQL schema with shared WebSocket broadcaster
-    let graphql_state = graphql::GraphQLState::with_broadcaster(state.ws_broadcaster.clone());
+    // Create GraphQL schema sharing the statute store, WebSocket broadcaster and audit log
+    let graphql_state =
+        graphql::GraphQLState::with_store(state.statutes.clone(), state.ws_broadcaster.clone())
+            .with_audit_log(state.audit_log.clone());
     let graphql_schema = graphql::create_schema(graphql_state);
 
-    Router::new()
+    let router = Router::new()
         .route("/health", get(health_check))
         .route("/health/ready", get(readiness_check))
         .route("/metrics", get(metrics_endpoint))
@@ -1353,6 +1472,19 @@ pub fn create_router(state: Arc<AppState>) -> Router {
             "/api/v1/verify/async/{job_id}",
             get(get_verification_job_status),
         )
+        .route("/api/v1/decide", post(decide_entity))
+        .route("/api/v1/registry/whoami", get(registry_whoami))
+        .route("/api/v1/registry/packages", get(list_registry_packages))
+        .route(
+            "/api/v1/registry/packages/{id}",
+            get(get_registry_package).put(push_registry_package),
+        )
+        .route(
+            "/api/v1/registry/packages/{id}/versions",
+            get(list_registry_package_versions),
+        )
+        .route("/api/v1/federation/identity", get(federation_identity))
+        .route("/api/v1/federation/search", get(federation_search))
         .route("/api/v1/simulate", post(run_simulation))
         .route("/api/v1/simulate/stream", post(stream_simulation))
         .route("/api/v1/simulate/compare", post(compare_simulations))
@@ -1385,7 +1517,15 @@ pub fn create_router(state: Arc<AppState>) -> Router {
             "/api/v1/api-keys/{id}",
             get(get_api_key).delete(revoke_api_key),
         )
-        .route("/api/v1/api-keys/{id}/rotate", post(rotate_api_key))
+        .route("/api/v1/api-keys/{id}/rotate", post(rotate_api_key));
+
+    // Bearer tokens are verified by the `AuthUser` extractor via this extension
+    let router = match state.jwt_verifier.clone() {
+        Some(verifier) => router.layer(Extension(verifier)),
+        None => router,
+    };
+
+    router
         .layer(Extension(graphql_schema))
         .layer(middleware::from_fn(logging::log_request))
         .layer(CompressionLayer::new())
@@ -1417,8 +1557,8 @@ async fn health_check() -> impl IntoResponse {
 async fn readiness_check(
     State(state): State<Arc<AppState>>,
 ) -> Result<impl IntoResponse, ApiError> {
-    // Check if we can access the statutes (test read lock)
-    let statutes_available = state.statutes.try_read().is_ok();
+    // Check that the statute storage backend responds
+    let statutes_available = state.statutes.count().await.is_ok();
     let rebac_available = state.rebac.try_read().is_ok();
 
     let is_ready = statutes_available && rebac_available;
@@ -1453,7 +1593,7 @@ async fn list_statutes(
 ) -> Result<impl IntoResponse, ApiError> {
     user.require_permission(auth::Permission::ReadStatutes)?;
 
-    let statutes = state.statutes.read().await;
+    let statutes = state.statutes.list().await?;
     let summaries: Vec<StatuteSummary> = statutes.iter().map(StatuteSummary::from).collect();
 
     Ok(Json(ApiResponse::new(StatuteListResponse {
@@ -1474,7 +1614,10 @@ async fn search_statutes(
         fields: query.fields.clone(),
     };
 
-    let statutes = state.statutes.read().await;
+    let statutes = match legalis_registry::AsOf::from_parts(query.as_of, query.known_at) {
+        Some(as_of) => state.statutes.list_as_of(&as_of).await?,
+        None => state.statutes.list().await?,
+    };
 
     let mut filtered: Vec<&Statute> = statutes.iter().collect();
 
@@ -1499,10 +1642,21 @@ async fn search_statutes(
         filtered.retain(|s| s.preconditions.len() <= max);
     }
 
+    // Rank by relevance to the free-text query
+    let scores = match query.q.as_deref().map(str::trim).filter(|q| !q.is_empty()) {
+        Some(text) => {
+            let scores = rank_statutes(&state, text, &query).await?;
+            filtered.retain(|s| scores.contains_key(&s.id));
+            filtered.sort_by(|a, b| scores[&b.id].total_cmp(&scores[&a.id]));
+            scores
+        }
+        None => std::collections::HashMap::new(),
+    };
+
     let total = filtered.len();
 
     // Support both cursor-based and offset-based pagination
-    let (paginated, meta) = if let Some(cursor) = query.cursor {
+    let (mut paginated, meta) = if let Some(cursor) = query.cursor {
         // Cursor-based pagination
         let limit = query.limit.unwrap_or(100).min(1000);
 
@@ -1578,6 +1732,10 @@ async fn search_statutes(
         (paginated, meta)
     };
 
+    for summary in &mut paginated {
+        summary.score = scores.get(&summary.id).copied();
+    }
+
     Ok(Json(
         ApiResponse::new(StatuteListResponse {
             statutes: paginated,
@@ -1586,6 +1744,47 @@ async fn search_statutes(
     ))
 }
 
+/// Scores the current statutes against the free-text query of a search.
+///
+/// The semantic index is synced with the statute store first, and saved
+/// when it changed. Statutes that do not match are left out.
+async fn rank_statutes(
+    state: &AppState,
+    text: &str,
+    query: &StatuteSearchQuery,
+) -> Result<std::collections::HashMap<String, f64>, ApiError> {
+    use legalis_registry::{RankingConfig, RegistryError, SearchMode};
+
+    let mut mode = match query.mode.as_deref() {
+        Some(mode) => mode
+            .parse::<SearchMode>()
+            .map_err(|e| ApiError::BadRequest(e.to_string()))?,
+        None => SearchMode::hybrid(),
+    };
+    if let (SearchMode::Hybrid { vector_weight }, Some(weight)) = (&mut mode, query.vector_weight) {
+        *vector_weight = weight.clamp(0.0, 1.0);
+    }
+
+    let entries = state.statutes.entries().await?;
+    let mut index = state.semantic_index.lock().await;
+    if mode.uses_vectors() {
+        let report = index.sync(&entries).map_err(RegistryError::from)?;
+        if report.has_changes()
+            && let Some(path) = &state.semantic_index_path
+        {
+            index.save(path).map_err(RegistryError::from)?;
+        }
+    }
+
+    let ranked = index
+        .rank(text, mode, &entries, &RankingConfig::default())
+        .map_err(RegistryError::from)?;
+    Ok(ranked
+        .into_iter()
+        .map(|(entry, score)| (entry.statute.id.clone(), score))
+        .collect())
+}
+
 /// AI-powered statute suggestion endpoint.
 async fn suggest_statutes(
     user: auth::AuthUser,
@@ -1595,7 +1794,7 @@ async fn suggest_statutes(
     user.require_permission(auth::Permission::ReadStatutes)?;
 
     // Get available statutes
-    let statutes = state.statutes.read().await;
+    let statutes = state.statutes.list().await?;
     let statute_vec: Vec<_> = statutes.iter().cloned().collect();
 
     // Create suggestion engine (without LLM provider for now, uses rule-based)
@@ -1631,13 +1830,13 @@ async fn get_statute(
 ) -> Result<impl IntoResponse, ApiError> {
     user.require_permission(auth::Permission::ReadStatutes)?;
 
-    let statutes = state.statutes.read().await;
-    let statute = statutes
-        .iter()
-        .find(|s| s.id == id)
+    let statute = state
+        .statutes
+        .get(&id)
+        .await?
         .ok_or_else(|| ApiError::NotFound(format!("Statute not found: {}", id)))?;
 
-    Ok(Json(ApiResponse::new(statute.clone())))
+    Ok(Json(ApiResponse::new(statute)))
 }
 
 /// Create a new statute.
@@ -1648,10 +1847,8 @@ async fn create_statute(
 ) -> Result<impl IntoResponse, ApiError> {
     user.require_permission(auth::Permission::CreateStatutes)?;
 
-    let mut statutes = state.statutes.write().await;
-
     // Check for duplicate ID
-    if statutes.iter().any(|s| s.id == req.statute.id) {
+    if state.statutes.contains(&req.statute.id).await? {
         return Err(ApiError::BadRequest(format!(
             "Statute with ID '{}' already exists",
             req.statute.id
@@ -1665,7 +1862,7 @@ async fn create_statute(
 
     let statute_id = req.statute.id.clone();
     let statute_title = req.statute.title.clone();
-    statutes.push(req.statute.clone());
+    state.statutes.insert(req.statute.clone()).await?;
 
     // Update metrics
     metrics::STATUTE_OPERATIONS
@@ -1722,7 +1919,7 @@ async fn compare_statutes_matrix(
         ));
     }
 
-    let statutes = state.statutes.read().await;
+    let statutes = state.statutes.list().await?;
 
     // Fetch all requested statutes
     let mut statute_list = Vec::new();
@@ -1803,7 +2000,7 @@ async fn compare_statutes(
 ) -> Result<impl IntoResponse, ApiError> {
     user.require_permission(auth::Permission::ReadStatutes)?;
 
-    let statutes = state.statutes.read().await;
+    let statutes = state.statutes.list().await?;
 
     let statute_a = statutes
         .iter()
@@ -1872,26 +2069,32 @@ async fn batch_create_statutes(
         return Err(ApiError::BadRequest("No statutes provided".to_string()));
     }
 
-    let mut statutes = state.statutes.write().await;
     let mut created = 0;
     let mut failed = 0;
     let mut errors = Vec::new();
     let total_requested = req.statutes.len();
 
+    // Each statute succeeds or fails on its own, so a failure mid-batch
+    // leaves the earlier statutes created and is reported with its ID
     for statute in req.statutes {
-        // Check for duplicate ID
-        if statutes.iter().any(|s| s.id == statute.id) {
-            errors.push(format!("Statute with ID '{}' already exists", statute.id));
-            failed += 1;
-            continue;
+        let id = statute.id.clone();
+        match state.statutes.insert(statute).await {
+            Ok(_) => {
+                info!("Created statute: {} by user {} (batch)", id, user.username);
+                created += 1;
+            }
+            Err(legalis_registry::RegistryError::DuplicateId(_)) => {
+                errors.push(format!("Statute with ID '{}' already exists", id));
+                failed += 1;
+            }
+            Err(e) => {
+                errors.push(format!(
+                    "Statute with ID '{}' could not be stored: {}",
+                    id, e
+                ));
+                failed += 1;
+            }
         }
-
-        info!(
-            "Creating statute: {} by user {} (batch)",
-            statute.id, user.username
-        );
-        statutes.push(statute);
-        created += 1;
     }
 
     // Audit log
@@ -1938,16 +2141,12 @@ async fn batch_delete_statutes(
         return Err(ApiError::BadRequest("No statute IDs provided".to_string()));
     }
 
-    let mut statutes = state.statutes.write().await;
     let mut deleted = 0;
     let mut not_found = Vec::new();
     let total_requested = req.statute_ids.len();
 
     for id in req.statute_ids {
-        let initial_len = statutes.len();
-        statutes.retain(|s| s.id != id);
-
-        if statutes.len() < initial_len {
+        if state.statutes.delete(&id).await? {
             info!("Deleted statute: {} by user {} (batch)", id, user.username);
             deleted += 1;
         } else {
@@ -1987,11 +2186,7 @@ async fn delete_statute(
 ) -> Result<impl IntoResponse, ApiError> {
     user.require_permission(auth::Permission::DeleteStatutes)?;
 
-    let mut statutes = state.statutes.write().await;
-    let initial_len = statutes.len();
-    statutes.retain(|s| s.id != id);
-
-    if statutes.len() == initial_len {
+    if !state.statutes.delete(&id).await? {
         return Err(ApiError::NotFound(format!("Statute not found: {}", id)));
     }
 
@@ -2038,7 +2233,7 @@ async fn verify_statutes(
 ) -> Result<impl IntoResponse, ApiError> {
     user.require_permission(auth::Permission::VerifyStatutes)?;
 
-    let statutes = state.statutes.read().await;
+    let statutes = state.statutes.list().await?;
 
     let to_verify: Vec<&Statute> = if req.statute_ids.is_empty() {
         statutes.iter().collect()
@@ -2078,7 +2273,7 @@ async fn verify_statutes_detailed(
 ) -> Result<impl IntoResponse, ApiError> {
     user.require_permission(auth::Permission::VerifyStatutes)?;
 
-    let statutes = state.statutes.read().await;
+    let statutes = state.statutes.list().await?;
 
     let to_verify: Vec<&Statute> = if req.statute_ids.is_empty() {
         statutes.iter().collect()
@@ -2122,7 +2317,7 @@ async fn detect_conflicts(
 ) -> Result<impl IntoResponse, ApiError> {
     user.require_permission(auth::Permission::VerifyStatutes)?;
 
-    let statutes = state.statutes.read().await;
+    let statutes = state.statutes.list().await?;
 
     let to_check: Vec<&Statute> = if req.statute_ids.is_empty() {
         statutes.iter().collect()
@@ -2195,7 +2390,18 @@ async fn verify_statutes_async(
             .await;
 
         // Get statutes
-        let statutes = state_clone.statutes.read().await;
+        let statutes = match state_clone.statutes.list().await {
+            Ok(statutes) => statutes,
+            Err(e) => {
+                state_clone
+                    .verification_jobs
+                    .update_job(&job_id, |job| {
+                        job.fail(format!("Failed to load statutes: {}", e));
+                    })
+                    .await;
+                return;
+            }
+        };
 
         let to_verify: Vec<&Statute> = if statute_ids.is_empty() {
             statutes.iter().collect()
@@ -2336,7 +2542,7 @@ async fn verify_bulk_stream(
     }
 
     // Clone data for async stream
-    let statutes = state.statutes.read().await.clone();
+    let statutes = state.statutes.list().await?;
 
     // Create stream
     let stream = stream::unfold(
@@ -2437,7 +2643,7 @@ async fn verify_batch(
         ));
     }
 
-    let statutes = state.statutes.read().await;
+    let statutes = state.statutes.list().await?;
     let verifier = legalis_verifier::StatuteVerifier::new();
 
     // Process each job
@@ -2498,7 +2704,7 @@ async fn analyze_complexity(
 ) -> Result<impl IntoResponse, ApiError> {
     user.require_permission(auth::Permission::ReadStatutes)?;
 
-    let statutes = state.statutes.read().await;
+    let statutes = state.statutes.list().await?;
     let statute = statutes
         .iter()
         .find(|s| s.id == id)
@@ -2523,8 +2729,7 @@ async fn analyze_complexity(
     })))
 }
 
-/// Get all versions of a statute by base ID.
-/// Statutes are grouped by their base ID (the part before the version suffix).
+/// Get every stored version of a statute, oldest first.
 async fn get_statute_versions(
     user: auth::AuthUser,
     State(state): State<Arc<AppState>>,
@@ -2532,18 +2737,16 @@ async fn get_statute_versions(
 ) -> Result<impl IntoResponse, ApiError> {
     user.require_permission(auth::Permission::ReadStatutes)?;
 
-    let statutes = state.statutes.read().await;
-
-    // Find all statutes that match the base_id
-    // We consider statutes with the same ID or ID that starts with base_id
-    let versions: Vec<StatuteVersionInfo> = statutes
+    let versions: Vec<StatuteVersionInfo> = state
+        .statutes
+        .history(&base_id)
+        .await?
         .iter()
-        .filter(|s| s.id == base_id || s.id.starts_with(&format!("{}-v", base_id)))
-        .map(|s| StatuteVersionInfo {
-            id: s.id.clone(),
-            version: s.version,
-            title: s.title.clone(),
-            created_at: None, // Would need to track creation timestamps
+        .map(|e| StatuteVersionInfo {
+            id: e.statute.id.clone(),
+            version: e.version,
+            title: e.statute.title.clone(),
+            created_at: Some(e.modified_at.to_rfc3339()),
         })
         .collect();
 
@@ -2564,6 +2767,8 @@ async fn get_statute_versions(
 }
 
 /// Create a new version of an existing statute.
+///
+/// The version is stored in the statute's history under the same ID.
 async fn create_statute_version(
     user: auth::AuthUser,
     State(state): State<Arc<AppState>>,
@@ -2572,43 +2777,15 @@ async fn create_statute_version(
 ) -> Result<impl IntoResponse, ApiError> {
     user.require_permission(auth::Permission::CreateStatutes)?;
 
-    let mut statutes = state.statutes.write().await;
-
-    // Find the original statute
-    let original = statutes
-        .iter()
-        .find(|s| s.id == id)
-        .ok_or_else(|| ApiError::NotFound(format!("Statute not found: {}", id)))?
-        .clone();
-
-    // Find the highest version number for this base statute
-    let base_id = if original.id.contains("-v") {
-        original.id.split("-v").next().unwrap_or(&original.id)
-    } else {
-        &original.id
-    };
-
-    let max_version = statutes
-        .iter()
-        .filter(|s| s.id == base_id || s.id.starts_with(&format!("{}-v", base_id)))
-        .map(|s| s.version)
-        .max()
-        .unwrap_or(original.version);
-
-    let new_version = max_version + 1;
-    let new_id = format!("{}-v{}", base_id, new_version);
-
-    // Check if new ID already exists
-    if statutes.iter().any(|s| s.id == new_id) {
-        return Err(ApiError::BadRequest(format!(
-            "Statute version already exists: {}",
-            new_id
-        )));
-    }
+    let mut writer = state.statutes.writer().await;
+    let current = writer
+        .entry(&id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Statute not found: {}", id)))?;
 
-    // Create new version based on original with optional modifications
-    let mut new_statute = original.clone();
-    new_statute.id = new_id.clone();
+    // Create new version based on the latest one with optional modifications
+    let new_version = current.version + 1;
+    let mut new_statute = current.statute;
     new_statute.version = new_version;
 
     if let Some(title) = req.title {
@@ -2629,9 +2806,10 @@ async fn create_statute_version(
 
     info!(
         "Creating statute version: {} (v{}) by user {}",
-        new_id, new_version, user.username
+        id, new_version, user.username
     );
-    statutes.push(new_statute.clone());
+    writer.update(new_statute.clone()).await?;
+    drop(writer);
 
     // Audit log
     state
@@ -2641,12 +2819,11 @@ async fn create_statute_version(
             user.id.to_string(),
             user.username.clone(),
             "create_statute_version".to_string(),
-            Some(new_id.clone()),
+            Some(id.clone()),
             Some("statute".to_string()),
             serde_json::json!({
-                "statute_id": new_id,
-                "version": new_version,
-                "base_id": base_id
+                "statute_id": id,
+                "version": new_version
             }),
         )
         .await;
@@ -2691,7 +2868,7 @@ async fn run_simulation(
         ));
     }
 
-    let statutes = state.statutes.read().await;
+    let statutes = state.statutes.list().await?;
 
     let to_simulate: Vec<Statute> = if req.statute_ids.is_empty() {
         statutes.clone()
@@ -2761,6 +2938,21 @@ async fn run_simulation(
         0.0
     };
 
+    let mut output_totals: Vec<OutputTotalInfo> = sim_metrics
+        .statute_metrics
+        .iter()
+        .flat_map(|(statute_id, m)| {
+            m.output_totals.iter().map(|(output, t)| OutputTotalInfo {
+                statute_id: statute_id.clone(),
+                output: output.clone(),
+                unit: t.unit.clone(),
+                total: t.total,
+                count: t.count,
+            })
+        })
+        .collect();
+    output_totals.sort_by(|a, b| (&a.statute_id, &a.output).cmp(&(&b.statute_id, &b.output)));
+
     Ok(Json(ApiResponse::new(SimulationResponse {
         simulation_id: uuid::Uuid::new_v4().to_string(),
         total_entities: req.population_size,
@@ -2771,6 +2963,7 @@ async fn run_simulation(
         discretionary_rate,
         void_rate,
         completed_at: chrono::Utc::now().to_rfc3339(),
+        output_totals,
     })))
 }
 
@@ -2794,7 +2987,7 @@ async fn stream_simulation(
         ));
     }
 
-    let statutes = state.statutes.read().await;
+    let statutes = state.statutes.list().await?;
 
     let to_simulate: Vec<Statute> = if req.statute_ids.is_empty() {
         statutes.clone()
@@ -2939,7 +3132,7 @@ async fn compare_simulations(
         ));
     }
 
-    let statutes = state.statutes.read().await;
+    let statutes = state.statutes.list().await?;
 
     let statutes_a: Vec<Statute> = statutes
         .iter()
@@ -3031,7 +3224,7 @@ async fn check_compliance(
 ) -> Result<impl IntoResponse, ApiError> {
     user.require_permission(auth::Permission::VerifyStatutes)?;
 
-    let statutes = state.statutes.read().await;
+    let statutes = state.statutes.list().await?;
 
     let to_check: Vec<Statute> = if req.statute_ids.is_empty() {
         statutes.clone()
@@ -3093,7 +3286,7 @@ async fn whatif_analysis(
 ) -> Result<impl IntoResponse, ApiError> {
     user.require_permission(auth::Permission::VerifyStatutes)?;
 
-    let statutes = state.statutes.read().await;
+    let statutes = state.statutes.list().await?;
 
     let to_analyze: Vec<Statute> = if req.statute_ids.is_empty() {
         statutes.clone()
@@ -3167,6 +3360,165 @@ async fn whatif_analysis(
     })))
 }
 
+/// Decide an entity's case against a set of statutes.
+///
+/// Returns each statute's result with its reasoning and the facts that are
+/// still missing, and records the decision in the audit log.
+async fn decide_entity(
+    user: auth::AuthUser,
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<decide::DecisionRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    user.require_permission(auth::Permission::VerifyStatutes)?;
+
+    let statutes = decide::load_statutes(&state.statutes, &req).await?;
+    let decision = decide::decide(&statutes, &req);
+
+    decide::record_decision(
+        &state.audit_log,
+        user.id.to_string(),
+        user.username.clone(),
+        &decision,
+    )
+    .await;
+
+    Ok(Json(ApiResponse::new(decision)))
+}
+
+/// Query parameters for fetching a registry package.
+#[derive(Deserialize)]
+pub struct PackageQuery {
+    /// Pinned registry version (defaults to the latest)
+    pub version: Option<u32>,
+}
+
+/// Report the identity behind the request's credentials.
+async fn registry_whoami(user: auth::AuthUser) -> Result<impl IntoResponse, ApiError> {
+    user.require_permission(auth::Permission::ReadStatutes)?;
+    Ok(Json(ApiResponse::new(registry_protocol::WhoAmI::from(
+        &user,
+    ))))
+}
+
+/// List the registry packages visible to the user.
+async fn list_registry_packages(
+    user: auth::AuthUser,
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, ApiError> {
+    user.require_permission(auth::Permission::ReadStatutes)?;
+    let packages = registry_protocol::list_packages(&state.statutes, &user).await?;
+    Ok(Json(ApiResponse::new(packages)))
+}
+
+/// Fetch the latest or a pinned version of a registry package.
+async fn get_registry_package(
+    user: auth::AuthUser,
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(query): Query<PackageQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    user.require_permission(auth::Permission::ReadStatutes)?;
+    let package = registry_protocol::fetch_package(&state.statutes, &user, &id, query.version)
+        .await?
+        .ok_or_else(|| match query.version {
+            Some(version) => ApiError::NotFound(format!("Package not found: {} v{}", id, version)),
+            None => ApiError::NotFound(format!("Package not found: {}", id)),
+        })?;
+    Ok(Json(ApiResponse::new(package)))
+}
+
+/// List every published version of a registry package.
+async fn list_registry_package_versions(
+    user: auth::AuthUser,
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    user.require_permission(auth::Permission::ReadStatutes)?;
+    let versions = registry_protocol::list_versions(&state.statutes, &user, &id).await?;
+    if versions.is_empty() {
+        return Err(ApiError::NotFound(format!("Package not found: {}", id)));
+    }
+    Ok(Json(ApiResponse::new(versions)))
+}
+
+/// Returns the federation identity peers pin to verify search responses.
+async fn federation_identity(
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, ApiError> {
+    let federation = enabled_federation(&state)?;
+    Ok(Json(ApiResponse::new(federation.identity())))
+}
+
+/// Answer a peer registry's search with a signed response.
+async fn federation_search(
+    user: auth::AuthUser,
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<federation::FederationSearchQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    user.require_permission(auth::Permission::ReadStatutes)?;
+    let federation = enabled_federation(&state)?;
+    let response = federation
+        .search(&state.statutes, &query.into_request())
+        .await?;
+    Ok(Json(ApiResponse::new(response)))
+}
+
+fn enabled_federation(state: &AppState) -> Result<&federation::FederationIdentity, ApiError> {
+    state
+        .federation
+        .as_deref()
+        .ok_or_else(|| ApiError::NotFound("Federation is not enabled".to_string()))
+}
+
+/// Publish a new version of a registry package.
+///
+/// Responds with `409 Conflict` when the push is based on a version other
+/// than the registry's latest and is not forced.
+async fn push_registry_package(
+    user: auth::AuthUser,
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(req): Json<registry_protocol::PushRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let forced = req.force;
+    let response = registry_protocol::publish(&state.statutes, &user, &id, req).await?;
+
+    let (event_type, status) = match response.status {
+        registry_protocol::PushStatus::Created => {
+            metrics::STATUTES_TOTAL.inc();
+            (audit::AuditEventType::StatuteCreated, StatusCode::CREATED)
+        }
+        registry_protocol::PushStatus::Updated => {
+            (audit::AuditEventType::StatuteUpdated, StatusCode::OK)
+        }
+        registry_protocol::PushStatus::Unchanged => {
+            return Ok((StatusCode::OK, Json(ApiResponse::new(response))));
+        }
+    };
+    metrics::STATUTE_OPERATIONS
+        .with_label_values(&["publish"])
+        .inc();
+    state
+        .audit_log
+        .log_success(
+            event_type,
+            user.id.to_string(),
+            user.username.clone(),
+            "publish_package".to_string(),
+            Some(id.clone()),
+            Some("statute".to_string()),
+            serde_json::json!({
+                "statute_id": id,
+                "version": response.package.version,
+                "content_hash": response.package.content_hash,
+                "forced": forced,
+            }),
+        )
+        .await;
+
+    Ok((status, Json(ApiResponse::new(response))))
+}
+
 /// Save a simulation result for later retrieval.
 async fn save_simulation(
     user: auth::AuthUser,
@@ -3315,7 +3667,7 @@ async fn visualize_statute(
 ) -> Result<impl IntoResponse, ApiError> {
     user.require_permission(auth::Permission::ReadStatutes)?;
 
-    let statutes = state.statutes.read().await;
+    let statutes = state.statutes.list().await?;
     let statute = statutes
         .iter()
         .find(|s| s.id == id)
@@ -3440,22 +3792,322 @@ mod tests {
         assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
     }
 
+    #[tokio::test]
+    async fn test_handlers_share_statute_store() {
+        let state = Arc::new(AppState::new());
+        let app = create_router(Arc::clone(&state));
+
+        let statute = Statute::new(
+            "shared-1",
+            "Shared Statute",
+            Effect::new(EffectType::Grant, "Test grant"),
+        )
+        .with_jurisdiction("JP");
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/statutes")
+                    .header("Authorization", "ApiKey lgl_12345678901234567890")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({ "statute": statute }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let entry = state.statutes.entry("shared-1").await.unwrap().unwrap();
+        assert_eq!(entry.version, 1);
+        assert_eq!(entry.jurisdiction, "JP");
+
+        // GraphQL and gRPC built from the state see the same statutes
+        let schema = graphql::create_schema(graphql::GraphQLState::with_store(
+            state.statutes.clone(),
+            state.ws_broadcaster.clone(),
+        ));
+        let result = schema.execute("{ statuteCount }").await;
+        assert_eq!(
+            result.data.into_json().unwrap()["statuteCount"],
+            serde_json::json!(1)
+        );
+
+        #[cfg(feature = "grpc")]
+        assert!(
+            state
+                .grpc_state()
+                .statutes
+                .contains("shared-1")
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_statute_versions_share_one_id() {
+        let state = Arc::new(AppState::new());
+        state
+            .statutes
+            .insert(Statute::new(
+                "benefit",
+                "Benefit",
+                Effect::new(EffectType::Grant, "Benefit"),
+            ))
+            .await
+            .unwrap();
+        let app = create_router(Arc::clone(&state));
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/statutes/benefit/versions/new")
+                    .header("Authorization", "ApiKey lgl_12345678901234567890")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({ "title": "Benefit (amended)" }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        assert_eq!(state.statutes.count().await.unwrap(), 1);
+        assert_eq!(
+            state.statutes.versions("benefit").await.unwrap(),
+            vec![1, 2]
+        );
+        let latest = state.statutes.get("benefit").await.unwrap().unwrap();
+        assert_eq!(latest.title, "Benefit (amended)");
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/statutes/benefit/versions")
+                    .header("Authorization", "ApiKey lgl_12345678901234567890")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let versions: Vec<_> = json["data"]["versions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| (v["id"].as_str().unwrap(), v["version"].as_u64().unwrap()))
+            .collect();
+        assert_eq!(versions, vec![("benefit", 1), ("benefit", 2)]);
+    }
+
+    #[tokio::test]
+    async fn test_batch_create_reports_each_duplicate() {
+        let state = Arc::new(AppState::new());
+        let statute = |id: &str| Statute::new(id, id, Effect::new(EffectType::Grant, "Grant"));
+        state.statutes.insert(statute("existing")).await.unwrap();
+        let app = create_router(Arc::clone(&state));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/statutes/batch")
+                    .header("Authorization", "ApiKey lgl_12345678901234567890")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({
+                            "statutes": [statute("a"), statute("existing"), statute("b"), statute("a")]
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["data"]["created"], 2);
+        assert_eq!(json["data"]["failed"], 2);
+        assert_eq!(
+            json["data"]["errors"],
+            serde_json::json!([
+                "Statute with ID 'existing' already exists",
+                "Statute with ID 'a' already exists"
+            ])
+        );
+        assert_eq!(state.statutes.count().await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_decide_endpoint() {
+        use legalis_core::{ComparisonOp, Condition};
+
+        let state = Arc::new(AppState::new());
+        state
+            .statutes
+            .insert(
+                Statute::new(
+                    "pension",
+                    "Pension",
+                    Effect::new(EffectType::Grant, "Pension"),
+                )
+                .with_precondition(Condition::age(ComparisonOp::GreaterOrEqual, 65))
+                .with_precondition(Condition::income(ComparisonOp::LessThan, 30_000)),
+            )
+            .await
+            .unwrap();
+        let app = create_router(Arc::clone(&state));
+
+        let decide_request = |body: serde_json::Value| {
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/decide")
+                .header("Authorization", "ApiKey lgl_12345678901234567890")
+                .header("Content-Type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(decide_request(serde_json::json!({
+                "statute_ids": ["pension"],
+                "facts": { "age": 70 }
+            })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let decision = &json["data"];
+        assert_eq!(decision["missing_facts"], serde_json::json!(["income"]));
+        assert_eq!(decision["results"][0]["statute_id"], "pension");
+        assert_eq!(
+            decision["results"][0]["audit_trail"]
+                .as_array()
+                .unwrap()
+                .len(),
+            2
+        );
+
+        let entries = state
+            .audit_log
+            .query(audit::AuditQueryFilter::default())
+            .await;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entries[0].event_type,
+            audit::AuditEventType::DecisionRendered
+        );
+        assert_eq!(
+            entries[0].resource_id.as_deref(),
+            decision["decision_id"].as_str()
+        );
+
+        let response = app
+            .oneshot(decide_request(serde_json::json!({
+                "statute_ids": ["unknown"],
+                "facts": {}
+            })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_registry_push_conflict() {
+        let state = Arc::new(AppState::new());
+        let app = create_router(Arc::clone(&state));
+        let push = |body: serde_json::Value| {
+            Request::builder()
+                .method("PUT")
+                .uri("/api/v1/registry/packages/grant")
+                .header("Authorization", "ApiKey lgl_12345678901234567890")
+                .header("Content-Type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap()
+        };
+        let statute = |title: &str| {
+            serde_json::to_value(Statute::new(
+                "grant",
+                title,
+                Effect::new(EffectType::Grant, "Grant"),
+            ))
+            .unwrap()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(push(serde_json::json!({ "statute": statute("First") })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = app
+            .clone()
+            .oneshot(push(serde_json::json!({ "statute": statute("Second") })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let response = app
+            .clone()
+            .oneshot(push(
+                serde_json::json!({ "statute": statute("Second"), "base_version": 1 }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/registry/packages/grant?version=1")
+                    .header("Authorization", "ApiKey lgl_12345678901234567890")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["data"]["statute"]["title"], "First");
+        assert_eq!(json["data"]["version"], 1);
+        assert_eq!(state.audit_log.count().await, 2);
+    }
+
     #[tokio::test]
     async fn test_statute_search() {
         let state = Arc::new(AppState::new());
 
         // Add test statute directly to state
-        {
-            let mut statutes = state.statutes.write().await;
-            statutes.push(
+        state
+            .statutes
+            .insert(
                 Statute::new(
                     "search-test-1",
                     "Searchable Statute",
                     Effect::new(EffectType::Grant, "Test grant"),
                 )
                 .with_jurisdiction("TEST"),
-            );
-        }
+            )
+            .await
+            .unwrap();
 
         let app = create_router(state);
 
@@ -3479,6 +4131,115 @@ mod tests {
         assert!(!json["data"]["statutes"].as_array().unwrap().is_empty());
     }
 
+    #[tokio::test]
+    async fn test_statute_search_as_of() {
+        use chrono::NaiveDate;
+        use legalis_core::TemporalValidity;
+
+        let state = Arc::new(AppState::new());
+        let effective = |y| {
+            TemporalValidity::new().with_effective_date(NaiveDate::from_ymd_opt(y, 1, 1).unwrap())
+        };
+        let original = Statute::new(
+            "allowance",
+            "Allowance 2020",
+            Effect::new(EffectType::Grant, "Allowance"),
+        )
+        .with_temporal_validity(effective(2020));
+        state.statutes.insert(original.clone()).await.unwrap();
+        let mut amended = original;
+        amended.title = "Allowance 2022".to_string();
+        amended.temporal_validity = effective(2022);
+        state.statutes.update(amended).await.unwrap();
+
+        let app = create_router(state);
+        let search = |query: &str| {
+            Request::builder()
+                .uri(format!("/api/v1/statutes/search?{}", query))
+                .header("Authorization", "ApiKey lgl_12345678901234567890")
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        for (query, expected) in [
+            ("as_of=2021-06-01", vec!["Allowance 2020"]),
+            ("as_of=2023-01-01", vec!["Allowance 2022"]),
+            ("as_of=2019-12-31", vec![]),
+            ("title=allowance", vec!["Allowance 2022"]),
+        ] {
+            let response = app.clone().oneshot(search(query)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            let titles: Vec<_> = json["data"]["statutes"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|s| s["title"].as_str().unwrap())
+                .collect();
+            assert_eq!(titles, expected, "{}", query);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_statute_search_by_meaning() {
+        let state = Arc::new(AppState::new());
+        for (id, title, effect) in [
+            (
+                "income-tax",
+                "Income Taxation Act",
+                "Residents pay tax on their income",
+            ),
+            (
+                "road-traffic",
+                "Road Traffic Act",
+                "Drivers must hold a licence",
+            ),
+            (
+                "child-care",
+                "Child Care Leave Act",
+                "Parents may take leave to raise children",
+            ),
+        ] {
+            let statute = Statute::new(id, title, Effect::new(EffectType::Grant, effect));
+            state.statutes.insert(statute).await.unwrap();
+        }
+
+        let app = create_router(state);
+        let search = |query: &str| {
+            Request::builder()
+                .uri(format!("/api/v1/statutes/search?{}", query))
+                .header("Authorization", "ApiKey lgl_12345678901234567890")
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        for (query, expected) in [
+            ("q=taxes+on+earned+income&mode=semantic", vec!["income-tax"]),
+            (
+                "q=raising+a+child&mode=hybrid&vector_weight=0.8",
+                vec!["child-care"],
+            ),
+            ("q=taxes+on+earned+income&mode=lexical", vec![]),
+        ] {
+            let response = app.clone().oneshot(search(query)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            let statutes = json["data"]["statutes"].as_array().unwrap();
+            let ids: Vec<_> = statutes.iter().map(|s| s["id"].as_str().unwrap()).collect();
+            assert_eq!(ids, expected, "{}", query);
+            assert!(statutes.iter().all(|s| s["score"].as_f64().unwrap() > 0.0));
+        }
+
+        let response = app.oneshot(search("q=tax&mode=fuzzy")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[tokio::test]
     async fn test_graphql_integration() {
         // GraphQL create and query test - uses GraphQL schema
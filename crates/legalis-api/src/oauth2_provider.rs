@@ -8,6 +8,8 @@
 //! - GitHub
 //! - Generic OIDC providers
 
+use crate::auth::Role;
+use crate::jwt::{JwtError, JwtSigner};
 #[cfg(feature = "oauth2-auth")]
 use oauth2::{
     AuthUrl, AuthorizationCode, ClientId, ClientSecret, CsrfToken, PkceCodeChallenge,
@@ -187,6 +189,39 @@ pub struct OAuth2UserInfo {
     pub email_verified: Option<bool>,
 }
 
+impl OAuth2UserInfo {
+    /// Issues an API session token for this user after a completed OAuth2 login.
+    ///
+    /// The token carries the provider identity (`sub`, `email`, `preferred_username`)
+    /// and the granted role, so a [`JwtVerifier`](crate::jwt::JwtVerifier) holding the
+    /// signer's verification key accepts it like any other bearer token.
+    pub fn issue_session_token(
+        &self,
+        signer: &JwtSigner,
+        issuer: &str,
+        audience: &str,
+        role: Role,
+        ttl_secs: i64,
+    ) -> Result<String, JwtError> {
+        let now = chrono::Utc::now().timestamp();
+        let mut claims = serde_json::json!({
+            "iss": issuer,
+            "aud": audience,
+            "iat": now,
+            "nbf": now,
+            "exp": now + ttl_secs,
+            "role": role,
+        });
+        let user = serde_json::to_value(self)?;
+        if let (Some(claims), Some(user)) = (claims.as_object_mut(), user.as_object()) {
+            for (name, value) in user.iter().filter(|(_, v)| !v.is_null()) {
+                claims.insert(name.clone(), value.clone());
+            }
+        }
+        signer.sign(&claims)
+    }
+}
+
 /// OAuth2 authorization state (stored during auth flow).
 #[derive(Debug, Clone)]
 pub struct AuthState {
@@ -368,3 +403,45 @@ impl OAuth2Manager {
         Err("OAuth2 support is not enabled. Enable the 'oauth2-auth' feature.".to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthMethod;
+    use crate::jwt::{ClaimMapping, JwtValidation, JwtVerifier};
+
+    #[test]
+    fn test_session_token_verifies_end_to_end() {
+        let signer = JwtSigner::hs256(b"session-secret").with_kid("session-1");
+        let verifier = JwtVerifier::new(
+            JwtValidation::default()
+                .with_issuer("legalis-api")
+                .with_audience("legalis"),
+        )
+        .with_key(signer.verification_key())
+        .with_claim_mapping(ClaimMapping::default());
+
+        let info = OAuth2UserInfo {
+            sub: Some("github|1234".to_string()),
+            email: Some("dana@example.com".to_string()),
+            name: Some("Dana".to_string()),
+            preferred_username: Some("dana".to_string()),
+            picture: None,
+            email_verified: Some(true),
+        };
+        let token = info
+            .issue_session_token(&signer, "legalis-api", "legalis", Role::Analyst, 300)
+            .unwrap();
+
+        let user = verifier.authenticate(&token).unwrap();
+        assert_eq!(user.role, Role::Analyst);
+        assert_eq!(user.username, "dana");
+        assert_eq!(user.auth_method, AuthMethod::Jwt);
+
+        // Wrong audience is rejected
+        let other = info
+            .issue_session_token(&signer, "legalis-api", "elsewhere", Role::Analyst, 300)
+            .unwrap();
+        assert!(verifier.authenticate(&other).is_err());
+    }
+}
@@ -108,6 +108,14 @@ pub fn generate_spec() -> Value {
             {
                 "name": "metrics",
                 "description": "Observability and monitoring"
+            },
+            {
+                "name": "registry",
+                "description": "Package protocol used by `legalis registry`"
+            },
+            {
+                "name": "federation",
+                "description": "Signed statute search between peer registries"
             }
         ],
         "paths": {
@@ -616,6 +624,272 @@ pub fn generate_spec() -> Value {
                     }
                 }
             },
+            "/api/v1/decide": {
+                "post": {
+                    "tags": ["simulation"],
+                    "summary": "Decide an entity's case",
+                    "description": "Evaluates an entity's facts against the selected statutes and returns per-statute results, computed effects, reasoning steps and missing facts. The decision is recorded in the audit log.",
+                    "operationId": "decide",
+                    "security": [
+                        {"ApiKeyAuth": []},
+                        {"ApiKeyHeader": []},
+                        {"BearerAuth": []}
+                    ],
+                    "requestBody": {
+                        "required": true,
+                        "content": {
+                            "application/json": {
+                                "schema": {
+                                    "$ref": "#/components/schemas/DecisionRequest"
+                                },
+                                "example": {
+                                    "statute_ids": ["pension-act-art-3"],
+                                    "facts": {
+                                        "age": 67,
+                                        "income": 18000
+                                    },
+                                    "date": "2026-04-01"
+                                }
+                            }
+                        }
+                    },
+                    "responses": {
+                        "200": {
+                            "description": "Decision rendered"
+                        },
+                        "404": {
+                            "description": "A requested statute does not exist"
+                        }
+                    }
+                }
+            },
+            "/api/v1/registry/whoami": {
+                "get": {
+                    "tags": ["registry"],
+                    "summary": "Check registry credentials",
+                    "description": "Returns the authenticated user and whether they may publish packages",
+                    "operationId": "registryWhoami",
+                    "security": [
+                        {"ApiKeyAuth": []},
+                        {"ApiKeyHeader": []},
+                        {"BearerAuth": []}
+                    ],
+                    "responses": {
+                        "200": {
+                            "description": "Authenticated user"
+                        },
+                        "401": {
+                            "description": "Missing or invalid credentials"
+                        }
+                    }
+                }
+            },
+            "/api/v1/registry/packages": {
+                "get": {
+                    "tags": ["registry"],
+                    "summary": "List packages",
+                    "description": "Lists the latest version of every package visible to the caller, with its content hash",
+                    "operationId": "listRegistryPackages",
+                    "security": [
+                        {"ApiKeyAuth": []},
+                        {"ApiKeyHeader": []},
+                        {"BearerAuth": []}
+                    ],
+                    "responses": {
+                        "200": {
+                            "description": "Package summaries"
+                        }
+                    }
+                }
+            },
+            "/api/v1/registry/packages/{id}": {
+                "get": {
+                    "tags": ["registry"],
+                    "summary": "Fetch a package",
+                    "description": "Returns a package version with its statute and DSL source",
+                    "operationId": "getRegistryPackage",
+                    "security": [
+                        {"ApiKeyAuth": []},
+                        {"ApiKeyHeader": []},
+                        {"BearerAuth": []}
+                    ],
+                    "parameters": [
+                        {
+                            "name": "id",
+                            "in": "path",
+                            "required": true,
+                            "schema": {
+                                "type": "string"
+                            }
+                        },
+                        {
+                            "name": "version",
+                            "in": "query",
+                            "description": "Version to fetch (defaults to latest)",
+                            "schema": {
+                                "type": "integer",
+                                "minimum": 1
+                            }
+                        }
+                    ],
+                    "responses": {
+                        "200": {
+                            "description": "Package found"
+                        },
+                        "404": {
+                            "description": "Package or version does not exist"
+                        }
+                    }
+                },
+                "put": {
+                    "tags": ["registry"],
+                    "summary": "Publish a package",
+                    "description": "Publishes a new package version. The push must name the version it was based on; pushes based on a stale version are rejected unless forced.",
+                    "operationId": "pushRegistryPackage",
+                    "security": [
+                        {"ApiKeyAuth": []},
+                        {"ApiKeyHeader": []},
+                        {"BearerAuth": []}
+                    ],
+                    "parameters": [
+                        {
+                            "name": "id",
+                            "in": "path",
+                            "required": true,
+                            "schema": {
+                                "type": "string"
+                            }
+                        }
+                    ],
+                    "requestBody": {
+                        "required": true,
+                        "content": {
+                            "application/json": {
+                                "schema": {
+                                    "$ref": "#/components/schemas/PushRequest"
+                                }
+                            }
+                        }
+                    },
+                    "responses": {
+                        "200": {
+                            "description": "Package updated, or already up to date"
+                        },
+                        "201": {
+                            "description": "Package created"
+                        },
+                        "403": {
+                            "description": "Caller may not publish, or may not force a push"
+                        },
+                        "409": {
+                            "description": "The registry moved past the pushed base version"
+                        }
+                    }
+                }
+            },
+            "/api/v1/registry/packages/{id}/versions": {
+                "get": {
+                    "tags": ["registry"],
+                    "summary": "List package versions",
+                    "description": "Lists the summary of every published version of a package, oldest first",
+                    "operationId": "listRegistryPackageVersions",
+                    "security": [
+                        {"ApiKeyAuth": []},
+                        {"ApiKeyHeader": []},
+                        {"BearerAuth": []}
+                    ],
+                    "parameters": [
+                        {
+                            "name": "id",
+                            "in": "path",
+                            "required": true,
+                            "schema": {
+                                "type": "string"
+                            }
+                        }
+                    ],
+                    "responses": {
+                        "200": {
+                            "description": "Version summaries"
+                        },
+                        "404": {
+                            "description": "Package does not exist"
+                        }
+                    }
+                }
+            },
+            "/api/v1/federation/identity": {
+                "get": {
+                    "tags": ["federation"],
+                    "summary": "Get federation identity",
+                    "description": "Returns the registry ID, name, jurisdictions and the Ed25519 public key peers pin",
+                    "operationId": "getFederationIdentity",
+                    "responses": {
+                        "200": {
+                            "description": "Registry identity"
+                        },
+                        "404": {
+                            "description": "Federation is not enabled"
+                        }
+                    }
+                }
+            },
+            "/api/v1/federation/search": {
+                "get": {
+                    "tags": ["federation"],
+                    "summary": "Search for a peer registry",
+                    "description": "Ranks public statutes against the query and returns them with a signature over the response",
+                    "operationId": "federationSearch",
+                    "security": [
+                        {"ApiKeyAuth": []},
+                        {"ApiKeyHeader": []},
+                        {"BearerAuth": []}
+                    ],
+                    "parameters": [
+                        {
+                            "name": "q",
+                            "in": "query",
+                            "schema": {
+                                "type": "string"
+                            }
+                        },
+                        {
+                            "name": "nonce",
+                            "in": "query",
+                            "required": true,
+                            "description": "Echoed in the signed response to prevent replays",
+                            "schema": {
+                                "type": "string"
+                            }
+                        },
+                        {
+                            "name": "limit",
+                            "in": "query",
+                            "schema": {
+                                "type": "integer",
+                                "default": 50,
+                                "maximum": 1000
+                            }
+                        },
+                        {
+                            "name": "jurisdiction",
+                            "in": "query",
+                            "description": "Comma-separated jurisdiction codes",
+                            "schema": {
+                                "type": "string"
+                            }
+                        }
+                    ],
+                    "responses": {
+                        "200": {
+                            "description": "Signed search response"
+                        },
+                        "404": {
+                            "description": "Federation is not enabled"
+                        }
+                    }
+                }
+            },
             "/api/v1/simulate": {
                 "post": {
                     "tags": ["simulation"],
@@ -868,6 +1142,10 @@ pub fn generate_spec() -> Value {
                             "type": "integer",
                             "description": "Number of preconditions",
                             "minimum": 0
+                        },
+                        "score": {
+                            "type": "number",
+                            "description": "Relevance to the free-text search query, when one was given"
                         }
                     }
                 },
@@ -1020,6 +1298,70 @@ pub fn generate_spec() -> Value {
                         }
                     }
                 },
+                "DecisionRequest": {
+                    "type": "object",
+                    "properties": {
+                        "statute_ids": {
+                            "type": "array",
+                            "items": {
+                                "type": "string"
+                            },
+                            "description": "IDs of statutes to evaluate (empty = all statutes)"
+                        },
+                        "facts": {
+                            "type": "object",
+                            "additionalProperties": true,
+                            "description": "Known facts about the entity; null values are treated as unknown"
+                        },
+                        "date": {
+                            "type": "string",
+                            "format": "date",
+                            "description": "Evaluation date; statutes are taken in the version in force on this date"
+                        },
+                        "entity_id": {
+                            "type": "string",
+                            "format": "uuid",
+                            "description": "Entity or case identifier"
+                        },
+                        "known_at": {
+                            "type": "string",
+                            "format": "date-time",
+                            "description": "Only use statute versions recorded by this moment"
+                        }
+                    }
+                },
+                "PushRequest": {
+                    "type": "object",
+                    "required": ["statute"],
+                    "properties": {
+                        "statute": {
+                            "$ref": "#/components/schemas/Statute"
+                        },
+                        "source": {
+                            "type": "string",
+                            "description": "DSL source the statute was parsed from"
+                        },
+                        "base_version": {
+                            "type": "integer",
+                            "description": "Registry version the push is based on (omit for a new package)"
+                        },
+                        "tags": {
+                            "type": "array",
+                            "items": {
+                                "type": "string"
+                            }
+                        },
+                        "visibility": {
+                            "type": "string",
+                            "enum": ["public", "private"]
+                        },
+                        "force": {
+                            "type": "boolean",
+                            "default": false,
+                            "description": "Publish even if the registry moved past base_version"
+                        }
+                    }
+                },
                 "SimulationRequest": {
                     "type": "object",
                     "required": ["statute_ids", "population_size", "entity_params"],
@@ -1089,6 +1431,41 @@ pub fn generate_spec() -> Value {
                             "type": "string",
                             "format": "date-time",
                             "description": "Completion timestamp (RFC3339)"
+                        },
+                        "output_totals": {
+                            "type": "array",
+                            "description": "Totals of computed effect outputs per statute",
+                            "items": {
+                                "$ref": "#/components/schemas/OutputTotalInfo"
+                            }
+                        }
+                    }
+                },
+                "OutputTotalInfo": {
+                    "type": "object",
+                    "required": ["statute_id", "output", "total", "count"],
+                    "properties": {
+                        "statute_id": {
+                            "type": "string",
+                            "description": "Statute that produced the output"
+                        },
+                        "output": {
+                            "type": "string",
+                            "description": "Name of the computed effect output"
+                        },
+                        "unit": {
+                            "type": "string",
+                            "nullable": true,
+                            "description": "Currency code or duration unit"
+                        },
+                        "total": {
+                            "type": "string",
+                            "pattern": "^-?[0-9]+(\\.[0-9]+)?$",
+                            "description": "Exact sum of computed values as a decimal string"
+                        },
+                        "count": {
+                            "type": "integer",
+                            "description": "Number of values summed"
                         }
                     }
                 },
@@ -0,0 +1,508 @@
+//! Registry wire protocol shared by the API server and `legalis registry`.
+//!
+//! Statutes are exchanged as packages: the statute, the DSL source it was
+//! parsed from, and the registry metadata needed to publish new versions
+//! safely. A push names the version it was based on; a push based on a stale
+//! version is rejected with `409 Conflict` unless it is forced.
+//!
+//! | Method | Path                                      | Purpose                              |
+//! |--------|-------------------------------------------|--------------------------------------|
+//! | `GET`  | `/api/v1/registry/whoami`                 | Checks credentials                   |
+//! | `GET`  | `/api/v1/registry/packages`               | Lists [`PackageSummary`]s            |
+//! | `GET`  | `/api/v1/registry/packages/{id}`          | Fetches a [`Package`] (`?version=N`) |
+//! | `GET`  | `/api/v1/registry/packages/{id}/versions` | Lists the summary of every version   |
+//! | `PUT`  | `/api/v1/registry/packages/{id}`          | Publishes a [`PushRequest`]          |
+//!
+//! Every response is wrapped in the usual `ApiResponse` envelope.
+
+use crate::auth::{AuthError, AuthUser, Permission, Role};
+use crate::store::StatuteStore;
+use chrono::{DateTime, Utc};
+use legalis_core::Statute;
+use legalis_registry::{RegistryError, StatuteEntry};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Entry metadata key holding the DSL source of a package.
+pub const SOURCE_KEY: &str = "registry.source";
+
+/// Entry metadata key holding the visibility of a package.
+pub const VISIBILITY_KEY: &str = "registry.visibility";
+
+/// Errors raised while publishing a package.
+#[derive(Debug, Error)]
+pub enum PublishError {
+    #[error("Statute ID mismatch: path names '{path}', package contains '{package}'")]
+    IdMismatch { path: String, package: String },
+
+    #[error(
+        "Conflict on '{statute_id}': pushed against version {base_version}, registry is at version {current_version}"
+    )]
+    Conflict {
+        statute_id: String,
+        base_version: u32,
+        current_version: u32,
+    },
+
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+
+    #[error(transparent)]
+    Storage(#[from] RegistryError),
+}
+
+/// Who can see a package.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    /// Visible to every authenticated reader
+    #[default]
+    Public,
+    /// Visible only to users allowed to publish
+    Private,
+}
+
+impl Visibility {
+    /// Returns the visibility as a lowercase identifier.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Private => "private",
+        }
+    }
+
+    /// Reads the visibility recorded on a registry entry.
+    pub fn of(entry: &StatuteEntry) -> Self {
+        match entry.metadata.get(VISIBILITY_KEY).map(String::as_str) {
+            Some("private") => Self::Private,
+            _ => Self::Public,
+        }
+    }
+}
+
+/// Returns the content hash of a statute.
+///
+/// The hash covers the statute's canonical JSON form, so two parses of the
+/// same source hash identically regardless of formatting.
+///
+/// # Example
+///
+/// ```
+/// use legalis_api::registry_protocol::content_hash;
+/// use legalis_core::{Effect, EffectType, Statute};
+///
+/// let statute = Statute::new("s-1", "Benefit", Effect::new(EffectType::Grant, "Grant"));
+/// let mut renamed = statute.clone();
+/// renamed.title = "Renamed".to_string();
+///
+/// assert_eq!(content_hash(&statute), content_hash(&statute.clone()));
+/// assert_ne!(content_hash(&statute), content_hash(&renamed));
+/// ```
+pub fn content_hash(statute: &Statute) -> String {
+    // `Value` objects are sorted maps, which makes the serialization canonical
+    let canonical = serde_json::to_value(statute)
+        .map(|value| value.to_string())
+        .unwrap_or_default();
+    hex::encode(Sha256::digest(canonical.as_bytes()))
+}
+
+/// Summary of a published package.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PackageSummary {
+    pub statute_id: String,
+    pub title: String,
+    /// Registry version number, incremented on every publish
+    pub version: u32,
+    pub content_hash: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub visibility: Visibility,
+    pub modified_at: DateTime<Utc>,
+}
+
+impl PackageSummary {
+    /// Summarizes a registry entry.
+    pub fn from_entry(entry: &StatuteEntry) -> Self {
+        Self {
+            statute_id: entry.statute.id.clone(),
+            title: entry.statute.title.clone(),
+            version: entry.version,
+            content_hash: content_hash(&entry.statute),
+            tags: entry.tags.clone(),
+            visibility: Visibility::of(entry),
+            modified_at: entry.modified_at,
+        }
+    }
+}
+
+/// A published package: a statute version with its source.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Package {
+    #[serde(flatten)]
+    pub summary: PackageSummary,
+    pub statute: Statute,
+    /// DSL source as pushed, when the package was published from a file
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+impl Package {
+    /// Builds a package from a registry entry.
+    pub fn from_entry(entry: &StatuteEntry) -> Self {
+        Self {
+            summary: PackageSummary::from_entry(entry),
+            statute: entry.statute.clone(),
+            source: entry.metadata.get(SOURCE_KEY).cloned(),
+        }
+    }
+}
+
+/// Request to publish a new version of a package.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PushRequest {
+    pub statute: Statute,
+    /// DSL source the statute was parsed from
+    #[serde(default)]
+    pub source: Option<String>,
+    /// Registry version this push is based on (`None` for a new package)
+    #[serde(default)]
+    pub base_version: Option<u32>,
+    /// Tags to set; existing tags are kept when empty
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Visibility to set; new packages default to public
+    #[serde(default)]
+    pub visibility: Option<Visibility>,
+    /// Publish even if the registry moved past `base_version`
+    #[serde(default)]
+    pub force: bool,
+}
+
+impl PushRequest {
+    /// Creates a request to publish a statute as a new package.
+    pub fn new(statute: Statute) -> Self {
+        Self {
+            statute,
+            source: None,
+            base_version: None,
+            tags: Vec::new(),
+            visibility: None,
+            force: false,
+        }
+    }
+
+    /// Attaches the DSL source.
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Sets the version the push is based on.
+    pub fn with_base_version(mut self, version: u32) -> Self {
+        self.base_version = Some(version);
+        self
+    }
+
+    /// Sets the tags.
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Sets the visibility.
+    pub fn with_visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    /// Overrides the concurrency check.
+    pub fn forced(mut self) -> Self {
+        self.force = true;
+        self
+    }
+}
+
+/// What a push did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PushStatus {
+    /// A new package was created as version 1
+    Created,
+    /// A new version was published
+    Updated,
+    /// The registry already held identical content
+    Unchanged,
+}
+
+/// Result of a push.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PushResponse {
+    pub status: PushStatus,
+    pub package: PackageSummary,
+}
+
+/// Identity behind a set of credentials.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WhoAmI {
+    pub user_id: Uuid,
+    pub username: String,
+    pub role: Role,
+    /// Whether the user may publish packages
+    pub can_publish: bool,
+}
+
+impl From<&AuthUser> for WhoAmI {
+    fn from(user: &AuthUser) -> Self {
+        Self {
+            user_id: user.id,
+            username: user.username.clone(),
+            role: user.role,
+            can_publish: user.has_permission(Permission::CreateStatutes),
+        }
+    }
+}
+
+/// Returns whether a user may see a package.
+pub fn can_view(user: &AuthUser, entry: &StatuteEntry) -> bool {
+    Visibility::of(entry) == Visibility::Public || user.has_permission(Permission::CreateStatutes)
+}
+
+/// Lists the packages visible to a user.
+pub async fn list_packages(
+    store: &StatuteStore,
+    user: &AuthUser,
+) -> Result<Vec<PackageSummary>, RegistryError> {
+    Ok(store
+        .entries()
+        .await?
+        .iter()
+        .filter(|entry| can_view(user, entry))
+        .map(PackageSummary::from_entry)
+        .collect())
+}
+
+/// Lists every published version of a package, oldest first.
+///
+/// Returns an empty list when the package does not exist or is not visible
+/// to the user.
+pub async fn list_versions(
+    store: &StatuteStore,
+    user: &AuthUser,
+    statute_id: &str,
+) -> Result<Vec<PackageSummary>, RegistryError> {
+    Ok(store
+        .history(statute_id)
+        .await?
+        .iter()
+        .filter(|entry| can_view(user, entry))
+        .map(PackageSummary::from_entry)
+        .collect())
+}
+
+/// Fetches the latest or a pinned version of a package.
+///
+/// Returns `None` when the package or version does not exist or is not
+/// visible to the user.
+pub async fn fetch_package(
+    store: &StatuteStore,
+    user: &AuthUser,
+    statute_id: &str,
+    version: Option<u32>,
+) -> Result<Option<Package>, RegistryError> {
+    let entry = match version {
+        Some(version) => store.get_version(statute_id, version).await?,
+        None => store.entry(statute_id).await?,
+    };
+    Ok(entry
+        .filter(|entry| can_view(user, entry))
+        .map(|entry| Package::from_entry(&entry)))
+}
+
+/// Publishes a package version.
+///
+/// Publishing requires [`Permission::CreateStatutes`]; forcing a push over a
+/// newer version additionally requires [`Permission::UpdateStatutes`]. The
+/// version check and the write happen under the store's write lock, so
+/// concurrent publishes and other writes cannot interleave with them.
+pub async fn publish(
+    store: &StatuteStore,
+    user: &AuthUser,
+    statute_id: &str,
+    request: PushRequest,
+) -> Result<PushResponse, PublishError> {
+    user.require_permission(Permission::CreateStatutes)?;
+    if request.statute.id != statute_id {
+        return Err(PublishError::IdMismatch {
+            path: statute_id.to_string(),
+            package: request.statute.id,
+        });
+    }
+
+    let mut writer = store.writer().await;
+    let current = writer.entry(statute_id).await?;
+    let current_version = current.as_ref().map_or(0, |entry| entry.version);
+    let base_version = request.base_version.unwrap_or(0);
+    if base_version != current_version {
+        if !request.force {
+            return Err(PublishError::Conflict {
+                statute_id: statute_id.to_string(),
+                base_version,
+                current_version,
+            });
+        }
+        user.require_permission(Permission::UpdateStatutes)?;
+    }
+
+    let Some(current) = current else {
+        let jurisdiction = request.statute.jurisdiction.clone().unwrap_or_default();
+        let mut entry = StatuteEntry::new(request.statute, jurisdiction);
+        entry.tags = request.tags;
+        entry.metadata.insert(
+            VISIBILITY_KEY.to_string(),
+            request.visibility.unwrap_or_default().as_str().to_string(),
+        );
+        if let Some(source) = request.source {
+            entry.metadata.insert(SOURCE_KEY.to_string(), source);
+        }
+        writer.store_entry(&entry).await?;
+        return Ok(PushResponse {
+            status: PushStatus::Created,
+            package: PackageSummary::from_entry(&entry),
+        });
+    };
+
+    let unchanged = content_hash(&current.statute) == content_hash(&request.statute)
+        && (request.tags.is_empty() || request.tags == current.tags)
+        && request
+            .visibility
+            .is_none_or(|v| v == Visibility::of(&current));
+    if unchanged {
+        return Ok(PushResponse {
+            status: PushStatus::Unchanged,
+            package: PackageSummary::from_entry(&current),
+        });
+    }
+
+    let mut entry = current.next_version(request.statute);
+    if let Some(jurisdiction) = &entry.statute.jurisdiction {
+        entry.jurisdiction = jurisdiction.clone();
+    }
+    if !request.tags.is_empty() {
+        entry.tags = request.tags;
+    }
+    if let Some(visibility) = request.visibility {
+        entry
+            .metadata
+            .insert(VISIBILITY_KEY.to_string(), visibility.as_str().to_string());
+    }
+    match request.source {
+        Some(source) => entry.metadata.insert(SOURCE_KEY.to_string(), source),
+        None => entry.metadata.remove(SOURCE_KEY),
+    };
+    writer.store_entry(&entry).await?;
+    Ok(PushResponse {
+        status: PushStatus::Updated,
+        package: PackageSummary::from_entry(&entry),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthMethod;
+    use legalis_core::{Effect, EffectType};
+
+    fn user(role: Role) -> AuthUser {
+        AuthUser::new(
+            Uuid::new_v4(),
+            "clerk".to_string(),
+            role,
+            AuthMethod::ApiKey,
+        )
+    }
+
+    fn statute(title: &str) -> Statute {
+        Statute::new("grant", title, Effect::new(EffectType::Grant, "Grant"))
+    }
+
+    #[tokio::test]
+    async fn test_publish_checks_base_version() {
+        let store = StatuteStore::in_memory();
+        let publisher = user(Role::ApiClient);
+
+        let created = publish(&store, &publisher, "grant", PushRequest::new(statute("v1")))
+            .await
+            .unwrap();
+        assert_eq!(created.status, PushStatus::Created);
+        assert_eq!(created.package.version, 1);
+
+        let request = PushRequest::new(statute("v2")).with_base_version(1);
+        let updated = publish(&store, &publisher, "grant", request).await.unwrap();
+        assert_eq!(updated.status, PushStatus::Updated);
+        assert_eq!(updated.package.version, 2);
+
+        // A second client still based on version 1 is rejected
+        let stale = PushRequest::new(statute("other")).with_base_version(1);
+        match publish(&store, &publisher, "grant", stale.clone()).await {
+            Err(PublishError::Conflict {
+                current_version, ..
+            }) => assert_eq!(current_version, 2),
+            other => panic!("expected conflict, got {:?}", other.map(|r| r.status)),
+        }
+
+        // Forcing past a newer version needs update rights
+        assert!(matches!(
+            publish(&store, &publisher, "grant", stale.clone().forced()).await,
+            Err(PublishError::Auth(AuthError::InsufficientPermissions))
+        ));
+        let forced = publish(&store, &user(Role::Admin), "grant", stale.forced())
+            .await
+            .unwrap();
+        assert_eq!(forced.package.version, 3);
+
+        let same = PushRequest::new(statute("other")).with_base_version(3);
+        let unchanged = publish(&store, &publisher, "grant", same).await.unwrap();
+        assert_eq!(unchanged.status, PushStatus::Unchanged);
+        assert_eq!(store.versions("grant").await.unwrap(), vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_private_packages_hidden_from_readers() {
+        let store = StatuteStore::in_memory();
+        let request = PushRequest::new(statute("secret"))
+            .with_source("STATUTE grant: \"secret\" {}")
+            .with_visibility(Visibility::Private);
+        publish(&store, &user(Role::ApiClient), "grant", request)
+            .await
+            .unwrap();
+
+        let reader = user(Role::Viewer);
+        assert!(list_packages(&store, &reader).await.unwrap().is_empty());
+        assert!(
+            fetch_package(&store, &reader, "grant", None)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let package = fetch_package(&store, &user(Role::Admin), "grant", Some(1))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(package.summary.visibility, Visibility::Private);
+        assert!(package.source.unwrap().contains("secret"));
+        assert!(matches!(
+            publish(
+                &store,
+                &reader,
+                "grant",
+                PushRequest::new(statute("x")).with_base_version(1)
+            )
+            .await,
+            Err(PublishError::Auth(_))
+        ));
+    }
+}
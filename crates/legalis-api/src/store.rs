@@ -0,0 +1,446 @@
+//! Statute persistence for the API server.
+//!
+//! REST, GraphQL and gRPC handlers all read and write statutes through a shared
+//! [`StatuteStore`], which sits on top of a `legalis-registry`
+//! [`StorageBackend`]. The in-memory backend is used by default; an embedded
+//! registry file is always available, and SQLite and PostgreSQL are available
+//! behind the `sqlite` and `postgres` features.
+//!
+//! Writes are serialized through a lock shared by every clone of the store, so
+//! a write that checks the current version (duplicate IDs, next version
+//! numbers, publish conflicts) cannot interleave with another write.
+
+use crate::config::{StorageConfig, StorageKind};
+use legalis_core::Statute;
+use legalis_registry::storage::{FileBackend, MemoryBackend, StorageBackend};
+use legalis_registry::{AsOf, RegistryError, RegistryResult, StatuteEntry};
+use std::sync::Arc;
+use tokio::sync::{Mutex, MutexGuard};
+
+/// Shared handle to the statute storage backend.
+///
+/// Cloning is cheap; all clones see the same data.
+///
+/// # Example
+///
+/// ```
+/// use legalis_api::store::StatuteStore;
+/// use legalis_core::{Effect, EffectType, Statute};
+///
+/// # tokio_test::block_on(async {
+/// let store = StatuteStore::in_memory();
+/// let statute = Statute::new("s-1", "Benefit", Effect::new(EffectType::Grant, "Grant"));
+/// store.insert(statute.clone()).await.unwrap();
+///
+/// let mut amended = statute;
+/// amended.title = "Amended benefit".to_string();
+/// let entry = store.update(amended).await.unwrap();
+///
+/// assert_eq!(entry.version, 2);
+/// assert_eq!(store.versions("s-1").await.unwrap(), vec![1, 2]);
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct StatuteStore {
+    backend: Arc<dyn StorageBackend>,
+    write_lock: Arc<Mutex<()>>,
+}
+
+impl StatuteStore {
+    /// Creates a store on top of the given backend.
+    pub fn new(backend: Arc<dyn StorageBackend>) -> Self {
+        Self {
+            backend,
+            write_lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    /// Creates a non-persistent in-memory store.
+    pub fn in_memory() -> Self {
+        Self::new(Arc::new(MemoryBackend::new()))
+    }
+
+    /// Connects to the backend selected in the configuration.
+    pub async fn connect(config: &StorageConfig) -> RegistryResult<Self> {
+        match config.kind {
+            StorageKind::Memory => Ok(Self::in_memory()),
+            StorageKind::File => {
+                let backend = FileBackend::open(config.database_url()?)?;
+                Ok(Self::new(Arc::new(backend)))
+            }
+            #[cfg(feature = "sqlite")]
+            StorageKind::Sqlite => {
+                let url = config.database_url()?;
+                let backend = legalis_registry::storage::SqliteBackend::new(url)
+                    .await
+                    .map_err(|e| RegistryError::InvalidOperation(e.to_string()))?;
+                Ok(Self::new(Arc::new(backend)))
+            }
+            #[cfg(feature = "postgres")]
+            StorageKind::Postgres => {
+                let url = config.database_url()?;
+                let backend = legalis_registry::storage::PostgresBackend::new(url)
+                    .await
+                    .map_err(|e| RegistryError::InvalidOperation(e.to_string()))?;
+                Ok(Self::new(Arc::new(backend)))
+            }
+            #[allow(unreachable_patterns)]
+            kind => Err(RegistryError::InvalidOperation(format!(
+                "Storage backend {:?} is not enabled in this build",
+                kind
+            ))),
+        }
+    }
+
+    /// Returns the underlying backend.
+    ///
+    /// Writing to the backend directly bypasses the store's write lock.
+    pub fn backend(&self) -> &Arc<dyn StorageBackend> {
+        &self.backend
+    }
+
+    /// Waits for exclusive write access to the store.
+    ///
+    /// Use this for multi-step writes, such as a read-check-write, that must
+    /// not interleave with other writes. The store's own write methods wait
+    /// for the same lock, so they must not be called while the writer is held.
+    pub async fn writer(&self) -> StoreWriter<'_> {
+        StoreWriter {
+            store: self,
+            _guard: self.write_lock.lock().await,
+        }
+    }
+
+    /// Lists the latest version of every statute.
+    pub async fn list(&self) -> RegistryResult<Vec<Statute>> {
+        Ok(self
+            .backend
+            .list()
+            .await?
+            .into_iter()
+            .map(|entry| entry.statute)
+            .collect())
+    }
+
+    /// Lists the latest registry entry of every statute.
+    pub async fn entries(&self) -> RegistryResult<Vec<StatuteEntry>> {
+        self.backend.list().await
+    }
+
+    /// Returns the latest version of a statute.
+    pub async fn get(&self, statute_id: &str) -> RegistryResult<Option<Statute>> {
+        Ok(self
+            .backend
+            .get(statute_id)
+            .await?
+            .map(|entry| entry.statute))
+    }
+
+    /// Returns the latest registry entry of a statute.
+    pub async fn entry(&self, statute_id: &str) -> RegistryResult<Option<StatuteEntry>> {
+        self.backend.get(statute_id).await
+    }
+
+    /// Returns a specific stored version of a statute.
+    pub async fn get_version(
+        &self,
+        statute_id: &str,
+        version: u32,
+    ) -> RegistryResult<Option<StatuteEntry>> {
+        self.backend.get_version(statute_id, version).await
+    }
+
+    /// Lists the stored version numbers of a statute.
+    pub async fn versions(&self, statute_id: &str) -> RegistryResult<Vec<u32>> {
+        self.backend.list_versions(statute_id).await
+    }
+
+    /// Returns every stored version of a statute, oldest first.
+    pub async fn history(&self, statute_id: &str) -> RegistryResult<Vec<StatuteEntry>> {
+        self.backend.history(statute_id).await
+    }
+
+    /// Returns the version of a statute in force at a point in time.
+    pub async fn get_as_of(
+        &self,
+        statute_id: &str,
+        as_of: &AsOf,
+    ) -> RegistryResult<Option<StatuteEntry>> {
+        self.backend.get_as_of(statute_id, as_of).await
+    }
+
+    /// Lists every statute as it was in force at a point in time.
+    pub async fn list_as_of(&self, as_of: &AsOf) -> RegistryResult<Vec<Statute>> {
+        Ok(self
+            .backend
+            .list_as_of(as_of)
+            .await?
+            .into_iter()
+            .map(|entry| entry.statute)
+            .collect())
+    }
+
+    /// Returns true if a statute with the given ID exists.
+    pub async fn contains(&self, statute_id: &str) -> RegistryResult<bool> {
+        Ok(self.backend.get(statute_id).await?.is_some())
+    }
+
+    /// Stores a new statute as version 1.
+    ///
+    /// Fails with [`RegistryError::DuplicateId`] if the ID is already taken.
+    pub async fn insert(&self, statute: Statute) -> RegistryResult<StatuteEntry> {
+        self.writer().await.insert(statute).await
+    }
+
+    /// Stores a registry entry as-is, keeping its version, status and tags.
+    pub async fn store_entry(&self, entry: &StatuteEntry) -> RegistryResult<()> {
+        self.writer().await.store_entry(entry).await
+    }
+
+    /// Stores new content for an existing statute as its next version.
+    ///
+    /// Status, tags and other registry metadata carry over from the previous version.
+    pub async fn update(&self, statute: Statute) -> RegistryResult<StatuteEntry> {
+        self.writer().await.update(statute).await
+    }
+
+    /// Deletes a statute and all its versions, returning whether it existed.
+    pub async fn delete(&self, statute_id: &str) -> RegistryResult<bool> {
+        self.writer().await.delete(statute_id).await
+    }
+
+    /// Counts stored statutes.
+    pub async fn count(&self) -> RegistryResult<usize> {
+        self.backend.count().await
+    }
+
+    /// Lists the latest version of statutes carrying the given tag.
+    pub async fn find_by_tag(&self, tag: &str) -> RegistryResult<Vec<StatuteEntry>> {
+        self.backend.find_by_tag(tag).await
+    }
+
+    /// Lists the latest version of statutes in the given jurisdiction.
+    pub async fn find_by_jurisdiction(
+        &self,
+        jurisdiction: &str,
+    ) -> RegistryResult<Vec<StatuteEntry>> {
+        self.backend.find_by_jurisdiction(jurisdiction).await
+    }
+}
+
+/// Exclusive write access to a [`StatuteStore`].
+///
+/// Obtained from [`StatuteStore::writer`]; other writers wait until it is dropped.
+pub struct StoreWriter<'a> {
+    store: &'a StatuteStore,
+    _guard: MutexGuard<'a, ()>,
+}
+
+impl StoreWriter<'_> {
+    /// Returns the latest registry entry of a statute.
+    pub async fn entry(&self, statute_id: &str) -> RegistryResult<Option<StatuteEntry>> {
+        self.store.backend.get(statute_id).await
+    }
+
+    /// Stores a new statute as version 1.
+    ///
+    /// Fails with [`RegistryError::DuplicateId`] if the ID is already taken.
+    pub async fn insert(&mut self, statute: Statute) -> RegistryResult<StatuteEntry> {
+        if self.entry(&statute.id).await?.is_some() {
+            return Err(RegistryError::DuplicateId(statute.id));
+        }
+        let jurisdiction = statute.jurisdiction.clone().unwrap_or_default();
+        let entry = StatuteEntry::new(statute, jurisdiction);
+        self.store.backend.store(&entry).await?;
+        Ok(entry)
+    }
+
+    /// Stores a registry entry as-is, keeping its version, status and tags.
+    pub async fn store_entry(&mut self, entry: &StatuteEntry) -> RegistryResult<()> {
+        self.store.backend.store(entry).await
+    }
+
+    /// Stores new content for an existing statute as its next version.
+    pub async fn update(&mut self, statute: Statute) -> RegistryResult<StatuteEntry> {
+        let current = self
+            .entry(&statute.id)
+            .await?
+            .ok_or_else(|| RegistryError::StatuteNotFound(statute.id.clone()))?;
+        let mut entry = current.next_version(statute);
+        if let Some(jurisdiction) = &entry.statute.jurisdiction {
+            entry.jurisdiction = jurisdiction.clone();
+        }
+        self.store.backend.store(&entry).await?;
+        Ok(entry)
+    }
+
+    /// Deletes a statute, returning whether it existed.
+    pub async fn delete(&mut self, statute_id: &str) -> RegistryResult<bool> {
+        if self.entry(statute_id).await?.is_none() {
+            return Ok(false);
+        }
+        self.store.backend.delete(statute_id).await?;
+        Ok(true)
+    }
+}
+
+impl Default for StatuteStore {
+    fn default() -> Self {
+        Self::in_memory()
+    }
+}
+
+impl std::fmt::Debug for StatuteStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StatuteStore").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use legalis_core::{Effect, EffectType};
+    use legalis_registry::StatuteStatus;
+
+    fn statute(id: &str) -> Statute {
+        Statute::new(id, "Test", Effect::new(EffectType::Grant, "Benefit")).with_jurisdiction("JP")
+    }
+
+    #[tokio::test]
+    async fn test_insert_rejects_duplicates() {
+        let store = StatuteStore::in_memory();
+        store.insert(statute("a")).await.unwrap();
+        assert!(matches!(
+            store.insert(statute("a")).await,
+            Err(RegistryError::DuplicateId(_))
+        ));
+        assert_eq!(store.count().await.unwrap(), 1);
+        assert_eq!(store.find_by_jurisdiction("JP").await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_update_preserves_registry_metadata() {
+        let store = StatuteStore::in_memory();
+        let entry = StatuteEntry::new(statute("a"), "JP")
+            .with_status(StatuteStatus::Active)
+            .with_tag("welfare");
+        store.store_entry(&entry).await.unwrap();
+
+        let mut amended = statute("a");
+        amended.title = "Amended".to_string();
+        let updated = store.update(amended).await.unwrap();
+        assert_eq!(updated.version, 2);
+        assert_eq!(updated.status, StatuteStatus::Active);
+        assert_eq!(updated.tags, vec!["welfare".to_string()]);
+        assert_ne!(updated.registry_id, entry.registry_id);
+
+        assert_eq!(store.get("a").await.unwrap().unwrap().title, "Amended");
+        let v1 = store.get_version("a", 1).await.unwrap().unwrap();
+        assert_eq!(v1.statute.title, "Test");
+        assert_eq!(store.find_by_tag("welfare").await.unwrap().len(), 1);
+
+        assert!(matches!(
+            store.update(statute("missing")).await,
+            Err(RegistryError::StatuteNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_list_as_of_follows_amendments() {
+        use chrono::NaiveDate;
+        use legalis_core::TemporalValidity;
+
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let store = StatuteStore::in_memory();
+        let original = statute("a")
+            .with_temporal_validity(TemporalValidity::new().with_effective_date(date(2010, 1, 1)));
+        let entry = StatuteEntry::new(original.clone(), "JP").with_status(StatuteStatus::Active);
+        store.store_entry(&entry).await.unwrap();
+
+        let mut amended = original;
+        amended.title = "Amended".to_string();
+        amended.temporal_validity.effective_date = Some(date(2020, 1, 1));
+        store.update(amended).await.unwrap();
+
+        let in_2019 = store
+            .list_as_of(&AsOf::new(date(2019, 4, 1)))
+            .await
+            .unwrap();
+        assert_eq!(in_2019[0].title, "Test");
+        let in_2020 = store
+            .list_as_of(&AsOf::new(date(2020, 1, 1)))
+            .await
+            .unwrap();
+        assert_eq!(in_2020[0].title, "Amended");
+
+        let known_then = AsOf::new(date(2020, 1, 1)).with_known_at(entry.modified_at);
+        let known = store.get_as_of("a", &known_then).await.unwrap().unwrap();
+        assert_eq!(known.version, 1);
+        assert_eq!(store.history("a").await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_connect_file_storage() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = StorageConfig {
+            kind: StorageKind::File,
+            url: Some(dir.path().join("statutes.lgr").display().to_string()),
+        };
+        let store = StatuteStore::connect(&config).await.unwrap();
+        store.insert(statute("a")).await.unwrap();
+        drop(store);
+
+        let store = StatuteStore::connect(&config).await.unwrap();
+        assert!(store.contains("a").await.unwrap());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_inserts_are_serialized() {
+        let store = StatuteStore::in_memory();
+        let attempts: Vec<_> = (0..16)
+            .map(|_| {
+                let store = store.clone();
+                tokio::spawn(async move { store.insert(statute("a")).await })
+            })
+            .collect();
+        let mut created = 0;
+        for attempt in attempts {
+            match attempt.await.unwrap() {
+                Ok(_) => created += 1,
+                Err(RegistryError::DuplicateId(_)) => {}
+                Err(other) => panic!("unexpected error: {}", other),
+            }
+        }
+        assert_eq!(created, 1);
+        assert_eq!(store.versions("a").await.unwrap(), vec![1]);
+
+        let updates: Vec<_> = (0..8)
+            .map(|i| {
+                let store = store.clone();
+                tokio::spawn(async move {
+                    let mut amended = statute("a");
+                    amended.title = format!("Amendment {}", i);
+                    store.update(amended).await
+                })
+            })
+            .collect();
+        for update in updates {
+            update.await.unwrap().unwrap();
+        }
+        assert_eq!(
+            store.versions("a").await.unwrap(),
+            (1..=9).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_clones_share_storage() {
+        let store = StatuteStore::in_memory();
+        let other = store.clone();
+        store.insert(statute("a")).await.unwrap();
+        assert!(other.contains("a").await.unwrap());
+        assert!(other.delete("a").await.unwrap());
+        assert!(!other.delete("a").await.unwrap());
+        assert!(store.list().await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,262 @@
+//! Multi-process federation tests.
+//!
+//! Every regional registry runs as its own `legalis-api-server` process with
+//! its own signing key, just like the regional offices do. The test seeds
+//! each process over HTTP and fans queries out with `FederatedQueryEngine`,
+//! alongside peers that are offline, stalled or impersonated.
+
+use legalis_core::{Effect, EffectType, Statute};
+use legalis_registry::federation::{
+    FederatedQuery, FederatedQueryEngine, FederatedSearchAggregator, FederationError,
+    FederationKeyPair, HttpPeerTransport, RankingStrategy, RegistryDiscovery, RegistryMetadata,
+};
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+const API_KEY: &str = "lgl_federation_test_key";
+
+/// A `legalis-api-server` process acting as one regional registry.
+struct Node {
+    metadata: RegistryMetadata,
+    child: Child,
+}
+
+impl Node {
+    /// Starts a server with a fresh signing key and waits until it is healthy.
+    async fn spawn(dir: &Path, name: &str, jurisdiction: &str) -> Self {
+        // The key is created here so its public half is pinned out of band.
+        let key_path = dir.join(format!("{}.key", name));
+        let pkcs8 = FederationKeyPair::generate_pkcs8().unwrap();
+        std::fs::write(&key_path, &pkcs8).unwrap();
+        let public_key = FederationKeyPair::from_pkcs8(&pkcs8).unwrap().public_key();
+
+        let port = free_port();
+        let registry_id = Uuid::new_v4();
+        let child = Command::new(env!("CARGO_BIN_EXE_legalis-api-server"))
+            .env("LEGALIS_API_HOST", "127.0.0.1")
+            .env("LEGALIS_API_PORT", port.to_string())
+            .env("LEGALIS_API_LOG_LEVEL", "warn")
+            .env("LEGALIS_API_STORAGE", "memory")
+            .env("LEGALIS_API_FEDERATION_KEY", &key_path)
+            .env("LEGALIS_API_FEDERATION_ID", registry_id.to_string())
+            .env("LEGALIS_API_FEDERATION_NAME", name)
+            .env("LEGALIS_API_FEDERATION_JURISDICTIONS", jurisdiction)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("failed to start legalis-api-server");
+
+        let metadata =
+            RegistryMetadata::new(name.to_string(), format!("http://127.0.0.1:{}", port))
+                .with_registry_id(registry_id)
+                .with_jurisdiction(jurisdiction)
+                .with_trust_level(80)
+                .with_public_key(public_key);
+
+        let mut node = Self { metadata, child };
+        node.wait_until_healthy().await;
+        node
+    }
+
+    async fn wait_until_healthy(&mut self) {
+        let client = reqwest::Client::new();
+        let deadline = Instant::now() + Duration::from_secs(30);
+        loop {
+            if let Some(status) = self.child.try_wait().unwrap() {
+                panic!("{} exited early: {}", self.metadata.name, status);
+            }
+            let health = client
+                .get(format!("{}/health", self.metadata.endpoint))
+                .send()
+                .await;
+            if health.is_ok_and(|r| r.status().is_success()) {
+                return;
+            }
+            assert!(
+                Instant::now() < deadline,
+                "{} did not become healthy",
+                self.metadata.name
+            );
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    }
+
+    async fn add_statute(&self, id: &str, title: &str) {
+        let statute = Statute::new(id, title, Effect::new(EffectType::Grant, "Applies"))
+            .with_jurisdiction(self.metadata.jurisdictions[0].clone());
+        let response = reqwest::Client::new()
+            .post(format!("{}/api/v1/statutes", self.metadata.endpoint))
+            .header("Authorization", format!("ApiKey {}", API_KEY))
+            .json(&serde_json::json!({ "statute": statute }))
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success(), "seeding {} failed", id);
+    }
+}
+
+impl Drop for Node {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+fn free_port() -> u16 {
+    std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+/// Accepts connections and never answers them.
+async fn stalled_peer() -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let mut open = Vec::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            open.push(socket);
+        }
+    });
+    endpoint
+}
+
+fn transport_for(peers: &[&RegistryMetadata]) -> HttpPeerTransport {
+    peers
+        .iter()
+        .fold(HttpPeerTransport::new(), |transport, peer| {
+            transport.with_api_key(peer.registry_id, API_KEY)
+        })
+}
+
+#[tokio::test]
+async fn test_federated_search_across_processes() {
+    let dir = tempfile::tempdir().unwrap();
+    let tokyo = Node::spawn(dir.path(), "tokyo", "JP").await;
+    let london = Node::spawn(dir.path(), "london", "UK").await;
+    let new_york = Node::spawn(dir.path(), "new-york", "US").await;
+
+    tokyo.add_statute("jp-income-tax", "Income Tax Act").await;
+    tokyo
+        .add_statute("data-protection", "Data Protection Act")
+        .await;
+    london
+        .add_statute("uk-income-tax", "Income Tax Act 2007")
+        .await;
+    london
+        .add_statute("data-protection", "Data Protection Act")
+        .await;
+    new_york
+        .add_statute("us-tax-code", "Internal Revenue Code")
+        .await;
+    new_york.add_statute("us-privacy", "Privacy Act").await;
+
+    // The identity endpoint publishes the key pinned above.
+    let identity: serde_json::Value = reqwest::get(format!(
+        "{}/api/v1/federation/identity",
+        tokyo.metadata.endpoint
+    ))
+    .await
+    .unwrap()
+    .json()
+    .await
+    .unwrap();
+    assert_eq!(
+        identity["data"]["public_key"].as_str(),
+        tokyo.metadata.public_key.as_deref()
+    );
+
+    // Peers that cannot answer properly.
+    let offline = RegistryMetadata::new(
+        "offline".to_string(),
+        format!("http://127.0.0.1:{}", free_port()),
+    );
+    let stalled = RegistryMetadata::new("stalled".to_string(), stalled_peer().await);
+    // Answers from London's server while claiming Tokyo's pinned key.
+    let impostor = RegistryMetadata::new("impostor".to_string(), london.metadata.endpoint.clone())
+        .with_public_key(tokyo.metadata.public_key.clone().unwrap());
+
+    let peers = [
+        &tokyo.metadata,
+        &london.metadata,
+        &new_york.metadata,
+        &offline,
+        &stalled,
+        &impostor,
+    ];
+    let discovery = Arc::new(RegistryDiscovery::new());
+    for peer in peers {
+        discovery.register(peer.clone());
+    }
+    let engine = FederatedQueryEngine::new(Arc::clone(&discovery))
+        .with_transport(Arc::new(transport_for(&peers)));
+
+    // Fan-out keeps the answers of healthy peers and reports the rest.
+    let result = engine
+        .execute(FederatedQuery::new("tax".to_string()).with_timeout(2))
+        .await;
+    assert_eq!(result.registries_queried, 6);
+    assert_eq!(result.successful_queries, 3);
+    assert!(result.is_partial());
+    assert!(result.total_execution_time < Duration::from_secs(10));
+
+    let errors: Vec<(String, String)> = result
+        .failures()
+        .map(|r| (r.registry_name.clone(), r.error.clone().unwrap()))
+        .collect();
+    assert!(errors.contains(&(
+        "stalled".to_string(),
+        FederationError::Timeout(2).to_string()
+    )));
+    assert!(errors.contains(&(
+        "impostor".to_string(),
+        FederationError::InvalidSignature.to_string()
+    )));
+    assert!(
+        errors
+            .iter()
+            .any(|(name, error)| name == "offline" && error.starts_with("Request failed"))
+    );
+
+    let merged = FederatedSearchAggregator::new(RankingStrategy::Combined).merge(&result);
+    let mut ids: Vec<_> = merged.iter().map(|r| r.statute_id.as_str()).collect();
+    ids.sort();
+    assert_eq!(ids, vec!["jp-income-tax", "uk-income-tax", "us-tax-code"]);
+    assert!(merged.iter().all(|r| r.verified));
+
+    // A statute held by two offices is merged into one result.
+    let healthy = vec![
+        tokyo.metadata.registry_id,
+        london.metadata.registry_id,
+        new_york.metadata.registry_id,
+    ];
+    let result = engine
+        .execute(
+            FederatedQuery::new("protection".to_string()).with_target_registries(healthy.clone()),
+        )
+        .await;
+    assert_eq!(result.total_statutes, 2);
+    let merged = FederatedSearchAggregator::new(RankingStrategy::Combined).merge(&result);
+    assert_eq!(merged.len(), 1);
+    assert_eq!(merged[0].statute_id, "data-protection");
+    assert_eq!(merged[0].also_found_in.len(), 1);
+
+    // Jurisdiction filters skip registries that do not cover them.
+    let result = engine
+        .execute(
+            FederatedQuery::new("tax".to_string())
+                .with_target_registries(healthy)
+                .with_jurisdictions(vec!["UK".to_string()]),
+        )
+        .await;
+    assert_eq!(result.registries_queried, 1);
+    assert_eq!(
+        result.registry_results[0].statute_ids,
+        vec!["uk-income-tax"]
+    );
+}
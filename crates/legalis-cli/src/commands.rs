@@ -3,7 +3,7 @@
 use crate::{
     BenchmarkType, DiffFormat, ExplainDetail, ExportFormat, FormatStyle, GraphFormat, GraphType,
     ImportOutputFormat, LegalDslFormat, OutputFormat, PortFormat, RdfOutputFormat, StatuteTemplate,
-    TraceFormat, VizFormat, WatchCommand,
+    TestReportFormat, TraceFormat, VizFormat, WatchCommand,
 };
 use anyhow::{Context, Result};
 use colored::Colorize;
@@ -16,6 +16,7 @@ use legalis_porting::{CompatibilityReport, PortedStatute, PortingEngine, Porting
 use legalis_verifier::StatuteVerifier;
 use legalis_viz::DecisionTree;
 use std::fs;
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 
 /// Handles the parse command.
@@ -1500,86 +1501,125 @@ pub async fn handle_watch(inputs: &[String], command: &WatchCommand) -> Result<(
 }
 
 /// Handles the test command.
-pub fn handle_test(inputs: &[String], tests_file: &str, verbose: bool) -> Result<()> {
-    let statutes = parse_statutes(inputs)?;
-
-    let test_content = fs::read_to_string(tests_file)
-        .with_context(|| format!("Failed to read test file: {}", tests_file))?;
-
-    println!("Running tests from: {}", tests_file);
-    println!("Testing {} statute(s)", statutes.len());
-
-    // Parse test cases (simple YAML format for now)
-    let test_cases: Vec<TestCase> = serde_yaml::from_str(&test_content)
-        .map_err(|e| anyhow::anyhow!("Failed to parse test file: {}", e))?;
-
-    let mut passed = 0;
-    let mut failed = 0;
+pub fn handle_test(
+    inputs: &[String],
+    tests_file: &str,
+    verbose: bool,
+    format: &TestReportFormat,
+    output: Option<&str>,
+    coverage: bool,
+) -> Result<()> {
+    use crate::test_runner::{TestRunner, TestSuite};
 
-    for (idx, test_case) in test_cases.iter().enumerate() {
-        if verbose {
-            println!("\nTest {}: {}", idx + 1, test_case.description);
+    let statutes = parse_statutes(inputs)?;
+    let suite = TestSuite::load(Path::new(tests_file))?;
+    let report = TestRunner::new(&statutes).run(&suite);
+
+    // Reports written to a file or piped elsewhere stay free of ANSI escapes
+    let color = output.is_none() && std::io::stdout().is_terminal();
+    let paint = |text: &str, shade: colored::Color| {
+        if color {
+            text.color(shade).to_string()
+        } else {
+            text.to_string()
         }
+    };
 
-        // Find the statute to test
-        let statute = statutes
-            .iter()
-            .find(|s| test_case.statute_id.as_ref().is_none_or(|id| &s.id == id))
-            .ok_or_else(|| anyhow::anyhow!("Statute not found for test case {}", idx + 1))?;
-
-        // Check if conditions match
-        let result = evaluate_test_case(statute, test_case);
-
-        if result {
-            passed += 1;
-            if verbose {
-                println!("  ✓ Passed");
-            } else {
-                print!(".");
+    let rendered = match format {
+        TestReportFormat::Junit => report.to_junit(tests_file),
+        TestReportFormat::Tap => report.to_tap(),
+        TestReportFormat::Json => serde_json::to_string_pretty(&report)?,
+        TestReportFormat::Text => {
+            let mut out = format!(
+                "Running tests from: {}\nTesting {} statute(s)\n",
+                tests_file,
+                statutes.len()
+            );
+            for result in &report.results {
+                let mark = if result.passed() {
+                    paint("✓", colored::Color::Green)
+                } else {
+                    paint("✗", colored::Color::Red)
+                };
+                if verbose || !result.passed() {
+                    out.push_str(&format!(
+                        "\n{} {} [{}]\n",
+                        mark, result.name, result.statute_id
+                    ));
+                    if verbose {
+                        let date = result
+                            .date
+                            .map_or_else(|| "undated".to_string(), |date| date.to_string());
+                        out.push_str(&format!(
+                            "  {} on {}: {}\n",
+                            result.outcome, date, result.reason
+                        ));
+                    }
+                    for line in result.diff().lines() {
+                        let line = if line.starts_with('-') {
+                            paint(line, colored::Color::Red)
+                        } else if line.starts_with('+') {
+                            paint(line, colored::Color::Green)
+                        } else {
+                            paint(line, colored::Color::Yellow)
+                        };
+                        out.push_str(&format!("  {}\n", line));
+                    }
+                }
             }
-        } else {
-            failed += 1;
-            if verbose {
-                println!("  ✗ Failed");
+            out.push_str(&format!(
+                "\nTest Results: {} passed, {} failed\n",
+                report.passed(),
+                report.failed()
+            ));
+            out
+        }
+    };
+
+    let mut rendered = rendered;
+    if coverage && matches!(format, TestReportFormat::Text) {
+        let report_coverage = &report.coverage;
+        rendered.push_str(&format!(
+            "\nCondition coverage: {}/{} ({:.1}%)\n",
+            report_coverage.exercised(),
+            report_coverage.conditions.len(),
+            report_coverage.percentage()
+        ));
+        for condition in &report_coverage.conditions {
+            let status = if condition.fully_covered() {
+                paint("both", colored::Color::Green)
+            } else if condition.exercised() {
+                paint("partial", colored::Color::Yellow)
             } else {
-                print!("F");
-            }
+                paint("never", colored::Color::Red)
+            };
+            rendered.push_str(&format!(
+                "  [{}] {}: {} (true {}, false {}, error {})\n",
+                status,
+                condition.statute_id,
+                condition.condition,
+                condition.true_count,
+                condition.false_count,
+                condition.error_count
+            ));
         }
     }
 
-    if !verbose {
-        println!();
+    if let Some(out_path) = output {
+        fs::write(out_path, &rendered)
+            .with_context(|| format!("Failed to write test report: {}", out_path))?;
+        println!("Test report written to: {}", out_path);
+    } else {
+        print!("{}", rendered);
     }
 
-    println!("\nTest Results: {} passed, {} failed", passed, failed);
-
-    if failed > 0 {
+    if report.failed() > 0 {
         std::process::exit(1);
     }
 
     Ok(())
 }
 
-/// Test case structure.
-#[derive(Debug, serde::Deserialize)]
-struct TestCase {
-    pub description: String,
-    pub statute_id: Option<String>,
-    #[allow(dead_code)]
-    pub expected_effect: Option<String>,
-    #[allow(dead_code)]
-    pub age: Option<u32>,
-    #[allow(dead_code)]
-    pub income: Option<u64>,
-}
-
-/// Evaluate a test case against a statute.
-fn evaluate_test_case(statute: &Statute, _test_case: &TestCase) -> bool {
-    // Simple evaluation - just check if statute exists for now
-    // In a real implementation, this would evaluate conditions
-    !statute.preconditions.is_empty() || !statute.effect.description.is_empty()
-}
-
 /// Handles the new command.
 pub fn handle_new(name: &str, template: &StatuteTemplate, output: Option<&str>) -> Result<()> {
     let statute_content = generate_statute_template(name, template);
@@ -2366,13 +2406,60 @@ pub fn handle_validate(
 }
 
 /// Handles the install command.
-pub fn handle_install(
-    statute_id: &str,
-    _registry_path: &str,
+///
+/// Without a statute ID, resolves `legalis.toml` against the registry, keeping
+/// the versions pinned in `legalis.lock`, and installs every resolved statute.
+pub async fn handle_install(
+    statute_id: Option<&str>,
+    registry: Option<&str>,
     output: &str,
     force: bool,
+    locked: bool,
 ) -> Result<()> {
-    use legalis_registry::StatuteRegistry;
+    use crate::package::{LOCKFILE, Lockfile, MANIFEST_FILE, Manifest, Unlock, integrity, resolve};
+
+    let lock = Lockfile::load(Path::new(LOCKFILE))?;
+
+    let Some(statute_id) = statute_id else {
+        println!(
+            "{} dependencies from {}...",
+            "Installing".bold(),
+            MANIFEST_FILE.cyan()
+        );
+        let manifest = Manifest::load(Path::new(MANIFEST_FILE))?;
+        let client = package_registry_client(registry, Some(&manifest))?;
+        let resolution = resolve(&client, &manifest, lock.as_ref(), &Unlock::None).await?;
+
+        if lock.as_ref() != Some(&resolution.lock) {
+            if locked {
+                anyhow::bail!("{} needs to be updated but --locked was passed", LOCKFILE);
+            }
+            resolution.lock.save(Path::new(LOCKFILE))?;
+            println!("  Updated {}", LOCKFILE);
+        }
+
+        fs::create_dir_all(output)?;
+        let (mut installed, mut unchanged) = (0, 0);
+        for (id, package) in &resolution.packages {
+            let output_path = Path::new(output).join(format!("{}.legal", id));
+            let current = fs::read_to_string(&output_path)
+                .ok()
+                .and_then(|content| LegalDslParser::new().parse_statute(&content).ok());
+            if !force && current.is_some_and(|s| integrity(&s) == integrity(&package.statute)) {
+                unchanged += 1;
+                continue;
+            }
+            crate::registry_client::write_package(package, &output_path)?;
+            println!("  {} {} v{}", "+".green(), id, package.summary.version);
+            installed += 1;
+        }
+
+        println!("{}", "✓ Installation successful".green().bold());
+        println!("  Installed: {}", installed);
+        println!("  Already up to date: {}", unchanged);
+        println!("  Directory: {}", output);
+        return Ok(());
+    };
 
     println!(
         "{} {} from registry...",
@@ -2380,12 +2467,30 @@ pub fn handle_install(
         statute_id.cyan()
     );
 
-    let mut registry = StatuteRegistry::new();
-
-    // Look up statute in registry
-    let entry = registry
-        .get(statute_id)
-        .ok_or_else(|| anyhow::anyhow!("Statute '{}' not found in registry", statute_id))?;
+    let manifest = Manifest::load(Path::new(MANIFEST_FILE)).ok();
+    let client = package_registry_client(registry, manifest.as_ref())?;
+    let pinned = lock.as_ref().and_then(|lock| lock.get(statute_id));
+    let package = client
+        .fetch(statute_id, pinned.map(|p| p.version))
+        .await
+        .map_err(|e| match e {
+            crate::registry_client::RegistryClientError::NotFound(_) => {
+                anyhow::anyhow!("Statute '{}' not found in registry", statute_id)
+            }
+            e => e.into(),
+        })?;
+    if let Some(pinned) = pinned
+        && pinned.integrity != integrity(&package.statute)
+    {
+        anyhow::bail!(
+            "Integrity check failed for {} v{}: {} has {}, registry served {}",
+            statute_id,
+            pinned.version,
+            LOCKFILE,
+            pinned.integrity,
+            integrity(&package.statute)
+        );
+    }
 
     let output_path = Path::new(output).join(format!("{}.legal", statute_id));
 
@@ -2399,18 +2504,16 @@ pub fn handle_install(
 
     // Create output directory if it doesn't exist
     fs::create_dir_all(output)?;
-
-    // Generate DSL format
-    let dsl_content = statute_to_dsl(&entry.statute);
-
-    // Write to file
-    fs::write(&output_path, dsl_content)?;
+    crate::registry_client::write_package(&package, &output_path)?;
 
     println!("{}", "✓ Installation successful".green().bold());
     println!("  Installed to: {}", output_path.display());
-    println!("  Version: {}", entry.statute.version);
-    if !entry.tags.is_empty() {
-        println!("  Tags: {}", entry.tags.join(", "));
+    println!("  Version: {}", package.summary.version);
+    if pinned.is_some() {
+        println!("  Pinned by: {}", LOCKFILE);
+    }
+    if !package.summary.tags.is_empty() {
+        println!("  Tags: {}", package.summary.tags.join(", "));
     }
 
     Ok(())
@@ -2517,93 +2620,133 @@ pub fn handle_list(directory: &str, verbose: bool) -> Result<()> {
 }
 
 /// Handles the add command.
-pub fn handle_add(statute_id: &str, _registry_path: &str, config_path: &str) -> Result<()> {
-    use legalis_registry::StatuteRegistry;
+///
+/// Adds the dependency to the manifest and re-resolves the lockfile next to it.
+pub async fn handle_add(
+    statute_id: &str,
+    registry: Option<&str>,
+    version: Option<&str>,
+    config_path: &str,
+) -> Result<()> {
+    use crate::package::{LOCKFILE, Lockfile, Manifest, Unlock, VersionReq, resolve};
 
     println!("{} {} as dependency...", "Adding".bold(), statute_id.cyan());
 
-    let mut registry = StatuteRegistry::new();
-
-    // Verify statute exists in registry
-    let entry = registry
-        .get(statute_id)
-        .ok_or_else(|| anyhow::anyhow!("Statute '{}' not found in registry", statute_id))?;
-
-    // Read config file
-    let config_content = if Path::new(config_path).exists() {
-        fs::read_to_string(config_path)?
+    let manifest_path = Path::new(config_path);
+    let manifest = if manifest_path.exists() {
+        Some(Manifest::load(manifest_path)?)
     } else {
-        String::from("version: \"0.2.0\"\ndependencies: []\n")
+        None
     };
+    let client = package_registry_client(registry, manifest.as_ref())?;
 
-    // Parse as YAML
-    let mut config: serde_yaml::Value = serde_yaml::from_str(&config_content)?;
-
-    // Add dependency
-    if let Some(deps) = config.get_mut("dependencies") {
-        if let Some(deps_array) = deps.as_sequence_mut() {
-            let dep = serde_yaml::Value::Mapping({
-                let mut map = serde_yaml::Mapping::new();
-                map.insert(
-                    serde_yaml::Value::String("id".to_string()),
-                    serde_yaml::Value::String(statute_id.to_string()),
-                );
-                map.insert(
-                    serde_yaml::Value::String("version".to_string()),
-                    serde_yaml::Value::Number(entry.statute.version.into()),
-                );
-                map
-            });
-            deps_array.push(dep);
-        }
-    } else {
-        let deps_array = vec![serde_yaml::Value::Mapping({
-            let mut map = serde_yaml::Mapping::new();
-            map.insert(
-                serde_yaml::Value::String("id".to_string()),
-                serde_yaml::Value::String(statute_id.to_string()),
-            );
-            map.insert(
-                serde_yaml::Value::String("version".to_string()),
-                serde_yaml::Value::Number(entry.statute.version.into()),
-            );
-            map
-        })];
-        config
-            .as_mapping_mut()
-            .expect("Config should be a mapping")
-            .insert("dependencies".into(), deps_array.into());
+    // Verify statute exists in registry
+    let versions: Vec<u32> = client
+        .versions(statute_id)
+        .await
+        .map_err(|e| match e {
+            crate::registry_client::RegistryClientError::NotFound(_) => {
+                anyhow::anyhow!("Statute '{}' not found in registry", statute_id)
+            }
+            e => e.into(),
+        })?
+        .iter()
+        .map(|summary| summary.version)
+        .collect();
+    let latest = versions.iter().copied().max().unwrap_or(1);
+    let req = match version {
+        Some(version) => version.parse::<VersionReq>()?,
+        None => VersionReq::caret(latest),
+    };
+    if req.best_match(versions.iter().copied()).is_none() {
+        anyhow::bail!(
+            "No version of '{}' satisfies {} (available: {:?})",
+            statute_id,
+            req,
+            versions
+        );
     }
 
-    // Write back to file
-    let updated_config = serde_yaml::to_string(&config)?;
-    fs::write(config_path, updated_config)?;
+    let manifest = Manifest::add_dependency(manifest_path, statute_id, &req)?;
+    let lock_path = manifest_path.with_file_name(LOCKFILE);
+    let lock = Lockfile::load(&lock_path)?;
+    let resolution = resolve(&client, &manifest, lock.as_ref(), &Unlock::None).await?;
+    resolution.lock.save(&lock_path)?;
+    let resolved = resolution
+        .lock
+        .get(statute_id)
+        .map(|p| p.version)
+        .unwrap_or(latest);
 
     println!("{}", "✓ Dependency added successfully".green().bold());
     println!("  Statute: {}", statute_id.cyan());
-    println!("  Version: {}", entry.statute.version);
-    println!("  Config updated: {}", config_path);
+    println!("  Requirement: {}", req);
+    println!("  Locked version: {}", resolved);
+    println!("  Manifest updated: {}", config_path);
+    println!("  Lockfile updated: {}", lock_path.display());
 
     Ok(())
 }
 
 /// Handles the update command.
-pub fn handle_update(statute_id: Option<&str>, _registry_path: &str, dry_run: bool) -> Result<()> {
+///
+/// Re-resolves locked statutes to the newest versions the manifest allows.
+pub async fn handle_update(
+    statute_id: Option<&str>,
+    registry: Option<&str>,
+    dry_run: bool,
+) -> Result<()> {
+    use crate::package::{LOCKFILE, Lockfile, MANIFEST_FILE, Manifest, Unlock, resolve};
+
     if let Some(id) = statute_id {
         println!("{} {}...", "Checking for updates for".bold(), id.cyan());
     } else {
         println!("{}", "Checking for updates for all statutes...".bold());
     }
 
+    let manifest = Manifest::load(Path::new(MANIFEST_FILE))?;
+    let client = package_registry_client(registry, Some(&manifest))?;
+    let lock = Lockfile::load(Path::new(LOCKFILE))?.unwrap_or_default();
+    if let Some(id) = statute_id
+        && lock.get(id).is_none()
+        && !manifest.dependencies.contains_key(id)
+    {
+        anyhow::bail!("'{}' is not a dependency of this package", id);
+    }
+
+    let unlock = match statute_id {
+        Some(id) => Unlock::Only(id.to_string()),
+        None => Unlock::All,
+    };
+    let resolution = resolve(&client, &manifest, Some(&lock), &unlock).await?;
+    let changes = resolution.lock.changes_from(&lock);
+
+    if changes.is_empty() {
+        println!("{}", "✓ All statutes are up to date".green().bold());
+        return Ok(());
+    }
+
+    for (id, before, after) in &changes {
+        match (before, after) {
+            (Some(before), Some(after)) => {
+                println!("  {} {} v{} -> v{}", "~".yellow(), id, before, after)
+            }
+            (None, Some(after)) => println!("  {} {} v{}", "+".green(), id, after),
+            (Some(before), None) => println!("  {} {} v{}", "-".red(), id, before),
+            (None, None) => {}
+        }
+    }
+
     if dry_run {
-        println!("{}", "[DRY RUN] Would check for and install updates".cyan());
-        println!("  No updates available (registry integration pending)");
+        println!("{}", "[DRY RUN] Would update legalis.lock".cyan());
         return Ok(());
     }
 
+    resolution.lock.save(Path::new(LOCKFILE))?;
+    println!("{} {} updated", "✓".green().bold(), LOCKFILE);
     println!(
         "{}",
-        "No updates available (registry integration pending)".yellow()
+        "Run 'legalis install' to install the updated statutes".cyan()
     );
 
     Ok(())
@@ -2816,52 +2959,53 @@ pub fn handle_search(
 }
 
 /// Handles the outdated command.
-pub fn handle_outdated(directory: &str, _registry_path: &str, show_all: bool) -> Result<()> {
+///
+/// Compares the versions pinned in `legalis.lock` with the registry.
+pub async fn handle_outdated(registry: Option<&str>, show_all: bool) -> Result<()> {
+    use crate::package::{LOCKFILE, Lockfile, MANIFEST_FILE, Manifest, VersionReq};
     use comfy_table::{Cell, Color, Table, modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL};
 
     println!(
         "{} {}",
         "Checking for outdated statutes in:".bold(),
-        directory.cyan()
+        LOCKFILE.cyan()
     );
     println!();
 
-    let dir_path = Path::new(directory);
-
-    if !dir_path.exists() {
-        println!("{}", "Directory does not exist".yellow());
-        return Ok(());
-    }
-
-    let parser = LegalDslParser::new();
-    let mut statutes = Vec::new();
-
-    // Read all .legal files
-    for entry in fs::read_dir(dir_path)? {
-        let entry = entry?;
-        let path = entry.path();
-
-        if path.extension().and_then(|s| s.to_str()) == Some("legal")
-            && let Ok(content) = fs::read_to_string(&path)
-            && let Ok(statute) = parser.parse_statute(&content)
-        {
-            statutes.push((path.clone(), statute));
-        }
-    }
-
-    if statutes.is_empty() {
-        println!("{}", "No statutes found".yellow());
+    let manifest = Manifest::load(Path::new(MANIFEST_FILE))?;
+    let Some(lock) = Lockfile::load(Path::new(LOCKFILE))? else {
+        println!(
+            "{}",
+            "No lockfile found; run 'legalis install' first".yellow()
+        );
         return Ok(());
-    }
+    };
+    let client = package_registry_client(registry, Some(&manifest))?;
 
-    // Check for updates (in a real implementation, this would query the registry)
-    let outdated: Vec<(std::path::PathBuf, Statute, u32, u32)> = Vec::new();
+    // (id, locked, newest allowed by the manifest, newest published)
+    let mut outdated = Vec::new();
     let mut up_to_date = Vec::new();
-
-    for (path, statute) in &statutes {
-        // Simulate version check (in real implementation, check against registry)
-        // For now, assume all are up to date
-        up_to_date.push((path, statute, statute.version));
+    for locked in &lock.packages {
+        let versions: Vec<u32> = client
+            .versions(&locked.id)
+            .await?
+            .iter()
+            .map(|summary| summary.version)
+            .collect();
+        let req = manifest
+            .dependencies
+            .get(&locked.id)
+            .cloned()
+            .unwrap_or_else(VersionReq::any);
+        let compatible = req
+            .best_match(versions.iter().copied())
+            .unwrap_or(locked.version);
+        let latest = versions.iter().copied().max().unwrap_or(locked.version);
+        if latest > locked.version {
+            outdated.push((locked, compatible, latest));
+        } else {
+            up_to_date.push(locked);
+        }
     }
 
     if !show_all && outdated.is_empty() {
@@ -2878,22 +3022,17 @@ pub fn handle_outdated(directory: &str, _registry_path: &str, show_all: bool) ->
             .apply_modifier(UTF8_ROUND_CORNERS)
             .set_header(vec![
                 Cell::new("ID").fg(Color::Cyan),
-                Cell::new("Current").fg(Color::Cyan),
+                Cell::new("Locked").fg(Color::Cyan),
+                Cell::new("Compatible").fg(Color::Cyan),
                 Cell::new("Latest").fg(Color::Cyan),
-                Cell::new("File").fg(Color::Cyan),
             ]);
 
-        for (path, statute, current_version, latest_version) in &outdated {
+        for (locked, compatible, latest) in &outdated {
             table.add_row(vec![
-                Cell::new(&statute.id),
-                Cell::new(current_version.to_string()).fg(Color::Yellow),
-                Cell::new(latest_version.to_string()).fg(Color::Green),
-                Cell::new(
-                    path.file_name()
-                        .expect("Path should have a filename")
-                        .to_string_lossy()
-                        .as_ref(),
-                ),
+                Cell::new(&locked.id),
+                Cell::new(locked.version.to_string()).fg(Color::Yellow),
+                Cell::new(compatible.to_string()),
+                Cell::new(latest.to_string()).fg(Color::Green),
             ]);
         }
 
@@ -2910,19 +3049,14 @@ pub fn handle_outdated(directory: &str, _registry_path: &str, show_all: bool) ->
             .set_header(vec![
                 Cell::new("ID").fg(Color::Cyan),
                 Cell::new("Version").fg(Color::Cyan),
-                Cell::new("File").fg(Color::Cyan),
+                Cell::new("Integrity").fg(Color::Cyan),
             ]);
 
-        for (path, statute, version) in &up_to_date {
+        for locked in &up_to_date {
             table.add_row(vec![
-                Cell::new(&statute.id),
-                Cell::new(version.to_string()),
-                Cell::new(
-                    path.file_name()
-                        .expect("Path should have a filename")
-                        .to_string_lossy()
-                        .as_ref(),
-                ),
+                Cell::new(&locked.id),
+                Cell::new(locked.version.to_string()),
+                Cell::new(&locked.integrity),
             ]);
         }
 
@@ -2933,12 +3067,15 @@ pub fn handle_outdated(directory: &str, _registry_path: &str, show_all: bool) ->
     println!(
         "{} {} total, {} outdated, {} up-to-date",
         "Summary:".bold(),
-        statutes.len(),
+        lock.packages.len(),
         outdated.len().to_string().red(),
         up_to_date.len().to_string().green()
     );
 
-    if !outdated.is_empty() {
+    if outdated
+        .iter()
+        .any(|(locked, compatible, _)| compatible > &locked.version)
+    {
         println!();
         println!(
             "{}",
@@ -4336,77 +4473,221 @@ impl crate::profile::ProfileData {
     }
 }
 
+/// Loads registry settings from the user-level credentials file only; project
+/// configuration never supplies the default registry or credentials.
+fn registry_settings() -> crate::config::RegistryConfig {
+    crate::config::RegistryConfig::load_user().unwrap_or_default()
+}
+
+/// Creates a client for the registry packages are resolved against: the
+/// `--registry` flag, then the manifest's `[package] registry`, then the
+/// configured default.
+fn package_registry_client(
+    registry: Option<&str>,
+    manifest: Option<&crate::package::Manifest>,
+) -> Result<crate::registry_client::RegistryClient> {
+    let settings = registry_settings();
+    let registry_url =
+        settings.resolve_url(registry.or_else(|| manifest.and_then(|m| m.registry())))?;
+    Ok(crate::registry_client::RegistryClient::new(&registry_url)
+        .with_credential(settings.credential(&registry_url)))
+}
+
+/// Returns the directory holding a statute file, for its sync state.
+fn statute_directory(path: &Path) -> &Path {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    }
+}
+
+/// Renders a statute diff without terminal colors.
+fn render_statute_diff(diff: &legalis_diff::StatuteDiff, format: &DiffFormat) -> Result<String> {
+    use std::fmt::Write;
+
+    let mut out = String::new();
+    match format {
+        DiffFormat::Json => out = serde_json::to_string_pretty(diff)?,
+        DiffFormat::Markdown => {
+            writeln!(out, "# Statute Diff: {}\n", diff.statute_id)?;
+            writeln!(out, "**Severity:** {:?}\n", diff.impact.severity)?;
+            writeln!(out, "## Changes\n")?;
+            for change in &diff.changes {
+                writeln!(
+                    out,
+                    "- **{:?}** {:?}: {}",
+                    change.change_type, change.target, change.description
+                )?;
+                if let Some(ref old) = change.old_value {
+                    writeln!(out, "  - Registry: `{}`", old)?;
+                }
+                if let Some(ref new) = change.new_value {
+                    writeln!(out, "  - Local: `{}`", new)?;
+                }
+            }
+            if !diff.impact.notes.is_empty() {
+                writeln!(out, "\n## Impact Notes\n")?;
+                for note in &diff.impact.notes {
+                    writeln!(out, "- {}", note)?;
+                }
+            }
+        }
+        DiffFormat::Text => {
+            writeln!(out, "Statute Diff: {} (registry -> local)", diff.statute_id)?;
+            writeln!(out, "Severity: {:?}", diff.impact.severity)?;
+            if diff.changes.is_empty() {
+                writeln!(out, "\nNo differences")?;
+            } else {
+                writeln!(out, "\nChanges:")?;
+                for change in &diff.changes {
+                    writeln!(
+                        out,
+                        "  {:?} {:?}: {}",
+                        change.change_type, change.target, change.description
+                    )?;
+                    if let Some(ref old) = change.old_value {
+                        writeln!(out, "    - {}", old)?;
+                    }
+                    if let Some(ref new) = change.new_value {
+                        writeln!(out, "    + {}", new)?;
+                    }
+                }
+            }
+            if !diff.impact.notes.is_empty() {
+                writeln!(out, "\nImpact Notes:")?;
+                for note in &diff.impact.notes {
+                    writeln!(out, "  - {}", note)?;
+                }
+            }
+        }
+    }
+    Ok(out)
+}
+
 /// Handles the registry push command.
+///
+/// The push is based on the registry version recorded in the sync state of
+/// the file's directory; the registry rejects it if that version is stale.
 #[allow(clippy::too_many_arguments)]
-pub fn handle_registry_push(
+pub async fn handle_registry_push(
     input: &str,
     registry: Option<&str>,
     tags: &[String],
     visibility: &crate::RegistryVisibility,
     dry_run: bool,
-    _force: bool,
+    force: bool,
 ) -> Result<()> {
+    use crate::registry_client::{LocalStatute, RegistryClient, SyncState, SyncedPackage};
     use colored::Colorize;
+    use legalis_api::registry_protocol::{PushRequest, PushStatus, Visibility};
 
     println!("{}", "Pushing statute to registry...".cyan().bold());
 
-    // Load and parse statute
-    let content = fs::read_to_string(input)
-        .with_context(|| format!("Failed to read input file: {}", input))?;
+    let local = LocalStatute::read(Path::new(input))?;
+    let settings = registry_settings();
+    let registry_url = settings.resolve_url(registry)?;
+    let directory = statute_directory(Path::new(input));
+    let mut state = SyncState::load(directory)?;
+    let base_version = state
+        .get(&registry_url, &local.statute.id)
+        .map(|synced| synced.version);
 
-    let parser = LegalDslParser::new();
-    let statute = parser
-        .parse_statute(&content)
-        .map_err(|e| anyhow::anyhow!("Parse error: {}", e))?;
-
-    let registry_url = registry.unwrap_or("default");
-    println!("  Statute ID: {}", statute.id.yellow());
+    println!("  Statute ID: {}", local.statute.id.yellow());
     println!("  Registry: {}", registry_url.yellow());
     println!("  Visibility: {:?}", visibility);
     println!("  Tags: {}", tags.join(", "));
+    match base_version {
+        Some(version) => println!("  Based on: version {}", version),
+        None => println!("  Based on: new package"),
+    }
+
+    let mut request = PushRequest::new(local.statute.clone())
+        .with_source(local.source.clone())
+        .with_tags(tags.to_vec())
+        .with_visibility(match visibility {
+            crate::RegistryVisibility::Public => Visibility::Public,
+            crate::RegistryVisibility::Private => Visibility::Private,
+        });
+    if let Some(version) = base_version {
+        request = request.with_base_version(version);
+    }
+    if force {
+        request = request.forced();
+    }
 
     if dry_run {
         println!("\n{}", "[DRY RUN] Would push statute to registry".green());
         return Ok(());
     }
 
-    // In a real implementation, this would:
-    // 1. Connect to the registry
-    // 2. Authenticate using stored credentials
-    // 3. Upload the statute with metadata
-    // 4. Handle conflicts if not using --force
+    let client =
+        RegistryClient::new(&registry_url).with_credential(settings.credential(&registry_url));
+    let response = client.push(&request).await.map_err(|e| match e {
+        crate::registry_client::RegistryClientError::Conflict(message) => anyhow::anyhow!(
+            "{}\n  Run `legalis registry pull` to update, or push with --force",
+            message
+        ),
+        e => e.into(),
+    })?;
 
-    println!("\n{} Statute pushed successfully!", "✓".green().bold());
-    println!("  View at: {}/statutes/{}", registry_url, statute.id);
+    state.record(
+        &registry_url,
+        &local.statute.id,
+        SyncedPackage {
+            version: response.package.version,
+            local_hash: local.hash,
+        },
+    );
+    state.save(directory)?;
+
+    match response.status {
+        PushStatus::Created => println!("\n{} Statute published!", "✓".green().bold()),
+        PushStatus::Updated => println!("\n{} Statute updated!", "✓".green().bold()),
+        PushStatus::Unchanged => {
+            println!("\n{} Registry already has this content", "✓".green().bold())
+        }
+    }
+    println!("  Version: {}", response.package.version);
+    println!("  Content hash: {}", response.package.content_hash.dimmed());
 
     Ok(())
 }
 
 /// Handles the registry pull command.
-pub fn handle_registry_pull(
+pub async fn handle_registry_pull(
     statute_id: &str,
     registry: Option<&str>,
     output: &str,
     version: Option<&str>,
     force: bool,
 ) -> Result<()> {
+    use crate::registry_client::{RegistryClient, SyncState, SyncedPackage, write_package};
     use colored::Colorize;
 
     println!("{}", "Pulling statute from registry...".cyan().bold());
 
-    let registry_url = registry.unwrap_or("default");
-    let version_str = version.unwrap_or("latest");
+    let settings = registry_settings();
+    let registry_url = settings.resolve_url(registry)?;
+    let pinned = version
+        .filter(|v| *v != "latest")
+        .map(|v| {
+            v.trim_start_matches('v')
+                .parse::<u32>()
+                .with_context(|| format!("Invalid version '{}': expected a number", v))
+        })
+        .transpose()?;
 
     println!("  Statute ID: {}", statute_id.yellow());
     println!("  Registry: {}", registry_url.yellow());
-    println!("  Version: {}", version_str.yellow());
+    println!("  Version: {}", version.unwrap_or("latest").yellow());
     println!("  Output: {}", output.yellow());
 
     // Create output directory if it doesn't exist
     fs::create_dir_all(output)
         .with_context(|| format!("Failed to create output directory: {}", output))?;
 
-    let output_file = Path::new(output).join(format!("{}.ldsl", statute_id));
+    let output_dir = Path::new(output);
+    let output_file = output_dir.join(format!("{}.ldsl", statute_id));
 
     // Check if file exists
     if output_file.exists() && !force {
@@ -4416,26 +4697,38 @@ pub fn handle_registry_pull(
         );
     }
 
-    // In a real implementation, this would:
-    // 1. Connect to the registry
-    // 2. Authenticate if needed
-    // 3. Download the statute with specified version
-    // 4. Save to the output directory
+    let client =
+        RegistryClient::new(&registry_url).with_credential(settings.credential(&registry_url));
+    let package = client.fetch(statute_id, pinned).await?;
+    let local = write_package(&package, &output_file)?;
+
+    let mut state = SyncState::load(output_dir)?;
+    state.record(
+        &registry_url,
+        statute_id,
+        SyncedPackage {
+            version: package.summary.version,
+            local_hash: local.hash,
+        },
+    );
+    state.save(output_dir)?;
 
     println!("\n{} Statute pulled successfully!", "✓".green().bold());
+    println!("  Version: {}", package.summary.version);
     println!("  Saved to: {}", output_file.display());
 
     Ok(())
 }
 
 /// Handles the registry diff command.
-pub fn handle_registry_diff(
+pub async fn handle_registry_diff(
     local: &str,
     statute_id: Option<&str>,
     registry: Option<&str>,
-    _diff_format: &crate::DiffFormat,
+    diff_format: &DiffFormat,
     output: Option<&str>,
 ) -> Result<()> {
+    use crate::registry_client::{LocalStatute, RegistryClient};
     use colored::Colorize;
 
     println!(
@@ -4443,35 +4736,25 @@ pub fn handle_registry_diff(
         "Comparing local statute with registry...".cyan().bold()
     );
 
-    // Load local statute
-    let content = fs::read_to_string(local)
-        .with_context(|| format!("Failed to read local file: {}", local))?;
-
-    let parser = LegalDslParser::new();
-    let local_statute = parser
-        .parse_statute(&content)
-        .map_err(|e| anyhow::anyhow!("Parse error: {}", e))?;
-
-    let statute_id_str = statute_id.unwrap_or(&local_statute.id);
-    let registry_url = registry.unwrap_or("default");
+    let mut local_statute = LocalStatute::read(Path::new(local))?.statute;
+    let statute_id = statute_id.unwrap_or(&local_statute.id).to_string();
+    let settings = registry_settings();
+    let registry_url = settings.resolve_url(registry)?;
 
     println!("  Local: {}", local.yellow());
-    println!("  Statute ID: {}", statute_id_str.yellow());
+    println!("  Statute ID: {}", statute_id.yellow());
     println!("  Registry: {}", registry_url.yellow());
 
-    // In a real implementation, this would:
-    // 1. Fetch the remote statute from registry
-    // 2. Compare local vs remote
-    // 3. Generate diff output
-
-    let diff_output = format!(
-        "=== Diff: Local vs Registry ===\n\
-         Local file: {}\n\
-         Remote statute: {} (from {})\n\
-         \n\
-         [Mock diff output - would show actual differences]\n",
-        local, statute_id_str, registry_url
-    );
+    let client =
+        RegistryClient::new(&registry_url).with_credential(settings.credential(&registry_url));
+    let remote = client.fetch(&statute_id, None).await?;
+    println!("  Remote version: {}", remote.summary.version);
+
+    // Compare under the registry's ID when a different one was requested
+    local_statute.id = statute_id;
+    let diff = legalis_diff::diff(&remote.statute, &local_statute)
+        .map_err(|e| anyhow::anyhow!("Diff error: {}", e))?;
+    let diff_output = render_statute_diff(&diff, diff_format)?;
 
     // Write output
     if let Some(out_path) = output {
@@ -4486,18 +4769,25 @@ pub fn handle_registry_diff(
 }
 
 /// Handles the registry sync command.
-pub fn handle_registry_sync(
+pub async fn handle_registry_sync(
     directory: &str,
     registry: Option<&str>,
     direction: &crate::SyncDirection,
     conflict: &crate::ConflictResolution,
     dry_run: bool,
 ) -> Result<()> {
+    use crate::registry_client::{
+        LocalStatute, RegistryClient, SyncAction, SyncState, SyncedPackage, plan_sync,
+        write_package,
+    };
+    use crate::{ConflictResolution, SyncDirection};
     use colored::Colorize;
+    use legalis_api::registry_protocol::PushRequest;
 
     println!("{}", "Synchronizing with registry...".cyan().bold());
 
-    let registry_url = registry.unwrap_or("default");
+    let settings = registry_settings();
+    let registry_url = settings.resolve_url(registry)?;
 
     println!("  Directory: {}", directory.yellow());
     println!("  Registry: {}", registry_url.yellow());
@@ -4505,101 +4795,248 @@ pub fn handle_registry_sync(
     println!("  Conflict resolution: {:?}", conflict);
 
     // Check directory exists
-    if !Path::new(directory).exists() {
+    let dir = Path::new(directory);
+    if !dir.exists() {
         anyhow::bail!("Directory does not exist: {}", directory);
     }
 
+    let client =
+        RegistryClient::new(&registry_url).with_credential(settings.credential(&registry_url));
+    let mut state = SyncState::load(dir)?;
+    let local = LocalStatute::scan(dir)?;
+    let remote = client.list().await?;
+    let actions = plan_sync(&registry_url, &local, &remote, &state);
+
+    let allow_pull = matches!(direction, SyncDirection::Pull | SyncDirection::Both);
+    let allow_push = matches!(direction, SyncDirection::Push | SyncDirection::Both);
+    let (mut pulled, mut pushed, mut resolved, mut skipped) = (0, 0, 0, 0);
+    println!();
+
+    for action in actions {
+        // Conflicts are resolved into a pull or a push first
+        let action = match action {
+            SyncAction::Conflict {
+                statute_id,
+                remote_version,
+            } => {
+                let choice = match conflict {
+                    ConflictResolution::Local => 'l',
+                    ConflictResolution::Remote => 'r',
+                    ConflictResolution::Ask if dry_run => 's',
+                    ConflictResolution::Ask => {
+                        use std::io::Write;
+                        print!(
+                            "  Conflict on {} (registry at v{}): keep [l]ocal, take [r]emote, or [s]kip? ",
+                            statute_id.yellow(),
+                            remote_version
+                        );
+                        std::io::stdout().flush()?;
+                        let mut input = String::new();
+                        std::io::stdin().read_line(&mut input)?;
+                        input.trim().chars().next().unwrap_or('s')
+                    }
+                };
+                println!("  {} conflict on {}", "!".red().bold(), statute_id);
+                match choice {
+                    'l' | 'L' if allow_push => {
+                        resolved += 1;
+                        SyncAction::Push {
+                            statute_id,
+                            base_version: Some(remote_version),
+                        }
+                    }
+                    'r' | 'R' if allow_pull => {
+                        resolved += 1;
+                        SyncAction::Pull {
+                            statute_id,
+                            version: remote_version,
+                        }
+                    }
+                    _ => {
+                        skipped += 1;
+                        continue;
+                    }
+                }
+            }
+            action => action,
+        };
+
+        match action {
+            SyncAction::InSync {
+                statute_id,
+                version,
+            } => {
+                let local_hash = local[&statute_id].hash.clone();
+                state.record(
+                    &registry_url,
+                    &statute_id,
+                    SyncedPackage {
+                        version,
+                        local_hash,
+                    },
+                );
+            }
+            SyncAction::Pull {
+                statute_id,
+                version,
+            } if allow_pull => {
+                println!("  {} pull {} (v{})", "↓".green(), statute_id, version);
+                pulled += 1;
+                if dry_run {
+                    continue;
+                }
+                let path = local
+                    .get(&statute_id)
+                    .map(|l| l.path.clone())
+                    .unwrap_or_else(|| dir.join(format!("{}.ldsl", statute_id)));
+                let package = client.fetch(&statute_id, Some(version)).await?;
+                let written = write_package(&package, &path)?;
+                state.record(
+                    &registry_url,
+                    &statute_id,
+                    SyncedPackage {
+                        version,
+                        local_hash: written.hash,
+                    },
+                );
+            }
+            SyncAction::Push {
+                statute_id,
+                base_version,
+            } if allow_push => {
+                println!("  {} push {}", "↑".cyan(), statute_id);
+                pushed += 1;
+                if dry_run {
+                    continue;
+                }
+                let statute = &local[&statute_id];
+                let mut request =
+                    PushRequest::new(statute.statute.clone()).with_source(statute.source.clone());
+                if let Some(version) = base_version {
+                    request = request.with_base_version(version);
+                }
+                let response = client.push(&request).await?;
+                state.record(
+                    &registry_url,
+                    &statute_id,
+                    SyncedPackage {
+                        version: response.package.version,
+                        local_hash: statute.hash.clone(),
+                    },
+                );
+            }
+            _ => skipped += 1,
+        }
+    }
+
     if dry_run {
         println!("\n{}", "[DRY RUN] Would synchronize with registry".green());
-        println!("  Files to pull: 3");
-        println!("  Files to push: 2");
-        println!("  Conflicts: 1");
+        println!("  Statutes to pull: {}", pulled);
+        println!("  Statutes to push: {}", pushed);
+        println!("  Conflicts resolved: {}", resolved);
+        println!("  Skipped: {}", skipped);
         return Ok(());
     }
 
-    // In a real implementation, this would:
-    // 1. Scan local directory for statutes
-    // 2. Fetch list of statutes from registry
-    // 3. Determine differences
-    // 4. Resolve conflicts based on strategy
-    // 5. Perform sync operations
+    state.save(dir)?;
 
     println!("\n{} Synchronization complete!", "✓".green().bold());
-    println!("  Pulled: 3 statutes");
-    println!("  Pushed: 2 statutes");
-    println!("  Conflicts resolved: 1");
+    println!("  Pulled: {} statutes", pulled);
+    println!("  Pushed: {} statutes", pushed);
+    println!("  Conflicts resolved: {}", resolved);
+    println!("  Skipped: {}", skipped);
 
     Ok(())
 }
 
 /// Handles the registry login command.
-pub fn handle_registry_login(
+///
+/// The token is read without echo and checked against the registry before it
+/// is stored in the user-level credentials file.
+pub async fn handle_registry_login(
     registry: &str,
     username: Option<&str>,
     password: Option<&str>,
     token: Option<&str>,
 ) -> Result<()> {
+    use crate::config::{RegistryConfig, RegistryCredential};
+    use crate::registry_client::RegistryClient;
     use colored::Colorize;
 
     println!("{}", "Logging in to registry...".cyan().bold());
-    println!("  Registry: {}", registry.yellow());
-
-    // Get credentials
-    let user = if let Some(_tok) = token {
-        println!("  Using API token");
-        "token".to_string()
-    } else {
-        let u = if let Some(u) = username {
-            u.to_string()
-        } else {
-            print!("  Username: ");
-            std::io::Write::flush(&mut std::io::stdout())?;
-            let mut input = String::new();
-            std::io::stdin().read_line(&mut input)?;
-            input.trim().to_string()
-        };
-
-        let _p = if let Some(p) = password {
-            p.to_string()
-        } else {
-            // In a real implementation, use a secure password input
-            use std::io::Write;
-            print!("  Password: ");
-            std::io::stdout().flush()?;
-            let mut input = String::new();
-            std::io::stdin().read_line(&mut input)?;
-            input.trim().to_string()
-        };
+    let registry_url = registry.trim_end_matches('/').to_string();
+    println!("  Registry: {}", registry_url.yellow());
 
-        u
+    let token = match token {
+        Some(token) => token.to_string(),
+        None if password.is_some() => {
+            anyhow::bail!("The registry authenticates with API tokens; use --token instead")
+        }
+        None => crate::interactive::prompt_secret("  API token")?,
     };
+    if token.is_empty() {
+        anyhow::bail!("No API token given");
+    }
 
-    // In a real implementation, this would:
-    // 1. Authenticate with the registry
-    // 2. Store credentials securely (e.g., in keyring)
-    // 3. Save session token
+    let user = RegistryClient::new(&registry_url)
+        .with_token(&token)
+        .whoami()
+        .await?;
+    if let Some(expected) = username
+        && expected != user.username
+    {
+        anyhow::bail!("Token belongs to '{}', not '{}'", user.username, expected);
+    }
+
+    let mut settings = RegistryConfig::load_user()?;
+    settings.credentials.insert(
+        registry_url.clone(),
+        RegistryCredential {
+            token,
+            username: Some(user.username.clone()),
+        },
+    );
+    if settings.default.is_none() {
+        settings.default = Some(registry_url);
+    }
+    let credentials_file = settings.save_user()?;
 
     println!("\n{} Logged in successfully!", "✓".green().bold());
-    println!("  User: {}", user);
+    println!("  User: {}", user.username);
+    println!("  Role: {:?}", user.role);
+    if !user.can_publish {
+        println!("  {}", "This account cannot publish statutes".yellow());
+    }
+    println!("  Credentials saved to: {}", credentials_file.display());
 
     Ok(())
 }
 
 /// Handles the registry logout command.
 pub fn handle_registry_logout(registry: Option<&str>, all: bool) -> Result<()> {
+    use crate::config::RegistryConfig;
     use colored::Colorize;
 
     println!("{}", "Logging out from registry...".cyan().bold());
 
+    let mut settings = RegistryConfig::load_user()?;
+
     if all {
         println!("  Clearing all credentials");
-        // In a real implementation, clear all stored credentials
+        settings.credentials.clear();
     } else if let Some(reg) = registry {
         println!("  Registry: {}", reg.yellow());
-        // In a real implementation, clear credentials for specific registry
+        if settings
+            .credentials
+            .remove(reg.trim_end_matches('/'))
+            .is_none()
+        {
+            println!("  {}", "No stored credentials for this registry".dimmed());
+        }
     } else {
         anyhow::bail!("Please specify --registry or use --all");
     }
+    settings.save_user()?;
 
     println!("\n{} Logged out successfully!", "✓".green().bold());
 
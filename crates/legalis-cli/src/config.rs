@@ -133,6 +133,132 @@ pub struct LintConfig {
     pub strict: bool,
 }
 
+/// Remote registry configuration.
+///
+/// Kept in its own user-level `credentials.toml` rather than in `Config`, so
+/// a project's `legalis.toml` can neither supply nor redirect credentials.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RegistryConfig {
+    /// Registry URL used when `--registry` is not given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+
+    /// Stored credentials (registry URL -> credential)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub credentials: HashMap<String, RegistryCredential>,
+}
+
+/// Credential for a remote registry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegistryCredential {
+    /// API key (`lgl_...`) or bearer token
+    pub token: String,
+
+    /// Username reported by the registry at login
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
+
+impl RegistryConfig {
+    /// Resolves the registry URL, falling back to the configured default.
+    pub fn resolve_url(&self, registry: Option<&str>) -> Result<String> {
+        registry
+            .map(String::from)
+            .or_else(|| self.default.clone())
+            .map(|url| url.trim_end_matches('/').to_string())
+            .context("No registry specified. Use --registry or run `legalis registry login`")
+    }
+
+    /// Returns the stored credential for a registry.
+    pub fn credential(&self, url: &str) -> Option<&RegistryCredential> {
+        self.credentials.get(url.trim_end_matches('/'))
+    }
+
+    /// Path of the user-level credentials file.
+    pub fn user_credentials_path() -> Result<PathBuf> {
+        Ok(Config::user_config_dir()
+            .context("Failed to determine config directory")?
+            .join("credentials.toml"))
+    }
+
+    /// Loads the user-level registry settings.
+    ///
+    /// Settings saved by older versions in the `[registry]` table of the user
+    /// `config.toml` are picked up until the next save moves them.
+    pub fn load_user() -> Result<Self> {
+        let path = Self::user_credentials_path()?;
+        if path.exists() {
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read credentials file: {}", path.display()))?;
+            return toml::from_str(&content)
+                .with_context(|| format!("Failed to parse credentials file: {}", path.display()));
+        }
+
+        let legacy = path.with_file_name("config.toml");
+        let Ok(content) = fs::read_to_string(&legacy) else {
+            return Ok(Self::default());
+        };
+        let mut table: toml::Table = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse config file: {}", legacy.display()))?;
+        match table.remove("registry") {
+            Some(registry) => registry
+                .try_into()
+                .with_context(|| format!("Invalid [registry] table in {}", legacy.display())),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Saves the registry settings to the user-level credentials file, readable
+    /// only by the current user, and drops any legacy `[registry]` table from
+    /// the user `config.toml`.
+    pub fn save_user(&self) -> Result<PathBuf> {
+        let path = Self::user_credentials_path()?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create config directory: {}", dir.display()))?;
+        }
+        let content = toml::to_string_pretty(self).context("Failed to serialize credentials")?;
+        write_private(&path, content.as_bytes())
+            .with_context(|| format!("Failed to write credentials file: {}", path.display()))?;
+
+        let legacy = path.with_file_name("config.toml");
+        if let Ok(existing) = fs::read_to_string(&legacy)
+            && let Ok(mut table) = toml::from_str::<toml::Table>(&existing)
+            && table.remove("registry").is_some()
+        {
+            let content = toml::to_string_pretty(&table).context("Failed to serialize config")?;
+            fs::write(&legacy, content)
+                .with_context(|| format!("Failed to write config file: {}", legacy.display()))?;
+        }
+
+        Ok(path)
+    }
+}
+
+/// Replaces a file with one readable only by its owner where the platform
+/// allows it. The contents go to a fresh temporary file first, so an existing
+/// file's permissions are never reused.
+fn write_private(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let temp = path.with_extension("toml.tmp");
+    match fs::remove_file(&temp) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => return Err(err),
+        _ => {}
+    }
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&temp)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    fs::rename(&temp, path)
+}
+
 fn default_true() -> bool {
     true
 }
@@ -280,8 +406,28 @@ impl Config {
     }
 
     /// Save configuration to a file.
+    ///
+    /// The package manifest tables (`[package]` and `[dependencies]`) of an
+    /// existing file are kept.
     pub fn save(&self, path: &Path) -> Result<()> {
-        let content = toml::to_string_pretty(self).context("Failed to serialize config")?;
+        let mut content = toml::to_string_pretty(self).context("Failed to serialize config")?;
+
+        let manifest: toml::Table = fs::read_to_string(path)
+            .ok()
+            .and_then(|existing| toml::from_str::<toml::Table>(&existing).ok())
+            .map(|existing| {
+                existing
+                    .into_iter()
+                    .filter(|(key, _)| key == "package" || key == "dependencies")
+                    .collect()
+            })
+            .unwrap_or_default();
+        if !manifest.is_empty() {
+            content.push('\n');
+            content.push_str(
+                &toml::to_string_pretty(&manifest).context("Failed to serialize manifest")?,
+            );
+        }
 
         fs::write(path, content)
             .with_context(|| format!("Failed to write config file: {}", path.display()))?;
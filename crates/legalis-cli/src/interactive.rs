@@ -199,6 +199,50 @@ impl Default for InteractivePrompt {
     }
 }
 
+/// Prompt for a secret such as an API token without echoing it.
+///
+/// Falls back to reading a plain line when stdin is not a terminal, so
+/// secrets can still be piped in.
+pub fn prompt_secret(prompt: &str) -> Result<String> {
+    use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+    use crossterm::terminal;
+    use std::io::{IsTerminal, Write};
+
+    print!("{}: ", prompt);
+    std::io::stdout().flush()?;
+
+    if !std::io::stdin().is_terminal() {
+        let mut line = String::new();
+        std::io::stdin().read_line(&mut line)?;
+        return Ok(line.trim().to_string());
+    }
+
+    terminal::enable_raw_mode()?;
+    let mut secret = String::new();
+    let result = loop {
+        let key = match event::read() {
+            Ok(Event::Key(key)) if key.kind != KeyEventKind::Release => key,
+            Ok(_) => continue,
+            Err(err) => break Err(err.into()),
+        };
+        match key.code {
+            KeyCode::Enter => break Ok(secret.trim().to_string()),
+            KeyCode::Backspace => {
+                secret.pop();
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                break Err(anyhow::anyhow!("Interactive mode cancelled"));
+            }
+            KeyCode::Esc => break Err(anyhow::anyhow!("Interactive mode cancelled")),
+            KeyCode::Char(c) => secret.push(c),
+            _ => {}
+        }
+    };
+    terminal::disable_raw_mode()?;
+    println!();
+    result
+}
+
 /// Interactive wizard for creating a new statute.
 pub fn interactive_new_statute() -> Result<(String, String, Option<String>)> {
     let mut prompt = InteractivePrompt::new()?;
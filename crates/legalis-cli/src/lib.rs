@@ -15,13 +15,16 @@ pub mod config;
 pub mod debug;
 pub mod error_suggestions;
 pub mod interactive;
+pub mod package;
 pub mod parallel;
 pub mod perf;
 pub mod plugin;
 pub mod profile;
 pub mod progress;
+pub mod registry_client;
 pub mod scripting;
 pub mod team;
+pub mod test_runner;
 pub mod theme;
 pub mod tui;
 pub mod tutorial;
@@ -93,6 +96,20 @@ pub enum OutputFormat {
     Html,
 }
 
+/// Test report format options.
+#[derive(Clone, Debug, Default, clap::ValueEnum)]
+pub enum TestReportFormat {
+    /// Human-readable results with diffs
+    #[default]
+    Text,
+    /// JUnit XML
+    Junit,
+    /// Test Anything Protocol
+    Tap,
+    /// JSON report
+    Json,
+}
+
 /// Color theme options.
 #[derive(Clone, Debug, Default, clap::ValueEnum)]
 pub enum ColorTheme {
@@ -400,13 +417,25 @@ pub enum Commands {
         #[arg(short, long)]
         input: Vec<String>,
 
-        /// Test specification file
+        /// Test specification file (YAML, or TOML with a .toml extension)
         #[arg(short, long)]
         tests: String,
 
         /// Verbose test output
         #[arg(short, long)]
         verbose: bool,
+
+        /// Report format
+        #[arg(short, long, default_value = "text")]
+        format: TestReportFormat,
+
+        /// Write the report to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// Show condition coverage
+        #[arg(long)]
+        coverage: bool,
     },
 
     /// Create a new statute from a template
@@ -501,13 +530,13 @@ pub enum Commands {
 
     /// Install a statute from a registry
     Install {
-        /// Statute ID to install
+        /// Statute ID to install (installs every dependency in legalis.toml if not specified)
         #[arg(short, long)]
-        statute_id: String,
+        statute_id: Option<String>,
 
-        /// Registry directory path (defaults to current directory)
-        #[arg(short, long, default_value = ".")]
-        registry: String,
+        /// Registry URL (defaults to the manifest's or configured registry)
+        #[arg(short, long)]
+        registry: Option<String>,
 
         /// Output directory for installed statute
         #[arg(short, long, default_value = "./statutes")]
@@ -516,6 +545,10 @@ pub enum Commands {
         /// Force reinstall if already installed
         #[arg(long)]
         force: bool,
+
+        /// Fail instead of updating legalis.lock
+        #[arg(long)]
+        locked: bool,
     },
 
     /// List installed statutes
@@ -535,12 +568,16 @@ pub enum Commands {
         #[arg(short, long)]
         statute_id: String,
 
-        /// Registry directory path (defaults to current directory)
-        #[arg(short, long, default_value = ".")]
-        registry: String,
+        /// Registry URL (defaults to the manifest's or configured registry)
+        #[arg(short, long)]
+        registry: Option<String>,
 
-        /// Project configuration file to update
-        #[arg(long, default_value = "legalis.yaml")]
+        /// Version requirement, e.g. "^3" or ">=2, <5" (defaults to the latest version)
+        #[arg(long)]
+        version: Option<String>,
+
+        /// Package manifest to update
+        #[arg(long, default_value = "legalis.toml")]
         config: String,
     },
 
@@ -550,9 +587,9 @@ pub enum Commands {
         #[arg(short, long)]
         statute_id: Option<String>,
 
-        /// Registry directory path (defaults to current directory)
-        #[arg(short, long, default_value = ".")]
-        registry: String,
+        /// Registry URL (defaults to the manifest's or configured registry)
+        #[arg(short, long)]
+        registry: Option<String>,
 
         /// Check for updates without installing
         #[arg(long)]
@@ -580,13 +617,9 @@ pub enum Commands {
 
     /// Check for outdated statutes
     Outdated {
-        /// Directory containing installed statutes
-        #[arg(short, long, default_value = "./statutes")]
-        directory: String,
-
-        /// Registry directory path (defaults to current directory)
-        #[arg(short, long, default_value = ".")]
-        registry: String,
+        /// Registry URL (defaults to the manifest's or configured registry)
+        #[arg(short, long)]
+        registry: Option<String>,
 
         /// Show all statutes, not just outdated ones
         #[arg(long)]
@@ -201,8 +201,11 @@ async fn main() -> Result<()> {
             input,
             tests,
             verbose,
+            format,
+            output,
+            coverage,
         } => {
-            commands::handle_test(input, tests, *verbose)?;
+            commands::handle_test(input, tests, *verbose, format, output.as_deref(), *coverage)?;
         }
         Commands::New {
             name,
@@ -246,8 +249,16 @@ async fn main() -> Result<()> {
             registry,
             output,
             force,
+            locked,
         } => {
-            commands::handle_install(statute_id, registry, output, *force)?;
+            commands::handle_install(
+                statute_id.as_deref(),
+                registry.as_deref(),
+                output,
+                *force,
+                *locked,
+            )
+            .await?;
         }
         Commands::List { directory, verbose } => {
             commands::handle_list(directory, *verbose)?;
@@ -255,16 +266,18 @@ async fn main() -> Result<()> {
         Commands::Add {
             statute_id,
             registry,
+            version,
             config,
         } => {
-            commands::handle_add(statute_id, registry, config)?;
+            commands::handle_add(statute_id, registry.as_deref(), version.as_deref(), config)
+                .await?;
         }
         Commands::Update {
             statute_id,
             registry,
             dry_run,
         } => {
-            commands::handle_update(statute_id.as_deref(), registry, *dry_run)?;
+            commands::handle_update(statute_id.as_deref(), registry.as_deref(), *dry_run).await?;
         }
         Commands::Clean {
             all,
@@ -274,12 +287,8 @@ async fn main() -> Result<()> {
         } => {
             commands::handle_clean(*all, *cache, *temp, *dry_run)?;
         }
-        Commands::Outdated {
-            directory,
-            registry,
-            all,
-        } => {
-            commands::handle_outdated(directory, registry, *all)?;
+        Commands::Outdated { registry, all } => {
+            commands::handle_outdated(registry.as_deref(), *all).await?;
         }
         Commands::Uninstall {
             statute_id,
@@ -412,7 +421,8 @@ async fn main() -> Result<()> {
                         visibility,
                         *dry_run,
                         *force,
-                    )?;
+                    )
+                    .await?;
                 }
                 RegistryOperation::Pull {
                     statute_id,
@@ -427,7 +437,8 @@ async fn main() -> Result<()> {
                         output,
                         version.as_deref(),
                         *force,
-                    )?;
+                    )
+                    .await?;
                 }
                 RegistryOperation::Diff {
                     local,
@@ -442,7 +453,8 @@ async fn main() -> Result<()> {
                         registry.as_deref(),
                         diff_format,
                         output.as_deref(),
-                    )?;
+                    )
+                    .await?;
                 }
                 RegistryOperation::Sync {
                     directory,
@@ -457,7 +469,8 @@ async fn main() -> Result<()> {
                         direction,
                         conflict,
                         *dry_run,
-                    )?;
+                    )
+                    .await?;
                 }
                 RegistryOperation::Login {
                     registry,
@@ -470,7 +483,8 @@ async fn main() -> Result<()> {
                         username.as_deref(),
                         password.as_deref(),
                         token.as_deref(),
-                    )?;
+                    )
+                    .await?;
                 }
                 RegistryOperation::Logout { registry, all } => {
                     commands::handle_registry_logout(registry.as_deref(), *all)?;
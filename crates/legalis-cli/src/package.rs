@@ -0,0 +1,652 @@
+//! Statute packages: manifest, version requirements and lockfile.
+//!
+//! This module provides:
+//! - [`Manifest`], the `[package]` and `[dependencies]` tables of `legalis.toml`
+//! - [`VersionReq`], semver-style requirements over registry versions
+//! - [`Lockfile`], the `legalis.lock` file pinning every resolved statute to a
+//!   registry version and an integrity hash
+//! - [`resolve`], which resolves a manifest (and the statutes its dependencies
+//!   derive from) against a registry
+//!
+//! A manifest looks like this:
+//!
+//! ```toml
+//! [package]
+//! name = "welfare-rules"
+//! registry = "https://registry.example.org"
+//!
+//! [dependencies]
+//! pension-act-art-3 = "^2"
+//! income-tax-sec-1 = ">=3, <5"
+//! ```
+//!
+//! Registry versions are single amendment counters rather than
+//! `major.minor.patch` triples, so `^N` (and a bare `N`) admits version `N`
+//! and every later amendment.
+
+use crate::registry_client::{RegistryClient, RegistryClientError};
+use anyhow::{Context, Result};
+use legalis_api::registry_protocol::Package;
+use legalis_core::Statute;
+use legalis_core::formats::StatuteHasher;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// File name of the package manifest.
+pub const MANIFEST_FILE: &str = "legalis.toml";
+
+/// File name of the lockfile.
+pub const LOCKFILE: &str = "legalis.lock";
+
+/// Current lockfile format version.
+pub const LOCKFILE_VERSION: u32 = 1;
+
+/// Comparison operator of a version requirement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    /// `=N`
+    Exact,
+    /// `>N`
+    Greater,
+    /// `>=N`
+    GreaterEq,
+    /// `<N`
+    Less,
+    /// `<=N`
+    LessEq,
+    /// `^N` or `N`: version `N` or any later amendment
+    Caret,
+}
+
+/// A single comparator such as `>=3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Comparator {
+    pub op: Op,
+    pub version: u32,
+}
+
+impl Comparator {
+    /// Returns true if `version` satisfies the comparator.
+    pub fn matches(&self, version: u32) -> bool {
+        match self.op {
+            Op::Exact => version == self.version,
+            Op::Greater => version > self.version,
+            Op::GreaterEq | Op::Caret => version >= self.version,
+            Op::Less => version < self.version,
+            Op::LessEq => version <= self.version,
+        }
+    }
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = match self.op {
+            Op::Exact => "=",
+            Op::Greater => ">",
+            Op::GreaterEq => ">=",
+            Op::Less => "<",
+            Op::LessEq => "<=",
+            Op::Caret => "^",
+        };
+        write!(f, "{}{}", op, self.version)
+    }
+}
+
+/// A version requirement: comma-separated comparators that must all hold.
+///
+/// `*` (no comparators) matches every version.
+///
+/// # Example
+///
+/// ```
+/// use legalis::package::VersionReq;
+///
+/// let req: VersionReq = ">=2, <5".parse().unwrap();
+/// assert!(req.matches(4));
+/// assert!(!req.matches(5));
+/// assert_eq!(req.best_match([1, 3, 4, 6]), Some(4));
+///
+/// let caret: VersionReq = "3".parse().unwrap();
+/// assert_eq!(caret.to_string(), "^3");
+/// assert!(caret.matches(7));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VersionReq {
+    pub comparators: Vec<Comparator>,
+}
+
+impl VersionReq {
+    /// Requirement matching every version.
+    pub fn any() -> Self {
+        Self::default()
+    }
+
+    /// Requirement for version `version` or any later amendment.
+    pub fn caret(version: u32) -> Self {
+        Self {
+            comparators: vec![Comparator {
+                op: Op::Caret,
+                version,
+            }],
+        }
+    }
+
+    /// Returns true if `version` satisfies every comparator.
+    pub fn matches(&self, version: u32) -> bool {
+        self.comparators.iter().all(|c| c.matches(version))
+    }
+
+    /// Returns the highest matching version.
+    pub fn best_match(&self, versions: impl IntoIterator<Item = u32>) -> Option<u32> {
+        versions.into_iter().filter(|v| self.matches(*v)).max()
+    }
+}
+
+impl FromStr for VersionReq {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if s == "*" {
+            return Ok(Self::any());
+        }
+
+        let mut comparators = Vec::new();
+        for part in s.split(',') {
+            let part = part.trim();
+            let (op, number) = if let Some(rest) = part.strip_prefix(">=") {
+                (Op::GreaterEq, rest)
+            } else if let Some(rest) = part.strip_prefix("<=") {
+                (Op::LessEq, rest)
+            } else if let Some(rest) = part.strip_prefix('>') {
+                (Op::Greater, rest)
+            } else if let Some(rest) = part.strip_prefix('<') {
+                (Op::Less, rest)
+            } else if let Some(rest) = part.strip_prefix('=') {
+                (Op::Exact, rest)
+            } else if let Some(rest) = part.strip_prefix('^') {
+                (Op::Caret, rest)
+            } else {
+                (Op::Caret, part)
+            };
+            let version = number
+                .trim()
+                .parse()
+                .with_context(|| format!("Invalid version requirement '{}'", s))?;
+            comparators.push(Comparator { op, version });
+        }
+        Ok(Self { comparators })
+    }
+}
+
+impl fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.comparators.is_empty() {
+            return write!(f, "*");
+        }
+        let parts: Vec<String> = self.comparators.iter().map(|c| c.to_string()).collect();
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+impl Serialize for VersionReq {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for VersionReq {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// The `[package]` table of a manifest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PackageInfo {
+    /// Package name
+    #[serde(default)]
+    pub name: String,
+
+    /// Package version
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
+    /// Registry URL dependencies are resolved against
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry: Option<String>,
+}
+
+/// Package manifest stored in `legalis.toml`, next to the CLI settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Manifest {
+    /// Package metadata
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<PackageInfo>,
+
+    /// Dependencies (statute ID -> version requirement)
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, VersionReq>,
+}
+
+impl Manifest {
+    /// Loads a manifest; other tables in the file are ignored.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read manifest: {}", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("Failed to parse manifest: {}", path.display()))
+    }
+
+    /// Returns the registry URL declared in `[package]`.
+    pub fn registry(&self) -> Option<&str> {
+        self.package.as_ref()?.registry.as_deref()
+    }
+
+    /// Adds or replaces a dependency in a manifest file, creating it if needed.
+    ///
+    /// Other tables in the file (such as the CLI settings) are kept.
+    pub fn add_dependency(path: &Path, statute_id: &str, req: &VersionReq) -> Result<Self> {
+        let mut table: toml::Table = if path.exists() {
+            let content = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read manifest: {}", path.display()))?;
+            toml::from_str(&content)
+                .with_context(|| format!("Failed to parse manifest: {}", path.display()))?
+        } else {
+            toml::Table::new()
+        };
+
+        let dependencies = table
+            .entry("dependencies")
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+            .as_table_mut()
+            .context("`dependencies` in the manifest must be a table")?;
+        dependencies.insert(statute_id.to_string(), toml::Value::String(req.to_string()));
+
+        let content = toml::to_string_pretty(&table).context("Failed to serialize manifest")?;
+        fs::write(path, &content)
+            .with_context(|| format!("Failed to write manifest: {}", path.display()))?;
+        toml::from_str(&content).context("Failed to parse manifest")
+    }
+}
+
+/// A statute pinned by the lockfile.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedPackage {
+    pub id: String,
+    /// Registry version
+    pub version: u32,
+    /// Registry the statute was resolved from
+    pub registry: String,
+    /// `sha256-` followed by the statute's [`StatuteHasher`] hash
+    pub integrity: String,
+    /// Statutes this one derives from
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<String>,
+}
+
+/// The `legalis.lock` file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lockfile {
+    /// Lockfile format version
+    pub version: u32,
+
+    /// Locked statutes, sorted by ID
+    #[serde(default, rename = "package")]
+    pub packages: Vec<LockedPackage>,
+}
+
+impl Default for Lockfile {
+    fn default() -> Self {
+        Self {
+            version: LOCKFILE_VERSION,
+            packages: Vec::new(),
+        }
+    }
+}
+
+impl Lockfile {
+    /// Loads a lockfile, returning `None` if it doesn't exist.
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read lockfile: {}", path.display()))?;
+        let lock: Self = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse lockfile: {}", path.display()))?;
+        if lock.version > LOCKFILE_VERSION {
+            anyhow::bail!(
+                "Lockfile {} has format version {}; this legalis supports up to {}",
+                path.display(),
+                lock.version,
+                LOCKFILE_VERSION
+            );
+        }
+        Ok(Some(lock))
+    }
+
+    /// Writes the lockfile.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let body = toml::to_string(self).context("Failed to serialize lockfile")?;
+        let content = format!(
+            "# This file is generated by legalis. It is not intended for manual editing.\n{}",
+            body
+        );
+        fs::write(path, content)
+            .with_context(|| format!("Failed to write lockfile: {}", path.display()))
+    }
+
+    /// Returns the locked entry of a statute.
+    pub fn get(&self, statute_id: &str) -> Option<&LockedPackage> {
+        self.packages.iter().find(|p| p.id == statute_id)
+    }
+
+    /// Lists version changes from `old` to `self` as `(id, old, new)`.
+    pub fn changes_from(&self, old: &Lockfile) -> Vec<(String, Option<u32>, Option<u32>)> {
+        let ids: BTreeSet<&str> = self
+            .packages
+            .iter()
+            .chain(&old.packages)
+            .map(|p| p.id.as_str())
+            .collect();
+        ids.into_iter()
+            .filter_map(|id| {
+                let before = old.get(id).map(|p| p.version);
+                let after = self.get(id).map(|p| p.version);
+                (before != after).then(|| (id.to_string(), before, after))
+            })
+            .collect()
+    }
+}
+
+/// Returns the integrity string recorded for a statute.
+///
+/// # Example
+///
+/// ```
+/// use legalis::package::integrity;
+/// use legalis_core::{Effect, EffectType, Statute};
+///
+/// let statute = Statute::new("s-1", "Benefit", Effect::new(EffectType::Grant, "Grant"));
+/// assert!(integrity(&statute).starts_with("sha256-"));
+/// ```
+pub fn integrity(statute: &Statute) -> String {
+    format!("sha256-{}", StatuteHasher::hash(statute))
+}
+
+/// Which locked versions a resolution may change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Unlock {
+    /// Keep every locked version that still satisfies the manifest
+    None,
+    /// Re-resolve every statute to its newest matching version
+    All,
+    /// Re-resolve only the given statute
+    Only(String),
+}
+
+impl Unlock {
+    fn covers(&self, statute_id: &str) -> bool {
+        match self {
+            Self::None => false,
+            Self::All => true,
+            Self::Only(id) => id == statute_id,
+        }
+    }
+}
+
+/// Result of resolving a manifest.
+#[derive(Debug, Clone)]
+pub struct Resolution {
+    pub lock: Lockfile,
+    /// Fetched packages, keyed by statute ID
+    pub packages: BTreeMap<String, Package>,
+}
+
+/// Resolves a manifest's dependencies, and the statutes they derive from,
+/// against a registry.
+///
+/// Locked versions are kept while they satisfy every requirement, unless
+/// `unlock` covers them; otherwise the newest matching version is chosen. A
+/// kept version whose content no longer matches its locked integrity hash is
+/// an error.
+pub async fn resolve(
+    client: &RegistryClient,
+    manifest: &Manifest,
+    lock: Option<&Lockfile>,
+    unlock: &Unlock,
+) -> Result<Resolution> {
+    let mut requirements: BTreeMap<String, Vec<(VersionReq, String)>> = BTreeMap::new();
+    let mut queue = VecDeque::new();
+    for (id, req) in &manifest.dependencies {
+        requirements
+            .entry(id.clone())
+            .or_default()
+            .push((req.clone(), MANIFEST_FILE.to_string()));
+        queue.push_back(id.clone());
+    }
+
+    let mut locked_packages = Vec::new();
+    let mut packages = BTreeMap::new();
+    while let Some(id) = queue.pop_front() {
+        if packages.contains_key(&id) {
+            continue;
+        }
+        let reqs = &requirements[&id];
+        let available = match client.versions(&id).await {
+            Ok(summaries) => summaries.into_iter().map(|s| s.version).collect(),
+            Err(RegistryClientError::NotFound(_)) => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let matching: Vec<u32> = available
+            .iter()
+            .copied()
+            .filter(|v| reqs.iter().all(|(req, _)| req.matches(*v)))
+            .collect();
+
+        let locked = lock
+            .filter(|_| !unlock.covers(&id))
+            .and_then(|lock| lock.get(&id))
+            .filter(|locked| matching.contains(&locked.version));
+        let Some(version) = locked
+            .map(|locked| locked.version)
+            .or_else(|| matching.iter().copied().max())
+        else {
+            let wanted: Vec<String> = reqs
+                .iter()
+                .map(|(req, by)| format!("{} (required by {})", req, by))
+                .collect();
+            if available.is_empty() {
+                anyhow::bail!("'{}' is not in the registry: {}", id, wanted.join("; "));
+            }
+            anyhow::bail!(
+                "No version of '{}' satisfies {}; available: {:?}",
+                id,
+                wanted.join("; "),
+                available
+            );
+        };
+
+        let package = client.fetch(&id, Some(version)).await?;
+        let hash = integrity(&package.statute);
+        if let Some(locked) = locked
+            && locked.integrity != hash
+        {
+            anyhow::bail!(
+                "Integrity check failed for {} v{}: legalis.lock has {}, registry served {}",
+                id,
+                version,
+                locked.integrity,
+                hash
+            );
+        }
+
+        let mut dependencies = package.statute.derives_from.clone();
+        dependencies.sort();
+        dependencies.dedup();
+        for dependency in &dependencies {
+            requirements
+                .entry(dependency.clone())
+                .or_default()
+                .push((VersionReq::any(), id.clone()));
+            queue.push_back(dependency.clone());
+        }
+
+        locked_packages.push(LockedPackage {
+            id: id.clone(),
+            version,
+            registry: client.base_url().to_string(),
+            integrity: hash,
+            dependencies,
+        });
+        packages.insert(id, package);
+    }
+
+    locked_packages.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(Resolution {
+        lock: Lockfile {
+            version: LOCKFILE_VERSION,
+            packages: locked_packages,
+        },
+        packages,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use legalis_api::registry_protocol::PushRequest;
+    use legalis_api::{AppState, create_router};
+    use legalis_core::{Effect, EffectType};
+    use std::sync::Arc;
+
+    async fn serve() -> RegistryClient {
+        let app = create_router(Arc::new(AppState::new()));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        RegistryClient::new(format!("http://{}", addr)).with_token("lgl_12345678901234567890")
+    }
+
+    async fn publish(client: &RegistryClient, statute: Statute, base: Option<u32>) {
+        let mut request = PushRequest::new(statute);
+        if let Some(base) = base {
+            request = request.with_base_version(base);
+        }
+        client.push(&request).await.unwrap();
+    }
+
+    fn statute(id: &str, title: &str) -> Statute {
+        Statute::new(id, title, Effect::new(EffectType::Grant, "Benefit"))
+    }
+
+    #[test]
+    fn test_version_req() {
+        let req: VersionReq = ">= 2, <4".parse().unwrap();
+        assert_eq!(req.to_string(), ">=2, <4");
+        assert_eq!(req.best_match([1, 2, 3, 4]), Some(3));
+        assert!("=3".parse::<VersionReq>().unwrap().matches(3));
+        assert!(!">3".parse::<VersionReq>().unwrap().matches(3));
+        assert!("<=3".parse::<VersionReq>().unwrap().matches(3));
+        assert!("*".parse::<VersionReq>().unwrap().matches(42));
+        assert!("~2".parse::<VersionReq>().is_err());
+        assert!(">=x".parse::<VersionReq>().is_err());
+    }
+
+    #[test]
+    fn test_manifest_keeps_other_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(MANIFEST_FILE);
+        fs::write(
+            &path,
+            "jurisdiction = \"JP\"\n\n[package]\nname = \"welfare\"\nregistry = \"http://r\"\n",
+        )
+        .unwrap();
+
+        let manifest = Manifest::add_dependency(&path, "pension", &VersionReq::caret(2)).unwrap();
+        assert_eq!(manifest.registry(), Some("http://r"));
+        assert_eq!(manifest.dependencies["pension"], VersionReq::caret(2));
+
+        let mut config = crate::config::Config::from_file(&path).unwrap();
+        assert_eq!(config.jurisdiction.as_deref(), Some("JP"));
+
+        // Saving the CLI settings keeps the manifest tables
+        config.jurisdiction = Some("US".to_string());
+        config.save(&path).unwrap();
+        let manifest = Manifest::load(&path).unwrap();
+        assert_eq!(manifest.dependencies["pension"], VersionReq::caret(2));
+        assert_eq!(
+            crate::config::Config::from_file(&path)
+                .unwrap()
+                .jurisdiction
+                .as_deref(),
+            Some("US")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_and_lock() {
+        let client = serve().await;
+        publish(&client, statute("base", "Base"), None).await;
+        publish(&client, statute("pension", "V1"), None).await;
+        publish(
+            &client,
+            statute("pension", "V2").with_derives_from("base"),
+            Some(1),
+        )
+        .await;
+
+        let mut manifest = Manifest::default();
+        manifest
+            .dependencies
+            .insert("pension".to_string(), ">=1, <3".parse().unwrap());
+        let resolution = resolve(&client, &manifest, None, &Unlock::None)
+            .await
+            .unwrap();
+        let lock = resolution.lock;
+        assert_eq!(lock.get("pension").unwrap().version, 2);
+        assert_eq!(lock.get("pension").unwrap().dependencies, vec!["base"]);
+        assert_eq!(lock.get("base").unwrap().version, 1);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(LOCKFILE);
+        lock.save(&path).unwrap();
+        assert_eq!(Lockfile::load(&path).unwrap().as_ref(), Some(&lock));
+
+        // New versions don't move locked statutes until they are unlocked
+        publish(&client, statute("base", "Base v2"), Some(1)).await;
+        let kept = resolve(&client, &manifest, Some(&lock), &Unlock::None)
+            .await
+            .unwrap();
+        assert_eq!(kept.lock, lock);
+        let updated = resolve(&client, &manifest, Some(&lock), &Unlock::All)
+            .await
+            .unwrap();
+        assert_eq!(
+            updated.lock.changes_from(&lock),
+            vec![("base".to_string(), Some(1), Some(2))]
+        );
+
+        let mut tampered = lock.clone();
+        tampered.packages[0].integrity = "sha256-0".to_string();
+        let err = resolve(&client, &manifest, Some(&tampered), &Unlock::None)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Integrity check failed"));
+
+        manifest
+            .dependencies
+            .insert("pension".to_string(), ">5".parse().unwrap());
+        assert!(
+            resolve(&client, &manifest, None, &Unlock::None)
+                .await
+                .is_err()
+        );
+    }
+}
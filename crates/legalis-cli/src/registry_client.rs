@@ -0,0 +1,535 @@
+//! Client for the registry protocol served by `legalis-api`.
+//!
+//! This module provides:
+//! - [`RegistryClient`], an authenticated HTTP client for the
+//!   `/api/v1/registry` endpoints
+//! - [`SyncState`], the per-directory record of which registry version each
+//!   local statute was last synchronized with
+//! - [`plan_sync`], which compares local files, remote packages and the sync
+//!   state to decide what `legalis registry sync` has to do
+
+use crate::config::RegistryCredential;
+use anyhow::{Context, Result};
+use legalis_api::registry_protocol::{
+    Package, PackageSummary, PushRequest, PushResponse, WhoAmI, content_hash,
+};
+use legalis_core::Statute;
+use legalis_dsl::LegalDslParser;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Name of the sync state file kept in synchronized directories.
+pub const SYNC_STATE_FILE: &str = ".legalis-sync.json";
+
+/// Errors returned by the registry.
+#[derive(Debug, Error)]
+pub enum RegistryClientError {
+    #[error("Registry request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    #[error("{0}")]
+    Conflict(String),
+
+    #[error("Authentication failed ({status}): {message}")]
+    Unauthorized { status: u16, message: String },
+
+    #[error("Registry returned {status}: {message}")]
+    Status { status: u16, message: String },
+}
+
+/// Response envelope used by every API endpoint.
+#[derive(Deserialize)]
+struct Envelope<T> {
+    data: T,
+}
+
+/// Authenticated client for a remote registry.
+#[derive(Debug, Clone)]
+pub struct RegistryClient {
+    base_url: String,
+    token: Option<String>,
+    http: reqwest::Client,
+}
+
+impl RegistryClient {
+    /// Creates an unauthenticated client for the registry at `base_url`.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            token: None,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Authenticates requests with an API key (`lgl_...`) or bearer token.
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Authenticates requests with a stored credential, if any.
+    pub fn with_credential(self, credential: Option<&RegistryCredential>) -> Self {
+        match credential {
+            Some(credential) => self.with_token(credential.token.clone()),
+            None => self,
+        }
+    }
+
+    /// Returns the registry URL.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the user the registry authenticates this client as.
+    pub async fn whoami(&self) -> Result<WhoAmI, RegistryClientError> {
+        self.send(self.request(reqwest::Method::GET, "whoami"))
+            .await
+    }
+
+    /// Lists the packages visible to this client.
+    pub async fn list(&self) -> Result<Vec<PackageSummary>, RegistryClientError> {
+        self.send(self.request(reqwest::Method::GET, "packages"))
+            .await
+    }
+
+    /// Fetches a package, optionally pinned to a version.
+    pub async fn fetch(
+        &self,
+        statute_id: &str,
+        version: Option<u32>,
+    ) -> Result<Package, RegistryClientError> {
+        let path = match version {
+            Some(version) => format!("packages/{}?version={}", statute_id, version),
+            None => format!("packages/{}", statute_id),
+        };
+        self.send(self.request(reqwest::Method::GET, &path)).await
+    }
+
+    /// Lists every published version of a package, oldest first.
+    pub async fn versions(
+        &self,
+        statute_id: &str,
+    ) -> Result<Vec<PackageSummary>, RegistryClientError> {
+        let path = format!("packages/{}/versions", statute_id);
+        self.send(self.request(reqwest::Method::GET, &path)).await
+    }
+
+    /// Fetches the latest version of a package, or `None` if it doesn't exist.
+    pub async fn find(&self, statute_id: &str) -> Result<Option<Package>, RegistryClientError> {
+        match self.fetch(statute_id, None).await {
+            Ok(package) => Ok(Some(package)),
+            Err(RegistryClientError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Publishes a statute.
+    ///
+    /// Fails with [`RegistryClientError::Conflict`] if the registry has moved
+    /// past the request's base version.
+    pub async fn push(&self, request: &PushRequest) -> Result<PushResponse, RegistryClientError> {
+        let path = format!("packages/{}", request.statute.id);
+        self.send(self.request(reqwest::Method::PUT, &path).json(request))
+            .await
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let url = format!("{}/api/v1/registry/{}", self.base_url, path);
+        let request = self.http.request(method, url);
+        match &self.token {
+            Some(token) if token.starts_with("lgl_") => {
+                request.header("Authorization", format!("ApiKey {}", token))
+            }
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+
+    async fn send<T: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<T, RegistryClientError> {
+        let response = request.send().await?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response.json::<Envelope<T>>().await?.data);
+        }
+
+        let body = response.text().await.unwrap_or_default();
+        let message = serde_json::from_str::<serde_json::Value>(&body)
+            .ok()
+            .and_then(|value| value["error"].as_str().map(String::from))
+            .unwrap_or(body);
+        Err(match status {
+            reqwest::StatusCode::NOT_FOUND => RegistryClientError::NotFound(message),
+            reqwest::StatusCode::CONFLICT => RegistryClientError::Conflict(message),
+            reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN => {
+                RegistryClientError::Unauthorized {
+                    status: status.as_u16(),
+                    message,
+                }
+            }
+            _ => RegistryClientError::Status {
+                status: status.as_u16(),
+                message,
+            },
+        })
+    }
+}
+
+/// What a directory was last synchronized with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncedPackage {
+    /// Registry version the local file matched
+    pub version: u32,
+    /// Content hash of the local file at that point
+    pub local_hash: String,
+}
+
+/// Sync state of a directory, keyed by registry URL and statute ID.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyncState {
+    #[serde(default)]
+    pub registries: BTreeMap<String, BTreeMap<String, SyncedPackage>>,
+}
+
+impl SyncState {
+    /// Loads the sync state of a directory; a missing file is an empty state.
+    pub fn load(directory: &Path) -> Result<Self> {
+        let path = directory.join(SYNC_STATE_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read sync state: {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse sync state: {}", path.display()))
+    }
+
+    /// Writes the sync state into a directory.
+    pub fn save(&self, directory: &Path) -> Result<()> {
+        let path = directory.join(SYNC_STATE_FILE);
+        fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write sync state: {}", path.display()))
+    }
+
+    /// Returns what a statute was last synchronized with.
+    pub fn get(&self, registry: &str, statute_id: &str) -> Option<&SyncedPackage> {
+        self.registries.get(registry)?.get(statute_id)
+    }
+
+    /// Records that a statute was synchronized.
+    pub fn record(&mut self, registry: &str, statute_id: &str, synced: SyncedPackage) {
+        self.registries
+            .entry(registry.to_string())
+            .or_default()
+            .insert(statute_id.to_string(), synced);
+    }
+}
+
+/// A parsed local statute file.
+#[derive(Debug, Clone)]
+pub struct LocalStatute {
+    pub path: PathBuf,
+    pub source: String,
+    pub statute: Statute,
+    pub hash: String,
+}
+
+impl LocalStatute {
+    /// Reads and parses a statute file.
+    pub fn read(path: &Path) -> Result<Self> {
+        let source = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read statute file: {}", path.display()))?;
+        let statute = LegalDslParser::new()
+            .parse_statute(&source)
+            .map_err(|e| anyhow::anyhow!("Parse error in {}: {}", path.display(), e))?;
+        let hash = content_hash(&statute);
+        Ok(Self {
+            path: path.to_path_buf(),
+            source,
+            statute,
+            hash,
+        })
+    }
+
+    /// Reads every `.ldsl` and `.legal` file in a directory, keyed by statute ID.
+    pub fn scan(directory: &Path) -> Result<BTreeMap<String, Self>> {
+        let mut statutes = BTreeMap::new();
+        for entry in fs::read_dir(directory)
+            .with_context(|| format!("Failed to read directory: {}", directory.display()))?
+        {
+            let path = entry?.path();
+            let extension = path.extension().and_then(|s| s.to_str());
+            if path.is_file() && matches!(extension, Some("ldsl") | Some("legal")) {
+                let local = Self::read(&path)?;
+                statutes.insert(local.statute.id.clone(), local);
+            }
+        }
+        Ok(statutes)
+    }
+}
+
+/// One step of a sync.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncAction {
+    /// Remote changed (or is new); local file is unchanged or missing
+    Pull { statute_id: String, version: u32 },
+    /// Local changed (or is new); remote is unchanged or missing
+    Push {
+        statute_id: String,
+        base_version: Option<u32>,
+    },
+    /// Both sides changed since the last sync
+    Conflict {
+        statute_id: String,
+        remote_version: u32,
+    },
+    /// Both sides hold the same content at this version
+    InSync { statute_id: String, version: u32 },
+}
+
+/// Decides what to do with every statute known locally or remotely.
+///
+/// Statutes deleted on one side after a sync are left alone: deletions are
+/// not propagated.
+pub fn plan_sync(
+    registry: &str,
+    local: &BTreeMap<String, LocalStatute>,
+    remote: &[PackageSummary],
+    state: &SyncState,
+) -> Vec<SyncAction> {
+    let remote: BTreeMap<&str, &PackageSummary> = remote
+        .iter()
+        .map(|summary| (summary.statute_id.as_str(), summary))
+        .collect();
+    let ids: BTreeSet<&str> = local
+        .keys()
+        .map(String::as_str)
+        .chain(remote.keys().copied())
+        .collect();
+
+    let mut actions = Vec::new();
+    for id in ids {
+        let statute_id = id.to_string();
+        let synced = state.get(registry, id);
+        let action = match (local.get(id), remote.get(id)) {
+            (Some(local), Some(remote)) => {
+                let local_changed = synced.is_none_or(|s| s.local_hash != local.hash);
+                let remote_changed = synced.is_none_or(|s| s.version != remote.version);
+                if local.hash == remote.content_hash {
+                    SyncAction::InSync {
+                        statute_id,
+                        version: remote.version,
+                    }
+                } else if !local_changed {
+                    SyncAction::Pull {
+                        statute_id,
+                        version: remote.version,
+                    }
+                } else if !remote_changed {
+                    SyncAction::Push {
+                        statute_id,
+                        base_version: Some(remote.version),
+                    }
+                } else {
+                    SyncAction::Conflict {
+                        statute_id,
+                        remote_version: remote.version,
+                    }
+                }
+            }
+            (Some(_), None) if synced.is_none() => SyncAction::Push {
+                statute_id,
+                base_version: None,
+            },
+            (None, Some(remote)) if synced.is_none() => SyncAction::Pull {
+                statute_id,
+                version: remote.version,
+            },
+            _ => continue,
+        };
+        actions.push(action);
+    }
+    actions
+}
+
+/// Writes a pulled package into a directory and returns the file path.
+///
+/// The pushed DSL source is written verbatim when available; otherwise the
+/// statute is pretty-printed.
+pub fn write_package(package: &Package, path: &Path) -> Result<LocalStatute> {
+    let source = package
+        .source
+        .clone()
+        .unwrap_or_else(|| legalis_dsl::format_statute(&package.statute));
+    fs::write(path, &source)
+        .with_context(|| format!("Failed to write statute file: {}", path.display()))?;
+    LocalStatute::read(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use legalis_api::{AppState, create_router};
+    use std::sync::Arc;
+
+    const API_KEY: &str = "lgl_12345678901234567890";
+
+    const SOURCE: &str = r#"STATUTE adult-benefit: "Adult Benefit" {
+    WHEN AGE >= 18
+    THEN GRANT "Benefit"
+}
+"#;
+
+    async fn serve() -> String {
+        let app = create_router(Arc::new(AppState::new()));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_push_pull_round_trip() {
+        let url = serve().await;
+        let client = RegistryClient::new(&url).with_token(API_KEY);
+        assert!(client.whoami().await.unwrap().can_publish);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("benefit.ldsl");
+        fs::write(&path, SOURCE).unwrap();
+        let local = LocalStatute::read(&path).unwrap();
+
+        let request = PushRequest::new(local.statute.clone()).with_source(&local.source);
+        let pushed = client.push(&request).await.unwrap();
+        assert_eq!(pushed.package.version, 1);
+        assert_eq!(pushed.package.content_hash, local.hash);
+
+        // Pushing again without a base version is a stale push
+        let mut amended = local.statute.clone();
+        amended.title = "Amended".to_string();
+        let stale = PushRequest::new(amended.clone());
+        assert!(matches!(
+            client.push(&stale).await,
+            Err(RegistryClientError::Conflict(_))
+        ));
+        let pushed = client
+            .push(&PushRequest::new(amended).with_base_version(1))
+            .await
+            .unwrap();
+        assert_eq!(pushed.package.version, 2);
+
+        let pinned = client.fetch("adult-benefit", Some(1)).await.unwrap();
+        assert_eq!(pinned.source.as_deref(), Some(SOURCE));
+        let pulled = write_package(&pinned, &dir.path().join("pulled.ldsl")).unwrap();
+        assert_eq!(pulled.hash, local.hash);
+
+        assert!(client.find("missing").await.unwrap().is_none());
+        let anonymous = RegistryClient::new(&url);
+        assert!(matches!(
+            anonymous.list().await,
+            Err(RegistryClientError::Unauthorized { status: 401, .. })
+        ));
+    }
+
+    #[test]
+    fn test_plan_sync() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("benefit.ldsl"), SOURCE).unwrap();
+        let local = LocalStatute::scan(dir.path()).unwrap();
+        let hash = local["adult-benefit"].hash.clone();
+        let summary = |id: &str, version: u32, content_hash: &str| PackageSummary {
+            statute_id: id.to_string(),
+            title: id.to_string(),
+            version,
+            content_hash: content_hash.to_string(),
+            tags: Vec::new(),
+            visibility: Default::default(),
+            modified_at: chrono::Utc::now(),
+        };
+        let registry = "http://registry";
+
+        // Nothing synced yet: local-only pushes, remote-only pulls
+        let remote = vec![summary("other", 3, "x")];
+        let actions = plan_sync(registry, &local, &remote, &SyncState::default());
+        assert_eq!(
+            actions,
+            vec![
+                SyncAction::Push {
+                    statute_id: "adult-benefit".to_string(),
+                    base_version: None
+                },
+                SyncAction::Pull {
+                    statute_id: "other".to_string(),
+                    version: 3
+                },
+            ]
+        );
+
+        let mut state = SyncState::default();
+        state.record(
+            registry,
+            "adult-benefit",
+            SyncedPackage {
+                version: 1,
+                local_hash: hash.clone(),
+            },
+        );
+
+        // Remote moved on, local untouched
+        let remote = vec![summary("adult-benefit", 2, "new")];
+        assert_eq!(
+            plan_sync(registry, &local, &remote, &state),
+            vec![SyncAction::Pull {
+                statute_id: "adult-benefit".to_string(),
+                version: 2
+            }]
+        );
+
+        // Local edited, remote untouched
+        state.record(
+            registry,
+            "adult-benefit",
+            SyncedPackage {
+                version: 2,
+                local_hash: "old".to_string(),
+            },
+        );
+        assert_eq!(
+            plan_sync(registry, &local, &remote, &state),
+            vec![SyncAction::Push {
+                statute_id: "adult-benefit".to_string(),
+                base_version: Some(2)
+            }]
+        );
+
+        // Both edited
+        let remote = vec![summary("adult-benefit", 3, "newer")];
+        assert_eq!(
+            plan_sync(registry, &local, &remote, &state),
+            vec![SyncAction::Conflict {
+                statute_id: "adult-benefit".to_string(),
+                remote_version: 3
+            }]
+        );
+
+        // Same content on both sides, and deletions are left alone
+        let remote = vec![summary("adult-benefit", 3, &hash)];
+        assert_eq!(
+            plan_sync(registry, &local, &remote, &state),
+            vec![SyncAction::InSync {
+                statute_id: "adult-benefit".to_string(),
+                version: 3
+            }]
+        );
+        assert!(plan_sync(registry, &local, &[], &state).is_empty());
+    }
+}
@@ -0,0 +1,942 @@
+//! Statute acceptance tests for the `legalis test` command.
+//!
+//! A test file describes cases as entity facts, an optional evaluation date
+//! and the expected outcome. Cases run against the core evaluator (through
+//! the simulation engine, so temporal validity and exceptions apply) and
+//! produce a report with diffs, JUnit/TAP output and condition coverage.
+//!
+//! ```yaml
+//! statute: pension
+//! date: 2026-04-01
+//! tests:
+//!   - name: retiree qualifies
+//!     facts:
+//!       age: 67
+//!       income: 12000
+//!     expect:
+//!       outcome: applies
+//!       outputs:
+//!         amount: 1200.00 JPY
+//!   - name: worker does not qualify
+//!     facts: { age: 40 }
+//!     expect: void
+//! ```
+
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use legalis_core::decimal::RoundingMode;
+use legalis_core::{
+    BasicEntity, Condition, Decimal, EntityContext, LegalEntity, LegalResult, OutputValue, Statute,
+};
+use legalis_sim::{ApplicationReason, LawApplicationResult, SimEngine};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::Instant;
+
+/// Expected outcome of applying a statute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExpectedOutcome {
+    /// The statute applies deterministically.
+    #[serde(alias = "deterministic")]
+    Applies,
+    /// The statute does not apply.
+    Void,
+    /// The case requires judicial discretion.
+    Discretion,
+}
+
+impl std::fmt::Display for ExpectedOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Applies => write!(f, "applies"),
+            Self::Void => write!(f, "void"),
+            Self::Discretion => write!(f, "discretion"),
+        }
+    }
+}
+
+/// Expectation for a single test case.
+///
+/// Written either as a bare outcome (`expect: void`) or as a table with
+/// expected outputs and effect description.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "ExpectationRepr")]
+pub struct Expectation {
+    /// Expected outcome
+    pub outcome: ExpectedOutcome,
+    /// Expected effect outputs, compared against their computed values
+    pub outputs: BTreeMap<String, String>,
+    /// Expected effect description (substring match)
+    pub effect: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ExpectationRepr {
+    Outcome(ExpectedOutcome),
+    Full {
+        outcome: ExpectedOutcome,
+        #[serde(default)]
+        outputs: BTreeMap<String, serde_json::Value>,
+        #[serde(default)]
+        effect: Option<String>,
+    },
+}
+
+impl From<ExpectationRepr> for Expectation {
+    fn from(repr: ExpectationRepr) -> Self {
+        match repr {
+            ExpectationRepr::Outcome(outcome) => Self {
+                outcome,
+                outputs: BTreeMap::new(),
+                effect: None,
+            },
+            ExpectationRepr::Full {
+                outcome,
+                outputs,
+                effect,
+            } => Self {
+                outcome,
+                outputs: outputs
+                    .into_iter()
+                    .map(|(name, value)| (name, fact_to_string(value)))
+                    .collect(),
+                effect,
+            },
+        }
+    }
+}
+
+/// A single statute test case.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TestCase {
+    /// Case name
+    #[serde(alias = "description")]
+    pub name: String,
+    /// Statute under test; defaults to the suite statute, then the first statute
+    #[serde(default, alias = "statute_id")]
+    pub statute: Option<String>,
+    /// Evaluation date; defaults to the suite date, then the `current_date` fact
+    #[serde(default)]
+    pub date: Option<NaiveDate>,
+    /// Entity attributes
+    #[serde(default)]
+    pub facts: BTreeMap<String, serde_json::Value>,
+    /// Expected result
+    #[serde(default)]
+    pub expect: Option<Expectation>,
+    /// Legacy shorthand for the `age` fact
+    #[serde(default)]
+    pub age: Option<u32>,
+    /// Legacy shorthand for the `income` fact
+    #[serde(default)]
+    pub income: Option<u64>,
+    /// Legacy shorthand for an `applies` expectation with this effect
+    #[serde(default)]
+    pub expected_effect: Option<String>,
+}
+
+impl TestCase {
+    /// Returns the expectation, folding in the legacy `expected_effect` field.
+    pub fn expectation(&self) -> Option<Expectation> {
+        self.expect.clone().or_else(|| {
+            self.expected_effect.as_ref().map(|effect| Expectation {
+                outcome: ExpectedOutcome::Applies,
+                outputs: BTreeMap::new(),
+                effect: Some(effect.clone()),
+            })
+        })
+    }
+
+    /// Builds the entity described by the case facts.
+    pub fn entity(&self) -> BasicEntity {
+        let mut entity = BasicEntity::new();
+        if let Some(age) = self.age {
+            entity.set_attribute("age", age.to_string());
+        }
+        if let Some(income) = self.income {
+            entity.set_attribute("income", income.to_string());
+        }
+        for (key, value) in &self.facts {
+            entity.set_attribute(key, fact_to_string(value.clone()));
+        }
+        entity
+    }
+}
+
+/// A file of statute test cases.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "TestSuiteRepr")]
+pub struct TestSuite {
+    /// Default statute for cases that do not name one
+    pub statute: Option<String>,
+    /// Default evaluation date
+    pub date: Option<NaiveDate>,
+    /// Test cases in file order
+    pub tests: Vec<TestCase>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TestSuiteRepr {
+    List(Vec<TestCase>),
+    Suite {
+        #[serde(default, alias = "statute_id")]
+        statute: Option<String>,
+        #[serde(default)]
+        date: Option<NaiveDate>,
+        tests: Vec<TestCase>,
+    },
+}
+
+impl From<TestSuiteRepr> for TestSuite {
+    fn from(repr: TestSuiteRepr) -> Self {
+        match repr {
+            TestSuiteRepr::List(tests) => Self {
+                statute: None,
+                date: None,
+                tests,
+            },
+            TestSuiteRepr::Suite {
+                statute,
+                date,
+                tests,
+            } => Self {
+                statute,
+                date,
+                tests,
+            },
+        }
+    }
+}
+
+impl TestSuite {
+    /// Parses a suite from YAML.
+    pub fn from_yaml(content: &str) -> Result<Self> {
+        serde_yaml::from_str(content).context("Failed to parse YAML test file")
+    }
+
+    /// Parses a suite from TOML (cases under `[[tests]]`).
+    pub fn from_toml(content: &str) -> Result<Self> {
+        toml::from_str(content).context("Failed to parse TOML test file")
+    }
+
+    /// Loads a suite, choosing the format from the file extension.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read test file: {}", path.display()))?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml(&content),
+            _ => Self::from_yaml(&content),
+        }
+    }
+}
+
+/// One expected-versus-actual difference.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Mismatch {
+    /// What was compared (`outcome`, `outputs.amount`, `effect`)
+    pub field: String,
+    /// Expected value
+    pub expected: String,
+    /// Actual value
+    pub actual: String,
+}
+
+/// Result of running one test case.
+#[derive(Debug, Clone, Serialize)]
+pub struct CaseResult {
+    /// Case name
+    pub name: String,
+    /// Statute the case ran against
+    pub statute_id: String,
+    /// Date the case was evaluated on; `None` when neither the case, the
+    /// suite nor a `current_date` fact gives one
+    pub date: Option<NaiveDate>,
+    /// Observed outcome
+    pub outcome: ExpectedOutcome,
+    /// Why the statute did or did not apply
+    pub reason: String,
+    /// Differences from the expectation; empty when the case passed
+    pub mismatches: Vec<Mismatch>,
+    /// Set when the case could not be run at all
+    pub error: Option<String>,
+    /// Wall-clock time in seconds
+    pub duration_secs: f64,
+}
+
+impl CaseResult {
+    /// Returns true if the case passed.
+    pub fn passed(&self) -> bool {
+        self.error.is_none() && self.mismatches.is_empty()
+    }
+
+    /// Renders the failure as a unified-style diff.
+    pub fn diff(&self) -> String {
+        let mut out = String::new();
+        if let Some(error) = &self.error {
+            let _ = writeln!(out, "error: {}", error);
+        }
+        for mismatch in &self.mismatches {
+            let _ = writeln!(out, "- {}: {}", mismatch.field, mismatch.expected);
+            let _ = writeln!(out, "+ {}: {}", mismatch.field, mismatch.actual);
+        }
+        out
+    }
+}
+
+/// How often one condition was exercised across the suite.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConditionCoverage {
+    /// Statute the condition belongs to
+    pub statute_id: String,
+    /// Condition as written
+    pub condition: String,
+    /// Times the condition evaluated to true
+    pub true_count: usize,
+    /// Times the condition evaluated to false
+    pub false_count: usize,
+    /// Times the condition could not be evaluated
+    pub error_count: usize,
+}
+
+impl ConditionCoverage {
+    /// Returns true if any case reached this condition.
+    pub fn exercised(&self) -> bool {
+        self.true_count + self.false_count + self.error_count > 0
+    }
+
+    /// Returns true if both outcomes were observed.
+    pub fn fully_covered(&self) -> bool {
+        self.true_count > 0 && self.false_count > 0
+    }
+}
+
+/// Condition coverage over all statutes under test.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CoverageReport {
+    /// Every condition node of every statute, in statute order
+    pub conditions: Vec<ConditionCoverage>,
+}
+
+impl CoverageReport {
+    fn for_statutes(statutes: &[Statute]) -> Self {
+        let mut conditions = Vec::new();
+        for statute in statutes {
+            let mut seen = Vec::new();
+            let exceptions = statute.exceptions.iter().map(|e| &e.condition);
+            for condition in statute.preconditions.iter().chain(exceptions) {
+                collect_conditions(condition, &mut seen);
+            }
+            conditions.extend(seen.into_iter().map(|condition| ConditionCoverage {
+                statute_id: statute.id.clone(),
+                condition,
+                true_count: 0,
+                false_count: 0,
+                error_count: 0,
+            }));
+        }
+        Self { conditions }
+    }
+
+    fn entry(&mut self, statute_id: &str, condition: &str) -> Option<&mut ConditionCoverage> {
+        self.conditions
+            .iter_mut()
+            .find(|c| c.statute_id == statute_id && c.condition == condition)
+    }
+
+    /// Returns the number of conditions reached by at least one case.
+    pub fn exercised(&self) -> usize {
+        self.conditions.iter().filter(|c| c.exercised()).count()
+    }
+
+    /// Returns the percentage of conditions reached by at least one case.
+    pub fn percentage(&self) -> f64 {
+        if self.conditions.is_empty() {
+            100.0
+        } else {
+            self.exercised() as f64 / self.conditions.len() as f64 * 100.0
+        }
+    }
+}
+
+/// Collects each distinct condition node, parents before children.
+fn collect_conditions(condition: &Condition, out: &mut Vec<String>) {
+    let rendered = condition.to_string();
+    if !out.contains(&rendered) {
+        out.push(rendered);
+    }
+    match condition {
+        Condition::And(left, right) | Condition::Or(left, right) => {
+            collect_conditions(left, out);
+            collect_conditions(right, out);
+        }
+        Condition::Not(inner) => collect_conditions(inner, out),
+        _ => {}
+    }
+}
+
+/// Outcome of running a suite.
+#[derive(Debug, Clone, Serialize)]
+pub struct TestReport {
+    /// Per-case results in suite order
+    pub results: Vec<CaseResult>,
+    /// Condition coverage
+    pub coverage: CoverageReport,
+}
+
+impl TestReport {
+    /// Returns the number of passing cases.
+    pub fn passed(&self) -> usize {
+        self.results.iter().filter(|r| r.passed()).count()
+    }
+
+    /// Returns the number of failing cases.
+    pub fn failed(&self) -> usize {
+        self.results.len() - self.passed()
+    }
+
+    /// Renders the report as TAP version 13.
+    pub fn to_tap(&self) -> String {
+        let mut out = String::from("TAP version 13\n");
+        let _ = writeln!(out, "1..{}", self.results.len());
+        for (i, result) in self.results.iter().enumerate() {
+            let status = if result.passed() { "ok" } else { "not ok" };
+            let _ = writeln!(out, "{} {} - {}", status, i + 1, result.name);
+            if !result.passed() {
+                out.push_str("  ---\n");
+                let _ = writeln!(out, "  statute: {}", result.statute_id);
+                if let Some(error) = &result.error {
+                    let _ = writeln!(out, "  error: {:?}", error);
+                }
+                for mismatch in &result.mismatches {
+                    let _ = writeln!(out, "  {}:", mismatch.field);
+                    let _ = writeln!(out, "    expected: {:?}", mismatch.expected);
+                    let _ = writeln!(out, "    actual: {:?}", mismatch.actual);
+                }
+                out.push_str("  ...\n");
+            }
+        }
+        out
+    }
+
+    /// Renders the report as a JUnit XML test suite.
+    pub fn to_junit(&self, suite_name: &str) -> String {
+        let total_time: f64 = self.results.iter().map(|r| r.duration_secs).sum();
+        let errors = self.results.iter().filter(|r| r.error.is_some()).count();
+        let failures = self.failed() - errors;
+
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            out,
+            "<testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" time=\"{:.6}\">",
+            xml_escape(suite_name),
+            self.results.len(),
+            failures,
+            errors,
+            total_time
+        );
+        for result in &self.results {
+            let _ = write!(
+                out,
+                "  <testcase classname=\"{}\" name=\"{}\" time=\"{:.6}\"",
+                xml_escape(&result.statute_id),
+                xml_escape(&result.name),
+                result.duration_secs
+            );
+            if result.passed() {
+                out.push_str("/>\n");
+                continue;
+            }
+            out.push_str(">\n");
+            if let Some(error) = &result.error {
+                let _ = writeln!(out, "    <error message=\"{}\"/>", xml_escape(error));
+            } else {
+                let message = result
+                    .mismatches
+                    .iter()
+                    .map(|m| m.field.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let _ = writeln!(
+                    out,
+                    "    <failure message=\"mismatch in {}\">{}</failure>",
+                    xml_escape(&message),
+                    xml_escape(&result.diff())
+                );
+            }
+            out.push_str("  </testcase>\n");
+        }
+        out.push_str("</testsuite>\n");
+        out
+    }
+}
+
+/// Runs test suites against a set of statutes.
+///
+/// # Example
+///
+/// ```
+/// use legalis::test_runner::{TestRunner, TestSuite};
+/// use legalis_core::{ComparisonOp, Condition, Effect, EffectType, Statute};
+///
+/// let statute = Statute::new("pension", "Pension", Effect::new(EffectType::Grant, "Pension"))
+///     .with_precondition(Condition::age(ComparisonOp::GreaterOrEqual, 65));
+/// let suite = TestSuite::from_yaml(
+///     "- name: retiree\n  facts: { age: 70 }\n  expect: applies\n\
+///      - name: worker\n  facts: { age: 30 }\n  expect: applies\n",
+/// )
+/// .unwrap();
+///
+/// let report = TestRunner::new(&[statute]).run(&suite);
+/// assert_eq!(report.passed(), 1);
+/// assert!(report.results[1].diff().contains("+ outcome: void"));
+/// ```
+pub struct TestRunner<'a> {
+    statutes: &'a [Statute],
+}
+
+impl<'a> TestRunner<'a> {
+    /// Creates a runner over the given statutes.
+    pub fn new(statutes: &'a [Statute]) -> Self {
+        Self { statutes }
+    }
+
+    /// Runs every case in the suite.
+    pub fn run(&self, suite: &TestSuite) -> TestReport {
+        let mut coverage = CoverageReport::for_statutes(self.statutes);
+        let results = suite
+            .tests
+            .iter()
+            .map(|case| self.run_case(suite, case, &mut coverage))
+            .collect();
+
+        TestReport { results, coverage }
+    }
+
+    fn run_case(
+        &self,
+        suite: &TestSuite,
+        case: &TestCase,
+        coverage: &mut CoverageReport,
+    ) -> CaseResult {
+        let started = Instant::now();
+        let statute_id = case.statute.as_ref().or(suite.statute.as_ref());
+        let statute = match statute_id {
+            Some(id) => self.statutes.iter().find(|s| &s.id == id),
+            None => self.statutes.first(),
+        };
+
+        let Some(statute) = statute else {
+            let statute_id = statute_id.cloned().unwrap_or_default();
+            return CaseResult {
+                name: case.name.clone(),
+                error: Some(format!("Statute not found: '{}'", statute_id)),
+                statute_id,
+                date: None,
+                outcome: ExpectedOutcome::Void,
+                reason: String::new(),
+                mismatches: Vec::new(),
+                duration_secs: started.elapsed().as_secs_f64(),
+            };
+        };
+
+        let entity = case.entity();
+        let date = case.date.or(suite.date);
+        let result = match date {
+            Some(date) => SimEngine::apply_law_at(&entity, statute, date),
+            None => SimEngine::apply_law_with_outputs(&entity, statute),
+        };
+        record_coverage(coverage, statute, &entity, date, &result);
+
+        let outcome = match &result.result {
+            LegalResult::Deterministic(_) => ExpectedOutcome::Applies,
+            LegalResult::Void { .. } => ExpectedOutcome::Void,
+            LegalResult::JudicialDiscretion { .. } => ExpectedOutcome::Discretion,
+        };
+        let (mismatches, error) = match case.expectation() {
+            Some(expectation) => (compare(&expectation, outcome, &result), None),
+            None => (Vec::new(), Some("Test case has no expectation".to_string())),
+        };
+
+        CaseResult {
+            name: case.name.clone(),
+            statute_id: statute.id.clone(),
+            date: result.evaluation_date,
+            outcome,
+            reason: result.reason.to_string(),
+            mismatches,
+            error,
+            duration_secs: started.elapsed().as_secs_f64(),
+        }
+    }
+}
+
+/// Re-evaluates the preconditions and exceptions the evaluator reached and
+/// records each step.
+fn record_coverage(
+    coverage: &mut CoverageReport,
+    statute: &Statute,
+    entity: &BasicEntity,
+    date: Option<NaiveDate>,
+    result: &LawApplicationResult,
+) {
+    if matches!(result.reason, ApplicationReason::NotInForce { .. }) {
+        return;
+    }
+
+    let context = match date {
+        Some(date) => EntityContext::new(entity as &dyn LegalEntity).with_date(date),
+        None => EntityContext::new(entity as &dyn LegalEntity),
+    };
+    for precondition in &statute.preconditions {
+        match record_condition(coverage, &statute.id, precondition, &context) {
+            Some(true) => {}
+            // An unmet precondition voids the statute before exceptions are checked
+            Some(false) => return,
+            None => break,
+        }
+    }
+    // Exceptions are checked in order until one applies or cannot be evaluated
+    for exception in &statute.exceptions {
+        if record_condition(coverage, &statute.id, &exception.condition, &context) != Some(false) {
+            break;
+        }
+    }
+}
+
+/// Records the steps of one condition evaluation, returning its outcome or
+/// `None` if it could not be evaluated.
+fn record_condition(
+    coverage: &mut CoverageReport,
+    statute_id: &str,
+    condition: &Condition,
+    context: &EntityContext<'_>,
+) -> Option<bool> {
+    match condition.evaluate_with_explanation(context) {
+        Ok((holds, explanation)) => {
+            for step in &explanation.steps {
+                if let Some(entry) = coverage.entry(statute_id, &step.condition) {
+                    if step.result {
+                        entry.true_count += 1;
+                    } else {
+                        entry.false_count += 1;
+                    }
+                }
+            }
+            Some(holds)
+        }
+        Err(_) => {
+            if let Some(entry) = coverage.entry(statute_id, &condition.to_string()) {
+                entry.error_count += 1;
+            }
+            None
+        }
+    }
+}
+
+fn compare(
+    expectation: &Expectation,
+    outcome: ExpectedOutcome,
+    result: &LawApplicationResult,
+) -> Vec<Mismatch> {
+    let mut mismatches = Vec::new();
+
+    if expectation.outcome != outcome {
+        mismatches.push(Mismatch {
+            field: "outcome".to_string(),
+            expected: expectation.outcome.to_string(),
+            actual: format!("{} ({})", outcome, result.reason),
+        });
+    }
+
+    if let (Some(expected), LegalResult::Deterministic(effect)) =
+        (&expectation.effect, &result.result)
+        && !effect.description.contains(expected.as_str())
+    {
+        mismatches.push(Mismatch {
+            field: "effect".to_string(),
+            expected: expected.clone(),
+            actual: effect.description.clone(),
+        });
+    }
+
+    for (name, expected) in &expectation.outputs {
+        let actual = result.outputs.get(name);
+        if !actual.is_some_and(|value| output_matches(value, expected)) {
+            mismatches.push(Mismatch {
+                field: format!("outputs.{}", name),
+                expected: expected.clone(),
+                actual: actual.map_or_else(|| "<missing>".to_string(), ToString::to_string),
+            });
+        }
+    }
+
+    mismatches
+}
+
+/// Compares a computed output with its expected text.
+///
+/// Money amounts must match exactly; other numbers are compared at the
+/// expected literal's decimal precision. A trailing unit, when given, must
+/// match the currency or duration unit.
+fn output_matches(actual: &OutputValue, expected: &str) -> bool {
+    let expected = expected.trim();
+    if actual.to_string() == expected {
+        return true;
+    }
+
+    let mut parts = expected.split_whitespace();
+    let (Some(number), Some(value)) = (
+        parts.next().and_then(|n| n.parse::<Decimal>().ok()),
+        actual.as_decimal(),
+    ) else {
+        return false;
+    };
+    let value = match actual {
+        OutputValue::Money { .. } => value,
+        _ => value.round(number.scale(), RoundingMode::HalfUp),
+    };
+    let unit_matches = match parts.next() {
+        Some(unit) => actual
+            .unit()
+            .is_some_and(|actual_unit| actual_unit.eq_ignore_ascii_case(unit)),
+        None => true,
+    };
+
+    unit_matches && parts.next().is_none() && number == value
+}
+
+/// Renders a fact value as an entity attribute string.
+fn fact_to_string(value: serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s,
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::Bool(b) => b.to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use legalis_core::{
+        ComparisonOp, Effect, EffectOutput, EffectType, StatuteException, TemporalValidity,
+    };
+
+    fn pension() -> Statute {
+        Statute::new(
+            "pension",
+            "Pension",
+            Effect::new(EffectType::Grant, "Old-age pension").with_output(EffectOutput::money(
+                "amount",
+                "JPY",
+                "income * 0.1",
+            )),
+        )
+        .with_precondition(
+            Condition::age(ComparisonOp::GreaterOrEqual, 65)
+                .and(Condition::income(ComparisonOp::LessThan, 50000)),
+        )
+        .with_temporal_validity(
+            TemporalValidity::new()
+                .with_effective_date(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
+        )
+    }
+
+    const SUITE: &str = r#"
+statute: pension
+date: 2026-04-01
+tests:
+  - name: retiree qualifies
+    facts:
+      age: 67
+      income: 12000
+    expect:
+      outcome: applies
+      effect: pension
+      outputs:
+        amount: 1200.00 JPY
+  - name: worker does not qualify
+    facts: { age: 40, income: 30000 }
+    expect: void
+  - name: not yet in force
+    date: 2024-06-01
+    facts: { age: 70, income: 1000 }
+    expect: void
+  - name: missing income needs discretion
+    facts: { age: 70 }
+    expect: discretion
+"#;
+
+    #[test]
+    fn test_suite_passes_against_core_evaluator() {
+        let suite = TestSuite::from_yaml(SUITE).unwrap();
+        let report = TestRunner::new(&[pension()]).run(&suite);
+
+        assert_eq!(report.passed(), 4, "{:#?}", report.results);
+        assert_eq!(report.results[2].reason, "Not in force on 2024-06-01");
+        // Every case records the date it ran on, so reports are reproducible
+        assert_eq!(report.results[0].date, NaiveDate::from_ymd_opt(2026, 4, 1));
+        assert_eq!(report.results[2].date, NaiveDate::from_ymd_opt(2024, 6, 1));
+    }
+
+    #[test]
+    fn test_failures_produce_diffs() {
+        let suite = TestSuite::from_yaml(
+            r#"
+- description: wrong outcome
+  statute_id: pension
+  date: 2026-01-01
+  age: 40
+  income: 1000
+  expected_effect: pension
+- name: wrong amount
+  date: 2026-01-01
+  facts: { age: 70, income: 20000 }
+  expect:
+    outcome: applies
+    outputs: { amount: 1500, bonus: 1 }
+- name: unknown statute
+  statute: missing
+  expect: void
+"#,
+        )
+        .unwrap();
+        let report = TestRunner::new(&[pension()]).run(&suite);
+
+        assert_eq!(report.failed(), 3);
+        let diff = report.results[0].diff();
+        assert!(diff.contains("- outcome: applies"));
+        assert!(diff.contains("+ outcome: void (Precondition not met"));
+
+        let fields: Vec<_> = report.results[1]
+            .mismatches
+            .iter()
+            .map(|m| (m.field.as_str(), m.actual.as_str()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("outputs.amount", "2000.00 JPY"),
+                ("outputs.bonus", "<missing>")
+            ]
+        );
+        assert_eq!(
+            report.results[2].error.as_deref(),
+            Some("Statute not found: 'missing'")
+        );
+    }
+
+    #[test]
+    fn test_toml_suite_and_reports() {
+        let suite = TestSuite::from_toml(
+            r#"
+statute = "pension"
+date = "2026-04-01"
+
+[[tests]]
+name = "retiree"
+facts = { age = 80, income = 100 }
+expect = "applies"
+
+[[tests]]
+name = "young <worker>"
+facts = { age = 20, income = 100 }
+expect = "applies"
+"#,
+        )
+        .unwrap();
+        let report = TestRunner::new(&[pension()]).run(&suite);
+
+        let tap = report.to_tap();
+        assert!(tap.starts_with("TAP version 13\n1..2\n"));
+        assert!(tap.contains("ok 1 - retiree"));
+        assert!(tap.contains("not ok 2 - young <worker>"));
+
+        let junit = report.to_junit("pension.toml");
+        assert!(junit.contains("tests=\"2\" failures=\"1\" errors=\"0\""));
+        assert!(junit.contains("name=\"young &lt;worker&gt;\""));
+        assert!(junit.contains("<failure message=\"mismatch in outcome\">"));
+    }
+
+    #[test]
+    fn test_condition_coverage() {
+        let suite = TestSuite::from_yaml(
+            "- name: young\n  date: 2026-01-01\n  facts: { age: 30, income: 10 }\n  expect: void\n",
+        )
+        .unwrap();
+        let report = TestRunner::new(&[pension()]).run(&suite);
+        let coverage = &report.coverage;
+
+        assert_eq!(coverage.conditions.len(), 3);
+        assert_eq!(coverage.exercised(), 2);
+        // Conditions are listed parents first: AND, age, income.
+        assert!(coverage.conditions[1].exercised());
+        assert!(!coverage.conditions[2].exercised());
+
+        let full = TestSuite::from_yaml(SUITE).unwrap();
+        let report = TestRunner::new(&[pension()]).run(&full);
+        assert_eq!(report.coverage.percentage(), 100.0);
+        assert!(report.coverage.conditions[0].fully_covered());
+    }
+
+    #[test]
+    fn test_exception_coverage() {
+        let centenarian = Condition::age(ComparisonOp::GreaterOrEqual, 100);
+        let statute = pension().with_exception(StatuteException::new(
+            "centenarian",
+            "Covered by the centenarian scheme",
+            centenarian.clone(),
+        ));
+        let suite = TestSuite::from_yaml(
+            r#"
+statute: pension
+date: 2026-04-01
+tests:
+  - name: centenarian is excepted
+    facts: { age: 101, income: 100 }
+    expect: void
+  - name: young worker never reaches the exception
+    facts: { age: 30, income: 100 }
+    expect: void
+"#,
+        )
+        .unwrap();
+        let report = TestRunner::new(&[statute]).run(&suite);
+
+        assert_eq!(report.passed(), 2, "{:#?}", report.results);
+        assert!(report.results[0].reason.contains("centenarian"));
+        let exception = report
+            .coverage
+            .conditions
+            .iter()
+            .find(|c| c.condition == centenarian.to_string())
+            .expect("exception condition is tracked");
+        assert_eq!((exception.true_count, exception.false_count), (1, 0));
+    }
+
+    #[test]
+    fn test_output_comparison_precision() {
+        let money = OutputValue::money("957.66".parse().unwrap(), "EUR");
+        assert!(output_matches(&money, "957.66 eur"));
+        assert!(output_matches(&money, "957.660"));
+        assert!(!output_matches(&money, "957.665 EUR"));
+        assert!(!output_matches(&money, "957.7 EUR"));
+        assert!(!output_matches(&money, "957.66 USD"));
+
+        let number = OutputValue::Number(0.1 + 0.2);
+        assert!(output_matches(&number, "0.3"));
+        assert!(!output_matches(&number, "0.31"));
+    }
+}
@@ -497,3 +497,100 @@ fn test_parse_nonexistent_file() {
         .assert()
         .failure();
 }
+
+#[test]
+fn test_test_report_file_has_no_ansi_escapes() {
+    let temp_dir = TempDir::new().unwrap();
+    let statute_file = create_test_statute(&temp_dir, "test.leg", simple_statute_dsl());
+    let tests_file = create_test_statute(
+        &temp_dir,
+        "tests.yaml",
+        "date: 2026-04-01\ntests:\n  - name: minor\n    facts: { age: 12 }\n    expect: applies\n",
+    );
+    let report = temp_dir.path().join("report.txt");
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("legalis"));
+    cmd.env("CLICOLOR_FORCE", "1")
+        .arg("test")
+        .arg("-i")
+        .arg(&statute_file)
+        .arg("-t")
+        .arg(&tests_file)
+        .arg("--verbose")
+        .arg("-o")
+        .arg(&report)
+        .assert()
+        .failure();
+
+    let content = fs::read_to_string(&report).unwrap();
+    assert!(!content.contains('\x1b'), "{:?}", content);
+    assert!(content.contains("void on 2026-04-01"), "{}", content);
+    assert!(content.contains("- outcome: applies"));
+}
+
+#[test]
+fn test_registry_logout_moves_credentials_to_private_file() {
+    let config_home = TempDir::new().unwrap();
+    let user_dir = config_home.path().join("legalis");
+    fs::create_dir_all(&user_dir).unwrap();
+    fs::write(
+        user_dir.join("config.toml"),
+        r#"jurisdiction = "JP"
+
+[registry]
+default = "http://a.example"
+
+[registry.credentials."http://a.example"]
+token = "lgl_a"
+
+[registry.credentials."http://b.example"]
+token = "lgl_b"
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("legalis"));
+    cmd.env("XDG_CONFIG_HOME", config_home.path())
+        .args(["registry", "logout", "--registry", "http://b.example"])
+        .assert()
+        .success();
+
+    let config = fs::read_to_string(user_dir.join("config.toml")).unwrap();
+    assert!(config.contains("JP"));
+    assert!(!config.contains("lgl_"));
+
+    let credentials_file = user_dir.join("credentials.toml");
+    let credentials = fs::read_to_string(&credentials_file).unwrap();
+    assert!(credentials.contains("lgl_a"));
+    assert!(!credentials.contains("lgl_b"));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(&credentials_file)
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
+
+#[test]
+fn test_project_config_cannot_set_registry() {
+    let config_home = TempDir::new().unwrap();
+    let project = TempDir::new().unwrap();
+    fs::write(
+        project.path().join("legalis.toml"),
+        r#"[registry]
+default = "http://attacker.example"
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("legalis"));
+    cmd.current_dir(project.path())
+        .env("XDG_CONFIG_HOME", config_home.path())
+        .args(["registry", "pull", "--statute-id", "some-act"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No registry specified"));
+}
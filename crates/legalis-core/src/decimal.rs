@@ -0,0 +1,561 @@
+//! Exact base-10 numbers for thresholds and monetary amounts.
+//!
+//! Statute thresholds such as `12500.50` must compare exactly, which binary
+//! floating point cannot guarantee (`0.1 + 0.2 != 0.3`). [`Decimal`] keeps an
+//! integer mantissa and a decimal scale and is used by the formula language,
+//! the DSL and decision-table evaluation.
+//!
+//! # Examples
+//!
+//! ```
+//! use legalis_core::decimal::{Decimal, RoundingMode};
+//!
+//! let a: Decimal = "0.1".parse().unwrap();
+//! let b: Decimal = "0.2".parse().unwrap();
+//! assert_eq!(a.checked_add(&b).unwrap(), "0.3".parse().unwrap());
+//!
+//! let half: Decimal = "2.5".parse().unwrap();
+//! assert_eq!(half.round(0, RoundingMode::HalfEven), Decimal::from(2));
+//! ```
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Maximum number of fractional digits a [`Decimal`] keeps.
+pub const MAX_DECIMAL_SCALE: u32 = 28;
+
+/// Errors produced when parsing a [`Decimal`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DecimalError {
+    #[error("Empty decimal literal")]
+    Empty,
+
+    #[error("Invalid decimal literal: {0}")]
+    Invalid(String),
+
+    #[error("Decimal out of range: {0}")]
+    OutOfRange(String),
+}
+
+/// How to round a value that lies between two representable results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Ties go to the even neighbour (banker's rounding): 2.5 → 2, 3.5 → 4
+    HalfEven,
+    /// Ties go away from zero: 2.5 → 3, -2.5 → -3
+    HalfUp,
+    /// Truncate toward zero
+    Down,
+    /// Round toward negative infinity
+    Floor,
+    /// Round toward positive infinity
+    Ceiling,
+}
+
+/// An exact base-10 number.
+///
+/// Values are stored as an integer mantissa and a decimal scale, so
+/// `12500.50` is kept as `1250050 × 10⁻²` and never goes through binary
+/// floating point. The scale written in the source is preserved for display,
+/// while equality, ordering and hashing compare numeric values
+/// (`1.5 == 1.50`).
+///
+/// # Example
+///
+/// ```
+/// use legalis_core::Decimal;
+///
+/// let threshold: Decimal = "12500.50".parse().unwrap();
+/// assert_eq!(threshold.to_string(), "12500.50");
+/// assert_eq!(threshold, "12500.5".parse().unwrap());
+/// assert!(threshold > Decimal::from(12500));
+/// assert!(!threshold.is_integer());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u32,
+}
+
+impl Decimal {
+    /// Zero.
+    pub const ZERO: Decimal = Decimal {
+        mantissa: 0,
+        scale: 0,
+    };
+
+    /// One.
+    pub const ONE: Decimal = Decimal {
+        mantissa: 1,
+        scale: 0,
+    };
+
+    /// Creates a decimal equal to `mantissa × 10^-scale`.
+    ///
+    /// The scale is capped at [`MAX_DECIMAL_SCALE`]; extra digits are truncated.
+    pub fn new(mantissa: i128, scale: u32) -> Self {
+        let mut value = Self { mantissa, scale };
+        while value.scale > MAX_DECIMAL_SCALE {
+            value.mantissa /= 10;
+            value.scale -= 1;
+        }
+        value
+    }
+
+    /// Converts a float through its shortest round-trip representation, so
+    /// `0.1_f64` becomes exactly `0.1`. Returns `None` for NaN and infinities.
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+        format!("{}", value).parse().ok()
+    }
+
+    /// Returns the integer mantissa.
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// Returns the number of fractional digits.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Returns true if the value has no fractional part.
+    pub fn is_integer(&self) -> bool {
+        self.mantissa % 10i128.pow(self.scale) == 0
+    }
+
+    /// Returns true if the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// Returns true if the value is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.mantissa < 0
+    }
+
+    /// Returns the absolute value.
+    pub fn abs(&self) -> Self {
+        Self::new(self.mantissa.abs(), self.scale)
+    }
+
+    /// Returns the value as an `i64` if it is a whole number in range.
+    pub fn to_i64(&self) -> Option<i64> {
+        if !self.is_integer() {
+            return None;
+        }
+        i64::try_from(self.mantissa / 10i128.pow(self.scale)).ok()
+    }
+
+    /// Returns the nearest `f64`.
+    pub fn to_f64(&self) -> f64 {
+        self.mantissa as f64 / 10f64.powi(self.scale as i32)
+    }
+
+    /// Returns the same value with trailing fractional zeros removed.
+    pub fn normalize(&self) -> Self {
+        let mut value = *self;
+        while value.scale > 0 && value.mantissa % 10 == 0 {
+            value.mantissa /= 10;
+            value.scale -= 1;
+        }
+        value
+    }
+
+    /// Adds two decimals, returning `None` on overflow.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let (lhs, rhs, scale) = self.aligned(other)?;
+        Some(Self::new(lhs.checked_add(rhs)?, scale))
+    }
+
+    /// Subtracts `other`, returning `None` on overflow.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.checked_add(&-*other)
+    }
+
+    /// Multiplies two decimals, returning `None` on overflow or if the
+    /// result needs more than [`MAX_DECIMAL_SCALE`] fractional digits.
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        let scale = self.scale + other.scale;
+        let product = Self {
+            mantissa: self.mantissa.checked_mul(other.mantissa)?,
+            scale,
+        };
+        if scale > MAX_DECIMAL_SCALE {
+            let normalized = product.normalize();
+            return (normalized.scale <= MAX_DECIMAL_SCALE).then_some(normalized);
+        }
+        Some(product)
+    }
+
+    /// Divides by `other`, returning `None` when dividing by zero, on
+    /// overflow, or when the quotient does not terminate within
+    /// [`MAX_DECIMAL_SCALE`] fractional digits (such as `1 / 3`).
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        if other.mantissa == 0 {
+            return None;
+        }
+        let (mut numerator, denominator, _) = self.aligned(other)?;
+        let mut scale = 0;
+        loop {
+            if numerator % denominator == 0 {
+                return Some(Self::new(numerator / denominator, scale));
+            }
+            if scale == MAX_DECIMAL_SCALE {
+                return None;
+            }
+            numerator = numerator.checked_mul(10)?;
+            scale += 1;
+        }
+    }
+
+    /// Divides by `other`, rounding a non-terminating quotient half-even to as
+    /// many fractional digits as fit (at most [`MAX_DECIMAL_SCALE`]).
+    ///
+    /// Returns `None` when dividing by zero or when even the integer part overflows.
+    pub fn div_rounded(&self, other: &Self) -> Option<Self> {
+        if let Some(exact) = self.checked_div(other) {
+            return Some(exact);
+        }
+        if other.mantissa == 0 {
+            return None;
+        }
+        let (numerator, denominator, _) = self.aligned(other)?;
+        let negative = (numerator < 0) != (denominator < 0);
+        let (numerator, denominator) = (numerator.unsigned_abs(), denominator.unsigned_abs());
+
+        // Long division, one digit at a time, until the scale limit or the
+        // mantissa would overflow
+        let mut quotient = numerator / denominator;
+        let mut remainder = numerator % denominator;
+        let mut scale = 0;
+        while scale < MAX_DECIMAL_SCALE && remainder != 0 {
+            let Some(next) = quotient.checked_mul(10).and_then(|q| {
+                q.checked_add((remainder * 10) / denominator)
+                    .filter(|q| *q <= i128::MAX as u128)
+            }) else {
+                break;
+            };
+            quotient = next;
+            remainder = (remainder * 10) % denominator;
+            scale += 1;
+        }
+        let doubled = remainder.checked_mul(2)?;
+        if doubled > denominator || (doubled == denominator && quotient % 2 == 1) {
+            quotient = quotient.checked_add(1)?;
+        }
+        let mantissa = i128::try_from(quotient).ok()?;
+        Some(Self::new(
+            if negative { -mantissa } else { mantissa },
+            scale,
+        ))
+    }
+
+    /// Returns the remainder of truncating division, with the sign of `self`.
+    pub fn checked_rem(&self, other: &Self) -> Option<Self> {
+        if other.mantissa == 0 {
+            return None;
+        }
+        let (lhs, rhs, scale) = self.aligned(other)?;
+        Some(Self::new(lhs % rhs, scale))
+    }
+
+    /// Rounds to `digits` fractional digits.
+    ///
+    /// Values that already have no more than `digits` fractional digits are
+    /// returned unchanged.
+    pub fn round(&self, digits: u32, mode: RoundingMode) -> Self {
+        if self.scale <= digits {
+            return *self;
+        }
+        let divisor = 10i128.pow(self.scale - digits);
+        let quotient = self.mantissa / divisor;
+        let remainder = self.mantissa % divisor;
+        if remainder == 0 {
+            return Self::new(quotient, digits);
+        }
+        let away = if remainder > 0 { 1 } else { -1 };
+        let doubled = remainder.abs() * 2;
+        let round_away = match mode {
+            RoundingMode::HalfEven => {
+                doubled > divisor || (doubled == divisor && quotient % 2 != 0)
+            }
+            RoundingMode::HalfUp => doubled >= divisor,
+            RoundingMode::Down => false,
+            RoundingMode::Floor => remainder < 0,
+            RoundingMode::Ceiling => remainder > 0,
+        };
+        Self::new(
+            if round_away {
+                quotient + away
+            } else {
+                quotient
+            },
+            digits,
+        )
+    }
+
+    /// Rescales both operands to a common scale.
+    fn aligned(&self, other: &Self) -> Option<(i128, i128, u32)> {
+        let scale = self.scale.max(other.scale);
+        let lhs = self
+            .mantissa
+            .checked_mul(10i128.checked_pow(scale - self.scale)?)?;
+        let rhs = other
+            .mantissa
+            .checked_mul(10i128.checked_pow(scale - other.scale)?)?;
+        Some((lhs, rhs, scale))
+    }
+}
+
+impl Default for Decimal {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<i64> for Decimal {
+    fn from(value: i64) -> Self {
+        Self::new(value as i128, 0)
+    }
+}
+
+impl std::ops::Neg for Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.mantissa, self.scale)
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.aligned(other) {
+            Some((lhs, rhs, _)) => lhs.cmp(&rhs),
+            None => self.to_f64().total_cmp(&other.to_f64()),
+        }
+    }
+}
+
+impl Hash for Decimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let normalized = self.normalize();
+        normalized.mantissa.hash(state);
+        normalized.scale.hash(state);
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = DecimalError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let cleaned = input.trim().replace('_', "");
+        if cleaned.is_empty() {
+            return Err(DecimalError::Empty);
+        }
+        let invalid = || DecimalError::Invalid(input.to_string());
+
+        let (negative, digits) = match cleaned.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, cleaned.strip_prefix('+').unwrap_or(&cleaned)),
+        };
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        if whole.is_empty()
+            || !whole.chars().all(|c| c.is_ascii_digit())
+            || !fraction.chars().all(|c| c.is_ascii_digit())
+            || fraction.len() > MAX_DECIMAL_SCALE as usize
+        {
+            return Err(invalid());
+        }
+
+        let mantissa: i128 = format!("{}{}", whole, fraction)
+            .parse()
+            .map_err(|_| DecimalError::OutOfRange(input.to_string()))?;
+        let mantissa = if negative { -mantissa } else { mantissa };
+        Ok(Self::new(mantissa, fraction.len() as u32))
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        if scale == 0 {
+            return write!(f, "{}{}", sign, digits);
+        }
+        let padded = format!("{:0>width$}", digits, width = scale + 1);
+        let (whole, fraction) = padded.split_at(padded.len() - scale);
+        write!(f, "{}{}.{}", sign, whole, fraction)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Decimal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Decimal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        raw.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Decimal {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Decimal".into()
+    }
+
+    fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": "^-?[0-9]+(\\.[0-9]+)?$"
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_decimal_parse_and_display() {
+        let value = dec("12500.50");
+        assert_eq!(value.mantissa(), 1_250_050);
+        assert_eq!(value.scale(), 2);
+        assert_eq!(value.to_string(), "12500.50");
+
+        assert_eq!(dec("-0.05").to_string(), "-0.05");
+        assert_eq!(dec("1_000.5").to_string(), "1000.5");
+        assert_eq!(dec("42").to_i64(), Some(42));
+        assert_eq!(dec("42.00").to_i64(), Some(42));
+        assert_eq!(dec("42.01").to_i64(), None);
+        assert!(matches!(
+            "1.2.3".parse::<Decimal>(),
+            Err(DecimalError::Invalid(_))
+        ));
+        assert!(".5".parse::<Decimal>().is_err());
+        assert_eq!("".parse::<Decimal>(), Err(DecimalError::Empty));
+        assert_eq!(Decimal::from_f64(0.1), Some(dec("0.1")));
+        assert_eq!(Decimal::from_f64(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_decimal_is_exact() {
+        let a = dec("0.1");
+        let b = dec("0.10");
+        let c = dec("0.30000000000000004");
+        assert_eq!(a, b);
+        assert!(c > dec("0.3"));
+        assert_eq!(a.checked_add(&dec("0.2")).unwrap(), dec("0.3"));
+        assert!(-a < Decimal::ZERO);
+        assert_eq!(a.normalize().scale(), 1);
+
+        let mut set = std::collections::HashSet::new();
+        set.insert(a);
+        assert!(set.contains(&b));
+
+        let price = dec("19.99");
+        let three = Decimal::from(3i64);
+        assert_eq!(price.checked_mul(&three).unwrap().to_string(), "59.97");
+        assert_eq!(price.checked_sub(&a).unwrap().to_string(), "19.89");
+        assert_eq!(price.checked_add(&-price).unwrap(), Decimal::ZERO);
+        assert_eq!(
+            Decimal::ONE.checked_div(&dec("0.8")).unwrap().to_string(),
+            "1.25"
+        );
+        assert_eq!(Decimal::ONE.checked_div(&three), None);
+        assert_eq!(Decimal::ONE.checked_div(&Decimal::ZERO), None);
+        assert_eq!(
+            dec("7.5").checked_rem(&Decimal::from(2i64)),
+            Some(dec("1.5"))
+        );
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&b).unwrap();
+            assert_eq!(json, "\"0.10\"");
+            assert_eq!(serde_json::from_str::<Decimal>(&json).unwrap(), a);
+        }
+    }
+
+    #[test]
+    fn test_decimal_rounded_division() {
+        let third = Decimal::ONE.div_rounded(&Decimal::from(3i64)).unwrap();
+        assert_eq!(third.scale(), MAX_DECIMAL_SCALE);
+        assert_eq!(third.round(4, RoundingMode::HalfEven), dec("0.3333"));
+        let two_thirds = Decimal::from(2i64)
+            .div_rounded(&Decimal::from(3i64))
+            .unwrap();
+        assert_eq!(two_thirds.round(2, RoundingMode::Down), dec("0.66"));
+        assert!(two_thirds.to_string().ends_with('7'));
+
+        // Large quotients keep as many fractional digits as fit
+        let large = dec("1000000000000")
+            .div_rounded(&Decimal::from(7i64))
+            .unwrap();
+        assert!(large.scale() < MAX_DECIMAL_SCALE);
+        assert_eq!(large.round(0, RoundingMode::HalfEven), dec("142857142857"));
+        assert_eq!(Decimal::ONE.div_rounded(&Decimal::ZERO), None);
+    }
+
+    #[test]
+    fn test_decimal_rounding_modes() {
+        let cases = [
+            ("2.5", RoundingMode::HalfEven, "2"),
+            ("3.5", RoundingMode::HalfEven, "4"),
+            ("-2.5", RoundingMode::HalfEven, "-2"),
+            ("2.5", RoundingMode::HalfUp, "3"),
+            ("-2.5", RoundingMode::HalfUp, "-3"),
+            ("2.7", RoundingMode::Down, "2"),
+            ("-2.7", RoundingMode::Down, "-2"),
+            ("-2.1", RoundingMode::Floor, "-3"),
+            ("2.1", RoundingMode::Ceiling, "3"),
+            ("2.0", RoundingMode::Ceiling, "2"),
+        ];
+        for (value, mode, expected) in cases {
+            assert_eq!(
+                dec(value).round(0, mode),
+                dec(expected),
+                "{} {:?}",
+                value,
+                mode
+            );
+        }
+        assert_eq!(dec("1.005").round(2, RoundingMode::HalfUp), dec("1.01"));
+        assert_eq!(
+            dec("1.5").round(3, RoundingMode::HalfEven).to_string(),
+            "1.5"
+        );
+    }
+}
@@ -0,0 +1,987 @@
+//! Defeasible reasoning over competing statutes.
+//!
+//! Statute books are written as "X applies unless Y, except where Z". A
+//! [`DefeasibleTheory`] takes a set of statutes, evaluates them against the
+//! facts in an [`EvaluationContext`] and returns the conclusions that survive
+//! together with the defeat graph that explains why the others did not.
+//!
+//! The semantics follow structured argumentation:
+//!
+//! - every statute whose preconditions hold yields an *argument* for its effect;
+//! - a statute exception whose condition holds *undercuts* that argument;
+//! - two arguments with conflicting effects *rebut* each other, and a rebuttal
+//!   only succeeds if the attacker is not weaker. Strength is decided by strict
+//!   rules, explicit superiority (`supersedes`), numeric priority, and then the
+//!   configured principles: lex superior, lex specialis and lex posterior;
+//! - the accepted arguments are the grounded extension of the defeat graph, so
+//!   a defeated attacker reinstates the argument it attacked.
+//!
+//! # Example
+//!
+//! ```
+//! use legalis_core::defeasible::{ArgumentStatus, DefeasibleTheory};
+//! use legalis_core::{AttributeBasedContext, ComparisonOp, Condition, Effect, Statute, StatuteException};
+//! use std::collections::HashMap;
+//!
+//! // Adults may vote...
+//! let general = Statute::new("vote", "Voting right", Effect::grant("voting").with_parameter("subject", "voting"))
+//!     .with_precondition(Condition::age(ComparisonOp::GreaterOrEqual, 18));
+//! // ...unless disqualified...
+//! let disqualification = Statute::new("disq", "Disqualification", Effect::revoke("voting").with_parameter("subject", "voting"))
+//!     .with_precondition(Condition::has_attribute("convicted"))
+//!     .with_exception(StatuteException::new("pardon", "Pardoned persons", Condition::has_attribute("pardoned")));
+//!
+//! let theory = DefeasibleTheory::new()
+//!     .with_statute(general)
+//!     .with_statute(disqualification)
+//!     .prefer("disq", "vote");
+//!
+//! let mut facts = HashMap::new();
+//! facts.insert("age".to_string(), "40".to_string());
+//! facts.insert("convicted".to_string(), "true".to_string());
+//! let outcome = theory.evaluate(&AttributeBasedContext::new(facts.clone()));
+//! assert_eq!(outcome.status("vote"), Some(&ArgumentStatus::Defeated));
+//!
+//! // ...except where pardoned: the disqualification is undercut and the right is reinstated.
+//! facts.insert("pardoned".to_string(), "true".to_string());
+//! let outcome = theory.evaluate(&AttributeBasedContext::new(facts));
+//! assert!(outcome.is_accepted("vote"));
+//! ```
+
+use crate::{
+    ConflictReason, Effect, EvaluationContext, LegalResult, Statute, StatuteConflictAnalyzer,
+};
+use chrono::NaiveDate;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use uuid::Uuid;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+/// Strength of a rule in a defeasible theory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum RuleKind {
+    /// Cannot be rebutted by defeasible rules
+    Strict,
+    /// Holds unless defeated (the default for statutes)
+    #[default]
+    Defeasible,
+    /// Only blocks conflicting conclusions; never concludes anything itself
+    Defeater,
+}
+
+impl fmt::Display for RuleKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Strict => write!(f, "strict"),
+            Self::Defeasible => write!(f, "defeasible"),
+            Self::Defeater => write!(f, "defeater"),
+        }
+    }
+}
+
+/// What an argument in the defeat graph stands for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum ArgumentKind {
+    /// A statute concluding its effect
+    Rule(RuleKind),
+    /// A statute exception whose condition holds
+    Exception {
+        /// Exception identifier
+        exception_id: String,
+        /// Exception description
+        description: String,
+    },
+}
+
+/// Status of an argument after evaluation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum ArgumentStatus {
+    /// The argument survives all attacks
+    Accepted,
+    /// The argument is defeated by an accepted attacker
+    Defeated,
+    /// The argument can neither be accepted nor rejected
+    Undecided,
+    /// The statute does not apply to the facts (or is not in force)
+    Inapplicable,
+}
+
+impl fmt::Display for ArgumentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Accepted => write!(f, "accepted"),
+            Self::Defeated => write!(f, "defeated"),
+            Self::Undecided => write!(f, "undecided"),
+            Self::Inapplicable => write!(f, "inapplicable"),
+        }
+    }
+}
+
+/// A node in the defeat graph.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct Argument {
+    /// Argument identifier (the statute ID, or `statute#exception` for exceptions)
+    pub id: String,
+    /// Statute the argument belongs to
+    pub statute_id: String,
+    /// What the argument stands for
+    pub kind: ArgumentKind,
+    /// Status after evaluation
+    pub status: ArgumentStatus,
+    /// Why the argument is inapplicable or undecided, if known
+    pub note: Option<String>,
+}
+
+/// How one argument attacks another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum AttackKind {
+    /// Denies that the rule applies (exceptions)
+    Undercut,
+    /// Concludes something incompatible
+    Rebut,
+}
+
+/// Why an attack succeeds as a defeat.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum DefeatReason {
+    /// A statute exception applies
+    Exception,
+    /// A strict rule prevails over a non-strict one
+    Strict,
+    /// Explicit superiority (e.g. `supersedes`)
+    Superiority,
+    /// Higher numeric priority
+    Priority {
+        /// Priority of the attacker
+        attacker: u32,
+        /// Priority of the target
+        target: u32,
+    },
+    /// One of the lex principles
+    Principle(ConflictReason),
+    /// Neither argument is stronger; both defeat each other
+    Unresolved,
+}
+
+impl fmt::Display for DefeatReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exception => write!(f, "exception applies"),
+            Self::Strict => write!(f, "strict rule prevails"),
+            Self::Superiority => write!(f, "explicitly superior"),
+            Self::Priority { attacker, target } => {
+                write!(f, "higher priority ({} > {})", attacker, target)
+            }
+            Self::Principle(reason) => write!(f, "{}", reason),
+            Self::Unresolved => write!(f, "unresolved conflict"),
+        }
+    }
+}
+
+/// An edge in the defeat graph.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct Defeat {
+    /// Attacking argument ID
+    pub attacker: String,
+    /// Attacked argument ID
+    pub target: String,
+    /// Kind of attack
+    pub kind: AttackKind,
+    /// Why the attack succeeds
+    pub reason: DefeatReason,
+}
+
+/// Defeat graph produced by evaluating a [`DefeasibleTheory`].
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct DefeatGraph {
+    /// All arguments, including inapplicable statutes
+    pub arguments: Vec<Argument>,
+    /// Successful attacks between arguments
+    pub defeats: Vec<Defeat>,
+}
+
+impl DefeatGraph {
+    /// Gets an argument by ID.
+    pub fn argument(&self, id: &str) -> Option<&Argument> {
+        self.arguments.iter().find(|a| a.id == id)
+    }
+
+    /// Returns the defeats targeting the given argument.
+    pub fn defeaters_of<'a>(&'a self, id: &'a str) -> impl Iterator<Item = &'a Defeat> + 'a {
+        self.defeats.iter().filter(move |d| d.target == id)
+    }
+
+    /// Exports the graph in Graphviz DOT format.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph defeats {\n  rankdir=LR;\n");
+        for arg in &self.arguments {
+            let (shape, color) = match (&arg.kind, &arg.status) {
+                (ArgumentKind::Exception { .. }, _) => ("diamond", "orange"),
+                (_, ArgumentStatus::Accepted) => ("box", "green"),
+                (_, ArgumentStatus::Defeated) => ("box", "red"),
+                (_, ArgumentStatus::Undecided) => ("box", "gray"),
+                (_, ArgumentStatus::Inapplicable) => ("box", "lightgray"),
+            };
+            dot.push_str(&format!(
+                "  \"{}\" [shape={}, color={}, label=\"{}\\n{}\"];\n",
+                arg.id, shape, color, arg.id, arg.status
+            ));
+        }
+        for defeat in &self.defeats {
+            let style = match defeat.kind {
+                AttackKind::Undercut => "dashed",
+                AttackKind::Rebut => "solid",
+            };
+            dot.push_str(&format!(
+                "  \"{}\" -> \"{}\" [style={}, label=\"{}\"];\n",
+                defeat.attacker, defeat.target, style, defeat.reason
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// A conclusion that survived evaluation.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct Conclusion {
+    /// Statute that concluded the effect
+    pub statute_id: String,
+    /// The concluded effect
+    pub effect: Effect,
+}
+
+/// Result of evaluating a [`DefeasibleTheory`] against facts.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct DefeasibleOutcome {
+    /// Effects of the accepted (non-defeater) statutes
+    pub conclusions: Vec<Conclusion>,
+    /// The defeat graph explaining the outcome
+    pub graph: DefeatGraph,
+}
+
+impl DefeasibleOutcome {
+    /// Returns the status of a statute's argument.
+    pub fn status(&self, statute_id: &str) -> Option<&ArgumentStatus> {
+        self.graph.argument(statute_id).map(|a| &a.status)
+    }
+
+    /// Returns true if the statute's conclusion survived.
+    pub fn is_accepted(&self, statute_id: &str) -> bool {
+        self.status(statute_id) == Some(&ArgumentStatus::Accepted)
+    }
+
+    /// Returns the IDs of statutes with the given status.
+    pub fn statutes_with_status(&self, status: &ArgumentStatus) -> Vec<&str> {
+        self.graph
+            .arguments
+            .iter()
+            .filter(|a| matches!(a.kind, ArgumentKind::Rule(_)) && &a.status == status)
+            .map(|a| a.statute_id.as_str())
+            .collect()
+    }
+
+    /// Explains the status of a statute by walking the defeat graph.
+    ///
+    /// Each line names an argument and the attack that decided it, following
+    /// accepted attackers back to the arguments nobody defeats.
+    pub fn explain(&self, statute_id: &str) -> Option<String> {
+        let mut lines = Vec::new();
+        let mut visited = HashSet::new();
+        self.explain_argument(statute_id, 0, &mut visited, &mut lines);
+        if lines.is_empty() {
+            None
+        } else {
+            Some(lines.join("\n"))
+        }
+    }
+
+    fn explain_argument(
+        &self,
+        id: &str,
+        depth: usize,
+        visited: &mut HashSet<String>,
+        lines: &mut Vec<String>,
+    ) {
+        let Some(arg) = self.graph.argument(id) else {
+            return;
+        };
+        let indent = "  ".repeat(depth);
+        let label = match &arg.kind {
+            ArgumentKind::Rule(_) => format!("'{}'", arg.id),
+            ArgumentKind::Exception { description, .. } => {
+                format!("exception '{}' ({})", arg.id, description)
+            }
+        };
+        let mut line = format!("{}{} is {}", indent, label, arg.status);
+        if let Some(note) = &arg.note {
+            line.push_str(&format!(": {}", note));
+        }
+        lines.push(line);
+
+        if !visited.insert(arg.id.clone()) {
+            return;
+        }
+
+        for defeat in self.graph.defeaters_of(id) {
+            let attacker_status = self
+                .graph
+                .argument(&defeat.attacker)
+                .map(|a| &a.status)
+                .unwrap_or(&ArgumentStatus::Undecided);
+            let verb = match defeat.kind {
+                AttackKind::Undercut => "undercut",
+                AttackKind::Rebut => "rebutted",
+            };
+            lines.push(format!(
+                "{}  {} by '{}' ({}), which is {}",
+                indent, verb, defeat.attacker, defeat.reason, attacker_status
+            ));
+            self.explain_argument(&defeat.attacker, depth + 2, visited, lines);
+        }
+    }
+
+    /// Converts a statute's status into a [`LegalResult`].
+    ///
+    /// Accepted statutes are deterministic, defeated and inapplicable ones are
+    /// void, and undecided ones require judicial discretion.
+    pub fn legal_result(&self, statute_id: &str, context_id: Uuid) -> Option<LegalResult<Effect>> {
+        let arg = self.graph.argument(statute_id)?;
+        Some(match arg.status {
+            ArgumentStatus::Accepted => {
+                let conclusion = self
+                    .conclusions
+                    .iter()
+                    .find(|c| c.statute_id == statute_id)?;
+                LegalResult::Deterministic(conclusion.effect.clone())
+            }
+            ArgumentStatus::Defeated => LegalResult::Void {
+                reason: self
+                    .explain(statute_id)
+                    .unwrap_or_else(|| format!("'{}' is defeated", statute_id)),
+            },
+            ArgumentStatus::Inapplicable => LegalResult::Void {
+                reason: arg
+                    .note
+                    .clone()
+                    .unwrap_or_else(|| format!("'{}' does not apply", statute_id)),
+            },
+            ArgumentStatus::Undecided => LegalResult::JudicialDiscretion {
+                issue: self
+                    .explain(statute_id)
+                    .unwrap_or_else(|| format!("'{}' is undecided", statute_id)),
+                context_id,
+                narrative_hint: None,
+            },
+        })
+    }
+}
+
+impl fmt::Display for DefeasibleOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Conclusions:")?;
+        for conclusion in &self.conclusions {
+            writeln!(f, "  {}: {}", conclusion.statute_id, conclusion.effect)?;
+        }
+        for status in [ArgumentStatus::Defeated, ArgumentStatus::Undecided] {
+            let ids = self.statutes_with_status(&status);
+            if !ids.is_empty() {
+                writeln!(f, "{}: {}", status, ids.join(", "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A set of statutes with the metadata needed to resolve conflicts between them.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DefeasibleTheory {
+    statutes: Vec<Statute>,
+    kinds: HashMap<String, RuleKind>,
+    priorities: HashMap<String, u32>,
+    superiority: Vec<(String, String)>,
+    conflicts: Vec<(String, String)>,
+    principles: Vec<ConflictReason>,
+    as_of: Option<NaiveDate>,
+}
+
+impl Default for DefeasibleTheory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DefeasibleTheory {
+    /// Creates an empty theory.
+    ///
+    /// Conflicts not settled by strictness, superiority or priority are
+    /// resolved by lex superior, then lex specialis, then lex posterior.
+    pub fn new() -> Self {
+        Self {
+            statutes: Vec::new(),
+            kinds: HashMap::new(),
+            priorities: HashMap::new(),
+            superiority: Vec::new(),
+            conflicts: Vec::new(),
+            principles: vec![
+                ConflictReason::Hierarchy,
+                ConflictReason::Specificity,
+                ConflictReason::TemporalPrecedence,
+            ],
+            as_of: None,
+        }
+    }
+
+    /// Adds a statute.
+    pub fn with_statute(mut self, statute: Statute) -> Self {
+        self.add_statute(statute);
+        self
+    }
+
+    /// Adds several statutes.
+    pub fn with_statutes(mut self, statutes: impl IntoIterator<Item = Statute>) -> Self {
+        for statute in statutes {
+            self.add_statute(statute);
+        }
+        self
+    }
+
+    /// Adds a statute in place.
+    pub fn add_statute(&mut self, statute: Statute) {
+        self.statutes.push(statute);
+    }
+
+    /// Returns the statutes in the theory.
+    pub fn statutes(&self) -> &[Statute] {
+        &self.statutes
+    }
+
+    /// Sets the rule kind of a statute.
+    pub fn with_kind(mut self, statute_id: impl Into<String>, kind: RuleKind) -> Self {
+        self.kinds.insert(statute_id.into(), kind);
+        self
+    }
+
+    /// Marks a statute as a strict rule.
+    pub fn strict(self, statute_id: impl Into<String>) -> Self {
+        self.with_kind(statute_id, RuleKind::Strict)
+    }
+
+    /// Marks a statute as a defeater.
+    pub fn defeater(self, statute_id: impl Into<String>) -> Self {
+        self.with_kind(statute_id, RuleKind::Defeater)
+    }
+
+    /// Sets the priority of a statute. Higher numbers win.
+    pub fn with_priority(mut self, statute_id: impl Into<String>, priority: u32) -> Self {
+        self.priorities.insert(statute_id.into(), priority);
+        self
+    }
+
+    /// Declares that `winner` prevails over `loser` whenever both apply.
+    ///
+    /// The two statutes are treated as conflicting even if their effects are
+    /// compatible, which models `supersedes`.
+    pub fn prefer(mut self, winner: impl Into<String>, loser: impl Into<String>) -> Self {
+        self.superiority.push((winner.into(), loser.into()));
+        self
+    }
+
+    /// Declares that two statutes conflict regardless of their effects.
+    pub fn with_conflict(mut self, first: impl Into<String>, second: impl Into<String>) -> Self {
+        self.conflicts.push((first.into(), second.into()));
+        self
+    }
+
+    /// Sets the order in which lex principles are tried.
+    pub fn with_principles(mut self, principles: Vec<ConflictReason>) -> Self {
+        self.principles = principles;
+        self
+    }
+
+    /// Only statutes in force on this date are considered.
+    ///
+    /// Without a date, the context's current date is used if it has one.
+    pub fn as_of(mut self, date: NaiveDate) -> Self {
+        self.as_of = Some(date);
+        self
+    }
+
+    /// Returns the rule kind of a statute.
+    pub fn kind_of(&self, statute_id: &str) -> RuleKind {
+        self.kinds.get(statute_id).copied().unwrap_or_default()
+    }
+
+    /// Returns the priority of a statute, if one was set.
+    pub fn priority_of(&self, statute_id: &str) -> Option<u32> {
+        self.priorities.get(statute_id).copied()
+    }
+
+    /// Returns the declared superiority relation as `(winner, loser)` pairs.
+    pub fn superiority(&self) -> &[(String, String)] {
+        &self.superiority
+    }
+
+    /// Returns true if the two statutes compete for the same conclusion.
+    ///
+    /// Statutes conflict when declared so, when one is superior to the other,
+    /// or when their effect types are incompatible (e.g. grant vs revoke) and
+    /// they concern the same subject: the `subject` effect parameter if both
+    /// set it, otherwise the effect description.
+    pub fn conflicts(&self, first: &Statute, second: &Statute) -> bool {
+        let declared = |pairs: &[(String, String)]| {
+            pairs.iter().any(|(a, b)| {
+                (a == &first.id && b == &second.id) || (a == &second.id && b == &first.id)
+            })
+        };
+        if declared(&self.conflicts) || declared(&self.superiority) {
+            return true;
+        }
+        StatuteConflictAnalyzer::has_conflict(first, second)
+            && Self::subject(&first.effect) == Self::subject(&second.effect)
+    }
+
+    fn subject(effect: &Effect) -> String {
+        effect
+            .parameters
+            .get("subject")
+            .unwrap_or(&effect.description)
+            .trim()
+            .to_lowercase()
+    }
+
+    /// Decides whether `attacker` defeats `target` in a rebuttal.
+    ///
+    /// Returns `None` if the target is strictly stronger.
+    fn compare(&self, attacker: &Statute, target: &Statute) -> Option<DefeatReason> {
+        let (a_strict, t_strict) = (
+            self.kind_of(&attacker.id) == RuleKind::Strict,
+            self.kind_of(&target.id) == RuleKind::Strict,
+        );
+        if a_strict != t_strict {
+            return a_strict.then_some(DefeatReason::Strict);
+        }
+
+        if self
+            .superiority
+            .iter()
+            .any(|(w, l)| w == &attacker.id && l == &target.id)
+        {
+            return Some(DefeatReason::Superiority);
+        }
+        if self
+            .superiority
+            .iter()
+            .any(|(w, l)| w == &target.id && l == &attacker.id)
+        {
+            return None;
+        }
+
+        if let (Some(&a), Some(&t)) = (
+            self.priorities.get(&attacker.id),
+            self.priorities.get(&target.id),
+        ) && a != t
+        {
+            return (a > t).then_some(DefeatReason::Priority {
+                attacker: a,
+                target: t,
+            });
+        }
+
+        for principle in &self.principles {
+            let ordering = match principle {
+                ConflictReason::Hierarchy => {
+                    StatuteConflictAnalyzer::jurisdiction_level(&attacker.jurisdiction).cmp(
+                        &StatuteConflictAnalyzer::jurisdiction_level(&target.jurisdiction),
+                    )
+                }
+                ConflictReason::Specificity => {
+                    StatuteConflictAnalyzer::calculate_specificity(attacker)
+                        .cmp(&StatuteConflictAnalyzer::calculate_specificity(target))
+                }
+                ConflictReason::TemporalPrecedence => {
+                    match (
+                        attacker.temporal_validity.effective_date,
+                        target.temporal_validity.effective_date,
+                    ) {
+                        (Some(a), Some(t)) => a.cmp(&t),
+                        _ => std::cmp::Ordering::Equal,
+                    }
+                }
+                ConflictReason::ExplicitAmendment => std::cmp::Ordering::Equal,
+            };
+            match ordering {
+                std::cmp::Ordering::Greater => {
+                    return Some(DefeatReason::Principle(principle.clone()));
+                }
+                std::cmp::Ordering::Less => return None,
+                std::cmp::Ordering::Equal => {}
+            }
+        }
+
+        Some(DefeatReason::Unresolved)
+    }
+
+    /// Evaluates the theory against the facts in `context`.
+    pub fn evaluate<C: EvaluationContext>(&self, context: &C) -> DefeasibleOutcome {
+        let as_of = self.as_of.or_else(|| context.get_current_date());
+        let mut graph = DefeatGraph::default();
+        // Arguments that can never be accepted or rejected (evaluation errors)
+        let mut blocked: HashSet<String> = HashSet::new();
+        let mut applicable: Vec<&Statute> = Vec::new();
+
+        for statute in &self.statutes {
+            let kind = self.kind_of(&statute.id);
+            let mut argument = Argument {
+                id: statute.id.clone(),
+                statute_id: statute.id.clone(),
+                kind: ArgumentKind::Rule(kind),
+                status: ArgumentStatus::Inapplicable,
+                note: None,
+            };
+
+            if let Some(date) = as_of.filter(|d| !statute.is_active(*d)) {
+                argument.note = Some(format!("Not in force on {}", date));
+                graph.arguments.push(argument);
+                continue;
+            }
+
+            let mut holds = true;
+            for condition in &statute.preconditions {
+                match condition.evaluate(context) {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        argument.note = Some(format!("Precondition not met: {}", condition));
+                        holds = false;
+                        break;
+                    }
+                    Err(e) => {
+                        argument.status = ArgumentStatus::Undecided;
+                        argument.note = Some(format!(
+                            "Cannot evaluate precondition '{}': {}",
+                            condition, e
+                        ));
+                        blocked.insert(statute.id.clone());
+                        break;
+                    }
+                }
+            }
+            if !holds || blocked.contains(&statute.id) {
+                graph.arguments.push(argument);
+                continue;
+            }
+
+            argument.status = ArgumentStatus::Undecided;
+            graph.arguments.push(argument);
+            applicable.push(statute);
+
+            for exception in &statute.exceptions {
+                let id = format!("{}#{}", statute.id, exception.id);
+                let note = match exception.condition.evaluate(context) {
+                    Ok(false) => continue,
+                    Ok(true) => None,
+                    Err(e) => {
+                        blocked.insert(id.clone());
+                        Some(format!("Cannot evaluate exception condition: {}", e))
+                    }
+                };
+                graph.arguments.push(Argument {
+                    id: id.clone(),
+                    statute_id: statute.id.clone(),
+                    kind: ArgumentKind::Exception {
+                        exception_id: exception.id.clone(),
+                        description: exception.description.clone(),
+                    },
+                    status: ArgumentStatus::Undecided,
+                    note,
+                });
+                graph.defeats.push(Defeat {
+                    attacker: id,
+                    target: statute.id.clone(),
+                    kind: AttackKind::Undercut,
+                    reason: DefeatReason::Exception,
+                });
+            }
+        }
+
+        for attacker in &applicable {
+            for target in &applicable {
+                if attacker.id == target.id || !self.conflicts(attacker, target) {
+                    continue;
+                }
+                if let Some(reason) = self.compare(attacker, target) {
+                    graph.defeats.push(Defeat {
+                        attacker: attacker.id.clone(),
+                        target: target.id.clone(),
+                        kind: AttackKind::Rebut,
+                        reason,
+                    });
+                }
+            }
+        }
+
+        Self::label_grounded(&mut graph, &blocked);
+
+        let conclusions = applicable
+            .iter()
+            .filter(|s| self.kind_of(&s.id) != RuleKind::Defeater)
+            .filter(|s| {
+                graph
+                    .argument(&s.id)
+                    .is_some_and(|a| a.status == ArgumentStatus::Accepted)
+            })
+            .map(|s| Conclusion {
+                statute_id: s.id.clone(),
+                effect: s.effect.clone(),
+            })
+            .collect();
+
+        DefeasibleOutcome { conclusions, graph }
+    }
+
+    /// Computes the grounded labelling of the graph in place.
+    ///
+    /// Arguments start undecided; an argument becomes accepted once all its
+    /// attackers are defeated, and defeated once any attacker is accepted.
+    /// Blocked arguments stay undecided.
+    fn label_grounded(graph: &mut DefeatGraph, blocked: &HashSet<String>) {
+        let mut status: HashMap<String, ArgumentStatus> = graph
+            .arguments
+            .iter()
+            .filter(|a| a.status == ArgumentStatus::Undecided)
+            .map(|a| (a.id.clone(), ArgumentStatus::Undecided))
+            .collect();
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for arg in &graph.arguments {
+                if blocked.contains(&arg.id)
+                    || status.get(&arg.id) != Some(&ArgumentStatus::Undecided)
+                {
+                    continue;
+                }
+                let attackers: Vec<&ArgumentStatus> = graph
+                    .defeats
+                    .iter()
+                    .filter(|d| d.target == arg.id)
+                    .filter_map(|d| status.get(&d.attacker))
+                    .collect();
+                let next = if attackers.contains(&&ArgumentStatus::Accepted) {
+                    ArgumentStatus::Defeated
+                } else if attackers.iter().all(|s| **s == ArgumentStatus::Defeated) {
+                    ArgumentStatus::Accepted
+                } else {
+                    continue;
+                };
+                status.insert(arg.id.clone(), next);
+                changed = true;
+            }
+        }
+
+        for arg in &mut graph.arguments {
+            if let Some(s) = status.remove(&arg.id) {
+                arg.status = s;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AttributeBasedContext, ComparisonOp, Condition, StatuteException};
+
+    fn context(pairs: &[(&str, &str)]) -> AttributeBasedContext {
+        AttributeBasedContext::new(
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        )
+    }
+
+    fn rule(id: &str, effect: Effect) -> Statute {
+        Statute::new(id, id, effect.with_parameter("subject", "permit"))
+    }
+
+    #[test]
+    fn test_exception_undercuts_rule() {
+        let theory = DefeasibleTheory::new().with_statute(
+            rule("permit", Effect::grant("permit"))
+                .with_precondition(Condition::age(ComparisonOp::GreaterOrEqual, 18))
+                .with_exception(StatuteException::new(
+                    "bankrupt",
+                    "Bankrupt persons",
+                    Condition::has_attribute("bankrupt"),
+                )),
+        );
+
+        let outcome = theory.evaluate(&context(&[("age", "30")]));
+        assert!(outcome.is_accepted("permit"));
+        assert_eq!(outcome.conclusions.len(), 1);
+
+        let outcome = theory.evaluate(&context(&[("age", "30"), ("bankrupt", "true")]));
+        assert_eq!(outcome.status("permit"), Some(&ArgumentStatus::Defeated));
+        assert!(outcome.conclusions.is_empty());
+        assert!(
+            outcome
+                .explain("permit")
+                .unwrap()
+                .contains("Bankrupt persons")
+        );
+
+        let outcome = theory.evaluate(&context(&[("age", "10")]));
+        assert_eq!(
+            outcome.status("permit"),
+            Some(&ArgumentStatus::Inapplicable)
+        );
+    }
+
+    #[test]
+    fn test_lex_principles() {
+        let general = rule("general", Effect::grant("permit"))
+            .with_precondition(Condition::age(ComparisonOp::GreaterOrEqual, 18));
+        let special = rule("special", Effect::revoke("permit"))
+            .with_precondition(Condition::age(ComparisonOp::GreaterOrEqual, 18))
+            .with_precondition(Condition::has_attribute("foreign"));
+        let facts = context(&[("age", "30"), ("foreign", "true")]);
+
+        // Lex specialis: the more specific rule wins
+        let outcome = DefeasibleTheory::new()
+            .with_statutes([general.clone(), special.clone()])
+            .evaluate(&facts);
+        assert!(outcome.is_accepted("special"));
+        assert_eq!(outcome.status("general"), Some(&ArgumentStatus::Defeated));
+
+        // Lex superior overrides specificity when tried first
+        let national = general.clone().with_jurisdiction("JP");
+        let local = special.clone().with_jurisdiction("local-tokyo");
+        let outcome = DefeasibleTheory::new()
+            .with_statutes([national, local])
+            .evaluate(&facts);
+        assert!(outcome.is_accepted("general"));
+
+        // Lex posterior when specificity is not considered
+        let later_general = general.with_temporal_validity(
+            crate::TemporalValidity::new()
+                .with_effective_date(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap()),
+        );
+        let earlier_special = special.with_temporal_validity(
+            crate::TemporalValidity::new()
+                .with_effective_date(NaiveDate::from_ymd_opt(1990, 1, 1).unwrap()),
+        );
+        let outcome = DefeasibleTheory::new()
+            .with_statutes([later_general, earlier_special])
+            .with_principles(vec![ConflictReason::TemporalPrecedence])
+            .evaluate(&facts);
+        assert!(outcome.is_accepted("general"));
+        assert!(matches!(
+            outcome.graph.defeaters_of("special").next().unwrap().reason,
+            DefeatReason::Principle(ConflictReason::TemporalPrecedence)
+        ));
+    }
+
+    #[test]
+    fn test_priority_strict_and_unresolved() {
+        let a = rule("a", Effect::grant("permit"));
+        let b = rule("b", Effect::revoke("permit"));
+        let facts = context(&[]);
+
+        let outcome = DefeasibleTheory::new()
+            .with_statutes([a.clone(), b.clone()])
+            .evaluate(&facts);
+        assert_eq!(outcome.status("a"), Some(&ArgumentStatus::Undecided));
+        assert_eq!(outcome.status("b"), Some(&ArgumentStatus::Undecided));
+        assert!(
+            outcome
+                .legal_result("a", Uuid::nil())
+                .unwrap()
+                .requires_discretion()
+        );
+
+        let outcome = DefeasibleTheory::new()
+            .with_statutes([a.clone(), b.clone()])
+            .with_priority("a", 1)
+            .with_priority("b", 5)
+            .evaluate(&facts);
+        assert!(outcome.is_accepted("b"));
+        assert!(outcome.legal_result("a", Uuid::nil()).unwrap().is_void());
+
+        let outcome = DefeasibleTheory::new()
+            .with_statutes([a, b])
+            .with_priority("b", 5)
+            .strict("a")
+            .evaluate(&facts);
+        assert!(outcome.is_accepted("a"));
+    }
+
+    #[test]
+    fn test_defeater_blocks_without_concluding() {
+        let outcome = DefeasibleTheory::new()
+            .with_statute(rule("grant", Effect::grant("permit")))
+            .with_statute(rule("block", Effect::revoke("permit")))
+            .defeater("block")
+            .prefer("block", "grant")
+            .evaluate(&context(&[]));
+
+        assert_eq!(outcome.status("grant"), Some(&ArgumentStatus::Defeated));
+        assert!(outcome.is_accepted("block"));
+        assert!(outcome.conclusions.is_empty());
+        assert!(outcome.graph.to_dot().contains("\"block\" -> \"grant\""));
+    }
+
+    #[test]
+    fn test_reinstatement_and_undecided_facts() {
+        // "X applies unless Y, except where Z"
+        let x = rule("x", Effect::grant("permit"));
+        let y = rule("y", Effect::revoke("permit")).with_exception(StatuteException::new(
+            "z",
+            "Z",
+            Condition::has_attribute("z"),
+        ));
+        let theory = DefeasibleTheory::new()
+            .with_statutes([x, y])
+            .prefer("y", "x");
+
+        assert!(!theory.evaluate(&context(&[])).is_accepted("x"));
+        let outcome = theory.evaluate(&context(&[("z", "yes")]));
+        assert!(outcome.is_accepted("x"));
+        assert_eq!(outcome.status("y"), Some(&ArgumentStatus::Defeated));
+
+        // A precondition that cannot be evaluated leaves the statute undecided
+        let outcome = DefeasibleTheory::new()
+            .with_statute(
+                rule("adult", Effect::grant("permit"))
+                    .with_precondition(Condition::age(ComparisonOp::GreaterOrEqual, 18)),
+            )
+            .evaluate(&context(&[]));
+        assert_eq!(outcome.status("adult"), Some(&ArgumentStatus::Undecided));
+    }
+}
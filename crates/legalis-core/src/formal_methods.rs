@@ -212,6 +212,61 @@ impl CoqExporter {
             Condition::Fuzzy { .. } => "(fuzzy_satisfied)".to_string(),
             Condition::Probabilistic { .. } => "(probabilistic_satisfied)".to_string(),
             Condition::Temporal { .. } => "(temporal_satisfied)".to_string(),
+            Condition::StatuteApplies { statute_id } => {
+                format!("(statute_applies {})", self.sanitize_identifier(statute_id))
+            }
+            Condition::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+                ..
+            } => format!(
+                "(statute_output {} \"{}\" {} {})",
+                self.sanitize_identifier(statute_id),
+                output,
+                self.export_comparison_op(operator),
+                value
+            ),
+            Condition::ForAll {
+                relationship,
+                condition,
+            } => format!(
+                "(forall_related {:?} {})",
+                relationship,
+                self.export_condition(condition)
+            ),
+            Condition::Exists {
+                relationship,
+                condition,
+            } => format!(
+                "(exists_related {:?} {})",
+                relationship,
+                self.export_condition(condition)
+            ),
+            Condition::Count {
+                relationship,
+                operator,
+                value,
+                ..
+            } => format!(
+                "(count_related {:?} {} {})",
+                relationship,
+                self.export_comparison_op(operator),
+                value
+            ),
+            Condition::Sum {
+                relationship,
+                attribute,
+                operator,
+                value,
+            } => format!(
+                "(sum_related {:?} \"{}\" {} {})",
+                relationship,
+                attribute,
+                self.export_comparison_op(operator),
+                value
+            ),
         }
     }
 
@@ -487,6 +542,61 @@ impl Lean4Exporter {
             Condition::Fuzzy { .. } => "(fuzzySatisfied)".to_string(),
             Condition::Probabilistic { .. } => "(probabilisticSatisfied)".to_string(),
             Condition::Temporal { .. } => "(temporalSatisfied)".to_string(),
+            Condition::StatuteApplies { statute_id } => {
+                format!("(statuteApplies {})", self.sanitize_identifier(statute_id))
+            }
+            Condition::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+                ..
+            } => format!(
+                "(statuteOutput {} \"{}\" {} {})",
+                self.sanitize_identifier(statute_id),
+                output,
+                self.export_comparison_op(operator),
+                value
+            ),
+            Condition::ForAll {
+                relationship,
+                condition,
+            } => format!(
+                "(forallRelated {:?} {})",
+                relationship,
+                self.export_condition(condition)
+            ),
+            Condition::Exists {
+                relationship,
+                condition,
+            } => format!(
+                "(existsRelated {:?} {})",
+                relationship,
+                self.export_condition(condition)
+            ),
+            Condition::Count {
+                relationship,
+                operator,
+                value,
+                ..
+            } => format!(
+                "(countRelated {:?} {} {})",
+                relationship,
+                self.export_comparison_op(operator),
+                value
+            ),
+            Condition::Sum {
+                relationship,
+                attribute,
+                operator,
+                value,
+            } => format!(
+                "(sumRelated {:?} \"{}\" {} {})",
+                relationship,
+                attribute,
+                self.export_comparison_op(operator),
+                value
+            ),
         }
     }
 
@@ -0,0 +1,1692 @@
+//! Built-in arithmetic expression language for calculated conditions.
+//!
+//! This module provides the parser, AST and evaluator behind
+//! [`Condition::Calculation`](crate::Condition::Calculation). Formulas reference
+//! entity attributes by name and support arithmetic, comparisons, boolean
+//! logic, conditionals and date arithmetic.
+//!
+//! # Syntax
+//!
+//! - Literals: `5000`, `0.2`, `true`, `"text"`, ISO dates such as `2024-04-01`,
+//!   money amounts such as `12500.50 JPY`
+//! - Attribute references: `income`, `household.size`, or backtick-quoted
+//!   names such as `` `gross-income` ``
+//! - Arithmetic: `+ - * / %` and unary `-`
+//! - Comparisons: `== != < <= > >=`
+//! - Logic: `and`, `or`, `not` (or `&&`, `||`, `!`)
+//! - Conditionals: `if age >= 65 then 1000 else 500`
+//! - Functions: `min`, `max`, `round`, `floor`, `ceil`, `abs`, `if`, `date`,
+//!   `today`, `year`, `month`, `day`, `days_between`, `months_between`,
+//!   `years_between`, `add_days`, `add_months`, `add_years`
+//!
+//! Subtracting two dates yields a number of days; adding a number to a date
+//! shifts it by that many days.
+//!
+//! Numeric literals and attributes are exact decimals ([`Decimal`]), so
+//! `0.1 + 0.2 == 0.3` holds and thresholds such as `12500.50` compare exactly.
+//! Money amounts carry their currency: combining or comparing amounts in
+//! different currencies is an error, while an untagged number adopts the
+//! currency of the amount it is combined with.
+//!
+//! # Examples
+//!
+//! ```
+//! use legalis_core::formula::{Formula, FormulaValue};
+//! use std::collections::HashMap;
+//!
+//! let formula = Formula::parse("income * 0.2 - deductions > 5000").unwrap();
+//! assert_eq!(formula.attributes(), vec!["deductions", "income"]);
+//!
+//! let mut attrs = HashMap::new();
+//! attrs.insert("income".to_string(), "40000".to_string());
+//! attrs.insert("deductions".to_string(), "2000".to_string());
+//!
+//! assert_eq!(formula.evaluate(&attrs).unwrap(), FormulaValue::Bool(true));
+//! ```
+
+use crate::decimal::{Decimal, RoundingMode};
+use crate::{ComparisonOp, EvaluationContext};
+use chrono::{Datelike, Duration, Months, NaiveDate};
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+/// Errors produced while parsing or evaluating a formula.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum FormulaError {
+    #[error("Syntax error at position {position}: {message}")]
+    Syntax { position: usize, message: String },
+
+    #[error("Unknown attribute: {0}")]
+    UnknownAttribute(String),
+
+    #[error("Unknown function: {0}")]
+    UnknownFunction(String),
+
+    #[error("Function '{name}' expects {expected} argument(s), got {actual}")]
+    Arity {
+        name: String,
+        expected: String,
+        actual: usize,
+    },
+
+    #[error("Type mismatch: expected {expected}, found {found}")]
+    TypeMismatch {
+        expected: FormulaType,
+        found: FormulaType,
+    },
+
+    #[error("Currency mismatch: expected {expected}, found {found}")]
+    CurrencyMismatch { expected: String, found: String },
+
+    #[error("Division by zero")]
+    DivisionByZero,
+
+    #[error("Invalid date: {0}")]
+    InvalidDate(String),
+
+    #[error("Value '{value}' is not one of: {}", allowed.join(", "))]
+    InvalidVariant { value: String, allowed: Vec<String> },
+}
+
+/// Static type of a formula value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum FormulaType {
+    Number,
+    Money,
+    Bool,
+    Date,
+    Text,
+}
+
+impl fmt::Display for FormulaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number => write!(f, "number"),
+            Self::Money => write!(f, "money"),
+            Self::Bool => write!(f, "bool"),
+            Self::Date => write!(f, "date"),
+            Self::Text => write!(f, "text"),
+        }
+    }
+}
+
+/// Runtime value produced by formula evaluation.
+///
+/// Numbers are compared by value, so `Number(0.5)` equals `Decimal(0.50)`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum FormulaValue {
+    /// Floating-point number, produced only where an exact result is unavailable
+    Number(f64),
+    /// Exact decimal number
+    Decimal(Decimal),
+    /// Exact amount in an ISO 4217 currency
+    Money {
+        amount: Decimal,
+        currency: String,
+    },
+    Bool(bool),
+    Date(NaiveDate),
+    Text(String),
+}
+
+impl PartialEq for FormulaValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a == b,
+            (Self::Number(a), Self::Decimal(b)) | (Self::Decimal(b), Self::Number(a)) => {
+                *a == b.to_f64()
+            }
+            (Self::Decimal(a), Self::Decimal(b)) => a == b,
+            (
+                Self::Money { amount, currency },
+                Self::Money {
+                    amount: other_amount,
+                    currency: other_currency,
+                },
+            ) => amount == other_amount && currency == other_currency,
+            (Self::Bool(a), Self::Bool(b)) => a == b,
+            (Self::Date(a), Self::Date(b)) => a == b,
+            (Self::Text(a), Self::Text(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl FormulaValue {
+    /// Parses an attribute string into the most specific value type.
+    ///
+    /// Numbers win over dates, dates over booleans, and anything else is text.
+    /// Decimal strings stay exact, and `"<amount> <CCY>"` becomes money.
+    pub fn from_attribute(raw: &str) -> Self {
+        let trimmed = raw.trim();
+        if let Ok(d) = trimmed.parse::<Decimal>() {
+            return Self::Decimal(d);
+        }
+        if let Some((amount, currency)) = trimmed.split_once(' ')
+            && let (Ok(amount), true) = (amount.parse::<Decimal>(), is_currency_code(currency))
+        {
+            return Self::Money {
+                amount,
+                currency: currency.to_string(),
+            };
+        }
+        if let Ok(n) = trimmed.parse::<f64>() {
+            return Self::Number(n);
+        }
+        if let Ok(d) = NaiveDate::parse_from_str(trimmed, "%Y-%m-%d") {
+            return Self::Date(d);
+        }
+        match trimmed {
+            "true" => Self::Bool(true),
+            "false" => Self::Bool(false),
+            _ => Self::Text(raw.to_string()),
+        }
+    }
+
+    /// Returns the type of this value.
+    pub fn value_type(&self) -> FormulaType {
+        match self {
+            Self::Number(_) | Self::Decimal(_) => FormulaType::Number,
+            Self::Money { .. } => FormulaType::Money,
+            Self::Bool(_) => FormulaType::Bool,
+            Self::Date(_) => FormulaType::Date,
+            Self::Text(_) => FormulaType::Text,
+        }
+    }
+
+    /// Returns the numeric value (the amount, for money), failing on any other type.
+    pub fn as_number(&self) -> Result<f64, FormulaError> {
+        match self {
+            Self::Number(n) => Ok(*n),
+            Self::Decimal(d) | Self::Money { amount: d, .. } => Ok(d.to_f64()),
+            other => Err(FormulaError::TypeMismatch {
+                expected: FormulaType::Number,
+                found: other.value_type(),
+            }),
+        }
+    }
+
+    /// Returns the exact numeric value (the amount, for money), if there is one.
+    pub fn as_decimal(&self) -> Option<Decimal> {
+        match self {
+            Self::Number(n) => Decimal::from_f64(*n),
+            Self::Decimal(d) | Self::Money { amount: d, .. } => Some(*d),
+            _ => None,
+        }
+    }
+
+    fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            Self::Number(_) | Self::Decimal(_) | Self::Money { .. }
+        )
+    }
+
+    fn is_plain_number(&self) -> bool {
+        matches!(self, Self::Number(_) | Self::Decimal(_))
+    }
+
+    /// Returns the currency of a money value.
+    pub fn currency(&self) -> Option<&str> {
+        match self {
+            Self::Money { currency, .. } => Some(currency),
+            _ => None,
+        }
+    }
+
+    /// Returns the boolean value, failing on any other type.
+    pub fn as_bool(&self) -> Result<bool, FormulaError> {
+        match self {
+            Self::Bool(b) => Ok(*b),
+            other => Err(FormulaError::TypeMismatch {
+                expected: FormulaType::Bool,
+                found: other.value_type(),
+            }),
+        }
+    }
+
+    /// Returns the date value, failing on any other type.
+    pub fn as_date(&self) -> Result<NaiveDate, FormulaError> {
+        match self {
+            Self::Date(d) => Ok(*d),
+            other => Err(FormulaError::TypeMismatch {
+                expected: FormulaType::Date,
+                found: other.value_type(),
+            }),
+        }
+    }
+
+    /// Converts the value to `f64` for comparison against a threshold.
+    ///
+    /// Booleans map to `1.0`/`0.0` so that predicate formulas can be used with
+    /// [`Condition::Calculation`](crate::Condition::Calculation).
+    pub fn to_f64(&self) -> Result<f64, FormulaError> {
+        match self {
+            Self::Number(n) => Ok(*n),
+            Self::Decimal(d) | Self::Money { amount: d, .. } => Ok(d.to_f64()),
+            Self::Bool(b) => Ok(if *b { 1.0 } else { 0.0 }),
+            other => Err(FormulaError::TypeMismatch {
+                expected: FormulaType::Number,
+                found: other.value_type(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for FormulaValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Decimal(d) => write!(f, "{}", d),
+            Self::Money { amount, currency } => write!(f, "{} {}", amount, currency),
+            Self::Bool(b) => write!(f, "{}", b),
+            Self::Date(d) => write!(f, "{}", d.format("%Y-%m-%d")),
+            Self::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// Binary arithmetic operators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum ArithmeticOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+}
+
+impl ArithmeticOp {
+    fn precedence(self) -> u8 {
+        match self {
+            Self::Add | Self::Subtract => 4,
+            Self::Multiply | Self::Divide | Self::Modulo => 5,
+        }
+    }
+}
+
+impl fmt::Display for ArithmeticOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Add => write!(f, "+"),
+            Self::Subtract => write!(f, "-"),
+            Self::Multiply => write!(f, "*"),
+            Self::Divide => write!(f, "/"),
+            Self::Modulo => write!(f, "%"),
+        }
+    }
+}
+
+/// Formula abstract syntax tree.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum Expr {
+    /// Floating-point literal too large or precise for [`Decimal`]
+    Number(f64),
+    /// Exact numeric literal
+    Decimal(Decimal),
+    /// Money literal such as `12500.50 JPY`
+    Money(Decimal, String),
+    /// Boolean literal
+    Bool(bool),
+    /// String literal
+    Text(String),
+    /// Date literal
+    Date(NaiveDate),
+    /// Reference to an entity attribute
+    Attribute(String),
+    /// Arithmetic negation
+    Negate(Box<Expr>),
+    /// Logical negation
+    Not(Box<Expr>),
+    /// Arithmetic operation
+    Arithmetic(ArithmeticOp, Box<Expr>, Box<Expr>),
+    /// Comparison producing a boolean
+    Compare(ComparisonOp, Box<Expr>, Box<Expr>),
+    /// Logical AND
+    And(Box<Expr>, Box<Expr>),
+    /// Logical OR
+    Or(Box<Expr>, Box<Expr>),
+    /// Conditional expression
+    If(Box<Expr>, Box<Expr>, Box<Expr>),
+    /// Built-in function call
+    Call(String, Vec<Expr>),
+}
+
+impl Expr {
+    /// Collects the names of all attributes referenced by this expression.
+    pub fn collect_attributes(&self, out: &mut BTreeSet<String>) {
+        match self {
+            Self::Attribute(name) => {
+                out.insert(name.clone());
+            }
+            Self::Negate(e) | Self::Not(e) => e.collect_attributes(out),
+            Self::Arithmetic(_, l, r)
+            | Self::Compare(_, l, r)
+            | Self::And(l, r)
+            | Self::Or(l, r) => {
+                l.collect_attributes(out);
+                r.collect_attributes(out);
+            }
+            Self::If(c, t, e) => {
+                c.collect_attributes(out);
+                t.collect_attributes(out);
+                e.collect_attributes(out);
+            }
+            Self::Call(_, args) => {
+                for arg in args {
+                    arg.collect_attributes(out);
+                }
+            }
+            Self::Number(_)
+            | Self::Decimal(_)
+            | Self::Money(..)
+            | Self::Bool(_)
+            | Self::Text(_)
+            | Self::Date(_) => {}
+        }
+    }
+
+    /// Returns true if the expression references no attributes.
+    pub fn is_constant(&self) -> bool {
+        let mut attrs = BTreeSet::new();
+        self.collect_attributes(&mut attrs);
+        attrs.is_empty() && !self.calls_today()
+    }
+
+    fn calls_today(&self) -> bool {
+        match self {
+            Self::Call(name, args) => name == "today" || args.iter().any(Self::calls_today),
+            Self::Negate(e) | Self::Not(e) => e.calls_today(),
+            Self::Arithmetic(_, l, r)
+            | Self::Compare(_, l, r)
+            | Self::And(l, r)
+            | Self::Or(l, r) => l.calls_today() || r.calls_today(),
+            Self::If(c, t, e) => c.calls_today() || t.calls_today() || e.calls_today(),
+            _ => false,
+        }
+    }
+
+    /// Evaluates the expression against a scope.
+    pub fn evaluate<S: FormulaScope + ?Sized>(
+        &self,
+        scope: &S,
+    ) -> Result<FormulaValue, FormulaError> {
+        match self {
+            Self::Number(n) => Ok(FormulaValue::Number(*n)),
+            Self::Decimal(d) => Ok(FormulaValue::Decimal(*d)),
+            Self::Money(amount, currency) => Ok(FormulaValue::Money {
+                amount: *amount,
+                currency: currency.clone(),
+            }),
+            Self::Bool(b) => Ok(FormulaValue::Bool(*b)),
+            Self::Text(s) => Ok(FormulaValue::Text(s.clone())),
+            Self::Date(d) => Ok(FormulaValue::Date(*d)),
+            Self::Attribute(name) => scope
+                .lookup(name)
+                .ok_or_else(|| FormulaError::UnknownAttribute(name.clone())),
+            Self::Negate(e) => eval_negate(e.evaluate(scope)?),
+            Self::Not(e) => Ok(FormulaValue::Bool(!e.evaluate(scope)?.as_bool()?)),
+            Self::Arithmetic(op, l, r) => {
+                eval_arithmetic(*op, l.evaluate(scope)?, r.evaluate(scope)?)
+            }
+            Self::Compare(op, l, r) => eval_compare(*op, &l.evaluate(scope)?, &r.evaluate(scope)?),
+            Self::And(l, r) => {
+                if !l.evaluate(scope)?.as_bool()? {
+                    return Ok(FormulaValue::Bool(false));
+                }
+                Ok(FormulaValue::Bool(r.evaluate(scope)?.as_bool()?))
+            }
+            Self::Or(l, r) => {
+                if l.evaluate(scope)?.as_bool()? {
+                    return Ok(FormulaValue::Bool(true));
+                }
+                Ok(FormulaValue::Bool(r.evaluate(scope)?.as_bool()?))
+            }
+            Self::If(c, t, e) => {
+                if c.evaluate(scope)?.as_bool()? {
+                    t.evaluate(scope)
+                } else {
+                    e.evaluate(scope)
+                }
+            }
+            Self::Call(name, args) => eval_call(name, args, scope),
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Self::If(..) => 0,
+            Self::Or(..) => 1,
+            Self::And(..) => 2,
+            Self::Compare(..) => 3,
+            Self::Arithmetic(op, ..) => op.precedence(),
+            Self::Negate(_) | Self::Not(_) => 6,
+            _ => 7,
+        }
+    }
+
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, min_precedence: u8) -> fmt::Result {
+        if self.precedence() < min_precedence {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Decimal(d) => write!(f, "{}", d),
+            Self::Money(amount, currency) => write!(f, "{} {}", amount, currency),
+            Self::Bool(b) => write!(f, "{}", b),
+            Self::Text(s) => write!(f, "\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"")),
+            Self::Date(d) => write!(f, "{}", d.format("%Y-%m-%d")),
+            Self::Attribute(name) => write!(f, "{}", quote_identifier(name)),
+            Self::Negate(e) => {
+                write!(f, "-")?;
+                e.fmt_operand(f, 6)
+            }
+            Self::Not(e) => {
+                write!(f, "not ")?;
+                e.fmt_operand(f, 6)
+            }
+            Self::Arithmetic(op, l, r) => {
+                let p = op.precedence();
+                l.fmt_operand(f, p)?;
+                write!(f, " {} ", op)?;
+                // Right operands of the same precedence need parentheses: a - (b - c)
+                r.fmt_operand(f, p + 1)
+            }
+            Self::Compare(op, l, r) => {
+                l.fmt_operand(f, 4)?;
+                write!(f, " {} ", op)?;
+                r.fmt_operand(f, 4)
+            }
+            Self::And(l, r) => {
+                l.fmt_operand(f, 2)?;
+                write!(f, " and ")?;
+                r.fmt_operand(f, 3)
+            }
+            Self::Or(l, r) => {
+                l.fmt_operand(f, 1)?;
+                write!(f, " or ")?;
+                r.fmt_operand(f, 2)
+            }
+            Self::If(c, t, e) => write!(f, "if {} then {} else {}", c, t, e),
+            Self::Call(name, args) => {
+                write!(f, "{}(", name)?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", arg)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// Source of attribute values for formula evaluation.
+pub trait FormulaScope {
+    /// Looks up an attribute by name.
+    fn lookup(&self, name: &str) -> Option<FormulaValue>;
+
+    /// Returns the evaluation date used by `today()`.
+    fn today(&self) -> Option<NaiveDate> {
+        self.lookup("current_date").and_then(|v| v.as_date().ok())
+    }
+}
+
+impl FormulaScope for HashMap<String, String> {
+    fn lookup(&self, name: &str) -> Option<FormulaValue> {
+        self.get(name).map(|v| FormulaValue::from_attribute(v))
+    }
+}
+
+impl FormulaScope for HashMap<String, FormulaValue> {
+    fn lookup(&self, name: &str) -> Option<FormulaValue> {
+        self.get(name).cloned()
+    }
+}
+
+/// Adapter exposing an [`EvaluationContext`] as a [`FormulaScope`].
+///
+/// Attributes are read through `get_attribute`, falling back to the
+/// dedicated `age`, `income` and `current_date` accessors.
+pub struct ContextScope<'a, C: EvaluationContext + ?Sized>(pub &'a C);
+
+impl<C: EvaluationContext + ?Sized> FormulaScope for ContextScope<'_, C> {
+    fn lookup(&self, name: &str) -> Option<FormulaValue> {
+        if let Some(raw) = self.0.get_attribute(name) {
+            return Some(FormulaValue::from_attribute(&raw));
+        }
+        match name {
+            "age" => self
+                .0
+                .get_age()
+                .map(|v| FormulaValue::Decimal(Decimal::from(v as i64))),
+            "income" => self
+                .0
+                .get_income()
+                .map(|v| FormulaValue::Decimal(Decimal::new(v as i128, 0))),
+            "current_date" => self.0.get_current_date().map(FormulaValue::Date),
+            _ => None,
+        }
+    }
+
+    fn today(&self) -> Option<NaiveDate> {
+        self.0.get_current_date()
+    }
+}
+
+/// A parsed formula together with its source text.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct Formula {
+    source: String,
+    expr: Expr,
+}
+
+impl Formula {
+    /// Parses a formula from source text.
+    pub fn parse(source: &str) -> Result<Self, FormulaError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.parse_expr()?;
+        if let Some((tok, position)) = parser.tokens.get(parser.pos) {
+            return Err(FormulaError::Syntax {
+                position: *position,
+                message: format!("unexpected token {:?}", tok),
+            });
+        }
+        Ok(Self {
+            source: source.to_string(),
+            expr,
+        })
+    }
+
+    /// Wraps an already-built expression.
+    pub fn from_expr(expr: Expr) -> Self {
+        Self {
+            source: expr.to_string(),
+            expr,
+        }
+    }
+
+    /// Returns the original source text.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the parsed expression.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// Returns the sorted, de-duplicated attribute names this formula reads.
+    pub fn attributes(&self) -> Vec<String> {
+        let mut attrs = BTreeSet::new();
+        self.expr.collect_attributes(&mut attrs);
+        attrs.into_iter().collect()
+    }
+
+    /// Evaluates the formula against a scope.
+    pub fn evaluate<S: FormulaScope + ?Sized>(
+        &self,
+        scope: &S,
+    ) -> Result<FormulaValue, FormulaError> {
+        self.expr.evaluate(scope)
+    }
+
+    /// Evaluates the formula and converts the result to `f64`.
+    ///
+    /// Boolean results are returned as `1.0` (true) or `0.0` (false).
+    pub fn evaluate_f64<S: FormulaScope + ?Sized>(&self, scope: &S) -> Result<f64, FormulaError> {
+        self.evaluate(scope)?.to_f64()
+    }
+}
+
+impl FromStr for Formula {
+    type Err = FormulaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for Formula {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.expr)
+    }
+}
+
+/// Parses and evaluates a formula in one step, returning an `f64`.
+///
+/// # Examples
+///
+/// ```
+/// use legalis_core::formula::evaluate_f64;
+/// use std::collections::HashMap;
+///
+/// let mut attrs = HashMap::new();
+/// attrs.insert("income".to_string(), "50000".to_string());
+/// assert_eq!(evaluate_f64("round(income * 0.0765, 2)", &attrs).unwrap(), 3825.0);
+/// ```
+pub fn evaluate_f64<S: FormulaScope + ?Sized>(
+    source: &str,
+    scope: &S,
+) -> Result<f64, FormulaError> {
+    Formula::parse(source)?.evaluate_f64(scope)
+}
+
+/// Returns `name` as a formula attribute reference, backtick-quoting it when
+/// it is not a plain identifier (for example `` `gross-income` ``).
+///
+/// # Examples
+///
+/// ```
+/// use legalis_core::formula::quote_identifier;
+///
+/// assert_eq!(quote_identifier("income"), "income");
+/// assert_eq!(quote_identifier("gross-income"), "`gross-income`");
+/// ```
+pub fn quote_identifier(name: &str) -> String {
+    const KEYWORDS: [&str; 8] = ["and", "or", "not", "if", "then", "else", "true", "false"];
+
+    let mut chars = name.chars();
+    let plain = chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+        && !KEYWORDS.iter().any(|k| k.eq_ignore_ascii_case(name))
+        && !is_currency_code(name);
+    if plain {
+        name.to_string()
+    } else {
+        format!("`{}`", name.replace('\\', "\\\\").replace('`', "\\`"))
+    }
+}
+
+/// Returns true for a three-letter upper-case ISO 4217 code such as `JPY`.
+pub fn is_currency_code(code: &str) -> bool {
+    code.len() == 3 && code.bytes().all(|b| b.is_ascii_uppercase())
+}
+
+// ==================================================
+// Evaluation helpers
+// ==================================================
+
+fn eval_negate(value: FormulaValue) -> Result<FormulaValue, FormulaError> {
+    match value {
+        FormulaValue::Number(n) => Ok(FormulaValue::Number(-n)),
+        FormulaValue::Decimal(d) => Ok(FormulaValue::Decimal(-d)),
+        FormulaValue::Money { amount, currency } => Ok(FormulaValue::Money {
+            amount: -amount,
+            currency,
+        }),
+        other => Err(FormulaError::TypeMismatch {
+            expected: FormulaType::Number,
+            found: other.value_type(),
+        }),
+    }
+}
+
+fn eval_arithmetic(
+    op: ArithmeticOp,
+    left: FormulaValue,
+    right: FormulaValue,
+) -> Result<FormulaValue, FormulaError> {
+    use FormulaValue::Date;
+
+    match (op, &left, &right) {
+        (ArithmeticOp::Subtract, Date(a), Date(b)) => Ok(FormulaValue::Decimal(Decimal::from(
+            a.signed_duration_since(*b).num_days(),
+        ))),
+        (ArithmeticOp::Add, Date(d), n) | (ArithmeticOp::Add, n, Date(d))
+            if n.is_plain_number() =>
+        {
+            shift_days(*d, n.as_number()?).map(Date)
+        }
+        (ArithmeticOp::Subtract, Date(d), n) if n.is_plain_number() => {
+            shift_days(*d, -n.as_number()?).map(Date)
+        }
+        _ => eval_numeric(op, &left, &right),
+    }
+}
+
+/// Applies an arithmetic operator to two numbers or money amounts.
+///
+/// The result is exact unless one operand is a float or the exact result
+/// overflows, in which case it falls back to `f64`.
+fn eval_numeric(
+    op: ArithmeticOp,
+    left: &FormulaValue,
+    right: &FormulaValue,
+) -> Result<FormulaValue, FormulaError> {
+    let a = left.as_number()?;
+    let b = right.as_number()?;
+    let currency = result_currency(op, left, right)?;
+
+    let floats =
+        matches!(left, FormulaValue::Number(_)) || matches!(right, FormulaValue::Number(_));
+    let exact = match (left.as_decimal(), right.as_decimal()) {
+        (Some(x), Some(y)) if !floats || currency.is_some() => match op {
+            ArithmeticOp::Add => x.checked_add(&y),
+            ArithmeticOp::Subtract => x.checked_sub(&y),
+            ArithmeticOp::Multiply => x.checked_mul(&y),
+            ArithmeticOp::Divide if y.is_zero() => return Err(FormulaError::DivisionByZero),
+            ArithmeticOp::Divide => x.div_rounded(&y),
+            ArithmeticOp::Modulo if y.is_zero() => return Err(FormulaError::DivisionByZero),
+            ArithmeticOp::Modulo => x.checked_rem(&y),
+        },
+        _ => None,
+    };
+    let value = match exact {
+        Some(value) => value,
+        None => {
+            let value = match op {
+                ArithmeticOp::Add => a + b,
+                ArithmeticOp::Subtract => a - b,
+                ArithmeticOp::Multiply => a * b,
+                ArithmeticOp::Divide | ArithmeticOp::Modulo if b == 0.0 => {
+                    return Err(FormulaError::DivisionByZero);
+                }
+                ArithmeticOp::Divide => a / b,
+                ArithmeticOp::Modulo => a % b,
+            };
+            // Money stays money; plain numbers keep the float result
+            match currency.as_ref().and_then(|_| Decimal::from_f64(value)) {
+                Some(amount) => amount,
+                None => return Ok(FormulaValue::Number(value)),
+            }
+        }
+    };
+    Ok(match currency {
+        Some(currency) => FormulaValue::Money {
+            amount: value,
+            currency,
+        },
+        None => FormulaValue::Decimal(value),
+    })
+}
+
+/// Returns the currency of an arithmetic result, rejecting mixed currencies.
+fn result_currency(
+    op: ArithmeticOp,
+    left: &FormulaValue,
+    right: &FormulaValue,
+) -> Result<Option<String>, FormulaError> {
+    match (left.currency(), right.currency()) {
+        (Some(expected), Some(found)) if expected != found => Err(FormulaError::CurrencyMismatch {
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }),
+        (Some(currency), Some(_)) => match op {
+            ArithmeticOp::Add | ArithmeticOp::Subtract | ArithmeticOp::Modulo => {
+                Ok(Some(currency.to_string()))
+            }
+            // A ratio of two amounts is a plain number
+            ArithmeticOp::Divide => Ok(None),
+            ArithmeticOp::Multiply => Err(FormulaError::TypeMismatch {
+                expected: FormulaType::Number,
+                found: FormulaType::Money,
+            }),
+        },
+        (Some(currency), None) => Ok(Some(currency.to_string())),
+        (None, Some(currency)) => match op {
+            ArithmeticOp::Divide => Ok(None),
+            _ => Ok(Some(currency.to_string())),
+        },
+        (None, None) => Ok(None),
+    }
+}
+
+/// Orders two numeric values, exactly where both have an exact value.
+fn compare_numbers(left: &FormulaValue, right: &FormulaValue) -> Result<Ordering, FormulaError> {
+    if let (Some(expected), Some(found)) = (left.currency(), right.currency())
+        && expected != found
+    {
+        return Err(FormulaError::CurrencyMismatch {
+            expected: expected.to_string(),
+            found: found.to_string(),
+        });
+    }
+    let (a, b) = (left.as_number()?, right.as_number()?);
+    Ok(match (left.as_decimal(), right.as_decimal()) {
+        (Some(x), Some(y)) => x.cmp(&y),
+        _ => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+    })
+}
+
+fn eval_compare(
+    op: ComparisonOp,
+    left: &FormulaValue,
+    right: &FormulaValue,
+) -> Result<FormulaValue, FormulaError> {
+    let ordering = match (left, right) {
+        (FormulaValue::Number(a), FormulaValue::Number(b)) => {
+            return Ok(FormulaValue::Bool(op.compare_f64(*a, *b)));
+        }
+        (l, r) if l.is_numeric() && r.is_numeric() => compare_numbers(l, r)?,
+        (FormulaValue::Date(a), FormulaValue::Date(b)) => a.cmp(b),
+        (FormulaValue::Text(a), FormulaValue::Text(b)) => a.cmp(b),
+        (FormulaValue::Bool(a), FormulaValue::Bool(b)) if op.is_equality() => a.cmp(b),
+        _ => {
+            return Err(FormulaError::TypeMismatch {
+                expected: left.value_type(),
+                found: right.value_type(),
+            });
+        }
+    };
+    let result = match op {
+        ComparisonOp::Equal => ordering.is_eq(),
+        ComparisonOp::NotEqual => ordering.is_ne(),
+        ComparisonOp::GreaterThan => ordering.is_gt(),
+        ComparisonOp::GreaterOrEqual => ordering.is_ge(),
+        ComparisonOp::LessThan => ordering.is_lt(),
+        ComparisonOp::LessOrEqual => ordering.is_le(),
+    };
+    Ok(FormulaValue::Bool(result))
+}
+
+fn shift_days(date: NaiveDate, days: f64) -> Result<NaiveDate, FormulaError> {
+    date.checked_add_signed(Duration::days(days.trunc() as i64))
+        .ok_or_else(|| FormulaError::InvalidDate(format!("{} + {} days", date, days)))
+}
+
+fn shift_months(date: NaiveDate, months: f64) -> Result<NaiveDate, FormulaError> {
+    let months = months.trunc() as i64;
+    let shifted = if months >= 0 {
+        date.checked_add_months(Months::new(months as u32))
+    } else {
+        date.checked_sub_months(Months::new(months.unsigned_abs() as u32))
+    };
+    shifted.ok_or_else(|| FormulaError::InvalidDate(format!("{} + {} months", date, months)))
+}
+
+/// Whole calendar months elapsed from `from` to `to` (negative if `to` is earlier).
+fn months_between(from: NaiveDate, to: NaiveDate) -> i64 {
+    let (start, end, sign) = if to >= from {
+        (from, to, 1)
+    } else {
+        (to, from, -1)
+    };
+    let mut months =
+        (end.year() - start.year()) as i64 * 12 + end.month() as i64 - start.month() as i64;
+    if end.day() < start.day() {
+        months -= 1;
+    }
+    months * sign
+}
+
+fn integer(value: i64) -> FormulaValue {
+    FormulaValue::Decimal(Decimal::from(value))
+}
+
+/// Rounds a number or money amount to `digits` fractional digits, exactly
+/// where possible and with `fallback` for floats.
+fn round_value(
+    value: FormulaValue,
+    digits: f64,
+    mode: RoundingMode,
+    fallback: impl Fn(f64) -> f64,
+) -> Result<FormulaValue, FormulaError> {
+    let exact_digits = (digits >= 0.0).then_some(digits as u32);
+    match (value, exact_digits) {
+        (FormulaValue::Decimal(d), Some(digits)) => {
+            Ok(FormulaValue::Decimal(d.round(digits, mode)))
+        }
+        (FormulaValue::Money { amount, currency }, Some(digits)) => Ok(FormulaValue::Money {
+            amount: amount.round(digits, mode),
+            currency,
+        }),
+        (FormulaValue::Money { amount, currency }, None) => Ok(FormulaValue::Money {
+            amount: Decimal::from_f64(fallback(amount.to_f64())).unwrap_or(amount),
+            currency,
+        }),
+        (value, _) => Ok(FormulaValue::Number(fallback(value.as_number()?))),
+    }
+}
+
+fn check_arity(name: &str, args: &[Expr], min: usize, max: usize) -> Result<(), FormulaError> {
+    if args.len() < min || args.len() > max {
+        let expected = if min == max {
+            min.to_string()
+        } else if max == usize::MAX {
+            format!("at least {}", min)
+        } else {
+            format!("{} to {}", min, max)
+        };
+        return Err(FormulaError::Arity {
+            name: name.to_string(),
+            expected,
+            actual: args.len(),
+        });
+    }
+    Ok(())
+}
+
+fn eval_call<S: FormulaScope + ?Sized>(
+    name: &str,
+    args: &[Expr],
+    scope: &S,
+) -> Result<FormulaValue, FormulaError> {
+    let number = |i: usize| -> Result<f64, FormulaError> { args[i].evaluate(scope)?.as_number() };
+    let date = |i: usize| -> Result<NaiveDate, FormulaError> { args[i].evaluate(scope)?.as_date() };
+
+    match name {
+        "min" | "max" => {
+            check_arity(name, args, 1, usize::MAX)?;
+            let mut acc = args[0].evaluate(scope)?;
+            acc.as_number()?;
+            for arg in &args[1..] {
+                let value = arg.evaluate(scope)?;
+                let ordering = compare_numbers(&value, &acc)?;
+                if (name == "min" && ordering.is_lt()) || (name == "max" && ordering.is_gt()) {
+                    acc = value;
+                }
+            }
+            Ok(acc)
+        }
+        "round" => {
+            check_arity(name, args, 1, 2)?;
+            let digits = if args.len() == 2 { number(1)? } else { 0.0 };
+            // Ties round away from zero, like f64::round
+            round_value(
+                args[0].evaluate(scope)?,
+                digits,
+                RoundingMode::HalfUp,
+                |v| {
+                    let factor = 10f64.powi(digits as i32);
+                    (v * factor).round() / factor
+                },
+            )
+        }
+        "floor" => {
+            check_arity(name, args, 1, 1)?;
+            round_value(
+                args[0].evaluate(scope)?,
+                0.0,
+                RoundingMode::Floor,
+                f64::floor,
+            )
+        }
+        "ceil" => {
+            check_arity(name, args, 1, 1)?;
+            round_value(
+                args[0].evaluate(scope)?,
+                0.0,
+                RoundingMode::Ceiling,
+                f64::ceil,
+            )
+        }
+        "abs" => {
+            check_arity(name, args, 1, 1)?;
+            match args[0].evaluate(scope)? {
+                value if value.as_number()? < 0.0 => eval_negate(value),
+                value => Ok(value),
+            }
+        }
+        "if" => {
+            check_arity(name, args, 3, 3)?;
+            if args[0].evaluate(scope)?.as_bool()? {
+                args[1].evaluate(scope)
+            } else {
+                args[2].evaluate(scope)
+            }
+        }
+        "date" => {
+            check_arity(name, args, 1, 1)?;
+            match args[0].evaluate(scope)? {
+                FormulaValue::Date(d) => Ok(FormulaValue::Date(d)),
+                FormulaValue::Text(s) => NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d")
+                    .map(FormulaValue::Date)
+                    .map_err(|_| FormulaError::InvalidDate(s)),
+                other => Err(FormulaError::TypeMismatch {
+                    expected: FormulaType::Text,
+                    found: other.value_type(),
+                }),
+            }
+        }
+        "today" => {
+            check_arity(name, args, 0, 0)?;
+            scope
+                .today()
+                .map(FormulaValue::Date)
+                .ok_or_else(|| FormulaError::UnknownAttribute("current_date".to_string()))
+        }
+        "year" => {
+            check_arity(name, args, 1, 1)?;
+            Ok(integer(date(0)?.year() as i64))
+        }
+        "month" => {
+            check_arity(name, args, 1, 1)?;
+            Ok(integer(date(0)?.month() as i64))
+        }
+        "day" => {
+            check_arity(name, args, 1, 1)?;
+            Ok(integer(date(0)?.day() as i64))
+        }
+        "days_between" => {
+            check_arity(name, args, 2, 2)?;
+            let days = date(1)?.signed_duration_since(date(0)?).num_days();
+            Ok(integer(days))
+        }
+        "months_between" => {
+            check_arity(name, args, 2, 2)?;
+            Ok(integer(months_between(date(0)?, date(1)?)))
+        }
+        "years_between" => {
+            check_arity(name, args, 2, 2)?;
+            Ok(integer(months_between(date(0)?, date(1)?) / 12))
+        }
+        "add_days" => {
+            check_arity(name, args, 2, 2)?;
+            shift_days(date(0)?, number(1)?).map(FormulaValue::Date)
+        }
+        "add_months" => {
+            check_arity(name, args, 2, 2)?;
+            shift_months(date(0)?, number(1)?).map(FormulaValue::Date)
+        }
+        "add_years" => {
+            check_arity(name, args, 2, 2)?;
+            shift_months(date(0)?, number(1)? * 12.0).map(FormulaValue::Date)
+        }
+        _ => Err(FormulaError::UnknownFunction(name.to_string())),
+    }
+}
+
+// ==================================================
+// Lexer
+// ==================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Decimal(Decimal),
+    Date(NaiveDate),
+    Text(String),
+    Ident(String),
+    QuotedIdent(String),
+    Op(&'static str),
+    LParen,
+    RParen,
+    Comma,
+}
+
+fn tokenize(source: &str) -> Result<Vec<(Token, usize)>, FormulaError> {
+    let chars: Vec<(usize, char)> = source.char_indices().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (pos, c) = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if c.is_ascii_digit()
+            || (c == '.' && chars.get(i + 1).is_some_and(|(_, n)| n.is_ascii_digit()))
+        {
+            // ISO date literal: YYYY-MM-DD with no surrounding spaces
+            let rest: String = chars[i..].iter().take(10).map(|(_, ch)| *ch).collect();
+            if rest.len() == 10 && is_iso_date(&rest) {
+                let date = NaiveDate::parse_from_str(&rest, "%Y-%m-%d")
+                    .map_err(|_| FormulaError::InvalidDate(rest.clone()))?;
+                tokens.push((Token::Date(date), pos));
+                i += 10;
+                continue;
+            }
+            let start = i;
+            while i < chars.len()
+                && (chars[i].1.is_ascii_digit() || chars[i].1 == '.' || chars[i].1 == '_')
+            {
+                i += 1;
+            }
+            let text: String = chars[start..i]
+                .iter()
+                .map(|(_, ch)| *ch)
+                .filter(|ch| *ch != '_')
+                .collect();
+            if let Ok(value) = text.parse::<Decimal>() {
+                tokens.push((Token::Decimal(value), pos));
+                continue;
+            }
+            let value = text.parse::<f64>().map_err(|_| FormulaError::Syntax {
+                position: pos,
+                message: format!("invalid number '{}'", text),
+            })?;
+            tokens.push((Token::Number(value), pos));
+            continue;
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].1.is_alphanumeric() || chars[i].1 == '_' || chars[i].1 == '.')
+            {
+                i += 1;
+            }
+            let ident: String = chars[start..i].iter().map(|(_, ch)| *ch).collect();
+            tokens.push((Token::Ident(ident), pos));
+            continue;
+        }
+
+        if c == '"' || c == '\'' || c == '`' {
+            let quote = c;
+            let mut text = String::new();
+            i += 1;
+            loop {
+                match chars.get(i) {
+                    Some((_, '\\')) => {
+                        if let Some((_, escaped)) = chars.get(i + 1) {
+                            text.push(*escaped);
+                        }
+                        i += 2;
+                    }
+                    Some((_, ch)) if *ch == quote => {
+                        i += 1;
+                        break;
+                    }
+                    Some((_, ch)) => {
+                        text.push(*ch);
+                        i += 1;
+                    }
+                    None => {
+                        return Err(FormulaError::Syntax {
+                            position: pos,
+                            message: "unterminated string literal".to_string(),
+                        });
+                    }
+                }
+            }
+            let token = if quote == '`' {
+                Token::QuotedIdent(text)
+            } else {
+                Token::Text(text)
+            };
+            tokens.push((token, pos));
+            continue;
+        }
+
+        let next = chars.get(i + 1).map(|(_, ch)| *ch);
+        let (token, width) = match (c, next) {
+            ('(', _) => (Token::LParen, 1),
+            (')', _) => (Token::RParen, 1),
+            (',', _) => (Token::Comma, 1),
+            ('=', Some('=')) => (Token::Op("=="), 2),
+            ('!', Some('=')) => (Token::Op("!="), 2),
+            ('<', Some('=')) => (Token::Op("<="), 2),
+            ('>', Some('=')) => (Token::Op(">="), 2),
+            ('&', Some('&')) => (Token::Op("and"), 2),
+            ('|', Some('|')) => (Token::Op("or"), 2),
+            ('=', _) => (Token::Op("=="), 1),
+            ('<', _) => (Token::Op("<"), 1),
+            ('>', _) => (Token::Op(">"), 1),
+            ('!', _) => (Token::Op("not"), 1),
+            ('+', _) => (Token::Op("+"), 1),
+            ('-', _) => (Token::Op("-"), 1),
+            ('*', _) => (Token::Op("*"), 1),
+            ('/', _) => (Token::Op("/"), 1),
+            ('%', _) => (Token::Op("%"), 1),
+            _ => {
+                return Err(FormulaError::Syntax {
+                    position: pos,
+                    message: format!("unexpected character '{}'", c),
+                });
+            }
+        };
+        tokens.push((token, pos));
+        i += width;
+    }
+
+    Ok(tokens)
+}
+
+fn is_iso_date(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    bytes.len() == 10
+        && bytes[4] == b'-'
+        && bytes[7] == b'-'
+        && bytes
+            .iter()
+            .enumerate()
+            .all(|(i, b)| i == 4 || i == 7 || b.is_ascii_digit())
+}
+
+// ==================================================
+// Parser
+// ==================================================
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or_else(|| self.tokens.last())
+            .map(|(_, p)| *p)
+            .unwrap_or(0)
+    }
+
+    fn error(&self, message: impl Into<String>) -> FormulaError {
+        FormulaError::Syntax {
+            position: self.position(),
+            message: message.into(),
+        }
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Ident(id)) if id.eq_ignore_ascii_case(keyword))
+    }
+
+    fn is_op(&self, op: &str) -> bool {
+        matches!(self.peek(), Some(Token::Op(o)) if *o == op)
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), FormulaError> {
+        if self.is_keyword(keyword) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(format!("expected '{}'", keyword)))
+        }
+    }
+
+    fn parse_expr(&mut self) -> Result<Expr, FormulaError> {
+        if self.is_keyword("if")
+            && !matches!(self.tokens.get(self.pos + 1), Some((Token::LParen, _)))
+        {
+            self.pos += 1;
+            let cond = self.parse_expr()?;
+            self.expect_keyword("then")?;
+            let then = self.parse_expr()?;
+            self.expect_keyword("else")?;
+            let otherwise = self.parse_expr()?;
+            return Ok(Expr::If(
+                Box::new(cond),
+                Box::new(then),
+                Box::new(otherwise),
+            ));
+        }
+        self.parse_or()
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, FormulaError> {
+        let mut left = self.parse_and()?;
+        while self.is_keyword("or") || self.is_op("or") {
+            self.pos += 1;
+            let right = self.parse_and()?;
+            left = Expr::Or(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, FormulaError> {
+        let mut left = self.parse_not()?;
+        while self.is_keyword("and") || self.is_op("and") {
+            self.pos += 1;
+            let right = self.parse_not()?;
+            left = Expr::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_not(&mut self) -> Result<Expr, FormulaError> {
+        if self.is_keyword("not") || self.is_op("not") {
+            self.pos += 1;
+            return Ok(Expr::Not(Box::new(self.parse_not()?)));
+        }
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expr, FormulaError> {
+        let left = self.parse_additive()?;
+        let op = match self.peek() {
+            Some(Token::Op("==")) => ComparisonOp::Equal,
+            Some(Token::Op("!=")) => ComparisonOp::NotEqual,
+            Some(Token::Op("<")) => ComparisonOp::LessThan,
+            Some(Token::Op("<=")) => ComparisonOp::LessOrEqual,
+            Some(Token::Op(">")) => ComparisonOp::GreaterThan,
+            Some(Token::Op(">=")) => ComparisonOp::GreaterOrEqual,
+            _ => return Ok(left),
+        };
+        self.pos += 1;
+        let right = self.parse_additive()?;
+        Ok(Expr::Compare(op, Box::new(left), Box::new(right)))
+    }
+
+    fn parse_additive(&mut self) -> Result<Expr, FormulaError> {
+        let mut left = self.parse_multiplicative()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Op("+")) => ArithmeticOp::Add,
+                Some(Token::Op("-")) => ArithmeticOp::Subtract,
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            let right = self.parse_multiplicative()?;
+            left = Expr::Arithmetic(op, Box::new(left), Box::new(right));
+        }
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<Expr, FormulaError> {
+        let mut left = self.parse_unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Op("*")) => ArithmeticOp::Multiply,
+                Some(Token::Op("/")) => ArithmeticOp::Divide,
+                Some(Token::Op("%")) => ArithmeticOp::Modulo,
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            let right = self.parse_unary()?;
+            left = Expr::Arithmetic(op, Box::new(left), Box::new(right));
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, FormulaError> {
+        if self.is_op("-") {
+            self.pos += 1;
+            let inner = self.parse_unary()?;
+            return Ok(match inner {
+                Expr::Number(n) => Expr::Number(-n),
+                Expr::Decimal(d) => Expr::Decimal(-d),
+                Expr::Money(amount, currency) => Expr::Money(-amount, currency),
+                other => Expr::Negate(Box::new(other)),
+            });
+        }
+        if self.is_op("+") {
+            self.pos += 1;
+            return self.parse_unary();
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, FormulaError> {
+        let Some((token, _)) = self.tokens.get(self.pos).cloned() else {
+            return Err(self.error("unexpected end of formula"));
+        };
+        self.pos += 1;
+
+        match token {
+            Token::Number(n) => Ok(Expr::Number(n)),
+            Token::Decimal(d) => match self.peek() {
+                Some(Token::Ident(code))
+                    if is_currency_code(code)
+                        && !matches!(self.tokens.get(self.pos + 1), Some((Token::LParen, _))) =>
+                {
+                    let currency = code.clone();
+                    self.pos += 1;
+                    Ok(Expr::Money(d, currency))
+                }
+                _ => Ok(Expr::Decimal(d)),
+            },
+            Token::Date(d) => Ok(Expr::Date(d)),
+            Token::QuotedIdent(name) => Ok(Expr::Attribute(name)),
+            Token::Text(s) => Ok(Expr::Text(s)),
+            Token::LParen => {
+                let inner = self.parse_expr()?;
+                if self.peek() != Some(&Token::RParen) {
+                    return Err(self.error("expected ')'"));
+                }
+                self.pos += 1;
+                Ok(inner)
+            }
+            Token::Ident(name) => {
+                if self.peek() == Some(&Token::LParen) {
+                    self.pos += 1;
+                    let args = self.parse_arguments()?;
+                    return Ok(Expr::Call(name.to_lowercase(), args));
+                }
+                match name.as_str() {
+                    "true" | "TRUE" | "True" => Ok(Expr::Bool(true)),
+                    "false" | "FALSE" | "False" => Ok(Expr::Bool(false)),
+                    _ => Ok(Expr::Attribute(name)),
+                }
+            }
+            other => {
+                self.pos -= 1;
+                Err(self.error(format!("unexpected token {:?}", other)))
+            }
+        }
+    }
+
+    fn parse_arguments(&mut self) -> Result<Vec<Expr>, FormulaError> {
+        let mut args = Vec::new();
+        if self.peek() == Some(&Token::RParen) {
+            self.pos += 1;
+            return Ok(args);
+        }
+        loop {
+            args.push(self.parse_expr()?);
+            match self.peek() {
+                Some(Token::Comma) => self.pos += 1,
+                Some(Token::RParen) => {
+                    self.pos += 1;
+                    return Ok(args);
+                }
+                _ => return Err(self.error("expected ',' or ')'")),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attrs(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_arithmetic_precedence() {
+        let scope = attrs(&[]);
+        assert_eq!(evaluate_f64("2 + 3 * 4", &scope).unwrap(), 14.0);
+        assert_eq!(evaluate_f64("(2 + 3) * 4", &scope).unwrap(), 20.0);
+        assert_eq!(evaluate_f64("10 - 4 - 3", &scope).unwrap(), 3.0);
+        assert_eq!(evaluate_f64("17 % 5", &scope).unwrap(), 2.0);
+        assert_eq!(evaluate_f64("-3 + 5", &scope).unwrap(), 2.0);
+    }
+
+    #[test]
+    fn test_tax_rule() {
+        let formula = Formula::parse("income * 0.2 - deductions > 5000").unwrap();
+        let scope = attrs(&[("income", "40000"), ("deductions", "2000")]);
+        assert_eq!(formula.evaluate(&scope).unwrap(), FormulaValue::Bool(true));
+
+        let scope = attrs(&[("income", "30000"), ("deductions", "2000")]);
+        assert_eq!(formula.evaluate(&scope).unwrap(), FormulaValue::Bool(false));
+    }
+
+    #[test]
+    fn test_functions() {
+        let scope = attrs(&[("income", "12345.678")]);
+        assert_eq!(evaluate_f64("round(income, 2)", &scope).unwrap(), 12345.68);
+        assert_eq!(evaluate_f64("floor(income)", &scope).unwrap(), 12345.0);
+        assert_eq!(evaluate_f64("ceil(income)", &scope).unwrap(), 12346.0);
+        assert_eq!(
+            evaluate_f64("min(income, 10000, 20000)", &scope).unwrap(),
+            10000.0
+        );
+        assert_eq!(evaluate_f64("max(income, 20000)", &scope).unwrap(), 20000.0);
+        assert_eq!(evaluate_f64("abs(-5)", &scope).unwrap(), 5.0);
+    }
+
+    #[test]
+    fn test_conditionals_and_logic() {
+        let scope = attrs(&[("age", "70"), ("resident", "true")]);
+        assert_eq!(
+            evaluate_f64("if age >= 65 then 1000 else 500", &scope).unwrap(),
+            1000.0
+        );
+        assert_eq!(evaluate_f64("if(age < 18, 1, 2)", &scope).unwrap(), 2.0);
+        assert_eq!(
+            evaluate_f64("resident and (age > 60 or age < 18)", &scope).unwrap(),
+            1.0
+        );
+        assert_eq!(evaluate_f64("not resident", &scope).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_date_arithmetic() {
+        let scope = attrs(&[("birth_date", "2000-03-15"), ("current_date", "2024-03-14")]);
+        assert_eq!(
+            evaluate_f64("years_between(birth_date, today())", &scope).unwrap(),
+            23.0
+        );
+        assert_eq!(
+            evaluate_f64("2024-04-01 - 2024-03-01", &scope).unwrap(),
+            31.0
+        );
+        assert_eq!(
+            Formula::parse("add_months(2024-01-31, 1)")
+                .unwrap()
+                .evaluate(&scope)
+                .unwrap(),
+            FormulaValue::Date(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())
+        );
+        assert_eq!(
+            evaluate_f64("birth_date + 1 < date(\"2000-03-17\")", &scope).unwrap(),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let scope = attrs(&[("income", "100")]);
+        assert_eq!(
+            evaluate_f64("income / 0", &scope),
+            Err(FormulaError::DivisionByZero)
+        );
+        assert_eq!(
+            evaluate_f64("missing + 1", &scope),
+            Err(FormulaError::UnknownAttribute("missing".to_string()))
+        );
+        assert!(matches!(
+            evaluate_f64("income +", &scope),
+            Err(FormulaError::Syntax { .. })
+        ));
+        assert!(matches!(
+            evaluate_f64("unknown_fn(1)", &scope),
+            Err(FormulaError::UnknownFunction(_))
+        ));
+        assert!(matches!(
+            evaluate_f64("income and true", &scope),
+            Err(FormulaError::TypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for source in [
+            "income * 0.2 - deductions > 5000",
+            "a - (b - c)",
+            "(a + b) * c",
+            "if age >= 65 then min(pension, 1000) else 0",
+            "not (a and b) or c",
+        ] {
+            let formula = Formula::parse(source).unwrap();
+            let printed = formula.to_string();
+            assert_eq!(Formula::parse(&printed).unwrap().expr(), formula.expr());
+        }
+    }
+
+    #[test]
+    fn test_exact_decimals() {
+        let scope = attrs(&[("income", "12500.50"), ("rate", "0.1")]);
+        assert_eq!(evaluate_f64("0.1 + 0.2 == 0.3", &scope).unwrap(), 1.0);
+        assert_eq!(evaluate_f64("income >= 12500.50", &scope).unwrap(), 1.0);
+        assert_eq!(evaluate_f64("income > 12500.5", &scope).unwrap(), 0.0);
+        assert_eq!(
+            Formula::parse("income * rate")
+                .unwrap()
+                .evaluate(&scope)
+                .unwrap(),
+            FormulaValue::Decimal("1250.050".parse().unwrap())
+        );
+        assert_eq!(evaluate_f64("round(2.5)", &scope).unwrap(), 3.0);
+        assert_eq!(evaluate_f64("10 / 4", &scope).unwrap(), 2.5);
+    }
+
+    #[test]
+    fn test_money() {
+        let scope = attrs(&[("salary", "300000 JPY"), ("bonus", "50000")]);
+        let total = Formula::parse("salary + bonus").unwrap();
+        assert_eq!(
+            total.evaluate(&scope).unwrap(),
+            FormulaValue::Money {
+                amount: Decimal::from(350000),
+                currency: "JPY".to_string(),
+            }
+        );
+        assert_eq!(evaluate_f64("salary >= 300000 JPY", &scope).unwrap(), 1.0);
+        assert_eq!(evaluate_f64("bonus < 60000 JPY", &scope).unwrap(), 1.0);
+        assert_eq!(
+            evaluate_f64("salary >= 100 USD", &scope),
+            Err(FormulaError::CurrencyMismatch {
+                expected: "JPY".to_string(),
+                found: "USD".to_string(),
+            })
+        );
+        assert_eq!(
+            Formula::parse("-12.50 EUR").unwrap().to_string(),
+            "-12.50 EUR"
+        );
+    }
+
+    #[test]
+    fn test_quoted_identifiers() {
+        let scope = attrs(&[("gross-income", "100"), ("and", "5")]);
+        let formula = Formula::parse("`gross-income` - `and` > 90").unwrap();
+        assert_eq!(formula.attributes(), vec!["and", "gross-income"]);
+        assert_eq!(formula.evaluate(&scope).unwrap(), FormulaValue::Bool(true));
+        assert_eq!(formula.to_string(), "`gross-income` - `and` > 90");
+        assert_eq!(quote_identifier("household.size"), "household.size");
+        assert_eq!(quote_identifier("a`b"), "`a\\`b`");
+        assert_eq!(
+            Formula::parse(&quote_identifier("a`b"))
+                .unwrap()
+                .attributes(),
+            vec!["a`b"]
+        );
+    }
+
+    #[test]
+    fn test_attributes() {
+        let formula = Formula::parse("max(income - tax, 0) + income").unwrap();
+        assert_eq!(formula.attributes(), vec!["income", "tax"]);
+        assert!(Formula::parse("1 + 2").unwrap().expr().is_constant());
+        assert!(!Formula::parse("today()").unwrap().expr().is_constant());
+    }
+}
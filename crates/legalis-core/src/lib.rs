@@ -284,7 +284,11 @@
 
 pub mod case_law;
 pub mod const_collections;
+pub mod decimal;
+pub mod defeasible;
 pub mod formats;
+pub mod formula;
+pub mod hierarchy;
 pub mod testing;
 pub mod transactions;
 pub mod typed_attributes;
@@ -379,6 +383,15 @@ pub use case_law::{
 // Re-export Typed Attributes
 pub use typed_attributes::{AttributeError, AttributeValue, TypedAttributes};
 
+// Re-export exact decimal numbers
+pub use decimal::Decimal;
+
+// Re-export computed effect outputs
+pub use typed_effects::{ComputedEffect, EffectOutput, OutputType, OutputValue};
+
+// Re-export statute hierarchy and amendment tracking
+pub use hierarchy::{Amendment, AmendmentType, StatuteHierarchy};
+
 /// Legal judgment result as an Algebraic Data Type (ADT).
 ///
 /// This type embodies the core philosophy of Legalis-RS:
@@ -1182,6 +1195,70 @@ pub enum Condition {
         /// Target value to compare against
         target_value: f64,
     },
+    /// Reference to another statute - satisfied when that statute applies
+    /// Example: "a person eligible under the Child Benefit Act"
+    StatuteApplies {
+        /// ID of the referenced statute
+        statute_id: String,
+    },
+    /// Reference to a computed output of another statute
+    /// Example: `child-benefit.amount >= 10000`; false if the statute does not apply
+    StatuteOutput {
+        /// ID of the referenced statute
+        statute_id: String,
+        /// Name of the effect output
+        output: String,
+        /// Comparison operator
+        operator: ComparisonOp,
+        /// Exact value to compare against
+        value: Decimal,
+        /// Currency the output must be in, for money thresholds
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        currency: Option<String>,
+    },
+    /// Every related entity satisfies the condition (true when there are none)
+    /// Example: "all directors are residents"
+    ForAll {
+        /// Relationship that selects the related entities
+        relationship: RelationshipType,
+        /// Condition evaluated against each related entity
+        condition: Box<Condition>,
+    },
+    /// At least one related entity satisfies the condition
+    /// Example: "any dependent under 18"
+    Exists {
+        /// Relationship that selects the related entities
+        relationship: RelationshipType,
+        /// Condition evaluated against each related entity
+        condition: Box<Condition>,
+    },
+    /// Number of related entities (optionally filtered) compared to a value
+    /// Example: "at least two household members have income below X"
+    Count {
+        /// Relationship that selects the related entities
+        relationship: RelationshipType,
+        /// Only entities satisfying this condition are counted
+        condition: Option<Box<Condition>>,
+        /// Comparison operator
+        operator: ComparisonOp,
+        /// Count to compare against
+        value: u32,
+    },
+    /// Sum of a numeric attribute over related entities compared to a value
+    /// Example: "combined household income below 3,000,000"
+    Sum {
+        /// Relationship that selects the related entities
+        relationship: RelationshipType,
+        /// Attribute summed over the related entities
+        attribute: String,
+        /// Comparison operator
+        operator: ComparisonOp,
+        /// Value to compare against
+        value: f64,
+    },
     /// Logical AND of conditions
     And(Box<Condition>, Box<Condition>),
     /// Logical OR of conditions
@@ -1344,6 +1421,224 @@ impl Condition {
         }
     }
 
+    /// Creates a condition that holds when another statute applies.
+    ///
+    /// # Examples
+    /// ```
+    /// use legalis_core::Condition;
+    ///
+    /// let eligible = Condition::statute_applies("child-benefit-act");
+    /// assert_eq!(eligible.referenced_statutes(), vec!["child-benefit-act"]);
+    /// ```
+    pub fn statute_applies(statute_id: impl Into<String>) -> Self {
+        Self::StatuteApplies {
+            statute_id: statute_id.into(),
+        }
+    }
+
+    /// Creates a condition on a computed output of another statute.
+    ///
+    /// # Examples
+    /// ```
+    /// use legalis_core::{Condition, ComparisonOp, Decimal};
+    ///
+    /// let cond = Condition::statute_output("child-benefit-act", "amount", ComparisonOp::GreaterThan, Decimal::ZERO);
+    /// assert_eq!(format!("{}", cond), "child-benefit-act.amount > 0");
+    /// ```
+    pub fn statute_output(
+        statute_id: impl Into<String>,
+        output: impl Into<String>,
+        operator: ComparisonOp,
+        value: Decimal,
+    ) -> Self {
+        Self::StatuteOutput {
+            statute_id: statute_id.into(),
+            output: output.into(),
+            operator,
+            value,
+            currency: None,
+        }
+    }
+
+    /// Creates a condition on a monetary output of another statute.
+    ///
+    /// The output must be a money value in `currency`; evaluation fails otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use legalis_core::{Condition, ComparisonOp, Decimal};
+    ///
+    /// let amount: Decimal = "10000.50".parse().unwrap();
+    /// let cond = Condition::statute_output_money("child-benefit", "amount", ComparisonOp::GreaterOrEqual, amount, "JPY");
+    /// assert_eq!(format!("{}", cond), "child-benefit.amount >= 10000.50 JPY");
+    /// ```
+    pub fn statute_output_money(
+        statute_id: impl Into<String>,
+        output: impl Into<String>,
+        operator: ComparisonOp,
+        amount: Decimal,
+        currency: impl Into<String>,
+    ) -> Self {
+        Self::StatuteOutput {
+            statute_id: statute_id.into(),
+            output: output.into(),
+            operator,
+            value: amount,
+            currency: Some(currency.into()),
+        }
+    }
+
+    /// Creates a condition that every related entity must satisfy.
+    ///
+    /// # Examples
+    /// ```
+    /// use legalis_core::{Condition, RelationshipType};
+    ///
+    /// let residents = Condition::for_all(
+    ///     RelationshipType::Director,
+    ///     Condition::has_attribute("resident"),
+    /// );
+    /// assert_eq!(format!("{}", residents), "all Director: has_attribute(resident)");
+    /// ```
+    pub fn for_all(relationship: RelationshipType, condition: Condition) -> Self {
+        Self::ForAll {
+            relationship,
+            condition: Box::new(condition),
+        }
+    }
+
+    /// Creates a condition that at least one related entity must satisfy.
+    ///
+    /// # Examples
+    /// ```
+    /// use legalis_core::{Condition, ComparisonOp, RelationshipType};
+    ///
+    /// let minor_child = Condition::exists(
+    ///     RelationshipType::ParentChild,
+    ///     Condition::age(ComparisonOp::LessThan, 18),
+    /// );
+    /// assert_eq!(format!("{}", minor_child), "any ParentChild: age < 18");
+    /// ```
+    pub fn exists(relationship: RelationshipType, condition: Condition) -> Self {
+        Self::Exists {
+            relationship,
+            condition: Box::new(condition),
+        }
+    }
+
+    /// Creates a condition on the number of related entities.
+    ///
+    /// # Examples
+    /// ```
+    /// use legalis_core::{Condition, ComparisonOp, RelationshipType};
+    ///
+    /// let large_family = Condition::count(RelationshipType::ParentChild, ComparisonOp::GreaterOrEqual, 3);
+    /// assert_eq!(format!("{}", large_family), "count(ParentChild) >= 3");
+    /// ```
+    pub fn count(relationship: RelationshipType, operator: ComparisonOp, value: u32) -> Self {
+        Self::Count {
+            relationship,
+            condition: None,
+            operator,
+            value,
+        }
+    }
+
+    /// Creates a condition on the number of related entities satisfying a condition.
+    ///
+    /// # Examples
+    /// ```
+    /// use legalis_core::{Condition, ComparisonOp, RelationshipType};
+    ///
+    /// let low_earners = Condition::count_where(
+    ///     RelationshipType::HouseholdMember,
+    ///     Condition::income(ComparisonOp::LessThan, 1_000_000),
+    ///     ComparisonOp::GreaterOrEqual,
+    ///     2,
+    /// );
+    /// assert_eq!(
+    ///     format!("{}", low_earners),
+    ///     "count(HouseholdMember where income < 1000000) >= 2"
+    /// );
+    /// ```
+    pub fn count_where(
+        relationship: RelationshipType,
+        condition: Condition,
+        operator: ComparisonOp,
+        value: u32,
+    ) -> Self {
+        Self::Count {
+            relationship,
+            condition: Some(Box::new(condition)),
+            operator,
+            value,
+        }
+    }
+
+    /// Creates a condition on the sum of an attribute over related entities.
+    ///
+    /// # Examples
+    /// ```
+    /// use legalis_core::{Condition, ComparisonOp, RelationshipType};
+    ///
+    /// let household_income = Condition::sum(
+    ///     RelationshipType::HouseholdMember,
+    ///     "income",
+    ///     ComparisonOp::LessThan,
+    ///     3_000_000.0,
+    /// );
+    /// assert_eq!(format!("{}", household_income), "sum(HouseholdMember.income) < 3000000");
+    /// ```
+    pub fn sum(
+        relationship: RelationshipType,
+        attribute: impl Into<String>,
+        operator: ComparisonOp,
+        value: f64,
+    ) -> Self {
+        Self::Sum {
+            relationship,
+            attribute: attribute.into(),
+            operator,
+            value,
+        }
+    }
+
+    /// Returns the IDs of statutes referenced by this condition.
+    #[must_use]
+    pub fn referenced_statutes(&self) -> Vec<&str> {
+        let mut ids = Vec::new();
+        self.collect_statute_references(&mut ids);
+        ids
+    }
+
+    fn collect_statute_references<'a>(&'a self, ids: &mut Vec<&'a str>) {
+        match self {
+            Self::StatuteApplies { statute_id } | Self::StatuteOutput { statute_id, .. }
+                if !ids.contains(&statute_id.as_str()) =>
+            {
+                ids.push(statute_id);
+            }
+            Self::And(left, right) | Self::Or(left, right) => {
+                left.collect_statute_references(ids);
+                right.collect_statute_references(ids);
+            }
+            Self::Not(inner) => inner.collect_statute_references(ids),
+            Self::Probabilistic { condition, .. }
+            | Self::ForAll { condition, .. }
+            | Self::Exists { condition, .. } => condition.collect_statute_references(ids),
+            Self::Count {
+                condition: Some(condition),
+                ..
+            } => condition.collect_statute_references(ids),
+            Self::Composite { conditions, .. } => {
+                for (_, condition) in conditions {
+                    condition.collect_statute_references(ids);
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Creates a new Composite condition with weighted sub-conditions.
     ///
     /// # Arguments
@@ -1676,7 +1971,6 @@ impl Condition {
                 operator,
                 value,
             } => {
-                // Simple formula evaluation (can be extended with a proper expression parser)
                 let result = Self::evaluate_formula(formula, ctx)?;
                 Ok(operator.compare_f64(result, *value))
             }
@@ -1709,26 +2003,95 @@ impl Condition {
                         })?;
                 Ok(operator.compare_u32(residency, *months))
             }
+            Self::StatuteApplies { statute_id } | Self::StatuteOutput { statute_id, .. } => {
+                Err(ConditionError::Custom {
+                    message: format!(
+                        "Reference to statute '{}' requires evaluation with a RegistryContext",
+                        statute_id
+                    ),
+                })
+            }
+            Self::ForAll {
+                relationship,
+                condition,
+            } => {
+                for related in ctx.related_of(*relationship) {
+                    if !condition.evaluate_simple_with_depth(related, depth + 1)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            Self::Exists {
+                relationship,
+                condition,
+            } => {
+                for related in ctx.related_of(*relationship) {
+                    if condition.evaluate_simple_with_depth(related, depth + 1)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            Self::Count {
+                relationship,
+                condition,
+                operator,
+                value,
+            } => {
+                let mut count = 0u32;
+                for related in ctx.related_of(*relationship) {
+                    let matches = match condition {
+                        Some(condition) => {
+                            condition.evaluate_simple_with_depth(related, depth + 1)?
+                        }
+                        None => true,
+                    };
+                    if matches {
+                        count += 1;
+                    }
+                }
+                Ok(operator.compare_u32(count, *value))
+            }
+            Self::Sum {
+                relationship,
+                attribute,
+                operator,
+                value,
+            } => {
+                let mut total = 0.0;
+                for related in ctx.related_of(*relationship) {
+                    let raw = related.attributes.get(attribute).ok_or_else(|| {
+                        ConditionError::MissingAttribute {
+                            key: attribute.clone(),
+                        }
+                    })?;
+                    total +=
+                        raw.trim()
+                            .parse::<f64>()
+                            .map_err(|_| ConditionError::TypeMismatch {
+                                expected: "f64".to_string(),
+                                actual: raw.clone(),
+                            })?;
+                }
+                Ok(operator.compare_f64(total, *value))
+            }
             // For other conditions, return Ok(true) as a placeholder
             // (implementation depends on specific evaluation logic)
             _ => Ok(true),
         }
     }
 
-    /// Evaluates a simple formula.
-    /// This is a basic implementation - can be extended with a proper expression parser.
-    #[allow(dead_code)]
-    fn evaluate_formula(
-        formula: &str,
-        _ctx: &AttributeBasedContext,
-    ) -> Result<f64, ConditionError> {
-        // Simple implementation: support basic arithmetic with attributes
-        // For production use, consider using a proper expression parser like `meval` or `evalexpr`
-
-        // For now, just return an error indicating formula evaluation needs implementation
-        Err(ConditionError::InvalidFormula {
-            formula: formula.to_string(),
-            error: "Formula evaluation not yet implemented - consider using 'meval' or 'evalexpr' crate".to_string(),
+    /// Evaluates a formula with the built-in [`formula`] language.
+    fn evaluate_formula(formula: &str, ctx: &AttributeBasedContext) -> Result<f64, ConditionError> {
+        formula::evaluate_f64(formula, &ctx.attributes).map_err(|e| match e {
+            formula::FormulaError::UnknownAttribute(key) => {
+                ConditionError::MissingAttribute { key }
+            }
+            other => ConditionError::InvalidFormula {
+                formula: formula.to_string(),
+                error: other.to_string(),
+            },
         })
     }
 
@@ -1767,12 +2130,15 @@ impl Condition {
     ///
     /// assert_eq!(condition.evaluate(&ctx).unwrap(), true);
     /// ```
-    pub fn evaluate<C: EvaluationContext>(&self, context: &C) -> Result<bool, EvaluationError> {
+    pub fn evaluate<C: EvaluationContext + ?Sized>(
+        &self,
+        context: &C,
+    ) -> Result<bool, EvaluationError> {
         self.evaluate_with_depth(context, 0)
     }
 
     /// Internal evaluation with depth tracking using the `EvaluationContext` trait.
-    fn evaluate_with_depth<C: EvaluationContext>(
+    fn evaluate_with_depth<C: EvaluationContext + ?Sized>(
         &self,
         context: &C,
         depth: usize,
@@ -1874,12 +2240,13 @@ impl Condition {
                 operator,
                 value,
             } => {
-                let result = context.evaluate_formula(formula).ok_or_else(|| {
-                    EvaluationError::InvalidFormula {
-                        formula: formula.clone(),
-                        reason: "Formula evaluation not supported".to_string(),
-                    }
-                })?;
+                // Contexts may override formula evaluation; otherwise fall back to the
+                // built-in language so that missing attributes are reported precisely.
+                let result = match context.evaluate_formula(formula) {
+                    Some(result) => result,
+                    None => formula::evaluate_f64(formula, &formula::ContextScope(context))
+                        .map_err(|e| EvaluationError::from_formula_error(formula, e))?,
+                };
                 Ok(operator.compare_f64(result, *value))
             }
             Self::Pattern {
@@ -2034,6 +2401,111 @@ impl Condition {
 
                 Ok(operator.compare_f64(current_value, *target_value))
             }
+            Self::StatuteApplies { statute_id } => {
+                Ok(context.resolve_statute(statute_id)?.is_some())
+            }
+            Self::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+                currency,
+            } => {
+                let Some(computed) = context.resolve_statute(statute_id)? else {
+                    return Ok(false);
+                };
+                let actual =
+                    computed
+                        .output(output)
+                        .ok_or_else(|| EvaluationError::MissingAttribute {
+                            key: format!("{}.{}", statute_id, output),
+                        })?;
+                if let Some(expected) = currency {
+                    let found = match actual {
+                        OutputValue::Money { currency, .. } => currency.as_str(),
+                        _ => "no currency",
+                    };
+                    if found != expected {
+                        return Err(EvaluationError::Custom {
+                            message: format!(
+                                "Output '{}.{}' is in {}, expected {}",
+                                statute_id, output, found, expected
+                            ),
+                        });
+                    }
+                }
+                let actual = actual.as_decimal().ok_or_else(|| EvaluationError::Custom {
+                    message: format!("Output '{}.{}' is not numeric", statute_id, output),
+                })?;
+                Ok(operator.compare_ord(&actual, value))
+            }
+            Self::ForAll {
+                relationship,
+                condition,
+            } => {
+                for related in Self::related(context, *relationship)? {
+                    if !condition.evaluate_with_depth(related.as_ref(), depth + 1)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            Self::Exists {
+                relationship,
+                condition,
+            } => {
+                for related in Self::related(context, *relationship)? {
+                    if condition.evaluate_with_depth(related.as_ref(), depth + 1)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            Self::Count {
+                relationship,
+                condition,
+                operator,
+                value,
+            } => {
+                let mut count = 0u32;
+                for related in Self::related(context, *relationship)? {
+                    let matches = match condition {
+                        Some(condition) => {
+                            condition.evaluate_with_depth(related.as_ref(), depth + 1)?
+                        }
+                        None => true,
+                    };
+                    if matches {
+                        count += 1;
+                    }
+                }
+                Ok(operator.compare_u32(count, *value))
+            }
+            Self::Sum {
+                relationship,
+                attribute,
+                operator,
+                value,
+            } => {
+                let mut total = 0.0;
+                for related in Self::related(context, *relationship)? {
+                    let raw = related.get_attribute(attribute).ok_or_else(|| {
+                        EvaluationError::MissingAttribute {
+                            key: attribute.clone(),
+                        }
+                    })?;
+                    total += raw
+                        .trim()
+                        .parse::<f64>()
+                        .map_err(|_| EvaluationError::Custom {
+                            message: format!(
+                                "Attribute '{}' of related entity is not numeric: {}",
+                                attribute, raw
+                            ),
+                        })?;
+                }
+                Ok(operator.compare_f64(total, *value))
+            }
             // For Custom conditions, we can't evaluate without more context
             Self::Custom { description } => Err(EvaluationError::Custom {
                 message: format!("Cannot evaluate custom condition: {}", description),
@@ -2041,6 +2513,18 @@ impl Condition {
         }
     }
 
+    /// Enumerates related entities, failing when the context cannot.
+    fn related<C: EvaluationContext + ?Sized>(
+        context: &C,
+        relationship: RelationshipType,
+    ) -> Result<Vec<Box<dyn EvaluationContext + '_>>, EvaluationError> {
+        context
+            .related_entities(relationship)
+            .ok_or_else(|| EvaluationError::MissingContext {
+                description: format!("related entities ({:?})", relationship),
+            })
+    }
+
     /// Evaluates the condition with detailed step-by-step explanation.
     ///
     /// This method provides a full trace of the evaluation process, useful for:
@@ -2431,6 +2915,8 @@ pub struct AttributeBasedContext {
     pub cache: Option<ConditionCache>,
     /// Optional audit trail for tracking evaluation history.
     pub audit_trail: Option<EvaluationAuditTrail>,
+    /// Related entities by relationship type, used by quantified conditions.
+    pub related: HashMap<RelationshipType, Vec<AttributeBasedContext>>,
 }
 
 impl AttributeBasedContext {
@@ -2442,6 +2928,7 @@ impl AttributeBasedContext {
             max_depth: 100,
             cache: None,
             audit_trail: None,
+            related: HashMap::new(),
         }
     }
 
@@ -2453,6 +2940,7 @@ impl AttributeBasedContext {
             max_depth,
             cache: None,
             audit_trail: None,
+            related: HashMap::new(),
         }
     }
 
@@ -2464,6 +2952,7 @@ impl AttributeBasedContext {
             max_depth: 100,
             cache: Some(ConditionCache::new()),
             audit_trail: None,
+            related: HashMap::new(),
         }
     }
 
@@ -2479,6 +2968,7 @@ impl AttributeBasedContext {
             max_depth,
             cache: Some(ConditionCache::with_capacity(cache_capacity)),
             audit_trail: None,
+            related: HashMap::new(),
         }
     }
 
@@ -2490,9 +2980,47 @@ impl AttributeBasedContext {
             max_depth: 100,
             cache: None,
             audit_trail: Some(EvaluationAuditTrail::new()),
+            related: HashMap::new(),
         }
     }
 
+    /// Adds a related entity for quantified conditions.
+    ///
+    /// # Example
+    /// ```
+    /// # use legalis_core::{AttributeBasedContext, ComparisonOp, Condition, RelationshipType};
+    /// # use std::collections::HashMap;
+    /// let child = AttributeBasedContext::new(HashMap::from([("age".to_string(), "7".to_string())]));
+    /// let parent = AttributeBasedContext::new(HashMap::new())
+    ///     .with_related(RelationshipType::ParentChild, child);
+    ///
+    /// let has_minor = Condition::exists(
+    ///     RelationshipType::ParentChild,
+    ///     Condition::age(ComparisonOp::LessThan, 18),
+    /// );
+    /// assert!(has_minor.evaluate(&parent).unwrap());
+    /// ```
+    #[must_use]
+    pub fn with_related(
+        mut self,
+        relationship_type: RelationshipType,
+        entity: AttributeBasedContext,
+    ) -> Self {
+        self.related
+            .entry(relationship_type)
+            .or_default()
+            .push(entity);
+        self
+    }
+
+    /// Returns the entities related by `relationship_type` (none when unset).
+    fn related_of(&self, relationship_type: RelationshipType) -> &[AttributeBasedContext] {
+        self.related
+            .get(&relationship_type)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
     /// Records an evaluation in the audit trail if enabled.
     pub fn record_evaluation(&mut self, condition: &str, result: bool, duration_micros: u64) {
         if let Some(trail) = &mut self.audit_trail {
@@ -2855,6 +3383,51 @@ impl fmt::Display for Condition {
                     base_value, reference_time, rate, operator, target_value
                 )
             }
+            Self::StatuteApplies { statute_id } => write!(f, "applies({})", statute_id),
+            Self::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+                currency,
+            } => {
+                write!(f, "{}.{} {} {}", statute_id, output, operator, value)?;
+                if let Some(currency) = currency {
+                    write!(f, " {}", currency)?;
+                }
+                Ok(())
+            }
+            Self::ForAll {
+                relationship,
+                condition,
+            } => write!(f, "all {:?}: {}", relationship, condition),
+            Self::Exists {
+                relationship,
+                condition,
+            } => write!(f, "any {:?}: {}", relationship, condition),
+            Self::Count {
+                relationship,
+                condition,
+                operator,
+                value,
+            } => match condition {
+                Some(condition) => write!(
+                    f,
+                    "count({:?} where {}) {} {}",
+                    relationship, condition, operator, value
+                ),
+                None => write!(f, "count({:?}) {} {}", relationship, operator, value),
+            },
+            Self::Sum {
+                relationship,
+                attribute,
+                operator,
+                value,
+            } => write!(
+                f,
+                "sum({:?}.{}) {} {}",
+                relationship, attribute, operator, value
+            ),
             Self::And(left, right) => write!(f, "({} AND {})", left, right),
             Self::Or(left, right) => write!(f, "({} OR {})", left, right),
             Self::Not(inner) => write!(f, "NOT {}", inner),
@@ -2899,6 +3472,10 @@ pub enum RelationshipType {
     BusinessOwner,
     /// Contractual relationship
     Contractual,
+    /// Member of the same household
+    HouseholdMember,
+    /// Director or officer of a company
+    Director,
 }
 
 /// Comparison operators for conditions.
@@ -3002,6 +3579,20 @@ impl ComparisonOp {
         }
     }
 
+    /// Compares two totally ordered values (such as [`Decimal`]s) using this operator.
+    #[must_use]
+    pub fn compare_ord<T: Ord + ?Sized>(&self, left: &T, right: &T) -> bool {
+        let ordering = left.cmp(right);
+        match self {
+            Self::Equal => ordering.is_eq(),
+            Self::NotEqual => ordering.is_ne(),
+            Self::GreaterThan => ordering.is_gt(),
+            Self::GreaterOrEqual => ordering.is_ge(),
+            Self::LessThan => ordering.is_lt(),
+            Self::LessOrEqual => ordering.is_le(),
+        }
+    }
+
     /// Compares two f64 values using this operator.
     #[must_use]
     pub fn compare_f64(&self, left: f64, right: f64) -> bool {
@@ -3099,8 +3690,100 @@ pub trait EvaluationContext {
     /// Get percentage value for a given context.
     fn get_percentage(&self, context: &str) -> Option<u32>;
 
-    /// Evaluate a custom formula and return the result.
-    fn evaluate_formula(&self, formula: &str) -> Option<f64>;
+    /// Evaluate a formula and return the result.
+    ///
+    /// The default implementation uses the built-in [`formula`] language with
+    /// attributes resolved through this context. Override it to supply
+    /// domain-specific named formulas.
+    fn evaluate_formula(&self, formula: &str) -> Option<f64> {
+        formula::evaluate_f64(formula, &formula::ContextScope(self)).ok()
+    }
+
+    /// Resolve a reference to another statute for the current entity.
+    ///
+    /// Returns the computed effect if the statute applies and `None` if it
+    /// does not. The default implementation has no access to other statutes;
+    /// wrap the context in a [`RegistryContext`] to resolve references against
+    /// a [`StatuteRegistry`].
+    fn resolve_statute(&self, statute_id: &str) -> Result<Option<ComputedEffect>, EvaluationError> {
+        Err(EvaluationError::MissingContext {
+            description: format!("statute registry to resolve reference to '{}'", statute_id),
+        })
+    }
+
+    /// Enumerate the entities related to the current one by `relationship_type`.
+    ///
+    /// Used by the quantified conditions ([`Condition::ForAll`],
+    /// [`Condition::Exists`], [`Condition::Count`] and [`Condition::Sum`]),
+    /// which evaluate against each related entity in turn. Returns `None` when
+    /// the context cannot enumerate relationships (the default).
+    fn related_entities(
+        &self,
+        _relationship_type: RelationshipType,
+    ) -> Option<Vec<Box<dyn EvaluationContext + '_>>> {
+        None
+    }
+}
+
+impl<T: EvaluationContext + ?Sized> EvaluationContext for &T {
+    fn get_attribute(&self, key: &str) -> Option<String> {
+        (**self).get_attribute(key)
+    }
+
+    fn get_age(&self) -> Option<u32> {
+        (**self).get_age()
+    }
+
+    fn get_income(&self) -> Option<u64> {
+        (**self).get_income()
+    }
+
+    fn get_current_date(&self) -> Option<NaiveDate> {
+        (**self).get_current_date()
+    }
+
+    fn get_current_timestamp(&self) -> Option<i64> {
+        (**self).get_current_timestamp()
+    }
+
+    fn check_geographic(&self, region_type: RegionType, region_id: &str) -> bool {
+        (**self).check_geographic(region_type, region_id)
+    }
+
+    fn check_relationship(
+        &self,
+        relationship_type: RelationshipType,
+        target_id: Option<&str>,
+    ) -> bool {
+        (**self).check_relationship(relationship_type, target_id)
+    }
+
+    fn get_residency_months(&self) -> Option<u32> {
+        (**self).get_residency_months()
+    }
+
+    fn get_duration(&self, unit: DurationUnit) -> Option<u32> {
+        (**self).get_duration(unit)
+    }
+
+    fn get_percentage(&self, context: &str) -> Option<u32> {
+        (**self).get_percentage(context)
+    }
+
+    fn evaluate_formula(&self, formula: &str) -> Option<f64> {
+        (**self).evaluate_formula(formula)
+    }
+
+    fn resolve_statute(&self, statute_id: &str) -> Result<Option<ComputedEffect>, EvaluationError> {
+        (**self).resolve_statute(statute_id)
+    }
+
+    fn related_entities(
+        &self,
+        relationship_type: RelationshipType,
+    ) -> Option<Vec<Box<dyn EvaluationContext + '_>>> {
+        (**self).related_entities(relationship_type)
+    }
 }
 
 /// Errors that can occur during condition evaluation.
@@ -3118,6 +3801,10 @@ pub enum EvaluationError {
     PatternError { pattern: String, reason: String },
     /// Maximum evaluation depth exceeded (prevents infinite recursion)
     MaxDepthExceeded { max_depth: usize },
+    /// Referenced statute does not exist
+    UnknownStatute { statute_id: String },
+    /// Statute references form a cycle
+    CircularReference { chain: Vec<String> },
     /// Custom error
     Custom { message: String },
 }
@@ -3136,6 +3823,12 @@ impl fmt::Display for EvaluationError {
             Self::MaxDepthExceeded { max_depth } => {
                 write!(f, "Maximum evaluation depth {} exceeded", max_depth)
             }
+            Self::UnknownStatute { statute_id } => {
+                write!(f, "Unknown statute referenced: {}", statute_id)
+            }
+            Self::CircularReference { chain } => {
+                write!(f, "Circular statute reference: {}", chain.join(" -> "))
+            }
             Self::Custom { message } => write!(f, "{}", message),
         }
     }
@@ -3143,6 +3836,19 @@ impl fmt::Display for EvaluationError {
 
 impl std::error::Error for EvaluationError {}
 
+impl EvaluationError {
+    /// Converts a [`formula::FormulaError`] raised while evaluating `formula`.
+    pub fn from_formula_error(formula: &str, error: formula::FormulaError) -> Self {
+        match error {
+            formula::FormulaError::UnknownAttribute(key) => Self::MissingAttribute { key },
+            other => Self::InvalidFormula {
+                formula: formula.to_string(),
+                reason: other.to_string(),
+            },
+        }
+    }
+}
+
 /// Context wrapper that provides default values for missing attributes.
 ///
 /// This is useful for handling optional attributes with sensible defaults.
@@ -3238,7 +3944,16 @@ impl<'a, C: EvaluationContext> EvaluationContext for DefaultValueContext<'a, C>
     }
 
     fn evaluate_formula(&self, formula: &str) -> Option<f64> {
-        self.inner.evaluate_formula(formula)
+        self.inner
+            .evaluate_formula(formula)
+            .or_else(|| formula::evaluate_f64(formula, &formula::ContextScope(self)).ok())
+    }
+
+    fn related_entities(
+        &self,
+        relationship_type: RelationshipType,
+    ) -> Option<Vec<Box<dyn EvaluationContext + '_>>> {
+        self.inner.related_entities(relationship_type)
     }
 }
 
@@ -3350,6 +4065,15 @@ impl<'a, C1: EvaluationContext, C2: EvaluationContext> EvaluationContext
             .evaluate_formula(formula)
             .or_else(|| self.fallback.evaluate_formula(formula))
     }
+
+    fn related_entities(
+        &self,
+        relationship_type: RelationshipType,
+    ) -> Option<Vec<Box<dyn EvaluationContext + '_>>> {
+        self.primary
+            .related_entities(relationship_type)
+            .or_else(|| self.fallback.related_entities(relationship_type))
+    }
 }
 
 /// Implement EvaluationContext for AttributeBasedContext for compatibility.
@@ -3408,9 +4132,333 @@ impl EvaluationContext for AttributeBasedContext {
         self.attributes.get(&key).and_then(|v| v.parse().ok())
     }
 
-    fn evaluate_formula(&self, _formula: &str) -> Option<f64> {
-        // Basic formula evaluation - can be extended with a proper parser
-        None
+    fn evaluate_formula(&self, formula: &str) -> Option<f64> {
+        formula::evaluate_f64(formula, &self.attributes).ok()
+    }
+
+    fn related_entities(
+        &self,
+        relationship_type: RelationshipType,
+    ) -> Option<Vec<Box<dyn EvaluationContext + '_>>> {
+        Some(
+            self.related
+                .get(&relationship_type)
+                .map(|related| {
+                    related
+                        .iter()
+                        .map(|ctx| Box::new(ctx) as Box<dyn EvaluationContext>)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+
+/// Evaluation context backed by a [`LegalEntity`].
+///
+/// Attributes are read with the same key conventions as
+/// [`AttributeBasedContext`]. The evaluation date defaults to the entity's
+/// `current_date` attribute and can be overridden with [`Self::with_date`].
+///
+/// # Example
+/// ```
+/// # use legalis_core::{BasicEntity, ComparisonOp, Condition, EntityContext, LegalEntity};
+/// let mut entity = BasicEntity::new();
+/// entity.set_attribute("age", "30".to_string());
+///
+/// let ctx = EntityContext::new(&entity);
+/// assert!(Condition::age(ComparisonOp::GreaterOrEqual, 18).evaluate(&ctx).unwrap());
+/// ```
+pub struct EntityContext<'a> {
+    entity: &'a dyn LegalEntity,
+    current_date: Option<NaiveDate>,
+}
+
+impl<'a> EntityContext<'a> {
+    /// Creates a context for the given entity.
+    pub fn new(entity: &'a dyn LegalEntity) -> Self {
+        Self {
+            entity,
+            current_date: None,
+        }
+    }
+
+    /// Sets the evaluation date.
+    pub fn with_date(mut self, date: NaiveDate) -> Self {
+        self.current_date = Some(date);
+        self
+    }
+
+    /// Returns the underlying entity.
+    pub fn entity(&self) -> &'a dyn LegalEntity {
+        self.entity
+    }
+
+    fn parsed<T: std::str::FromStr>(&self, key: &str) -> Option<T> {
+        self.entity.get_attribute(key).and_then(|v| v.parse().ok())
+    }
+}
+
+impl fmt::Debug for EntityContext<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EntityContext")
+            .field("entity_id", &self.entity.id())
+            .field("current_date", &self.current_date)
+            .finish()
+    }
+}
+
+impl EvaluationContext for EntityContext<'_> {
+    fn get_attribute(&self, key: &str) -> Option<String> {
+        match (key, self.current_date) {
+            ("current_date", Some(date)) => Some(date.format("%Y-%m-%d").to_string()),
+            _ => self.entity.get_attribute(key),
+        }
+    }
+
+    fn get_age(&self) -> Option<u32> {
+        self.parsed("age")
+    }
+
+    fn get_income(&self) -> Option<u64> {
+        self.parsed("income")
+    }
+
+    fn get_current_date(&self) -> Option<NaiveDate> {
+        self.current_date.or_else(|| {
+            self.entity
+                .get_attribute("current_date")
+                .and_then(|v| NaiveDate::parse_from_str(&v, "%Y-%m-%d").ok())
+        })
+    }
+
+    fn check_geographic(&self, _region_type: RegionType, region_id: &str) -> bool {
+        self.entity
+            .get_attribute("region")
+            .is_some_and(|v| v == region_id)
+    }
+
+    fn check_relationship(
+        &self,
+        relationship_type: RelationshipType,
+        target_id: Option<&str>,
+    ) -> bool {
+        let typed_key = format!("relationship_{:?}", relationship_type).to_lowercase();
+        let value = self
+            .entity
+            .get_attribute(&typed_key)
+            .or_else(|| self.entity.get_attribute("relationship"));
+        match (value, target_id) {
+            (Some(v), Some(target)) => v == target,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+
+    fn get_residency_months(&self) -> Option<u32> {
+        self.parsed("residency_months")
+    }
+
+    fn get_duration(&self, unit: DurationUnit) -> Option<u32> {
+        self.parsed(&format!("duration_{:?}", unit).to_lowercase())
+    }
+
+    fn get_percentage(&self, context: &str) -> Option<u32> {
+        self.parsed(&format!("percentage_{}", context))
+            .or_else(|| self.parsed(&format!("{}_percentage", context)))
+    }
+}
+
+/// Evaluation context that resolves statute references against a [`StatuteRegistry`].
+///
+/// All other lookups are delegated to the wrapped context. Each referenced
+/// statute is evaluated at most once per context (results are memoised), and
+/// reference cycles are reported as [`EvaluationError::CircularReference`].
+///
+/// A referenced statute applies when it is in force on the context's current
+/// date (if known), no exception applies, and all its preconditions hold.
+///
+/// # Example
+/// ```
+/// use legalis_core::{AttributeBasedContext, ComparisonOp, Condition, Decimal, Effect};
+/// use legalis_core::{EffectOutput, RegistryContext, Statute, StatuteRegistry};
+/// use std::collections::HashMap;
+///
+/// let mut registry = StatuteRegistry::new();
+/// registry.add(
+///     Statute::new("child-benefit", "Child Benefit Act", Effect::grant("child benefit")
+///         .with_output(EffectOutput::number("amount", "children * 10000")))
+///         .with_precondition(Condition::has_attribute("children")),
+/// );
+///
+/// let mut attrs = HashMap::new();
+/// attrs.insert("children".to_string(), "2".to_string());
+/// let facts = AttributeBasedContext::new(attrs);
+/// let ctx = RegistryContext::new(&registry, &facts);
+///
+/// assert!(Condition::statute_applies("child-benefit").evaluate(&ctx).unwrap());
+/// assert!(
+///     Condition::statute_output("child-benefit", "amount", ComparisonOp::GreaterOrEqual, Decimal::from(20000))
+///         .evaluate(&ctx)
+///         .unwrap()
+/// );
+/// ```
+pub struct RegistryContext<'a, C: EvaluationContext> {
+    registry: &'a StatuteRegistry,
+    inner: &'a C,
+    memo: std::cell::RefCell<HashMap<String, Result<Option<ComputedEffect>, EvaluationError>>>,
+    stack: std::cell::RefCell<Vec<String>>,
+}
+
+impl<'a, C: EvaluationContext> RegistryContext<'a, C> {
+    /// Creates a context resolving references in `registry` with facts from `inner`.
+    pub fn new(registry: &'a StatuteRegistry, inner: &'a C) -> Self {
+        Self {
+            registry,
+            inner,
+            memo: std::cell::RefCell::new(HashMap::new()),
+            stack: std::cell::RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns the wrapped context.
+    pub fn inner(&self) -> &'a C {
+        self.inner
+    }
+
+    /// Returns the number of statutes evaluated so far.
+    pub fn resolved_count(&self) -> usize {
+        self.memo.borrow().len()
+    }
+
+    fn evaluate_statute(
+        &self,
+        statute: &Statute,
+    ) -> Result<Option<ComputedEffect>, EvaluationError> {
+        if let Some(date) = self.get_current_date()
+            && !statute.is_active(date)
+        {
+            return Ok(None);
+        }
+
+        for exception in &statute.exceptions {
+            if exception.condition.evaluate(self)? {
+                return Ok(None);
+            }
+        }
+
+        // Evaluate preconditions here so reference errors (cycles, unknown
+        // statutes) propagate unchanged instead of becoming discretion.
+        for condition in &statute.preconditions {
+            if !condition.evaluate(self)? {
+                return Ok(None);
+            }
+        }
+
+        match statute.apply_effect(self, Uuid::nil()) {
+            LegalResult::Deterministic(computed) => Ok(Some(computed)),
+            LegalResult::Void { .. } => Ok(None),
+            LegalResult::JudicialDiscretion { issue, .. } => Err(EvaluationError::Custom {
+                message: format!("Statute '{}' cannot be decided: {}", statute.id, issue),
+            }),
+        }
+    }
+}
+
+impl<C: EvaluationContext> fmt::Debug for RegistryContext<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegistryContext")
+            .field("statutes", &self.registry.len())
+            .field("resolved", &self.resolved_count())
+            .finish()
+    }
+}
+
+impl<C: EvaluationContext> EvaluationContext for RegistryContext<'_, C> {
+    fn get_attribute(&self, key: &str) -> Option<String> {
+        self.inner.get_attribute(key)
+    }
+
+    fn get_age(&self) -> Option<u32> {
+        self.inner.get_age()
+    }
+
+    fn get_income(&self) -> Option<u64> {
+        self.inner.get_income()
+    }
+
+    fn get_current_date(&self) -> Option<NaiveDate> {
+        self.inner.get_current_date()
+    }
+
+    fn get_current_timestamp(&self) -> Option<i64> {
+        self.inner.get_current_timestamp()
+    }
+
+    fn check_geographic(&self, region_type: RegionType, region_id: &str) -> bool {
+        self.inner.check_geographic(region_type, region_id)
+    }
+
+    fn check_relationship(
+        &self,
+        relationship_type: RelationshipType,
+        target_id: Option<&str>,
+    ) -> bool {
+        self.inner.check_relationship(relationship_type, target_id)
+    }
+
+    fn get_residency_months(&self) -> Option<u32> {
+        self.inner.get_residency_months()
+    }
+
+    fn get_duration(&self, unit: DurationUnit) -> Option<u32> {
+        self.inner.get_duration(unit)
+    }
+
+    fn get_percentage(&self, context: &str) -> Option<u32> {
+        self.inner.get_percentage(context)
+    }
+
+    fn evaluate_formula(&self, formula: &str) -> Option<f64> {
+        self.inner.evaluate_formula(formula)
+    }
+
+    fn related_entities(
+        &self,
+        relationship_type: RelationshipType,
+    ) -> Option<Vec<Box<dyn EvaluationContext + '_>>> {
+        self.inner.related_entities(relationship_type)
+    }
+
+    fn resolve_statute(&self, statute_id: &str) -> Result<Option<ComputedEffect>, EvaluationError> {
+        if let Some(cached) = self.memo.borrow().get(statute_id) {
+            return cached.clone();
+        }
+
+        {
+            let stack = self.stack.borrow();
+            if let Some(start) = stack.iter().position(|id| id == statute_id) {
+                let mut chain = stack[start..].to_vec();
+                chain.push(statute_id.to_string());
+                return Err(EvaluationError::CircularReference { chain });
+            }
+        }
+
+        let statute =
+            self.registry
+                .get(statute_id)
+                .ok_or_else(|| EvaluationError::UnknownStatute {
+                    statute_id: statute_id.to_string(),
+                })?;
+
+        self.stack.borrow_mut().push(statute_id.to_string());
+        let result = self.evaluate_statute(statute);
+        self.stack.borrow_mut().pop();
+
+        self.memo
+            .borrow_mut()
+            .insert(statute_id.to_string(), result.clone());
+        result
     }
 }
 
@@ -3598,6 +4646,12 @@ impl Condition {
             | Self::Threshold { .. }
             | Self::Fuzzy { .. }
             | Self::Temporal { .. }
+            | Self::StatuteApplies { .. }
+            | Self::StatuteOutput { .. }
+            | Self::ForAll { .. }
+            | Self::Exists { .. }
+            | Self::Count { .. }
+            | Self::Sum { .. }
             | Self::Custom { .. } => {
                 // Delegate to sequential evaluation
                 self.evaluate(context)
@@ -4326,41 +5380,134 @@ impl ForwardChainingEngine {
                     continue;
                 }
 
-                // Check if conditions are met
-                if self.can_apply_statute(statute, context) {
-                    let depends_on = self.find_dependencies(&inferences, statute);
+                // Check if conditions are met
+                if self.can_apply_statute(statute, context, &inferences) {
+                    let depends_on = self.find_dependencies(&inferences, statute);
+
+                    inferences.push(InferenceStep {
+                        statute_id: statute.id.clone(),
+                        effect: statute.effect.clone(),
+                        depends_on,
+                    });
+
+                    changed = true;
+                }
+            }
+        }
+
+        inferences
+    }
+
+    /// Checks if a statute's conditions can be applied given the current context.
+    ///
+    /// References to other statutes are satisfied by statutes already inferred.
+    fn can_apply_statute(
+        &self,
+        statute: &Statute,
+        context: &AttributeBasedContext,
+        inferences: &[InferenceStep],
+    ) -> bool {
+        if statute.preconditions.is_empty() {
+            return true;
+        }
+
+        let chain = ChainContext {
+            inner: context,
+            inferences,
+        };
+        statute.preconditions.iter().all(|cond| {
+            if cond.referenced_statutes().is_empty() {
+                cond.evaluate_simple(context).unwrap_or(false)
+            } else {
+                cond.evaluate(&chain).unwrap_or(false)
+            }
+        })
+    }
+
+    /// Finds which previous inferences this statute depends on.
+    fn find_dependencies(&self, inferences: &[InferenceStep], statute: &Statute) -> Vec<usize> {
+        // Only explicit statute references are tracked; dependencies through
+        // attributes set by earlier effects are not.
+        let referenced = statute.referenced_statutes();
+        inferences
+            .iter()
+            .enumerate()
+            .filter(|(_, inf)| referenced.contains(&inf.statute_id.as_str()))
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// Context for forward chaining in which statute references resolve to the
+/// statutes inferred so far.
+struct ChainContext<'a> {
+    inner: &'a AttributeBasedContext,
+    inferences: &'a [InferenceStep],
+}
+
+impl EvaluationContext for ChainContext<'_> {
+    fn get_attribute(&self, key: &str) -> Option<String> {
+        self.inner.get_attribute(key)
+    }
+
+    fn get_age(&self) -> Option<u32> {
+        self.inner.get_age()
+    }
+
+    fn get_income(&self) -> Option<u64> {
+        self.inner.get_income()
+    }
+
+    fn get_current_date(&self) -> Option<NaiveDate> {
+        self.inner.get_current_date()
+    }
+
+    fn check_geographic(&self, region_type: RegionType, region_id: &str) -> bool {
+        self.inner.check_geographic(region_type, region_id)
+    }
+
+    fn check_relationship(
+        &self,
+        relationship_type: RelationshipType,
+        target_id: Option<&str>,
+    ) -> bool {
+        self.inner.check_relationship(relationship_type, target_id)
+    }
 
-                    inferences.push(InferenceStep {
-                        statute_id: statute.id.clone(),
-                        effect: statute.effect.clone(),
-                        depends_on,
-                    });
+    fn get_residency_months(&self) -> Option<u32> {
+        self.inner.get_residency_months()
+    }
 
-                    changed = true;
-                }
-            }
-        }
+    fn get_duration(&self, unit: DurationUnit) -> Option<u32> {
+        self.inner.get_duration(unit)
+    }
 
-        inferences
+    fn get_percentage(&self, context: &str) -> Option<u32> {
+        self.inner.get_percentage(context)
     }
 
-    /// Checks if a statute's conditions can be applied given the current context.
-    fn can_apply_statute(&self, statute: &Statute, context: &AttributeBasedContext) -> bool {
-        if statute.preconditions.is_empty() {
-            return true;
-        }
+    fn evaluate_formula(&self, formula: &str) -> Option<f64> {
+        self.inner.evaluate_formula(formula)
+    }
 
-        statute
-            .preconditions
-            .iter()
-            .all(|cond| cond.evaluate_simple(context).unwrap_or(false))
+    fn related_entities(
+        &self,
+        relationship_type: RelationshipType,
+    ) -> Option<Vec<Box<dyn EvaluationContext + '_>>> {
+        self.inner.related_entities(relationship_type)
     }
 
-    /// Finds which previous inferences this statute depends on.
-    fn find_dependencies(&self, _inferences: &[InferenceStep], _statute: &Statute) -> Vec<usize> {
-        // For now, return empty dependencies
-        // TODO: Implement dependency tracking based on which effects enable conditions
-        Vec::new()
+    fn resolve_statute(&self, statute_id: &str) -> Result<Option<ComputedEffect>, EvaluationError> {
+        let Some(step) = self.inferences.iter().find(|i| i.statute_id == statute_id) else {
+            return Ok(None);
+        };
+        let outputs = step
+            .effect
+            .compute_outputs(&self.inner.attributes)
+            .map_err(|e| EvaluationError::Custom {
+                message: format!("Cannot compute outputs of '{}': {}", statute_id, e),
+            })?;
+        Ok(Some(ComputedEffect::new(step.effect.clone(), outputs)))
     }
 }
 
@@ -5041,6 +6188,76 @@ impl StatuteRegistry {
         &self.statutes
     }
 
+    /// Returns references to statutes that are not in the registry.
+    ///
+    /// Each entry is `(referencing statute ID, missing statute ID)`.
+    #[must_use]
+    pub fn dangling_references(&self) -> Vec<(&str, &str)> {
+        self.statutes
+            .iter()
+            .flat_map(|s| {
+                s.referenced_statutes()
+                    .into_iter()
+                    .filter(|id| self.get(id).is_none())
+                    .map(move |id| (s.id.as_str(), id))
+            })
+            .collect()
+    }
+
+    /// Finds cycles among statute references.
+    ///
+    /// Each cycle is returned as a chain of statute IDs that starts and ends
+    /// with the same statute.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use legalis_core::{Condition, Effect, Statute, StatuteRegistry};
+    ///
+    /// let registry = StatuteRegistry::from_statutes(vec![
+    ///     Statute::new("a", "A", Effect::grant("a")).with_precondition(Condition::statute_applies("b")),
+    ///     Statute::new("b", "B", Effect::grant("b")).with_precondition(Condition::statute_applies("a")),
+    /// ]);
+    ///
+    /// assert_eq!(registry.reference_cycles(), vec![vec!["a", "b", "a"]]);
+    /// ```
+    #[must_use]
+    pub fn reference_cycles(&self) -> Vec<Vec<String>> {
+        fn visit<'a>(
+            registry: &'a StatuteRegistry,
+            id: &'a str,
+            path: &mut Vec<&'a str>,
+            done: &mut std::collections::HashSet<&'a str>,
+            cycles: &mut Vec<Vec<String>>,
+        ) {
+            if let Some(start) = path.iter().position(|p| *p == id) {
+                let mut cycle: Vec<String> = path[start..].iter().map(|p| p.to_string()).collect();
+                cycle.push(id.to_string());
+                cycles.push(cycle);
+                return;
+            }
+            if done.contains(id) {
+                return;
+            }
+            let Some(statute) = registry.get(id) else {
+                return;
+            };
+            path.push(id);
+            for next in statute.referenced_statutes() {
+                visit(registry, next, path, done, cycles);
+            }
+            path.pop();
+            done.insert(id);
+        }
+
+        let mut cycles = Vec::new();
+        let mut done = std::collections::HashSet::new();
+        for statute in &self.statutes {
+            visit(self, &statute.id, &mut Vec::new(), &mut done, &mut cycles);
+        }
+        cycles
+    }
+
     /// Finds statutes that conflict with each other at a given date.
     #[must_use]
     pub fn find_conflicts(&self, date: NaiveDate) -> Vec<(&Statute, &Statute)> {
@@ -5561,6 +6778,12 @@ pub struct Effect {
     pub description: String,
     /// Parameters for the effect
     pub parameters: std::collections::HashMap<String, String>,
+    /// Typed outputs computed from the entity when the effect is applied
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub outputs: Vec<EffectOutput>,
 }
 
 impl Effect {
@@ -5570,9 +6793,38 @@ impl Effect {
             effect_type,
             description: description.into(),
             parameters: std::collections::HashMap::new(),
+            outputs: Vec::new(),
         }
     }
 
+    /// Adds a computed output to the effect.
+    ///
+    /// # Example
+    /// ```
+    /// # use legalis_core::{Effect, EffectOutput};
+    /// let benefit = Effect::grant("Housing benefit")
+    ///     .with_output(EffectOutput::money("amount", "JPY", "max(80000 - income * 0.1, 0)"));
+    /// assert!(benefit.has_outputs());
+    /// ```
+    pub fn with_output(mut self, output: EffectOutput) -> Self {
+        self.outputs.push(output);
+        self
+    }
+
+    /// Returns true if the effect declares computed outputs.
+    #[must_use]
+    pub fn has_outputs(&self) -> bool {
+        !self.outputs.is_empty()
+    }
+
+    /// Computes all declared outputs against a formula scope.
+    pub fn compute_outputs<S: formula::FormulaScope + ?Sized>(
+        &self,
+        scope: &S,
+    ) -> Result<std::collections::BTreeMap<String, OutputValue>, formula::FormulaError> {
+        typed_effects::compute_outputs(&self.outputs, scope)
+    }
+
     /// Adds a parameter to the effect.
     pub fn with_parameter(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.parameters.insert(key.into(), value.into());
@@ -6793,6 +8045,89 @@ impl Statute {
         self.temporal_validity.is_active(as_of)
     }
 
+    /// Applies this statute to an entity, computing the effect's outputs.
+    ///
+    /// Returns `Void` if a precondition is not met, `JudicialDiscretion` if the
+    /// statute carries discretion logic or a precondition or output cannot be
+    /// evaluated, and otherwise `Deterministic` with the computed output values.
+    ///
+    /// # Example
+    /// ```
+    /// # use legalis_core::{BasicEntity, ComparisonOp, Condition, Decimal, Effect, EffectOutput, LegalEntity, LegalResult, OutputValue, Statute};
+    /// let statute = Statute::new("benefit", "Low-income benefit", Effect::grant("benefit")
+    ///         .with_output(EffectOutput::money("amount", "USD", "(30000 - income) * 0.1")))
+    ///     .with_precondition(Condition::income(ComparisonOp::LessThan, 30000));
+    ///
+    /// let mut entity = BasicEntity::new();
+    /// entity.set_attribute("income", "20000".to_string());
+    ///
+    /// let LegalResult::Deterministic(computed) = statute.apply(&entity) else {
+    ///     panic!("statute should apply");
+    /// };
+    /// assert_eq!(
+    ///     computed.output("amount"),
+    ///     Some(&OutputValue::money(Decimal::from(1000), "USD"))
+    /// );
+    /// ```
+    pub fn apply(&self, entity: &dyn LegalEntity) -> LegalResult<ComputedEffect> {
+        self.apply_in_context(&EntityContext::new(entity), entity.id())
+    }
+
+    /// Applies this statute using an arbitrary evaluation context.
+    ///
+    /// `context_id` identifies the case in any `JudicialDiscretion` result.
+    pub fn apply_in_context<C: EvaluationContext>(
+        &self,
+        context: &C,
+        context_id: Uuid,
+    ) -> LegalResult<ComputedEffect> {
+        for condition in &self.preconditions {
+            match condition.evaluate(context) {
+                Ok(true) => {}
+                Ok(false) => {
+                    return LegalResult::Void {
+                        reason: format!("Precondition not met: {}", condition),
+                    };
+                }
+                Err(e) => {
+                    return LegalResult::JudicialDiscretion {
+                        issue: format!("Cannot evaluate precondition '{}': {}", condition, e),
+                        context_id,
+                        narrative_hint: self.discretion_logic.clone(),
+                    };
+                }
+            }
+        }
+
+        self.apply_effect(context, context_id)
+    }
+
+    /// Resolves discretion and computes the effect once the preconditions hold.
+    fn apply_effect<C: EvaluationContext>(
+        &self,
+        context: &C,
+        context_id: Uuid,
+    ) -> LegalResult<ComputedEffect> {
+        if self.discretion_logic.is_some() {
+            return LegalResult::JudicialDiscretion {
+                issue: "Discretionary review required".to_string(),
+                context_id,
+                narrative_hint: self.discretion_logic.clone(),
+            };
+        }
+
+        match self.effect.compute_outputs(&formula::ContextScope(context)) {
+            Ok(outputs) => {
+                LegalResult::Deterministic(ComputedEffect::new(self.effect.clone(), outputs))
+            }
+            Err(e) => LegalResult::JudicialDiscretion {
+                issue: format!("Cannot compute effect outputs: {}", e),
+                context_id,
+                narrative_hint: None,
+            },
+        }
+    }
+
     /// Returns the number of preconditions.
     #[must_use]
     pub fn precondition_count(&self) -> usize {
@@ -6932,6 +8267,24 @@ impl Statute {
         self.exceptions.len()
     }
 
+    /// Returns the IDs of other statutes referenced by the preconditions and exceptions.
+    #[must_use]
+    pub fn referenced_statutes(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = Vec::new();
+        let conditions = self
+            .preconditions
+            .iter()
+            .chain(self.exceptions.iter().map(|e| &e.condition));
+        for condition in conditions {
+            for id in condition.referenced_statutes() {
+                if !ids.contains(&id) {
+                    ids.push(id);
+                }
+            }
+        }
+        ids
+    }
+
     /// Validates the statute and returns a list of validation errors.
     pub fn validate(&self) -> Vec<ValidationError> {
         let mut errors = Vec::new();
@@ -8227,7 +9580,7 @@ impl StatuteConflictAnalyzer {
     }
 
     /// Checks if two statutes have conflicting effects.
-    fn has_conflict(first: &Statute, second: &Statute) -> bool {
+    pub(crate) fn has_conflict(first: &Statute, second: &Statute) -> bool {
         // Statutes conflict if they have incompatible effects
         // For simplicity, we consider Grant vs Prohibition/Revoke as conflicts
         use EffectType::*;
@@ -8282,7 +9635,7 @@ impl StatuteConflictAnalyzer {
     }
 
     /// Calculates specificity score based on number and complexity of conditions.
-    fn calculate_specificity(statute: &Statute) -> usize {
+    pub(crate) fn calculate_specificity(statute: &Statute) -> usize {
         statute
             .preconditions
             .iter()
@@ -8311,7 +9664,7 @@ impl StatuteConflictAnalyzer {
     /// Determines jurisdiction hierarchy level.
     ///
     /// Higher number = higher authority
-    fn jurisdiction_level(jurisdiction: &Option<String>) -> u32 {
+    pub(crate) fn jurisdiction_level(jurisdiction: &Option<String>) -> u32 {
         jurisdiction.as_ref().map_or(0, |j| {
             if j.to_lowercase().contains("federal") || j.to_lowercase().contains("national") {
                 3
@@ -8797,6 +10150,7 @@ impl EffectBuilder {
             effect_type: self.effect_type.expect("Effect type must be set"),
             description: self.description.expect("Description must be set"),
             parameters: self.parameters,
+            outputs: Vec::new(),
         }
     }
 
@@ -8808,6 +10162,7 @@ impl EffectBuilder {
             effect_type,
             description,
             parameters: self.parameters,
+            outputs: Vec::new(),
         })
     }
 }
@@ -11010,4 +12365,425 @@ mod tests {
         // Unknown/None
         assert_eq!(StatuteConflictAnalyzer::jurisdiction_level(&None), 0);
     }
+
+    #[test]
+    fn test_calculation_condition_evaluation() {
+        let mut attrs = HashMap::new();
+        attrs.insert("income".to_string(), "40000".to_string());
+        attrs.insert("deductions".to_string(), "2000".to_string());
+        let ctx = AttributeBasedContext::new(attrs);
+
+        let tax = Condition::calculation(
+            "income * 0.2 - deductions",
+            ComparisonOp::GreaterThan,
+            5000.0,
+        );
+        assert!(tax.evaluate_simple(&ctx).unwrap());
+        assert!(tax.evaluate(&ctx).unwrap());
+
+        let predicate =
+            Condition::calculation("income * 0.2 - deductions > 7000", ComparisonOp::Equal, 1.0);
+        assert!(!predicate.evaluate(&ctx).unwrap());
+
+        let missing = Condition::calculation("assets / 2", ComparisonOp::LessThan, 100.0);
+        assert_eq!(
+            missing.evaluate(&ctx),
+            Err(EvaluationError::MissingAttribute {
+                key: "assets".to_string()
+            })
+        );
+        assert!(matches!(
+            missing.evaluate_simple(&ctx),
+            Err(ConditionError::MissingAttribute { .. })
+        ));
+    }
+
+    #[test]
+    fn test_calculation_with_default_values() {
+        let ctx = AttributeBasedContext::new(HashMap::new());
+        let with_defaults = DefaultValueContext::new(&ctx, HashMap::new())
+            .with_default("income", "12000")
+            .with_default("household_size", "3");
+
+        let per_capita =
+            Condition::calculation("income / household_size", ComparisonOp::LessOrEqual, 4000.0);
+        assert!(per_capita.evaluate(&with_defaults).unwrap());
+    }
+
+    fn welfare_registry() -> StatuteRegistry {
+        StatuteRegistry::from_statutes(vec![
+            Statute::new(
+                "child-benefit",
+                "Child Benefit Act",
+                Effect::grant("child benefit")
+                    .with_output(EffectOutput::number("amount", "children * 10000")),
+            )
+            .with_precondition(Condition::has_attribute("children")),
+            Statute::new(
+                "single-parent",
+                "Single parent allowance",
+                Effect::grant("allowance"),
+            )
+            .with_precondition(Condition::statute_applies("child-benefit"))
+            .with_precondition(Condition::has_attribute("single_parent")),
+            Statute::new("housing", "Housing support", Effect::grant("housing"))
+                .with_precondition(Condition::statute_applies("single-parent"))
+                .with_precondition(Condition::statute_output(
+                    "child-benefit",
+                    "amount",
+                    ComparisonOp::GreaterOrEqual,
+                    Decimal::from(20000),
+                ))
+                .with_exception(StatuteException::new(
+                    "owner",
+                    "Home owners",
+                    Condition::has_attribute("home_owner"),
+                )),
+        ])
+    }
+
+    #[test]
+    fn test_statute_reference_conditions() {
+        let registry = welfare_registry();
+        assert!(registry.reference_cycles().is_empty());
+        assert!(registry.dangling_references().is_empty());
+
+        let mut attrs = HashMap::new();
+        attrs.insert("children".to_string(), "2".to_string());
+        attrs.insert("single_parent".to_string(), "true".to_string());
+        let facts = AttributeBasedContext::new(attrs.clone());
+        let ctx = RegistryContext::new(&registry, &facts);
+
+        let housing = Condition::statute_applies("housing");
+        assert!(housing.evaluate(&ctx).unwrap());
+        assert_eq!(ctx.resolved_count(), 3);
+
+        attrs.insert("home_owner".to_string(), "true".to_string());
+        let facts = AttributeBasedContext::new(attrs);
+        let ctx = RegistryContext::new(&registry, &facts);
+        assert!(!housing.evaluate(&ctx).unwrap());
+        assert!(
+            Condition::statute_applies("single-parent")
+                .evaluate(&ctx)
+                .unwrap()
+        );
+
+        assert_eq!(
+            Condition::statute_applies("missing").evaluate(&ctx),
+            Err(EvaluationError::UnknownStatute {
+                statute_id: "missing".to_string()
+            })
+        );
+        assert!(matches!(
+            housing.evaluate(&facts),
+            Err(EvaluationError::MissingContext { .. })
+        ));
+    }
+
+    #[test]
+    fn test_registry_evaluates_preconditions_once() {
+        /// Counts attribute lookups made through the wrapped context.
+        struct Counting(AttributeBasedContext, std::cell::Cell<usize>);
+
+        impl EvaluationContext for Counting {
+            fn get_attribute(&self, key: &str) -> Option<String> {
+                self.1.set(self.1.get() + 1);
+                self.0.get_attribute(key)
+            }
+            fn get_age(&self) -> Option<u32> {
+                self.0.get_age()
+            }
+            fn get_income(&self) -> Option<u64> {
+                self.0.get_income()
+            }
+            fn get_current_date(&self) -> Option<NaiveDate> {
+                self.0.get_current_date()
+            }
+            fn check_geographic(&self, region_type: RegionType, region_id: &str) -> bool {
+                self.0.check_geographic(region_type, region_id)
+            }
+            fn check_relationship(
+                &self,
+                relationship_type: RelationshipType,
+                target_id: Option<&str>,
+            ) -> bool {
+                self.0.check_relationship(relationship_type, target_id)
+            }
+            fn get_residency_months(&self) -> Option<u32> {
+                self.0.get_residency_months()
+            }
+            fn get_duration(&self, unit: DurationUnit) -> Option<u32> {
+                self.0.get_duration(unit)
+            }
+            fn get_percentage(&self, context: &str) -> Option<u32> {
+                self.0.get_percentage(context)
+            }
+        }
+
+        let registry = StatuteRegistry::from_statutes(vec![
+            Statute::new("base", "Base", Effect::grant("base"))
+                .with_precondition(Condition::has_attribute("resident")),
+        ]);
+        let mut attrs = HashMap::new();
+        attrs.insert("resident".to_string(), "true".to_string());
+        let facts = Counting(AttributeBasedContext::new(attrs), std::cell::Cell::new(0));
+        let ctx = RegistryContext::new(&registry, &facts);
+
+        assert!(Condition::statute_applies("base").evaluate(&ctx).unwrap());
+        assert_eq!(facts.1.get(), 1);
+    }
+
+    #[test]
+    fn test_statute_output_money_threshold() {
+        let registry = StatuteRegistry::from_statutes(vec![Statute::new(
+            "allowance",
+            "Allowance",
+            Effect::grant("allowance").with_output(EffectOutput::money(
+                "amount",
+                "JPY",
+                "base * 0.1",
+            )),
+        )]);
+        let mut attrs = HashMap::new();
+        attrs.insert("base".to_string(), "100005".to_string());
+        let facts = AttributeBasedContext::new(attrs);
+        let ctx = RegistryContext::new(&registry, &facts);
+
+        let threshold: Decimal = "10000.50".parse().unwrap();
+        let at_least = |currency: &str| {
+            Condition::statute_output_money(
+                "allowance",
+                "amount",
+                ComparisonOp::GreaterOrEqual,
+                threshold,
+                currency,
+            )
+        };
+        assert!(at_least("JPY").evaluate(&ctx).unwrap());
+        assert!(
+            !Condition::statute_output("allowance", "amount", ComparisonOp::GreaterThan, threshold)
+                .evaluate(&ctx)
+                .unwrap()
+        );
+        assert!(matches!(
+            at_least("USD").evaluate(&ctx),
+            Err(EvaluationError::Custom { .. })
+        ));
+        assert_eq!(
+            at_least("JPY").to_string(),
+            "allowance.amount >= 10000.50 JPY"
+        );
+    }
+
+    #[test]
+    fn test_statute_reference_cycles() {
+        let registry = StatuteRegistry::from_statutes(vec![
+            Statute::new("a", "A", Effect::grant("a"))
+                .with_precondition(Condition::statute_applies("b")),
+            Statute::new("b", "B", Effect::grant("b"))
+                .with_precondition(Condition::statute_applies("c")),
+            Statute::new("c", "C", Effect::grant("c")).with_precondition(
+                Condition::statute_applies("a").or(Condition::statute_applies("d")),
+            ),
+        ]);
+
+        assert_eq!(registry.reference_cycles(), vec![vec!["a", "b", "c", "a"]]);
+        assert_eq!(registry.dangling_references(), vec![("c", "d")]);
+
+        let facts = AttributeBasedContext::new(HashMap::new());
+        let ctx = RegistryContext::new(&registry, &facts);
+        assert!(matches!(
+            Condition::statute_applies("a").evaluate(&ctx),
+            Err(EvaluationError::CircularReference { chain }) if chain == ["a", "b", "c", "a"]
+        ));
+    }
+
+    #[test]
+    fn test_forward_chaining_statute_references() {
+        let registry = welfare_registry();
+        let mut attrs = HashMap::new();
+        attrs.insert("children".to_string(), "3".to_string());
+        attrs.insert("single_parent".to_string(), "true".to_string());
+        let ctx = AttributeBasedContext::new(attrs);
+
+        // Declared in reverse order so chaining takes several rounds
+        let mut statutes = registry.all().to_vec();
+        statutes.reverse();
+        let chain = ForwardChainingEngine::new(statutes).infer(&ctx, 10);
+
+        let ids: Vec<&str> = chain.iter().map(|s| s.statute_id.as_str()).collect();
+        assert_eq!(ids, vec!["child-benefit", "single-parent", "housing"]);
+        assert_eq!(chain[1].depends_on, vec![0]);
+        assert_eq!(chain[2].depends_on, vec![0, 1]);
+    }
+
+    fn person(age: u32, income: u64) -> AttributeBasedContext {
+        let mut attrs = HashMap::new();
+        attrs.insert("age".to_string(), age.to_string());
+        attrs.insert("income".to_string(), income.to_string());
+        AttributeBasedContext::new(attrs)
+    }
+
+    #[test]
+    fn test_quantified_conditions() {
+        let household = person(45, 4_000_000)
+            .with_related(RelationshipType::ParentChild, person(8, 0))
+            .with_related(RelationshipType::ParentChild, person(19, 600_000))
+            .with_related(RelationshipType::HouseholdMember, person(19, 600_000))
+            .with_related(RelationshipType::HouseholdMember, person(70, 900_000));
+
+        let minor = Condition::age(ComparisonOp::LessThan, 18);
+        assert!(
+            Condition::exists(RelationshipType::ParentChild, minor.clone())
+                .evaluate(&household)
+                .unwrap()
+        );
+        assert!(
+            !Condition::for_all(RelationshipType::ParentChild, minor.clone())
+                .evaluate(&household)
+                .unwrap()
+        );
+        // Vacuous truth / falsity over an empty relationship set
+        assert!(
+            Condition::for_all(RelationshipType::Director, minor.clone())
+                .evaluate(&household)
+                .unwrap()
+        );
+        assert!(
+            !Condition::exists(RelationshipType::Director, minor.clone())
+                .evaluate(&household)
+                .unwrap()
+        );
+
+        let low_income = Condition::income(ComparisonOp::LessThan, 1_000_000);
+        assert!(
+            Condition::count_where(
+                RelationshipType::HouseholdMember,
+                low_income,
+                ComparisonOp::GreaterOrEqual,
+                2
+            )
+            .evaluate(&household)
+            .unwrap()
+        );
+        assert!(
+            Condition::count(RelationshipType::ParentChild, ComparisonOp::Equal, 2)
+                .evaluate(&household)
+                .unwrap()
+        );
+        assert!(
+            Condition::sum(
+                RelationshipType::HouseholdMember,
+                "income",
+                ComparisonOp::Equal,
+                1_500_000.0
+            )
+            .evaluate(&household)
+            .unwrap()
+        );
+
+        let no_income = AttributeBasedContext::new(HashMap::new()).with_related(
+            RelationshipType::Spouse,
+            AttributeBasedContext::new(HashMap::new()),
+        );
+        assert_eq!(
+            Condition::sum(
+                RelationshipType::Spouse,
+                "income",
+                ComparisonOp::LessThan,
+                1.0
+            )
+            .evaluate(&no_income),
+            Err(EvaluationError::MissingAttribute {
+                key: "income".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_quantified_conditions_evaluate_simple() {
+        let household = person(45, 4_000_000)
+            .with_related(RelationshipType::ParentChild, person(8, 0))
+            .with_related(RelationshipType::ParentChild, person(19, 600_000));
+
+        let minor = Condition::age(ComparisonOp::LessThan, 18);
+        let all_minors = Condition::for_all(RelationshipType::ParentChild, minor.clone());
+        assert!(!all_minors.evaluate_simple(&household).unwrap());
+        assert!(
+            Condition::exists(RelationshipType::ParentChild, minor.clone())
+                .evaluate_simple(&household)
+                .unwrap()
+        );
+        assert!(
+            Condition::count_where(RelationshipType::ParentChild, minor, ComparisonOp::Equal, 1)
+                .evaluate_simple(&household)
+                .unwrap()
+        );
+        assert!(
+            Condition::sum(
+                RelationshipType::ParentChild,
+                "income",
+                ComparisonOp::Equal,
+                600_000.0
+            )
+            .evaluate_simple(&household)
+            .unwrap()
+        );
+
+        // Forward chaining evaluates reference-free conditions with evaluate_simple
+        let engine = ForwardChainingEngine::new(vec![
+            Statute::new(
+                "minors-only",
+                "Household of minors",
+                Effect::new(EffectType::Grant, "Child allowance"),
+            )
+            .with_precondition(all_minors),
+        ]);
+        assert!(engine.infer(&household, 5).is_empty());
+    }
+
+    #[test]
+    fn test_quantified_conditions_without_relationships() {
+        struct Flat;
+        impl EvaluationContext for Flat {
+            fn get_attribute(&self, _key: &str) -> Option<String> {
+                None
+            }
+            fn get_age(&self) -> Option<u32> {
+                None
+            }
+            fn get_income(&self) -> Option<u64> {
+                None
+            }
+            fn get_current_date(&self) -> Option<NaiveDate> {
+                None
+            }
+            fn check_geographic(&self, _region_type: RegionType, _region_id: &str) -> bool {
+                false
+            }
+            fn check_relationship(
+                &self,
+                _relationship_type: RelationshipType,
+                _target_id: Option<&str>,
+            ) -> bool {
+                false
+            }
+            fn get_residency_months(&self) -> Option<u32> {
+                None
+            }
+            fn get_duration(&self, _unit: DurationUnit) -> Option<u32> {
+                None
+            }
+            fn get_percentage(&self, _context: &str) -> Option<u32> {
+                None
+            }
+        }
+
+        let cond = Condition::count(RelationshipType::ParentChild, ComparisonOp::GreaterThan, 0);
+        assert!(matches!(
+            cond.evaluate(&Flat),
+            Err(EvaluationError::MissingContext { .. })
+        ));
+        assert_eq!(cond.to_string(), "count(ParentChild) > 0");
+    }
 }
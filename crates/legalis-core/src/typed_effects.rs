@@ -16,11 +16,42 @@
 //! let effect: TypedEffect = grant.into();
 //! assert_eq!(effect.description(), "Grant: driver_license");
 //! ```
+//!
+//! Effects can also declare computed outputs whose values are derived from the
+//! entity when a statute is applied:
+//!
+//! ```
+//! use legalis_core::typed_effects::{EffectOutput, OutputValue};
+//! use legalis_core::{Decimal, Effect, EffectType};
+//! use std::collections::HashMap;
+//!
+//! let effect = Effect::new(EffectType::MonetaryTransfer, "Income tax")
+//!     .with_output(EffectOutput::money("tax", "EUR", "income * 0.22"));
+//!
+//! let mut attrs = HashMap::new();
+//! attrs.insert("income".to_string(), "40000".to_string());
+//!
+//! let outputs = effect.compute_outputs(&attrs).unwrap();
+//! assert_eq!(
+//!     outputs["tax"],
+//!     OutputValue::money(Decimal::from(8800), "EUR")
+//! );
+//! ```
 
-use crate::{Effect, EffectType};
-use std::collections::HashMap;
+use crate::decimal::{Decimal, RoundingMode};
+use crate::formula::{Formula, FormulaError, FormulaScope, FormulaType, FormulaValue};
+use crate::{DurationUnit, Effect, EffectType};
+use chrono::NaiveDate;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
 use std::marker::PhantomData;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 /// Trait for typed effect parameters.
 ///
 /// Implement this trait to create custom effect parameter types.
@@ -506,6 +537,347 @@ impl<P: EffectParameter> GenericTypedEffect<P> {
     }
 }
 
+/// Declared type of a computed effect output.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum OutputType {
+    /// Plain number
+    Number,
+    /// Monetary amount in the given currency (rounded to two decimal places)
+    Money { currency: String },
+    /// Duration in the given unit
+    Duration { unit: DurationUnit },
+    /// Calendar date
+    Date,
+    /// Boolean flag
+    Boolean,
+    /// One of a fixed set of values
+    Enum { variants: Vec<String> },
+}
+
+impl fmt::Display for OutputType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number => write!(f, "number"),
+            Self::Money { currency } => write!(f, "money({})", currency),
+            Self::Duration { unit } => write!(f, "duration({})", unit),
+            Self::Date => write!(f, "date"),
+            Self::Boolean => write!(f, "boolean"),
+            Self::Enum { variants } => write!(f, "enum({})", variants.join("|")),
+        }
+    }
+}
+
+/// A typed output computed from the entity when an effect is applied.
+///
+/// The expression uses the [`formula`](crate::formula) language. Outputs are
+/// evaluated in declaration order and may reference earlier outputs by name.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct EffectOutput {
+    /// Output name (e.g., "benefit_amount")
+    pub name: String,
+    /// Declared output type
+    pub output_type: OutputType,
+    /// Formula computing the value
+    pub expression: String,
+}
+
+impl EffectOutput {
+    /// Creates a new output declaration.
+    pub fn new(
+        name: impl Into<String>,
+        output_type: OutputType,
+        expression: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            output_type,
+            expression: expression.into(),
+        }
+    }
+
+    /// Creates a numeric output.
+    pub fn number(name: impl Into<String>, expression: impl Into<String>) -> Self {
+        Self::new(name, OutputType::Number, expression)
+    }
+
+    /// Creates a monetary output.
+    pub fn money(
+        name: impl Into<String>,
+        currency: impl Into<String>,
+        expression: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            name,
+            OutputType::Money {
+                currency: currency.into(),
+            },
+            expression,
+        )
+    }
+
+    /// Creates a duration output.
+    pub fn duration(
+        name: impl Into<String>,
+        unit: DurationUnit,
+        expression: impl Into<String>,
+    ) -> Self {
+        Self::new(name, OutputType::Duration { unit }, expression)
+    }
+
+    /// Creates a date output.
+    pub fn date(name: impl Into<String>, expression: impl Into<String>) -> Self {
+        Self::new(name, OutputType::Date, expression)
+    }
+
+    /// Creates a boolean output.
+    pub fn boolean(name: impl Into<String>, expression: impl Into<String>) -> Self {
+        Self::new(name, OutputType::Boolean, expression)
+    }
+
+    /// Creates an enumerated output restricted to `variants`.
+    pub fn enumeration(
+        name: impl Into<String>,
+        variants: Vec<String>,
+        expression: impl Into<String>,
+    ) -> Self {
+        Self::new(name, OutputType::Enum { variants }, expression)
+    }
+
+    /// Evaluates the output expression and checks it against the declared type.
+    pub fn evaluate<S: FormulaScope + ?Sized>(
+        &self,
+        scope: &S,
+    ) -> Result<OutputValue, FormulaError> {
+        let value = Formula::parse(&self.expression)?.evaluate(scope)?;
+        match &self.output_type {
+            OutputType::Number => Ok(OutputValue::Number(value.as_number()?)),
+            OutputType::Money { currency } => {
+                if let Some(found) = value.currency().filter(|found| found != currency) {
+                    return Err(FormulaError::CurrencyMismatch {
+                        expected: currency.clone(),
+                        found: found.to_string(),
+                    });
+                }
+                let amount = value
+                    .as_decimal()
+                    .ok_or_else(|| FormulaError::TypeMismatch {
+                        expected: FormulaType::Money,
+                        found: value.value_type(),
+                    })?;
+                Ok(OutputValue::money(
+                    amount.round(2, RoundingMode::HalfUp),
+                    currency.clone(),
+                ))
+            }
+            OutputType::Duration { unit } => Ok(OutputValue::Duration {
+                value: value.as_number()?,
+                unit: *unit,
+            }),
+            OutputType::Date => Ok(OutputValue::Date(value.as_date()?)),
+            OutputType::Boolean => Ok(OutputValue::Boolean(value.as_bool()?)),
+            OutputType::Enum { variants } => {
+                let text = value.to_string();
+                if variants.contains(&text) {
+                    Ok(OutputValue::Enum(text))
+                } else {
+                    Err(FormulaError::InvalidVariant {
+                        value: text,
+                        allowed: variants.clone(),
+                    })
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for EffectOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} = {}",
+            self.name, self.output_type, self.expression
+        )
+    }
+}
+
+/// Value of a computed effect output.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum OutputValue {
+    Number(f64),
+    /// Exact amount, rounded to cents when computed
+    Money {
+        amount: Decimal,
+        currency: String,
+    },
+    Duration {
+        value: f64,
+        unit: DurationUnit,
+    },
+    Date(NaiveDate),
+    Boolean(bool),
+    Enum(String),
+}
+
+impl OutputValue {
+    /// Creates a monetary value.
+    pub fn money(amount: Decimal, currency: impl Into<String>) -> Self {
+        Self::Money {
+            amount,
+            currency: currency.into(),
+        }
+    }
+
+    /// Returns the value as a number for aggregation, if it has one.
+    ///
+    /// Booleans count as `1.0`/`0.0`; dates and enum values have no numeric form.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            Self::Money { amount, .. } => Some(amount.to_f64()),
+            Self::Duration { value, .. } => Some(*value),
+            Self::Boolean(b) => Some(if *b { 1.0 } else { 0.0 }),
+            Self::Date(_) | Self::Enum(_) => None,
+        }
+    }
+
+    /// Returns the value as an exact decimal, if it has a numeric form.
+    ///
+    /// Money is returned as is; other numbers go through their shortest
+    /// decimal representation, as in [`OutputValue::as_f64`].
+    pub fn as_decimal(&self) -> Option<Decimal> {
+        match self {
+            Self::Money { amount, .. } => Some(*amount),
+            other => other.as_f64().and_then(Decimal::from_f64),
+        }
+    }
+
+    /// Returns the unit of the value (currency code or duration unit), if any.
+    pub fn unit(&self) -> Option<String> {
+        match self {
+            Self::Money { currency, .. } => Some(currency.clone()),
+            Self::Duration { unit, .. } => Some(unit.to_string()),
+            _ => None,
+        }
+    }
+}
+
+impl From<&OutputValue> for FormulaValue {
+    fn from(value: &OutputValue) -> Self {
+        match value {
+            OutputValue::Number(n) => FormulaValue::Number(*n),
+            OutputValue::Money { amount, currency } => FormulaValue::Money {
+                amount: *amount,
+                currency: currency.clone(),
+            },
+            OutputValue::Duration { value, .. } => FormulaValue::Number(*value),
+            OutputValue::Date(d) => FormulaValue::Date(*d),
+            OutputValue::Boolean(b) => FormulaValue::Bool(*b),
+            OutputValue::Enum(s) => FormulaValue::Text(s.clone()),
+        }
+    }
+}
+
+impl fmt::Display for OutputValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Money { amount, currency } => {
+                // Always show cents, padding amounts with fewer fraction digits
+                let cents = amount.round(2, RoundingMode::HalfUp);
+                let padded = cents.checked_add(&Decimal::new(0, 2)).unwrap_or(cents);
+                write!(f, "{} {}", padded, currency)
+            }
+            Self::Duration { value, unit } => write!(f, "{} {}", value, unit),
+            Self::Date(d) => write!(f, "{}", d.format("%Y-%m-%d")),
+            Self::Boolean(b) => write!(f, "{}", b),
+            Self::Enum(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// Scope that layers already-computed outputs over the entity's attributes.
+struct OutputScope<'a, S: FormulaScope + ?Sized> {
+    base: &'a S,
+    computed: &'a BTreeMap<String, OutputValue>,
+}
+
+impl<S: FormulaScope + ?Sized> FormulaScope for OutputScope<'_, S> {
+    fn lookup(&self, name: &str) -> Option<FormulaValue> {
+        self.computed
+            .get(name)
+            .map(FormulaValue::from)
+            .or_else(|| self.base.lookup(name))
+    }
+
+    fn today(&self) -> Option<NaiveDate> {
+        self.base.today()
+    }
+}
+
+/// Evaluates a list of output declarations in order.
+pub fn compute_outputs<S: FormulaScope + ?Sized>(
+    outputs: &[EffectOutput],
+    scope: &S,
+) -> Result<BTreeMap<String, OutputValue>, FormulaError> {
+    let mut computed = BTreeMap::new();
+    for output in outputs {
+        let value = output.evaluate(&OutputScope {
+            base: scope,
+            computed: &computed,
+        })?;
+        computed.insert(output.name.clone(), value);
+    }
+    Ok(computed)
+}
+
+/// An effect together with the output values computed for a specific entity.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct ComputedEffect {
+    /// The effect that was applied
+    pub effect: Effect,
+    /// Computed output values keyed by output name
+    pub outputs: BTreeMap<String, OutputValue>,
+}
+
+impl ComputedEffect {
+    /// Creates a computed effect.
+    pub fn new(effect: Effect, outputs: BTreeMap<String, OutputValue>) -> Self {
+        Self { effect, outputs }
+    }
+
+    /// Gets a computed output by name.
+    pub fn output(&self, name: &str) -> Option<&OutputValue> {
+        self.outputs.get(name)
+    }
+}
+
+impl fmt::Display for ComputedEffect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.effect)?;
+        for (i, (name, value)) in self.outputs.iter().enumerate() {
+            write!(
+                f,
+                "{}{} = {}",
+                if i == 0 { " [" } else { ", " },
+                name,
+                value
+            )?;
+        }
+        if !self.outputs.is_empty() {
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -576,4 +948,66 @@ mod tests {
 
         assert_eq!(effect.get_parameter("value"), Some(&TestParam(42)));
     }
+
+    #[test]
+    fn test_computed_outputs() {
+        let effect = Effect::new(EffectType::MonetaryTransfer, "Child benefit")
+            .with_output(EffectOutput::money(
+                "benefit",
+                "GBP",
+                "min(children, 3) * 1331.2 / 3",
+            ))
+            .with_output(EffectOutput::boolean("capped", "children > 3"))
+            .with_output(EffectOutput::duration(
+                "period",
+                DurationUnit::Months,
+                "if benefit > 1000 then 12 else 6",
+            ))
+            .with_output(EffectOutput::date("review_on", "add_months(today(), 12)"))
+            .with_output(EffectOutput::enumeration(
+                "band",
+                vec!["low".to_string(), "high".to_string()],
+                "if benefit > 1000 then \"high\" else \"low\"",
+            ));
+
+        let mut attrs = HashMap::new();
+        attrs.insert("children".to_string(), "4".to_string());
+        attrs.insert("current_date".to_string(), "2024-04-01".to_string());
+
+        let outputs = effect.compute_outputs(&attrs).unwrap();
+        assert_eq!(
+            outputs["benefit"],
+            OutputValue::money("1331.20".parse().unwrap(), "GBP")
+        );
+        assert_eq!(outputs["benefit"].to_string(), "1331.20 GBP");
+        assert_eq!(outputs["capped"], OutputValue::Boolean(true));
+        assert_eq!(
+            outputs["period"],
+            OutputValue::Duration {
+                value: 12.0,
+                unit: DurationUnit::Months
+            }
+        );
+        assert_eq!(
+            outputs["review_on"],
+            OutputValue::Date(NaiveDate::from_ymd_opt(2025, 4, 1).unwrap())
+        );
+        assert_eq!(outputs["band"], OutputValue::Enum("high".to_string()));
+    }
+
+    #[test]
+    fn test_output_type_errors() {
+        let attrs: HashMap<String, String> = HashMap::new();
+        let flag = EffectOutput::boolean("flag", "1 + 1");
+        assert!(matches!(
+            flag.evaluate(&attrs),
+            Err(FormulaError::TypeMismatch { .. })
+        ));
+
+        let band = EffectOutput::enumeration("band", vec!["a".to_string()], "\"b\"");
+        assert!(matches!(
+            band.evaluate(&attrs),
+            Err(FormulaError::InvalidVariant { .. })
+        ));
+    }
 }
@@ -43,7 +43,7 @@
 //! println!("{}", summary);
 //! ```
 
-use legalis_core::{Condition, Statute};
+use legalis_core::{Condition, Effect, EffectType, Statute};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -298,8 +298,8 @@ pub fn diff(old: &Statute, new: &Statute) -> DiffResult<StatuteDiff> {
             change_type: ChangeType::Modified,
             target: ChangeTarget::Effect,
             description: "Effect was modified".to_string(),
-            old_value: Some(format!("{:?}", old.effect)),
-            new_value: Some(format!("{:?}", new.effect)),
+            old_value: Some(describe_effect(&old.effect)),
+            new_value: Some(describe_effect(&new.effect)),
         });
         impact.affects_outcome = true;
         impact.severity = impact.severity.max(Severity::Major);
@@ -795,14 +795,49 @@ pub fn diff_effect_only(old: &Statute, new: &Statute) -> DiffResult<Option<Chang
             change_type: ChangeType::Modified,
             target: ChangeTarget::Effect,
             description: "Effect was modified".to_string(),
-            old_value: Some(format!("{:?}", old.effect)),
-            new_value: Some(format!("{:?}", new.effect)),
+            old_value: Some(describe_effect(&old.effect)),
+            new_value: Some(describe_effect(&new.effect)),
         }))
     } else {
         Ok(None)
     }
 }
 
+/// Renders an effect for change records and merge conflicts.
+///
+/// The format is explicit rather than derived from `Debug`, so adding fields
+/// to [`Effect`] does not change diff output:
+/// `Grant: Tax credit granted [amount=500]; outputs: credit: money(JPY) = 500`.
+pub fn describe_effect(effect: &Effect) -> String {
+    let effect_type = match effect.effect_type {
+        EffectType::Grant => "Grant",
+        EffectType::Revoke => "Revoke",
+        EffectType::Obligation => "Obligation",
+        EffectType::Prohibition => "Prohibition",
+        EffectType::MonetaryTransfer => "MonetaryTransfer",
+        EffectType::StatusChange => "StatusChange",
+        EffectType::Custom => "Custom",
+    };
+    let mut rendered = format!("{}: {}", effect_type, effect.description);
+
+    if !effect.parameters.is_empty() {
+        let mut parameters: Vec<_> = effect.parameters.iter().collect();
+        parameters.sort();
+        let parameters: Vec<String> = parameters
+            .into_iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        rendered.push_str(&format!(" [{}]", parameters.join(", ")));
+    }
+
+    if !effect.outputs.is_empty() {
+        let outputs: Vec<String> = effect.outputs.iter().map(ToString::to_string).collect();
+        rendered.push_str(&format!("; outputs: {}", outputs.join(", ")));
+    }
+
+    rendered
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -828,6 +863,21 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_describe_effect() {
+        let effect = Effect::new(EffectType::Grant, "Tax credit granted");
+        assert_eq!(describe_effect(&effect), "Grant: Tax credit granted");
+
+        let effect = effect
+            .with_parameter("rate", "0.1")
+            .with_parameter("amount", "500")
+            .with_output(legalis_core::EffectOutput::money("credit", "JPY", "500"));
+        assert_eq!(
+            describe_effect(&effect),
+            "Grant: Tax credit granted [amount=500, rate=0.1]; outputs: credit: money(JPY) = 500"
+        );
+    }
+
     #[test]
     fn test_no_changes() {
         let statute = test_statute();
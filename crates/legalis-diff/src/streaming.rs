@@ -7,7 +7,7 @@
 
 use crate::{
     Change, ChangeTarget, ChangeType, DiffError, DiffResult, ImpactAssessment, Severity,
-    StatuteDiff,
+    StatuteDiff, describe_effect,
 };
 use legalis_core::{Condition, Statute};
 use std::collections::VecDeque;
@@ -112,8 +112,8 @@ impl StreamingDiffer {
                 change_type: ChangeType::Modified,
                 target: ChangeTarget::Effect,
                 description: "Effect was modified".to_string(),
-                old_value: Some(format!("{:?}", old.effect)),
-                new_value: Some(format!("{:?}", new.effect)),
+                old_value: Some(describe_effect(&old.effect)),
+                new_value: Some(describe_effect(&new.effect)),
             });
             impact.affects_outcome = true;
             impact.severity = impact.severity.max(Severity::Major);
@@ -7,7 +7,7 @@
 //! - Pull request diff integration
 //! - Blame analysis for statute history
 
-use crate::{DiffResult, StatuteDiff, diff};
+use crate::{DiffResult, StatuteDiff, describe_effect, diff};
 use chrono::{DateTime, Utc};
 use legalis_core::Statute;
 use serde::{Deserialize, Serialize};
@@ -486,8 +486,8 @@ pub fn merge_statutes(ours: &Statute, theirs: &Statute, strategy: MergeStrategy)
     if ours.effect != theirs.effect {
         conflicts.push(MergeConflict {
             target: "Effect".to_string(),
-            ours: format!("{:?}", ours.effect),
-            theirs: format!("{:?}", theirs.effect),
+            ours: describe_effect(&ours.effect),
+            theirs: describe_effect(&theirs.effect),
         });
     }
 
@@ -1,7 +1,10 @@
 //! AST (Abstract Syntax Tree) definitions for the legal DSL.
 
 use crate::SourceLocation;
+use crate::numeric::Decimal;
+use legalis_core::DurationUnit;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Token with source location information.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -44,6 +47,7 @@ pub enum Token {
     Priority,
     Scope,
     Constraint,
+    Let, // LET / DEFINE derived variables
 
     // Module system keywords (v0.1.4)
     Namespace,
@@ -59,6 +63,7 @@ pub enum Token {
     Jurisdiction,
     Version,
     Has,
+    Applies,
 
     // Temporal keywords
     CurrentDate,
@@ -78,6 +83,10 @@ pub enum Token {
     NotInRange,
     Default,
 
+    // Arithmetic operators (`*` reuses Star and `-` reuses Dash)
+    Plus,
+    Slash,
+
     // Set operations
     Union,
     Intersect,
@@ -97,6 +106,10 @@ pub enum Token {
     Ident(String),
     StringLit(String),
     Number(u64),
+    /// A number with a fractional part, e.g. `12500.50`
+    Decimal(Decimal),
+    /// An ISO 8601 calendar date, e.g. `2024-04-01`
+    Date(String),
     Operator(String),
 }
 
@@ -122,6 +135,249 @@ pub struct LegalDocument {
     pub exports: Vec<crate::module_system::ExportNode>,
     /// Statute definitions.
     pub statutes: Vec<StatuteNode>,
+    /// Derived variables declared with `LET` (or `DEFINE`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub definitions: Vec<DefinitionNode>,
+}
+
+impl LegalDocument {
+    /// Returns a copy of the document with every `LET` binding inlined into
+    /// the statute conditions that use it.
+    ///
+    /// Bindings may refer to earlier or later bindings; a binding that
+    /// (directly or indirectly) refers to itself is an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use legalis_dsl::{ConditionNode, LegalDslParser};
+    ///
+    /// let doc = LegalDslParser::new()
+    ///     .parse_document(
+    ///         r#"
+    ///         LET net_income = income - tax
+    ///         STATUTE relief: "Relief" { WHEN net_income < 30000 THEN GRANT "Relief" }
+    ///         "#,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let inlined = doc.inline_definitions().unwrap();
+    /// assert!(matches!(
+    ///     inlined.statutes[0].conditions[0],
+    ///     ConditionNode::Expression { .. }
+    /// ));
+    /// ```
+    pub fn inline_definitions(&self) -> crate::DslResult<LegalDocument> {
+        if self.definitions.is_empty() {
+            return Ok(self.clone());
+        }
+        let sources: HashMap<&str, &ExprNode> = self
+            .definitions
+            .iter()
+            .map(|d| (d.name.as_str(), &d.expr))
+            .collect();
+        let mut resolved = HashMap::new();
+        for definition in &self.definitions {
+            resolve_definition(&definition.name, &sources, &mut resolved, &mut Vec::new())?;
+        }
+
+        let mut doc = self.clone();
+        for statute in &mut doc.statutes {
+            for condition in &mut statute.conditions {
+                *condition = condition.substitute(&resolved);
+            }
+            for exception in &mut statute.exceptions {
+                for condition in &mut exception.conditions {
+                    *condition = condition.substitute(&resolved);
+                }
+            }
+        }
+        Ok(doc)
+    }
+}
+
+/// Resolves a binding to an expression over plain fields, detecting cycles.
+fn resolve_definition(
+    name: &str,
+    sources: &HashMap<&str, &ExprNode>,
+    resolved: &mut HashMap<String, ExprNode>,
+    stack: &mut Vec<String>,
+) -> crate::DslResult<ExprNode> {
+    if let Some(expr) = resolved.get(name) {
+        return Ok(expr.clone());
+    }
+    if stack.iter().any(|s| s == name) {
+        stack.push(name.to_string());
+        return Err(crate::DslError::InvalidCondition(format!(
+            "Circular definition: {}",
+            stack.join(" -> ")
+        )));
+    }
+    let Some(source) = sources.get(name) else {
+        return Ok(ExprNode::Field(name.to_string()));
+    };
+
+    stack.push(name.to_string());
+    let mut bindings = HashMap::new();
+    for field in source.fields() {
+        if sources.contains_key(field) {
+            let expr = resolve_definition(field, sources, resolved, stack)?;
+            bindings.insert(field.to_string(), expr);
+        }
+    }
+    stack.pop();
+
+    let expr = source.substitute(&bindings);
+    resolved.insert(name.to_string(), expr.clone());
+    Ok(expr)
+}
+
+/// AST node for a derived variable (`LET net_income = income - tax`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DefinitionNode {
+    /// Name the expression is bound to
+    pub name: String,
+    /// The bound expression
+    pub expr: ExprNode,
+}
+
+/// Arithmetic operators in DSL expressions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ArithOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl ArithOp {
+    /// Returns the operator symbol.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            ArithOp::Add => "+",
+            ArithOp::Sub => "-",
+            ArithOp::Mul => "*",
+            ArithOp::Div => "/",
+        }
+    }
+
+    /// Binding strength; multiplication and division bind tighter.
+    fn precedence(&self) -> u8 {
+        match self {
+            ArithOp::Add | ArithOp::Sub => 1,
+            ArithOp::Mul | ArithOp::Div => 2,
+        }
+    }
+}
+
+/// An arithmetic expression over fields and literals (`income - deductions`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ExprNode {
+    /// A field, attribute or `LET` binding
+    Field(String),
+    /// A literal value
+    Literal(ConditionValue),
+    /// Unary minus
+    Negate(Box<ExprNode>),
+    /// A binary arithmetic operation
+    Binary {
+        op: ArithOp,
+        left: Box<ExprNode>,
+        right: Box<ExprNode>,
+    },
+}
+
+impl ExprNode {
+    /// Creates a binary expression.
+    pub fn binary(op: ArithOp, left: ExprNode, right: ExprNode) -> Self {
+        ExprNode::Binary {
+            op,
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    /// Returns the fields referenced by the expression, in order of appearance.
+    pub fn fields(&self) -> Vec<&str> {
+        match self {
+            ExprNode::Field(name) => vec![name.as_str()],
+            ExprNode::Literal(_) => Vec::new(),
+            ExprNode::Negate(inner) => inner.fields(),
+            ExprNode::Binary { left, right, .. } => {
+                let mut fields = left.fields();
+                fields.extend(right.fields());
+                fields
+            }
+        }
+    }
+
+    /// Replaces bound field references with their expressions.
+    pub fn substitute(&self, bindings: &HashMap<String, ExprNode>) -> ExprNode {
+        match self {
+            ExprNode::Field(name) => bindings.get(name).cloned().unwrap_or_else(|| self.clone()),
+            ExprNode::Literal(_) => self.clone(),
+            ExprNode::Negate(inner) => ExprNode::Negate(Box::new(inner.substitute(bindings))),
+            ExprNode::Binary { op, left, right } => {
+                ExprNode::binary(*op, left.substitute(bindings), right.substitute(bindings))
+            }
+        }
+    }
+
+    /// Renders the expression in the core formula language used by
+    /// [`legalis_core::Condition::Calculation`].
+    pub fn to_formula(&self) -> String {
+        match self {
+            ExprNode::Field(name) => legalis_core::formula::quote_identifier(name),
+            ExprNode::Literal(value) => value.to_formula(),
+            ExprNode::Negate(inner) => format!("-({})", inner.to_formula()),
+            ExprNode::Binary { op, left, right } => format!(
+                "({} {} {})",
+                left.to_formula(),
+                op.symbol(),
+                right.to_formula()
+            ),
+        }
+    }
+
+    fn fmt_with_precedence(&self, f: &mut std::fmt::Formatter<'_>, parent: u8) -> std::fmt::Result {
+        match self {
+            ExprNode::Field(name) => write!(f, "{}", name),
+            ExprNode::Literal(value) => match value {
+                ConditionValue::Number(n) => write!(f, "{}", n),
+                ConditionValue::Decimal(d) => write!(f, "{}", d),
+                ConditionValue::Money { amount, currency } => write!(f, "{} {}", amount, currency),
+                ConditionValue::Duration { value, unit } => write!(f, "{} {}", value, unit),
+                ConditionValue::String(s) => write!(f, "\"{}\"", s),
+                ConditionValue::Boolean(b) => write!(f, "{}", b),
+                ConditionValue::Date(d) => write!(f, "{}", d),
+                ConditionValue::SetExpr(_) => write!(f, "SET_EXPR"),
+            },
+            ExprNode::Negate(inner) => {
+                write!(f, "-")?;
+                inner.fmt_with_precedence(f, 3)
+            }
+            ExprNode::Binary { op, left, right } => {
+                let precedence = op.precedence();
+                if precedence < parent {
+                    write!(f, "(")?;
+                }
+                left.fmt_with_precedence(f, precedence)?;
+                write!(f, " {} ", op.symbol())?;
+                // Right operands bind one level tighter: a - (b - c)
+                right.fmt_with_precedence(f, precedence + 1)?;
+                if precedence < parent {
+                    write!(f, ")")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ExprNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_with_precedence(f, 0)
+    }
 }
 
 /// AST node for an exception clause.
@@ -272,11 +528,82 @@ pub enum ConditionNode {
         operator: String,
         value: ConditionValue,
     },
+    /// Another statute applies to the same entity (`APPLIES child-benefit`)
+    StatuteApplies {
+        statute_id: String,
+    },
+    /// Comparison on a computed output of another statute
+    /// (`child-benefit.amount > 0`)
+    StatuteOutput {
+        statute_id: String,
+        output: String,
+        operator: String,
+        value: ConditionValue,
+    },
+    /// Comparison between arithmetic expressions (`income - deductions > 30000`)
+    Expression {
+        left: ExprNode,
+        operator: String,
+        right: ExprNode,
+    },
     And(Box<ConditionNode>, Box<ConditionNode>),
     Or(Box<ConditionNode>, Box<ConditionNode>),
     Not(Box<ConditionNode>),
 }
 
+impl ConditionNode {
+    /// Replaces references to bound names with their expressions.
+    ///
+    /// A comparison on a bound name becomes an [`ConditionNode::Expression`].
+    pub fn substitute(&self, bindings: &HashMap<String, ExprNode>) -> ConditionNode {
+        match self {
+            ConditionNode::Comparison {
+                field,
+                operator,
+                value,
+            } if bindings.contains_key(field) => ConditionNode::Expression {
+                left: bindings[field].clone(),
+                operator: operator.clone(),
+                right: ExprNode::Literal(value.clone()),
+            },
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => ConditionNode::Expression {
+                left: left.substitute(bindings),
+                operator: operator.clone(),
+                right: right.substitute(bindings),
+            },
+            ConditionNode::And(left, right) => ConditionNode::And(
+                Box::new(left.substitute(bindings)),
+                Box::new(right.substitute(bindings)),
+            ),
+            ConditionNode::Or(left, right) => ConditionNode::Or(
+                Box::new(left.substitute(bindings)),
+                Box::new(right.substitute(bindings)),
+            ),
+            ConditionNode::Not(inner) => ConditionNode::Not(Box::new(inner.substitute(bindings))),
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns the IDs of statutes this condition refers to.
+    pub fn referenced_statutes(&self) -> Vec<&str> {
+        match self {
+            ConditionNode::StatuteApplies { statute_id }
+            | ConditionNode::StatuteOutput { statute_id, .. } => vec![statute_id.as_str()],
+            ConditionNode::And(left, right) | ConditionNode::Or(left, right) => {
+                let mut refs = left.referenced_statutes();
+                refs.extend(right.referenced_statutes());
+                refs
+            }
+            ConditionNode::Not(inner) => inner.referenced_statutes(),
+            _ => Vec::new(),
+        }
+    }
+}
+
 /// Temporal field types for date/time conditions
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TemporalField {
@@ -303,6 +630,19 @@ pub enum SetExpression {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ConditionValue {
     Number(i64),
+    /// An exact decimal, e.g. `12500.50` or `-0.5`
+    Decimal(Decimal),
+    /// A currency-tagged amount, e.g. `300 JPY`
+    Money {
+        amount: Decimal,
+        /// ISO 4217 currency code
+        currency: String,
+    },
+    /// A length of time, e.g. `6 months`
+    Duration {
+        value: i64,
+        unit: DurationUnit,
+    },
     String(String),
     Boolean(bool),
     Date(String),
@@ -310,6 +650,47 @@ pub enum ConditionValue {
     SetExpr(SetExpression),
 }
 
+impl ConditionValue {
+    /// Returns the numeric value of a number, decimal or money literal.
+    pub fn as_decimal(&self) -> Option<Decimal> {
+        match self {
+            ConditionValue::Number(n) => Some(Decimal::from(*n)),
+            ConditionValue::Decimal(d) => Some(*d),
+            ConditionValue::Money { amount, .. } => Some(*amount),
+            _ => None,
+        }
+    }
+
+    /// Renders the literal in the core formula language.
+    ///
+    /// Decimals keep every digit and money keeps its currency (`300.50 JPY`).
+    pub fn to_formula(&self) -> String {
+        match self {
+            ConditionValue::Number(n) => n.to_string(),
+            ConditionValue::Decimal(d) => d.to_string(),
+            ConditionValue::Money { amount, currency } => format!("{} {}", amount, currency),
+            ConditionValue::Duration { value, .. } => value.to_string(),
+            ConditionValue::Boolean(b) => b.to_string(),
+            ConditionValue::Date(d) => d.clone(),
+            ConditionValue::String(s) => format!("{:?}", s),
+            ConditionValue::SetExpr(_) => "0".to_string(),
+        }
+    }
+
+    /// Returns the arithmetic negation of a numeric literal.
+    pub fn negated(&self) -> Option<ConditionValue> {
+        match self {
+            ConditionValue::Number(n) => n.checked_neg().map(ConditionValue::Number),
+            ConditionValue::Decimal(d) => Some(ConditionValue::Decimal(-*d)),
+            ConditionValue::Money { amount, currency } => Some(ConditionValue::Money {
+                amount: -*amount,
+                currency: currency.clone(),
+            }),
+            _ => None,
+        }
+    }
+}
+
 /// AST node for effects.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EffectNode {
@@ -466,6 +847,7 @@ mod tests {
                 constraints: vec![],
                 priority: None,
             }],
+            definitions: Vec::new(),
         };
 
         let mut counter = ConditionCounter { count: 0 };
@@ -525,6 +907,7 @@ mod tests {
                     priority: None,
                 },
             ],
+            definitions: Vec::new(),
         };
 
         let mut counter = ConditionCounter { count: 0 };
@@ -1303,6 +1686,7 @@ pub mod transform {
                     constraints: vec![],
                     priority: None,
                 }],
+                definitions: Vec::new(),
             };
 
             let diff = diff_documents(&doc, &doc);
@@ -1317,6 +1701,7 @@ pub mod transform {
                 exports: vec![],
                 imports: vec![],
                 statutes: vec![],
+                definitions: Vec::new(),
             };
 
             let new_doc = LegalDocument {
@@ -1340,6 +1725,7 @@ pub mod transform {
                     constraints: vec![],
                     priority: None,
                 }],
+                definitions: Vec::new(),
             };
 
             let diff = diff_documents(&old_doc, &new_doc);
@@ -1359,6 +1745,7 @@ pub mod transform {
                     kind: crate::module_system::ImportKind::Simple,
                 }],
                 statutes: vec![],
+                definitions: Vec::new(),
             };
 
             let new_doc = LegalDocument {
@@ -1366,6 +1753,7 @@ pub mod transform {
                 exports: vec![],
                 imports: vec![],
                 statutes: vec![],
+                definitions: Vec::new(),
             };
 
             let diff = diff_documents(&old_doc, &new_doc);
@@ -17,12 +17,18 @@
 //! - Hover information for keywords
 //! - Keyword completion
 //! - Document symbols (outline view)
+//! - Go to definition, find references and rename across imported files
+//! - Type checker and verifier diagnostics on save, with quick fixes
+//!
+//! Files below the workspace root are indexed on startup so references
+//! resolve into files that are not open in the editor.
 //!
 //! ## Editor Configuration
 //!
 //! ### VS Code
 //!
-//! Add to your VS Code settings.json:
+//! Install the bundled extension from `vscode-extension/`, then point it at
+//! the server in your VS Code settings.json:
 //! ```json
 //! {
 //!   "legalis-dsl.server.path": "/path/to/legalis-lsp"
@@ -4,7 +4,9 @@
 //! target languages from the legal DSL AST.
 
 use crate::DslResult;
-use crate::ast::{ConditionNode, ConditionValue, LegalDocument, StatuteNode, TemporalField};
+use crate::ast::{
+    ConditionNode, ConditionValue, ExprNode, LegalDocument, StatuteNode, TemporalField,
+};
 use std::fmt::Write;
 
 /// Helper function to convert TemporalField to a string representation.
@@ -15,6 +17,26 @@ fn temporal_field_to_string(field: &TemporalField) -> String {
     }
 }
 
+/// Renders an arithmetic expression, mapping fields and literals through the
+/// target language's conventions. Binary operations are always parenthesised.
+fn format_expr(
+    expr: &ExprNode,
+    field: &dyn Fn(&str) -> String,
+    literal: &dyn Fn(&ConditionValue) -> DslResult<String>,
+) -> DslResult<String> {
+    match expr {
+        ExprNode::Field(name) => Ok(field(name)),
+        ExprNode::Literal(value) => literal(value),
+        ExprNode::Negate(inner) => Ok(format!("-({})", format_expr(inner, field, literal)?)),
+        ExprNode::Binary { op, left, right } => Ok(format!(
+            "({} {} {})",
+            format_expr(left, field, literal)?,
+            op.symbol(),
+            format_expr(right, field, literal)?
+        )),
+    }
+}
+
 /// Trait for code generators that can translate legal documents.
 pub trait CodeGenerator {
     /// Generates code for the entire document.
@@ -27,6 +49,25 @@ pub trait CodeGenerator {
     fn file_extension(&self) -> &str;
 }
 
+/// Field name under which a statute output is exposed on the entity.
+fn output_field(statute_id: &str, output: &str) -> String {
+    format!("{}_{}", statute_id.replace('-', "_"), output)
+}
+
+/// Rewrites a statute output comparison as a comparison on its entity field.
+fn output_comparison(
+    statute_id: &str,
+    output: &str,
+    operator: &str,
+    value: &ConditionValue,
+) -> ConditionNode {
+    ConditionNode::Comparison {
+        field: output_field(statute_id, output),
+        operator: operator.to_string(),
+        value: value.clone(),
+    }
+}
+
 /// SQL generator for creating database schemas and queries from statutes.
 pub struct SqlGenerator {
     /// Use CHECK constraints for conditions
@@ -66,6 +107,20 @@ impl SqlGenerator {
                 let val = self.format_value(value)?;
                 Ok(format!("{} {} {}", field, operator, val))
             }
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => {
+                let field = |name: &str| name.to_string();
+                let literal = |value: &ConditionValue| self.format_value(value);
+                Ok(format!(
+                    "{} {} {}",
+                    format_expr(left, &field, &literal)?,
+                    operator,
+                    format_expr(right, &field, &literal)?
+                ))
+            }
             ConditionNode::HasAttribute { key } => Ok(format!("{} IS NOT NULL", key)),
             ConditionNode::Between { field, min, max } => {
                 let min_val = self.format_value(min)?;
@@ -131,6 +186,15 @@ impl SqlGenerator {
                 // SQL doesn't have universal regex support, use LIKE as fallback
                 Ok(format!("{} LIKE '%'", field))
             }
+            ConditionNode::StatuteApplies { statute_id } => {
+                Ok(format!("{}_applies = TRUE", self.table_name(statute_id)))
+            }
+            ConditionNode::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+            } => self.generate_condition(&output_comparison(statute_id, output, operator, value)),
             ConditionNode::TemporalComparison {
                 field,
                 operator,
@@ -146,6 +210,11 @@ impl SqlGenerator {
     fn format_value(&self, value: &ConditionValue) -> DslResult<String> {
         match value {
             ConditionValue::Number(n) => Ok(n.to_string()),
+            ConditionValue::Decimal(d) => Ok(d.to_string()),
+            ConditionValue::Money { amount, .. } => Ok(amount.to_string()),
+            ConditionValue::Duration { value, unit } => {
+                Ok(format!("INTERVAL '{} {}'", value, unit))
+            }
             ConditionValue::String(s) => Ok(format!("'{}'", s.replace('\'', "''"))),
             ConditionValue::Boolean(b) => Ok(if *b {
                 "TRUE".to_string()
@@ -219,6 +288,11 @@ impl SqlGenerator {
             ConditionNode::HasAttribute { key } => {
                 fields.insert(key.clone());
             }
+            ConditionNode::Expression { left, right, .. } => {
+                for field in left.fields().into_iter().chain(right.fields()) {
+                    fields.insert(field.to_string());
+                }
+            }
             ConditionNode::And(left, right) | ConditionNode::Or(left, right) => {
                 self.extract_fields(left, fields);
                 self.extract_fields(right, fields);
@@ -226,6 +300,14 @@ impl SqlGenerator {
             ConditionNode::Not(inner) => {
                 self.extract_fields(inner, fields);
             }
+            ConditionNode::StatuteApplies { statute_id } => {
+                fields.insert(format!("{}_applies", self.table_name(statute_id)));
+            }
+            ConditionNode::StatuteOutput {
+                statute_id, output, ..
+            } => {
+                fields.insert(output_field(statute_id, output));
+            }
             ConditionNode::TemporalComparison { .. } => {
                 // Temporal comparisons might need special handling
             }
@@ -235,6 +317,7 @@ impl SqlGenerator {
 
 impl CodeGenerator for SqlGenerator {
     fn generate(&self, doc: &LegalDocument) -> DslResult<String> {
+        let doc = &doc.inline_definitions()?;
         let mut sql = String::new();
 
         writeln!(&mut sql, "-- Generated SQL from Legal DSL").unwrap();
@@ -302,6 +385,24 @@ impl PythonGenerator {
                 let val = self.format_value(value)?;
                 Ok(format!("{}.{} {} {}", "obj", field, py_op, val))
             }
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => {
+                let py_op = match operator.as_str() {
+                    "=" | "==" => "==",
+                    op => op,
+                };
+                let field = |name: &str| format!("obj.{}", name);
+                let literal = |value: &ConditionValue| self.format_value(value);
+                Ok(format!(
+                    "{} {} {}",
+                    format_expr(left, &field, &literal)?,
+                    py_op,
+                    format_expr(right, &field, &literal)?
+                ))
+            }
             ConditionNode::HasAttribute { key } => Ok(format!(
                 "hasattr(obj, '{}') and obj.{} is not None",
                 key, key
@@ -330,6 +431,18 @@ impl PythonGenerator {
                 let inner_py = self.generate_condition(inner, indent)?;
                 Ok(format!("not ({})", inner_py))
             }
+            ConditionNode::StatuteApplies { statute_id } => {
+                Ok(format!("{}(obj)", self.function_name(statute_id)))
+            }
+            ConditionNode::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+            } => self.generate_condition(
+                &output_comparison(statute_id, output, operator, value),
+                indent,
+            ),
             _ => Ok("True".to_string()),
         }
     }
@@ -338,6 +451,9 @@ impl PythonGenerator {
     fn format_value(&self, value: &ConditionValue) -> DslResult<String> {
         match value {
             ConditionValue::Number(n) => Ok(n.to_string()),
+            ConditionValue::Decimal(d) => Ok(d.to_string()),
+            ConditionValue::Money { amount, .. } => Ok(amount.to_string()),
+            ConditionValue::Duration { value, .. } => Ok(value.to_string()),
             ConditionValue::String(s) => Ok(format!("'{}'", s.replace('\'', "\\'"))),
             ConditionValue::Boolean(b) => Ok(if *b {
                 "True".to_string()
@@ -386,6 +502,7 @@ impl PythonGenerator {
 
 impl CodeGenerator for PythonGenerator {
     fn generate(&self, doc: &LegalDocument) -> DslResult<String> {
+        let doc = &doc.inline_definitions()?;
         let mut py = String::new();
 
         writeln!(&mut py, "# Generated Python from Legal DSL").unwrap();
@@ -459,6 +576,26 @@ impl PrologGenerator {
                 let val = self.format_value(value)?;
                 Ok(format!("{}_{} {} {}", var, field, pl_op, val))
             }
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => {
+                let pl_op = match operator.as_str() {
+                    "=" | "==" => "=:=",
+                    "!=" => "=\\=",
+                    "<=" => "=<",
+                    op => op,
+                };
+                let field = |name: &str| format!("{}_{}", var, name);
+                let literal = |value: &ConditionValue| self.format_value(value);
+                Ok(format!(
+                    "{} {} {}",
+                    format_expr(left, &field, &literal)?,
+                    pl_op,
+                    format_expr(right, &field, &literal)?
+                ))
+            }
             ConditionNode::HasAttribute { key } => Ok(format!(
                 "nonvar({}_{}) , {}_{} \\= null",
                 var, key, var, key
@@ -539,6 +676,16 @@ impl PrologGenerator {
                     var, field, regex_pattern
                 ))
             }
+            ConditionNode::StatuteApplies { statute_id } => {
+                Ok(format!("{}({})", self.predicate_name(statute_id), var))
+            }
+            ConditionNode::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+            } => self
+                .generate_condition(&output_comparison(statute_id, output, operator, value), var),
             ConditionNode::TemporalComparison {
                 field,
                 operator,
@@ -563,6 +710,9 @@ impl PrologGenerator {
     fn format_value(&self, value: &ConditionValue) -> DslResult<String> {
         match value {
             ConditionValue::Number(n) => Ok(n.to_string()),
+            ConditionValue::Decimal(d) => Ok(d.to_string()),
+            ConditionValue::Money { amount, .. } => Ok(amount.to_string()),
+            ConditionValue::Duration { value, .. } => Ok(value.to_string()),
             ConditionValue::String(s) => Ok(format!("'{}'", s.replace('\'', "\\'"))),
             ConditionValue::Boolean(b) => Ok(if *b {
                 "true".to_string()
@@ -643,6 +793,7 @@ impl PrologGenerator {
 
 impl CodeGenerator for PrologGenerator {
     fn generate(&self, doc: &LegalDocument) -> DslResult<String> {
+        let doc = &doc.inline_definitions()?;
         let mut pl = String::new();
 
         writeln!(&mut pl, "% Generated Prolog from Legal DSL").unwrap();
@@ -718,6 +869,20 @@ impl TypeScriptGenerator {
                 let val = self.format_value(value)?;
                 Ok(format!("{}.{} {} {}", entity_var, field, operator, val))
             }
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => {
+                let field = |name: &str| format!("{}.{}", entity_var, name);
+                let literal = |value: &ConditionValue| self.format_value(value);
+                Ok(format!(
+                    "{} {} {}",
+                    format_expr(left, &field, &literal)?,
+                    operator,
+                    format_expr(right, &field, &literal)?
+                ))
+            }
             ConditionNode::HasAttribute { key } => Ok(format!(
                 "{}.{} !== undefined && {}.{} !== null",
                 entity_var, key, entity_var, key
@@ -799,6 +964,20 @@ impl TypeScriptGenerator {
                 "/{}/i.test({}.{})",
                 regex_pattern, entity_var, field
             )),
+            ConditionNode::StatuteApplies { statute_id } => Ok(format!(
+                "{}({})",
+                self.function_name(statute_id),
+                entity_var
+            )),
+            ConditionNode::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+            } => self.generate_condition(
+                &output_comparison(statute_id, output, operator, value),
+                entity_var,
+            ),
             ConditionNode::TemporalComparison {
                 field,
                 operator,
@@ -818,6 +997,9 @@ impl TypeScriptGenerator {
     fn format_value(&self, value: &ConditionValue) -> DslResult<String> {
         match value {
             ConditionValue::Number(n) => Ok(n.to_string()),
+            ConditionValue::Decimal(d) => Ok(d.to_string()),
+            ConditionValue::Money { amount, .. } => Ok(amount.to_string()),
+            ConditionValue::Duration { value, .. } => Ok(value.to_string()),
             ConditionValue::String(s) => Ok(format!("\"{}\"", s.replace('"', "\\\""))),
             ConditionValue::Boolean(b) => Ok(b.to_string()),
             ConditionValue::Date(d) => Ok(format!("\"{}\"", d)),
@@ -883,6 +1065,7 @@ impl TypeScriptGenerator {
 
 impl CodeGenerator for TypeScriptGenerator {
     fn generate(&self, doc: &LegalDocument) -> DslResult<String> {
+        let doc = &doc.inline_definitions()?;
         let mut code = String::new();
 
         writeln!(
@@ -948,6 +1131,20 @@ impl RustGenerator {
                 let val = self.format_value(value)?;
                 Ok(format!("{}.{} {} {}", entity_var, field, operator, val))
             }
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => {
+                let field = |name: &str| format!("{}.{}", entity_var, name);
+                let literal = |value: &ConditionValue| self.format_value(value);
+                Ok(format!(
+                    "{} {} {}",
+                    format_expr(left, &field, &literal)?,
+                    operator,
+                    format_expr(right, &field, &literal)?
+                ))
+            }
             ConditionNode::HasAttribute { key } => Ok(format!("{}.{}.is_some()", entity_var, key)),
             ConditionNode::Between { field, min, max } => {
                 let min_val = self.format_value(min)?;
@@ -1026,6 +1223,20 @@ impl RustGenerator {
                 "Regex::new(r\"{}\").unwrap().is_match(&{}.{})",
                 regex_pattern, entity_var, field
             )),
+            ConditionNode::StatuteApplies { statute_id } => Ok(format!(
+                "{}({})",
+                self.function_name(statute_id),
+                entity_var
+            )),
+            ConditionNode::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+            } => self.generate_condition(
+                &output_comparison(statute_id, output, operator, value),
+                entity_var,
+            ),
             ConditionNode::TemporalComparison {
                 field,
                 operator,
@@ -1042,6 +1253,9 @@ impl RustGenerator {
     fn format_value(&self, value: &ConditionValue) -> DslResult<String> {
         match value {
             ConditionValue::Number(n) => Ok(n.to_string()),
+            ConditionValue::Decimal(d) => Ok(d.to_string()),
+            ConditionValue::Money { amount, .. } => Ok(amount.to_string()),
+            ConditionValue::Duration { value, .. } => Ok(value.to_string()),
             ConditionValue::String(s) => Ok(format!("\"{}\"", s.replace('"', "\\\""))),
             ConditionValue::Boolean(b) => Ok(b.to_string()),
             ConditionValue::Date(d) => Ok(format!("\"{}\"", d)),
@@ -1081,6 +1295,7 @@ impl RustGenerator {
 
 impl CodeGenerator for RustGenerator {
     fn generate(&self, doc: &LegalDocument) -> DslResult<String> {
+        let doc = &doc.inline_definitions()?;
         let mut code = String::new();
 
         writeln!(&mut code, "// Generated Rust code from Legal DSL").unwrap();
@@ -1151,6 +1366,20 @@ impl GoGenerator {
                 let val = self.format_value(value)?;
                 Ok(format!("{}.{} {} {}", entity_var, field, operator, val))
             }
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => {
+                let field = |name: &str| format!("{}.{}", entity_var, name);
+                let literal = |value: &ConditionValue| self.format_value(value);
+                Ok(format!(
+                    "{} {} {}",
+                    format_expr(left, &field, &literal)?,
+                    operator,
+                    format_expr(right, &field, &literal)?
+                ))
+            }
             ConditionNode::HasAttribute { key } => Ok(format!("{}.{} != nil", entity_var, key)),
             ConditionNode::Between { field, min, max } => {
                 let min_val = self.format_value(min)?;
@@ -1229,6 +1458,20 @@ impl GoGenerator {
                 "regexp.MustCompile(\"{}\").MatchString({}.{})",
                 regex_pattern, entity_var, field
             )),
+            ConditionNode::StatuteApplies { statute_id } => Ok(format!(
+                "{}({})",
+                self.function_name(statute_id),
+                entity_var
+            )),
+            ConditionNode::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+            } => self.generate_condition(
+                &output_comparison(statute_id, output, operator, value),
+                entity_var,
+            ),
             ConditionNode::TemporalComparison {
                 field,
                 operator,
@@ -1245,6 +1488,9 @@ impl GoGenerator {
     fn format_value(&self, value: &ConditionValue) -> DslResult<String> {
         match value {
             ConditionValue::Number(n) => Ok(n.to_string()),
+            ConditionValue::Decimal(d) => Ok(d.to_string()),
+            ConditionValue::Money { amount, .. } => Ok(amount.to_string()),
+            ConditionValue::Duration { value, .. } => Ok(value.to_string()),
             ConditionValue::String(s) => Ok(format!("\"{}\"", s.replace('"', "\\\""))),
             ConditionValue::Boolean(b) => Ok(b.to_string()),
             ConditionValue::Date(d) => Ok(format!("\"{}\"", d)),
@@ -1282,6 +1528,7 @@ impl GoGenerator {
 
 impl CodeGenerator for GoGenerator {
     fn generate(&self, doc: &LegalDocument) -> DslResult<String> {
+        let doc = &doc.inline_definitions()?;
         let mut code = String::new();
 
         writeln!(&mut code, "// Generated Go code from Legal DSL").unwrap();
@@ -1365,6 +1612,21 @@ impl JavaGenerator {
                 let getter = format!("get{}()", self.capitalize_first(field));
                 Ok(format!("{}.{} {} {}", entity_var, getter, operator, val))
             }
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => {
+                let field =
+                    |name: &str| format!("{}.get{}()", entity_var, self.capitalize_first(name));
+                let literal = |value: &ConditionValue| self.format_value(value);
+                Ok(format!(
+                    "{} {} {}",
+                    format_expr(left, &field, &literal)?,
+                    operator,
+                    format_expr(right, &field, &literal)?
+                ))
+            }
             ConditionNode::HasAttribute { key } => {
                 let getter = format!("get{}()", self.capitalize_first(key));
                 Ok(format!("{}.{} != null", entity_var, getter))
@@ -1456,6 +1718,18 @@ impl JavaGenerator {
                     regex_pattern, entity_var, getter
                 ))
             }
+            ConditionNode::StatuteApplies { statute_id } => {
+                Ok(format!("{}({})", self.method_name(statute_id), entity_var))
+            }
+            ConditionNode::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+            } => self.generate_condition(
+                &output_comparison(statute_id, output, operator, value),
+                entity_var,
+            ),
             ConditionNode::TemporalComparison {
                 field,
                 operator,
@@ -1482,6 +1756,9 @@ impl JavaGenerator {
     fn format_value(&self, value: &ConditionValue) -> DslResult<String> {
         match value {
             ConditionValue::Number(n) => Ok(n.to_string()),
+            ConditionValue::Decimal(d) => Ok(d.to_string()),
+            ConditionValue::Money { amount, .. } => Ok(amount.to_string()),
+            ConditionValue::Duration { value, .. } => Ok(value.to_string()),
             ConditionValue::String(s) => Ok(format!("\"{}\"", s.replace('"', "\\\""))),
             ConditionValue::Boolean(b) => Ok(b.to_string()),
             ConditionValue::Date(d) => Ok(format!("\"{}\"", d)),
@@ -1528,6 +1805,7 @@ impl JavaGenerator {
 
 impl CodeGenerator for JavaGenerator {
     fn generate(&self, doc: &LegalDocument) -> DslResult<String> {
+        let doc = &doc.inline_definitions()?;
         let mut code = String::new();
 
         writeln!(&mut code, "// Generated Java code from Legal DSL").unwrap();
@@ -1616,6 +1894,20 @@ impl CSharpGenerator {
                     val
                 ))
             }
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => {
+                let field = |name: &str| format!("{}.{}", entity_var, self.capitalize_first(name));
+                let literal = |value: &ConditionValue| self.format_value(value);
+                Ok(format!(
+                    "{} {} {}",
+                    format_expr(left, &field, &literal)?,
+                    operator,
+                    format_expr(right, &field, &literal)?
+                ))
+            }
             ConditionNode::HasAttribute { key } => Ok(format!(
                 "{}.{} != null",
                 entity_var,
@@ -1708,6 +2000,18 @@ impl CSharpGenerator {
                     entity_var, prop, regex_pattern
                 ))
             }
+            ConditionNode::StatuteApplies { statute_id } => {
+                Ok(format!("{}({})", self.method_name(statute_id), entity_var))
+            }
+            ConditionNode::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+            } => self.generate_condition(
+                &output_comparison(statute_id, output, operator, value),
+                entity_var,
+            ),
             ConditionNode::TemporalComparison {
                 field,
                 operator,
@@ -1734,6 +2038,9 @@ impl CSharpGenerator {
     fn format_value(&self, value: &ConditionValue) -> DslResult<String> {
         match value {
             ConditionValue::Number(n) => Ok(n.to_string()),
+            ConditionValue::Decimal(d) => Ok(d.to_string()),
+            ConditionValue::Money { amount, .. } => Ok(amount.to_string()),
+            ConditionValue::Duration { value, .. } => Ok(value.to_string()),
             ConditionValue::String(s) => Ok(format!("\"{}\"", s.replace('"', "\\\""))),
             ConditionValue::Boolean(b) => Ok(if *b { "true" } else { "false" }.to_string()),
             ConditionValue::Date(d) => Ok(format!("\"{}\"", d)),
@@ -1789,6 +2096,7 @@ impl CSharpGenerator {
 
 impl CodeGenerator for CSharpGenerator {
     fn generate(&self, doc: &LegalDocument) -> DslResult<String> {
+        let doc = &doc.inline_definitions()?;
         let mut code = String::new();
 
         writeln!(&mut code, "// Generated C# code from Legal DSL").unwrap();
@@ -1871,6 +2179,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute()],
+            definitions: Vec::new(),
         };
 
         let generator = SqlGenerator::new();
@@ -1888,6 +2197,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute()],
+            definitions: Vec::new(),
         };
 
         let generator = PythonGenerator::new();
@@ -1919,6 +2229,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute()],
+            definitions: Vec::new(),
         };
 
         let generator = PrologGenerator::new();
@@ -1943,6 +2254,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute()],
+            definitions: Vec::new(),
         };
 
         let generator = PrologGenerator {
@@ -1962,6 +2274,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute()],
+            definitions: Vec::new(),
         };
 
         let generator = PrologGenerator::new();
@@ -1978,6 +2291,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute()],
+            definitions: Vec::new(),
         };
 
         let generator = SqlGenerator::new();
@@ -2000,6 +2314,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute()],
+            definitions: Vec::new(),
         };
 
         let generator = PythonGenerator::new();
@@ -2021,6 +2336,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute()],
+            definitions: Vec::new(),
         };
 
         let generator = PrologGenerator::new();
@@ -2083,6 +2399,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![complex_statute],
+            definitions: Vec::new(),
         };
 
         // Test all generators can handle complex documents
@@ -2127,6 +2444,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute()],
+            definitions: Vec::new(),
         };
 
         let generator = TypeScriptGenerator::new();
@@ -2145,6 +2463,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute()],
+            definitions: Vec::new(),
         };
 
         let generator = RustGenerator::new();
@@ -2162,6 +2481,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute()],
+            definitions: Vec::new(),
         };
 
         let generator = GoGenerator::new();
@@ -2180,6 +2500,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute()],
+            definitions: Vec::new(),
         };
 
         let generator = JavaGenerator::new();
@@ -2198,6 +2519,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute()],
+            definitions: Vec::new(),
         };
 
         let mut generator = TypeScriptGenerator::new();
@@ -2217,6 +2539,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute()],
+            definitions: Vec::new(),
         };
 
         let generator = CSharpGenerator::new();
@@ -617,6 +617,7 @@ mod tests {
                 scope: None,
                 constraints: vec![],
             }],
+            definitions: Vec::new(),
         };
 
         let provider = CompletionProvider::from_document(&doc);
@@ -287,6 +287,7 @@ mod tests {
                 create_test_statute("US-CA-law-2", "REVOKE"),
                 create_test_statute("US-NY-law-1", "GRANT"),
             ],
+            definitions: Vec::new(),
         };
 
         let matrix = ComplianceMatrix::from_document(&doc);
@@ -310,6 +311,7 @@ mod tests {
                 create_test_statute("US-CA-law-1", "GRANT"),
                 create_test_statute("US-CA-law-2", "GRANT"),
             ],
+            definitions: Vec::new(),
         };
 
         let matrix = ComplianceMatrix::from_document(&doc);
@@ -329,6 +331,7 @@ mod tests {
                 create_test_statute("US-CA-law-1", "GRANT"),
                 create_test_statute("US-NY-law-1", "REVOKE"),
             ],
+            definitions: Vec::new(),
         };
 
         let matrix = ComplianceMatrix::from_document(&doc);
@@ -352,6 +355,7 @@ mod tests {
                 create_test_statute("US-NY-law-1", "REVOKE"),
                 // US-CA has no REVOKE, US-NY has no GRANT
             ],
+            definitions: Vec::new(),
         };
 
         let matrix = ComplianceMatrix::from_document(&doc);
@@ -369,6 +373,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![create_test_statute("US-CA-law-1", "GRANT")],
+            definitions: Vec::new(),
         };
 
         let matrix = ComplianceMatrix::from_document(&doc);
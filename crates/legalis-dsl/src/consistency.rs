@@ -265,6 +265,9 @@ impl ConsistencyChecker {
     fn format_condition(&self, op: &str, val: &ConditionValue) -> String {
         match val {
             ConditionValue::Number(n) => format!("{} {}", op, n),
+            ConditionValue::Decimal(d) => format!("{} {}", op, d),
+            ConditionValue::Money { amount, currency } => format!("{} {} {}", op, amount, currency),
+            ConditionValue::Duration { value, unit } => format!("{} {} {}", op, value, unit),
             ConditionValue::String(s) => format!("{} \"{}\"", op, s),
             ConditionValue::Boolean(b) => format!("{} {}", op, b),
             ConditionValue::Date(d) => format!("{} {}", op, d),
@@ -418,6 +421,7 @@ mod tests {
                 constraints: vec![],
                 priority: None,
             }],
+            definitions: Vec::new(),
         };
 
         let mut checker = ConsistencyChecker::new();
@@ -448,6 +452,7 @@ mod tests {
                     ..Default::default()
                 },
             ],
+            definitions: Vec::new(),
         };
 
         let mut checker = ConsistencyChecker::new();
@@ -488,6 +493,7 @@ mod tests {
                     ..Default::default()
                 },
             ],
+            definitions: Vec::new(),
         };
 
         let mut checker = ConsistencyChecker::new();
@@ -530,6 +536,7 @@ mod tests {
                     ..Default::default()
                 },
             ],
+            definitions: Vec::new(),
         };
 
         let mut checker = ConsistencyChecker::new();
@@ -570,6 +577,7 @@ mod tests {
                     ..Default::default()
                 },
             ],
+            definitions: Vec::new(),
         };
 
         let mut checker = ConsistencyChecker::new();
@@ -34,9 +34,20 @@ fn collect_reads(condition: &ConditionNode, reads: &mut HashSet<String>) {
         ConditionNode::HasAttribute { key } => {
             reads.insert(key.clone());
         }
+        ConditionNode::Expression { left, right, .. } => {
+            for field in left.fields().into_iter().chain(right.fields()) {
+                reads.insert(field.to_string());
+            }
+        }
         ConditionNode::TemporalComparison { field, .. } => {
             reads.insert(format!("{:?}", field));
         }
+        ConditionNode::StatuteOutput {
+            statute_id, output, ..
+        } => {
+            reads.insert(format!("{}.{}", statute_id, output));
+        }
+        ConditionNode::StatuteApplies { .. } => {}
         ConditionNode::And(left, right) | ConditionNode::Or(left, right) => {
             collect_reads(left, reads);
             collect_reads(right, reads);
@@ -108,6 +119,13 @@ impl DataFlowAnalyzer {
                 state.dependencies.insert(req.clone());
             }
 
+            // Collect statutes referenced from conditions
+            for condition in &statute.conditions {
+                for referenced in condition.referenced_statutes() {
+                    state.dependencies.insert(referenced.to_string());
+                }
+            }
+
             // Collect delegation dependencies
             for delegate in &statute.delegates {
                 state.dependencies.insert(delegate.target_id.clone());
@@ -289,6 +307,7 @@ mod tests {
                     ..Default::default()
                 },
             ],
+            definitions: Vec::new(),
         };
 
         let mut analyzer = DataFlowAnalyzer::new();
@@ -336,6 +355,7 @@ mod tests {
                     ..Default::default()
                 },
             ],
+            definitions: Vec::new(),
         };
 
         let mut analyzer = DataFlowAnalyzer::new();
@@ -384,6 +404,7 @@ mod tests {
                     ..Default::default()
                 },
             ],
+            definitions: Vec::new(),
         };
 
         let mut analyzer = DataFlowAnalyzer::new();
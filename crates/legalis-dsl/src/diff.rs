@@ -367,6 +367,7 @@ mod tests {
             exports: vec![],
             imports: Vec::new(),
             statutes: vec![create_test_statute("stat1", "Statute 1")],
+            definitions: Vec::new(),
         };
 
         let doc2 = LegalDocument {
@@ -377,6 +378,7 @@ mod tests {
                 create_test_statute("stat1", "Statute 1"),
                 create_test_statute("stat2", "Statute 2"),
             ],
+            definitions: Vec::new(),
         };
 
         let diff = DocumentDiff::compute(&doc1, &doc2);
@@ -395,6 +397,7 @@ mod tests {
                 create_test_statute("stat1", "Statute 1"),
                 create_test_statute("stat2", "Statute 2"),
             ],
+            definitions: Vec::new(),
         };
 
         let doc2 = LegalDocument {
@@ -402,6 +405,7 @@ mod tests {
             exports: vec![],
             imports: Vec::new(),
             statutes: vec![create_test_statute("stat1", "Statute 1")],
+            definitions: Vec::new(),
         };
 
         let diff = DocumentDiff::compute(&doc1, &doc2);
@@ -417,6 +421,7 @@ mod tests {
             exports: vec![],
             imports: Vec::new(),
             statutes: vec![create_test_statute("stat1", "Old Title")],
+            definitions: Vec::new(),
         };
 
         let doc2 = LegalDocument {
@@ -424,6 +429,7 @@ mod tests {
             exports: vec![],
             imports: Vec::new(),
             statutes: vec![create_test_statute("stat1", "New Title")],
+            definitions: Vec::new(),
         };
 
         let diff = DocumentDiff::compute(&doc1, &doc2);
@@ -441,6 +447,7 @@ mod tests {
             exports: vec![],
             imports: Vec::new(),
             statutes: vec![create_test_statute("stat1", "Title")],
+            definitions: Vec::new(),
         };
 
         let doc2 = LegalDocument {
@@ -448,6 +455,7 @@ mod tests {
             exports: vec![],
             imports: Vec::new(),
             statutes: vec![create_test_statute("stat1", "Title")],
+            definitions: Vec::new(),
         };
 
         let diff = DocumentDiff::compute(&doc1, &doc2);
@@ -458,6 +466,7 @@ mod tests {
             exports: vec![],
             imports: Vec::new(),
             statutes: vec![create_test_statute("stat1", "New Title")],
+            definitions: Vec::new(),
         };
 
         let diff2 = DocumentDiff::compute(&doc1, &doc3);
@@ -471,6 +480,7 @@ mod tests {
             exports: vec![],
             imports: Vec::new(),
             statutes: vec![create_test_statute("stat1", "Old")],
+            definitions: Vec::new(),
         };
 
         let doc2 = LegalDocument {
@@ -481,6 +491,7 @@ mod tests {
                 create_test_statute("stat1", "New"),
                 create_test_statute("stat2", "Added"),
             ],
+            definitions: Vec::new(),
         };
 
         let diff = DocumentDiff::compute(&doc1, &doc2);
@@ -186,9 +186,31 @@ impl MarkdownGenerator {
             } => {
                 format!("`{}` {} {}", field, operator, self.format_value(value))
             }
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => format!("`{}` {} `{}`", left, operator, right),
             ConditionNode::HasAttribute { key } => {
                 format!("Has attribute `{}`", key)
             }
+            ConditionNode::StatuteApplies { statute_id } => {
+                format!("Statute `{}` applies", statute_id)
+            }
+            ConditionNode::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+            } => {
+                format!(
+                    "`{}.{}` {} {}",
+                    statute_id,
+                    output,
+                    operator,
+                    self.format_value(value)
+                )
+            }
             ConditionNode::Between { field, min, max } => {
                 format!(
                     "`{}` BETWEEN {} AND {}",
@@ -269,6 +291,9 @@ impl MarkdownGenerator {
     fn format_value(&self, value: &ConditionValue) -> String {
         match value {
             ConditionValue::Number(n) => n.to_string(),
+            ConditionValue::Decimal(d) => d.to_string(),
+            ConditionValue::Money { amount, currency } => format!("{} {}", amount, currency),
+            ConditionValue::Duration { value, unit } => format!("{} {}", value, unit),
             ConditionValue::String(s) => format!("\"{}\"", s),
             ConditionValue::Boolean(b) => b.to_string(),
             ConditionValue::Date(d) => format!("**{}**", d),
@@ -656,9 +681,39 @@ impl LaTeXGenerator {
                     self.format_value(value)
                 )
             }
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => format!(
+                "\\texttt{{{}}} {} \\texttt{{{}}}",
+                Self::escape_latex(&left.to_string()),
+                Self::escape_latex(operator),
+                Self::escape_latex(&right.to_string())
+            ),
             ConditionNode::HasAttribute { key } => {
                 format!("Has attribute \\texttt{{{}}}", Self::escape_latex(key))
             }
+            ConditionNode::StatuteApplies { statute_id } => {
+                format!(
+                    "Statute \\texttt{{{}}} applies",
+                    Self::escape_latex(statute_id)
+                )
+            }
+            ConditionNode::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+            } => {
+                format!(
+                    "\\texttt{{{}.{}}} {} {}",
+                    Self::escape_latex(statute_id),
+                    Self::escape_latex(output),
+                    Self::escape_latex(operator),
+                    self.format_value(value)
+                )
+            }
             ConditionNode::Between { field, min, max } => {
                 format!(
                     "\\texttt{{{}}} BETWEEN {} AND {}",
@@ -756,6 +811,9 @@ impl LaTeXGenerator {
     fn format_value(&self, value: &ConditionValue) -> String {
         match value {
             ConditionValue::Number(n) => n.to_string(),
+            ConditionValue::Decimal(d) => d.to_string(),
+            ConditionValue::Money { amount, currency } => format!("{} {}", amount, currency),
+            ConditionValue::Duration { value, unit } => format!("{} {}", value, unit),
             ConditionValue::String(s) => format!("\"{}\"", Self::escape_latex(s)),
             ConditionValue::Boolean(b) => b.to_string(),
             ConditionValue::Date(d) => format!("\\textbf{{{}}}", Self::escape_latex(d)),
@@ -873,6 +931,7 @@ mod tests {
                 constraints: vec![],
                 priority: None,
             }],
+            definitions: Vec::new(),
         }
     }
 
@@ -321,6 +321,8 @@ pub fn legalis_grammar() -> GrammarSpec {
         "DEFAULT",
         "IMPORT",
         "AS",
+        "LET",
+        "DEFINE",
         "JURISDICTION",
         "VERSION",
         "EFFECTIVE_DATE",
@@ -355,7 +357,8 @@ pub fn legalis_grammar() -> GrammarSpec {
 
     // Add operators
     for operator in &[
-        ">=", "<=", ">", "<", "==", "=", "!=", ":", ",", "{", "}", "(", ")", ".", "..",
+        ">=", "<=", ">", "<", "==", "=", "!=", ":", ",", "{", "}", "(", ")", ".", "..", "+", "-",
+        "*", "/",
     ] {
         spec.add_operator(*operator);
     }
@@ -459,12 +462,46 @@ pub fn legalis_grammar() -> GrammarSpec {
             .with_example(r#"DEFAULT status "pending""#),
     );
 
+    spec.add_rule(
+        GrammarRule::new(
+            "VALUE",
+            "[\"-\"] NUMBER [CURRENCY | DURATION_UNIT] | DATE | STRING | \"TRUE\" | \"FALSE\"",
+        )
+        .with_description(
+            "A literal: signed integer or exact decimal, money with an ISO 4217 currency code, \
+             a duration in days/weeks/months/years, or an ISO 8601 date (YYYY-MM-DD).",
+        )
+        .with_example("INCOME < 12500.50 AND fee = 300 JPY AND residency >= 6 months"),
+    );
+
     spec.add_rule(
         GrammarRule::new("IMPORT", "\"IMPORT\" STRING [\"AS\" IDENT]")
             .with_description("Imports definitions from another statute file with optional alias.")
             .with_example(r#"IMPORT "common-definitions.dsl" AS common"#),
     );
 
+    spec.add_rule(
+        GrammarRule::new("LET", "(\"LET\" | \"DEFINE\") IDENT (\"=\" | \":\") EXPR")
+            .with_description(
+                "Binds a name to an arithmetic expression at document level; \
+                 conditions may use the name like a field.",
+            )
+            .with_example("LET net_income = income - deductions"),
+    );
+
+    spec.add_rule(
+        GrammarRule::new(
+            "EXPR",
+            "TERM ((\"+\" | \"-\") TERM)*; TERM = FACTOR ((\"*\" | \"/\") FACTOR)*; \
+             FACTOR = [\"-\"] (VALUE | IDENT | \"(\" EXPR \")\")",
+        )
+        .with_description(
+            "Arithmetic over fields and literals. Either side of a comparison may be an \
+             expression; identifiers containing '-' require spaces around subtraction.",
+        )
+        .with_example("WHEN income - deductions > 12 * monthly_rent"),
+    );
+
     spec
 }
 
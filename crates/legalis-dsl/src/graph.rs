@@ -397,6 +397,7 @@ mod tests {
                     priority: None,
                 },
             ],
+            definitions: Vec::new(),
         }
     }
 
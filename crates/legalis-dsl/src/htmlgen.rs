@@ -466,6 +466,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![create_test_statute()],
+            definitions: Vec::new(),
         };
 
         let generator = HtmlGenerator::new();
@@ -492,6 +493,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![create_test_statute()],
+            definitions: Vec::new(),
         };
 
         let generator = HtmlGenerator::new().with_theme(HtmlTheme::Dark);
@@ -507,6 +509,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![create_test_statute()],
+            definitions: Vec::new(),
         };
 
         let generator = HtmlGenerator::new().with_toc(false);
@@ -234,6 +234,7 @@ mod tests {
                 alias: None,
             }],
             statutes: vec![],
+            definitions: Vec::new(),
         };
 
         let resolver = ImportResolver::new("/tmp");
@@ -253,6 +254,7 @@ mod tests {
                 alias: None,
             }],
             statutes: vec![],
+            definitions: Vec::new(),
         };
 
         // This test would require actual files to work properly
@@ -20,7 +20,8 @@
 //! OR_EXPR ::= AND_EXPR ("OR" AND_EXPR)*
 //! AND_EXPR ::= UNARY_EXPR ("AND" UNARY_EXPR)*
 //! UNARY_EXPR ::= "NOT" UNARY_EXPR | "(" CONDITION ")" | PRIMARY_COND
-//! PRIMARY_COND ::= FIELD_COND | "HAS" IDENT | IDENT
+//! PRIMARY_COND ::= FIELD_COND | "HAS" IDENT | "APPLIES" IDENT | OUTPUT_COND | IDENT
+//! OUTPUT_COND ::= IDENT "." IDENT COMPARISON_OP NUMBER
 //! FIELD_COND ::= FIELD (COMPARISON_OP VALUE | "BETWEEN" VALUE "AND" VALUE | "IN" VALUE_LIST | "LIKE" PATTERN)
 //! FIELD ::= "AGE" | "INCOME" | IDENT
 //! VALUE_LIST ::= "(" VALUE ("," VALUE)* ")" | VALUE ("," VALUE)*
@@ -86,6 +87,7 @@ pub mod htmlgen;
 pub mod import_resolver;
 pub mod incremental;
 pub mod interpolation;
+#[cfg(feature = "lsp")]
 pub mod lsp;
 pub mod macros;
 pub mod metadata;
@@ -109,6 +111,8 @@ pub mod tree_view;
 pub mod type_checker;
 pub mod validation;
 pub mod watch;
+#[cfg(feature = "lsp")]
+pub mod workspace;
 
 #[cfg(test)]
 mod tests;
@@ -150,7 +154,7 @@ pub use nl_to_dsl::{
     CommonTemplates, NLPattern, NLTranslator, TranslationResult, TranslatorBuilder,
 };
 pub use nlgen::{Language, NLConfig, NLGenerator, Verbosity};
-pub use numeric::{NumericError, NumericParser, NumericValue, parse_numeric};
+pub use numeric::{Decimal, NumericError, NumericParser, NumericValue, parse_numeric};
 pub use parser::*;
 pub use printer::*;
 pub use profiler::{ParseProfiler, ProfileComparison, ProfileReport, Profiler};
@@ -540,6 +544,55 @@ impl DslError {
     }
 }
 
+/// Returns true for an ISO 4217 style currency code such as `JPY` or `EUR`.
+fn is_currency_code(word: &str) -> bool {
+    word.len() == 3 && word.chars().all(|c| c.is_ascii_uppercase())
+}
+
+/// Returns true when the parenthesised group at the cursor is an arithmetic
+/// operand rather than a grouped condition, i.e. the closing paren is followed
+/// by an arithmetic or comparison operator: `(income + bonus) / 12 > 2000`.
+fn is_arithmetic_group<'a, I>(iter: &std::iter::Peekable<I>) -> bool
+where
+    I: Iterator<Item = &'a Token> + Clone,
+{
+    let mut lookahead = iter.clone();
+    let mut depth = 0usize;
+    while let Some(token) = lookahead.next() {
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    let next = lookahead.next();
+                    return is_arith_operator(next) || matches!(next, Some(Token::Operator(_)));
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Returns true for the arithmetic operator tokens `+ - * /`.
+fn is_arith_operator(token: Option<&Token>) -> bool {
+    matches!(
+        token,
+        Some(Token::Plus) | Some(Token::Dash) | Some(Token::Star) | Some(Token::Slash)
+    )
+}
+
+/// Maps a duration unit word (`day`, `weeks`, `MONTHS`, ...) to its unit.
+fn parse_duration_unit(word: &str) -> Option<legalis_core::DurationUnit> {
+    match word.to_lowercase().as_str() {
+        "day" | "days" => Some(legalis_core::DurationUnit::Days),
+        "week" | "weeks" => Some(legalis_core::DurationUnit::Weeks),
+        "month" | "months" => Some(legalis_core::DurationUnit::Months),
+        "year" | "years" => Some(legalis_core::DurationUnit::Years),
+        _ => None,
+    }
+}
+
 /// Calculates the Levenshtein distance between two strings.
 /// Used for "did you mean?" suggestions.
 fn levenshtein_distance(a: &str, b: &str) -> usize {
@@ -789,14 +842,19 @@ impl LegalDslParser {
             exports.push(self.parse_export(&mut iter)?);
         }
 
-        // Parse statutes
+        // Parse statutes and LET definitions
         let mut statutes = Vec::new();
+        let mut definitions = Vec::new();
         while iter.peek().is_some() {
             // Skip until we find a STATUTE keyword
             while let Some(token) = iter.peek() {
                 if matches!(token, Token::Statute) {
                     break;
                 }
+                if matches!(token, Token::Let) {
+                    definitions.push(self.parse_definition(&mut iter)?);
+                    continue;
+                }
                 iter.next();
             }
 
@@ -843,6 +901,7 @@ impl LegalDslParser {
             imports,
             exports,
             statutes,
+            definitions,
         })
     }
 
@@ -873,14 +932,22 @@ impl LegalDslParser {
             }
         }
 
-        // Parse statutes with error recovery
+        // Parse statutes and LET definitions with error recovery
         let mut statutes = Vec::new();
+        let mut definitions = Vec::new();
         while iter.peek().is_some() {
             // Skip until we find a STATUTE keyword
             while let Some(token) = iter.peek() {
                 if matches!(token, Token::Statute) {
                     break;
                 }
+                if matches!(token, Token::Let) {
+                    match self.parse_definition(&mut iter) {
+                        Ok(definition) => definitions.push(definition),
+                        Err(e) => errors.push(e),
+                    }
+                    continue;
+                }
                 iter.next();
             }
 
@@ -932,6 +999,7 @@ impl LegalDslParser {
             imports,
             exports: vec![],
             statutes,
+            definitions,
         };
 
         if errors.is_empty() {
@@ -945,7 +1013,7 @@ impl LegalDslParser {
     /// Synchronization points are: IMPORT, STATUTE, or EOF.
     fn skip_to_sync_point<'a, I>(&self, iter: &mut std::iter::Peekable<I>)
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         while let Some(token) = iter.peek() {
             if matches!(token, Token::Import | Token::Statute) {
@@ -962,7 +1030,7 @@ impl LegalDslParser {
     /// - Selective: IMPORT { item1, item2 } FROM path
     fn parse_import<'a, I>(&self, iter: &mut std::iter::Peekable<I>) -> DslResult<ast::ImportNode>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         // Expect IMPORT
         match iter.next() {
@@ -1058,7 +1126,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<crate::module_system::NamespaceNode>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         // Expect NAMESPACE
         match iter.next() {
@@ -1086,7 +1154,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<crate::module_system::ExportNode>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         // Expect EXPORT
         match iter.next() {
@@ -1309,7 +1377,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<Option<ast::ConditionNode>>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         self.parse_or_condition_node(iter)
     }
@@ -1319,7 +1387,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<Option<ast::ConditionNode>>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         let left = self.parse_and_condition_node(iter)?;
         if left.is_none() {
@@ -1343,7 +1411,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<Option<ast::ConditionNode>>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         let left = self.parse_unary_condition_node(iter)?;
         if left.is_none() {
@@ -1367,14 +1435,18 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<Option<ast::ConditionNode>>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
-        match iter.peek() {
+        match iter.peek().copied() {
             Some(Token::Not) => {
                 iter.next();
                 let inner = self.parse_unary_condition_node(iter)?;
                 Ok(inner.map(|c| ast::ConditionNode::Not(Box::new(c))))
             }
+            Some(Token::LParen) if is_arithmetic_group(iter) => {
+                let left = self.parse_arith_expr(iter)?;
+                self.parse_expression_condition(iter, left)
+            }
             Some(Token::LParen) => {
                 iter.next();
                 let inner = self.parse_or_condition_node(iter)?;
@@ -1395,7 +1467,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<Option<ast::ConditionNode>>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         match iter.peek().cloned() {
             Some(Token::Age) => {
@@ -1406,6 +1478,11 @@ impl LegalDslParser {
                 iter.next();
                 self.parse_field_condition(iter, "income")
             }
+            Some(Token::Number(_)) | Some(Token::Decimal(_)) | Some(Token::Dash) => {
+                // Expression starting with a literal, e.g. "12 * monthly_rent > income"
+                let left = self.parse_arith_expr(iter)?;
+                self.parse_expression_condition(iter, left)
+            }
             Some(Token::CurrentDate) => {
                 iter.next();
                 self.parse_temporal_condition(iter, ast::TemporalField::CurrentDate)
@@ -1440,6 +1517,19 @@ impl LegalDslParser {
                     ))
                 }
             }
+            Some(Token::Applies) => {
+                iter.next();
+                match iter.next() {
+                    Some(Token::Ident(statute_id)) | Some(Token::StringLit(statute_id)) => {
+                        Ok(Some(ast::ConditionNode::StatuteApplies {
+                            statute_id: statute_id.clone(),
+                        }))
+                    }
+                    _ => Err(DslError::InvalidCondition(
+                        "Expected statute ID after APPLIES".to_string(),
+                    )),
+                }
+            }
             Some(Token::Ident(name)) => {
                 let name = name.clone();
                 iter.next();
@@ -1447,6 +1537,17 @@ impl LegalDslParser {
                 if matches!(iter.peek(), Some(Token::Dot)) {
                     iter.next(); // consume dot
                     if let Some(Token::Ident(member)) = iter.next() {
+                        if matches!(iter.peek(), Some(Token::Operator(_))) {
+                            // Comparison on a statute output like "child-benefit.amount > 0"
+                            let op = self.parse_comparison_op(iter)?;
+                            let value = self.parse_condition_value(iter)?;
+                            return Ok(Some(ast::ConditionNode::StatuteOutput {
+                                statute_id: name,
+                                output: member.clone(),
+                                operator: op.to_string(),
+                                value,
+                            }));
+                        }
                         // This is a qualified reference like "other.adult_rights"
                         Ok(Some(ast::ConditionNode::HasAttribute {
                             key: format!("{}.{}", name, member),
@@ -1454,6 +1555,10 @@ impl LegalDslParser {
                     } else {
                         Err(DslError::parse_error("Expected identifier after '.'"))
                     }
+                } else if is_arith_operator(iter.peek().copied()) {
+                    // Arithmetic on the left, e.g. "income - deductions > 30000"
+                    let left = self.continue_arith_expr(iter, ast::ExprNode::Field(name))?;
+                    self.parse_expression_condition(iter, left)
                 } else if matches!(
                     iter.peek(),
                     Some(Token::Operator(_))
@@ -1482,7 +1587,7 @@ impl LegalDslParser {
         field: ast::TemporalField,
     ) -> DslResult<Option<ast::ConditionNode>>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         let op = self.parse_comparison_op(iter)?;
         let value = self.parse_condition_value(iter)?;
@@ -1502,7 +1607,7 @@ impl LegalDslParser {
         negated: bool,
     ) -> DslResult<Option<ast::ConditionNode>>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         // Check for opening bracket/paren to determine inclusivity
         let mut inclusive_min = true;
@@ -1578,8 +1683,12 @@ impl LegalDslParser {
         field: &str,
     ) -> DslResult<Option<ast::ConditionNode>>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
+        if is_arith_operator(iter.peek().copied()) {
+            let left = self.continue_arith_expr(iter, ast::ExprNode::Field(field.to_string()))?;
+            return self.parse_expression_condition(iter, left);
+        }
         match iter.peek() {
             Some(Token::Between) => {
                 iter.next(); // consume BETWEEN
@@ -1697,12 +1806,18 @@ impl LegalDslParser {
             }
             Some(Token::Operator(_)) => {
                 let op = self.parse_comparison_op(iter)?;
-                let value = self.parse_condition_value(iter)?;
-                Ok(Some(ast::ConditionNode::Comparison {
-                    field: field.to_string(),
-                    operator: op.to_string(),
-                    value,
-                }))
+                match self.parse_expression_operand(iter, false)? {
+                    ast::ExprNode::Literal(value) => Ok(Some(ast::ConditionNode::Comparison {
+                        field: field.to_string(),
+                        operator: op.to_string(),
+                        value,
+                    })),
+                    right => Ok(Some(ast::ConditionNode::Expression {
+                        left: ast::ExprNode::Field(field.to_string()),
+                        operator: op.to_string(),
+                        right,
+                    })),
+                }
             }
             _ => Err(DslError::InvalidCondition(format!(
                 "Expected comparison operator, BETWEEN, IN, or LIKE after {}",
@@ -1711,17 +1826,20 @@ impl LegalDslParser {
         }
     }
 
-    /// Parses a condition value (number, string, date, or boolean).
+    /// Parses a condition value (number, decimal, money, duration, string, date, or boolean).
     fn parse_condition_value<'a, I>(
         &self,
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<ast::ConditionValue>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         match iter.peek() {
-            Some(Token::Number(n)) => {
-                let val = ast::ConditionValue::Number(*n as i64);
+            Some(Token::Number(_)) | Some(Token::Decimal(_)) | Some(Token::Dash) => {
+                self.parse_numeric_value(iter)
+            }
+            Some(Token::Date(d)) => {
+                let val = ast::ConditionValue::Date(d.clone());
                 iter.next();
                 Ok(val)
             }
@@ -1756,6 +1874,261 @@ impl LegalDslParser {
         }
     }
 
+    /// Parses a signed number or decimal and its optional unit suffix.
+    ///
+    /// A three-letter upper-case currency code makes it money (`300 JPY`),
+    /// a time unit makes it a duration (`6 months`).
+    fn parse_numeric_value<'a, I>(
+        &self,
+        iter: &mut std::iter::Peekable<I>,
+    ) -> DslResult<ast::ConditionValue>
+    where
+        I: Iterator<Item = &'a Token> + Clone,
+    {
+        let negative = matches!(iter.peek(), Some(Token::Dash));
+        if negative {
+            iter.next();
+        }
+        let amount = match iter.next() {
+            Some(Token::Number(n)) => {
+                let n = i64::try_from(*n).map_err(|_| {
+                    DslError::InvalidCondition(format!("Number out of range: {}", n))
+                })?;
+                Decimal::from(n)
+            }
+            Some(Token::Decimal(d)) => *d,
+            _ => {
+                return Err(DslError::InvalidCondition(
+                    "Expected number after '-'".to_string(),
+                ));
+            }
+        };
+        let amount = if negative { -amount } else { amount };
+
+        if let Some(Token::Ident(suffix)) = iter.peek() {
+            if is_currency_code(suffix) {
+                let currency = suffix.clone();
+                iter.next();
+                return Ok(ast::ConditionValue::Money { amount, currency });
+            }
+            if let Some(unit) = parse_duration_unit(suffix) {
+                let value = amount.to_i64().ok_or_else(|| {
+                    DslError::InvalidCondition(format!(
+                        "Duration must be a whole number of {}, found {}",
+                        unit, amount
+                    ))
+                })?;
+                iter.next();
+                return Ok(ast::ConditionValue::Duration { value, unit });
+            }
+        }
+
+        Ok(if amount.scale() == 0 {
+            // Whole-number literals stay integers so existing rules are unchanged
+            amount.to_i64().map_or(
+                ast::ConditionValue::Decimal(amount),
+                ast::ConditionValue::Number,
+            )
+        } else {
+            ast::ConditionValue::Decimal(amount)
+        })
+    }
+
+    /// Parses `OP right` after an arithmetic left-hand side.
+    fn parse_expression_condition<'a, I>(
+        &self,
+        iter: &mut std::iter::Peekable<I>,
+        left: ast::ExprNode,
+    ) -> DslResult<Option<ast::ConditionNode>>
+    where
+        I: Iterator<Item = &'a Token> + Clone,
+    {
+        let op = self.parse_comparison_op(iter)?;
+        let right = self.parse_expression_operand(iter, true)?;
+        Ok(Some(ast::ConditionNode::Expression {
+            left,
+            operator: op.to_string(),
+            right,
+        }))
+    }
+
+    /// Parses the right-hand side of a comparison.
+    ///
+    /// A value followed by an arithmetic operator, or a parenthesised
+    /// expression, is parsed as an expression. A lone bare identifier is a
+    /// field when `bare_as_field` is set and a string otherwise, as in plain
+    /// comparisons like `status = active`.
+    fn parse_expression_operand<'a, I>(
+        &self,
+        iter: &mut std::iter::Peekable<I>,
+        bare_as_field: bool,
+    ) -> DslResult<ast::ExprNode>
+    where
+        I: Iterator<Item = &'a Token> + Clone,
+    {
+        if matches!(iter.peek(), Some(Token::LParen)) {
+            return self.parse_arith_expr(iter);
+        }
+        let is_identifier = matches!(iter.peek(), Some(Token::Ident(_)));
+        let value = self.parse_condition_value(iter)?;
+        let continues = is_arith_operator(iter.peek().copied());
+        let first = match value {
+            ast::ConditionValue::String(name) if is_identifier && (continues || bare_as_field) => {
+                ast::ExprNode::Field(name)
+            }
+            other => ast::ExprNode::Literal(other),
+        };
+        if continues {
+            self.continue_arith_expr(iter, first)
+        } else {
+            Ok(first)
+        }
+    }
+
+    /// Parses an arithmetic expression: terms joined by `+` and `-`.
+    fn parse_arith_expr<'a, I>(&self, iter: &mut std::iter::Peekable<I>) -> DslResult<ast::ExprNode>
+    where
+        I: Iterator<Item = &'a Token> + Clone,
+    {
+        let first = self.parse_arith_factor(iter)?;
+        self.continue_arith_expr(iter, first)
+    }
+
+    /// Continues an arithmetic expression whose first operand is already parsed.
+    fn continue_arith_expr<'a, I>(
+        &self,
+        iter: &mut std::iter::Peekable<I>,
+        first: ast::ExprNode,
+    ) -> DslResult<ast::ExprNode>
+    where
+        I: Iterator<Item = &'a Token> + Clone,
+    {
+        let mut result = self.continue_arith_term(iter, first)?;
+        loop {
+            let op = match iter.peek() {
+                Some(Token::Plus) => ast::ArithOp::Add,
+                Some(Token::Dash) => ast::ArithOp::Sub,
+                _ => break,
+            };
+            iter.next();
+            let factor = self.parse_arith_factor(iter)?;
+            let term = self.continue_arith_term(iter, factor)?;
+            result = ast::ExprNode::binary(op, result, term);
+        }
+        Ok(result)
+    }
+
+    /// Continues a term: factors joined by `*` and `/`.
+    fn continue_arith_term<'a, I>(
+        &self,
+        iter: &mut std::iter::Peekable<I>,
+        first: ast::ExprNode,
+    ) -> DslResult<ast::ExprNode>
+    where
+        I: Iterator<Item = &'a Token> + Clone,
+    {
+        let mut result = first;
+        loop {
+            let op = match iter.peek() {
+                Some(Token::Star) => ast::ArithOp::Mul,
+                Some(Token::Slash) => ast::ArithOp::Div,
+                _ => break,
+            };
+            iter.next();
+            let factor = self.parse_arith_factor(iter)?;
+            result = ast::ExprNode::binary(op, result, factor);
+        }
+        Ok(result)
+    }
+
+    /// Parses a factor: a literal, a field, a negation or a parenthesised expression.
+    fn parse_arith_factor<'a, I>(
+        &self,
+        iter: &mut std::iter::Peekable<I>,
+    ) -> DslResult<ast::ExprNode>
+    where
+        I: Iterator<Item = &'a Token> + Clone,
+    {
+        match iter.peek() {
+            Some(Token::Number(_)) | Some(Token::Decimal(_)) => {
+                Ok(ast::ExprNode::Literal(self.parse_numeric_value(iter)?))
+            }
+            Some(Token::Dash) => {
+                iter.next();
+                Ok(match self.parse_arith_factor(iter)? {
+                    ast::ExprNode::Literal(value) => {
+                        ast::ExprNode::Literal(value.negated().ok_or_else(|| {
+                            DslError::InvalidCondition(format!("Cannot negate {:?}", value))
+                        })?)
+                    }
+                    inner => ast::ExprNode::Negate(Box::new(inner)),
+                })
+            }
+            Some(Token::Date(d)) => {
+                let value = ast::ConditionValue::Date(d.clone());
+                iter.next();
+                Ok(ast::ExprNode::Literal(value))
+            }
+            Some(Token::StringLit(s)) => {
+                // Accepted here so the type checker can report it
+                let value = ast::ConditionValue::String(s.clone());
+                iter.next();
+                Ok(ast::ExprNode::Literal(value))
+            }
+            Some(Token::Ident(name)) => {
+                let name = name.clone();
+                iter.next();
+                Ok(ast::ExprNode::Field(name))
+            }
+            Some(Token::Age) => {
+                iter.next();
+                Ok(ast::ExprNode::Field("age".to_string()))
+            }
+            Some(Token::Income) => {
+                iter.next();
+                Ok(ast::ExprNode::Field("income".to_string()))
+            }
+            Some(Token::LParen) => {
+                iter.next();
+                let inner = self.parse_arith_expr(iter)?;
+                match iter.next() {
+                    Some(Token::RParen) => Ok(inner),
+                    _ => Err(DslError::UnmatchedParen(None)),
+                }
+            }
+            _ => Err(DslError::InvalidCondition(
+                "Expected number, field or '(' in arithmetic expression".to_string(),
+            )),
+        }
+    }
+
+    /// Parses a `LET name = expression` (or `DEFINE`) declaration.
+    fn parse_definition<'a, I>(
+        &self,
+        iter: &mut std::iter::Peekable<I>,
+    ) -> DslResult<ast::DefinitionNode>
+    where
+        I: Iterator<Item = &'a Token> + Clone,
+    {
+        iter.next(); // consume LET
+        let name = match iter.next() {
+            Some(Token::Ident(name)) => name.clone(),
+            _ => return Err(DslError::parse_error("Expected name after LET")),
+        };
+        match iter.next() {
+            Some(Token::Operator(op)) if op == "=" => {}
+            Some(Token::Colon) => {}
+            _ => {
+                return Err(DslError::parse_error(format!(
+                    "Expected '=' after LET {}",
+                    name
+                )));
+            }
+        }
+        let expr = self.parse_arith_expr(iter)?;
+        Ok(ast::DefinitionNode { name, expr })
+    }
+
     /// Parses a set expression for set operations.
     /// Supports UNION, INTERSECT, and DIFFERENCE operations.
     /// Example: (1, 2, 3) UNION (4, 5, 6)
@@ -1765,7 +2138,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<ast::SetExpression>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         // Parse the initial set (values in parentheses or a single value list)
         let left = self.parse_simple_set(iter)?;
@@ -1804,7 +2177,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<ast::SetExpression>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         let mut values = Vec::new();
 
@@ -1857,7 +2230,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<ast::EffectNode>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         let effect_type = match iter.next() {
             Some(Token::Grant) => "grant".to_string(),
@@ -1887,7 +2260,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<ast::ExceptionNode>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         // Parse optional conditions
         let mut conditions = Vec::new();
@@ -1917,7 +2290,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<ast::DefaultNode>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         // Get field name
         let field = match iter.next() {
@@ -1939,8 +2312,11 @@ impl LegalDslParser {
 
         // Get value
         let value = match iter.peek() {
-            Some(Token::Number(n)) => {
-                let val = ast::ConditionValue::Number(*n as i64);
+            Some(Token::Number(_)) | Some(Token::Decimal(_)) | Some(Token::Dash) => {
+                self.parse_numeric_value(iter)?
+            }
+            Some(Token::Date(d)) => {
+                let val = ast::ConditionValue::Date(d.clone());
                 iter.next();
                 val
             }
@@ -1973,7 +2349,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<ast::DelegateNode>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         // Get target statute ID
         let target_id = match iter.next() {
@@ -2011,7 +2387,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<ast::ScopeNode>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         // Parse entity types (comma-separated list)
         let mut entity_types = Vec::new();
@@ -2065,7 +2441,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<ast::ConstraintNode>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         // Get constraint name
         let name = match iter.next() {
@@ -2113,7 +2489,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<ast::AmendmentNode>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         // Get target statute ID
         let target_id = match iter.next() {
@@ -2142,6 +2518,12 @@ impl LegalDslParser {
                     let mut found_string = false;
                     while let Some(token) = iter.peek() {
                         match token {
+                            Token::Date(d) if date_parts.is_empty() => {
+                                date = Some(d.clone());
+                                found_string = true;
+                                iter.next();
+                                break;
+                            }
                             Token::Number(n) => {
                                 date_parts.push(n.to_string());
                                 iter.next();
@@ -2402,6 +2784,7 @@ impl LegalDslParser {
                         "PRIORITY" => Token::Priority,
                         "SCOPE" => Token::Scope,
                         "CONSTRAINT" | "CONSTRAINTS" | "INVARIANT" => Token::Constraint,
+                        "LET" | "DEFINE" => Token::Let,
                         // Module system keywords (v0.1.4)
                         "NAMESPACE" => Token::Namespace,
                         "FROM" => Token::From,
@@ -2412,6 +2795,7 @@ impl LegalDslParser {
                         "OR" => Token::Or,
                         "NOT" => Token::Not,
                         "HAS" => Token::Has,
+                        "APPLIES" => Token::Applies,
                         "BETWEEN" => Token::Between,
                         "IN" => Token::In,
                         "LIKE" => Token::Like,
@@ -2445,6 +2829,65 @@ impl LegalDslParser {
                             break;
                         }
                     }
+
+                    // ISO date literal: YYYY-MM-DD
+                    if num.len() == 4 {
+                        let rest: String = chars.clone().take(7).collect();
+                        let shape = rest.as_bytes();
+                        if shape.len() >= 6
+                            && shape[0] == b'-'
+                            && shape[3] == b'-'
+                            && [1, 2, 4, 5].iter().all(|&i| shape[i].is_ascii_digit())
+                            && shape.get(6).is_none_or(|c| !c.is_ascii_alphanumeric())
+                        {
+                            let literal = format!("{}{}", num, &rest[..6]);
+                            if NaiveDate::parse_from_str(&literal, "%Y-%m-%d").is_err() {
+                                return Err(DslError::parse_error_at(
+                                    token_start.line,
+                                    token_start.column,
+                                    format!("Invalid date literal '{}'", literal),
+                                ));
+                            }
+                            for _ in 0..6 {
+                                chars.next();
+                            }
+                            offset += 6;
+                            column += 6;
+                            tokens.push(SpannedToken::new(Token::Date(literal), token_start));
+                            continue;
+                        }
+                    }
+
+                    // Decimal literal: digits '.' digits (but not the range in `1..10`)
+                    let mut lookahead = chars.clone();
+                    if lookahead.next() == Some('.')
+                        && lookahead.peek().is_some_and(|c| c.is_ascii_digit())
+                    {
+                        num.push('.');
+                        chars.next();
+                        offset += 1;
+                        column += 1;
+                        while let Some(&c) = chars.peek() {
+                            if c.is_ascii_digit() {
+                                num.push(c);
+                                chars.next();
+                                offset += 1;
+                                column += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                        let value = num.parse().map_err(|e| {
+                            DslError::parse_error_at(
+                                token_start.line,
+                                token_start.column,
+                                format!("{}", e),
+                            )
+                        })?;
+                        tokens.push(SpannedToken::new(Token::Decimal(value), token_start));
+                        continue;
+                    }
+
                     tokens.push(SpannedToken::new(
                         Token::Number(num.parse().unwrap_or(0)),
                         token_start,
@@ -2484,6 +2927,18 @@ impl LegalDslParser {
                     offset += 1;
                     column += 1;
                 }
+                '+' => {
+                    tokens.push(SpannedToken::new(Token::Plus, token_start));
+                    chars.next();
+                    offset += 1;
+                    column += 1;
+                }
+                '/' => {
+                    tokens.push(SpannedToken::new(Token::Slash, token_start));
+                    chars.next();
+                    offset += 1;
+                    column += 1;
+                }
                 _ => {
                     chars.next();
                     offset += 1;
@@ -2621,7 +3076,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<Option<Condition>>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         self.parse_or_condition(iter)
     }
@@ -2632,7 +3087,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<Option<Condition>>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         let left = self.parse_and_condition(iter)?;
         if left.is_none() {
@@ -2657,7 +3112,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<Option<Condition>>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         let left = self.parse_unary_condition(iter)?;
         if left.is_none() {
@@ -2682,14 +3137,20 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<Option<Condition>>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
-        match iter.peek() {
+        match iter.peek().copied() {
             Some(Token::Not) => {
                 iter.next(); // consume NOT
                 let inner = self.parse_unary_condition(iter)?;
                 Ok(inner.map(|c| Condition::Not(Box::new(c))))
             }
+            Some(Token::LParen) if is_arithmetic_group(iter) => {
+                let left = self.parse_arith_expr(iter)?;
+                self.parse_expression_condition(iter, left)?
+                    .map(|node| node.to_core())
+                    .transpose()
+            }
             Some(Token::LParen) => {
                 iter.next(); // consume (
                 let inner = self.parse_or_condition(iter)?;
@@ -2712,26 +3173,26 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<Option<Condition>>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         match iter.peek().cloned() {
-            Some(Token::Age) => {
-                iter.next();
-                let op = self.parse_comparison_op(iter)?;
-                let value = self.parse_number(iter)?;
-                Ok(Some(Condition::Age {
-                    operator: op,
-                    value: value as u32,
-                }))
-            }
-            Some(Token::Income) => {
-                iter.next();
+            Some(Token::Age) | Some(Token::Income) => {
+                let field = if matches!(iter.next(), Some(Token::Age)) {
+                    "age"
+                } else {
+                    "income"
+                };
+                if is_arith_operator(iter.peek().copied()) {
+                    let left =
+                        self.continue_arith_expr(iter, ast::ExprNode::Field(field.to_string()))?;
+                    return self
+                        .parse_expression_condition(iter, left)?
+                        .map(|node| node.to_core())
+                        .transpose();
+                }
                 let op = self.parse_comparison_op(iter)?;
-                let value = self.parse_number(iter)?;
-                Ok(Some(Condition::Income {
-                    operator: op,
-                    value,
-                }))
+                let value = self.parse_quantity(iter)?;
+                Ok(Some(parser::comparison_to_core(field, op, &value)?))
             }
             Some(Token::Has) => {
                 iter.next();
@@ -2748,8 +3209,35 @@ impl LegalDslParser {
                     Ok(None)
                 }
             }
+            Some(Token::Applies) => {
+                iter.next();
+                match iter.next() {
+                    Some(Token::Ident(statute_id)) | Some(Token::StringLit(statute_id)) => {
+                        Ok(Some(Condition::statute_applies(statute_id)))
+                    }
+                    _ => Err(DslError::InvalidCondition(
+                        "Expected statute ID after APPLIES".to_string(),
+                    )),
+                }
+            }
             Some(Token::Ident(key)) => {
                 iter.next();
+                if matches!(iter.peek(), Some(Token::Dot)) {
+                    iter.next();
+                    let Some(Token::Ident(output)) = iter.next() else {
+                        return Err(DslError::parse_error("Expected identifier after '.'"));
+                    };
+                    let op = self.parse_comparison_op(iter)?;
+                    let value = self.parse_quantity(iter)?;
+                    return parser::statute_output_to_core(key, output, op, &value).map(Some);
+                }
+                if is_arith_operator(iter.peek().copied()) {
+                    let left = self.continue_arith_expr(iter, ast::ExprNode::Field(key.clone()))?;
+                    return self
+                        .parse_expression_condition(iter, left)?
+                        .map(|node| node.to_core())
+                        .transpose();
+                }
                 Ok(Some(Condition::HasAttribute { key: key.clone() }))
             }
             _ => Ok(None),
@@ -2761,7 +3249,7 @@ impl LegalDslParser {
         iter: &mut std::iter::Peekable<I>,
     ) -> DslResult<legalis_core::ComparisonOp>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         match iter.next() {
             Some(Token::Operator(op)) => match op.as_str() {
@@ -2781,19 +3269,25 @@ impl LegalDslParser {
         }
     }
 
-    fn parse_number<'a, I>(&self, iter: &mut std::iter::Peekable<I>) -> DslResult<u64>
+    /// Parses a numeric, money or duration literal.
+    fn parse_quantity<'a, I>(
+        &self,
+        iter: &mut std::iter::Peekable<I>,
+    ) -> DslResult<ast::ConditionValue>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
-        match iter.next() {
-            Some(Token::Number(n)) => Ok(*n),
+        match iter.peek() {
+            Some(Token::Number(_)) | Some(Token::Decimal(_)) | Some(Token::Dash) => {
+                self.parse_numeric_value(iter)
+            }
             _ => Err(DslError::InvalidCondition("Expected number".to_string())),
         }
     }
 
     fn parse_effect<'a, I>(&self, iter: &mut std::iter::Peekable<I>) -> DslResult<Effect>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         let effect_type = match iter.next() {
             Some(Token::Grant) => EffectType::Grant,
@@ -2819,12 +3313,12 @@ impl LegalDslParser {
     /// Parses a date in YYYY-MM-DD format.
     fn parse_date<'a, I>(&self, iter: &mut std::iter::Peekable<I>) -> Option<NaiveDate>
     where
-        I: Iterator<Item = &'a Token>,
+        I: Iterator<Item = &'a Token> + Clone,
     {
         // Try to parse date as YYYY-MM-DD (Number-Dash-Number-Dash-Number)
         // or as a quoted string "YYYY-MM-DD"
         match iter.peek() {
-            Some(Token::StringLit(s)) => {
+            Some(Token::StringLit(s)) | Some(Token::Date(s)) => {
                 let date_str = s.clone();
                 iter.next();
                 NaiveDate::parse_from_str(&date_str, "%Y-%m-%d").ok()
@@ -5,9 +5,15 @@
 //! - Hover information for keywords and statutes
 //! - Code completion for keywords
 //! - Document symbols navigation
+//! - Go to definition, find references and rename across imported files
+//! - Type checker and verifier diagnostics on open and save, with quick fixes
 
-use crate::{DslError, LegalDslParser};
+use crate::workspace::{self, Workspace, WorkspaceDiagnostic};
+use crate::{DslError, LegalDslParser, SourceSpan};
+use legalis_verifier::{Severity, VerificationError, generate_quick_fixes};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer, LspService, Server};
@@ -16,6 +22,16 @@ use tower_lsp::{Client, LanguageServer, LspService, Server};
 pub struct LegalisLspBackend {
     client: Client,
     document_map: tokio::sync::RwLock<HashMap<String, String>>,
+    workspace: tokio::sync::RwLock<Workspace>,
+}
+
+/// Data attached to workspace diagnostics so code actions can fix them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct DiagnosticData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<VerificationError>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    replacement: Option<String>,
 }
 
 impl LegalisLspBackend {
@@ -24,9 +40,33 @@ impl LegalisLspBackend {
         Self {
             client,
             document_map: tokio::sync::RwLock::new(HashMap::new()),
+            workspace: tokio::sync::RwLock::new(Workspace::new()),
         }
     }
 
+    /// Indexes a document and publishes its diagnostics.
+    ///
+    /// Reference checks run on every call; the type checker and verifier only
+    /// run when `full` is set, i.e. on open and save.
+    async fn publish_diagnostics(&self, uri: Url, text: String, full: bool) {
+        let path = uri_to_path(&uri);
+        let mut diagnostics = self.validate_document(&uri, &text).await;
+
+        let mut workspace = self.workspace.write().await;
+        workspace.update(&path, text);
+        let findings = if full {
+            workspace.check(&path)
+        } else {
+            workspace.reference_diagnostics(&path)
+        };
+        drop(workspace);
+
+        diagnostics.extend(findings.iter().map(to_diagnostic));
+        self.client
+            .publish_diagnostics(uri, diagnostics, None)
+            .await;
+    }
+
     /// Parses a document and returns diagnostics.
     async fn validate_document(&self, _uri: &Url, text: &str) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
@@ -118,15 +158,32 @@ impl LegalisLspBackend {
 
 #[tower_lsp::async_trait]
 impl LanguageServer for LegalisLspBackend {
-    async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        #[allow(deprecated)]
+        let roots: Vec<PathBuf> = match (params.workspace_folders, params.root_uri) {
+            (Some(folders), _) => folders.iter().map(|f| uri_to_path(&f.uri)).collect(),
+            (None, Some(root)) => vec![uri_to_path(&root)],
+            (None, None) => Vec::new(),
+        };
+        let mut workspace = self.workspace.write().await;
+        for root in roots {
+            workspace.scan(root);
+        }
+        drop(workspace);
+
         Ok(InitializeResult {
             server_info: Some(ServerInfo {
                 name: "legalis-lsp".to_string(),
                 version: Some(env!("CARGO_PKG_VERSION").to_string()),
             }),
             capabilities: ServerCapabilities {
-                text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
+                text_document_sync: Some(TextDocumentSyncCapability::Options(
+                    TextDocumentSyncOptions {
+                        open_close: Some(true),
+                        change: Some(TextDocumentSyncKind::FULL),
+                        save: Some(TextDocumentSyncSaveOptions::Supported(true)),
+                        ..Default::default()
+                    },
                 )),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
                 completion_provider: Some(CompletionOptions {
@@ -137,6 +194,13 @@ impl LanguageServer for LegalisLspBackend {
                 document_symbol_provider: Some(OneOf::Left(true)),
                 code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
                 document_formatting_provider: Some(OneOf::Left(true)),
+                definition_provider: Some(OneOf::Left(true)),
+                references_provider: Some(OneOf::Left(true)),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: Default::default(),
+                })),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
                 ..Default::default()
             },
         })
@@ -163,11 +227,7 @@ impl LanguageServer for LegalisLspBackend {
             .insert(uri.clone(), text.clone());
 
         // Validate and send diagnostics
-        let diagnostics = self
-            .validate_document(&params.text_document.uri, &text)
-            .await;
-        self.client
-            .publish_diagnostics(params.text_document.uri, diagnostics, None)
+        self.publish_diagnostics(params.text_document.uri, text, true)
             .await;
     }
 
@@ -183,11 +243,23 @@ impl LanguageServer for LegalisLspBackend {
                 .insert(uri.clone(), text.clone());
 
             // Validate and send diagnostics
-            let diagnostics = self
-                .validate_document(&params.text_document.uri, &text)
+            self.publish_diagnostics(params.text_document.uri, text, false)
                 .await;
-            self.client
-                .publish_diagnostics(params.text_document.uri, diagnostics, None)
+        }
+    }
+
+    async fn did_save(&self, params: DidSaveTextDocumentParams) {
+        let text = match params.text {
+            Some(text) => Some(text),
+            None => self
+                .document_map
+                .read()
+                .await
+                .get(params.text_document.uri.as_str())
+                .cloned(),
+        };
+        if let Some(text) = text {
+            self.publish_diagnostics(params.text_document.uri, text, true)
                 .await;
         }
     }
@@ -195,6 +267,100 @@ impl LanguageServer for LegalisLspBackend {
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         let uri = params.text_document.uri.to_string();
         self.document_map.write().await.remove(&uri);
+
+        // Fall back to the saved copy so other files still resolve against it
+        self.workspace
+            .write()
+            .await
+            .reload(uri_to_path(&params.text_document.uri));
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>> {
+        let uri = params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+
+        let workspace = self.workspace.read().await;
+        let definition = workspace.definition(
+            uri_to_path(&uri),
+            position.line as usize + 1,
+            position.character as usize + 1,
+        );
+        Ok(definition
+            .and_then(|location| to_location(&location))
+            .map(GotoDefinitionResponse::Scalar))
+    }
+
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let uri = params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+
+        let workspace = self.workspace.read().await;
+        let locations: Vec<Location> = workspace
+            .references(
+                uri_to_path(&uri),
+                position.line as usize + 1,
+                position.character as usize + 1,
+                params.context.include_declaration,
+            )
+            .iter()
+            .filter_map(to_location)
+            .collect();
+
+        Ok(if locations.is_empty() {
+            None
+        } else {
+            Some(locations)
+        })
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        let workspace = self.workspace.read().await;
+        let occurrence = workspace.occurrence_at(
+            uri_to_path(&params.text_document.uri),
+            params.position.line as usize + 1,
+            params.position.character as usize + 1,
+        );
+        Ok(
+            occurrence.map(|o| PrepareRenameResponse::RangeWithPlaceholder {
+                range: to_range(&o.span),
+                placeholder: o.name.clone(),
+            }),
+        )
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let uri = params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+
+        let workspace = self.workspace.read().await;
+        let locations = workspace
+            .rename(
+                uri_to_path(&uri),
+                position.line as usize + 1,
+                position.character as usize + 1,
+                &params.new_name,
+            )
+            .map_err(|e| tower_lsp::jsonrpc::Error::invalid_params(e.to_string()))?;
+
+        let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+        for location in locations.iter().filter_map(to_location) {
+            changes.entry(location.uri).or_default().push(TextEdit {
+                range: location.range,
+                new_text: params.new_name.clone(),
+            });
+        }
+
+        Ok(Some(WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }))
     }
 
     async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
@@ -312,9 +478,15 @@ impl LanguageServer for LegalisLspBackend {
         if let Some(text) = doc_map.get(&uri) {
             let parser = LegalDslParser::new();
             if let Ok(doc) = parser.parse_document(text) {
+                let workspace = self.workspace.read().await;
+                let index = workspace.file(uri_to_path(&params.text_document.uri));
                 let mut symbols = Vec::new();
 
                 for statute in doc.statutes {
+                    let range = index
+                        .and_then(|index| index.statute_span(&statute.id))
+                        .map(|span| to_range(&span))
+                        .unwrap_or_default();
                     #[allow(deprecated)]
                     let symbol = DocumentSymbol {
                         name: statute.id.clone(),
@@ -322,8 +494,8 @@ impl LanguageServer for LegalisLspBackend {
                         kind: SymbolKind::CLASS,
                         tags: None,
                         deprecated: None,
-                        range: Range::default(), // Would need source location tracking
-                        selection_range: Range::default(),
+                        range,
+                        selection_range: range,
                         children: None,
                     };
                     symbols.push(symbol);
@@ -338,7 +510,13 @@ impl LanguageServer for LegalisLspBackend {
 
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
         let uri = params.text_document.uri.to_string();
-        let mut actions = Vec::new();
+        let mut actions: Vec<CodeActionOrCommand> = params
+            .context
+            .diagnostics
+            .iter()
+            .flat_map(|diagnostic| quick_fixes(&params.text_document.uri, diagnostic))
+            .map(CodeActionOrCommand::CodeAction)
+            .collect();
 
         // Get diagnostics for this document
         let doc_map = self.document_map.read().await;
@@ -641,45 +819,22 @@ impl LanguageServer for LegalisLspBackend {
         &self,
         params: WorkspaceSymbolParams,
     ) -> Result<Option<Vec<SymbolInformation>>> {
-        let query = params.query.to_lowercase();
-        let mut symbols = Vec::new();
-
-        // Search through all documents in the workspace
-        let documents = self.document_map.read().await;
-        for (uri_str, text) in documents.iter() {
-            let parser = LegalDslParser::new();
-            if let Ok(doc) = parser.parse_document(text) {
-                for statute in &doc.statutes {
-                    // Match statute ID or title
-                    if statute.id.to_lowercase().contains(&query)
-                        || statute.title.to_lowercase().contains(&query)
-                    {
-                        #[allow(deprecated)]
-                        symbols.push(SymbolInformation {
-                            name: statute.id.clone(),
-                            kind: SymbolKind::FUNCTION,
-                            tags: None,
-                            deprecated: None,
-                            location: Location {
-                                uri: Url::parse(uri_str)
-                                    .unwrap_or_else(|_| Url::parse("file:///").unwrap()),
-                                range: Range {
-                                    start: Position {
-                                        line: 0,
-                                        character: 0,
-                                    },
-                                    end: Position {
-                                        line: 0,
-                                        character: statute.id.len() as u32,
-                                    },
-                                },
-                            },
-                            container_name: Some(statute.title.clone()),
-                        });
-                    }
-                }
-            }
-        }
+        let workspace = self.workspace.read().await;
+        #[allow(deprecated)]
+        let symbols: Vec<SymbolInformation> = workspace
+            .symbols(&params.query)
+            .into_iter()
+            .filter_map(|(location, statute)| {
+                Some(SymbolInformation {
+                    name: statute.id.clone(),
+                    kind: SymbolKind::FUNCTION,
+                    tags: None,
+                    deprecated: None,
+                    location: to_location(&location)?,
+                    container_name: Some(statute.title.clone()),
+                })
+            })
+            .collect();
 
         Ok(if symbols.is_empty() {
             None
@@ -1093,6 +1248,127 @@ impl LanguageServer for LegalisLspBackend {
     }
 }
 
+/// Converts a document URI to the path used by the workspace.
+fn uri_to_path(uri: &Url) -> PathBuf {
+    uri.to_file_path()
+        .unwrap_or_else(|_| PathBuf::from(uri.path()))
+}
+
+/// Converts a 1-indexed source span to a 0-indexed LSP range.
+fn to_range(span: &SourceSpan) -> Range {
+    let position = |line: usize, column: usize| Position {
+        line: line.saturating_sub(1) as u32,
+        character: column.saturating_sub(1) as u32,
+    };
+    Range {
+        start: position(span.start.line, span.start.column),
+        end: position(span.end.line, span.end.column),
+    }
+}
+
+fn to_location(location: &workspace::Location) -> Option<Location> {
+    Some(Location {
+        uri: Url::from_file_path(&location.path).ok()?,
+        range: to_range(&location.span),
+    })
+}
+
+fn to_diagnostic(finding: &WorkspaceDiagnostic) -> Diagnostic {
+    let data = DiagnosticData {
+        error: finding.error.clone(),
+        replacement: finding.replacement.clone(),
+    };
+    Diagnostic {
+        range: to_range(&finding.span),
+        severity: Some(match finding.severity {
+            Severity::Info => DiagnosticSeverity::INFORMATION,
+            Severity::Warning => DiagnosticSeverity::WARNING,
+            Severity::Error | Severity::Critical => DiagnosticSeverity::ERROR,
+        }),
+        source: Some(finding.source.name().to_string()),
+        message: finding.message.clone(),
+        data: (data.error.is_some() || data.replacement.is_some())
+            .then(|| serde_json::to_value(data).ok())
+            .flatten(),
+        ..Default::default()
+    }
+}
+
+/// Builds code actions for a diagnostic published by [`to_diagnostic`].
+///
+/// Verifier quick fixes without edits are returned disabled, with their
+/// description as the reason, so editors can still show the guidance.
+fn quick_fixes(uri: &Url, diagnostic: &Diagnostic) -> Vec<CodeAction> {
+    let Some(data) = diagnostic
+        .data
+        .clone()
+        .and_then(|data| serde_json::from_value::<DiagnosticData>(data).ok())
+    else {
+        return Vec::new();
+    };
+    let mut actions = Vec::new();
+
+    if let Some(replacement) = data.replacement {
+        let edit = TextEdit {
+            range: diagnostic.range,
+            new_text: replacement.clone(),
+        };
+        actions.push(CodeAction {
+            title: format!("Change to '{}'", replacement),
+            kind: Some(CodeActionKind::QUICKFIX),
+            diagnostics: Some(vec![diagnostic.clone()]),
+            edit: Some(WorkspaceEdit {
+                changes: Some(HashMap::from([(uri.clone(), vec![edit])])),
+                document_changes: None,
+                change_annotations: None,
+            }),
+            is_preferred: Some(true),
+            ..Default::default()
+        });
+    }
+
+    for fix in data.error.iter().flat_map(generate_quick_fixes) {
+        let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+        for edit in &fix.edits {
+            let target = Url::from_file_path(&edit.file).unwrap_or_else(|_| uri.clone());
+            changes.entry(target).or_default().push(TextEdit {
+                range: Range {
+                    start: Position {
+                        line: edit.start_line.saturating_sub(1) as u32,
+                        character: edit.start_column.saturating_sub(1) as u32,
+                    },
+                    end: Position {
+                        line: edit.end_line.saturating_sub(1) as u32,
+                        character: edit.end_column.saturating_sub(1) as u32,
+                    },
+                },
+                new_text: edit.new_text.clone(),
+            });
+        }
+        let (edit, disabled) = if changes.is_empty() {
+            let reason = fix.description.clone();
+            (None, Some(CodeActionDisabled { reason }))
+        } else {
+            let edit = WorkspaceEdit {
+                changes: Some(changes),
+                document_changes: None,
+                change_annotations: None,
+            };
+            (Some(edit), None)
+        };
+        actions.push(CodeAction {
+            title: fix.title,
+            kind: Some(CodeActionKind::from(fix.kind)),
+            diagnostics: Some(vec![diagnostic.clone()]),
+            edit,
+            disabled,
+            ..Default::default()
+        });
+    }
+
+    actions
+}
+
 /// Gets the word at a specific position in a line.
 fn get_word_at_position(line: &str, char_pos: usize) -> String {
     let chars: Vec<char> = line.chars().collect();
@@ -1164,4 +1440,52 @@ mod tests {
         assert!(get_keyword_detail("STATUTE").contains("Statute"));
         assert!(get_keyword_detail("GRANT").contains("Grant"));
     }
+
+    #[test]
+    fn test_to_range() {
+        let span = SourceSpan::new(
+            crate::SourceLocation::new(2, 5, 20),
+            crate::SourceLocation::new(2, 12, 27),
+        );
+        let range = to_range(&span);
+        assert_eq!(range.start, Position::new(1, 4));
+        assert_eq!(range.end, Position::new(1, 11));
+    }
+
+    #[test]
+    fn test_quick_fixes() {
+        let uri = Url::parse("file:///project/top-up.legalis").unwrap();
+        let span = SourceSpan::new(
+            crate::SourceLocation::new(5, 14, 60),
+            crate::SourceLocation::new(5, 28, 74),
+        );
+        let mut finding = WorkspaceDiagnostic {
+            span,
+            severity: Severity::Error,
+            source: workspace::DiagnosticSource::Workspace,
+            message: "Unknown statute 'child-benefits'".to_string(),
+            error: None,
+            replacement: Some("child-benefit".to_string()),
+        };
+
+        let actions = quick_fixes(&uri, &to_diagnostic(&finding));
+        assert_eq!(actions.len(), 1);
+        let edits = &actions[0].edit.as_ref().unwrap().changes.as_ref().unwrap()[&uri];
+        assert_eq!(edits[0].new_text, "child-benefit");
+        assert_eq!(edits[0].range, to_range(&span));
+
+        finding.replacement = None;
+        finding.error = Some(VerificationError::DeadStatute {
+            statute_id: "top-up".to_string(),
+        });
+        let actions = quick_fixes(&uri, &to_diagnostic(&finding));
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].title, "Fix unsatisfiable conditions");
+        assert!(actions[0].disabled.is_some());
+
+        finding.error = None;
+        let diagnostic = to_diagnostic(&finding);
+        assert!(diagnostic.data.is_none());
+        assert!(quick_fixes(&uri, &diagnostic).is_empty());
+    }
 }
@@ -369,6 +369,7 @@ mod tests {
                 create_test_statute("US-CA-law-2", vec![], vec![]),
                 create_test_statute("US-NY-law-1", vec![], vec![]),
             ],
+            definitions: Vec::new(),
         };
 
         let hierarchy = JurisdictionHierarchy::from_document(&doc);
@@ -391,6 +392,7 @@ mod tests {
                 create_test_statute("stat2", vec![], vec!["stat3".to_string()]),
                 create_test_statute("stat3", vec![], vec![]),
             ],
+            definitions: Vec::new(),
         };
 
         let relationships = EntityRelationships::from_document(&doc);
@@ -443,6 +445,7 @@ mod tests {
             exports: vec![],
             imports: Vec::new(),
             statutes: vec![statute1],
+            definitions: Vec::new(),
         };
 
         let audit_trail = AmendmentAuditTrail::from_document(&doc);
@@ -315,6 +315,17 @@ impl NLGenerator {
                 operator,
                 value,
             } => self.generate_comparison(field, operator, value),
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => {
+                let right = match right {
+                    ExprNode::Literal(value) => self.value_to_string(value),
+                    other => other.to_string(),
+                };
+                self.describe_comparison(&left.to_string(), operator, &right)
+            }
             ConditionNode::Between { field, min, max } => {
                 format!(
                     "the {} is between {} and {}",
@@ -346,6 +357,19 @@ impl NLGenerator {
             ConditionNode::HasAttribute { key } => {
                 format!("has the attribute '{}'", key)
             }
+            ConditionNode::StatuteApplies { statute_id } => {
+                format!("statute {} applies", statute_id)
+            }
+            ConditionNode::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+            } => self.generate_comparison(
+                &format!("{} under statute {}", output, statute_id),
+                operator,
+                value,
+            ),
             ConditionNode::InRange {
                 field,
                 min,
@@ -402,6 +426,10 @@ impl NLGenerator {
     }
 
     fn generate_comparison(&self, field: &str, op: &str, value: &ConditionValue) -> String {
+        self.describe_comparison(field, op, &self.value_to_string(value))
+    }
+
+    fn describe_comparison(&self, subject: &str, op: &str, value: &str) -> String {
         let op_word = match op {
             "=" | "==" => "equals",
             "!=" | "<>" => "does not equal",
@@ -412,7 +440,7 @@ impl NLGenerator {
             _ => op,
         };
 
-        format!("the {} {} {}", field, op_word, self.value_to_string(value))
+        format!("the {} {} {}", subject, op_word, value)
     }
 
     fn generate_effect(&self, effect: &EffectNode) -> String {
@@ -569,6 +597,9 @@ impl NLGenerator {
     fn value_to_string(&self, value: &ConditionValue) -> String {
         match value {
             ConditionValue::Number(n) => n.to_string(),
+            ConditionValue::Decimal(d) => d.to_string(),
+            ConditionValue::Money { amount, currency } => format!("{} {}", amount, currency),
+            ConditionValue::Duration { value, unit } => format!("{} {}", value, unit),
             ConditionValue::String(s) => format!("\"{}\"", s),
             ConditionValue::Boolean(b) => b.to_string(),
             ConditionValue::Date(d) => d.clone(),
@@ -6,10 +6,13 @@
 //! - Hexadecimal literals (0xFF, 0XAB)
 //! - Octal literals (0o755, 0O644)
 //! - Regular integers and floats
+//! - Exact decimals ([`Decimal`]) for monetary thresholds such as `12500.50`
 
 use std::num::{ParseFloatError, ParseIntError};
 use thiserror::Error;
 
+pub use legalis_core::decimal::{Decimal, DecimalError, MAX_DECIMAL_SCALE, RoundingMode};
+
 /// Errors that can occur during numeric literal parsing
 #[derive(Debug, Error, Clone, PartialEq)]
 pub enum NumericError {
@@ -8,7 +8,7 @@
 //! - Condition reordering for short-circuit optimization
 //! - Constant folding for static expressions
 
-use crate::ast::{ConditionNode, ConditionValue, LegalDocument, StatuteNode};
+use crate::ast::{ArithOp, ConditionNode, ConditionValue, ExprNode, LegalDocument, StatuteNode};
 use std::collections::HashMap;
 
 /// Optimizer for legal documents and statutes
@@ -149,6 +149,13 @@ impl Optimizer {
                 value,
             } => format!("CMP:{}:{}:{:?}", field, operator, value),
             ConditionNode::HasAttribute { key } => format!("HAS:{}", key),
+            ConditionNode::StatuteApplies { statute_id } => format!("APPLIES:{}", statute_id),
+            ConditionNode::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+            } => format!("OUTPUT:{}.{}:{}:{:?}", statute_id, output, operator, value),
             ConditionNode::And(left, right) => {
                 format!(
                     "AND:{}:{}",
@@ -200,6 +207,11 @@ impl Optimizer {
                 operator,
                 value,
             } => format!("TEMPORAL:{:?}:{}:{:?}", field, operator, value),
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => format!("EXPR:{:?}:{}:{:?}", left, operator, right),
         }
     }
 
@@ -290,6 +302,7 @@ impl Optimizer {
         match condition {
             ConditionNode::HasAttribute { .. } => 1, // Cheapest: simple attribute check
             ConditionNode::Comparison { .. } => 2,   // Cheap: single comparison
+            ConditionNode::Expression { .. } => 3,   // Medium: arithmetic then compare
             ConditionNode::Between { .. } => 3,      // Medium: two comparisons
             ConditionNode::In { values, .. } => {
                 // Cost grows with number of values
@@ -399,10 +412,57 @@ impl Optimizer {
 
                 Some(ConditionNode::Not(Box::new(folded)))
             }
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => match (self.fold_expr(left), self.fold_expr(right)) {
+                // `income > 1000 * 12` folds to the plain comparison `income > 12000`
+                (ExprNode::Field(field), ExprNode::Literal(value)) => {
+                    Some(ConditionNode::Comparison {
+                        field,
+                        operator: operator.clone(),
+                        value,
+                    })
+                }
+                (left, right) => Some(ConditionNode::Expression {
+                    left,
+                    operator: operator.clone(),
+                    right,
+                }),
+            },
             _ => None,
         }
     }
 
+    /// Evaluates literal-only subexpressions of an arithmetic expression.
+    fn fold_expr(&mut self, expr: &ExprNode) -> ExprNode {
+        match expr {
+            ExprNode::Negate(inner) => {
+                let inner = self.fold_expr(inner);
+                if let ExprNode::Literal(value) = &inner
+                    && let Some(negated) = value.negated()
+                {
+                    self.stats.folded_constants += 1;
+                    return ExprNode::Literal(negated);
+                }
+                ExprNode::Negate(Box::new(inner))
+            }
+            ExprNode::Binary { op, left, right } => {
+                let left = self.fold_expr(left);
+                let right = self.fold_expr(right);
+                if let (ExprNode::Literal(a), ExprNode::Literal(b)) = (&left, &right)
+                    && let Some(value) = fold_literals(*op, a, b)
+                {
+                    self.stats.folded_constants += 1;
+                    return ExprNode::Literal(value);
+                }
+                ExprNode::binary(*op, left, right)
+            }
+            ExprNode::Field(_) | ExprNode::Literal(_) => expr.clone(),
+        }
+    }
+
     /// Checks if a condition is always true
     fn is_always_true(&self, _condition: &ConditionNode) -> bool {
         // Placeholder for more sophisticated analysis
@@ -415,6 +475,66 @@ impl Optimizer {
     }
 }
 
+/// Applies an arithmetic operator to two literals using exact decimal
+/// arithmetic. Returns `None` when the result is not exactly representable
+/// (e.g. `1 / 3`) or the operands do not combine (mixed currencies).
+fn fold_literals(
+    op: ArithOp,
+    left: &ConditionValue,
+    right: &ConditionValue,
+) -> Option<ConditionValue> {
+    let apply = |a: crate::Decimal, b: crate::Decimal| match op {
+        ArithOp::Add => a.checked_add(&b),
+        ArithOp::Sub => a.checked_sub(&b),
+        ArithOp::Mul => a.checked_mul(&b),
+        ArithOp::Div => a.checked_div(&b),
+    };
+    let money = |amount: crate::Decimal, currency: &str| ConditionValue::Money {
+        amount,
+        currency: currency.to_string(),
+    };
+    match (left, right) {
+        (
+            ConditionValue::Money {
+                amount: a,
+                currency: c1,
+            },
+            ConditionValue::Money {
+                amount: b,
+                currency: c2,
+            },
+        ) if c1 == c2 && matches!(op, ArithOp::Add | ArithOp::Sub) => {
+            apply(*a, *b).map(|amount| money(amount, c1))
+        }
+        (ConditionValue::Money { amount, currency }, other)
+            if !matches!(other, ConditionValue::Money { .. }) =>
+        {
+            match op {
+                ArithOp::Mul | ArithOp::Div => {
+                    apply(*amount, other.as_decimal()?).map(|a| money(a, currency))
+                }
+                ArithOp::Add | ArithOp::Sub => None,
+            }
+        }
+        (other, ConditionValue::Money { amount, currency })
+            if op == ArithOp::Mul && !matches!(other, ConditionValue::Money { .. }) =>
+        {
+            apply(other.as_decimal()?, *amount).map(|a| money(a, currency))
+        }
+        (
+            ConditionValue::Number(_) | ConditionValue::Decimal(_),
+            ConditionValue::Number(_) | ConditionValue::Decimal(_),
+        ) => {
+            let result = apply(left.as_decimal()?, right.as_decimal()?)?.normalize();
+            Some(match result.to_i64() {
+                Some(n) if result.is_integer() => ConditionValue::Number(n),
+                _ => ConditionValue::Decimal(result),
+            })
+        }
+        _ => None,
+    }
+}
+
 impl Default for Optimizer {
     fn default() -> Self {
         Self::new()
@@ -656,6 +776,7 @@ mod tests {
                     },
                 ],
             )],
+            definitions: Vec::new(),
         };
 
         let optimized = optimizer.optimize(doc);
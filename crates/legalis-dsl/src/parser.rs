@@ -1,7 +1,8 @@
 //! Parser utilities for the legal DSL.
 
-use crate::DslResult;
 use crate::ast::*;
+use crate::{DslError, DslResult};
+use legalis_core::formula::quote_identifier;
 
 /// Trait for converting AST nodes to core types.
 pub trait ToCore {
@@ -18,47 +19,7 @@ impl ToCore for ConditionNode {
                 field,
                 operator,
                 value,
-            } => {
-                let op = match operator.as_str() {
-                    ">=" => legalis_core::ComparisonOp::GreaterOrEqual,
-                    "<=" => legalis_core::ComparisonOp::LessOrEqual,
-                    ">" => legalis_core::ComparisonOp::GreaterThan,
-                    "<" => legalis_core::ComparisonOp::LessThan,
-                    "==" | "=" => legalis_core::ComparisonOp::Equal,
-                    "!=" => legalis_core::ComparisonOp::NotEqual,
-                    _ => legalis_core::ComparisonOp::Equal,
-                };
-
-                match field.to_lowercase().as_str() {
-                    "age" => {
-                        if let ConditionValue::Number(n) = value {
-                            Ok(legalis_core::Condition::Age {
-                                operator: op,
-                                value: *n as u32,
-                            })
-                        } else {
-                            Ok(legalis_core::Condition::Custom {
-                                description: "Age condition with non-numeric value".to_string(),
-                            })
-                        }
-                    }
-                    "income" => {
-                        if let ConditionValue::Number(n) = value {
-                            Ok(legalis_core::Condition::Income {
-                                operator: op,
-                                value: *n as u64,
-                            })
-                        } else {
-                            Ok(legalis_core::Condition::Custom {
-                                description: "Income condition with non-numeric value".to_string(),
-                            })
-                        }
-                    }
-                    _ => Ok(legalis_core::Condition::Custom {
-                        description: format!("{field} {operator} {:?}", value),
-                    }),
-                }
-            }
+            } => comparison_to_core(field, comparison_op(operator), value),
             ConditionNode::HasAttribute { key } => {
                 Ok(legalis_core::Condition::HasAttribute { key: key.clone() })
             }
@@ -114,6 +75,18 @@ impl ToCore for ConditionNode {
                 operator,
                 value,
             } => {
+                // Dates lower to a day difference against the literal
+                if let ConditionValue::Date(date) = value {
+                    let subject = match field {
+                        TemporalField::CurrentDate => "today()".to_string(),
+                        TemporalField::DateField(name) => quote_identifier(name),
+                    };
+                    return Ok(legalis_core::Condition::calculation(
+                        format!("{} - {}", subject, date),
+                        comparison_op(operator),
+                        0.0,
+                    ));
+                }
                 let field_desc = match field {
                     TemporalField::CurrentDate => "CURRENT_DATE".to_string(),
                     TemporalField::DateField(name) => format!("DATE_FIELD({})", name),
@@ -122,6 +95,20 @@ impl ToCore for ConditionNode {
                     description: format!("{} {} {:?}", field_desc, operator, value),
                 })
             }
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => expression_to_core(left, comparison_op(operator), right),
+            ConditionNode::StatuteApplies { statute_id } => {
+                Ok(legalis_core::Condition::statute_applies(statute_id))
+            }
+            ConditionNode::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+            } => statute_output_to_core(statute_id, output, comparison_op(operator), value),
             ConditionNode::And(left, right) => Ok(legalis_core::Condition::And(
                 Box::new(left.to_core()?),
                 Box::new(right.to_core()?),
@@ -137,6 +124,205 @@ impl ToCore for ConditionNode {
     }
 }
 
+/// Maps a DSL comparison operator to its core equivalent.
+fn comparison_op(operator: &str) -> legalis_core::ComparisonOp {
+    match operator {
+        ">=" => legalis_core::ComparisonOp::GreaterOrEqual,
+        "<=" => legalis_core::ComparisonOp::LessOrEqual,
+        ">" => legalis_core::ComparisonOp::GreaterThan,
+        "<" => legalis_core::ComparisonOp::LessThan,
+        "==" | "=" => legalis_core::ComparisonOp::Equal,
+        "!=" => legalis_core::ComparisonOp::NotEqual,
+        _ => legalis_core::ComparisonOp::Equal,
+    }
+}
+
+/// Lowers a reference to another statute's output to a core condition.
+///
+/// Money thresholds keep their currency, so the output must be in the same
+/// currency when evaluated.
+pub(crate) fn statute_output_to_core(
+    statute_id: &str,
+    output: &str,
+    operator: legalis_core::ComparisonOp,
+    value: &ConditionValue,
+) -> DslResult<legalis_core::Condition> {
+    use legalis_core::Condition;
+
+    match value {
+        ConditionValue::Money { amount, currency } => Ok(Condition::statute_output_money(
+            statute_id, output, operator, *amount, currency,
+        )),
+        _ => match value.as_decimal() {
+            Some(amount) => Ok(Condition::statute_output(
+                statute_id, output, operator, amount,
+            )),
+            None => Err(DslError::InvalidCondition(format!(
+                "Output {}.{} can only be compared with a number",
+                statute_id, output
+            ))),
+        },
+    }
+}
+
+/// Returns a literal as an `f64` threshold when the conversion is exact.
+///
+/// Only whole numbers within the `f64` integer range qualify; fractional and
+/// money literals stay in the formula so that they compare exactly.
+fn exact_threshold(value: &ConditionValue) -> Option<f64> {
+    const MAX_EXACT_INTEGER: u64 = 1 << f64::MANTISSA_DIGITS;
+
+    let whole = match value {
+        ConditionValue::Number(n) => *n,
+        ConditionValue::Decimal(d) => d.to_i64()?,
+        _ => return None,
+    };
+    (whole.unsigned_abs() <= MAX_EXACT_INTEGER).then_some(whole as f64)
+}
+
+/// Builds a calculation that holds when the formula predicate is true.
+fn predicate(formula: String) -> legalis_core::Condition {
+    legalis_core::Condition::calculation(formula, legalis_core::ComparisonOp::Equal, 1.0)
+}
+
+/// Lowers an arithmetic comparison to a [`legalis_core::Condition::Calculation`].
+///
+/// A whole-number right-hand side becomes the threshold (`income - tax > 30000`);
+/// otherwise the whole comparison moves into the formula, where decimals and
+/// money amounts compare exactly.
+fn expression_to_core(
+    left: &ExprNode,
+    op: legalis_core::ComparisonOp,
+    right: &ExprNode,
+) -> DslResult<legalis_core::Condition> {
+    for expr in [left, right] {
+        check_numeric_operands(expr)?;
+    }
+    let condition = match right {
+        ExprNode::Literal(value) if exact_threshold(value).is_some() => {
+            let threshold = exact_threshold(value).unwrap_or_default();
+            legalis_core::Condition::calculation(left.to_formula(), op, threshold)
+        }
+        _ => predicate(format!(
+            "{} {} {}",
+            left.to_formula(),
+            op,
+            right.to_formula()
+        )),
+    };
+    Ok(condition)
+}
+
+/// Rejects literals that cannot take part in arithmetic.
+fn check_numeric_operands(expr: &ExprNode) -> DslResult<()> {
+    match expr {
+        ExprNode::Literal(
+            value @ (ConditionValue::String(_)
+            | ConditionValue::Boolean(_)
+            | ConditionValue::SetExpr(_)),
+        ) => Err(DslError::InvalidCondition(format!(
+            "Non-numeric operand in arithmetic expression: {:?}",
+            value
+        ))),
+        ExprNode::Field(_) | ExprNode::Literal(_) => Ok(()),
+        ExprNode::Negate(inner) => check_numeric_operands(inner),
+        ExprNode::Binary { left, right, .. } => {
+            check_numeric_operands(left)?;
+            check_numeric_operands(right)
+        }
+    }
+}
+
+/// Lowers a `field OP value` comparison to a core condition.
+///
+/// Whole, non-negative plain-number `age` and `income` thresholds keep their
+/// dedicated conditions; money never does, as they carry no currency. Other
+/// numeric literals compare the attribute through
+/// [`legalis_core::Condition::Calculation`]: whole numbers as the threshold,
+/// decimals and money as an exact formula predicate such as
+/// `` `gross-income` >= 300.50 JPY ``, which also fails at evaluation time if
+/// the attribute is money in another currency. Dates compare the day
+/// difference against zero, and durations become residency or duration checks.
+pub(crate) fn comparison_to_core(
+    field: &str,
+    operator: legalis_core::ComparisonOp,
+    value: &ConditionValue,
+) -> DslResult<legalis_core::Condition> {
+    use legalis_core::{Condition, DurationUnit};
+
+    let field_lower = field.to_lowercase();
+    match value {
+        ConditionValue::Duration { value, unit } => {
+            let amount = u32::try_from(*value).map_err(|_| {
+                DslError::InvalidCondition(format!("Invalid duration for {}: {}", field, value))
+            })?;
+            Ok(match (field_lower.as_str(), unit) {
+                ("age", DurationUnit::Years) => Condition::age(operator, amount),
+                ("residency", DurationUnit::Months) => Condition::ResidencyDuration {
+                    operator,
+                    months: amount,
+                },
+                ("residency", DurationUnit::Years) => Condition::ResidencyDuration {
+                    operator,
+                    months: amount.saturating_mul(12),
+                },
+                _ => Condition::Duration {
+                    operator,
+                    value: amount,
+                    unit: *unit,
+                },
+            })
+        }
+        ConditionValue::Date(date) => Ok(Condition::calculation(
+            format!("{} - {}", quote_identifier(field), date),
+            operator,
+            0.0,
+        )),
+        ConditionValue::Money { .. } => Ok(numeric_comparison(field, operator, value)),
+        _ => match (field_lower.as_str(), value.as_decimal()) {
+            ("age", Some(amount)) => Ok(amount
+                .to_i64()
+                .and_then(|n| u32::try_from(n).ok())
+                .map(|n| Condition::age(operator, n))
+                .unwrap_or_else(|| numeric_comparison("age", operator, value))),
+            ("income", Some(amount)) => Ok(amount
+                .to_i64()
+                .and_then(|n| u64::try_from(n).ok())
+                .map(|n| Condition::income(operator, n))
+                .unwrap_or_else(|| numeric_comparison("income", operator, value))),
+            (_, Some(_)) => Ok(numeric_comparison(field, operator, value)),
+            ("age", None) => Ok(Condition::Custom {
+                description: "Age condition with non-numeric value".to_string(),
+            }),
+            ("income", None) => Ok(Condition::Custom {
+                description: "Income condition with non-numeric value".to_string(),
+            }),
+            (_, None) => Ok(Condition::Custom {
+                description: format!("{field} {operator} {:?}", value),
+            }),
+        },
+    }
+}
+
+/// Compares an attribute with a numeric literal, exactly.
+fn numeric_comparison(
+    field: &str,
+    operator: legalis_core::ComparisonOp,
+    value: &ConditionValue,
+) -> legalis_core::Condition {
+    match exact_threshold(value) {
+        Some(threshold) => {
+            legalis_core::Condition::calculation(quote_identifier(field), operator, threshold)
+        }
+        None => predicate(format!(
+            "{} {} {}",
+            quote_identifier(field),
+            operator,
+            value.to_formula()
+        )),
+    }
+}
+
 impl ToCore for EffectNode {
     type Output = legalis_core::Effect;
 
@@ -159,6 +345,81 @@ impl ToCore for EffectNode {
     }
 }
 
+impl ToCore for StatuteNode {
+    type Output = legalis_core::Statute;
+
+    /// Fails if the statute has more than one effect, as a core statute
+    /// carries exactly one.
+    fn to_core(&self) -> DslResult<Self::Output> {
+        let effect = match self.effects.as_slice() {
+            [effect] => effect.to_core()?,
+            [] => {
+                legalis_core::Effect::new(legalis_core::EffectType::Custom, "No effect specified")
+            }
+            effects => {
+                return Err(DslError::InvalidEffect(format!(
+                    "Statute '{}' has {} effects; only one can be converted",
+                    self.id,
+                    effects.len()
+                )));
+            }
+        };
+
+        let mut statute = legalis_core::Statute::new(&self.id, &self.title, effect);
+        for condition in &self.conditions {
+            statute = statute.with_precondition(condition.to_core()?);
+        }
+        if let Some(discretion) = &self.discretion {
+            statute = statute.with_discretion(discretion);
+        }
+        for (index, exception) in self.exceptions.iter().enumerate() {
+            let condition = exception
+                .conditions
+                .iter()
+                .map(ToCore::to_core)
+                .reduce(|acc, next| {
+                    Ok(legalis_core::Condition::And(
+                        Box::new(acc?),
+                        Box::new(next?),
+                    ))
+                })
+                .transpose()?
+                .unwrap_or(legalis_core::Condition::Custom {
+                    description: exception.description.clone(),
+                });
+            statute = statute.with_exception(legalis_core::StatuteException::new(
+                format!("exception-{}", index + 1),
+                &exception.description,
+                condition,
+            ));
+        }
+        Ok(statute)
+    }
+}
+
+/// Converts a document into a defeasible theory.
+///
+/// `PRIORITY` values become rule priorities and `SUPERSEDES` clauses become
+/// superiority relations, so the engine resolves the conflicts they describe.
+impl ToCore for LegalDocument {
+    type Output = legalis_core::defeasible::DefeasibleTheory;
+
+    fn to_core(&self) -> DslResult<Self::Output> {
+        let doc = self.inline_definitions()?;
+        let mut theory = legalis_core::defeasible::DefeasibleTheory::new();
+        for node in &doc.statutes {
+            theory.add_statute(node.to_core()?);
+            if let Some(priority) = node.priority {
+                theory = theory.with_priority(&node.id, priority);
+            }
+            for superseded in &node.supersedes {
+                theory = theory.prefer(&node.id, superseded);
+            }
+        }
+        Ok(theory)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -193,4 +454,56 @@ mod tests {
         assert_eq!(effect.effect_type, legalis_core::EffectType::Grant);
         assert_eq!(effect.parameters.get("scope"), Some(&"full".to_string()));
     }
+
+    #[test]
+    fn test_document_to_defeasible_theory() {
+        let doc = crate::LegalDslParser::new()
+            .parse_document(
+                r#"
+                STATUTE general: "General permit" {
+                    WHEN AGE >= 18
+                    THEN GRANT "permit"
+                }
+                STATUTE ban: "Ban" {
+                    WHEN HAS banned
+                    THEN REVOKE "permit"
+                    SUPERSEDES general
+                }
+                "#,
+            )
+            .unwrap();
+
+        let theory = doc.to_core().unwrap();
+        assert_eq!(theory.statutes().len(), 2);
+
+        let mut facts = std::collections::HashMap::new();
+        facts.insert("age".to_string(), "30".to_string());
+        facts.insert("banned".to_string(), "true".to_string());
+        let outcome = theory.evaluate(&legalis_core::AttributeBasedContext::new(facts));
+        assert!(outcome.is_accepted("ban"));
+        assert!(!outcome.is_accepted("general"));
+    }
+
+    #[test]
+    fn test_multiple_effects_are_rejected() {
+        let doc = crate::LegalDslParser::new()
+            .parse_document(
+                r#"
+                STATUTE both: "Both" {
+                    WHEN AGE >= 18
+                    THEN GRANT "vote"
+                    THEN GRANT "drive"
+                }
+                "#,
+            )
+            .unwrap();
+        assert_eq!(doc.statutes[0].effects.len(), 2);
+
+        let err = doc.statutes[0].to_core().unwrap_err();
+        assert!(
+            matches!(&err, DslError::InvalidEffect(msg) if msg.contains("2 effects")),
+            "{err}"
+        );
+        assert!(doc.to_core().is_err());
+    }
 }
@@ -342,6 +342,85 @@ impl DslPrinter {
                     value
                 )
             }
+            Condition::StatuteApplies { statute_id } => {
+                format!("{} {}", self.kw("APPLIES"), statute_id)
+            }
+            Condition::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+                currency,
+            } => {
+                let mut formatted = format!(
+                    "{}.{} {} {}",
+                    statute_id,
+                    output,
+                    self.format_op(*operator),
+                    value
+                );
+                if let Some(currency) = currency {
+                    formatted.push(' ');
+                    formatted.push_str(currency);
+                }
+                formatted
+            }
+            Condition::ForAll {
+                relationship,
+                condition,
+            } => {
+                format!(
+                    "{} {:?} ({})",
+                    self.kw("FORALL"),
+                    relationship,
+                    self.format_condition(condition)
+                )
+            }
+            Condition::Exists {
+                relationship,
+                condition,
+            } => {
+                format!(
+                    "{} {:?} ({})",
+                    self.kw("EXISTS"),
+                    relationship,
+                    self.format_condition(condition)
+                )
+            }
+            Condition::Count {
+                relationship,
+                condition,
+                operator,
+                value,
+            } => {
+                let filter = condition
+                    .as_ref()
+                    .map(|c| format!(" {} ({})", self.kw("WHERE"), self.format_condition(c)))
+                    .unwrap_or_default();
+                format!(
+                    "{} {:?}{} {} {}",
+                    self.kw("COUNT"),
+                    relationship,
+                    filter,
+                    self.format_op(*operator),
+                    value
+                )
+            }
+            Condition::Sum {
+                relationship,
+                attribute,
+                operator,
+                value,
+            } => {
+                format!(
+                    "{} {:?}.{} {} {}",
+                    self.kw("SUM"),
+                    relationship,
+                    attribute,
+                    self.format_op(*operator),
+                    value
+                )
+            }
             Condition::Composite {
                 conditions,
                 threshold,
@@ -522,6 +601,14 @@ pub fn format_document(doc: &LegalDocument) -> String {
         output.push('\n');
     }
 
+    // Format LET definitions
+    for definition in &doc.definitions {
+        output.push_str(&format!("LET {} = {}\n", definition.name, definition.expr));
+    }
+    if !doc.definitions.is_empty() && !doc.statutes.is_empty() {
+        output.push('\n');
+    }
+
     // Format statutes
     for (idx, statute) in doc.statutes.iter().enumerate() {
         if idx > 0 {
@@ -626,9 +713,31 @@ fn format_condition_node(cond: &ConditionNode) -> String {
         } => {
             format!("{} {} {}", field, operator, format_condition_value(value))
         }
+        ConditionNode::Expression {
+            left,
+            operator,
+            right,
+        } => format!("{} {} {}", left, operator, right),
         ConditionNode::HasAttribute { key } => {
             format!("HAS {}", key)
         }
+        ConditionNode::StatuteApplies { statute_id } => {
+            format!("APPLIES {}", statute_id)
+        }
+        ConditionNode::StatuteOutput {
+            statute_id,
+            output,
+            operator,
+            value,
+        } => {
+            format!(
+                "{}.{} {} {}",
+                statute_id,
+                output,
+                operator,
+                format_condition_value(value)
+            )
+        }
         ConditionNode::Between { field, min, max } => {
             format!(
                 "{} BETWEEN {} AND {}",
@@ -717,6 +826,9 @@ fn format_condition_node(cond: &ConditionNode) -> String {
 fn format_condition_value(value: &ConditionValue) -> String {
     match value {
         ConditionValue::Number(n) => n.to_string(),
+        ConditionValue::Decimal(d) => d.to_string(),
+        ConditionValue::Money { amount, currency } => format!("{} {}", amount, currency),
+        ConditionValue::Duration { value, unit } => format!("{} {}", value, unit),
         ConditionValue::String(s) => format!("\"{}\"", s),
         ConditionValue::Boolean(b) => b.to_string(),
         ConditionValue::Date(d) => d.clone(),
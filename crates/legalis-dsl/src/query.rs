@@ -462,6 +462,7 @@ mod tests {
                     priority: None,
                 },
             ],
+            definitions: Vec::new(),
         }
     }
 
@@ -144,7 +144,27 @@ impl SearchIndex {
             } => {
                 format!("{} {} {}", field, operator, self.format_value(value))
             }
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => format!("{} {} {}", left, operator, right),
             ConditionNode::HasAttribute { key } => format!("has {}", key),
+            ConditionNode::StatuteApplies { statute_id } => format!("applies {}", statute_id),
+            ConditionNode::StatuteOutput {
+                statute_id,
+                output,
+                operator,
+                value,
+            } => {
+                format!(
+                    "{} {} {} {}",
+                    statute_id,
+                    output,
+                    operator,
+                    self.format_value(value)
+                )
+            }
             ConditionNode::Between { field, min, max } => {
                 format!(
                     "{} between {} and {}",
@@ -217,6 +237,9 @@ impl SearchIndex {
     fn format_value(&self, value: &ConditionValue) -> String {
         match value {
             ConditionValue::Number(n) => n.to_string(),
+            ConditionValue::Decimal(d) => d.to_string(),
+            ConditionValue::Money { amount, currency } => format!("{} {}", amount, currency),
+            ConditionValue::Duration { value, unit } => format!("{} {}", value, unit),
             ConditionValue::String(s) => s.clone(),
             ConditionValue::Boolean(b) => b.to_string(),
             ConditionValue::Date(d) => d.clone(),
@@ -406,6 +429,7 @@ mod tests {
                     priority: None,
                 },
             ],
+            definitions: Vec::new(),
         }
     }
 
@@ -162,6 +162,7 @@ fn condition_depth(condition: &ConditionNode) -> usize {
 fn count_condition_types(condition: &ConditionNode, counts: &mut HashMap<String, usize>) {
     let cond_type = match condition {
         ConditionNode::Comparison { .. } => "Comparison",
+        ConditionNode::Expression { .. } => "Expression",
         ConditionNode::HasAttribute { .. } => "HasAttribute",
         ConditionNode::Between { .. } => "Between",
         ConditionNode::In { .. } => "In",
@@ -170,6 +171,8 @@ fn count_condition_types(condition: &ConditionNode, counts: &mut HashMap<String,
         ConditionNode::InRange { .. } => "InRange",
         ConditionNode::NotInRange { .. } => "NotInRange",
         ConditionNode::TemporalComparison { .. } => "TemporalComparison",
+        ConditionNode::StatuteApplies { .. } => "StatuteApplies",
+        ConditionNode::StatuteOutput { .. } => "StatuteOutput",
         ConditionNode::And(left, right) => {
             count_condition_types(left, counts);
             count_condition_types(right, counts);
@@ -446,6 +449,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute()],
+            definitions: Vec::new(),
         };
 
         let stats = DocumentStatistics::from_document(&doc);
@@ -464,6 +468,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute()],
+            definitions: Vec::new(),
         };
 
         let analysis = DependencyAnalysis::from_document(&doc);
@@ -459,6 +459,7 @@ mod tests {
                 }],
                 ..Default::default()
             }],
+            definitions: Vec::new(),
         };
 
         let mut analyzer = TaintAnalyzer::new();
@@ -511,6 +512,7 @@ mod tests {
                     ..Default::default()
                 },
             ],
+            definitions: Vec::new(),
         };
 
         let mut analyzer = TaintAnalyzer::new();
@@ -556,6 +558,7 @@ mod tests {
                     ..Default::default()
                 },
             ],
+            definitions: Vec::new(),
         };
 
         let mut analyzer = TaintAnalyzer::new();
@@ -593,6 +596,7 @@ mod tests {
                 }],
                 ..Default::default()
             }],
+            definitions: Vec::new(),
         };
 
         let mut analyzer = TaintAnalyzer::new();
@@ -88,6 +88,431 @@ fn test_parse_not_condition() {
     assert!(matches!(statute.preconditions[0], Condition::Not(_)));
 }
 
+#[test]
+fn test_parse_statute_reference_conditions() {
+    let input = r#"
+        STATUTE housing: "Housing Support" {
+            WHEN APPLIES single-parent AND child-benefit.amount >= 20000
+            THEN GRANT "Housing support"
+        }
+    "#;
+
+    let parser = LegalDslParser::new();
+    let statute = parser.parse_statute(input).unwrap();
+    assert_eq!(
+        statute.referenced_statutes(),
+        vec!["single-parent", "child-benefit"]
+    );
+    assert_eq!(
+        statute.preconditions[0].to_string(),
+        "(applies(single-parent) AND child-benefit.amount >= 20000)"
+    );
+
+    let doc = parser.parse_document(input).unwrap();
+    let condition = &doc.statutes[0].conditions[0];
+    assert_eq!(
+        condition.referenced_statutes(),
+        vec!["single-parent", "child-benefit"]
+    );
+    assert_eq!(condition.to_core().unwrap(), statute.preconditions[0]);
+}
+
+#[test]
+fn test_parse_numeric_money_duration_and_date_literals() {
+    let input = r#"
+        STATUTE allowance: "Allowance" {
+            WHEN INCOME < 12500.50 AND balance > -100 AND fee = 300 JPY
+            WHEN residency >= 6 months AND registered >= 2024-04-01
+            THEN GRANT "Allowance"
+        }
+    "#;
+
+    let parser = LegalDslParser::new();
+    let doc = parser.parse_document(input).unwrap();
+    let statute = &doc.statutes[0];
+
+    let ast::ConditionNode::And(first, fee) = &statute.conditions[0] else {
+        panic!("Expected AND condition");
+    };
+    let ast::ConditionNode::And(income, balance) = first.as_ref() else {
+        panic!("Expected AND condition");
+    };
+    let value_of = |node: &ast::ConditionNode| match node {
+        ast::ConditionNode::Comparison { value, .. } => value.clone(),
+        other => panic!("Expected comparison, got {:?}", other),
+    };
+    assert_eq!(
+        value_of(income),
+        ast::ConditionValue::Decimal("12500.50".parse().unwrap())
+    );
+    assert_eq!(value_of(balance), ast::ConditionValue::Number(-100));
+    assert_eq!(
+        value_of(fee),
+        ast::ConditionValue::Money {
+            amount: Decimal::from(300),
+            currency: "JPY".to_string(),
+        }
+    );
+
+    let ast::ConditionNode::And(residency, registered) = &statute.conditions[1] else {
+        panic!("Expected AND condition");
+    };
+    assert_eq!(
+        value_of(residency),
+        ast::ConditionValue::Duration {
+            value: 6,
+            unit: legalis_core::DurationUnit::Months,
+        }
+    );
+    assert_eq!(
+        value_of(registered),
+        ast::ConditionValue::Date("2024-04-01".to_string())
+    );
+
+    // Lowering to core keeps the cents, the sign and the currency
+    assert_eq!(
+        income.to_core().unwrap(),
+        Condition::calculation("income < 12500.50", legalis_core::ComparisonOp::Equal, 1.0)
+    );
+    assert_eq!(
+        balance.to_core().unwrap(),
+        Condition::calculation("balance", legalis_core::ComparisonOp::GreaterThan, -100.0)
+    );
+    assert_eq!(
+        fee.to_core().unwrap(),
+        Condition::calculation("fee == 300 JPY", legalis_core::ComparisonOp::Equal, 1.0)
+    );
+
+    // ... and evaluates exactly, rejecting amounts in another currency
+    let context = |pairs: &[(&str, &str)]| {
+        legalis_core::AttributeBasedContext::new(
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        )
+    };
+    let lowered = income.to_core().unwrap();
+    assert!(
+        lowered
+            .evaluate_simple(&context(&[("income", "12500.49")]))
+            .unwrap()
+    );
+    assert!(
+        !lowered
+            .evaluate_simple(&context(&[("income", "12500.50")]))
+            .unwrap()
+    );
+    let lowered = fee.to_core().unwrap();
+    assert!(
+        lowered
+            .evaluate_simple(&context(&[("fee", "300.00 JPY")]))
+            .unwrap()
+    );
+    assert!(
+        lowered
+            .evaluate_simple(&context(&[("fee", "300 USD")]))
+            .is_err()
+    );
+    assert_eq!(
+        residency.to_core().unwrap(),
+        Condition::ResidencyDuration {
+            operator: legalis_core::ComparisonOp::GreaterOrEqual,
+            months: 6,
+        }
+    );
+    assert_eq!(
+        registered.to_core().unwrap(),
+        Condition::calculation(
+            "registered - 2024-04-01",
+            legalis_core::ComparisonOp::GreaterOrEqual,
+            0.0
+        )
+    );
+
+    // Field names that are not plain identifiers are quoted in the formula
+    let date = ast::ConditionValue::Date("2024-04-01".to_string());
+    let lowered =
+        parser::comparison_to_core("filing-date", legalis_core::ComparisonOp::LessThan, &date)
+            .unwrap();
+    assert_eq!(
+        lowered,
+        Condition::calculation(
+            "`filing-date` - 2024-04-01",
+            legalis_core::ComparisonOp::LessThan,
+            0.0
+        )
+    );
+    assert!(
+        lowered
+            .evaluate_simple(&context(&[("filing-date", "2024-03-31")]))
+            .unwrap()
+    );
+
+    // Whole money amounts keep their currency even on dedicated fields
+    let money = ast::ConditionValue::Money {
+        amount: Decimal::from(300),
+        currency: "JPY".to_string(),
+    };
+    let lowered =
+        parser::comparison_to_core("income", legalis_core::ComparisonOp::GreaterOrEqual, &money)
+            .unwrap();
+    assert_eq!(
+        lowered,
+        Condition::calculation("income >= 300 JPY", legalis_core::ComparisonOp::Equal, 1.0)
+    );
+    assert!(
+        lowered
+            .evaluate_simple(&context(&[("income", "300 JPY")]))
+            .unwrap()
+    );
+    assert!(
+        lowered
+            .evaluate_simple(&context(&[("income", "300 USD")]))
+            .is_err()
+    );
+
+    // The direct statute parser lowers the same way
+    let core = parser
+        .parse_statute(
+            r#"STATUTE s: "S" { WHEN INCOME < 12500.50 AND AGE >= 18 years THEN GRANT "X" }"#,
+        )
+        .unwrap();
+    assert_eq!(
+        core.preconditions[0].to_string(),
+        "((income < 12500.50) == 1 AND age >= 18)"
+    );
+
+    // Printing and re-parsing is lossless
+    let printed = crate::printer::format_document(&doc);
+    assert!(printed.contains("12500.50"), "{}", printed);
+    assert!(printed.contains("300 JPY"), "{}", printed);
+    assert!(printed.contains("6 months"), "{}", printed);
+    assert_eq!(
+        parser.parse_document(&printed).unwrap().statutes,
+        doc.statutes
+    );
+}
+
+#[test]
+fn test_invalid_literals_and_currency_mismatch() {
+    let parser = LegalDslParser::new();
+    let invalid_date = r#"
+        STATUTE s: "S" { WHEN registered >= 2024-02-30 THEN GRANT "X" }
+    "#;
+    assert!(parser.parse_document(invalid_date).is_err());
+
+    let fractional_duration = r#"
+        STATUTE s: "S" { WHEN residency >= 1.5 years THEN GRANT "X" }
+    "#;
+    assert!(parser.parse_document(fractional_duration).is_err());
+
+    let mixed = r#"
+        STATUTE s: "S" { WHEN fee >= 300 JPY AND fee < 10 USD THEN GRANT "X" }
+    "#;
+    let doc = parser.parse_document(mixed).unwrap();
+    let errors = TypeChecker::new().check_document(&doc);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].expected, Type::Money("JPY".to_string()));
+    assert_eq!(errors[0].actual, Type::Money("USD".to_string()));
+}
+
+#[test]
+fn test_parse_arithmetic_expressions() {
+    let input = r#"
+        STATUTE rent-relief: "Rent Relief" {
+            WHEN income - deductions > 12 * monthly_rent
+            WHEN (income + bonus) / 12 <= 2500.50
+            WHEN AGE + 5 >= 70
+            THEN GRANT "Relief"
+        }
+    "#;
+
+    let parser = LegalDslParser::new();
+    let doc = parser.parse_document(input).unwrap();
+    let conditions = &doc.statutes[0].conditions;
+
+    let field = |name: &str| ast::ExprNode::Field(name.to_string());
+    let number = |n: i64| ast::ExprNode::Literal(ast::ConditionValue::Number(n));
+    assert_eq!(
+        conditions[0],
+        ast::ConditionNode::Expression {
+            left: ast::ExprNode::binary(ast::ArithOp::Sub, field("income"), field("deductions")),
+            operator: ">".to_string(),
+            right: ast::ExprNode::binary(ast::ArithOp::Mul, number(12), field("monthly_rent")),
+        }
+    );
+
+    // Precedence: parentheses group, division binds tighter than comparison
+    let ast::ConditionNode::Expression { left, .. } = &conditions[1] else {
+        panic!("Expected expression, got {:?}", conditions[1]);
+    };
+    assert_eq!(left.to_string(), "(income + bonus) / 12");
+    assert_eq!(left.to_formula(), "((income + bonus) / 12)");
+
+    // Lowering: a whole-number right-hand side is the threshold, otherwise
+    // the comparison itself becomes the formula
+    assert_eq!(
+        conditions[0].to_core().unwrap(),
+        Condition::calculation(
+            "(income - deductions) > (12 * monthly_rent)",
+            legalis_core::ComparisonOp::Equal,
+            1.0
+        )
+    );
+    assert_eq!(
+        conditions[1].to_core().unwrap(),
+        Condition::calculation(
+            "((income + bonus) / 12) <= 2500.50",
+            legalis_core::ComparisonOp::Equal,
+            1.0
+        )
+    );
+    assert_eq!(
+        conditions[2].to_core().unwrap(),
+        Condition::calculation(
+            "(age + 5)",
+            legalis_core::ComparisonOp::GreaterOrEqual,
+            70.0
+        )
+    );
+
+    // The direct statute parser produces the same calculation
+    let statute = parser.parse_statute(input).unwrap();
+    assert_eq!(statute.preconditions[0], conditions[0].to_core().unwrap());
+
+    // Printing and re-parsing is lossless
+    let printed = crate::printer::format_document(&doc);
+    assert!(
+        printed.contains("income - deductions > 12 * monthly_rent"),
+        "{}",
+        printed
+    );
+    assert_eq!(
+        parser.parse_document(&printed).unwrap().statutes,
+        doc.statutes
+    );
+}
+
+#[test]
+fn test_let_bindings_inline_into_conditions() {
+    let input = r#"
+        LET net_income = income - deductions
+        DEFINE monthly_net: net_income / 12
+        STATUTE relief: "Relief" {
+            WHEN monthly_net < 2000 AND HAS resident
+            THEN GRANT "Relief"
+        }
+    "#;
+
+    let parser = LegalDslParser::new();
+    let doc = parser.parse_document(input).unwrap();
+    assert_eq!(doc.definitions.len(), 2);
+    assert_eq!(doc.definitions[1].name, "monthly_net");
+
+    let inlined = doc.inline_definitions().unwrap();
+    let ast::ConditionNode::And(monthly, _) = &inlined.statutes[0].conditions[0] else {
+        panic!("Expected AND condition");
+    };
+    assert_eq!(
+        monthly.to_core().unwrap(),
+        Condition::calculation(
+            "((income - deductions) / 12)",
+            legalis_core::ComparisonOp::LessThan,
+            2000.0
+        )
+    );
+
+    // Lowering the document inlines the bindings
+    let theory = doc.to_core().unwrap();
+    assert!(
+        theory.statutes()[0].preconditions[0]
+            .to_string()
+            .contains("income - deductions")
+    );
+
+    // LET lines survive a print / parse round trip
+    let printed = crate::printer::format_document(&doc);
+    assert!(
+        printed.contains("LET net_income = income - deductions"),
+        "{}",
+        printed
+    );
+    assert_eq!(parser.parse_document(&printed).unwrap(), doc);
+
+    // Cycles are rejected with the chain of names
+    let cyclic = r#"
+        LET a = b + 1
+        LET b = a * 2
+        STATUTE s: "S" { WHEN a > 0 THEN GRANT "X" }
+    "#;
+    let err = parser
+        .parse_document(cyclic)
+        .unwrap()
+        .inline_definitions()
+        .unwrap_err();
+    assert!(err.to_string().contains("a -> b -> a"), "{}", err);
+}
+
+#[test]
+fn test_expression_folding_and_type_errors() {
+    let parser = LegalDslParser::new();
+    let doc = parser
+        .parse_document(
+            r#"
+            STATUTE s: "S" {
+                WHEN income > 1000 * 12 + 500
+                WHEN fee * 2 <= 1.5 * 100 JPY
+                WHEN ratio < 1 / 3
+                THEN GRANT "X"
+            }
+        "#,
+        )
+        .unwrap();
+
+    let mut optimizer = crate::optimizer::Optimizer::new();
+    let folded = optimizer.fold_only(doc);
+    let conditions = &folded.statutes[0].conditions;
+    assert_eq!(
+        conditions[0],
+        ast::ConditionNode::Comparison {
+            field: "income".to_string(),
+            operator: ">".to_string(),
+            value: ast::ConditionValue::Number(12500),
+        }
+    );
+    let ast::ConditionNode::Expression { right, .. } = &conditions[1] else {
+        panic!("Expected expression, got {:?}", conditions[1]);
+    };
+    assert_eq!(
+        right,
+        &ast::ExprNode::Literal(ast::ConditionValue::Money {
+            amount: Decimal::from(150),
+            currency: "JPY".to_string(),
+        })
+    );
+    // 1 / 3 has no exact decimal form and is left alone
+    let ast::ConditionNode::Expression { right, .. } = &conditions[2] else {
+        panic!("Expected expression, got {:?}", conditions[2]);
+    };
+    assert!(matches!(right, ast::ExprNode::Binary { .. }));
+
+    let invalid = parser
+        .parse_document(
+            r#"
+            LET total = 300 JPY + 10 USD
+            STATUTE s: "S" { WHEN income - "abc" > 0 THEN GRANT "X" }
+        "#,
+        )
+        .unwrap();
+    let errors = TypeChecker::new().check_document(&invalid);
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert_eq!(errors[0].location, "definition total");
+    assert_eq!(errors[0].actual, Type::Money("USD".to_string()));
+    assert_eq!(errors[1].actual, Type::String);
+    assert!(invalid.statutes[0].conditions[0].to_core().is_err());
+}
+
 #[test]
 fn test_parse_nested_conditions() {
     let input = r#"
@@ -616,7 +1041,7 @@ fn test_amendment_clause() {
     assert_eq!(statute.amendments.len(), 1);
     assert_eq!(statute.amendments[0].target_id, "voting-rights");
     assert_eq!(statute.amendments[0].version, Some(3));
-    assert_eq!(statute.amendments[0].date, Some("2024-1-15".to_string()));
+    assert_eq!(statute.amendments[0].date, Some("2024-01-15".to_string()));
     assert_eq!(
         statute.amendments[0].description,
         "Lowered voting age to 16"
@@ -2720,6 +3145,7 @@ mod proptest_tests {
                 exports: vec![],
                 imports: vec![],
                 statutes,
+                definitions: Vec::new(),
             })
         }
 
@@ -209,6 +209,7 @@ impl DocumentTransform for DeduplicateStatutes {
             imports: doc.imports.clone(),
             exports: doc.exports.clone(),
             statutes: deduplicated,
+            definitions: doc.definitions.clone(),
         })
     }
 
@@ -273,6 +274,7 @@ impl DocumentTransform for SimplifyConditions {
             imports: doc.imports.clone(),
             exports: doc.exports.clone(),
             statutes: simplified_statutes,
+            definitions: doc.definitions.clone(),
         })
     }
 
@@ -298,6 +300,7 @@ impl DocumentTransform for RemoveEmptyStatutes {
             imports: doc.imports.clone(),
             exports: doc.exports.clone(),
             statutes: filtered,
+            definitions: doc.definitions.clone(),
         })
     }
 
@@ -375,6 +378,7 @@ impl DocumentTransform for SortByDependencies {
             imports: doc.imports.clone(),
             exports: doc.exports.clone(),
             statutes: sorted_statutes,
+            definitions: doc.definitions.clone(),
         })
     }
 
@@ -427,6 +431,7 @@ impl DocumentTransform for NormalizeIds {
             imports: doc.imports.clone(),
             exports: doc.exports.clone(),
             statutes: normalized,
+            definitions: doc.definitions.clone(),
         })
     }
 
@@ -523,6 +528,7 @@ mod tests {
                 sample_statute("B", vec![]),
                 sample_statute("A", vec![]), // duplicate
             ],
+            definitions: Vec::new(),
         };
 
         let transform = DeduplicateStatutes;
@@ -573,6 +579,7 @@ mod tests {
                     priority: None,
                 },
             ],
+            definitions: Vec::new(),
         };
 
         let transform = RemoveEmptyStatutes;
@@ -592,6 +599,7 @@ mod tests {
                 sample_statute("My_Statute", vec!["Other_Statute".to_string()]),
                 sample_statute("Other_Statute", vec![]),
             ],
+            definitions: Vec::new(),
         };
 
         let transform = NormalizeIds;
@@ -629,6 +637,7 @@ mod tests {
                     priority: None,
                 },
             ],
+            definitions: Vec::new(),
         };
 
         let pipeline = TransformPipeline::new()
@@ -654,6 +663,7 @@ mod tests {
                 sample_statute("A", vec![]),
                 sample_statute("A", vec![]), // duplicate
             ],
+            definitions: Vec::new(),
         };
 
         let mut history = TransformHistory::new(initial);
@@ -693,6 +703,7 @@ mod tests {
             exports: vec![],
             imports: vec![],
             statutes: vec![sample_statute("A", vec![])],
+            definitions: Vec::new(),
         };
 
         let pipeline = TransformPipeline::new()
@@ -736,6 +747,7 @@ mod tests {
                     priority: None,
                 },
             ],
+            definitions: Vec::new(),
         };
 
         let pipeline = cleanup_pipeline();
@@ -757,6 +769,7 @@ mod tests {
                 sample_statute("My_Statute", vec![]),
                 sample_statute("My_Statute", vec![]), // duplicate
             ],
+            definitions: Vec::new(),
         };
 
         let pipeline = full_pipeline();
@@ -300,6 +300,20 @@ impl TreeFormatter {
                     self.format_value(value)
                 ));
             }
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => {
+                output.push_str(&format!(
+                    "{}{}{} {} {}\n",
+                    prefix,
+                    branch_prefix,
+                    self.colorize(&left.to_string(), Color::Cyan, false),
+                    operator,
+                    right
+                ));
+            }
             ConditionNode::Between { field, min, max } => {
                 output.push_str(&format!(
                     "{}{}{} BETWEEN {} AND {}\n",
@@ -331,6 +345,33 @@ impl TreeFormatter {
                     self.colorize(key, Color::Cyan, false)
                 ));
             }
+            ConditionNode::StatuteApplies { statute_id } => {
+                output.push_str(&format!(
+                    "{}{}APPLIES {}\n",
+                    prefix,
+                    branch_prefix,
+                    self.colorize(statute_id, Color::Cyan, false)
+                ));
+            }
+            ConditionNode::StatuteOutput {
+                statute_id,
+                output: output_name,
+                operator,
+                value,
+            } => {
+                output.push_str(&format!(
+                    "{}{}{} {} {}\n",
+                    prefix,
+                    branch_prefix,
+                    self.colorize(
+                        &format!("{}.{}", statute_id, output_name),
+                        Color::Cyan,
+                        false
+                    ),
+                    operator,
+                    self.format_value(value)
+                ));
+            }
             ConditionNode::And(left, right) => {
                 output.push_str(&format!("{}{}AND\n", prefix, branch_prefix));
                 let child_prefix = format!("{}{}", prefix, continuation_prefix);
@@ -493,6 +534,9 @@ impl TreeFormatter {
     fn format_value(&self, value: &ConditionValue) -> String {
         match value {
             ConditionValue::Number(n) => n.to_string(),
+            ConditionValue::Decimal(d) => d.to_string(),
+            ConditionValue::Money { amount, currency } => format!("{} {}", amount, currency),
+            ConditionValue::Duration { value, unit } => format!("{} {}", value, unit),
             ConditionValue::String(s) => format!("\"{}\"", s),
             ConditionValue::Boolean(b) => b.to_string(),
             ConditionValue::Date(d) => d.clone(),
@@ -565,6 +609,7 @@ mod tests {
                 constraints: vec![],
                 priority: None,
             }],
+            definitions: Vec::new(),
         };
 
         let formatter = TreeFormatter::new();
@@ -599,6 +644,7 @@ mod tests {
                 constraints: vec![],
                 priority: None,
             }],
+            definitions: Vec::new(),
         };
 
         let formatter = TreeFormatter::new().with_color(true);
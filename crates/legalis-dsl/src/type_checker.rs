@@ -3,7 +3,7 @@
 //! This module provides type checking and inference for legal document conditions,
 //! ensuring type safety and catching common errors at compile time.
 
-use crate::ast::{ConditionNode, ConditionValue, LegalDocument, StatuteNode};
+use crate::ast::{ArithOp, ConditionNode, ConditionValue, ExprNode, LegalDocument, StatuteNode};
 use std::collections::HashMap;
 
 /// Type information for a value.
@@ -21,6 +21,10 @@ pub enum Type {
     Boolean,
     /// Date type
     Date,
+    /// Money in a specific currency (ISO 4217 code)
+    Money(String),
+    /// Duration type (e.g. `6 months`)
+    Duration,
     /// Set expression type
     Set(Box<Type>),
     /// Enum type with named variants - v0.1.3
@@ -48,6 +52,11 @@ impl Type {
             // Number is compatible with Integer and Decimal (v0.1.3)
             (Type::Number, Type::Integer) | (Type::Integer, Type::Number) => true,
             (Type::Number, Type::Decimal) | (Type::Decimal, Type::Number) => true,
+            // Amounts in different currencies never compare; bare numbers are unitless
+            (Type::Money(c1), Type::Money(c2)) => c1 == c2,
+            (Type::Money(_), Type::Number | Type::Integer | Type::Decimal)
+            | (Type::Number | Type::Integer | Type::Decimal, Type::Money(_)) => true,
+            (Type::Duration, Type::Number) | (Type::Number, Type::Duration) => true,
             // Enum compatibility - same name means compatible (v0.1.3)
             (Type::Enum { name: n1, .. }, Type::Enum { name: n2, .. }) => n1 == n2,
             // Alias is compatible with anything for now (should resolve first) (v0.1.3)
@@ -66,6 +75,8 @@ impl std::fmt::Display for Type {
             Type::String => write!(f, "String"),
             Type::Boolean => write!(f, "Boolean"),
             Type::Date => write!(f, "Date"),
+            Type::Money(currency) => write!(f, "Money<{}>", currency),
+            Type::Duration => write!(f, "Duration"),
             Type::Set(inner) => write!(f, "Set<{}>", inner),
             Type::Enum { name, variants } => {
                 write!(f, "{}: ", name)?;
@@ -225,6 +236,9 @@ impl TypeContext {
     pub fn infer_value_type(&self, value: &ConditionValue) -> Type {
         match value {
             ConditionValue::Number(_) => Type::Number,
+            ConditionValue::Decimal(_) => Type::Decimal,
+            ConditionValue::Money { currency, .. } => Type::Money(currency.clone()),
+            ConditionValue::Duration { .. } => Type::Duration,
             ConditionValue::String(_) => Type::String,
             ConditionValue::Boolean(_) => Type::Boolean,
             ConditionValue::Date(_) => Type::Date,
@@ -232,6 +246,28 @@ impl TypeContext {
         }
     }
 
+    /// Infers the result type of an arithmetic expression.
+    ///
+    /// On failure returns the expected and actual operand types: strings and
+    /// booleans take no part in arithmetic, and amounts in different
+    /// currencies never combine.
+    pub fn infer_expr_type(&self, expr: &ExprNode) -> Result<Type, (Type, Type)> {
+        let ty = match expr {
+            ExprNode::Field(name) => self.get_field_type(name),
+            ExprNode::Literal(value) => self.infer_value_type(value),
+            ExprNode::Negate(inner) => self.infer_expr_type(inner)?,
+            ExprNode::Binary { op, left, right } => {
+                let left = self.infer_expr_type(left)?;
+                let right = self.infer_expr_type(right)?;
+                return arithmetic_result(*op, left, right);
+            }
+        };
+        match ty {
+            Type::String | Type::Boolean | Type::Set(_) => Err((Type::Number, ty)),
+            ty => Ok(ty),
+        }
+    }
+
     /// Infers types from a condition and updates the context.
     pub fn infer_from_condition(&mut self, condition: &ConditionNode) {
         match condition {
@@ -241,6 +277,12 @@ impl TypeContext {
 
                 if existing_type == Type::Unknown {
                     self.register_field(field.clone(), value_type);
+                } else if matches!(
+                    (&existing_type, &value_type),
+                    (Type::Money(_), Type::Money(_))
+                ) {
+                    // A monetary field keeps its first currency so that
+                    // comparisons in another currency are reported
                 } else if !existing_type.is_compatible_with(&value_type) {
                     // Upgrade to union type
                     self.register_field(
@@ -284,11 +326,63 @@ impl TypeContext {
             ConditionNode::Not(inner) => {
                 self.infer_from_condition(inner);
             }
+            ConditionNode::Expression { left, right, .. } => {
+                // A lone field takes the type of the other side
+                for (side, other) in [(left, right), (right, left)] {
+                    if let ExprNode::Field(field) = side
+                        && self.get_field_type(field) == Type::Unknown
+                        && let Ok(ty) = self.infer_expr_type(other)
+                    {
+                        self.register_field(field.clone(), ty);
+                    }
+                }
+            }
             _ => {}
         }
     }
 }
 
+/// Result type of `left op right`, or the mismatched operand types.
+fn arithmetic_result(op: ArithOp, left: Type, right: Type) -> Result<Type, (Type, Type)> {
+    let is_numeric = |ty: &Type| matches!(ty, Type::Number | Type::Integer | Type::Decimal);
+    for ty in [&left, &right] {
+        if matches!(ty, Type::String | Type::Boolean | Type::Set(_)) {
+            return Err((Type::Number, ty.clone()));
+        }
+    }
+    match (left, right) {
+        (Type::Unknown, ty) | (ty, Type::Unknown) => Ok(ty),
+        (Type::Money(c1), Type::Money(c2)) => match op {
+            _ if c1 != c2 => Err((Type::Money(c1), Type::Money(c2))),
+            ArithOp::Add | ArithOp::Sub => Ok(Type::Money(c1)),
+            ArithOp::Div => Ok(Type::Decimal),
+            ArithOp::Mul => Err((Type::Number, Type::Money(c2))),
+        },
+        (Type::Money(c), ty) | (ty, Type::Money(c)) if is_numeric(&ty) => Ok(Type::Money(c)),
+        (Type::Date, Type::Date) if op == ArithOp::Sub => Ok(Type::Number),
+        (Type::Date, ty) if matches!(op, ArithOp::Add | ArithOp::Sub) => {
+            if is_numeric(&ty) || ty == Type::Duration {
+                Ok(Type::Date)
+            } else {
+                Err((Type::Duration, ty))
+            }
+        }
+        (Type::Duration, Type::Duration) if matches!(op, ArithOp::Add | ArithOp::Sub) => {
+            Ok(Type::Duration)
+        }
+        (Type::Duration, ty) | (ty, Type::Duration) if is_numeric(&ty) => Ok(Type::Duration),
+        (Type::Integer, Type::Integer) if op != ArithOp::Div => Ok(Type::Integer),
+        (Type::Decimal, r) if is_numeric(&r) => Ok(Type::Decimal),
+        (l, Type::Decimal) if is_numeric(&l) => Ok(Type::Decimal),
+        (l, r) if is_numeric(&l) && is_numeric(&r) => Ok(if op == ArithOp::Div {
+            Type::Decimal
+        } else {
+            Type::Number
+        }),
+        (l, r) => Err((l, r)),
+    }
+}
+
 impl Default for TypeContext {
     fn default() -> Self {
         Self::new()
@@ -312,6 +406,19 @@ impl TypeChecker {
     pub fn check_document(&mut self, doc: &LegalDocument) -> Vec<TypeError> {
         let mut errors = Vec::new();
 
+        // LET bindings behave as fields of their expression's type
+        for definition in &doc.definitions {
+            match self.context.infer_expr_type(&definition.expr) {
+                Ok(ty) => self.context.register_field(definition.name.clone(), ty),
+                Err((expected, actual)) => errors.push(TypeError {
+                    location: format!("definition {}", definition.name),
+                    message: "Incompatible operands in arithmetic expression".to_string(),
+                    expected,
+                    actual,
+                }),
+            }
+        }
+
         for statute in &doc.statutes {
             errors.extend(self.check_statute(statute));
         }
@@ -406,6 +513,35 @@ impl TypeChecker {
             ConditionNode::Not(inner) => {
                 errors.extend(self.check_condition(inner, statute_id));
             }
+            ConditionNode::Expression {
+                left,
+                operator,
+                right,
+            } => {
+                let location = format!("statute {} expression {}", statute_id, left);
+                let mut side_types = Vec::new();
+                for side in [left, right] {
+                    match self.context.infer_expr_type(side) {
+                        Ok(ty) => side_types.push(ty),
+                        Err((expected, actual)) => errors.push(TypeError {
+                            location: location.clone(),
+                            message: "Incompatible operands in arithmetic expression".to_string(),
+                            expected,
+                            actual,
+                        }),
+                    }
+                }
+                if let [left_type, right_type] = side_types.as_slice()
+                    && !left_type.is_compatible_with(right_type)
+                {
+                    errors.push(TypeError {
+                        location,
+                        message: format!("Type mismatch in comparison with {}", operator),
+                        expected: left_type.clone(),
+                        actual: right_type.clone(),
+                    });
+                }
+            }
             _ => {}
         }
 
@@ -704,6 +704,7 @@ mod tests {
                     ..Default::default()
                 },
             ],
+            definitions: Vec::new(),
         };
 
         let validator = SemanticValidator::new();
@@ -731,6 +732,7 @@ mod tests {
                 requires: vec!["nonexistent".to_string()],
                 ..Default::default()
             }],
+            definitions: Vec::new(),
         };
 
         let validator = SemanticValidator::new();
@@ -758,6 +760,7 @@ mod tests {
                 requires: vec!["statute1".to_string()],
                 ..Default::default()
             }],
+            definitions: Vec::new(),
         };
 
         let validator = SemanticValidator::new();
@@ -812,6 +815,7 @@ mod tests {
                 }],
                 ..Default::default()
             }],
+            definitions: Vec::new(),
         };
 
         let detector = DeadCodeDetector::new();
@@ -858,6 +862,7 @@ mod tests {
                     ..Default::default()
                 },
             ],
+            definitions: Vec::new(),
         };
 
         let detector = DeadCodeDetector::new();
@@ -908,6 +913,7 @@ mod tests {
                     ..Default::default()
                 },
             ],
+            definitions: Vec::new(),
         };
 
         let detector = DeadCodeDetector::new();
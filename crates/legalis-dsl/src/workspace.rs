@@ -0,0 +1,1088 @@
+//! Workspace model for multi-file legal document projects.
+//!
+//! A [`Workspace`] indexes the statute IDs and field names used in every file
+//! it knows about and follows IMPORT statements through the
+//! [`ImportResolver`]. It answers definition, reference and rename queries
+//! across files, and checks a file by running the type checker and
+//! `legalis-verifier` over the statutes in its scope, mapping their findings
+//! back to source spans. The language server is built on top of it.
+
+use crate::import_resolver::{ImportResolver, detect_circular_imports};
+use crate::type_checker::TypeChecker;
+use crate::{
+    DslError, DslResult, LegalDocument, LegalDslParser, SourceLocation, SourceSpan, StatuteNode,
+    ToCore, Token,
+};
+use legalis_verifier::{
+    CrossReferenceErrorType, Severity, StatuteVerifier, VerificationError,
+    detect_statute_conflicts, validate_cross_references,
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Component, Path, PathBuf};
+
+/// File extensions picked up when scanning a directory.
+pub const SOURCE_EXTENSIONS: &[&str] = &["legalis", "legal"];
+
+/// Kind of symbol tracked by the workspace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolKind {
+    /// A statute ID
+    Statute,
+    /// A field or LET binding used in conditions
+    Field,
+}
+
+/// How an occurrence uses its symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolRole {
+    /// `STATUTE id`, `LET name` or `DEFAULT name`
+    Definition,
+    /// Named in REQUIRES, SUPERSEDES, AMENDMENT, EXPORT or a selective IMPORT
+    Dependency,
+    /// Used inside a condition
+    Condition,
+}
+
+/// A statute ID or field name at a position in a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Occurrence {
+    /// Symbol name
+    pub name: String,
+    /// Symbol kind
+    pub kind: SymbolKind,
+    /// How the symbol is used
+    pub role: SymbolRole,
+    /// Statute whose body contains the occurrence
+    pub statute: Option<String>,
+    /// Source span of the name
+    pub span: SourceSpan,
+}
+
+impl Occurrence {
+    /// Returns `true` if the 1-indexed position lies on the name.
+    pub fn contains(&self, line: usize, column: usize) -> bool {
+        self.span.start.line == line
+            && self.span.start.column <= column
+            && column <= self.span.end.column
+    }
+}
+
+/// A span in a specific file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// File path
+    pub path: PathBuf,
+    /// Source span
+    pub span: SourceSpan,
+}
+
+/// An IMPORT statement of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportRef {
+    /// Path as written in the source
+    pub raw: String,
+    /// Resolved file path
+    pub target: PathBuf,
+    /// Source span of the path literal
+    pub span: SourceSpan,
+}
+
+/// Index of a single file.
+#[derive(Debug, Clone)]
+pub struct FileIndex {
+    text: String,
+    document: Option<LegalDocument>,
+    occurrences: Vec<Occurrence>,
+    imports: Vec<ImportRef>,
+}
+
+impl FileIndex {
+    /// Indexes the text of a file located at `path`.
+    fn new(path: &Path, text: String) -> Self {
+        let parser = LegalDslParser::new();
+        let document = parser.parse_document(&text).ok();
+        let tokens = parser.tokenize(&blank_comments(&text)).unwrap_or_default();
+        let occurrences = collect_occurrences(&tokens);
+
+        let resolver = ImportResolver::new(path.parent().unwrap_or(Path::new("")));
+        let imports = tokens
+            .windows(2)
+            .filter(|pair| matches!(pair[0].token, Token::Import | Token::From))
+            .filter_map(|pair| {
+                let (raw, width) = match &pair[1].token {
+                    Token::StringLit(raw) => (raw.clone(), raw.chars().count() + 2),
+                    Token::Ident(raw) => (raw.clone(), raw.chars().count()),
+                    _ => return None,
+                };
+                Some(ImportRef {
+                    target: normalize(&resolver.resolve_path(&raw)),
+                    span: span_at(pair[1].location, width),
+                    raw,
+                })
+            })
+            .collect();
+
+        Self {
+            text,
+            document,
+            occurrences,
+            imports,
+        }
+    }
+
+    /// Returns the source text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the parsed document, if the file parses.
+    pub fn document(&self) -> Option<&LegalDocument> {
+        self.document.as_ref()
+    }
+
+    /// Returns all symbol occurrences in source order.
+    pub fn occurrences(&self) -> &[Occurrence] {
+        &self.occurrences
+    }
+
+    /// Returns the IMPORT statements.
+    pub fn imports(&self) -> &[ImportRef] {
+        &self.imports
+    }
+
+    /// Returns the occurrence at a 1-indexed position.
+    pub fn occurrence_at(&self, line: usize, column: usize) -> Option<&Occurrence> {
+        self.occurrences.iter().find(|o| o.contains(line, column))
+    }
+
+    /// Returns the definition span of a statute declared in this file.
+    pub fn statute_span(&self, statute_id: &str) -> Option<SourceSpan> {
+        self.occurrences
+            .iter()
+            .find(|o| {
+                o.kind == SymbolKind::Statute
+                    && o.role == SymbolRole::Definition
+                    && o.name == statute_id
+            })
+            .map(|o| o.span)
+    }
+
+    /// Returns the statutes declared in this file.
+    pub fn statutes(&self) -> &[StatuteNode] {
+        self.document
+            .as_ref()
+            .map(|doc| doc.statutes.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+/// Origin of a [`WorkspaceDiagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSource {
+    /// Import and reference resolution
+    Workspace,
+    /// The type checker
+    TypeChecker,
+    /// `legalis-verifier`
+    Verifier,
+}
+
+impl DiagnosticSource {
+    /// Returns the name reported to editors.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Workspace => "legalis-workspace",
+            Self::TypeChecker => "legalis-types",
+            Self::Verifier => "legalis-verifier",
+        }
+    }
+}
+
+/// A problem found in a file.
+#[derive(Debug, Clone)]
+pub struct WorkspaceDiagnostic {
+    /// Where the problem is reported
+    pub span: SourceSpan,
+    /// Severity of the problem
+    pub severity: Severity,
+    /// Which check found the problem
+    pub source: DiagnosticSource,
+    /// Description of the problem
+    pub message: String,
+    /// Verification error behind the diagnostic, for quick fixes
+    pub error: Option<VerificationError>,
+    /// Text that fixes the problem when it replaces the span
+    pub replacement: Option<String>,
+}
+
+impl WorkspaceDiagnostic {
+    fn new(
+        span: SourceSpan,
+        severity: Severity,
+        source: DiagnosticSource,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            span,
+            severity,
+            source,
+            message: message.into(),
+            error: None,
+            replacement: None,
+        }
+    }
+}
+
+/// Indexed files of a multi-file project.
+///
+/// # Example
+///
+/// ```
+/// use legalis_dsl::workspace::Workspace;
+///
+/// let mut workspace = Workspace::new();
+/// workspace.update(
+///     "/project/benefits.legalis",
+///     r#"STATUTE child-benefit: "Child Benefit" {
+///         WHEN AGE < 18
+///         THEN GRANT "Monthly allowance"
+///     }
+///     STATUTE top-up: "Top-up" {
+///         REQUIRES child-benefit
+///         THEN GRANT "Additional allowance"
+///     }"#,
+/// );
+///
+/// // Line 6 is `REQUIRES child-benefit`
+/// let definition = workspace
+///     .definition("/project/benefits.legalis", 6, 20)
+///     .unwrap();
+/// assert_eq!(definition.span.start.line, 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Workspace {
+    files: HashMap<PathBuf, FileIndex>,
+}
+
+impl Workspace {
+    /// Creates an empty workspace.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Indexes every legal document below a directory.
+    ///
+    /// Files already known to the workspace keep their current text, so
+    /// unsaved editor buffers win over the copies on disk. Returns the
+    /// number of newly indexed files.
+    pub fn scan(&mut self, root: impl AsRef<Path>) -> usize {
+        let mut pending = vec![root.as_ref().to_path_buf()];
+        let before = self.files.len();
+        while let Some(dir) = pending.pop() {
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                if path.is_dir() {
+                    if !name.starts_with('.') && name != "target" && name != "node_modules" {
+                        pending.push(path);
+                    }
+                } else if is_source_file(&path) && !self.files.contains_key(&normalize(&path)) {
+                    self.load(&path);
+                }
+            }
+        }
+        self.files.len() - before
+    }
+
+    /// Indexes new text for a file and loads the files it imports.
+    pub fn update(&mut self, path: impl AsRef<Path>, text: impl Into<String>) {
+        let path = normalize(path.as_ref());
+        let index = FileIndex::new(&path, text.into());
+        let imports: Vec<PathBuf> = index.imports.iter().map(|i| i.target.clone()).collect();
+        self.files.insert(path, index);
+        self.load_missing(imports);
+    }
+
+    /// Re-reads a file from disk, dropping it if it no longer exists.
+    pub fn reload(&mut self, path: impl AsRef<Path>) {
+        let path = normalize(path.as_ref());
+        if !self.load(&path) {
+            self.files.remove(&path);
+        }
+    }
+
+    /// Drops a file from the workspace.
+    pub fn remove(&mut self, path: impl AsRef<Path>) {
+        self.files.remove(&normalize(path.as_ref()));
+    }
+
+    /// Returns the index of a file.
+    pub fn file(&self, path: impl AsRef<Path>) -> Option<&FileIndex> {
+        self.files.get(&normalize(path.as_ref()))
+    }
+
+    /// Returns the indexed file paths in sorted order.
+    pub fn paths(&self) -> Vec<&Path> {
+        let mut paths: Vec<&Path> = self.files.keys().map(PathBuf::as_path).collect();
+        paths.sort();
+        paths
+    }
+
+    /// Returns a file followed by everything it imports, transitively.
+    pub fn scope(&self, path: impl AsRef<Path>) -> Vec<&Path> {
+        let path = normalize(path.as_ref());
+        let mut seen = HashSet::new();
+        let mut order = Vec::new();
+        let mut queue = VecDeque::from([path]);
+        while let Some(next) = queue.pop_front() {
+            let Some((key, index)) = self.files.get_key_value(&next) else {
+                continue;
+            };
+            if !seen.insert(key.as_path()) {
+                continue;
+            }
+            order.push(key.as_path());
+            queue.extend(index.imports.iter().map(|i| i.target.clone()));
+        }
+        order
+    }
+
+    /// Returns the statutes visible from a file: its own and imported ones.
+    pub fn statutes_in_scope(&self, path: impl AsRef<Path>) -> Vec<&StatuteNode> {
+        self.scope(path)
+            .into_iter()
+            .filter_map(|p| self.files.get(p))
+            .flat_map(|index| index.statutes())
+            .collect()
+    }
+
+    /// Returns the symbol at a 1-indexed position.
+    pub fn occurrence_at(
+        &self,
+        path: impl AsRef<Path>,
+        line: usize,
+        column: usize,
+    ) -> Option<&Occurrence> {
+        self.file(path)?.occurrence_at(line, column)
+    }
+
+    /// Finds where the symbol at a position is defined.
+    ///
+    /// The file's own scope is searched first, then the rest of the
+    /// workspace.
+    pub fn definition(
+        &self,
+        path: impl AsRef<Path>,
+        line: usize,
+        column: usize,
+    ) -> Option<Location> {
+        let path = path.as_ref();
+        let symbol = self.occurrence_at(path, line, column)?;
+        let scope = self.scope(path);
+        let rest = self.paths().into_iter().filter(|p| !scope.contains(p));
+        scope.iter().copied().chain(rest).find_map(|candidate| {
+            let index = self.files.get(candidate)?;
+            index
+                .occurrences
+                .iter()
+                .find(|o| {
+                    o.role == SymbolRole::Definition
+                        && o.kind == symbol.kind
+                        && o.name == symbol.name
+                })
+                .map(|o| Location {
+                    path: candidate.to_path_buf(),
+                    span: o.span,
+                })
+        })
+    }
+
+    /// Finds every use of the symbol at a position across the workspace.
+    pub fn references(
+        &self,
+        path: impl AsRef<Path>,
+        line: usize,
+        column: usize,
+        include_definitions: bool,
+    ) -> Vec<Location> {
+        let Some(symbol) = self.occurrence_at(path, line, column) else {
+            return Vec::new();
+        };
+        self.paths()
+            .into_iter()
+            .flat_map(|candidate| {
+                self.files[candidate]
+                    .occurrences
+                    .iter()
+                    .filter(|o| o.kind == symbol.kind && o.name == symbol.name)
+                    .filter(|o| include_definitions || o.role != SymbolRole::Definition)
+                    .map(|o| Location {
+                        path: candidate.to_path_buf(),
+                        span: o.span,
+                    })
+            })
+            .collect()
+    }
+
+    /// Computes the edits that rename the symbol at a position.
+    ///
+    /// Fails if there is no symbol at the position or the new name is not a
+    /// valid identifier.
+    pub fn rename(
+        &self,
+        path: impl AsRef<Path>,
+        line: usize,
+        column: usize,
+        new_name: &str,
+    ) -> DslResult<Vec<Location>> {
+        let path = path.as_ref();
+        if self.occurrence_at(path, line, column).is_none() {
+            return Err(DslError::parse_error_at(
+                line,
+                column,
+                "No statute or field at this position",
+            ));
+        }
+        let tokens = LegalDslParser::new().tokenize(new_name)?;
+        if !matches!(tokens.as_slice(), [token] if token.token == Token::Ident(new_name.to_string()))
+        {
+            return Err(DslError::parse_error(format!(
+                "'{}' is not a valid identifier",
+                new_name
+            )));
+        }
+        Ok(self.references(path, line, column, true))
+    }
+
+    /// Finds statute definitions whose ID or title contains the query.
+    pub fn symbols(&self, query: &str) -> Vec<(Location, &StatuteNode)> {
+        let query = &query.to_lowercase();
+        self.paths()
+            .into_iter()
+            .flat_map(|path| {
+                let index = &self.files[path];
+                index.statutes().iter().filter_map(move |statute| {
+                    let matches = statute.id.to_lowercase().contains(query)
+                        || statute.title.to_lowercase().contains(query);
+                    let span = index.statute_span(&statute.id)?;
+                    matches.then(|| {
+                        (
+                            Location {
+                                path: path.to_path_buf(),
+                                span,
+                            },
+                            statute,
+                        )
+                    })
+                })
+            })
+            .collect()
+    }
+
+    /// Checks imports and statute dependencies of a file.
+    ///
+    /// This is cheap enough to run on every edit.
+    pub fn reference_diagnostics(&self, path: impl AsRef<Path>) -> Vec<WorkspaceDiagnostic> {
+        let path = normalize(path.as_ref());
+        let Some(index) = self.files.get(&path) else {
+            return Vec::new();
+        };
+        let mut diagnostics = Vec::new();
+
+        for import in &index.imports {
+            if !self.files.contains_key(&import.target) && !import.target.is_file() {
+                diagnostics.push(WorkspaceDiagnostic::new(
+                    import.span,
+                    Severity::Error,
+                    DiagnosticSource::Workspace,
+                    format!("Import path does not exist: {:?}", import.raw),
+                ));
+            }
+        }
+        if let Some(doc) = &index.document {
+            let base_dir = path.parent().unwrap_or(Path::new(""));
+            for cycle in detect_circular_imports(doc, base_dir) {
+                let Some(import) = index.imports.iter().find(|i| Some(&i.raw) == cycle.first())
+                else {
+                    continue;
+                };
+                diagnostics.push(WorkspaceDiagnostic::new(
+                    import.span,
+                    Severity::Error,
+                    DiagnosticSource::Workspace,
+                    format!("Circular import: {}", cycle.join(" -> ")),
+                ));
+            }
+        }
+
+        let known = self.statute_ids_in_scope(&path);
+        for occurrence in &index.occurrences {
+            if occurrence.kind == SymbolKind::Statute
+                && occurrence.role == SymbolRole::Dependency
+                && !known.contains(occurrence.name.as_str())
+            {
+                diagnostics.push(self.unknown_statute(occurrence.span, &occurrence.name, &known));
+            }
+        }
+
+        diagnostics
+    }
+
+    /// Runs every check on a file: references, types and the verifier.
+    ///
+    /// The verifier sees all statutes in scope of the file, but only
+    /// findings about statutes declared in the file itself are reported.
+    pub fn check(&self, path: impl AsRef<Path>) -> Vec<WorkspaceDiagnostic> {
+        let path = normalize(path.as_ref());
+        let mut diagnostics = self.reference_diagnostics(&path);
+        let Some(index) = self.files.get(&path) else {
+            return diagnostics;
+        };
+        let Some(doc) = &index.document else {
+            return diagnostics;
+        };
+
+        for error in TypeChecker::new().check_document(doc) {
+            diagnostics.push(WorkspaceDiagnostic::new(
+                type_error_span(index, &error.location),
+                Severity::Error,
+                DiagnosticSource::TypeChecker,
+                error.to_string(),
+            ));
+        }
+
+        let statutes: Vec<legalis_core::Statute> = self
+            .statutes_in_scope(&path)
+            .into_iter()
+            .filter_map(|node| node.to_core().ok())
+            .collect();
+        let local: Vec<&str> = index.statutes().iter().map(|s| s.id.as_str()).collect();
+        let local_spans = |text: &str| {
+            local
+                .iter()
+                .filter(|id| mentions(text, id))
+                .filter_map(|id| index.statute_span(id))
+                .collect::<Vec<_>>()
+        };
+
+        let result = StatuteVerifier::new().verify(&statutes);
+        for error in &result.errors {
+            for span in local_spans(&error.to_string()) {
+                let mut diagnostic = WorkspaceDiagnostic::new(
+                    span,
+                    error.severity(),
+                    DiagnosticSource::Verifier,
+                    error.to_string(),
+                );
+                diagnostic.error = Some(error.clone());
+                diagnostics.push(diagnostic);
+            }
+        }
+        for warning in &result.warnings {
+            for span in local_spans(warning) {
+                diagnostics.push(WorkspaceDiagnostic::new(
+                    span,
+                    Severity::Warning,
+                    DiagnosticSource::Verifier,
+                    warning.clone(),
+                ));
+            }
+        }
+
+        for conflict in detect_statute_conflicts(&statutes) {
+            let mut message = conflict.description.clone();
+            if let Some(suggestion) = conflict.resolution_suggestions.first() {
+                message = format!("{} ({})", message, suggestion);
+            }
+            for id in conflict
+                .statute_ids
+                .iter()
+                .filter(|id| local.contains(&id.as_str()))
+            {
+                if let Some(span) = index.statute_span(id) {
+                    diagnostics.push(WorkspaceDiagnostic::new(
+                        span,
+                        conflict.severity,
+                        DiagnosticSource::Verifier,
+                        message.clone(),
+                    ));
+                }
+            }
+        }
+
+        let known = self.statute_ids_in_scope(&path);
+        for error in validate_cross_references(&statutes) {
+            if error.error_type != CrossReferenceErrorType::NotFound
+                || !local.contains(&error.source_statute_id.as_str())
+            {
+                continue;
+            }
+            let span = index
+                .occurrences
+                .iter()
+                .find(|o| {
+                    o.kind == SymbolKind::Statute
+                        && o.role == SymbolRole::Condition
+                        && o.name == error.referenced_statute_id
+                        && o.statute.as_deref() == Some(error.source_statute_id.as_str())
+                })
+                .map(|o| o.span)
+                .or_else(|| index.statute_span(&error.source_statute_id));
+            if let Some(span) = span {
+                let mut diagnostic =
+                    self.unknown_statute(span, &error.referenced_statute_id, &known);
+                diagnostic.source = DiagnosticSource::Verifier;
+                diagnostic.message = error.to_string();
+                diagnostics.push(diagnostic);
+            }
+        }
+
+        diagnostics
+    }
+
+    /// Reads and indexes a file from disk.
+    fn load(&mut self, path: &Path) -> bool {
+        match std::fs::read_to_string(path) {
+            Ok(text) => {
+                self.update(path, text);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Loads imported files that are not indexed yet.
+    fn load_missing(&mut self, mut pending: Vec<PathBuf>) {
+        while let Some(path) = pending.pop() {
+            if self.files.contains_key(&path) {
+                continue;
+            }
+            let Ok(text) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            let index = FileIndex::new(&path, text);
+            pending.extend(index.imports.iter().map(|i| i.target.clone()));
+            self.files.insert(path, index);
+        }
+    }
+
+    fn statute_ids_in_scope(&self, path: &Path) -> HashSet<&str> {
+        self.statutes_in_scope(path)
+            .into_iter()
+            .map(|s| s.id.as_str())
+            .collect()
+    }
+
+    /// Reports an unknown statute, suggesting the closest known ID.
+    fn unknown_statute(
+        &self,
+        span: SourceSpan,
+        name: &str,
+        known: &HashSet<&str>,
+    ) -> WorkspaceDiagnostic {
+        let suggestion = known
+            .iter()
+            .map(|id| (crate::levenshtein_distance(name, id), *id))
+            .filter(|(distance, _)| *distance <= 2)
+            .min()
+            .map(|(_, id)| id.to_string());
+        let mut diagnostic = WorkspaceDiagnostic::new(
+            span,
+            Severity::Error,
+            DiagnosticSource::Workspace,
+            match &suggestion {
+                Some(id) => format!("Unknown statute '{}' (did you mean '{}'?)", name, id),
+                None => format!("Unknown statute '{}'", name),
+            },
+        );
+        diagnostic.replacement = suggestion;
+        diagnostic
+    }
+}
+
+/// Returns `true` if the path has a legal document extension.
+pub fn is_source_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| SOURCE_EXTENSIONS.contains(&ext))
+}
+
+/// Removes `.` and `..` components without touching the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Replaces comments with spaces so token positions match the source.
+fn blank_comments(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match (ch, chars.peek()) {
+            ('/', Some('/')) => {
+                while chars.peek().is_some_and(|&c| c != '\n') {
+                    chars.next();
+                    result.push(' ');
+                }
+                result.push(' ');
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                result.push_str("  ");
+                while let Some(c) = chars.next() {
+                    if c == '*' && chars.peek() == Some(&'/') {
+                        chars.next();
+                        result.push_str("  ");
+                        break;
+                    }
+                    result.push(if c == '\n' { '\n' } else { ' ' });
+                }
+            }
+            _ => result.push(ch),
+        }
+    }
+    result
+}
+
+fn span_at(start: SourceLocation, width: usize) -> SourceSpan {
+    SourceSpan::new(
+        start,
+        SourceLocation::new(start.line, start.column + width, start.offset + width),
+    )
+}
+
+/// Classifies identifier tokens as statute and field occurrences.
+fn collect_occurrences(tokens: &[crate::SpannedToken]) -> Vec<Occurrence> {
+    let mut occurrences = Vec::new();
+    let mut statute: Option<String> = None;
+    let mut depth = 0usize;
+    // Inside a REQUIRES, SUPERSEDES, EXPORT or selective IMPORT list
+    let mut list = false;
+    let mut braced_list = false;
+
+    for (i, spanned) in tokens.iter().enumerate() {
+        let prev = i.checked_sub(1).map(|p| &tokens[p].token);
+        let next = tokens.get(i + 1).map(|t| &t.token);
+
+        let name = match &spanned.token {
+            Token::Requires | Token::Supersedes | Token::Export => {
+                list = true;
+                continue;
+            }
+            Token::Import if next == Some(&Token::LBrace) => {
+                list = true;
+                continue;
+            }
+            Token::LBrace if list && matches!(prev, Some(Token::Import | Token::Export)) => {
+                braced_list = true;
+                continue;
+            }
+            Token::RBrace if braced_list => {
+                list = false;
+                braced_list = false;
+                continue;
+            }
+            Token::Comma if list => continue,
+            Token::Ident(name) => name,
+            other => {
+                match other {
+                    Token::LBrace => depth += 1,
+                    Token::RBrace => {
+                        depth = depth.saturating_sub(1);
+                        if depth == 0 {
+                            statute = None;
+                        }
+                    }
+                    _ => {}
+                }
+                if !braced_list {
+                    list = false;
+                }
+                continue;
+            }
+        };
+
+        let classified = if list {
+            Some((SymbolKind::Statute, SymbolRole::Dependency))
+        } else {
+            match (prev, next) {
+                (Some(Token::Statute), _) => {
+                    statute = Some(name.clone());
+                    Some((SymbolKind::Statute, SymbolRole::Definition))
+                }
+                (Some(Token::Amendment), _) => Some((SymbolKind::Statute, SymbolRole::Dependency)),
+                (Some(Token::Applies), _) => Some((SymbolKind::Statute, SymbolRole::Condition)),
+                // The output name in `statute.output`
+                (Some(Token::Dot), _) => None,
+                (_, Some(Token::Dot))
+                    if matches!(tokens.get(i + 2).map(|t| &t.token), Some(Token::Ident(_))) =>
+                {
+                    Some((SymbolKind::Statute, SymbolRole::Condition))
+                }
+                (Some(Token::Let | Token::Default), _) => {
+                    Some((SymbolKind::Field, SymbolRole::Definition))
+                }
+                (Some(Token::Has | Token::Plus | Token::Dash | Token::Star | Token::Slash), _)
+                | (
+                    _,
+                    Some(
+                        Token::Operator(_)
+                        | Token::Between
+                        | Token::In
+                        | Token::Like
+                        | Token::Matches
+                        | Token::InRange
+                        | Token::NotInRange
+                        | Token::Plus
+                        | Token::Dash
+                        | Token::Star
+                        | Token::Slash,
+                    ),
+                ) => Some((SymbolKind::Field, SymbolRole::Condition)),
+                _ => None,
+            }
+        };
+
+        if let Some((kind, role)) = classified {
+            occurrences.push(Occurrence {
+                name: name.clone(),
+                kind,
+                role,
+                statute: statute
+                    .clone()
+                    .filter(|_| role != SymbolRole::Definition || kind != SymbolKind::Statute),
+                span: span_at(spanned.location, name.chars().count()),
+            });
+        }
+    }
+
+    occurrences
+}
+
+/// Returns `true` if a message names the statute as a whole word.
+fn mentions(text: &str, statute_id: &str) -> bool {
+    text.match_indices(statute_id).any(|(start, _)| {
+        let is_id_char = |c: char| c.is_alphanumeric() || c == '_' || c == '-';
+        let before = text[..start].chars().next_back();
+        let after = text[start + statute_id.len()..].chars().next();
+        !before.is_some_and(is_id_char) && !after.is_some_and(is_id_char)
+    })
+}
+
+/// Maps a type checker location such as `statute s field f` to a span.
+fn type_error_span(index: &FileIndex, location: &str) -> SourceSpan {
+    let words: Vec<&str> = location.split_whitespace().collect();
+    let field_span = |statute: Option<&str>, field: &str| {
+        index
+            .occurrences
+            .iter()
+            .find(|o| {
+                o.kind == SymbolKind::Field
+                    && o.name == field
+                    && (statute.is_none() || o.statute.as_deref() == statute)
+            })
+            .map(|o| o.span)
+    };
+    let span = match words.as_slice() {
+        ["statute", id, "field", field, ..] => {
+            field_span(Some(id), field).or_else(|| index.statute_span(id))
+        }
+        ["statute", id, ..] => index.statute_span(id),
+        ["definition", name, ..] => field_span(None, name),
+        _ => None,
+    };
+    span.unwrap_or_else(|| span_at(SourceLocation::new(1, 1, 0), 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BENEFITS: &str = r#"STATUTE child-benefit: "Child Benefit" {
+    WHEN AGE < 18 AND HAS resident
+    THEN GRANT "Monthly allowance"
+}
+"#;
+
+    const TOP_UP: &str = r#"IMPORT "benefits.legalis"
+
+// Paid on top of the child benefit
+STATUTE top-up: "Top-up" {
+    REQUIRES child-benefit
+    WHEN HAS resident
+    THEN GRANT "Additional allowance"
+}
+"#;
+
+    fn project() -> (tempfile::TempDir, Workspace) {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("benefits.legalis"), BENEFITS).unwrap();
+        std::fs::write(dir.path().join("top-up.legalis"), TOP_UP).unwrap();
+        let mut workspace = Workspace::new();
+        assert_eq!(workspace.scan(dir.path()), 2);
+        (dir, workspace)
+    }
+
+    #[test]
+    fn test_occurrences() {
+        let index = FileIndex::new(Path::new("/top-up.legalis"), TOP_UP.to_string());
+        let names: Vec<_> = index
+            .occurrences()
+            .iter()
+            .map(|o| (o.name.as_str(), o.kind, o.role))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("top-up", SymbolKind::Statute, SymbolRole::Definition),
+                ("child-benefit", SymbolKind::Statute, SymbolRole::Dependency),
+                ("resident", SymbolKind::Field, SymbolRole::Condition),
+            ]
+        );
+
+        // Positions survive the comment on line 3
+        let requires = &index.occurrences()[1];
+        assert_eq!(requires.span.start.line, 5);
+        assert_eq!(requires.span.start.column, 14);
+        assert_eq!(requires.statute.as_deref(), Some("top-up"));
+        assert_eq!(
+            index.imports()[0].target,
+            PathBuf::from("/benefits.legalis")
+        );
+    }
+
+    #[test]
+    fn test_definition_and_references_across_imports() {
+        let (dir, workspace) = project();
+        let top_up = dir.path().join("top-up.legalis");
+
+        let definition = workspace.definition(&top_up, 5, 16).unwrap();
+        assert_eq!(definition.path, dir.path().join("benefits.legalis"));
+        assert_eq!(definition.span.start.line, 1);
+        assert_eq!(definition.span.start.column, 9);
+
+        // `resident` is used in both files
+        let references = workspace.references(&top_up, 6, 15, true);
+        assert_eq!(references.len(), 2);
+
+        let scope = workspace.scope(&top_up);
+        assert_eq!(scope.len(), 2);
+        assert_eq!(workspace.statutes_in_scope(&top_up).len(), 2);
+    }
+
+    #[test]
+    fn test_rename() {
+        let (dir, workspace) = project();
+        let benefits = dir.path().join("benefits.legalis");
+
+        let edits = workspace
+            .rename(&benefits, 1, 10, "family-benefit")
+            .unwrap();
+        assert_eq!(edits.len(), 2);
+        assert!(
+            edits
+                .iter()
+                .any(|l| l.path == dir.path().join("top-up.legalis"))
+        );
+
+        assert!(workspace.rename(&benefits, 1, 10, "STATUTE").is_err());
+        assert!(workspace.rename(&benefits, 1, 10, "two words").is_err());
+        assert!(workspace.rename(&benefits, 3, 1, "anything").is_err());
+    }
+
+    #[test]
+    fn test_reference_diagnostics() {
+        let (dir, mut workspace) = project();
+        let top_up = dir.path().join("top-up.legalis");
+        assert!(workspace.reference_diagnostics(&top_up).is_empty());
+
+        let edited = TOP_UP
+            .replace("REQUIRES child-benefit", "REQUIRES child-benefits")
+            .replace("benefits.legalis", "missing.legalis");
+        workspace.update(&top_up, edited);
+        let diagnostics = workspace.reference_diagnostics(&top_up);
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics[0].message.contains("missing.legalis"));
+        assert_eq!(diagnostics[0].span.start.line, 1);
+        // Nothing is imported any more, so there is nothing to suggest
+        assert!(diagnostics[1].message.contains("child-benefits"));
+        assert_eq!(diagnostics[1].replacement, None);
+
+        let edited = TOP_UP.replace("REQUIRES child-benefit", "REQUIRES child-benefits");
+        workspace.update(&top_up, edited);
+        let diagnostics = workspace.reference_diagnostics(&top_up);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].replacement.as_deref(), Some("child-benefit"));
+    }
+
+    #[test]
+    fn test_check_reports_verifier_findings() {
+        let mut workspace = Workspace::new();
+        let path = Path::new("/project/permits.legalis");
+        workspace.update(
+            path,
+            r#"STATUTE permit: "Permit" {
+    WHEN AGE >= 18 AND APPLIES registration
+    THEN GRANT "Permit"
+}
+STATUTE renewal: "Renewal" {
+    WHEN APPLIES reissue
+    THEN GRANT "Renewed permit"
+}
+STATUTE reissue: "Reissue" {
+    WHEN APPLIES renewal
+    THEN GRANT "Reissued permit"
+}
+STATUTE retired: "Retired" {
+    WHEN AGE BETWEEN 18 AND "old"
+    THEN GRANT "Pension"
+}
+"#,
+        );
+
+        let diagnostics = workspace.check(path);
+        let from = |source| {
+            diagnostics
+                .iter()
+                .filter(move |d| d.source == source)
+                .collect::<Vec<_>>()
+        };
+
+        let cross_reference = from(DiagnosticSource::Verifier)
+            .into_iter()
+            .find(|d| d.message.contains("registration"))
+            .unwrap();
+        assert_eq!(cross_reference.span.start.line, 2);
+        assert_eq!(cross_reference.span.start.column, 32);
+
+        assert!(!from(DiagnosticSource::TypeChecker).is_empty());
+        assert!(
+            from(DiagnosticSource::Verifier)
+                .iter()
+                .any(|d| d.error.is_some() && d.span.start.line == 5)
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            normalize(Path::new("/a/b/../c/./d.legalis")),
+            PathBuf::from("/a/c/d.legalis")
+        );
+    }
+}
@@ -5,18 +5,1298 @@
 //! for legislative and parliamentary documents.
 //!
 //! Key features:
-//! - Hierarchical document structure (act, bill, section, article)
+//! - Hierarchical document structure (act, bill, part, chapter, section, article)
+//! - FRBR Work/Expression/Manifestation identification
+//! - Lifecycle events, temporal groups and passive/active modifications
 //! - Semantic markup for legal concepts
-//! - Metadata and identification system
 //! - Multi-language support
+//!
+//! Documents are read into an [`AknDocument`] that keeps mixed content
+//! verbatim, so a parsed document serializes back without losing its text or
+//! consolidation data. The outermost `article`, `section`, `rule` or
+//! `paragraph` elements become statutes: in-force periods and lifecycle events
+//! map onto [`TemporalValidity`], passive modifications onto [`Amendment`]s and
+//! the body structure onto [`StatuteHierarchy`] entries.
 
 use crate::{
     ConversionReport, FormatExporter, FormatImporter, InteropError, InteropResult, LegalFormat,
 };
-use legalis_core::{Condition, Effect, EffectType, Statute};
-use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
-use quick_xml::{Reader, Writer};
-use std::io::Cursor;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use legalis_core::{
+    Amendment, AmendmentType, Condition, Effect, EffectType, Statute, StatuteHierarchy,
+    TemporalValidity,
+};
+use quick_xml::Reader;
+use quick_xml::escape::{escape, partial_escape};
+use quick_xml::events::{BytesStart, Event};
+use std::collections::{HashMap, HashSet};
+
+/// Akoma Ntoso 3.0 namespace.
+pub const AKN_NAMESPACE: &str = "http://docs.oasis-open.org/legaldocml/ns/akn/3.0";
+
+/// Namespace of the proprietary block carrying statute logic.
+const LEGALIS_NAMESPACE: &str = "https://legalis.dev/ns/akn";
+
+/// Hierarchical elements recognised in a document body.
+const HIERARCHY_ELEMENTS: &[&str] = &[
+    "alinea",
+    "article",
+    "book",
+    "chapter",
+    "clause",
+    "division",
+    "hcontainer",
+    "indent",
+    "item",
+    "level",
+    "list",
+    "paragraph",
+    "part",
+    "point",
+    "proviso",
+    "rule",
+    "section",
+    "subchapter",
+    "subclause",
+    "subdivision",
+    "sublist",
+    "subparagraph",
+    "subpart",
+    "subrule",
+    "subsection",
+    "subtitle",
+    "title",
+    "tome",
+    "transitional",
+];
+
+/// Hierarchical elements that become statutes; the outermost one wins.
+const PROVISION_ELEMENTS: &[&str] = &["article", "paragraph", "rule", "section"];
+
+/// Elements holding the main body of a document.
+const BODY_ELEMENTS: &[&str] = &[
+    "amendmentBody",
+    "body",
+    "debateBody",
+    "judgmentBody",
+    "mainBody",
+    "portionBody",
+];
+
+/// Order of the `meta` children required by the schema.
+const META_ORDER: &[&str] = &[
+    "identification",
+    "publication",
+    "classification",
+    "lifecycle",
+    "workflow",
+    "analysis",
+    "temporalData",
+    "references",
+    "notes",
+    "proprietary",
+    "presentation",
+];
+
+// ==================================================
+// XML tree
+// ==================================================
+
+/// A node of an Akoma Ntoso document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AknNode {
+    /// Child element
+    Element(AknElement),
+    /// Character data
+    Text(String),
+}
+
+/// An element of an Akoma Ntoso document.
+///
+/// Unlike the DMN tree, text and child elements are kept interleaved so that
+/// inline markup such as `<ref>` inside a `<p>` survives a round trip.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AknElement {
+    /// Qualified element name
+    pub name: String,
+    /// Attributes in document order
+    pub attributes: Vec<(String, String)>,
+    /// Child elements and text in document order
+    pub children: Vec<AknNode>,
+}
+
+impl AknElement {
+    /// Creates an element without attributes or content.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Parses a document into its root element.
+    ///
+    /// Whitespace-only text is dropped from element-only content and kept
+    /// verbatim in mixed content.
+    pub fn parse(source: &str) -> InteropResult<Self> {
+        let mut reader = Reader::from_str(source);
+        let mut stack: Vec<AknElement> = Vec::new();
+        let mut root = None;
+
+        loop {
+            let event = reader
+                .read_event()
+                .map_err(|e| InteropError::ParseError(format!("XML parse error: {}", e)))?;
+            match event {
+                Event::Start(e) => stack.push(Self::from_start(&e)?),
+                Event::Empty(e) => {
+                    let element = Self::from_start(&e)?;
+                    Self::attach(&mut stack, &mut root, element);
+                }
+                Event::End(_) => {
+                    let Some(mut element) = stack.pop() else {
+                        return Err(InteropError::ParseError("Unbalanced XML".to_string()));
+                    };
+                    if !element.has_text() {
+                        element
+                            .children
+                            .retain(|child| matches!(child, AknNode::Element(_)));
+                    }
+                    Self::attach(&mut stack, &mut root, element);
+                }
+                Event::Text(e) => {
+                    if let Some(element) = stack.last_mut() {
+                        let text = e
+                            .xml_content()
+                            .map_err(|e| InteropError::ParseError(e.to_string()))?;
+                        element.push_text(&text);
+                    }
+                }
+                Event::CData(e) => {
+                    if let Some(element) = stack.last_mut() {
+                        element.push_text(&String::from_utf8_lossy(e.into_inner().as_ref()));
+                    }
+                }
+                Event::GeneralRef(e) => {
+                    if let Some(element) = stack.last_mut() {
+                        let name = e
+                            .decode()
+                            .map_err(|e| InteropError::ParseError(e.to_string()))?;
+                        let resolved = match e.resolve_char_ref() {
+                            Ok(Some(ch)) => ch.to_string(),
+                            _ => quick_xml::escape::resolve_predefined_entity(&name)
+                                .map(str::to_string)
+                                .unwrap_or_else(|| format!("&{};", name)),
+                        };
+                        element.push_text(&resolved);
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        if let Some(open) = stack.last() {
+            return Err(InteropError::ParseError(format!(
+                "Unclosed element <{}>",
+                open.name
+            )));
+        }
+        root.ok_or_else(|| InteropError::ParseError("Empty XML document".to_string()))
+    }
+
+    fn from_start(start: &BytesStart) -> InteropResult<Self> {
+        let mut element = Self::new(String::from_utf8_lossy(start.name().as_ref()));
+        for attribute in start.attributes() {
+            let attribute = attribute.map_err(|e| InteropError::ParseError(e.to_string()))?;
+            let value = attribute
+                .unescape_value()
+                .map_err(|e| InteropError::ParseError(e.to_string()))?;
+            element.attributes.push((
+                String::from_utf8_lossy(attribute.key.as_ref()).to_string(),
+                value.to_string(),
+            ));
+        }
+        Ok(element)
+    }
+
+    fn attach(stack: &mut [AknElement], root: &mut Option<AknElement>, element: AknElement) {
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(AknNode::Element(element)),
+            None => *root = Some(element),
+        }
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if let Some(AknNode::Text(last)) = self.children.last_mut() {
+            last.push_str(text);
+        } else {
+            self.children.push(AknNode::Text(text.to_string()));
+        }
+    }
+
+    /// Returns the element name without its namespace prefix.
+    pub fn local_name(&self) -> &str {
+        self.name.rsplit(':').next().unwrap_or_default()
+    }
+
+    /// Returns an attribute value.
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the child elements.
+    pub fn elements(&self) -> impl Iterator<Item = &AknElement> {
+        self.children.iter().filter_map(|child| match child {
+            AknNode::Element(element) => Some(element),
+            AknNode::Text(_) => None,
+        })
+    }
+
+    /// Returns the first child element with the given local name.
+    pub fn child(&self, local_name: &str) -> Option<&AknElement> {
+        self.elements().find(|c| c.local_name() == local_name)
+    }
+
+    fn children_named<'a>(&'a self, local_name: &'a str) -> impl Iterator<Item = &'a AknElement> {
+        self.elements()
+            .filter(move |c| c.local_name() == local_name)
+    }
+
+    /// Returns the text content with whitespace collapsed.
+    pub fn text(&self) -> String {
+        let mut raw = String::new();
+        self.collect_text(&mut raw);
+        raw.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    fn collect_text(&self, out: &mut String) {
+        // Block-level children are separated; inline children run on.
+        let mixed = self.has_text();
+        for child in &self.children {
+            match child {
+                AknNode::Text(text) => out.push_str(text),
+                AknNode::Element(element) => {
+                    if !mixed {
+                        out.push(' ');
+                    }
+                    element.collect_text(out);
+                }
+            }
+        }
+    }
+
+    fn has_text(&self) -> bool {
+        self.children
+            .iter()
+            .any(|child| matches!(child, AknNode::Text(text) if !text.trim().is_empty()))
+    }
+
+    /// Collects the `href` of every `ref` element in the subtree.
+    fn collect_refs(&self, out: &mut Vec<String>) {
+        for element in self.elements() {
+            if element.local_name() == "ref"
+                && let Some(href) = element.attr("href")
+            {
+                out.push(href.to_string());
+            }
+            element.collect_refs(out);
+        }
+    }
+
+    fn with_attr(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.attributes.push((name.to_string(), value.into()));
+        self
+    }
+
+    fn with_opt_attr(self, name: &str, value: Option<&str>) -> Self {
+        match value {
+            Some(value) => self.with_attr(name, value),
+            None => self,
+        }
+    }
+
+    fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.children.push(AknNode::Text(text.into()));
+        self
+    }
+
+    fn with_child(mut self, child: AknElement) -> Self {
+        self.children.push(AknNode::Element(child));
+        self
+    }
+
+    /// Serializes the element as an XML document.
+    ///
+    /// Element-only content is indented; mixed content is written as is.
+    pub fn to_xml(&self) -> String {
+        let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        self.write(&mut output, Some(0));
+        output.push('\n');
+        output
+    }
+
+    fn write(&self, out: &mut String, indent: Option<usize>) {
+        out.push('<');
+        out.push_str(&self.name);
+        for (key, value) in &self.attributes {
+            out.push(' ');
+            out.push_str(key);
+            out.push_str("=\"");
+            out.push_str(&escape(value.as_str()));
+            out.push('"');
+        }
+        if self.children.is_empty() {
+            out.push_str("/>");
+            return;
+        }
+        out.push('>');
+
+        let pretty = indent.filter(|_| !self.has_text());
+        for child in &self.children {
+            match child {
+                AknNode::Text(text) => out.push_str(&partial_escape(text.as_str())),
+                AknNode::Element(element) => match pretty {
+                    Some(depth) => {
+                        out.push('\n');
+                        out.push_str(&"  ".repeat(depth + 1));
+                        element.write(out, Some(depth + 1));
+                    }
+                    None => element.write(out, None),
+                },
+            }
+        }
+        if let Some(depth) = pretty {
+            out.push('\n');
+            out.push_str(&"  ".repeat(depth));
+        }
+        out.push_str("</");
+        out.push_str(&self.name);
+        out.push('>');
+    }
+}
+
+// ==================================================
+// Model
+// ==================================================
+
+/// One FRBR level (Work, Expression or Manifestation) of the identification.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrbrItem {
+    /// `FRBRthis`: IRI of this component
+    pub this: String,
+    /// `FRBRuri`: IRI of the level as a whole
+    pub uri: String,
+    /// `FRBRdate` date
+    pub date: Option<NaiveDate>,
+    /// `FRBRdate` name (e.g. "enactment", "generation")
+    pub date_name: String,
+    /// `FRBRauthor` href
+    pub author: String,
+    /// Further properties (`FRBRcountry`, `FRBRlanguage`, `FRBRalias`, ...)
+    pub properties: Vec<AknElement>,
+}
+
+impl FrbrItem {
+    fn from_element(level: &AknElement) -> Self {
+        let mut item = Self::default();
+        for property in level.elements() {
+            match property.local_name() {
+                "FRBRthis" if item.this.is_empty() => {
+                    item.this = property.attr("value").unwrap_or_default().to_string();
+                }
+                "FRBRuri" if item.uri.is_empty() => {
+                    item.uri = property.attr("value").unwrap_or_default().to_string();
+                }
+                "FRBRdate" if item.date_name.is_empty() && item.date.is_none() => {
+                    item.date = property.attr("date").and_then(parse_date);
+                    item.date_name = property.attr("name").unwrap_or_default().to_string();
+                }
+                "FRBRauthor" if item.author.is_empty() => {
+                    item.author = property.attr("href").unwrap_or_default().to_string();
+                }
+                _ => item.properties.push(property.clone()),
+            }
+        }
+        item
+    }
+
+    fn to_element(&self, name: &str) -> AknElement {
+        let (aliases, others): (Vec<_>, Vec<_>) = self
+            .properties
+            .iter()
+            .partition(|p| p.local_name() == "FRBRalias");
+        let mut element = AknElement::new(name)
+            .with_child(AknElement::new("FRBRthis").with_attr("value", self.this.as_str()))
+            .with_child(AknElement::new("FRBRuri").with_attr("value", self.uri.as_str()));
+        for alias in aliases {
+            element = element.with_child(alias.clone());
+        }
+        element = element
+            .with_child(
+                AknElement::new("FRBRdate")
+                    .with_opt_attr("date", self.date.map(|d| d.to_string()).as_deref())
+                    .with_attr("name", self.date_name.as_str()),
+            )
+            .with_child(AknElement::new("FRBRauthor").with_attr("href", self.author.as_str()));
+        for property in others {
+            element = element.with_child(property.clone());
+        }
+        element
+    }
+
+    /// Returns an attribute of the first property with the given name.
+    pub fn property(&self, local_name: &str, attribute: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|p| p.local_name() == local_name)
+            .and_then(|p| p.attr(attribute))
+    }
+}
+
+/// The FRBR identification block of a document.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrbrIdentification {
+    /// Reference to the agent responsible for the metadata
+    pub source: String,
+    /// The abstract work
+    pub work: FrbrItem,
+    /// A version of the work in one language
+    pub expression: FrbrItem,
+    /// The physical embodiment of the expression
+    pub manifestation: FrbrItem,
+}
+
+impl FrbrIdentification {
+    fn from_element(identification: &AknElement) -> Self {
+        let level = |name: &str| {
+            identification
+                .child(name)
+                .map(FrbrItem::from_element)
+                .unwrap_or_default()
+        };
+        Self {
+            source: identification
+                .attr("source")
+                .unwrap_or_default()
+                .to_string(),
+            work: level("FRBRWork"),
+            expression: level("FRBRExpression"),
+            manifestation: level("FRBRManifestation"),
+        }
+    }
+
+    fn to_element(&self) -> AknElement {
+        AknElement::new("identification")
+            .with_attr("source", self.source.as_str())
+            .with_child(self.work.to_element("FRBRWork"))
+            .with_child(self.expression.to_element("FRBRExpression"))
+            .with_child(self.manifestation.to_element("FRBRManifestation"))
+    }
+
+    /// Returns the country code of the work.
+    pub fn country(&self) -> Option<&str> {
+        self.work.property("FRBRcountry", "value")
+    }
+
+    /// Returns the language code of the expression.
+    pub fn language(&self) -> Option<&str> {
+        self.expression.property("FRBRlanguage", "language")
+    }
+}
+
+/// A lifecycle event (`eventRef`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LifecycleEvent {
+    /// Event identifier
+    pub eid: String,
+    /// Date of the event
+    pub date: Option<NaiveDate>,
+    /// Reference to the document causing the event
+    pub source: String,
+    /// Event type ("generation", "amendment", "repeal")
+    pub event_type: String,
+    /// Optional reference to an event concept
+    pub refers_to: Option<String>,
+}
+
+impl LifecycleEvent {
+    fn from_element(element: &AknElement) -> Self {
+        Self {
+            eid: element_id(element).unwrap_or_default().to_string(),
+            date: element.attr("date").and_then(parse_date),
+            source: element.attr("source").unwrap_or_default().to_string(),
+            event_type: element.attr("type").unwrap_or_default().to_string(),
+            refers_to: element.attr("refersTo").map(str::to_string),
+        }
+    }
+
+    fn to_element(&self) -> AknElement {
+        AknElement::new("eventRef")
+            .with_attr("eId", self.eid.as_str())
+            .with_opt_attr("date", self.date.map(|d| d.to_string()).as_deref())
+            .with_attr("source", self.source.as_str())
+            .with_attr("type", self.event_type.as_str())
+            .with_opt_attr("refersTo", self.refers_to.as_deref())
+    }
+}
+
+/// An interval of a temporal group, bounded by lifecycle events.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TimeInterval {
+    /// Reference to the starting event
+    pub start: Option<String>,
+    /// Reference to the ending event (exclusive)
+    pub end: Option<String>,
+    /// Duration, when the interval is not bounded by an event
+    pub duration: Option<String>,
+    /// What the interval describes (e.g. "#inForce", "#efficacy")
+    pub refers_to: Option<String>,
+}
+
+/// A named group of time intervals (`temporalGroup`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TemporalGroup {
+    /// Group identifier, referenced by `period` attributes
+    pub eid: String,
+    /// Intervals of the group
+    pub intervals: Vec<TimeInterval>,
+}
+
+impl TemporalGroup {
+    fn from_element(element: &AknElement) -> Self {
+        Self {
+            eid: element_id(element).unwrap_or_default().to_string(),
+            intervals: element
+                .children_named("timeInterval")
+                .map(|interval| TimeInterval {
+                    start: interval.attr("start").map(str::to_string),
+                    end: interval.attr("end").map(str::to_string),
+                    duration: interval.attr("duration").map(str::to_string),
+                    refers_to: interval.attr("refersTo").map(str::to_string),
+                })
+                .collect(),
+        }
+    }
+
+    fn to_element(&self) -> AknElement {
+        self.intervals.iter().fold(
+            AknElement::new("temporalGroup").with_attr("eId", self.eid.as_str()),
+            |group, interval| {
+                group.with_child(
+                    AknElement::new("timeInterval")
+                        .with_opt_attr("start", interval.start.as_deref())
+                        .with_opt_attr("end", interval.end.as_deref())
+                        .with_opt_attr("duration", interval.duration.as_deref())
+                        .with_opt_attr("refersTo", interval.refers_to.as_deref()),
+                )
+            },
+        )
+    }
+}
+
+/// A modification recorded in the analysis block.
+///
+/// Passive modifications are changes made to this document by others;
+/// active modifications are changes this document makes to others.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Modification {
+    /// Element name (`textualMod`, `meaningMod`, `scopeMod`, `forceMod`, ...)
+    pub kind: String,
+    /// Modification identifier
+    pub eid: Option<String>,
+    /// Modification type ("substitution", "insertion", "repeal", ...)
+    pub mod_type: Option<String>,
+    /// References to the modifying provisions or documents
+    pub sources: Vec<String>,
+    /// References to the modified provisions
+    pub destinations: Vec<String>,
+    /// Temporal group in which the modification is in force
+    pub force: Option<String>,
+    /// Temporal group in which the modification is efficacious
+    pub efficacy: Option<String>,
+    /// Other children (`condition`, `old`, `new`, `domain`, ...) kept verbatim
+    pub extra: Vec<AknElement>,
+}
+
+impl Modification {
+    fn from_element(element: &AknElement) -> Self {
+        let mut modification = Self {
+            kind: element.local_name().to_string(),
+            eid: element_id(element).map(str::to_string),
+            mod_type: element.attr("type").map(str::to_string),
+            ..Default::default()
+        };
+        for child in element.elements() {
+            let href = child.attr("href").unwrap_or_default().to_string();
+            match child.local_name() {
+                "source" => modification.sources.push(href),
+                "destination" => modification.destinations.push(href),
+                "force" => modification.force = child.attr("period").map(str::to_string),
+                "efficacy" => modification.efficacy = child.attr("period").map(str::to_string),
+                _ => modification.extra.push(child.clone()),
+            }
+        }
+        modification
+    }
+
+    fn to_element(&self) -> AknElement {
+        let mut element = AknElement::new(self.kind.as_str())
+            .with_opt_attr("eId", self.eid.as_deref())
+            .with_opt_attr("type", self.mod_type.as_deref());
+        for source in &self.sources {
+            element =
+                element.with_child(AknElement::new("source").with_attr("href", source.as_str()));
+        }
+        for destination in &self.destinations {
+            element = element
+                .with_child(AknElement::new("destination").with_attr("href", destination.as_str()));
+        }
+        if let Some(period) = &self.force {
+            element =
+                element.with_child(AknElement::new("force").with_attr("period", period.as_str()));
+        }
+        if let Some(period) = &self.efficacy {
+            element = element
+                .with_child(AknElement::new("efficacy").with_attr("period", period.as_str()));
+        }
+        for extra in &self.extra {
+            element = element.with_child(extra.clone());
+        }
+        element
+    }
+
+    /// Returns the amendment type corresponding to the modification.
+    pub fn amendment_type(&self) -> AmendmentType {
+        match (self.kind.as_str(), self.mod_type.as_deref()) {
+            ("textualMod", Some("insertion")) => AmendmentType::Addition,
+            ("textualMod", Some("repeal")) => AmendmentType::Deletion,
+            ("textualMod", Some("substitution")) => AmendmentType::Substitution,
+            ("textualMod", Some("renumbering" | "split" | "join")) => AmendmentType::Reorganization,
+            ("meaningMod", _) => AmendmentType::Clarification,
+            _ => AmendmentType::Modification,
+        }
+    }
+
+    /// Returns the element name and type used to record an amendment type.
+    fn for_amendment_type(amendment_type: AmendmentType) -> (&'static str, Option<&'static str>) {
+        match amendment_type {
+            AmendmentType::Addition => ("textualMod", Some("insertion")),
+            AmendmentType::Deletion => ("textualMod", Some("repeal")),
+            AmendmentType::Substitution => ("textualMod", Some("substitution")),
+            AmendmentType::Reorganization => ("textualMod", Some("renumbering")),
+            AmendmentType::Clarification => ("meaningMod", None),
+            AmendmentType::Modification => ("scopeMod", None),
+        }
+    }
+}
+
+/// An entry of the references block (`original`, `passiveRef`, `TLCOrganization`, ...).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Reference {
+    /// Element name
+    pub kind: String,
+    /// Identifier other metadata refers to
+    pub eid: String,
+    /// Referenced IRI
+    pub href: String,
+    /// Display name
+    pub show_as: String,
+}
+
+impl Reference {
+    fn new(kind: &str, eid: &str, href: impl Into<String>, show_as: impl Into<String>) -> Self {
+        Self {
+            kind: kind.to_string(),
+            eid: eid.to_string(),
+            href: href.into(),
+            show_as: show_as.into(),
+        }
+    }
+
+    fn from_element(element: &AknElement) -> Self {
+        Self::new(
+            element.local_name(),
+            element_id(element).unwrap_or_default(),
+            element.attr("href").unwrap_or_default(),
+            element.attr("showAs").unwrap_or_default(),
+        )
+    }
+
+    fn to_element(&self) -> AknElement {
+        AknElement::new(self.kind.as_str())
+            .with_attr("eId", self.eid.as_str())
+            .with_attr("href", self.href.as_str())
+            .with_attr("showAs", self.show_as.as_str())
+    }
+}
+
+/// A hierarchical unit of the body (part, chapter, section, article, ...).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AknUnit {
+    /// Element name
+    pub kind: String,
+    /// Expression-level identifier
+    pub eid: Option<String>,
+    /// Temporal group governing the unit
+    pub period: Option<String>,
+    /// Other attributes in document order
+    pub attributes: Vec<(String, String)>,
+    /// Number as plain text
+    pub num: Option<String>,
+    /// Heading as plain text
+    pub heading: Option<String>,
+    /// Subheading as plain text
+    pub subheading: Option<String>,
+    /// Introductory text before the sub-units
+    pub intro: Option<AknElement>,
+    /// Sub-units
+    pub children: Vec<AknUnit>,
+    /// Concluding text after the sub-units
+    pub wrap_up: Option<AknElement>,
+    /// Content of a leaf unit
+    pub content: Option<AknElement>,
+    /// Other children kept verbatim
+    pub other: Vec<AknElement>,
+}
+
+impl AknUnit {
+    /// Creates a unit with the given element name and identifier.
+    pub fn new(kind: impl Into<String>, eid: impl Into<String>) -> Self {
+        Self {
+            kind: kind.into(),
+            eid: Some(eid.into()),
+            ..Default::default()
+        }
+    }
+
+    fn from_element(element: &AknElement) -> Self {
+        let mut unit = Self {
+            kind: element.local_name().to_string(),
+            eid: element.attr("eId").map(str::to_string),
+            period: element.attr("period").map(str::to_string),
+            attributes: element
+                .attributes
+                .iter()
+                .filter(|(key, _)| key != "eId" && key != "period")
+                .cloned()
+                .collect(),
+            ..Default::default()
+        };
+        for child in element.elements() {
+            match child.local_name() {
+                "num" => unit.num = Some(child.text()),
+                "heading" => unit.heading = Some(child.text()),
+                "subheading" => unit.subheading = Some(child.text()),
+                "intro" => unit.intro = Some(child.clone()),
+                "wrapUp" => unit.wrap_up = Some(child.clone()),
+                "content" => unit.content = Some(child.clone()),
+                name if is_hierarchy(name) => unit.children.push(Self::from_element(child)),
+                _ => unit.other.push(child.clone()),
+            }
+        }
+        unit
+    }
+
+    fn to_element(&self) -> AknElement {
+        let mut element =
+            AknElement::new(self.kind.as_str()).with_opt_attr("eId", self.eid.as_deref());
+        for (key, value) in &self.attributes {
+            element = element.with_attr(key, value.as_str());
+        }
+        element = element.with_opt_attr("period", self.period.as_deref());
+        for (name, text) in [
+            ("num", &self.num),
+            ("heading", &self.heading),
+            ("subheading", &self.subheading),
+        ] {
+            if let Some(text) = text {
+                element = element.with_child(AknElement::new(name).with_text(text.as_str()));
+            }
+        }
+        if let Some(intro) = &self.intro {
+            element = element.with_child(intro.clone());
+        }
+        for child in &self.children {
+            element = element.with_child(child.to_element());
+        }
+        for block in [&self.wrap_up, &self.content].into_iter().flatten() {
+            element = element.with_child(block.clone());
+        }
+        for other in &self.other {
+            element = element.with_child(other.clone());
+        }
+        element
+    }
+
+    /// Returns the plain text of the unit below its own number and heading.
+    pub fn text(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(intro) = &self.intro {
+            parts.push(intro.text());
+        }
+        for child in &self.children {
+            parts.extend(child.num.iter().chain(&child.heading).cloned());
+            parts.push(child.text());
+        }
+        for block in [&self.wrap_up, &self.content].into_iter().flatten() {
+            parts.push(block.text());
+        }
+        parts.extend(self.other.iter().map(AknElement::text));
+        parts.retain(|part| !part.is_empty());
+        parts.join(" ")
+    }
+
+    /// Collects the identifiers of the unit and its descendants.
+    fn collect_eids<'a>(&'a self, out: &mut HashSet<&'a str>) {
+        if let Some(eid) = &self.eid {
+            out.insert(eid);
+        }
+        for child in &self.children {
+            child.collect_eids(out);
+        }
+    }
+
+    /// Collects the `ref` targets in the unit and its descendants.
+    fn collect_refs(&self, out: &mut Vec<String>) {
+        let blocks = [&self.intro, &self.wrap_up, &self.content];
+        for block in blocks.into_iter().flatten().chain(&self.other) {
+            block.collect_refs(out);
+        }
+        for child in &self.children {
+            child.collect_refs(out);
+        }
+    }
+}
+
+/// An Akoma Ntoso document.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AknDocument {
+    /// Document type element (act, bill, doc, ...)
+    pub doc_type: String,
+    /// Value of the document's `name` attribute
+    pub name: String,
+    /// FRBR identification
+    pub identification: FrbrIdentification,
+    /// Publication details, kept verbatim
+    pub publication: Option<AknElement>,
+    /// Lifecycle events
+    pub lifecycle: Vec<LifecycleEvent>,
+    /// Changes this document makes to others
+    pub active_modifications: Vec<Modification>,
+    /// Changes made to this document by others
+    pub passive_modifications: Vec<Modification>,
+    /// Other analysis children, kept verbatim
+    pub analysis_other: Vec<AknElement>,
+    /// Temporal groups referenced by `period` attributes
+    pub temporal_groups: Vec<TemporalGroup>,
+    /// References block entries
+    pub references: Vec<Reference>,
+    /// Other metadata (classification, workflow, notes, proprietary, ...)
+    pub meta_other: Vec<AknElement>,
+    /// Preface, kept verbatim
+    pub preface: Option<AknElement>,
+    /// Preamble, kept verbatim
+    pub preamble: Option<AknElement>,
+    /// Name of the body element
+    pub body_name: String,
+    /// Hierarchical units of the body
+    pub body: Vec<AknUnit>,
+    /// Non-hierarchical body children, kept verbatim
+    pub body_other: Vec<AknElement>,
+    /// Conclusions, kept verbatim
+    pub conclusions: Option<AknElement>,
+    /// Other document children (coverPage, attachments, ...)
+    pub other: Vec<AknElement>,
+}
+
+impl AknDocument {
+    /// Creates an empty document of the given type.
+    pub fn new(doc_type: impl Into<String>) -> Self {
+        let doc_type = doc_type.into();
+        Self {
+            name: doc_type.clone(),
+            doc_type,
+            body_name: "body".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Parses an Akoma Ntoso document.
+    ///
+    /// Accepts either an `akomaNtoso` root or a bare document element.
+    pub fn parse(source: &str) -> InteropResult<Self> {
+        let root = AknElement::parse(source)?;
+        let doc = if root.local_name() == "akomaNtoso" {
+            root.elements().next().ok_or_else(|| {
+                InteropError::ParseError("Akoma Ntoso document has no document element".to_string())
+            })?
+        } else {
+            &root
+        };
+
+        let mut document = Self::new(doc.local_name());
+        if let Some(name) = doc.attr("name") {
+            document.name = name.to_string();
+        }
+        for child in doc.elements() {
+            match child.local_name() {
+                "meta" => document.read_meta(child),
+                "preface" => document.preface = Some(child.clone()),
+                "preamble" => document.preamble = Some(child.clone()),
+                "conclusions" => document.conclusions = Some(child.clone()),
+                name if BODY_ELEMENTS.contains(&name) => {
+                    document.body_name = name.to_string();
+                    for element in child.elements() {
+                        if is_hierarchy(element.local_name()) {
+                            document.body.push(AknUnit::from_element(element));
+                        } else {
+                            document.body_other.push(element.clone());
+                        }
+                    }
+                }
+                _ => document.other.push(child.clone()),
+            }
+        }
+        Ok(document)
+    }
+
+    fn read_meta(&mut self, meta: &AknElement) {
+        for block in meta.elements() {
+            match block.local_name() {
+                "identification" => {
+                    self.identification = FrbrIdentification::from_element(block);
+                }
+                "publication" => self.publication = Some(block.clone()),
+                "lifecycle" => self.lifecycle.extend(
+                    block
+                        .children_named("eventRef")
+                        .map(LifecycleEvent::from_element),
+                ),
+                "analysis" => {
+                    for group in block.elements() {
+                        let target = match group.local_name() {
+                            "activeModifications" => &mut self.active_modifications,
+                            "passiveModifications" => &mut self.passive_modifications,
+                            _ => {
+                                self.analysis_other.push(group.clone());
+                                continue;
+                            }
+                        };
+                        target.extend(group.elements().map(Modification::from_element));
+                    }
+                }
+                "temporalData" => self.temporal_groups.extend(
+                    block
+                        .children_named("temporalGroup")
+                        .map(TemporalGroup::from_element),
+                ),
+                "references" => self
+                    .references
+                    .extend(block.elements().map(Reference::from_element)),
+                _ => self.meta_other.push(block.clone()),
+            }
+        }
+    }
+
+    /// Returns the lifecycle event an href points to.
+    pub fn event(&self, href: &str) -> Option<&LifecycleEvent> {
+        let eid = fragment(href);
+        self.lifecycle.iter().find(|event| event.eid == eid)
+    }
+
+    /// Returns the temporal group an href points to.
+    pub fn temporal_group(&self, href: &str) -> Option<&TemporalGroup> {
+        let eid = fragment(href);
+        self.temporal_groups.iter().find(|group| group.eid == eid)
+    }
+
+    /// Returns the reference an href points to.
+    pub fn reference(&self, href: &str) -> Option<&Reference> {
+        let eid = fragment(href);
+        self.references
+            .iter()
+            .find(|reference| reference.eid == eid)
+    }
+
+    /// Returns the start and end dates of a temporal group's in-force interval.
+    ///
+    /// The interval referring to an in-force concept is preferred; otherwise
+    /// the first interval is used. The end date is exclusive.
+    pub fn in_force_period(&self, period: &str) -> Option<(Option<NaiveDate>, Option<NaiveDate>)> {
+        let group = self.temporal_group(period)?;
+        let interval = group
+            .intervals
+            .iter()
+            .find(|interval| {
+                interval
+                    .refers_to
+                    .as_deref()
+                    .is_some_and(|concept| concept.to_lowercase().contains("inforce"))
+            })
+            .or_else(|| group.intervals.first())?;
+        let date = |href: &Option<String>| {
+            href.as_deref()
+                .and_then(|href| self.event(href))
+                .and_then(|event| event.date)
+        };
+        Some((date(&interval.start), date(&interval.end)))
+    }
+
+    /// Returns the enactment date: the first generation event, or the work date.
+    pub fn enactment_date(&self) -> Option<NaiveDate> {
+        self.lifecycle
+            .iter()
+            .filter(|event| event.event_type == "generation")
+            .filter_map(|event| event.date)
+            .min()
+            .or(self.identification.work.date)
+    }
+
+    /// Checks the document against the constraints of the Akoma Ntoso schema
+    /// and the consistency of its internal references.
+    ///
+    /// Returns a description of every violation found.
+    pub fn validate(&self) -> Vec<String> {
+        let mut issues = Vec::new();
+        let identification = &self.identification;
+        if identification.source.is_empty() {
+            issues.push("identification has no source".to_string());
+        }
+        for (level, item) in [
+            ("FRBRWork", &identification.work),
+            ("FRBRExpression", &identification.expression),
+            ("FRBRManifestation", &identification.manifestation),
+        ] {
+            for (property, missing) in [
+                ("FRBRthis", item.this.is_empty()),
+                ("FRBRuri", item.uri.is_empty()),
+                ("FRBRdate", item.date.is_none()),
+                ("FRBRauthor", item.author.is_empty()),
+            ] {
+                if missing {
+                    issues.push(format!("{} has no {}", level, property));
+                }
+            }
+            if item.author.starts_with('#') && self.reference(&item.author).is_none() {
+                issues.push(format!(
+                    "{} author {} is not in the references",
+                    level, item.author
+                ));
+            }
+        }
+        if identification.country().is_none() {
+            issues.push("FRBRWork has no FRBRcountry".to_string());
+        }
+        if identification.language().is_none() {
+            issues.push("FRBRExpression has no FRBRlanguage".to_string());
+        }
+
+        for event in &self.lifecycle {
+            if event.date.is_none() {
+                issues.push(format!("eventRef {} has no valid date", event.eid));
+            }
+            if self.reference(&event.source).is_none() {
+                issues.push(format!(
+                    "eventRef {} refers to unknown source {}",
+                    event.eid, event.source
+                ));
+            }
+        }
+        for group in &self.temporal_groups {
+            for interval in &group.intervals {
+                for href in interval.start.iter().chain(&interval.end) {
+                    if self.event(href).is_none() {
+                        issues.push(format!(
+                            "temporalGroup {} refers to unknown event {}",
+                            group.eid, href
+                        ));
+                    }
+                }
+            }
+        }
+        for modification in self
+            .active_modifications
+            .iter()
+            .chain(&self.passive_modifications)
+        {
+            let eid = modification.eid.as_deref().unwrap_or("(unnamed)");
+            if modification.sources.is_empty() {
+                issues.push(format!("{} {} has no source", modification.kind, eid));
+            }
+            if modification.destinations.is_empty() {
+                issues.push(format!("{} {} has no destination", modification.kind, eid));
+            }
+        }
+
+        let mut seen = HashSet::new();
+        let mut stack: Vec<&AknUnit> = self.body.iter().collect();
+        while let Some(unit) = stack.pop() {
+            let eid = unit.eid.as_deref().unwrap_or("(unnamed)");
+            if let Some(eid) = &unit.eid
+                && !seen.insert(eid.as_str())
+            {
+                issues.push(format!("duplicate eId {}", eid));
+            }
+            if let Some(period) = &unit.period
+                && self.temporal_group(period).is_none()
+            {
+                issues.push(format!(
+                    "{} {} refers to unknown period {}",
+                    unit.kind, eid, period
+                ));
+            }
+            match (unit.content.is_some(), unit.children.is_empty()) {
+                (true, false) => issues.push(format!(
+                    "{} {} has both content and sub-units",
+                    unit.kind, eid
+                )),
+                (false, true) => issues.push(format!(
+                    "{} {} has neither content nor sub-units",
+                    unit.kind, eid
+                )),
+                _ => {}
+            }
+            stack.extend(&unit.children);
+        }
+        issues
+    }
+
+    /// Builds the XML tree of the document.
+    pub fn to_element(&self) -> AknElement {
+        let source = self.identification.source.as_str();
+        let mut meta = vec![self.identification.to_element()];
+        meta.extend(self.publication.clone());
+        if !self.lifecycle.is_empty() {
+            meta.push(self.lifecycle.iter().fold(
+                AknElement::new("lifecycle").with_attr("source", source),
+                |block, event| block.with_child(event.to_element()),
+            ));
+        }
+        if !self.active_modifications.is_empty()
+            || !self.passive_modifications.is_empty()
+            || !self.analysis_other.is_empty()
+        {
+            let mut analysis = AknElement::new("analysis").with_attr("source", source);
+            for (name, modifications) in [
+                ("activeModifications", &self.active_modifications),
+                ("passiveModifications", &self.passive_modifications),
+            ] {
+                if !modifications.is_empty() {
+                    analysis = analysis.with_child(
+                        modifications
+                            .iter()
+                            .fold(AknElement::new(name), |group, modification| {
+                                group.with_child(modification.to_element())
+                            }),
+                    );
+                }
+            }
+            for other in &self.analysis_other {
+                analysis = analysis.with_child(other.clone());
+            }
+            meta.push(analysis);
+        }
+        if !self.temporal_groups.is_empty() {
+            meta.push(self.temporal_groups.iter().fold(
+                AknElement::new("temporalData").with_attr("source", source),
+                |block, group| block.with_child(group.to_element()),
+            ));
+        }
+        if !self.references.is_empty() {
+            meta.push(self.references.iter().fold(
+                AknElement::new("references").with_attr("source", source),
+                |block, reference| block.with_child(reference.to_element()),
+            ));
+        }
+        meta.extend(self.meta_other.iter().cloned());
+        meta.sort_by_key(|block| {
+            META_ORDER
+                .iter()
+                .position(|name| *name == block.local_name())
+                .unwrap_or(META_ORDER.len())
+        });
+
+        let (cover, trailing): (Vec<_>, Vec<_>) = self
+            .other
+            .iter()
+            .partition(|element| element.local_name() == "coverPage");
+        let body_name = if self.body_name.is_empty() {
+            "body"
+        } else {
+            self.body_name.as_str()
+        };
+        let body = self
+            .body
+            .iter()
+            .map(AknUnit::to_element)
+            .chain(self.body_other.iter().cloned())
+            .fold(AknElement::new(body_name), AknElement::with_child);
+
+        let mut doc = AknElement::new(self.doc_type.as_str())
+            .with_attr("name", self.name.as_str())
+            .with_child(
+                meta.into_iter()
+                    .fold(AknElement::new("meta"), AknElement::with_child),
+            );
+        for element in cover {
+            doc = doc.with_child(element.clone());
+        }
+        for block in [&self.preface, &self.preamble].into_iter().flatten() {
+            doc = doc.with_child(block.clone());
+        }
+        doc = doc.with_child(body);
+        if let Some(conclusions) = &self.conclusions {
+            doc = doc.with_child(conclusions.clone());
+        }
+        for element in trailing {
+            doc = doc.with_child(element.clone());
+        }
+
+        AknElement::new("akomaNtoso")
+            .with_attr("xmlns", AKN_NAMESPACE)
+            .with_child(doc)
+    }
+
+    /// Serializes the document.
+    pub fn to_xml(&self) -> String {
+        self.to_element().to_xml()
+    }
+
+    /// Returns the statutes stored in the proprietary block by the exporter.
+    fn proprietary_statutes(&self) -> InteropResult<Vec<Statute>> {
+        let block = self
+            .meta_other
+            .iter()
+            .filter(|block| block.local_name() == "proprietary")
+            .find_map(|block| block.child("statutes"));
+        match block {
+            Some(block) => serde_json::from_str(&block.text())
+                .map_err(|e| InteropError::SerializationError(e.to_string())),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+// ==================================================
+// Import
+// ==================================================
+
+/// Result of importing an Akoma Ntoso document with its consolidation data.
+#[derive(Debug, Clone)]
+pub struct AknImport {
+    /// Parsed document
+    pub document: AknDocument,
+    /// Statutes for the document's provisions
+    pub statutes: Vec<Statute>,
+    /// Structure and amendment history keyed by unit ID
+    pub hierarchy: HashMap<String, StatuteHierarchy>,
+    /// Conversion report
+    pub report: ConversionReport,
+}
 
 /// Akoma Ntoso format importer.
 pub struct AkomaNtosoImporter {
@@ -38,133 +1318,68 @@ impl AkomaNtosoImporter {
         self
     }
 
-    /// Parses an Akoma Ntoso document and extracts statutes.
-    fn parse_document(&self, source: &str, report: &mut ConversionReport) -> Vec<Statute> {
-        let mut statutes = Vec::new();
-        let mut reader = Reader::from_str(source);
-        reader.config_mut().trim_text(true);
-
-        let mut current_id = String::new();
-        let mut current_title = String::new();
-        let mut current_content = String::new();
-        let mut in_article = false;
-        let mut in_heading = false;
-        let mut in_content = false;
-        let mut in_num = false;
-        let mut depth = 0;
+    /// Imports a document together with its hierarchy and amendment history.
+    ///
+    /// ```
+    /// use legalis_interop::akoma_ntoso::AkomaNtosoImporter;
+    ///
+    /// let source = r#"<akomaNtoso><act name="act"><body>
+    ///   <chapter eId="chp_1"><num>1</num>
+    ///     <section eId="sec_1"><heading>Scope</heading>
+    ///       <content><p>This Act applies to all residents.</p></content>
+    ///     </section>
+    ///   </chapter>
+    /// </body></act></akomaNtoso>"#;
+    ///
+    /// let import = AkomaNtosoImporter::new().import_document(source).unwrap();
+    /// assert_eq!(import.statutes[0].id, "sec-1");
+    /// assert_eq!(import.hierarchy["sec-1"].parent_id.as_deref(), Some("chp-1"));
+    /// ```
+    pub fn import_document(&self, source: &str) -> InteropResult<AknImport> {
+        let mut report = ConversionReport::new(LegalFormat::AkomaNtoso, LegalFormat::Legalis);
+        let document = AknDocument::parse(source)?;
+        for issue in document.validate() {
+            report.add_warning(issue);
+        }
 
-        loop {
-            match reader.read_event() {
-                Ok(Event::Start(ref e)) => {
-                    let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
-                    match name.as_str() {
-                        "article" | "section" | "rule" | "paragraph" => {
-                            in_article = true;
-                            depth += 1;
-                            // Extract eId attribute if present
-                            for attr in e.attributes().flatten() {
-                                if attr.key.as_ref() == b"eId" || attr.key.as_ref() == b"GUID" {
-                                    current_id = String::from_utf8_lossy(&attr.value).to_string();
-                                }
-                            }
-                        }
-                        "heading" | "title" => {
-                            in_heading = true;
-                        }
-                        "content" | "p" | "block" => {
-                            in_content = true;
-                        }
-                        "num" => {
-                            in_num = true;
-                        }
-                        _ => {}
-                    }
-                }
-                Ok(Event::End(ref e)) => {
-                    let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
-                    match name.as_str() {
-                        "article" | "section" | "rule" | "paragraph" => {
-                            depth -= 1;
-                            if depth == 0 && in_article {
-                                // Create statute from collected data
-                                if !current_title.is_empty() || !current_content.is_empty() {
-                                    let id = if current_id.is_empty() {
-                                        format!("akn-{}", statutes.len() + 1)
-                                    } else {
-                                        current_id.to_lowercase().replace(['_', ' '], "-")
-                                    };
-
-                                    let title = if current_title.is_empty() {
-                                        format!("Article {}", statutes.len() + 1)
-                                    } else {
-                                        current_title.clone()
-                                    };
-
-                                    let effect_desc = if current_content.is_empty() {
-                                        title.clone()
-                                    } else {
-                                        current_content.clone()
-                                    };
-
-                                    let statute = Statute::new(
-                                        &id,
-                                        &title,
-                                        Effect::new(EffectType::Grant, &effect_desc),
-                                    );
-
-                                    statutes.push(statute);
-                                }
-
-                                // Reset state
-                                current_id.clear();
-                                current_title.clear();
-                                current_content.clear();
-                                in_article = false;
-                            }
-                        }
-                        "heading" | "title" => {
-                            in_heading = false;
-                        }
-                        "content" | "p" | "block" => {
-                            in_content = false;
-                        }
-                        "num" => {
-                            in_num = false;
-                        }
-                        _ => {}
-                    }
-                }
-                Ok(Event::Text(e)) => {
-                    let text = std::str::from_utf8(e.as_ref())
-                        .unwrap_or_default()
-                        .trim()
-                        .to_string();
-                    if !text.is_empty() {
-                        if in_heading {
-                            if !current_title.is_empty() {
-                                current_title.push(' ');
-                            }
-                            current_title.push_str(&text);
-                        } else if in_content {
-                            if !current_content.is_empty() {
-                                current_content.push(' ');
-                            }
-                            current_content.push_str(&text);
-                        } else if in_num && current_id.is_empty() {
-                            current_id = text.to_lowercase().replace(['.', ' '], "-");
-                        }
-                    }
-                }
-                Ok(Event::Eof) => break,
-                Err(e) => {
-                    report.add_warning(format!("XML parse error: {}", e));
-                    break;
-                }
-                _ => {}
+        let proprietary = match document.proprietary_statutes() {
+            Ok(statutes) => statutes,
+            Err(e) => {
+                report.add_warning(format!("Ignoring proprietary statute data: {}", e));
+                Vec::new()
             }
+        };
+
+        let mut lowering = Lowering {
+            document: &document,
+            preserve_metadata: self.preserve_metadata,
+            proprietary: export_eids(&proprietary)
+                .into_iter()
+                .zip(proprietary)
+                .collect(),
+            statutes: Vec::new(),
+            hierarchy: HashMap::new(),
+        };
+        lowering.walk(&document.body, None, None, false);
+        let Lowering {
+            statutes,
+            hierarchy,
+            ..
+        } = lowering;
+
+        if statutes.is_empty() {
+            return Err(InteropError::ParseError(
+                "No valid Akoma Ntoso articles/sections found".to_string(),
+            ));
         }
 
-        statutes
+        report.statutes_converted = statutes.len();
+        Ok(AknImport {
+            document,
+            statutes,
+            hierarchy,
+            report,
+        })
     }
 }
 
@@ -180,42 +1395,327 @@ impl FormatImporter for AkomaNtosoImporter {
     }
 
     fn import(&self, source: &str) -> InteropResult<(Vec<Statute>, ConversionReport)> {
-        let mut report = ConversionReport::new(LegalFormat::AkomaNtoso, LegalFormat::Legalis);
+        let import = self.import_document(source)?;
+        Ok((import.statutes, import.report))
+    }
+
+    fn validate(&self, source: &str) -> bool {
+        source.contains("<akomaNtoso") || source.contains("<act") || source.contains("<bill")
+    }
+}
 
-        let statutes = self.parse_document(source, &mut report);
+/// Maps the units of a document onto statutes and hierarchy entries.
+struct Lowering<'a> {
+    document: &'a AknDocument,
+    preserve_metadata: bool,
+    /// Statutes from the proprietary block keyed by their exported eId
+    proprietary: HashMap<String, Statute>,
+    statutes: Vec<Statute>,
+    hierarchy: HashMap<String, StatuteHierarchy>,
+}
 
-        if statutes.is_empty() {
-            return Err(InteropError::ParseError(
-                "No valid Akoma Ntoso articles/sections found".to_string(),
-            ));
+impl<'a> Lowering<'a> {
+    fn walk(
+        &mut self,
+        units: &'a [AknUnit],
+        parent: Option<&str>,
+        inherited_period: Option<&'a str>,
+        in_provision: bool,
+    ) {
+        for unit in units {
+            let period = unit.period.as_deref().or(inherited_period);
+            let provision = !in_provision && PROVISION_ELEMENTS.contains(&unit.kind.as_str());
+
+            // A provision's statute covers its sub-units, so it collects their
+            // amendments and references; other units only their own.
+            let mut eids = HashSet::new();
+            if provision {
+                unit.collect_eids(&mut eids);
+            } else if let Some(eid) = &unit.eid {
+                eids.insert(eid.as_str());
+            }
+            let amendments: Vec<Amendment> = self
+                .document
+                .passive_modifications
+                .iter()
+                .enumerate()
+                .filter(|(_, m)| targets(&m.destinations, &eids))
+                .map(|(i, m)| self.amendment(m, i))
+                .collect();
+
+            let id = if provision {
+                Some(self.lower_provision(unit, parent, period, &amendments))
+            } else {
+                unit.eid.as_deref().map(normalize_id)
+            };
+
+            if let Some(id) = &id {
+                let entry = self.hierarchy_entry(unit, parent, provision, &eids, amendments);
+                self.hierarchy.insert(id.clone(), entry);
+            }
+
+            self.walk(
+                &unit.children,
+                id.as_deref().or(parent),
+                period,
+                in_provision || provision,
+            );
         }
+    }
 
-        // Note unsupported features
-        if source.contains("<condition") {
-            report.add_unsupported("Akoma Ntoso condition elements");
+    /// Creates the statute for a provision and returns its ID.
+    fn lower_provision(
+        &mut self,
+        unit: &AknUnit,
+        parent: Option<&str>,
+        period: Option<&str>,
+        amendments: &[Amendment],
+    ) -> String {
+        let index = self.statutes.len() + 1;
+        let id = unit
+            .eid
+            .as_deref()
+            .or_else(|| attribute(&unit.attributes, "GUID"))
+            .map(normalize_id)
+            .or_else(|| {
+                unit.num
+                    .as_deref()
+                    .map(|num| num.to_lowercase().replace(['.', ' '], "-"))
+            })
+            .unwrap_or_else(|| format!("akn-{}", index));
+
+        let text = unit.text();
+        if unit.heading.is_none() && unit.num.is_none() && text.is_empty() {
+            return id;
+        }
+        let title = unit
+            .heading
+            .clone()
+            .or_else(|| unit.num.clone())
+            .unwrap_or_else(|| format!("Article {}", index));
+        let description = if text.is_empty() { title.clone() } else { text };
+
+        let mut effect = Effect::new(EffectType::Grant, description);
+        if self.preserve_metadata {
+            let identification = &self.document.identification;
+            let metadata = [
+                ("akn_element", Some(unit.kind.as_str())),
+                ("akn_eid", unit.eid.as_deref()),
+                ("akn_num", unit.num.as_deref()),
+                ("akn_parent", parent),
+                ("akn_period", period),
+                ("akn_work", Some(identification.work.uri.as_str())),
+                (
+                    "akn_expression",
+                    Some(identification.expression.uri.as_str()),
+                ),
+                ("akn_language", identification.language()),
+            ];
+            for (key, value) in metadata {
+                if let Some(value) = value.filter(|v| !v.is_empty()) {
+                    effect.parameters.insert(key.to_string(), value.to_string());
+                }
+            }
         }
-        if source.contains("<temporal") {
-            report.add_unsupported("Akoma Ntoso temporal groups");
+
+        let mut statute = Statute::new(&id, title, effect);
+        statute.temporal_validity = self.temporal_validity(period, amendments);
+        if let Some(country) = self.document.identification.country() {
+            statute = statute.with_jurisdiction(country.to_uppercase());
         }
-        if source.contains("<lifecycle") {
-            report.add_unsupported("Akoma Ntoso lifecycle events");
+
+        // Statutes written by the exporter carry their logic in a proprietary
+        // block; the AKN metadata only fills what it leaves open.
+        if let Some(mut restored) = unit
+            .eid
+            .as_deref()
+            .and_then(|eid| self.proprietary.remove(eid))
+        {
+            let validity = &mut restored.temporal_validity;
+            let imported = statute.temporal_validity;
+            validity.effective_date = validity.effective_date.or(imported.effective_date);
+            validity.expiry_date = validity.expiry_date.or(imported.expiry_date);
+            validity.enacted_at = validity.enacted_at.or(imported.enacted_at);
+            validity.amended_at = validity.amended_at.or(imported.amended_at);
+            for (key, value) in statute.effect.parameters {
+                restored.effect.parameters.entry(key).or_insert(value);
+            }
+            statute = restored;
         }
 
-        report.statutes_converted = statutes.len();
-        Ok((statutes, report))
+        let id = statute.id.clone();
+        self.statutes.push(statute);
+        id
     }
 
-    fn validate(&self, source: &str) -> bool {
-        source.contains("<akomaNtoso") || source.contains("<act") || source.contains("<bill")
+    fn temporal_validity(
+        &self,
+        period: Option<&str>,
+        amendments: &[Amendment],
+    ) -> TemporalValidity {
+        let document = self.document;
+        let mut validity = TemporalValidity::new();
+        validity.enacted_at = document.enactment_date().map(midnight);
+
+        // A document with a single temporal group applies it throughout.
+        let period = period.or(match document.temporal_groups.as_slice() {
+            [group] => Some(group.eid.as_str()),
+            _ => None,
+        });
+        if let Some((start, end)) = period.and_then(|p| document.in_force_period(p)) {
+            validity.effective_date = start;
+            validity.expiry_date = end.and_then(|date| date.pred_opt());
+        }
+        if validity.expiry_date.is_none() {
+            validity.expiry_date = document
+                .lifecycle
+                .iter()
+                .filter(|event| event.event_type == "repeal")
+                .filter_map(|event| event.date)
+                .min()
+                .and_then(|date| date.pred_opt());
+        }
+        validity.amended_at = amendments.iter().map(|a| a.enacted_at).max();
+        validity
+    }
+
+    fn hierarchy_entry(
+        &self,
+        unit: &AknUnit,
+        parent: Option<&str>,
+        provision: bool,
+        eids: &HashSet<&str>,
+        amendments: Vec<Amendment>,
+    ) -> StatuteHierarchy {
+        let mut entry = StatuteHierarchy::new();
+        entry.parent_id = parent.map(str::to_string);
+        entry.child_ids = unit
+            .children
+            .iter()
+            .filter_map(|child| child.eid.as_deref().map(normalize_id))
+            .collect();
+
+        // Only a repeal of the unit itself supersedes it, not of a sub-unit.
+        if let Some(eid) = unit.eid.as_deref() {
+            entry.superseded_by = self
+                .document
+                .passive_modifications
+                .iter()
+                .enumerate()
+                .find(|(_, m)| {
+                    m.amendment_type() == AmendmentType::Deletion
+                        && m.destinations.iter().any(|d| fragment(d) == eid)
+                })
+                .map(|(i, m)| self.amendment(m, i).amending_statute_id);
+        }
+        entry.amendments = amendments;
+
+        for modification in &self.document.active_modifications {
+            if targets(&modification.sources, eids)
+                && matches!(
+                    modification.amendment_type(),
+                    AmendmentType::Substitution | AmendmentType::Deletion
+                )
+            {
+                entry
+                    .supersedes
+                    .extend(modification.destinations.iter().cloned());
+            }
+        }
+
+        let mut refs = Vec::new();
+        if provision {
+            unit.collect_refs(&mut refs);
+        } else {
+            let blocks = [&unit.intro, &unit.wrap_up, &unit.content];
+            for block in blocks.into_iter().flatten() {
+                block.collect_refs(&mut refs);
+            }
+        }
+        for href in refs {
+            let target = match href.strip_prefix('#') {
+                Some(eid) => normalize_id(eid),
+                None => href,
+            };
+            if !entry.cross_references.contains(&target) {
+                entry.cross_references.push(target);
+            }
+        }
+        entry
+    }
+
+    fn amendment(&self, modification: &Modification, index: usize) -> Amendment {
+        let document = self.document;
+        let source = modification.sources.first().map(String::as_str);
+        let reference = source.and_then(|source| document.reference(source));
+        let amending = reference
+            .map(|r| r.href.as_str())
+            .filter(|href| !href.is_empty())
+            .or(source.map(fragment))
+            .unwrap_or_default()
+            .to_string();
+        let label = reference
+            .map(|r| r.show_as.as_str())
+            .filter(|label| !label.is_empty())
+            .unwrap_or(&amending);
+        let description = format!(
+            "{} of {} by {}",
+            modification
+                .mod_type
+                .as_deref()
+                .unwrap_or(&modification.kind),
+            modification.destinations.join(", "),
+            label
+        );
+
+        let id = modification
+            .eid
+            .as_deref()
+            .map(normalize_id)
+            .unwrap_or_else(|| format!("mod-{}", index + 1));
+        let amendment = Amendment::new(id, amending, description, modification.amendment_type());
+
+        // The amending event shares the modification's source; failing that,
+        // the modification's in-force period or the expression date is used.
+        let date = document
+            .lifecycle
+            .iter()
+            .find(|event| {
+                modification
+                    .sources
+                    .iter()
+                    .any(|source| fragment(source) == fragment(&event.source))
+            })
+            .and_then(|event| event.date)
+            .or_else(|| {
+                modification
+                    .force
+                    .as_deref()
+                    .and_then(|period| document.in_force_period(period))
+                    .and_then(|(start, _)| start)
+            })
+            .or(document.identification.expression.date);
+        match date {
+            Some(date) => amendment.with_enacted_at(midnight(date)),
+            None => amendment,
+        }
     }
 }
 
+// ==================================================
+// Export
+// ==================================================
+
 /// Akoma Ntoso format exporter.
 pub struct AkomaNtosoExporter {
     /// Document type (act, bill, etc.)
     doc_type: String,
     /// Country code for the document
     country: String,
+    /// Expression language code (ISO 639-2)
+    language: String,
+    /// Work date; defaults to the earliest statute date
+    date: Option<NaiveDate>,
 }
 
 impl AkomaNtosoExporter {
@@ -224,6 +1724,8 @@ impl AkomaNtosoExporter {
         Self {
             doc_type: "act".to_string(),
             country: "un".to_string(),
+            language: "eng".to_string(),
+            date: None,
         }
     }
 
@@ -239,6 +1741,351 @@ impl AkomaNtosoExporter {
         self
     }
 
+    /// Sets the expression language code.
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = language.into();
+        self
+    }
+
+    /// Sets the work date used in the FRBR identifiers.
+    pub fn with_date(mut self, date: NaiveDate) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Exports statutes together with their amendment history.
+    ///
+    /// Amendments become passive modifications and `supersedes` entries
+    /// become active modifications. The document is checked with
+    /// [`AknDocument::validate`] before it is serialized.
+    pub fn export_with_hierarchy(
+        &self,
+        statutes: &[Statute],
+        hierarchy: &HashMap<String, StatuteHierarchy>,
+    ) -> InteropResult<(String, ConversionReport)> {
+        let mut report = ConversionReport::new(LegalFormat::Legalis, LegalFormat::AkomaNtoso);
+        let document = self.build_document(statutes, hierarchy, &mut report)?;
+
+        let issues = document.validate();
+        if !issues.is_empty() {
+            return Err(InteropError::ValidationError(issues.join("; ")));
+        }
+        Ok((document.to_xml(), report))
+    }
+
+    fn build_document(
+        &self,
+        statutes: &[Statute],
+        hierarchy: &HashMap<String, StatuteHierarchy>,
+        report: &mut ConversionReport,
+    ) -> InteropResult<AknDocument> {
+        let date = self
+            .date
+            .or_else(|| {
+                statutes
+                    .iter()
+                    .filter_map(|s| {
+                        let validity = &s.temporal_validity;
+                        validity
+                            .enacted_at
+                            .map(|t| t.date_naive())
+                            .or(validity.effective_date)
+                    })
+                    .min()
+            })
+            .unwrap_or_else(|| Utc::now().date_naive());
+
+        let work_uri = format!("/akn/{}/{}/{}/1", self.country, self.doc_type, date);
+        let expression_uri = format!("{}/{}@{}", work_uri, self.language, date);
+        let author = "#legalis";
+        let mut document = AknDocument::new(self.doc_type.as_str());
+        document.identification = FrbrIdentification {
+            source: author.to_string(),
+            work: FrbrItem {
+                this: format!("{}/!main", work_uri),
+                uri: work_uri,
+                date: Some(date),
+                date_name: "enactment".to_string(),
+                author: author.to_string(),
+                properties: vec![
+                    AknElement::new("FRBRcountry").with_attr("value", self.country.as_str()),
+                ],
+            },
+            expression: FrbrItem {
+                this: format!("{}/!main", expression_uri),
+                uri: expression_uri.clone(),
+                date: Some(date),
+                date_name: "expression".to_string(),
+                author: author.to_string(),
+                properties: vec![
+                    AknElement::new("FRBRlanguage").with_attr("language", self.language.as_str()),
+                ],
+            },
+            manifestation: FrbrItem {
+                this: format!("{}/!main.xml", expression_uri),
+                uri: format!("{}.akn", expression_uri),
+                date: Some(date),
+                date_name: "generation".to_string(),
+                author: author.to_string(),
+                properties: Vec::new(),
+            },
+        };
+        document.references = vec![
+            Reference::new(
+                "original",
+                "original",
+                document.identification.expression.this.clone(),
+                self.doc_type.as_str(),
+            ),
+            Reference::new(
+                "TLCOrganization",
+                "legalis",
+                "/ontology/organization/legalis",
+                "Legalis",
+            ),
+        ];
+
+        for (i, (statute, eid)) in statutes.iter().zip(export_eids(statutes)).enumerate() {
+            let mut unit = self.statute_unit(statute, &eid, i, report);
+            unit.period = Self::add_period(&mut document, &statute.temporal_validity);
+
+            if let Some(entry) = hierarchy.get(&statute.id) {
+                for amendment in &entry.amendments {
+                    Self::add_amendment(&mut document, amendment, &eid);
+                }
+                for target in &entry.supersedes {
+                    let count = document.active_modifications.len();
+                    document.active_modifications.push(Modification {
+                        kind: "textualMod".to_string(),
+                        eid: Some(format!("amod_{}", count + 1)),
+                        mod_type: Some("substitution".to_string()),
+                        sources: vec![format!("#{}", eid)],
+                        destinations: vec![target.clone()],
+                        ..Default::default()
+                    });
+                }
+            }
+
+            document.body.push(unit);
+            report.statutes_converted += 1;
+        }
+
+        if !document.temporal_groups.is_empty() {
+            document.references.push(Reference::new(
+                "TLCConcept",
+                "inForce",
+                "/ontology/concept/inForce",
+                "In force",
+            ));
+        }
+        document.lifecycle.sort_by_key(|event| event.date);
+
+        let json = serde_json::to_string(statutes)
+            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
+        document.meta_other.push(
+            AknElement::new("proprietary")
+                .with_attr("source", author)
+                .with_child(
+                    AknElement::new("legalis:statutes")
+                        .with_attr("xmlns:legalis", LEGALIS_NAMESPACE)
+                        .with_text(json),
+                ),
+        );
+        Ok(document)
+    }
+
+    /// Builds the body unit for a statute.
+    fn statute_unit(
+        &self,
+        statute: &Statute,
+        eid: &str,
+        index: usize,
+        report: &mut ConversionReport,
+    ) -> AknUnit {
+        let parameters = &statute.effect.parameters;
+        let kind = parameters
+            .get("akn_element")
+            .map(String::as_str)
+            .filter(|kind| is_hierarchy(kind))
+            .unwrap_or("article");
+        let mut unit = AknUnit::new(kind, eid);
+        unit.num = Some(
+            parameters
+                .get("akn_num")
+                .cloned()
+                .unwrap_or_else(|| format!("Article {}", index + 1)),
+        );
+        unit.heading = Some(statute.title.clone());
+
+        let mut paragraphs = Vec::new();
+        if !statute.preconditions.is_empty() {
+            let conditions: Vec<String> = statute
+                .preconditions
+                .iter()
+                .map(Self::condition_to_comment)
+                .collect();
+            paragraphs.push(
+                AknElement::new("p").with_text(format!("When: {}", conditions.join(" AND "))),
+            );
+        }
+        paragraphs.push(AknElement::new("p").with_text(format!(
+            "Effect: {} - {}",
+            statute.effect.effect_type, statute.effect.description
+        )));
+        if let Some(discretion) = &statute.discretion_logic {
+            report.add_warning(format!(
+                "Discretion '{}' added as remark element",
+                discretion
+            ));
+            paragraphs.push(
+                AknElement::new("p").with_child(
+                    AknElement::new("remark")
+                        .with_attr("type", "discretion")
+                        .with_text(discretion.as_str()),
+                ),
+            );
+        }
+
+        unit.children = paragraphs
+            .into_iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let mut paragraph = AknUnit::new("paragraph", format!("{}__para_{}", eid, i + 1));
+                paragraph.content = Some(AknElement::new("content").with_child(p));
+                paragraph
+            })
+            .collect();
+        unit
+    }
+
+    /// Records a statute's validity as lifecycle events and a temporal group,
+    /// returning the `period` reference.
+    fn add_period(document: &mut AknDocument, validity: &TemporalValidity) -> Option<String> {
+        if let Some(enacted) = validity.enacted_at {
+            Self::add_event(document, enacted.date_naive(), "#original", "generation");
+        }
+        let start = validity
+            .effective_date
+            .map(|date| Self::add_event(document, date, "#original", "generation"));
+        // Expiry dates are inclusive, interval ends are not.
+        let end = validity
+            .expiry_date
+            .and_then(|date| date.succ_opt())
+            .map(|date| Self::add_event(document, date, "#original", "repeal"));
+        if start.is_none() && end.is_none() {
+            return None;
+        }
+
+        let interval = TimeInterval {
+            start,
+            end,
+            duration: None,
+            refers_to: Some("#inForce".to_string()),
+        };
+        let existing = document
+            .temporal_groups
+            .iter()
+            .find(|group| group.intervals.as_slice() == std::slice::from_ref(&interval));
+        let eid = match existing {
+            Some(group) => group.eid.clone(),
+            None => {
+                let eid = format!("period_{}", document.temporal_groups.len() + 1);
+                document.temporal_groups.push(TemporalGroup {
+                    eid: eid.clone(),
+                    intervals: vec![interval],
+                });
+                eid
+            }
+        };
+        Some(format!("#{}", eid))
+    }
+
+    /// Records an amendment as a passive modification of a unit.
+    fn add_amendment(document: &mut AknDocument, amendment: &Amendment, target: &str) {
+        let existing = document
+            .references
+            .iter()
+            .find(|r| r.kind == "passiveRef" && r.href == amendment.amending_statute_id);
+        let reference = match existing {
+            Some(reference) => reference.eid.clone(),
+            None => {
+                let count = document
+                    .references
+                    .iter()
+                    .filter(|r| r.kind == "passiveRef")
+                    .count();
+                let eid = format!("passive_{}", count + 1);
+                document.references.push(Reference::new(
+                    "passiveRef",
+                    &eid,
+                    amendment.amending_statute_id.as_str(),
+                    amendment.amending_statute_id.as_str(),
+                ));
+                eid
+            }
+        };
+        let source = format!("#{}", reference);
+        Self::add_event(
+            document,
+            amendment.enacted_at.date_naive(),
+            &source,
+            "amendment",
+        );
+
+        let (kind, mod_type) = Modification::for_amendment_type(amendment.amendment_type);
+        let eid = export_eid(&amendment.id);
+        let taken = document
+            .passive_modifications
+            .iter()
+            .any(|m| m.eid.as_deref() == Some(eid.as_str()));
+        let eid = if taken {
+            format!("pmod_{}", document.passive_modifications.len() + 1)
+        } else {
+            eid
+        };
+        document.passive_modifications.push(Modification {
+            kind: kind.to_string(),
+            eid: Some(eid),
+            mod_type: mod_type.map(str::to_string),
+            sources: vec![source],
+            destinations: vec![format!("#{}", target)],
+            ..Default::default()
+        });
+    }
+
+    /// Returns a reference to the lifecycle event for a date and source,
+    /// adding the event when it is missing.
+    fn add_event(
+        document: &mut AknDocument,
+        date: NaiveDate,
+        source: &str,
+        event_type: &str,
+    ) -> String {
+        let existing = document.lifecycle.iter().find(|event| {
+            event.date == Some(date) && event.source == source && event.event_type == event_type
+        });
+        let eid = match existing {
+            Some(event) => event.eid.clone(),
+            None => {
+                let eid = format!("e_{}", date.format("%Y%m%d"));
+                let eid = if document.event(&eid).is_some() {
+                    format!("{}_{}", eid, document.lifecycle.len() + 1)
+                } else {
+                    eid
+                };
+                document.lifecycle.push(LifecycleEvent {
+                    eid: eid.clone(),
+                    date: Some(date),
+                    source: source.to_string(),
+                    event_type: event_type.to_string(),
+                    refers_to: None,
+                });
+                eid
+            }
+        };
+        format!("#{}", eid)
+    }
+
     /// Converts a condition to Akoma Ntoso comment format.
     fn condition_to_comment(condition: &Condition) -> String {
         match condition {
@@ -285,231 +2132,7 @@ impl FormatExporter for AkomaNtosoExporter {
     }
 
     fn export(&self, statutes: &[Statute]) -> InteropResult<(String, ConversionReport)> {
-        let mut report = ConversionReport::new(LegalFormat::Legalis, LegalFormat::AkomaNtoso);
-        let mut writer = Writer::new(Cursor::new(Vec::new()));
-
-        // XML declaration
-        writer
-            .write_event(Event::Decl(quick_xml::events::BytesDecl::new(
-                "1.0",
-                Some("UTF-8"),
-                None,
-            )))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        // Root element
-        let mut root = BytesStart::new("akomaNtoso");
-        root.push_attribute(("xmlns", "http://docs.oasis-open.org/legaldocml/ns/akn/3.0"));
-        writer
-            .write_event(Event::Start(root))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        // Document type element (act, bill, etc.)
-        let doc_start = BytesStart::new(self.doc_type.as_str());
-        writer
-            .write_event(Event::Start(doc_start))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        // Meta section
-        writer
-            .write_event(Event::Start(BytesStart::new("meta")))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        // Identification
-        let mut ident = BytesStart::new("identification");
-        ident.push_attribute(("source", "#legalis"));
-        writer
-            .write_event(Event::Start(ident))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        // FRBRWork
-        writer
-            .write_event(Event::Start(BytesStart::new("FRBRWork")))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        let mut frbrthis = BytesStart::new("FRBRthis");
-        frbrthis.push_attribute((
-            "value",
-            format!("/akn/{}/{}/main", self.country, self.doc_type).as_str(),
-        ));
-        writer
-            .write_event(Event::Empty(frbrthis))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        let mut frbrcountry = BytesStart::new("FRBRcountry");
-        frbrcountry.push_attribute(("value", self.country.as_str()));
-        writer
-            .write_event(Event::Empty(frbrcountry))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        writer
-            .write_event(Event::End(BytesEnd::new("FRBRWork")))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        writer
-            .write_event(Event::End(BytesEnd::new("identification")))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        writer
-            .write_event(Event::End(BytesEnd::new("meta")))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        // Body section
-        writer
-            .write_event(Event::Start(BytesStart::new("body")))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        // Convert each statute to an article
-        for (i, statute) in statutes.iter().enumerate() {
-            let mut article = BytesStart::new("article");
-            article.push_attribute(("eId", statute.id.as_str()));
-            writer
-                .write_event(Event::Start(article))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            // Article number
-            writer
-                .write_event(Event::Start(BytesStart::new("num")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-            writer
-                .write_event(Event::Text(BytesText::new(&format!("Article {}", i + 1))))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-            writer
-                .write_event(Event::End(BytesEnd::new("num")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            // Heading (title)
-            writer
-                .write_event(Event::Start(BytesStart::new("heading")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-            writer
-                .write_event(Event::Text(BytesText::new(&statute.title)))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-            writer
-                .write_event(Event::End(BytesEnd::new("heading")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            // Content paragraph with conditions
-            if !statute.preconditions.is_empty() {
-                let mut para = BytesStart::new("paragraph");
-                para.push_attribute(("eId", format!("{}_para1", statute.id).as_str()));
-                writer
-                    .write_event(Event::Start(para))
-                    .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-                writer
-                    .write_event(Event::Start(BytesStart::new("content")))
-                    .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-                writer
-                    .write_event(Event::Start(BytesStart::new("p")))
-                    .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-                let conditions: Vec<String> = statute
-                    .preconditions
-                    .iter()
-                    .map(Self::condition_to_comment)
-                    .collect();
-
-                let condition_text = format!("When: {}", conditions.join(" AND "));
-                writer
-                    .write_event(Event::Text(BytesText::new(&condition_text)))
-                    .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-                writer
-                    .write_event(Event::End(BytesEnd::new("p")))
-                    .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-                writer
-                    .write_event(Event::End(BytesEnd::new("content")))
-                    .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-                writer
-                    .write_event(Event::End(BytesEnd::new("paragraph")))
-                    .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-            }
-
-            // Effect paragraph
-            let mut effect_para = BytesStart::new("paragraph");
-            effect_para.push_attribute(("eId", format!("{}_effect", statute.id).as_str()));
-            writer
-                .write_event(Event::Start(effect_para))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            writer
-                .write_event(Event::Start(BytesStart::new("content")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            writer
-                .write_event(Event::Start(BytesStart::new("p")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            let effect_text = format!(
-                "Effect: {} - {}",
-                statute.effect.effect_type, statute.effect.description
-            );
-            writer
-                .write_event(Event::Text(BytesText::new(&effect_text)))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            writer
-                .write_event(Event::End(BytesEnd::new("p")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            writer
-                .write_event(Event::End(BytesEnd::new("content")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            writer
-                .write_event(Event::End(BytesEnd::new("paragraph")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            // Discretion note if present
-            if let Some(ref discretion) = statute.discretion_logic {
-                report.add_warning(format!(
-                    "Discretion '{}' added as remark element",
-                    discretion
-                ));
-
-                let mut remark = BytesStart::new("remark");
-                remark.push_attribute(("type", "discretion"));
-                writer
-                    .write_event(Event::Start(remark))
-                    .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-                writer
-                    .write_event(Event::Text(BytesText::new(discretion)))
-                    .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-                writer
-                    .write_event(Event::End(BytesEnd::new("remark")))
-                    .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-            }
-
-            writer
-                .write_event(Event::End(BytesEnd::new("article")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            report.statutes_converted += 1;
-        }
-
-        // Close body
-        writer
-            .write_event(Event::End(BytesEnd::new("body")))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        // Close document type
-        writer
-            .write_event(Event::End(BytesEnd::new(&self.doc_type)))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        // Close root
-        writer
-            .write_event(Event::End(BytesEnd::new("akomaNtoso")))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        let output = String::from_utf8(writer.into_inner().into_inner())
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        Ok((output, report))
+        self.export_with_hierarchy(statutes, &HashMap::new())
     }
 
     fn can_represent(&self, statute: &Statute) -> Vec<String> {
@@ -531,11 +2154,211 @@ impl FormatExporter for AkomaNtosoExporter {
     }
 }
 
+// ==================================================
+// Helpers
+// ==================================================
+
+fn is_hierarchy(name: &str) -> bool {
+    HIERARCHY_ELEMENTS.contains(&name)
+}
+
+/// Returns the identifier of a metadata element (`eId`, or `id` in AKN 2.0).
+fn element_id(element: &AknElement) -> Option<&str> {
+    element.attr("eId").or_else(|| element.attr("id"))
+}
+
+fn attribute<'a>(attributes: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Returns the part of an href after `#`, or the whole href.
+fn fragment(href: &str) -> &str {
+    href.rsplit_once('#').map_or(href, |(_, eid)| eid)
+}
+
+/// Returns whether any href points at one of the identifiers.
+fn targets(hrefs: &[String], eids: &HashSet<&str>) -> bool {
+    hrefs.iter().any(|href| eids.contains(fragment(href)))
+}
+
+/// Converts an eId into a statute ID.
+fn normalize_id(eid: &str) -> String {
+    eid.to_lowercase().replace(['_', ' '], "-")
+}
+
+/// Converts a statute ID into a valid eId.
+fn export_eid(id: &str) -> String {
+    let eid: String = id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if eid.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        eid
+    } else {
+        format!("_{}", eid)
+    }
+}
+
+/// Assigns each statute a unique eId.
+fn export_eids(statutes: &[Statute]) -> Vec<String> {
+    let mut used = HashSet::new();
+    statutes
+        .iter()
+        .map(|statute| {
+            let base = export_eid(&statute.id);
+            let mut eid = base.clone();
+            let mut n = 1;
+            while !used.insert(eid.clone()) {
+                n += 1;
+                eid = format!("{}_{}", base, n);
+            }
+            eid
+        })
+        .collect()
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.get(..10)?, "%Y-%m-%d").ok()
+}
+
+fn midnight(date: NaiveDate) -> DateTime<Utc> {
+    date.and_time(NaiveTime::MIN).and_utc()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use legalis_core::ComparisonOp;
 
+    const CONSOLIDATED_ACT: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<akomaNtoso xmlns="http://docs.oasis-open.org/legaldocml/ns/akn/3.0">
+  <act name="act">
+    <meta>
+      <identification source="#parliament">
+        <FRBRWork>
+          <FRBRthis value="/akn/ke/act/2019/12/!main"/>
+          <FRBRuri value="/akn/ke/act/2019/12"/>
+          <FRBRdate date="2019-06-01" name="enactment"/>
+          <FRBRauthor href="#parliament"/>
+          <FRBRcountry value="ke"/>
+          <FRBRnumber value="12"/>
+        </FRBRWork>
+        <FRBRExpression>
+          <FRBRthis value="/akn/ke/act/2019/12/eng@2021-03-01/!main"/>
+          <FRBRuri value="/akn/ke/act/2019/12/eng@2021-03-01"/>
+          <FRBRdate date="2021-03-01" name="consolidation"/>
+          <FRBRauthor href="#parliament"/>
+          <FRBRlanguage language="eng"/>
+        </FRBRExpression>
+        <FRBRManifestation>
+          <FRBRthis value="/akn/ke/act/2019/12/eng@2021-03-01/!main.xml"/>
+          <FRBRuri value="/akn/ke/act/2019/12/eng@2021-03-01.akn"/>
+          <FRBRdate date="2021-03-05" name="generation"/>
+          <FRBRauthor href="#gazette"/>
+        </FRBRManifestation>
+      </identification>
+      <lifecycle source="#parliament">
+        <eventRef eId="e_enact" date="2019-06-01" source="#original" type="generation"/>
+        <eventRef eId="e_force" date="2019-07-01" source="#original" type="generation"/>
+        <eventRef eId="e_amend" date="2021-03-01" source="#amending_act" type="amendment"/>
+      </lifecycle>
+      <analysis source="#parliament">
+        <activeModifications>
+          <textualMod eId="amod_1" type="substitution">
+            <source href="#sec_1"/>
+            <destination href="/akn/ke/act/2010/4/!main#sec_5"/>
+          </textualMod>
+        </activeModifications>
+        <passiveModifications>
+          <textualMod eId="pmod_1" type="substitution">
+            <source href="#amending_act"/>
+            <destination href="#sec_2__subsec_1"/>
+            <old>30 days</old>
+            <new>14 days</new>
+          </textualMod>
+          <textualMod eId="pmod_2" type="repeal">
+            <source href="#amending_act"/>
+            <destination href="#sec_3"/>
+          </textualMod>
+        </passiveModifications>
+      </analysis>
+      <temporalData source="#parliament">
+        <temporalGroup eId="period_1">
+          <timeInterval start="#e_force" refersTo="#inForce"/>
+        </temporalGroup>
+        <temporalGroup eId="period_2">
+          <timeInterval start="#e_force" end="#e_amend" refersTo="#inForce"/>
+        </temporalGroup>
+      </temporalData>
+      <references source="#parliament">
+        <original eId="original" href="/akn/ke/act/2019/12/eng@2019-06-01/!main" showAs="Data Act"/>
+        <passiveRef eId="amending_act" href="/akn/ke/act/2021/3/!main" showAs="Data (Amendment) Act"/>
+        <TLCOrganization eId="parliament" href="/ontology/organization/ke/parliament" showAs="Parliament"/>
+        <TLCOrganization eId="gazette" href="/ontology/organization/ke/gazette" showAs="Gazette"/>
+        <TLCConcept eId="inForce" href="/ontology/concept/inForce" showAs="In force"/>
+      </references>
+    </meta>
+    <body>
+      <part eId="part_1" period="#period_1">
+        <num>Part I</num>
+        <heading>Preliminary</heading>
+        <section eId="sec_1">
+          <num>1.</num>
+          <heading>Amendment of the Registration Act</heading>
+          <content>
+            <p>Section 5 of the Registration Act is replaced.</p>
+          </content>
+        </section>
+        <chapter eId="part_1__chp_1">
+          <num>Chapter 1</num>
+          <heading>Data controllers</heading>
+          <section eId="sec_2">
+            <num>2.</num>
+            <heading>Registration</heading>
+            <subsection eId="sec_2__subsec_1">
+              <num>(1)</num>
+              <content>
+                <p>A controller registers with the <ref href="#sec_3">Commissioner</ref> within 14 days.</p>
+              </content>
+            </subsection>
+            <subsection eId="sec_2__subsec_2">
+              <num>(2)</num>
+              <content>
+                <p>Registration is renewed every three years.</p>
+              </content>
+            </subsection>
+          </section>
+          <section eId="sec_3" period="#period_2">
+            <num>3.</num>
+            <heading>Commissioner</heading>
+            <content>
+              <p>The Commissioner keeps the register.</p>
+            </content>
+          </section>
+        </chapter>
+      </part>
+    </body>
+  </act>
+</akomaNtoso>
+"##;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn statute<'a>(import: &'a AknImport, id: &str) -> &'a Statute {
+        import.statutes.iter().find(|s| s.id == id).unwrap()
+    }
+
     #[test]
     fn test_akoma_ntoso_importer_validate() {
         let importer = AkomaNtosoImporter::new();
@@ -595,6 +2418,154 @@ mod tests {
         assert_eq!(report.statutes_converted, 2);
     }
 
+    #[test]
+    fn test_akoma_ntoso_frbr_identification() {
+        let import = AkomaNtosoImporter::new()
+            .import_document(CONSOLIDATED_ACT)
+            .unwrap();
+        let identification = &import.document.identification;
+
+        assert_eq!(identification.work.uri, "/akn/ke/act/2019/12");
+        assert_eq!(identification.work.date, Some(date(2019, 6, 1)));
+        assert_eq!(
+            identification.work.property("FRBRnumber", "value"),
+            Some("12")
+        );
+        assert_eq!(identification.country(), Some("ke"));
+        assert_eq!(identification.language(), Some("eng"));
+        assert_eq!(identification.expression.date_name, "consolidation");
+        assert_eq!(identification.manifestation.author, "#gazette");
+        assert!(
+            import.report.warnings.is_empty(),
+            "{:?}",
+            import.report.warnings
+        );
+
+        let registration = statute(&import, "sec-2");
+        assert_eq!(registration.jurisdiction.as_deref(), Some("KE"));
+        assert_eq!(
+            registration
+                .effect
+                .parameters
+                .get("akn_expression")
+                .map(String::as_str),
+            Some("/akn/ke/act/2019/12/eng@2021-03-01")
+        );
+    }
+
+    #[test]
+    fn test_akoma_ntoso_hierarchy() {
+        let import = AkomaNtosoImporter::new()
+            .import_document(CONSOLIDATED_ACT)
+            .unwrap();
+
+        let ids: Vec<&str> = import.statutes.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, ["sec-1", "sec-2", "sec-3"]);
+        assert_eq!(
+            statute(&import, "sec-2").effect.description,
+            "(1) A controller registers with the Commissioner within 14 days. \
+             (2) Registration is renewed every three years."
+        );
+
+        let hierarchy = &import.hierarchy;
+        assert!(hierarchy["part-1"].is_root());
+        assert_eq!(hierarchy["part-1"].child_ids, ["sec-1", "part-1--chp-1"]);
+        assert_eq!(
+            hierarchy["sec-2"].parent_id.as_deref(),
+            Some("part-1--chp-1")
+        );
+        assert_eq!(
+            hierarchy["sec-2"].child_ids,
+            ["sec-2--subsec-1", "sec-2--subsec-2"]
+        );
+        assert_eq!(
+            hierarchy["sec-2--subsec-1"].parent_id.as_deref(),
+            Some("sec-2")
+        );
+        assert_eq!(hierarchy["sec-2"].cross_references, ["sec-3"]);
+    }
+
+    #[test]
+    fn test_akoma_ntoso_lifecycle_and_temporal_groups() {
+        let import = AkomaNtosoImporter::new()
+            .import_document(CONSOLIDATED_ACT)
+            .unwrap();
+
+        // Inherits the part's open-ended period
+        let registration = &statute(&import, "sec-2").temporal_validity;
+        assert_eq!(registration.effective_date, Some(date(2019, 7, 1)));
+        assert_eq!(registration.expiry_date, None);
+        assert_eq!(registration.enacted_at, Some(midnight(date(2019, 6, 1))));
+        assert_eq!(registration.amended_at, Some(midnight(date(2021, 3, 1))));
+
+        // The interval end is exclusive, the expiry date inclusive
+        let commissioner = &statute(&import, "sec-3").temporal_validity;
+        assert_eq!(commissioner.expiry_date, Some(date(2021, 2, 28)));
+        assert!(commissioner.is_active(date(2020, 1, 1)));
+        assert!(!commissioner.is_active(date(2021, 3, 1)));
+        assert!(!commissioner.is_active(date(2019, 6, 15)));
+    }
+
+    #[test]
+    fn test_akoma_ntoso_modifications() {
+        let import = AkomaNtosoImporter::new()
+            .import_document(CONSOLIDATED_ACT)
+            .unwrap();
+        let hierarchy = &import.hierarchy;
+
+        let amendments = &hierarchy["sec-2"].amendments;
+        assert_eq!(amendments.len(), 1);
+        assert_eq!(amendments[0].id, "pmod-1");
+        assert_eq!(amendments[0].amendment_type, AmendmentType::Substitution);
+        assert_eq!(
+            amendments[0].amending_statute_id,
+            "/akn/ke/act/2021/3/!main"
+        );
+        assert_eq!(amendments[0].enacted_at, midnight(date(2021, 3, 1)));
+        assert!(amendments[0].description.contains("Data (Amendment) Act"));
+        assert_eq!(hierarchy["sec-2--subsec-1"].amendment_count(), 1);
+        assert_eq!(hierarchy["sec-2--subsec-2"].amendment_count(), 0);
+
+        let commissioner = &hierarchy["sec-3"];
+        assert_eq!(
+            commissioner.amendments[0].amendment_type,
+            AmendmentType::Deletion
+        );
+        assert_eq!(
+            commissioner.superseded_by.as_deref(),
+            Some("/akn/ke/act/2021/3/!main")
+        );
+
+        assert_eq!(
+            hierarchy["sec-1"].supersedes,
+            ["/akn/ke/act/2010/4/!main#sec_5"]
+        );
+        // Verbatim children of the modification survive
+        let substitution = &import.document.passive_modifications[0];
+        assert_eq!(substitution.extra[1].text(), "14 days");
+    }
+
+    #[test]
+    fn test_akoma_ntoso_document_roundtrip() {
+        let document = AknDocument::parse(CONSOLIDATED_ACT).unwrap();
+        assert!(document.validate().is_empty());
+
+        let xml = document.to_xml();
+        assert!(xml.contains("with the <ref href=\"#sec_3\">Commissioner</ref> within"));
+        assert_eq!(AknDocument::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_akoma_ntoso_validate_reports_broken_references() {
+        let mut document = AknDocument::parse(CONSOLIDATED_ACT).unwrap();
+        document.temporal_groups.remove(1);
+        document.identification.expression.properties.clear();
+
+        let issues = document.validate();
+        assert!(issues.contains(&"FRBRExpression has no FRBRlanguage".to_string()));
+        assert!(issues.contains(&"section sec_3 refers to unknown period #period_2".to_string()));
+    }
+
     #[test]
     fn test_akoma_ntoso_exporter_basic() {
         let exporter = AkomaNtosoExporter::new();
@@ -618,6 +2589,79 @@ mod tests {
         assert_eq!(report.statutes_converted, 1);
     }
 
+    #[test]
+    fn test_akoma_ntoso_exporter_consolidation_data() {
+        let mut statute = Statute::new(
+            "data_registration",
+            "Registration",
+            Effect::new(EffectType::Obligation, "Register with the Commissioner"),
+        );
+        statute.temporal_validity = TemporalValidity::new()
+            .with_enacted_at(midnight(date(2019, 6, 1)))
+            .with_effective_date(date(2019, 7, 1))
+            .with_expiry_date(date(2024, 12, 31));
+        let hierarchy = HashMap::from([(
+            statute.id.clone(),
+            StatuteHierarchy::new()
+                .with_amendment(
+                    Amendment::new(
+                        "amend-1",
+                        "/akn/ke/act/2021/3/!main",
+                        "Shorter deadline",
+                        AmendmentType::Substitution,
+                    )
+                    .with_enacted_at(midnight(date(2021, 3, 1))),
+                )
+                .with_supersedes("/akn/ke/act/2010/4/!main#sec_5"),
+        )]);
+
+        let (xml, _) = AkomaNtosoExporter::new()
+            .with_country("ke")
+            .export_with_hierarchy(std::slice::from_ref(&statute), &hierarchy)
+            .unwrap();
+        let document = AknDocument::parse(&xml).unwrap();
+        assert!(document.validate().is_empty());
+        assert_eq!(document.identification.work.uri, "/akn/ke/act/2019-06-01/1");
+        let period = document.body[0].period.as_deref().unwrap();
+        assert_eq!(
+            document.in_force_period(period),
+            Some((Some(date(2019, 7, 1)), Some(date(2025, 1, 1))))
+        );
+
+        // The proprietary block restores the statute as exported
+        let import = AkomaNtosoImporter::new().import_document(&xml).unwrap();
+        let restored = &import.statutes[0];
+        assert_eq!(restored.id, "data_registration");
+        assert_eq!(restored.effect.effect_type, EffectType::Obligation);
+        assert_eq!(
+            restored.temporal_validity.expiry_date,
+            Some(date(2024, 12, 31))
+        );
+        let entry = &import.hierarchy["data_registration"];
+        assert_eq!(entry.amendments[0].id, "amend-1");
+        assert_eq!(
+            entry.amendments[0].amendment_type,
+            AmendmentType::Substitution
+        );
+        assert_eq!(entry.supersedes, ["/akn/ke/act/2010/4/!main#sec_5"]);
+
+        // Without it, the AKN metadata alone carries the consolidation data
+        let mut document = document;
+        document.meta_other.clear();
+        let import = AkomaNtosoImporter::new()
+            .import_document(&document.to_xml())
+            .unwrap();
+        let validity = &import.statutes[0].temporal_validity;
+        assert_eq!(import.statutes[0].id, "data-registration");
+        assert_eq!(validity.enacted_at, Some(midnight(date(2019, 6, 1))));
+        assert_eq!(validity.effective_date, Some(date(2019, 7, 1)));
+        assert_eq!(validity.expiry_date, Some(date(2024, 12, 31)));
+        assert_eq!(validity.amended_at, Some(midnight(date(2021, 3, 1))));
+        let amendment = &import.hierarchy["data-registration"].amendments[0];
+        assert_eq!(amendment.id, "amend-1");
+        assert_eq!(amendment.enacted_at, midnight(date(2021, 3, 1)));
+    }
+
     #[test]
     fn test_akoma_ntoso_roundtrip() {
         let exporter = AkomaNtosoExporter::new();
@@ -8,11 +8,32 @@
 //! - Scope-based organization
 //! - Strong typing with dates, money, durations
 //! - Default logic for legal reasoning
+//!
+//! The importer parses sources with [`crate::catala_parser`] and lowers them:
+//! - Every scope becomes a statute whose effect computes the scope's variables
+//!   as [`EffectOutput`] formulas, with exceptions folded into
+//!   `if`/`then`/`else` chains by default-logic priority
+//! - A scope defining a single condition becomes a grant whose preconditions
+//!   are the conjuncts of that condition
+//! - Conditional, labelled and exceptional definitions additionally become
+//!   rule statutes chained through [`StatuteException`]s
+//!
+//! Constructs without a legalis-core counterpart are handled by the
+//! importer's [`FallbackConfig`] and scored in a [`FidelityScore`].
 
+use crate::catala_parser::{
+    BinaryOp, CatalaLanguage, CatalaProgram, CatalaType, Consequence, Definition, DurationUnit,
+    Expr, LawSection, ScopeDeclaration, ScopeItem, ScopeVariable,
+};
+use crate::fidelity::{FallbackConfig, FallbackStrategy, FidelityScore};
 use crate::{
     ConversionReport, FormatExporter, FormatImporter, InteropError, InteropResult, LegalFormat,
 };
-use legalis_core::{ComparisonOp, Condition, Effect, EffectType, Statute};
+use legalis_core::formula::Formula;
+use legalis_core::{
+    ComparisonOp, Condition, Effect, EffectOutput, EffectType, OutputType, Statute,
+    StatuteException,
+};
 
 /// Catala scope metadata for tracking inheritance.
 #[derive(Debug, Clone)]
@@ -27,6 +48,35 @@ pub struct CatalaScope {
     pub exceptions: Vec<String>,
 }
 
+/// A Catala construct that was not lowered exactly.
+#[derive(Debug, Clone)]
+pub struct LoweringIssue {
+    /// Scope and variable (e.g. "IncomeTax.amount")
+    pub location: String,
+    /// Fallback feature key: "condition", "expression", "function",
+    /// "assertion" or "include"
+    pub feature: String,
+    /// Strategy that was applied
+    pub strategy: FallbackStrategy,
+    /// Catala source of the construct
+    pub source: String,
+}
+
+/// Result of [`CatalaImporter::import_with_fidelity`].
+#[derive(Debug, Clone)]
+pub struct CatalaImport {
+    /// Imported statutes
+    pub statutes: Vec<Statute>,
+    /// Conversion report
+    pub report: ConversionReport,
+    /// How much of the source survived lowering
+    pub fidelity: FidelityScore,
+    /// Constructs handled by a fallback strategy
+    pub issues: Vec<LoweringIssue>,
+    /// Parsed program
+    pub program: CatalaProgram,
+}
+
 /// Catala format importer.
 pub struct CatalaImporter {
     /// Whether to preserve legal text comments
@@ -35,6 +85,8 @@ pub struct CatalaImporter {
     track_articles: bool,
     /// Whether to preserve exception handling
     preserve_exceptions: bool,
+    /// Strategies for constructs without an exact lowering
+    fallback: FallbackConfig,
 }
 
 impl CatalaImporter {
@@ -44,6 +96,7 @@ impl CatalaImporter {
             preserve_comments: true,
             track_articles: true,
             preserve_exceptions: true,
+            fallback: FallbackConfig::new(),
         }
     }
 
@@ -65,6 +118,15 @@ impl CatalaImporter {
         self
     }
 
+    /// Sets the fallback strategies.
+    ///
+    /// Strategies are looked up by [`LoweringIssue::feature`]. The default
+    /// preserves unsupported constructs as effect parameters.
+    pub fn with_fallback_config(mut self, config: FallbackConfig) -> Self {
+        self.fallback = config;
+        self
+    }
+
     /// Extracts legal article references from Catala comments.
     fn extract_article_refs(&self, content: &str) -> Vec<String> {
         let mut refs = Vec::new();
@@ -99,196 +161,1105 @@ impl CatalaImporter {
         refs
     }
 
-    /// Extracts exception handlers from Catala code.
-    fn extract_exceptions(&self, content: &str) -> Vec<String> {
-        let mut exceptions = Vec::new();
+    /// Imports a Catala source and scores the fidelity of the lowering.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use legalis_interop::catala::CatalaImporter;
+    ///
+    /// let source = "\
+    /// declaration scope Benefit:
+    ///   input age content integer
+    ///   output amount content money
+    ///
+    /// scope Benefit:
+    ///   label base definition amount equals $100
+    ///   exception base definition amount under condition age >= 65 consequence equals $150
+    /// ";
+    /// let import = CatalaImporter::new().import_with_fidelity(source).unwrap();
+    /// let benefit = &import.statutes[0];
+    /// assert_eq!(
+    ///     benefit.effect.outputs[0].expression,
+    ///     "if age >= 65 then 150 else 100"
+    /// );
+    /// assert!(import.fidelity.is_lossless());
+    /// ```
+    pub fn import_with_fidelity(&self, source: &str) -> InteropResult<CatalaImport> {
+        let program = CatalaProgram::parse(source);
+        if program.scope_names().is_empty() {
+            return Err(InteropError::ParseError(
+                "No valid Catala scopes found".to_string(),
+            ));
+        }
 
-        // Look for "exception" keyword
-        let exception_re = regex_lite::Regex::new(r"(?m)^\s*exception\s+(\w+)\s+").ok();
+        let mut lowering = Lowering::new(self, &program);
+        for issue in &program.issues {
+            lowering
+                .report
+                .add_warning(format!("Line {}: {}", issue.line, issue.message));
+        }
+        lowering.directives()?;
+        let mut statutes = Vec::new();
+        for name in program.scope_names() {
+            lowering.scope(name, &mut statutes)?;
+        }
 
-        if let Some(re) = exception_re {
-            for cap in re.captures_iter(content) {
-                if let Some(m) = cap.get(1) {
-                    exceptions.push(m.as_str().to_string());
+        let fidelity = lowering.fidelity(statutes.len());
+        let Lowering {
+            mut report, issues, ..
+        } = lowering;
+        report.statutes_converted = statutes.len();
+        report.confidence = fidelity.overall;
+        Ok(CatalaImport {
+            statutes,
+            report,
+            fidelity,
+            issues,
+            program,
+        })
+    }
+}
+
+impl Default for CatalaImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FormatImporter for CatalaImporter {
+    fn format(&self) -> LegalFormat {
+        LegalFormat::Catala
+    }
+
+    fn import(&self, source: &str) -> InteropResult<(Vec<Statute>, ConversionReport)> {
+        let import = self.import_with_fidelity(source)?;
+        Ok((import.statutes, import.report))
+    }
+
+    fn validate(&self, source: &str) -> bool {
+        // Check for Catala markers
+        source.contains("declaration scope")
+            || source.contains("```catala")
+            || source.contains("# Catala")
+    }
+}
+
+// ============================================================================
+// Lowering
+// ============================================================================
+
+/// Running ratio of preserved to total items.
+#[derive(Debug, Default)]
+struct Tally {
+    kept: f64,
+    total: f64,
+}
+
+impl Tally {
+    fn add(&mut self, kept: f64) {
+        self.kept += kept;
+        self.total += 1.0;
+    }
+
+    fn ratio(&self) -> f64 {
+        if self.total == 0.0 {
+            1.0
+        } else {
+            self.kept / self.total
+        }
+    }
+}
+
+/// A definition together with the condition of its scope block.
+struct Branch<'p> {
+    def: &'p Definition,
+    condition: Option<Expr>,
+}
+
+impl Branch<'_> {
+    fn value(&self) -> Expr {
+        match &self.def.consequence {
+            Consequence::Value(value) => value.clone(),
+            Consequence::Fulfilled(fulfilled) => Expr::Bool(*fulfilled),
+        }
+    }
+}
+
+/// All definitions of one variable (and state) in a scope.
+struct Group<'p> {
+    name: String,
+    typ: Option<CatalaType>,
+    branches: Vec<Branch<'p>>,
+}
+
+impl Group<'_> {
+    fn is_boolean(&self) -> bool {
+        match &self.typ {
+            Some(typ) => *typ == CatalaType::Boolean,
+            None => self.branches.iter().all(|b| b.value().is_boolean()),
+        }
+    }
+
+    fn is_function(&self) -> bool {
+        self.branches.iter().any(|b| !b.def.parameters.is_empty())
+    }
+
+    /// Returns `true` if the group needs rule statutes to keep its structure.
+    fn has_default_logic(&self) -> bool {
+        self.branches.len() > 1
+            || self
+                .branches
+                .iter()
+                .any(|b| b.condition.is_some() || b.def.label.is_some() || b.def.is_exception())
+    }
+
+    /// Folds the definitions into one expression, highest priority first.
+    ///
+    /// Booleans default to `false`; other variables take their lowest
+    /// priority definition unconditionally, as Catala rejects inputs that
+    /// no definition covers.
+    fn fold(&self) -> Expr {
+        let mut folded = self.is_boolean().then_some(Expr::Bool(false));
+        for branch in by_priority(&self.branches).into_iter().rev() {
+            let value = branch.value();
+            folded = Some(match (&branch.condition, folded) {
+                (Some(condition), Some(rest)) => {
+                    Expr::If(Box::new(condition.clone()), Box::new(value), Box::new(rest))
                 }
+                _ => value,
+            });
+        }
+        simplify(folded.unwrap_or(Expr::Bool(false)))
+    }
+}
+
+/// Returns `true` if `child` is an exception to `parent`.
+fn excepts(child: &Definition, parent: &Definition) -> bool {
+    match &child.exception {
+        Some(Some(label)) => parent.label.as_ref() == Some(label),
+        Some(None) => !parent.is_exception(),
+        None => false,
+    }
+}
+
+/// Orders branches so that exceptions precede the definitions they override.
+fn by_priority<'b, 'p>(branches: &'b [Branch<'p>]) -> Vec<&'b Branch<'p>> {
+    fn visit<'b, 'p>(
+        branches: &'b [Branch<'p>],
+        index: usize,
+        visited: &mut [bool],
+        ordered: &mut Vec<&'b Branch<'p>>,
+    ) {
+        if visited[index] {
+            return;
+        }
+        visited[index] = true;
+        for (child, branch) in branches.iter().enumerate() {
+            if excepts(branch.def, branches[index].def) {
+                visit(branches, child, visited, ordered);
             }
         }
+        ordered.push(&branches[index]);
+    }
 
-        // Also look for "definition ... under condition ... consequence exception"
-        let exception_def_re = regex_lite::Regex::new(r"(?i)consequence\s+exception").ok();
+    let mut visited = vec![false; branches.len()];
+    let mut ordered = Vec::new();
+    for (index, branch) in branches.iter().enumerate() {
+        if !branch.def.is_exception() {
+            visit(branches, index, &mut visited, &mut ordered);
+        }
+    }
+    // Exceptions to labels that do not exist still take precedence
+    let mut orphans: Vec<&Branch<'p>> = branches
+        .iter()
+        .zip(&visited)
+        .filter(|(_, visited)| !**visited)
+        .map(|(branch, _)| branch)
+        .collect();
+    orphans.extend(ordered);
+    orphans
+}
 
-        if let Some(re) = exception_def_re
-            && re.is_match(content)
-        {
-            exceptions.push("Default logic exception".to_string());
+/// Returns how many exception levels separate a branch from a base case.
+fn priority(branches: &[Branch<'_>], index: usize) -> usize {
+    let mut depth = 0;
+    let mut current = vec![index];
+    while depth < branches.len() {
+        let parents: Vec<usize> = branches
+            .iter()
+            .enumerate()
+            .filter(|(_, parent)| {
+                current
+                    .iter()
+                    .any(|&child| excepts(branches[child].def, parent.def))
+            })
+            .map(|(i, _)| i)
+            .collect();
+        if parents.is_empty() {
+            break;
         }
+        depth += 1;
+        current = parents;
+    }
+    depth
+}
 
-        exceptions
+struct Lowering<'a> {
+    importer: &'a CatalaImporter,
+    program: &'a CatalaProgram,
+    currency: &'static str,
+    report: ConversionReport,
+    issues: Vec<LoweringIssue>,
+    /// Definitions represented in statutes
+    structure: Tally,
+    /// Conditions and values lowered exactly
+    semantic: Tally,
+    /// Headings, law text, labels, assertions and directives kept
+    metadata: Tally,
+    /// Items containing unparsed expressions
+    raw_items: usize,
+    preserved_statutes: usize,
+}
+
+impl<'a> Lowering<'a> {
+    fn new(importer: &'a CatalaImporter, program: &'a CatalaProgram) -> Self {
+        Self {
+            importer,
+            program,
+            currency: match program.language {
+                CatalaLanguage::English => "USD",
+                CatalaLanguage::French => "EUR",
+            },
+            report: ConversionReport::new(LegalFormat::Catala, LegalFormat::Legalis),
+            issues: Vec::new(),
+            structure: Tally::default(),
+            semantic: Tally::default(),
+            metadata: Tally::default(),
+            raw_items: 0,
+            preserved_statutes: 0,
+        }
     }
 
-    /// Detects scope inheritance from Catala code.
-    fn detect_scope_inheritance(&self, content: &str) -> Option<String> {
-        // Look for scope inheritance: "scope Child extends Parent:"
-        let inherit_re = regex_lite::Regex::new(r"(?i)scope\s+\w+\s+extends\s+(\w+)\s*:").ok()?;
+    fn fidelity(&self, total_statutes: usize) -> FidelityScore {
+        let parsed = self.program.items_parsed;
+        let attempted = parsed + self.program.issues.len();
+        let mut score = FidelityScore::new();
+        score.syntax = if attempted == 0 {
+            1.0
+        } else {
+            parsed.saturating_sub(self.raw_items) as f64 / attempted as f64
+        };
+        score.structure = self.structure.ratio();
+        score.semantic = self.semantic.ratio();
+        score.metadata = self.metadata.ratio();
+        score.statutes_preserved = self.preserved_statutes;
+        score.total_statutes = total_statutes;
+        score.calculate_overall();
+        score
+    }
 
-        inherit_re
-            .captures(content)
-            .and_then(|cap| cap.get(1))
-            .map(|m| m.as_str().to_string())
+    /// Records a construct without exact lowering and returns the strategy.
+    fn fallback(
+        &mut self,
+        feature: &str,
+        location: &str,
+        source: String,
+    ) -> InteropResult<FallbackStrategy> {
+        let strategy = self.importer.fallback.get_strategy(feature).clone();
+        if let FallbackStrategy::Fail = strategy {
+            return Err(InteropError::UnsupportedFeature(format!(
+                "Catala {} in {}: {}",
+                feature, location, source
+            )));
+        }
+        self.report.add_warning(format!(
+            "{}: {} handled with {:?}: {}",
+            location, feature, strategy, source
+        ));
+        self.issues.push(LoweringIssue {
+            location: location.to_string(),
+            feature: feature.to_string(),
+            strategy: strategy.clone(),
+            source,
+        });
+        Ok(strategy)
     }
 
-    /// Parses a Catala scope declaration.
-    fn parse_scope(&self, content: &str, report: &mut ConversionReport) -> Option<Statute> {
-        // Look for scope declaration: "declaration scope ScopeName:"
-        let scope_re = regex_lite::Regex::new(r"declaration\s+scope\s+(\w+):").ok()?;
-        let captures = scope_re.captures(content)?;
-        let scope_name = captures.get(1)?.as_str();
+    /// Handles `> Include`, `> Module` and `> Using` directives.
+    fn directives(&mut self) -> InteropResult<()> {
+        let program = self.program;
+        for directive in &program.directives {
+            let linked = ["Include", "Module", "Using", "Inclusion"]
+                .iter()
+                .any(|keyword| directive.starts_with(keyword));
+            if !linked {
+                continue;
+            }
+            match self.fallback("include", "directives", directive.clone())? {
+                FallbackStrategy::Skip => self.metadata.add(0.0),
+                _ => {
+                    self.report
+                        .add_unsupported(format!("Catala directive: {}", directive));
+                    self.metadata.add(0.0);
+                }
+            }
+        }
+        Ok(())
+    }
 
-        // Extract article references if enabled
-        if self.track_articles {
-            let article_refs = self.extract_article_refs(content);
-            if !article_refs.is_empty() {
-                report.add_warning(format!(
-                    "Preserved {} article reference(s) in metadata",
-                    article_refs.len()
-                ));
+    /// Lowers one scope into its statute and, if enabled, its rule statutes.
+    fn scope(&mut self, name: &str, statutes: &mut Vec<Statute>) -> InteropResult<()> {
+        let program = self.program;
+        let declaration = program.scope(name);
+        let scope_id = name.to_lowercase().replace(' ', "-");
+        let issues_before = self.issues.len();
+        let first_statute = statutes.len();
+
+        let mut groups: Vec<Group<'a>> = Vec::new();
+        let mut assertions = Vec::new();
+        let mut sections = declaration
+            .map(|d| &d.section)
+            .into_iter()
+            .collect::<Vec<_>>();
+        for block in program.scope_uses.iter().filter(|u| u.scope == name) {
+            sections.push(&block.section);
+            for item in &block.items {
+                match item {
+                    ScopeItem::Definition(def) => {
+                        let condition = conjoin(block.condition.as_ref(), def.condition.as_ref());
+                        if condition.as_ref().is_some_and(Expr::has_raw)
+                            || matches!(&def.consequence, Consequence::Value(v) if v.has_raw())
+                        {
+                            self.raw_items += 1;
+                        }
+                        let key = match &def.state {
+                            Some(state) => format!("{}.{}", def.target, state),
+                            None => def.target.clone(),
+                        };
+                        let branch = Branch {
+                            def: def.as_ref(),
+                            condition,
+                        };
+                        match groups.iter_mut().find(|g| g.name == key) {
+                            Some(group) => group.branches.push(branch),
+                            None => groups.push(Group {
+                                typ: declared_type(declaration, &def.target),
+                                name: key,
+                                branches: vec![branch],
+                            }),
+                        }
+                    }
+                    ScopeItem::Assertion(expr) => {
+                        if expr.has_raw() {
+                            self.raw_items += 1;
+                        }
+                        assertions.push(expr);
+                    }
+                }
             }
         }
 
-        // Extract exceptions if enabled
-        if self.preserve_exceptions {
-            let exceptions = self.extract_exceptions(content);
-            if !exceptions.is_empty() {
-                report.add_warning(format!(
-                    "Found {} exception(s): {}",
-                    exceptions.len(),
-                    exceptions.join(", ")
-                ));
+        let decided = decision(&groups);
+        let mut statute = match &decided {
+            Some((group, conjuncts)) => {
+                let location = format!("{}.{}", name, group.name);
+                let output = group.name.rsplit('.').next().unwrap_or(&group.name);
+                let mut statute = Statute::new(
+                    scope_id.as_str(),
+                    name,
+                    Effect::new(EffectType::Grant, output),
+                );
+                for conjunct in conjuncts {
+                    if let Some(condition) = self.condition(conjunct, &location)? {
+                        statute.preconditions.push(condition);
+                    }
+                }
+                self.structure.add(1.0);
+                statute
+            }
+            None => {
+                let mut effect = Effect::new(EffectType::Custom, name);
+                for group in &groups {
+                    let kept = self.group_output(name, group, &mut effect)?;
+                    self.structure.add(kept);
+                }
+                Statute::new(scope_id.as_str(), name, effect)
+            }
+        };
+
+        let parameters = &mut statute.effect.parameters;
+        parameters.insert("catala_scope".to_string(), name.to_string());
+        parameters.insert(
+            "catala_language".to_string(),
+            self.program.language.code().to_string(),
+        );
+        if let Some(declaration) = declaration {
+            let signature = |filter: fn(&&ScopeVariable) -> bool| {
+                declaration
+                    .variables
+                    .iter()
+                    .filter(filter)
+                    .map(|v| format!("{}: {}", v.name, v.typ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            let inputs = signature(|v| v.input);
+            let outputs = signature(|v| v.output);
+            if !inputs.is_empty() {
+                parameters.insert("catala_inputs".to_string(), inputs);
+            }
+            if !outputs.is_empty() {
+                parameters.insert("catala_outputs".to_string(), outputs);
+            }
+            for variable in &declaration.variables {
+                if let Some(scope) = &variable.sub_scope {
+                    parameters.insert(format!("catala_subscope.{}", variable.name), scope.clone());
+                }
+            }
+        }
+        self.sections(&sections, &mut statute);
+        self.assertions(name, &assertions, &mut statute)?;
+        statutes.push(statute);
+
+        let rule_statutes = self.importer.preserve_exceptions && decided.is_none();
+        if rule_statutes {
+            for group in groups.iter().filter(|g| g.has_default_logic()) {
+                self.rules(name, &scope_id, group, statutes)?;
+            }
+        }
+        for group in &groups {
+            for branch in &group.branches {
+                if branch.def.label.is_some() {
+                    self.metadata.add(if rule_statutes { 1.0 } else { 0.0 });
+                }
             }
         }
 
-        // Check for scope inheritance
-        if let Some(parent) = self.detect_scope_inheritance(content) {
-            report.add_warning(format!(
-                "Scope inheritance detected: {} extends {}",
-                scope_name, parent
-            ));
+        if self.issues.len() == issues_before {
+            self.preserved_statutes += statutes.len() - first_statute;
         }
+        Ok(())
+    }
 
-        // Create statute from scope
-        let mut statute = Statute::new(
-            scope_name.to_lowercase().replace(' ', "-"),
-            scope_name,
-            Effect::new(EffectType::Grant, "Scope output"),
+    /// Records the headings and legal text of a scope's blocks.
+    fn sections(&mut self, sections: &[&LawSection], statute: &mut Statute) {
+        let headings: Vec<&str> = sections.iter().filter_map(|s| s.heading.as_deref()).fold(
+            Vec::new(),
+            |mut headings, heading| {
+                if !headings.contains(&heading) {
+                    headings.push(heading);
+                }
+                headings
+            },
         );
+        let text: Vec<&str> = sections
+            .iter()
+            .map(|s| s.text.as_str())
+            .filter(|t| !t.is_empty())
+            .collect();
+
+        for _ in &headings {
+            self.metadata.add(if self.importer.track_articles {
+                1.0
+            } else {
+                0.0
+            });
+        }
+        for _ in &text {
+            self.metadata.add(if self.importer.preserve_comments {
+                1.0
+            } else {
+                0.0
+            });
+        }
+
+        let parameters = &mut statute.effect.parameters;
+        if self.importer.track_articles && !headings.is_empty() {
+            parameters.insert("catala_article".to_string(), headings.join("; "));
+            let mut references = Vec::new();
+            for section in sections {
+                references.extend(self.importer.extract_article_refs(&section.text));
+            }
+            references.dedup();
+            if !references.is_empty() {
+                parameters.insert("catala_references".to_string(), references.join("; "));
+            }
+        }
+        if self.importer.preserve_comments && !text.is_empty() {
+            parameters.insert("catala_law_text".to_string(), text.join("\n\n"));
+        }
+    }
 
-        // Parse context variables as conditions
-        let context_re = regex_lite::Regex::new(r"context\s+(\w+)\s+content\s+(\w+)").ok()?;
-        for cap in context_re.captures_iter(content) {
-            let var_name = cap.get(1).map(|m| m.as_str()).unwrap_or("unknown");
-            let var_type = cap.get(2).map(|m| m.as_str()).unwrap_or("unknown");
-
-            // Map Catala types to conditions
-            match var_type {
-                "integer" | "decimal" => {
-                    if var_name.to_lowercase().contains("age") {
-                        statute.preconditions.push(Condition::Age {
-                            operator: ComparisonOp::GreaterOrEqual,
-                            value: 0,
-                        });
+    /// Keeps assertions as formulas (or Catala text) in the effect parameters.
+    fn assertions(
+        &mut self,
+        scope: &str,
+        assertions: &[&Expr],
+        statute: &mut Statute,
+    ) -> InteropResult<()> {
+        for (index, assertion) in assertions.iter().enumerate() {
+            let text = match formula(assertion) {
+                Some(formula) => formula,
+                None => match self.fallback("assertion", scope, assertion.to_string())? {
+                    FallbackStrategy::Skip | FallbackStrategy::Fail => {
+                        self.metadata.add(0.0);
+                        continue;
+                    }
+                    FallbackStrategy::UseDefault(default) => default,
+                    FallbackStrategy::Approximate | FallbackStrategy::PreserveAsMetadata => {
+                        assertion.to_string()
                     }
+                },
+            };
+            self.metadata.add(1.0);
+            statute
+                .effect
+                .parameters
+                .insert(format!("catala_assertion.{}", index + 1), text);
+        }
+        Ok(())
+    }
+
+    /// Adds the folded value of a variable to `effect` and returns how much
+    /// of it was kept.
+    fn group_output(
+        &mut self,
+        scope: &str,
+        group: &Group<'_>,
+        effect: &mut Effect,
+    ) -> InteropResult<f64> {
+        let location = format!("{}.{}", scope, group.name);
+        if group.is_function() {
+            let source = group
+                .branches
+                .iter()
+                .map(|b| {
+                    format!(
+                        "{} of ({}) equals {}",
+                        group.name,
+                        b.def.parameters.join(", "),
+                        b.value()
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("; ");
+            self.semantic.add(0.0);
+            return self.unlowered("function", &location, &group.name, source, None, effect);
+        }
+        let value = group.fold();
+        let output_type = self.output_type(group.typ.as_ref(), &value, group.is_boolean());
+        self.value(&location, &group.name, &value, output_type, effect)
+    }
+
+    /// Adds `value` as an output of `effect`, falling back if it cannot be
+    /// expressed as a formula.
+    fn value(
+        &mut self,
+        location: &str,
+        name: &str,
+        value: &Expr,
+        output_type: Option<OutputType>,
+        effect: &mut Effect,
+    ) -> InteropResult<f64> {
+        if let (Some(output_type), Some(formula)) = (&output_type, formula(value)) {
+            self.semantic.add(1.0);
+            effect
+                .outputs
+                .push(EffectOutput::new(name, output_type.clone(), formula));
+            return Ok(1.0);
+        }
+        self.semantic.add(0.0);
+        self.unlowered(
+            "expression",
+            location,
+            name,
+            value.to_string(),
+            output_type,
+            effect,
+        )
+    }
+
+    fn unlowered(
+        &mut self,
+        feature: &str,
+        location: &str,
+        name: &str,
+        source: String,
+        output_type: Option<OutputType>,
+        effect: &mut Effect,
+    ) -> InteropResult<f64> {
+        match self.fallback(feature, location, source.clone())? {
+            FallbackStrategy::Skip | FallbackStrategy::Fail => Ok(0.0),
+            FallbackStrategy::UseDefault(default) => {
+                effect.outputs.push(EffectOutput::new(
+                    name,
+                    output_type.unwrap_or(OutputType::Number),
+                    default,
+                ));
+                Ok(0.5)
+            }
+            FallbackStrategy::Approximate | FallbackStrategy::PreserveAsMetadata => {
+                effect
+                    .parameters
+                    .insert(format!("catala_{}.{}", feature, name), source);
+                Ok(0.5)
+            }
+        }
+    }
+
+    fn output_type(
+        &self,
+        typ: Option<&CatalaType>,
+        value: &Expr,
+        boolean: bool,
+    ) -> Option<OutputType> {
+        match typ {
+            Some(CatalaType::Integer | CatalaType::Decimal) => Some(OutputType::Number),
+            Some(CatalaType::Money) => Some(OutputType::Money {
+                currency: self.currency.to_string(),
+            }),
+            Some(CatalaType::Boolean) => Some(OutputType::Boolean),
+            Some(CatalaType::Date) => Some(OutputType::Date),
+            Some(CatalaType::Named(name)) => {
+                let enumeration = self.program.enumerations.iter().find(|e| e.name == *name)?;
+                enumeration
+                    .cases
+                    .iter()
+                    .all(|(_, payload)| payload.is_none())
+                    .then(|| OutputType::Enum {
+                        variants: enumeration.cases.iter().map(|(c, _)| c.clone()).collect(),
+                    })
+            }
+            Some(CatalaType::Duration | CatalaType::Text | CatalaType::List(_)) => None,
+            None if boolean => Some(OutputType::Boolean),
+            None if value.mentions_money() => Some(OutputType::Money {
+                currency: self.currency.to_string(),
+            }),
+            None => Some(OutputType::Number),
+        }
+    }
+
+    /// Lowers a boolean expression into a condition.
+    ///
+    /// Returns `None` if the fallback strategy skips it.
+    fn condition(&mut self, expr: &Expr, location: &str) -> InteropResult<Option<Condition>> {
+        if let Some(condition) = condition(expr) {
+            self.semantic.add(1.0);
+            return Ok(Some(condition));
+        }
+        self.semantic.add(0.0);
+        Ok(
+            match self.fallback("condition", location, expr.to_string())? {
+                FallbackStrategy::Skip | FallbackStrategy::Fail => None,
+                FallbackStrategy::UseDefault(default) => Some(Condition::custom(default)),
+                FallbackStrategy::Approximate | FallbackStrategy::PreserveAsMetadata => {
+                    Some(Condition::custom(expr.to_string()))
                 }
-                "money" => {
-                    if var_name.to_lowercase().contains("income") {
-                        statute.preconditions.push(Condition::Income {
-                            operator: ComparisonOp::GreaterOrEqual,
-                            value: 0,
-                        });
+            },
+        )
+    }
+
+    /// Emits one statute per definition of a variable with default logic.
+    fn rules(
+        &mut self,
+        scope: &str,
+        scope_id: &str,
+        group: &Group<'_>,
+        statutes: &mut Vec<Statute>,
+    ) -> InteropResult<()> {
+        if group.is_function() {
+            return Ok(());
+        }
+        let branches = &group.branches;
+        let ids: Vec<String> = branches
+            .iter()
+            .enumerate()
+            .map(|(index, branch)| {
+                let shared = |label: &String| {
+                    branches
+                        .iter()
+                        .filter(|b| b.def.label.as_ref() == Some(label))
+                        .count()
+                        > 1
+                };
+                match &branch.def.label {
+                    Some(label) if shared(label) => {
+                        format!("{}.{}.{}.{}", scope_id, group.name, label, index + 1)
                     }
+                    Some(label) => format!("{}.{}.{}", scope_id, group.name, label),
+                    None if branches.len() == 1 => format!("{}.{}", scope_id, group.name),
+                    None => format!("{}.{}.{}", scope_id, group.name, index + 1),
                 }
-                "boolean" => {
-                    statute.preconditions.push(Condition::AttributeEquals {
-                        key: var_name.to_string(),
-                        value: "true".to_string(),
-                    });
+            })
+            .collect();
+
+        for (index, branch) in branches.iter().enumerate() {
+            let id = &ids[index];
+            let def = branch.def;
+            let effect = match &def.consequence {
+                Consequence::Fulfilled(true) | Consequence::Value(Expr::Bool(true)) => {
+                    Effect::new(EffectType::Grant, group.name.as_str())
                 }
-                _ => {
-                    report.add_warning(format!("Unknown Catala type: {}", var_type));
+                Consequence::Fulfilled(false) | Consequence::Value(Expr::Bool(false)) => {
+                    Effect::new(EffectType::Revoke, group.name.as_str())
+                }
+                Consequence::Value(value) => {
+                    let output_type =
+                        self.output_type(group.typ.as_ref(), value, group.is_boolean());
+                    let effect_type = match output_type {
+                        Some(OutputType::Money { .. }) => EffectType::MonetaryTransfer,
+                        _ => EffectType::Custom,
+                    };
+                    let mut effect = Effect::new(effect_type, group.name.as_str());
+                    self.value(id, &group.name, value, output_type, &mut effect)?;
+                    effect
+                }
+            };
+
+            let title = match &def.label {
+                Some(label) => format!("{}: {} ({})", scope, group.name, label),
+                None => format!("{}: {}", scope, group.name),
+            };
+            let mut statute = Statute::new(id.as_str(), title, effect);
+            if let Some(condition) = &branch.condition {
+                for conjunct in conjuncts(condition) {
+                    if let Some(condition) = self.condition(&conjunct, id)? {
+                        statute.preconditions.push(condition);
+                    }
                 }
             }
+            for (child, other) in branches.iter().enumerate() {
+                if excepts(other.def, def) {
+                    let description = match &other.def.label {
+                        Some(label) => format!("Catala exception '{}'", label),
+                        None => format!("Catala exception to {}", group.name),
+                    };
+                    statute = statute.with_exception(StatuteException::new(
+                        ids[child].as_str(),
+                        description,
+                        Condition::statute_applies(ids[child].as_str()),
+                    ));
+                }
+            }
+
+            let parameters = &mut statute.effect.parameters;
+            parameters.insert("catala_scope".to_string(), scope.to_string());
+            parameters.insert("catala_variable".to_string(), group.name.clone());
+            parameters.insert(
+                "catala_priority".to_string(),
+                priority(branches, index).to_string(),
+            );
+            parameters.insert("catala_line".to_string(), def.line.to_string());
+            if let Some(label) = &def.label {
+                parameters.insert("catala_label".to_string(), label.clone());
+            }
+            if let Some(target) = &def.exception {
+                parameters.insert(
+                    "catala_exception_to".to_string(),
+                    target.clone().unwrap_or_else(|| "base".to_string()),
+                );
+            }
+            if self.importer.track_articles
+                && let Some(heading) = &def.section.heading
+            {
+                parameters.insert("catala_article".to_string(), heading.clone());
+            }
+            statutes.push(statute);
         }
+        Ok(())
+    }
+}
+
+/// Looks up the declared type of a definition target.
+fn declared_type(declaration: Option<&ScopeDeclaration>, target: &str) -> Option<CatalaType> {
+    let variable = declaration?.variable(target)?;
+    Some(variable.typ.clone())
+}
 
-        // Check for rule definitions
-        let rule_re = regex_lite::Regex::new(r"rule\s+(\w+)\s+under\s+condition").ok()?;
-        for cap in rule_re.captures_iter(content) {
-            let rule_name = cap.get(1).map(|m| m.as_str()).unwrap_or("rule");
-            report.add_warning(format!("Rule '{}' converted to condition", rule_name));
+/// Returns the group and conjuncts of a scope that only decides one
+/// condition, such as `definition eligible equals age >= 18 and resident`.
+fn decision<'g, 'p>(groups: &'g [Group<'p>]) -> Option<(&'g Group<'p>, Vec<Expr>)> {
+    let [group] = groups else {
+        return None;
+    };
+    let [branch] = group.branches.as_slice() else {
+        return None;
+    };
+    if branch.def.is_exception() || group.is_function() {
+        return None;
+    }
+    let mut parts = branch.condition.as_ref().map(conjuncts).unwrap_or_default();
+    match &branch.def.consequence {
+        Consequence::Fulfilled(true) => {}
+        Consequence::Value(value) if value.is_boolean() || group.is_boolean() => {
+            parts.extend(conjuncts(&simplify(value.clone())));
         }
+        _ => return None,
+    }
+    Some((group, parts))
+}
 
-        Some(statute)
+fn conjoin(a: Option<&Expr>, b: Option<&Expr>) -> Option<Expr> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(Expr::Binary(
+            BinaryOp::And,
+            Box::new(a.clone()),
+            Box::new(b.clone()),
+        )),
+        (a, b) => a.or(b).cloned(),
     }
 }
 
-impl Default for CatalaImporter {
-    fn default() -> Self {
-        Self::new()
+/// Splits an expression on top-level `and`, dropping literal `true`s.
+fn conjuncts(expr: &Expr) -> Vec<Expr> {
+    match expr {
+        Expr::Binary(BinaryOp::And, left, right) => {
+            let mut parts = conjuncts(left);
+            parts.extend(conjuncts(right));
+            parts
+        }
+        Expr::Bool(true) => Vec::new(),
+        other => vec![other.clone()],
     }
 }
 
-impl FormatImporter for CatalaImporter {
-    fn format(&self) -> LegalFormat {
-        LegalFormat::Catala
+/// Rewrites `if c then true else false` to `c`.
+fn simplify(expr: Expr) -> Expr {
+    match expr {
+        Expr::If(condition, then, otherwise) => match (*then, *otherwise) {
+            (Expr::Bool(true), Expr::Bool(false)) => *condition,
+            (Expr::Bool(false), Expr::Bool(true)) => Expr::Not(condition),
+            (then, otherwise) => Expr::If(condition, Box::new(then), Box::new(otherwise)),
+        },
+        other => other,
     }
+}
 
-    fn import(&self, source: &str) -> InteropResult<(Vec<Statute>, ConversionReport)> {
-        let mut report = ConversionReport::new(LegalFormat::Catala, LegalFormat::Legalis);
-        let mut statutes = Vec::new();
+/// Attribute name of a path; the `input.` prefix of generated scopes is
+/// dropped.
+fn attribute(parts: &[String]) -> String {
+    match parts {
+        [first, rest @ ..] if first == "input" && !rest.is_empty() => rest.join("."),
+        _ => parts.join("."),
+    }
+}
 
-        // Split by scope declarations
-        let sections: Vec<&str> = source.split("declaration scope").collect();
+fn comparison_op(op: BinaryOp) -> Option<ComparisonOp> {
+    Some(match op {
+        BinaryOp::Eq => ComparisonOp::Equal,
+        BinaryOp::Ne => ComparisonOp::NotEqual,
+        BinaryOp::Lt => ComparisonOp::LessThan,
+        BinaryOp::Le => ComparisonOp::LessOrEqual,
+        BinaryOp::Gt => ComparisonOp::GreaterThan,
+        BinaryOp::Ge => ComparisonOp::GreaterOrEqual,
+        _ => return None,
+    })
+}
 
-        for (i, section) in sections.iter().enumerate() {
-            if i == 0 && !section.contains("scope") {
-                continue; // Skip preamble
-            }
+/// Mirrors a comparison so that its operands can be swapped.
+fn flip(op: BinaryOp) -> BinaryOp {
+    match op {
+        BinaryOp::Lt => BinaryOp::Gt,
+        BinaryOp::Le => BinaryOp::Ge,
+        BinaryOp::Gt => BinaryOp::Lt,
+        BinaryOp::Ge => BinaryOp::Le,
+        other => other,
+    }
+}
 
-            let full_section = if i > 0 {
-                format!("declaration scope{}", section)
-            } else {
-                section.to_string()
+fn number_literal(expr: &Expr) -> Option<f64> {
+    match expr {
+        Expr::Integer(n) => Some(*n as f64),
+        Expr::Decimal(d) | Expr::Money(d) => Some(*d),
+        _ => None,
+    }
+}
+
+/// Lowers a boolean expression into the closest legalis-core condition.
+fn condition(expr: &Expr) -> Option<Condition> {
+    structural_condition(expr).or_else(|| {
+        formula(expr).map(|formula| Condition::calculation(formula, ComparisonOp::Equal, 1.0))
+    })
+}
+
+fn structural_condition(expr: &Expr) -> Option<Condition> {
+    match expr {
+        Expr::Binary(BinaryOp::And, left, right) => Some(Condition::And(
+            Box::new(condition(left)?),
+            Box::new(condition(right)?),
+        )),
+        Expr::Binary(BinaryOp::Or, left, right) => Some(Condition::Or(
+            Box::new(condition(left)?),
+            Box::new(condition(right)?),
+        )),
+        Expr::Not(inner) => Some(Condition::Not(Box::new(condition(inner)?))),
+        Expr::Path(parts) => Some(Condition::AttributeEquals {
+            key: attribute(parts),
+            value: "true".to_string(),
+        }),
+        Expr::WithPattern(subject, constructor) => match subject.as_ref() {
+            Expr::Path(parts) => Some(Condition::AttributeEquals {
+                key: attribute(parts),
+                value: constructor.clone(),
+            }),
+            _ => None,
+        },
+        Expr::Binary(op, left, right) => {
+            let (op, path, literal) = match (left.as_ref(), right.as_ref()) {
+                (Expr::Path(parts), literal) => (*op, parts, literal),
+                (literal, Expr::Path(parts)) => (flip(*op), parts, literal),
+                _ => return None,
             };
+            comparison(op, path, literal)
+        }
+        _ => None,
+    }
+}
 
-            if let Some(statute) = self.parse_scope(&full_section, &mut report) {
-                statutes.push(statute);
-            }
+/// Lowers `path op literal`.
+fn comparison(op: BinaryOp, path: &[String], literal: &Expr) -> Option<Condition> {
+    let operator = comparison_op(op)?;
+    let key = attribute(path);
+    let equality = |value: String| {
+        let condition = Condition::AttributeEquals {
+            key: key.clone(),
+            value,
+        };
+        match op {
+            BinaryOp::Eq => Some(condition),
+            BinaryOp::Ne => Some(Condition::Not(Box::new(condition))),
+            _ => None,
         }
+    };
+    match literal {
+        Expr::Constructor(name, None) => return equality(name.clone()),
+        Expr::Text(text) => return equality(text.clone()),
+        Expr::Bool(value) => return equality(value.to_string()),
+        _ => {}
+    }
 
-        if statutes.is_empty() {
-            return Err(InteropError::ParseError(
-                "No valid Catala scopes found".to_string(),
-            ));
+    let value = number_literal(literal)?;
+    let field = path.last().map(String::as_str).unwrap_or_default();
+    let whole = value >= 0.0 && value.fract() == 0.0;
+    match (field, literal) {
+        ("age" | "âge", Expr::Integer(_)) if whole && value <= f64::from(u32::MAX) => {
+            Some(Condition::Age {
+                operator,
+                value: value as u32,
+            })
+        }
+        ("income" | "revenu" | "revenus", _) if whole => Some(Condition::Income {
+            operator,
+            value: value as u64,
+        }),
+        _ => {
+            let formula = formula(&Expr::Path(path.to_vec()))?;
+            Some(Condition::calculation(formula, operator, value))
         }
+    }
+}
 
-        // Note unsupported features
-        if source.contains("exception") {
-            report.add_unsupported("Catala exceptions (default logic)");
+/// Translates an expression into the legalis-core formula language.
+///
+/// Returns `None` for constructs the formula language lacks (structures,
+/// lists, `let`, bound patterns) or if the result does not parse.
+fn formula(expr: &Expr) -> Option<String> {
+    let text = to_formula(expr)?;
+    Formula::parse(&text).ok().map(|_| text)
+}
+
+fn to_formula(expr: &Expr) -> Option<String> {
+    Some(match expr {
+        Expr::Bool(value) => value.to_string(),
+        Expr::Integer(n) => n.to_string(),
+        Expr::Decimal(d) | Expr::Money(d) => d.to_string(),
+        Expr::Date(date) => date.format("%Y-%m-%d").to_string(),
+        Expr::Text(text) if !text.contains('"') => format!("\"{}\"", text),
+        Expr::Constructor(name, None) => format!("\"{}\"", name),
+        Expr::Path(parts) => attribute(parts),
+        Expr::Not(inner) => format!("not {}", operand(inner)?),
+        Expr::Neg(inner) => format!("-{}", operand(inner)?),
+        Expr::Binary(op, left, right) => {
+            if let (BinaryOp::Add | BinaryOp::Sub, Expr::Duration(n, unit)) = (op, right.as_ref()) {
+                let function = match unit {
+                    DurationUnit::Year => "add_years",
+                    DurationUnit::Month => "add_months",
+                    DurationUnit::Day => "add_days",
+                };
+                let n = if *op == BinaryOp::Sub { -n } else { *n };
+                return Some(format!("{}({}, {})", function, to_formula(left)?, n));
+            }
+            let symbol = match op {
+                BinaryOp::Eq => "==".to_string(),
+                BinaryOp::Xor => "!=".to_string(),
+                BinaryOp::Contains => return None,
+                other => other.to_string(),
+            };
+            format!("{} {} {}", operand(left)?, symbol, operand(right)?)
         }
-        if source.contains("assertion") {
-            report.add_unsupported("Catala assertions");
+        Expr::If(condition, then, otherwise) => conditional(
+            to_formula(condition)?,
+            to_formula(then)?,
+            to_formula(otherwise)?,
+        ),
+        Expr::WithPattern(subject, constructor) => {
+            format!("{} == \"{}\"", operand(subject)?, constructor)
         }
-        if source.contains("definition") && source.contains("state") {
-            report.add_unsupported("Catala state definitions");
+        Expr::Match(subject, arms) => {
+            let subject = operand(subject)?;
+            let mut folded: Option<String> = None;
+            for arm in arms.iter().rev() {
+                if arm.binding.is_some() {
+                    return None;
+                }
+                let body = to_formula(&arm.body)?;
+                folded = Some(match (&arm.constructor, folded) {
+                    (Some(constructor), Some(rest)) => {
+                        conditional(format!("{} == \"{}\"", subject, constructor), body, rest)
+                    }
+                    _ => body,
+                });
+            }
+            folded?
         }
+        Expr::Call(name, args) => {
+            let function = match (name.as_str(), args.len()) {
+                ("round" | "arrondi", 1) => "round",
+                ("maximum" | "max", 2) => "max",
+                ("minimum" | "min", 2) => "min",
+                _ => return None,
+            };
+            let args = args.iter().map(to_formula).collect::<Option<Vec<_>>>()?;
+            format!("{}({})", function, args.join(", "))
+        }
+        _ => return None,
+    })
+}
 
-        report.statutes_converted = statutes.len();
-        Ok((statutes, report))
-    }
+/// Formats an operand, parenthesizing compound expressions.
+fn operand(expr: &Expr) -> Option<String> {
+    let text = to_formula(expr)?;
+    Some(match expr {
+        Expr::Binary(..)
+        | Expr::If(..)
+        | Expr::Match(..)
+        | Expr::Not(_)
+        | Expr::Neg(_)
+        | Expr::WithPattern(..) => {
+            format!("({})", text)
+        }
+        _ => text,
+    })
+}
 
-    fn validate(&self, source: &str) -> bool {
-        // Check for Catala markers
-        source.contains("declaration scope")
-            || source.contains("```catala")
-            || source.contains("# Catala")
+/// Formats a conditional. `if (` would be read as a call, so parenthesized
+/// conditions use the function form.
+fn conditional(condition: String, then: String, otherwise: String) -> String {
+    if condition.starts_with('(') {
+        format!("if({}, {}, {})", condition, then, otherwise)
+    } else {
+        format!("if {} then {} else {}", condition, then, otherwise)
     }
 }
 
@@ -487,6 +1458,60 @@ impl FormatExporter for CatalaExporter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use legalis_core::{Decimal, OutputValue};
+    use std::collections::HashMap;
+
+    fn attrs(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn output<'s>(statute: &'s Statute, name: &str) -> &'s EffectOutput {
+        statute
+            .effect
+            .outputs
+            .iter()
+            .find(|o| o.name == name)
+            .unwrap_or_else(|| panic!("missing output {}", name))
+    }
+
+    const HOUSING: &str = r#"# Housing benefit
+
+## Article 1
+
+Tenants aged 18 or more with an income below $30,000 are eligible.
+
+```catala
+declaration enumeration Tenure:
+  -- Tenant
+  -- Owner
+
+declaration scope HousingBenefit:
+  input age content integer
+  input income content money
+  input tenure content Tenure
+  output eligible condition
+  output amount content money
+
+scope HousingBenefit:
+  rule eligible under condition age >= 18 and income < $30,000 consequence fulfilled
+  exception rule eligible under condition tenure with pattern Owner
+    consequence not fulfilled
+```
+
+## Article 2
+
+The benefit is $100 a month, or $150 for people aged 65 or more.
+
+```catala
+scope HousingBenefit:
+  label base definition amount equals $100
+  exception base definition amount under condition age >= 65 consequence equals $150
+  assertion amount >= $0
+```
+"#;
 
     #[test]
     fn test_catala_importer_validate() {
@@ -542,4 +1567,260 @@ mod tests {
         assert!(output.contains("and"));
         assert!(output.contains("input.income < $50000"));
     }
+
+    #[test]
+    fn test_import_scope_outputs() {
+        let import = CatalaImporter::new().import_with_fidelity(HOUSING).unwrap();
+        let scope = &import.statutes[0];
+        assert_eq!(scope.id, "housingbenefit");
+        assert_eq!(scope.effect.effect_type, EffectType::Custom);
+        assert_eq!(
+            scope
+                .effect
+                .parameters
+                .get("catala_article")
+                .map(String::as_str),
+            Some("Article 1; Article 2")
+        );
+        assert!(scope.effect.parameters["catala_law_text"].contains("$150"));
+        assert_eq!(scope.effect.parameters["catala_assertion.1"], "amount >= 0");
+
+        let eligible = output(scope, "eligible");
+        let amount = output(scope, "amount");
+        assert_eq!(eligible.output_type, OutputType::Boolean);
+        let cases = [
+            (("70", "20000", "Tenant"), true, 150),
+            (("40", "20000", "Owner"), false, 100),
+            (("40", "35000", "Tenant"), false, 100),
+            (("16", "0", "Tenant"), false, 100),
+        ];
+        for ((age, income, tenure), expected_eligible, expected_amount) in cases {
+            let entity = attrs(&[("age", age), ("income", income), ("tenure", tenure)]);
+            assert_eq!(
+                eligible.evaluate(&entity).unwrap(),
+                OutputValue::Boolean(expected_eligible),
+                "{} / {} / {}",
+                age,
+                income,
+                tenure
+            );
+            assert_eq!(
+                amount.evaluate(&entity).unwrap(),
+                OutputValue::money(Decimal::from(expected_amount), "USD")
+            );
+        }
+        assert!(import.issues.is_empty());
+        assert!(import.fidelity.is_lossless());
+        assert_eq!(import.report.confidence, import.fidelity.overall);
+    }
+
+    #[test]
+    fn test_import_exception_chain() {
+        let (statutes, report) = CatalaImporter::new().import(HOUSING).unwrap();
+        let ids: Vec<&str> = statutes.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "housingbenefit",
+                "housingbenefit.eligible.1",
+                "housingbenefit.eligible.2",
+                "housingbenefit.amount.base",
+                "housingbenefit.amount.2",
+            ]
+        );
+        assert_eq!(report.statutes_converted, 5);
+
+        let base_rule = &statutes[1];
+        assert_eq!(base_rule.effect.effect_type, EffectType::Grant);
+        assert_eq!(
+            base_rule.preconditions,
+            vec![
+                Condition::Age {
+                    operator: ComparisonOp::GreaterOrEqual,
+                    value: 18
+                },
+                Condition::Income {
+                    operator: ComparisonOp::LessThan,
+                    value: 30000
+                },
+            ]
+        );
+        assert_eq!(base_rule.exceptions.len(), 1);
+        assert_eq!(
+            base_rule.exceptions[0].condition,
+            Condition::statute_applies("housingbenefit.eligible.2")
+        );
+
+        let owner_rule = &statutes[2];
+        assert_eq!(owner_rule.effect.effect_type, EffectType::Revoke);
+        assert_eq!(
+            owner_rule.preconditions,
+            vec![Condition::AttributeEquals {
+                key: "tenure".to_string(),
+                value: "Owner".to_string()
+            }]
+        );
+        assert_eq!(owner_rule.effect.parameters["catala_priority"], "1");
+        assert_eq!(owner_rule.effect.parameters["catala_exception_to"], "base");
+
+        let base_amount = &statutes[3];
+        assert_eq!(base_amount.effect.effect_type, EffectType::MonetaryTransfer);
+        assert_eq!(base_amount.effect.outputs[0].expression, "100");
+        assert_eq!(base_amount.exceptions[0].id, "housingbenefit.amount.2");
+        assert_eq!(statutes[4].effect.parameters["catala_exception_to"], "base");
+
+        let (statutes, _) = CatalaImporter::new()
+            .with_exception_preservation(false)
+            .import(HOUSING)
+            .unwrap();
+        assert_eq!(statutes.len(), 1);
+    }
+
+    #[test]
+    fn test_import_french_tax_code() {
+        let source = r#"# Code général des impôts
+
+## Article 197
+
+L'impôt est calculé en appliquant au revenu imposable un taux de 11 % pour
+la fraction excédant 11 294 €. Les non-résidents sont imposés à 20 %.
+
+```catala
+déclaration champ d'application CalculImpôtRevenu:
+  entrée revenu_imposable contenu argent
+  entrée résident contenu booléen
+  résultat impôt contenu argent
+
+champ d'application CalculImpôtRevenu:
+  étiquette barème définition impôt égal à
+    si revenu_imposable <= 11 294 € alors 0 €
+    sinon (revenu_imposable - 11 294 €) * 11%
+  exception barème définition impôt sous condition non résident
+    conséquence égal à revenu_imposable * 20%
+```
+"#;
+        let import = CatalaImporter::new().import_with_fidelity(source).unwrap();
+        let scope = &import.statutes[0];
+        assert_eq!(scope.id, "calculimpôtrevenu");
+        assert_eq!(scope.effect.parameters["catala_language"], "fr");
+        assert_eq!(
+            scope.effect.parameters["catala_inputs"],
+            "revenu_imposable: money, résident: boolean"
+        );
+
+        let impot = output(scope, "impôt");
+        let tax = |revenu: &str, resident: &str| {
+            impot
+                .evaluate(&attrs(&[
+                    ("revenu_imposable", revenu),
+                    ("résident", resident),
+                ]))
+                .unwrap()
+        };
+        let euros = |amount: &str| OutputValue::money(amount.parse().unwrap(), "EUR");
+        assert_eq!(tax("10000", "true"), euros("0"));
+        assert_eq!(tax("20000", "true"), euros("957.66"));
+        assert_eq!(tax("20000", "false"), euros("4000"));
+
+        let exception = &import.statutes[2];
+        assert_eq!(exception.id, "calculimpôtrevenu.impôt.2");
+        assert_eq!(
+            exception.preconditions,
+            vec![Condition::Not(Box::new(Condition::AttributeEquals {
+                key: "résident".to_string(),
+                value: "true".to_string()
+            }))]
+        );
+        assert!(import.fidelity.is_lossless());
+    }
+
+    #[test]
+    fn test_exporter_roundtrip_preconditions() {
+        let statute = Statute::new(
+            "tax-benefit",
+            "Tax Benefit Rule",
+            Effect::new(EffectType::Grant, "Tax reduction"),
+        )
+        .with_precondition(Condition::Age {
+            operator: ComparisonOp::GreaterOrEqual,
+            value: 65,
+        })
+        .with_precondition(Condition::Income {
+            operator: ComparisonOp::LessThan,
+            value: 50000,
+        });
+        let (output, _) = CatalaExporter::new()
+            .export(std::slice::from_ref(&statute))
+            .unwrap();
+
+        let (statutes, _) = CatalaImporter::new().import(&output).unwrap();
+        assert_eq!(statutes.len(), 1);
+        assert_eq!(statutes[0].id, "taxbenefit");
+        assert_eq!(statutes[0].effect.effect_type, EffectType::Grant);
+        assert_eq!(statutes[0].preconditions, statute.preconditions);
+    }
+
+    #[test]
+    fn test_fallback_strategies() {
+        let source = "\
+declaration scope Allowance:
+  input children content integer
+  output amount content money
+  output bonus content money
+
+scope Allowance:
+  definition amount equals $50 * children
+  definition bonus equals let base equals $10 in base * children
+";
+        let import = CatalaImporter::new().import_with_fidelity(source).unwrap();
+        let allowance = &import.statutes[0];
+        assert_eq!(output(allowance, "amount").expression, "50 * children");
+        assert!(
+            allowance.effect.parameters["catala_expression.bonus"].starts_with("let base equals")
+        );
+        assert_eq!(import.issues.len(), 1);
+        assert_eq!(import.issues[0].location, "Allowance.bonus");
+        assert_eq!(import.fidelity.semantic, 0.5);
+        assert_eq!(import.fidelity.statutes_preserved, 0);
+        assert!(!import.fidelity.is_lossless());
+
+        let with = |strategy: FallbackStrategy| {
+            let mut config = FallbackConfig::new();
+            config.set_strategy("expression".to_string(), strategy);
+            CatalaImporter::new().with_fallback_config(config)
+        };
+        let import = with(FallbackStrategy::UseDefault("0".to_string()))
+            .import_with_fidelity(source)
+            .unwrap();
+        assert_eq!(output(&import.statutes[0], "bonus").expression, "0");
+
+        let import = with(FallbackStrategy::Skip)
+            .import_with_fidelity(source)
+            .unwrap();
+        assert_eq!(import.statutes[0].effect.outputs.len(), 1);
+        assert!(import.fidelity.structure < 1.0);
+
+        let result = with(FallbackStrategy::Fail).import(source);
+        assert!(matches!(result, Err(InteropError::UnsupportedFeature(_))));
+    }
+
+    #[test]
+    fn test_import_reports_syntax_errors() {
+        let source = "\
+declaration scope Fine:
+  input speed content integer
+  output amount content money
+
+scope Fine:
+  definition amount under condition speed > 50 equals $100
+  definition amount under condition speed <= 50 consequence equals $0
+";
+        let import = CatalaImporter::new().import_with_fidelity(source).unwrap();
+        assert!(import.report.warnings[0].starts_with("Line 6:"));
+        assert!(import.fidelity.syntax < 1.0);
+        let amount = output(&import.statutes[0], "amount");
+        assert_eq!(amount.expression, "0");
+
+        assert!(CatalaImporter::new().import("no scopes here").is_err());
+    }
 }
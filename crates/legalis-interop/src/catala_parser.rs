@@ -0,0 +1,2066 @@
+//! Parser for the Catala surface syntax.
+//!
+//! Catala programs are literate markdown: legal text is written as markdown
+//! and code lives in fenced ` ```catala ` blocks. The parser extracts the code
+//! blocks, remembers the heading and legal text preceding each of them, and
+//! builds a [`CatalaProgram`] from:
+//! - Scope, structure and enumeration declarations, and top-level constants
+//! - `scope` blocks with definitions, rules, labels, exceptions and assertions
+//! - Expressions: literals (money, decimals, percentages, dates, durations),
+//!   arithmetic, comparisons, boolean logic, `if`/`then`/`else`, `match`,
+//!   `with pattern`, function application and structure literals
+//!
+//! Both the English and the French keyword sets are supported; the language
+//! is detected from the source. Syntax errors are recovered at the next item,
+//! so one malformed definition does not hide the rest of the program.
+//! Expressions that cannot be parsed are kept as [`Expr::Raw`].
+//!
+//! Reference: <https://catala-lang.org/> (syntax cheat sheet)
+
+use chrono::NaiveDate;
+use std::fmt;
+
+/// Keyword language of a Catala program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CatalaLanguage {
+    /// English keywords (`.catala_en`)
+    #[default]
+    English,
+    /// French keywords (`.catala_fr`)
+    French,
+}
+
+impl CatalaLanguage {
+    /// Detects the language from French keywords in the source.
+    pub fn detect(source: &str) -> Self {
+        const FRENCH_MARKERS: &[&str] = &[
+            "champ d'application",
+            "déclaration",
+            "définition",
+            "règle ",
+            "```catala_fr",
+        ];
+        if FRENCH_MARKERS.iter().any(|marker| source.contains(marker)) {
+            Self::French
+        } else {
+            Self::English
+        }
+    }
+
+    /// Returns the ISO 639-1 code.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::French => "fr",
+        }
+    }
+}
+
+/// A Catala type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CatalaType {
+    /// `integer`
+    Integer,
+    /// `decimal`
+    Decimal,
+    /// `money`
+    Money,
+    /// `boolean`, also used for `condition` variables
+    Boolean,
+    /// `date`
+    Date,
+    /// `duration`
+    Duration,
+    /// `text`
+    Text,
+    /// `list of T`
+    List(Box<CatalaType>),
+    /// A structure or enumeration name
+    Named(String),
+}
+
+impl fmt::Display for CatalaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer => write!(f, "integer"),
+            Self::Decimal => write!(f, "decimal"),
+            Self::Money => write!(f, "money"),
+            Self::Boolean => write!(f, "boolean"),
+            Self::Date => write!(f, "date"),
+            Self::Duration => write!(f, "duration"),
+            Self::Text => write!(f, "text"),
+            Self::List(inner) => write!(f, "list of {}", inner),
+            Self::Named(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Markdown context of a code block.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LawSection {
+    /// Closest markdown heading above the block (e.g. "Article 1")
+    pub heading: Option<String>,
+    /// Legal text between that heading and the block
+    pub text: String,
+}
+
+/// A variable declared in a scope.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScopeVariable {
+    /// Variable name
+    pub name: String,
+    /// Declared type
+    pub typ: CatalaType,
+    /// Whether the variable is declared `condition`
+    pub condition: bool,
+    /// Whether the variable is an input (`input` or `context`)
+    pub input: bool,
+    /// Whether the variable is an output
+    pub output: bool,
+    /// Scope called through this variable, for sub-scope declarations
+    pub sub_scope: Option<String>,
+    /// Function parameters (`depends on`)
+    pub parameters: Vec<(String, CatalaType)>,
+    /// Declared states, in order
+    pub states: Vec<String>,
+}
+
+/// `declaration scope`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScopeDeclaration {
+    /// Scope name
+    pub name: String,
+    /// Declared variables
+    pub variables: Vec<ScopeVariable>,
+    /// Markdown context of the declaration
+    pub section: LawSection,
+}
+
+impl ScopeDeclaration {
+    /// Looks up a variable by name.
+    pub fn variable(&self, name: &str) -> Option<&ScopeVariable> {
+        self.variables.iter().find(|v| v.name == name)
+    }
+}
+
+/// `declaration structure`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructDeclaration {
+    /// Structure name
+    pub name: String,
+    /// Fields and their types
+    pub fields: Vec<(String, CatalaType)>,
+}
+
+/// `declaration enumeration`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumDeclaration {
+    /// Enumeration name
+    pub name: String,
+    /// Cases and their optional payload type
+    pub cases: Vec<(String, Option<CatalaType>)>,
+}
+
+/// Top-level `declaration name content T equals e`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstantDeclaration {
+    /// Constant or function name
+    pub name: String,
+    /// Declared type
+    pub typ: CatalaType,
+    /// Function parameters
+    pub parameters: Vec<(String, CatalaType)>,
+    /// Value
+    pub value: Expr,
+}
+
+/// Whether an item is a `definition` or a `rule`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefinitionKind {
+    /// `definition x equals e`
+    Definition,
+    /// `rule x consequence fulfilled`
+    Rule,
+}
+
+/// Right-hand side of a definition or rule.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Consequence {
+    /// `equals e`
+    Value(Expr),
+    /// `fulfilled` or `not fulfilled`
+    Fulfilled(bool),
+}
+
+/// A `definition` or `rule` in a scope block.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Definition {
+    /// Definition or rule
+    pub kind: DefinitionKind,
+    /// Defined variable, possibly a sub-scope variable (`sub.x`)
+    pub target: String,
+    /// Function parameters (`definition f of x equals ...`)
+    pub parameters: Vec<String>,
+    /// State being defined
+    pub state: Option<String>,
+    /// `label` of the definition
+    pub label: Option<String>,
+    /// `None` for base cases, `Some(None)` for `exception` and
+    /// `Some(Some(label))` for `exception label`
+    pub exception: Option<Option<String>>,
+    /// `under condition` guard
+    pub condition: Option<Expr>,
+    /// Defined value
+    pub consequence: Consequence,
+    /// Line of the definition in the source
+    pub line: usize,
+    /// Markdown context of the definition
+    pub section: LawSection,
+}
+
+impl Definition {
+    /// Returns `true` for exceptions.
+    pub fn is_exception(&self) -> bool {
+        self.exception.is_some()
+    }
+}
+
+/// An item of a scope block.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScopeItem {
+    /// `definition` or `rule`
+    Definition(Box<Definition>),
+    /// `assertion e`
+    Assertion(Expr),
+}
+
+/// A `scope Name:` block.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScopeUse {
+    /// Scope name
+    pub scope: String,
+    /// `under condition` guard of the whole block
+    pub condition: Option<Expr>,
+    /// Items in source order
+    pub items: Vec<ScopeItem>,
+    /// Markdown context of the block
+    pub section: LawSection,
+}
+
+/// A recovered syntax error.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxIssue {
+    /// Line in the source
+    pub line: usize,
+    /// Description
+    pub message: String,
+}
+
+/// A parsed Catala program.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CatalaProgram {
+    /// Keyword language
+    pub language: CatalaLanguage,
+    /// Scope declarations
+    pub scopes: Vec<ScopeDeclaration>,
+    /// Scope blocks
+    pub scope_uses: Vec<ScopeUse>,
+    /// Structure declarations
+    pub structures: Vec<StructDeclaration>,
+    /// Enumeration declarations
+    pub enumerations: Vec<EnumDeclaration>,
+    /// Top-level constants and functions
+    pub constants: Vec<ConstantDeclaration>,
+    /// `> Include`, `> Module` and `> Using` directives
+    pub directives: Vec<String>,
+    /// Syntax errors the parser recovered from
+    pub issues: Vec<SyntaxIssue>,
+    /// Number of items parsed without errors
+    pub items_parsed: usize,
+}
+
+impl CatalaProgram {
+    /// Parses a Catala source file, detecting its language.
+    ///
+    /// Sources without fenced code blocks are parsed as plain code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use legalis_interop::catala_parser::CatalaProgram;
+    ///
+    /// let program = CatalaProgram::parse(
+    ///     "declaration scope Benefit:\n  input age content integer\n  output eligible condition\n\n\
+    ///      scope Benefit:\n  rule eligible under condition age >= 18 consequence fulfilled\n",
+    /// );
+    /// assert_eq!(program.scopes[0].variables.len(), 2);
+    /// assert_eq!(program.definitions("Benefit").count(), 1);
+    /// ```
+    pub fn parse(source: &str) -> Self {
+        Self::parse_with_language(source, CatalaLanguage::detect(source))
+    }
+
+    /// Parses a Catala source file written in `language`.
+    pub fn parse_with_language(source: &str, language: CatalaLanguage) -> Self {
+        let mut program = Self {
+            language,
+            ..Self::default()
+        };
+        let blocks = code_blocks(source, &mut program.directives);
+        for block in blocks {
+            let tokens = tokenize(&block.code, block.first_line, language);
+            let mut parser = Parser {
+                tokens,
+                pos: 0,
+                language,
+                section: block.section,
+            };
+            parser.program(&mut program);
+        }
+        program
+    }
+
+    /// Looks up a scope declaration.
+    pub fn scope(&self, name: &str) -> Option<&ScopeDeclaration> {
+        self.scopes.iter().find(|s| s.name == name)
+    }
+
+    /// Returns every definition and rule of a scope, across all its blocks.
+    pub fn definitions<'a>(&'a self, scope: &'a str) -> impl Iterator<Item = &'a Definition> {
+        self.scope_uses
+            .iter()
+            .filter(move |u| u.scope == scope)
+            .flat_map(|u| &u.items)
+            .filter_map(|item| match item {
+                ScopeItem::Definition(def) => Some(def.as_ref()),
+                ScopeItem::Assertion(_) => None,
+            })
+    }
+
+    /// Returns the names of all scopes, declared or used, in source order.
+    pub fn scope_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        let declared = self.scopes.iter().map(|s| s.name.as_str());
+        let used = self.scope_uses.iter().map(|u| u.scope.as_str());
+        for name in declared.chain(used) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+}
+
+/// A Catala expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// `true` / `false`
+    Bool(bool),
+    /// Integer literal
+    Integer(i64),
+    /// Decimal literal, including percentages
+    Decimal(f64),
+    /// Money literal (`$1,000.50`, `1 000,50 €`)
+    Money(f64),
+    /// Date literal (`|2024-01-01|`)
+    Date(NaiveDate),
+    /// Duration literal (`3 year`)
+    Duration(i64, DurationUnit),
+    /// Text literal
+    Text(String),
+    /// Variable or field path (`person.income`)
+    Path(Vec<String>),
+    /// Enumeration constructor, with an optional payload
+    Constructor(String, Option<Box<Expr>>),
+    /// Field access on a computed value
+    Field(Box<Expr>, String),
+    /// List literal (`[a; b]`)
+    List(Vec<Expr>),
+    /// Structure literal (`Person { -- age: 3 }`)
+    Struct(String, Vec<(String, Expr)>),
+    /// `not e`
+    Not(Box<Expr>),
+    /// `-e`
+    Neg(Box<Expr>),
+    /// Binary operation
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    /// `if c then a else b`
+    If(Box<Expr>, Box<Expr>, Box<Expr>),
+    /// `let x equals e in body`
+    Let(String, Box<Expr>, Box<Expr>),
+    /// `f of x` or `f of (x, y)`
+    Call(String, Vec<Expr>),
+    /// `match e with pattern -- C of x: e ...`
+    Match(Box<Expr>, Vec<MatchArm>),
+    /// `e with pattern C`
+    WithPattern(Box<Expr>, String),
+    /// Source text the parser could not understand
+    Raw(String),
+}
+
+impl Expr {
+    /// Returns the dotted path of a variable reference.
+    pub fn as_path(&self) -> Option<String> {
+        match self {
+            Self::Path(parts) => Some(parts.join(".")),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the expression evaluates to a boolean.
+    pub fn is_boolean(&self) -> bool {
+        match self {
+            Self::Bool(_) | Self::Not(_) | Self::WithPattern(..) => true,
+            Self::Binary(op, ..) => op.is_comparison() || op.is_logical(),
+            Self::If(_, then, otherwise) => then.is_boolean() && otherwise.is_boolean(),
+            Self::Let(_, _, body) => body.is_boolean(),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the expression contains a money literal.
+    pub fn mentions_money(&self) -> bool {
+        match self {
+            Self::Money(_) => true,
+            Self::Neg(e) | Self::Field(e, _) => e.mentions_money(),
+            Self::Binary(op, l, r) if !op.is_comparison() && !op.is_logical() => {
+                l.mentions_money() || r.mentions_money()
+            }
+            Self::If(_, a, b) => a.mentions_money() || b.mentions_money(),
+            Self::Call(_, args) => args.iter().any(Self::mentions_money),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if any part of the expression failed to parse.
+    pub fn has_raw(&self) -> bool {
+        match self {
+            Self::Raw(_) => true,
+            Self::Constructor(_, Some(e)) | Self::Not(e) | Self::Neg(e) | Self::Field(e, _) => {
+                e.has_raw()
+            }
+            Self::WithPattern(e, _) => e.has_raw(),
+            Self::List(items) | Self::Call(_, items) => items.iter().any(Self::has_raw),
+            Self::Struct(_, fields) => fields.iter().any(|(_, e)| e.has_raw()),
+            Self::Binary(_, l, r) | Self::Let(_, l, r) => l.has_raw() || r.has_raw(),
+            Self::If(c, a, b) => c.has_raw() || a.has_raw() || b.has_raw(),
+            Self::Match(e, arms) => e.has_raw() || arms.iter().any(|arm| arm.body.has_raw()),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    /// Formats the expression in English Catala syntax.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(b) => write!(f, "{}", b),
+            Self::Integer(n) => write!(f, "{}", n),
+            Self::Decimal(d) => write!(f, "{}", d),
+            Self::Money(m) => write!(f, "${}", m),
+            Self::Date(d) => write!(f, "|{}|", d.format("%Y-%m-%d")),
+            Self::Duration(n, unit) => write!(f, "{} {}", n, unit),
+            Self::Text(s) => write!(f, "\"{}\"", s),
+            Self::Path(parts) => write!(f, "{}", parts.join(".")),
+            Self::Constructor(name, None) => write!(f, "{}", name),
+            Self::Constructor(name, Some(payload)) => write!(f, "{} content ({})", name, payload),
+            Self::Field(e, field) => write!(f, "({}).{}", e, field),
+            Self::List(items) => {
+                let items: Vec<String> = items.iter().map(ToString::to_string).collect();
+                write!(f, "[{}]", items.join("; "))
+            }
+            Self::Struct(name, fields) => {
+                write!(f, "{} {{", name)?;
+                for (field, value) in fields {
+                    write!(f, " -- {}: {}", field, value)?;
+                }
+                write!(f, " }}")
+            }
+            Self::Not(e) => write!(f, "not ({})", e),
+            Self::Neg(e) => write!(f, "-({})", e),
+            Self::Binary(op, l, r) => write!(f, "({} {} {})", l, op, r),
+            Self::If(c, a, b) => write!(f, "if {} then {} else {}", c, a, b),
+            Self::Let(name, value, body) => write!(f, "let {} equals {} in {}", name, value, body),
+            Self::Call(name, args) => {
+                let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+                write!(f, "{} of ({})", name, args.join(", "))
+            }
+            Self::Match(e, arms) => {
+                write!(f, "match {} with pattern", e)?;
+                for arm in arms {
+                    match (&arm.constructor, &arm.binding) {
+                        (Some(c), Some(b)) => write!(f, " -- {} content {}: {}", c, b, arm.body)?,
+                        (Some(c), None) => write!(f, " -- {}: {}", c, arm.body)?,
+                        (None, _) => write!(f, " -- anything: {}", arm.body)?,
+                    }
+                }
+                Ok(())
+            }
+            Self::WithPattern(e, c) => write!(f, "{} with pattern {}", e, c),
+            Self::Raw(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+/// Unit of a duration literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationUnit {
+    /// `year`
+    Year,
+    /// `month`
+    Month,
+    /// `day`
+    Day,
+}
+
+impl fmt::Display for DurationUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Year => write!(f, "year"),
+            Self::Month => write!(f, "month"),
+            Self::Day => write!(f, "day"),
+        }
+    }
+}
+
+/// Binary operators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    /// `+`
+    Add,
+    /// `-`
+    Sub,
+    /// `*`
+    Mul,
+    /// `/`
+    Div,
+    /// `=`
+    Eq,
+    /// `!=`
+    Ne,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+    /// `and`
+    And,
+    /// `or`
+    Or,
+    /// `xor`
+    Xor,
+    /// `contains`
+    Contains,
+}
+
+impl BinaryOp {
+    /// Returns `true` for comparison operators.
+    pub fn is_comparison(&self) -> bool {
+        matches!(
+            self,
+            Self::Eq | Self::Ne | Self::Lt | Self::Le | Self::Gt | Self::Ge | Self::Contains
+        )
+    }
+
+    /// Returns `true` for `and`, `or` and `xor`.
+    pub fn is_logical(&self) -> bool {
+        matches!(self, Self::And | Self::Or | Self::Xor)
+    }
+}
+
+impl fmt::Display for BinaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::Mul => "*",
+            Self::Div => "/",
+            Self::Eq => "=",
+            Self::Ne => "!=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::And => "and",
+            Self::Or => "or",
+            Self::Xor => "xor",
+            Self::Contains => "contains",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+/// An arm of a `match` expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArm {
+    /// Constructor, or `None` for `anything`
+    pub constructor: Option<String>,
+    /// Name bound to the payload
+    pub binding: Option<String>,
+    /// Arm body
+    pub body: Expr,
+}
+
+// ============================================================================
+// Literate markdown
+// ============================================================================
+
+/// Catala code extracted from a markdown file.
+struct CodeBlock {
+    code: String,
+    first_line: usize,
+    section: LawSection,
+}
+
+/// Splits a literate file into code blocks, collecting directives.
+fn code_blocks(source: &str, directives: &mut Vec<String>) -> Vec<CodeBlock> {
+    let has_fences = source
+        .lines()
+        .any(|line| line.trim_start().starts_with("```catala"));
+    if !has_fences {
+        return vec![CodeBlock {
+            code: source.to_string(),
+            first_line: 1,
+            section: LawSection::default(),
+        }];
+    }
+
+    let mut blocks = Vec::new();
+    let mut section = LawSection::default();
+    let mut current: Option<CodeBlock> = None;
+    let mut in_other_fence = false;
+
+    for (index, line) in source.lines().enumerate() {
+        let trimmed = line.trim();
+        if let Some(mut block) = current.take() {
+            if trimmed == "```" {
+                blocks.push(block);
+                section.text.clear();
+            } else {
+                block.code.push_str(line);
+                block.code.push('\n');
+                current = Some(block);
+            }
+            continue;
+        }
+        if in_other_fence {
+            in_other_fence = trimmed != "```";
+            continue;
+        }
+        if trimmed.starts_with("```catala") {
+            current = Some(CodeBlock {
+                code: String::new(),
+                first_line: index + 2,
+                section: LawSection {
+                    heading: section.heading.clone(),
+                    text: section.text.trim().to_string(),
+                },
+            });
+        } else if trimmed.starts_with("```") {
+            in_other_fence = true;
+        } else if let Some(heading) = trimmed.strip_prefix('#') {
+            section.heading = Some(heading.trim_start_matches('#').trim().to_string());
+            section.text.clear();
+        } else if let Some(directive) = trimmed.strip_prefix('>') {
+            directives.push(directive.trim().to_string());
+        } else if !trimmed.is_empty() {
+            if !section.text.is_empty() {
+                section.text.push(' ');
+            }
+            section.text.push_str(trimmed);
+        }
+    }
+    blocks.extend(current);
+    blocks
+}
+
+// ============================================================================
+// Lexer
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Word(String),
+    Int(i64),
+    Decimal(f64),
+    Money(f64),
+    Date(NaiveDate),
+    Text(String),
+    Sym(&'static str),
+    Other(char),
+}
+
+impl fmt::Display for Tok {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Word(w) => write!(f, "{}", w),
+            Self::Int(n) => write!(f, "{}", n),
+            Self::Decimal(d) => write!(f, "{}", d),
+            Self::Money(m) => write!(f, "${}", m),
+            Self::Date(d) => write!(f, "|{}|", d.format("%Y-%m-%d")),
+            Self::Text(s) => write!(f, "\"{}\"", s),
+            Self::Sym(s) => write!(f, "{}", s),
+            Self::Other(c) => write!(f, "{}", c),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    tok: Tok,
+    line: usize,
+}
+
+const SYMBOLS: &[&str] = &[
+    "--", "!=", "<=", ">=", "++", "(", ")", "[", "]", "{", "}", ",", ";", ":", ".", "+", "-", "*",
+    "/", "=", "<", ">",
+];
+
+/// Suffixes of typed operators in older Catala versions (`+$`, `>=@`, `*.`).
+const OPERATOR_SUFFIXES: &[char] = &['$', '€', '.', '@', '^'];
+
+/// Splits code into tokens. Characters outside the syntax become
+/// [`Tok::Other`] and are reported by the parser.
+fn tokenize(code: &str, first_line: usize, language: CatalaLanguage) -> Vec<Token> {
+    let chars: Vec<char> = code.chars().collect();
+    let mut tokens = Vec::new();
+    let mut line = first_line;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\n' {
+            line += 1;
+            i += 1;
+            continue;
+        }
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        if c == '#' {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            continue;
+        }
+        if c == '"' {
+            let start = i + 1;
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                if chars[i] == '\n' {
+                    line += 1;
+                }
+                i += 1;
+            }
+            let text: String = chars[start..i.min(chars.len())].iter().collect();
+            tokens.push(Token {
+                tok: Tok::Text(text),
+                line,
+            });
+            i += 1;
+            continue;
+        }
+        if c == '|' {
+            let end = chars[i + 1..]
+                .iter()
+                .position(|&ch| ch == '|' || ch == '\n');
+            let date = end.filter(|&e| chars[i + 1 + e] == '|').and_then(|end| {
+                let text: String = chars[i + 1..i + 1 + end].iter().collect();
+                NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d")
+                    .ok()
+                    .map(|date| (date, end))
+            });
+            if let Some((date, end)) = date {
+                tokens.push(Token {
+                    tok: Tok::Date(date),
+                    line,
+                });
+                i += end + 2;
+                continue;
+            }
+        }
+        if c == '$' && chars.get(i + 1).is_some_and(|ch| ch.is_ascii_digit()) {
+            let (value, next) = english_money(&chars, i + 1);
+            tokens.push(Token {
+                tok: Tok::Money(value),
+                line,
+            });
+            i = next;
+            continue;
+        }
+        if c.is_ascii_digit() {
+            if language == CatalaLanguage::French
+                && let Some((value, next)) = french_money(&chars, i)
+            {
+                tokens.push(Token {
+                    tok: Tok::Money(value),
+                    line,
+                });
+                i = next;
+                continue;
+            }
+            let (tok, next) = number(&chars, i, language);
+            tokens.push(Token { tok, line });
+            i = next;
+            continue;
+        }
+        if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '\'')
+            {
+                i += 1;
+            }
+            tokens.push(Token {
+                tok: Tok::Word(chars[start..i].iter().collect()),
+                line,
+            });
+            continue;
+        }
+
+        let rest: String = chars[i..chars.len().min(i + 2)].iter().collect();
+        let Some(symbol) = SYMBOLS.iter().find(|s| rest.starts_with(**s)) else {
+            tokens.push(Token {
+                tok: Tok::Other(c),
+                line,
+            });
+            i += 1;
+            continue;
+        };
+        i += symbol.chars().count();
+        let is_operator = matches!(
+            *symbol,
+            "+" | "-" | "*" | "/" | "<" | "<=" | ">" | ">=" | "=" | "!="
+        );
+        if is_operator
+            && chars
+                .get(i)
+                .is_some_and(|ch| OPERATOR_SUFFIXES.contains(ch))
+            && !chars.get(i + 1).is_some_and(|ch| ch.is_ascii_digit())
+        {
+            i += 1;
+        }
+        tokens.push(Token {
+            tok: Tok::Sym(symbol),
+            line,
+        });
+    }
+    tokens
+}
+
+/// Lexes `1,000.50` after a `$` sign.
+fn english_money(chars: &[char], start: usize) -> (f64, usize) {
+    let mut i = start;
+    let mut text = String::new();
+    while i < chars.len() {
+        match chars[i] {
+            d if d.is_ascii_digit() => text.push(d),
+            ',' if chars.get(i + 1).is_some_and(|ch| ch.is_ascii_digit()) => {}
+            '.' if chars.get(i + 1).is_some_and(|ch| ch.is_ascii_digit()) => text.push('.'),
+            _ => break,
+        }
+        i += 1;
+    }
+    (text.parse().unwrap_or(0.0), i)
+}
+
+/// Lexes `1 000,50 €` if the digits at `start` form a French money literal.
+fn french_money(chars: &[char], start: usize) -> Option<(f64, usize)> {
+    let mut i = start;
+    let mut text = String::new();
+    while i < chars.len() {
+        match chars[i] {
+            d if d.is_ascii_digit() => text.push(d),
+            ' ' if chars.len() > i + 3
+                && chars[i + 1..i + 4].iter().all(char::is_ascii_digit)
+                && !chars.get(i + 4).is_some_and(char::is_ascii_digit) =>
+            {
+                // Thousands separator
+            }
+            ',' if chars.get(i + 1).is_some_and(char::is_ascii_digit) => text.push('.'),
+            _ => break,
+        }
+        i += 1;
+    }
+    while chars.get(i) == Some(&' ') {
+        i += 1;
+    }
+    (chars.get(i) == Some(&'€')).then(|| (text.parse().unwrap_or(0.0), i + 1))
+}
+
+/// Lexes an integer, decimal or percentage.
+fn number(chars: &[char], start: usize, language: CatalaLanguage) -> (Tok, usize) {
+    let separator = match language {
+        CatalaLanguage::English => '.',
+        CatalaLanguage::French => ',',
+    };
+    let mut i = start;
+    let mut text = String::new();
+    let mut decimal = false;
+    while i < chars.len() {
+        let ch = chars[i];
+        if ch.is_ascii_digit() {
+            text.push(ch);
+        } else if (ch == separator || ch == '.')
+            && !decimal
+            && chars.get(i + 1).is_some_and(char::is_ascii_digit)
+        {
+            text.push('.');
+            decimal = true;
+        } else {
+            break;
+        }
+        i += 1;
+    }
+    if chars.get(i) == Some(&'%') {
+        let value: f64 = text.parse().unwrap_or(0.0);
+        return (Tok::Decimal(value / 100.0), i + 1);
+    }
+    let tok = if decimal {
+        Tok::Decimal(text.parse().unwrap_or(0.0))
+    } else {
+        text.parse()
+            .map(Tok::Int)
+            .unwrap_or_else(|_| Tok::Decimal(text.parse().unwrap_or(0.0)))
+    };
+    (tok, i)
+}
+
+// ============================================================================
+// Keywords
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kw {
+    Declaration,
+    Scope,
+    Structure,
+    Enumeration,
+    Data,
+    Content,
+    Condition,
+    Input,
+    Output,
+    Context,
+    Internal,
+    DependsOn,
+    State,
+    UnderCondition,
+    Consequence,
+    Definition,
+    Rule,
+    Label,
+    Exception,
+    Assertion,
+    Equals,
+    Fulfilled,
+    NotFulfilled,
+    DateRound,
+    If,
+    Then,
+    Else,
+    Let,
+    In,
+    Match,
+    WithPattern,
+    Anything,
+    Of,
+    And,
+    Or,
+    Xor,
+    Not,
+    True,
+    False,
+    Contains,
+    ListOf,
+}
+
+impl Kw {
+    /// Spellings of the keyword as word sequences.
+    fn spelling(self, language: CatalaLanguage) -> &'static [&'static str] {
+        use CatalaLanguage::{English as En, French as Fr};
+        match (self, language) {
+            (Self::Declaration, En) => &["declaration"],
+            (Self::Declaration, Fr) => &["déclaration"],
+            (Self::Scope, En) => &["scope"],
+            (Self::Scope, Fr) => &["champ", "d'application"],
+            (Self::Structure, _) => &["structure"],
+            (Self::Enumeration, En) => &["enumeration"],
+            (Self::Enumeration, Fr) => &["énumération"],
+            (Self::Data, En) => &["data"],
+            (Self::Data, Fr) => &["donnée"],
+            (Self::Content, En) => &["content"],
+            (Self::Content, Fr) => &["contenu"],
+            (Self::Condition, _) => &["condition"],
+            (Self::Input, En) => &["input"],
+            (Self::Input, Fr) => &["entrée"],
+            (Self::Output, En) => &["output"],
+            (Self::Output, Fr) => &["résultat"],
+            (Self::Context, En) => &["context"],
+            (Self::Context, Fr) => &["contexte"],
+            (Self::Internal, En) => &["internal"],
+            (Self::Internal, Fr) => &["interne"],
+            (Self::DependsOn, En) => &["depends", "on"],
+            (Self::DependsOn, Fr) => &["dépend", "de"],
+            (Self::State, En) => &["state"],
+            (Self::State, Fr) => &["état"],
+            (Self::UnderCondition, En) => &["under", "condition"],
+            (Self::UnderCondition, Fr) => &["sous", "condition"],
+            (Self::Consequence, En) => &["consequence"],
+            (Self::Consequence, Fr) => &["conséquence"],
+            (Self::Definition, En) => &["definition"],
+            (Self::Definition, Fr) => &["définition"],
+            (Self::Rule, En) => &["rule"],
+            (Self::Rule, Fr) => &["règle"],
+            (Self::Label, En) => &["label"],
+            (Self::Label, Fr) => &["étiquette"],
+            (Self::Exception, _) => &["exception"],
+            (Self::Assertion, _) => &["assertion"],
+            (Self::Equals, En) => &["equals"],
+            (Self::Equals, Fr) => &["égal", "à"],
+            (Self::Fulfilled, En) => &["fulfilled"],
+            (Self::Fulfilled, Fr) => &["rempli"],
+            (Self::NotFulfilled, En) => &["not", "fulfilled"],
+            (Self::NotFulfilled, Fr) => &["non", "rempli"],
+            (Self::DateRound, En) => &["date", "round"],
+            (Self::DateRound, Fr) => &["date", "arrondi"],
+            (Self::If, En) => &["if"],
+            (Self::If, Fr) => &["si"],
+            (Self::Then, En) => &["then"],
+            (Self::Then, Fr) => &["alors"],
+            (Self::Else, En) => &["else"],
+            (Self::Else, Fr) => &["sinon"],
+            (Self::Let, En) => &["let"],
+            (Self::Let, Fr) => &["soit"],
+            (Self::In, En) => &["in"],
+            (Self::In, Fr) => &["dans"],
+            (Self::Match, En) => &["match"],
+            (Self::Match, Fr) => &["selon"],
+            (Self::WithPattern, En) => &["with", "pattern"],
+            (Self::WithPattern, Fr) => &["sous", "forme"],
+            (Self::Anything, En) => &["anything"],
+            (Self::Anything, Fr) => &["n'importe", "quel"],
+            (Self::Of, En) => &["of"],
+            (Self::Of, Fr) => &["de"],
+            (Self::And, En) => &["and"],
+            (Self::And, Fr) => &["et"],
+            (Self::Or, En) => &["or"],
+            (Self::Or, Fr) => &["ou"],
+            (Self::Xor, En) => &["xor"],
+            (Self::Xor, Fr) => &["ou", "bien"],
+            (Self::Not, En) => &["not"],
+            (Self::Not, Fr) => &["non"],
+            (Self::True, En) => &["true"],
+            (Self::True, Fr) => &["vrai"],
+            (Self::False, En) => &["false"],
+            (Self::False, Fr) => &["faux"],
+            (Self::Contains, En) => &["contains"],
+            (Self::Contains, Fr) => &["contient"],
+            (Self::ListOf, En) => &["list", "of"],
+            (Self::ListOf, Fr) => &["liste", "de"],
+        }
+    }
+}
+
+/// Keywords that start an item; expressions never continue past them.
+const ITEM_STARTS: &[Kw] = &[
+    Kw::Declaration,
+    Kw::Scope,
+    Kw::Definition,
+    Kw::Rule,
+    Kw::Label,
+    Kw::Exception,
+    Kw::Assertion,
+    Kw::DateRound,
+];
+
+/// Single-word keywords that cannot be used as variable names.
+const RESERVED: &[Kw] = &[
+    Kw::Declaration,
+    Kw::Definition,
+    Kw::Rule,
+    Kw::Label,
+    Kw::Exception,
+    Kw::Assertion,
+    Kw::Consequence,
+    Kw::Equals,
+    Kw::Fulfilled,
+    Kw::If,
+    Kw::Then,
+    Kw::Else,
+    Kw::Let,
+    Kw::Match,
+    Kw::And,
+    Kw::Or,
+    Kw::Xor,
+    Kw::Not,
+    Kw::True,
+    Kw::False,
+    Kw::Contains,
+];
+
+// ============================================================================
+// Parser
+// ============================================================================
+
+type ParseResult<T> = Result<T, SyntaxIssue>;
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    language: CatalaLanguage,
+    section: LawSection,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Tok> {
+        self.tokens.get(self.pos).map(|t| &t.tok)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Tok> {
+        self.tokens.get(self.pos + offset).map(|t| &t.tok)
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or_else(|| self.tokens.last())
+            .map(|t| t.line)
+            .unwrap_or(0)
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> ParseResult<T> {
+        let found = self
+            .peek()
+            .map(|t| format!(", found '{}'", t))
+            .unwrap_or_else(|| ", found end of block".to_string());
+        Err(SyntaxIssue {
+            line: self.line(),
+            message: format!("{}{}", message.into(), found),
+        })
+    }
+
+    /// Returns the number of tokens `kw` spans at `offset`, if it is there.
+    fn kw_len_at(&self, offset: usize, kw: Kw) -> Option<usize> {
+        let words = kw.spelling(self.language);
+        words
+            .iter()
+            .enumerate()
+            .all(|(i, word)| {
+                matches!(self.peek_at(offset + i), Some(Tok::Word(w)) if w.eq_ignore_ascii_case(word))
+            })
+            .then_some(words.len())
+    }
+
+    fn at(&self, kw: Kw) -> bool {
+        self.kw_len_at(0, kw).is_some()
+    }
+
+    fn eat(&mut self, kw: Kw) -> bool {
+        match self.kw_len_at(0, kw) {
+            Some(len) => {
+                self.pos += len;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn expect(&mut self, kw: Kw) -> ParseResult<()> {
+        if self.eat(kw) {
+            Ok(())
+        } else {
+            self.error(format!(
+                "Expected '{}'",
+                kw.spelling(self.language).join(" ")
+            ))
+        }
+    }
+
+    fn at_sym(&self, symbol: &str) -> bool {
+        matches!(self.peek(), Some(Tok::Sym(s)) if *s == symbol)
+    }
+
+    fn eat_sym(&mut self, symbol: &str) -> bool {
+        if self.at_sym(symbol) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_sym(&mut self, symbol: &str) -> ParseResult<()> {
+        if self.eat_sym(symbol) {
+            Ok(())
+        } else {
+            self.error(format!("Expected '{}'", symbol))
+        }
+    }
+
+    fn at_item_start(&self) -> bool {
+        ITEM_STARTS.iter().any(|kw| self.at(*kw))
+    }
+
+    fn is_reserved(&self, word: &str) -> bool {
+        RESERVED.iter().any(|kw| {
+            let spelling = kw.spelling(self.language);
+            spelling.len() == 1 && spelling[0].eq_ignore_ascii_case(word)
+        })
+    }
+
+    fn ident(&mut self) -> ParseResult<String> {
+        match self.peek() {
+            Some(Tok::Word(w)) if !self.is_reserved(w) => {
+                let w = w.clone();
+                self.pos += 1;
+                Ok(w)
+            }
+            _ => self.error("Expected an identifier"),
+        }
+    }
+
+    /// Skips to the next item keyword after an error.
+    fn recover(&mut self) {
+        self.pos += 1;
+        while self.pos < self.tokens.len() && !self.at_item_start() {
+            self.pos += 1;
+        }
+    }
+
+    /// Returns the source text of tokens `start..self.pos`.
+    fn text(&self, start: usize) -> String {
+        self.tokens[start..self.pos.min(self.tokens.len())]
+            .iter()
+            .map(|t| t.tok.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    // ------------------------------------------------------------------------
+    // Items
+    // ------------------------------------------------------------------------
+
+    fn program(&mut self, program: &mut CatalaProgram) {
+        while self.pos < self.tokens.len() {
+            let result = if self.at(Kw::Declaration) {
+                self.declaration(program)
+            } else if self.at(Kw::Scope) {
+                self.scope_use(program)
+            } else {
+                self.error("Expected 'declaration' or 'scope'")
+            };
+            match result {
+                Ok(()) => program.items_parsed += 1,
+                Err(issue) => {
+                    program.issues.push(issue);
+                    self.recover();
+                }
+            }
+        }
+    }
+
+    fn declaration(&mut self, program: &mut CatalaProgram) -> ParseResult<()> {
+        self.expect(Kw::Declaration)?;
+        if self.eat(Kw::Scope) {
+            let name = self.ident()?;
+            self.expect_sym(":")?;
+            let mut variables = Vec::new();
+            while self.pos < self.tokens.len() && !self.at_item_start() {
+                variables.push(self.scope_variable()?);
+                program.items_parsed += 1;
+            }
+            program.scopes.push(ScopeDeclaration {
+                name,
+                variables,
+                section: self.section.clone(),
+            });
+        } else if self.eat(Kw::Structure) {
+            let name = self.ident()?;
+            self.expect_sym(":")?;
+            let mut fields = Vec::new();
+            while self.eat(Kw::Data) {
+                let field = self.ident()?;
+                let typ = self.content_type()?;
+                fields.push((field, typ));
+            }
+            program.structures.push(StructDeclaration { name, fields });
+        } else if self.eat(Kw::Enumeration) {
+            let name = self.ident()?;
+            self.expect_sym(":")?;
+            let mut cases = Vec::new();
+            while self.eat_sym("--") {
+                let case = self.ident()?;
+                let payload = if self.eat(Kw::Content) {
+                    Some(self.typ()?)
+                } else {
+                    None
+                };
+                cases.push((case, payload));
+            }
+            program.enumerations.push(EnumDeclaration { name, cases });
+        } else {
+            let name = self.ident()?;
+            let typ = self.content_type()?;
+            let parameters = self.depends_on()?;
+            self.expect(Kw::Equals)?;
+            let value = self.expression_or_raw();
+            program.constants.push(ConstantDeclaration {
+                name,
+                typ,
+                parameters,
+                value,
+            });
+        }
+        Ok(())
+    }
+
+    /// Parses `content T` or `condition`.
+    fn content_type(&mut self) -> ParseResult<CatalaType> {
+        if self.eat(Kw::Content) {
+            self.typ()
+        } else if self.eat(Kw::Condition) {
+            Ok(CatalaType::Boolean)
+        } else {
+            self.error("Expected 'content' or 'condition'")
+        }
+    }
+
+    fn typ(&mut self) -> ParseResult<CatalaType> {
+        if self.eat(Kw::ListOf) {
+            return Ok(CatalaType::List(Box::new(self.typ()?)));
+        }
+        let Some(Tok::Word(word)) = self.peek() else {
+            return self.error("Expected a type");
+        };
+        let typ = match word.to_lowercase().as_str() {
+            "integer" | "entier" => CatalaType::Integer,
+            "decimal" | "décimal" => CatalaType::Decimal,
+            "money" | "argent" => CatalaType::Money,
+            "boolean" | "booléen" => CatalaType::Boolean,
+            "date" => CatalaType::Date,
+            "duration" | "durée" => CatalaType::Duration,
+            "text" | "texte" => CatalaType::Text,
+            "collection" => {
+                self.pos += 1;
+                self.eat(Kw::Of);
+                return Ok(CatalaType::List(Box::new(self.typ()?)));
+            }
+            _ => CatalaType::Named(word.clone()),
+        };
+        self.pos += 1;
+        Ok(typ)
+    }
+
+    fn depends_on(&mut self) -> ParseResult<Vec<(String, CatalaType)>> {
+        let mut parameters = Vec::new();
+        if !self.eat(Kw::DependsOn) {
+            return Ok(parameters);
+        }
+        let parenthesized = self.eat_sym("(");
+        loop {
+            let name = self.ident()?;
+            let typ = self.content_type()?;
+            parameters.push((name, typ));
+            if !(self.eat_sym(",") || self.eat(Kw::And)) {
+                break;
+            }
+        }
+        if parenthesized {
+            self.expect_sym(")")?;
+        }
+        Ok(parameters)
+    }
+
+    fn scope_variable(&mut self) -> ParseResult<ScopeVariable> {
+        let mut qualifiers = Vec::new();
+        loop {
+            let kw = [Kw::Input, Kw::Output, Kw::Context, Kw::Internal]
+                .into_iter()
+                .find(|kw| self.at(*kw));
+            match kw {
+                Some(kw) => {
+                    let word = self.peek().map(ToString::to_string).unwrap_or_default();
+                    self.pos += 1;
+                    qualifiers.push((kw, word));
+                }
+                None => break,
+            }
+        }
+        // Older sources write `context input content T`: the last qualifier
+        // is then the variable name.
+        let name = if self.at(Kw::Content) || self.at(Kw::Condition) {
+            match qualifiers.pop() {
+                Some((_, word)) => word,
+                None => return self.error("Expected a variable name"),
+            }
+        } else {
+            self.ident()?
+        };
+        let kinds: Vec<Kw> = qualifiers.iter().map(|(kw, _)| *kw).collect();
+
+        let (typ, condition, sub_scope) = if self.eat(Kw::Scope) {
+            let scope = self.ident()?;
+            (CatalaType::Named(scope.clone()), false, Some(scope))
+        } else {
+            let condition = self.at(Kw::Condition);
+            (self.content_type()?, condition, None)
+        };
+        let parameters = self.depends_on()?;
+        let mut states = Vec::new();
+        while self.eat(Kw::State) {
+            states.push(self.ident()?);
+        }
+        Ok(ScopeVariable {
+            name,
+            typ,
+            condition,
+            input: kinds.contains(&Kw::Input) || kinds.contains(&Kw::Context),
+            output: kinds.contains(&Kw::Output),
+            sub_scope,
+            parameters,
+            states,
+        })
+    }
+
+    fn scope_use(&mut self, program: &mut CatalaProgram) -> ParseResult<()> {
+        self.expect(Kw::Scope)?;
+        let scope = self.ident()?;
+        let condition = if self.eat(Kw::UnderCondition) {
+            Some(self.expression()?)
+        } else {
+            None
+        };
+        self.expect_sym(":")?;
+
+        let mut items = Vec::new();
+        while self.pos < self.tokens.len() && !self.at(Kw::Declaration) && !self.at(Kw::Scope) {
+            let start = self.pos;
+            match self.scope_item() {
+                Ok(Some(item)) => {
+                    items.push(item);
+                    program.items_parsed += 1;
+                }
+                Ok(None) => {}
+                Err(issue) => {
+                    program.issues.push(issue);
+                    if self.pos == start {
+                        self.pos += 1;
+                    }
+                    while self.pos < self.tokens.len() && !self.at_item_start() {
+                        self.pos += 1;
+                    }
+                }
+            }
+        }
+        program.scope_uses.push(ScopeUse {
+            scope,
+            condition,
+            items,
+            section: self.section.clone(),
+        });
+        Ok(())
+    }
+
+    fn scope_item(&mut self) -> ParseResult<Option<ScopeItem>> {
+        let line = self.line();
+        if self.eat(Kw::Assertion) {
+            return Ok(Some(ScopeItem::Assertion(self.expression_or_raw())));
+        }
+        if self.eat(Kw::DateRound) {
+            // `date round increasing`: rounding mode, no statute content
+            self.ident()?;
+            return Ok(None);
+        }
+
+        let mut label = None;
+        let mut exception = None;
+        loop {
+            if self.eat(Kw::Label) {
+                label = Some(self.ident()?);
+            } else if self.eat(Kw::Exception) {
+                let target = if self.at(Kw::Definition) || self.at(Kw::Rule) {
+                    None
+                } else {
+                    Some(self.ident()?)
+                };
+                exception = Some(target);
+            } else {
+                break;
+            }
+        }
+
+        let kind = if self.eat(Kw::Definition) {
+            DefinitionKind::Definition
+        } else if self.eat(Kw::Rule) {
+            DefinitionKind::Rule
+        } else {
+            return self.error("Expected 'definition', 'rule' or 'assertion'");
+        };
+
+        let mut target = self.ident()?;
+        while self.eat_sym(".") {
+            target.push('.');
+            target.push_str(&self.ident()?);
+        }
+        let mut parameters = Vec::new();
+        if self.eat(Kw::Of) {
+            let parenthesized = self.eat_sym("(");
+            loop {
+                parameters.push(self.ident()?);
+                if !self.eat_sym(",") {
+                    break;
+                }
+            }
+            if parenthesized {
+                self.expect_sym(")")?;
+            }
+        }
+        let state = if self.eat(Kw::State) {
+            Some(self.ident()?)
+        } else {
+            None
+        };
+
+        let condition = if self.eat(Kw::UnderCondition) {
+            let condition = self.expression_or_raw();
+            self.expect(Kw::Consequence)?;
+            Some(condition)
+        } else {
+            if kind == DefinitionKind::Rule {
+                self.expect(Kw::Consequence)?;
+            }
+            None
+        };
+
+        let consequence = match kind {
+            DefinitionKind::Definition => {
+                self.expect(Kw::Equals)?;
+                Consequence::Value(self.expression_or_raw())
+            }
+            DefinitionKind::Rule => {
+                if self.eat(Kw::NotFulfilled) {
+                    Consequence::Fulfilled(false)
+                } else {
+                    self.expect(Kw::Fulfilled)?;
+                    Consequence::Fulfilled(true)
+                }
+            }
+        };
+
+        Ok(Some(ScopeItem::Definition(Box::new(Definition {
+            kind,
+            target,
+            parameters,
+            state,
+            label,
+            exception,
+            condition,
+            consequence,
+            line,
+            section: self.section.clone(),
+        }))))
+    }
+
+    // ------------------------------------------------------------------------
+    // Expressions
+    // ------------------------------------------------------------------------
+
+    /// Parses an expression, keeping its text as [`Expr::Raw`] on failure.
+    ///
+    /// The raw text stops before the next `consequence`, `equals` or item
+    /// keyword.
+    fn expression_or_raw(&mut self) -> Expr {
+        let start = self.pos;
+        match self.expression() {
+            Ok(expr) if self.at_expression_end() => expr,
+            _ => {
+                self.pos = start;
+                while self.pos < self.tokens.len() && !self.at_expression_end() {
+                    self.pos += 1;
+                }
+                Expr::Raw(self.text(start))
+            }
+        }
+    }
+
+    fn at_expression_end(&self) -> bool {
+        self.pos >= self.tokens.len()
+            || self.at_item_start()
+            || self.at(Kw::Consequence)
+            || self.at(Kw::Equals)
+    }
+
+    fn expression(&mut self) -> ParseResult<Expr> {
+        if self.eat(Kw::If) {
+            let condition = self.expression()?;
+            self.expect(Kw::Then)?;
+            let then = self.expression()?;
+            self.expect(Kw::Else)?;
+            let otherwise = self.expression()?;
+            return Ok(Expr::If(
+                Box::new(condition),
+                Box::new(then),
+                Box::new(otherwise),
+            ));
+        }
+        if self.eat(Kw::Let) {
+            let name = self.ident()?;
+            self.expect(Kw::Equals)?;
+            let value = self.expression()?;
+            self.expect(Kw::In)?;
+            let body = self.expression()?;
+            return Ok(Expr::Let(name, Box::new(value), Box::new(body)));
+        }
+        if self.eat(Kw::Match) {
+            return self.match_expression();
+        }
+        self.or_expression()
+    }
+
+    fn match_expression(&mut self) -> ParseResult<Expr> {
+        let scrutinee = self.or_expression()?;
+        self.expect(Kw::WithPattern)?;
+        let mut arms = Vec::new();
+        while self.eat_sym("--") {
+            let (constructor, binding) = if self.eat(Kw::Anything) {
+                (None, None)
+            } else {
+                let constructor = self.ident()?;
+                let binding = if self.eat(Kw::Content) || self.eat(Kw::Of) {
+                    Some(self.ident()?)
+                } else {
+                    None
+                };
+                (Some(constructor), binding)
+            };
+            self.expect_sym(":")?;
+            let body = self.expression()?;
+            arms.push(MatchArm {
+                constructor,
+                binding,
+                body,
+            });
+        }
+        if arms.is_empty() {
+            return self.error("Expected '--' in match");
+        }
+        Ok(Expr::Match(Box::new(scrutinee), arms))
+    }
+
+    fn or_expression(&mut self) -> ParseResult<Expr> {
+        let mut left = self.and_expression()?;
+        loop {
+            let op = if self.eat(Kw::Xor) {
+                BinaryOp::Xor
+            } else if self.eat(Kw::Or) {
+                BinaryOp::Or
+            } else {
+                return Ok(left);
+            };
+            let right = self.and_expression()?;
+            left = Expr::Binary(op, Box::new(left), Box::new(right));
+        }
+    }
+
+    fn and_expression(&mut self) -> ParseResult<Expr> {
+        let mut left = self.not_expression()?;
+        while self.eat(Kw::And) {
+            let right = self.not_expression()?;
+            left = Expr::Binary(BinaryOp::And, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn not_expression(&mut self) -> ParseResult<Expr> {
+        if !self.at(Kw::NotFulfilled) && self.eat(Kw::Not) {
+            return Ok(Expr::Not(Box::new(self.not_expression()?)));
+        }
+        self.comparison()
+    }
+
+    fn comparison(&mut self) -> ParseResult<Expr> {
+        let left = self.additive()?;
+        let op = match self.peek() {
+            Some(Tok::Sym("=")) => BinaryOp::Eq,
+            Some(Tok::Sym("!=")) => BinaryOp::Ne,
+            Some(Tok::Sym("<")) => BinaryOp::Lt,
+            Some(Tok::Sym("<=")) => BinaryOp::Le,
+            Some(Tok::Sym(">")) => BinaryOp::Gt,
+            Some(Tok::Sym(">=")) => BinaryOp::Ge,
+            _ if self.at(Kw::Contains) => BinaryOp::Contains,
+            _ => return Ok(left),
+        };
+        if op == BinaryOp::Contains {
+            self.expect(Kw::Contains)?;
+        } else {
+            self.pos += 1;
+        }
+        let right = self.additive()?;
+        Ok(Expr::Binary(op, Box::new(left), Box::new(right)))
+    }
+
+    fn additive(&mut self) -> ParseResult<Expr> {
+        let mut left = self.multiplicative()?;
+        loop {
+            let op = match self.peek() {
+                Some(Tok::Sym("+")) => BinaryOp::Add,
+                Some(Tok::Sym("-")) => BinaryOp::Sub,
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            let right = self.multiplicative()?;
+            left = Expr::Binary(op, Box::new(left), Box::new(right));
+        }
+    }
+
+    fn multiplicative(&mut self) -> ParseResult<Expr> {
+        let mut left = self.unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(Tok::Sym("*")) => BinaryOp::Mul,
+                Some(Tok::Sym("/")) => BinaryOp::Div,
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            let right = self.unary()?;
+            left = Expr::Binary(op, Box::new(left), Box::new(right));
+        }
+    }
+
+    fn unary(&mut self) -> ParseResult<Expr> {
+        if self.eat_sym("-") {
+            return Ok(Expr::Neg(Box::new(self.unary()?)));
+        }
+        self.postfix()
+    }
+
+    fn postfix(&mut self) -> ParseResult<Expr> {
+        let mut expr = self.primary()?;
+        loop {
+            if self.at_sym(".") && matches!(self.peek_at(1), Some(Tok::Word(_))) {
+                self.pos += 1;
+                let field = self.ident()?;
+                expr = match expr {
+                    Expr::Path(mut parts) => {
+                        parts.push(field);
+                        Expr::Path(parts)
+                    }
+                    other => Expr::Field(Box::new(other), field),
+                };
+            } else if self
+                .kw_len_at(0, Kw::WithPattern)
+                .is_some_and(|len| matches!(self.peek_at(len), Some(Tok::Word(_))))
+            {
+                self.expect(Kw::WithPattern)?;
+                let constructor = self.ident()?;
+                expr = Expr::WithPattern(Box::new(expr), constructor);
+            } else {
+                return Ok(expr);
+            }
+        }
+    }
+
+    fn primary(&mut self) -> ParseResult<Expr> {
+        let Some(tok) = self.peek().cloned() else {
+            return self.error("Expected an expression");
+        };
+        match tok {
+            Tok::Int(n) => {
+                self.pos += 1;
+                Ok(match self.duration_unit() {
+                    Some(unit) => {
+                        self.pos += 1;
+                        Expr::Duration(n, unit)
+                    }
+                    None => Expr::Integer(n),
+                })
+            }
+            Tok::Decimal(d) => {
+                self.pos += 1;
+                Ok(Expr::Decimal(d))
+            }
+            Tok::Money(m) => {
+                self.pos += 1;
+                Ok(Expr::Money(m))
+            }
+            Tok::Date(d) => {
+                self.pos += 1;
+                Ok(Expr::Date(d))
+            }
+            Tok::Text(s) => {
+                self.pos += 1;
+                Ok(Expr::Text(s))
+            }
+            Tok::Sym("(") => {
+                self.pos += 1;
+                let expr = self.expression()?;
+                self.expect_sym(")")?;
+                Ok(expr)
+            }
+            Tok::Sym("[") => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                if !self.eat_sym("]") {
+                    loop {
+                        items.push(self.expression()?);
+                        if !self.eat_sym(";") {
+                            break;
+                        }
+                    }
+                    self.expect_sym("]")?;
+                }
+                Ok(Expr::List(items))
+            }
+            Tok::Word(_) if self.eat(Kw::True) => Ok(Expr::Bool(true)),
+            Tok::Word(_) if self.eat(Kw::False) => Ok(Expr::Bool(false)),
+            Tok::Word(word) => {
+                let name = self.ident()?;
+                if word.starts_with(|c: char| c.is_uppercase()) {
+                    self.constructor(name)
+                } else if self.at(Kw::Of) {
+                    self.call(name)
+                } else {
+                    Ok(Expr::Path(vec![name]))
+                }
+            }
+            _ => self.error("Expected an expression"),
+        }
+    }
+
+    fn duration_unit(&self) -> Option<DurationUnit> {
+        let Some(Tok::Word(word)) = self.peek() else {
+            return None;
+        };
+        match word.as_str() {
+            "year" | "an" => Some(DurationUnit::Year),
+            "month" | "mois" => Some(DurationUnit::Month),
+            "day" | "jour" => Some(DurationUnit::Day),
+            _ => None,
+        }
+    }
+
+    /// Parses a constructor, structure literal or qualified name after `name`.
+    fn constructor(&mut self, name: String) -> ParseResult<Expr> {
+        if self.eat_sym("{") {
+            let mut fields = Vec::new();
+            while self.eat_sym("--") {
+                let field = self.ident()?;
+                self.expect_sym(":")?;
+                fields.push((field, self.expression()?));
+            }
+            self.expect_sym("}")?;
+            return Ok(Expr::Struct(name, fields));
+        }
+        if self.eat(Kw::Content) {
+            let payload = self.postfix()?;
+            return Ok(Expr::Constructor(name, Some(Box::new(payload))));
+        }
+        // `Enum.Case`
+        if self.at_sym(".")
+            && matches!(self.peek_at(1), Some(Tok::Word(w)) if w.starts_with(|c: char| c.is_uppercase()))
+        {
+            self.pos += 1;
+            let case = self.ident()?;
+            return Ok(Expr::Constructor(case, None));
+        }
+        Ok(Expr::Constructor(name, None))
+    }
+
+    fn call(&mut self, name: String) -> ParseResult<Expr> {
+        self.expect(Kw::Of)?;
+        if self.at_sym("(") {
+            self.pos += 1;
+            let mut args = vec![self.expression()?];
+            while self.eat_sym(",") {
+                args.push(self.expression()?);
+            }
+            self.expect_sym(")")?;
+            return Ok(Expr::Call(name, args));
+        }
+        let arg = self.postfix()?;
+        Ok(Expr::Call(name, vec![arg]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUSING: &str = r#"# Housing benefit
+
+## Article 1
+
+A person aged 18 or more is eligible.
+
+```catala
+declaration structure Person:
+  data age content integer
+  data income content money
+
+declaration enumeration Tenure:
+  -- Tenant
+  -- Owner content money
+
+declaration scope HousingBenefit:
+  input person content Person
+  input tenure content Tenure
+  output eligible condition
+  output amount content money
+  internal rate content decimal
+    state base
+    state final
+```
+
+## Article 2
+
+The benefit is $100, or $150 for people aged 65 or more.
+
+```catala
+scope HousingBenefit:
+  rule eligible under condition person.age >= 18 consequence fulfilled
+
+  label base_amount definition amount equals $100
+  exception base_amount definition amount
+    under condition person.age >= 65
+    consequence equals $1,150.50
+
+  definition rate state base equals 5%
+  assertion amount >= $0
+```
+"#;
+
+    #[test]
+    fn test_parse_literate_program() {
+        let program = CatalaProgram::parse(HOUSING);
+        assert!(program.issues.is_empty(), "{:?}", program.issues);
+        assert_eq!(program.language, CatalaLanguage::English);
+
+        assert_eq!(program.structures[0].fields.len(), 2);
+        assert_eq!(
+            program.enumerations[0].cases[1],
+            ("Owner".to_string(), Some(CatalaType::Money))
+        );
+
+        let scope = program.scope("HousingBenefit").unwrap();
+        assert_eq!(scope.section.heading.as_deref(), Some("Article 1"));
+        let eligible = scope.variable("eligible").unwrap();
+        assert!(eligible.condition && eligible.output);
+        assert_eq!(
+            scope.variable("rate").unwrap().states,
+            vec!["base", "final"]
+        );
+
+        let definitions: Vec<_> = program.definitions("HousingBenefit").collect();
+        assert_eq!(definitions.len(), 4);
+        assert_eq!(definitions[0].kind, DefinitionKind::Rule);
+        assert_eq!(definitions[0].consequence, Consequence::Fulfilled(true));
+        assert_eq!(definitions[1].label.as_deref(), Some("base_amount"));
+        assert_eq!(
+            definitions[2].exception,
+            Some(Some("base_amount".to_string()))
+        );
+        assert_eq!(
+            definitions[2].consequence,
+            Consequence::Value(Expr::Money(1150.5))
+        );
+        assert_eq!(definitions[3].state.as_deref(), Some("base"));
+        assert_eq!(
+            definitions[3].consequence,
+            Consequence::Value(Expr::Decimal(0.05))
+        );
+        assert_eq!(definitions[2].section.heading.as_deref(), Some("Article 2"));
+        assert!(definitions[2].section.text.contains("$150"));
+    }
+
+    #[test]
+    fn test_parse_expressions() {
+        let program = CatalaProgram::parse(
+            "scope S:\n\
+             definition a equals if x.age >= 18 and not minor then $10 *$ 2 else $0\n\
+             definition b equals match tenure with pattern -- Tenant: 1 -- Owner content v: 2\n\
+             definition c equals round of (x / 3)\n\
+             definition d equals birth + 18 year\n\
+             definition e equals tenure with pattern Owner\n\
+             definition f equals |2024-01-01|\n",
+        );
+        assert!(program.issues.is_empty(), "{:?}", program.issues);
+        let values: Vec<String> = program
+            .definitions("S")
+            .map(|d| match &d.consequence {
+                Consequence::Value(e) => e.to_string(),
+                Consequence::Fulfilled(_) => String::new(),
+            })
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                "if ((x.age >= 18) and not (minor)) then ($10 * 2) else $0",
+                "match tenure with pattern -- Tenant: 1 -- Owner content v: 2",
+                "round of ((x / 3))",
+                "(birth + 18 year)",
+                "tenure with pattern Owner",
+                "|2024-01-01|",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_french() {
+        let source = "```catala\n\
+déclaration champ d'application CalculImpôt:\n  \
+entrée revenu contenu argent\n  \
+résultat impôt contenu argent\n\n\
+champ d'application CalculImpôt:\n  \
+étiquette base définition impôt égal à revenu * 20%\n  \
+exception base définition impôt sous condition revenu <= 10 000 € conséquence égal à 0,00 €\n\
+```\n";
+        let program = CatalaProgram::parse(source);
+        assert!(program.issues.is_empty(), "{:?}", program.issues);
+        assert_eq!(program.language, CatalaLanguage::French);
+        let scope = program.scope("CalculImpôt").unwrap();
+        assert!(scope.variable("revenu").unwrap().input);
+        let definitions: Vec<_> = program.definitions("CalculImpôt").collect();
+        assert_eq!(
+            definitions[1].condition.as_ref().unwrap().to_string(),
+            "(revenu <= $10000)"
+        );
+        assert_eq!(
+            definitions[0].consequence,
+            Consequence::Value(Expr::Binary(
+                BinaryOp::Mul,
+                Box::new(Expr::Path(vec!["revenu".to_string()])),
+                Box::new(Expr::Decimal(0.2))
+            ))
+        );
+    }
+
+    #[test]
+    fn test_recovers_from_errors() {
+        let program = CatalaProgram::parse(
+            "declaration scope S:\n  context input content integer\n\n\
+             scope S:\n  bogus\n  definition a equals\n  \
+             definition b equals sum integer of x among l\n  \
+             definition c 3\n  definition d equals 4 $\n",
+        );
+        assert_eq!(program.scopes[0].variables[0].name, "input");
+        let definitions: Vec<_> = program.definitions("S").collect();
+        let targets: Vec<&str> = definitions.iter().map(|d| d.target.as_str()).collect();
+        assert_eq!(targets, vec!["a", "b", "d"]);
+        assert!(matches!(
+            &definitions[1].consequence,
+            Consequence::Value(Expr::Raw(text)) if text.contains("among")
+        ));
+        assert_eq!(
+            definitions[2].consequence,
+            Consequence::Value(Expr::Raw("4 $".to_string()))
+        );
+        assert_eq!(program.issues.len(), 2);
+        assert!(program.issues[0].message.contains("Expected 'definition'"));
+        assert!(program.issues[1].message.contains("Expected 'equals'"));
+    }
+}
@@ -191,15 +191,15 @@ impl FormatCoverage {
             "Hierarchical sections".to_string(),
             "Title and headings".to_string(),
             "Basic content".to_string(),
-        ];
-
-        let unsupported = vec![
+            "FRBR identification".to_string(),
+            "Lifecycle events".to_string(),
+            "Temporal groups".to_string(),
             "Amendments".to_string(),
             "References".to_string(),
-            "Complex document relationships".to_string(),
-            "Lifecycle events".to_string(),
         ];
 
+        let unsupported = vec!["Complex document relationships".to_string()];
+
         let partial = HashMap::new();
 
         (supported, unsupported, partial)
@@ -212,18 +212,19 @@ impl FormatCoverage {
             "Conclusions (effects)".to_string(),
             "Rule metadata".to_string(),
             "Prescriptive/Constitutive rules".to_string(),
-        ];
-
-        let unsupported = vec![
             "Defeasibility".to_string(),
-            "Argumentation structures".to_string(),
             "Rule overrides".to_string(),
+            "Deontic operators and bearers".to_string(),
+            "Reparation chains".to_string(),
+            "Legal source associations".to_string(),
         ];
 
+        let unsupported = vec!["Argumentation structures".to_string()];
+
         let mut partial = HashMap::new();
         partial.insert(
-            "Deontic qualifiers".to_string(),
-            "Basic qualifiers supported, complex deontic logic partial".to_string(),
+            "RuleML formulas".to_string(),
+            "Comparisons and connectives mapped, other relations become attributes".to_string(),
         );
 
         (supported, unsupported, partial)
@@ -634,12 +635,21 @@ impl FormatCoverage {
             "Legal categories and classifications".to_string(),
             "Document metadata".to_string(),
             "Legal stamps and watermarks".to_string(),
+            "Native PDF text extraction (fonts, ToUnicode, compressed streams)".to_string(),
+            "Article, paragraph and item segmentation".to_string(),
+            "Footnotes and cross-references".to_string(),
+            "Source offsets for provenance".to_string(),
         ];
         let unsupported = vec![
-            "Advanced PDF features".to_string(),
+            "Encrypted PDF files".to_string(),
+            "Scanned pages without a text layer (OCR)".to_string(),
             "Digital signature validation".to_string(),
         ];
-        let partial = HashMap::new();
+        let mut partial = HashMap::new();
+        partial.insert(
+            "Reading order".to_string(),
+            "Columns and vertical writing are ordered; tables are read row by row".to_string(),
+        );
         (supported, unsupported, partial)
     }
 
@@ -1,16 +1,2046 @@
 //! DMN (Decision Model and Notation) import/export - OMG standard for decision modeling.
+//!
+//! Reads and writes DMN 1.1 to 1.4 documents such as those produced by the
+//! Camunda Modeler:
+//! - Decisions with decision tables (all hit policies and aggregations) or
+//!   literal expressions
+//! - Input and output clauses, input values, output values and default outputs
+//! - Input data, business knowledge models and knowledge sources with their
+//!   information, knowledge and authority requirements
+//! - Diagram interchange (DMNDI), extension elements and vendor attributes,
+//!   kept verbatim so documents round-trip without loss
+//!
+//! [`DmnDefinitions`] evaluates decisions with [`crate::feel`], following the
+//! decision requirements graph. The importer turns every decision table rule
+//! into a statute whose preconditions mirror the rule's input entries, and
+//! [`DmnDefinitions::cross_check`] executes the table against the generated
+//! statutes to catch translation differences.
+//!
+//! Reference: <https://www.omg.org/spec/DMN/1.4/>
 
+use crate::feel::{BinaryOp, Expr, FeelContext, FeelExpression, FeelValue, UnaryTest, UnaryTests};
 use crate::{
     ConversionReport, FormatExporter, FormatImporter, InteropError, InteropResult, LegalFormat,
 };
-use legalis_core::{Effect, EffectType, Statute};
+use legalis_core::{
+    AttributeBasedContext, ComparisonOp, Condition, Decimal, Effect, EffectType, Statute,
+};
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::{Reader, Writer};
+use std::collections::{BTreeMap, HashMap};
+use std::io::Cursor;
+
+/// Namespace of DMN 1.4 models.
+pub const DMN14_NAMESPACE: &str = "https://www.omg.org/spec/DMN/20211108/MODEL/";
+
+// ==================================================
+// XML tree
+// ==================================================
+
+/// An XML element kept verbatim, such as a DMNDI diagram.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct XmlElement {
+    /// Qualified element name
+    pub name: String,
+    /// Attributes in document order
+    pub attributes: Vec<(String, String)>,
+    /// Text content
+    pub text: String,
+    /// Child elements
+    pub children: Vec<XmlElement>,
+}
+
+impl XmlElement {
+    /// Creates an element without attributes or content.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Parses a document into its root element.
+    pub fn parse(source: &str) -> InteropResult<Self> {
+        let mut reader = Reader::from_str(source);
+        let mut stack: Vec<XmlElement> = Vec::new();
+        let mut root = None;
+
+        loop {
+            let event = reader
+                .read_event()
+                .map_err(|e| InteropError::ParseError(format!("XML parse error: {}", e)))?;
+            match event {
+                Event::Start(e) => stack.push(Self::from_start(&e)?),
+                Event::Empty(e) => {
+                    let element = Self::from_start(&e)?;
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => root = Some(element),
+                    }
+                }
+                Event::End(_) => {
+                    let Some(mut element) = stack.pop() else {
+                        return Err(InteropError::ParseError("Unbalanced XML".to_string()));
+                    };
+                    element.text = element.text.trim().to_string();
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => root = Some(element),
+                    }
+                }
+                Event::Text(e) => {
+                    if let Some(element) = stack.last_mut() {
+                        let text = e
+                            .xml_content()
+                            .map_err(|e| InteropError::ParseError(e.to_string()))?;
+                        element.text.push_str(&text);
+                    }
+                }
+                Event::CData(e) => {
+                    if let Some(element) = stack.last_mut() {
+                        element
+                            .text
+                            .push_str(&String::from_utf8_lossy(e.into_inner().as_ref()));
+                    }
+                }
+                Event::GeneralRef(e) => {
+                    if let Some(element) = stack.last_mut() {
+                        let name = e
+                            .decode()
+                            .map_err(|e| InteropError::ParseError(e.to_string()))?;
+                        let resolved = match e.resolve_char_ref() {
+                            Ok(Some(ch)) => ch.to_string(),
+                            _ => quick_xml::escape::resolve_predefined_entity(&name)
+                                .map(str::to_string)
+                                .unwrap_or_else(|| format!("&{};", name)),
+                        };
+                        element.text.push_str(&resolved);
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        root.ok_or_else(|| InteropError::ParseError("Empty XML document".to_string()))
+    }
+
+    fn from_start(start: &BytesStart) -> InteropResult<Self> {
+        let mut element = Self::new(String::from_utf8_lossy(start.name().as_ref()));
+        for attribute in start.attributes() {
+            let attribute = attribute.map_err(|e| InteropError::ParseError(e.to_string()))?;
+            let value = attribute
+                .unescape_value()
+                .map_err(|e| InteropError::ParseError(e.to_string()))?;
+            element.attributes.push((
+                String::from_utf8_lossy(attribute.key.as_ref()).to_string(),
+                value.to_string(),
+            ));
+        }
+        Ok(element)
+    }
+
+    /// Returns the element name without its namespace prefix.
+    pub fn local_name(&self) -> &str {
+        self.name.rsplit(':').next().unwrap_or_default()
+    }
+
+    /// Returns an attribute value.
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub(crate) fn with_attr(mut self, name: &str, value: Option<&str>) -> Self {
+        if let Some(value) = value {
+            self.attributes.push((name.to_string(), value.to_string()));
+        }
+        self
+    }
+
+    pub(crate) fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    pub(crate) fn with_child(mut self, child: XmlElement) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub(crate) fn child(&self, local_name: &str) -> Option<&XmlElement> {
+        self.children.iter().find(|c| c.local_name() == local_name)
+    }
+
+    pub(crate) fn children_named<'a>(
+        &'a self,
+        local_name: &'a str,
+    ) -> impl Iterator<Item = &'a XmlElement> {
+        self.children
+            .iter()
+            .filter(move |c| c.local_name() == local_name)
+    }
+
+    /// Returns the attributes other than the listed ones.
+    fn other_attributes(&self, known: &[&str]) -> Vec<(String, String)> {
+        self.attributes
+            .iter()
+            .filter(|(key, _)| !known.contains(&key.as_str()))
+            .cloned()
+            .collect()
+    }
+
+    /// Serializes the element as an XML document.
+    pub fn to_xml(&self) -> InteropResult<String> {
+        let mut writer = Writer::new_with_indent(Cursor::new(Vec::new()), b' ', 2);
+        writer
+            .write_event(Event::Decl(quick_xml::events::BytesDecl::new(
+                "1.0",
+                Some("UTF-8"),
+                None,
+            )))
+            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
+        self.write(&mut writer)?;
+        let mut output = String::from_utf8(writer.into_inner().into_inner())
+            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
+        output.push('\n');
+        Ok(output)
+    }
+
+    fn write(&self, writer: &mut Writer<Cursor<Vec<u8>>>) -> InteropResult<()> {
+        let mut start = BytesStart::new(self.name.as_str());
+        for (key, value) in &self.attributes {
+            start.push_attribute((key.as_str(), value.as_str()));
+        }
+        let result = if self.text.is_empty() && self.children.is_empty() {
+            writer.write_event(Event::Empty(start))
+        } else {
+            writer.write_event(Event::Start(start)).and_then(|_| {
+                if !self.text.is_empty() {
+                    writer.write_event(Event::Text(BytesText::new(&self.text)))?;
+                }
+                Ok(())
+            })
+        };
+        result.map_err(|e| InteropError::SerializationError(e.to_string()))?;
+
+        if !self.text.is_empty() || !self.children.is_empty() {
+            for child in &self.children {
+                child.write(writer)?;
+            }
+            writer
+                .write_event(Event::End(BytesEnd::new(self.name.as_str())))
+                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+// ==================================================
+// Model
+// ==================================================
+
+/// Hit policy of a decision table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HitPolicy {
+    /// At most one rule may match
+    #[default]
+    Unique,
+    /// The first matching rule in rule order wins
+    First,
+    /// The matching rule with the highest output priority wins
+    Priority,
+    /// Several rules may match if they have the same output
+    Any,
+    /// All matching rules, optionally aggregated
+    Collect,
+    /// All matching rules in rule order
+    RuleOrder,
+    /// All matching rules in output priority order
+    OutputOrder,
+}
+
+impl HitPolicy {
+    /// Returns the DMN attribute value.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unique => "UNIQUE",
+            Self::First => "FIRST",
+            Self::Priority => "PRIORITY",
+            Self::Any => "ANY",
+            Self::Collect => "COLLECT",
+            Self::RuleOrder => "RULE ORDER",
+            Self::OutputOrder => "OUTPUT ORDER",
+        }
+    }
+
+    /// Parses a DMN attribute value.
+    pub fn from_dmn(value: &str) -> Option<Self> {
+        match value.trim().to_uppercase().as_str() {
+            "UNIQUE" | "U" => Some(Self::Unique),
+            "FIRST" | "F" => Some(Self::First),
+            "PRIORITY" | "P" => Some(Self::Priority),
+            "ANY" | "A" => Some(Self::Any),
+            "COLLECT" | "C" => Some(Self::Collect),
+            "RULE ORDER" | "R" => Some(Self::RuleOrder),
+            "OUTPUT ORDER" | "O" => Some(Self::OutputOrder),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the policy produces a single result.
+    pub fn is_single_hit(&self) -> bool {
+        matches!(
+            self,
+            Self::Unique | Self::First | Self::Priority | Self::Any
+        )
+    }
+}
+
+/// Aggregation of a `COLLECT` decision table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    /// Sum of the outputs (`C+`)
+    Sum,
+    /// Number of outputs (`C#`)
+    Count,
+    /// Smallest output (`C<`)
+    Min,
+    /// Largest output (`C>`)
+    Max,
+}
+
+impl Aggregation {
+    /// Returns the DMN attribute value.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Sum => "SUM",
+            Self::Count => "COUNT",
+            Self::Min => "MIN",
+            Self::Max => "MAX",
+        }
+    }
+
+    /// Parses a DMN attribute value.
+    pub fn from_dmn(value: &str) -> Option<Self> {
+        match value.trim().to_uppercase().as_str() {
+            "SUM" => Some(Self::Sum),
+            "COUNT" => Some(Self::Count),
+            "MIN" => Some(Self::Min),
+            "MAX" => Some(Self::Max),
+            _ => None,
+        }
+    }
+}
+
+/// A FEEL expression or unary tests with an optional ID and type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LiteralExpression {
+    /// Element ID
+    pub id: Option<String>,
+    /// Type reference
+    pub type_ref: Option<String>,
+    /// FEEL text
+    pub text: String,
+}
+
+impl LiteralExpression {
+    /// Creates an expression without ID or type.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    fn from_element(element: &XmlElement) -> Self {
+        Self {
+            id: element.attr("id").map(str::to_string),
+            type_ref: element.attr("typeRef").map(str::to_string),
+            text: element
+                .child("text")
+                .map(|t| t.text.clone())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn to_element(&self, name: &str) -> XmlElement {
+        XmlElement::new(name)
+            .with_attr("id", self.id.as_deref())
+            .with_attr("typeRef", self.type_ref.as_deref())
+            .with_child(XmlElement::new("text").with_text(&self.text))
+    }
+}
+
+/// A named, typed variable.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InformationItem {
+    /// Element ID
+    pub id: Option<String>,
+    /// Variable name
+    pub name: String,
+    /// Type reference
+    pub type_ref: Option<String>,
+}
+
+impl InformationItem {
+    fn from_element(element: &XmlElement) -> Self {
+        Self {
+            id: element.attr("id").map(str::to_string),
+            name: element.attr("name").unwrap_or_default().to_string(),
+            type_ref: element.attr("typeRef").map(str::to_string),
+        }
+    }
+
+    fn to_element(&self, name: &str) -> XmlElement {
+        XmlElement::new(name)
+            .with_attr("id", self.id.as_deref())
+            .with_attr("name", Some(&self.name))
+            .with_attr("typeRef", self.type_ref.as_deref())
+    }
+}
+
+/// Kind of a DRG requirement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequirementKind {
+    /// `informationRequirement`
+    Information,
+    /// `knowledgeRequirement`
+    Knowledge,
+    /// `authorityRequirement`
+    Authority,
+}
+
+impl RequirementKind {
+    fn element_name(&self) -> &'static str {
+        match self {
+            Self::Information => "informationRequirement",
+            Self::Knowledge => "knowledgeRequirement",
+            Self::Authority => "authorityRequirement",
+        }
+    }
+}
+
+/// Kind of DRG element a requirement points to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequiredElement {
+    /// `requiredDecision`
+    Decision,
+    /// `requiredInput`
+    Input,
+    /// `requiredKnowledge`
+    Knowledge,
+    /// `requiredAuthority`
+    Authority,
+}
+
+impl RequiredElement {
+    fn element_name(&self) -> &'static str {
+        match self {
+            Self::Decision => "requiredDecision",
+            Self::Input => "requiredInput",
+            Self::Knowledge => "requiredKnowledge",
+            Self::Authority => "requiredAuthority",
+        }
+    }
+
+    fn from_element_name(name: &str) -> Option<Self> {
+        match name {
+            "requiredDecision" => Some(Self::Decision),
+            "requiredInput" => Some(Self::Input),
+            "requiredKnowledge" => Some(Self::Knowledge),
+            "requiredAuthority" => Some(Self::Authority),
+            _ => None,
+        }
+    }
+}
+
+/// An edge of the decision requirements graph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Requirement {
+    /// Element ID
+    pub id: Option<String>,
+    /// Requirement kind
+    pub kind: RequirementKind,
+    /// Kind of the required element
+    pub required: RequiredElement,
+    /// ID of the required element, without the leading `#`
+    pub href: String,
+}
+
+impl Requirement {
+    fn parse_all(element: &XmlElement) -> Vec<Self> {
+        let kinds = [
+            RequirementKind::Information,
+            RequirementKind::Knowledge,
+            RequirementKind::Authority,
+        ];
+        element
+            .children
+            .iter()
+            .filter_map(|child| {
+                let kind = *kinds
+                    .iter()
+                    .find(|k| k.element_name() == child.local_name())?;
+                let target = child.children.first()?;
+                Some(Self {
+                    id: child.attr("id").map(str::to_string),
+                    kind,
+                    required: RequiredElement::from_element_name(target.local_name())?,
+                    href: target
+                        .attr("href")
+                        .unwrap_or_default()
+                        .trim_start_matches('#')
+                        .to_string(),
+                })
+            })
+            .collect()
+    }
+
+    fn to_element(&self) -> XmlElement {
+        let href = format!("#{}", self.href);
+        XmlElement::new(self.kind.element_name())
+            .with_attr("id", self.id.as_deref())
+            .with_child(
+                XmlElement::new(self.required.element_name()).with_attr("href", Some(&href)),
+            )
+    }
+}
+
+/// An input column of a decision table.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InputClause {
+    /// Element ID
+    pub id: Option<String>,
+    /// Column label
+    pub label: Option<String>,
+    /// Expression whose value the input entries test
+    pub expression: LiteralExpression,
+    /// Allowed values, as unary tests
+    pub input_values: Option<LiteralExpression>,
+    /// Other attributes, such as vendor extensions
+    pub attributes: Vec<(String, String)>,
+}
+
+/// An output column of a decision table.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OutputClause {
+    /// Element ID
+    pub id: Option<String>,
+    /// Column label
+    pub label: Option<String>,
+    /// Output name, used as context key for multi-output tables
+    pub name: Option<String>,
+    /// Type reference
+    pub type_ref: Option<String>,
+    /// Allowed values in priority order, as unary tests
+    pub output_values: Option<LiteralExpression>,
+    /// Output used when no rule matches
+    pub default_output_entry: Option<LiteralExpression>,
+    /// Other attributes, such as vendor extensions
+    pub attributes: Vec<(String, String)>,
+}
+
+/// A decision table rule.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DecisionRule {
+    /// Element ID
+    pub id: Option<String>,
+    /// Description of the rule
+    pub description: Option<String>,
+    /// Unary tests, one per input clause
+    pub input_entries: Vec<LiteralExpression>,
+    /// Output expressions, one per output clause
+    pub output_entries: Vec<LiteralExpression>,
+    /// Annotation texts, one per annotation column
+    pub annotation_entries: Vec<String>,
+}
+
+/// A decision table.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DecisionTable {
+    /// Element ID
+    pub id: Option<String>,
+    /// Hit policy
+    pub hit_policy: HitPolicy,
+    /// Aggregation for `COLLECT` tables
+    pub aggregation: Option<Aggregation>,
+    /// Label of the output
+    pub output_label: Option<String>,
+    /// Input columns
+    pub inputs: Vec<InputClause>,
+    /// Output columns
+    pub outputs: Vec<OutputClause>,
+    /// Names of the annotation columns
+    pub annotations: Vec<String>,
+    /// Rules in table order
+    pub rules: Vec<DecisionRule>,
+    /// Other attributes, such as `preferredOrientation`
+    pub attributes: Vec<(String, String)>,
+}
+
+impl DecisionTable {
+    fn from_element(element: &XmlElement) -> InteropResult<Self> {
+        let hit_policy = match element.attr("hitPolicy") {
+            Some(value) => HitPolicy::from_dmn(value).ok_or_else(|| {
+                InteropError::ParseError(format!("Unknown DMN hit policy: {}", value))
+            })?,
+            None => HitPolicy::Unique,
+        };
+        let aggregation = match element.attr("aggregation") {
+            Some(value) => Some(Aggregation::from_dmn(value).ok_or_else(|| {
+                InteropError::ParseError(format!("Unknown DMN aggregation: {}", value))
+            })?),
+            None => None,
+        };
+
+        let inputs = element
+            .children_named("input")
+            .map(|input| InputClause {
+                id: input.attr("id").map(str::to_string),
+                label: input.attr("label").map(str::to_string),
+                expression: input
+                    .child("inputExpression")
+                    .map(LiteralExpression::from_element)
+                    .unwrap_or_default(),
+                input_values: input
+                    .child("inputValues")
+                    .map(LiteralExpression::from_element),
+                attributes: input.other_attributes(&["id", "label"]),
+            })
+            .collect();
+        let outputs = element
+            .children_named("output")
+            .map(|output| OutputClause {
+                id: output.attr("id").map(str::to_string),
+                label: output.attr("label").map(str::to_string),
+                name: output.attr("name").map(str::to_string),
+                type_ref: output.attr("typeRef").map(str::to_string),
+                output_values: output
+                    .child("outputValues")
+                    .map(LiteralExpression::from_element),
+                default_output_entry: output
+                    .child("defaultOutputEntry")
+                    .map(LiteralExpression::from_element),
+                attributes: output.other_attributes(&["id", "label", "name", "typeRef"]),
+            })
+            .collect();
+        let rules = element
+            .children_named("rule")
+            .map(|rule| DecisionRule {
+                id: rule.attr("id").map(str::to_string),
+                description: rule.child("description").map(|d| d.text.clone()),
+                input_entries: rule
+                    .children_named("inputEntry")
+                    .map(LiteralExpression::from_element)
+                    .collect(),
+                output_entries: rule
+                    .children_named("outputEntry")
+                    .map(LiteralExpression::from_element)
+                    .collect(),
+                annotation_entries: rule
+                    .children_named("annotationEntry")
+                    .map(|a| a.child("text").map(|t| t.text.clone()).unwrap_or_default())
+                    .collect(),
+            })
+            .collect();
+
+        Ok(Self {
+            id: element.attr("id").map(str::to_string),
+            hit_policy,
+            aggregation,
+            output_label: element.attr("outputLabel").map(str::to_string),
+            inputs,
+            outputs,
+            annotations: element
+                .children_named("annotation")
+                .map(|a| a.attr("name").unwrap_or_default().to_string())
+                .collect(),
+            rules,
+            attributes: element.other_attributes(&[
+                "id",
+                "hitPolicy",
+                "aggregation",
+                "outputLabel",
+            ]),
+        })
+    }
+
+    fn to_element(&self) -> XmlElement {
+        let mut element = XmlElement::new("decisionTable")
+            .with_attr("id", self.id.as_deref())
+            .with_attr("hitPolicy", Some(self.hit_policy.as_str()))
+            .with_attr("aggregation", self.aggregation.map(|a| a.as_str()))
+            .with_attr("outputLabel", self.output_label.as_deref());
+        element.attributes.extend(self.attributes.iter().cloned());
+
+        for input in &self.inputs {
+            let mut clause = XmlElement::new("input")
+                .with_attr("id", input.id.as_deref())
+                .with_attr("label", input.label.as_deref());
+            clause.attributes.extend(input.attributes.iter().cloned());
+            clause = clause.with_child(input.expression.to_element("inputExpression"));
+            if let Some(values) = &input.input_values {
+                clause = clause.with_child(values.to_element("inputValues"));
+            }
+            element = element.with_child(clause);
+        }
+        for output in &self.outputs {
+            let mut clause = XmlElement::new("output")
+                .with_attr("id", output.id.as_deref())
+                .with_attr("label", output.label.as_deref())
+                .with_attr("name", output.name.as_deref())
+                .with_attr("typeRef", output.type_ref.as_deref());
+            clause.attributes.extend(output.attributes.iter().cloned());
+            if let Some(values) = &output.output_values {
+                clause = clause.with_child(values.to_element("outputValues"));
+            }
+            if let Some(default) = &output.default_output_entry {
+                clause = clause.with_child(default.to_element("defaultOutputEntry"));
+            }
+            element = element.with_child(clause);
+        }
+        for annotation in &self.annotations {
+            element = element
+                .with_child(XmlElement::new("annotation").with_attr("name", Some(annotation)));
+        }
+        for rule in &self.rules {
+            let mut row = XmlElement::new("rule").with_attr("id", rule.id.as_deref());
+            if let Some(description) = &rule.description {
+                row = row.with_child(XmlElement::new("description").with_text(description));
+            }
+            for entry in &rule.input_entries {
+                row = row.with_child(entry.to_element("inputEntry"));
+            }
+            for entry in &rule.output_entries {
+                row = row.with_child(entry.to_element("outputEntry"));
+            }
+            for annotation in &rule.annotation_entries {
+                row = row.with_child(
+                    XmlElement::new("annotationEntry")
+                        .with_child(XmlElement::new("text").with_text(annotation)),
+                );
+            }
+            element = element.with_child(row);
+        }
+        element
+    }
+
+    /// Evaluates the input expressions.
+    fn input_values(&self, context: &FeelContext) -> InteropResult<Vec<FeelValue>> {
+        self.inputs
+            .iter()
+            .map(|input| Ok(FeelExpression::parse(&input.expression.text)?.evaluate(context)))
+            .collect()
+    }
+
+    /// Returns the indices of the rules whose input entries all match.
+    pub fn matching_rules(&self, context: &FeelContext) -> InteropResult<Vec<usize>> {
+        let values = self.input_values(context)?;
+        let mut matched = Vec::new();
+        for (index, rule) in self.rules.iter().enumerate() {
+            if rule.input_entries.len() != values.len() {
+                return Err(InteropError::ValidationError(format!(
+                    "Rule {} has {} input entries but the table has {} inputs",
+                    index + 1,
+                    rule.input_entries.len(),
+                    values.len()
+                )));
+            }
+            let mut matches = true;
+            for (entry, value) in rule.input_entries.iter().zip(&values) {
+                if !UnaryTests::parse(&entry.text)?.matches(value, context) {
+                    matches = false;
+                    break;
+                }
+            }
+            if matches {
+                matched.push(index);
+            }
+        }
+        Ok(matched)
+    }
+
+    /// Evaluates the table, applying its hit policy.
+    ///
+    /// Single-hit tables return the output of the selected rule, multi-hit
+    /// tables a list of outputs or their aggregation. Tables with several
+    /// outputs produce contexts keyed by output name.
+    pub fn evaluate(&self, context: &FeelContext) -> InteropResult<FeelValue> {
+        let matched = self.matching_rules(context)?;
+        let mut results = Vec::with_capacity(matched.len());
+        for &index in &matched {
+            let entries: Vec<&str> = self.rules[index]
+                .output_entries
+                .iter()
+                .map(|e| e.text.as_str())
+                .collect();
+            results.push(self.output(&entries, context)?);
+        }
+
+        if results.is_empty() {
+            let defaults: Vec<&str> = self
+                .outputs
+                .iter()
+                .map(|o| {
+                    o.default_output_entry
+                        .as_ref()
+                        .map_or("null", |d| d.text.as_str())
+                })
+                .collect();
+            if self
+                .outputs
+                .iter()
+                .any(|o| o.default_output_entry.is_some())
+            {
+                results.push(self.output(&defaults, context)?);
+            } else if self.hit_policy.is_single_hit() {
+                return Ok(FeelValue::Null);
+            }
+        }
+
+        let rule_ids = || {
+            matched
+                .iter()
+                .map(|&i| {
+                    self.rules[i]
+                        .id
+                        .clone()
+                        .unwrap_or_else(|| (i + 1).to_string())
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match self.hit_policy {
+            HitPolicy::Unique if results.len() > 1 => Err(InteropError::ValidationError(format!(
+                "UNIQUE hit policy violated: rules {} match",
+                rule_ids()
+            ))),
+            HitPolicy::Any if results.iter().any(|r| r.feel_eq(&results[0]) != Some(true)) => {
+                Err(InteropError::ValidationError(format!(
+                    "ANY hit policy violated: rules {} match with different outputs",
+                    rule_ids()
+                )))
+            }
+            HitPolicy::Unique | HitPolicy::Any | HitPolicy::First => {
+                Ok(results.into_iter().next().unwrap_or(FeelValue::Null))
+            }
+            HitPolicy::Priority => Ok(self
+                .by_priority(results)
+                .into_iter()
+                .next()
+                .unwrap_or(FeelValue::Null)),
+            HitPolicy::OutputOrder => Ok(FeelValue::List(self.by_priority(results))),
+            HitPolicy::RuleOrder => Ok(FeelValue::List(results)),
+            HitPolicy::Collect => Ok(match self.aggregation {
+                None => FeelValue::List(results),
+                Some(Aggregation::Count) => FeelValue::from(results.len() as i64),
+                Some(aggregation) => aggregate(aggregation, &results),
+            }),
+        }
+    }
+
+    /// Evaluates output entries into the table's output value.
+    fn output(&self, entries: &[&str], context: &FeelContext) -> InteropResult<FeelValue> {
+        let mut values = Vec::with_capacity(entries.len());
+        for entry in entries {
+            values.push(if entry.trim().is_empty() {
+                FeelValue::Null
+            } else {
+                FeelExpression::parse(entry)?.evaluate(context)
+            });
+        }
+        if values.len() == 1 {
+            return Ok(values.remove(0));
+        }
+        Ok(FeelValue::Context(
+            self.outputs
+                .iter()
+                .enumerate()
+                .zip(values)
+                .map(|((i, clause), value)| (output_name(clause, i), value))
+                .collect(),
+        ))
+    }
+
+    /// Sorts outputs by the order of the output values lists.
+    fn by_priority(&self, mut results: Vec<FeelValue>) -> Vec<FeelValue> {
+        let priorities: Vec<Vec<FeelValue>> = self
+            .outputs
+            .iter()
+            .map(|clause| {
+                clause
+                    .output_values
+                    .as_ref()
+                    .and_then(|values| UnaryTests::parse(&values.text).ok())
+                    .map(|tests| {
+                        tests
+                            .tests()
+                            .iter()
+                            .filter_map(|test| match test {
+                                UnaryTest::Expr(expr) => Some(
+                                    FeelExpression::from_expr(expr.clone())
+                                        .evaluate(&FeelContext::new()),
+                                ),
+                                _ => None,
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            })
+            .collect();
+        let rank = |result: &FeelValue| -> Vec<usize> {
+            priorities
+                .iter()
+                .enumerate()
+                .map(|(i, values)| {
+                    let value = match result {
+                        FeelValue::Context(entries) if self.outputs.len() > 1 => entries
+                            .get(&output_name(&self.outputs[i], i))
+                            .cloned()
+                            .unwrap_or(FeelValue::Null),
+                        other => other.clone(),
+                    };
+                    values
+                        .iter()
+                        .position(|v| v.feel_eq(&value) == Some(true))
+                        .unwrap_or(usize::MAX)
+                })
+                .collect()
+        };
+        results.sort_by_cached_key(rank);
+        results
+    }
+}
+
+fn output_name(clause: &OutputClause, index: usize) -> String {
+    clause
+        .name
+        .clone()
+        .or_else(|| clause.label.clone())
+        .unwrap_or_else(|| format!("output{}", index + 1))
+}
+
+fn aggregate(aggregation: Aggregation, results: &[FeelValue]) -> FeelValue {
+    let Some(numbers) = results
+        .iter()
+        .map(FeelValue::as_number)
+        .collect::<Option<Vec<Decimal>>>()
+    else {
+        return FeelValue::Null;
+    };
+    if numbers.is_empty() {
+        return FeelValue::Null;
+    }
+    let result = match aggregation {
+        Aggregation::Sum => numbers
+            .iter()
+            .try_fold(Decimal::ZERO, |total, n| total.checked_add(n)),
+        Aggregation::Min => numbers.iter().min().copied(),
+        Aggregation::Max => numbers.iter().max().copied(),
+        Aggregation::Count => Some(Decimal::from(numbers.len() as i64)),
+    };
+    result.map_or(FeelValue::Null, FeelValue::Number)
+}
+
+/// The logic of a decision or business knowledge model.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecisionLogic {
+    /// A decision table
+    DecisionTable(DecisionTable),
+    /// A single FEEL expression
+    LiteralExpression(LiteralExpression),
+}
+
+impl DecisionLogic {
+    fn from_parent(element: &XmlElement) -> InteropResult<Option<Self>> {
+        if let Some(table) = element.child("decisionTable") {
+            return Ok(Some(Self::DecisionTable(DecisionTable::from_element(
+                table,
+            )?)));
+        }
+        Ok(element
+            .child("literalExpression")
+            .map(|e| Self::LiteralExpression(LiteralExpression::from_element(e))))
+    }
+
+    fn to_element(&self) -> XmlElement {
+        match self {
+            Self::DecisionTable(table) => table.to_element(),
+            Self::LiteralExpression(expression) => expression.to_element("literalExpression"),
+        }
+    }
+}
+
+/// Elements of DRG nodes that are modelled explicitly.
+const KNOWN_DRG_CHILDREN: &[&str] = &[
+    "description",
+    "variable",
+    "informationRequirement",
+    "knowledgeRequirement",
+    "authorityRequirement",
+    "decisionTable",
+    "literalExpression",
+    "encapsulatedLogic",
+];
+
+/// Returns the children that are not modelled explicitly.
+fn extension_children(element: &XmlElement) -> Vec<XmlElement> {
+    element
+        .children
+        .iter()
+        .filter(|c| !KNOWN_DRG_CHILDREN.contains(&c.local_name()))
+        .cloned()
+        .collect()
+}
+
+/// Creates a DRG element with the parts shared by all node kinds.
+fn drg_element(
+    name: &str,
+    id: &str,
+    label: &str,
+    attributes: &[(String, String)],
+    description: Option<&str>,
+    extensions: &[XmlElement],
+) -> XmlElement {
+    let mut element = XmlElement::new(name)
+        .with_attr("id", Some(id))
+        .with_attr("name", Some(label));
+    element.attributes.extend(attributes.iter().cloned());
+    if let Some(description) = description {
+        element = element.with_child(XmlElement::new("description").with_text(description));
+    }
+    element.children.extend(extensions.iter().cloned());
+    element
+}
+
+/// A decision.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Decision {
+    /// Element ID
+    pub id: String,
+    /// Decision name
+    pub name: String,
+    /// Description
+    pub description: Option<String>,
+    /// Output variable
+    pub variable: Option<InformationItem>,
+    /// Requirements on inputs, other decisions and knowledge
+    pub requirements: Vec<Requirement>,
+    /// Decision logic
+    pub logic: Option<DecisionLogic>,
+    /// Other attributes, such as vendor extensions
+    pub attributes: Vec<(String, String)>,
+    /// Other child elements such as `extensionElements` or `question`, kept verbatim
+    pub extensions: Vec<XmlElement>,
+}
+
+impl Decision {
+    /// Returns the name under which the decision's result is visible to
+    /// dependent decisions.
+    pub fn variable_name(&self) -> &str {
+        self.variable
+            .as_ref()
+            .map(|v| v.name.as_str())
+            .filter(|name| !name.is_empty())
+            .unwrap_or(&self.name)
+    }
+
+    /// Returns the decision table, if the decision has one.
+    pub fn decision_table(&self) -> Option<&DecisionTable> {
+        match &self.logic {
+            Some(DecisionLogic::DecisionTable(table)) => Some(table),
+            _ => None,
+        }
+    }
+
+    /// Returns the IDs of the decisions this decision requires.
+    pub fn required_decisions(&self) -> impl Iterator<Item = &str> {
+        self.requirements
+            .iter()
+            .filter(|r| r.required == RequiredElement::Decision)
+            .map(|r| r.href.as_str())
+    }
+
+    fn from_element(element: &XmlElement) -> InteropResult<Self> {
+        Ok(Self {
+            id: element.attr("id").unwrap_or_default().to_string(),
+            name: element.attr("name").unwrap_or_default().to_string(),
+            description: element.child("description").map(|d| d.text.clone()),
+            variable: element.child("variable").map(InformationItem::from_element),
+            requirements: Requirement::parse_all(element),
+            logic: DecisionLogic::from_parent(element)?,
+            attributes: element.other_attributes(&["id", "name"]),
+            extensions: extension_children(element),
+        })
+    }
+
+    fn to_element(&self) -> XmlElement {
+        let mut element = drg_element(
+            "decision",
+            &self.id,
+            &self.name,
+            &self.attributes,
+            self.description.as_deref(),
+            &self.extensions,
+        );
+        if let Some(variable) = &self.variable {
+            element = element.with_child(variable.to_element("variable"));
+        }
+        element
+            .children
+            .extend(self.requirements.iter().map(Requirement::to_element));
+        if let Some(logic) = &self.logic {
+            element = element.with_child(logic.to_element());
+        }
+        element
+    }
+}
+
+/// An input of the decision model.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InputData {
+    /// Element ID
+    pub id: String,
+    /// Input name
+    pub name: String,
+    /// Description
+    pub description: Option<String>,
+    /// Input variable
+    pub variable: Option<InformationItem>,
+    /// Other attributes
+    pub attributes: Vec<(String, String)>,
+    /// Other child elements, kept verbatim
+    pub extensions: Vec<XmlElement>,
+}
+
+impl InputData {
+    fn from_element(element: &XmlElement) -> Self {
+        Self {
+            id: element.attr("id").unwrap_or_default().to_string(),
+            name: element.attr("name").unwrap_or_default().to_string(),
+            description: element.child("description").map(|d| d.text.clone()),
+            variable: element.child("variable").map(InformationItem::from_element),
+            attributes: element.other_attributes(&["id", "name"]),
+            extensions: extension_children(element),
+        }
+    }
+
+    fn to_element(&self) -> XmlElement {
+        let mut element = drg_element(
+            "inputData",
+            &self.id,
+            &self.name,
+            &self.attributes,
+            self.description.as_deref(),
+            &self.extensions,
+        );
+        if let Some(variable) = &self.variable {
+            element = element.with_child(variable.to_element("variable"));
+        }
+        element
+    }
+}
+
+/// A reusable piece of decision logic.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BusinessKnowledgeModel {
+    /// Element ID
+    pub id: String,
+    /// Model name
+    pub name: String,
+    /// Description
+    pub description: Option<String>,
+    /// Function variable
+    pub variable: Option<InformationItem>,
+    /// ID of the `encapsulatedLogic` element
+    pub logic_id: Option<String>,
+    /// Formal parameters
+    pub parameters: Vec<InformationItem>,
+    /// Function body
+    pub logic: Option<DecisionLogic>,
+    /// Requirements on other knowledge
+    pub requirements: Vec<Requirement>,
+    /// Other attributes
+    pub attributes: Vec<(String, String)>,
+    /// Other child elements, kept verbatim
+    pub extensions: Vec<XmlElement>,
+}
+
+impl BusinessKnowledgeModel {
+    fn from_element(element: &XmlElement) -> InteropResult<Self> {
+        let logic = element.child("encapsulatedLogic");
+        Ok(Self {
+            id: element.attr("id").unwrap_or_default().to_string(),
+            name: element.attr("name").unwrap_or_default().to_string(),
+            description: element.child("description").map(|d| d.text.clone()),
+            variable: element.child("variable").map(InformationItem::from_element),
+            logic_id: logic.and_then(|l| l.attr("id")).map(str::to_string),
+            parameters: logic
+                .map(|l| {
+                    l.children_named("formalParameter")
+                        .map(InformationItem::from_element)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            logic: match logic {
+                Some(logic) => DecisionLogic::from_parent(logic)?,
+                None => None,
+            },
+            requirements: Requirement::parse_all(element),
+            attributes: element.other_attributes(&["id", "name"]),
+            extensions: extension_children(element),
+        })
+    }
+
+    fn to_element(&self) -> XmlElement {
+        let mut element = drg_element(
+            "businessKnowledgeModel",
+            &self.id,
+            &self.name,
+            &self.attributes,
+            self.description.as_deref(),
+            &self.extensions,
+        );
+        if let Some(variable) = &self.variable {
+            element = element.with_child(variable.to_element("variable"));
+        }
+        if self.logic.is_some() || !self.parameters.is_empty() || self.logic_id.is_some() {
+            let mut logic =
+                XmlElement::new("encapsulatedLogic").with_attr("id", self.logic_id.as_deref());
+            for parameter in &self.parameters {
+                logic = logic.with_child(parameter.to_element("formalParameter"));
+            }
+            if let Some(body) = &self.logic {
+                logic = logic.with_child(body.to_element());
+            }
+            element = element.with_child(logic);
+        }
+        element
+            .children
+            .extend(self.requirements.iter().map(Requirement::to_element));
+        element
+    }
+}
 
+/// An authority such as a regulation or policy that governs decisions.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KnowledgeSource {
+    /// Element ID
+    pub id: String,
+    /// Source name
+    pub name: String,
+    /// Description
+    pub description: Option<String>,
+    /// Requirements on other authorities or inputs
+    pub requirements: Vec<Requirement>,
+    /// Other attributes, such as `locationURI`
+    pub attributes: Vec<(String, String)>,
+    /// Other child elements, kept verbatim
+    pub extensions: Vec<XmlElement>,
+}
+
+impl KnowledgeSource {
+    fn from_element(element: &XmlElement) -> Self {
+        Self {
+            id: element.attr("id").unwrap_or_default().to_string(),
+            name: element.attr("name").unwrap_or_default().to_string(),
+            description: element.child("description").map(|d| d.text.clone()),
+            requirements: Requirement::parse_all(element),
+            attributes: element.other_attributes(&["id", "name"]),
+            extensions: extension_children(element),
+        }
+    }
+
+    fn to_element(&self) -> XmlElement {
+        let mut element = drg_element(
+            "knowledgeSource",
+            &self.id,
+            &self.name,
+            &self.attributes,
+            self.description.as_deref(),
+            &self.extensions,
+        );
+        element
+            .children
+            .extend(self.requirements.iter().map(Requirement::to_element));
+        element
+    }
+}
+
+/// A sample on which the decision table and its statutes disagree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleMismatch {
+    /// Decision whose table was checked
+    pub decision_id: String,
+    /// Index of the sample in the input slice
+    pub sample: usize,
+    /// Rules matched by FEEL evaluation
+    pub table_rules: Vec<usize>,
+    /// Rules whose statutes' preconditions hold
+    pub statute_rules: Vec<usize>,
+}
+
+/// A DMN document.
+///
+/// # Example
+///
+/// ```
+/// use legalis_interop::dmn::DmnDefinitions;
+/// use legalis_interop::feel::{FeelContext, FeelValue};
+///
+/// let xml = r#"<definitions xmlns="https://www.omg.org/spec/DMN/20211108/MODEL/"
+///     id="benefits" name="Benefits" namespace="urn:benefits">
+///   <decision id="eligibility" name="Eligibility">
+///     <decisionTable hitPolicy="FIRST">
+///       <input label="Age"><inputExpression typeRef="number"><text>age</text></inputExpression></input>
+///       <output name="eligible" typeRef="boolean"/>
+///       <rule><inputEntry><text>&gt;= 18</text></inputEntry><outputEntry><text>true</text></outputEntry></rule>
+///       <rule><inputEntry><text>-</text></inputEntry><outputEntry><text>false</text></outputEntry></rule>
+///     </decisionTable>
+///   </decision>
+/// </definitions>"#;
+///
+/// let definitions = DmnDefinitions::parse(xml).unwrap();
+/// let adult = FeelContext::new().with_variable("age", 30i64);
+/// assert_eq!(
+///     definitions.evaluate("eligibility", &adult).unwrap(),
+///     FeelValue::Bool(true)
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DmnDefinitions {
+    /// Element ID
+    pub id: String,
+    /// Model name
+    pub name: String,
+    /// Model namespace (the `namespace` attribute)
+    pub namespace: String,
+    /// Other attributes, including XML namespace declarations
+    pub attributes: Vec<(String, String)>,
+    /// Elements before the DRG elements, such as imports and item
+    /// definitions, kept verbatim
+    pub header: Vec<XmlElement>,
+    /// Decisions
+    pub decisions: Vec<Decision>,
+    /// Input data
+    pub input_data: Vec<InputData>,
+    /// Business knowledge models
+    pub business_knowledge_models: Vec<BusinessKnowledgeModel>,
+    /// Knowledge sources
+    pub knowledge_sources: Vec<KnowledgeSource>,
+    /// Elements after the DRG elements, such as text annotations and
+    /// DMNDI diagrams, kept verbatim
+    pub extensions: Vec<XmlElement>,
+}
+
+impl DmnDefinitions {
+    /// Parses a DMN document.
+    pub fn parse(source: &str) -> InteropResult<Self> {
+        let root = XmlElement::parse(source)?;
+        if root.local_name() != "definitions" {
+            return Err(InteropError::ParseError(format!(
+                "Not a valid DMN document: root element is <{}>",
+                root.name
+            )));
+        }
+
+        let mut definitions = Self {
+            id: root.attr("id").unwrap_or_default().to_string(),
+            name: root.attr("name").unwrap_or_default().to_string(),
+            namespace: root.attr("namespace").unwrap_or_default().to_string(),
+            attributes: root.other_attributes(&["id", "name", "namespace"]),
+            ..Default::default()
+        };
+        let mut seen_drg_element = false;
+        for child in &root.children {
+            match child.local_name() {
+                "decision" => definitions.decisions.push(Decision::from_element(child)?),
+                "inputData" => definitions.input_data.push(InputData::from_element(child)),
+                "businessKnowledgeModel" => definitions
+                    .business_knowledge_models
+                    .push(BusinessKnowledgeModel::from_element(child)?),
+                "knowledgeSource" => definitions
+                    .knowledge_sources
+                    .push(KnowledgeSource::from_element(child)),
+                _ if seen_drg_element => definitions.extensions.push(child.clone()),
+                _ => definitions.header.push(child.clone()),
+            }
+            seen_drg_element |= matches!(
+                child.local_name(),
+                "decision" | "inputData" | "businessKnowledgeModel" | "knowledgeSource"
+            );
+        }
+        Ok(definitions)
+    }
+
+    /// Writes the document as DMN XML.
+    pub fn to_xml(&self) -> InteropResult<String> {
+        let mut root = XmlElement::new("definitions");
+        if !self.attributes.iter().any(|(key, _)| key == "xmlns") {
+            root = root.with_attr("xmlns", Some(DMN14_NAMESPACE));
+        }
+        root = root
+            .with_attr("id", Some(&self.id))
+            .with_attr("name", Some(&self.name))
+            .with_attr("namespace", Some(&self.namespace));
+        root.attributes.extend(self.attributes.iter().cloned());
+
+        root.children.extend(self.header.iter().cloned());
+        root.children
+            .extend(self.decisions.iter().map(Decision::to_element));
+        root.children
+            .extend(self.input_data.iter().map(InputData::to_element));
+        root.children.extend(
+            self.business_knowledge_models
+                .iter()
+                .map(BusinessKnowledgeModel::to_element),
+        );
+        root.children.extend(
+            self.knowledge_sources
+                .iter()
+                .map(KnowledgeSource::to_element),
+        );
+        root.children.extend(self.extensions.iter().cloned());
+        root.to_xml()
+    }
+
+    /// Finds a decision by ID or name.
+    pub fn decision(&self, id_or_name: &str) -> Option<&Decision> {
+        self.decisions
+            .iter()
+            .find(|d| d.id == id_or_name)
+            .or_else(|| self.decisions.iter().find(|d| d.name == id_or_name))
+    }
+
+    /// Evaluates a decision.
+    ///
+    /// Required decisions are evaluated first and their results added to the
+    /// context under their variable names. Input data must be supplied in
+    /// `context` under the input data names.
+    pub fn evaluate(&self, decision: &str, context: &FeelContext) -> InteropResult<FeelValue> {
+        self.evaluate_decision(decision, context, &mut Vec::new())
+    }
+
+    fn evaluate_decision(
+        &self,
+        id_or_name: &str,
+        context: &FeelContext,
+        stack: &mut Vec<String>,
+    ) -> InteropResult<FeelValue> {
+        let decision = self.decision(id_or_name).ok_or_else(|| {
+            InteropError::ValidationError(format!("Unknown DMN decision: {}", id_or_name))
+        })?;
+        if stack.contains(&decision.id) {
+            return Err(InteropError::ValidationError(format!(
+                "Circular decision requirements: {} -> {}",
+                stack.join(" -> "),
+                decision.id
+            )));
+        }
+
+        stack.push(decision.id.clone());
+        let scope = self.decision_context(decision, context, stack)?;
+        let result = match &decision.logic {
+            Some(DecisionLogic::DecisionTable(table)) => table.evaluate(&scope),
+            Some(DecisionLogic::LiteralExpression(expression)) => {
+                Ok(FeelExpression::parse(&expression.text)?.evaluate(&scope))
+            }
+            None => Err(InteropError::ValidationError(format!(
+                "DMN decision {} has no decision logic",
+                decision.id
+            ))),
+        };
+        stack.pop();
+        result
+    }
+
+    /// Adds the results of required decisions to a context.
+    fn decision_context(
+        &self,
+        decision: &Decision,
+        context: &FeelContext,
+        stack: &mut Vec<String>,
+    ) -> InteropResult<FeelContext> {
+        let mut scope = context.clone();
+        for required in decision.required_decisions() {
+            let value = self.evaluate_decision(required, context, stack)?;
+            let name = self
+                .decision(required)
+                .map(|d| d.variable_name().to_string())
+                .unwrap_or_else(|| required.to_string());
+            scope.set(name, value);
+        }
+        Ok(scope)
+    }
+
+    /// Executes every decision table on the samples and compares the matched
+    /// rules with the rules whose imported statutes apply.
+    ///
+    /// Rules whose statutes contain custom conditions cannot be evaluated
+    /// outside FEEL and are left out of the comparison.
+    pub fn cross_check(
+        &self,
+        statutes: &[Statute],
+        samples: &[FeelContext],
+    ) -> InteropResult<Vec<RuleMismatch>> {
+        let mut mismatches = Vec::new();
+        for decision in &self.decisions {
+            let Some(table) = decision.decision_table() else {
+                continue;
+            };
+            let rule_statutes: Vec<(usize, &Statute)> = statutes
+                .iter()
+                .filter(|s| s.effect.parameters.get(PARAM_DECISION) == Some(&decision.id))
+                .filter_map(|s| {
+                    let index = s.effect.parameters.get(PARAM_RULE_INDEX)?.parse().ok()?;
+                    Some((index, s))
+                })
+                .collect();
+            let opaque: Vec<usize> = rule_statutes
+                .iter()
+                .filter(|(_, s)| s.preconditions.iter().any(has_custom_condition))
+                .map(|(i, _)| *i)
+                .collect();
+
+            for (sample, context) in samples.iter().enumerate() {
+                let scope = self.decision_context(decision, context, &mut Vec::new())?;
+                let mut table_rules = table.matching_rules(&scope)?;
+                table_rules.retain(|i| !opaque.contains(i));
+
+                let attributes = AttributeBasedContext::new(statute_attributes(table, &scope)?);
+                let mut statute_rules: Vec<usize> = rule_statutes
+                    .iter()
+                    .filter(|(i, _)| !opaque.contains(i))
+                    .filter(|(_, s)| {
+                        s.preconditions
+                            .iter()
+                            .all(|c| c.evaluate_simple(&attributes).unwrap_or(false))
+                    })
+                    .map(|(i, _)| *i)
+                    .collect();
+                statute_rules.sort_unstable();
+
+                if table_rules != statute_rules {
+                    mismatches.push(RuleMismatch {
+                        decision_id: decision.id.clone(),
+                        sample,
+                        table_rules,
+                        statute_rules,
+                    });
+                }
+            }
+        }
+        Ok(mismatches)
+    }
+}
+
+fn has_custom_condition(condition: &Condition) -> bool {
+    match condition {
+        Condition::Custom { .. } => true,
+        Condition::And(a, b) | Condition::Or(a, b) => {
+            has_custom_condition(a) || has_custom_condition(b)
+        }
+        Condition::Not(inner) => has_custom_condition(inner),
+        _ => false,
+    }
+}
+
+/// Builds the statute attributes for a sample: every input expression's
+/// value keyed by the expression text.
+fn statute_attributes(
+    table: &DecisionTable,
+    context: &FeelContext,
+) -> InteropResult<HashMap<String, String>> {
+    let mut attributes = HashMap::new();
+    for (input, value) in table.inputs.iter().zip(table.input_values(context)?) {
+        let text = match value {
+            FeelValue::Null | FeelValue::List(_) | FeelValue::Context(_) => continue,
+            FeelValue::Range { .. } => continue,
+            other => other.to_plain_string(),
+        };
+        let key = input.expression.text.trim().to_string();
+        let lower = key.to_lowercase();
+        if lower == "age" || lower == "income" {
+            attributes.insert(lower, text.clone());
+        }
+        attributes.insert(key, text);
+    }
+    Ok(attributes)
+}
+
+// ==================================================
+// Statute mapping
+// ==================================================
+
+const PARAM_DECISION: &str = "dmn_decision";
+const PARAM_DECISION_NAME: &str = "dmn_decision_name";
+const PARAM_HIT_POLICY: &str = "dmn_hit_policy";
+const PARAM_AGGREGATION: &str = "dmn_aggregation";
+const PARAM_RULE: &str = "dmn_rule";
+const PARAM_RULE_INDEX: &str = "dmn_rule_index";
+const PARAM_EXPRESSION: &str = "dmn_expression";
+const PARAM_REQUIRES: &str = "dmn_requires";
+
+fn input_param(index: usize, suffix: &str) -> String {
+    format!("dmn_input.{}{}", index, suffix)
+}
+
+fn output_param(index: usize, suffix: &str) -> String {
+    format!("dmn_output.{}{}", index, suffix)
+}
+
+fn comparison_op(op: BinaryOp) -> Option<ComparisonOp> {
+    match op {
+        BinaryOp::Eq => Some(ComparisonOp::Equal),
+        BinaryOp::Ne => Some(ComparisonOp::NotEqual),
+        BinaryOp::Lt => Some(ComparisonOp::LessThan),
+        BinaryOp::Le => Some(ComparisonOp::LessOrEqual),
+        BinaryOp::Gt => Some(ComparisonOp::GreaterThan),
+        BinaryOp::Ge => Some(ComparisonOp::GreaterOrEqual),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the name can be used in a core formula.
+fn is_formula_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+}
+
+/// Builds a numeric comparison on an input.
+fn numeric_condition(attribute: &str, op: ComparisonOp, value: Decimal) -> Option<Condition> {
+    let age = value.to_i64().and_then(|n| u32::try_from(n).ok());
+    let income = value.to_i64().and_then(|n| u64::try_from(n).ok());
+    match (attribute.to_lowercase().as_str(), age, income) {
+        ("age", Some(age), _) => Some(Condition::Age {
+            operator: op,
+            value: age,
+        }),
+        ("income", _, Some(income)) => Some(Condition::Income {
+            operator: op,
+            value: income,
+        }),
+        _ if is_formula_identifier(attribute) => Some(Condition::Calculation {
+            formula: attribute.to_string(),
+            operator: op,
+            value: value.to_f64(),
+        }),
+        _ => None,
+    }
+}
+
+fn or_all(conditions: Vec<Condition>) -> Option<Condition> {
+    conditions
+        .into_iter()
+        .reduce(|a, b| Condition::Or(Box::new(a), Box::new(b)))
+}
+
+/// Translates a literal compared with the input into a condition.
+fn literal_condition(attribute: &str, value: &Expr) -> Option<Condition> {
+    match value {
+        Expr::Literal(FeelValue::Number(n)) => {
+            numeric_condition(attribute, ComparisonOp::Equal, *n)
+        }
+        Expr::Literal(FeelValue::String(s)) => Some(Condition::AttributeEquals {
+            key: attribute.to_string(),
+            value: s.clone(),
+        }),
+        Expr::Literal(FeelValue::Bool(b)) => Some(Condition::AttributeEquals {
+            key: attribute.to_string(),
+            value: b.to_string(),
+        }),
+        _ => None,
+    }
+}
+
+/// Translates unary tests on an input into a condition.
+///
+/// Returns `None` when the tests have no typed equivalent.
+fn tests_condition(attribute: &str, tests: &[UnaryTest]) -> Option<Condition> {
+    let conditions =
+        tests
+            .iter()
+            .map(|test| match test {
+                UnaryTest::Any => None,
+                UnaryTest::Not(inner) => {
+                    tests_condition(attribute, inner).map(|c| Condition::Not(Box::new(c)))
+                }
+                UnaryTest::Compare(op, value) => match (comparison_op(*op)?, value) {
+                    (op, Expr::Literal(FeelValue::Number(n))) => {
+                        numeric_condition(attribute, op, *n)
+                    }
+                    (ComparisonOp::Equal, value) => literal_condition(attribute, value),
+                    (ComparisonOp::NotEqual, value) => {
+                        literal_condition(attribute, value).map(|c| Condition::Not(Box::new(c)))
+                    }
+                    _ => None,
+                },
+                UnaryTest::Expr(Expr::Range {
+                    low,
+                    high,
+                    low_closed,
+                    high_closed,
+                }) => {
+                    let (
+                        Expr::Literal(FeelValue::Number(low)),
+                        Expr::Literal(FeelValue::Number(high)),
+                    ) = (low.as_ref(), high.as_ref())
+                    else {
+                        return None;
+                    };
+                    let low_op = if *low_closed {
+                        ComparisonOp::GreaterOrEqual
+                    } else {
+                        ComparisonOp::GreaterThan
+                    };
+                    let high_op = if *high_closed {
+                        ComparisonOp::LessOrEqual
+                    } else {
+                        ComparisonOp::LessThan
+                    };
+                    Some(Condition::And(
+                        Box::new(numeric_condition(attribute, low_op, *low)?),
+                        Box::new(numeric_condition(attribute, high_op, *high)?),
+                    ))
+                }
+                UnaryTest::Expr(Expr::List(items)) => or_all(
+                    items
+                        .iter()
+                        .map(|item| literal_condition(attribute, item))
+                        .collect::<Option<Vec<_>>>()?,
+                ),
+                UnaryTest::Expr(value) => literal_condition(attribute, value),
+            })
+            .collect::<Option<Vec<_>>>()?;
+    or_all(conditions)
+}
+
+/// Translates an input entry into a statute precondition.
+///
+/// Returns `Ok(None)` for entries that match anything, and a custom
+/// condition (with `false`) when the entry has no typed equivalent.
+fn entry_condition(input: &str, entry: &str) -> InteropResult<Option<(Condition, bool)>> {
+    let tests = UnaryTests::parse(entry)?;
+    if tests.tests().iter().all(|t| matches!(t, UnaryTest::Any)) {
+        return Ok(None);
+    }
+    Ok(Some(match tests_condition(input.trim(), tests.tests()) {
+        Some(condition) => (condition, true),
+        None => (
+            Condition::Custom {
+                description: format!("{} satisfies {}", input.trim(), entry.trim()),
+            },
+            false,
+        ),
+    }))
+}
+
+/// Sets the parameters describing a decision on an effect.
+fn decision_effect(decision: &Decision, description: String) -> Effect {
+    let mut effect = Effect::new(EffectType::Grant, description)
+        .with_parameter(PARAM_DECISION, &decision.id)
+        .with_parameter(PARAM_DECISION_NAME, &decision.name);
+    let requires: Vec<&str> = decision.required_decisions().collect();
+    if !requires.is_empty() {
+        effect = effect.with_parameter(PARAM_REQUIRES, requires.join(","));
+    }
+    effect
+}
+
+/// Converts a decision table rule into a statute.
+fn rule_statute(
+    decision: &Decision,
+    table: &DecisionTable,
+    index: usize,
+    untranslated: &mut usize,
+) -> InteropResult<Statute> {
+    let rule = &table.rules[index];
+    let outputs: Vec<String> = table
+        .outputs
+        .iter()
+        .enumerate()
+        .zip(&rule.output_entries)
+        .map(|((i, clause), entry)| format!("{} = {}", output_name(clause, i), entry.text))
+        .collect();
+
+    let mut effect = decision_effect(decision, outputs.join(", "))
+        .with_parameter(PARAM_HIT_POLICY, table.hit_policy.as_str())
+        .with_parameter(PARAM_RULE_INDEX, index.to_string());
+    if let Some(aggregation) = table.aggregation {
+        effect = effect.with_parameter(PARAM_AGGREGATION, aggregation.as_str());
+    }
+    if let Some(id) = &rule.id {
+        effect = effect.with_parameter(PARAM_RULE, id);
+    }
+    for (i, input) in table.inputs.iter().enumerate() {
+        effect = effect.with_parameter(input_param(i, ""), &input.expression.text);
+        if let Some(type_ref) = &input.expression.type_ref {
+            effect = effect.with_parameter(input_param(i, ".type"), type_ref);
+        }
+        if let Some(entry) = rule.input_entries.get(i) {
+            effect = effect.with_parameter(input_param(i, ".entry"), &entry.text);
+        }
+    }
+    for (i, output) in table.outputs.iter().enumerate() {
+        effect = effect.with_parameter(output_param(i, ""), output_name(output, i));
+        if let Some(type_ref) = &output.type_ref {
+            effect = effect.with_parameter(output_param(i, ".type"), type_ref);
+        }
+        if let Some(entry) = rule.output_entries.get(i) {
+            effect = effect.with_parameter(output_param(i, ".entry"), &entry.text);
+        }
+    }
+
+    let id = match &rule.id {
+        Some(rule_id) => format!("{}_{}", decision.id, rule_id),
+        None => format!("{}_rule_{}", decision.id, index + 1),
+    };
+    let title = rule
+        .description
+        .clone()
+        .unwrap_or_else(|| format!("{} rule {}", decision.name, index + 1));
+    let mut statute = Statute::new(id, title, effect);
+    for (input, entry) in table.inputs.iter().zip(&rule.input_entries) {
+        if let Some((condition, typed)) = entry_condition(&input.expression.text, &entry.text)? {
+            if !typed {
+                *untranslated += 1;
+            }
+            statute = statute.with_precondition(condition);
+        }
+    }
+    Ok(statute)
+}
+
+/// Converts a precondition into an input expression and unary test.
+fn condition_entry(condition: &Condition) -> Option<(String, String)> {
+    let test = |op: &ComparisonOp, value: String| match op {
+        ComparisonOp::Equal => value,
+        ComparisonOp::NotEqual => format!("not({})", value),
+        ComparisonOp::GreaterThan => format!("> {}", value),
+        ComparisonOp::GreaterOrEqual => format!(">= {}", value),
+        ComparisonOp::LessThan => format!("< {}", value),
+        ComparisonOp::LessOrEqual => format!("<= {}", value),
+    };
+    let quoted = |values: &[String]| {
+        values
+            .iter()
+            .map(|v| FeelValue::String(v.clone()).to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    match condition {
+        Condition::Age { operator, value } => {
+            Some(("age".to_string(), test(operator, value.to_string())))
+        }
+        Condition::Income { operator, value } => {
+            Some(("income".to_string(), test(operator, value.to_string())))
+        }
+        Condition::Calculation {
+            formula,
+            operator,
+            value,
+        } if is_formula_identifier(formula) => Some((
+            formula.clone(),
+            test(
+                operator,
+                crate::feel::format_number(&Decimal::from_f64(*value)?),
+            ),
+        )),
+        Condition::AttributeEquals { key, value } => {
+            Some((key.clone(), quoted(std::slice::from_ref(value))))
+        }
+        Condition::HasAttribute { key } => Some((key.clone(), "not(null)".to_string())),
+        Condition::SetMembership {
+            attribute,
+            values,
+            negated,
+        } => {
+            let list = quoted(values);
+            Some((
+                attribute.clone(),
+                if *negated {
+                    format!("not({})", list)
+                } else {
+                    list
+                },
+            ))
+        }
+        Condition::Not(inner) => {
+            let (input, entry) = condition_entry(inner)?;
+            if entry.starts_with("not(") {
+                return None;
+            }
+            Some((input, format!("not({})", entry)))
+        }
+        _ => None,
+    }
+}
+
+/// Builds a decision from statutes produced by [`DmnImporter`].
+fn decision_from_rules(id: &str, statutes: &[&Statute]) -> Decision {
+    let params = &statutes[0].effect.parameters;
+    let param = |key: &str| params.get(key).cloned();
+    let name = param(PARAM_DECISION_NAME).unwrap_or_else(|| id.to_string());
+
+    let mut decision = Decision {
+        id: id.to_string(),
+        name: name.clone(),
+        requirements: param(PARAM_REQUIRES)
+            .map(|ids| {
+                ids.split(',')
+                    .map(|href| Requirement {
+                        id: None,
+                        kind: RequirementKind::Information,
+                        required: RequiredElement::Decision,
+                        href: href.to_string(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+        ..Default::default()
+    };
+
+    if let Some(expression) = param(PARAM_EXPRESSION) {
+        decision.logic = Some(DecisionLogic::LiteralExpression(LiteralExpression::new(
+            expression,
+        )));
+        return decision;
+    }
+    if !params.contains_key(PARAM_RULE_INDEX) {
+        return decision;
+    }
+
+    let inputs = (0..)
+        .map_while(|i| {
+            Some(InputClause {
+                expression: LiteralExpression {
+                    type_ref: param(&input_param(i, ".type")),
+                    ..LiteralExpression::new(param(&input_param(i, ""))?)
+                },
+                ..Default::default()
+            })
+        })
+        .collect::<Vec<_>>();
+    let outputs = (0..)
+        .map_while(|i| {
+            Some(OutputClause {
+                name: Some(param(&output_param(i, ""))?),
+                type_ref: param(&output_param(i, ".type")),
+                ..Default::default()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut rules: Vec<(usize, DecisionRule)> = statutes
+        .iter()
+        .map(|statute| {
+            let params = &statute.effect.parameters;
+            let index = params
+                .get(PARAM_RULE_INDEX)
+                .and_then(|i| i.parse().ok())
+                .unwrap_or(usize::MAX);
+            let entry = |key: String| {
+                LiteralExpression::new(params.get(&key).cloned().unwrap_or_else(|| "-".into()))
+            };
+            let default_title = format!("{} rule {}", name, index.saturating_add(1));
+            let rule = DecisionRule {
+                id: params.get(PARAM_RULE).cloned(),
+                description: (statute.title != default_title).then(|| statute.title.clone()),
+                input_entries: (0..inputs.len())
+                    .map(|i| entry(input_param(i, ".entry")))
+                    .collect(),
+                output_entries: (0..outputs.len())
+                    .map(|i| entry(output_param(i, ".entry")))
+                    .collect(),
+                annotation_entries: Vec::new(),
+            };
+            (index, rule)
+        })
+        .collect();
+    rules.sort_by_key(|(index, _)| *index);
+
+    decision.logic = Some(DecisionLogic::DecisionTable(DecisionTable {
+        hit_policy: param(PARAM_HIT_POLICY)
+            .and_then(|p| HitPolicy::from_dmn(&p))
+            .unwrap_or_default(),
+        aggregation: param(PARAM_AGGREGATION).and_then(|a| Aggregation::from_dmn(&a)),
+        inputs,
+        outputs,
+        rules: rules.into_iter().map(|(_, rule)| rule).collect(),
+        ..Default::default()
+    }));
+    decision
+}
+
+/// Builds a single-rule decision table from a statute's preconditions.
+fn decision_from_statute(statute: &Statute, report: &mut ConversionReport) -> Decision {
+    let mut inputs = Vec::new();
+    let mut entries = Vec::new();
+    for condition in &statute.preconditions {
+        match condition_entry(condition) {
+            Some((expression, entry)) => {
+                inputs.push(InputClause {
+                    label: Some(expression.clone()),
+                    expression: LiteralExpression::new(expression),
+                    ..Default::default()
+                });
+                entries.push(LiteralExpression::new(entry));
+            }
+            None => report.add_unsupported(format!(
+                "Condition in statute {} has no DMN equivalent: {}",
+                statute.id, condition
+            )),
+        }
+    }
+
+    Decision {
+        id: statute.id.clone(),
+        name: statute.title.clone(),
+        logic: Some(DecisionLogic::DecisionTable(DecisionTable {
+            inputs,
+            outputs: vec![OutputClause {
+                name: Some("effect".to_string()),
+                type_ref: Some("string".to_string()),
+                ..Default::default()
+            }],
+            rules: vec![DecisionRule {
+                input_entries: entries,
+                output_entries: vec![LiteralExpression::new(
+                    FeelValue::String(format!(
+                        "{} {}",
+                        statute.effect.effect_type, statute.effect.description
+                    ))
+                    .to_string(),
+                )],
+                ..Default::default()
+            }],
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+}
+
+// ==================================================
+// Importer and exporter
+// ==================================================
+
+/// DMN importer.
+///
+/// Each decision table rule becomes a statute; decisions with a literal
+/// expression or without logic become a single statute.
 pub struct DmnImporter;
+
 impl DmnImporter {
+    /// Creates a new DMN importer.
     pub fn new() -> Self {
         Self
     }
 }
+
 impl Default for DmnImporter {
     fn default() -> Self {
         Self::new()
@@ -26,24 +2056,51 @@ impl FormatImporter for DmnImporter {
         let mut report = ConversionReport::new(LegalFormat::Dmn, LegalFormat::Legalis);
         let mut statutes = Vec::new();
 
-        if !source.trim_start().starts_with('<')
-            || (!source.contains("dmn") && !source.contains("decision"))
-        {
+        if !self.validate(source) {
             return Err(InteropError::ParseError(
                 "Not a valid DMN document".to_string(),
             ));
         }
+        let definitions = DmnDefinitions::parse(source)?;
 
-        let decision_pattern =
-            regex_lite::Regex::new(r#"<decision[^>]*id="([^"]*)"[^>]*name="([^"]*)"[^>]*>"#)
-                .map_err(|e| InteropError::ParseError(e.to_string()))?;
+        for decision in &definitions.decisions {
+            match &decision.logic {
+                Some(DecisionLogic::DecisionTable(table)) => {
+                    let mut untranslated = 0;
+                    for index in 0..table.rules.len() {
+                        statutes.push(rule_statute(decision, table, index, &mut untranslated)?);
+                    }
+                    if untranslated > 0 {
+                        report.add_warning(format!(
+                            "{} input entries of decision {} have no typed equivalent and were kept as custom conditions",
+                            untranslated, decision.id
+                        ));
+                    }
+                }
+                Some(DecisionLogic::LiteralExpression(expression)) => {
+                    let effect = decision_effect(
+                        decision,
+                        format!("{} = {}", decision.variable_name(), expression.text),
+                    )
+                    .with_parameter(PARAM_EXPRESSION, &expression.text);
+                    statutes.push(Statute::new(&decision.id, &decision.name, effect));
+                }
+                None => {
+                    let effect = decision_effect(decision, format!("decide_{}", decision.id));
+                    statutes.push(Statute::new(&decision.id, &decision.name, effect));
+                }
+            }
+        }
 
-        for cap in decision_pattern.captures_iter(source) {
-            let id = cap.get(1).map(|m| m.as_str()).unwrap_or("unknown");
-            let name = cap.get(2).map(|m| m.as_str()).unwrap_or("Decision");
-            let effect = Effect::new(EffectType::Grant, format!("decide_{}", id));
-            let statute = Statute::new(id, name, effect);
-            statutes.push(statute);
+        if !definitions.business_knowledge_models.is_empty() {
+            report.add_unsupported("DMN business knowledge models");
+        }
+        if definitions
+            .extensions
+            .iter()
+            .any(|e| e.local_name() == "DMNDI")
+        {
+            report.add_unsupported("DMN diagram interchange (DMNDI)");
         }
 
         if statutes.is_empty() {
@@ -66,12 +2123,59 @@ impl FormatImporter for DmnImporter {
     }
 }
 
+/// DMN exporter.
+///
+/// Statutes imported from DMN are regrouped into their original decisions;
+/// other statutes become single-rule decision tables over their
+/// preconditions.
 pub struct DmnExporter;
+
 impl DmnExporter {
+    /// Creates a new DMN exporter.
     pub fn new() -> Self {
         Self
     }
+
+    /// Builds a DMN model from statutes.
+    pub fn to_definitions(
+        &self,
+        statutes: &[Statute],
+        report: &mut ConversionReport,
+    ) -> DmnDefinitions {
+        let mut groups: BTreeMap<usize, (String, Vec<&Statute>)> = BTreeMap::new();
+        let mut group_of: HashMap<&str, usize> = HashMap::new();
+        let mut decisions: Vec<(usize, Decision)> = Vec::new();
+
+        for (position, statute) in statutes.iter().enumerate() {
+            match statute.effect.parameters.get(PARAM_DECISION) {
+                Some(id) => {
+                    let first = *group_of.entry(id.as_str()).or_insert(position);
+                    groups
+                        .entry(first)
+                        .or_insert_with(|| (id.clone(), Vec::new()))
+                        .1
+                        .push(statute);
+                }
+                None => decisions.push((position, decision_from_statute(statute, report))),
+            }
+        }
+        decisions.extend(
+            groups
+                .into_iter()
+                .map(|(position, (id, rules))| (position, decision_from_rules(&id, &rules))),
+        );
+        decisions.sort_by_key(|(position, _)| *position);
+
+        DmnDefinitions {
+            id: "legalis_definitions".to_string(),
+            name: "Legalis Statutes".to_string(),
+            namespace: "urn:legalis:dmn".to_string(),
+            decisions: decisions.into_iter().map(|(_, d)| d).collect(),
+            ..Default::default()
+        }
+    }
 }
+
 impl Default for DmnExporter {
     fn default() -> Self {
         Self::new()
@@ -85,31 +2189,21 @@ impl FormatExporter for DmnExporter {
 
     fn export(&self, statutes: &[Statute]) -> InteropResult<(String, ConversionReport)> {
         let mut report = ConversionReport::new(LegalFormat::Legalis, LegalFormat::Dmn);
-        let mut output = String::from(
-            r#"<?xml version="1.0" encoding="UTF-8"?>
-<definitions xmlns="http://www.omg.org/spec/DMN/20151101/dmn.xsd" id="Definitions_1">
-"#,
-        );
-
-        for statute in statutes {
-            output.push_str(&format!(
-                r#"  <decision id="{}" name="{}">
-    <decisionTable>
-      <output name="result"/>
-    </decisionTable>
-  </decision>
-"#,
-                statute.id, statute.title
-            ));
-        }
-
-        output.push_str("</definitions>\n");
+        let output = self.to_definitions(statutes, &mut report).to_xml()?;
         report.statutes_converted = statutes.len();
         Ok((output, report))
     }
 
-    fn can_represent(&self, _statute: &Statute) -> Vec<String> {
-        Vec::new()
+    fn can_represent(&self, statute: &Statute) -> Vec<String> {
+        if statute.effect.parameters.contains_key(PARAM_DECISION) {
+            return Vec::new();
+        }
+        statute
+            .preconditions
+            .iter()
+            .filter(|c| condition_entry(c).is_none())
+            .map(|c| format!("Condition without DMN equivalent: {}", c))
+            .collect()
     }
 }
 
@@ -117,6 +2211,95 @@ impl FormatExporter for DmnExporter {
 mod tests {
     use super::*;
 
+    const CAMUNDA_DMN: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<definitions xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/" xmlns:dmndi="https://www.omg.org/spec/DMN/20191111/DMNDI/" xmlns:dc="http://www.omg.org/spec/DMN/20180521/DC/" xmlns:camunda="http://camunda.org/schema/1.0/dmn" id="benefits" name="Benefits" namespace="http://camunda.org/schema/1.0/dmn" exporter="Camunda Modeler" exporterVersion="5.17.0">
+  <decision id="eligibility" name="Eligibility" camunda:historyTimeToLive="30">
+    <informationRequirement id="InformationRequirement_1">
+      <requiredInput href="#applicant_age" />
+    </informationRequirement>
+    <decisionTable id="DecisionTable_1" hitPolicy="FIRST">
+      <input id="Input_1" label="Age" camunda:inputVariable="age">
+        <inputExpression id="InputExpression_1" typeRef="number">
+          <text>age</text>
+        </inputExpression>
+      </input>
+      <input id="Input_2" label="Status">
+        <inputExpression id="InputExpression_2" typeRef="string">
+          <text>status</text>
+        </inputExpression>
+        <inputValues id="UnaryTests_0"><text>"resident","visitor"</text></inputValues>
+      </input>
+      <output id="Output_1" label="Eligible" name="eligible" typeRef="boolean" />
+      <annotation name="Notes" />
+      <rule id="Rule_adult">
+        <description>Adult residents</description>
+        <inputEntry id="UnaryTests_1"><text>&gt;= 18</text></inputEntry>
+        <inputEntry id="UnaryTests_2"><text>"resident"</text></inputEntry>
+        <outputEntry id="LiteralExpression_1"><text>true</text></outputEntry>
+        <annotationEntry><text>Section 2 &amp; 3</text></annotationEntry>
+      </rule>
+      <rule id="Rule_other">
+        <inputEntry id="UnaryTests_3"><text>-</text></inputEntry>
+        <inputEntry id="UnaryTests_4"><text>-</text></inputEntry>
+        <outputEntry id="LiteralExpression_2"><text>false</text></outputEntry>
+        <annotationEntry><text></text></annotationEntry>
+      </rule>
+    </decisionTable>
+  </decision>
+  <decision id="allowance" name="Allowance">
+    <informationRequirement id="InformationRequirement_2">
+      <requiredDecision href="#eligibility" />
+    </informationRequirement>
+    <knowledgeRequirement id="KnowledgeRequirement_1">
+      <requiredKnowledge href="#rates" />
+    </knowledgeRequirement>
+    <decisionTable id="DecisionTable_2" hitPolicy="COLLECT" aggregation="SUM">
+      <input id="Input_3" label="Eligible">
+        <inputExpression id="InputExpression_3" typeRef="boolean"><text>Eligibility</text></inputExpression>
+      </input>
+      <input id="Input_4" label="Children">
+        <inputExpression id="InputExpression_4" typeRef="number"><text>children</text></inputExpression>
+      </input>
+      <output id="Output_2" name="amount" typeRef="number" />
+      <rule id="Rule_base">
+        <inputEntry><text>true</text></inputEntry>
+        <inputEntry><text>-</text></inputEntry>
+        <outputEntry><text>100</text></outputEntry>
+      </rule>
+      <rule id="Rule_children">
+        <inputEntry><text>true</text></inputEntry>
+        <inputEntry><text>[1..3]</text></inputEntry>
+        <outputEntry><text>children * 50</text></outputEntry>
+      </rule>
+    </decisionTable>
+  </decision>
+  <inputData id="applicant_age" name="age">
+    <variable id="InformationItem_1" name="age" typeRef="number" />
+  </inputData>
+  <businessKnowledgeModel id="rates" name="Rates">
+    <encapsulatedLogic id="FunctionDefinition_1">
+      <formalParameter name="children" typeRef="number" />
+      <literalExpression id="LiteralExpression_9"><text>children * 50</text></literalExpression>
+    </encapsulatedLogic>
+  </businessKnowledgeModel>
+  <knowledgeSource id="act" name="Benefits Act" locationURI="https://example.org/act" />
+  <dmndi:DMNDI>
+    <dmndi:DMNDiagram id="DMNDiagram_1">
+      <dmndi:DMNShape id="DMNShape_1" dmnElementRef="eligibility">
+        <dc:Bounds height="80" width="180" x="160" y="100" />
+      </dmndi:DMNShape>
+    </dmndi:DMNDiagram>
+  </dmndi:DMNDI>
+</definitions>
+"##;
+
+    fn context(age: i64, status: &str, children: i64) -> FeelContext {
+        FeelContext::new()
+            .with_variable("age", age)
+            .with_variable("status", status)
+            .with_variable("children", children)
+    }
+
     #[test]
     fn test_dmn_import_export() {
         let importer = DmnImporter::new();
@@ -129,4 +2312,246 @@ mod tests {
         let (output, _) = exporter.export(&statutes).unwrap();
         assert!(output.contains("decision"));
     }
+
+    #[test]
+    fn test_parse_model() {
+        let definitions = DmnDefinitions::parse(CAMUNDA_DMN).unwrap();
+        assert_eq!(definitions.decisions.len(), 2);
+        assert_eq!(definitions.input_data.len(), 1);
+        assert_eq!(definitions.business_knowledge_models[0].parameters.len(), 1);
+        assert_eq!(
+            definitions.knowledge_sources[0].attributes,
+            vec![(
+                "locationURI".to_string(),
+                "https://example.org/act".to_string()
+            )]
+        );
+
+        let eligibility = definitions.decision("Eligibility").unwrap();
+        let table = eligibility.decision_table().unwrap();
+        assert_eq!(table.hit_policy, HitPolicy::First);
+        assert_eq!(table.inputs.len(), 2);
+        assert_eq!(table.rules[0].input_entries[0].text, ">= 18");
+        assert_eq!(table.rules[0].annotation_entries, vec!["Section 2 & 3"]);
+        assert_eq!(
+            table.rules[0].description.as_deref(),
+            Some("Adult residents")
+        );
+        assert_eq!(eligibility.requirements[0].required, RequiredElement::Input);
+
+        let allowance = definitions.decision("allowance").unwrap();
+        assert_eq!(
+            allowance.required_decisions().collect::<Vec<_>>(),
+            vec!["eligibility"]
+        );
+        assert_eq!(
+            allowance.decision_table().unwrap().aggregation,
+            Some(Aggregation::Sum)
+        );
+        assert_eq!(definitions.extensions[0].local_name(), "DMNDI");
+    }
+
+    #[test]
+    fn test_lossless_round_trip() {
+        let definitions = DmnDefinitions::parse(CAMUNDA_DMN).unwrap();
+        let xml = definitions.to_xml().unwrap();
+        assert!(xml.contains("camunda:historyTimeToLive=\"30\""));
+        assert!(xml.contains("&gt;= 18"));
+        assert!(xml.contains("<dmndi:DMNShape"));
+        assert_eq!(DmnDefinitions::parse(&xml).unwrap(), definitions);
+    }
+
+    #[test]
+    fn test_evaluate_hit_policies() {
+        let definitions = DmnDefinitions::parse(CAMUNDA_DMN).unwrap();
+
+        assert_eq!(
+            definitions
+                .evaluate("eligibility", &context(30, "resident", 0))
+                .unwrap(),
+            FeelValue::Bool(true)
+        );
+        assert_eq!(
+            definitions
+                .evaluate("eligibility", &context(30, "visitor", 0))
+                .unwrap(),
+            FeelValue::Bool(false)
+        );
+        // COLLECT SUM over the required eligibility decision
+        assert_eq!(
+            definitions
+                .evaluate("allowance", &context(30, "resident", 2))
+                .unwrap(),
+            FeelValue::from(200i64)
+        );
+        assert_eq!(
+            definitions
+                .evaluate("allowance", &context(12, "resident", 2))
+                .unwrap(),
+            FeelValue::Null
+        );
+
+        let mut table = definitions
+            .decision("eligibility")
+            .unwrap()
+            .decision_table()
+            .unwrap()
+            .clone();
+        let adult = context(30, "resident", 0);
+        table.hit_policy = HitPolicy::Unique;
+        assert!(table.evaluate(&adult).is_err());
+        table.hit_policy = HitPolicy::RuleOrder;
+        assert_eq!(
+            table.evaluate(&adult).unwrap(),
+            FeelValue::List(vec![FeelValue::Bool(true), FeelValue::Bool(false)])
+        );
+        table.hit_policy = HitPolicy::Any;
+        assert!(table.evaluate(&adult).is_err());
+
+        table.hit_policy = HitPolicy::Priority;
+        table.outputs[0].output_values = Some(LiteralExpression::new("false,true"));
+        assert_eq!(table.evaluate(&adult).unwrap(), FeelValue::Bool(false));
+        table.hit_policy = HitPolicy::OutputOrder;
+        assert_eq!(
+            table.evaluate(&adult).unwrap(),
+            FeelValue::List(vec![FeelValue::Bool(false), FeelValue::Bool(true)])
+        );
+
+        table.hit_policy = HitPolicy::Collect;
+        table.aggregation = Some(Aggregation::Count);
+        assert_eq!(table.evaluate(&adult).unwrap(), FeelValue::from(2i64));
+    }
+
+    #[test]
+    fn test_import_rules_as_statutes() {
+        let (statutes, report) = DmnImporter::new().import(CAMUNDA_DMN).unwrap();
+        assert_eq!(statutes.len(), 4);
+        assert_eq!(report.statutes_converted, 4);
+
+        let adult = &statutes[0];
+        assert_eq!(adult.id, "eligibility_Rule_adult");
+        assert_eq!(adult.title, "Adult residents");
+        assert_eq!(adult.effect.description, "eligible = true");
+        assert_eq!(
+            adult.preconditions,
+            vec![
+                Condition::Age {
+                    operator: ComparisonOp::GreaterOrEqual,
+                    value: 18
+                },
+                Condition::AttributeEquals {
+                    key: "status".to_string(),
+                    value: "resident".to_string()
+                },
+            ]
+        );
+        assert!(statutes[1].preconditions.is_empty());
+        assert_eq!(
+            statutes[3].preconditions[1],
+            Condition::And(
+                Box::new(Condition::Calculation {
+                    formula: "children".to_string(),
+                    operator: ComparisonOp::GreaterOrEqual,
+                    value: 1.0
+                }),
+                Box::new(Condition::Calculation {
+                    formula: "children".to_string(),
+                    operator: ComparisonOp::LessOrEqual,
+                    value: 3.0
+                })
+            )
+        );
+    }
+
+    #[test]
+    fn test_cross_check() {
+        let definitions = DmnDefinitions::parse(CAMUNDA_DMN).unwrap();
+        let (mut statutes, _) = DmnImporter::new().import(CAMUNDA_DMN).unwrap();
+        let samples = vec![
+            context(30, "resident", 2),
+            context(17, "resident", 0),
+            context(40, "visitor", 5),
+        ];
+        assert!(
+            definitions
+                .cross_check(&statutes, &samples)
+                .unwrap()
+                .is_empty()
+        );
+
+        // A mistranslated rule is reported
+        statutes[0].preconditions[0] = Condition::Age {
+            operator: ComparisonOp::GreaterOrEqual,
+            value: 21,
+        };
+        let mismatches = definitions.cross_check(&statutes, &samples).unwrap();
+        assert_eq!(mismatches.len(), 0);
+        let samples = vec![context(19, "resident", 0)];
+        let mismatches = definitions.cross_check(&statutes, &samples).unwrap();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].decision_id, "eligibility");
+        assert_eq!(mismatches[0].table_rules, vec![0, 1]);
+        assert_eq!(mismatches[0].statute_rules, vec![1]);
+    }
+
+    #[test]
+    fn test_statute_round_trip() {
+        let (statutes, _) = DmnImporter::new().import(CAMUNDA_DMN).unwrap();
+        let (xml, _) = DmnExporter::new().export(&statutes).unwrap();
+        let exported = DmnDefinitions::parse(&xml).unwrap();
+        let original = DmnDefinitions::parse(CAMUNDA_DMN).unwrap();
+
+        assert_eq!(exported.decisions.len(), 2);
+        for sample in [context(30, "resident", 2), context(30, "visitor", 1)] {
+            for decision in ["eligibility", "allowance"] {
+                assert_eq!(
+                    exported.evaluate(decision, &sample).unwrap(),
+                    original.evaluate(decision, &sample).unwrap()
+                );
+            }
+        }
+        let table = exported.decisions[0].decision_table().unwrap();
+        assert_eq!(table.rules[0].id.as_deref(), Some("Rule_adult"));
+        assert_eq!(
+            table.rules[0].description.as_deref(),
+            Some("Adult residents")
+        );
+        assert_eq!(table.rules[1].description, None);
+    }
+
+    #[test]
+    fn test_export_plain_statutes() {
+        let statute = Statute::new(
+            "voting",
+            "Voting rights",
+            Effect::new(EffectType::Grant, "vote"),
+        )
+        .with_precondition(Condition::Age {
+            operator: ComparisonOp::GreaterOrEqual,
+            value: 18,
+        })
+        .with_precondition(Condition::AttributeEquals {
+            key: "citizen".to_string(),
+            value: "yes".to_string(),
+        });
+
+        let exporter = DmnExporter::new();
+        assert!(exporter.can_represent(&statute).is_empty());
+        let (xml, report) = exporter.export(std::slice::from_ref(&statute)).unwrap();
+        assert!(report.unsupported_features.is_empty());
+
+        let definitions = DmnDefinitions::parse(&xml).unwrap();
+        let granted = FeelContext::new()
+            .with_variable("age", 20i64)
+            .with_variable("citizen", "yes");
+        assert_eq!(
+            definitions.evaluate("voting", &granted).unwrap(),
+            FeelValue::from("GRANT vote")
+        );
+        let minor = granted.clone().with_variable("age", 16i64);
+        assert_eq!(
+            definitions.evaluate("voting", &minor).unwrap(),
+            FeelValue::Null
+        );
+    }
 }
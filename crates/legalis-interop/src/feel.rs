@@ -0,0 +1,1453 @@
+//! FEEL (Friendly Enough Expression Language) evaluation for DMN.
+//!
+//! FEEL is the expression language of DMN. This module implements the part
+//! of FEEL used by decision tables and literal expressions:
+//! - Exact decimal numbers (`0.1 + 0.2 = 0.3`), strings, booleans, `null`,
+//!   dates, lists, contexts and ranges
+//! - Arithmetic, comparisons, `and`/`or`, `between`, `in` and `if`/`then`/`else`
+//! - Names containing spaces and path access (`Applicant Age`, `applicant.age`)
+//! - Unary tests for input entries (`< 18`, `[18..65]`, `"a","b"`, `not(...)`, `-`, `?`)
+//! - Common built-in functions (`date`, `string length`, `contains`, `sum`, ...)
+//!
+//! Evaluation follows FEEL semantics: type errors produce `null` rather than
+//! failing. Syntax errors and calls to functions outside [`FUNCTIONS`] (such
+//! as `date and time`, `time` or `duration`) are reported as [`InteropError`]s.
+//!
+//! Reference: <https://www.omg.org/spec/DMN/1.4/> (chapter 10)
+
+use crate::{InteropError, InteropResult};
+use chrono::{Datelike, NaiveDate};
+use legalis_core::Decimal;
+use legalis_core::decimal::{MAX_DECIMAL_SCALE, RoundingMode};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// A FEEL value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeelValue {
+    /// `null`, also the result of invalid operations
+    Null,
+    /// Boolean
+    Bool(bool),
+    /// Number
+    Number(Decimal),
+    /// String
+    String(String),
+    /// Date
+    Date(NaiveDate),
+    /// List
+    List(Vec<FeelValue>),
+    /// Context (key-value map)
+    Context(BTreeMap<String, FeelValue>),
+    /// Range such as `[1..10)`
+    Range {
+        /// Lower bound
+        low: Box<FeelValue>,
+        /// Upper bound
+        high: Box<FeelValue>,
+        /// Whether the lower bound is included
+        low_closed: bool,
+        /// Whether the upper bound is included
+        high_closed: bool,
+    },
+}
+
+impl FeelValue {
+    /// Returns `true` for `null`.
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// Returns the boolean value, if this is a boolean.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Returns the numeric value, if this is a number.
+    pub fn as_number(&self) -> Option<Decimal> {
+        match self {
+            Self::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Returns the string value, if this is a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as plain text, without FEEL string quotes.
+    pub fn to_plain_string(&self) -> String {
+        match self {
+            Self::String(s) => s.clone(),
+            other => other.to_string(),
+        }
+    }
+
+    /// FEEL equality; `None` when the values cannot be compared.
+    pub(crate) fn feel_eq(&self, other: &Self) -> Option<bool> {
+        match (self, other) {
+            (Self::Null, Self::Null) => Some(true),
+            (Self::Null, _) | (_, Self::Null) => Some(false),
+            (Self::Bool(a), Self::Bool(b)) => Some(a == b),
+            (Self::Number(a), Self::Number(b)) => Some(a == b),
+            (Self::String(a), Self::String(b)) => Some(a == b),
+            (Self::Date(a), Self::Date(b)) => Some(a == b),
+            (Self::List(a), Self::List(b)) => {
+                if a.len() != b.len() {
+                    return Some(false);
+                }
+                a.iter()
+                    .zip(b)
+                    .try_fold(true, |acc, (x, y)| Some(acc && x.feel_eq(y)?))
+            }
+            (Self::Context(a), Self::Context(b)) => {
+                if a.len() != b.len() {
+                    return Some(false);
+                }
+                a.iter().try_fold(true, |acc, (key, x)| match b.get(key) {
+                    Some(y) => Some(acc && x.feel_eq(y)?),
+                    None => Some(false),
+                })
+            }
+            (Self::Range { .. }, Self::Range { .. }) => Some(self == other),
+            _ => None,
+        }
+    }
+
+    /// FEEL ordering; `None` when the values cannot be ordered.
+    pub(crate) fn feel_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => Some(a.cmp(b)),
+            (Self::String(a), Self::String(b)) => Some(a.cmp(b)),
+            (Self::Date(a), Self::Date(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+
+    /// Returns whether a range contains a value.
+    fn range_contains(&self, value: &Self) -> Option<bool> {
+        let Self::Range {
+            low,
+            high,
+            low_closed,
+            high_closed,
+        } = self
+        else {
+            return None;
+        };
+        let above = match value.feel_cmp(low)? {
+            Ordering::Greater => true,
+            Ordering::Equal => *low_closed,
+            Ordering::Less => false,
+        };
+        let below = match value.feel_cmp(high)? {
+            Ordering::Less => true,
+            Ordering::Equal => *high_closed,
+            Ordering::Greater => false,
+        };
+        Some(above && below)
+    }
+}
+
+impl fmt::Display for FeelValue {
+    /// Formats the value as a FEEL literal.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => write!(f, "null"),
+            Self::Bool(b) => write!(f, "{}", b),
+            Self::Number(n) => write!(f, "{}", format_number(n)),
+            Self::String(s) => write!(f, "\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"")),
+            Self::Date(d) => write!(f, "date(\"{}\")", d.format("%Y-%m-%d")),
+            Self::List(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+            Self::Context(entries) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "\"{}\": {}", key, value)?;
+                }
+                write!(f, "}}")
+            }
+            Self::Range {
+                low,
+                high,
+                low_closed,
+                high_closed,
+            } => write!(
+                f,
+                "{}{}..{}{}",
+                if *low_closed { '[' } else { '(' },
+                low,
+                high,
+                if *high_closed { ']' } else { ')' }
+            ),
+        }
+    }
+}
+
+impl From<bool> for FeelValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<Decimal> for FeelValue {
+    fn from(value: Decimal) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<f64> for FeelValue {
+    fn from(value: f64) -> Self {
+        Decimal::from_f64(value).map_or(Self::Null, Self::Number)
+    }
+}
+
+impl From<i64> for FeelValue {
+    fn from(value: i64) -> Self {
+        Self::Number(Decimal::from(value))
+    }
+}
+
+impl From<&str> for FeelValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for FeelValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<NaiveDate> for FeelValue {
+    fn from(value: NaiveDate) -> Self {
+        Self::Date(value)
+    }
+}
+
+/// Formats a number without trailing fraction zeros.
+pub(crate) fn format_number(n: &Decimal) -> String {
+    n.normalize().to_string()
+}
+
+/// Variables available to FEEL expressions.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FeelContext {
+    variables: HashMap<String, FeelValue>,
+}
+
+impl FeelContext {
+    /// Creates an empty context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a variable.
+    pub fn with_variable(mut self, name: impl Into<String>, value: impl Into<FeelValue>) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    /// Sets a variable.
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<FeelValue>) {
+        self.variables.insert(name.into(), value.into());
+    }
+
+    /// Returns a variable.
+    pub fn get(&self, name: &str) -> Option<&FeelValue> {
+        self.variables.get(name)
+    }
+
+    /// Iterates over the variables.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &FeelValue)> {
+        self.variables.iter()
+    }
+}
+
+/// Parses and evaluates a FEEL expression.
+pub fn evaluate(expression: &str, context: &FeelContext) -> InteropResult<FeelValue> {
+    Ok(FeelExpression::parse(expression)?.evaluate(context))
+}
+
+/// A parsed FEEL expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeelExpression {
+    source: String,
+    expr: Expr,
+}
+
+impl FeelExpression {
+    /// Parses an expression.
+    pub fn parse(source: &str) -> InteropResult<Self> {
+        let mut parser = Parser::new(source)?;
+        let expr = parser.expression()?;
+        parser.finish()?;
+        Ok(Self {
+            source: source.to_string(),
+            expr,
+        })
+    }
+
+    /// Returns the source text.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Evaluates the expression.
+    pub fn evaluate(&self, context: &FeelContext) -> FeelValue {
+        eval(&self.expr, context, None)
+    }
+
+    /// Wraps an already parsed expression.
+    pub(crate) fn from_expr(expr: Expr) -> Self {
+        Self {
+            source: String::new(),
+            expr,
+        }
+    }
+}
+
+/// Parsed unary tests, the syntax of decision table input entries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnaryTests {
+    source: String,
+    tests: Vec<UnaryTest>,
+}
+
+impl UnaryTests {
+    /// Parses unary tests such as `< 18`, `"a","b"` or `not([1..5])`.
+    pub fn parse(source: &str) -> InteropResult<Self> {
+        let mut parser = Parser::new(source)?;
+        let tests = parser.unary_tests()?;
+        parser.finish()?;
+        Ok(Self {
+            source: source.to_string(),
+            tests,
+        })
+    }
+
+    /// Returns the source text.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns `true` if the input value satisfies the tests.
+    pub fn matches(&self, input: &FeelValue, context: &FeelContext) -> bool {
+        self.tests.iter().any(|test| test.matches(input, context))
+    }
+
+    pub(crate) fn tests(&self) -> &[UnaryTest] {
+        &self.tests
+    }
+}
+
+/// A single unary test.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum UnaryTest {
+    /// `-`, matches anything
+    Any,
+    /// `< e`, `>= e`, ...
+    Compare(BinaryOp, Expr),
+    /// A value, list or range the input is compared with, or a boolean
+    /// expression over `?`
+    Expr(Expr),
+    /// `not(tests)`
+    Not(Vec<UnaryTest>),
+}
+
+impl UnaryTest {
+    fn matches(&self, input: &FeelValue, context: &FeelContext) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Compare(op, expr) => {
+                let value = eval(expr, context, Some(input));
+                binary(*op, input, &value) == FeelValue::Bool(true)
+            }
+            Self::Expr(expr) => {
+                let value = eval(expr, context, Some(input));
+                if expr.uses_input() {
+                    return value == FeelValue::Bool(true);
+                }
+                match &value {
+                    FeelValue::Range { .. } => value.range_contains(input) == Some(true),
+                    FeelValue::List(items) if !matches!(input, FeelValue::List(_)) => {
+                        items.iter().any(|item| item.feel_eq(input) == Some(true))
+                    }
+                    _ => value.feel_eq(input) == Some(true),
+                }
+            }
+            Self::Not(tests) => !tests.iter().any(|test| test.matches(input, context)),
+        }
+    }
+}
+
+/// Binary operators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// FEEL abstract syntax tree.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Expr {
+    Literal(FeelValue),
+    Name(String),
+    /// `?`, the input value in unary tests
+    Input,
+    Path(Box<Expr>, String),
+    Call(String, Vec<Expr>),
+    List(Vec<Expr>),
+    Context(Vec<(String, Expr)>),
+    Range {
+        low: Box<Expr>,
+        high: Box<Expr>,
+        low_closed: bool,
+        high_closed: bool,
+    },
+    Negate(Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Between(Box<Expr>, Box<Expr>, Box<Expr>),
+    In(Box<Expr>, Vec<UnaryTest>),
+    If(Box<Expr>, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Returns `true` if the expression refers to `?`.
+    fn uses_input(&self) -> bool {
+        match self {
+            Self::Input => true,
+            Self::Literal(_) | Self::Name(_) => false,
+            Self::Path(base, _) | Self::Negate(base) => base.uses_input(),
+            Self::Call(_, args) | Self::List(args) => args.iter().any(Expr::uses_input),
+            Self::Context(entries) => entries.iter().any(|(_, e)| e.uses_input()),
+            Self::Range { low, high, .. } => low.uses_input() || high.uses_input(),
+            Self::Binary(_, a, b) | Self::And(a, b) | Self::Or(a, b) => {
+                a.uses_input() || b.uses_input()
+            }
+            Self::Between(a, b, c) | Self::If(a, b, c) => {
+                a.uses_input() || b.uses_input() || c.uses_input()
+            }
+            Self::In(value, _) => value.uses_input(),
+        }
+    }
+}
+
+// ==================================================
+// Tokenizer
+// ==================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(Decimal),
+    String(String),
+    Name(String),
+    /// `@"..."` temporal literal
+    Temporal(String),
+    Symbol(&'static str),
+}
+
+const SYMBOLS: &[&str] = &[
+    "..", "**", "!=", "<=", ">=", "(", ")", "[", "]", "{", "}", ",", ":", ".", "?", "+", "-", "*",
+    "/", "=", "<", ">",
+];
+
+/// Words that end a multi-word name.
+const RESERVED: &[&str] = &[
+    "and",
+    "or",
+    "between",
+    "in",
+    "if",
+    "then",
+    "else",
+    "true",
+    "false",
+    "null",
+    "for",
+    "return",
+    "some",
+    "every",
+    "satisfies",
+    "instance",
+    "of",
+    "function",
+];
+
+fn tokenize(source: &str) -> InteropResult<Vec<Token>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit()
+            || (c == '.' && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit()))
+        {
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            if i < chars.len()
+                && chars[i] == '.'
+                && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit())
+            {
+                i += 1;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+            }
+            let text: String = chars[start..i].iter().collect();
+            // `.5` is a valid FEEL number but needs a whole part for `Decimal`
+            let whole = if text.starts_with('.') {
+                format!("0{}", text)
+            } else {
+                text.clone()
+            };
+            let number = whole
+                .parse()
+                .map_err(|_| InteropError::ParseError(format!("Invalid FEEL number: {}", text)))?;
+            tokens.push(Token::Number(number));
+        } else if c == '"' || (c == '@' && chars.get(i + 1) == Some(&'"')) {
+            let temporal = c == '@';
+            i += if temporal { 2 } else { 1 };
+            let mut text = String::new();
+            loop {
+                match chars.get(i) {
+                    None => {
+                        return Err(InteropError::ParseError(format!(
+                            "Unterminated FEEL string in: {}",
+                            source
+                        )));
+                    }
+                    Some('"') => {
+                        i += 1;
+                        break;
+                    }
+                    Some('\\') => {
+                        match chars.get(i + 1) {
+                            Some('n') => text.push('\n'),
+                            Some('t') => text.push('\t'),
+                            Some(other) => text.push(*other),
+                            None => {}
+                        }
+                        i += 2;
+                    }
+                    Some(other) => {
+                        text.push(*other);
+                        i += 1;
+                    }
+                }
+            }
+            tokens.push(if temporal {
+                Token::Temporal(text)
+            } else {
+                Token::String(text)
+            });
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token::Name(chars[start..i].iter().collect()));
+        } else {
+            let rest: String = chars[i..chars.len().min(i + 2)].iter().collect();
+            let Some(symbol) = SYMBOLS.iter().find(|s| rest.starts_with(**s)) else {
+                return Err(InteropError::ParseError(format!(
+                    "Unexpected character '{}' in FEEL expression: {}",
+                    c, source
+                )));
+            };
+            i += symbol.len();
+            tokens.push(Token::Symbol(symbol));
+        }
+    }
+
+    Ok(tokens)
+}
+
+// ==================================================
+// Parser
+// ==================================================
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn new(source: &str) -> InteropResult<Self> {
+        Ok(Self {
+            tokens: tokenize(source)?,
+            pos: 0,
+        })
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn is_symbol(&self, symbol: &str) -> bool {
+        matches!(self.peek(), Some(Token::Symbol(s)) if *s == symbol)
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Name(n)) if n == keyword)
+    }
+
+    fn eat_symbol(&mut self, symbol: &str) -> bool {
+        let found = self.is_symbol(symbol);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let found = self.is_keyword(keyword);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> InteropResult<()> {
+        if self.eat_symbol(symbol) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", symbol)))
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> InteropResult<()> {
+        if self.eat_keyword(keyword) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", keyword)))
+        }
+    }
+
+    fn error(&self, message: &str) -> InteropError {
+        match self.peek() {
+            Some(token) => InteropError::ParseError(format!(
+                "FEEL syntax error: {} but found {:?}",
+                message, token
+            )),
+            None => {
+                InteropError::ParseError(format!("FEEL syntax error: {} at end of input", message))
+            }
+        }
+    }
+
+    fn finish(&self) -> InteropResult<()> {
+        if self.pos < self.tokens.len() {
+            Err(self.error("expected end of expression"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn unary_tests(&mut self) -> InteropResult<Vec<UnaryTest>> {
+        if self.tokens.is_empty() {
+            return Ok(vec![UnaryTest::Any]);
+        }
+        if self.tokens == [Token::Symbol("-")] {
+            self.pos = 1;
+            return Ok(vec![UnaryTest::Any]);
+        }
+        if self.is_keyword("not")
+            && self.peek_at(1) == Some(&Token::Symbol("("))
+            && self.tokens.last() == Some(&Token::Symbol(")"))
+        {
+            let checkpoint = self.pos;
+            self.pos += 2;
+            if let Ok(tests) = self.positive_unary_tests()
+                && self.eat_symbol(")")
+                && self.pos == self.tokens.len()
+            {
+                return Ok(vec![UnaryTest::Not(tests)]);
+            }
+            self.pos = checkpoint;
+        }
+        self.positive_unary_tests()
+    }
+
+    fn positive_unary_tests(&mut self) -> InteropResult<Vec<UnaryTest>> {
+        let mut tests = vec![self.positive_unary_test()?];
+        while self.eat_symbol(",") {
+            tests.push(self.positive_unary_test()?);
+        }
+        Ok(tests)
+    }
+
+    fn positive_unary_test(&mut self) -> InteropResult<UnaryTest> {
+        let op = match self.peek() {
+            Some(Token::Symbol("<")) => Some(BinaryOp::Lt),
+            Some(Token::Symbol("<=")) => Some(BinaryOp::Le),
+            Some(Token::Symbol(">")) => Some(BinaryOp::Gt),
+            Some(Token::Symbol(">=")) => Some(BinaryOp::Ge),
+            Some(Token::Symbol("=")) => Some(BinaryOp::Eq),
+            Some(Token::Symbol("!=")) => Some(BinaryOp::Ne),
+            _ => None,
+        };
+        match op {
+            Some(op) => {
+                self.pos += 1;
+                Ok(UnaryTest::Compare(op, self.additive()?))
+            }
+            None => Ok(UnaryTest::Expr(self.expression()?)),
+        }
+    }
+
+    fn expression(&mut self) -> InteropResult<Expr> {
+        if self.eat_keyword("if") {
+            let condition = self.expression()?;
+            self.expect_keyword("then")?;
+            let then = self.expression()?;
+            self.expect_keyword("else")?;
+            let otherwise = self.expression()?;
+            return Ok(Expr::If(
+                Box::new(condition),
+                Box::new(then),
+                Box::new(otherwise),
+            ));
+        }
+        for keyword in ["for", "some", "every"] {
+            if self.is_keyword(keyword) {
+                return Err(InteropError::ParseError(format!(
+                    "FEEL '{}' expressions are not supported",
+                    keyword
+                )));
+            }
+        }
+        self.disjunction()
+    }
+
+    fn disjunction(&mut self) -> InteropResult<Expr> {
+        let mut left = self.conjunction()?;
+        while self.eat_keyword("or") {
+            left = Expr::Or(Box::new(left), Box::new(self.conjunction()?));
+        }
+        Ok(left)
+    }
+
+    fn conjunction(&mut self) -> InteropResult<Expr> {
+        let mut left = self.comparison()?;
+        while self.eat_keyword("and") {
+            left = Expr::And(Box::new(left), Box::new(self.comparison()?));
+        }
+        Ok(left)
+    }
+
+    fn comparison(&mut self) -> InteropResult<Expr> {
+        let left = self.additive()?;
+        let op = match self.peek() {
+            Some(Token::Symbol("=")) => BinaryOp::Eq,
+            Some(Token::Symbol("!=")) => BinaryOp::Ne,
+            Some(Token::Symbol("<")) => BinaryOp::Lt,
+            Some(Token::Symbol("<=")) => BinaryOp::Le,
+            Some(Token::Symbol(">")) => BinaryOp::Gt,
+            Some(Token::Symbol(">=")) => BinaryOp::Ge,
+            _ => {
+                if self.eat_keyword("between") {
+                    let low = self.additive()?;
+                    self.expect_keyword("and")?;
+                    let high = self.additive()?;
+                    return Ok(Expr::Between(Box::new(left), Box::new(low), Box::new(high)));
+                }
+                if self.eat_keyword("in") {
+                    let tests = if self.is_symbol("(")
+                        && !matches!(self.peek_at(2), Some(Token::Symbol("..")))
+                    {
+                        self.pos += 1;
+                        let tests = self.positive_unary_tests()?;
+                        self.expect_symbol(")")?;
+                        tests
+                    } else {
+                        vec![self.positive_unary_test()?]
+                    };
+                    return Ok(Expr::In(Box::new(left), tests));
+                }
+                return Ok(left);
+            }
+        };
+        self.pos += 1;
+        let right = self.additive()?;
+        Ok(Expr::Binary(op, Box::new(left), Box::new(right)))
+    }
+
+    fn additive(&mut self) -> InteropResult<Expr> {
+        let mut left = self.multiplicative()?;
+        loop {
+            let op = if self.eat_symbol("+") {
+                BinaryOp::Add
+            } else if self.eat_symbol("-") {
+                BinaryOp::Sub
+            } else {
+                return Ok(left);
+            };
+            left = Expr::Binary(op, Box::new(left), Box::new(self.multiplicative()?));
+        }
+    }
+
+    fn multiplicative(&mut self) -> InteropResult<Expr> {
+        let mut left = self.power()?;
+        loop {
+            let op = if self.eat_symbol("*") {
+                BinaryOp::Mul
+            } else if self.eat_symbol("/") {
+                BinaryOp::Div
+            } else {
+                return Ok(left);
+            };
+            left = Expr::Binary(op, Box::new(left), Box::new(self.power()?));
+        }
+    }
+
+    fn power(&mut self) -> InteropResult<Expr> {
+        let mut left = self.unary()?;
+        while self.eat_symbol("**") {
+            left = Expr::Binary(BinaryOp::Pow, Box::new(left), Box::new(self.unary()?));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> InteropResult<Expr> {
+        if self.eat_symbol("-") {
+            return Ok(match self.unary()? {
+                Expr::Literal(FeelValue::Number(n)) => Expr::Literal(FeelValue::Number(-n)),
+                other => Expr::Negate(Box::new(other)),
+            });
+        }
+        self.postfix()
+    }
+
+    fn postfix(&mut self) -> InteropResult<Expr> {
+        let mut expr = self.primary()?;
+        while self.eat_symbol(".") {
+            match self.next() {
+                Some(Token::Name(name)) => expr = Expr::Path(Box::new(expr), name),
+                _ => {
+                    self.pos -= 1;
+                    return Err(self.error("expected a name after '.'"));
+                }
+            }
+        }
+        Ok(expr)
+    }
+
+    fn primary(&mut self) -> InteropResult<Expr> {
+        match self.next() {
+            Some(Token::Number(n)) => Ok(Expr::Literal(FeelValue::Number(n))),
+            Some(Token::String(s)) => Ok(Expr::Literal(FeelValue::String(s))),
+            Some(Token::Temporal(s)) => Ok(Expr::Literal(
+                parse_date(&s).map_or(FeelValue::Null, FeelValue::Date),
+            )),
+            Some(Token::Symbol("?")) => Ok(Expr::Input),
+            Some(Token::Symbol("(")) => {
+                let inner = self.expression()?;
+                if self.eat_symbol("..") {
+                    return self.range_end(inner, false);
+                }
+                self.expect_symbol(")")?;
+                Ok(inner)
+            }
+            Some(Token::Symbol("]")) => {
+                let low = self.expression()?;
+                self.expect_symbol("..")?;
+                self.range_end(low, false)
+            }
+            Some(Token::Symbol("[")) => {
+                if self.eat_symbol("]") {
+                    return Ok(Expr::List(Vec::new()));
+                }
+                let first = self.expression()?;
+                if self.eat_symbol("..") {
+                    return self.range_end(first, true);
+                }
+                let mut items = vec![first];
+                while self.eat_symbol(",") {
+                    items.push(self.expression()?);
+                }
+                self.expect_symbol("]")?;
+                Ok(Expr::List(items))
+            }
+            Some(Token::Symbol("{")) => {
+                let mut entries = Vec::new();
+                if !self.eat_symbol("}") {
+                    loop {
+                        let key = match self.next() {
+                            Some(Token::String(s)) => s,
+                            Some(Token::Name(first)) => self.name_from(first),
+                            _ => {
+                                self.pos -= 1;
+                                return Err(self.error("expected a context key"));
+                            }
+                        };
+                        self.expect_symbol(":")?;
+                        entries.push((key, self.expression()?));
+                        if !self.eat_symbol(",") {
+                            break;
+                        }
+                    }
+                    self.expect_symbol("}")?;
+                }
+                Ok(Expr::Context(entries))
+            }
+            Some(Token::Name(word)) => match word.as_str() {
+                "true" => Ok(Expr::Literal(FeelValue::Bool(true))),
+                "false" => Ok(Expr::Literal(FeelValue::Bool(false))),
+                "null" => Ok(Expr::Literal(FeelValue::Null)),
+                _ if RESERVED.contains(&word.as_str()) => {
+                    self.pos -= 1;
+                    Err(self.error("expected an expression"))
+                }
+                _ => {
+                    let name = self.name_from(word);
+                    if self.eat_symbol("(") {
+                        let mut args = Vec::new();
+                        if !self.eat_symbol(")") {
+                            loop {
+                                args.push(self.expression()?);
+                                if !self.eat_symbol(",") {
+                                    break;
+                                }
+                            }
+                            self.expect_symbol(")")?;
+                        }
+                        if !FUNCTIONS.contains(&name.as_str()) {
+                            return Err(InteropError::ParseError(format!(
+                                "Unsupported FEEL function: {}",
+                                name
+                            )));
+                        }
+                        Ok(Expr::Call(name, args))
+                    } else {
+                        Ok(Expr::Name(name))
+                    }
+                }
+            },
+            _ => {
+                self.pos -= 1;
+                Err(self.error("expected an expression"))
+            }
+        }
+    }
+
+    /// Joins the words of a name such as `Applicant Age`.
+    fn name_from(&mut self, first: String) -> String {
+        let mut name = first;
+        loop {
+            match self.peek() {
+                Some(Token::Name(word)) if !RESERVED.contains(&word.as_str()) => {
+                    name.push(' ');
+                    name.push_str(word);
+                    self.pos += 1;
+                }
+                // `date and time(...)` is the only built-in with a reserved word
+                Some(Token::Name(word))
+                    if name == "date"
+                        && word == "and"
+                        && self.peek_at(1) == Some(&Token::Name("time".to_string())) =>
+                {
+                    name.push_str(" and time");
+                    self.pos += 2;
+                }
+                _ => return name,
+            }
+        }
+    }
+
+    fn range_end(&mut self, low: Expr, low_closed: bool) -> InteropResult<Expr> {
+        let high = self.expression()?;
+        let high_closed = if self.eat_symbol("]") {
+            true
+        } else if self.eat_symbol(")") || self.eat_symbol("[") {
+            false
+        } else {
+            return Err(self.error("expected ']', ')' or '[' to close the range"));
+        };
+        Ok(Expr::Range {
+            low: Box::new(low),
+            high: Box::new(high),
+            low_closed,
+            high_closed,
+        })
+    }
+}
+
+// ==================================================
+// Evaluation
+// ==================================================
+
+fn eval(expr: &Expr, context: &FeelContext, input: Option<&FeelValue>) -> FeelValue {
+    match expr {
+        Expr::Literal(value) => value.clone(),
+        Expr::Name(name) => context.get(name).cloned().unwrap_or(FeelValue::Null),
+        Expr::Input => input.cloned().unwrap_or(FeelValue::Null),
+        Expr::Path(base, key) => match eval(base, context, input) {
+            FeelValue::Context(entries) => entries.get(key).cloned().unwrap_or(FeelValue::Null),
+            FeelValue::Date(date) => match key.as_str() {
+                "year" => FeelValue::from(i64::from(date.year())),
+                "month" => FeelValue::from(i64::from(date.month())),
+                "day" => FeelValue::from(i64::from(date.day())),
+                _ => FeelValue::Null,
+            },
+            _ => FeelValue::Null,
+        },
+        Expr::Call(name, args) => {
+            let args: Vec<FeelValue> = args.iter().map(|a| eval(a, context, input)).collect();
+            call(name, &args)
+        }
+        Expr::List(items) => {
+            FeelValue::List(items.iter().map(|i| eval(i, context, input)).collect())
+        }
+        Expr::Context(entries) => {
+            let mut scope = context.clone();
+            let mut result = BTreeMap::new();
+            for (key, value) in entries {
+                let value = eval(value, &scope, input);
+                scope.set(key.clone(), value.clone());
+                result.insert(key.clone(), value);
+            }
+            FeelValue::Context(result)
+        }
+        Expr::Range {
+            low,
+            high,
+            low_closed,
+            high_closed,
+        } => FeelValue::Range {
+            low: Box::new(eval(low, context, input)),
+            high: Box::new(eval(high, context, input)),
+            low_closed: *low_closed,
+            high_closed: *high_closed,
+        },
+        Expr::Negate(inner) => match eval(inner, context, input) {
+            FeelValue::Number(n) => FeelValue::Number(-n),
+            _ => FeelValue::Null,
+        },
+        Expr::Binary(op, left, right) => binary(
+            *op,
+            &eval(left, context, input),
+            &eval(right, context, input),
+        ),
+        Expr::And(left, right) => match (eval(left, context, input), eval(right, context, input)) {
+            (FeelValue::Bool(false), _) | (_, FeelValue::Bool(false)) => FeelValue::Bool(false),
+            (FeelValue::Bool(true), FeelValue::Bool(true)) => FeelValue::Bool(true),
+            _ => FeelValue::Null,
+        },
+        Expr::Or(left, right) => match (eval(left, context, input), eval(right, context, input)) {
+            (FeelValue::Bool(true), _) | (_, FeelValue::Bool(true)) => FeelValue::Bool(true),
+            (FeelValue::Bool(false), FeelValue::Bool(false)) => FeelValue::Bool(false),
+            _ => FeelValue::Null,
+        },
+        Expr::Between(value, low, high) => {
+            let value = eval(value, context, input);
+            let range = FeelValue::Range {
+                low: Box::new(eval(low, context, input)),
+                high: Box::new(eval(high, context, input)),
+                low_closed: true,
+                high_closed: true,
+            };
+            range
+                .range_contains(&value)
+                .map_or(FeelValue::Null, FeelValue::Bool)
+        }
+        Expr::In(value, tests) => {
+            let value = eval(value, context, input);
+            FeelValue::Bool(tests.iter().any(|test| test.matches(&value, context)))
+        }
+        Expr::If(condition, then, otherwise) => {
+            if eval(condition, context, input) == FeelValue::Bool(true) {
+                eval(then, context, input)
+            } else {
+                eval(otherwise, context, input)
+            }
+        }
+    }
+}
+
+fn binary(op: BinaryOp, left: &FeelValue, right: &FeelValue) -> FeelValue {
+    use FeelValue::{Bool, Null, Number, String as Str};
+    match op {
+        BinaryOp::Eq => left.feel_eq(right).map_or(Null, Bool),
+        BinaryOp::Ne => left.feel_eq(right).map_or(Null, |eq| Bool(!eq)),
+        BinaryOp::Lt | BinaryOp::Le | BinaryOp::Gt | BinaryOp::Ge => {
+            let Some(ordering) = left.feel_cmp(right) else {
+                return Null;
+            };
+            Bool(match op {
+                BinaryOp::Lt => ordering == Ordering::Less,
+                BinaryOp::Le => ordering != Ordering::Greater,
+                BinaryOp::Gt => ordering == Ordering::Greater,
+                _ => ordering != Ordering::Less,
+            })
+        }
+        BinaryOp::Add => match (left, right) {
+            (Number(a), Number(b)) => a.checked_add(b).map_or(Null, Number),
+            (Str(a), Str(b)) => Str(format!("{}{}", a, b)),
+            _ => Null,
+        },
+        BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Pow => {
+            let (Number(a), Number(b)) = (left, right) else {
+                return Null;
+            };
+            let result = match op {
+                BinaryOp::Sub => a.checked_sub(b),
+                BinaryOp::Mul => a.checked_mul(b),
+                BinaryOp::Div => a.div_rounded(b),
+                _ => power(a, b),
+            };
+            result.map_or(Null, Number)
+        }
+    }
+}
+
+/// Raises `base` to `exponent`, exactly for whole exponents and through
+/// `f64` otherwise.
+fn power(base: &Decimal, exponent: &Decimal) -> Option<Decimal> {
+    let exact = exponent.to_i64().and_then(|e| {
+        let mut result = Decimal::ONE;
+        let mut square = *base;
+        let mut remaining = e.unsigned_abs();
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = result.checked_mul(&square)?;
+            }
+            remaining >>= 1;
+            if remaining > 0 {
+                square = square.checked_mul(&square)?;
+            }
+        }
+        if e < 0 {
+            Decimal::ONE.div_rounded(&result)
+        } else {
+            Some(result)
+        }
+    });
+    exact.or_else(|| Decimal::from_f64(base.to_f64().powf(exponent.to_f64())))
+}
+
+fn parse_date(text: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d").ok()
+}
+
+/// Flattens `f(list)` and `f(a, b, c)` into the same argument list.
+fn list_args(args: &[FeelValue]) -> Vec<FeelValue> {
+    match args {
+        [FeelValue::List(items)] => items.clone(),
+        _ => args.to_vec(),
+    }
+}
+
+fn numbers(args: &[FeelValue]) -> Option<Vec<Decimal>> {
+    list_args(args).iter().map(FeelValue::as_number).collect()
+}
+
+/// Truncates a number to a whole position or length.
+fn whole(n: &Decimal) -> Option<i64> {
+    n.round(0, RoundingMode::Down).to_i64()
+}
+
+/// Built-in functions the evaluator implements; calls to any other function
+/// are rejected when parsing.
+pub const FUNCTIONS: &[&str] = &[
+    "not",
+    "date",
+    "number",
+    "string",
+    "string length",
+    "upper case",
+    "lower case",
+    "contains",
+    "starts with",
+    "ends with",
+    "substring",
+    "abs",
+    "floor",
+    "ceiling",
+    "decimal",
+    "count",
+    "sum",
+    "mean",
+    "min",
+    "max",
+    "list contains",
+];
+
+/// Evaluates a built-in function from [`FUNCTIONS`]; arguments of the wrong
+/// type yield `null`.
+fn call(name: &str, args: &[FeelValue]) -> FeelValue {
+    use FeelValue::{Bool, Null, Number, String as Str};
+    match (name, args) {
+        ("not", [Bool(b)]) => Bool(!b),
+        ("date", [Str(s)]) => parse_date(s).map_or(Null, FeelValue::Date),
+        ("date", [Number(y), Number(m), Number(d)]) => {
+            let part = |n: &Decimal| n.to_i64().and_then(|n| u32::try_from(n).ok());
+            match (
+                y.to_i64().and_then(|y| i32::try_from(y).ok()),
+                part(m),
+                part(d),
+            ) {
+                (Some(y), Some(m), Some(d)) => {
+                    NaiveDate::from_ymd_opt(y, m, d).map_or(Null, FeelValue::Date)
+                }
+                _ => Null,
+            }
+        }
+        ("number", [Str(s)]) => s.trim().parse().map_or(Null, Number),
+        ("string", [value]) => match value {
+            Null => Null,
+            other => Str(other.to_plain_string()),
+        },
+        ("string length", [Str(s)]) => FeelValue::from(s.chars().count() as i64),
+        ("upper case", [Str(s)]) => Str(s.to_uppercase()),
+        ("lower case", [Str(s)]) => Str(s.to_lowercase()),
+        ("contains", [Str(s), Str(m)]) => Bool(s.contains(m.as_str())),
+        ("starts with", [Str(s), Str(m)]) => Bool(s.starts_with(m.as_str())),
+        ("ends with", [Str(s), Str(m)]) => Bool(s.ends_with(m.as_str())),
+        ("substring", [Str(s), Number(start), rest @ ..]) => {
+            let chars: Vec<char> = s.chars().collect();
+            let len = chars.len() as i64;
+            let Some(start) = whole(start) else {
+                return Null;
+            };
+            let start = if start < 0 { len + start } else { start - 1 };
+            if start < 0 || start > len {
+                return Null;
+            }
+            let end = match rest {
+                [] => len,
+                [Number(length)] => match whole(length) {
+                    Some(length) if length >= 0 => start.saturating_add(length).min(len),
+                    _ => return Null,
+                },
+                _ => return Null,
+            };
+            Str(chars[start as usize..end as usize].iter().collect())
+        }
+        ("abs", [Number(n)]) => Number(n.abs()),
+        ("floor", [Number(n)]) => Number(n.round(0, RoundingMode::Floor)),
+        ("ceiling", [Number(n)]) => Number(n.round(0, RoundingMode::Ceiling)),
+        ("decimal", [Number(n), Number(scale)]) => {
+            match scale.to_i64().and_then(|scale| u32::try_from(scale).ok()) {
+                Some(scale) if scale <= MAX_DECIMAL_SCALE => {
+                    Number(n.round(scale, RoundingMode::HalfEven))
+                }
+                _ => Null,
+            }
+        }
+        ("count", _) => match args {
+            [FeelValue::List(items)] => FeelValue::from(items.len() as i64),
+            _ => Null,
+        },
+        ("sum", _) => numbers(args).and_then(|ns| sum(&ns)).map_or(Null, Number),
+        ("mean", _) => match numbers(args) {
+            Some(ns) if !ns.is_empty() => sum(&ns)
+                .and_then(|total| total.div_rounded(&Decimal::from(ns.len() as i64)))
+                .map_or(Null, Number),
+            _ => Null,
+        },
+        ("min" | "max", _) => {
+            let items = list_args(args);
+            let mut best: Option<&FeelValue> = None;
+            for item in &items {
+                let better = match best {
+                    None => true,
+                    Some(current) => match item.feel_cmp(current) {
+                        Some(ordering) => (ordering == Ordering::Less) == (name == "min"),
+                        None => return Null,
+                    },
+                };
+                if better {
+                    best = Some(item);
+                }
+            }
+            best.cloned().unwrap_or(Null)
+        }
+        ("list contains", [FeelValue::List(items), value]) => {
+            Bool(items.iter().any(|item| item.feel_eq(value) == Some(true)))
+        }
+        _ => Null,
+    }
+}
+
+fn sum(numbers: &[Decimal]) -> Option<Decimal> {
+    numbers
+        .iter()
+        .try_fold(Decimal::ZERO, |total, n| total.checked_add(n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval_str(source: &str, context: &FeelContext) -> FeelValue {
+        evaluate(source, context).unwrap()
+    }
+
+    #[test]
+    fn test_arithmetic_and_logic() {
+        let ctx = FeelContext::new()
+            .with_variable("Applicant Age", 42i64)
+            .with_variable("income", 30000.5);
+
+        assert_eq!(eval_str("1 + 2 * 3", &ctx), FeelValue::from(7i64));
+        assert_eq!(eval_str("2 ** 3 - 1", &ctx), FeelValue::from(7i64));
+        assert_eq!(eval_str("Applicant Age >= 18", &ctx), FeelValue::Bool(true));
+        assert_eq!(
+            eval_str("Applicant Age between 18 and 65 and income < 40000", &ctx),
+            FeelValue::Bool(true)
+        );
+        assert_eq!(eval_str("unknown > 1", &ctx), FeelValue::Null);
+        assert_eq!(
+            eval_str("false and unknown > 1", &ctx),
+            FeelValue::Bool(false)
+        );
+        assert_eq!(
+            eval_str("if income > 20000 then \"high\" else \"low\"", &ctx),
+            FeelValue::from("high")
+        );
+        assert_eq!(eval_str("\"a\" + \"b\"", &ctx), FeelValue::from("ab"));
+        assert_eq!(eval_str("1 / 0", &ctx), FeelValue::Null);
+        assert_eq!(eval_str("0.1 + 0.2 = 0.3", &ctx), FeelValue::Bool(true));
+        assert_eq!(eval_str("1 / 8", &ctx), FeelValue::from(0.125));
+        assert_eq!(eval_str("2 ** -2", &ctx), FeelValue::from(0.25));
+    }
+
+    #[test]
+    fn test_lists_contexts_and_functions() {
+        let ctx = FeelContext::new();
+        assert_eq!(eval_str("sum([1, 2, 3])", &ctx), FeelValue::from(6i64));
+        assert_eq!(eval_str("max(4, 9, 2)", &ctx), FeelValue::from(9i64));
+        assert_eq!(eval_str("count([1, 2])", &ctx), FeelValue::from(2i64));
+        assert_eq!(
+            eval_str("string length(\"hello\")", &ctx),
+            FeelValue::from(5i64)
+        );
+        assert_eq!(
+            eval_str("substring(\"foobar\", 3, 2)", &ctx),
+            FeelValue::from("ob")
+        );
+        assert_eq!(
+            eval_str("substring(\"foobar\", 3, 9223372036854775807)", &ctx),
+            FeelValue::from("obar")
+        );
+        assert_eq!(eval_str("decimal(2.5, 0)", &ctx), FeelValue::from(2i64));
+        assert_eq!(eval_str("decimal(1.125, 2)", &ctx), FeelValue::from(1.12));
+        assert_eq!(eval_str("mean(1, 2)", &ctx), FeelValue::from(1.5));
+        assert_eq!(eval_str("{a: 1, b: a + 1}.b", &ctx), FeelValue::from(2i64));
+        assert_eq!(
+            eval_str("date(\"2024-03-01\").month", &ctx),
+            FeelValue::from(3i64)
+        );
+        assert_eq!(
+            eval_str("@\"2024-03-01\" < date(2024, 4, 1)", &ctx),
+            FeelValue::Bool(true)
+        );
+        assert_eq!(eval_str("5 in [1..10)", &ctx), FeelValue::Bool(true));
+        assert_eq!(eval_str("10 in (1..10)", &ctx), FeelValue::Bool(false));
+        assert_eq!(
+            eval_str("\"b\" in (\"a\", \"b\")", &ctx),
+            FeelValue::Bool(true)
+        );
+    }
+
+    #[test]
+    fn test_unary_tests() {
+        let ctx = FeelContext::new().with_variable("limit", 100i64);
+        let matches =
+            |tests: &str, input: FeelValue| UnaryTests::parse(tests).unwrap().matches(&input, &ctx);
+
+        assert!(matches("-", FeelValue::Null));
+        assert!(matches("", FeelValue::from(1i64)));
+        assert!(matches("< 18", FeelValue::from(17i64)));
+        assert!(!matches("< 18", FeelValue::from(18i64)));
+        assert!(matches("[18..65]", FeelValue::from(65i64)));
+        assert!(!matches("]18..65]", FeelValue::from(18i64)));
+        assert!(matches("<= limit", FeelValue::from(100i64)));
+        assert!(matches("\"gold\",\"silver\"", FeelValue::from("silver")));
+        assert!(!matches(
+            "not(\"gold\",\"silver\")",
+            FeelValue::from("gold")
+        ));
+        assert!(matches("not(\"gold\")", FeelValue::from("bronze")));
+        assert!(matches("true", FeelValue::Bool(true)));
+        assert!(!matches("true", FeelValue::Bool(false)));
+        assert!(matches("? > 5 and ? < 10", FeelValue::from(7i64)));
+        assert!(matches("null", FeelValue::Null));
+        assert!(matches("not(null)", FeelValue::from(1i64)));
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        assert!(FeelExpression::parse("1 +").is_err());
+        assert!(FeelExpression::parse("(1, 2").is_err());
+        assert!(FeelExpression::parse("\"open").is_err());
+        assert!(FeelExpression::parse("for x in [1] return x").is_err());
+        assert!(UnaryTests::parse("< ").is_err());
+        for source in [
+            "date and time(\"2024-03-01T10:00:00\")",
+            "time(\"10:00:00\")",
+            "duration(\"P1Y\")",
+        ] {
+            let error = FeelExpression::parse(source).unwrap_err();
+            assert!(error.to_string().contains("Unsupported FEEL function"));
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(FeelValue::from(18i64).to_string(), "18");
+        assert_eq!(FeelValue::from(0.5).to_string(), "0.5");
+        assert_eq!(FeelValue::from("a\"b").to_string(), "\"a\\\"b\"");
+        assert_eq!(
+            FeelValue::List(vec![FeelValue::Bool(true), FeelValue::Null]).to_string(),
+            "[true, null]"
+        );
+    }
+}
@@ -4,18 +4,56 @@
 //! It provides a standardized way to express legal reasoning, precedents, and rules.
 //!
 //! Key features:
-//! - Rule-based representation with premises and conclusions
-//! - Support for defeasible and strict rules
-//! - Legal authority and source tracking
-//! - Temporal validity expressions
+//! - Prescriptive and constitutive statements wrapping RuleML rules
+//! - Strict, defeasible and defeater rule strength
+//! - `lrml:Override` superiority relations between rules
+//! - Deontic conclusions (`lrml:Obligation`, `lrml:Permission`, `lrml:Prohibition`) with bearers
+//! - Reparation chains expressed as `lrml:SuborderList` penalties
+//! - Associations between statements and their legal sources
+//!
+//! Documents in the compact shape (`<LegalRule>` elements with textual
+//! `<Premise>`s) are still accepted by the importer.
+//!
+//! # Mapping
+//!
+//! | LegalRuleML | Legalis |
+//! |-------------|---------|
+//! | `lrml:Obligation` / `lrml:Permission` / `lrml:Prohibition` | [`EffectType::Obligation`] / [`EffectType::Grant`] / [`EffectType::Prohibition`] |
+//! | `lrml:Bearer` | `bearer` effect parameter |
+//! | `lrml:hasStrength` | [`RuleKind`] of the rule in the [`DefeasibleTheory`] |
+//! | `lrml:Override` between rules | [`DefeasibleTheory::prefer`] |
+//! | Defeater overriding a rule | [`StatuteException`] of the overridden rule |
+//! | Suborder *k* of a penalty repairing `S` | statute `S-reparation-k`, applicable once its predecessor is violated |
+//! | `lrml:LegalSource` associated with a statement | [`Statute::derives_from`] |
+//!
+//! Strength and overrides are also kept in the `lrml_strength` and
+//! `lrml_overrides` effect parameters, so they survive conversions that only
+//! carry statutes.
 
+use crate::dmn::XmlElement;
 use crate::{
     ConversionReport, FormatExporter, FormatImporter, InteropError, InteropResult, LegalFormat,
 };
-use legalis_core::{ComparisonOp, Condition, Effect, EffectType, Statute};
-use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
-use quick_xml::{Reader, Writer};
-use std::io::Cursor;
+use legalis_core::defeasible::{DefeasibleTheory, RuleKind};
+use legalis_core::{ComparisonOp, Condition, Effect, EffectType, Statute, StatuteException};
+use std::collections::{HashMap, HashSet};
+
+/// Namespace of LegalRuleML 1.0 documents.
+pub const LRML_NAMESPACE: &str = "http://docs.oasis-open.org/legalruleml/ns/v1.0/";
+
+/// Namespace of RuleML formulas embedded in LegalRuleML.
+pub const RULEML_NAMESPACE: &str = "http://ruleml.org/spec";
+
+/// Effect parameter holding the bearer of a deontic conclusion.
+const BEARER_PARAM: &str = "bearer";
+/// Effect parameter holding the rule strength.
+const STRENGTH_PARAM: &str = "lrml_strength";
+/// Effect parameter holding the comma-separated ids of the rules this one overrides.
+const OVERRIDES_PARAM: &str = "lrml_overrides";
+/// Effect parameter holding the id of the statement a reparation repairs.
+const REPARATION_OF_PARAM: &str = "lrml_reparation_of";
+/// Effect parameter holding the position of a reparation in its suborder list.
+const SUBORDER_PARAM: &str = "lrml_suborder";
 
 /// Rule type in LegalRuleML.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -47,6 +85,94 @@ impl RuleType {
             _ => None,
         }
     }
+
+    /// Returns the strength of rules of this type. Constitutive rules are strict.
+    pub fn rule_kind(&self) -> RuleKind {
+        match self {
+            RuleType::Strict | RuleType::Constitutive => RuleKind::Strict,
+            RuleType::Defeasible => RuleKind::Defeasible,
+        }
+    }
+}
+
+/// Parses a strength name or IRI such as `defeasible:defeater`.
+fn parse_strength(value: &str) -> Option<RuleKind> {
+    let name = value.rsplit([':', '#', '/']).next().unwrap_or(value);
+    match name.to_lowercase().as_str() {
+        "strict" | "strictstrength" => Some(RuleKind::Strict),
+        "defeasible" | "defeasiblestrength" => Some(RuleKind::Defeasible),
+        "defeater" => Some(RuleKind::Defeater),
+        _ => None,
+    }
+}
+
+/// Maps a SWRL built-in comparison to a comparison operator.
+fn parse_builtin(name: &str) -> Option<ComparisonOp> {
+    match name {
+        "equal" => Some(ComparisonOp::Equal),
+        "notEqual" => Some(ComparisonOp::NotEqual),
+        "greaterThan" => Some(ComparisonOp::GreaterThan),
+        "greaterThanOrEqual" => Some(ComparisonOp::GreaterOrEqual),
+        "lessThan" => Some(ComparisonOp::LessThan),
+        "lessThanOrEqual" => Some(ComparisonOp::LessOrEqual),
+        _ => None,
+    }
+}
+
+/// Returns the SWRL built-in for a comparison operator.
+fn builtin_name(op: &ComparisonOp) -> &'static str {
+    match op {
+        ComparisonOp::Equal => "equal",
+        ComparisonOp::NotEqual => "notEqual",
+        ComparisonOp::GreaterThan => "greaterThan",
+        ComparisonOp::GreaterOrEqual => "greaterThanOrEqual",
+        ComparisonOp::LessThan => "lessThan",
+        ComparisonOp::LessOrEqual => "lessThanOrEqual",
+    }
+}
+
+/// Returns the relation name used for effect types without a deontic operator.
+fn effect_relation(effect_type: &EffectType) -> &'static str {
+    match effect_type {
+        EffectType::Grant => "grant",
+        EffectType::Revoke => "revoke",
+        EffectType::Obligation => "obligation",
+        EffectType::Prohibition => "prohibition",
+        EffectType::MonetaryTransfer => "monetaryTransfer",
+        EffectType::StatusChange => "statusChange",
+        EffectType::Custom => "custom",
+    }
+}
+
+/// Strips the leading `#` of a key reference.
+fn keyref(value: &str) -> String {
+    value.trim_start_matches('#').to_string()
+}
+
+/// Returns the part of an IRI after its prefix.
+fn local_iri(iri: &str) -> &str {
+    iri.rsplit([':', '#']).next().unwrap_or(iri)
+}
+
+/// Collects the descendants of `element` with one of the given local names, in document order.
+fn descendants<'a>(element: &'a XmlElement, names: &[&str], out: &mut Vec<&'a XmlElement>) {
+    for child in &element.children {
+        if names.contains(&child.local_name()) {
+            out.push(child);
+        }
+        descendants(child, names, out);
+    }
+}
+
+fn find_all<'a>(element: &'a XmlElement, names: &[&str]) -> Vec<&'a XmlElement> {
+    let mut out = Vec::new();
+    descendants(element, names, &mut out);
+    out
+}
+
+/// Joins conditions with `And`.
+fn conjunction(conditions: Vec<Condition>) -> Option<Condition> {
+    conditions.into_iter().reduce(|a, b| a.and(b))
 }
 
 /// LegalRuleML format importer.
@@ -55,6 +181,14 @@ pub struct LegalRuleMLImporter {
     preserve_metadata: bool,
 }
 
+/// Rules read from a LegalRuleML document.
+#[derive(Default)]
+struct ParsedDocument {
+    statutes: Vec<Statute>,
+    kinds: Vec<(String, RuleKind)>,
+    superiority: Vec<(String, String)>,
+}
+
 impl LegalRuleMLImporter {
     /// Creates a new LegalRuleML importer.
     pub fn new() -> Self {
@@ -69,147 +203,570 @@ impl LegalRuleMLImporter {
         self
     }
 
+    /// Imports a document as a defeasible theory.
+    ///
+    /// Rule strengths become rule kinds and overrides between rules become
+    /// superiority relations. Defeaters that override a rule are attached to
+    /// it as exceptions.
+    ///
+    /// ```
+    /// use legalis_core::defeasible::RuleKind;
+    /// use legalis_interop::legalruleml::LegalRuleMLImporter;
+    ///
+    /// let source = r##"
+    /// <lrml:LegalRuleML xmlns:lrml="http://docs.oasis-open.org/legalruleml/ns/v1.0/"
+    ///                   xmlns:ruleml="http://ruleml.org/spec">
+    ///   <lrml:Statements>
+    ///     <lrml:PrescriptiveStatement key="pay">
+    ///       <ruleml:Rule>
+    ///         <lrml:hasStrength><lrml:DefeasibleStrength iri="defeasible:defeasible"/></lrml:hasStrength>
+    ///         <ruleml:then>
+    ///           <lrml:Obligation>
+    ///             <lrml:Bearer keyref="#employer"/>
+    ///             <ruleml:Atom><ruleml:Rel>pay the wage</ruleml:Rel></ruleml:Atom>
+    ///           </lrml:Obligation>
+    ///         </ruleml:then>
+    ///       </ruleml:Rule>
+    ///     </lrml:PrescriptiveStatement>
+    ///     <lrml:PrescriptiveStatement key="strike">
+    ///       <ruleml:Rule>
+    ///         <ruleml:then>
+    ///           <lrml:Permission>
+    ///             <ruleml:Atom><ruleml:Rel>withhold the wage</ruleml:Rel></ruleml:Atom>
+    ///           </lrml:Permission>
+    ///         </ruleml:then>
+    ///       </ruleml:Rule>
+    ///     </lrml:PrescriptiveStatement>
+    ///     <lrml:OverrideStatement>
+    ///       <lrml:Override over="#strike" under="#pay"/>
+    ///     </lrml:OverrideStatement>
+    ///   </lrml:Statements>
+    /// </lrml:LegalRuleML>
+    /// "##;
+    ///
+    /// let (theory, _) = LegalRuleMLImporter::new().import_theory(source).unwrap();
+    /// assert_eq!(theory.statutes().len(), 2);
+    /// assert_eq!(theory.kind_of("pay"), RuleKind::Defeasible);
+    /// assert_eq!(
+    ///     theory.superiority(),
+    ///     &[("strike".to_string(), "pay".to_string())]
+    /// );
+    /// ```
+    pub fn import_theory(
+        &self,
+        source: &str,
+    ) -> InteropResult<(DefeasibleTheory, ConversionReport)> {
+        let mut report = ConversionReport::new(LegalFormat::LegalRuleML, LegalFormat::Legalis);
+        let root = XmlElement::parse(source)?;
+        let parsed = self.parse_document(&root, &mut report);
+
+        if parsed.statutes.is_empty() {
+            return Err(InteropError::ParseError(
+                "No valid LegalRuleML rules found".to_string(),
+            ));
+        }
+
+        // Note unsupported features
+        if source.contains("<authority") || source.contains("lrml:Authorit") {
+            report.add_unsupported("LegalRuleML authority metadata");
+        }
+        if source.contains("<temporal") || source.contains("lrml:TemporalCharacteristic") {
+            report.add_unsupported("LegalRuleML temporal validity");
+        }
+
+        report.statutes_converted = parsed.statutes.len();
+        let mut theory = DefeasibleTheory::new().with_statutes(parsed.statutes);
+        for (id, kind) in parsed.kinds {
+            theory = theory.with_kind(id, kind);
+        }
+        for (winner, loser) in parsed.superiority {
+            theory = theory.prefer(winner, loser);
+        }
+        Ok((theory, report))
+    }
+
     /// Parses a LegalRuleML document and extracts statutes.
-    fn parse_document(&self, source: &str, report: &mut ConversionReport) -> Vec<Statute> {
-        let mut statutes = Vec::new();
-        let mut reader = Reader::from_str(source);
-        reader.config_mut().trim_text(true);
-
-        let mut current_id = String::new();
-        let mut current_name = String::new();
-        let mut current_premises: Vec<String> = Vec::new();
-        let mut current_conclusion = String::new();
-        let mut in_rule = false;
-        let mut in_name = false;
-        let mut in_premise = false;
-        let mut in_conclusion = false;
-        let mut premise_text = String::new();
-        let mut conclusion_text = String::new();
-
-        loop {
-            match reader.read_event() {
-                Ok(Event::Start(ref e)) => {
-                    let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
-                    match name.as_str() {
-                        "Rule" | "LegalRule" => {
-                            in_rule = true;
-                            // Extract rule ID
-                            for attr in e.attributes().flatten() {
-                                if attr.key.as_ref() == b"key" || attr.key.as_ref() == b"id" {
-                                    current_id = String::from_utf8_lossy(&attr.value).to_string();
-                                }
-                            }
-                        }
-                        "Name" => {
-                            in_name = true;
-                        }
-                        "Premise" | "if" => {
-                            in_premise = true;
-                            premise_text.clear();
-                        }
-                        "Conclusion" | "then" => {
-                            in_conclusion = true;
-                            conclusion_text.clear();
-                        }
-                        _ => {}
-                    }
+    fn parse_document(&self, root: &XmlElement, report: &mut ConversionReport) -> ParsedDocument {
+        let statements = find_all(root, &["PrescriptiveStatement", "ConstitutiveStatement"]);
+        if statements.is_empty() {
+            return ParsedDocument {
+                statutes: self.parse_compact(root, report),
+                ..Default::default()
+            };
+        }
+
+        let sources = self.parse_sources(root);
+        let overrides: Vec<(String, String)> = find_all(root, &["Override"])
+            .into_iter()
+            .filter_map(|o| Some((keyref(o.attr("over")?), keyref(o.attr("under")?))))
+            .collect();
+
+        let mut parsed = ParsedDocument::default();
+        // Defeaters with a target become exceptions: (target, exception)
+        let mut exceptions: Vec<(String, StatuteException)> = Vec::new();
+        let mut defeaters: HashSet<String> = HashSet::new();
+
+        for (index, statement) in statements.iter().enumerate() {
+            let rule = statement.child("Rule");
+            let id = statement
+                .attr("key")
+                .or_else(|| rule.and_then(|r| r.attr("key")))
+                .map(keyref)
+                .unwrap_or_else(|| format!("lrml-{}", index + 1));
+            let title = statement
+                .child("Paraphrase")
+                .map(|p| p.text.clone())
+                .filter(|t| !t.is_empty())
+                .unwrap_or_else(|| format!("Rule {}", index + 1));
+
+            let strength = rule
+                .and_then(|r| r.child("hasStrength"))
+                .or_else(|| statement.child("hasStrength"))
+                .and_then(|s| s.children.first())
+                .and_then(|s| {
+                    s.attr("iri")
+                        .and_then(parse_strength)
+                        .or_else(|| parse_strength(s.local_name()))
+                });
+            let preconditions = rule
+                .and_then(|r| r.child("if"))
+                .map(|body| self.parse_body(body, report))
+                .unwrap_or_default();
+
+            let targets: Vec<&String> = overrides
+                .iter()
+                .filter(|(over, _)| over == &id)
+                .map(|(_, under)| under)
+                .collect();
+            if strength == Some(RuleKind::Defeater) && !targets.is_empty() {
+                let condition =
+                    conjunction(preconditions).unwrap_or_else(|| Condition::custom(&title));
+                for target in targets {
+                    exceptions.push((
+                        target.clone(),
+                        StatuteException::new(&id, &title, condition.clone()),
+                    ));
                 }
-                Ok(Event::End(ref e)) => {
-                    let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
-                    match name.as_str() {
-                        "Rule" | "LegalRule" => {
-                            if in_rule {
-                                // Create statute from collected data
-                                let id = if current_id.is_empty() {
-                                    format!("lrml-{}", statutes.len() + 1)
-                                } else {
-                                    current_id.to_lowercase().replace(['_', ' ', ':'], "-")
-                                };
-
-                                let title = if current_name.is_empty() {
-                                    format!("Rule {}", statutes.len() + 1)
-                                } else {
-                                    current_name.clone()
-                                };
-
-                                let effect_desc = if current_conclusion.is_empty() {
-                                    title.clone()
-                                } else {
-                                    current_conclusion.clone()
-                                };
-
-                                let mut statute = Statute::new(
-                                    &id,
-                                    &title,
-                                    Effect::new(EffectType::Grant, &effect_desc),
-                                );
-
-                                // Add premises as conditions
-                                for premise in &current_premises {
-                                    if let Some(cond) = Self::parse_premise(premise, report) {
-                                        statute.preconditions.push(cond);
-                                    }
-                                }
-
-                                statutes.push(statute);
-
-                                // Reset state
-                                current_id.clear();
-                                current_name.clear();
-                                current_premises.clear();
-                                current_conclusion.clear();
-                                in_rule = false;
-                            }
-                        }
-                        "Name" => {
-                            in_name = false;
-                        }
-                        "Premise" => {
-                            if !premise_text.trim().is_empty() {
-                                current_premises.push(premise_text.clone());
-                            }
-                            in_premise = false;
-                        }
-                        "if" => {
-                            // Container element, no action needed
-                        }
-                        "Conclusion" | "then" => {
-                            current_conclusion = conclusion_text.clone();
-                            in_conclusion = false;
-                        }
-                        _ => {}
+                defeaters.insert(id);
+                continue;
+            }
+
+            let mut effect = rule
+                .and_then(|r| r.child("then"))
+                .and_then(|head| head.children.first())
+                .map(|conclusion| self.parse_conclusion(conclusion, report))
+                .unwrap_or_else(|| Effect::grant(&title));
+            if let Some(kind) = strength {
+                effect = effect.with_parameter(STRENGTH_PARAM, kind.to_string());
+                parsed.kinds.push((id.clone(), kind));
+            }
+
+            let mut statute = Statute::new(&id, &title, effect);
+            statute.preconditions = preconditions;
+            if let Some(derived) = sources.get(&id) {
+                statute.derives_from = derived.clone();
+            }
+            if self.preserve_metadata
+                && let Some(discretion) = statement
+                    .children_named("Comment")
+                    .find_map(|c| c.text.strip_prefix("DISCRETION: "))
+            {
+                statute.discretion_logic = Some(discretion.to_string());
+            }
+            parsed.statutes.push(statute);
+        }
+
+        for (target, exception) in exceptions {
+            match parsed.statutes.iter_mut().find(|s| s.id == target) {
+                Some(statute) => statute.exceptions.push(exception),
+                None => report.add_warning(format!(
+                    "Defeater '{}' overrides unknown rule '{}'",
+                    exception.id, target
+                )),
+            }
+        }
+
+        for (over, under) in overrides {
+            if defeaters.contains(&over) {
+                continue;
+            }
+            if let Some(statute) = parsed.statutes.iter_mut().find(|s| s.id == over) {
+                let overridden = match statute.effect.get_parameter(OVERRIDES_PARAM) {
+                    Some(existing) => format!("{},{}", existing, under),
+                    None => under.clone(),
+                };
+                statute
+                    .effect
+                    .parameters
+                    .insert(OVERRIDES_PARAM.to_string(), overridden);
+            }
+            parsed.superiority.push((over, under));
+        }
+
+        let reparations = self.parse_reparations(root, &parsed.statutes, report);
+        parsed.statutes.extend(reparations);
+        parsed
+    }
+
+    /// Maps statement keys to the legal sources associated with them.
+    fn parse_sources(&self, root: &XmlElement) -> HashMap<String, Vec<String>> {
+        let legal_sources: HashMap<String, String> = find_all(root, &["LegalSource"])
+            .into_iter()
+            .filter_map(|source| {
+                let key = source.attr("key")?.to_string();
+                let reference = source
+                    .attr("sameAs")
+                    .or_else(|| source.attr("iri"))
+                    .unwrap_or(&key)
+                    .to_string();
+                Some((key, reference))
+            })
+            .collect();
+
+        let mut sources: HashMap<String, Vec<String>> = HashMap::new();
+        for association in find_all(root, &["Association"]) {
+            let applied: Vec<String> = association
+                .children_named("appliesSource")
+                .filter_map(|s| s.attr("keyref"))
+                .map(|k| {
+                    let key = keyref(k);
+                    legal_sources.get(&key).cloned().unwrap_or(key)
+                })
+                .collect();
+            for target in association
+                .children_named("toTarget")
+                .filter_map(|t| t.attr("keyref"))
+            {
+                let entry = sources.entry(keyref(target)).or_default();
+                for source in &applied {
+                    if !entry.contains(source) {
+                        entry.push(source.clone());
                     }
                 }
-                Ok(Event::Text(e)) => {
-                    let text = std::str::from_utf8(e.as_ref())
-                        .unwrap_or_default()
-                        .trim()
-                        .to_string();
-                    if !text.is_empty() {
-                        if in_name {
-                            current_name = text;
-                        } else if in_premise {
-                            if !premise_text.is_empty() {
-                                premise_text.push(' ');
-                            }
-                            premise_text.push_str(&text);
-                        } else if in_conclusion {
-                            if !conclusion_text.is_empty() {
-                                conclusion_text.push(' ');
-                            }
-                            conclusion_text.push_str(&text);
-                        }
-                    }
+            }
+        }
+        sources
+    }
+
+    /// Turns penalty suborder lists into chains of reparation statutes.
+    ///
+    /// Suborder `k` applies when the statement it repairs (or suborder `k - 1`)
+    /// applies and has been violated.
+    fn parse_reparations(
+        &self,
+        root: &XmlElement,
+        statutes: &[Statute],
+        report: &mut ConversionReport,
+    ) -> Vec<Statute> {
+        let penalties: HashMap<&str, &XmlElement> = find_all(root, &["PenaltyStatement"])
+            .into_iter()
+            .filter_map(|p| Some((p.attr("key")?, p)))
+            .collect();
+
+        let mut reparations = Vec::new();
+        for reparation in find_all(root, &["Reparation"]) {
+            let penalty = reparation
+                .child("appliesPenalty")
+                .and_then(|p| p.attr("keyref"))
+                .map(keyref);
+            let repaired = reparation
+                .child("toPrescriptiveStatement")
+                .and_then(|p| p.attr("keyref"))
+                .map(keyref);
+            let (Some(penalty), Some(repaired)) = (penalty, repaired) else {
+                report.add_warning("Reparation without penalty or statement reference");
+                continue;
+            };
+            let Some(suborders) = penalties
+                .get(penalty.as_str())
+                .and_then(|p| p.child("SuborderList"))
+            else {
+                report.add_warning(format!("Unknown penalty '{}'", penalty));
+                continue;
+            };
+
+            let repaired_title = statutes
+                .iter()
+                .find(|s| s.id == repaired)
+                .map(|s| s.title.clone())
+                .unwrap_or_else(|| repaired.clone());
+            let mut previous = repaired.clone();
+            for (index, deontic) in suborders.children.iter().enumerate() {
+                let position = index + 1;
+                let id = deontic
+                    .attr("key")
+                    .map(keyref)
+                    .unwrap_or_else(|| format!("{}-reparation-{}", repaired, position));
+                let effect = self
+                    .parse_conclusion(deontic, report)
+                    .with_parameter(REPARATION_OF_PARAM, &repaired)
+                    .with_parameter(SUBORDER_PARAM, position.to_string());
+                let statute = Statute::new(
+                    &id,
+                    format!("{}: reparation {}", repaired_title, position),
+                    effect,
+                )
+                .with_precondition(Condition::statute_applies(&previous))
+                .with_precondition(Condition::has_attribute(format!("{}_violated", previous)));
+                previous = id;
+                reparations.push(statute);
+            }
+        }
+        reparations
+    }
+
+    /// Parses the body of a rule into preconditions, one per top-level conjunct.
+    fn parse_body(&self, body: &XmlElement, report: &mut ConversionReport) -> Vec<Condition> {
+        match body.children.first() {
+            Some(formula) if formula.local_name() == "And" => formula
+                .children
+                .iter()
+                .filter_map(|c| self.parse_formula(c, report))
+                .collect(),
+            Some(formula) => self.parse_formula(formula, report).into_iter().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Parses a RuleML formula into a condition.
+    fn parse_formula(
+        &self,
+        formula: &XmlElement,
+        report: &mut ConversionReport,
+    ) -> Option<Condition> {
+        match formula.local_name() {
+            "And" => conjunction(
+                formula
+                    .children
+                    .iter()
+                    .filter_map(|c| self.parse_formula(c, report))
+                    .collect(),
+            ),
+            "Or" => formula
+                .children
+                .iter()
+                .filter_map(|c| self.parse_formula(c, report))
+                .reduce(|a, b| a.or(b)),
+            "Neg" | "Naf" => formula
+                .children
+                .first()
+                .and_then(|inner| self.parse_formula(inner, report))
+                .map(|inner| inner.not()),
+            "Atom" => self.parse_atom(formula, report),
+            "Premise" => Self::parse_premise(&formula.text, report),
+            other => {
+                report.add_warning(format!("Unsupported RuleML formula: {}", other));
+                None
+            }
+        }
+    }
+
+    /// Parses a RuleML atom.
+    ///
+    /// SWRL comparisons on `age`, `income` and `residency_months` map to the
+    /// matching conditions, other relations without a built-in meaning to
+    /// attributes.
+    fn parse_atom(&self, atom: &XmlElement, report: &mut ConversionReport) -> Option<Condition> {
+        let rel = atom.child("Rel")?;
+        let name = rel.attr("iri").map(local_iri).unwrap_or(rel.text.as_str());
+        let args: Vec<&XmlElement> = atom
+            .children
+            .iter()
+            .filter(|c| !matches!(c.local_name(), "Rel" | "op" | "slot"))
+            .collect();
+
+        match name {
+            "statuteApplies" => {
+                return args.first().map(|a| Condition::statute_applies(&a.text));
+            }
+            "custom" => return args.first().map(|a| Condition::custom(&a.text)),
+            _ => {}
+        }
+
+        let Some(operator) = parse_builtin(name) else {
+            return Some(Condition::has_attribute(name));
+        };
+        let (Some(subject), Some(value)) = (args.first(), args.get(1)) else {
+            report.add_warning(format!("Comparison '{}' needs two arguments", name));
+            return None;
+        };
+        let value = value.text.trim();
+
+        if subject.local_name() == "Expr" {
+            let formula = subject
+                .children
+                .iter()
+                .find(|c| c.local_name() != "Fun")
+                .map(|c| c.text.clone())
+                .unwrap_or_default();
+            return match value.parse::<f64>() {
+                Ok(value) => Some(Condition::Calculation {
+                    formula,
+                    operator,
+                    value,
+                }),
+                Err(_) => {
+                    report.add_warning(format!("Non-numeric calculation bound: {}", value));
+                    None
+                }
+            };
+        }
+
+        let variable = subject.text.trim();
+        match variable {
+            "age" => {
+                if let Ok(value) = value.parse::<u32>() {
+                    return Some(Condition::Age { operator, value });
+                }
+            }
+            "income" => {
+                if let Ok(value) = value.parse::<u64>() {
+                    return Some(Condition::Income { operator, value });
                 }
-                Ok(Event::Eof) => break,
-                Err(e) => {
-                    report.add_warning(format!("XML parse error: {}", e));
-                    break;
+            }
+            "residency_months" => {
+                if let Ok(months) = value.parse::<u32>() {
+                    return Some(Condition::ResidencyDuration { operator, months });
+                }
+            }
+            _ => {}
+        }
+
+        if operator == ComparisonOp::Equal {
+            return Some(Condition::AttributeEquals {
+                key: variable.to_string(),
+                value: value.to_string(),
+            });
+        }
+        match value.parse::<f64>() {
+            Ok(value) => Some(Condition::Calculation {
+                formula: variable.to_string(),
+                operator,
+                value,
+            }),
+            Err(_) => {
+                report.add_warning(format!(
+                    "Could not map comparison '{} {} {}'",
+                    variable, name, value
+                ));
+                None
+            }
+        }
+    }
+
+    /// Parses the conclusion of a rule or a suborder into an effect.
+    fn parse_conclusion(&self, conclusion: &XmlElement, report: &mut ConversionReport) -> Effect {
+        let deontic = match conclusion.local_name() {
+            "Obligation" => Some(EffectType::Obligation),
+            "Prohibition" => Some(EffectType::Prohibition),
+            "Permission" | "Right" => Some(EffectType::Grant),
+            _ => None,
+        };
+
+        let Some(effect_type) = deontic else {
+            return match conclusion.local_name() {
+                "Atom" => Self::parse_action(conclusion, None),
+                other => {
+                    report.add_warning(format!("Unsupported conclusion: {}", other));
+                    Effect::grant(&conclusion.text)
                 }
-                _ => {}
+            };
+        };
+
+        let mut effect = match conclusion.child("Atom") {
+            Some(atom) => Self::parse_action(atom, Some(effect_type)),
+            None => Effect::new(effect_type, &conclusion.text),
+        };
+        if let Some(bearer) = conclusion.child("Bearer") {
+            let agent = bearer
+                .attr("keyref")
+                .or_else(|| bearer.children.first().and_then(|a| a.attr("keyref")))
+                .map(keyref)
+                .or_else(|| {
+                    bearer
+                        .children
+                        .first()
+                        .map(|a| a.text.clone())
+                        .or(Some(bearer.text.clone()))
+                })
+                .filter(|b| !b.is_empty());
+            if let Some(agent) = agent {
+                effect = effect.with_parameter(BEARER_PARAM, agent);
             }
         }
+        effect
+    }
 
+    /// Parses the atom describing an action. Slots become effect parameters.
+    fn parse_action(atom: &XmlElement, effect_type: Option<EffectType>) -> Effect {
+        let rel = atom.child("Rel");
+        let description = rel.map(|r| r.text.clone()).unwrap_or_default();
+        let effect_type =
+            effect_type.unwrap_or_else(|| match rel.and_then(|r| r.attr("iri")).map(local_iri) {
+                Some("revoke") => EffectType::Revoke,
+                Some("obligation") => EffectType::Obligation,
+                Some("prohibition") => EffectType::Prohibition,
+                Some("monetaryTransfer") => EffectType::MonetaryTransfer,
+                Some("statusChange") => EffectType::StatusChange,
+                Some("custom") => EffectType::Custom,
+                _ => EffectType::Grant,
+            });
+
+        let mut effect = Effect::new(effect_type, description);
+        for slot in atom.children_named("slot") {
+            if let (Some(key), Some(value)) = (slot.children.first(), slot.children.get(1)) {
+                effect = effect.with_parameter(&key.text, &value.text);
+            }
+        }
+        effect
+    }
+
+    /// Parses documents made of `<LegalRule>` elements with textual premises.
+    fn parse_compact(&self, root: &XmlElement, report: &mut ConversionReport) -> Vec<Statute> {
+        let mut rules = Vec::new();
+        if matches!(root.local_name(), "Rule" | "LegalRule") {
+            rules.push(root);
+        }
+        descendants(root, &["Rule", "LegalRule"], &mut rules);
+
+        let mut statutes = Vec::new();
+        for rule in rules {
+            let id = match rule.attr("key").or_else(|| rule.attr("id")) {
+                Some(key) => key.to_lowercase().replace(['_', ' ', ':'], "-"),
+                None => format!("lrml-{}", statutes.len() + 1),
+            };
+            let title = rule
+                .child("Name")
+                .map(|n| n.text.clone())
+                .filter(|t| !t.is_empty())
+                .unwrap_or_else(|| format!("Rule {}", statutes.len() + 1));
+            let conclusion = find_all(rule, &["Conclusion"])
+                .first()
+                .map(|c| c.text.clone())
+                .or_else(|| rule.child("then").map(|t| t.text.clone()))
+                .filter(|c| !c.is_empty())
+                .unwrap_or_else(|| title.clone());
+
+            let mut effect = Effect::new(EffectType::Grant, &conclusion);
+            if let Some(kind) = rule
+                .attr("type")
+                .and_then(RuleType::from_lrml)
+                .map(|t| t.rule_kind())
+            {
+                effect = effect.with_parameter(STRENGTH_PARAM, kind.to_string());
+            }
+
+            let mut statute = Statute::new(&id, &title, effect);
+            for premise in find_all(rule, &["Premise"]) {
+                if premise.text.trim().is_empty() {
+                    continue;
+                }
+                if let Some(cond) = Self::parse_premise(&premise.text, report) {
+                    statute.preconditions.push(cond);
+                }
+            }
+            statutes.push(statute);
+        }
         statutes
     }
 
@@ -273,33 +830,13 @@ impl FormatImporter for LegalRuleMLImporter {
     }
 
     fn import(&self, source: &str) -> InteropResult<(Vec<Statute>, ConversionReport)> {
-        let mut report = ConversionReport::new(LegalFormat::LegalRuleML, LegalFormat::Legalis);
-
-        let statutes = self.parse_document(source, &mut report);
-
-        if statutes.is_empty() {
-            return Err(InteropError::ParseError(
-                "No valid LegalRuleML rules found".to_string(),
-            ));
-        }
-
-        // Note unsupported features
-        if source.contains("<override") || source.contains("<overrides") {
-            report.add_unsupported("LegalRuleML override relationships");
-        }
-        if source.contains("<authority") {
-            report.add_unsupported("LegalRuleML authority metadata");
-        }
-        if source.contains("<temporal") {
-            report.add_unsupported("LegalRuleML temporal validity");
-        }
-
-        report.statutes_converted = statutes.len();
-        Ok((statutes, report))
+        let (theory, report) = self.import_theory(source)?;
+        Ok((theory.statutes().to_vec(), report))
     }
 
     fn validate(&self, source: &str) -> bool {
         source.contains("<LegalRuleML")
+            || source.contains("<lrml:LegalRuleML")
             || source.contains("<legalruleml")
             || (source.contains("<Rule") && source.contains("<Premise"))
     }
@@ -323,6 +860,8 @@ impl LegalRuleMLExporter {
     }
 
     /// Sets the default rule type.
+    ///
+    /// Used for statutes that carry no strength of their own.
     pub fn with_rule_type(mut self, rule_type: RuleType) -> Self {
         self.default_rule_type = rule_type;
         self
@@ -334,58 +873,424 @@ impl LegalRuleMLExporter {
         self
     }
 
-    /// Converts a condition to LegalRuleML premise text.
-    fn condition_to_premise(condition: &Condition, report: &mut ConversionReport) -> String {
-        match condition {
-            Condition::Age { operator, value } => {
-                format!("age {} {}", Self::operator_symbol(operator), value)
+    /// Exports a defeasible theory.
+    ///
+    /// Rule kinds become strengths and superiority becomes `lrml:Override`.
+    /// Priorities are exported as overrides between conflicting statutes,
+    /// since LegalRuleML has no numeric priorities.
+    pub fn export_theory(
+        &self,
+        theory: &DefeasibleTheory,
+    ) -> InteropResult<(String, ConversionReport)> {
+        let mut report = ConversionReport::new(LegalFormat::Legalis, LegalFormat::LegalRuleML);
+        let statutes = theory.statutes();
+
+        let mut overrides: Vec<(String, String)> = theory.superiority().to_vec();
+        for (i, first) in statutes.iter().enumerate() {
+            for second in &statutes[i + 1..] {
+                let (Some(a), Some(b)) = (
+                    theory.priority_of(&first.id),
+                    theory.priority_of(&second.id),
+                ) else {
+                    continue;
+                };
+                let declared = overrides.iter().any(|(w, l)| {
+                    (w == &first.id && l == &second.id) || (w == &second.id && l == &first.id)
+                });
+                if a == b || declared || !theory.conflicts(first, second) {
+                    continue;
+                }
+                let (winner, loser) = if a > b {
+                    (first, second)
+                } else {
+                    (second, first)
+                };
+                overrides.push((winner.id.clone(), loser.id.clone()));
             }
-            Condition::Income { operator, value } => {
-                format!("income {} {}", Self::operator_symbol(operator), value)
+        }
+
+        let document =
+            self.build_document(statutes, |id| theory.kind_of(id), overrides, &mut report);
+        let output = document.to_xml()?;
+        Ok((output, report))
+    }
+
+    /// Builds the document for `statutes`.
+    fn build_document(
+        &self,
+        statutes: &[Statute],
+        kind_of: impl Fn(&str) -> RuleKind,
+        mut overrides: Vec<(String, String)>,
+        report: &mut ConversionReport,
+    ) -> XmlElement {
+        let (reparations, rules): (Vec<&Statute>, Vec<&Statute>) = statutes
+            .iter()
+            .partition(|s| s.effect.get_parameter(REPARATION_OF_PARAM).is_some());
+
+        for statute in &rules {
+            if let Some(overridden) = statute.effect.get_parameter(OVERRIDES_PARAM) {
+                for under in overridden.split(',').filter(|u| !u.is_empty()) {
+                    let pair = (statute.id.clone(), under.to_string());
+                    if !overrides.contains(&pair) {
+                        overrides.push(pair);
+                    }
+                }
+            }
+        }
+
+        let mut keys: HashSet<String> = statutes.iter().map(|s| s.id.clone()).collect();
+        let mut statements =
+            XmlElement::new("lrml:Statements").with_attr("key", Some("statements"));
+        let mut agents: Vec<String> = Vec::new();
+
+        for statute in &rules {
+            let kind = kind_of(&statute.id);
+            let mut rule = XmlElement::new("ruleml:Rule")
+                .with_attr("closure", Some("universal"))
+                .with_child(Self::strength(kind));
+            if !statute.preconditions.is_empty() {
+                rule = rule.with_child(
+                    XmlElement::new("ruleml:if")
+                        .with_child(Self::body(&statute.preconditions, report)),
+                );
+            }
+            let conclusion = Self::conclusion(&statute.effect, None, &mut agents);
+            rule = rule.with_child(XmlElement::new("ruleml:then").with_child(conclusion.clone()));
+
+            let statement_name = if self.default_rule_type == RuleType::Constitutive {
+                "lrml:ConstitutiveStatement"
+            } else {
+                "lrml:PrescriptiveStatement"
+            };
+            let mut statement = XmlElement::new(statement_name)
+                .with_attr("key", Some(statute.id.as_str()))
+                .with_child(XmlElement::new("lrml:Paraphrase").with_text(&statute.title))
+                .with_child(rule);
+            if let Some(ref discretion) = statute.discretion_logic {
+                report.add_warning(format!(
+                    "Discretion '{}' added as comment element",
+                    discretion
+                ));
+                if self.include_metadata {
+                    statement = statement.with_child(
+                        XmlElement::new("lrml:Comment")
+                            .with_text(format!("DISCRETION: {}", discretion)),
+                    );
+                }
             }
-            Condition::And(left, right) => {
-                let l = Self::condition_to_premise(left, report);
-                let r = Self::condition_to_premise(right, report);
-                format!("({}) AND ({})", l, r)
+            statements = statements.with_child(statement);
+
+            // Exceptions are defeaters of the rule's conclusion
+            for exception in &statute.exceptions {
+                let key = if keys.insert(exception.id.clone()) {
+                    exception.id.clone()
+                } else {
+                    let key = format!("{}-{}", statute.id, exception.id);
+                    report.add_warning(format!(
+                        "Exception '{}' of '{}' exported as '{}' to keep keys unique",
+                        exception.id, statute.id, key
+                    ));
+                    keys.insert(key.clone());
+                    key
+                };
+                let defeater =
+                    XmlElement::new("ruleml:Rule")
+                        .with_attr("closure", Some("universal"))
+                        .with_child(Self::strength(RuleKind::Defeater))
+                        .with_child(
+                            XmlElement::new("ruleml:if")
+                                .with_child(Self::formula(&exception.condition, report)),
+                        )
+                        .with_child(XmlElement::new("ruleml:then").with_child(
+                            XmlElement::new("ruleml:Neg").with_child(conclusion.clone()),
+                        ));
+                statements = statements.with_child(
+                    XmlElement::new("lrml:PrescriptiveStatement")
+                        .with_attr("key", Some(key.as_str()))
+                        .with_child(
+                            XmlElement::new("lrml:Paraphrase").with_text(&exception.description),
+                        )
+                        .with_child(defeater),
+                );
+                overrides.push((key, statute.id.clone()));
             }
-            Condition::Or(left, right) => {
-                let l = Self::condition_to_premise(left, report);
-                let r = Self::condition_to_premise(right, report);
-                format!("({}) OR ({})", l, r)
+            report.statutes_converted += 1;
+        }
+
+        if !overrides.is_empty() {
+            let mut statement =
+                XmlElement::new("lrml:OverrideStatement").with_attr("key", Some("overrides"));
+            for (over, under) in &overrides {
+                statement = statement.with_child(
+                    XmlElement::new("lrml:Override")
+                        .with_attr("over", Some(format!("#{}", over).as_str()))
+                        .with_attr("under", Some(format!("#{}", under).as_str())),
+                );
             }
-            Condition::Not(inner) => {
-                let i = Self::condition_to_premise(inner, report);
-                format!("NOT ({})", i)
+            statements = statements.with_child(statement);
+        }
+
+        // Reparations, grouped by the statement they repair
+        let mut chains: Vec<(String, Vec<&Statute>)> = Vec::new();
+        for statute in reparations {
+            let repaired = statute.effect.get_parameter(REPARATION_OF_PARAM).cloned();
+            let repaired = repaired.unwrap_or_default();
+            match chains.iter_mut().find(|(id, _)| id == &repaired) {
+                Some((_, chain)) => chain.push(statute),
+                None => chains.push((repaired, vec![statute])),
             }
-            Condition::AttributeEquals { key, value } => {
-                format!("{} = {}", key, value)
+        }
+        for (repaired, mut chain) in chains {
+            chain.sort_by_key(|s| {
+                s.effect
+                    .get_parameter(SUBORDER_PARAM)
+                    .and_then(|k| k.parse::<usize>().ok())
+                    .unwrap_or(usize::MAX)
+            });
+            let mut suborders = XmlElement::new("lrml:SuborderList");
+            for statute in chain {
+                suborders = suborders.with_child(Self::conclusion(
+                    &statute.effect,
+                    Some(statute.id.as_str()),
+                    &mut agents,
+                ));
+                report.statutes_converted += 1;
+            }
+            let penalty = format!("{}-penalty", repaired);
+            statements =
+                statements
+                    .with_child(
+                        XmlElement::new("lrml:PenaltyStatement")
+                            .with_attr("key", Some(penalty.as_str()))
+                            .with_child(suborders),
+                    )
+                    .with_child(
+                        XmlElement::new("lrml:ReparationStatement")
+                            .with_attr("key", Some(format!("{}-reparation", repaired).as_str()))
+                            .with_child(
+                                XmlElement::new("lrml:Reparation")
+                                    .with_child(XmlElement::new("lrml:appliesPenalty").with_attr(
+                                        "keyref",
+                                        Some(format!("#{}", penalty).as_str()),
+                                    ))
+                                    .with_child(
+                                        XmlElement::new("lrml:toPrescriptiveStatement").with_attr(
+                                            "keyref",
+                                            Some(format!("#{}", repaired).as_str()),
+                                        ),
+                                    ),
+                            ),
+                    );
+        }
+
+        let mut root = XmlElement::new("lrml:LegalRuleML")
+            .with_attr("xmlns:lrml", Some(LRML_NAMESPACE))
+            .with_attr("xmlns:ruleml", Some(RULEML_NAMESPACE));
+
+        // Legal sources and their associations with statements
+        let mut sources: Vec<&String> = Vec::new();
+        let mut associations = XmlElement::new("lrml:Associations");
+        for statute in &rules {
+            if statute.derives_from.is_empty() {
+                continue;
+            }
+            let mut association = XmlElement::new("lrml:Association");
+            for source in &statute.derives_from {
+                let index = match sources.iter().position(|s| *s == source) {
+                    Some(index) => index,
+                    None => {
+                        sources.push(source);
+                        sources.len() - 1
+                    }
+                };
+                association = association.with_child(
+                    XmlElement::new("lrml:appliesSource")
+                        .with_attr("keyref", Some(format!("#source-{}", index + 1).as_str())),
+                );
+            }
+            associations = associations.with_child(
+                association.with_child(
+                    XmlElement::new("lrml:toTarget")
+                        .with_attr("keyref", Some(format!("#{}", statute.id).as_str())),
+                ),
+            );
+        }
+        if !sources.is_empty() {
+            let mut legal_sources = XmlElement::new("lrml:LegalSources");
+            for (index, source) in sources.iter().enumerate() {
+                legal_sources = legal_sources.with_child(
+                    XmlElement::new("lrml:LegalSource")
+                        .with_attr("key", Some(format!("source-{}", index + 1).as_str()))
+                        .with_attr("sameAs", Some(source)),
+                );
             }
-            Condition::HasAttribute { key } => {
-                format!("{} exists", key)
+            root = root.with_child(legal_sources).with_child(
+                XmlElement::new("lrml:Context")
+                    .with_attr("key", Some("sources"))
+                    .with_child(associations),
+            );
+        }
+
+        if !agents.is_empty() {
+            let mut declared = XmlElement::new("lrml:Agents");
+            for agent in &agents {
+                declared = declared
+                    .with_child(XmlElement::new("lrml:Agent").with_attr("key", Some(agent)));
+            }
+            root = root.with_child(declared);
+        }
+
+        root.with_child(statements)
+    }
+
+    fn strength(kind: RuleKind) -> XmlElement {
+        XmlElement::new("lrml:hasStrength").with_child(
+            XmlElement::new("lrml:DefeasibleStrength")
+                .with_attr("iri", Some(format!("defeasible:{}", kind).as_str())),
+        )
+    }
+
+    /// Writes preconditions as the body of a rule.
+    ///
+    /// Several preconditions, or a single conjunction, are wrapped in an
+    /// `And` so that the importer splits them back the same way.
+    fn body(preconditions: &[Condition], report: &mut ConversionReport) -> XmlElement {
+        match preconditions {
+            [single] if !matches!(single, Condition::And(..)) => Self::formula(single, report),
+            _ => {
+                let mut and = XmlElement::new("ruleml:And");
+                for condition in preconditions {
+                    and = and.with_child(Self::formula(condition, report));
+                }
+                and
+            }
+        }
+    }
+
+    /// Converts a condition to a RuleML formula.
+    fn formula(condition: &Condition, report: &mut ConversionReport) -> XmlElement {
+        match condition {
+            Condition::Age { operator, value } => {
+                Self::comparison(operator, Self::var("age"), value.to_string())
+            }
+            Condition::Income { operator, value } => {
+                Self::comparison(operator, Self::var("income"), value.to_string())
             }
             Condition::ResidencyDuration { operator, months } => {
-                format!(
-                    "residency_months {} {}",
-                    Self::operator_symbol(operator),
-                    months
-                )
+                Self::comparison(operator, Self::var("residency_months"), months.to_string())
+            }
+            Condition::AttributeEquals { key, value } => {
+                Self::comparison(&ComparisonOp::Equal, Self::var(key), value.clone())
+            }
+            Condition::Calculation {
+                formula,
+                operator,
+                value,
+            } => Self::comparison(
+                operator,
+                XmlElement::new("ruleml:Expr")
+                    .with_child(
+                        XmlElement::new("ruleml:Fun").with_attr("iri", Some("legalis:calculate")),
+                    )
+                    .with_child(XmlElement::new("ruleml:Data").with_text(formula)),
+                value.to_string(),
+            ),
+            Condition::HasAttribute { key } => XmlElement::new("ruleml:Atom")
+                .with_child(XmlElement::new("ruleml:Rel").with_text(key)),
+            Condition::StatuteApplies { statute_id } => Self::relation(
+                "legalis:statuteApplies",
+                XmlElement::new("ruleml:Ind").with_text(statute_id),
+            ),
+            Condition::Custom { description } => Self::relation(
+                "legalis:custom",
+                XmlElement::new("ruleml:Data").with_text(description),
+            ),
+            Condition::And(left, right) => XmlElement::new("ruleml:And")
+                .with_child(Self::formula(left, report))
+                .with_child(Self::formula(right, report)),
+            Condition::Or(left, right) => XmlElement::new("ruleml:Or")
+                .with_child(Self::formula(left, report))
+                .with_child(Self::formula(right, report)),
+            Condition::Not(inner) => {
+                XmlElement::new("ruleml:Neg").with_child(Self::formula(inner, report))
             }
             _ => {
                 report.add_unsupported(format!("Condition type: {:?}", condition));
-                "true".to_string()
+                Self::relation(
+                    "legalis:custom",
+                    XmlElement::new("ruleml:Data").with_text(condition.to_string()),
+                )
             }
         }
     }
 
-    fn operator_symbol(op: &ComparisonOp) -> &'static str {
-        match op {
-            ComparisonOp::Equal => "=",
-            ComparisonOp::NotEqual => "!=",
-            ComparisonOp::GreaterThan => ">",
-            ComparisonOp::GreaterOrEqual => ">=",
-            ComparisonOp::LessThan => "<",
-            ComparisonOp::LessOrEqual => "<=",
+    fn var(name: &str) -> XmlElement {
+        XmlElement::new("ruleml:Var").with_text(name)
+    }
+
+    fn relation(iri: &str, argument: XmlElement) -> XmlElement {
+        XmlElement::new("ruleml:Atom")
+            .with_child(XmlElement::new("ruleml:Rel").with_attr("iri", Some(iri)))
+            .with_child(argument)
+    }
+
+    fn comparison(op: &ComparisonOp, subject: XmlElement, value: String) -> XmlElement {
+        XmlElement::new("ruleml:Atom")
+            .with_child(
+                XmlElement::new("ruleml:Rel")
+                    .with_attr("iri", Some(format!("swrlb:{}", builtin_name(op)).as_str())),
+            )
+            .with_child(subject)
+            .with_child(XmlElement::new("ruleml:Data").with_text(value))
+    }
+
+    /// Converts an effect to a conclusion.
+    ///
+    /// Obligations, grants and prohibitions become deontic operators with
+    /// their bearer; other effects become atoms whose relation names the
+    /// effect type.
+    fn conclusion(effect: &Effect, key: Option<&str>, agents: &mut Vec<String>) -> XmlElement {
+        let mut rel = XmlElement::new("ruleml:Rel").with_text(&effect.description);
+        let deontic = match effect.effect_type {
+            EffectType::Obligation => Some("lrml:Obligation"),
+            EffectType::Prohibition => Some("lrml:Prohibition"),
+            EffectType::Grant => Some("lrml:Permission"),
+            _ => None,
+        };
+        if deontic.is_none() {
+            let iri = format!("legalis:{}", effect_relation(&effect.effect_type));
+            rel = rel.with_attr("iri", Some(iri.as_str()));
         }
+
+        let mut atom = XmlElement::new("ruleml:Atom").with_child(rel);
+        let mut parameters: Vec<(&String, &String)> = effect
+            .parameters
+            .iter()
+            .filter(|(k, _)| k.as_str() != BEARER_PARAM && !k.starts_with("lrml_"))
+            .collect();
+        parameters.sort();
+        for (name, value) in parameters {
+            atom = atom.with_child(
+                XmlElement::new("ruleml:slot")
+                    .with_child(XmlElement::new("ruleml:Ind").with_text(name))
+                    .with_child(XmlElement::new("ruleml:Data").with_text(value)),
+            );
+        }
+
+        let Some(deontic) = deontic else {
+            return atom;
+        };
+        let mut element = XmlElement::new(deontic).with_attr("key", key);
+        if let Some(bearer) = effect.get_parameter(BEARER_PARAM) {
+            if !agents.contains(bearer) {
+                agents.push(bearer.clone());
+            }
+            element = element.with_child(
+                XmlElement::new("lrml:Bearer")
+                    .with_attr("keyref", Some(format!("#{}", bearer).as_str())),
+            );
+        }
+        element.with_child(atom)
     }
 }
 
@@ -402,142 +1307,22 @@ impl FormatExporter for LegalRuleMLExporter {
 
     fn export(&self, statutes: &[Statute]) -> InteropResult<(String, ConversionReport)> {
         let mut report = ConversionReport::new(LegalFormat::Legalis, LegalFormat::LegalRuleML);
-        let mut writer = Writer::new(Cursor::new(Vec::new()));
-
-        // XML declaration
-        writer
-            .write_event(Event::Decl(quick_xml::events::BytesDecl::new(
-                "1.0",
-                Some("UTF-8"),
-                None,
-            )))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        // Root element
-        let mut root = BytesStart::new("legalruleml");
-        root.push_attribute(("xmlns", "http://docs.oasis-open.org/legalruleml/ns/v1.0/"));
-        root.push_attribute(("xmlns:ruleml", "http://ruleml.org/spec"));
-        writer
-            .write_event(Event::Start(root))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        // Statements section
-        writer
-            .write_event(Event::Start(BytesStart::new("Statements")))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        // Convert each statute to a rule
-        for statute in statutes {
-            let mut rule = BytesStart::new("LegalRule");
-            rule.push_attribute(("key", statute.id.as_str()));
-            rule.push_attribute(("type", self.default_rule_type.to_lrml()));
-            writer
-                .write_event(Event::Start(rule))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            // Rule name
-            writer
-                .write_event(Event::Start(BytesStart::new("Name")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-            writer
-                .write_event(Event::Text(BytesText::new(&statute.title)))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-            writer
-                .write_event(Event::End(BytesEnd::new("Name")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            // Premises (conditions)
-            if !statute.preconditions.is_empty() {
-                writer
-                    .write_event(Event::Start(BytesStart::new("if")))
-                    .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-                for condition in &statute.preconditions {
-                    writer
-                        .write_event(Event::Start(BytesStart::new("Premise")))
-                        .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-                    let premise_text = Self::condition_to_premise(condition, &mut report);
-                    writer
-                        .write_event(Event::Text(BytesText::new(&premise_text)))
-                        .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-                    writer
-                        .write_event(Event::End(BytesEnd::new("Premise")))
-                        .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-                }
-
-                writer
-                    .write_event(Event::End(BytesEnd::new("if")))
-                    .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-            }
-
-            // Conclusion (effect)
-            writer
-                .write_event(Event::Start(BytesStart::new("then")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            writer
-                .write_event(Event::Start(BytesStart::new("Conclusion")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            let conclusion_text = format!(
-                "{}: {}",
-                statute.effect.effect_type, statute.effect.description
-            );
-            writer
-                .write_event(Event::Text(BytesText::new(&conclusion_text)))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            writer
-                .write_event(Event::End(BytesEnd::new("Conclusion")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            writer
-                .write_event(Event::End(BytesEnd::new("then")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            // Discretion as metadata
-            if let Some(ref discretion) = statute.discretion_logic {
-                report.add_warning(format!(
-                    "Discretion '{}' added as remark element",
-                    discretion
-                ));
-
-                writer
-                    .write_event(Event::Start(BytesStart::new("Remark")))
-                    .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-                writer
-                    .write_event(Event::Text(BytesText::new(&format!(
-                        "DISCRETION: {}",
-                        discretion
-                    ))))
-                    .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-                writer
-                    .write_event(Event::End(BytesEnd::new("Remark")))
-                    .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-            }
-
-            writer
-                .write_event(Event::End(BytesEnd::new("LegalRule")))
-                .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-            report.statutes_converted += 1;
-        }
-
-        // Close Statements
-        writer
-            .write_event(Event::End(BytesEnd::new("Statements")))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        // Close root
-        writer
-            .write_event(Event::End(BytesEnd::new("legalruleml")))
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
-        let output = String::from_utf8(writer.into_inner().into_inner())
-            .map_err(|e| InteropError::SerializationError(e.to_string()))?;
-
+        let strengths: HashMap<&str, RuleKind> = statutes
+            .iter()
+            .filter_map(|s| {
+                let kind = s.effect.get_parameter(STRENGTH_PARAM)?;
+                Some((s.id.as_str(), parse_strength(kind)?))
+            })
+            .collect();
+        let default_kind = self.default_rule_type.rule_kind();
+
+        let document = self.build_document(
+            statutes,
+            |id| strengths.get(id).copied().unwrap_or(default_kind),
+            Vec::new(),
+            &mut report,
+        );
+        let output = document.to_xml()?;
         Ok((output, report))
     }
 
@@ -545,7 +1330,7 @@ impl FormatExporter for LegalRuleMLExporter {
         let mut issues = Vec::new();
 
         if statute.discretion_logic.is_some() {
-            issues.push("Discretionary logic will be added as remark element".to_string());
+            issues.push("Discretionary logic will be added as comment element".to_string());
         }
 
         // Check for complex conditions
@@ -564,6 +1349,129 @@ impl FormatExporter for LegalRuleMLExporter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fidelity::FidelityAnalyzer;
+    use legalis_core::AttributeBasedContext;
+    use legalis_core::defeasible::ArgumentStatus;
+
+    const EMPLOYMENT_RULES: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<lrml:LegalRuleML xmlns:lrml="http://docs.oasis-open.org/legalruleml/ns/v1.0/"
+                  xmlns:ruleml="http://ruleml.org/spec">
+  <lrml:LegalSources>
+    <lrml:LegalSource key="src-wages" sameAs="/akn/it/act/2003/276/!main#art2"/>
+  </lrml:LegalSources>
+  <lrml:Context key="ctx">
+    <lrml:Associations>
+      <lrml:Association>
+        <lrml:appliesSource keyref="#src-wages"/>
+        <lrml:toTarget keyref="#pay-wage"/>
+        <lrml:toTarget keyref="#withhold-wage"/>
+      </lrml:Association>
+    </lrml:Associations>
+  </lrml:Context>
+  <lrml:Agents>
+    <lrml:Agent key="employer"/>
+  </lrml:Agents>
+  <lrml:Statements key="employment">
+    <lrml:PrescriptiveStatement key="pay-wage">
+      <lrml:Paraphrase>Employers must pay adult employees</lrml:Paraphrase>
+      <ruleml:Rule closure="universal">
+        <lrml:hasStrength><lrml:DefeasibleStrength iri="defeasible:defeasible"/></lrml:hasStrength>
+        <ruleml:if>
+          <ruleml:And>
+            <ruleml:Atom><ruleml:Rel>employed</ruleml:Rel></ruleml:Atom>
+            <ruleml:Atom>
+              <ruleml:Rel iri="swrlb:greaterThanOrEqual"/>
+              <ruleml:Var>age</ruleml:Var>
+              <ruleml:Data>18</ruleml:Data>
+            </ruleml:Atom>
+          </ruleml:And>
+        </ruleml:if>
+        <ruleml:then>
+          <lrml:Obligation>
+            <lrml:Bearer keyref="#employer"/>
+            <ruleml:Atom><ruleml:Rel>pay the wage</ruleml:Rel></ruleml:Atom>
+          </lrml:Obligation>
+        </ruleml:then>
+      </ruleml:Rule>
+    </lrml:PrescriptiveStatement>
+    <lrml:PrescriptiveStatement key="withhold-wage">
+      <lrml:Paraphrase>Wages may be withheld during a strike</lrml:Paraphrase>
+      <ruleml:Rule closure="universal">
+        <lrml:hasStrength><lrml:DefeasibleStrength iri="defeasible:defeasible"/></lrml:hasStrength>
+        <ruleml:if><ruleml:Atom><ruleml:Rel>on_strike</ruleml:Rel></ruleml:Atom></ruleml:if>
+        <ruleml:then>
+          <lrml:Permission>
+            <lrml:Bearer keyref="#employer"/>
+            <ruleml:Atom><ruleml:Rel>withhold the wage</ruleml:Rel></ruleml:Atom>
+          </lrml:Permission>
+        </ruleml:then>
+      </ruleml:Rule>
+    </lrml:PrescriptiveStatement>
+    <lrml:PrescriptiveStatement key="force-majeure">
+      <lrml:Paraphrase>Force majeure</lrml:Paraphrase>
+      <ruleml:Rule closure="universal">
+        <lrml:hasStrength><lrml:DefeasibleStrength iri="defeasible:defeater"/></lrml:hasStrength>
+        <ruleml:if><ruleml:Atom><ruleml:Rel>force_majeure</ruleml:Rel></ruleml:Atom></ruleml:if>
+        <ruleml:then>
+          <ruleml:Neg>
+            <lrml:Obligation>
+              <ruleml:Atom><ruleml:Rel>pay the wage</ruleml:Rel></ruleml:Atom>
+            </lrml:Obligation>
+          </ruleml:Neg>
+        </ruleml:then>
+      </ruleml:Rule>
+    </lrml:PrescriptiveStatement>
+    <lrml:PrescriptiveStatement key="no-dismissal">
+      <lrml:Paraphrase>No dismissal during sick leave</lrml:Paraphrase>
+      <ruleml:Rule closure="universal">
+        <lrml:hasStrength><lrml:DefeasibleStrength iri="defeasible:strict"/></lrml:hasStrength>
+        <ruleml:if><ruleml:Atom><ruleml:Rel>sick_leave</ruleml:Rel></ruleml:Atom></ruleml:if>
+        <ruleml:then>
+          <lrml:Prohibition>
+            <lrml:Bearer keyref="#employer"/>
+            <ruleml:Atom><ruleml:Rel>dismiss the employee</ruleml:Rel></ruleml:Atom>
+          </lrml:Prohibition>
+        </ruleml:then>
+      </ruleml:Rule>
+    </lrml:PrescriptiveStatement>
+    <lrml:OverrideStatement key="ovr">
+      <lrml:Override over="#withhold-wage" under="#pay-wage"/>
+      <lrml:Override over="#force-majeure" under="#pay-wage"/>
+    </lrml:OverrideStatement>
+    <lrml:PenaltyStatement key="late-payment">
+      <lrml:SuborderList>
+        <lrml:Obligation>
+          <lrml:Bearer keyref="#employer"/>
+          <ruleml:Atom><ruleml:Rel>pay the wage with interest</ruleml:Rel></ruleml:Atom>
+        </lrml:Obligation>
+        <lrml:Obligation>
+          <lrml:Bearer keyref="#employer"/>
+          <ruleml:Atom><ruleml:Rel>pay compensation</ruleml:Rel></ruleml:Atom>
+        </lrml:Obligation>
+      </lrml:SuborderList>
+    </lrml:PenaltyStatement>
+    <lrml:ReparationStatement key="rep">
+      <lrml:Reparation>
+        <lrml:appliesPenalty keyref="#late-payment"/>
+        <lrml:toPrescriptiveStatement keyref="#pay-wage"/>
+      </lrml:Reparation>
+    </lrml:ReparationStatement>
+  </lrml:Statements>
+</lrml:LegalRuleML>
+"##;
+
+    fn context(pairs: &[(&str, &str)]) -> AttributeBasedContext {
+        AttributeBasedContext::new(
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        )
+    }
+
+    fn statute<'a>(statutes: &'a [Statute], id: &str) -> &'a Statute {
+        statutes.iter().find(|s| s.id == id).unwrap()
+    }
 
     #[test]
     fn test_rule_type_conversion() {
@@ -581,6 +1489,7 @@ mod tests {
         let importer = LegalRuleMLImporter::new();
         assert!(importer.validate("<LegalRuleML><Rule></Rule></LegalRuleML>"));
         assert!(importer.validate("<Rule><Premise>test</Premise></Rule>"));
+        assert!(importer.validate("<lrml:LegalRuleML></lrml:LegalRuleML>"));
         assert!(!importer.validate("STATUTE foo: \"bar\" {}"));
     }
 
@@ -625,10 +1534,11 @@ mod tests {
 
         let (output, report) = exporter.export(&[statute]).unwrap();
 
-        assert!(output.contains("<legalruleml"));
+        assert!(output.contains("<lrml:LegalRuleML"));
         assert!(output.contains("Voting Rights Rule"));
-        assert!(output.contains("age"));
-        assert!(output.contains("18"));
+        assert!(output.contains("<ruleml:Var>age</ruleml:Var>"));
+        assert!(output.contains("<ruleml:Data>18</ruleml:Data>"));
+        assert!(output.contains("<lrml:Permission>"));
         assert_eq!(report.statutes_converted, 1);
     }
 
@@ -677,7 +1587,220 @@ mod tests {
 
         let (output, report) = exporter.export(&[statute]).unwrap();
         assert_eq!(report.statutes_converted, 1);
-        assert!(output.contains("<Premise>age"));
-        assert!(output.contains("<Premise>income"));
+        assert!(output.contains("<ruleml:And>"));
+        assert!(output.contains("<ruleml:Var>age</ruleml:Var>"));
+        assert!(output.contains("<ruleml:Var>income</ruleml:Var>"));
+    }
+
+    #[test]
+    fn test_legalruleml_deontic_strength_and_bearers() {
+        let (theory, report) = LegalRuleMLImporter::new()
+            .import_theory(EMPLOYMENT_RULES)
+            .unwrap();
+        let statutes = theory.statutes();
+        assert_eq!(report.statutes_converted, 5);
+
+        let pay = statute(statutes, "pay-wage");
+        assert_eq!(pay.title, "Employers must pay adult employees");
+        assert_eq!(pay.effect.effect_type, EffectType::Obligation);
+        assert_eq!(pay.effect.description, "pay the wage");
+        assert_eq!(
+            pay.effect.get_parameter("bearer").map(String::as_str),
+            Some("employer")
+        );
+        assert_eq!(
+            pay.preconditions,
+            vec![
+                Condition::has_attribute("employed"),
+                Condition::Age {
+                    operator: ComparisonOp::GreaterOrEqual,
+                    value: 18,
+                },
+            ]
+        );
+
+        let withhold = statute(statutes, "withhold-wage");
+        assert_eq!(withhold.effect.effect_type, EffectType::Grant);
+        let dismissal = statute(statutes, "no-dismissal");
+        assert_eq!(dismissal.effect.effect_type, EffectType::Prohibition);
+
+        assert_eq!(theory.kind_of("pay-wage"), RuleKind::Defeasible);
+        assert_eq!(theory.kind_of("no-dismissal"), RuleKind::Strict);
+        assert_eq!(
+            dismissal
+                .effect
+                .get_parameter(STRENGTH_PARAM)
+                .map(String::as_str),
+            Some("strict")
+        );
+    }
+
+    #[test]
+    fn test_legalruleml_override_and_defeater() {
+        let (theory, _) = LegalRuleMLImporter::new()
+            .import_theory(EMPLOYMENT_RULES)
+            .unwrap();
+
+        // The defeater is an exception of the rule it overrides, not a rule
+        assert!(!theory.statutes().iter().any(|s| s.id == "force-majeure"));
+        let pay = statute(theory.statutes(), "pay-wage");
+        assert_eq!(pay.exceptions.len(), 1);
+        assert_eq!(pay.exceptions[0].id, "force-majeure");
+        assert_eq!(pay.exceptions[0].description, "Force majeure");
+        assert_eq!(
+            pay.exceptions[0].condition,
+            Condition::has_attribute("force_majeure")
+        );
+        assert_eq!(
+            theory.superiority(),
+            &[("withhold-wage".to_string(), "pay-wage".to_string())]
+        );
+
+        let outcome = theory.evaluate(&context(&[("employed", "true"), ("age", "30")]));
+        assert!(outcome.is_accepted("pay-wage"));
+
+        let outcome = theory.evaluate(&context(&[
+            ("employed", "true"),
+            ("age", "30"),
+            ("on_strike", "true"),
+        ]));
+        assert!(outcome.is_accepted("withhold-wage"));
+        assert_eq!(outcome.status("pay-wage"), Some(&ArgumentStatus::Defeated));
+
+        let outcome = theory.evaluate(&context(&[
+            ("employed", "true"),
+            ("age", "30"),
+            ("force_majeure", "true"),
+        ]));
+        assert_eq!(outcome.status("pay-wage"), Some(&ArgumentStatus::Defeated));
+    }
+
+    #[test]
+    fn test_legalruleml_reparation_chain() {
+        let (statutes, _) = LegalRuleMLImporter::new().import(EMPLOYMENT_RULES).unwrap();
+
+        let first = statute(&statutes, "pay-wage-reparation-1");
+        assert_eq!(first.effect.effect_type, EffectType::Obligation);
+        assert_eq!(first.effect.description, "pay the wage with interest");
+        assert_eq!(
+            first.preconditions,
+            vec![
+                Condition::statute_applies("pay-wage"),
+                Condition::has_attribute("pay-wage_violated"),
+            ]
+        );
+
+        let second = statute(&statutes, "pay-wage-reparation-2");
+        assert_eq!(second.effect.description, "pay compensation");
+        assert_eq!(
+            second.preconditions,
+            vec![
+                Condition::statute_applies("pay-wage-reparation-1"),
+                Condition::has_attribute("pay-wage-reparation-1_violated"),
+            ]
+        );
+        assert_eq!(
+            second
+                .effect
+                .get_parameter(SUBORDER_PARAM)
+                .map(String::as_str),
+            Some("2")
+        );
+    }
+
+    #[test]
+    fn test_legalruleml_source_associations() {
+        let (statutes, _) = LegalRuleMLImporter::new().import(EMPLOYMENT_RULES).unwrap();
+
+        assert_eq!(
+            statute(&statutes, "pay-wage").derives_from,
+            vec!["/akn/it/act/2003/276/!main#art2".to_string()]
+        );
+        assert_eq!(
+            statute(&statutes, "withhold-wage").derives_from,
+            statute(&statutes, "pay-wage").derives_from
+        );
+        assert!(statute(&statutes, "no-dismissal").derives_from.is_empty());
+    }
+
+    #[test]
+    fn test_legalruleml_export_theory() {
+        let permit = Statute::new("permit", "Permit", Effect::grant("permit"))
+            .with_precondition(Condition::has_attribute("applicant"))
+            .with_exception(StatuteException::new(
+                "bankrupt",
+                "Bankrupt persons",
+                Condition::has_attribute("bankrupt").and(Condition::has_attribute("insolvent")),
+            ))
+            .with_derives_from("https://example.org/act/5");
+        let revoke = Statute::new("revoke", "Revoke", Effect::revoke("permit"))
+            .with_precondition(Condition::has_attribute("applicant"));
+        let theory = DefeasibleTheory::new()
+            .with_statutes([permit, revoke])
+            .strict("permit")
+            .with_priority("permit", 1)
+            .with_priority("revoke", 2);
+
+        let (output, _) = LegalRuleMLExporter::new().export_theory(&theory).unwrap();
+        assert!(output.contains(r##"<lrml:Override over="#revoke" under="#permit"/>"##));
+        assert!(output.contains(r##"<lrml:Override over="#bankrupt" under="#permit"/>"##));
+        assert!(output.contains(r#"iri="defeasible:strict""#));
+        assert!(output.contains(r#"iri="legalis:revoke""#));
+
+        let (imported, _) = LegalRuleMLImporter::new().import_theory(&output).unwrap();
+        assert_eq!(imported.statutes().len(), 2);
+        assert_eq!(imported.kind_of("permit"), RuleKind::Strict);
+        assert_eq!(imported.kind_of("revoke"), RuleKind::Defeasible);
+        assert_eq!(
+            imported.superiority(),
+            &[("revoke".to_string(), "permit".to_string())]
+        );
+
+        let permit = statute(imported.statutes(), "permit");
+        assert_eq!(permit.exceptions.len(), 1);
+        assert_eq!(
+            permit.exceptions[0].condition,
+            theory.statutes()[0].exceptions[0].condition
+        );
+        assert_eq!(permit.derives_from, vec!["https://example.org/act/5"]);
+        let revoke = statute(imported.statutes(), "revoke");
+        assert_eq!(revoke.effect.effect_type, EffectType::Revoke);
+    }
+
+    #[test]
+    fn test_legalruleml_export_reimport_preserves_statutes() {
+        let importer = LegalRuleMLImporter::new();
+        let (original, _) = importer.import(EMPLOYMENT_RULES).unwrap();
+        let (output, _) = LegalRuleMLExporter::new().export(&original).unwrap();
+        assert!(output.contains("<lrml:SuborderList>"));
+        assert!(output.contains(r##"<lrml:Bearer keyref="#employer"/>"##));
+
+        let (reimported, _) = importer.import(&output).unwrap();
+        assert_eq!(reimported.len(), original.len());
+        for before in &original {
+            let after = statute(&reimported, &before.id);
+            assert_eq!(after.title, before.title);
+            assert_eq!(after.effect, before.effect);
+            assert_eq!(after.preconditions, before.preconditions);
+            assert_eq!(after.derives_from, before.derives_from);
+            assert_eq!(after.exceptions.len(), before.exceptions.len());
+        }
+    }
+
+    #[test]
+    fn test_legalruleml_fidelity_roundtrip() {
+        let mut analyzer = FidelityAnalyzer::new();
+        let verification = analyzer
+            .verify_roundtrip(
+                EMPLOYMENT_RULES,
+                LegalFormat::LegalRuleML,
+                LegalFormat::LegalRuleML,
+            )
+            .unwrap();
+
+        assert!(verification.success);
+        assert!(verification.fidelity.is_lossless());
+        assert_eq!(verification.fidelity.statutes_preserved, 5);
+        assert!(verification.delta.is_empty());
     }
 }
@@ -26,6 +26,7 @@ pub mod bpmn;
 pub mod cache;
 pub mod cadence;
 pub mod catala;
+pub mod catala_parser;
 pub mod cicero;
 pub mod clauseio;
 pub mod cli;
@@ -43,6 +44,7 @@ mod edge_cases_tests;
 pub mod enhanced;
 pub mod error_handling;
 pub mod errors;
+pub mod feel;
 pub mod fidelity;
 pub mod finreg;
 pub mod format_detection;
@@ -64,6 +66,7 @@ pub mod niem;
 pub mod openlaw;
 pub mod optimizations;
 pub mod pdf_legal;
+pub mod pdf_text;
 pub mod performance;
 pub mod quality;
 pub mod regml;
@@ -73,6 +76,7 @@ pub mod salesforce_contract;
 pub mod sap_legal;
 pub mod sbvr;
 pub mod schema;
+pub mod segmenter;
 pub mod solidity;
 pub mod spdx;
 pub mod stipula;
@@ -1219,7 +1223,7 @@ declaration scope AdultRights:
             .export(&[statute], LegalFormat::LegalRuleML)
             .unwrap();
         assert_eq!(export_report.statutes_converted, 1);
-        assert!(lrml_output.contains("<legalruleml"));
+        assert!(lrml_output.contains("<lrml:LegalRuleML"));
         assert!(lrml_output.contains("Legal Rule Example"));
 
         // Import from LegalRuleML
@@ -1267,8 +1271,8 @@ declaration scope TaxRule:
             .unwrap();
 
         assert!(report.statutes_converted >= 1);
-        assert!(lrml_output.contains("<legalruleml"));
-        assert!(lrml_output.contains("<LegalRule"));
+        assert!(lrml_output.contains("<lrml:LegalRuleML"));
+        assert!(lrml_output.contains("<lrml:PrescriptiveStatement"));
     }
 
     #[test]
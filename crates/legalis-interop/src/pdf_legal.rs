@@ -6,15 +6,21 @@
 //! - Form fields and signature fields
 //! - Legal stamps and watermarks
 //! - Document metadata and properties
+//! - Native PDF files, whose text is extracted and segmented into articles
 //!
 //! This module provides bidirectional conversion between PDF legal annotations
-//! format and legalis_core::Statute format.
+//! format and legalis_core::Statute format. PDF files themselves are imported
+//! through [`PdfLegalImporter::import_pdf`], which runs [`PdfTextExtractor`]
+//! and a [`StatuteSegmenter`] and keeps the source offsets of every article.
 
+use crate::pdf_text::{PdfText, PdfTextExtractor};
+use crate::segmenter::{Segmentation, StatuteSegmenter};
 use crate::{
     ConversionReport, FormatExporter, FormatImporter, InteropError, InteropResult, LegalFormat,
 };
-use legalis_core::{Effect, EffectType, Statute};
+use legalis_core::{Effect, EffectType, Statute, StatuteHierarchy};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// PDF legal document structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -105,13 +111,76 @@ pub struct PdfProperties {
     pub legal_status: Option<String>,
 }
 
+/// Result of importing a PDF file
+#[derive(Debug, Clone)]
+pub struct PdfImport {
+    /// Extracted text and layout
+    pub text: PdfText,
+    /// Articles, paragraphs, footnotes and cross-references found in the text
+    pub segmentation: Segmentation,
+    /// Statute skeletons, one per article
+    pub statutes: Vec<Statute>,
+    /// Structure of the document keyed by unit ID
+    pub hierarchy: HashMap<String, StatuteHierarchy>,
+    /// Conversion report
+    pub report: ConversionReport,
+}
+
 /// PDF Legal importer
-pub struct PdfLegalImporter;
+#[derive(Debug, Clone)]
+pub struct PdfLegalImporter {
+    segmenter: StatuteSegmenter,
+}
 
 impl PdfLegalImporter {
     /// Creates a new PDF Legal importer
     pub fn new() -> Self {
-        Self
+        Self {
+            segmenter: StatuteSegmenter::new(),
+        }
+    }
+
+    /// Sets the segmenter used for PDF files, for example to fix the jurisdiction
+    pub fn with_segmenter(mut self, segmenter: StatuteSegmenter) -> Self {
+        self.segmenter = segmenter;
+        self
+    }
+
+    /// Imports a PDF file, segmenting its text into statutes
+    pub fn import_pdf(&self, data: &[u8]) -> InteropResult<PdfImport> {
+        let text = PdfTextExtractor::new().extract(data)?;
+        let segmentation = self.segmenter.segment_pdf(&text);
+        let statutes = segmentation.to_statutes();
+        let hierarchy = segmentation.hierarchy();
+
+        let mut report = ConversionReport::new(LegalFormat::PdfLegal, LegalFormat::Legalis);
+        for warning in &text.warnings {
+            report.add_warning(warning);
+        }
+        if statutes.is_empty() {
+            report.add_warning("No article numbering found in PDF text");
+        }
+        let unresolved = segmentation
+            .provisions
+            .iter()
+            .flat_map(|provision| &provision.references)
+            .filter(|reference| !reference.external && reference.target.is_none())
+            .count();
+        if unresolved > 0 {
+            report.add_warning(format!(
+                "{} cross-reference(s) could not be resolved within the document",
+                unresolved
+            ));
+        }
+        report.statutes_converted = statutes.len();
+
+        Ok(PdfImport {
+            text,
+            segmentation,
+            statutes,
+            hierarchy,
+            report,
+        })
     }
 
     fn parse_pdf_legal(&self, source: &str) -> InteropResult<PdfLegalDocument> {
@@ -200,6 +269,11 @@ impl FormatImporter for PdfLegalImporter {
     }
 
     fn import(&self, source: &str) -> InteropResult<(Vec<Statute>, ConversionReport)> {
+        if source.starts_with("%PDF-") {
+            let import = self.import_pdf(source.as_bytes())?;
+            return Ok((import.statutes, import.report));
+        }
+
         let doc = self.parse_pdf_legal(source)?;
         let mut report = ConversionReport::new(LegalFormat::PdfLegal, LegalFormat::Legalis);
         let mut statutes = Vec::new();
@@ -228,6 +302,10 @@ impl FormatImporter for PdfLegalImporter {
     }
 
     fn validate(&self, source: &str) -> bool {
+        if source.starts_with("%PDF-") {
+            return true;
+        }
+
         // Try to parse as JSON and check for PDF legal specific fields
         if let Ok(value) = serde_json::from_str::<serde_json::Value>(source)
             && let Some(obj) = value.as_object()
@@ -443,4 +521,180 @@ mod tests {
         let doc: PdfLegalDocument = serde_json::from_str(&output).unwrap();
         assert_eq!(doc.annotations.len(), 1);
     }
+
+    /// Builds an ASCII-only PDF with one page per entry, setting each line in Helvetica
+    fn text_pdf(pages: &[&[(&str, f64)]]) -> String {
+        let mut objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            String::new(),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                .to_string(),
+        ];
+        let mut kids = Vec::new();
+        for lines in pages {
+            let mut content = String::from("BT\n");
+            let mut y = 780.0;
+            for (line, size) in lines.iter() {
+                y -= size * 1.5;
+                // Latin-1 characters coincide with WinAnsiEncoding
+                let escaped: String = line
+                    .chars()
+                    .map(|c| match c {
+                        '(' | ')' | '\\' => format!("\\{}", c),
+                        c if c.is_ascii() => c.to_string(),
+                        c => format!("\\{:03o}", u32::from(c)),
+                    })
+                    .collect();
+                content.push_str(&format!(
+                    "/F1 {} Tf 1 0 0 1 72 {} Tm ({}) Tj\n",
+                    size, y, escaped
+                ));
+            }
+            content.push_str("ET");
+            let page = objects.len() + 1;
+            kids.push(format!("{} 0 R", page));
+            objects.push(format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 595 842] /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+                page + 1
+            ));
+            objects.push(format!(
+                "<< /Length {} >>\nstream\n{}\nendstream",
+                content.len(),
+                content
+            ));
+        }
+        objects[1] = format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids.join(" "),
+            kids.len()
+        );
+
+        let mut out = String::from("%PDF-1.4\n");
+        let mut offsets = Vec::new();
+        for (index, body) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            out.push_str(&format!("{} 0 obj\n{}\nendobj\n", index + 1, body));
+        }
+        let xref = out.len();
+        out.push_str(&format!(
+            "xref\n0 {}\n0000000000 65535 f \n",
+            objects.len() + 1
+        ));
+        for offset in offsets {
+            out.push_str(&format!("{:010} 00000 n \n", offset));
+        }
+        out.push_str(&format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref
+        ));
+        out
+    }
+
+    #[test]
+    fn test_pdf_import_segments_articles() {
+        let pdf = text_pdf(&[
+            &[
+                ("Regulation on Example Matters", 14.0),
+                ("Article 1", 11.0),
+                ("Subject matter", 11.0),
+                ("This Regulation lays down rules on example matters.", 11.0),
+                ("Article 2", 11.0),
+                ("Scope", 11.0),
+                ("1. It applies to the matters set out in Article 1.", 11.0),
+                ("2. It does not apply to Article 9.", 11.0),
+            ],
+            &[
+                ("Article 3", 11.0),
+                ("Entry into force", 11.0),
+                ("This Regulation enters into force on publication.", 11.0),
+            ],
+        ]);
+        let importer =
+            PdfLegalImporter::new().with_segmenter(StatuteSegmenter::new().with_jurisdiction("EU"));
+        assert!(importer.validate(&pdf));
+
+        let import = importer.import_pdf(pdf.as_bytes()).unwrap();
+        let ids: Vec<&str> = import.statutes.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["art-1", "art-2", "art-3"]);
+        assert_eq!(import.report.statutes_converted, 3);
+        assert_eq!(
+            import.segmentation.title.as_deref(),
+            Some("Regulation on Example Matters")
+        );
+
+        let statute = &import.statutes[2];
+        assert_eq!(statute.title, "Article 3 Entry into force");
+        assert_eq!(statute.jurisdiction.as_deref(), Some("EU"));
+        assert_eq!(
+            statute.effect.description,
+            "This Regulation enters into force on publication."
+        );
+        assert_eq!(
+            statute
+                .effect
+                .get_parameter("source_page")
+                .map(String::as_str),
+            Some("2")
+        );
+        let start: usize = statute
+            .effect
+            .get_parameter("source_start")
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(import.text.text()[start..].starts_with("Article 3"));
+
+        assert_eq!(import.hierarchy["art-2"].cross_references, vec!["art-1"]);
+        assert!(
+            import
+                .report
+                .warnings
+                .iter()
+                .any(|warning| warning.contains("1 cross-reference(s)"))
+        );
+    }
+
+    #[test]
+    fn test_pdf_import_through_format_importer() {
+        let pdf = text_pdf(&[&[
+            ("§ 1 Geltungsbereich", 11.0),
+            ("(1) Dieses Gesetz gilt für alle Beispiele.", 11.0),
+            ("(2) Es gilt auch für Gegenbeispiele.", 11.0),
+            ("§ 2 Inkrafttreten", 11.0),
+            (
+                "Dieses Gesetz tritt am Tag nach der Verkündung in Kraft.",
+                11.0,
+            ),
+        ]]);
+        let importer =
+            PdfLegalImporter::new().with_segmenter(StatuteSegmenter::new().with_jurisdiction("DE"));
+
+        let (statutes, report) = importer.import(&pdf).unwrap();
+        assert_eq!(statutes.len(), 2);
+        assert_eq!(report.statutes_converted, 2);
+        assert_eq!(statutes[0].title, "§ 1 Geltungsbereich");
+        assert!(
+            statutes[0]
+                .effect
+                .description
+                .starts_with("(1) Dieses Gesetz")
+        );
+    }
+
+    #[test]
+    fn test_pdf_import_without_articles_and_invalid_data() {
+        let pdf = text_pdf(&[&[("Notice of publication", 11.0)]]);
+        let import = PdfLegalImporter::new().import_pdf(pdf.as_bytes()).unwrap();
+        assert!(import.statutes.is_empty());
+        assert!(
+            import
+                .report
+                .warnings
+                .iter()
+                .any(|warning| warning.contains("No article numbering"))
+        );
+
+        assert!(PdfLegalImporter::new().import_pdf(b"not a pdf").is_err());
+    }
 }
@@ -0,0 +1,2958 @@
+//! Native PDF text extraction.
+//!
+//! Recovers the text layer of a PDF file, together with its layout, in pure Rust:
+//! - Objects are located by scanning the file, which tolerates damaged
+//!   cross-reference tables and incremental updates; object streams are expanded
+//! - Streams are decoded (Flate, LZW, ASCIIHex, ASCII85, RunLength, PNG predictors)
+//! - Page content streams and form XObjects are interpreted to position every glyph
+//! - Glyphs are mapped to Unicode through `ToUnicode` CMaps, predefined UCS-2 CMaps
+//!   or simple font encodings with `Differences`
+//! - Glyphs are grouped into lines, and lines are put in reading order so that
+//!   multi-column layouts and vertical writing read naturally
+//!
+//! Encrypted files are rejected. Pages without a text layer, such as scans that
+//! were never OCRed, are reported in [`PdfText::warnings`].
+
+use crate::{InteropError, InteropResult};
+use flate2::read::{DeflateDecoder, ZlibDecoder};
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::f64::consts::FRAC_PI_2;
+use std::io::Read;
+use std::rc::Rc;
+
+/// Text extracted from a PDF file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PdfText {
+    /// Document information dictionary entries (Title, Author, ...)
+    pub info: BTreeMap<String, String>,
+    /// Pages in document order
+    pub pages: Vec<PdfPageText>,
+    /// Problems met during extraction
+    pub warnings: Vec<String>,
+}
+
+impl PdfText {
+    /// Returns the text of all pages; lines are separated by `\n` and pages by `\x0c`.
+    pub fn text(&self) -> String {
+        self.pages
+            .iter()
+            .map(PdfPageText::text)
+            .collect::<Vec<_>>()
+            .join("\x0c")
+    }
+
+    /// Returns the document title from the information dictionary.
+    pub fn title(&self) -> Option<&str> {
+        self.info
+            .get("Title")
+            .map(|title| title.trim())
+            .filter(|title| !title.is_empty())
+    }
+}
+
+/// Text of one page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PdfPageText {
+    /// Page number, starting at 1
+    pub number: usize,
+    /// Page width in points, in the reading direction
+    pub width: f64,
+    /// Page height in points, in the reading direction
+    pub height: f64,
+    /// Lines in reading order
+    pub lines: Vec<PdfTextLine>,
+}
+
+impl PdfPageText {
+    /// Returns the lines of the page separated by `\n`.
+    pub fn text(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| line.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// A line of text with its position on the page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PdfTextLine {
+    /// Line text
+    pub text: String,
+    /// Distance from the left edge of the page, in points
+    pub x: f64,
+    /// Distance of the baseline from the top of the page, in points
+    pub y: f64,
+    /// Line width in points
+    pub width: f64,
+    /// Typical font size of the line, in points
+    pub font_size: f64,
+}
+
+/// Extracts text from PDF files.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PdfTextExtractor;
+
+impl PdfTextExtractor {
+    /// Creates a new extractor.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Extracts the text of every page of a PDF file.
+    pub fn extract(&self, data: &[u8]) -> InteropResult<PdfText> {
+        let document = Document::parse(data)?;
+        if document.trailer.contains_key("Encrypt") {
+            return Err(InteropError::UnsupportedFeature(
+                "Encrypted PDF files are not supported".to_string(),
+            ));
+        }
+
+        let pages = document.pages();
+        if pages.is_empty() {
+            return Err(InteropError::ParseError("PDF has no pages".to_string()));
+        }
+
+        let mut warnings = Vec::new();
+        let mut interpreter = Interpreter::new(&document);
+        let mut result = Vec::with_capacity(pages.len());
+        for (index, page) in pages.iter().enumerate() {
+            let number = index + 1;
+            let content = document.page_content(page.dict, number, &mut warnings);
+            interpreter.glyphs.clear();
+            interpreter.images = 0;
+            interpreter.run(&content, page.resources, GraphicsState::default());
+
+            let glyphs = std::mem::take(&mut interpreter.glyphs);
+            if glyphs.is_empty() && interpreter.images > 0 {
+                warnings.push(format!(
+                    "Page {} has no text layer; it may be a scanned image that needs OCR",
+                    number
+                ));
+            }
+            let layout = layout_page(glyphs, page.media_box);
+            if layout.skipped > 0 {
+                warnings.push(format!(
+                    "Page {}: skipped {} glyphs written in a different direction",
+                    number, layout.skipped
+                ));
+            }
+            result.push(PdfPageText {
+                number,
+                width: layout.width,
+                height: layout.height,
+                lines: layout.lines,
+            });
+        }
+
+        let truncated = document.truncated_streams.get();
+        if truncated > 0 {
+            warnings.push(format!(
+                "{} compressed stream(s) exceed {} MB when decompressed and were truncated",
+                truncated,
+                MAX_INFLATED_SIZE / (1024 * 1024)
+            ));
+        }
+
+        let mut unmapped: Vec<_> = interpreter.unmapped.into_iter().collect();
+        unmapped.sort();
+        for font in unmapped {
+            warnings.push(format!(
+                "Font {} has no Unicode mapping; some characters could not be decoded",
+                font
+            ));
+        }
+
+        Ok(PdfText {
+            info: document.info(),
+            pages: result,
+            warnings,
+        })
+    }
+}
+
+// ============================================================================
+// Objects and lexer
+// ============================================================================
+
+type Dictionary = HashMap<String, Object>;
+type Matrix = [f64; 6];
+
+const IDENTITY: Matrix = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+const DEFAULT_MEDIA_BOX: [f64; 4] = [0.0, 0.0, 612.0, 792.0];
+
+/// A PDF object; generation numbers are ignored.
+#[derive(Debug, Clone, PartialEq)]
+enum Object {
+    Null,
+    Boolean(bool),
+    Integer(i64),
+    Real(f64),
+    Name(String),
+    String(Vec<u8>),
+    Array(Vec<Object>),
+    Dictionary(Dictionary),
+    Stream(Stream),
+    Reference(u32),
+    /// Bare keyword, such as a content stream operator
+    Keyword(String),
+}
+
+static NULL: Object = Object::Null;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Stream {
+    dict: Dictionary,
+    data: Vec<u8>,
+}
+
+impl Object {
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Object::Integer(value) => Some(*value as f64),
+            Object::Real(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn as_i64(&self) -> Option<i64> {
+        match self {
+            Object::Integer(value) => Some(*value),
+            Object::Real(value) => Some(*value as i64),
+            _ => None,
+        }
+    }
+
+    fn as_name(&self) -> Option<&str> {
+        match self {
+            Object::Name(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&[Object]> {
+        match self {
+            Object::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    fn as_dict(&self) -> Option<&Dictionary> {
+        match self {
+            Object::Dictionary(dict) => Some(dict),
+            Object::Stream(stream) => Some(&stream.dict),
+            _ => None,
+        }
+    }
+}
+
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b'\0' | b'\t' | b'\n' | b'\x0c' | b'\r' | b' ')
+}
+
+fn is_delimiter(byte: u8) -> bool {
+    matches!(
+        byte,
+        b'(' | b')' | b'<' | b'>' | b'[' | b']' | b'{' | b'}' | b'/' | b'%'
+    )
+}
+
+fn find(data: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    if from >= data.len() || needle.is_empty() {
+        return None;
+    }
+    data[from..]
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|position| position + from)
+}
+
+struct Lexer<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(data: &'a [u8], pos: usize) -> Self {
+        Self { data, pos }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.pos).copied()
+    }
+
+    fn starts_with(&self, prefix: &[u8]) -> bool {
+        self.data
+            .get(self.pos..)
+            .is_some_and(|rest| rest.starts_with(prefix))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(byte) = self.peek() {
+            if is_whitespace(byte) {
+                self.pos += 1;
+            } else if byte == b'%' {
+                while let Some(byte) = self.peek() {
+                    if byte == b'\r' || byte == b'\n' {
+                        break;
+                    }
+                    self.pos += 1;
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Reads the next object, or `None` at the end of the data.
+    fn next_object(&mut self) -> Option<Object> {
+        self.skip_whitespace();
+        let byte = self.peek()?;
+        let object = match byte {
+            b'/' => Object::Name(self.read_name()),
+            b'(' => Object::String(self.read_literal()),
+            b'<' if self.starts_with(b"<<") => {
+                self.pos += 2;
+                self.read_dictionary()
+            }
+            b'<' => Object::String(self.read_hex()),
+            b'>' if self.starts_with(b">>") => {
+                self.pos += 2;
+                Object::Keyword(">>".to_string())
+            }
+            b'[' => {
+                self.pos += 1;
+                self.read_array()
+            }
+            b'0'..=b'9' | b'+' | b'-' | b'.' => self.read_number(),
+            b')' | b'>' | b']' | b'{' | b'}' => {
+                self.pos += 1;
+                Object::Keyword((byte as char).to_string())
+            }
+            _ => {
+                let word = self.read_word();
+                match word.as_str() {
+                    "true" => Object::Boolean(true),
+                    "false" => Object::Boolean(false),
+                    "null" => Object::Null,
+                    _ => Object::Keyword(word),
+                }
+            }
+        };
+        Some(object)
+    }
+
+    fn read_word(&mut self) -> String {
+        let start = self.pos;
+        while let Some(byte) = self.peek() {
+            if is_whitespace(byte) || is_delimiter(byte) {
+                break;
+            }
+            self.pos += 1;
+        }
+        if self.pos == start {
+            self.pos += 1;
+        }
+        String::from_utf8_lossy(&self.data[start..self.pos.min(self.data.len())]).into_owned()
+    }
+
+    fn read_name(&mut self) -> String {
+        self.pos += 1;
+        let mut bytes = Vec::new();
+        while let Some(byte) = self.peek() {
+            if is_whitespace(byte) || is_delimiter(byte) {
+                break;
+            }
+            if byte == b'#'
+                && let Some(decoded) = self
+                    .data
+                    .get(self.pos + 1..self.pos + 3)
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                bytes.push(decoded);
+                self.pos += 3;
+                continue;
+            }
+            bytes.push(byte);
+            self.pos += 1;
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    }
+
+    fn read_literal(&mut self) -> Vec<u8> {
+        self.pos += 1;
+        let mut bytes = Vec::new();
+        let mut depth = 1;
+        while let Some(byte) = self.peek() {
+            self.pos += 1;
+            match byte {
+                b'\\' => {
+                    let Some(escaped) = self.peek() else { break };
+                    self.pos += 1;
+                    match escaped {
+                        b'n' => bytes.push(b'\n'),
+                        b'r' => bytes.push(b'\r'),
+                        b't' => bytes.push(b'\t'),
+                        b'b' => bytes.push(0x08),
+                        b'f' => bytes.push(0x0c),
+                        b'0'..=b'7' => {
+                            let mut value = u32::from(escaped - b'0');
+                            for _ in 0..2 {
+                                match self.peek() {
+                                    Some(digit @ b'0'..=b'7') => {
+                                        value = value * 8 + u32::from(digit - b'0');
+                                        self.pos += 1;
+                                    }
+                                    _ => break,
+                                }
+                            }
+                            bytes.push((value & 0xff) as u8);
+                        }
+                        // Line continuation
+                        b'\r' => {
+                            if self.peek() == Some(b'\n') {
+                                self.pos += 1;
+                            }
+                        }
+                        b'\n' => {}
+                        other => bytes.push(other),
+                    }
+                }
+                b'(' => {
+                    depth += 1;
+                    bytes.push(byte);
+                }
+                b')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                    bytes.push(byte);
+                }
+                b'\r' => {
+                    if self.peek() == Some(b'\n') {
+                        self.pos += 1;
+                    }
+                    bytes.push(b'\n');
+                }
+                _ => bytes.push(byte),
+            }
+        }
+        bytes
+    }
+
+    fn read_hex(&mut self) -> Vec<u8> {
+        self.pos += 1;
+        let mut digits = Vec::new();
+        while let Some(byte) = self.peek() {
+            self.pos += 1;
+            if byte == b'>' {
+                break;
+            }
+            if byte.is_ascii_hexdigit() {
+                digits.push(byte);
+            }
+        }
+        if digits.len() % 2 == 1 {
+            digits.push(b'0');
+        }
+        digits
+            .chunks(2)
+            .map(|pair| {
+                let text = std::str::from_utf8(pair).unwrap_or("00");
+                u8::from_str_radix(text, 16).unwrap_or(0)
+            })
+            .collect()
+    }
+
+    fn read_array(&mut self) -> Object {
+        let mut items = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None => break,
+                Some(b']') => {
+                    self.pos += 1;
+                    break;
+                }
+                _ => {
+                    let start = self.pos;
+                    match self.next_object() {
+                        Some(Object::Keyword(keyword))
+                            if matches!(keyword.as_str(), ">>" | "endobj" | "stream") =>
+                        {
+                            self.pos = start;
+                            break;
+                        }
+                        Some(object) => items.push(object),
+                        None => break,
+                    }
+                }
+            }
+        }
+        Object::Array(items)
+    }
+
+    fn read_dictionary(&mut self) -> Object {
+        let mut dict = Dictionary::new();
+        loop {
+            let start = self.pos;
+            match self.next_object() {
+                None => break,
+                Some(Object::Keyword(keyword)) if keyword == ">>" => break,
+                Some(Object::Keyword(keyword)) if keyword == "endobj" || keyword == "stream" => {
+                    self.pos = start;
+                    break;
+                }
+                Some(Object::Name(key)) => match self.next_object() {
+                    Some(Object::Keyword(keyword)) if keyword == ">>" => break,
+                    Some(value) => {
+                        dict.insert(key, value);
+                    }
+                    None => break,
+                },
+                Some(_) => {}
+            }
+        }
+        Object::Dictionary(dict)
+    }
+
+    fn read_number(&mut self) -> Object {
+        let start = self.pos;
+        while let Some(byte) = self.peek() {
+            if byte.is_ascii_digit() || matches!(byte, b'+' | b'-' | b'.') {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        let token = std::str::from_utf8(&self.data[start..self.pos]).unwrap_or("0");
+        let Some(value) = token.parse::<i64>().ok().filter(|_| !token.contains('.')) else {
+            return Object::Real(parse_real(token));
+        };
+
+        // "num gen R" is an indirect reference
+        if value >= 0 && !token.starts_with(['+', '-']) {
+            let save = self.pos;
+            self.skip_whitespace();
+            let generation = self.pos;
+            while self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+                self.pos += 1;
+            }
+            if self.pos > generation {
+                self.skip_whitespace();
+                if self.peek() == Some(b'R')
+                    && self
+                        .data
+                        .get(self.pos + 1)
+                        .is_none_or(|byte| is_whitespace(*byte) || is_delimiter(*byte))
+                    && let Ok(number) = u32::try_from(value)
+                {
+                    self.pos += 1;
+                    return Object::Reference(number);
+                }
+            }
+            self.pos = save;
+        }
+        Object::Integer(value)
+    }
+
+    /// Skips the data of an inline image, after its `ID` operator.
+    fn skip_inline_image(&mut self) {
+        let mut from = self.pos + 1;
+        while let Some(found) = find(self.data, b"EI", from) {
+            let before = found == 0 || is_whitespace(self.data[found - 1]);
+            let after = self
+                .data
+                .get(found + 2)
+                .is_none_or(|byte| is_whitespace(*byte) || is_delimiter(*byte));
+            if before && after {
+                self.pos = found + 2;
+                return;
+            }
+            from = found + 2;
+        }
+        self.pos = self.data.len();
+    }
+}
+
+/// Parses a malformed real number such as `--1` or `1.2.3` as leniently as readers do.
+fn parse_real(token: &str) -> f64 {
+    let negative = token.starts_with('-');
+    let mut cleaned = String::new();
+    let mut seen_point = false;
+    for c in token.chars() {
+        if c.is_ascii_digit() {
+            cleaned.push(c);
+        } else if c == '.' && !seen_point {
+            seen_point = true;
+            cleaned.push(c);
+        }
+    }
+    let value = cleaned.parse::<f64>().unwrap_or(0.0);
+    if negative { -value } else { value }
+}
+
+fn multiply(m: &Matrix, n: &Matrix) -> Matrix {
+    [
+        m[0] * n[0] + m[1] * n[2],
+        m[0] * n[1] + m[1] * n[3],
+        m[2] * n[0] + m[3] * n[2],
+        m[2] * n[1] + m[3] * n[3],
+        m[4] * n[0] + m[5] * n[2] + n[4],
+        m[4] * n[1] + m[5] * n[3] + n[5],
+    ]
+}
+
+fn translate(tx: f64, ty: f64) -> Matrix {
+    [1.0, 0.0, 0.0, 1.0, tx, ty]
+}
+
+/// Decodes a PDF text string (UTF-16BE with BOM, UTF-8 with BOM, or PDFDocEncoding).
+fn decode_text_string(bytes: &[u8]) -> String {
+    if let Some(utf16) = bytes.strip_prefix(&[0xfe, 0xff]) {
+        let units: Vec<u16> = utf16
+            .chunks(2)
+            .map(|pair| u16::from_be_bytes([pair[0], *pair.get(1).unwrap_or(&0)]))
+            .collect();
+        return String::from_utf16_lossy(&units);
+    }
+    if let Some(utf8) = bytes.strip_prefix(&[0xef, 0xbb, 0xbf]) {
+        return String::from_utf8_lossy(utf8).into_owned();
+    }
+    bytes
+        .iter()
+        .map(|&byte| match byte {
+            0x80..=0x9f => WIN_ANSI_HIGH[usize::from(byte - 0x80)].unwrap_or('\u{fffd}'),
+            _ => char::from(byte),
+        })
+        .collect()
+}
+
+// ============================================================================
+// Document structure
+// ============================================================================
+
+struct Document {
+    objects: HashMap<u32, Object>,
+    trailer: Dictionary,
+    /// Streams cut off at [`MAX_INFLATED_SIZE`]
+    truncated_streams: Cell<usize>,
+}
+
+struct PageRef<'a> {
+    dict: &'a Dictionary,
+    resources: Option<&'a Dictionary>,
+    media_box: [f64; 4],
+}
+
+impl Document {
+    fn parse(data: &[u8]) -> InteropResult<Self> {
+        if find(data, b"%PDF-", 0).is_none_or(|position| position > 1024) {
+            return Err(InteropError::ParseError(
+                "Not a PDF file: missing %PDF- header".to_string(),
+            ));
+        }
+
+        // Scan for "N G obj"; later definitions of an object win, as in
+        // incrementally updated files
+        let mut objects = HashMap::new();
+        let mut positions = HashMap::new();
+        let mut pos = 0;
+        while let Some(found) = find(data, b"obj", pos) {
+            pos = found + 3;
+            if data
+                .get(found + 3)
+                .is_some_and(|byte| !is_whitespace(*byte) && !is_delimiter(*byte))
+            {
+                continue;
+            }
+            let Some(number) = object_number(data, found) else {
+                continue;
+            };
+            let mut lexer = Lexer::new(data, found + 3);
+            let Some(mut object) = lexer.next_object() else {
+                continue;
+            };
+            if let Object::Dictionary(dict) = &mut object {
+                let save = lexer.pos;
+                lexer.skip_whitespace();
+                if lexer.starts_with(b"stream") {
+                    let dict = std::mem::take(dict);
+                    let (stream_data, end) = read_stream_data(data, lexer.pos + 6, &dict);
+                    object = Object::Stream(Stream {
+                        dict,
+                        data: stream_data,
+                    });
+                    lexer.pos = end;
+                } else {
+                    lexer.pos = save;
+                }
+            }
+            pos = pos.max(lexer.pos);
+            objects.insert(number, object);
+            positions.insert(number, found);
+        }
+
+        let mut trailer = Dictionary::new();
+        let mut pos = 0;
+        while let Some(found) = find(data, b"trailer", pos) {
+            pos = found + 7;
+            if let Some(Object::Dictionary(dict)) = Lexer::new(data, pos).next_object() {
+                trailer.extend(dict);
+            }
+        }
+
+        let mut document = Document {
+            objects,
+            trailer,
+            truncated_streams: Cell::new(0),
+        };
+        document.expand_object_streams(&mut positions);
+
+        // Cross-reference streams replace the trailer in compressed files
+        let mut xref_streams: Vec<(usize, &Dictionary)> = document
+            .objects
+            .iter()
+            .filter_map(|(number, object)| match object {
+                Object::Stream(stream)
+                    if stream.dict.get("Type").and_then(Object::as_name) == Some("XRef") =>
+                {
+                    Some((positions.get(number).copied().unwrap_or(0), &stream.dict))
+                }
+                _ => None,
+            })
+            .collect();
+        xref_streams.sort_by_key(|(position, _)| *position);
+        let mut trailer_entries = Vec::new();
+        for (_, dict) in xref_streams {
+            for key in ["Root", "Info", "Encrypt"] {
+                if let Some(value) = dict.get(key) {
+                    trailer_entries.push((key.to_string(), value.clone()));
+                }
+            }
+        }
+        document.trailer.extend(trailer_entries);
+
+        if !document.trailer.contains_key("Root") {
+            let mut catalogs: Vec<u32> = document
+                .objects
+                .iter()
+                .filter(|(_, object)| {
+                    object
+                        .as_dict()
+                        .and_then(|dict| dict.get("Type"))
+                        .and_then(Object::as_name)
+                        == Some("Catalog")
+                })
+                .map(|(number, _)| *number)
+                .collect();
+            catalogs.sort_unstable();
+            if let Some(number) = catalogs.last() {
+                document
+                    .trailer
+                    .insert("Root".to_string(), Object::Reference(*number));
+            }
+        }
+        Ok(document)
+    }
+
+    fn expand_object_streams(&mut self, positions: &mut HashMap<u32, usize>) {
+        let mut compressed = Vec::new();
+        for (number, object) in &self.objects {
+            let Object::Stream(stream) = object else {
+                continue;
+            };
+            if stream.dict.get("Type").and_then(Object::as_name) != Some("ObjStm") {
+                continue;
+            }
+            let Ok(decoded) = self.decode_stream(stream) else {
+                continue;
+            };
+            let position = positions.get(number).copied().unwrap_or(0);
+            let first = self.get_i64(&stream.dict, "First").unwrap_or(0).max(0) as usize;
+            let count = self.get_i64(&stream.dict, "N").unwrap_or(0).max(0);
+            let mut header = Lexer::new(&decoded, 0);
+            for _ in 0..count {
+                let (Some(Object::Integer(inner)), Some(Object::Integer(offset))) =
+                    (header.next_object(), header.next_object())
+                else {
+                    break;
+                };
+                let (Ok(inner), Ok(offset)) = (u32::try_from(inner), usize::try_from(offset))
+                else {
+                    continue;
+                };
+                if let Some(object) = Lexer::new(&decoded, first + offset).next_object() {
+                    compressed.push((inner, position, object));
+                }
+            }
+        }
+        for (number, position, object) in compressed {
+            if positions
+                .get(&number)
+                .is_none_or(|&existing| existing < position)
+            {
+                self.objects.insert(number, object);
+                positions.insert(number, position);
+            }
+        }
+    }
+
+    fn resolve<'a>(&'a self, object: &'a Object) -> &'a Object {
+        let mut current = object;
+        for _ in 0..32 {
+            match current {
+                Object::Reference(number) => {
+                    current = self.objects.get(number).unwrap_or(&NULL);
+                }
+                _ => return current,
+            }
+        }
+        &NULL
+    }
+
+    fn get<'a>(&'a self, dict: &'a Dictionary, key: &str) -> Option<&'a Object> {
+        dict.get(key)
+            .map(|object| self.resolve(object))
+            .filter(|object| !matches!(object, Object::Null))
+    }
+
+    fn get_f64(&self, dict: &Dictionary, key: &str) -> Option<f64> {
+        self.get(dict, key).and_then(Object::as_f64)
+    }
+
+    fn get_i64(&self, dict: &Dictionary, key: &str) -> Option<i64> {
+        self.get(dict, key).and_then(Object::as_i64)
+    }
+
+    fn get_dict<'a>(&'a self, dict: &'a Dictionary, key: &str) -> Option<&'a Dictionary> {
+        self.get(dict, key).and_then(Object::as_dict)
+    }
+
+    fn numbers(&self, object: &Object) -> Vec<f64> {
+        self.resolve(object)
+            .as_array()
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| self.resolve(item).as_f64())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn rectangle(&self, object: &Object) -> Option<[f64; 4]> {
+        match self.numbers(object)[..] {
+            [x0, y0, x1, y1] => Some([x0.min(x1), y0.min(y1), x0.max(x1), y0.max(y1)]),
+            _ => None,
+        }
+    }
+
+    fn info(&self) -> BTreeMap<String, String> {
+        let Some(info) = self.get_dict(&self.trailer, "Info") else {
+            return BTreeMap::new();
+        };
+        info.iter()
+            .filter_map(|(key, value)| match self.resolve(value) {
+                Object::String(bytes) => Some((key.clone(), decode_text_string(bytes))),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn pages(&self) -> Vec<PageRef<'_>> {
+        let mut pages = Vec::new();
+        if let Some(tree) = self
+            .get_dict(&self.trailer, "Root")
+            .and_then(|root| root.get("Pages"))
+        {
+            let mut visited = HashSet::new();
+            self.collect_pages(tree, None, DEFAULT_MEDIA_BOX, &mut visited, &mut pages, 0);
+        }
+
+        if pages.is_empty() {
+            // Broken page tree: take every page object in object order
+            let mut numbers: Vec<&u32> = self
+                .objects
+                .iter()
+                .filter(|(_, object)| {
+                    object
+                        .as_dict()
+                        .and_then(|dict| dict.get("Type"))
+                        .and_then(Object::as_name)
+                        == Some("Page")
+                })
+                .map(|(number, _)| number)
+                .collect();
+            numbers.sort_unstable();
+            for number in numbers {
+                if let Some(dict) = self.objects.get(number).and_then(Object::as_dict) {
+                    pages.push(PageRef {
+                        dict,
+                        resources: self.get_dict(dict, "Resources"),
+                        media_box: dict
+                            .get("MediaBox")
+                            .and_then(|media_box| self.rectangle(media_box))
+                            .unwrap_or(DEFAULT_MEDIA_BOX),
+                    });
+                }
+            }
+        }
+        pages
+    }
+
+    fn collect_pages<'a>(
+        &'a self,
+        node: &'a Object,
+        resources: Option<&'a Dictionary>,
+        media_box: [f64; 4],
+        visited: &mut HashSet<u32>,
+        pages: &mut Vec<PageRef<'a>>,
+        depth: usize,
+    ) {
+        if depth > 64 {
+            return;
+        }
+        if let Object::Reference(number) = node
+            && !visited.insert(*number)
+        {
+            return;
+        }
+        let Some(dict) = self.resolve(node).as_dict() else {
+            return;
+        };
+        let resources = self.get_dict(dict, "Resources").or(resources);
+        let media_box = dict
+            .get("MediaBox")
+            .and_then(|media_box| self.rectangle(media_box))
+            .unwrap_or(media_box);
+        match self.get(dict, "Kids").and_then(Object::as_array) {
+            Some(kids) => {
+                for kid in kids {
+                    self.collect_pages(kid, resources, media_box, visited, pages, depth + 1);
+                }
+            }
+            None => pages.push(PageRef {
+                dict,
+                resources,
+                media_box,
+            }),
+        }
+    }
+
+    fn page_content(
+        &self,
+        page: &Dictionary,
+        number: usize,
+        warnings: &mut Vec<String>,
+    ) -> Vec<u8> {
+        let parts: Vec<&Object> = match page.get("Contents") {
+            Some(contents) => match self.resolve(contents) {
+                Object::Array(items) => items.iter().collect(),
+                _ => vec![contents],
+            },
+            None => Vec::new(),
+        };
+        let mut content = Vec::new();
+        for part in parts {
+            if let Object::Stream(stream) = self.resolve(part) {
+                match self.decode_stream(stream) {
+                    Ok(data) => {
+                        content.extend(data);
+                        content.push(b'\n');
+                    }
+                    Err(error) => warnings.push(format!("Page {}: {}", number, error)),
+                }
+            }
+        }
+        content
+    }
+
+    fn decode_stream(&self, stream: &Stream) -> Result<Vec<u8>, String> {
+        let filters: Vec<&str> = match self.get(&stream.dict, "Filter") {
+            Some(Object::Name(name)) => vec![name.as_str()],
+            Some(Object::Array(items)) => items
+                .iter()
+                .filter_map(|item| self.resolve(item).as_name())
+                .collect(),
+            _ => Vec::new(),
+        };
+        let params: Vec<Option<&Dictionary>> = match self
+            .get(&stream.dict, "DecodeParms")
+            .or_else(|| self.get(&stream.dict, "DP"))
+        {
+            Some(Object::Dictionary(dict)) => vec![Some(dict)],
+            Some(Object::Array(items)) => items
+                .iter()
+                .map(|item| self.resolve(item).as_dict())
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        let mut data = stream.data.clone();
+        for (index, filter) in filters.iter().enumerate() {
+            let params = params.get(index).copied().flatten();
+            data = match *filter {
+                "FlateDecode" | "Fl" => {
+                    let (inflated, truncated) = inflate(&data, MAX_INFLATED_SIZE)?;
+                    if truncated {
+                        self.truncated_streams.set(self.truncated_streams.get() + 1);
+                    }
+                    self.predict(inflated, params)
+                }
+                "LZWDecode" | "LZW" => {
+                    let early_change = params
+                        .and_then(|params| self.get_i64(params, "EarlyChange"))
+                        .unwrap_or(1);
+                    self.predict(lzw_decode(&data, early_change != 0), params)
+                }
+                "ASCIIHexDecode" | "AHx" => ascii_hex_decode(&data),
+                "ASCII85Decode" | "A85" => ascii85_decode(&data),
+                "RunLengthDecode" | "RL" => run_length_decode(&data),
+                other => return Err(format!("unsupported stream filter {}", other)),
+            };
+        }
+        Ok(data)
+    }
+
+    fn predict(&self, data: Vec<u8>, params: Option<&Dictionary>) -> Vec<u8> {
+        let Some(params) = params else { return data };
+        let predictor = self.get_i64(params, "Predictor").unwrap_or(1);
+        if predictor < 2 {
+            return data;
+        }
+        let colors = self.get_i64(params, "Colors").unwrap_or(1).clamp(1, 32) as usize;
+        let bits = self
+            .get_i64(params, "BitsPerComponent")
+            .unwrap_or(8)
+            .clamp(1, 16) as usize;
+        let columns = self
+            .get_i64(params, "Columns")
+            .unwrap_or(1)
+            .clamp(1, 1 << 20) as usize;
+        png_predict(&data, predictor, colors, bits, columns)
+    }
+
+    fn load_font(&self, dict: &Dictionary) -> Font {
+        let subtype = self
+            .get(dict, "Subtype")
+            .and_then(Object::as_name)
+            .unwrap_or("");
+        let mut font = Font {
+            name: self
+                .get(dict, "BaseFont")
+                .and_then(Object::as_name)
+                .unwrap_or("unnamed")
+                .to_string(),
+            width_scale: 0.001,
+            vertical_advance: -1000.0,
+            ..Font::default()
+        };
+
+        let mut unicode_codespace = Vec::new();
+        if let Some(Object::Stream(stream)) = self.get(dict, "ToUnicode")
+            && let Ok(data) = self.decode_stream(stream)
+        {
+            let cmap = parse_cmap(&data);
+            font.to_unicode = cmap.unicode;
+            unicode_codespace = cmap.codespace;
+        }
+
+        if subtype == "Type0" {
+            match self.get(dict, "Encoding") {
+                Some(Object::Name(name)) => {
+                    font.vertical = name.ends_with("-V");
+                    font.unicode_codes = name.contains("UCS2") || name.contains("UTF16");
+                    if name.starts_with("Identity") || font.unicode_codes {
+                        font.codespace = vec![CodespaceRange::two_byte()];
+                    }
+                }
+                Some(Object::Stream(stream)) => {
+                    if let Ok(data) = self.decode_stream(stream) {
+                        let cmap = parse_cmap(&data);
+                        font.codespace = cmap.codespace;
+                        font.cids = cmap.cids;
+                        font.vertical = cmap.vertical;
+                    }
+                }
+                _ => {}
+            }
+            if font.codespace.is_empty() {
+                font.codespace = if unicode_codespace.is_empty() {
+                    vec![CodespaceRange::two_byte()]
+                } else {
+                    unicode_codespace
+                };
+            }
+
+            font.default_width = 1000.0;
+            if let Some(descendant) = self
+                .get(dict, "DescendantFonts")
+                .and_then(Object::as_array)
+                .and_then(|fonts| fonts.first())
+                .and_then(|first| self.resolve(first).as_dict())
+            {
+                font.default_width = self.get_f64(descendant, "DW").unwrap_or(1000.0);
+                if let Some(widths) = self.get(descendant, "W").and_then(Object::as_array) {
+                    font.widths = self.cid_widths(widths);
+                }
+                if let Some(advance) = descendant
+                    .get("DW2")
+                    .map(|dw2| self.numbers(dw2))
+                    .and_then(|dw2| dw2.get(1).copied())
+                {
+                    font.vertical_advance = advance;
+                }
+            }
+        } else {
+            font.encoding = self.simple_encoding(dict, subtype);
+            let first_char = self.get_i64(dict, "FirstChar").unwrap_or(0).max(0) as u32;
+            if let Some(widths) = dict.get("Widths") {
+                for (offset, width) in self.numbers(widths).into_iter().enumerate() {
+                    font.widths.insert(first_char + offset as u32, width);
+                }
+            }
+            font.default_width = self
+                .get_dict(dict, "FontDescriptor")
+                .and_then(|descriptor| self.get_f64(descriptor, "MissingWidth"))
+                .filter(|width| *width > 0.0)
+                .unwrap_or(if font.name.contains("Courier") {
+                    600.0
+                } else {
+                    500.0
+                });
+            if subtype == "Type3"
+                && let Some(scale) = dict
+                    .get("FontMatrix")
+                    .map(|matrix| self.numbers(matrix))
+                    .and_then(|matrix| matrix.first().copied())
+            {
+                font.width_scale = scale;
+            }
+        }
+        font
+    }
+
+    fn simple_encoding(&self, dict: &Dictionary, subtype: &str) -> Vec<Option<String>> {
+        let (base, differences) = match self.get(dict, "Encoding") {
+            Some(Object::Name(name)) => (Some(name.as_str()), None),
+            Some(Object::Dictionary(encoding)) => (
+                self.get(encoding, "BaseEncoding").and_then(Object::as_name),
+                self.get(encoding, "Differences").and_then(Object::as_array),
+            ),
+            _ => (None, None),
+        };
+        let default = if subtype == "TrueType" {
+            "WinAnsiEncoding"
+        } else {
+            "StandardEncoding"
+        };
+        let mut table = base_encoding(base.unwrap_or(default));
+        if let Some(differences) = differences {
+            let mut code = 0usize;
+            for item in differences {
+                match self.resolve(item) {
+                    Object::Integer(value) => code = (*value).max(0) as usize,
+                    Object::Name(name) => {
+                        if let Some(slot) = table.get_mut(code) {
+                            *slot = glyph_name_to_unicode(name);
+                        }
+                        code += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        table
+    }
+
+    fn cid_widths(&self, widths: &[Object]) -> HashMap<u32, f64> {
+        let mut result = HashMap::new();
+        let mut index = 0;
+        while index < widths.len() {
+            let Some(first) = self.resolve(&widths[index]).as_i64() else {
+                index += 1;
+                continue;
+            };
+            let first = first.max(0) as u32;
+            match widths.get(index + 1).map(|next| self.resolve(next)) {
+                Some(Object::Array(list)) => {
+                    for (offset, width) in list.iter().enumerate() {
+                        if let Some(width) = self.resolve(width).as_f64() {
+                            result.insert(first + offset as u32, width);
+                        }
+                    }
+                    index += 2;
+                }
+                Some(last) if last.as_i64().is_some() => {
+                    let last = last.as_i64().unwrap_or(0).max(0) as u32;
+                    let width = widths
+                        .get(index + 2)
+                        .and_then(|width| self.resolve(width).as_f64())
+                        .unwrap_or(1000.0);
+                    for cid in first..=last.min(first.saturating_add(0xffff)) {
+                        result.insert(cid, width);
+                    }
+                    index += 3;
+                }
+                _ => break,
+            }
+        }
+        result
+    }
+}
+
+/// Returns the object number of an "N G obj" header ending just before `found`.
+fn object_number(data: &[u8], found: usize) -> Option<u32> {
+    let skip_space = |mut index: usize| {
+        while index > 0 && is_whitespace(data[index - 1]) {
+            index -= 1;
+        }
+        index
+    };
+    let skip_digits = |mut index: usize| {
+        while index > 0 && data[index - 1].is_ascii_digit() {
+            index -= 1;
+        }
+        index
+    };
+    let generation_end = skip_space(found);
+    let generation_start = skip_digits(generation_end);
+    let number_end = skip_space(generation_start);
+    let number_start = skip_digits(number_end);
+    if generation_end == found
+        || generation_start == generation_end
+        || number_end == generation_start
+        || number_start == number_end
+    {
+        return None;
+    }
+    if number_start > 0
+        && !is_whitespace(data[number_start - 1])
+        && !is_delimiter(data[number_start - 1])
+    {
+        return None;
+    }
+    std::str::from_utf8(&data[number_start..number_end])
+        .ok()?
+        .parse()
+        .ok()
+}
+
+/// Reads stream data starting after the `stream` keyword; returns the data and
+/// the position after `endstream`.
+fn read_stream_data(data: &[u8], pos: usize, dict: &Dictionary) -> (Vec<u8>, usize) {
+    let mut start = pos;
+    if data.get(start) == Some(&b'\r') {
+        start += 1;
+    }
+    if data.get(start) == Some(&b'\n') {
+        start += 1;
+    }
+    if let Some(Object::Integer(length)) = dict.get("Length")
+        && let Ok(length) = usize::try_from(*length)
+        && let Some(end) = start.checked_add(length).filter(|end| *end <= data.len())
+    {
+        let mut lexer = Lexer::new(data, end);
+        lexer.skip_whitespace();
+        if lexer.starts_with(b"endstream") {
+            return (data[start..end].to_vec(), lexer.pos + 9);
+        }
+    }
+    // Missing or wrong /Length: look for the end marker instead
+    match find(data, b"endstream", start) {
+        Some(end) => {
+            let mut stop = end;
+            if stop > start && data[stop - 1] == b'\n' {
+                stop -= 1;
+            }
+            if stop > start && data[stop - 1] == b'\r' {
+                stop -= 1;
+            }
+            (data[start..stop].to_vec(), end + 9)
+        }
+        None => (data.get(start..).unwrap_or_default().to_vec(), data.len()),
+    }
+}
+
+// ============================================================================
+// Stream filters
+// ============================================================================
+
+/// Largest decompressed size accepted for one Flate stream, so that a small
+/// compressed stream cannot expand without bound.
+const MAX_INFLATED_SIZE: u64 = 256 * 1024 * 1024;
+
+/// Inflates a Flate stream to at most `max_size` bytes; the flag is set when
+/// the output was cut off.
+fn inflate(data: &[u8], max_size: u64) -> Result<(Vec<u8>, bool), String> {
+    let limit = |mut output: Vec<u8>| {
+        let truncated = output.len() as u64 > max_size;
+        output.truncate(max_size as usize);
+        (output, truncated)
+    };
+    let mut output = Vec::new();
+    match ZlibDecoder::new(data)
+        .take(max_size + 1)
+        .read_to_end(&mut output)
+    {
+        Ok(_) => return Ok(limit(output)),
+        // Truncated streams are common; keep what could be decoded
+        Err(_) if !output.is_empty() => return Ok(limit(output)),
+        Err(_) => {}
+    }
+    let mut output = Vec::new();
+    match DeflateDecoder::new(data)
+        .take(max_size + 1)
+        .read_to_end(&mut output)
+    {
+        Ok(_) => Ok(limit(output)),
+        Err(_) if !output.is_empty() => Ok(limit(output)),
+        Err(error) => Err(format!("invalid Flate data: {}", error)),
+    }
+}
+
+fn lzw_decode(data: &[u8], early_change: bool) -> Vec<u8> {
+    let mut output = Vec::new();
+    let mut table: Vec<Vec<u8>> = (0..=255u8).map(|byte| vec![byte]).collect();
+    table.push(Vec::new());
+    table.push(Vec::new());
+    let mut width = 9;
+    let mut previous: Option<Vec<u8>> = None;
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for &byte in data {
+        buffer = (buffer << 8) | u32::from(byte);
+        bits += 8;
+        while bits >= width {
+            let code = ((buffer >> (bits - width)) & ((1 << width) - 1)) as usize;
+            bits -= width;
+            match code {
+                256 => {
+                    table.truncate(258);
+                    width = 9;
+                    previous = None;
+                    continue;
+                }
+                257 => return output,
+                _ => {}
+            }
+            let entry = match (table.get(code), &previous) {
+                (Some(entry), _) if code < table.len() && (code < 256 || !entry.is_empty()) => {
+                    entry.clone()
+                }
+                (_, Some(previous)) if code == table.len() => {
+                    let mut entry = previous.clone();
+                    entry.push(previous[0]);
+                    entry
+                }
+                _ => return output,
+            };
+            output.extend(&entry);
+            if let Some(previous) = previous.take() {
+                let mut added = previous;
+                added.push(entry[0]);
+                table.push(added);
+            }
+            let limit = table.len() + usize::from(early_change);
+            if limit >= (1 << width) && width < 12 {
+                width += 1;
+            }
+            previous = Some(entry);
+        }
+    }
+    output
+}
+
+fn ascii_hex_decode(data: &[u8]) -> Vec<u8> {
+    let mut digits: Vec<u8> = data
+        .iter()
+        .take_while(|byte| **byte != b'>')
+        .filter(|byte| byte.is_ascii_hexdigit())
+        .copied()
+        .collect();
+    if digits.len() % 2 == 1 {
+        digits.push(b'0');
+    }
+    digits
+        .chunks(2)
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).unwrap_or("00"), 16).unwrap_or(0))
+        .collect()
+}
+
+fn ascii85_decode(data: &[u8]) -> Vec<u8> {
+    let mut output = Vec::new();
+    let mut group = Vec::with_capacity(5);
+    let data = data.strip_prefix(b"<~").unwrap_or(data);
+    for &byte in data {
+        match byte {
+            b'~' => break,
+            b'z' if group.is_empty() => output.extend([0, 0, 0, 0]),
+            b'!'..=b'u' => {
+                group.push(u32::from(byte - b'!'));
+                if group.len() == 5 {
+                    let value = group
+                        .iter()
+                        .fold(0u32, |acc, digit| acc.wrapping_mul(85).wrapping_add(*digit));
+                    output.extend(value.to_be_bytes());
+                    group.clear();
+                }
+            }
+            _ => {}
+        }
+    }
+    if group.len() > 1 {
+        let count = group.len() - 1;
+        group.resize(5, 84);
+        let value = group
+            .iter()
+            .fold(0u32, |acc, digit| acc.wrapping_mul(85).wrapping_add(*digit));
+        output.extend(&value.to_be_bytes()[..count]);
+    }
+    output
+}
+
+fn run_length_decode(data: &[u8]) -> Vec<u8> {
+    let mut output = Vec::new();
+    let mut index = 0;
+    while let Some(&length) = data.get(index) {
+        index += 1;
+        match length {
+            128 => break,
+            0..=127 => {
+                let end = (index + usize::from(length) + 1).min(data.len());
+                output.extend(&data[index..end]);
+                index = end;
+            }
+            _ => {
+                if let Some(&byte) = data.get(index) {
+                    output.extend(std::iter::repeat_n(byte, 257 - usize::from(length)));
+                }
+                index += 1;
+            }
+        }
+    }
+    output
+}
+
+fn png_predict(data: &[u8], predictor: i64, colors: usize, bits: usize, columns: usize) -> Vec<u8> {
+    let pixel = (colors * bits).div_ceil(8).max(1);
+    let row = (colors * bits * columns).div_ceil(8);
+    if predictor == 2 {
+        // TIFF predictor, for 8-bit components only
+        if bits != 8 {
+            return data.to_vec();
+        }
+        let mut output = data.to_vec();
+        for line in output.chunks_mut(row) {
+            for index in pixel..line.len() {
+                line[index] = line[index].wrapping_add(line[index - pixel]);
+            }
+        }
+        return output;
+    }
+
+    let mut output = Vec::with_capacity(data.len());
+    let mut previous = vec![0u8; row];
+    for chunk in data.chunks(row + 1) {
+        let Some((&filter, line)) = chunk.split_first() else {
+            break;
+        };
+        let mut current = line.to_vec();
+        current.resize(row, 0);
+        for index in 0..row {
+            let left = if index >= pixel {
+                current[index - pixel]
+            } else {
+                0
+            };
+            let up = previous[index];
+            let up_left = if index >= pixel {
+                previous[index - pixel]
+            } else {
+                0
+            };
+            current[index] = current[index].wrapping_add(match filter {
+                1 => left,
+                2 => up,
+                3 => ((u16::from(left) + u16::from(up)) / 2) as u8,
+                4 => paeth(left, up, up_left),
+                _ => 0,
+            });
+        }
+        output.extend(&current[..line.len().min(row)]);
+        previous = current;
+    }
+    output
+}
+
+fn paeth(left: u8, up: u8, up_left: u8) -> u8 {
+    let estimate = i16::from(left) + i16::from(up) - i16::from(up_left);
+    let distance_left = (estimate - i16::from(left)).abs();
+    let distance_up = (estimate - i16::from(up)).abs();
+    let distance_up_left = (estimate - i16::from(up_left)).abs();
+    if distance_left <= distance_up && distance_left <= distance_up_left {
+        left
+    } else if distance_up <= distance_up_left {
+        up
+    } else {
+        up_left
+    }
+}
+
+// ============================================================================
+// Fonts and encodings
+// ============================================================================
+
+#[derive(Debug, Clone)]
+struct CodespaceRange {
+    low: Vec<u8>,
+    high: Vec<u8>,
+}
+
+impl CodespaceRange {
+    fn two_byte() -> Self {
+        Self {
+            low: vec![0, 0],
+            high: vec![0xff, 0xff],
+        }
+    }
+
+    fn matches(&self, bytes: &[u8]) -> bool {
+        bytes.len() == self.low.len()
+            && bytes
+                .iter()
+                .zip(self.low.iter().zip(&self.high))
+                .all(|(byte, (low, high))| low <= byte && byte <= high)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Font {
+    name: String,
+    /// Code lengths of composite fonts; empty for single-byte fonts
+    codespace: Vec<CodespaceRange>,
+    to_unicode: HashMap<u32, String>,
+    /// Text of single-byte codes under the font encoding
+    encoding: Vec<Option<String>>,
+    /// Codes are Unicode values (predefined UCS-2 and UTF-16 CMaps)
+    unicode_codes: bool,
+    /// Code to CID mapping of embedded encoding CMaps
+    cids: HashMap<u32, u32>,
+    widths: HashMap<u32, f64>,
+    default_width: f64,
+    /// Glyph space to text space scale
+    width_scale: f64,
+    vertical: bool,
+    /// Vertical displacement in glyph space
+    vertical_advance: f64,
+}
+
+impl Font {
+    fn codes(&self, bytes: &[u8]) -> Vec<(u32, usize)> {
+        let mut codes = Vec::new();
+        let mut index = 0;
+        while index < bytes.len() {
+            let length = if self.codespace.is_empty() {
+                1
+            } else {
+                (1..=4)
+                    .find(|&length| {
+                        index + length <= bytes.len()
+                            && self
+                                .codespace
+                                .iter()
+                                .any(|range| range.matches(&bytes[index..index + length]))
+                    })
+                    .unwrap_or(self.codespace[0].low.len())
+            };
+            let length = length.clamp(1, bytes.len() - index);
+            let code = bytes[index..index + length]
+                .iter()
+                .fold(0u32, |acc, byte| (acc << 8) | u32::from(*byte));
+            codes.push((code, length));
+            index += length;
+        }
+        codes
+    }
+
+    fn text(&self, code: u32) -> Option<String> {
+        if let Some(text) = self.to_unicode.get(&code) {
+            return Some(text.clone());
+        }
+        if self.codespace.is_empty() {
+            return self
+                .encoding
+                .get(code as usize)
+                .cloned()
+                .flatten()
+                .or_else(|| (code >= 0x20).then(|| char::from(code as u8).to_string()));
+        }
+        if self.unicode_codes {
+            return char::from_u32(code).map(String::from);
+        }
+        None
+    }
+
+    /// Horizontal advance of a glyph in text space units per unit of font size.
+    fn width(&self, code: u32) -> f64 {
+        let cid = self.cids.get(&code).copied().unwrap_or(code);
+        self.widths.get(&cid).copied().unwrap_or(self.default_width) * self.width_scale
+    }
+}
+
+#[derive(Default)]
+struct CMap {
+    codespace: Vec<CodespaceRange>,
+    unicode: HashMap<u32, String>,
+    cids: HashMap<u32, u32>,
+    vertical: bool,
+}
+
+/// Largest number of codes expanded from one range, against hostile files.
+const MAX_RANGE: u32 = 0x10000;
+
+fn parse_cmap(data: &[u8]) -> CMap {
+    let mut cmap = CMap::default();
+    let mut lexer = Lexer::new(data, 0);
+    let mut operands: Vec<Object> = Vec::new();
+    while let Some(object) = lexer.next_object() {
+        let Object::Keyword(keyword) = object else {
+            operands.push(object);
+            continue;
+        };
+        match keyword.as_str() {
+            "endcodespacerange" => {
+                for pair in operands.chunks(2) {
+                    if let [Object::String(low), Object::String(high)] = pair {
+                        cmap.codespace.push(CodespaceRange {
+                            low: low.clone(),
+                            high: high.clone(),
+                        });
+                    }
+                }
+            }
+            "endbfchar" => {
+                for pair in operands.chunks(2) {
+                    match pair {
+                        [Object::String(code), Object::String(text)] => {
+                            cmap.unicode.insert(code_value(code), utf16_text(text));
+                        }
+                        [Object::String(code), Object::Name(name)] => {
+                            if let Some(text) = glyph_name_to_unicode(name) {
+                                cmap.unicode.insert(code_value(code), text);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            "endbfrange" => {
+                for triple in operands.chunks(3) {
+                    let [Object::String(low), Object::String(high), target] = triple else {
+                        continue;
+                    };
+                    let (low, high) = (code_value(low), code_value(high));
+                    let count = high.saturating_sub(low).min(MAX_RANGE);
+                    match target {
+                        Object::String(text) => {
+                            let mut units: Vec<u16> = text
+                                .chunks(2)
+                                .map(|pair| {
+                                    u16::from_be_bytes([pair[0], *pair.get(1).unwrap_or(&0)])
+                                })
+                                .collect();
+                            for offset in 0..=count {
+                                cmap.unicode
+                                    .insert(low + offset, String::from_utf16_lossy(&units));
+                                if let Some(last) = units.last_mut() {
+                                    *last = last.wrapping_add(1);
+                                }
+                            }
+                        }
+                        Object::Array(texts) => {
+                            for (offset, text) in texts.iter().enumerate().take(count as usize + 1)
+                            {
+                                if let Object::String(text) = text {
+                                    cmap.unicode.insert(low + offset as u32, utf16_text(text));
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            "endcidchar" => {
+                for pair in operands.chunks(2) {
+                    if let [Object::String(code), Object::Integer(cid)] = pair {
+                        cmap.cids.insert(code_value(code), cid_value(*cid));
+                    }
+                }
+            }
+            "endcidrange" => {
+                for triple in operands.chunks(3) {
+                    if let [
+                        Object::String(low),
+                        Object::String(high),
+                        Object::Integer(cid),
+                    ] = triple
+                    {
+                        let (low, high) = (code_value(low), code_value(high));
+                        for offset in 0..=high.saturating_sub(low).min(MAX_RANGE) {
+                            cmap.cids
+                                .insert(low + offset, cid_value(*cid).saturating_add(offset));
+                        }
+                    }
+                }
+            }
+            "def" => {
+                if let [.., Object::Name(name), Object::Integer(mode)] = &operands[..]
+                    && name == "WMode"
+                {
+                    cmap.vertical = *mode == 1;
+                }
+            }
+            _ => {}
+        }
+        operands.clear();
+    }
+    cmap
+}
+
+fn cid_value(cid: i64) -> u32 {
+    u32::try_from(cid.max(0)).unwrap_or(u32::MAX)
+}
+
+fn code_value(bytes: &[u8]) -> u32 {
+    bytes
+        .iter()
+        .take(4)
+        .fold(0u32, |acc, byte| (acc << 8) | u32::from(*byte))
+}
+
+fn utf16_text(bytes: &[u8]) -> String {
+    let units: Vec<u16> = bytes
+        .chunks(2)
+        .map(|pair| u16::from_be_bytes([pair[0], *pair.get(1).unwrap_or(&0)]))
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+/// WinAnsiEncoding codes 0x80-0x9F; the rest of the upper half is Latin-1.
+const WIN_ANSI_HIGH: [Option<char>; 32] = [
+    Some('€'),
+    None,
+    Some('‚'),
+    Some('ƒ'),
+    Some('„'),
+    Some('…'),
+    Some('†'),
+    Some('‡'),
+    Some('ˆ'),
+    Some('‰'),
+    Some('Š'),
+    Some('‹'),
+    Some('Œ'),
+    None,
+    Some('Ž'),
+    None,
+    None,
+    Some('‘'),
+    Some('’'),
+    Some('“'),
+    Some('”'),
+    Some('•'),
+    Some('–'),
+    Some('—'),
+    Some('˜'),
+    Some('™'),
+    Some('š'),
+    Some('›'),
+    Some('œ'),
+    None,
+    Some('ž'),
+    Some('Ÿ'),
+];
+
+/// MacRomanEncoding codes 0x80-0xFF.
+const MAC_ROMAN_HIGH: &str = "ÄÅÇÉÑÖÜáàâäãåçéèêëíìîïñóòôöõúùûü†°¢£§•¶ß®©™´¨≠ÆØ∞±≤≥¥µ∂∑∏π∫ªºΩæø\
+¿¡¬√ƒ≈∆«»…\u{a0}ÀÃÕŒœ–—“”‘’÷◊ÿŸ⁄€‹›ﬁﬂ‡·‚„‰ÂÊÁËÈÍÎÏÌÓÔ\u{f8ff}ÒÚÛÙıˆ˜¯˘˙˚¸˝˛ˇ";
+
+/// StandardEncoding codes that differ from ASCII.
+const STANDARD_DIFFERENCES: &[(u8, char)] = &[
+    (0x27, '’'),
+    (0x60, '‘'),
+    (0xa1, '¡'),
+    (0xa2, '¢'),
+    (0xa3, '£'),
+    (0xa4, '⁄'),
+    (0xa5, '¥'),
+    (0xa6, 'ƒ'),
+    (0xa7, '§'),
+    (0xa8, '¤'),
+    (0xa9, '\''),
+    (0xaa, '“'),
+    (0xab, '«'),
+    (0xac, '‹'),
+    (0xad, '›'),
+    (0xae, 'ﬁ'),
+    (0xaf, 'ﬂ'),
+    (0xb1, '–'),
+    (0xb2, '†'),
+    (0xb3, '‡'),
+    (0xb4, '·'),
+    (0xb6, '¶'),
+    (0xb7, '•'),
+    (0xb8, '‚'),
+    (0xb9, '„'),
+    (0xba, '”'),
+    (0xbb, '»'),
+    (0xbc, '…'),
+    (0xbd, '‰'),
+    (0xbf, '¿'),
+    (0xc1, '`'),
+    (0xc2, '´'),
+    (0xc3, 'ˆ'),
+    (0xc4, '˜'),
+    (0xc5, '¯'),
+    (0xc6, '˘'),
+    (0xc7, '˙'),
+    (0xc8, '¨'),
+    (0xca, '˚'),
+    (0xcb, '¸'),
+    (0xcd, '˝'),
+    (0xce, '˛'),
+    (0xcf, 'ˇ'),
+    (0xd0, '—'),
+    (0xe1, 'Æ'),
+    (0xe3, 'ª'),
+    (0xe8, 'Ł'),
+    (0xe9, 'Ø'),
+    (0xea, 'Œ'),
+    (0xeb, 'º'),
+    (0xf1, 'æ'),
+    (0xf5, 'ı'),
+    (0xf8, 'ł'),
+    (0xf9, 'ø'),
+    (0xfa, 'œ'),
+    (0xfb, 'ß'),
+];
+
+fn base_encoding(name: &str) -> Vec<Option<String>> {
+    let mut table: Vec<Option<String>> = (0..=255u8)
+        .map(|code| {
+            (0x20..0x7f)
+                .contains(&code)
+                .then(|| char::from(code).to_string())
+        })
+        .collect();
+    match name {
+        "WinAnsiEncoding" | "PDFDocEncoding" => {
+            for (offset, c) in WIN_ANSI_HIGH.iter().enumerate() {
+                table[0x80 + offset] = c.map(String::from);
+            }
+            for code in 0xa0..=0xffu8 {
+                table[usize::from(code)] = Some(char::from(code).to_string());
+            }
+        }
+        "MacRomanEncoding" => {
+            for (offset, c) in MAC_ROMAN_HIGH.chars().enumerate() {
+                table[0x80 + offset] = Some(c.to_string());
+            }
+        }
+        "StandardEncoding" | "MacExpertEncoding" => {
+            for &(code, c) in STANDARD_DIFFERENCES {
+                table[usize::from(code)] = Some(c.to_string());
+            }
+        }
+        _ => {}
+    }
+    // No-break spaces and soft hyphens read as their plain forms
+    for (code, text) in [(0xa0u8, " "), (0xad, "-")] {
+        let slot = &mut table[usize::from(code)];
+        if slot.as_deref().and_then(|slot| slot.chars().next()) == Some(char::from(code)) {
+            *slot = Some(text.to_string());
+        }
+    }
+    table
+}
+
+/// Glyph names of the Adobe Glyph List that are common in legal texts.
+const GLYPH_NAMES: &[(&str, &str)] = &[
+    ("space", " "),
+    ("exclam", "!"),
+    ("quotedbl", "\""),
+    ("numbersign", "#"),
+    ("dollar", "$"),
+    ("percent", "%"),
+    ("ampersand", "&"),
+    ("quotesingle", "'"),
+    ("quoteright", "’"),
+    ("quoteleft", "‘"),
+    ("parenleft", "("),
+    ("parenright", ")"),
+    ("asterisk", "*"),
+    ("plus", "+"),
+    ("comma", ","),
+    ("hyphen", "-"),
+    ("period", "."),
+    ("slash", "/"),
+    ("zero", "0"),
+    ("one", "1"),
+    ("two", "2"),
+    ("three", "3"),
+    ("four", "4"),
+    ("five", "5"),
+    ("six", "6"),
+    ("seven", "7"),
+    ("eight", "8"),
+    ("nine", "9"),
+    ("colon", ":"),
+    ("semicolon", ";"),
+    ("less", "<"),
+    ("equal", "="),
+    ("greater", ">"),
+    ("question", "?"),
+    ("at", "@"),
+    ("bracketleft", "["),
+    ("backslash", "\\"),
+    ("bracketright", "]"),
+    ("asciicircum", "^"),
+    ("underscore", "_"),
+    ("grave", "`"),
+    ("braceleft", "{"),
+    ("bar", "|"),
+    ("braceright", "}"),
+    ("asciitilde", "~"),
+    ("section", "§"),
+    ("paragraph", "¶"),
+    ("degree", "°"),
+    ("ordmasculine", "º"),
+    ("ordfeminine", "ª"),
+    ("copyright", "©"),
+    ("registered", "®"),
+    ("trademark", "™"),
+    ("bullet", "•"),
+    ("endash", "–"),
+    ("emdash", "—"),
+    ("quotedblleft", "“"),
+    ("quotedblright", "”"),
+    ("quotesinglbase", "‚"),
+    ("quotedblbase", "„"),
+    ("guillemotleft", "«"),
+    ("guillemotright", "»"),
+    ("guilsinglleft", "‹"),
+    ("guilsinglright", "›"),
+    ("ellipsis", "…"),
+    ("dagger", "†"),
+    ("daggerdbl", "‡"),
+    ("fi", "fi"),
+    ("fl", "fl"),
+    ("ff", "ff"),
+    ("ffi", "ffi"),
+    ("ffl", "ffl"),
+    ("germandbls", "ß"),
+    ("adieresis", "ä"),
+    ("odieresis", "ö"),
+    ("udieresis", "ü"),
+    ("Adieresis", "Ä"),
+    ("Odieresis", "Ö"),
+    ("Udieresis", "Ü"),
+    ("eacute", "é"),
+    ("egrave", "è"),
+    ("ecircumflex", "ê"),
+    ("agrave", "à"),
+    ("acircumflex", "â"),
+    ("ccedilla", "ç"),
+    ("icircumflex", "î"),
+    ("ocircumflex", "ô"),
+    ("ugrave", "ù"),
+    ("ucircumflex", "û"),
+    ("Eacute", "É"),
+    ("oe", "œ"),
+    ("OE", "Œ"),
+    ("Euro", "€"),
+    ("sterling", "£"),
+    ("nbspace", " "),
+    ("sfthyphen", "-"),
+];
+
+/// Maps a glyph name to text, following the Adobe Glyph List conventions.
+fn glyph_name_to_unicode(name: &str) -> Option<String> {
+    let base = name.split('.').next().unwrap_or(name);
+    if base.contains('_') {
+        let parts: Option<Vec<String>> = base.split('_').map(glyph_name_to_unicode).collect();
+        return parts.map(|parts| parts.concat());
+    }
+    if let Some(hex) = base.strip_prefix("uni")
+        && hex.len() >= 4
+        && hex.len() % 4 == 0
+        && hex.chars().all(|c| c.is_ascii_hexdigit())
+    {
+        let units: Vec<u16> = hex
+            .as_bytes()
+            .chunks(4)
+            .filter_map(|unit| u16::from_str_radix(std::str::from_utf8(unit).ok()?, 16).ok())
+            .collect();
+        return Some(String::from_utf16_lossy(&units));
+    }
+    if let Some(hex) = base.strip_prefix('u')
+        && (4..=6).contains(&hex.len())
+        && hex.chars().all(|c| c.is_ascii_hexdigit())
+    {
+        return u32::from_str_radix(hex, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .map(String::from);
+    }
+    let mut chars = base.chars();
+    if let (Some(c), None) = (chars.next(), chars.next())
+        && c.is_ascii_alphabetic()
+    {
+        return Some(c.to_string());
+    }
+    GLYPH_NAMES
+        .iter()
+        .find(|(glyph, _)| *glyph == base)
+        .map(|(_, text)| text.to_string())
+}
+
+/// Returns true for characters of scripts written without spaces between words.
+pub(crate) fn is_cjk(c: char) -> bool {
+    matches!(
+        u32::from(c),
+        0x3000..=0x30ff | 0x3400..=0x4dbf | 0x4e00..=0x9fff | 0xf900..=0xfaff | 0xff00..=0xffef
+    )
+}
+
+// ============================================================================
+// Content stream interpretation
+// ============================================================================
+
+#[derive(Debug, Clone)]
+struct GraphicsState {
+    ctm: Matrix,
+    char_spacing: f64,
+    word_spacing: f64,
+    scale: f64,
+    leading: f64,
+    font: Option<Rc<Font>>,
+    font_size: f64,
+    rise: f64,
+}
+
+impl Default for GraphicsState {
+    fn default() -> Self {
+        Self {
+            ctm: IDENTITY,
+            char_spacing: 0.0,
+            word_spacing: 0.0,
+            scale: 1.0,
+            leading: 0.0,
+            font: None,
+            font_size: 0.0,
+            rise: 0.0,
+        }
+    }
+}
+
+/// A glyph placed on the page, in device space.
+#[derive(Debug, Clone)]
+struct Glyph {
+    text: String,
+    x: f64,
+    y: f64,
+    /// Unit vector of the writing direction
+    direction: (f64, f64),
+    advance: f64,
+    size: f64,
+}
+
+/// Nesting limit for form XObjects.
+const MAX_FORM_DEPTH: usize = 12;
+
+struct Interpreter<'a> {
+    document: &'a Document,
+    fonts: HashMap<u32, Rc<Font>>,
+    glyphs: Vec<Glyph>,
+    images: usize,
+    unmapped: HashSet<String>,
+    forms: Vec<Option<u32>>,
+}
+
+impl<'a> Interpreter<'a> {
+    fn new(document: &'a Document) -> Self {
+        Self {
+            document,
+            fonts: HashMap::new(),
+            glyphs: Vec::new(),
+            images: 0,
+            unmapped: HashSet::new(),
+            forms: Vec::new(),
+        }
+    }
+
+    fn run(&mut self, content: &[u8], resources: Option<&'a Dictionary>, state: GraphicsState) {
+        let mut lexer = Lexer::new(content, 0);
+        let mut saved: Vec<GraphicsState> = Vec::new();
+        let mut gs = state;
+        let mut tm = IDENTITY;
+        let mut tlm = IDENTITY;
+        let mut operands: Vec<Object> = Vec::new();
+
+        while let Some(object) = lexer.next_object() {
+            let Object::Keyword(operator) = object else {
+                operands.push(object);
+                continue;
+            };
+            let number = |index: usize| {
+                operands
+                    .len()
+                    .checked_sub(index + 1)
+                    .and_then(|position| operands[position].as_f64())
+                    .unwrap_or(0.0)
+            };
+            match operator.as_str() {
+                "q" => saved.push(gs.clone()),
+                "Q" => {
+                    if let Some(state) = saved.pop() {
+                        gs = state;
+                    }
+                }
+                "cm" => {
+                    if let Some(matrix) = matrix_operand(&operands) {
+                        gs.ctm = multiply(&matrix, &gs.ctm);
+                    }
+                }
+                "BT" => {
+                    tm = IDENTITY;
+                    tlm = IDENTITY;
+                }
+                "Tc" => gs.char_spacing = number(0),
+                "Tw" => gs.word_spacing = number(0),
+                "Tz" => gs.scale = number(0) / 100.0,
+                "TL" => gs.leading = number(0),
+                "Ts" => gs.rise = number(0),
+                "Tf" => {
+                    gs.font_size = number(0);
+                    if let Some(Object::Name(name)) =
+                        operands.len().checked_sub(2).map(|i| &operands[i])
+                    {
+                        gs.font = self.font(resources, name);
+                    }
+                }
+                "Td" | "TD" => {
+                    if operator == "TD" {
+                        gs.leading = -number(0);
+                    }
+                    tlm = multiply(&translate(number(1), number(0)), &tlm);
+                    tm = tlm;
+                }
+                "Tm" => {
+                    if let Some(matrix) = matrix_operand(&operands) {
+                        tlm = matrix;
+                        tm = matrix;
+                    }
+                }
+                "T*" => {
+                    tlm = multiply(&translate(0.0, -gs.leading), &tlm);
+                    tm = tlm;
+                }
+                "Tj" | "'" | "\"" => {
+                    if operator == "\"" {
+                        gs.word_spacing = number(2);
+                        gs.char_spacing = number(1);
+                    }
+                    if operator != "Tj" {
+                        tlm = multiply(&translate(0.0, -gs.leading), &tlm);
+                        tm = tlm;
+                    }
+                    if let Some(Object::String(bytes)) = operands.last() {
+                        self.show(bytes, &gs, &mut tm);
+                    }
+                }
+                "TJ" => {
+                    if let Some(Object::Array(items)) = operands.last() {
+                        for item in items {
+                            match item {
+                                Object::String(bytes) => self.show(bytes, &gs, &mut tm),
+                                other => {
+                                    let shift =
+                                        -other.as_f64().unwrap_or(0.0) / 1000.0 * gs.font_size;
+                                    let vertical =
+                                        gs.font.as_ref().is_some_and(|font| font.vertical);
+                                    tm = if vertical {
+                                        multiply(&translate(0.0, shift), &tm)
+                                    } else {
+                                        multiply(&translate(shift * gs.scale, 0.0), &tm)
+                                    };
+                                }
+                            }
+                        }
+                    }
+                }
+                "Do" => {
+                    if let Some(Object::Name(name)) = operands.last() {
+                        self.draw_xobject(resources, name, &gs);
+                    }
+                }
+                "ID" => {
+                    lexer.skip_inline_image();
+                    self.images += 1;
+                }
+                _ => {}
+            }
+            operands.clear();
+        }
+    }
+
+    fn show(&mut self, bytes: &[u8], gs: &GraphicsState, tm: &mut Matrix) {
+        let Some(font) = gs.font.clone() else {
+            return;
+        };
+        let size = gs.font_size;
+        for (code, length) in font.codes(bytes) {
+            let text = font.text(code).unwrap_or_else(|| {
+                self.unmapped.insert(font.name.clone());
+                "\u{fffd}".to_string()
+            });
+            let user = multiply(tm, &gs.ctm);
+            let trm = multiply(&[size * gs.scale, 0.0, 0.0, size, 0.0, gs.rise], &user);
+            let spacing = gs.char_spacing
+                + if length == 1 && code == 32 {
+                    gs.word_spacing
+                } else {
+                    0.0
+                };
+            let (tx, ty) = if font.vertical {
+                (0.0, font.vertical_advance * 0.001 * size + spacing)
+            } else {
+                ((font.width(code) * size + spacing) * gs.scale, 0.0)
+            };
+            let (dx, dy) = (tx * user[0] + ty * user[2], tx * user[1] + ty * user[3]);
+            let direction = if font.vertical {
+                normalize(-trm[2], -trm[3])
+            } else {
+                normalize(trm[0], trm[1])
+            };
+            self.glyphs.push(Glyph {
+                text,
+                x: trm[4],
+                y: trm[5],
+                direction,
+                advance: dx.hypot(dy),
+                size: trm[2].hypot(trm[3]),
+            });
+            *tm = multiply(&translate(tx, ty), tm);
+        }
+    }
+
+    fn font(&mut self, resources: Option<&'a Dictionary>, name: &str) -> Option<Rc<Font>> {
+        let document = self.document;
+        let fonts = resources.and_then(|resources| document.get_dict(resources, "Font"))?;
+        let reference = fonts.get(name)?;
+        if let Object::Reference(number) = reference
+            && let Some(font) = self.fonts.get(number)
+        {
+            return Some(font.clone());
+        }
+        let font = Rc::new(document.load_font(document.resolve(reference).as_dict()?));
+        if let Object::Reference(number) = reference {
+            self.fonts.insert(*number, font.clone());
+        }
+        Some(font)
+    }
+
+    fn draw_xobject(&mut self, resources: Option<&'a Dictionary>, name: &str, gs: &GraphicsState) {
+        let document = self.document;
+        let Some(reference) = resources
+            .and_then(|resources| document.get_dict(resources, "XObject"))
+            .and_then(|xobjects| xobjects.get(name))
+        else {
+            return;
+        };
+        let Object::Stream(stream) = document.resolve(reference) else {
+            return;
+        };
+        match document
+            .get(&stream.dict, "Subtype")
+            .and_then(Object::as_name)
+        {
+            Some("Image") => self.images += 1,
+            Some("Form") => {
+                let id = match reference {
+                    Object::Reference(number) => Some(*number),
+                    _ => None,
+                };
+                if self.forms.len() >= MAX_FORM_DEPTH || (id.is_some() && self.forms.contains(&id))
+                {
+                    return;
+                }
+                let Ok(content) = document.decode_stream(stream) else {
+                    return;
+                };
+                let mut state = gs.clone();
+                if let Some(matrix) = document
+                    .get(&stream.dict, "Matrix")
+                    .and_then(Object::as_array)
+                    .and_then(matrix_operand)
+                {
+                    state.ctm = multiply(&matrix, &gs.ctm);
+                }
+                let form_resources = document.get_dict(&stream.dict, "Resources").or(resources);
+                self.forms.push(id);
+                self.run(&content, form_resources, state);
+                self.forms.pop();
+            }
+            _ => {}
+        }
+    }
+}
+
+fn matrix_operand(operands: &[Object]) -> Option<Matrix> {
+    let start = operands.len().checked_sub(6)?;
+    let mut matrix = [0.0; 6];
+    for (slot, operand) in matrix.iter_mut().zip(&operands[start..]) {
+        *slot = operand.as_f64()?;
+    }
+    Some(matrix)
+}
+
+fn normalize(x: f64, y: f64) -> (f64, f64) {
+    let length = x.hypot(y);
+    if length > 0.0 {
+        (x / length, y / length)
+    } else {
+        (1.0, 0.0)
+    }
+}
+
+// ============================================================================
+// Layout analysis
+// ============================================================================
+
+/// Largest gap between glyphs of one segment, relative to the font size.
+const SEGMENT_GAP: f64 = 1.2;
+/// Pages with more segments than this are ordered top to bottom only.
+const MAX_ORDERED_SEGMENTS: usize = 800;
+
+/// A glyph in the reading frame of the page, where text runs left to right
+/// and lines go top to bottom (`y` grows upwards).
+#[derive(Debug, Clone)]
+struct FrameGlyph {
+    text: String,
+    x0: f64,
+    x1: f64,
+    y: f64,
+    size: f64,
+}
+
+/// A run of glyphs on one line.
+#[derive(Debug, Clone)]
+struct Segment {
+    text: String,
+    x0: f64,
+    x1: f64,
+    y: f64,
+    sizes: Vec<f64>,
+}
+
+impl Segment {
+    fn size(&self) -> f64 {
+        let mut sizes = self.sizes.clone();
+        sizes.sort_by(f64::total_cmp);
+        sizes.get(sizes.len() / 2).copied().unwrap_or(0.0)
+    }
+}
+
+struct PageLayout {
+    lines: Vec<PdfTextLine>,
+    width: f64,
+    height: f64,
+    skipped: usize,
+}
+
+fn layout_page(glyphs: Vec<Glyph>, media_box: [f64; 4]) -> PageLayout {
+    // Reading frame: the dominant writing direction, in quarter turns
+    let quarter = |glyph: &Glyph| {
+        let turns = (glyph.direction.1.atan2(glyph.direction.0) / FRAC_PI_2).round() as i64;
+        turns.rem_euclid(4) as usize
+    };
+    let mut counts = [0usize; 4];
+    for glyph in &glyphs {
+        counts[quarter(glyph)] += glyph.text.chars().count().max(1);
+    }
+    let turn = (0..4)
+        .max_by_key(|&index| (counts[index], std::cmp::Reverse(index)))
+        .unwrap_or(0);
+    let (sin, cos) = (turn as f64 * FRAC_PI_2).sin_cos();
+    let rotate = |x: f64, y: f64| (x * cos + y * sin, -x * sin + y * cos);
+
+    let corners = [
+        rotate(media_box[0], media_box[1]),
+        rotate(media_box[2], media_box[1]),
+        rotate(media_box[0], media_box[3]),
+        rotate(media_box[2], media_box[3]),
+    ];
+    let left = corners.iter().map(|c| c.0).fold(f64::INFINITY, f64::min);
+    let right = corners
+        .iter()
+        .map(|c| c.0)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let bottom = corners.iter().map(|c| c.1).fold(f64::INFINITY, f64::min);
+    let top = corners
+        .iter()
+        .map(|c| c.1)
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    let mut skipped = 0;
+    let mut frame = Vec::with_capacity(glyphs.len());
+    for glyph in glyphs {
+        if quarter(&glyph) != turn {
+            skipped += 1;
+            continue;
+        }
+        let (x, y) = rotate(glyph.x, glyph.y);
+        let size = glyph.size.max(0.1);
+        // Text outside the page, such as printer marks, is not part of the document
+        if x < left - size || x > right + size || y < bottom - size || y > top + size {
+            continue;
+        }
+        frame.push(FrameGlyph {
+            text: glyph.text,
+            x0: x,
+            x1: x + glyph.advance,
+            y,
+            size,
+        });
+    }
+
+    let segments = segments(group_rows(frame));
+    let order = if segments.len() > MAX_ORDERED_SEGMENTS {
+        let mut order: Vec<usize> = (0..segments.len()).collect();
+        order.sort_by(|&a, &b| {
+            segments[b]
+                .y
+                .total_cmp(&segments[a].y)
+                .then(segments[a].x0.total_cmp(&segments[b].x0))
+        });
+        order
+    } else {
+        reading_order(&segments)
+    };
+
+    let lines = order
+        .into_iter()
+        .filter_map(|index| {
+            let segment = &segments[index];
+            let text = segment.text.trim();
+            (!text.is_empty()).then(|| PdfTextLine {
+                text: text.to_string(),
+                x: segment.x0 - left,
+                y: top - segment.y,
+                width: segment.x1 - segment.x0,
+                font_size: segment.size(),
+            })
+        })
+        .collect();
+
+    PageLayout {
+        lines,
+        width: right - left,
+        height: top - bottom,
+        skipped,
+    }
+}
+
+/// Groups glyphs into rows of nearly equal baseline.
+fn group_rows(mut glyphs: Vec<FrameGlyph>) -> Vec<Vec<FrameGlyph>> {
+    glyphs.sort_by(|a, b| b.y.total_cmp(&a.y).then(a.x0.total_cmp(&b.x0)));
+    let mut rows: Vec<Vec<FrameGlyph>> = Vec::new();
+    let mut anchor = (0.0, 0.0);
+    for glyph in glyphs {
+        match rows.last_mut() {
+            Some(row) if (anchor.0 - glyph.y).abs() <= 0.5 * glyph.size.max(anchor.1) => {
+                row.push(glyph)
+            }
+            _ => {
+                anchor = (glyph.y, glyph.size);
+                rows.push(vec![glyph]);
+            }
+        }
+    }
+    rows
+}
+
+/// Splits rows into segments at wide gaps, inserting spaces between words.
+fn segments(rows: Vec<Vec<FrameGlyph>>) -> Vec<Segment> {
+    let mut result = Vec::new();
+    for mut row in rows {
+        row.sort_by(|a, b| a.x0.total_cmp(&b.x0));
+        let baseline = row
+            .iter()
+            .max_by(|a, b| a.size.total_cmp(&b.size))
+            .map(|glyph| glyph.y)
+            .unwrap_or(0.0);
+        let mut current: Option<Segment> = None;
+        let mut previous: Option<(String, f64)> = None;
+        for glyph in row {
+            // Glyphs overprinted to simulate bold type
+            if let Some((text, x0)) = &previous
+                && *text == glyph.text
+                && (glyph.x0 - x0).abs() < 0.3 * glyph.size
+            {
+                continue;
+            }
+            previous = Some((glyph.text.clone(), glyph.x0));
+
+            if let Some(segment) = current.as_mut() {
+                let gap = glyph.x0 - segment.x1;
+                let size = glyph.size.max(segment.size());
+                let joins = gap <= SEGMENT_GAP * size
+                    || (is_list_label(segment.text.trim()) && gap <= 6.0 * size);
+                if joins {
+                    let cjk = segment.text.chars().last().is_some_and(is_cjk)
+                        && glyph.text.chars().next().is_some_and(is_cjk);
+                    let threshold = if cjk { 0.8 } else { 0.15 } * glyph.size;
+                    if gap > threshold
+                        && !segment.text.ends_with(char::is_whitespace)
+                        && !glyph.text.starts_with(char::is_whitespace)
+                    {
+                        segment.text.push(' ');
+                    }
+                    segment.text.push_str(&glyph.text);
+                    segment.x1 = segment.x1.max(glyph.x1);
+                    segment.sizes.push(glyph.size);
+                    continue;
+                }
+            }
+            if let Some(segment) = current.take() {
+                result.push(segment);
+            }
+            current = Some(Segment {
+                text: glyph.text,
+                x0: glyph.x0,
+                x1: glyph.x1,
+                y: baseline,
+                sizes: vec![glyph.size],
+            });
+        }
+        result.extend(current);
+    }
+    result
+}
+
+/// Returns true for short numbering labels, such as `(a)` or `1.`, that are
+/// set apart from the text they introduce.
+fn is_list_label(text: &str) -> bool {
+    if matches!(text, "•" | "-" | "–" | "—" | "*") {
+        return true;
+    }
+    let inner = text
+        .trim_start_matches(['(', '['])
+        .trim_end_matches(['.', ')', ']', '°']);
+    // Labels are punctuated: "(a)", "1.", "iv)"
+    if inner.is_empty() || inner.len() == text.len() || inner.chars().count() > 4 {
+        return false;
+    }
+    inner.chars().all(|c| c.is_ascii_digit())
+        || (inner.len() <= 2 && inner.chars().all(|c| c.is_ascii_alphabetic()))
+        || inner
+            .chars()
+            .all(|c| matches!(c, 'i' | 'v' | 'x' | 'I' | 'V' | 'X'))
+}
+
+/// Orders segments for reading, following Breuel's rules: a segment comes
+/// before another if it is above it and they overlap horizontally, or if it is
+/// entirely to the left and no segment between them vertically spans both.
+fn reading_order(segments: &[Segment]) -> Vec<usize> {
+    let count = segments.len();
+    let mut successors = vec![Vec::new(); count];
+    let mut predecessors = vec![0usize; count];
+    let mut by_height: Vec<usize> = (0..count).collect();
+    by_height.sort_by(|&a, &b| segments[a].y.total_cmp(&segments[b].y));
+
+    for a in 0..count {
+        for b in 0..count {
+            if a == b {
+                continue;
+            }
+            let (first, second) = (&segments[a], &segments[b]);
+            let overlap = first.x1.min(second.x1) - first.x0.max(second.x0);
+            let precedes = if overlap > 0.0 {
+                first.y > second.y + 0.01
+            } else if first.x1 <= second.x0 {
+                let (low, high) = if first.y < second.y {
+                    (first.y, second.y)
+                } else {
+                    (second.y, first.y)
+                };
+                let start = by_height.partition_point(|&index| segments[index].y <= low);
+                !by_height[start..]
+                    .iter()
+                    .take_while(|&&index| segments[index].y < high)
+                    .any(|&index| {
+                        let between = &segments[index];
+                        between.x0 < first.x1 && between.x1 > second.x0
+                    })
+            } else {
+                false
+            };
+            if precedes {
+                successors[a].push(b);
+                predecessors[b] += 1;
+            }
+        }
+    }
+
+    // Topological sort, preferring the topmost then leftmost segment
+    let better = |a: usize, b: usize| {
+        let (first, second) = (&segments[a], &segments[b]);
+        first.y > second.y + 0.01 || ((first.y - second.y).abs() <= 0.01 && first.x0 < second.x0)
+    };
+    let mut done = vec![false; count];
+    let mut order = Vec::with_capacity(count);
+    while order.len() < count {
+        let mut choice: Option<usize> = None;
+        for index in 0..count {
+            if !done[index]
+                && predecessors[index] == 0
+                && choice.is_none_or(|current| better(index, current))
+            {
+                choice = Some(index);
+            }
+        }
+        // A cycle: fall back on geometry
+        if choice.is_none() {
+            choice = (0..count)
+                .filter(|index| !done[*index])
+                .reduce(|current, index| {
+                    if better(index, current) {
+                        index
+                    } else {
+                        current
+                    }
+                });
+        }
+        let Some(index) = choice else { break };
+        done[index] = true;
+        order.push(index);
+        for &next in &successors[index] {
+            predecessors[next] = predecessors[next].saturating_sub(1);
+        }
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::Compression;
+    use flate2::write::ZlibEncoder;
+    use std::io::Write;
+
+    /// Assembles a PDF file; object `i + 1` has the body `objects[i]`.
+    fn pdf(objects: &[Vec<u8>], trailer: Option<&str>) -> Vec<u8> {
+        let mut out = b"%PDF-1.7\n%\xe2\xe3\xcf\xd3\n".to_vec();
+        let mut offsets = Vec::new();
+        for (index, body) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            out.extend(format!("{} 0 obj\n", index + 1).as_bytes());
+            out.extend(body);
+            out.extend(b"\nendobj\n");
+        }
+        let xref = out.len();
+        if let Some(trailer) = trailer {
+            out.extend(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes());
+            for offset in offsets {
+                out.extend(format!("{:010} 00000 n \n", offset).as_bytes());
+            }
+            out.extend(format!("trailer\n{}\n", trailer).as_bytes());
+        }
+        out.extend(format!("startxref\n{}\n%%EOF\n", xref).as_bytes());
+        out
+    }
+
+    fn stream(dict: &str, data: &[u8]) -> Vec<u8> {
+        let mut out = format!("<< {} /Length {} >>\nstream\n", dict, data.len()).into_bytes();
+        out.extend(data);
+        out.extend(b"\nendstream");
+        out
+    }
+
+    fn deflate(data: &[u8]) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    /// A one-page document whose resources name font objects 5 onwards `F1`, `F2`, ...
+    fn document(content: &[u8], fonts: &[&str], extra: &[Vec<u8>]) -> Vec<u8> {
+        let font_refs: String = (0..fonts.len())
+            .map(|index| format!("/F{} {} 0 R ", index + 1, index + 5))
+            .collect();
+        let mut objects = vec![
+            b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec(),
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 595 842] /Resources << /Font << {}>> /XObject << /Fm1 {} 0 R /Im1 {} 0 R >> >> /Contents 4 0 R >>",
+                font_refs,
+                fonts.len() + 5,
+                fonts.len() + 6
+            )
+            .into_bytes(),
+            stream("", content),
+        ];
+        objects.extend(fonts.iter().map(|font| font.as_bytes().to_vec()));
+        objects.extend(extra.iter().cloned());
+        pdf(&objects, Some("<< /Size 20 /Root 1 0 R /Info 19 0 R >>"))
+    }
+
+    const HELVETICA: &str =
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>";
+
+    fn extract(data: &[u8]) -> PdfText {
+        PdfTextExtractor::new().extract(data).unwrap()
+    }
+
+    fn lines(text: &PdfText) -> Vec<String> {
+        text.pages[0]
+            .lines
+            .iter()
+            .map(|line| line.text.clone())
+            .collect()
+    }
+
+    /// Identity-H font whose ToUnicode CMap maps codes 1.. to `chars`.
+    fn cjk_font(vertical: bool, chars: &str) -> (String, Vec<u8>) {
+        let entries: String = chars
+            .encode_utf16()
+            .enumerate()
+            .map(|(index, unit)| format!("<{:04X}> <{:04X}>\n", index + 1, unit))
+            .collect();
+        let cmap = format!(
+            "/CIDInit /ProcSet findresource begin\n12 dict begin\nbegincmap\n1 begincodespacerange\n<0000> <FFFF>\nendcodespacerange\n{} beginbfchar\n{}endbfchar\nendcmap\nend end",
+            chars.chars().count(),
+            entries
+        );
+        let font = format!(
+            "<< /Type /Font /Subtype /Type0 /BaseFont /MS-Mincho /Encoding /Identity-{} /DescendantFonts [<< /Type /Font /Subtype /CIDFontType2 /DW 1000 >>] /ToUnicode 6 0 R >>",
+            if vertical { "V" } else { "H" }
+        );
+        (font, stream("", cmap.as_bytes()))
+    }
+
+    #[test]
+    fn test_extract_lines_and_spacing() {
+        let content =
+            b"BT /F1 12 Tf 72 720 Td (Article 1) Tj 0 -14 Td (Scope of this) Tj ( Act) Tj \
+            0 -14 Td [(The) -300 (Act) -300 (ap) 20 (plies.)] TJ ET";
+        let text = extract(&document(content, &[HELVETICA], &[]));
+
+        assert_eq!(
+            lines(&text),
+            vec!["Article 1", "Scope of this Act", "The Act applies."]
+        );
+        let first = &text.pages[0].lines[0];
+        assert!((first.x - 72.0).abs() < 0.01);
+        assert!((first.y - 122.0).abs() < 0.01);
+        assert!((first.font_size - 12.0).abs() < 0.01);
+        assert_eq!(
+            text.text(),
+            "Article 1\nScope of this Act\nThe Act applies."
+        );
+    }
+
+    #[test]
+    fn test_string_escapes_and_encodings() {
+        let content = b"BT /F1 10 Tf 72 720 Td (\\(a\\) caf\\351 \\223quoted\\224) Tj \
+            0 -14 Td <48656C6C6F> Tj 0 -14 Td /F2 10 Tf (A 1) Tj ET";
+        let differences = "<< /Type /Font /Subtype /Type1 /BaseFont /Times-Roman \
+            /Encoding << /Type /Encoding /BaseEncoding /WinAnsiEncoding /Differences [65 /section] >> >>";
+        let text = extract(&document(content, &[HELVETICA, differences], &[]));
+
+        assert_eq!(lines(&text), vec!["(a) café “quoted”", "Hello", "§ 1"]);
+    }
+
+    #[test]
+    fn test_compressed_objects_and_streams() {
+        let content = deflate(b"BT /F1 12 Tf 72 720 Td (Compressed text) Tj ET");
+        let mut header = String::new();
+        let mut body = String::new();
+        for (index, object) in [
+            "<< /Type /Catalog /Pages 2 0 R >>",
+            "<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            "<< /Type /Page /Parent 2 0 R /Resources << /Font << /F1 5 0 R >> >> /Contents 4 0 R >>",
+        ]
+        .iter()
+        .enumerate()
+        {
+            header.push_str(&format!("{} {} ", index + 1, body.len()));
+            body.push_str(object);
+            body.push(' ');
+        }
+        let object_stream = stream(
+            &format!(
+                "/Type /ObjStm /N 3 /First {} /Filter /FlateDecode",
+                header.len()
+            ),
+            &deflate(format!("{}{}", header, body).as_bytes()),
+        );
+        let data = pdf(
+            &[
+                b"<< /Type /Catalog /Pages 99 0 R >>".to_vec(),
+                b"null".to_vec(),
+                b"null".to_vec(),
+                stream("/Filter [/FlateDecode]", &content),
+                HELVETICA.as_bytes().to_vec(),
+                object_stream,
+                stream("/Type /XRef /Size 8 /Root 1 0 R /W [1 2 1]", b"\x00"),
+            ],
+            None,
+        );
+        let text = extract(&data);
+
+        assert_eq!(lines(&text), vec!["Compressed text"]);
+    }
+
+    #[test]
+    fn test_stream_filters() {
+        assert_eq!(ascii_hex_decode(b"48 65 6C6c6F>"), b"Hello");
+        assert_eq!(ascii85_decode(b"87cURD]i,\"Ebo7~>"), b"Hello World");
+        assert_eq!(
+            run_length_decode(&[2, b'a', b'b', b'c', 254, b'x', 128]),
+            b"abcxxx"
+        );
+        // 9-bit codes A, B, AB, AB, end of data
+        assert_eq!(
+            lzw_decode(&[0x20, 0x90, 0xa0, 0x50, 0x28, 0x08], true),
+            b"ABABAB"
+        );
+
+        // Flate output is capped so that small streams cannot expand without bound
+        let bomb = deflate(&[0; 4096]);
+        assert_eq!(inflate(&bomb, 100).unwrap(), (vec![0; 100], true));
+        assert_eq!(inflate(&bomb, 4096).unwrap(), (vec![0; 4096], false));
+
+        // CIDs near the top of the range saturate instead of overflowing
+        let cmap = parse_cmap(b"1 begincidrange <0000> <0002> 4294967295 endcidrange");
+        assert_eq!(cmap.cids[&2], u32::MAX);
+
+        // PNG Up predictor over two rows of three bytes
+        let encoded = [2, 1, 2, 3, 2, 1, 1, 1];
+        assert_eq!(png_predict(&encoded, 12, 1, 8, 3), vec![1, 2, 3, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_type0_font_with_to_unicode() {
+        let (font, cmap) = cjk_font(false, "第一条この法律");
+        let content =
+            b"BT /F1 10.5 Tf 1 0 0 1 72 720 Tm <00010002000300040005> Tj [<0006> 0 <0007>] TJ ET";
+        let text = extract(&document(content, &[&font], &[cmap]));
+
+        assert_eq!(lines(&text), vec!["第一条この法律"]);
+        assert!(text.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_two_columns_in_reading_order() {
+        let content = b"BT /F1 10 Tf \
+            1 0 0 1 50 800 Tm (Heading that spans both of the columns on this page of the act) Tj \
+            1 0 0 1 50 760 Tm (Left column line one) Tj \
+            1 0 0 1 320 760 Tm (Right column line one) Tj \
+            1 0 0 1 50 748 Tm (Left column line two) Tj \
+            1 0 0 1 320 748 Tm (Right column line two) Tj \
+            1 0 0 1 50 736 Tm (Left column line three) Tj \
+            1 0 0 1 50 40 Tm (Footer spanning the width of the whole page below the columns) Tj ET";
+        let text = extract(&document(content, &[HELVETICA], &[]));
+
+        assert_eq!(
+            lines(&text),
+            vec![
+                "Heading that spans both of the columns on this page of the act",
+                "Left column line one",
+                "Left column line two",
+                "Left column line three",
+                "Right column line one",
+                "Right column line two",
+                "Footer spanning the width of the whole page below the columns",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_vertical_writing() {
+        let (font, cmap) = cjk_font(true, "第一条目的");
+        let content = b"BT /F1 12 Tf 1 0 0 1 500 700 Tm <000100020003> Tj \
+            1 0 0 1 484 700 Tm <00040005> Tj ET";
+        let text = extract(&document(content, &[&font], &[cmap]));
+
+        assert_eq!(lines(&text), vec!["第一条", "目的"]);
+    }
+
+    #[test]
+    fn test_form_xobjects_and_overprinted_bold() {
+        let form = stream(
+            "/Type /XObject /Subtype /Form /BBox [0 0 595 842] /Matrix [1 0 0 1 0 -100]",
+            b"BT /F1 12 Tf 72 700 Td (From a form) Tj ET",
+        );
+        let content = b"BT /F1 12 Tf 72 720 Td (Bold) Tj 0.3 0 Td (Bold) Tj ET q /Fm1 Do Q";
+        let text = extract(&document(content, &[HELVETICA], &[form]));
+
+        assert_eq!(lines(&text), vec!["Bold", "From a form"]);
+    }
+
+    #[test]
+    fn test_scanned_page_warns() {
+        let image = stream(
+            "/Type /XObject /Subtype /Image /Width 1 /Height 1 /ColorSpace /DeviceGray /BitsPerComponent 8",
+            b"\x00",
+        );
+        let data = document(
+            b"q 595 0 0 842 0 0 cm /Im1 Do Q",
+            &[HELVETICA],
+            &[b"null".to_vec(), image],
+        );
+        let text = extract(&data);
+
+        assert!(text.pages[0].lines.is_empty());
+        assert!(text.warnings.iter().any(|warning| warning.contains("OCR")));
+    }
+
+    #[test]
+    fn test_document_info() {
+        let info = "<< /Title <FEFF6C116CD5> /Author (Diet) >>";
+        let mut extra = vec![b"null".to_vec(); 13];
+        extra.push(info.as_bytes().to_vec());
+        let text = extract(&document(b"", &[HELVETICA], &extra));
+
+        assert_eq!(text.title(), Some("民法"));
+        assert_eq!(text.info.get("Author").map(String::as_str), Some("Diet"));
+    }
+
+    #[test]
+    fn test_rejects_invalid_and_encrypted_files() {
+        let extractor = PdfTextExtractor::new();
+        assert!(matches!(
+            extractor.extract(b"not a pdf"),
+            Err(InteropError::ParseError(_))
+        ));
+
+        let data = pdf(
+            &[
+                b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+                b"<< /Type /Pages /Kids [] /Count 0 >>".to_vec(),
+                b"<< /Filter /Standard /V 2 /R 3 >>".to_vec(),
+            ],
+            Some("<< /Size 4 /Root 1 0 R /Encrypt 3 0 R >>"),
+        );
+        assert!(matches!(
+            extractor.extract(&data),
+            Err(InteropError::UnsupportedFeature(_))
+        ));
+    }
+}
@@ -0,0 +1,2113 @@
+//! Segmentation of statute text into structural units.
+//!
+//! [`StatuteSegmenter`] recognises the numbering of parts, chapters, articles,
+//! paragraphs and items in plain or PDF-extracted text, following the drafting
+//! conventions of a jurisdiction:
+//!
+//! | Scheme | Groupings | Articles | Paragraphs | Items |
+//! |--------|-----------|----------|------------|-------|
+//! | Japanese | 第X編, 第X章, 第X節 | 第X条, captions （…） | ２, ３, ... | 一, 二, ... |
+//! | German | Buch, Teil, Kapitel, Abschnitt, Titel | § X, Art. X | (1) | 1., a) |
+//! | French | Livre, Titre, Chapitre, Section | Article X, Article L. X-Y | I. - | 1°, a) |
+//! | European | Part, Title, Chapter, Section | Article X | 1. | (a) |
+//! | American | Title, Chapter, Subchapter | Section X, SEC. X., § X | (a) | (1) |
+//! | British | Part, Chapter | Section X, numbered headings | (1) | (a) |
+//!
+//! Article-level units are identified as `art-N` in every scheme, including US
+//! and UK sections. Each unit records the byte range and page of its source
+//! text so that statutes built from it can be traced back to the document.
+//! Running headers, page numbers and tables of contents are skipped, footnotes
+//! are set apart, and cross-references between articles are resolved.
+
+use crate::pdf_text::{PdfText, is_cjk};
+use legalis_core::{Effect, EffectType, Statute, StatuteHierarchy};
+use regex_lite::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Numbering conventions of a legal system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NumberingScheme {
+    /// Japanese statutes (第X条)
+    Japanese,
+    /// German, Austrian and Swiss statutes (§ X, Art. X)
+    German,
+    /// French and Belgian codes and statutes (Article X)
+    French,
+    /// European Union acts (Article X)
+    European,
+    /// United States Code and federal bills (Section X, § X)
+    American,
+    /// United Kingdom and Irish Acts (numbered sections)
+    British,
+}
+
+impl NumberingScheme {
+    /// All schemes, in the order used to break ties when detecting a scheme.
+    pub const ALL: [NumberingScheme; 6] = [
+        NumberingScheme::Japanese,
+        NumberingScheme::German,
+        NumberingScheme::French,
+        NumberingScheme::European,
+        NumberingScheme::American,
+        NumberingScheme::British,
+    ];
+
+    /// Returns the scheme used in a jurisdiction, given its ISO 3166 code (or `EU`).
+    pub fn for_jurisdiction(code: &str) -> Option<Self> {
+        match code.to_uppercase().as_str() {
+            "JP" => Some(NumberingScheme::Japanese),
+            "DE" | "AT" | "CH" | "LI" => Some(NumberingScheme::German),
+            "FR" | "BE" | "LU" | "MC" => Some(NumberingScheme::French),
+            "EU" => Some(NumberingScheme::European),
+            "US" => Some(NumberingScheme::American),
+            "GB" | "UK" | "IE" => Some(NumberingScheme::British),
+            _ => None,
+        }
+    }
+}
+
+/// Kind of structural unit, from the outermost to the innermost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum UnitKind {
+    /// Book, part or title
+    Part,
+    /// Chapter
+    Chapter,
+    /// Section or subdivision grouping articles
+    Section,
+    /// Article, or section in US and UK legislation
+    Article,
+    /// Numbered paragraph of an article
+    Paragraph,
+    /// Item of an enumeration
+    Item,
+}
+
+impl UnitKind {
+    fn prefix(self) -> &'static str {
+        match self {
+            UnitKind::Part => "part",
+            UnitKind::Chapter => "chp",
+            UnitKind::Section => "sec",
+            UnitKind::Article => "art",
+            UnitKind::Paragraph => "para",
+            UnitKind::Item => "item",
+        }
+    }
+
+    fn is_grouping(self) -> bool {
+        self < UnitKind::Article
+    }
+}
+
+/// Location of a piece of text in the segmented source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceSpan {
+    /// Page on which the text starts, from 1
+    pub page: usize,
+    /// Byte offset of the start in [`Segmentation::text`]
+    pub start: usize,
+    /// Byte offset of the end in [`Segmentation::text`]
+    pub end: usize,
+}
+
+/// A structural unit of a statute.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Provision {
+    /// Unit identifier, such as `chp-1`, `art-12-2` or `art-5-para-2`
+    pub id: String,
+    /// Kind of unit
+    pub kind: UnitKind,
+    /// Number as printed, such as `十二条の二` or `5a`
+    pub number: String,
+    /// Numbering label as printed, such as `第十二条の二` or `§ 5a`
+    pub label: String,
+    /// Heading or caption
+    pub heading: Option<String>,
+    /// Text of the unit, including its paragraphs and items
+    pub text: String,
+    /// Source range from the label to the end of the unit
+    pub span: SourceSpan,
+    /// Enclosing unit
+    pub parent: Option<String>,
+    /// Directly enclosed units
+    pub children: Vec<String>,
+    /// References made in the text of the unit itself
+    pub references: Vec<CrossReference>,
+}
+
+/// A reference to another provision.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrossReference {
+    /// Reference as written
+    pub text: String,
+    /// Source range of the reference
+    pub span: SourceSpan,
+    /// Referenced unit, when it was found in this document
+    pub target: Option<String>,
+    /// Whether the reference points to another instrument
+    pub external: bool,
+}
+
+/// A footnote set apart from the body text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Footnote {
+    /// Footnote marker, such as `1` or `*`
+    pub marker: String,
+    /// Footnote text
+    pub text: String,
+    /// Source range of the footnote
+    pub span: SourceSpan,
+    /// Article in force where the footnote appears
+    pub provision: Option<String>,
+}
+
+/// Result of segmenting a statute.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Segmentation {
+    /// Numbering scheme that was applied
+    pub scheme: NumberingScheme,
+    /// Jurisdiction given to the segmenter
+    pub jurisdiction: Option<String>,
+    /// Document title
+    pub title: Option<String>,
+    /// Source text that spans refer to
+    pub text: String,
+    /// Units in document order
+    pub provisions: Vec<Provision>,
+    /// Footnotes in document order
+    pub footnotes: Vec<Footnote>,
+}
+
+impl Segmentation {
+    /// Returns the unit with the given ID.
+    pub fn provision(&self, id: &str) -> Option<&Provision> {
+        self.provisions.iter().find(|provision| provision.id == id)
+    }
+
+    /// Returns the article-level units.
+    pub fn articles(&self) -> impl Iterator<Item = &Provision> {
+        self.provisions
+            .iter()
+            .filter(|provision| provision.kind == UnitKind::Article)
+    }
+
+    /// Returns the source text of a span.
+    pub fn source(&self, span: &SourceSpan) -> &str {
+        self.text.get(span.start..span.end).unwrap_or("")
+    }
+
+    /// Builds a statute skeleton for every article.
+    ///
+    /// The article text becomes the description of a custom effect, whose
+    /// parameters record the label and source location for provenance.
+    pub fn to_statutes(&self) -> Vec<Statute> {
+        self.articles()
+            .map(|article| {
+                let title = match &article.heading {
+                    Some(heading) => format!("{} {}", article.label, heading),
+                    None => article.label.clone(),
+                };
+                let effect = Effect::new(EffectType::Custom, &article.text)
+                    .with_parameter("label", &article.label)
+                    .with_parameter("source_page", article.span.page.to_string())
+                    .with_parameter("source_start", article.span.start.to_string())
+                    .with_parameter("source_end", article.span.end.to_string());
+                let statute = Statute::new(&article.id, title, effect);
+                match &self.jurisdiction {
+                    Some(jurisdiction) => statute.with_jurisdiction(jurisdiction),
+                    None => statute,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the structure of the document keyed by unit ID.
+    ///
+    /// Articles list the resolved cross-references of all their paragraphs
+    /// and items; other units list their own.
+    pub fn hierarchy(&self) -> HashMap<String, StatuteHierarchy> {
+        let mut hierarchy = HashMap::new();
+        for provision in &self.provisions {
+            let mut entry = StatuteHierarchy::new();
+            if let Some(parent) = &provision.parent {
+                entry = entry.with_parent(parent);
+            }
+            for child in &provision.children {
+                entry = entry.with_child(child);
+            }
+            let mut targets = Vec::new();
+            let mut pending = vec![provision];
+            while let Some(unit) = pending.pop() {
+                for reference in &unit.references {
+                    if let Some(target) = &reference.target
+                        && *target != provision.id
+                        && !targets.contains(target)
+                    {
+                        targets.push(target.clone());
+                    }
+                }
+                if provision.kind == UnitKind::Article {
+                    pending.extend(unit.children.iter().filter_map(|id| self.provision(id)));
+                }
+            }
+            for target in targets {
+                entry = entry.with_cross_reference(target);
+            }
+            hierarchy.insert(provision.id.clone(), entry);
+        }
+        hierarchy
+    }
+}
+
+/// Splits statute text into articles, paragraphs and items.
+#[derive(Debug, Clone, Default)]
+pub struct StatuteSegmenter {
+    scheme: Option<NumberingScheme>,
+    jurisdiction: Option<String>,
+}
+
+impl StatuteSegmenter {
+    /// Creates a segmenter that detects the numbering scheme from the text.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the jurisdiction of the statutes, which also selects its numbering scheme.
+    pub fn with_jurisdiction(mut self, jurisdiction: impl Into<String>) -> Self {
+        let jurisdiction = jurisdiction.into();
+        if let Some(scheme) = NumberingScheme::for_jurisdiction(&jurisdiction) {
+            self.scheme = Some(scheme);
+        }
+        self.jurisdiction = Some(jurisdiction);
+        self
+    }
+
+    /// Sets the numbering scheme explicitly.
+    pub fn with_scheme(mut self, scheme: NumberingScheme) -> Self {
+        self.scheme = Some(scheme);
+        self
+    }
+
+    /// Segments plain text; form feeds (`\x0c`) separate pages.
+    pub fn segment(&self, text: &str) -> Segmentation {
+        let lines = text_lines(text);
+        self.segment_lines(text, &lines, None)
+    }
+
+    /// Segments text extracted from a PDF, using its layout to tell footnotes
+    /// from body text. Spans refer to [`PdfText::text`].
+    pub fn segment_pdf(&self, pdf: &PdfText) -> Segmentation {
+        let text = pdf.text();
+        let mut lines = Vec::new();
+        let mut offset = 0;
+        for (page_index, page) in pdf.pages.iter().enumerate() {
+            if page_index > 0 {
+                offset += 1;
+            }
+            for (line_index, line) in page.lines.iter().enumerate() {
+                if line_index > 0 {
+                    offset += 1;
+                }
+                let leading = line.text.len() - line.text.trim_start().len();
+                let trimmed = line.text.trim();
+                if !trimmed.is_empty() {
+                    lines.push(Line {
+                        text: trimmed,
+                        start: offset + leading,
+                        end: offset + leading + trimmed.len(),
+                        page: page.number,
+                        font_size: Some(line.font_size),
+                        position: (page.height > 0.0).then(|| line.y / page.height),
+                    });
+                }
+                offset += line.text.len();
+            }
+        }
+        let lines: Vec<Line> = lines
+            .into_iter()
+            .map(|line| Line {
+                text: &text[line.start..line.end],
+                ..line
+            })
+            .collect();
+        self.segment_lines(&text, &lines, pdf.title())
+    }
+
+    fn segment_lines(&self, text: &str, lines: &[Line], title: Option<&str>) -> Segmentation {
+        let scheme = self.scheme.unwrap_or_else(|| detect_scheme(text, lines));
+        let mut segmentation = Parser::new(scheme, lines).run();
+        if let Some(title) = title {
+            segmentation.title = Some(title.to_string());
+        }
+        segmentation.jurisdiction = self.jurisdiction.clone();
+        segmentation.text = text.to_string();
+        segmentation
+    }
+}
+
+/// Picks the scheme that finds the most units.
+fn detect_scheme(text: &str, lines: &[Line]) -> NumberingScheme {
+    let cjk = text.chars().any(is_cjk);
+    NumberingScheme::ALL
+        .into_iter()
+        .filter(|scheme| cjk || *scheme != NumberingScheme::Japanese)
+        .map(|scheme| (scheme, Parser::new(scheme, lines).run().provisions.len()))
+        .fold(
+            None,
+            |best: Option<(NumberingScheme, usize)>, (scheme, count)| match best {
+                Some((_, best_count)) if best_count >= count => best,
+                _ => Some((scheme, count)),
+            },
+        )
+        .map(|(scheme, _)| scheme)
+        .unwrap_or(NumberingScheme::European)
+}
+
+// ============================================================================
+// Lines
+// ============================================================================
+
+#[derive(Debug, Clone, Copy)]
+struct Line<'a> {
+    text: &'a str,
+    start: usize,
+    end: usize,
+    page: usize,
+    font_size: Option<f64>,
+    /// Vertical position on the page, from 0 at the top to 1 at the bottom
+    position: Option<f64>,
+}
+
+fn text_lines(text: &str) -> Vec<Line<'_>> {
+    let mut lines = Vec::new();
+    let mut offset = 0;
+    for (page_index, page) in text.split('\x0c').enumerate() {
+        for raw in page.split('\n') {
+            let leading = raw.len() - raw.trim_start().len();
+            let trimmed = raw.trim();
+            if !trimmed.is_empty() {
+                lines.push(Line {
+                    text: trimmed,
+                    start: offset + leading,
+                    end: offset + leading + trimmed.len(),
+                    page: page_index + 1,
+                    font_size: None,
+                    position: None,
+                });
+            }
+            offset += raw.len() + 1;
+        }
+    }
+    lines
+}
+
+/// Finds running headers, running footers and page numbers.
+fn page_furniture(lines: &[Line]) -> HashSet<usize> {
+    let page_number = Regex::new(
+        r"^(?:[-–—－]\s*)?(?:(?i:page|seite|p\.)\s*)?[0-9０-９]+(?:\s*(?:/|(?i:of|von|sur))\s*[0-9]+)?(?:\s*[-–—－])?$",
+    )
+    .unwrap();
+
+    let mut by_page: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for (index, line) in lines.iter().enumerate() {
+        by_page.entry(line.page).or_default().push(index);
+    }
+    let mut edges = Vec::new();
+    let mut outer = Vec::new();
+    for indices in by_page.values() {
+        let count = indices.len();
+        for (position, index) in indices.iter().enumerate() {
+            if position < 2 || position + 2 >= count {
+                edges.push(*index);
+            }
+            if position == 0 || position + 1 == count {
+                outer.push(*index);
+            }
+        }
+    }
+
+    let mut furniture: HashSet<usize> = edges
+        .iter()
+        .copied()
+        .filter(|index| page_number.is_match(lines[*index].text))
+        .collect();
+
+    // First or last lines repeated on most pages, ignoring page numbers
+    if by_page.len() >= 3 {
+        let key = |text: &str| -> String {
+            text.chars()
+                .map(|c| if c.is_numeric() { '#' } else { c })
+                .filter(|c| !c.is_whitespace())
+                .flat_map(char::to_lowercase)
+                .collect()
+        };
+        let mut pages: HashMap<String, HashSet<usize>> = HashMap::new();
+        for index in &outer {
+            pages
+                .entry(key(lines[*index].text))
+                .or_default()
+                .insert(lines[*index].page);
+        }
+        let threshold = 3.max(by_page.len().div_ceil(2));
+        furniture.extend(
+            outer
+                .iter()
+                .filter(|index| pages[&key(lines[**index].text)].len() >= threshold),
+        );
+    }
+    furniture
+}
+
+/// Role of a line in a footnote area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FootnoteLine {
+    Separator,
+    Start,
+    Continuation,
+}
+
+/// Finds footnote areas: below a separator rule in plain text, or set in
+/// small type in the lower part of a PDF page.
+fn footnote_lines(lines: &[Line], marker: &Regex) -> HashMap<usize, FootnoteLine> {
+    let separator = Regex::new(r"^[_\-‐–—─━＿]{5,}$").unwrap();
+    let mut result = HashMap::new();
+
+    // Body size: the most common font size, weighted by characters
+    let mut sizes: HashMap<i64, usize> = HashMap::new();
+    for line in lines {
+        if let Some(size) = line.font_size {
+            *sizes.entry((size * 2.0).round() as i64).or_default() += line.text.chars().count();
+        }
+    }
+    let body_size = sizes
+        .into_iter()
+        .max_by_key(|(size, count)| (*count, *size))
+        .map(|(size, _)| size as f64 / 2.0);
+
+    let mut index = 0;
+    while index < lines.len() {
+        let page = lines[index].page;
+        let end = lines[index..]
+            .iter()
+            .position(|line| line.page != page)
+            .map_or(lines.len(), |offset| index + offset);
+
+        match body_size {
+            Some(body) => {
+                let small = |line: &Line| line.font_size.is_some_and(|size| size <= 0.88 * body);
+                if let Some(first) = (index..end).find(|&i| {
+                    lines[i].position.is_some_and(|position| position >= 0.6)
+                        && small(&lines[i])
+                        && marker.is_match(lines[i].text)
+                }) {
+                    for (i, line) in lines.iter().enumerate().take(end).skip(first) {
+                        if small(line) {
+                            let role = if i == first || marker.is_match(line.text) {
+                                FootnoteLine::Start
+                            } else {
+                                FootnoteLine::Continuation
+                            };
+                            result.insert(i, role);
+                        }
+                    }
+                }
+            }
+            None => {
+                if let Some(rule) = (index..end).find(|&i| separator.is_match(lines[i].text)) {
+                    result.insert(rule, FootnoteLine::Separator);
+                    for (i, line) in lines.iter().enumerate().take(end).skip(rule + 1) {
+                        let role = if i == rule + 1 || marker.is_match(line.text) {
+                            FootnoteLine::Start
+                        } else {
+                            FootnoteLine::Continuation
+                        };
+                        result.insert(i, role);
+                    }
+                }
+            }
+        }
+        index = end;
+    }
+    result
+}
+
+// ============================================================================
+// Numbering rules
+// ============================================================================
+
+const KANJI_DIGITS: &str = "〇零一二三四五六七八九十百千";
+
+struct Pattern {
+    kind: UnitKind,
+    regex: Regex,
+    /// Bare numbers, accepted only in strict sequence
+    strict: bool,
+}
+
+struct Rules {
+    scheme: NumberingScheme,
+    patterns: Vec<Pattern>,
+    supplementary: Option<Regex>,
+    caption: Option<Regex>,
+    references: Vec<Regex>,
+    toc: Regex,
+    footnote: Regex,
+    /// Words after an article label that show the line is a reference, not a heading
+    reference_words: Option<Regex>,
+    /// Text after an article label is its heading rather than body text
+    rest_is_heading: bool,
+    /// A short line after a bare article label is its heading
+    heading_on_next_line: bool,
+    first_paragraph: u32,
+}
+
+fn pattern(kind: UnitKind, regex: &str) -> Pattern {
+    Pattern {
+        kind,
+        regex: Regex::new(regex).unwrap(),
+        strict: false,
+    }
+}
+
+/// Builds a Latin-script grouping pattern, such as "CHAPTER II" or "Erster Abschnitt".
+fn grouping(kind: UnitKind, keywords: &str, number: &str) -> Pattern {
+    pattern(
+        kind,
+        &format!(
+            r"^(?P<label>(?:(?P<word>\p{{L}}+)\s+)?(?i:{keywords})(?:\s+(?P<num>{number}))?)(?:\s*[.:–—-])?(?:\s+(?P<rest>.*))?$"
+        )
+        .replace(r"\p{L}", "[A-Za-zÀ-ÿ]"),
+    )
+}
+
+impl Rules {
+    fn new(scheme: NumberingScheme) -> Self {
+        let latin_number = r"[0-9]+[A-Za-z]?|[IVXLCDM]+(?:er)?|(?i:one|two|three|four|five|six|seven|eight|nine|ten|premier|première|préliminaire)";
+        let footnote = Regex::new(
+            r"^(?P<marker>\(?[0-9]{1,3}\)|\[[0-9]{1,3}\]|[0-9]{1,3}[.)]?|\*{1,3}|[†‡]+|注[0-9０-９]*)[\s　]+(?P<text>.+)$",
+        )
+        .unwrap();
+        let toc =
+            Regex::new(r"(?:\.{3,}|…{2,}|(?:\s\.){3,})\s*[0-9]+$|[（(]第[^）)]*条[）)]$").unwrap();
+        let mut rules = Rules {
+            scheme,
+            patterns: Vec::new(),
+            supplementary: None,
+            caption: None,
+            references: Vec::new(),
+            toc,
+            footnote,
+            reference_words: None,
+            rest_is_heading: true,
+            heading_on_next_line: false,
+            first_paragraph: 1,
+        };
+
+        match scheme {
+            NumberingScheme::Japanese => {
+                let n = format!("[{}0-9０-９]+", KANJI_DIGITS);
+                let heading = |kind, units: &str| {
+                    pattern(
+                        kind,
+                        &format!(
+                            r"^(?P<label>第(?P<num>{n})[{units}](?P<sub>(?:の{n})*))(?:[\s　]+(?P<rest>.*))?$"
+                        ),
+                    )
+                };
+                rules.patterns = vec![
+                    heading(UnitKind::Part, "編"),
+                    heading(UnitKind::Chapter, "章"),
+                    heading(UnitKind::Section, "節款目"),
+                    heading(UnitKind::Article, "条"),
+                    pattern(
+                        UnitKind::Paragraph,
+                        r"^(?P<label>(?P<num>[0-9０-９]+))[\s　]+(?P<rest>.+)$",
+                    ),
+                    pattern(
+                        UnitKind::Item,
+                        &format!(
+                            r"^(?P<label>(?P<num>[{}]+))[\s　]+(?P<rest>.+)$",
+                            KANJI_DIGITS
+                        ),
+                    ),
+                ];
+                rules.supplementary =
+                    Some(Regex::new(r"^(?P<label>附[\s　]*則)(?:[\s　]*(?P<rest>.*))?$").unwrap());
+                rules.caption =
+                    Some(Regex::new(r"^[（(](?P<caption>[^（）()]{1,40})[）)]$").unwrap());
+                rules.references = vec![
+                    Regex::new(&format!(
+                        r"(?P<same>同法)?第(?P<art>{n})条(?P<sub>(?:の{n})*)(?:第(?P<para>{n})項)?(?:第{n}号)?"
+                    ))
+                    .unwrap(),
+                    Regex::new(r"(?P<rel>前条|次条|前項|次項)").unwrap(),
+                ];
+                rules.rest_is_heading = false;
+                rules.first_paragraph = 2;
+            }
+            NumberingScheme::German => {
+                let number = r"[0-9]+|[IVXLC]+";
+                rules.patterns = vec![
+                    grouping(UnitKind::Part, "Buch|Teil", number),
+                    grouping(UnitKind::Chapter, "Kapitel|Abschnitt", number),
+                    grouping(UnitKind::Section, "Unterabschnitt|Titel|Untertitel", number),
+                    pattern(
+                        UnitKind::Article,
+                        r"^(?P<label>(?:§|Art\.|Artikel)\s*(?P<num>[0-9]+[a-z]?))(?:\s+(?P<rest>.*))?$",
+                    ),
+                    pattern(
+                        UnitKind::Paragraph,
+                        r"^(?P<label>\((?P<num>[0-9]+[a-z]?)\))\s*(?P<rest>.*)$",
+                    ),
+                    pattern(
+                        UnitKind::Item,
+                        r"^(?P<label>(?P<num>[0-9]+)\.)\s+(?P<rest>.+)$",
+                    ),
+                    pattern(
+                        UnitKind::Item,
+                        r"^(?P<label>(?P<num>[a-z])\))\s+(?P<rest>.+)$",
+                    ),
+                ];
+                rules.references = vec![
+                    Regex::new(
+                        r"(?:§§?|Art\.|Artikel)\s*(?P<art>[0-9]+[a-z]?)(?:\s+(?:Abs\.|Absatz)\s*(?P<para>[0-9]+))?(?:\s+(?:Satz|S\.)\s*[0-9]+)?(?:\s+(?:Nr\.|Nummer)\s*[0-9]+)?(?P<of>\s+(?:[A-Z][A-Za-zÄÖÜäöü]*[A-Z][a-z]*\b|(?:des|der|dieses)\s+[A-ZÄÖÜ][\wäöüß]*))?",
+                    )
+                    .unwrap(),
+                ];
+                rules.reference_words = Some(
+                    Regex::new(r"^(?:Abs\.|Absatz|Satz|S\.|Nr\.|Nummer|Hs\.|[A-Z][A-Za-zäöü]*[A-Z][a-z]*\b)")
+                        .unwrap(),
+                );
+                rules.heading_on_next_line = true;
+            }
+            NumberingScheme::French => {
+                rules.patterns = vec![
+                    grouping(UnitKind::Part, "Livre|Partie", latin_number),
+                    grouping(UnitKind::Chapter, "Titre|Chapitre", latin_number),
+                    grouping(UnitKind::Section, "Section|Sous-section", latin_number),
+                    pattern(
+                        UnitKind::Article,
+                        r"^(?P<label>(?:Article|ARTICLE|Art\.)\s+(?P<num>(?:[LRDA]\.?\s*)?[0-9]+(?:[-.][0-9]+)*|premier|unique)(?:er)?(?:\s+(?P<suffix>bis|ter|quater|quinquies))?)\s*(?:[.:–—-]\s*)?(?P<rest>.*)$",
+                    ),
+                    pattern(
+                        UnitKind::Paragraph,
+                        r"^(?P<label>(?P<num>[IVX]+)\.)\s*[-–—]?\s*(?P<rest>.+)$",
+                    ),
+                    pattern(
+                        UnitKind::Item,
+                        r"^(?P<label>(?P<num>[0-9]+)°)\s*(?P<rest>.+)$",
+                    ),
+                    pattern(
+                        UnitKind::Item,
+                        r"^(?P<label>(?P<num>[a-z])\))\s+(?P<rest>.+)$",
+                    ),
+                ];
+                rules.references = vec![
+                    Regex::new(
+                        r"\b[Aa]rticles?\s+(?P<art>(?:[LRD]\.?\s*)?[0-9]+(?:-[0-9]+)*)(?:er)?(?P<of>\s+(?:du|de la|de l'|de l’|des)\s+\S+)?",
+                    )
+                    .unwrap(),
+                ];
+                rules.rest_is_heading = false;
+            }
+            NumberingScheme::European => {
+                rules.patterns = vec![
+                    grouping(UnitKind::Part, "Part", latin_number),
+                    grouping(UnitKind::Chapter, "Title|Chapter", latin_number),
+                    grouping(UnitKind::Section, "Section|Sub-section", latin_number),
+                    pattern(
+                        UnitKind::Article,
+                        r"^(?P<label>(?:Article|ARTICLE|Art\.)\s+(?P<num>[0-9]+[a-z]?))(?:\s+(?P<rest>.*))?$",
+                    ),
+                    pattern(
+                        UnitKind::Paragraph,
+                        r"^(?P<label>(?P<num>[0-9]+)\.)\s+(?P<rest>.+)$",
+                    ),
+                    pattern(
+                        UnitKind::Item,
+                        r"^(?P<label>\((?P<num>[a-z]{1,2})\))\s*(?P<rest>.+)$",
+                    ),
+                ];
+                rules.references = vec![
+                    Regex::new(
+                        r"\bArticles?\s+(?P<art>[0-9]+[a-z]?)(?:\s*\((?P<para>[0-9]+)\))?(?:\s*\([a-z]+\))*(?P<of>\s+of\s+\S+)?",
+                    )
+                    .unwrap(),
+                ];
+                rules.heading_on_next_line = true;
+            }
+            NumberingScheme::American => {
+                rules.patterns = vec![
+                    grouping(UnitKind::Part, "Title", latin_number),
+                    grouping(UnitKind::Chapter, "Subtitle|Chapter", latin_number),
+                    grouping(UnitKind::Section, "Subchapter|Part|Subpart", latin_number),
+                    pattern(
+                        UnitKind::Article,
+                        r"^(?P<label>(?:Section|SECTION|Sec\.|SEC\.|§)\s*(?P<num>[0-9]+[A-Za-z]?(?:-[0-9]+)?))\.?(?:\s+(?P<rest>.*))?$",
+                    ),
+                    pattern(
+                        UnitKind::Paragraph,
+                        r"^(?P<label>\((?P<num>[a-z])\))\s*(?P<rest>.*)$",
+                    ),
+                    pattern(
+                        UnitKind::Item,
+                        r"^(?P<label>\((?P<num>[0-9]+)\))\s*(?P<rest>.*)$",
+                    ),
+                ];
+                rules.references = vec![
+                    Regex::new(r"(?P<law>[0-9]+\s+U\.S\.C\.\s+§+\s*[0-9]+[a-z]?)").unwrap(),
+                    Regex::new(
+                        r"(?:\b[Ss]ections?|§)\s*(?P<art>[0-9]+[A-Za-z]?(?:-[0-9]+)?)(?:\((?P<para>[a-z])\))?(?:\([0-9A-Za-z]+\))*(?P<of>\s+of\s+(?:the\s+)?\S+)?",
+                    )
+                    .unwrap(),
+                ];
+            }
+            NumberingScheme::British => {
+                rules.patterns = vec![
+                    grouping(UnitKind::Part, "Part", r"[0-9]+[A-Z]?|[IVXLC]+"),
+                    grouping(UnitKind::Chapter, "Chapter", r"[0-9]+[A-Z]?|[IVXLC]+"),
+                    pattern(
+                        UnitKind::Article,
+                        r"^(?P<label>(?:Section|SECTION)\s+(?P<num>[0-9]+[A-Z]{0,2}))\.?(?:\s+(?P<rest>.*))?$",
+                    ),
+                    Pattern {
+                        kind: UnitKind::Article,
+                        regex: Regex::new(
+                            r"^(?P<label>(?P<num>[0-9]+[A-Z]{0,2}))\s+(?P<rest>[A-Z][^.;:]{0,80})$",
+                        )
+                        .unwrap(),
+                        strict: true,
+                    },
+                    pattern(
+                        UnitKind::Paragraph,
+                        r"^(?P<label>\((?P<num>[0-9]+[A-Z]?)\))\s*(?P<rest>.*)$",
+                    ),
+                    pattern(
+                        UnitKind::Item,
+                        r"^(?P<label>\((?P<num>[a-z]{1,2})\))\s*(?P<rest>.*)$",
+                    ),
+                ];
+                rules.references = vec![
+                    Regex::new(
+                        r"\b[Ss]ections?\s+(?P<art>[0-9]+[A-Z]*)(?:\((?P<para>[0-9]+[A-Z]?)\))?(?:\([a-z]+\))*(?P<of>\s+of\s+(?:the\s+)?\S+)?",
+                    )
+                    .unwrap(),
+                ];
+            }
+        }
+        rules
+    }
+
+    /// Returns true when a line is a plausible heading for the preceding label.
+    fn is_heading_line(&self, text: &str) -> bool {
+        text.chars().count() <= 100
+            && !text.ends_with(['.', ',', ';', ':', '。', '、'])
+            && !text.chars().next().is_some_and(char::is_lowercase)
+            && !self
+                .patterns
+                .iter()
+                .any(|pattern| match_label(pattern, text).is_some())
+    }
+}
+
+/// A numbering label found at the start of a line.
+#[derive(Debug, Clone)]
+struct Label<'t> {
+    kind: UnitKind,
+    number: String,
+    label: String,
+    rest: &'t str,
+    rest_offset: usize,
+    strict: bool,
+}
+
+fn match_label<'t>(pattern: &Pattern, text: &'t str) -> Option<Label<'t>> {
+    let captures = pattern.regex.captures(text)?;
+    let mut number = captures
+        .name("num")
+        .map(|m| m.as_str().to_string())
+        .or_else(|| {
+            captures
+                .name("word")
+                .and_then(|m| word_number(m.as_str()))
+                .map(|n| n.to_string())
+        })?;
+    if let Some(word) = captures.name("word")
+        && captures.name("num").is_some()
+        && word_number(word.as_str()).is_none()
+    {
+        // "Erster Teil" or "Part 1" but not "Final Part 1"
+        return None;
+    }
+    for suffix in ["sub", "suffix"] {
+        if let Some(m) = captures.name(suffix) {
+            number.push_str(m.as_str());
+        }
+    }
+    let (rest, rest_offset) = captures
+        .name("rest")
+        .map(|m| (m.as_str(), m.start()))
+        .unwrap_or(("", text.len()));
+    Some(Label {
+        kind: pattern.kind,
+        number,
+        label: captures
+            .name("label")
+            .map(|m| m.as_str().trim().to_string())
+            .unwrap_or_default(),
+        rest: rest.trim(),
+        rest_offset: rest_offset + (rest.len() - rest.trim_start().len()),
+        strict: pattern.strict,
+    })
+}
+
+// ============================================================================
+// Numbers
+// ============================================================================
+
+/// Parses arabic, full-width or kanji numerals.
+fn parse_number(text: &str) -> Option<u32> {
+    if text.is_empty() {
+        return None;
+    }
+    if text
+        .chars()
+        .all(|c| c.is_ascii_digit() || ('０'..='９').contains(&c))
+    {
+        return text
+            .chars()
+            .map(|c| c.to_digit(10).or_else(|| fullwidth_digit(c)))
+            .try_fold(0u32, |acc, digit| acc.checked_mul(10)?.checked_add(digit?));
+    }
+    if !text.chars().all(|c| KANJI_DIGITS.contains(c)) {
+        return None;
+    }
+    let mut total = 0u32;
+    let mut digits: Option<u32> = None;
+    for c in text.chars() {
+        let unit = match c {
+            '十' => 10,
+            '百' => 100,
+            '千' => 1000,
+            _ => {
+                let value = "〇一二三四五六七八九"
+                    .chars()
+                    .position(|d| d == c)
+                    .unwrap_or(0) as u32;
+                digits = Some(digits.unwrap_or(0) * 10 + value);
+                continue;
+            }
+        };
+        total += digits.unwrap_or(1) * unit;
+        digits = None;
+    }
+    Some(total + digits.unwrap_or(0))
+}
+
+fn fullwidth_digit(c: char) -> Option<u32> {
+    ('０'..='９')
+        .contains(&c)
+        .then(|| u32::from(c) - u32::from('０'))
+}
+
+fn roman_value(text: &str) -> Option<u32> {
+    let mut total = 0u32;
+    let mut previous = 0u32;
+    for c in text.chars().rev() {
+        let value = match c.to_ascii_uppercase() {
+            'I' => 1,
+            'V' => 5,
+            'X' => 10,
+            'L' => 50,
+            'C' => 100,
+            'D' => 500,
+            'M' => 1000,
+            _ => return None,
+        };
+        if value < previous {
+            total = total.checked_sub(value)?;
+        } else {
+            total += value;
+            previous = value;
+        }
+    }
+    (total > 0).then_some(total)
+}
+
+fn word_number(word: &str) -> Option<u32> {
+    let word = word.to_lowercase();
+    const WORDS: [(&str, u32); 21] = [
+        ("préliminaire", 0),
+        ("premier", 1),
+        ("première", 1),
+        ("one", 1),
+        ("erst", 1),
+        ("two", 2),
+        ("zweit", 2),
+        ("three", 3),
+        ("dritt", 3),
+        ("four", 4),
+        ("viert", 4),
+        ("five", 5),
+        ("fünft", 5),
+        ("six", 6),
+        ("sechst", 6),
+        ("seven", 7),
+        ("siebt", 7),
+        ("eight", 8),
+        ("acht", 8),
+        ("nine", 9),
+        ("ten", 10),
+    ];
+    WORDS
+        .iter()
+        .find(|(name, _)| {
+            word == *name
+                || (word.starts_with(name)
+                    && matches!(&word[name.len()..], "e" | "er" | "es" | "en"))
+        })
+        .map(|(_, value)| *value)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Number,
+    Letter,
+    Roman,
+}
+
+/// Possible ordinal values of a number label, by numbering style.
+fn ordinal_values(number: &str) -> Vec<(Style, u32)> {
+    let mut values = Vec::new();
+    if let Some(value) = parse_number(number) {
+        values.push((Style::Number, value));
+    }
+    let letters: Vec<char> = number.chars().collect();
+    if (1..=2).contains(&letters.len())
+        && letters.iter().all(char::is_ascii_lowercase)
+        && letters.iter().all(|c| *c == letters[0])
+    {
+        let index = u32::from(letters[0]) - u32::from('a') + 1;
+        values.push((Style::Letter, index + 26 * (letters.len() as u32 - 1)));
+    }
+    if let Some(value) = roman_value(number) {
+        values.push((Style::Roman, value));
+    }
+    values
+}
+
+/// Returns true when `next` continues the numbering of `previous`.
+fn follows(previous: Option<&str>, next: &str, first: u32) -> bool {
+    let next = ordinal_values(next);
+    match previous {
+        None => next.iter().any(|(_, value)| *value == first),
+        Some(previous) => {
+            let previous = ordinal_values(previous);
+            next.iter().any(|(style, value)| {
+                previous.iter().any(|(previous_style, previous_value)| {
+                    previous_style == style && previous_value + 1 == *value
+                })
+            })
+        }
+    }
+}
+
+/// Normalizes a grouping number, so that "II", "Two" and "2" all become "2".
+fn grouping_number(number: &str) -> String {
+    word_number(number)
+        .or_else(|| {
+            let roman = number.strip_suffix("er").unwrap_or(number);
+            roman
+                .chars()
+                .all(|c| c.is_ascii_uppercase())
+                .then(|| roman_value(roman))
+                .flatten()
+        })
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| id_number(number))
+}
+
+/// Leading numeric value of an article number: 12 for "12a", "十二条の二" or "L. 12-1".
+fn leading_value(number: &str) -> Option<u32> {
+    let start = number.find(|c: char| {
+        c.is_ascii_digit() || fullwidth_digit(c).is_some() || KANJI_DIGITS.contains(c)
+    })?;
+    let digits: String = number[start..]
+        .chars()
+        .take_while(|c| {
+            c.is_ascii_digit() || fullwidth_digit(*c).is_some() || KANJI_DIGITS.contains(*c)
+        })
+        .collect();
+    parse_number(&digits)
+}
+
+/// Normalizes a number for use in IDs: "十二の二" becomes "12-2" and "L. 121-1" "l121-1".
+fn id_number(number: &str) -> String {
+    let mut result = String::new();
+    let mut kanji = String::new();
+    let flush = |kanji: &mut String, result: &mut String| {
+        if let Some(value) = parse_number(kanji) {
+            result.push_str(&value.to_string());
+        }
+        kanji.clear();
+    };
+    for c in number.chars() {
+        if KANJI_DIGITS.contains(c) {
+            kanji.push(c);
+            continue;
+        }
+        flush(&mut kanji, &mut result);
+        if let Some(digit) = fullwidth_digit(c) {
+            result.push_str(&digit.to_string());
+        } else if matches!(c, 'の' | '-' | '‐' | '/') {
+            result.push('-');
+        } else if c.is_alphanumeric() {
+            result.extend(c.to_lowercase());
+        }
+    }
+    flush(&mut kanji, &mut result);
+    match result.as_str() {
+        "premier" => "1".to_string(),
+        _ => result.trim_matches('-').to_string(),
+    }
+}
+
+// ============================================================================
+// Parser
+// ============================================================================
+
+#[derive(Debug, Clone)]
+enum ReferenceTarget {
+    Article {
+        article: String,
+        paragraph: Option<String>,
+    },
+    PreviousArticle,
+    NextArticle,
+    PreviousParagraph,
+    NextParagraph,
+}
+
+struct PendingReference {
+    provision: usize,
+    reference: usize,
+    target: Option<ReferenceTarget>,
+}
+
+struct Parser<'a> {
+    rules: Rules,
+    lines: &'a [Line<'a>],
+    provisions: Vec<Provision>,
+    ids: HashSet<String>,
+    /// Indices of the open units, outermost first
+    open: Vec<usize>,
+    footnotes: Vec<Footnote>,
+    references: Vec<PendingReference>,
+    title: Option<String>,
+    caption: Option<String>,
+    article_prefix: String,
+    last_article: Option<u32>,
+    last_paragraph: Option<String>,
+    last_item: Option<String>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(scheme: NumberingScheme, lines: &'a [Line<'a>]) -> Self {
+        Self {
+            rules: Rules::new(scheme),
+            lines,
+            provisions: Vec::new(),
+            ids: HashSet::new(),
+            open: Vec::new(),
+            footnotes: Vec::new(),
+            references: Vec::new(),
+            title: None,
+            caption: None,
+            article_prefix: String::new(),
+            last_article: None,
+            last_paragraph: None,
+            last_item: None,
+        }
+    }
+
+    fn run(mut self) -> Segmentation {
+        let lines = self.lines;
+        let furniture = page_furniture(lines);
+        let footnotes = footnote_lines(lines, &self.rules.footnote);
+        let content: Vec<usize> = (0..lines.len())
+            .filter(|index| !furniture.contains(index) && !footnotes.contains_key(index))
+            .collect();
+
+        let mut position = 0;
+        let mut footnote_index = 0;
+        let footnote_order: Vec<usize> = {
+            let mut order: Vec<usize> = footnotes
+                .keys()
+                .copied()
+                .filter(|index| !furniture.contains(index))
+                .collect();
+            order.sort_unstable();
+            order
+        };
+        while position < content.len() {
+            let index = content[position];
+            // Footnotes belong to the text that precedes them
+            while footnote_index < footnote_order.len() && footnote_order[footnote_index] < index {
+                let line = footnote_order[footnote_index];
+                self.footnote(&lines[line], footnotes[&line]);
+                footnote_index += 1;
+            }
+
+            let line = &lines[index];
+            let next = content.get(position + 1).map(|&next| &lines[next]);
+            position += 1;
+
+            if let Some(label) = self.classify(line.text) {
+                if self.rules.toc.is_match(line.text) {
+                    continue;
+                }
+                let consumed_next = self.open_unit(line, label, next);
+                if consumed_next {
+                    position += 1;
+                }
+                continue;
+            }
+            if let Some(caption) = self
+                .rules
+                .caption
+                .as_ref()
+                .and_then(|caption| caption.captures(line.text))
+                && next.is_some_and(|next| {
+                    self.classify(next.text)
+                        .is_some_and(|label| label.kind == UnitKind::Article)
+                })
+            {
+                self.caption = Some(caption["caption"].to_string());
+                continue;
+            }
+            if self.open.is_empty() {
+                if self.title.is_none() && self.provisions.is_empty() {
+                    self.title = Some(line.text.to_string());
+                }
+                continue;
+            }
+            self.body(line, line.text, 0);
+        }
+        for &line in &footnote_order[footnote_index..] {
+            self.footnote(&lines[line], footnotes[&line]);
+        }
+
+        self.resolve_references();
+        Segmentation {
+            scheme: self.rules.scheme,
+            jurisdiction: None,
+            title: self.title,
+            text: String::new(),
+            provisions: self.provisions,
+            footnotes: self.footnotes,
+        }
+    }
+
+    /// Recognises the numbering label of a line, if it opens a unit here.
+    fn classify<'t>(&self, text: &'t str) -> Option<Label<'t>> {
+        if let Some(regex) = &self.rules.supplementary
+            && let Some(captures) = regex.captures(text)
+        {
+            let rest = captures.name("rest").map_or("", |m| m.as_str());
+            return Some(Label {
+                kind: UnitKind::Part,
+                number: "suppl".to_string(),
+                label: captures["label"].to_string(),
+                rest,
+                rest_offset: captures.name("rest").map_or(text.len(), |m| m.start()),
+                strict: false,
+            });
+        }
+
+        let in_article = self.current(UnitKind::Article).is_some();
+        for pattern in &self.rules.patterns {
+            let Some(label) = match_label(pattern, text) else {
+                continue;
+            };
+            let accepted = match label.kind {
+                kind if kind.is_grouping() => self.plausible_heading(&label),
+                UnitKind::Article => {
+                    self.plausible_heading(&label)
+                        && match (leading_value(&label.number), self.last_article) {
+                            (Some(value), Some(last)) if label.strict => {
+                                value == last + 1
+                                    || (value == last
+                                        && label.number.ends_with(char::is_alphabetic))
+                            }
+                            (Some(value), None) if label.strict => value == 1,
+                            (Some(value), Some(last)) => value >= last,
+                            _ => true,
+                        }
+                }
+                UnitKind::Paragraph => {
+                    in_article
+                        && follows(
+                            self.last_paragraph.as_deref(),
+                            &label.number,
+                            self.rules.first_paragraph,
+                        )
+                }
+                _ => in_article && follows(self.last_item.as_deref(), &label.number, 1),
+            };
+            if accepted {
+                return Some(label);
+            }
+        }
+        None
+    }
+
+    /// Rejects labels that start a sentence referring to a provision.
+    fn plausible_heading(&self, label: &Label) -> bool {
+        if self.rules.scheme == NumberingScheme::Japanese {
+            return true;
+        }
+        let first = label.rest.chars().next();
+        if first.is_some_and(|c| c.is_lowercase() || matches!(c, '(' | ',' | ';')) {
+            return false;
+        }
+        !self
+            .rules
+            .reference_words
+            .as_ref()
+            .is_some_and(|words| words.is_match(label.rest))
+    }
+
+    fn current(&self, kind: UnitKind) -> Option<usize> {
+        self.open
+            .iter()
+            .rev()
+            .copied()
+            .find(|index| self.provisions[*index].kind == kind)
+    }
+
+    /// Opens a unit; returns true when the next line was taken as its heading.
+    fn open_unit(&mut self, line: &Line, label: Label, next: Option<&Line>) -> bool {
+        let kind = label.kind;
+        while let Some(&last) = self.open.last() {
+            if self.provisions[last].kind >= kind {
+                self.open.pop();
+            } else {
+                break;
+            }
+        }
+
+        let parent = self.open.last().copied();
+        let number = if kind.is_grouping() {
+            grouping_number(&label.number)
+        } else {
+            id_number(&label.number)
+        };
+        let base = match kind {
+            UnitKind::Article => format!("{}art-{}", self.article_prefix, number),
+            _ if label.number == "suppl" => "suppl".to_string(),
+            _ => match parent {
+                Some(parent) => format!(
+                    "{}-{}-{}",
+                    self.provisions[parent].id,
+                    kind.prefix(),
+                    number
+                ),
+                None => format!("{}-{}", kind.prefix(), number),
+            },
+        };
+        let mut id = base.clone();
+        let mut counter = 2;
+        while self.ids.contains(&id) {
+            id = format!("{}_{}", base, counter);
+            counter += 1;
+        }
+        self.ids.insert(id.clone());
+
+        match kind {
+            UnitKind::Article => {
+                self.last_article = leading_value(&label.number).or(self.last_article);
+                self.last_paragraph = None;
+                self.last_item = None;
+            }
+            UnitKind::Paragraph => {
+                self.last_paragraph = Some(label.number.clone());
+                self.last_item = None;
+            }
+            UnitKind::Item => self.last_item = Some(label.number.clone()),
+            _ => {
+                if label.number == "suppl" {
+                    self.article_prefix = format!("{}-", id);
+                    self.last_article = None;
+                }
+            }
+        }
+
+        // Heading: caption, rest of the label line, or the following line
+        let mut heading = None;
+        let mut body = label.rest;
+        let mut consumed_next = false;
+        if kind == UnitKind::Article {
+            heading = self.caption.take();
+        }
+        if kind.is_grouping() || (kind == UnitKind::Article && self.rules.rest_is_heading) {
+            if !label.rest.is_empty() {
+                let (title, remainder) = split_heading(label.rest, self.rules.scheme);
+                heading = Some(title.to_string());
+                body = remainder;
+            } else if (kind.is_grouping() || self.rules.heading_on_next_line)
+                && let Some(next) = next
+                && self.rules.is_heading_line(next.text)
+            {
+                heading = Some(next.text.to_string());
+                consumed_next = true;
+            }
+        }
+
+        // The label line belongs to the text of the enclosing articles and paragraphs
+        if kind > UnitKind::Article {
+            for &open in &self.open {
+                if self.provisions[open].kind >= UnitKind::Article {
+                    append_text(&mut self.provisions[open].text, line.text);
+                }
+            }
+        }
+
+        let end = if consumed_next {
+            next.map_or(line.end, |next| next.end)
+        } else {
+            line.end
+        };
+        let index = self.provisions.len();
+        self.provisions.push(Provision {
+            id: id.clone(),
+            kind,
+            number: label.number.clone(),
+            label: label.label.clone(),
+            heading,
+            text: String::new(),
+            span: SourceSpan {
+                page: line.page,
+                start: line.start,
+                end,
+            },
+            parent: parent.map(|parent| self.provisions[parent].id.clone()),
+            children: Vec::new(),
+            references: Vec::new(),
+        });
+        if let Some(parent) = parent {
+            self.provisions[parent].children.push(id);
+        }
+        self.open.push(index);
+        self.extend_spans(end);
+
+        if !kind.is_grouping() && !body.is_empty() {
+            let offset = label.rest_offset + (label.rest.len() - body.len());
+            append_text(&mut self.provisions[index].text, body);
+            self.find_references(line, body, offset);
+        }
+        consumed_next
+    }
+
+    fn body(&mut self, line: &Line, text: &str, offset: usize) {
+        for &open in &self.open {
+            if self.provisions[open].kind >= UnitKind::Article {
+                append_text(&mut self.provisions[open].text, text);
+            }
+        }
+        self.extend_spans(line.end);
+        self.find_references(line, text, offset);
+    }
+
+    fn extend_spans(&mut self, end: usize) {
+        for &open in &self.open {
+            let span = &mut self.provisions[open].span;
+            span.end = span.end.max(end);
+        }
+    }
+
+    fn footnote(&mut self, line: &Line, role: FootnoteLine) {
+        let provision = self
+            .current(UnitKind::Article)
+            .map(|index| self.provisions[index].id.clone());
+        match role {
+            FootnoteLine::Separator => {}
+            FootnoteLine::Continuation if !self.footnotes.is_empty() => {
+                if let Some(footnote) = self.footnotes.last_mut() {
+                    append_text(&mut footnote.text, line.text);
+                    footnote.span.end = line.end;
+                }
+            }
+            _ => {
+                let (marker, text) = match self.rules.footnote.captures(line.text) {
+                    Some(captures) => (
+                        captures["marker"]
+                            .trim_matches(|c| matches!(c, '(' | ')' | '[' | ']' | '.'))
+                            .to_string(),
+                        captures["text"].to_string(),
+                    ),
+                    None => (String::new(), line.text.to_string()),
+                };
+                self.footnotes.push(Footnote {
+                    marker,
+                    text,
+                    span: SourceSpan {
+                        page: line.page,
+                        start: line.start,
+                        end: line.end,
+                    },
+                    provision,
+                });
+            }
+        }
+    }
+
+    fn find_references(&mut self, line: &Line, text: &str, offset: usize) {
+        let Some(&provision) = self.open.last() else {
+            return;
+        };
+        if self.provisions[provision].kind.is_grouping() {
+            return;
+        }
+        let mut found: Vec<(usize, usize, Option<ReferenceTarget>, bool)> = Vec::new();
+        for regex in &self.rules.references {
+            for captures in regex.captures_iter(text) {
+                let whole = captures.get(0).unwrap();
+                if found
+                    .iter()
+                    .any(|(start, end, _, _)| whole.start() < *end && *start < whole.end())
+                {
+                    continue;
+                }
+                let (target, external) = self.reference_target(&captures, &text[..whole.start()]);
+                found.push((whole.start(), whole.end(), target, external));
+            }
+        }
+        found.sort_by_key(|(start, _, _, _)| *start);
+
+        for (start, end, target, external) in found {
+            let reference = CrossReference {
+                text: text[start..end].trim_end().to_string(),
+                span: SourceSpan {
+                    page: line.page,
+                    start: line.start + offset + start,
+                    end: line.start + offset + start + text[start..end].trim_end().len(),
+                },
+                target: None,
+                external,
+            };
+            let references = &mut self.provisions[provision].references;
+            references.push(reference);
+            self.references.push(PendingReference {
+                provision,
+                reference: references.len() - 1,
+                target: if external { None } else { target },
+            });
+        }
+    }
+
+    fn reference_target(
+        &self,
+        captures: &Captures,
+        before: &str,
+    ) -> (Option<ReferenceTarget>, bool) {
+        if captures.name("law").is_some() {
+            return (None, true);
+        }
+        if let Some(relative) = captures.name("rel") {
+            let target = match relative.as_str() {
+                "前条" => ReferenceTarget::PreviousArticle,
+                "次条" => ReferenceTarget::NextArticle,
+                "前項" => ReferenceTarget::PreviousParagraph,
+                _ => ReferenceTarget::NextParagraph,
+            };
+            return (Some(target), false);
+        }
+        let external = match self.rules.scheme {
+            NumberingScheme::Japanese => {
+                captures.name("same").is_some()
+                    || before
+                        .chars()
+                        .last()
+                        .is_some_and(|c| matches!(c, '法' | '令' | '則' | '）' | ')'))
+            }
+            _ => captures.name("of").is_some_and(|of| {
+                let qualifier = of.as_str().trim().to_lowercase();
+                ![
+                    "of this",
+                    "of the present",
+                    "dieses",
+                    "de la présente",
+                    "du présent",
+                ]
+                .iter()
+                .any(|own| qualifier.starts_with(own))
+            }),
+        };
+        let Some(article) = captures.name("art") else {
+            return (None, external);
+        };
+        let mut article = id_number(article.as_str());
+        if let Some(sub) = captures.name("sub").filter(|sub| !sub.as_str().is_empty()) {
+            article = format!("{}-{}", article, id_number(sub.as_str()));
+        }
+        let paragraph = captures.name("para").map(|para| id_number(para.as_str()));
+        (
+            Some(ReferenceTarget::Article { article, paragraph }),
+            external,
+        )
+    }
+
+    fn resolve_references(&mut self) {
+        let articles: Vec<usize> = (0..self.provisions.len())
+            .filter(|index| self.provisions[*index].kind == UnitKind::Article)
+            .collect();
+        let article_of = |provisions: &[Provision], mut index: usize| -> Option<usize> {
+            loop {
+                if provisions[index].kind == UnitKind::Article {
+                    return Some(index);
+                }
+                let parent = provisions[index].parent.as_ref()?;
+                index = provisions.iter().position(|p| &p.id == parent)?;
+            }
+        };
+
+        let pending = std::mem::take(&mut self.references);
+        for reference in pending {
+            let Some(target) = &reference.target else {
+                continue;
+            };
+            let container = article_of(&self.provisions, reference.provision);
+            let resolved = match target {
+                ReferenceTarget::Article { article, paragraph } => {
+                    let id = format!("art-{}", article);
+                    if !self.ids.contains(&id) {
+                        None
+                    } else {
+                        match paragraph {
+                            Some(paragraph) => {
+                                let para = format!("{}-para-{}", id, paragraph);
+                                Some(if self.ids.contains(&para) { para } else { id })
+                            }
+                            None => Some(id),
+                        }
+                    }
+                }
+                ReferenceTarget::PreviousArticle | ReferenceTarget::NextArticle => container
+                    .and_then(|container| {
+                        let position = articles.iter().position(|index| *index == container)?;
+                        let target = if matches!(target, ReferenceTarget::PreviousArticle) {
+                            position.checked_sub(1)?
+                        } else {
+                            position + 1
+                        };
+                        articles
+                            .get(target)
+                            .map(|index| self.provisions[*index].id.clone())
+                    }),
+                ReferenceTarget::PreviousParagraph | ReferenceTarget::NextParagraph => {
+                    let unit = &self.provisions[reference.provision];
+                    let paragraph = if unit.kind == UnitKind::Paragraph {
+                        Some(reference.provision)
+                    } else {
+                        unit.parent.as_ref().and_then(|parent| {
+                            self.provisions
+                                .iter()
+                                .position(|p| &p.id == parent && p.kind == UnitKind::Paragraph)
+                        })
+                    };
+                    let current = paragraph
+                        .and_then(|index| parse_number(&self.provisions[index].number))
+                        .unwrap_or(1);
+                    let wanted = if matches!(target, ReferenceTarget::PreviousParagraph) {
+                        current.checked_sub(1)
+                    } else {
+                        Some(current + 1)
+                    };
+                    container.zip(wanted).and_then(|(container, wanted)| {
+                        let article = &self.provisions[container];
+                        if wanted == 1 {
+                            return Some(article.id.clone());
+                        }
+                        let id = format!("{}-para-{}", article.id, wanted);
+                        self.ids.contains(&id).then_some(id)
+                    })
+                }
+            };
+            self.provisions[reference.provision].references[reference.reference].target = resolved;
+        }
+    }
+}
+
+/// Splits "Definitions. In this title..." into a heading and body text.
+fn split_heading(rest: &str, scheme: NumberingScheme) -> (&str, &str) {
+    if scheme == NumberingScheme::American
+        && let Some(stop) = rest.find(". ")
+        && rest[..stop].chars().count() <= 80
+    {
+        return (&rest[..stop], rest[stop + 2..].trim_start());
+    }
+    (rest.trim_end_matches('.'), "")
+}
+
+/// Appends a line to running text, undoing hyphenation and joining CJK text
+/// without spaces.
+fn append_text(text: &mut String, line: &str) {
+    if text.is_empty() {
+        text.push_str(line);
+        return;
+    }
+    let last = text.chars().last();
+    let first = line.chars().next();
+    if last == Some('-')
+        && text.chars().rev().nth(1).is_some_and(char::is_alphabetic)
+        && first.is_some_and(char::is_lowercase)
+    {
+        text.pop();
+    } else if !(last.is_some_and(is_cjk) && first.is_some_and(is_cjk)) {
+        text.push(' ');
+    }
+    text.push_str(line);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_japanese_articles_paragraphs_and_items() {
+        let text = "民法\n\
+            第一編　総則\n\
+            第一章　通則\n\
+            （基本原則）\n\
+            第一条　私権は、公共の福祉に適合しなければならない。\n\
+            ２　権利の行使及び義務の履行は、信義に従い誠実に行わなければならない。\n\
+            ３　権利の濫用は、これを許さない。\n\
+            第一条の二　この法律は、個人の尊厳を旨として、\n\
+            解釈しなければならない。\n\
+            第二条　次に掲げる者は、前条の規定にかかわらず、\n\
+            一　未成年者\n\
+            二　成年被後見人\n\
+            ２　前項の規定は、第一条第二項の場合及び商法第五条の場合に準用する。";
+        let segmentation = StatuteSegmenter::new()
+            .with_jurisdiction("JP")
+            .segment(text);
+
+        assert_eq!(segmentation.scheme, NumberingScheme::Japanese);
+        assert_eq!(segmentation.title.as_deref(), Some("民法"));
+        let ids: Vec<&str> = segmentation
+            .provisions
+            .iter()
+            .map(|p| p.id.as_str())
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                "part-1",
+                "part-1-chp-1",
+                "art-1",
+                "art-1-para-2",
+                "art-1-para-3",
+                "art-1-2",
+                "art-2",
+                "art-2-item-1",
+                "art-2-item-2",
+                "art-2-para-2",
+            ]
+        );
+
+        let article = segmentation.provision("art-1").unwrap();
+        assert_eq!(article.heading.as_deref(), Some("基本原則"));
+        assert_eq!(article.parent.as_deref(), Some("part-1-chp-1"));
+        assert!(article.text.starts_with("私権は"));
+        assert!(article.text.contains("３　権利の濫用"));
+        assert_eq!(
+            segmentation.provision("art-1-2").unwrap().text,
+            "この法律は、個人の尊厳を旨として、解釈しなければならない。"
+        );
+        assert_eq!(
+            segmentation.provision("part-1").unwrap().heading.as_deref(),
+            Some("総則")
+        );
+
+        let references = &segmentation.provision("art-2").unwrap().references;
+        assert_eq!(references[0].text, "前条");
+        assert_eq!(references[0].target.as_deref(), Some("art-1-2"));
+        let references = &segmentation.provision("art-2-para-2").unwrap().references;
+        let targets: Vec<(&str, Option<&str>, bool)> = references
+            .iter()
+            .map(|r| (r.text.as_str(), r.target.as_deref(), r.external))
+            .collect();
+        assert_eq!(
+            targets,
+            vec![
+                ("前項", Some("art-2"), false),
+                ("第一条第二項", Some("art-1-para-2"), false),
+                ("第五条", None, true),
+            ]
+        );
+        let reference = &references[1];
+        assert_eq!(segmentation.source(&reference.span), "第一条第二項");
+    }
+
+    #[test]
+    fn test_german_paragraphs_and_headings() {
+        let text = "Bürgerliches Gesetzbuch\n\
+            Buch 1\n\
+            Allgemeiner Teil\n\
+            Abschnitt 1\n\
+            Personen\n\
+            § 1 Beginn der Rechtsfähigkeit\n\
+            Die Rechtsfähigkeit des Menschen beginnt mit der Vollendung der Geburt.\n\
+            § 2 Eintritt der Volljährigkeit\n\
+            (1) Die Volljährigkeit tritt mit der Vollendung des 18. Lebensjahres ein.\n\
+            (2) Die Vorschriften des § 1 und des § 823 Abs. 1 BGB bleiben unberührt;\n\
+            § 1 gilt entsprechend.\n\
+            1. für Minderjährige,\n\
+            2. für Betreute.";
+        let segmentation = StatuteSegmenter::new()
+            .with_jurisdiction("DE")
+            .segment(text);
+
+        let ids: Vec<&str> = segmentation
+            .provisions
+            .iter()
+            .map(|p| p.id.as_str())
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                "part-1",
+                "part-1-chp-1",
+                "art-1",
+                "art-2",
+                "art-2-para-1",
+                "art-2-para-2",
+                "art-2-para-2-item-1",
+                "art-2-para-2-item-2",
+            ]
+        );
+        assert_eq!(
+            segmentation.provision("part-1").unwrap().heading.as_deref(),
+            Some("Allgemeiner Teil")
+        );
+        let article = segmentation.provision("art-1").unwrap();
+        assert_eq!(article.label, "§ 1");
+        assert_eq!(
+            article.heading.as_deref(),
+            Some("Beginn der Rechtsfähigkeit")
+        );
+        assert!(
+            segmentation
+                .provision("art-2-para-2")
+                .unwrap()
+                .text
+                .contains("unberührt; § 1 gilt entsprechend. 1. für Minderjährige,")
+        );
+
+        let references: Vec<(&str, Option<&str>, bool)> = segmentation
+            .provision("art-2-para-2")
+            .unwrap()
+            .references
+            .iter()
+            .map(|r| (r.text.as_str(), r.target.as_deref(), r.external))
+            .collect();
+        assert_eq!(
+            references,
+            vec![
+                ("§ 1", Some("art-1"), false),
+                ("§ 823 Abs. 1 BGB", None, true),
+                ("§ 1", Some("art-1"), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_european_regulation() {
+        let text = "REGULATION (EU) 2016/679\n\
+            CHAPTER I\n\
+            General provisions\n\
+            Article 1\n\
+            Subject-matter and objectives\n\
+            1. This Regulation lays down rules relating to the protection of natural\n\
+            persons with regard to the processing of personal data.\n\
+            2. This Regulation protects fundamental rights and freedoms.\n\
+            Article 2\n\
+            Material scope\n\
+            1. This Regulation applies to the processing of personal data referred to in\n\
+            Article 1(2) and in Article 5 of Directive 95/46/EC:\n\
+            (a) wholly or partly by automated means;\n\
+            (b) otherwise than by automated means.\n\
+            Article 3 of this Regulation applies too.";
+        let segmentation = StatuteSegmenter::new().segment(text);
+
+        assert_eq!(segmentation.scheme, NumberingScheme::European);
+        let chapter = segmentation.provision("chp-1").unwrap();
+        assert_eq!(chapter.heading.as_deref(), Some("General provisions"));
+        assert_eq!(chapter.children, vec!["art-1", "art-2"]);
+        let article = segmentation.provision("art-1").unwrap();
+        assert_eq!(
+            article.heading.as_deref(),
+            Some("Subject-matter and objectives")
+        );
+        assert_eq!(article.children, vec!["art-1-para-1", "art-1-para-2"]);
+        assert_eq!(
+            segmentation.provision("art-2-para-1").unwrap().children,
+            vec!["art-2-para-1-item-a", "art-2-para-1-item-b"]
+        );
+
+        let references: Vec<(&str, Option<&str>, bool)> = segmentation
+            .provision("art-2-para-1")
+            .unwrap()
+            .references
+            .iter()
+            .map(|r| (r.text.as_str(), r.target.as_deref(), r.external))
+            .collect();
+        assert_eq!(
+            references,
+            vec![
+                ("Article 1(2)", Some("art-1-para-2"), false),
+                ("Article 5 of Directive", None, true),
+            ]
+        );
+        // A line that starts with a reference does not open an article
+        assert!(segmentation.provision("art-3").is_none());
+    }
+
+    #[test]
+    fn test_american_and_british_sections() {
+        let text = "AN ACT\n\
+            SECTION 1. SHORT TITLE.\n\
+            This Act may be cited as the Example Act.\n\
+            SEC. 2. DEFINITIONS. In this Act:\n\
+            (a) The term \"agency\" has the meaning given in section 551 of title 5.\n\
+            (1) Each agency shall comply with section 1.";
+        let segmentation = StatuteSegmenter::new()
+            .with_jurisdiction("US")
+            .segment(text);
+
+        let article = segmentation.provision("art-2").unwrap();
+        assert_eq!(article.heading.as_deref(), Some("DEFINITIONS"));
+        assert!(article.text.starts_with("In this Act:"));
+        let item = segmentation.provision("art-2-para-a-item-1").unwrap();
+        assert_eq!(item.references[0].target.as_deref(), Some("art-1"));
+        let reference = &segmentation.provision("art-2-para-a").unwrap().references[0];
+        assert!(reference.external);
+
+        let text = "Data Protection Act 2018\n\
+            PART 1\n\
+            Preliminary\n\
+            1 Overview\n\
+            (1) This Act makes provision about the processing of personal data.\n\
+            (2) Most processing is subject to the GDPR.\n\
+            2 Protection of personal data\n\
+            (1) The GDPR, the applied GDPR and this Act protect individuals.\n\
+            3 years after the day on which this Act is passed\n";
+        let segmentation = StatuteSegmenter::new()
+            .with_jurisdiction("GB")
+            .segment(text);
+
+        let articles: Vec<(&str, Option<&str>)> = segmentation
+            .articles()
+            .map(|a| (a.id.as_str(), a.heading.as_deref()))
+            .collect();
+        assert_eq!(
+            articles,
+            vec![
+                ("art-1", Some("Overview")),
+                ("art-2", Some("Protection of personal data")),
+            ]
+        );
+        assert_eq!(
+            segmentation.provision("art-1").unwrap().parent.as_deref(),
+            Some("part-1")
+        );
+    }
+
+    #[test]
+    fn test_french_code() {
+        let text = "Code civil\n\
+            Titre préliminaire : De la publication, des effets et de l'application des lois\n\
+            Article 1er\n\
+            Les lois et, lorsqu'ils sont publiés au Journal officiel, les actes administratifs\n\
+            entrent en vigueur à la date qu'ils fixent.\n\
+            Article 2\n\
+            La loi ne dispose que pour l'avenir ; elle n'a point d'effet rétroactif.\n\
+            Article L. 121-1\n\
+            I. - Sont interdites les pratiques commerciales déloyales.\n\
+            II. - Une pratique est trompeuse au sens de l'article 2 :\n\
+            1° Lorsqu'elle crée une confusion ;\n\
+            2° Lorsqu'elle repose sur des allégations fausses, au sens de l'article 1382 du code civil.";
+        let segmentation = StatuteSegmenter::new()
+            .with_jurisdiction("FR")
+            .segment(text);
+
+        let ids: Vec<&str> = segmentation
+            .provisions
+            .iter()
+            .map(|p| p.id.as_str())
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                "chp-0",
+                "art-1",
+                "art-2",
+                "art-l121-1",
+                "art-l121-1-para-i",
+                "art-l121-1-para-ii",
+                "art-l121-1-para-ii-item-1",
+                "art-l121-1-para-ii-item-2",
+            ]
+        );
+        assert_eq!(
+            segmentation.provision("art-1").unwrap().label,
+            "Article 1er"
+        );
+        let references: Vec<(Option<&str>, bool)> = segmentation
+            .provision("art-l121-1-para-ii")
+            .unwrap()
+            .references
+            .iter()
+            .chain(
+                &segmentation
+                    .provision("art-l121-1-para-ii-item-2")
+                    .unwrap()
+                    .references,
+            )
+            .map(|r| (r.target.as_deref(), r.external))
+            .collect();
+        assert_eq!(references, vec![(Some("art-2"), false), (None, true)]);
+    }
+
+    #[test]
+    fn test_page_furniture_footnotes_and_offsets() {
+        let text = "Example Act\n\
+            Article 1\n\
+            Scope\n\
+            This Act applies to all contracts.¹\n\
+            ______\n\
+            1 As amended in 2020.\n\
+            continued note\n\
+            Page 1 of 3\
+            \x0cExample Act\n\
+            Article 2\n\
+            Definitions\n\
+            Terms have their ordinary meaning.\n\
+            Page 2 of 3\
+            \x0cExample Act\n\
+            Article 3\n\
+            Entry into force\n\
+            This Act enters into force on publication.\n\
+            Page 3 of 3";
+        let segmentation = StatuteSegmenter::new()
+            .with_jurisdiction("EU")
+            .segment(text);
+
+        assert_eq!(segmentation.articles().count(), 3);
+        let article = segmentation.provision("art-1").unwrap();
+        assert_eq!(article.text, "This Act applies to all contracts.¹");
+        assert_eq!(article.span.page, 1);
+        assert_eq!(segmentation.provision("art-3").unwrap().span.page, 3);
+        assert!(
+            segmentation
+                .source(&article.span)
+                .starts_with("Article 1\nScope")
+        );
+        assert_eq!(
+            segmentation.provision("art-3").unwrap().text,
+            "This Act enters into force on publication."
+        );
+
+        assert_eq!(segmentation.footnotes.len(), 1);
+        let footnote = &segmentation.footnotes[0];
+        assert_eq!(footnote.marker, "1");
+        assert_eq!(footnote.text, "As amended in 2020. continued note");
+        assert_eq!(footnote.provision.as_deref(), Some("art-1"));
+        assert!(
+            segmentation
+                .source(&footnote.span)
+                .starts_with("1 As amended")
+        );
+    }
+
+    #[test]
+    fn test_detects_scheme() {
+        let cases = [
+            ("第一条　目的\n第二条　定義", NumberingScheme::Japanese),
+            (
+                "§ 1 Geltungsbereich\n(1) Text.\n§ 2 Begriffe",
+                NumberingScheme::German,
+            ),
+            (
+                "Article 1\nSubject matter\n1. Text.\n2. Text.\nArticle 2\nScope",
+                NumberingScheme::European,
+            ),
+            (
+                "Article 1er\nTexte.\n1° Premier ;\n2° Second.",
+                NumberingScheme::French,
+            ),
+        ];
+        for (text, scheme) in cases {
+            assert_eq!(
+                StatuteSegmenter::new().segment(text).scheme,
+                scheme,
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_statutes_and_hierarchy() {
+        let text = "目次\n\
+            第一章　総則（第一条―第三条）\n\
+            第一章　総則\n\
+            第一条　この法律は、目的を定める。\n\
+            第二条　前条の目的を達成する。\n\
+            ２　第一条の規定を準用する。\n\
+            第三条　この法律は、別に定める。\n\
+            附　則\n\
+            第一条　この法律は、公布の日から施行する。";
+        let segmentation = StatuteSegmenter::new()
+            .with_jurisdiction("JP")
+            .segment(text);
+        let statutes = segmentation.to_statutes();
+
+        let ids: Vec<&str> = statutes.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["art-1", "art-2", "art-3", "suppl-art-1"]);
+        let statute = &statutes[1];
+        assert_eq!(statute.title, "第二条");
+        assert_eq!(statute.jurisdiction.as_deref(), Some("JP"));
+        assert_eq!(
+            statute.effect.description,
+            "前条の目的を達成する。２　第一条の規定を準用する。"
+        );
+        assert_eq!(
+            statute
+                .effect
+                .get_parameter("source_page")
+                .map(String::as_str),
+            Some("1")
+        );
+        let start: usize = statute
+            .effect
+            .get_parameter("source_start")
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(segmentation.text[start..].starts_with("第二条"));
+
+        let hierarchy = segmentation.hierarchy();
+        let entry = &hierarchy["art-2"];
+        assert_eq!(entry.parent_id.as_deref(), Some("chp-1"));
+        assert_eq!(entry.child_ids, vec!["art-2-para-2"]);
+        assert_eq!(entry.cross_references, vec!["art-1"]);
+        assert_eq!(
+            hierarchy["chp-1"].child_ids,
+            vec!["art-1", "art-2", "art-3"]
+        );
+        assert_eq!(hierarchy["suppl"].child_ids, vec!["suppl-art-1"]);
+    }
+}
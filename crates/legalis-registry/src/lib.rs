@@ -10,7 +10,7 @@
 //! - Fuzzy matching for statute IDs
 //! - Pagination support
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use fuzzy_matcher::FuzzyMatcher;
 use fuzzy_matcher::skim::SkimMatcherV2;
 use indexmap::IndexMap;
@@ -43,6 +43,9 @@ pub enum RegistryError {
 
     #[error("Concurrent modification: expected ETag {expected}, got {actual}")]
     ConcurrentModification { expected: String, actual: String },
+
+    #[error("Semantic search failed: {0}")]
+    Semantic(#[from] vector_search::VectorSearchError),
 }
 
 /// Result type for registry operations.
@@ -55,6 +58,9 @@ pub struct RegistryBackup {
     pub statutes: Vec<StatuteEntry>,
     /// All version history
     pub versions: HashMap<String, HashMap<u32, StatuteEntry>>,
+    /// Versions of deleted statutes, kept for as-of queries
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub deleted_versions: HashMap<String, Vec<StatuteEntry>>,
     /// Event history
     pub events: Vec<RegistryEvent>,
     /// Backup metadata
@@ -406,6 +412,9 @@ pub struct SearchQuery {
     pub references: Vec<String>,
     /// Include only statutes with supersedes relationships
     pub has_supersedes: Option<bool>,
+    /// How the text search term is matched
+    #[serde(default)]
+    pub mode: SearchMode,
 }
 
 impl SearchQuery {
@@ -503,6 +512,56 @@ impl SearchQuery {
         self.has_supersedes = Some(false);
         self
     }
+
+    /// Sets how the text search term is matched.
+    pub fn with_mode(mut self, mode: SearchMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+/// How the text term of a [`SearchQuery`] is matched.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchMode {
+    /// Case-insensitive substring match on ID, title, effect and discretion
+    #[default]
+    Lexical,
+    /// Statutes closest in meaning, by embedding similarity
+    Semantic,
+    /// Weighted blend of [`RankingConfig`] relevance and embedding similarity
+    Hybrid {
+        /// Weight of the embedding similarity (0.0 - 1.0)
+        vector_weight: f64,
+    },
+}
+
+impl SearchMode {
+    /// Hybrid mode weighting relevance and similarity equally.
+    pub fn hybrid() -> Self {
+        Self::Hybrid { vector_weight: 0.5 }
+    }
+
+    /// Returns `true` if the mode needs the semantic index.
+    pub fn uses_vectors(&self) -> bool {
+        !matches!(self, Self::Lexical)
+    }
+}
+
+impl std::str::FromStr for SearchMode {
+    type Err = RegistryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "lexical" => Ok(Self::Lexical),
+            "semantic" => Ok(Self::Semantic),
+            "hybrid" => Ok(Self::hybrid()),
+            other => Err(RegistryError::InvalidOperation(format!(
+                "Unknown search mode: {} (expected lexical, semantic or hybrid)",
+                other
+            ))),
+        }
+    }
 }
 
 /// A search result with relevance scoring.
@@ -599,6 +658,63 @@ impl RankingConfig {
         self.exact_match_boost = boost;
         self
     }
+
+    /// Scores a statute's searchable fields against a lowercase query.
+    ///
+    /// Returns a value normalized to the 0.0 - 1.0 range, so scores computed
+    /// by different registries with the same configuration are comparable.
+    pub fn score(
+        &self,
+        query: &str,
+        id: &str,
+        title: &str,
+        tags: &[String],
+        jurisdiction: &str,
+    ) -> f64 {
+        let mut score = 0.0;
+
+        // Title matching
+        let title_lower = title.to_lowercase();
+        if title_lower == query {
+            score += self.title_weight * self.exact_match_boost;
+        } else if title_lower.contains(query) {
+            score += self.title_weight;
+        }
+
+        // ID matching
+        let id_lower = id.to_lowercase();
+        if id_lower == query {
+            score += self.id_weight * self.exact_match_boost;
+        } else if id_lower.contains(query) {
+            score += self.id_weight;
+        }
+
+        // Tag matching
+        for tag in tags {
+            let tag_lower = tag.to_lowercase();
+            if tag_lower == query {
+                score += self.tag_weight * self.exact_match_boost;
+            } else if tag_lower.contains(query) {
+                score += self.tag_weight;
+            }
+        }
+
+        // Jurisdiction matching
+        let jurisdiction_lower = jurisdiction.to_lowercase();
+        if jurisdiction_lower == query {
+            score += self.jurisdiction_weight * self.exact_match_boost;
+        } else if jurisdiction_lower.contains(query) {
+            score += self.jurisdiction_weight;
+        }
+
+        // Normalize score to 0.0-1.0 range
+        // Max possible score is title + id + all tags + jurisdiction (with boost)
+        let max_score =
+            (self.title_weight + self.id_weight + self.jurisdiction_weight + self.tag_weight * 5.0)
+                * self.exact_match_boost;
+
+        (score / max_score).min(1.0)
+    }
 }
 
 /// A versioned statute entry.
@@ -731,6 +847,20 @@ impl StatuteEntry {
         self
     }
 
+    /// Creates the next version of this entry with new statute content.
+    ///
+    /// Status, tags, references, dates, jurisdiction and metadata carry over;
+    /// each version gets its own registry ID and ETag.
+    pub fn next_version(&self, statute: Statute) -> Self {
+        let mut entry = self.clone();
+        entry.registry_id = Uuid::new_v4();
+        entry.statute = statute;
+        entry.version = self.version + 1;
+        entry.modified_at = Utc::now();
+        entry.update_etag();
+        entry
+    }
+
     /// Returns whether this statute is currently active.
     pub fn is_active(&self) -> bool {
         let now = Utc::now();
@@ -738,6 +868,150 @@ impl StatuteEntry {
             && self.effective_date.is_none_or(|d| d <= now)
             && self.expiry_date.is_none_or(|d| d > now)
     }
+
+    /// Returns the date from which this version applies.
+    ///
+    /// The statute's own [`TemporalValidity`](legalis_core::TemporalValidity)
+    /// takes precedence over the registry effective date.
+    pub fn effective_from(&self) -> Option<NaiveDate> {
+        self.statute
+            .temporal_validity
+            .effective_date
+            .or_else(|| self.effective_date.map(|d| d.date_naive()))
+    }
+
+    /// Returns whether this version was in force on the given date.
+    ///
+    /// Only the dates are considered, since the status is not tracked over
+    /// time. A statute expiry date is the last day in force, while a registry
+    /// expiry timestamp is the moment the statute stops applying.
+    pub fn is_in_force_on(&self, date: NaiveDate) -> bool {
+        if self.effective_from().is_some_and(|from| date < from) {
+            return false;
+        }
+        match self.statute.temporal_validity.expiry_date {
+            Some(expiry) => date <= expiry,
+            None => self.expiry_date.is_none_or(|d| date < d.date_naive()),
+        }
+    }
+}
+
+/// A bitemporal point in time for "as of" queries.
+///
+/// `valid_date` selects the law in force on that date; `known_at` restricts
+/// the versions considered to those recorded by that moment (a version is
+/// recorded at its `modified_at` timestamp). Without `known_at` every stored
+/// version is considered.
+///
+/// Among the known versions, the one that took effect most recently on or
+/// before `valid_date` applies, with later versions winning ties; undated
+/// versions apply from the beginning of time. If that version has expired,
+/// the statute was not in force.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use legalis_core::{Effect, EffectType, Statute, TemporalValidity};
+/// use legalis_registry::{AsOf, StatuteEntry};
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// let statute = Statute::new("tax", "Tax", Effect::new(EffectType::Obligation, "Pay"))
+///     .with_temporal_validity(TemporalValidity::new().with_effective_date(date(2010, 1, 1)));
+/// let v1 = StatuteEntry::new(statute.clone(), "JP");
+///
+/// let mut amended = statute;
+/// amended.temporal_validity.effective_date = Some(date(2020, 1, 1));
+/// let v2 = v1.next_version(amended);
+///
+/// let versions = [v1, v2];
+/// assert_eq!(AsOf::new(date(2019, 4, 1)).select(&versions).unwrap().version, 1);
+/// assert_eq!(AsOf::new(date(2021, 1, 1)).select(&versions).unwrap().version, 2);
+/// assert!(AsOf::new(date(2000, 1, 1)).select(&versions).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AsOf {
+    /// Date on which the law must have been in force (valid time)
+    pub valid_date: NaiveDate,
+    /// Moment the registry knowledge is taken from (transaction time)
+    pub known_at: Option<DateTime<Utc>>,
+}
+
+impl AsOf {
+    /// Creates a point in time using everything currently recorded.
+    pub fn new(valid_date: NaiveDate) -> Self {
+        Self {
+            valid_date,
+            known_at: None,
+        }
+    }
+
+    /// Restricts the query to versions recorded by the given moment.
+    pub fn with_known_at(mut self, known_at: DateTime<Utc>) -> Self {
+        self.known_at = Some(known_at);
+        self
+    }
+
+    /// Builds a point in time from optional query parameters.
+    ///
+    /// Returns `None` when neither part is given, meaning "latest version".
+    /// A missing valid date means the law in force today.
+    pub fn from_parts(
+        valid_date: Option<NaiveDate>,
+        known_at: Option<DateTime<Utc>>,
+    ) -> Option<Self> {
+        if valid_date.is_none() && known_at.is_none() {
+            return None;
+        }
+        Some(Self {
+            valid_date: valid_date.unwrap_or_else(|| Utc::now().date_naive()),
+            known_at,
+        })
+    }
+
+    /// Returns whether a version had been recorded at `known_at`.
+    pub fn is_known(&self, entry: &StatuteEntry) -> bool {
+        self.known_at.is_none_or(|t| entry.modified_at <= t)
+    }
+
+    /// Returns the highest version recorded at `known_at`, whether in force or not.
+    pub fn latest_known<'a, I>(&self, versions: I) -> Option<&'a StatuteEntry>
+    where
+        I: IntoIterator<Item = &'a StatuteEntry>,
+    {
+        versions
+            .into_iter()
+            .filter(|entry| self.is_known(entry))
+            .max_by_key(|entry| entry.version)
+    }
+
+    /// Selects the version of a statute in force at this point in time.
+    ///
+    /// `versions` are the stored versions of a single statute, in any order.
+    pub fn select<'a, I>(&self, versions: I) -> Option<&'a StatuteEntry>
+    where
+        I: IntoIterator<Item = &'a StatuteEntry>,
+    {
+        versions
+            .into_iter()
+            .filter(|entry| self.is_known(entry))
+            .filter(|entry| entry.effective_from().is_none_or(|d| d <= self.valid_date))
+            .max_by_key(|entry| (entry.effective_from(), entry.version))
+            .filter(|entry| entry.is_in_force_on(self.valid_date))
+    }
+
+    /// Selects the version in force from a statute's current versions, falling
+    /// back to the versions it had before being deleted.
+    ///
+    /// Deleting a statute removes it from the registry, not from the law that
+    /// applied while it was in force, so its history stays visible here.
+    pub fn select_with_deleted<'a, I, J>(&self, versions: I, deleted: J) -> Option<&'a StatuteEntry>
+    where
+        I: IntoIterator<Item = &'a StatuteEntry>,
+        J: IntoIterator<Item = &'a StatuteEntry>,
+    {
+        self.select(versions).or_else(|| self.select(deleted))
+    }
 }
 
 /// Status of a statute.
@@ -1088,6 +1362,8 @@ pub struct StatuteRegistry {
     statutes: IndexMap<String, StatuteEntry>,
     /// Version history: statute_id -> version -> entry
     versions: HashMap<String, HashMap<u32, StatuteEntry>>,
+    /// Versions of deleted statutes, kept for as-of queries
+    deleted_versions: HashMap<String, Vec<StatuteEntry>>,
     /// Index by tag
     tag_index: HashMap<String, HashSet<String>>,
     /// Index by jurisdiction
@@ -1106,6 +1382,8 @@ pub struct StatuteRegistry {
     retention_policy: RetentionPolicy,
     /// Analytics cache with TTL support
     analytics_cache: CachedAnalytics,
+    /// Embeddings for semantic search, synced lazily
+    semantic_index: Mutex<vector_search::SemanticIndex>,
 }
 
 impl std::fmt::Debug for StatuteRegistry {
@@ -1113,6 +1391,7 @@ impl std::fmt::Debug for StatuteRegistry {
         f.debug_struct("StatuteRegistry")
             .field("statutes", &self.statutes)
             .field("versions", &self.versions)
+            .field("deleted_versions", &self.deleted_versions)
             .field("tag_index", &self.tag_index)
             .field("jurisdiction_index", &self.jurisdiction_index)
             .field("cache", &"<LruCache>")
@@ -1121,6 +1400,7 @@ impl std::fmt::Debug for StatuteRegistry {
             .field("webhook_manager", &self.webhook_manager)
             .field("archive", &self.archive)
             .field("retention_policy", &self.retention_policy)
+            .field("semantic_index", &self.semantic_index)
             .finish()
     }
 }
@@ -1130,6 +1410,7 @@ impl Default for StatuteRegistry {
         Self {
             statutes: IndexMap::new(),
             versions: HashMap::new(),
+            deleted_versions: HashMap::new(),
             tag_index: HashMap::new(),
             jurisdiction_index: HashMap::new(),
             cache: LruCache::new(NonZeroUsize::new(1000).unwrap()),
@@ -1139,6 +1420,7 @@ impl Default for StatuteRegistry {
             archive: StatuteArchive::new(),
             retention_policy: RetentionPolicy::new(),
             analytics_cache: CachedAnalytics::new(300), // 5 minute default cache
+            semantic_index: Mutex::new(vector_search::SemanticIndex::default()),
         }
     }
 }
@@ -1369,6 +1651,34 @@ impl StatuteRegistry {
             .unwrap_or_default()
     }
 
+    /// Gets the version of a statute in force at a point in time.
+    ///
+    /// See [`AsOf`] for how versions are selected. Statutes deleted since are
+    /// still found through their kept history.
+    pub fn get_as_of(&self, statute_id: &str, as_of: &AsOf) -> Option<&StatuteEntry> {
+        as_of.select_with_deleted(
+            self.versions
+                .get(statute_id)
+                .into_iter()
+                .flat_map(HashMap::values),
+            self.deleted_versions.get(statute_id).into_iter().flatten(),
+        )
+    }
+
+    /// Lists every statute as it was in force at a point in time, including
+    /// statutes deleted since.
+    pub fn list_as_of(&self, as_of: &AsOf) -> Vec<&StatuteEntry> {
+        let deleted = self
+            .deleted_versions
+            .keys()
+            .filter(|id| !self.statutes.contains_key(*id));
+        self.statutes
+            .keys()
+            .chain(deleted)
+            .filter_map(|id| self.get_as_of(id, as_of))
+            .collect()
+    }
+
     /// Lists all statutes.
     pub fn list(&self) -> Vec<&StatuteEntry> {
         self.statutes.values().collect()
@@ -1601,58 +1911,157 @@ impl StatuteRegistry {
     }
 
     /// Advanced search with multiple filters.
+    ///
+    /// In [`SearchMode::Semantic`] and [`SearchMode::Hybrid`] the text term
+    /// ranks statutes instead of filtering them, and results come best
+    /// first. Embedding failures yield no results; use
+    /// [`StatuteRegistry::search_scored`] to observe them.
     pub fn search(&self, query: &SearchQuery) -> Vec<&StatuteEntry> {
+        if query.text.is_some() && query.mode.uses_vectors() {
+            return self
+                .search_scored(query)
+                .map(|results| results.into_iter().map(|r| r.entry).collect())
+                .unwrap_or_default();
+        }
+
         self.statutes
             .values()
-            .filter(|entry| {
-                // Text search
-                if let Some(text) = &query.text {
-                    let text_lower = text.to_lowercase();
-                    if !entry.statute.id.to_lowercase().contains(&text_lower)
-                        && !entry.statute.title.to_lowercase().contains(&text_lower)
-                        && !entry
-                            .statute
-                            .effect
-                            .description
-                            .to_lowercase()
-                            .contains(&text_lower)
-                        && !entry
-                            .statute
-                            .discretion_logic
-                            .as_ref()
-                            .is_some_and(|d| d.to_lowercase().contains(&text_lower))
-                    {
-                        return false;
-                    }
-                }
+            .filter(|entry| Self::matches_text(entry, query) && Self::matches_filters(entry, query))
+            .collect()
+    }
 
-                // Tag filter
-                if !query.tags.is_empty() && !query.tags.iter().any(|t| entry.tags.contains(t)) {
-                    return false;
-                }
+    /// Searches and scores statutes, best first.
+    ///
+    /// The text term is scored according to the query's [`SearchMode`];
+    /// vector modes sync the semantic index with the registry first. Without
+    /// a text term every statute passing the filters scores 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use legalis_core::{Effect, EffectType, Statute};
+    /// use legalis_registry::{SearchMode, SearchQuery, StatuteEntry, StatuteRegistry};
+    ///
+    /// let mut registry = StatuteRegistry::new();
+    /// for (id, title) in [
+    ///     ("child-benefit", "Child Benefit Act"),
+    ///     ("vehicle-registration", "Vehicle Registration Act"),
+    /// ] {
+    ///     let statute = Statute::new(id, title, Effect::new(EffectType::Grant, "Applies"));
+    ///     registry.register(StatuteEntry::new(statute, "UK")).unwrap();
+    /// }
+    ///
+    /// let query = SearchQuery::new()
+    ///     .with_text("benefits for children")
+    ///     .with_mode(SearchMode::Semantic);
+    /// let results = registry.search_scored(&query).unwrap();
+    /// assert_eq!(results[0].entry.statute.id, "child-benefit");
+    /// ```
+    pub fn search_scored(&self, query: &SearchQuery) -> RegistryResult<Vec<SearchResult<'_>>> {
+        let filtered = self
+            .statutes
+            .values()
+            .filter(|entry| Self::matches_filters(entry, query));
+        let Some(text) = &query.text else {
+            return Ok(filtered
+                .map(|entry| SearchResult::new(entry, 1.0))
+                .collect());
+        };
 
-                // Jurisdiction filter
-                if let Some(jurisdiction) = &query.jurisdiction
-                    && &entry.jurisdiction != jurisdiction
-                {
-                    return false;
-                }
+        let ranking = RankingConfig::default();
+        if query.mode.uses_vectors() {
+            let mut index = self.semantic_index.lock().unwrap();
+            index.sync(self.statutes.values())?;
+            let ranked = index.rank(text, query.mode, filtered, &ranking)?;
+            return Ok(ranked
+                .into_iter()
+                .map(|(entry, score)| SearchResult::new(entry, score))
+                .collect());
+        }
 
-                // Status filter
-                if let Some(status) = &query.status
-                    && &entry.status != status
-                {
-                    return false;
-                }
+        let text_lower = text.to_lowercase();
+        let mut results: Vec<SearchResult> = filtered
+            .filter(|entry| Self::matches_text(entry, query))
+            .map(|entry| {
+                let score = self.calculate_relevance_score(entry, &text_lower, &ranking);
+                SearchResult::new(entry, score)
+            })
+            .collect();
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+        Ok(results)
+    }
 
-                // Active only filter
-                if query.active_only && !entry.is_active() {
-                    return false;
-                }
+    /// Brings the semantic index up to date with the registry.
+    pub fn sync_semantic_index(&self) -> RegistryResult<vector_search::SyncReport> {
+        let mut index = self.semantic_index.lock().unwrap();
+        Ok(index.sync(self.statutes.values())?)
+    }
 
-                true
-            })
-            .collect()
+    /// Replaces the semantic index, e.g. with one loaded from disk or using
+    /// a different embedder.
+    pub fn with_semantic_index(mut self, index: vector_search::SemanticIndex) -> Self {
+        self.semantic_index = Mutex::new(index);
+        self
+    }
+
+    /// Syncs the semantic index and writes it to a file.
+    ///
+    /// Load it back with [`vector_search::SemanticIndex::load`] and
+    /// [`StatuteRegistry::with_semantic_index`].
+    pub fn save_semantic_index(&self, path: impl AsRef<std::path::Path>) -> RegistryResult<()> {
+        let mut index = self.semantic_index.lock().unwrap();
+        index.sync(self.statutes.values())?;
+        Ok(index.save(path)?)
+    }
+
+    /// Checks the text term of a lexical search.
+    fn matches_text(entry: &StatuteEntry, query: &SearchQuery) -> bool {
+        let Some(text) = &query.text else {
+            return true;
+        };
+        let text_lower = text.to_lowercase();
+        entry.statute.id.to_lowercase().contains(&text_lower)
+            || entry.statute.title.to_lowercase().contains(&text_lower)
+            || entry
+                .statute
+                .effect
+                .description
+                .to_lowercase()
+                .contains(&text_lower)
+            || entry
+                .statute
+                .discretion_logic
+                .as_ref()
+                .is_some_and(|d| d.to_lowercase().contains(&text_lower))
+    }
+
+    /// Checks every filter of a search query except the text term.
+    fn matches_filters(entry: &StatuteEntry, query: &SearchQuery) -> bool {
+        // Tag filter
+        if !query.tags.is_empty() && !query.tags.iter().any(|t| entry.tags.contains(t)) {
+            return false;
+        }
+
+        // Jurisdiction filter
+        if let Some(jurisdiction) = &query.jurisdiction
+            && &entry.jurisdiction != jurisdiction
+        {
+            return false;
+        }
+
+        // Status filter
+        if let Some(status) = &query.status
+            && &entry.status != status
+        {
+            return false;
+        }
+
+        // Active only filter
+        if query.active_only && !entry.is_active() {
+            return false;
+        }
+
+        true
     }
 
     /// Searches with pagination support.
@@ -1719,7 +2128,8 @@ impl StatuteRegistry {
     /// Deletes a statute from the registry.
     ///
     /// This removes the statute, all its versions, and cleans up all indexes.
-    /// Returns the deleted entry if found.
+    /// The versions are kept as deleted history for [`Self::get_as_of`] and
+    /// [`Self::list_as_of`]. Returns the deleted entry if found.
     pub fn delete(&mut self, statute_id: &str) -> RegistryResult<StatuteEntry> {
         let entry = self
             .statutes
@@ -1750,8 +2160,15 @@ impl StatuteRegistry {
             }
         }
 
-        // Remove all versions
-        self.versions.remove(statute_id);
+        // Move all versions to the deleted history
+        if let Some(versions) = self.versions.remove(statute_id) {
+            let mut versions: Vec<StatuteEntry> = versions.into_values().collect();
+            versions.sort_by_key(|v| v.version);
+            self.deleted_versions
+                .entry(statute_id.to_string())
+                .or_default()
+                .extend(versions);
+        }
 
         // Record event and trigger webhooks
         self.record_event(RegistryEvent::StatuteDeleted {
@@ -2091,58 +2508,19 @@ impl StatuteRegistry {
     }
 
     /// Calculates relevance score for a statute entry.
-    #[allow(dead_code)]
     fn calculate_relevance_score(
         &self,
         entry: &StatuteEntry,
         query: &str,
         config: &RankingConfig,
     ) -> f64 {
-        let mut score = 0.0;
-
-        // Title matching
-        let title_lower = entry.statute.title.to_lowercase();
-        if title_lower == query {
-            score += config.title_weight * config.exact_match_boost;
-        } else if title_lower.contains(query) {
-            score += config.title_weight;
-        }
-
-        // ID matching
-        let id_lower = entry.statute.id.to_lowercase();
-        if id_lower == query {
-            score += config.id_weight * config.exact_match_boost;
-        } else if id_lower.contains(query) {
-            score += config.id_weight;
-        }
-
-        // Tag matching
-        for tag in &entry.tags {
-            let tag_lower = tag.to_lowercase();
-            if tag_lower == query {
-                score += config.tag_weight * config.exact_match_boost;
-            } else if tag_lower.contains(query) {
-                score += config.tag_weight;
-            }
-        }
-
-        // Jurisdiction matching
-        let jurisdiction_lower = entry.jurisdiction.to_lowercase();
-        if jurisdiction_lower == query {
-            score += config.jurisdiction_weight * config.exact_match_boost;
-        } else if jurisdiction_lower.contains(query) {
-            score += config.jurisdiction_weight;
-        }
-
-        // Normalize score to 0.0-1.0 range
-        // Max possible score is title + id + all tags + jurisdiction (with boost)
-        let max_score = (config.title_weight
-            + config.id_weight
-            + config.jurisdiction_weight
-            + config.tag_weight * 5.0)
-            * config.exact_match_boost;
-
-        (score / max_score).min(1.0)
+        config.score(
+            query,
+            &entry.statute.id,
+            &entry.statute.title,
+            &entry.tags,
+            &entry.jurisdiction,
+        )
     }
 
     /// Searches statutes with fuzzy matching and ranking.
@@ -2207,6 +2585,7 @@ impl StatuteRegistry {
         RegistryBackup {
             statutes: statutes.clone(),
             versions: self.versions.clone(),
+            deleted_versions: self.deleted_versions.clone(),
             events,
             metadata: BackupMetadata {
                 created_at: Utc::now(),
@@ -2230,6 +2609,7 @@ impl StatuteRegistry {
         // Clear current state
         self.statutes.clear();
         self.versions.clear();
+        self.deleted_versions.clear();
         self.tag_index.clear();
         self.jurisdiction_index.clear();
         self.cache.clear();
@@ -2237,6 +2617,7 @@ impl StatuteRegistry {
 
         // Restore versions
         self.versions = backup.versions;
+        self.deleted_versions = backup.deleted_versions;
 
         // Restore statutes and rebuild indexes
         for entry in backup.statutes {
@@ -4739,19 +5120,24 @@ pub mod akoma_ntoso {
 // Database Backend Support
 // =============================================================================
 
-#[cfg(any(feature = "sqlite", feature = "postgres"))]
+#[cfg(feature = "storage")]
 pub mod storage {
     //! Storage backend implementations for persistent statute storage.
     //!
     //! This module provides database backends with connection pooling
-    //! for SQLite and PostgreSQL.
+    //! for SQLite and PostgreSQL, an embedded single-file backend for the CLI
+    //! and small deployments, and an in-memory backend for tests and
+    //! ephemeral deployments.
 
     use super::*;
+    #[cfg(any(feature = "sqlite", feature = "postgres"))]
     use sqlx::{Pool, Row};
-    use std::sync::Arc;
+    use std::sync::{Arc, RwLock};
+
+    mod file;
+    pub use file::{FileBackend, FileStats, FsckProblem, FsckReport, LogRecord};
 
     /// Storage backend trait for statute persistence.
-    #[cfg(feature = "async")]
     #[async_trait::async_trait]
     pub trait StorageBackend: Send + Sync {
         /// Stores a statute entry.
@@ -4774,6 +5160,10 @@ pub mod storage {
         async fn list_versions(&self, statute_id: &str) -> RegistryResult<Vec<u32>>;
 
         /// Deletes a statute.
+        ///
+        /// The built-in backends keep the deleted versions as history, so
+        /// [`Self::get_as_of`] and [`Self::list_as_of`] still return the law that
+        /// applied while the statute was in force.
         async fn delete(&self, statute_id: &str) -> RegistryResult<()>;
 
         /// Searches statutes by jurisdiction.
@@ -4787,6 +5177,224 @@ pub mod storage {
 
         /// Counts total statutes.
         async fn count(&self) -> RegistryResult<usize>;
+
+        /// Retrieves every stored version of a statute, oldest first.
+        async fn history(&self, statute_id: &str) -> RegistryResult<Vec<StatuteEntry>> {
+            let mut history = Vec::new();
+            for version in self.list_versions(statute_id).await? {
+                if let Some(entry) = self.get_version(statute_id, version).await? {
+                    history.push(entry);
+                }
+            }
+            Ok(history)
+        }
+
+        /// Retrieves the version of a statute in force at a point in time.
+        ///
+        /// See [`AsOf`] for how versions are selected. The default
+        /// implementation only sees statutes that are still stored; backends
+        /// that keep deleted history override it.
+        async fn get_as_of(
+            &self,
+            statute_id: &str,
+            as_of: &AsOf,
+        ) -> RegistryResult<Option<StatuteEntry>> {
+            let history = self.history(statute_id).await?;
+            Ok(as_of.select(&history).cloned())
+        }
+
+        /// Lists every statute as it was in force at a point in time.
+        ///
+        /// The default implementation queries each stored statute in turn and
+        /// misses deleted ones; backends override it with a single lookup.
+        async fn list_as_of(&self, as_of: &AsOf) -> RegistryResult<Vec<StatuteEntry>> {
+            let mut entries = Vec::new();
+            for latest in self.list().await? {
+                if let Some(entry) = self.get_as_of(&latest.statute.id, as_of).await? {
+                    entries.push(entry);
+                }
+            }
+            Ok(entries)
+        }
+    }
+
+    /// Selects the version in force for each statute from `(entry, deleted)`
+    /// rows holding current and deleted versions, in first-seen order.
+    fn select_as_of<I>(as_of: &AsOf, rows: I) -> Vec<StatuteEntry>
+    where
+        I: IntoIterator<Item = (StatuteEntry, bool)>,
+    {
+        let mut histories: IndexMap<String, (Vec<StatuteEntry>, Vec<StatuteEntry>)> =
+            IndexMap::new();
+        for (entry, deleted) in rows {
+            let (current, removed) = histories.entry(entry.statute.id.clone()).or_default();
+            if deleted {
+                removed.push(entry);
+            } else {
+                current.push(entry);
+            }
+        }
+        histories
+            .values()
+            .filter_map(|(current, removed)| as_of.select_with_deleted(current, removed))
+            .cloned()
+            .collect()
+    }
+
+    /// In-memory storage backend.
+    ///
+    /// Keeps every version of every statute, in registration order, and the
+    /// versions of deleted statutes for as-of queries. Data is lost when the
+    /// backend is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use legalis_core::{Effect, EffectType, Statute};
+    /// use legalis_registry::StatuteEntry;
+    /// use legalis_registry::storage::{MemoryBackend, StorageBackend};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let backend = MemoryBackend::new();
+    /// let statute = Statute::new("s-1", "Test", Effect::new(EffectType::Grant, "Benefit"));
+    /// backend.store(&StatuteEntry::new(statute, "JP")).await.unwrap();
+    ///
+    /// assert_eq!(backend.count().await.unwrap(), 1);
+    /// assert_eq!(backend.list_versions("s-1").await.unwrap(), vec![1]);
+    /// # });
+    /// ```
+    #[derive(Debug, Default, Clone)]
+    pub struct MemoryBackend {
+        entries: Arc<RwLock<IndexMap<String, Vec<StatuteEntry>>>>,
+        deleted: Arc<RwLock<IndexMap<String, Vec<StatuteEntry>>>>,
+    }
+
+    impl MemoryBackend {
+        /// Creates an empty in-memory backend.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        fn latest<F>(&self, filter: F) -> Vec<StatuteEntry>
+        where
+            F: Fn(&StatuteEntry) -> bool,
+        {
+            let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+            entries
+                .values()
+                .filter_map(|versions| versions.last())
+                .filter(|entry| filter(entry))
+                .cloned()
+                .collect()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl StorageBackend for MemoryBackend {
+        async fn store(&self, entry: &StatuteEntry) -> RegistryResult<()> {
+            let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+            let versions = entries.entry(entry.statute.id.clone()).or_default();
+            match versions.iter().position(|v| v.version >= entry.version) {
+                Some(i) if versions[i].version == entry.version => versions[i] = entry.clone(),
+                Some(i) => versions.insert(i, entry.clone()),
+                None => versions.push(entry.clone()),
+            }
+            Ok(())
+        }
+
+        async fn get(&self, statute_id: &str) -> RegistryResult<Option<StatuteEntry>> {
+            let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+            Ok(entries
+                .get(statute_id)
+                .and_then(|versions| versions.last())
+                .cloned())
+        }
+
+        async fn get_version(
+            &self,
+            statute_id: &str,
+            version: u32,
+        ) -> RegistryResult<Option<StatuteEntry>> {
+            let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+            Ok(entries
+                .get(statute_id)
+                .and_then(|versions| versions.iter().find(|v| v.version == version))
+                .cloned())
+        }
+
+        async fn list(&self) -> RegistryResult<Vec<StatuteEntry>> {
+            Ok(self.latest(|_| true))
+        }
+
+        async fn list_versions(&self, statute_id: &str) -> RegistryResult<Vec<u32>> {
+            let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+            Ok(entries
+                .get(statute_id)
+                .map(|versions| versions.iter().map(|v| v.version).collect())
+                .unwrap_or_default())
+        }
+
+        async fn delete(&self, statute_id: &str) -> RegistryResult<()> {
+            let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+            if let Some(versions) = entries.shift_remove(statute_id) {
+                let mut deleted = self.deleted.write().unwrap_or_else(|e| e.into_inner());
+                deleted
+                    .entry(statute_id.to_string())
+                    .or_default()
+                    .extend(versions);
+            }
+            Ok(())
+        }
+
+        async fn find_by_jurisdiction(
+            &self,
+            jurisdiction: &str,
+        ) -> RegistryResult<Vec<StatuteEntry>> {
+            Ok(self.latest(|entry| entry.jurisdiction == jurisdiction))
+        }
+
+        async fn find_by_tag(&self, tag: &str) -> RegistryResult<Vec<StatuteEntry>> {
+            Ok(self.latest(|entry| entry.tags.iter().any(|t| t == tag)))
+        }
+
+        async fn count(&self) -> RegistryResult<usize> {
+            let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+            Ok(entries.len())
+        }
+
+        async fn history(&self, statute_id: &str) -> RegistryResult<Vec<StatuteEntry>> {
+            let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+            Ok(entries.get(statute_id).cloned().unwrap_or_default())
+        }
+
+        async fn get_as_of(
+            &self,
+            statute_id: &str,
+            as_of: &AsOf,
+        ) -> RegistryResult<Option<StatuteEntry>> {
+            let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+            let deleted = self.deleted.read().unwrap_or_else(|e| e.into_inner());
+            Ok(as_of
+                .select_with_deleted(
+                    entries.get(statute_id).into_iter().flatten(),
+                    deleted.get(statute_id).into_iter().flatten(),
+                )
+                .cloned())
+        }
+
+        async fn list_as_of(&self, as_of: &AsOf) -> RegistryResult<Vec<StatuteEntry>> {
+            let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+            let deleted = self.deleted.read().unwrap_or_else(|e| e.into_inner());
+            let current = entries
+                .values()
+                .flatten()
+                .map(|entry| (entry.clone(), false));
+            let removed = deleted
+                .values()
+                .flatten()
+                .map(|entry| (entry.clone(), true));
+            Ok(select_as_of(as_of, current.chain(removed)))
+        }
     }
 
     /// SQLite storage backend with connection pooling.
@@ -4821,7 +5429,7 @@ pub mod storage {
                     modified_at TEXT NOT NULL,
                     statute_data TEXT NOT NULL,
                     tags TEXT NOT NULL,
-                    references TEXT NOT NULL,
+                    "references" TEXT NOT NULL,
                     supersedes TEXT NOT NULL,
                     metadata TEXT NOT NULL,
                     UNIQUE(statute_id, version)
@@ -4830,6 +5438,27 @@ pub mod storage {
                 CREATE INDEX IF NOT EXISTS idx_statute_id ON statutes(statute_id);
                 CREATE INDEX IF NOT EXISTS idx_jurisdiction ON statutes(jurisdiction);
                 CREATE INDEX IF NOT EXISTS idx_status ON statutes(status);
+
+                CREATE TABLE IF NOT EXISTS deleted_statutes (
+                    registry_id TEXT NOT NULL,
+                    statute_id TEXT NOT NULL,
+                    version INTEGER NOT NULL,
+                    etag TEXT NOT NULL,
+                    status TEXT NOT NULL,
+                    effective_date TEXT,
+                    expiry_date TEXT,
+                    amends TEXT,
+                    jurisdiction TEXT NOT NULL,
+                    created_at TEXT NOT NULL,
+                    modified_at TEXT NOT NULL,
+                    statute_data TEXT NOT NULL,
+                    tags TEXT NOT NULL,
+                    "references" TEXT NOT NULL,
+                    supersedes TEXT NOT NULL,
+                    metadata TEXT NOT NULL
+                );
+
+                CREATE INDEX IF NOT EXISTS idx_deleted_statute_id ON deleted_statutes(statute_id);
                 "#,
             )
             .execute(&pool)
@@ -4866,7 +5495,7 @@ pub mod storage {
                 INSERT OR REPLACE INTO statutes (
                     registry_id, statute_id, version, etag, status,
                     effective_date, expiry_date, amends, jurisdiction,
-                    created_at, modified_at, statute_data, tags, references,
+                    created_at, modified_at, statute_data, tags, "references",
                     supersedes, metadata
                 ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                 "#,
@@ -4947,9 +5576,22 @@ pub mod storage {
         }
 
         async fn delete(&self, statute_id: &str) -> RegistryResult<()> {
+            let mut tx = self
+                .pool
+                .begin()
+                .await
+                .map_err(|e| RegistryError::InvalidOperation(e.to_string()))?;
+            sqlx::query("INSERT INTO deleted_statutes SELECT * FROM statutes WHERE statute_id = ?")
+                .bind(statute_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| RegistryError::InvalidOperation(e.to_string()))?;
             sqlx::query("DELETE FROM statutes WHERE statute_id = ?")
                 .bind(statute_id)
-                .execute(&*self.pool)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| RegistryError::InvalidOperation(e.to_string()))?;
+            tx.commit()
                 .await
                 .map_err(|e| RegistryError::InvalidOperation(e.to_string()))?;
 
@@ -4994,19 +5636,66 @@ pub mod storage {
         async fn count(&self) -> RegistryResult<usize> {
             let row = sqlx::query(
                 r#"
-                SELECT COUNT(DISTINCT statute_id) FROM statutes
+                SELECT COUNT(DISTINCT statute_id) FROM statutes
+                "#,
+            )
+            .fetch_one(&*self.pool)
+            .await
+            .map_err(|e| RegistryError::InvalidOperation(e.to_string()))?;
+
+            Ok(row.get::<i64, _>(0) as usize)
+        }
+
+        async fn get_as_of(
+            &self,
+            statute_id: &str,
+            as_of: &AsOf,
+        ) -> RegistryResult<Option<StatuteEntry>> {
+            let rows = sqlx::query(
+                r#"
+                SELECT *, 0 AS deleted FROM statutes WHERE statute_id = ?
+                UNION ALL
+                SELECT *, 1 AS deleted FROM deleted_statutes WHERE statute_id = ?
+                "#,
+            )
+            .bind(statute_id)
+            .bind(statute_id)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| RegistryError::InvalidOperation(e.to_string()))?;
+
+            Ok(select_as_of(as_of, self.rows_with_deleted(&rows)?)
+                .into_iter()
+                .next())
+        }
+
+        async fn list_as_of(&self, as_of: &AsOf) -> RegistryResult<Vec<StatuteEntry>> {
+            let rows = sqlx::query(
+                r#"
+                SELECT *, 0 AS deleted FROM statutes
+                UNION ALL
+                SELECT *, 1 AS deleted FROM deleted_statutes
                 "#,
             )
-            .fetch_one(&*self.pool)
+            .fetch_all(&*self.pool)
             .await
             .map_err(|e| RegistryError::InvalidOperation(e.to_string()))?;
 
-            Ok(row.get::<i64, _>(0) as usize)
+            Ok(select_as_of(as_of, self.rows_with_deleted(&rows)?))
         }
     }
 
     #[cfg(feature = "sqlite")]
     impl SqliteBackend {
+        fn rows_with_deleted(
+            &self,
+            rows: &[sqlx::sqlite::SqliteRow],
+        ) -> RegistryResult<Vec<(StatuteEntry, bool)>> {
+            rows.iter()
+                .map(|row| Ok((self.row_to_entry(row)?, row.get::<i64, _>("deleted") != 0)))
+                .collect()
+        }
+
         #[allow(dead_code)]
         fn row_to_entry(&self, row: &sqlx::sqlite::SqliteRow) -> RegistryResult<StatuteEntry> {
             let statute_json: String = row.get("statute_data");
@@ -5091,7 +5780,7 @@ pub mod storage {
                     modified_at TIMESTAMPTZ NOT NULL,
                     statute_data JSONB NOT NULL,
                     tags JSONB NOT NULL,
-                    references JSONB NOT NULL,
+                    "references" JSONB NOT NULL,
                     supersedes JSONB NOT NULL,
                     metadata JSONB NOT NULL,
                     UNIQUE(statute_id, version)
@@ -5101,6 +5790,27 @@ pub mod storage {
                 CREATE INDEX IF NOT EXISTS idx_jurisdiction ON statutes(jurisdiction);
                 CREATE INDEX IF NOT EXISTS idx_status ON statutes(status);
                 CREATE INDEX IF NOT EXISTS idx_tags ON statutes USING GIN (tags);
+
+                CREATE TABLE IF NOT EXISTS deleted_statutes (
+                    registry_id UUID NOT NULL,
+                    statute_id TEXT NOT NULL,
+                    version INTEGER NOT NULL,
+                    etag TEXT NOT NULL,
+                    status TEXT NOT NULL,
+                    effective_date TIMESTAMPTZ,
+                    expiry_date TIMESTAMPTZ,
+                    amends TEXT,
+                    jurisdiction TEXT NOT NULL,
+                    created_at TIMESTAMPTZ NOT NULL,
+                    modified_at TIMESTAMPTZ NOT NULL,
+                    statute_data JSONB NOT NULL,
+                    tags JSONB NOT NULL,
+                    "references" JSONB NOT NULL,
+                    supersedes JSONB NOT NULL,
+                    metadata JSONB NOT NULL
+                );
+
+                CREATE INDEX IF NOT EXISTS idx_deleted_statute_id ON deleted_statutes(statute_id);
                 "#,
             )
             .execute(&pool)
@@ -5137,7 +5847,7 @@ pub mod storage {
                 INSERT INTO statutes (
                     registry_id, statute_id, version, etag, status,
                     effective_date, expiry_date, amends, jurisdiction,
-                    created_at, modified_at, statute_data, tags, references,
+                    created_at, modified_at, statute_data, tags, "references",
                     supersedes, metadata
                 ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
                 ON CONFLICT (statute_id, version)
@@ -5226,11 +5936,16 @@ pub mod storage {
         }
 
         async fn delete(&self, statute_id: &str) -> RegistryResult<()> {
-            sqlx::query("DELETE FROM statutes WHERE statute_id = $1")
-                .bind(statute_id)
-                .execute(&*self.pool)
-                .await
-                .map_err(|e| RegistryError::InvalidOperation(e.to_string()))?;
+            sqlx::query(
+                r#"
+                WITH removed AS (DELETE FROM statutes WHERE statute_id = $1 RETURNING *)
+                INSERT INTO deleted_statutes SELECT * FROM removed
+                "#,
+            )
+            .bind(statute_id)
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| RegistryError::InvalidOperation(e.to_string()))?;
 
             Ok(())
         }
@@ -5280,10 +5995,56 @@ pub mod storage {
 
             Ok(row.get::<i64, _>(0) as usize)
         }
+
+        async fn get_as_of(
+            &self,
+            statute_id: &str,
+            as_of: &AsOf,
+        ) -> RegistryResult<Option<StatuteEntry>> {
+            let rows = sqlx::query(
+                r#"
+                SELECT *, FALSE AS deleted FROM statutes WHERE statute_id = $1
+                UNION ALL
+                SELECT *, TRUE AS deleted FROM deleted_statutes WHERE statute_id = $1
+                "#,
+            )
+            .bind(statute_id)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| RegistryError::InvalidOperation(e.to_string()))?;
+
+            Ok(select_as_of(as_of, self.rows_with_deleted(&rows)?)
+                .into_iter()
+                .next())
+        }
+
+        async fn list_as_of(&self, as_of: &AsOf) -> RegistryResult<Vec<StatuteEntry>> {
+            let rows = sqlx::query(
+                r#"
+                SELECT *, FALSE AS deleted FROM statutes
+                UNION ALL
+                SELECT *, TRUE AS deleted FROM deleted_statutes
+                "#,
+            )
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| RegistryError::InvalidOperation(e.to_string()))?;
+
+            Ok(select_as_of(as_of, self.rows_with_deleted(&rows)?))
+        }
     }
 
     #[cfg(feature = "postgres")]
     impl PostgresBackend {
+        fn rows_with_deleted(
+            &self,
+            rows: &[sqlx::postgres::PgRow],
+        ) -> RegistryResult<Vec<(StatuteEntry, bool)>> {
+            rows.iter()
+                .map(|row| Ok((self.row_to_entry(row)?, row.get::<bool, _>("deleted"))))
+                .collect()
+        }
+
         #[allow(dead_code)]
         fn row_to_entry(&self, row: &sqlx::postgres::PgRow) -> RegistryResult<StatuteEntry> {
             let statute_json: serde_json::Value = row.get("statute_data");
@@ -6300,6 +7061,7 @@ impl StatuteRegistry {
         let backup = RegistryBackup {
             statutes: self.statutes.values().cloned().collect(),
             versions: self.versions.clone(),
+            deleted_versions: self.deleted_versions.clone(),
             events: self.event_store.all_events().into_iter().cloned().collect(),
             metadata: BackupMetadata {
                 created_at: Utc::now(),
@@ -13109,13 +13871,18 @@ pub mod advanced_search {
         }
     }
 
-    /// Semantic search using embeddings (placeholder for future ML integration).
+    /// Semantic search over statute embeddings.
+    ///
+    /// Uses the offline [`vector_search::HashedTfIdfEmbedder`]; searches
+    /// return nothing until the engine is enabled and statutes are indexed.
     #[derive(Debug)]
     pub struct SemanticSearch {
         /// Enabled flag
         enabled: bool,
         /// Embedding dimension
         dimension: usize,
+        /// Indexed statute embeddings
+        index: vector_search::SemanticIndex,
     }
 
     impl SemanticSearch {
@@ -13124,6 +13891,9 @@ pub mod advanced_search {
             Self {
                 enabled: false,
                 dimension,
+                index: vector_search::SemanticIndex::new(vector_search::HashedTfIdfEmbedder::new(
+                    dimension,
+                )),
             }
         }
 
@@ -13142,13 +13912,27 @@ pub mod advanced_search {
             self.dimension
         }
 
-        /// Placeholder for semantic search (would integrate with ML models).
-        pub fn search(&self, _query: &str, _top_k: usize) -> Vec<(String, f64)> {
-            // In a real implementation, this would:
-            // 1. Generate embedding for query
-            // 2. Search vector database for similar embeddings
-            // 3. Return statute IDs with similarity scores
-            Vec::new()
+        /// Indexes the given statutes, dropping any no longer present.
+        pub fn index_entries<'a>(
+            &mut self,
+            entries: impl IntoIterator<Item = &'a StatuteEntry>,
+        ) -> RegistryResult<vector_search::SyncReport> {
+            Ok(self.index.sync(entries)?)
+        }
+
+        /// Returns statute IDs closest in meaning to the query with their
+        /// similarity, most similar first.
+        pub fn search(&self, query: &str, top_k: usize) -> Vec<(String, f64)> {
+            if !self.enabled {
+                return Vec::new();
+            }
+            // The hashed embedder cannot fail
+            self.index
+                .search(query, top_k)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(id, similarity)| (id, f64::from(similarity)))
+                .collect()
         }
     }
 
@@ -15358,6 +16142,7 @@ pub mod event_sourcing_v2 {
                             effect_type: legalis_core::EffectType::Obligation,
                             description: "Default effect".to_string(),
                             parameters: HashMap::new(),
+                            outputs: Vec::new(),
                         },
                         discretion_logic: None,
                         temporal_validity: legalis_core::TemporalValidity {
@@ -15941,9 +16726,10 @@ pub mod event_sourcing_v2 {
 // ============================================================================
 
 /// Federation Protocol: Multi-registry federation and cross-registry queries.
+///
+/// Querying peers over HTTP and signing responses require the `federation` feature.
 pub mod federation {
     use super::*;
-    use std::collections::BTreeSet;
 
     // ========================================================================
     // 1. Federated Registry Discovery
@@ -15968,6 +16754,9 @@ pub mod federation {
         pub last_seen: DateTime<Utc>,
         /// Trust level (0-100)
         pub trust_level: u8,
+        /// Hex-encoded Ed25519 key the registry signs its search responses with
+        #[serde(default)]
+        pub public_key: Option<String>,
     }
 
     impl RegistryMetadata {
@@ -15982,9 +16771,46 @@ pub mod federation {
                 capabilities: Vec::new(),
                 last_seen: Utc::now(),
                 trust_level: 50,
+                public_key: None,
             }
         }
 
+        /// Sets the registry ID.
+        pub fn with_registry_id(mut self, registry_id: Uuid) -> Self {
+            self.registry_id = registry_id;
+            self
+        }
+
+        /// Adds a covered jurisdiction.
+        pub fn with_jurisdiction(mut self, jurisdiction: impl Into<String>) -> Self {
+            self.jurisdictions.push(jurisdiction.into());
+            self
+        }
+
+        /// Sets the trust level (0-100).
+        pub fn with_trust_level(mut self, trust_level: u8) -> Self {
+            self.trust_level = trust_level.min(100);
+            self
+        }
+
+        /// Pins the public key that responses from this registry must be signed with.
+        pub fn with_public_key(mut self, public_key: impl Into<String>) -> Self {
+            self.public_key = Some(public_key.into());
+            self
+        }
+
+        /// Checks whether this registry may hold statutes for any of the jurisdictions.
+        ///
+        /// Registries that do not declare their jurisdictions match every filter.
+        pub fn covers_any(&self, jurisdictions: &[String]) -> bool {
+            jurisdictions.is_empty()
+                || self.jurisdictions.is_empty()
+                || self
+                    .jurisdictions
+                    .iter()
+                    .any(|j| jurisdictions.iter().any(|q| q.eq_ignore_ascii_case(j)))
+        }
+
         /// Updates the last seen timestamp.
         pub fn update_last_seen(&mut self) {
             self.last_seen = Utc::now();
@@ -16139,6 +16965,18 @@ pub mod federation {
             self.target_registries = registries;
             self
         }
+
+        /// Sets the maximum number of results taken from each registry.
+        pub fn with_max_results_per_registry(mut self, max: usize) -> Self {
+            self.max_results_per_registry = max;
+            self
+        }
+
+        /// Sets the per-registry timeout in seconds.
+        pub fn with_timeout(mut self, seconds: u64) -> Self {
+            self.timeout = seconds;
+            self
+        }
     }
 
     /// Federated query result from a single registry.
@@ -16150,6 +16988,12 @@ pub mod federation {
         pub registry_name: String,
         /// Matched statute IDs
         pub statute_ids: Vec<String>,
+        /// Matched statutes, in the order the registry ranked them
+        pub hits: Vec<SearchHit>,
+        /// Trust level of the registry at query time
+        pub trust_level: u8,
+        /// Whether the response carried a valid signature from the pinned key
+        pub verified: bool,
         /// Query execution time
         pub execution_time: std::time::Duration,
         /// Success flag
@@ -16175,22 +17019,490 @@ pub mod federation {
         pub total_execution_time: std::time::Duration,
     }
 
+    impl FederatedQueryResult {
+        /// Checks whether some registries failed or timed out.
+        pub fn is_partial(&self) -> bool {
+            self.successful_queries < self.registries_queried
+        }
+
+        /// Returns the results of registries that failed, with their errors.
+        pub fn failures(&self) -> impl Iterator<Item = &RegistryQueryResult> {
+            self.registry_results.iter().filter(|r| !r.success)
+        }
+    }
+
+    /// Errors raised while querying a peer registry.
+    #[derive(Debug, Clone, Error, PartialEq, Eq)]
+    pub enum FederationError {
+        #[error("Request failed: {0}")]
+        Transport(String),
+
+        #[error("Peer returned {status}: {message}")]
+        Status { status: u16, message: String },
+
+        #[error("No response within {0}s")]
+        Timeout(u64),
+
+        #[error("Response is not signed")]
+        Unsigned,
+
+        #[error("Response signature does not match the pinned key")]
+        InvalidSignature,
+
+        #[error("No public key is pinned for this registry")]
+        UntrustedPeer,
+
+        #[error("Invalid key: {0}")]
+        InvalidKey(String),
+
+        #[error("Response does not answer this request: {0}")]
+        Mismatch(String),
+    }
+
+    /// Search request sent to a peer registry.
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+    pub struct PeerSearchRequest {
+        /// Query text
+        pub query: String,
+        /// Only statutes in these jurisdictions (all when empty)
+        #[serde(default)]
+        pub jurisdictions: Vec<String>,
+        /// Maximum number of hits to return
+        pub limit: usize,
+        /// Random value the peer echoes, so a signed response cannot be replayed
+        pub nonce: String,
+    }
+
+    impl PeerSearchRequest {
+        /// Creates a search request with a fresh nonce.
+        pub fn new(query: impl Into<String>) -> Self {
+            Self {
+                query: query.into(),
+                jurisdictions: Vec::new(),
+                limit: 50,
+                nonce: Uuid::new_v4().to_string(),
+            }
+        }
+
+        /// Filters by jurisdictions.
+        pub fn with_jurisdictions(mut self, jurisdictions: Vec<String>) -> Self {
+            self.jurisdictions = jurisdictions;
+            self
+        }
+
+        /// Sets the maximum number of hits.
+        pub fn with_limit(mut self, limit: usize) -> Self {
+            self.limit = limit;
+            self
+        }
+
+        /// Ranks candidate statutes for this request and keeps the best `limit` hits.
+        ///
+        /// This is what a registry runs when answering a peer, so every member
+        /// of a federation filters and orders results the same way.
+        pub fn rank(
+            &self,
+            candidates: impl IntoIterator<Item = SearchHit>,
+            config: &RankingConfig,
+        ) -> Vec<SearchHit> {
+            let query = self.query.to_lowercase();
+            let mut scored: Vec<(f64, SearchHit)> = candidates
+                .into_iter()
+                .filter(|hit| {
+                    self.jurisdictions.is_empty()
+                        || self
+                            .jurisdictions
+                            .iter()
+                            .any(|j| j.eq_ignore_ascii_case(&hit.jurisdiction))
+                })
+                .map(|hit| (hit.score(&query, config), hit))
+                .filter(|(score, _)| *score > 0.0)
+                .collect();
+
+            scored.sort_by(|(a_score, a), (b_score, b)| {
+                b_score
+                    .partial_cmp(a_score)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then_with(|| a.statute_id.cmp(&b.statute_id))
+            });
+
+            scored
+                .into_iter()
+                .take(self.limit)
+                .map(|(_, hit)| hit)
+                .collect()
+        }
+    }
+
+    /// A statute matched by a peer registry.
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+    pub struct SearchHit {
+        /// Statute ID
+        pub statute_id: String,
+        /// Statute title
+        pub title: String,
+        /// Statute version
+        pub version: u32,
+        /// Jurisdiction code (empty when unknown)
+        #[serde(default)]
+        pub jurisdiction: String,
+        /// Tags
+        #[serde(default)]
+        pub tags: Vec<String>,
+    }
+
+    impl SearchHit {
+        /// Creates a hit from a bare statute.
+        pub fn from_statute(statute: &Statute) -> Self {
+            Self {
+                statute_id: statute.id.clone(),
+                title: statute.title.clone(),
+                version: statute.version,
+                jurisdiction: statute.jurisdiction.clone().unwrap_or_default(),
+                tags: Vec::new(),
+            }
+        }
+
+        /// Scores this hit against a lowercase query.
+        pub fn score(&self, query: &str, config: &RankingConfig) -> f64 {
+            config.score(
+                query,
+                &self.statute_id,
+                &self.title,
+                &self.tags,
+                &self.jurisdiction,
+            )
+        }
+    }
+
+    impl From<&StatuteEntry> for SearchHit {
+        fn from(entry: &StatuteEntry) -> Self {
+            Self {
+                statute_id: entry.statute.id.clone(),
+                title: entry.statute.title.clone(),
+                version: entry.version,
+                jurisdiction: entry.jurisdiction.clone(),
+                tags: entry.tags.clone(),
+            }
+        }
+    }
+
+    /// A peer registry's answer to a [`PeerSearchRequest`].
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+    pub struct PeerSearchResponse {
+        /// Answering registry
+        pub registry_id: Uuid,
+        /// Answering registry name
+        pub registry_name: String,
+        /// Query text, echoed from the request
+        pub query: String,
+        /// Nonce, echoed from the request
+        pub nonce: String,
+        /// Matched statutes, best first
+        pub hits: Vec<SearchHit>,
+        /// When the response was produced
+        pub issued_at: DateTime<Utc>,
+        /// Hex-encoded Ed25519 signature over [`Self::signing_payload`]
+        #[serde(default)]
+        pub signature: Option<String>,
+    }
+
+    impl PeerSearchResponse {
+        /// Creates an unsigned response to a request.
+        pub fn new(
+            registry_id: Uuid,
+            registry_name: impl Into<String>,
+            request: &PeerSearchRequest,
+            hits: Vec<SearchHit>,
+        ) -> Self {
+            Self {
+                registry_id,
+                registry_name: registry_name.into(),
+                query: request.query.clone(),
+                nonce: request.nonce.clone(),
+                hits,
+                issued_at: Utc::now(),
+                signature: None,
+            }
+        }
+
+        /// Returns the bytes covered by the signature: every field but the signature.
+        pub fn signing_payload(&self) -> Vec<u8> {
+            #[derive(Serialize)]
+            struct Signed<'a> {
+                registry_id: &'a Uuid,
+                registry_name: &'a str,
+                query: &'a str,
+                nonce: &'a str,
+                hits: &'a [SearchHit],
+                issued_at: &'a DateTime<Utc>,
+            }
+
+            serde_json::to_vec(&Signed {
+                registry_id: &self.registry_id,
+                registry_name: &self.registry_name,
+                query: &self.query,
+                nonce: &self.nonce,
+                hits: &self.hits,
+                issued_at: &self.issued_at,
+            })
+            .expect("search responses always serialize")
+        }
+
+        /// Checks the signature against a hex-encoded Ed25519 public key.
+        #[cfg(feature = "federation")]
+        pub fn verify(&self, public_key: &str) -> Result<(), FederationError> {
+            let signature = self.signature.as_deref().ok_or(FederationError::Unsigned)?;
+            let key = from_hex(public_key)
+                .ok_or_else(|| FederationError::InvalidKey("public key is not hex".to_string()))?;
+            let signature = from_hex(signature).ok_or(FederationError::InvalidSignature)?;
+            ring::signature::UnparsedPublicKey::new(&ring::signature::ED25519, key)
+                .verify(&self.signing_payload(), &signature)
+                .map_err(|_| FederationError::InvalidSignature)
+        }
+    }
+
+    /// Identity a registry publishes so peers can pin its signing key.
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+    pub struct PeerIdentity {
+        /// Registry ID
+        pub registry_id: Uuid,
+        /// Registry name
+        pub name: String,
+        /// Jurisdictions covered by the registry
+        #[serde(default)]
+        pub jurisdictions: Vec<String>,
+        /// Hex-encoded Ed25519 public key
+        pub public_key: String,
+    }
+
+    impl PeerIdentity {
+        /// Builds discovery metadata for the registry served at `endpoint`.
+        ///
+        /// Only use this with an identity obtained over a channel you trust;
+        /// fetching it from the peer itself trusts the key on first use.
+        pub fn into_metadata(self, endpoint: impl Into<String>) -> RegistryMetadata {
+            let mut metadata = RegistryMetadata::new(self.name, endpoint.into())
+                .with_registry_id(self.registry_id)
+                .with_public_key(self.public_key);
+            metadata.jurisdictions = self.jurisdictions;
+            metadata
+                .capabilities
+                .push(RegistryCapability::FullTextSearch);
+            metadata
+        }
+    }
+
+    /// Ed25519 key a registry signs its search responses with.
+    #[cfg(feature = "federation")]
+    #[derive(Debug)]
+    pub struct FederationKeyPair {
+        key_pair: ring::signature::Ed25519KeyPair,
+    }
+
+    #[cfg(feature = "federation")]
+    impl FederationKeyPair {
+        /// Generates a new key as a PKCS#8 document, suitable for storing on disk.
+        pub fn generate_pkcs8() -> Result<Vec<u8>, FederationError> {
+            let rng = ring::rand::SystemRandom::new();
+            ring::signature::Ed25519KeyPair::generate_pkcs8(&rng)
+                .map(|document| document.as_ref().to_vec())
+                .map_err(|_| FederationError::InvalidKey("key generation failed".to_string()))
+        }
+
+        /// Loads a key from a PKCS#8 document.
+        pub fn from_pkcs8(pkcs8: &[u8]) -> Result<Self, FederationError> {
+            ring::signature::Ed25519KeyPair::from_pkcs8(pkcs8)
+                .map(|key_pair| Self { key_pair })
+                .map_err(|e| FederationError::InvalidKey(e.to_string()))
+        }
+
+        /// Returns the hex-encoded public key peers pin.
+        pub fn public_key(&self) -> String {
+            use ring::signature::KeyPair;
+            to_hex(self.key_pair.public_key().as_ref())
+        }
+
+        /// Signs a response in place.
+        pub fn sign(&self, response: &mut PeerSearchResponse) {
+            let signature = self.key_pair.sign(&response.signing_payload());
+            response.signature = Some(to_hex(signature.as_ref()));
+        }
+    }
+
+    #[cfg(feature = "federation")]
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[cfg(feature = "federation")]
+    fn from_hex(text: &str) -> Option<Vec<u8>> {
+        if !text.len().is_multiple_of(2) {
+            return None;
+        }
+        (0..text.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+            .collect()
+    }
+
+    /// Sends search requests to peer registries.
+    #[cfg(feature = "federation")]
+    #[async_trait::async_trait]
+    pub trait PeerTransport: Send + Sync {
+        /// Asks `peer` to run `request` against its statutes.
+        async fn search(
+            &self,
+            peer: &RegistryMetadata,
+            request: &PeerSearchRequest,
+        ) -> Result<PeerSearchResponse, FederationError>;
+    }
+
+    /// Transport calling the `/api/v1/federation/search` endpoint of legalis-api peers.
+    #[cfg(feature = "federation")]
+    #[derive(Debug, Clone, Default)]
+    pub struct HttpPeerTransport {
+        client: reqwest::Client,
+        credentials: HashMap<Uuid, String>,
+    }
+
+    #[cfg(feature = "federation")]
+    impl HttpPeerTransport {
+        /// Creates a transport without peer credentials.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Authenticates to a peer with an API key.
+        pub fn with_api_key(mut self, registry_id: Uuid, key: impl AsRef<str>) -> Self {
+            self.credentials
+                .insert(registry_id, format!("ApiKey {}", key.as_ref()));
+            self
+        }
+
+        /// Authenticates to a peer with a bearer token.
+        pub fn with_bearer_token(mut self, registry_id: Uuid, token: impl AsRef<str>) -> Self {
+            self.credentials
+                .insert(registry_id, format!("Bearer {}", token.as_ref()));
+            self
+        }
+    }
+
+    #[cfg(feature = "federation")]
+    #[async_trait::async_trait]
+    impl PeerTransport for HttpPeerTransport {
+        async fn search(
+            &self,
+            peer: &RegistryMetadata,
+            request: &PeerSearchRequest,
+        ) -> Result<PeerSearchResponse, FederationError> {
+            #[derive(Deserialize)]
+            struct Envelope {
+                data: PeerSearchResponse,
+            }
+
+            let endpoint = format!(
+                "{}/api/v1/federation/search",
+                peer.endpoint.trim_end_matches('/')
+            );
+            let mut url = reqwest::Url::parse(&endpoint)
+                .map_err(|e| FederationError::Transport(format!("{}: {}", endpoint, e)))?;
+            {
+                let mut pairs = url.query_pairs_mut();
+                pairs
+                    .append_pair("q", &request.query)
+                    .append_pair("limit", &request.limit.to_string())
+                    .append_pair("nonce", &request.nonce);
+                if !request.jurisdictions.is_empty() {
+                    pairs.append_pair("jurisdiction", &request.jurisdictions.join(","));
+                }
+            }
+
+            let mut builder = self.client.get(url);
+            if let Some(credential) = self.credentials.get(&peer.registry_id) {
+                builder = builder.header(reqwest::header::AUTHORIZATION, credential);
+            }
+
+            let response = builder
+                .send()
+                .await
+                .map_err(|e| FederationError::Transport(e.to_string()))?;
+            let status = response.status();
+            if !status.is_success() {
+                return Err(FederationError::Status {
+                    status: status.as_u16(),
+                    message: response.text().await.unwrap_or_default(),
+                });
+            }
+
+            response
+                .json::<Envelope>()
+                .await
+                .map(|envelope| envelope.data)
+                .map_err(|e| FederationError::Transport(e.to_string()))
+        }
+    }
+
     /// Cross-registry query engine.
-    #[derive(Debug)]
+    ///
+    /// Queries fan out to every selected peer at once. A peer that fails,
+    /// times out or answers with a bad signature is reported in its
+    /// [`RegistryQueryResult`] while the other peers' results are kept.
     pub struct FederatedQueryEngine {
         discovery: Arc<RegistryDiscovery>,
+        #[cfg(feature = "federation")]
+        transport: Arc<dyn PeerTransport>,
+        #[cfg(feature = "federation")]
+        allow_unsigned: bool,
+    }
+
+    impl std::fmt::Debug for FederatedQueryEngine {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("FederatedQueryEngine")
+                .field("discovery", &self.discovery)
+                .finish_non_exhaustive()
+        }
     }
 
     impl FederatedQueryEngine {
         /// Creates a new federated query engine.
         pub fn new(discovery: Arc<RegistryDiscovery>) -> Self {
-            Self { discovery }
+            Self {
+                discovery,
+                #[cfg(feature = "federation")]
+                transport: Arc::new(HttpPeerTransport::new()),
+                #[cfg(feature = "federation")]
+                allow_unsigned: false,
+            }
+        }
+
+        /// Returns the discovery service peers are selected from.
+        pub fn discovery(&self) -> &Arc<RegistryDiscovery> {
+            &self.discovery
+        }
+
+        /// Uses the given transport to reach peers.
+        #[cfg(feature = "federation")]
+        pub fn with_transport(mut self, transport: Arc<dyn PeerTransport>) -> Self {
+            self.transport = transport;
+            self
+        }
+
+        /// Accepts responses from peers without a pinned public key.
+        ///
+        /// Their results are marked unverified. Peers with a pinned key must
+        /// always answer with a valid signature.
+        #[cfg(feature = "federation")]
+        pub fn with_unsigned_peers(mut self, allow: bool) -> Self {
+            self.allow_unsigned = allow;
+            self
         }
 
         /// Executes a federated query across multiple registries.
-        pub fn execute(&self, query: FederatedQuery) -> FederatedQueryResult {
+        #[cfg(feature = "federation")]
+        pub async fn execute(&self, query: FederatedQuery) -> FederatedQueryResult {
             let start = std::time::Instant::now();
-            let registries = if query.target_registries.is_empty() {
+            let registries: Vec<RegistryMetadata> = if query.target_registries.is_empty() {
                 self.discovery.get_active_registries()
             } else {
                 self.discovery
@@ -16198,17 +17510,23 @@ pub mod federation {
                     .into_iter()
                     .filter(|r| query.target_registries.contains(&r.registry_id))
                     .collect()
-            };
+            }
+            .into_iter()
+            .filter(|r| r.covers_any(&query.jurisdictions))
+            .collect();
 
-            let mut registry_results = Vec::new();
-            let mut total_statutes = 0;
+            let request = PeerSearchRequest::new(query.query.clone())
+                .with_jurisdictions(query.jurisdictions.clone())
+                .with_limit(query.max_results_per_registry);
 
-            for registry in &registries {
-                let result = self.query_single_registry(registry, &query);
-                total_statutes += result.statute_ids.len();
-                registry_results.push(result);
-            }
+            let registry_results = futures::future::join_all(
+                registries
+                    .iter()
+                    .map(|registry| self.query_single_registry(registry, &request, query.timeout)),
+            )
+            .await;
 
+            let total_statutes = registry_results.iter().map(|r| r.hits.len()).sum();
             let successful_queries = registry_results.iter().filter(|r| r.success).count();
 
             FederatedQueryResult {
@@ -16221,23 +17539,79 @@ pub mod federation {
             }
         }
 
-        fn query_single_registry(
+        #[cfg(feature = "federation")]
+        async fn query_single_registry(
             &self,
             registry: &RegistryMetadata,
-            _query: &FederatedQuery,
+            request: &PeerSearchRequest,
+            timeout: u64,
         ) -> RegistryQueryResult {
             let start = std::time::Instant::now();
+            let outcome = match tokio::time::timeout(
+                std::time::Duration::from_secs(timeout),
+                self.transport.search(registry, request),
+            )
+            .await
+            {
+                Ok(response) => response.and_then(|r| self.accept(registry, request, r)),
+                Err(_) => Err(FederationError::Timeout(timeout)),
+            };
+
+            let (hits, verified, error) = match outcome {
+                Ok((hits, verified)) => {
+                    let mut seen = registry.clone();
+                    seen.update_last_seen();
+                    let _ = self.discovery.update_metadata(seen.registry_id, seen);
+                    (hits, verified, None)
+                }
+                Err(e) => (Vec::new(), false, Some(e.to_string())),
+            };
 
-            // Simulated query execution
-            // In a real implementation, this would make HTTP calls to the remote registry
             RegistryQueryResult {
                 registry_id: registry.registry_id,
                 registry_name: registry.name.clone(),
-                statute_ids: Vec::new(),
+                statute_ids: hits.iter().map(|h| h.statute_id.clone()).collect(),
+                hits,
+                trust_level: registry.trust_level,
+                verified,
                 execution_time: start.elapsed(),
-                success: true,
-                error: None,
+                success: error.is_none(),
+                error,
+            }
+        }
+
+        /// Checks that a response is signed by the peer and answers this request.
+        #[cfg(feature = "federation")]
+        fn accept(
+            &self,
+            registry: &RegistryMetadata,
+            request: &PeerSearchRequest,
+            response: PeerSearchResponse,
+        ) -> Result<(Vec<SearchHit>, bool), FederationError> {
+            let verified = match &registry.public_key {
+                Some(key) => {
+                    response.verify(key)?;
+                    true
+                }
+                None if self.allow_unsigned => false,
+                None => return Err(FederationError::UntrustedPeer),
+            };
+
+            if response.registry_id != registry.registry_id {
+                return Err(FederationError::Mismatch(format!(
+                    "answered by registry {}",
+                    response.registry_id
+                )));
+            }
+            if response.nonce != request.nonce || response.query != request.query {
+                return Err(FederationError::Mismatch(
+                    "query or nonce differs".to_string(),
+                ));
             }
+
+            let mut hits = response.hits;
+            hits.truncate(request.limit);
+            Ok((hits, verified))
         }
     }
 
@@ -16438,6 +17812,10 @@ pub mod federation {
     pub struct AggregatedSearchResult {
         /// Statute ID
         pub statute_id: String,
+        /// Statute title
+        pub title: String,
+        /// Statute version held by the source registry
+        pub version: u32,
         /// Source registry ID
         pub registry_id: Uuid,
         /// Registry name
@@ -16446,27 +17824,47 @@ pub mod federation {
         pub relevance_score: f64,
         /// Registry trust level
         pub trust_level: u8,
+        /// Whether the source registry's response was signature-verified
+        pub verified: bool,
         /// Combined score
         pub combined_score: f64,
+        /// Other registries that returned the same statute
+        pub also_found_in: Vec<String>,
     }
 
     /// Federated search aggregator.
     #[derive(Debug)]
     pub struct FederatedSearchAggregator {
         ranking_strategy: RankingStrategy,
+        ranking_config: RankingConfig,
     }
 
     impl FederatedSearchAggregator {
         /// Creates a new search aggregator.
         pub fn new(ranking_strategy: RankingStrategy) -> Self {
-            Self { ranking_strategy }
+            Self {
+                ranking_strategy,
+                ranking_config: RankingConfig::default(),
+            }
+        }
+
+        /// Sets the field weights used to score hits.
+        ///
+        /// Hits are re-scored locally, so results from registries with
+        /// different local ranking settings stay comparable.
+        pub fn with_ranking_config(mut self, config: RankingConfig) -> Self {
+            self.ranking_config = config;
+            self
         }
 
         /// Aggregates results from multiple registries.
+        ///
+        /// Results from unverified registries count with at most a low trust level.
         pub fn aggregate(
             &self,
             federated_result: &FederatedQueryResult,
         ) -> Vec<AggregatedSearchResult> {
+            let query = federated_result.query.to_lowercase();
             let mut results = Vec::new();
 
             for registry_result in &federated_result.registry_results {
@@ -16474,14 +17872,24 @@ pub mod federation {
                     continue;
                 }
 
-                for statute_id in &registry_result.statute_ids {
+                let trust_level = if registry_result.verified {
+                    registry_result.trust_level
+                } else {
+                    registry_result.trust_level.min(TrustLevel::Low.to_score())
+                };
+
+                for hit in &registry_result.hits {
                     let result = AggregatedSearchResult {
-                        statute_id: statute_id.clone(),
+                        statute_id: hit.statute_id.clone(),
+                        title: hit.title.clone(),
+                        version: hit.version,
                         registry_id: registry_result.registry_id,
                         registry_name: registry_result.registry_name.clone(),
-                        relevance_score: 1.0, // Would be calculated based on query match
-                        trust_level: 50,      // Would come from registry metadata
+                        relevance_score: hit.score(&query, &self.ranking_config),
+                        trust_level,
+                        verified: registry_result.verified,
                         combined_score: 0.0,
+                        also_found_in: Vec::new(),
                     };
                     results.push(result);
                 }
@@ -16491,6 +17899,14 @@ pub mod federation {
             results
         }
 
+        /// Aggregates, ranks and de-duplicates results in one step.
+        pub fn merge(
+            &self,
+            federated_result: &FederatedQueryResult,
+        ) -> Vec<AggregatedSearchResult> {
+            self.deduplicate(self.aggregate(federated_result))
+        }
+
         fn rank_results(&self, results: &mut [AggregatedSearchResult]) {
             for result in results.iter_mut() {
                 result.combined_score = match self.ranking_strategy {
@@ -16512,15 +17928,32 @@ pub mod federation {
         }
 
         /// Deduplicates results across registries.
+        ///
+        /// The best-ranked copy of each statute is kept and records the other
+        /// registries that returned it.
         pub fn deduplicate(
             &self,
             results: Vec<AggregatedSearchResult>,
         ) -> Vec<AggregatedSearchResult> {
-            let mut seen = BTreeSet::new();
-            results
-                .into_iter()
-                .filter(|r| seen.insert(r.statute_id.clone()))
-                .collect()
+            let mut merged: Vec<AggregatedSearchResult> = Vec::new();
+            let mut positions: HashMap<String, usize> = HashMap::new();
+
+            for result in results {
+                match positions.get(&result.statute_id) {
+                    Some(&position) => {
+                        let kept = &mut merged[position];
+                        if !kept.also_found_in.contains(&result.registry_name) {
+                            kept.also_found_in.push(result.registry_name);
+                        }
+                    }
+                    None => {
+                        positions.insert(result.statute_id.clone(), merged.len());
+                        merged.push(result);
+                    }
+                }
+            }
+
+            merged
         }
     }
 
@@ -16703,6 +18136,205 @@ mod tests {
         )
     }
 
+    #[cfg(feature = "storage")]
+    #[test]
+    fn test_memory_backend_versions() {
+        use storage::{MemoryBackend, StorageBackend};
+
+        tokio_test::block_on(async {
+            let backend = MemoryBackend::new();
+            let mut v1 = StatuteEntry::new(test_statute("a"), "JP");
+            v1.tags.push("tax".to_string());
+            backend.store(&v1).await.unwrap();
+            backend
+                .store(&StatuteEntry::new(test_statute("b"), "US"))
+                .await
+                .unwrap();
+
+            let mut v2 = v1.clone();
+            v2.version = 2;
+            v2.status = StatuteStatus::Active;
+            backend.store(&v2).await.unwrap();
+
+            assert_eq!(backend.count().await.unwrap(), 2);
+            assert_eq!(backend.list_versions("a").await.unwrap(), vec![1, 2]);
+            let latest = backend.get("a").await.unwrap().unwrap();
+            assert_eq!(latest.version, 2);
+            assert_eq!(latest.status, StatuteStatus::Active);
+            assert_eq!(
+                backend.get_version("a", 1).await.unwrap().unwrap().status,
+                StatuteStatus::Draft
+            );
+            assert_eq!(backend.find_by_tag("tax").await.unwrap().len(), 1);
+            assert_eq!(backend.find_by_jurisdiction("US").await.unwrap().len(), 1);
+
+            // Listing preserves registration order
+            let ids: Vec<_> = backend
+                .list()
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|e| e.statute.id)
+                .collect();
+            assert_eq!(ids, vec!["a", "b"]);
+
+            backend.delete("a").await.unwrap();
+            assert!(backend.get("a").await.unwrap().is_none());
+            assert_eq!(backend.count().await.unwrap(), 1);
+        });
+    }
+
+    #[cfg(feature = "storage")]
+    #[test]
+    fn test_memory_backend_as_of() {
+        use storage::{MemoryBackend, StorageBackend};
+
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let statute = |from| {
+            test_statute("a").with_temporal_validity(
+                legalis_core::TemporalValidity::new().with_effective_date(from),
+            )
+        };
+
+        tokio_test::block_on(async {
+            let backend = MemoryBackend::new();
+            let v1 = StatuteEntry::new(statute(date(2010, 1, 1)), "JP");
+            let mut v2 = v1.next_version(statute(date(2020, 1, 1)));
+            v2.modified_at = v1.modified_at + chrono::Duration::days(30);
+            backend.store(&v1).await.unwrap();
+            backend.store(&v2).await.unwrap();
+            let repealed = test_statute("repealed").with_temporal_validity(
+                legalis_core::TemporalValidity::new().with_expiry_date(date(2015, 12, 31)),
+            );
+            backend
+                .store(&StatuteEntry::new(repealed, "JP"))
+                .await
+                .unwrap();
+
+            assert_eq!(backend.history("a").await.unwrap().len(), 2);
+
+            let in_2019 = AsOf::new(date(2019, 4, 1));
+            let entries = backend.list_as_of(&in_2019).await.unwrap();
+            assert_eq!(entries.len(), 1);
+            assert_eq!(entries[0].version, 1);
+
+            let in_2021 = AsOf::new(date(2021, 1, 1));
+            let latest = backend.get_as_of("a", &in_2021).await.unwrap().unwrap();
+            assert_eq!(latest.version, 2);
+
+            // Before the amendment was recorded, the old text still applied
+            let known_before = in_2021.with_known_at(v1.modified_at);
+            let known = backend
+                .get_as_of("a", &known_before)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(known.version, 1);
+
+            assert!(
+                backend
+                    .get_as_of("a", &AsOf::new(date(2000, 1, 1)))
+                    .await
+                    .unwrap()
+                    .is_none()
+            );
+
+            // Deleting a statute does not rewrite the law that applied before
+            backend.delete("a").await.unwrap();
+            assert!(backend.get("a").await.unwrap().is_none());
+            let entries = backend.list_as_of(&in_2019).await.unwrap();
+            assert_eq!(entries.len(), 1);
+            assert_eq!(entries[0].version, 1);
+            let latest = backend.get_as_of("a", &in_2021).await.unwrap().unwrap();
+            assert_eq!(latest.version, 2);
+        });
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_sqlite_backend_as_of_keeps_deleted_history() {
+        use storage::{SqliteBackend, StorageBackend};
+
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let url = format!("sqlite://{}?mode=rwc", dir.path().join("r.db").display());
+
+        tokio::runtime::Runtime::new().unwrap().block_on(async {
+            let backend = SqliteBackend::new(&url).await.unwrap();
+            let statute = test_statute("a").with_temporal_validity(
+                legalis_core::TemporalValidity::new().with_effective_date(date(2010, 1, 1)),
+            );
+            let v1 = StatuteEntry::new(statute.clone(), "JP");
+            backend.store(&v1).await.unwrap();
+            backend.store(&v1.next_version(statute)).await.unwrap();
+            backend
+                .store(&StatuteEntry::new(test_statute("b"), "JP"))
+                .await
+                .unwrap();
+            backend.delete("a").await.unwrap();
+
+            let as_of = AsOf::new(date(2019, 4, 1));
+            assert_eq!(backend.count().await.unwrap(), 1);
+            assert_eq!(backend.list_as_of(&as_of).await.unwrap().len(), 2);
+            let entry = backend.get_as_of("a", &as_of).await.unwrap().unwrap();
+            assert_eq!(entry.version, 2);
+        });
+    }
+
+    #[test]
+    fn test_registry_as_of() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let mut registry = StatuteRegistry::new();
+        let statute = test_statute("tax").with_temporal_validity(
+            legalis_core::TemporalValidity::new()
+                .with_effective_date(date(2015, 4, 1))
+                .with_expiry_date(date(2019, 3, 31)),
+        );
+        registry.register(StatuteEntry::new(statute, "JP")).unwrap();
+
+        let replacement = test_statute("tax").with_temporal_validity(
+            legalis_core::TemporalValidity::new().with_effective_date(date(2019, 4, 1)),
+        );
+        registry.update("tax", replacement).unwrap();
+
+        assert_eq!(
+            registry
+                .get_as_of("tax", &AsOf::new(date(2019, 3, 31)))
+                .unwrap()
+                .version,
+            1
+        );
+        assert_eq!(
+            registry
+                .get_as_of("tax", &AsOf::new(date(2019, 4, 1)))
+                .unwrap()
+                .version,
+            2
+        );
+        assert!(
+            registry
+                .list_as_of(&AsOf::new(date(2015, 3, 31)))
+                .is_empty()
+        );
+        assert_eq!(registry.list_as_of(&AsOf::new(date(2020, 1, 1))).len(), 1);
+
+        registry.delete("tax").unwrap();
+        assert_eq!(
+            registry
+                .get_as_of("tax", &AsOf::new(date(2019, 3, 31)))
+                .unwrap()
+                .version,
+            1
+        );
+        assert_eq!(registry.list_as_of(&AsOf::new(date(2020, 1, 1))).len(), 1);
+
+        // Deleted history survives a backup round trip
+        let backup = registry.create_backup(None);
+        let mut restored = StatuteRegistry::new();
+        restored.restore_from_backup(backup).unwrap();
+        assert_eq!(restored.list_as_of(&AsOf::new(date(2020, 1, 1))).len(), 1);
+    }
+
     #[test]
     fn test_register_statute() {
         let mut registry = StatuteRegistry::new();
@@ -16846,6 +18478,124 @@ mod tests {
         assert_eq!(results.len(), 2);
     }
 
+    fn semantic_test_registry() -> StatuteRegistry {
+        let mut registry = StatuteRegistry::new();
+        for (id, title, effect, jurisdiction) in [
+            (
+                "income-tax",
+                "Income Taxation Act",
+                "Residents pay tax on their income",
+                "JP",
+            ),
+            (
+                "tax-credit",
+                "Earned Income Credit",
+                "Refundable tax credit for low earners",
+                "US",
+            ),
+            (
+                "road-traffic",
+                "Road Traffic Act",
+                "Drivers must hold a licence",
+                "JP",
+            ),
+            (
+                "child-care",
+                "Child Care Leave Act",
+                "Parents may take leave to raise children",
+                "JP",
+            ),
+        ] {
+            let statute = Statute::new(id, title, Effect::new(EffectType::Grant, effect));
+            registry
+                .register(StatuteEntry::new(statute, jurisdiction))
+                .unwrap();
+        }
+        registry
+    }
+
+    #[test]
+    fn test_semantic_search_mode() {
+        let registry = semantic_test_registry();
+
+        // A lexical search needs the literal phrase
+        let lexical = SearchQuery::new().with_text("taxes on earned income");
+        assert!(registry.search(&lexical).is_empty());
+
+        let semantic = lexical.with_mode(SearchMode::Semantic);
+        let ids: Vec<_> = registry
+            .search(&semantic)
+            .iter()
+            .map(|e| e.statute.id.as_str())
+            .collect();
+        assert!(ids.contains(&"income-tax"));
+        assert!(ids.contains(&"tax-credit"));
+        assert!(!ids.contains(&"road-traffic"));
+
+        // Filters still apply to semantic matches
+        let in_japan = semantic.with_jurisdiction("JP");
+        let ids: Vec<_> = registry
+            .search(&in_japan)
+            .iter()
+            .map(|e| e.statute.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["income-tax"]);
+    }
+
+    #[test]
+    fn test_hybrid_search_mode() {
+        let mut registry = semantic_test_registry();
+        let query = SearchQuery::new()
+            .with_text("income credit")
+            .with_mode(SearchMode::hybrid());
+
+        let results = registry.search_scored(&query).unwrap();
+        assert_eq!(results[0].entry.statute.id, "tax-credit");
+        assert!(results.windows(2).all(|w| w[0].score >= w[1].score));
+        assert!(results.iter().all(|r| r.entry.statute.id != "road-traffic"));
+
+        // Newly registered statutes are picked up on the next search
+        let statute = Statute::new(
+            "credit-union",
+            "Credit Union Act",
+            Effect::new(EffectType::Grant, "Members pool their income"),
+        );
+        registry.register(StatuteEntry::new(statute, "US")).unwrap();
+        let results = registry.search_scored(&query).unwrap();
+        assert!(results.iter().any(|r| r.entry.statute.id == "credit-union"));
+    }
+
+    #[test]
+    fn test_search_mode_parse() {
+        assert_eq!(
+            "lexical".parse::<SearchMode>().unwrap(),
+            SearchMode::Lexical
+        );
+        assert_eq!(
+            "Hybrid".parse::<SearchMode>().unwrap(),
+            SearchMode::Hybrid { vector_weight: 0.5 }
+        );
+        assert!("fuzzy".parse::<SearchMode>().is_err());
+    }
+
+    #[test]
+    fn test_semantic_index_persistence() {
+        use vector_search::{HashedTfIdfEmbedder, SemanticIndex};
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("registry.semantic");
+        let registry = semantic_test_registry();
+        registry.save_semantic_index(&path).unwrap();
+
+        let index = SemanticIndex::load(&path, HashedTfIdfEmbedder::default()).unwrap();
+        assert_eq!(index.len(), 4);
+        let registry = semantic_test_registry().with_semantic_index(index);
+
+        // The restored index is current, so nothing is re-embedded
+        let report = registry.sync_semantic_index().unwrap();
+        assert!(!report.has_changes());
+    }
+
     #[test]
     fn test_pagination() {
         let mut registry = StatuteRegistry::new();
@@ -24620,11 +26370,52 @@ mod tests {
         semantic.enable();
         assert!(semantic.is_enabled());
 
-        // Placeholder search returns empty (no ML integration yet)
+        // Nothing indexed yet
         let results = semantic.search("test query", 10);
         assert!(results.is_empty());
     }
 
+    #[test]
+    fn test_semantic_search_indexed() {
+        use advanced_search::*;
+
+        let entries: Vec<StatuteEntry> = [
+            (
+                "pension-act",
+                "Pension Act",
+                "Retirement pension for the elderly",
+            ),
+            (
+                "parking-rules",
+                "Parking Rules",
+                "Penalty for illegal parking",
+            ),
+            (
+                "minimum-wage",
+                "Minimum Wage Act",
+                "Employers pay the minimum wage",
+            ),
+        ]
+        .into_iter()
+        .map(|(id, title, effect)| {
+            StatuteEntry::new(
+                Statute::new(id, title, Effect::new(EffectType::Grant, effect)),
+                "JP",
+            )
+        })
+        .collect();
+
+        let mut semantic = SemanticSearch::default();
+        let report = semantic.index_entries(&entries).unwrap();
+        assert_eq!(report.added, 3);
+        assert!(semantic.search("pensions", 3).is_empty());
+
+        semantic.enable();
+        let results = semantic.search("retirement pensions", 3);
+        assert_eq!(results[0].0, "pension-act");
+        assert!(results.iter().all(|(id, _)| id != "parking-rules"));
+    }
+
     #[test]
     fn test_semantic_search_default() {
         use advanced_search::*;
@@ -26395,24 +28186,132 @@ mod tests {
         assert!(format!("{:?}", engine).contains("FederatedQueryEngine"));
     }
 
+    /// Answers peer searches from an in-memory registry, signing with its own key.
+    #[cfg(feature = "federation")]
+    struct LoopbackTransport {
+        entries: Vec<StatuteEntry>,
+        keys: HashMap<Uuid, federation::FederationKeyPair>,
+    }
+
+    #[cfg(feature = "federation")]
+    #[async_trait::async_trait]
+    impl federation::PeerTransport for LoopbackTransport {
+        async fn search(
+            &self,
+            peer: &federation::RegistryMetadata,
+            request: &federation::PeerSearchRequest,
+        ) -> Result<federation::PeerSearchResponse, federation::FederationError> {
+            let candidates = self.entries.iter().map(federation::SearchHit::from);
+            let hits = request.rank(candidates, &RankingConfig::default());
+            let mut response =
+                federation::PeerSearchResponse::new(peer.registry_id, &peer.name, request, hits);
+            if let Some(key) = self.keys.get(&peer.registry_id) {
+                key.sign(&mut response);
+            }
+            Ok(response)
+        }
+    }
+
+    #[cfg(feature = "federation")]
     #[test]
     fn test_federated_query_engine_execute() {
         use federation::*;
 
+        let entries = vec![StatuteEntry::new(test_statute("test-1"), "JP")];
+        let key =
+            FederationKeyPair::from_pkcs8(&FederationKeyPair::generate_pkcs8().unwrap()).unwrap();
         let discovery = Arc::new(RegistryDiscovery::new());
         let metadata = RegistryMetadata::new(
             "Test Registry".to_string(),
             "https://example.com".to_string(),
-        );
+        )
+        .with_public_key(key.public_key());
+        let registry_id = metadata.registry_id;
         discovery.register(metadata);
 
-        let engine = FederatedQueryEngine::new(discovery);
+        let transport = LoopbackTransport {
+            entries,
+            keys: HashMap::from([(registry_id, key)]),
+        };
+        let engine = FederatedQueryEngine::new(discovery).with_transport(Arc::new(transport));
         let query = FederatedQuery::new("test".to_string());
-        let result = engine.execute(query);
+        let result = tokio_test::block_on(engine.execute(query));
 
         assert_eq!(result.query, "test");
         assert_eq!(result.registries_queried, 1);
         assert_eq!(result.successful_queries, 1);
+        assert!(result.registry_results[0].verified);
+        assert_eq!(result.registry_results[0].statute_ids, vec!["test-1"]);
+    }
+
+    #[cfg(feature = "federation")]
+    #[test]
+    fn test_federated_query_rejects_bad_signatures() {
+        use federation::*;
+
+        let pinned =
+            FederationKeyPair::from_pkcs8(&FederationKeyPair::generate_pkcs8().unwrap()).unwrap();
+        let impostor =
+            FederationKeyPair::from_pkcs8(&FederationKeyPair::generate_pkcs8().unwrap()).unwrap();
+
+        let discovery = Arc::new(RegistryDiscovery::new());
+        let signed = RegistryMetadata::new("Signed".to_string(), "https://a.example".to_string())
+            .with_public_key(pinned.public_key());
+        let unsigned =
+            RegistryMetadata::new("Unsigned".to_string(), "https://b.example".to_string());
+        let signed_id = signed.registry_id;
+        discovery.register(signed);
+        discovery.register(unsigned);
+
+        let transport = Arc::new(LoopbackTransport {
+            entries: Vec::new(),
+            keys: HashMap::from([(signed_id, impostor)]),
+        });
+        let engine =
+            FederatedQueryEngine::new(Arc::clone(&discovery)).with_transport(transport.clone());
+        let result = tokio_test::block_on(engine.execute(FederatedQuery::new("x".to_string())));
+
+        assert_eq!(result.registries_queried, 2);
+        assert_eq!(result.successful_queries, 0);
+        assert!(result.is_partial());
+        let errors: Vec<_> = result.failures().filter_map(|r| r.error.clone()).collect();
+        assert!(errors.contains(&FederationError::InvalidSignature.to_string()));
+        assert!(errors.contains(&FederationError::UntrustedPeer.to_string()));
+
+        // Unpinned peers are accepted, unverified, only when explicitly allowed.
+        let engine = FederatedQueryEngine::new(discovery)
+            .with_transport(transport)
+            .with_unsigned_peers(true);
+        let result = tokio_test::block_on(engine.execute(FederatedQuery::new("x".to_string())));
+        assert_eq!(result.successful_queries, 1);
+        assert!(result.registry_results.iter().all(|r| !r.verified));
+    }
+
+    #[test]
+    fn test_peer_search_request_rank() {
+        use federation::*;
+
+        let hit = |id: &str, title: &str, jurisdiction: &str| SearchHit {
+            statute_id: id.to_string(),
+            title: title.to_string(),
+            version: 1,
+            jurisdiction: jurisdiction.to_string(),
+            tags: Vec::new(),
+        };
+        let candidates = vec![
+            hit("tax-relief", "Tax Relief", "JP"),
+            hit("tax", "Tax", "JP"),
+            hit("tax-us", "Income Tax", "US"),
+            hit("pension", "Pension", "JP"),
+        ];
+
+        let request = PeerSearchRequest::new("tax").with_jurisdictions(vec!["jp".to_string()]);
+        let ranked = request.rank(candidates.clone(), &RankingConfig::default());
+        let ids: Vec<_> = ranked.iter().map(|h| h.statute_id.as_str()).collect();
+        assert_eq!(ids, vec!["tax", "tax-relief"]);
+
+        let request = PeerSearchRequest::new("tax").with_limit(1);
+        assert_eq!(request.rank(candidates, &RankingConfig::default()).len(), 1);
     }
 
     #[test]
@@ -26558,24 +28457,34 @@ mod tests {
         let results = vec![
             AggregatedSearchResult {
                 statute_id: "S1".to_string(),
+                title: "Statute 1".to_string(),
+                version: 1,
                 registry_id: Uuid::new_v4(),
                 registry_name: "R1".to_string(),
                 relevance_score: 1.0,
                 trust_level: 50,
+                verified: true,
                 combined_score: 0.0,
+                also_found_in: Vec::new(),
             },
             AggregatedSearchResult {
                 statute_id: "S1".to_string(),
+                title: "Statute 1".to_string(),
+                version: 1,
                 registry_id: Uuid::new_v4(),
                 registry_name: "R2".to_string(),
                 relevance_score: 0.9,
                 trust_level: 60,
+                verified: true,
                 combined_score: 0.0,
+                also_found_in: Vec::new(),
             },
         ];
 
         let deduplicated = aggregator.deduplicate(results);
         assert_eq!(deduplicated.len(), 1);
+        assert_eq!(deduplicated[0].registry_name, "R1");
+        assert_eq!(deduplicated[0].also_found_in, vec!["R2"]);
     }
 
     #[test]
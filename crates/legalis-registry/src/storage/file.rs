@@ -0,0 +1,1050 @@
+//! Embedded single-file storage backend.
+//!
+//! [`FileBackend`] keeps the registry in one append-only log file, so a CLI
+//! workspace or a small deployment can persist statutes without a database
+//! server. Every `store` and `delete` appends a checksummed record; an
+//! in-memory index maps each statute version to its record offset, and
+//! [`FileBackend::compact`] rewrites the file without superseded records.
+//! Deleted statutes keep their records as history for as-of queries.
+//!
+//! # File format
+//!
+//! The file starts with an 8-byte magic (`LGLSREG\0`) followed by a
+//! little-endian `u32` format version. Each record is:
+//!
+//! | Field    | Size | Content                                         |
+//! |----------|------|-------------------------------------------------|
+//! | length   | 4    | payload length, little-endian                   |
+//! | checksum | 8    | first 8 bytes of the payload's SHA-256          |
+//! | payload  | n    | JSON-encoded [`LogRecord`]                      |
+//!
+//! # Crash safety
+//!
+//! Records are appended with a single write and flushed with `fsync` before
+//! the call returns (see [`FileBackend::with_sync`]). A write interrupted by
+//! a crash leaves a torn record at the end of the file, which is detected by
+//! its length or checksum and truncated the next time the file is opened.
+//! Damage anywhere else is reported rather than repaired; [`FileBackend::fsck`]
+//! inspects a file offline without modifying it. Compaction writes a new file
+//! next to the original and atomically renames it into place.
+//!
+//! A registry file is locked exclusively while a backend has it open, so a
+//! second backend on the same file, in this process or another, fails to open.
+
+use super::{StorageBackend, select_as_of};
+use crate::{AsOf, RegistryError, RegistryResult, StatuteEntry};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+const MAGIC: &[u8; 8] = b"LGLSREG\0";
+const FORMAT_VERSION: u32 = 1;
+const FILE_HEADER_LEN: u64 = 12;
+const RECORD_HEADER_LEN: u64 = 12;
+/// Files smaller than this are never compacted automatically.
+const MIN_AUTO_COMPACT_BYTES: u64 = 64 * 1024;
+
+/// A record in the registry log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum LogRecord {
+    /// Stores (or replaces) one version of a statute.
+    Put { entry: Box<StatuteEntry> },
+    /// Deletes a statute, moving its versions to the deleted history.
+    Delete { statute_id: String },
+}
+
+/// Location and searchable metadata of a stored statute version.
+#[derive(Debug, Clone)]
+struct IndexedVersion {
+    version: u32,
+    offset: u64,
+    len: u64,
+    jurisdiction: String,
+    tags: Vec<String>,
+}
+
+impl IndexedVersion {
+    fn record_size(&self) -> u64 {
+        RECORD_HEADER_LEN + self.len
+    }
+}
+
+/// Versions a statute had when it was deleted, one entry per deletion.
+type DeletedHistory = Vec<(String, Vec<IndexedVersion>)>;
+
+#[derive(Debug)]
+struct Inner {
+    path: PathBuf,
+    file: File,
+    index: IndexMap<String, Vec<IndexedVersion>>,
+    deleted: DeletedHistory,
+    /// Current end of the log.
+    file_size: u64,
+    /// Bytes taken by records that no longer hold live data.
+    garbage_bytes: u64,
+    sync: bool,
+    compact_threshold: Option<f64>,
+}
+
+/// Size and space usage of a registry file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileStats {
+    /// Total file size in bytes
+    pub file_size: u64,
+    /// Bytes taken by superseded records
+    pub garbage_bytes: u64,
+    /// Number of statutes
+    pub statutes: usize,
+    /// Number of stored versions across all statutes
+    pub versions: usize,
+}
+
+/// A problem found while checking a registry file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FsckProblem {
+    /// The file does not start with a registry header.
+    BadHeader { message: String },
+    /// The last record was only partially written; it is dropped on the next open.
+    TornTail { offset: u64, bytes: u64 },
+    /// A complete record whose checksum does not match its payload.
+    ChecksumMismatch { offset: u64 },
+    /// A record whose payload is not a valid log record.
+    MalformedRecord { offset: u64, message: String },
+}
+
+impl FsckProblem {
+    /// Returns true if opening the file repairs the problem automatically.
+    pub fn is_recoverable(&self) -> bool {
+        matches!(self, Self::TornTail { .. })
+    }
+}
+
+impl std::fmt::Display for FsckProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BadHeader { message } => write!(f, "bad header: {}", message),
+            Self::TornTail { offset, bytes } => {
+                write!(f, "torn record at offset {} ({} bytes)", offset, bytes)
+            }
+            Self::ChecksumMismatch { offset } => {
+                write!(f, "checksum mismatch at offset {}", offset)
+            }
+            Self::MalformedRecord { offset, message } => {
+                write!(f, "malformed record at offset {}: {}", offset, message)
+            }
+        }
+    }
+}
+
+/// Result of an offline consistency check.
+#[derive(Debug, Clone)]
+pub struct FsckReport {
+    /// Checked file
+    pub path: PathBuf,
+    /// Total file size in bytes
+    pub file_size: u64,
+    /// Bytes up to the end of the last valid record
+    pub valid_length: u64,
+    /// Number of valid records
+    pub records: usize,
+    /// Number of statutes after replaying the valid records
+    pub statutes: usize,
+    /// Number of live statute versions after replaying the valid records
+    pub versions: usize,
+    /// Bytes that compaction would reclaim
+    pub garbage_bytes: u64,
+    /// Problems found; scanning stops at the first one
+    pub problems: Vec<FsckProblem>,
+}
+
+impl FsckReport {
+    /// Returns true if no problems were found.
+    pub fn is_clean(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    /// Returns true if the file can be opened, possibly after dropping a torn tail.
+    pub fn is_recoverable(&self) -> bool {
+        self.problems.iter().all(FsckProblem::is_recoverable)
+    }
+}
+
+/// Outcome of scanning a log from the start.
+struct Scan {
+    index: IndexMap<String, Vec<IndexedVersion>>,
+    deleted: DeletedHistory,
+    records: usize,
+    valid_length: u64,
+    garbage_bytes: u64,
+    problem: Option<FsckProblem>,
+}
+
+/// Embedded single-file storage backend.
+///
+/// See the [module documentation](self) for the file format and its
+/// crash-safety guarantees. File I/O is synchronous, which suits the CLI and
+/// small deployments; the [`StorageBackend`] methods run it on Tokio's
+/// blocking thread pool, each call holding the backend's lock for one read or
+/// append.
+///
+/// # Example
+///
+/// ```
+/// use legalis_core::{Effect, EffectType, Statute};
+/// use legalis_registry::StatuteEntry;
+/// use legalis_registry::storage::{FileBackend, StorageBackend};
+///
+/// # tokio_test::block_on(async {
+/// let dir = std::env::temp_dir().join(format!("legalis-doc-{}", uuid::Uuid::new_v4()));
+/// std::fs::create_dir_all(&dir).unwrap();
+/// let path = dir.join("registry.lgr");
+///
+/// let statute = Statute::new("s-1", "Test", Effect::new(EffectType::Grant, "Benefit"));
+/// let entry = StatuteEntry::new(statute.clone(), "JP");
+/// {
+///     let backend = FileBackend::open(&path).unwrap();
+///     backend.store(&entry).await.unwrap();
+///     backend.store(&entry.next_version(statute)).await.unwrap();
+/// }
+///
+/// // Reopening replays the log
+/// let backend = FileBackend::open(&path).unwrap();
+/// assert_eq!(backend.list_versions("s-1").await.unwrap(), vec![1, 2]);
+/// assert!(FileBackend::fsck(&path).unwrap().is_clean());
+/// # std::fs::remove_dir_all(&dir).unwrap();
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct FileBackend {
+    path: PathBuf,
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl FileBackend {
+    /// Opens a registry file, creating it if it does not exist.
+    ///
+    /// Fails if another backend already has the file open. A torn record left
+    /// at the end of the file by an interrupted write is truncated. Any other
+    /// damage is an error; use [`Self::fsck`] to inspect it.
+    pub fn open(path: impl AsRef<Path>) -> RegistryResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)
+            .map_err(|e| io_error(&path, e))?;
+        lock_file(&file, &path)?;
+
+        if file.metadata().map_err(|e| io_error(&path, e))?.len() == 0 {
+            write_header(&mut file).map_err(|e| io_error(&path, e))?;
+            sync_parent_dir(&path);
+        }
+
+        let scan = scan(&mut file).map_err(|e| io_error(&path, e))?;
+        match &scan.problem {
+            None => {}
+            Some(FsckProblem::TornTail { .. }) => {
+                file.set_len(scan.valid_length)
+                    .and_then(|_| file.sync_all())
+                    .map_err(|e| io_error(&path, e))?;
+            }
+            Some(problem) => {
+                return Err(RegistryError::InvalidOperation(format!(
+                    "{}: {}",
+                    path.display(),
+                    problem
+                )));
+            }
+        }
+
+        Ok(Self {
+            path: path.clone(),
+            inner: Arc::new(Mutex::new(Inner {
+                path,
+                file,
+                index: scan.index,
+                deleted: scan.deleted,
+                file_size: scan.valid_length,
+                garbage_bytes: scan.garbage_bytes,
+                sync: true,
+                compact_threshold: None,
+            })),
+        })
+    }
+
+    /// Sets whether every write is flushed to disk before returning (default: true).
+    ///
+    /// Disabling sync speeds up bulk imports at the cost of losing the most
+    /// recent writes on power failure; the file is still never left corrupt.
+    pub fn with_sync(self, sync: bool) -> Self {
+        self.lock().sync = sync;
+        self
+    }
+
+    /// Compacts automatically once garbage exceeds the given fraction of the file.
+    ///
+    /// Files under 64 KiB are never compacted automatically.
+    pub fn with_compaction_threshold(self, ratio: f64) -> Self {
+        self.lock().compact_threshold = Some(ratio);
+        self
+    }
+
+    /// Returns the path of the registry file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the file's size and space usage.
+    pub fn stats(&self) -> FileStats {
+        let inner = self.lock();
+        FileStats {
+            file_size: inner.file_size,
+            garbage_bytes: inner.garbage_bytes,
+            statutes: inner.index.len(),
+            versions: inner.index.values().map(Vec::len).sum(),
+        }
+    }
+
+    /// Rewrites the file with only live records, returning the bytes reclaimed.
+    ///
+    /// The compacted log is written to a temporary file, flushed, and renamed
+    /// over the original, so a crash leaves either the old or the new file.
+    /// This blocks the calling thread for the whole rewrite.
+    pub fn compact(&self) -> RegistryResult<u64> {
+        self.lock().compact()
+    }
+
+    /// Checks a registry file without modifying it.
+    pub fn fsck(path: impl AsRef<Path>) -> RegistryResult<FsckReport> {
+        let path = path.as_ref();
+        let mut file = File::open(path).map_err(|e| io_error(path, e))?;
+        let file_size = file.metadata().map_err(|e| io_error(path, e))?.len();
+        let scan = scan(&mut file).map_err(|e| io_error(path, e))?;
+
+        Ok(FsckReport {
+            path: path.to_path_buf(),
+            file_size,
+            valid_length: scan.valid_length,
+            records: scan.records,
+            statutes: scan.index.len(),
+            versions: scan.index.values().map(Vec::len).sum(),
+            garbage_bytes: scan.garbage_bytes,
+            problems: scan.problem.into_iter().collect(),
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        lock(&self.inner)
+    }
+
+    /// Runs file I/O on the blocking thread pool under the backend's lock.
+    async fn run<T, F>(&self, f: F) -> RegistryResult<T>
+    where
+        F: FnOnce(&mut Inner) -> RegistryResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let inner = Arc::clone(&self.inner);
+        tokio::task::spawn_blocking(move || f(&mut lock(&inner)))
+            .await
+            .map_err(|e| {
+                RegistryError::InvalidOperation(format!(
+                    "{}: file task failed: {}",
+                    self.path.display(),
+                    e
+                ))
+            })?
+    }
+}
+
+fn lock(inner: &Mutex<Inner>) -> MutexGuard<'_, Inner> {
+    inner.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl Inner {
+    fn append(&mut self, record: &LogRecord) -> RegistryResult<(u64, u64)> {
+        let payload = serde_json::to_vec(record)
+            .map_err(|e| RegistryError::InvalidOperation(e.to_string()))?;
+        let offset = self.file_size;
+        let sync = self.sync;
+        let file = &mut self.file;
+        let result = file
+            .seek(SeekFrom::Start(offset))
+            .and_then(|_| file.write_all(&encode_record(&payload)))
+            .and_then(|_| if sync { file.sync_data() } else { Ok(()) });
+        if let Err(e) = result {
+            // Drop whatever part of the record made it to disk
+            let _ = self.file.set_len(offset);
+            return Err(io_error(&self.path, e));
+        }
+        let len = payload.len() as u64;
+        self.file_size = offset + RECORD_HEADER_LEN + len;
+        Ok((offset, len))
+    }
+
+    fn read_entry(&mut self, version: &IndexedVersion) -> RegistryResult<StatuteEntry> {
+        let mut buf = vec![0; version.record_size() as usize];
+        self.file
+            .seek(SeekFrom::Start(version.offset))
+            .and_then(|_| self.file.read_exact(&mut buf))
+            .map_err(|e| io_error(&self.path, e))?;
+        let (header, payload) = buf.split_at(RECORD_HEADER_LEN as usize);
+        if header[4..] != checksum(payload) {
+            return Err(RegistryError::InvalidOperation(format!(
+                "{}: {}",
+                self.path.display(),
+                FsckProblem::ChecksumMismatch {
+                    offset: version.offset
+                }
+            )));
+        }
+        match serde_json::from_slice(payload) {
+            Ok(LogRecord::Put { entry }) => Ok(*entry),
+            Ok(LogRecord::Delete { .. }) | Err(_) => Err(RegistryError::InvalidOperation(format!(
+                "{}: index points at a non-entry record at offset {}",
+                self.path.display(),
+                version.offset
+            ))),
+        }
+    }
+
+    /// Reads versions as `(entry, deleted)` rows, current versions first.
+    fn read_history(
+        &mut self,
+        current: &[IndexedVersion],
+        deleted: &[IndexedVersion],
+    ) -> RegistryResult<Vec<(StatuteEntry, bool)>> {
+        let versions = current
+            .iter()
+            .map(|v| (v, false))
+            .chain(deleted.iter().map(|v| (v, true)));
+        versions
+            .map(|(version, deleted)| Ok((self.read_entry(version)?, deleted)))
+            .collect()
+    }
+
+    fn latest<F>(&mut self, filter: F) -> RegistryResult<Vec<StatuteEntry>>
+    where
+        F: Fn(&IndexedVersion) -> bool,
+    {
+        let latest: Vec<IndexedVersion> = self
+            .index
+            .values()
+            .filter_map(|versions| versions.last())
+            .filter(|version| filter(version))
+            .cloned()
+            .collect();
+        latest
+            .iter()
+            .map(|version| self.read_entry(version))
+            .collect()
+    }
+
+    fn maybe_compact(&mut self) -> RegistryResult<()> {
+        if let Some(ratio) = self.compact_threshold
+            && self.file_size >= MIN_AUTO_COMPACT_BYTES
+            && self.garbage_bytes as f64 > self.file_size as f64 * ratio
+        {
+            self.compact()?;
+        }
+        Ok(())
+    }
+
+    fn compact(&mut self) -> RegistryResult<u64> {
+        let tmp_path = self.path.with_extension("compact");
+        let mut tmp = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&tmp_path)
+            .map_err(|e| io_error(&tmp_path, e))?;
+        // Lock the new file before it replaces the old one, so the file at
+        // `path` is never unlocked while this backend has it open
+        lock_file(&tmp, &tmp_path)?;
+        write_header(&mut tmp).map_err(|e| io_error(&tmp_path, e))?;
+
+        let mut offset = FILE_HEADER_LEN;
+        // Deleted history goes first, each deletion followed by its Delete
+        // record, so replaying the file rebuilds the same history and index.
+        let mut deleted = DeletedHistory::new();
+        for (statute_id, versions) in self.deleted.clone() {
+            let copied = self.copy_records(&mut tmp, &tmp_path, &versions, &mut offset)?;
+            let payload = serde_json::to_vec(&LogRecord::Delete {
+                statute_id: statute_id.clone(),
+            })
+            .map_err(|e| RegistryError::InvalidOperation(e.to_string()))?;
+            tmp.write_all(&encode_record(&payload))
+                .map_err(|e| io_error(&tmp_path, e))?;
+            offset += RECORD_HEADER_LEN + payload.len() as u64;
+            deleted.push((statute_id, copied));
+        }
+        let mut index: IndexMap<String, Vec<IndexedVersion>> = IndexMap::new();
+        for (statute_id, versions) in self.index.clone() {
+            let copied = self.copy_records(&mut tmp, &tmp_path, &versions, &mut offset)?;
+            index.insert(statute_id, copied);
+        }
+        tmp.sync_all().map_err(|e| io_error(&tmp_path, e))?;
+
+        std::fs::rename(&tmp_path, &self.path).map_err(|e| io_error(&self.path, e))?;
+        sync_parent_dir(&self.path);
+        self.file = tmp;
+
+        let reclaimed = self.file_size.saturating_sub(offset);
+        self.index = index;
+        self.deleted = deleted;
+        self.file_size = offset;
+        self.garbage_bytes = 0;
+        Ok(reclaimed)
+    }
+
+    /// Copies version records into a compacted file, returning their new locations.
+    fn copy_records(
+        &mut self,
+        tmp: &mut File,
+        tmp_path: &Path,
+        versions: &[IndexedVersion],
+        offset: &mut u64,
+    ) -> RegistryResult<Vec<IndexedVersion>> {
+        let mut copied = Vec::with_capacity(versions.len());
+        for version in versions {
+            let mut record = vec![0; version.record_size() as usize];
+            self.file
+                .seek(SeekFrom::Start(version.offset))
+                .and_then(|_| self.file.read_exact(&mut record))
+                .map_err(|e| io_error(&self.path, e))?;
+            tmp.write_all(&record).map_err(|e| io_error(tmp_path, e))?;
+            copied.push(IndexedVersion {
+                offset: *offset,
+                ..version.clone()
+            });
+            *offset += version.record_size();
+        }
+        Ok(copied)
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageBackend for FileBackend {
+    async fn store(&self, entry: &StatuteEntry) -> RegistryResult<()> {
+        let statute_id = entry.statute.id.clone();
+        let indexed = IndexedVersion {
+            version: entry.version,
+            offset: 0,
+            len: 0,
+            jurisdiction: entry.jurisdiction.clone(),
+            tags: entry.tags.clone(),
+        };
+        let record = LogRecord::Put {
+            entry: Box::new(entry.clone()),
+        };
+        self.run(move |inner| {
+            let (offset, len) = inner.append(&record)?;
+            let indexed = IndexedVersion {
+                offset,
+                len,
+                ..indexed
+            };
+            if let Some(replaced) = index_put(&mut inner.index, &statute_id, indexed) {
+                inner.garbage_bytes += replaced;
+            }
+            inner.maybe_compact()
+        })
+        .await
+    }
+
+    async fn get(&self, statute_id: &str) -> RegistryResult<Option<StatuteEntry>> {
+        let statute_id = statute_id.to_string();
+        self.run(
+            move |inner| match inner.index.get(&statute_id).and_then(|v| v.last()).cloned() {
+                Some(version) => inner.read_entry(&version).map(Some),
+                None => Ok(None),
+            },
+        )
+        .await
+    }
+
+    async fn get_version(
+        &self,
+        statute_id: &str,
+        version: u32,
+    ) -> RegistryResult<Option<StatuteEntry>> {
+        let statute_id = statute_id.to_string();
+        self.run(move |inner| {
+            let indexed = inner
+                .index
+                .get(&statute_id)
+                .and_then(|versions| versions.iter().find(|v| v.version == version))
+                .cloned();
+            match indexed {
+                Some(indexed) => inner.read_entry(&indexed).map(Some),
+                None => Ok(None),
+            }
+        })
+        .await
+    }
+
+    async fn list(&self) -> RegistryResult<Vec<StatuteEntry>> {
+        self.run(|inner| inner.latest(|_| true)).await
+    }
+
+    async fn list_versions(&self, statute_id: &str) -> RegistryResult<Vec<u32>> {
+        let inner = self.lock();
+        Ok(inner
+            .index
+            .get(statute_id)
+            .map(|versions| versions.iter().map(|v| v.version).collect())
+            .unwrap_or_default())
+    }
+
+    async fn delete(&self, statute_id: &str) -> RegistryResult<()> {
+        let statute_id = statute_id.to_string();
+        self.run(move |inner| {
+            if !inner.index.contains_key(&statute_id) {
+                return Ok(());
+            }
+            inner.append(&LogRecord::Delete {
+                statute_id: statute_id.clone(),
+            })?;
+            let Inner { index, deleted, .. } = inner;
+            index_delete(index, deleted, &statute_id);
+            Ok(())
+        })
+        .await
+    }
+
+    async fn find_by_jurisdiction(&self, jurisdiction: &str) -> RegistryResult<Vec<StatuteEntry>> {
+        let jurisdiction = jurisdiction.to_string();
+        self.run(move |inner| inner.latest(|version| version.jurisdiction == jurisdiction))
+            .await
+    }
+
+    async fn find_by_tag(&self, tag: &str) -> RegistryResult<Vec<StatuteEntry>> {
+        let tag = tag.to_string();
+        self.run(move |inner| inner.latest(|version| version.tags.contains(&tag)))
+            .await
+    }
+
+    async fn count(&self) -> RegistryResult<usize> {
+        Ok(self.lock().index.len())
+    }
+
+    async fn history(&self, statute_id: &str) -> RegistryResult<Vec<StatuteEntry>> {
+        let statute_id = statute_id.to_string();
+        self.run(move |inner| {
+            let versions = inner.index.get(&statute_id).cloned().unwrap_or_default();
+            versions
+                .iter()
+                .map(|version| inner.read_entry(version))
+                .collect()
+        })
+        .await
+    }
+
+    async fn get_as_of(
+        &self,
+        statute_id: &str,
+        as_of: &AsOf,
+    ) -> RegistryResult<Option<StatuteEntry>> {
+        let statute_id = statute_id.to_string();
+        let as_of = *as_of;
+        self.run(move |inner| {
+            let current = inner.index.get(&statute_id).cloned().unwrap_or_default();
+            let deleted: Vec<IndexedVersion> = inner
+                .deleted
+                .iter()
+                .filter(|(id, _)| *id == statute_id)
+                .flat_map(|(_, versions)| versions.iter().cloned())
+                .collect();
+            let rows = inner.read_history(&current, &deleted)?;
+            Ok(select_as_of(&as_of, rows).into_iter().next())
+        })
+        .await
+    }
+
+    async fn list_as_of(&self, as_of: &AsOf) -> RegistryResult<Vec<StatuteEntry>> {
+        let as_of = *as_of;
+        self.run(move |inner| {
+            let current: Vec<IndexedVersion> = inner.index.values().flatten().cloned().collect();
+            let deleted: Vec<IndexedVersion> = inner
+                .deleted
+                .iter()
+                .flat_map(|(_, versions)| versions.iter().cloned())
+                .collect();
+            let rows = inner.read_history(&current, &deleted)?;
+            Ok(select_as_of(&as_of, rows))
+        })
+        .await
+    }
+}
+
+/// Adds a version to the index, returning the size of the record it replaces.
+fn index_put(
+    index: &mut IndexMap<String, Vec<IndexedVersion>>,
+    statute_id: &str,
+    indexed: IndexedVersion,
+) -> Option<u64> {
+    let versions = index.entry(statute_id.to_string()).or_default();
+    match versions.iter().position(|v| v.version >= indexed.version) {
+        Some(i) if versions[i].version == indexed.version => {
+            let replaced = std::mem::replace(&mut versions[i], indexed);
+            Some(replaced.record_size())
+        }
+        Some(i) => {
+            versions.insert(i, indexed);
+            None
+        }
+        None => {
+            versions.push(indexed);
+            None
+        }
+    }
+}
+
+/// Moves a statute's versions from the index to the deleted history.
+fn index_delete(
+    index: &mut IndexMap<String, Vec<IndexedVersion>>,
+    deleted: &mut DeletedHistory,
+    statute_id: &str,
+) {
+    if let Some(versions) = index.shift_remove(statute_id) {
+        deleted.push((statute_id.to_string(), versions));
+    }
+}
+
+/// Replays a log from the start, stopping at the first damaged record.
+fn scan(file: &mut File) -> io::Result<Scan> {
+    let file_size = file.metadata()?.len();
+    let mut reader = BufReader::new(&mut *file);
+    reader.seek(SeekFrom::Start(0))?;
+
+    let mut result = Scan {
+        index: IndexMap::new(),
+        deleted: DeletedHistory::new(),
+        records: 0,
+        valid_length: 0,
+        garbage_bytes: 0,
+        problem: None,
+    };
+
+    let mut header = [0u8; FILE_HEADER_LEN as usize];
+    if file_size < FILE_HEADER_LEN {
+        result.problem = Some(FsckProblem::BadHeader {
+            message: format!("file is only {} bytes", file_size),
+        });
+        return Ok(result);
+    }
+    reader.read_exact(&mut header)?;
+    if &header[..8] != MAGIC {
+        result.problem = Some(FsckProblem::BadHeader {
+            message: "not a legalis registry file".to_string(),
+        });
+        return Ok(result);
+    }
+    let format_version = u32::from_le_bytes(header[8..12].try_into().unwrap_or_default());
+    if format_version != FORMAT_VERSION {
+        result.problem = Some(FsckProblem::BadHeader {
+            message: format!("unsupported format version {}", format_version),
+        });
+        return Ok(result);
+    }
+
+    let mut offset = FILE_HEADER_LEN;
+    result.valid_length = offset;
+    while offset < file_size {
+        let remaining = file_size - offset;
+        let torn = FsckProblem::TornTail {
+            offset,
+            bytes: remaining,
+        };
+        if remaining < RECORD_HEADER_LEN {
+            result.problem = Some(torn);
+            break;
+        }
+        let mut record_header = [0u8; RECORD_HEADER_LEN as usize];
+        reader.read_exact(&mut record_header)?;
+        let len = u64::from(u32::from_le_bytes(
+            record_header[..4].try_into().unwrap_or_default(),
+        ));
+        let end = offset + RECORD_HEADER_LEN + len;
+        if end > file_size {
+            result.problem = Some(torn);
+            break;
+        }
+        let mut payload = vec![0; len as usize];
+        reader.read_exact(&mut payload)?;
+        if record_header[4..] != checksum(&payload) {
+            // A bad final record is an interrupted write; anywhere else it is damage
+            result.problem = Some(if end == file_size {
+                torn
+            } else {
+                FsckProblem::ChecksumMismatch { offset }
+            });
+            break;
+        }
+        let record: LogRecord = match serde_json::from_slice(&payload) {
+            Ok(record) => record,
+            Err(e) => {
+                result.problem = Some(FsckProblem::MalformedRecord {
+                    offset,
+                    message: e.to_string(),
+                });
+                break;
+            }
+        };
+
+        match record {
+            LogRecord::Put { entry } => {
+                let indexed = IndexedVersion {
+                    version: entry.version,
+                    offset,
+                    len,
+                    jurisdiction: entry.jurisdiction.clone(),
+                    tags: entry.tags.clone(),
+                };
+                if let Some(replaced) = index_put(&mut result.index, &entry.statute.id, indexed) {
+                    result.garbage_bytes += replaced;
+                }
+            }
+            LogRecord::Delete { statute_id } => {
+                index_delete(&mut result.index, &mut result.deleted, &statute_id);
+            }
+        }
+        result.records += 1;
+        offset = end;
+        result.valid_length = offset;
+    }
+
+    Ok(result)
+}
+
+/// Takes the exclusive advisory lock that keeps a second backend off the file.
+fn lock_file(file: &File, path: &Path) -> RegistryResult<()> {
+    file.try_lock().map_err(|e| match e {
+        std::fs::TryLockError::WouldBlock => RegistryError::InvalidOperation(format!(
+            "{}: registry file is already open in another backend",
+            path.display()
+        )),
+        std::fs::TryLockError::Error(e) => io_error(path, e),
+    })
+}
+
+fn write_header(file: &mut File) -> io::Result<()> {
+    let mut header = Vec::with_capacity(FILE_HEADER_LEN as usize);
+    header.extend_from_slice(MAGIC);
+    header.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+    file.write_all(&header)?;
+    file.sync_all()
+}
+
+fn encode_record(payload: &[u8]) -> Vec<u8> {
+    let mut record = Vec::with_capacity(RECORD_HEADER_LEN as usize + payload.len());
+    record.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    record.extend_from_slice(&checksum(payload));
+    record.extend_from_slice(payload);
+    record
+}
+
+fn checksum(payload: &[u8]) -> [u8; 8] {
+    let digest = Sha256::digest(payload);
+    let mut sum = [0u8; 8];
+    sum.copy_from_slice(&digest[..8]);
+    sum
+}
+
+/// Flushes the directory entry so a newly created or renamed file survives a crash.
+fn sync_parent_dir(path: &Path) {
+    #[cfg(unix)]
+    if let Some(dir) = path.parent() {
+        let dir = if dir.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            dir
+        };
+        if let Ok(dir) = File::open(dir) {
+            let _ = dir.sync_all();
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+}
+
+fn io_error(path: &Path, err: io::Error) -> RegistryError {
+    RegistryError::InvalidOperation(format!("{}: {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use legalis_core::{Effect, EffectType, Statute};
+
+    fn entry(id: &str, jurisdiction: &str) -> StatuteEntry {
+        let statute = Statute::new(id, "Test", Effect::new(EffectType::Grant, "Benefit"));
+        StatuteEntry::new(statute, jurisdiction).with_tag("welfare")
+    }
+
+    #[test]
+    fn test_file_backend_persists_versions_and_deletes() {
+        tokio_test::block_on(async {
+            let dir = tempfile::tempdir().unwrap();
+            let path = dir.path().join("registry.lgr");
+            {
+                let backend = FileBackend::open(&path).unwrap();
+                let a = entry("a", "JP");
+                backend.store(&a).await.unwrap();
+                backend
+                    .store(&a.next_version(a.statute.clone()))
+                    .await
+                    .unwrap();
+                backend.store(&entry("b", "US")).await.unwrap();
+                backend.store(&entry("c", "US")).await.unwrap();
+                backend.delete("c").await.unwrap();
+            }
+
+            let backend = FileBackend::open(&path).unwrap();
+            assert_eq!(backend.count().await.unwrap(), 2);
+            assert_eq!(backend.list_versions("a").await.unwrap(), vec![1, 2]);
+            let history: Vec<u32> = backend
+                .history("a")
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|e| e.version)
+                .collect();
+            assert_eq!(history, vec![1, 2]);
+            assert_eq!(backend.get("a").await.unwrap().unwrap().version, 2);
+            assert_eq!(
+                backend.get_version("a", 1).await.unwrap().unwrap().version,
+                1
+            );
+            assert!(backend.get("c").await.unwrap().is_none());
+            assert_eq!(backend.find_by_jurisdiction("US").await.unwrap().len(), 1);
+            assert_eq!(backend.find_by_tag("welfare").await.unwrap().len(), 2);
+
+            // The deleted statute's history survives for as-of queries
+            let today = AsOf::new(chrono::Utc::now().date_naive());
+            assert_eq!(backend.list_as_of(&today).await.unwrap().len(), 3);
+            assert!(backend.get_as_of("c", &today).await.unwrap().is_some());
+        });
+    }
+
+    #[test]
+    fn test_file_backend_locks_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("registry.lgr");
+        let backend = FileBackend::open(&path).unwrap();
+        assert!(FileBackend::open(&path).is_err());
+
+        // The compacted file replacing the original is locked as well
+        backend.compact().unwrap();
+        assert!(FileBackend::open(&path).is_err());
+
+        drop(backend);
+        assert!(FileBackend::open(&path).is_ok());
+    }
+
+    #[test]
+    fn test_file_backend_compaction() {
+        tokio_test::block_on(async {
+            let dir = tempfile::tempdir().unwrap();
+            let path = dir.path().join("registry.lgr");
+            let backend = FileBackend::open(&path).unwrap();
+            let a = entry("a", "JP");
+            // Rewriting the same version leaves garbage behind
+            for _ in 0..5 {
+                backend.store(&a).await.unwrap();
+            }
+            backend.store(&entry("b", "JP")).await.unwrap();
+            backend.delete("b").await.unwrap();
+
+            let before = backend.stats();
+            let reclaimed = backend.compact().unwrap();
+            let after = backend.stats();
+            assert_eq!(reclaimed, before.file_size - after.file_size);
+            assert_eq!(after.garbage_bytes, 0);
+            assert_eq!(after.versions, 1);
+            assert_eq!(std::fs::metadata(&path).unwrap().len(), after.file_size);
+            assert!(!path.with_extension("compact").exists());
+
+            // Writes after compaction land in the new file
+            backend.store(&entry("c", "JP")).await.unwrap();
+            drop(backend);
+            let backend = FileBackend::open(&path).unwrap();
+            assert_eq!(backend.count().await.unwrap(), 2);
+            assert_eq!(backend.get("a").await.unwrap().unwrap().etag, a.etag);
+            let today = AsOf::new(chrono::Utc::now().date_naive());
+            assert!(backend.get_as_of("b", &today).await.unwrap().is_some());
+        });
+    }
+
+    #[test]
+    fn test_file_backend_recovers_torn_tail() {
+        tokio_test::block_on(async {
+            let dir = tempfile::tempdir().unwrap();
+            let path = dir.path().join("registry.lgr");
+            {
+                let backend = FileBackend::open(&path).unwrap();
+                backend.store(&entry("a", "JP")).await.unwrap();
+                backend.store(&entry("b", "JP")).await.unwrap();
+            }
+            // Simulate a crash halfway through the last append
+            let size = std::fs::metadata(&path).unwrap().len();
+            let file = OpenOptions::new().write(true).open(&path).unwrap();
+            file.set_len(size - 10).unwrap();
+            drop(file);
+
+            let report = FileBackend::fsck(&path).unwrap();
+            assert!(!report.is_clean());
+            assert!(report.is_recoverable());
+            assert_eq!(report.records, 1);
+
+            let backend = FileBackend::open(&path).unwrap();
+            assert_eq!(backend.count().await.unwrap(), 1);
+            assert!(backend.get("a").await.unwrap().is_some());
+            drop(backend);
+            assert!(FileBackend::fsck(&path).unwrap().is_clean());
+        });
+    }
+
+    #[test]
+    fn test_file_backend_rejects_corruption() {
+        tokio_test::block_on(async {
+            let dir = tempfile::tempdir().unwrap();
+            let path = dir.path().join("registry.lgr");
+            {
+                let backend = FileBackend::open(&path).unwrap();
+                backend.store(&entry("a", "JP")).await.unwrap();
+                backend.store(&entry("b", "JP")).await.unwrap();
+            }
+            // Flip a byte inside the first record's payload
+            let mut bytes = std::fs::read(&path).unwrap();
+            let target = (FILE_HEADER_LEN + RECORD_HEADER_LEN + 5) as usize;
+            bytes[target] ^= 0xff;
+            std::fs::write(&path, &bytes).unwrap();
+
+            let report = FileBackend::fsck(&path).unwrap();
+            assert_eq!(
+                report.problems,
+                vec![FsckProblem::ChecksumMismatch {
+                    offset: FILE_HEADER_LEN
+                }]
+            );
+            assert!(!report.is_recoverable());
+            assert!(FileBackend::open(&path).is_err());
+            // fsck never modifies the file
+            assert_eq!(std::fs::read(&path).unwrap(), bytes);
+
+            std::fs::write(&path, b"not a registry").unwrap();
+            assert!(matches!(
+                FileBackend::fsck(&path).unwrap().problems[0],
+                FsckProblem::BadHeader { .. }
+            ));
+        });
+    }
+}
@@ -6,9 +6,18 @@
 //! - Hybrid search (keyword + vector)
 //! - Embedding-based deduplication
 //! - Semantic clustering
+//!
+//! [`HashedTfIdfEmbedder`] works fully offline; a local ONNX sentence
+//! encoder is available as `OnnxEmbedder` with the `onnx` feature.
+//! [`SemanticIndex`] keeps registry statutes embedded in an [`HnswIndex`]
+//! and backs [`SearchMode::Semantic`] and [`SearchMode::Hybrid`] queries.
 
+use crate::{RankingConfig, SearchMode, StatuteEntry};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::path::Path;
+use thiserror::Error;
 
 // ============================================================================
 // Vector Embeddings
@@ -163,6 +172,547 @@ pub struct StatuteEmbedding {
     pub model: String,
 }
 
+// ============================================================================
+// Embedders
+// ============================================================================
+
+/// Errors raised while embedding text or persisting a vector index.
+#[derive(Debug, Error)]
+pub enum VectorSearchError {
+    #[error("Embedding model error: {0}")]
+    Model(String),
+
+    #[error("Failed to access semantic index: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to (de)serialize semantic index: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+/// Turns text into embeddings.
+///
+/// Embedders must be deterministic: the same text and model state always
+/// yield the same vector, which is what lets a persisted index be reused.
+pub trait Embedder: Send + Sync {
+    /// Identifies the model together with any fitted state.
+    ///
+    /// An index built under a different signature is rebuilt from scratch.
+    fn model(&self) -> String;
+
+    /// Number of dimensions of the produced vectors.
+    fn dimensions(&self) -> usize;
+
+    /// Embeds a single text.
+    fn embed(&self, text: &str) -> Result<Embedding, VectorSearchError>;
+
+    /// Adapts corpus statistics to the given documents.
+    ///
+    /// Returns `true` when the model changed. Pretrained models ignore this.
+    fn fit(&mut self, _corpus: &[String]) -> bool {
+        false
+    }
+
+    /// Fitted state to persist alongside an index.
+    fn state(&self) -> Option<serde_json::Value> {
+        None
+    }
+
+    /// Restores state previously returned by [`Embedder::state`].
+    fn restore(&mut self, _state: serde_json::Value) -> Result<(), VectorSearchError> {
+        Ok(())
+    }
+}
+
+impl Embedder for Box<dyn Embedder> {
+    fn model(&self) -> String {
+        self.as_ref().model()
+    }
+
+    fn dimensions(&self) -> usize {
+        self.as_ref().dimensions()
+    }
+
+    fn embed(&self, text: &str) -> Result<Embedding, VectorSearchError> {
+        self.as_ref().embed(text)
+    }
+
+    fn fit(&mut self, corpus: &[String]) -> bool {
+        self.as_mut().fit(corpus)
+    }
+
+    fn state(&self) -> Option<serde_json::Value> {
+        self.as_ref().state()
+    }
+
+    fn restore(&mut self, state: serde_json::Value) -> Result<(), VectorSearchError> {
+        self.as_mut().restore(state)
+    }
+}
+
+/// Words that carry no meaning on their own in statute text.
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "any", "are", "as", "at", "be", "by", "for", "from", "if", "in", "is", "it",
+    "may", "of", "on", "or", "shall", "such", "that", "the", "this", "to", "which", "with",
+];
+
+/// Weight of a character trigram relative to a whole word.
+const TRIGRAM_WEIGHT: f32 = 0.2;
+
+/// Weight of a single CJK character relative to a bigram.
+const CJK_UNIGRAM_WEIGHT: f32 = 0.25;
+
+/// Offline embedder producing hashed BM25-weighted sparse vectors.
+///
+/// Words, in-word character trigrams and CJK character bigrams are hashed
+/// into a fixed number of signed buckets. Term frequencies are saturated
+/// and length-normalized as in BM25 and weighted by inverse document
+/// frequencies learned with [`Embedder::fit`], so related wording such as
+/// "taxation" and "taxes" still lands close together without any model
+/// files.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HashedTfIdfEmbedder {
+    /// Number of hash buckets
+    dimensions: usize,
+    /// BM25 term frequency saturation
+    k1: f32,
+    /// BM25 length normalization
+    b: f32,
+    /// Number of fitted documents containing each bucket
+    document_frequency: Vec<u32>,
+    /// Number of fitted documents
+    document_count: u32,
+    /// Average document length in terms
+    average_length: f32,
+}
+
+impl HashedTfIdfEmbedder {
+    /// Default number of hash buckets.
+    pub const DEFAULT_DIMENSIONS: usize = 1024;
+
+    /// Creates an unfitted embedder with the given number of buckets.
+    pub fn new(dimensions: usize) -> Self {
+        let dimensions = dimensions.max(1);
+        Self {
+            dimensions,
+            k1: 1.2,
+            b: 0.75,
+            document_frequency: vec![0; dimensions],
+            document_count: 0,
+            average_length: 0.0,
+        }
+    }
+
+    /// Sets the BM25 `k1` and `b` parameters.
+    pub fn with_bm25(mut self, k1: f32, b: f32) -> Self {
+        self.k1 = k1.max(0.0);
+        self.b = b.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns the number of documents the embedder was fitted on.
+    pub fn document_count(&self) -> usize {
+        self.document_count as usize
+    }
+
+    fn bucket(&self, feature: u64) -> (usize, f32) {
+        let sign = if feature >> 63 == 1 { -1.0 } else { 1.0 };
+        ((feature % self.dimensions as u64) as usize, sign)
+    }
+
+    fn idf(&self, bucket: usize) -> f32 {
+        if self.document_count == 0 {
+            return 1.0;
+        }
+        let n = self.document_count as f32;
+        let df = self.document_frequency[bucket] as f32;
+        (1.0 + (n - df + 0.5) / (df + 0.5)).ln()
+    }
+
+    fn signature(&self) -> u64 {
+        let mut hash = FNV_OFFSET;
+        let mut feed = |bytes: &[u8]| hash = fnv1a(hash, bytes);
+        feed(&self.k1.to_le_bytes());
+        feed(&self.b.to_le_bytes());
+        feed(&self.document_count.to_le_bytes());
+        feed(&self.average_length.to_le_bytes());
+        for df in &self.document_frequency {
+            feed(&df.to_le_bytes());
+        }
+        hash
+    }
+}
+
+impl Default for HashedTfIdfEmbedder {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_DIMENSIONS)
+    }
+}
+
+impl Embedder for HashedTfIdfEmbedder {
+    fn model(&self) -> String {
+        format!("hashed-bm25-{}:{:016x}", self.dimensions, self.signature())
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn embed(&self, text: &str) -> Result<Embedding, VectorSearchError> {
+        let (features, length) = features(text);
+        let mut frequencies: HashMap<u64, f32> = HashMap::new();
+        for (feature, weight) in features {
+            *frequencies.entry(feature).or_default() += weight;
+        }
+
+        let relative_length = if self.average_length > 0.0 {
+            length as f32 / self.average_length
+        } else {
+            1.0
+        };
+        let norm = self.k1 * (1.0 - self.b + self.b * relative_length);
+
+        let mut vector = vec![0.0; self.dimensions];
+        for (feature, tf) in frequencies {
+            let (bucket, sign) = self.bucket(feature);
+            let saturated = tf * (self.k1 + 1.0) / (tf + norm);
+            vector[bucket] += sign * saturated * self.idf(bucket);
+        }
+
+        let magnitude = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if magnitude > 0.0 {
+            vector.iter_mut().for_each(|x| *x /= magnitude);
+        }
+        Ok(Embedding::new(vector))
+    }
+
+    fn fit(&mut self, corpus: &[String]) -> bool {
+        let mut document_frequency = vec![0u32; self.dimensions];
+        let mut total_length = 0usize;
+        for text in corpus {
+            let (features, length) = features(text);
+            total_length += length;
+            let buckets: HashSet<usize> = features
+                .into_iter()
+                .map(|(feature, _)| self.bucket(feature).0)
+                .collect();
+            for bucket in buckets {
+                document_frequency[bucket] += 1;
+            }
+        }
+
+        let fitted = Self {
+            document_frequency,
+            document_count: corpus.len() as u32,
+            average_length: if corpus.is_empty() {
+                0.0
+            } else {
+                total_length as f32 / corpus.len() as f32
+            },
+            ..self.clone()
+        };
+        let changed = fitted != *self;
+        *self = fitted;
+        changed
+    }
+
+    fn state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(self).ok()
+    }
+
+    fn restore(&mut self, state: serde_json::Value) -> Result<(), VectorSearchError> {
+        *self = serde_json::from_value(state)?;
+        Ok(())
+    }
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+/// Hashes a feature, mixing the bits so bucket and sign are independent.
+fn feature_hash(kind: u8, text: &str) -> u64 {
+    let mut hash = fnv1a(fnv1a(FNV_OFFSET, &[kind]), text.as_bytes());
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    hash ^ (hash >> 33)
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x3040..=0x30FF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xAC00..=0xD7AF | 0xF900..=0xFAFF
+    )
+}
+
+/// Strips common English inflections so "taxes" and "taxation" share a term.
+fn stem(word: &str) -> &str {
+    const SUFFIXES: &[&str] = &[
+        "ations", "ation", "ments", "ment", "ings", "ing", "ers", "ed", "ly",
+    ];
+    let long_enough = |stem: &str| stem.chars().count() >= 3;
+
+    for suffix in SUFFIXES {
+        if let Some(stem) = word.strip_suffix(suffix)
+            && long_enough(stem)
+        {
+            return stem;
+        }
+    }
+    if let Some(stem) = word.strip_suffix("es")
+        && ["s", "x", "z", "ch", "sh"]
+            .iter()
+            .any(|end| stem.ends_with(end))
+        && long_enough(stem)
+    {
+        return stem;
+    }
+    match word.strip_suffix('s') {
+        Some(stem) if long_enough(stem) && !stem.ends_with('s') => stem,
+        _ => word,
+    }
+}
+
+/// Extracts weighted hashed features and the document length in terms.
+fn features(text: &str) -> (Vec<(u64, f32)>, usize) {
+    let mut features = Vec::new();
+    let mut length = 0;
+    let mut word = String::new();
+    let mut cjk = Vec::new();
+
+    fn flush_word(word: &mut String, features: &mut Vec<(u64, f32)>, length: &mut usize) {
+        if !word.is_empty() && !STOPWORDS.contains(&word.as_str()) {
+            *length += 1;
+            features.push((feature_hash(b'w', stem(word)), 1.0));
+            if word.chars().count() >= 3 && !word.chars().all(|c| c.is_ascii_digit()) {
+                let padded: Vec<char> = std::iter::once('<')
+                    .chain(word.chars())
+                    .chain(std::iter::once('>'))
+                    .collect();
+                for trigram in padded.windows(3) {
+                    let trigram: String = trigram.iter().collect();
+                    features.push((feature_hash(b't', &trigram), TRIGRAM_WEIGHT));
+                }
+            }
+        }
+        word.clear();
+    }
+
+    fn flush_cjk(cjk: &mut Vec<char>, features: &mut Vec<(u64, f32)>, length: &mut usize) {
+        if cjk.len() == 1 {
+            *length += 1;
+            features.push((feature_hash(b'c', &cjk[0].to_string()), 1.0));
+        } else {
+            for c in cjk.iter() {
+                features.push((feature_hash(b'c', &c.to_string()), CJK_UNIGRAM_WEIGHT));
+            }
+            for pair in cjk.windows(2) {
+                *length += 1;
+                let bigram: String = pair.iter().collect();
+                features.push((feature_hash(b'b', &bigram), 1.0));
+            }
+        }
+        cjk.clear();
+    }
+
+    for c in text.chars() {
+        if is_cjk(c) {
+            flush_word(&mut word, &mut features, &mut length);
+            cjk.push(c);
+        } else if c.is_alphanumeric() {
+            flush_cjk(&mut cjk, &mut features, &mut length);
+            word.extend(c.to_lowercase());
+        } else {
+            flush_word(&mut word, &mut features, &mut length);
+            flush_cjk(&mut cjk, &mut features, &mut length);
+        }
+    }
+    flush_word(&mut word, &mut features, &mut length);
+    flush_cjk(&mut cjk, &mut features, &mut length);
+
+    (features, length)
+}
+
+/// Sentence encoder running a local ONNX model.
+///
+/// Expects a sentence-transformers style export: a `model.onnx` taking
+/// `input_ids` and `attention_mask` (and optionally `token_type_ids`), plus
+/// the matching Hugging Face `tokenizer.json`. Token embeddings are
+/// mean-pooled and normalized. The ONNX Runtime library is loaded at run
+/// time from `ORT_DYLIB_PATH`.
+#[cfg(feature = "onnx")]
+pub struct OnnxEmbedder {
+    session: std::sync::Mutex<ort::session::Session>,
+    tokenizer: tokenizers::Tokenizer,
+    model: String,
+    dimensions: usize,
+    token_type_ids: bool,
+}
+
+#[cfg(feature = "onnx")]
+impl OnnxEmbedder {
+    /// Default maximum number of tokens per text.
+    pub const DEFAULT_MAX_TOKENS: usize = 256;
+
+    /// Loads `model.onnx` and `tokenizer.json` from a directory.
+    pub fn from_dir(dir: impl AsRef<std::path::Path>) -> Result<Self, VectorSearchError> {
+        let dir = dir.as_ref();
+        Self::from_files(dir.join("model.onnx"), dir.join("tokenizer.json"))
+    }
+
+    /// Loads a model and tokenizer from explicit paths.
+    pub fn from_files(
+        model_path: impl AsRef<std::path::Path>,
+        tokenizer_path: impl AsRef<std::path::Path>,
+    ) -> Result<Self, VectorSearchError> {
+        use sha2::{Digest, Sha256};
+
+        let model_bytes = std::fs::read(model_path.as_ref())?;
+        let session = ort::session::Session::builder()
+            .and_then(|builder| builder.commit_from_memory(&model_bytes))
+            .map_err(|e| VectorSearchError::Model(e.to_string()))?;
+        let token_type_ids = session
+            .inputs
+            .iter()
+            .any(|input| input.name == "token_type_ids");
+
+        let mut tokenizer = tokenizers::Tokenizer::from_file(tokenizer_path.as_ref())
+            .map_err(|e| VectorSearchError::Model(e.to_string()))?;
+        tokenizer
+            .with_truncation(Some(tokenizers::TruncationParams {
+                max_length: Self::DEFAULT_MAX_TOKENS,
+                ..Default::default()
+            }))
+            .map_err(|e| VectorSearchError::Model(e.to_string()))?;
+
+        let digest = Sha256::digest(&model_bytes);
+        let model = format!(
+            "onnx:{}",
+            digest[..8]
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>()
+        );
+
+        let mut embedder = Self {
+            session: std::sync::Mutex::new(session),
+            tokenizer,
+            model,
+            dimensions: 0,
+            token_type_ids,
+        };
+        embedder.dimensions = embedder.embed("statute")?.dimensions;
+        Ok(embedder)
+    }
+}
+
+#[cfg(feature = "onnx")]
+impl std::fmt::Debug for OnnxEmbedder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OnnxEmbedder")
+            .field("model", &self.model)
+            .field("dimensions", &self.dimensions)
+            .finish()
+    }
+}
+
+#[cfg(feature = "onnx")]
+impl Embedder for OnnxEmbedder {
+    fn model(&self) -> String {
+        self.model.clone()
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn embed(&self, text: &str) -> Result<Embedding, VectorSearchError> {
+        use ort::value::Tensor;
+
+        let model_error = |e: ort::Error| VectorSearchError::Model(e.to_string());
+        let encoding = self
+            .tokenizer
+            .encode(text, true)
+            .map_err(|e| VectorSearchError::Model(e.to_string()))?;
+        let mask: Vec<i64> = encoding
+            .get_attention_mask()
+            .iter()
+            .map(|&m| i64::from(m))
+            .collect();
+        let shape = [1usize, mask.len()];
+        let ids: Vec<i64> = encoding.get_ids().iter().map(|&i| i64::from(i)).collect();
+
+        let mut inputs = vec![
+            (
+                "input_ids",
+                Tensor::from_array((shape, ids)).map_err(model_error)?,
+            ),
+            (
+                "attention_mask",
+                Tensor::from_array((shape, mask.clone())).map_err(model_error)?,
+            ),
+        ];
+        if self.token_type_ids {
+            let types: Vec<i64> = encoding
+                .get_type_ids()
+                .iter()
+                .map(|&t| i64::from(t))
+                .collect();
+            inputs.push((
+                "token_type_ids",
+                Tensor::from_array((shape, types)).map_err(model_error)?,
+            ));
+        }
+
+        let mut session = self
+            .session
+            .lock()
+            .map_err(|_| VectorSearchError::Model("model session poisoned".to_string()))?;
+        let outputs = session.run(inputs).map_err(model_error)?;
+        let (output_shape, values) = outputs[0]
+            .try_extract_tensor::<f32>()
+            .map_err(model_error)?;
+
+        let mut vector = match **output_shape {
+            // Token embeddings: mean-pool over attended tokens
+            [_, tokens, hidden] => {
+                let hidden = hidden as usize;
+                let mut pooled = vec![0.0f32; hidden];
+                let mut count = 0.0f32;
+                for (token, &attended) in mask.iter().enumerate().take(tokens as usize) {
+                    if attended == 0 {
+                        continue;
+                    }
+                    count += 1.0;
+                    let row = &values[token * hidden..(token + 1) * hidden];
+                    pooled.iter_mut().zip(row).for_each(|(p, v)| *p += v);
+                }
+                pooled.iter_mut().for_each(|p| *p /= count.max(1.0));
+                pooled
+            }
+            // Already pooled sentence embedding
+            [_, hidden] => values[..hidden as usize].to_vec(),
+            _ => {
+                return Err(VectorSearchError::Model(format!(
+                    "unexpected output shape {:?}",
+                    &**output_shape
+                )));
+            }
+        };
+
+        let magnitude = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if magnitude > 0.0 {
+            vector.iter_mut().for_each(|x| *x /= magnitude);
+        }
+        Ok(Embedding::new(vector))
+    }
+}
+
 // ============================================================================
 // HNSW Index (Hierarchical Navigable Small World)
 // ============================================================================
@@ -190,12 +740,11 @@ impl HnswLayer {
 
     fn connect(&mut self, from: usize, to: usize) {
         let connections = self.adjacency.entry(from).or_default();
-        if !connections.contains(&to) && connections.len() < self.max_connections {
+        if from != to && !connections.contains(&to) {
             connections.push(to);
         }
     }
 
-    #[allow(dead_code)]
     fn neighbors(&self, node_id: usize) -> &[usize] {
         self.adjacency
             .get(&node_id)
@@ -204,10 +753,42 @@ impl HnswLayer {
     }
 }
 
+/// A node reached while searching, ordered by distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Candidate {
+    distance: f32,
+    node: usize,
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.node.cmp(&other.node))
+    }
+}
+
 /// HNSW index for fast similarity search.
+///
+/// Nodes are assigned random levels and linked to their nearest neighbors
+/// on every layer up to that level, so searches descend greedily from the
+/// sparse top layers before expanding on the dense base layer. Level
+/// assignment uses a seeded generator, making builds reproducible.
+///
+/// Adding a statute that is already indexed replaces it. Replaced and
+/// removed nodes stay in the graph as tombstones for navigation until
+/// [`HnswIndex::compact`] rebuilds it.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HnswIndex {
-    /// All embeddings in the index
+    /// All embeddings in the index, including tombstones
     embeddings: Vec<StatuteEmbedding>,
     /// Hierarchical layers
     layers: Vec<HnswLayer>,
@@ -221,6 +802,14 @@ pub struct HnswIndex {
     ef_construction: usize,
     /// Search expansion factor for queries
     ef_search: usize,
+    /// Top layer of each node
+    levels: Vec<usize>,
+    /// Live node of each statute
+    nodes: HashMap<String, usize>,
+    /// Replaced or removed nodes
+    tombstones: HashSet<usize>,
+    /// Level generator state
+    rng_state: u64,
 }
 
 impl HnswIndex {
@@ -228,12 +817,16 @@ impl HnswIndex {
     pub fn new() -> Self {
         Self {
             embeddings: Vec::new(),
-            layers: vec![HnswLayer::new(16)], // Base layer
+            layers: vec![HnswLayer::new(32)], // Base layer
             entry_point: None,
             max_layers: 5,
             max_connections: 16,
             ef_construction: 200,
             ef_search: 50,
+            levels: Vec::new(),
+            nodes: HashMap::new(),
+            tombstones: HashSet::new(),
+            rng_state: 0x5eed_1e9a_1150_2024,
         }
     }
 
@@ -245,114 +838,286 @@ impl HnswIndex {
         ef_construction: usize,
         ef_search: usize,
     ) -> Self {
-        self.max_layers = max_layers;
-        self.max_connections = max_connections;
-        self.ef_construction = ef_construction;
-        self.ef_search = ef_search;
+        self.max_layers = max_layers.max(1);
+        self.max_connections = max_connections.max(2);
+        self.ef_construction = ef_construction.max(1);
+        self.ef_search = ef_search.max(1);
+        for level in 0..self.layers.len() {
+            self.layers[level].max_connections = self.layer_capacity(level);
+        }
         self
     }
 
-    /// Add an embedding to the index.
+    /// Add an embedding to the index, replacing any previous one for the statute.
     pub fn add(&mut self, embedding: StatuteEmbedding) {
+        if let Some(previous) = self.nodes.remove(&embedding.statute_id) {
+            self.tombstones.insert(previous);
+        }
+
         let node_id = self.embeddings.len();
+        let level = self.random_level();
+        self.nodes.insert(embedding.statute_id.clone(), node_id);
         self.embeddings.push(embedding);
-
-        // Add to base layer
-        self.layers[0].add_node(node_id);
+        self.levels.push(level);
+        while self.layers.len() <= level {
+            let capacity = self.layer_capacity(self.layers.len());
+            self.layers.push(HnswLayer::new(capacity));
+        }
+        for layer in &mut self.layers[..=level] {
+            layer.add_node(node_id);
+        }
 
         // Set entry point if this is the first node
-        if self.entry_point.is_none() {
+        let Some(entry) = self.entry_point else {
             self.entry_point = Some(node_id);
             return;
-        }
+        };
 
-        // Connect to nearest neighbors in base layer
-        let neighbors = self.find_nearest_neighbors(node_id, self.ef_construction, 0);
-        for &neighbor_id in neighbors.iter().take(self.max_connections) {
-            self.layers[0].connect(node_id, neighbor_id);
-            self.layers[0].connect(neighbor_id, node_id);
+        let query = self.embeddings[node_id].embedding.clone();
+        let top = self.levels[entry];
+        let mut nearest = vec![entry];
+        for layer in (level + 1..=top).rev() {
+            nearest = self.closest(&query, &nearest, 1, layer);
         }
-    }
-
-    /// Find k nearest neighbors.
-    fn find_nearest_neighbors(&self, query_id: usize, k: usize, layer: usize) -> Vec<usize> {
-        if self.embeddings.is_empty() || layer >= self.layers.len() {
-            return Vec::new();
+        for layer in (0..=level.min(top)).rev() {
+            let candidates = self.search_layer(&query, &nearest, self.ef_construction, layer);
+            let neighbors = self.select_neighbors(&candidates, self.max_connections);
+            for neighbor in neighbors {
+                self.layers[layer].connect(node_id, neighbor);
+                self.layers[layer].connect(neighbor, node_id);
+                self.prune(neighbor, layer);
+            }
+            nearest = candidates.iter().map(|c| c.node).collect();
         }
 
-        let mut candidates = Vec::new();
-        let mut visited = HashSet::new();
+        if level > top {
+            self.entry_point = Some(node_id);
+        }
+    }
 
-        // Start from entry point or all nodes if no entry point
-        let start_nodes = if let Some(entry) = self.entry_point {
-            vec![entry]
-        } else {
-            (0..self.embeddings.len()).collect()
+    /// Remove a statute from the index.
+    ///
+    /// Returns `false` if it was not indexed.
+    pub fn remove(&mut self, statute_id: &str) -> bool {
+        let Some(node_id) = self.nodes.remove(statute_id) else {
+            return false;
         };
-
-        for start_id in start_nodes {
-            if start_id == query_id {
-                continue;
-            }
-
-            let similarity = self.embeddings[query_id]
-                .embedding
-                .cosine_similarity(&self.embeddings[start_id].embedding);
-
-            candidates.push((start_id, similarity));
-            visited.insert(start_id);
+        self.tombstones.insert(node_id);
+        if self.tombstones.len() > self.nodes.len().max(16) {
+            self.compact();
         }
+        true
+    }
 
-        // Sort by similarity (descending)
-        candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+    /// Rebuild the graph from live embeddings, dropping tombstones.
+    pub fn compact(&mut self) {
+        let live: Vec<StatuteEmbedding> = self
+            .embeddings
+            .drain(..)
+            .enumerate()
+            .filter(|(node_id, _)| !self.tombstones.contains(node_id))
+            .map(|(_, embedding)| embedding)
+            .collect();
 
-        // Take top k
-        candidates.truncate(k);
-        candidates.into_iter().map(|(id, _)| id).collect()
+        let mut rebuilt = Self::new().with_params(
+            self.max_layers,
+            self.max_connections,
+            self.ef_construction,
+            self.ef_search,
+        );
+        for embedding in live {
+            rebuilt.add(embedding);
+        }
+        *self = rebuilt;
     }
 
     /// Search for similar embeddings.
     pub fn search(&self, query: &Embedding, k: usize) -> Vec<SearchResult> {
-        if self.embeddings.is_empty() {
+        let Some(entry) = self.entry_point else {
+            return Vec::new();
+        };
+        if k == 0 || self.nodes.is_empty() {
             return Vec::new();
         }
 
-        let mut results = Vec::new();
-
-        for statute_emb in self.embeddings.iter() {
-            let similarity = query.cosine_similarity(&statute_emb.embedding);
-            results.push(SearchResult {
-                statute_id: statute_emb.statute_id.clone(),
-                similarity,
-                embedding: statute_emb.clone(),
-            });
+        let mut nearest = vec![entry];
+        for layer in (1..=self.levels[entry]).rev() {
+            nearest = self.closest(query, &nearest, 1, layer);
         }
 
-        // Sort by similarity (descending)
-        results.sort_by(|a, b| b.similarity.partial_cmp(&a.similarity).unwrap());
+        // Tombstones still occupy slots in the candidate list
+        let ef = self.ef_search.max(k);
+        let ef = ef + self.tombstones.len().min(ef);
+        self.search_layer(query, &nearest, ef, 0)
+            .into_iter()
+            .filter(|c| !self.tombstones.contains(&c.node))
+            .take(k)
+            .map(|c| {
+                let embedding = &self.embeddings[c.node];
+                SearchResult {
+                    statute_id: embedding.statute_id.clone(),
+                    similarity: 1.0 - c.distance,
+                    embedding: embedding.clone(),
+                }
+            })
+            .collect()
+    }
 
-        // Take top k
-        results.truncate(k);
-        results
+    /// Get the indexed embedding of a statute.
+    pub fn get(&self, statute_id: &str) -> Option<&StatuteEmbedding> {
+        self.nodes
+            .get(statute_id)
+            .map(|&node_id| &self.embeddings[node_id])
     }
 
     /// Get the number of embeddings in the index.
     pub fn len(&self) -> usize {
-        self.embeddings.len()
+        self.nodes.len()
     }
 
     /// Check if the index is empty.
     pub fn is_empty(&self) -> bool {
-        self.embeddings.is_empty()
+        self.nodes.is_empty()
     }
 
     /// Get all statute IDs in the index.
     pub fn statute_ids(&self) -> Vec<String> {
         self.embeddings
             .iter()
-            .map(|e| e.statute_id.clone())
+            .enumerate()
+            .filter(|(node_id, _)| !self.tombstones.contains(node_id))
+            .map(|(_, e)| e.statute_id.clone())
+            .collect()
+    }
+
+    /// Maximum connections on a layer; the base layer is twice as dense.
+    fn layer_capacity(&self, layer: usize) -> usize {
+        if layer == 0 {
+            self.max_connections * 2
+        } else {
+            self.max_connections
+        }
+    }
+
+    /// Draws a level from the exponentially decaying HNSW distribution.
+    fn random_level(&mut self) -> usize {
+        // splitmix64
+        self.rng_state = self.rng_state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.rng_state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+
+        let uniform = ((z >> 11) as f64 + 1.0) / (1u64 << 53) as f64;
+        let scale = 1.0 / (self.max_connections as f64).ln();
+        ((-uniform.ln() * scale) as usize).min(self.max_layers - 1)
+    }
+
+    fn distance(&self, query: &Embedding, node_id: usize) -> f32 {
+        1.0 - query.cosine_similarity(&self.embeddings[node_id].embedding)
+    }
+
+    fn closest(&self, query: &Embedding, entry: &[usize], ef: usize, layer: usize) -> Vec<usize> {
+        self.search_layer(query, entry, ef, layer)
+            .into_iter()
+            .map(|c| c.node)
             .collect()
     }
+
+    /// Best-first search on one layer, returning up to `ef` nodes nearest first.
+    fn search_layer(
+        &self,
+        query: &Embedding,
+        entry: &[usize],
+        ef: usize,
+        layer: usize,
+    ) -> Vec<Candidate> {
+        let mut visited: HashSet<usize> = entry.iter().copied().collect();
+        let mut frontier = BinaryHeap::new();
+        let mut found = BinaryHeap::new();
+        for &node in entry {
+            let candidate = Candidate {
+                distance: self.distance(query, node),
+                node,
+            };
+            frontier.push(Reverse(candidate));
+            found.push(candidate);
+        }
+        while found.len() > ef {
+            found.pop();
+        }
+
+        while let Some(Reverse(current)) = frontier.pop() {
+            let worst = found.peek().map_or(f32::INFINITY, |c| c.distance);
+            if current.distance > worst && found.len() >= ef {
+                break;
+            }
+            for &neighbor in self.layers[layer].neighbors(current.node) {
+                if !visited.insert(neighbor) {
+                    continue;
+                }
+                let candidate = Candidate {
+                    distance: self.distance(query, neighbor),
+                    node: neighbor,
+                };
+                let worst = found.peek().map_or(f32::INFINITY, |c| c.distance);
+                if found.len() < ef || candidate.distance < worst {
+                    frontier.push(Reverse(candidate));
+                    found.push(candidate);
+                    if found.len() > ef {
+                        found.pop();
+                    }
+                }
+            }
+        }
+
+        found.into_sorted_vec()
+    }
+
+    /// Picks up to `m` neighbors, preferring ones that are not already
+    /// reachable through a closer pick so the graph keeps long-range links.
+    fn select_neighbors(&self, candidates: &[Candidate], m: usize) -> Vec<usize> {
+        let mut selected: Vec<Candidate> = Vec::with_capacity(m);
+        let mut skipped = Vec::new();
+        for &candidate in candidates {
+            if selected.len() >= m {
+                break;
+            }
+            let embedding = &self.embeddings[candidate.node].embedding;
+            let diverse = selected
+                .iter()
+                .all(|s| self.distance(embedding, s.node) > candidate.distance);
+            if diverse {
+                selected.push(candidate);
+            } else {
+                skipped.push(candidate);
+            }
+        }
+        let missing = m.saturating_sub(selected.len());
+        selected.extend(skipped.into_iter().take(missing));
+        selected.into_iter().map(|c| c.node).collect()
+    }
+
+    /// Trims a node's connections on a layer back to the layer capacity.
+    fn prune(&mut self, node_id: usize, layer: usize) {
+        let capacity = self.layers[layer].max_connections;
+        let neighbors = self.layers[layer].neighbors(node_id);
+        if neighbors.len() <= capacity {
+            return;
+        }
+
+        let embedding = &self.embeddings[node_id].embedding;
+        let mut candidates: Vec<Candidate> = neighbors
+            .iter()
+            .map(|&node| Candidate {
+                distance: self.distance(embedding, node),
+                node,
+            })
+            .collect();
+        candidates.sort();
+        let kept = self.select_neighbors(&candidates, capacity);
+        self.layers[layer].adjacency.insert(node_id, kept);
+    }
 }
 
 impl Default for HnswIndex {
@@ -372,6 +1137,348 @@ pub struct SearchResult {
     pub embedding: StatuteEmbedding,
 }
 
+// ============================================================================
+// Semantic Index
+// ============================================================================
+
+/// Changes applied by [`SemanticIndex::sync`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncReport {
+    /// Statutes embedded for the first time
+    pub added: usize,
+    /// Statutes re-embedded because their text changed
+    pub updated: usize,
+    /// Statutes dropped from the index
+    pub removed: usize,
+    /// Whether the whole index was rebuilt for a new model signature
+    pub rebuilt: bool,
+}
+
+impl SyncReport {
+    /// Returns `true` if the index changed.
+    pub fn has_changes(&self) -> bool {
+        self.added > 0 || self.updated > 0 || self.removed > 0 || self.rebuilt
+    }
+}
+
+/// Persisted form of a [`SemanticIndex`].
+#[derive(Serialize, Deserialize)]
+struct SemanticIndexSnapshot {
+    model: String,
+    embedder_state: Option<serde_json::Value>,
+    fitted_documents: usize,
+    fingerprints: HashMap<String, u64>,
+    index: HnswIndex,
+}
+
+/// Text of a statute entry that is embedded for semantic search.
+pub fn embedding_text(entry: &StatuteEntry) -> String {
+    let statute = &entry.statute;
+    let mut parts = vec![
+        statute.title.clone(),
+        statute.id.replace(['-', '_', '.'], " "),
+        statute.effect.description.clone(),
+    ];
+    parts.extend(statute.discretion_logic.iter().cloned());
+    parts.extend(statute.preconditions.iter().map(|c| c.to_string()));
+    parts.extend(entry.tags.iter().cloned());
+    parts.join("\n")
+}
+
+/// Embeddings of registry statutes kept in an HNSW index.
+///
+/// [`SemanticIndex::sync`] brings the index up to date with the registry,
+/// re-embedding only statutes whose text changed. Embedders that learn
+/// corpus statistics are refitted once the corpus size drifts past the
+/// refit threshold, which rebuilds the index under the new model signature.
+///
+/// # Examples
+///
+/// ```
+/// use legalis_core::{Effect, EffectType, Statute};
+/// use legalis_registry::StatuteEntry;
+/// use legalis_registry::vector_search::SemanticIndex;
+///
+/// let entries = vec![
+///     StatuteEntry::new(
+///         Statute::new("income-tax", "Income Taxation Act", Effect::new(EffectType::Obligation, "Pay tax on income")),
+///         "JP",
+///     ),
+///     StatuteEntry::new(
+///         Statute::new("road-traffic", "Road Traffic Act", Effect::new(EffectType::Prohibition, "Speeding")),
+///         "JP",
+///     ),
+/// ];
+///
+/// let mut index = SemanticIndex::default();
+/// index.sync(&entries).unwrap();
+///
+/// let hits = index.search("taxes on earnings", 1).unwrap();
+/// assert_eq!(hits[0].0, "income-tax");
+/// ```
+pub struct SemanticIndex {
+    embedder: Box<dyn Embedder>,
+    index: HnswIndex,
+    /// Text hash of each indexed statute
+    fingerprints: HashMap<String, u64>,
+    /// Model signature the index was built with
+    model: String,
+    /// Corpus size at the last fit
+    fitted_documents: usize,
+    /// Relative corpus size change that triggers a refit
+    refit_threshold: f64,
+    /// Nearest neighbors retrieved per query
+    candidates: usize,
+    /// Similarities below this are not considered matches
+    min_similarity: f32,
+}
+
+impl SemanticIndex {
+    /// Creates an empty index using the given embedder.
+    pub fn new(embedder: impl Embedder + 'static) -> Self {
+        Self {
+            embedder: Box::new(embedder),
+            index: HnswIndex::new(),
+            fingerprints: HashMap::new(),
+            model: String::new(),
+            fitted_documents: 0,
+            refit_threshold: 0.25,
+            candidates: 100,
+            min_similarity: 0.1,
+        }
+    }
+
+    /// Sets the relative corpus size change that triggers a refit.
+    pub fn with_refit_threshold(mut self, threshold: f64) -> Self {
+        self.refit_threshold = threshold.max(0.0);
+        self
+    }
+
+    /// Sets the number of nearest neighbors retrieved per query.
+    pub fn with_candidates(mut self, candidates: usize) -> Self {
+        self.candidates = candidates.max(1);
+        self
+    }
+
+    /// Sets the similarity below which statutes are not considered matches.
+    pub fn with_min_similarity(mut self, min_similarity: f32) -> Self {
+        self.min_similarity = min_similarity;
+        self
+    }
+
+    /// Returns the embedder.
+    pub fn embedder(&self) -> &dyn Embedder {
+        self.embedder.as_ref()
+    }
+
+    /// Returns the underlying HNSW index.
+    pub fn index(&self) -> &HnswIndex {
+        &self.index
+    }
+
+    /// Returns the number of indexed statutes.
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Returns `true` if nothing is indexed.
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// Brings the index up to date with the given statutes.
+    ///
+    /// Statutes missing from `entries` are removed from the index.
+    pub fn sync<'a>(
+        &mut self,
+        entries: impl IntoIterator<Item = &'a StatuteEntry>,
+    ) -> Result<SyncReport, VectorSearchError> {
+        let documents: Vec<(&str, String)> = entries
+            .into_iter()
+            .map(|entry| (entry.statute.id.as_str(), embedding_text(entry)))
+            .collect();
+        let mut report = SyncReport::default();
+
+        let drift = documents.len().abs_diff(self.fitted_documents) as f64;
+        if drift > self.refit_threshold * self.fitted_documents as f64 {
+            let corpus: Vec<String> = documents.iter().map(|(_, text)| text.clone()).collect();
+            self.embedder.fit(&corpus);
+            self.fitted_documents = documents.len();
+        }
+
+        let model = self.embedder.model();
+        if model != self.model {
+            report.rebuilt = !self.fingerprints.is_empty();
+            self.index = HnswIndex::new();
+            self.fingerprints.clear();
+            self.model = model;
+        }
+
+        let current: HashSet<&str> = documents.iter().map(|(id, _)| *id).collect();
+        let stale: Vec<String> = self
+            .fingerprints
+            .keys()
+            .filter(|id| !current.contains(id.as_str()))
+            .cloned()
+            .collect();
+        for id in stale {
+            self.fingerprints.remove(&id);
+            self.index.remove(&id);
+            report.removed += 1;
+        }
+
+        for (id, text) in documents {
+            let fingerprint = fnv1a(FNV_OFFSET, text.as_bytes());
+            match self.fingerprints.get(id) {
+                Some(&known) if known == fingerprint => continue,
+                Some(_) => report.updated += 1,
+                None => report.added += 1,
+            }
+            let embedding = self.embedder.embed(&text)?;
+            self.index.add(StatuteEmbedding {
+                statute_id: id.to_string(),
+                embedding,
+                embedded_text: text,
+                generated_at: chrono::Utc::now(),
+                model: self.model.clone(),
+            });
+            self.fingerprints.insert(id.to_string(), fingerprint);
+        }
+
+        Ok(report)
+    }
+
+    /// Finds the statutes closest in meaning to the query text.
+    ///
+    /// Returns statute IDs with their cosine similarity, most similar first.
+    pub fn search(&self, query: &str, k: usize) -> Result<Vec<(String, f32)>, VectorSearchError> {
+        if self.index.is_empty() || query.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        let embedding = self.embedder.embed(query)?;
+        Ok(self
+            .index
+            .search(&embedding, k)
+            .into_iter()
+            .filter(|result| result.similarity >= self.min_similarity)
+            .map(|result| (result.statute_id, result.similarity))
+            .collect())
+    }
+
+    /// Ranks statutes against a free-text query.
+    ///
+    /// Lexical scores come from [`RankingConfig`]; semantic scores are the
+    /// similarities of the nearest indexed statutes. Entries scoring zero
+    /// are dropped and the rest are returned best first. The index should
+    /// have been synced with `entries`.
+    pub fn rank<'a>(
+        &self,
+        query: &str,
+        mode: SearchMode,
+        entries: impl IntoIterator<Item = &'a StatuteEntry>,
+        ranking: &RankingConfig,
+    ) -> Result<Vec<(&'a StatuteEntry, f64)>, VectorSearchError> {
+        let similarities: HashMap<String, f64> = if mode.uses_vectors() {
+            self.search(query, self.candidates)?
+                .into_iter()
+                .map(|(id, similarity)| (id, f64::from(similarity)))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        let query_lower = query.to_lowercase();
+        let lexical = |entry: &StatuteEntry| {
+            ranking.score(
+                &query_lower,
+                &entry.statute.id,
+                &entry.statute.title,
+                &entry.tags,
+                &entry.jurisdiction,
+            )
+        };
+
+        let mut ranked: Vec<(&StatuteEntry, f64)> = entries
+            .into_iter()
+            .filter_map(|entry| {
+                let similarity = similarities.get(&entry.statute.id).copied().unwrap_or(0.0);
+                let score = match mode {
+                    SearchMode::Lexical => lexical(entry),
+                    SearchMode::Semantic => similarity,
+                    SearchMode::Hybrid { vector_weight } => {
+                        let weight = vector_weight.clamp(0.0, 1.0);
+                        weight * similarity + (1.0 - weight) * lexical(entry)
+                    }
+                };
+                (score > 0.0).then_some((entry, score))
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Ok(ranked)
+    }
+
+    /// Writes the index and the embedder's fitted state to a file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), VectorSearchError> {
+        let path = path.as_ref();
+        let snapshot = SemanticIndexSnapshot {
+            model: self.model.clone(),
+            embedder_state: self.embedder.state(),
+            fitted_documents: self.fitted_documents,
+            fingerprints: self.fingerprints.clone(),
+            index: self.index.clone(),
+        };
+
+        // Write to a sibling file first so a crash never leaves a torn index
+        let mut temporary = path.as_os_str().to_owned();
+        temporary.push(".tmp");
+        std::fs::write(&temporary, serde_json::to_vec(&snapshot)?)?;
+        std::fs::rename(&temporary, path)?;
+        Ok(())
+    }
+
+    /// Reads an index written by [`SemanticIndex::save`].
+    ///
+    /// If the embedder's signature no longer matches the saved one, the
+    /// index is rebuilt on the next [`SemanticIndex::sync`].
+    pub fn load(
+        path: impl AsRef<Path>,
+        embedder: impl Embedder + 'static,
+    ) -> Result<Self, VectorSearchError> {
+        let snapshot: SemanticIndexSnapshot = serde_json::from_slice(&std::fs::read(path)?)?;
+        let mut index = Self::new(embedder);
+        if let Some(state) = snapshot.embedder_state {
+            index.embedder.restore(state)?;
+        }
+        // A different embedder starts unfitted and rebuilds on the next sync
+        if index.embedder.model() == snapshot.model {
+            index.fitted_documents = snapshot.fitted_documents;
+        }
+        index.model = snapshot.model;
+        index.fingerprints = snapshot.fingerprints;
+        index.index = snapshot.index;
+        Ok(index)
+    }
+}
+
+impl Default for SemanticIndex {
+    fn default() -> Self {
+        Self::new(HashedTfIdfEmbedder::default())
+    }
+}
+
+impl std::fmt::Debug for SemanticIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SemanticIndex")
+            .field("model", &self.model)
+            .field("len", &self.index.len())
+            .field("fitted_documents", &self.fitted_documents)
+            .field("refit_threshold", &self.refit_threshold)
+            .field("candidates", &self.candidates)
+            .field("min_similarity", &self.min_similarity)
+            .finish()
+    }
+}
+
 // ============================================================================
 // Hybrid Search
 // ============================================================================
@@ -1276,4 +2383,151 @@ mod tests {
         assert!(ids.contains(&"statute-1".to_string()));
         assert!(ids.contains(&"statute-2".to_string()));
     }
+
+    fn random_vectors(count: usize, dimensions: usize, seed: u64) -> Vec<Vec<f32>> {
+        let mut state = seed;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 2000) as f32 / 1000.0 - 1.0
+        };
+        (0..count)
+            .map(|_| (0..dimensions).map(|_| next()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_hnsw_recall_against_exact_search() {
+        let vectors = random_vectors(500, 16, 0x2545_f491_4f6c_dd1d);
+        let mut index = HnswIndex::new().with_params(5, 8, 64, 32);
+        for (i, vector) in vectors.iter().enumerate() {
+            index.add(create_statute_embedding(
+                &format!("s-{}", i),
+                vector.clone(),
+            ));
+        }
+        assert!(index.layers.len() > 1);
+
+        let queries = random_vectors(20, 16, 0x9e37_79b9_7f4a_7c15);
+        let mut hits = 0;
+        for query in queries {
+            let query = Embedding::new(query);
+            let mut exact: Vec<(usize, f32)> = vectors
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (i, query.cosine_similarity(&Embedding::new(v.clone()))))
+                .collect();
+            exact.sort_by(|a, b| b.1.total_cmp(&a.1));
+            let expected: HashSet<String> = exact[..10]
+                .iter()
+                .map(|(i, _)| format!("s-{}", i))
+                .collect();
+
+            let found = index.search(&query, 10);
+            assert_eq!(found.len(), 10);
+            hits += found
+                .iter()
+                .filter(|r| expected.contains(&r.statute_id))
+                .count();
+        }
+        // Recall@10 over 20 queries
+        assert!(hits >= 180, "recall too low: {}/200", hits);
+    }
+
+    #[test]
+    fn test_hnsw_replace_and_remove() {
+        let mut index = HnswIndex::new();
+        index.add(create_statute_embedding("statute-1", vec![1.0, 0.0]));
+        index.add(create_statute_embedding("statute-2", vec![0.0, 1.0]));
+
+        // Re-adding a statute replaces its embedding
+        index.add(create_statute_embedding("statute-1", vec![0.1, 1.0]));
+        assert_eq!(index.len(), 2);
+        let results = index.search(&create_test_embedding(vec![1.0, 0.0]), 2);
+        assert_eq!(results.len(), 2);
+        assert!(results[0].similarity < 0.5);
+
+        assert!(index.remove("statute-2"));
+        assert!(!index.remove("statute-2"));
+        assert_eq!(index.statute_ids(), vec!["statute-1".to_string()]);
+        let results = index.search(&create_test_embedding(vec![0.0, 1.0]), 5);
+        assert_eq!(results.len(), 1);
+
+        index.compact();
+        assert_eq!(index.embeddings.len(), 1);
+        assert_eq!(
+            index.get("statute-1").unwrap().embedding.vector,
+            vec![0.1, 1.0]
+        );
+    }
+
+    #[test]
+    fn test_hashed_embedder_related_wording() {
+        let mut embedder = HashedTfIdfEmbedder::default();
+        let corpus = vec![
+            "Income taxation of residents".to_string(),
+            "Road traffic and driving licences".to_string(),
+        ];
+        assert!(embedder.fit(&corpus));
+        assert!(!embedder.fit(&corpus));
+
+        let tax = embedder.embed("income taxation").unwrap();
+        let taxes = embedder.embed("taxes on income").unwrap();
+        let traffic = embedder.embed("road traffic").unwrap();
+        assert_eq!(tax.dimensions, HashedTfIdfEmbedder::DEFAULT_DIMENSIONS);
+        assert!(tax.cosine_similarity(&taxes) > tax.cosine_similarity(&traffic));
+        assert_eq!(
+            embedder.embed("income taxation").unwrap().vector,
+            tax.vector
+        );
+
+        // CJK text is split into character bigrams
+        let shotoku = embedder.embed("所得税法").unwrap();
+        let shotokuzei = embedder.embed("所得税の課税").unwrap();
+        let douro = embedder.embed("道路交通法").unwrap();
+        assert!(shotoku.cosine_similarity(&shotokuzei) > shotoku.cosine_similarity(&douro));
+    }
+
+    #[test]
+    fn test_semantic_index_sync() {
+        use legalis_core::{Effect, EffectType, Statute};
+
+        let entry = |id: &str, title: &str| {
+            StatuteEntry::new(
+                Statute::new(id, title, Effect::new(EffectType::Grant, title)),
+                "JP",
+            )
+        };
+        let mut entries = vec![
+            entry("pension", "National Pension Act"),
+            entry("labour", "Labour Standards Act"),
+            entry("tax", "Income Tax Act"),
+            entry("traffic", "Road Traffic Act"),
+        ];
+
+        let mut index = SemanticIndex::default();
+        let report = index.sync(&entries).unwrap();
+        assert_eq!(report.added, 4);
+        assert!(!report.rebuilt);
+        assert!(!index.sync(&entries).unwrap().has_changes());
+
+        // Only the changed statute is re-embedded
+        entries[0].statute.title = "National Pension Insurance Act".to_string();
+        entries.pop();
+        let report = index.sync(&entries).unwrap();
+        assert_eq!((report.added, report.updated, report.removed), (0, 1, 1));
+        assert_eq!(index.len(), 3);
+
+        // Growing the corpus past the refit threshold refits the embedder
+        entries.push(entry("health", "Health Insurance Act"));
+        entries.push(entry("welfare", "Public Assistance Act"));
+        entries.push(entry("housing", "Public Housing Act"));
+        let report = index.sync(&entries).unwrap();
+        assert!(report.rebuilt);
+        assert_eq!(index.len(), 6);
+
+        let hits = index.search("pension insurance", 2).unwrap();
+        assert_eq!(hits[0].0, "pension");
+    }
 }
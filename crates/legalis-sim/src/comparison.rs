@@ -172,28 +172,24 @@ impl ABTest {
 
     /// Runs the A/B test and returns comparison.
     pub async fn run(&self) -> StatuteComparison {
-        use crate::engine::{LawApplicationResult, SimEngine};
+        use crate::engine::SimEngine;
 
         // Run simulation with version A
         let mut metrics_a = SimulationMetrics::new();
         for agent in &self.population {
-            let result = SimEngine::apply_law(agent.as_ref(), &self.version_a);
-            metrics_a.record_result(&LawApplicationResult {
-                agent_id: agent.id(),
-                statute_id: self.version_a.id.clone(),
-                result,
-            });
+            metrics_a.record_result(&SimEngine::apply_law_with_outputs(
+                agent.as_ref(),
+                &self.version_a,
+            ));
         }
 
         // Run simulation with version B
         let mut metrics_b = SimulationMetrics::new();
         for agent in &self.population {
-            let result = SimEngine::apply_law(agent.as_ref(), &self.version_b);
-            metrics_b.record_result(&LawApplicationResult {
-                agent_id: agent.id(),
-                statute_id: self.version_b.id.clone(),
-                result,
-            });
+            metrics_b.record_result(&SimEngine::apply_law_with_outputs(
+                agent.as_ref(),
+                &self.version_b,
+            ));
         }
 
         StatuteComparison::new(
@@ -232,19 +228,17 @@ impl SensitivityAnalysis {
 
     /// Runs sensitivity analysis across all variations.
     pub async fn run(&self, population: &[Box<dyn LegalEntity>]) -> SensitivityResults {
-        use crate::engine::{LawApplicationResult, SimEngine};
+        use crate::engine::SimEngine;
 
         let mut results = HashMap::new();
 
         // Test base statute
         let mut base_metrics = SimulationMetrics::new();
         for entity in population {
-            let result = SimEngine::apply_law(entity.as_ref(), &self.base_statute);
-            base_metrics.record_result(&LawApplicationResult {
-                agent_id: entity.id(),
-                statute_id: self.base_statute.id.clone(),
-                result,
-            });
+            base_metrics.record_result(&SimEngine::apply_law_with_outputs(
+                entity.as_ref(),
+                &self.base_statute,
+            ));
         }
 
         results.insert("Base".to_string(), base_metrics.clone());
@@ -253,12 +247,7 @@ impl SensitivityAnalysis {
         for (name, statute) in &self.variations {
             let mut metrics = SimulationMetrics::new();
             for entity in population {
-                let result = SimEngine::apply_law(entity.as_ref(), statute);
-                metrics.record_result(&LawApplicationResult {
-                    agent_id: entity.id(),
-                    statute_id: statute.id.clone(),
-                    result,
-                });
+                metrics.record_result(&SimEngine::apply_law_with_outputs(entity.as_ref(), statute));
             }
             results.insert(name.clone(), metrics);
         }
@@ -355,12 +344,8 @@ impl BatchSimulationRunner {
             let mut metrics = crate::SimulationMetrics::new();
             for entity in population {
                 for statute in &statutes {
-                    let result = crate::SimEngine::apply_law(*entity, statute);
-                    metrics.record_result(&crate::LawApplicationResult {
-                        agent_id: entity.id(),
-                        statute_id: statute.id.clone(),
-                        result,
-                    });
+                    metrics
+                        .record_result(&crate::SimEngine::apply_law_with_outputs(*entity, statute));
                 }
             }
             results.insert(name, metrics);
@@ -546,48 +531,42 @@ mod tests {
 
         // Metrics A: 80% deterministic
         for _ in 0..80 {
-            metrics_a.record_result(&LawApplicationResult {
-                agent_id: Uuid::new_v4(),
-                statute_id: "test".to_string(),
-                result: legalis_core::LegalResult::Deterministic(Effect::new(
-                    EffectType::Grant,
-                    "Test",
-                )),
-            });
+            metrics_a.record_result(&LawApplicationResult::new(
+                Uuid::new_v4(),
+                "test".to_string(),
+                legalis_core::LegalResult::Deterministic(Effect::new(EffectType::Grant, "Test")),
+            ));
         }
         for _ in 0..20 {
-            metrics_a.record_result(&LawApplicationResult {
-                agent_id: Uuid::new_v4(),
-                statute_id: "test".to_string(),
-                result: legalis_core::LegalResult::JudicialDiscretion {
+            metrics_a.record_result(&LawApplicationResult::new(
+                Uuid::new_v4(),
+                "test".to_string(),
+                legalis_core::LegalResult::JudicialDiscretion {
                     issue: "Test".to_string(),
                     context_id: Uuid::new_v4(),
                     narrative_hint: None,
                 },
-            });
+            ));
         }
 
         // Metrics B: 90% deterministic
         for _ in 0..90 {
-            metrics_b.record_result(&LawApplicationResult {
-                agent_id: Uuid::new_v4(),
-                statute_id: "test".to_string(),
-                result: legalis_core::LegalResult::Deterministic(Effect::new(
-                    EffectType::Grant,
-                    "Test",
-                )),
-            });
+            metrics_b.record_result(&LawApplicationResult::new(
+                Uuid::new_v4(),
+                "test".to_string(),
+                legalis_core::LegalResult::Deterministic(Effect::new(EffectType::Grant, "Test")),
+            ));
         }
         for _ in 0..10 {
-            metrics_b.record_result(&LawApplicationResult {
-                agent_id: Uuid::new_v4(),
-                statute_id: "test".to_string(),
-                result: legalis_core::LegalResult::JudicialDiscretion {
+            metrics_b.record_result(&LawApplicationResult::new(
+                Uuid::new_v4(),
+                "test".to_string(),
+                legalis_core::LegalResult::JudicialDiscretion {
                     issue: "Test".to_string(),
                     context_id: Uuid::new_v4(),
                     narrative_hint: None,
                 },
-            });
+            ));
         }
 
         let diff = DifferentialAnalysis::compute(&metrics_a, &metrics_b);
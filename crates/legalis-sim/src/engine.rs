@@ -1,9 +1,14 @@
 //! Simulation engine implementation.
 
 use crate::metrics::SimulationMetrics;
+use crate::streaming::StreamingSimulation;
+use chrono::NaiveDate;
 use legalis_core::{
-    BasicEntity, ComparisonOp, Condition, Effect, LegalEntity, LegalResult, Statute,
+    BasicEntity, Condition, Effect, EntityContext, EvaluationContext, EvaluationError, LegalEntity,
+    LegalResult, OutputValue, Statute,
 };
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 use tokio::sync::mpsc;
 use uuid::Uuid;
@@ -14,12 +19,82 @@ pub struct LawApplicationResult {
     pub agent_id: Uuid,
     pub statute_id: String,
     pub result: LegalResult<Effect>,
+    /// Computed effect outputs (empty unless the result is deterministic)
+    pub outputs: BTreeMap<String, OutputValue>,
+    /// Why the law did or did not apply
+    pub reason: ApplicationReason,
+    /// Date the law was evaluated on, if the context had one
+    pub evaluation_date: Option<NaiveDate>,
+}
+
+impl LawApplicationResult {
+    /// Creates a result without outputs, deriving the reason from `result`.
+    pub fn new(agent_id: Uuid, statute_id: impl Into<String>, result: LegalResult<Effect>) -> Self {
+        let reason = ApplicationReason::from_result(&result);
+        Self {
+            agent_id,
+            statute_id: statute_id.into(),
+            result,
+            outputs: BTreeMap::new(),
+            reason,
+            evaluation_date: None,
+        }
+    }
+}
+
+/// Why a law did or did not apply to an entity.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ApplicationReason {
+    /// All preconditions held and no exception applied
+    Applied,
+    /// The law was not in force on the evaluation date
+    NotInForce { date: NaiveDate },
+    /// A precondition was not met
+    PreconditionNotMet { detail: String },
+    /// An exception defeated the law
+    ExceptionApplied {
+        exception_id: String,
+        description: String,
+    },
+    /// The outcome requires human review
+    Discretion { issue: String },
+}
+
+impl ApplicationReason {
+    /// Derives the reason from a result produced without temporal or exception checks.
+    pub fn from_result(result: &LegalResult<Effect>) -> Self {
+        match result {
+            LegalResult::Deterministic(_) => Self::Applied,
+            LegalResult::Void { reason } => Self::PreconditionNotMet {
+                detail: reason.clone(),
+            },
+            LegalResult::JudicialDiscretion { issue, .. } => Self::Discretion {
+                issue: issue.clone(),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for ApplicationReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Applied => write!(f, "All preconditions met"),
+            Self::NotInForce { date } => write!(f, "Not in force on {}", date),
+            Self::PreconditionNotMet { detail } => write!(f, "{}", detail),
+            Self::ExceptionApplied {
+                exception_id,
+                description,
+            } => write!(f, "Exception '{}' applies: {}", exception_id, description),
+            Self::Discretion { issue } => write!(f, "{}", issue),
+        }
+    }
 }
 
 /// Simulation engine for running legal simulations.
 pub struct SimEngine {
     statutes: Vec<Statute>,
     population: Vec<Arc<dyn LegalEntity>>,
+    simulation_date: Option<NaiveDate>,
 }
 
 impl SimEngine {
@@ -28,9 +103,25 @@ impl SimEngine {
         Self {
             statutes,
             population: population.into_iter().map(Arc::from).collect(),
+            simulation_date: None,
         }
     }
 
+    /// Sets the date the simulation is run on.
+    ///
+    /// Statutes not in force on this date are void for every agent. Without
+    /// a date, each agent's `current_date` attribute is used; statutes with
+    /// effective or expiry dates need review for agents that have none.
+    pub fn with_simulation_date(mut self, date: NaiveDate) -> Self {
+        self.simulation_date = Some(date);
+        self
+    }
+
+    /// Returns the configured simulation date, if any.
+    pub fn simulation_date(&self) -> Option<NaiveDate> {
+        self.simulation_date
+    }
+
     /// Returns the number of agents in the simulation.
     pub fn population_size(&self) -> usize {
         self.population.len()
@@ -41,27 +132,38 @@ impl SimEngine {
         self.statutes.len()
     }
 
+    /// Creates a streaming simulation over this engine's statutes and date.
+    ///
+    /// The engine's in-memory population is not used; entities come from the
+    /// [`crate::EntitySource`] passed to [`StreamingSimulation::run`].
+    pub fn streaming(&self) -> StreamingSimulation {
+        let simulation = StreamingSimulation::new(self.statutes.clone());
+        match self.simulation_date {
+            Some(date) => simulation.with_simulation_date(date),
+            None => simulation,
+        }
+    }
+
     /// Runs the simulation and returns metrics.
     pub async fn run_simulation(&self) -> SimulationMetrics {
         let (tx, mut rx) = mpsc::channel::<LawApplicationResult>(1000);
 
         let mut metrics = SimulationMetrics::new();
+        let statutes: Arc<[Statute]> = self.statutes.clone().into();
 
         for agent in &self.population {
             let agent_ref = agent.clone();
-            let statutes_ref = self.statutes.clone();
+            let statutes_ref = Arc::clone(&statutes);
             let tx_clone = tx.clone();
+            let date = self.simulation_date;
 
             tokio::spawn(async move {
-                for statute in statutes_ref {
-                    let result = Self::apply_law(agent_ref.as_ref(), &statute);
-                    let _ = tx_clone
-                        .send(LawApplicationResult {
-                            agent_id: agent_ref.id(),
-                            statute_id: statute.id.clone(),
-                            result,
-                        })
-                        .await;
+                for statute in statutes_ref.iter() {
+                    let result = match date {
+                        Some(date) => Self::apply_law_at(agent_ref.as_ref(), statute, date),
+                        None => Self::apply_law_with_outputs(agent_ref.as_ref(), statute),
+                    };
+                    let _ = tx_clone.send(result).await;
                 }
             });
         }
@@ -78,496 +180,245 @@ impl SimEngine {
     }
 
     /// Applies a single law to an entity and returns the result.
+    ///
+    /// The law is evaluated on the entity's `current_date` attribute, if any;
+    /// see [`SimEngine::apply_law_in_context`].
     pub fn apply_law(agent: &dyn LegalEntity, law: &Statute) -> LegalResult<Effect> {
-        // Check all preconditions
-        for condition in &law.preconditions {
-            match Self::evaluate_condition(agent, condition) {
-                ConditionResult::True => continue,
-                ConditionResult::False => {
-                    return LegalResult::Void {
-                        reason: format!("Precondition not met: {condition:?}"),
-                    };
-                }
-                ConditionResult::Indeterminate(reason) => {
-                    return LegalResult::JudicialDiscretion {
-                        issue: reason,
-                        context_id: agent.id(),
-                        narrative_hint: law.discretion_logic.clone(),
-                    };
-                }
-            }
-        }
+        Self::apply_law_with_outputs(agent, law).result
+    }
+
+    /// Applies a single law to an entity and computes the effect's declared outputs.
+    ///
+    /// If an output cannot be computed for the entity, the result is downgraded
+    /// to `JudicialDiscretion`.
+    pub fn apply_law_with_outputs(agent: &dyn LegalEntity, law: &Statute) -> LawApplicationResult {
+        Self::apply_law_in_context(&EntityContext::new(agent), agent.id(), law)
+    }
 
-        // If discretion logic is specified, flag for human review
-        if law.discretion_logic.is_some() {
-            return LegalResult::JudicialDiscretion {
-                issue: "Discretionary review required".to_string(),
-                context_id: agent.id(),
-                narrative_hint: law.discretion_logic.clone(),
-            };
+    /// Applies a single law to an entity as of the given date.
+    pub fn apply_law_at(
+        agent: &dyn LegalEntity,
+        law: &Statute,
+        date: NaiveDate,
+    ) -> LawApplicationResult {
+        Self::apply_law_in_context(&EntityContext::new(agent).with_date(date), agent.id(), law)
+    }
+
+    /// Applies a single law using an arbitrary evaluation context.
+    ///
+    /// Evaluation follows the core rules: a law that is not in force on the
+    /// context's date is void, and a law with effective or expiry dates needs
+    /// review when the context has no date, so results never depend on the
+    /// wall clock. Otherwise the
+    /// preconditions, discretion logic and outputs are handled by
+    /// [`Statute::apply_in_context`], and a law that would apply is defeated by
+    /// the first exception whose condition holds. The reason for the outcome
+    /// is reported in [`LawApplicationResult::reason`].
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use legalis_core::{BasicEntity, Condition, Effect, EffectType, Statute, StatuteException, TemporalValidity};
+    /// use legalis_sim::{ApplicationReason, SimEngine};
+    ///
+    /// let law = Statute::new("relief", "Relief", Effect::new(EffectType::Grant, "Relief"))
+    ///     .with_temporal_validity(
+    ///         TemporalValidity::new()
+    ///             .with_expiry_date(NaiveDate::from_ymd_opt(2030, 3, 31).unwrap()),
+    ///     )
+    ///     .with_exception(StatuteException::new(
+    ///         "abroad",
+    ///         "Residents abroad are excluded",
+    ///         Condition::has_attribute("abroad"),
+    ///     ));
+    ///
+    /// let entity = BasicEntity::new();
+    /// let date = NaiveDate::from_ymd_opt(2031, 1, 1).unwrap();
+    /// let result = SimEngine::apply_law_at(&entity, &law, date);
+    /// assert_eq!(result.reason, ApplicationReason::NotInForce { date });
+    /// ```
+    pub fn apply_law_in_context<C: EvaluationContext>(
+        context: &C,
+        agent_id: Uuid,
+        law: &Statute,
+    ) -> LawApplicationResult {
+        let (result, outputs, reason) = Self::evaluate_law(context, agent_id, law);
+        LawApplicationResult {
+            agent_id,
+            statute_id: law.id.clone(),
+            result,
+            outputs,
+            reason,
+            evaluation_date: context.get_current_date(),
         }
+    }
 
-        // All conditions met, apply effect
-        LegalResult::Deterministic(law.effect.clone())
-    }
-
-    fn evaluate_condition(agent: &dyn LegalEntity, condition: &Condition) -> ConditionResult {
-        match condition {
-            Condition::Age { operator, value } => {
-                match agent
-                    .get_attribute("age")
-                    .and_then(|v| v.parse::<u32>().ok())
-                {
-                    Some(age) => {
-                        let result = match operator {
-                            ComparisonOp::Equal => age == *value,
-                            ComparisonOp::NotEqual => age != *value,
-                            ComparisonOp::GreaterThan => age > *value,
-                            ComparisonOp::GreaterOrEqual => age >= *value,
-                            ComparisonOp::LessThan => age < *value,
-                            ComparisonOp::LessOrEqual => age <= *value,
-                        };
-                        if result {
-                            ConditionResult::True
-                        } else {
-                            ConditionResult::False
-                        }
-                    }
-                    None => ConditionResult::Indeterminate(
-                        "Age attribute not found or invalid".to_string(),
-                    ),
-                }
-            }
-            Condition::Income { operator, value } => {
-                match agent
-                    .get_attribute("income")
-                    .and_then(|v| v.parse::<u64>().ok())
-                {
-                    Some(income) => {
-                        let result = match operator {
-                            ComparisonOp::Equal => income == *value,
-                            ComparisonOp::NotEqual => income != *value,
-                            ComparisonOp::GreaterThan => income > *value,
-                            ComparisonOp::GreaterOrEqual => income >= *value,
-                            ComparisonOp::LessThan => income < *value,
-                            ComparisonOp::LessOrEqual => income <= *value,
-                        };
-                        if result {
-                            ConditionResult::True
-                        } else {
-                            ConditionResult::False
-                        }
-                    }
-                    None => ConditionResult::Indeterminate(
-                        "Income attribute not found or invalid".to_string(),
-                    ),
-                }
-            }
-            Condition::HasAttribute { key } => {
-                if agent.get_attribute(key).is_some() {
-                    ConditionResult::True
-                } else {
-                    ConditionResult::False
-                }
-            }
-            Condition::AttributeEquals { key, value } => match agent.get_attribute(key) {
-                Some(attr_value) if attr_value == *value => ConditionResult::True,
-                Some(_) => ConditionResult::False,
-                None => ConditionResult::Indeterminate(format!("Attribute '{key}' not found")),
-            },
-            Condition::And(left, right) => {
-                match (
-                    Self::evaluate_condition(agent, left),
-                    Self::evaluate_condition(agent, right),
-                ) {
-                    (ConditionResult::True, ConditionResult::True) => ConditionResult::True,
-                    (ConditionResult::False, _) | (_, ConditionResult::False) => {
-                        ConditionResult::False
-                    }
-                    (ConditionResult::Indeterminate(r), _)
-                    | (_, ConditionResult::Indeterminate(r)) => ConditionResult::Indeterminate(r),
-                }
-            }
-            Condition::Or(left, right) => {
-                match (
-                    Self::evaluate_condition(agent, left),
-                    Self::evaluate_condition(agent, right),
-                ) {
-                    (ConditionResult::True, _) | (_, ConditionResult::True) => {
-                        ConditionResult::True
-                    }
-                    (ConditionResult::False, ConditionResult::False) => ConditionResult::False,
-                    (ConditionResult::Indeterminate(r), _)
-                    | (_, ConditionResult::Indeterminate(r)) => ConditionResult::Indeterminate(r),
-                }
-            }
-            Condition::Not(inner) => match Self::evaluate_condition(agent, inner) {
-                ConditionResult::True => ConditionResult::False,
-                ConditionResult::False => ConditionResult::True,
-                ConditionResult::Indeterminate(r) => ConditionResult::Indeterminate(r),
-            },
-            Condition::Custom { description } => ConditionResult::Indeterminate(format!(
-                "Custom condition requires evaluation: {description}"
-            )),
-            Condition::DateRange { start, end } => {
-                match agent
-                    .get_attribute("current_date")
-                    .and_then(|v| chrono::NaiveDate::parse_from_str(&v, "%Y-%m-%d").ok())
-                {
-                    Some(date) => {
-                        let after_start = start.is_none_or(|s| date >= s);
-                        let before_end = end.is_none_or(|e| date <= e);
-                        if after_start && before_end {
-                            ConditionResult::True
-                        } else {
-                            ConditionResult::False
-                        }
-                    }
-                    None => ConditionResult::Indeterminate(
-                        "Date attribute not found or invalid".to_string(),
-                    ),
-                }
-            }
-            Condition::Geographic { region_id, .. } => match agent.get_attribute("region") {
-                Some(agent_region) if agent_region == *region_id => ConditionResult::True,
-                Some(_) => ConditionResult::False,
-                None => ConditionResult::Indeterminate("Region attribute not found".to_string()),
-            },
-            Condition::EntityRelationship {
-                relationship_type,
-                target_entity_id,
-            } => {
-                let rel_key = format!("relationship_{:?}", relationship_type).to_lowercase();
-                match agent.get_attribute(&rel_key) {
-                    Some(rel_value) => match target_entity_id {
-                        Some(target) if rel_value == *target => ConditionResult::True,
-                        Some(_) => ConditionResult::False,
-                        None => ConditionResult::True,
-                    },
-                    None => ConditionResult::Indeterminate(format!(
-                        "Relationship {:?} not found",
-                        relationship_type
-                    )),
-                }
-            }
-            Condition::ResidencyDuration { operator, months } => {
-                match agent
-                    .get_attribute("residency_months")
-                    .and_then(|v| v.parse::<u32>().ok())
-                {
-                    Some(residency) => {
-                        let result = match operator {
-                            ComparisonOp::Equal => residency == *months,
-                            ComparisonOp::NotEqual => residency != *months,
-                            ComparisonOp::GreaterThan => residency > *months,
-                            ComparisonOp::GreaterOrEqual => residency >= *months,
-                            ComparisonOp::LessThan => residency < *months,
-                            ComparisonOp::LessOrEqual => residency <= *months,
-                        };
-                        if result {
-                            ConditionResult::True
-                        } else {
-                            ConditionResult::False
-                        }
-                    }
-                    None => ConditionResult::Indeterminate(
-                        "Residency duration not found or invalid".to_string(),
-                    ),
-                }
-            }
-            Condition::Duration {
-                operator,
-                value,
-                unit,
-            } => {
-                // Convert unit to attribute key (e.g., "duration_days", "duration_months")
-                let attr_key = match unit {
-                    legalis_core::DurationUnit::Days => "duration_days",
-                    legalis_core::DurationUnit::Weeks => "duration_weeks",
-                    legalis_core::DurationUnit::Months => "duration_months",
-                    legalis_core::DurationUnit::Years => "duration_years",
+    fn evaluate_law<C: EvaluationContext>(
+        context: &C,
+        agent_id: Uuid,
+        law: &Statute,
+    ) -> (
+        LegalResult<Effect>,
+        BTreeMap<String, OutputValue>,
+        ApplicationReason,
+    ) {
+        let validity = &law.temporal_validity;
+        match context.get_current_date() {
+            Some(date) if !law.is_active(date) => {
+                let reason = ApplicationReason::NotInForce { date };
+                let result = LegalResult::Void {
+                    reason: reason.to_string(),
                 };
-                match agent
-                    .get_attribute(attr_key)
-                    .and_then(|v| v.parse::<u32>().ok())
-                {
-                    Some(duration) => {
-                        let result = match operator {
-                            ComparisonOp::Equal => duration == *value,
-                            ComparisonOp::NotEqual => duration != *value,
-                            ComparisonOp::GreaterThan => duration > *value,
-                            ComparisonOp::GreaterOrEqual => duration >= *value,
-                            ComparisonOp::LessThan => duration < *value,
-                            ComparisonOp::LessOrEqual => duration <= *value,
-                        };
-                        if result {
-                            ConditionResult::True
-                        } else {
-                            ConditionResult::False
-                        }
-                    }
-                    None => ConditionResult::Indeterminate(format!(
-                        "Duration attribute '{}' not found or invalid",
-                        attr_key
-                    )),
-                }
-            }
-            Condition::Percentage {
-                operator,
-                value,
-                context,
-            } => {
-                // Use context as attribute key (e.g., "ownership_percentage")
-                let attr_key = format!("{}_percentage", context);
-                match agent
-                    .get_attribute(&attr_key)
-                    .and_then(|v| v.parse::<u32>().ok())
-                {
-                    Some(percentage) => {
-                        let result = match operator {
-                            ComparisonOp::Equal => percentage == *value,
-                            ComparisonOp::NotEqual => percentage != *value,
-                            ComparisonOp::GreaterThan => percentage > *value,
-                            ComparisonOp::GreaterOrEqual => percentage >= *value,
-                            ComparisonOp::LessThan => percentage < *value,
-                            ComparisonOp::LessOrEqual => percentage <= *value,
-                        };
-                        if result {
-                            ConditionResult::True
-                        } else {
-                            ConditionResult::False
-                        }
-                    }
-                    None => ConditionResult::Indeterminate(format!(
-                        "Percentage attribute '{}' not found or invalid",
-                        attr_key
-                    )),
-                }
-            }
-            Condition::SetMembership {
-                attribute,
-                values,
-                negated,
-            } => match agent.get_attribute(attribute) {
-                Some(attr_value) => {
-                    let is_member = values.iter().any(|v| v == &attr_value);
-                    let result = if *negated { !is_member } else { is_member };
-                    if result {
-                        ConditionResult::True
-                    } else {
-                        ConditionResult::False
-                    }
-                }
-                None => ConditionResult::Indeterminate(format!(
-                    "Attribute '{}' not found for set membership check",
-                    attribute
-                )),
-            },
-            Condition::Pattern {
-                attribute,
-                pattern,
-                negated,
-            } => match agent.get_attribute(attribute) {
-                Some(attr_value) => {
-                    // Simple pattern matching - check if pattern is contained in value
-                    // For full regex support, would need regex crate
-                    let matches = attr_value.contains(pattern);
-                    let result = if *negated { !matches } else { matches };
-                    if result {
-                        ConditionResult::True
-                    } else {
-                        ConditionResult::False
-                    }
-                }
-                None => ConditionResult::Indeterminate(format!(
-                    "Attribute '{}' not found for pattern check",
-                    attribute
-                )),
-            },
-            Condition::Calculation { formula, .. } => {
-                // Calculation conditions require external evaluation
-                ConditionResult::Indeterminate(format!(
-                    "Calculation condition requires evaluation: {formula}"
-                ))
+                return (result, BTreeMap::new(), reason);
             }
-            Condition::Composite {
-                conditions,
-                threshold,
-            } => {
-                // Evaluate all sub-conditions and sum weights of satisfied ones
-                let mut score = 0.0;
-                for (weight, condition) in conditions {
-                    match Self::evaluate_condition(agent, condition) {
-                        ConditionResult::True => score += weight,
-                        ConditionResult::False => {}
-                        ConditionResult::Indeterminate(_) => {
-                            // For composite, treat indeterminate as false
-                        }
-                    }
-                }
-                if score >= *threshold {
-                    ConditionResult::True
-                } else {
-                    ConditionResult::False
-                }
+            None if validity.has_effective_date() || validity.has_expiry_date() => {
+                let issue = format!(
+                    "No evaluation date to check whether '{}' is in force",
+                    law.id
+                );
+                let reason = ApplicationReason::Discretion {
+                    issue: issue.clone(),
+                };
+                let result = LegalResult::JudicialDiscretion {
+                    issue,
+                    context_id: agent_id,
+                    narrative_hint: law.discretion_logic.clone(),
+                };
+                return (result, BTreeMap::new(), reason);
             }
-            Condition::Threshold {
-                attributes,
-                operator,
-                value,
-            } => {
-                // Sum all attribute values (with multipliers)
-                let mut total = 0.0;
-                let mut has_error = false;
-                for (attr_name, multiplier) in attributes {
-                    match agent
-                        .get_attribute(attr_name)
-                        .and_then(|v| v.parse::<f64>().ok())
-                    {
-                        Some(attr_value) => total += attr_value * multiplier,
-                        None => {
-                            has_error = true;
-                            break;
-                        }
-                    }
-                }
-                if has_error {
-                    ConditionResult::Indeterminate("One or more attributes not found".to_string())
-                } else {
-                    let result = match operator {
-                        ComparisonOp::Equal => (total - value).abs() < 1e-6,
-                        ComparisonOp::NotEqual => (total - value).abs() >= 1e-6,
-                        ComparisonOp::GreaterThan => total > *value,
-                        ComparisonOp::GreaterOrEqual => total >= *value,
-                        ComparisonOp::LessThan => total < *value,
-                        ComparisonOp::LessOrEqual => total <= *value,
+            _ => {}
+        }
+
+        let applied = law.apply_in_context(context, agent_id);
+        if let LegalResult::Void { .. } = applied {
+            let result = applied.map(|computed| computed.effect);
+            let reason = ApplicationReason::from_result(&result);
+            return (result, BTreeMap::new(), reason);
+        }
+
+        // The law would apply (or needs review): an exception defeats it
+        for exception in &law.exceptions {
+            match exception.condition.evaluate(context) {
+                Ok(true) => {
+                    let reason = ApplicationReason::ExceptionApplied {
+                        exception_id: exception.id.clone(),
+                        description: exception.description.clone(),
                     };
-                    if result {
-                        ConditionResult::True
-                    } else {
-                        ConditionResult::False
-                    }
+                    let result = LegalResult::Void {
+                        reason: reason.to_string(),
+                    };
+                    return (result, BTreeMap::new(), reason);
                 }
-            }
-            Condition::Fuzzy {
-                attribute,
-                membership_points,
-                min_membership,
-            } => {
-                match agent
-                    .get_attribute(attribute)
-                    .and_then(|v| v.parse::<f64>().ok())
-                {
-                    Some(value) => {
-                        // Linear interpolation between membership points
-                        let membership = if membership_points.is_empty() {
-                            0.0
-                        } else if membership_points.len() == 1 {
-                            if (value - membership_points[0].0).abs() < 1e-6 {
-                                membership_points[0].1
-                            } else {
-                                0.0
-                            }
-                        } else {
-                            // Find surrounding points
-                            let mut lower = None;
-                            let mut upper = None;
-                            for point in membership_points {
-                                if point.0 <= value {
-                                    lower = Some(point);
-                                }
-                                if point.0 >= value && upper.is_none() {
-                                    upper = Some(point);
-                                }
-                            }
-                            match (lower, upper) {
-                                (Some(l), Some(u)) if (l.0 - u.0).abs() < 1e-6 => l.1,
-                                (Some(l), Some(u)) => {
-                                    // Linear interpolation
-                                    let t = (value - l.0) / (u.0 - l.0);
-                                    l.1 + t * (u.1 - l.1)
-                                }
-                                (Some(l), None) => l.1,
-                                (None, Some(u)) => u.1,
-                                (None, None) => 0.0,
-                            }
-                        };
-                        if membership >= *min_membership {
-                            ConditionResult::True
-                        } else {
-                            ConditionResult::False
-                        }
-                    }
-                    None => ConditionResult::Indeterminate(format!(
-                        "Attribute '{}' not found for fuzzy evaluation",
-                        attribute
-                    )),
+                Ok(false) => {}
+                Err(e) => {
+                    let issue = format!("Cannot evaluate exception '{}': {}", exception.id, e);
+                    let reason = ApplicationReason::Discretion {
+                        issue: issue.clone(),
+                    };
+                    let result = LegalResult::JudicialDiscretion {
+                        issue,
+                        context_id: agent_id,
+                        narrative_hint: law.discretion_logic.clone(),
+                    };
+                    return (result, BTreeMap::new(), reason);
                 }
             }
-            Condition::Probabilistic {
-                condition,
-                probability: _,
-                threshold: _,
-            } => {
-                // For simulation, evaluate the base condition deterministically
-                // A full implementation would use probability and threshold for uncertain evaluation
-                Self::evaluate_condition(agent, condition)
-            }
-            Condition::Temporal {
-                base_value,
-                reference_time,
-                rate,
-                operator,
-                target_value,
-            } => {
-                // Get current time from agent attributes
-                match agent
-                    .get_attribute("current_timestamp")
-                    .and_then(|v| v.parse::<i64>().ok())
-                {
-                    Some(current_time) => {
-                        let time_elapsed = (current_time - reference_time) as f64;
-                        let current_value = base_value * (1.0 + rate).powf(time_elapsed);
-                        let result = match operator {
-                            ComparisonOp::Equal => (current_value - target_value).abs() < 1e-6,
-                            ComparisonOp::NotEqual => (current_value - target_value).abs() >= 1e-6,
-                            ComparisonOp::GreaterThan => current_value > *target_value,
-                            ComparisonOp::GreaterOrEqual => current_value >= *target_value,
-                            ComparisonOp::LessThan => current_value < *target_value,
-                            ComparisonOp::LessOrEqual => current_value <= *target_value,
-                        };
-                        if result {
-                            ConditionResult::True
-                        } else {
-                            ConditionResult::False
-                        }
-                    }
-                    None => ConditionResult::Indeterminate(
-                        "Current timestamp not found for temporal evaluation".to_string(),
-                    ),
-                }
+        }
+
+        match applied {
+            LegalResult::Deterministic(computed) => (
+                LegalResult::Deterministic(computed.effect),
+                computed.outputs,
+                ApplicationReason::Applied,
+            ),
+            other => {
+                let result = other.map(|computed| computed.effect);
+                let reason = ApplicationReason::from_result(&result);
+                (result, BTreeMap::new(), reason)
             }
         }
     }
 }
 
-/// Result of evaluating a condition.
-#[derive(Debug, Clone)]
-enum ConditionResult {
-    True,
-    False,
-    Indeterminate(String),
-}
-
 /// Relationship-based condition evaluator.
+///
+/// Besides direct relationship queries, it evaluates quantified conditions
+/// ([`Condition::ForAll`], [`Condition::Exists`], [`Condition::Count`] and
+/// [`Condition::Sum`]) by following graph edges to the registered entities.
 pub struct RelationshipConditions {
     graph: crate::relationships::RelationshipGraph,
+    entities: HashMap<Uuid, Arc<dyn LegalEntity>>,
 }
 
 impl RelationshipConditions {
     /// Creates a new relationship condition evaluator.
     pub fn new(graph: crate::relationships::RelationshipGraph) -> Self {
-        Self { graph }
+        Self {
+            graph,
+            entities: HashMap::new(),
+        }
+    }
+
+    /// Registers the entities that graph edges may point to.
+    pub fn with_entities(
+        mut self,
+        entities: impl IntoIterator<Item = Arc<dyn LegalEntity>>,
+    ) -> Self {
+        self.entities
+            .extend(entities.into_iter().map(|entity| (entity.id(), entity)));
+        self
+    }
+
+    /// Returns the underlying relationship graph.
+    pub fn graph(&self) -> &crate::relationships::RelationshipGraph {
+        &self.graph
+    }
+
+    /// Creates an evaluation context for an entity that resolves related
+    /// entities through the graph.
+    pub fn context<'a>(&'a self, entity: &'a dyn LegalEntity) -> RelationshipContext<'a> {
+        RelationshipContext {
+            entity: EntityContext::new(entity),
+            conditions: self,
+        }
+    }
+
+    /// Evaluates a condition, including quantified conditions, for an entity.
+    pub fn evaluate(
+        &self,
+        entity: &dyn LegalEntity,
+        condition: &Condition,
+    ) -> Result<bool, EvaluationError> {
+        condition.evaluate(&self.context(entity))
+    }
+
+    /// Counts related entities of a given type that satisfy a condition.
+    pub fn count_matching(
+        &self,
+        entity_id: uuid::Uuid,
+        rel_type: crate::relationships::RelationshipType,
+        condition: &Condition,
+    ) -> Result<usize, EvaluationError> {
+        let mut count = 0;
+        for related in self.related_entities(entity_id, rel_type) {
+            if self.evaluate(related.as_ref(), condition)? {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Returns the registered entities related to an entity by a given type.
+    pub fn related_entities(
+        &self,
+        entity_id: uuid::Uuid,
+        rel_type: crate::relationships::RelationshipType,
+    ) -> Vec<&Arc<dyn LegalEntity>> {
+        self.graph
+            .get_relationships(entity_id, rel_type)
+            .iter()
+            .filter_map(|id| self.entities.get(id))
+            .collect()
     }
 
     /// Checks if an entity has a specific relationship type.
@@ -631,6 +482,78 @@ impl RelationshipConditions {
     }
 }
 
+/// Evaluation context for an entity within a [`RelationshipConditions`] graph.
+pub struct RelationshipContext<'a> {
+    entity: EntityContext<'a>,
+    conditions: &'a RelationshipConditions,
+}
+
+impl EvaluationContext for RelationshipContext<'_> {
+    fn get_attribute(&self, key: &str) -> Option<String> {
+        self.entity.get_attribute(key)
+    }
+
+    fn get_age(&self) -> Option<u32> {
+        self.entity.get_age()
+    }
+
+    fn get_income(&self) -> Option<u64> {
+        self.entity.get_income()
+    }
+
+    fn get_current_date(&self) -> Option<chrono::NaiveDate> {
+        self.entity.get_current_date()
+    }
+
+    fn check_geographic(&self, region_type: legalis_core::RegionType, region_id: &str) -> bool {
+        self.entity.check_geographic(region_type, region_id)
+    }
+
+    fn check_relationship(
+        &self,
+        relationship_type: legalis_core::RelationshipType,
+        target_id: Option<&str>,
+    ) -> bool {
+        let related = self
+            .conditions
+            .graph
+            .get_relationships(self.entity.entity().id(), relationship_type.into());
+        let in_graph = match target_id {
+            Some(target) => related.iter().any(|id| id.to_string() == target),
+            None => !related.is_empty(),
+        };
+        in_graph || self.entity.check_relationship(relationship_type, target_id)
+    }
+
+    fn get_residency_months(&self) -> Option<u32> {
+        self.entity.get_residency_months()
+    }
+
+    fn get_duration(&self, unit: legalis_core::DurationUnit) -> Option<u32> {
+        self.entity.get_duration(unit)
+    }
+
+    fn get_percentage(&self, context: &str) -> Option<u32> {
+        self.entity.get_percentage(context)
+    }
+
+    fn related_entities(
+        &self,
+        relationship_type: legalis_core::RelationshipType,
+    ) -> Option<Vec<Box<dyn EvaluationContext + '_>>> {
+        Some(
+            self.conditions
+                .related_entities(self.entity.entity().id(), relationship_type.into())
+                .into_iter()
+                .map(|related| {
+                    Box::new(self.conditions.context(related.as_ref()))
+                        as Box<dyn EvaluationContext>
+                })
+                .collect(),
+        )
+    }
+}
+
 /// Contract-based condition evaluator.
 pub struct ContractConditions {
     registry: crate::relationships::ContractRegistry,
@@ -740,7 +663,7 @@ fn rand_simple() -> f64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use legalis_core::{Effect, EffectType};
+    use legalis_core::{ComparisonOp, Effect, EffectType, StatuteException, TemporalValidity};
 
     #[tokio::test]
     async fn test_sim_engine_basic() {
@@ -785,6 +708,243 @@ mod tests {
         assert!(result.is_deterministic());
     }
 
+    #[test]
+    fn test_apply_law_with_outputs() {
+        let mut entity = BasicEntity::new();
+        entity.set_attribute("income", "2000000".to_string());
+
+        let statute = Statute::new(
+            "tax",
+            "Income tax",
+            Effect::new(EffectType::MonetaryTransfer, "Income tax").with_output(
+                legalis_core::EffectOutput::money("tax", "JPY", "income * 0.1"),
+            ),
+        );
+
+        let result = SimEngine::apply_law_with_outputs(&entity, &statute);
+        assert!(result.result.is_deterministic());
+        assert_eq!(result.outputs["tax"].as_f64(), Some(200000.0));
+
+        // Missing inputs downgrade the result to discretion
+        let result = SimEngine::apply_law_with_outputs(&BasicEntity::new(), &statute);
+        assert!(result.result.requires_discretion());
+        assert!(result.outputs.is_empty());
+    }
+
+    #[test]
+    fn test_apply_law_temporal_validity_and_exceptions() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let statute = Statute::new(
+            "housing-relief",
+            "Housing relief",
+            Effect::new(EffectType::Grant, "Relief"),
+        )
+        .with_precondition(Condition::income(ComparisonOp::LessThan, 30000))
+        .with_temporal_validity(
+            TemporalValidity::new()
+                .with_effective_date(date(2024, 4, 1))
+                .with_expiry_date(date(2027, 3, 31)),
+        )
+        .with_exception(StatuteException::new(
+            "owner",
+            "Home owners are excluded",
+            Condition::attribute_equals("tenure", "owner"),
+        ))
+        .with_exception(StatuteException::new(
+            "minor",
+            "Minors are excluded",
+            Condition::age(ComparisonOp::LessThan, 18),
+        ));
+
+        let household = |tenure: &str| {
+            let mut entity = BasicEntity::new();
+            entity.set_attribute("income", "20000".to_string());
+            entity.set_attribute("age", "40".to_string());
+            entity.set_attribute("tenure", tenure.to_string());
+            entity
+        };
+        let tenant = household("tenant");
+        let owner = household("owner");
+
+        let applied = SimEngine::apply_law_at(&tenant, &statute, date(2025, 1, 1));
+        assert!(applied.result.is_deterministic());
+        assert_eq!(applied.reason, ApplicationReason::Applied);
+        assert_eq!(applied.evaluation_date, Some(date(2025, 1, 1)));
+
+        // Without a date the validity period cannot be checked
+        let undated = SimEngine::apply_law_with_outputs(&tenant, &statute);
+        assert!(undated.result.requires_discretion());
+        assert!(undated.reason.to_string().contains("No evaluation date"));
+        assert_eq!(undated.evaluation_date, None);
+
+        // Sunset clause: void after expiry, before the effective date too
+        for day in [date(2027, 4, 1), date(2024, 3, 31)] {
+            let expired = SimEngine::apply_law_at(&tenant, &statute, day);
+            assert!(expired.result.is_void());
+            assert_eq!(expired.reason, ApplicationReason::NotInForce { date: day });
+        }
+
+        let excluded = SimEngine::apply_law_at(&owner, &statute, date(2025, 1, 1));
+        assert!(excluded.result.is_void());
+        assert_eq!(
+            excluded.reason,
+            ApplicationReason::ExceptionApplied {
+                exception_id: "owner".to_string(),
+                description: "Home owners are excluded".to_string(),
+            }
+        );
+
+        // A failed precondition wins over an undecidable exception
+        let mut rich = BasicEntity::new();
+        rich.set_attribute("income", "90000".to_string());
+        let void = SimEngine::apply_law_at(&rich, &statute, date(2025, 1, 1));
+        assert!(matches!(
+            void.reason,
+            ApplicationReason::PreconditionNotMet { .. }
+        ));
+
+        // An exception that cannot be evaluated (no age) needs review
+        let mut unknown = BasicEntity::new();
+        unknown.set_attribute("income", "20000".to_string());
+        let review = SimEngine::apply_law_at(&unknown, &statute, date(2025, 1, 1));
+        assert!(review.result.requires_discretion());
+        assert!(review.reason.to_string().contains("exception 'minor'"));
+
+        // The core evaluator handles calculations the old evaluator left undecided
+        let calc =
+            Statute::new("calc", "Calc", Effect::new(EffectType::Grant, "Calc")).with_precondition(
+                Condition::calculation("income * 2", ComparisonOp::GreaterThan, 30000.0),
+            );
+        assert!(SimEngine::apply_law(&tenant, &calc).is_deterministic());
+    }
+
+    #[tokio::test]
+    async fn test_simulation_date_and_reason_metrics() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let sunset = Statute::new("sunset", "Sunset", Effect::new(EffectType::Grant, "Sunset"))
+            .with_temporal_validity(TemporalValidity::new().with_expiry_date(date(2025, 12, 31)));
+        let excepted = Statute::new("excepted", "Excepted", Effect::new(EffectType::Grant, "X"))
+            .with_exception(StatuteException::new(
+                "minor",
+                "Minors are excluded",
+                Condition::age(ComparisonOp::LessThan, 18),
+            ));
+
+        let population: Vec<Box<dyn LegalEntity>> = [25, 15]
+            .into_iter()
+            .map(|age| {
+                let mut entity = BasicEntity::new();
+                entity.set_attribute("age", age.to_string());
+                Box::new(entity) as Box<dyn LegalEntity>
+            })
+            .collect();
+
+        let engine = SimEngine::new(vec![sunset, excepted], population)
+            .with_simulation_date(date(2026, 6, 1));
+        assert_eq!(engine.simulation_date(), Some(date(2026, 6, 1)));
+        let metrics = engine.run_simulation().await;
+
+        assert_eq!(metrics.deterministic_count, 1);
+        assert_eq!(metrics.void_count, 3);
+        assert_eq!(metrics.statute_metrics["sunset"].not_in_force, 2);
+        assert_eq!(metrics.statute_metrics["excepted"].exceptions, 1);
+        assert!(metrics.summary().contains("void by exception: 1"));
+    }
+
+    #[test]
+    fn test_relationship_quantified_conditions() {
+        use crate::relationships::{Relationship, RelationshipGraph, RelationshipType};
+        use legalis_core::RelationshipType as CoreRelationship;
+
+        let person = |age: u32, income: u64| {
+            let mut entity = BasicEntity::new();
+            entity.set_attribute("age", age.to_string());
+            entity.set_attribute("income", income.to_string());
+            Arc::new(entity) as Arc<dyn LegalEntity>
+        };
+        let parent = person(40, 3_000_000);
+        let child = person(10, 0);
+        let teen = person(17, 500_000);
+        let grandchild = person(1, 0);
+
+        let mut graph = RelationshipGraph::new();
+        for (from, to) in [(&parent, &child), (&parent, &teen), (&teen, &grandchild)] {
+            graph.add_relationship(Relationship::new(
+                from.id(),
+                to.id(),
+                RelationshipType::Parent,
+            ));
+        }
+        graph.add_relationship(Relationship::new(
+            parent.id(),
+            teen.id(),
+            RelationshipType::HouseholdMember,
+        ));
+
+        let conditions = RelationshipConditions::new(graph).with_entities(vec![
+            parent.clone(),
+            child.clone(),
+            teen.clone(),
+            grandchild.clone(),
+        ]);
+
+        let any_minor = Condition::exists(
+            CoreRelationship::ParentChild,
+            Condition::age(ComparisonOp::LessThan, 18),
+        );
+        assert!(conditions.evaluate(parent.as_ref(), &any_minor).unwrap());
+        assert!(!conditions.evaluate(child.as_ref(), &any_minor).unwrap());
+
+        let all_under_12 = Condition::for_all(
+            CoreRelationship::ParentChild,
+            Condition::age(ComparisonOp::LessThan, 12),
+        );
+        assert!(!conditions.evaluate(parent.as_ref(), &all_under_12).unwrap());
+
+        // Nested: a grandchild exists
+        let grandparent = Condition::exists(
+            CoreRelationship::ParentChild,
+            Condition::count(
+                CoreRelationship::ParentChild,
+                ComparisonOp::GreaterOrEqual,
+                1,
+            ),
+        );
+        assert!(conditions.evaluate(parent.as_ref(), &grandparent).unwrap());
+
+        let household_income = Condition::sum(
+            CoreRelationship::HouseholdMember,
+            "income",
+            ComparisonOp::LessThan,
+            1_000_000.0,
+        );
+        assert!(
+            conditions
+                .evaluate(parent.as_ref(), &household_income)
+                .unwrap()
+        );
+        // Household membership is symmetric
+        assert!(
+            conditions
+                .evaluate(
+                    teen.as_ref(),
+                    &Condition::count(CoreRelationship::HouseholdMember, ComparisonOp::Equal, 1)
+                )
+                .unwrap()
+        );
+
+        assert_eq!(
+            conditions
+                .count_matching(
+                    parent.id(),
+                    RelationshipType::Parent,
+                    &Condition::income(ComparisonOp::GreaterThan, 0)
+                )
+                .unwrap(),
+            1
+        );
+    }
+
     #[test]
     fn test_population_builder() {
         let population = PopulationBuilder::new().generate_random(100).build();
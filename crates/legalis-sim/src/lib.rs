@@ -31,6 +31,7 @@
 //! ## Performance & Scalability
 //! - Batch processing for large populations
 //! - Memory-efficient streaming mode
+//! - Chunked streaming simulation with checkpoint/resume for national-scale populations
 //! - Entity pooling and recycling
 //! - Lazy attribute evaluation
 //! - Optimized work distribution across threads
@@ -202,6 +203,7 @@ mod realtime;
 mod relationships;
 mod risk;
 mod scenarios;
+mod streaming;
 mod stress_tests;
 mod synthetic_data;
 mod temporal;
@@ -248,6 +250,7 @@ pub use realtime::*;
 pub use relationships::*;
 pub use risk::*;
 pub use scenarios::*;
+pub use streaming::*;
 pub use synthetic_data::*;
 pub use temporal::*;
 pub use urban::*;
@@ -1,9 +1,9 @@
 //! Simulation metrics collection and reporting.
 
-use crate::engine::LawApplicationResult;
-use legalis_core::LegalResult;
+use crate::engine::{ApplicationReason, LawApplicationResult};
+use legalis_core::{Decimal, LegalResult, OutputValue};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use uuid::Uuid;
 
 /// Metrics collected during simulation.
@@ -44,6 +44,9 @@ impl SimulationMetrics {
             LegalResult::Deterministic(_) => {
                 self.deterministic_count += 1;
                 statute_metrics.deterministic += 1;
+                for (name, value) in &result.outputs {
+                    statute_metrics.record_output(name, value);
+                }
             }
             LegalResult::JudicialDiscretion { .. } => {
                 self.discretion_count += 1;
@@ -53,6 +56,11 @@ impl SimulationMetrics {
             LegalResult::Void { .. } => {
                 self.void_count += 1;
                 statute_metrics.void += 1;
+                match result.reason {
+                    ApplicationReason::ExceptionApplied { .. } => statute_metrics.exceptions += 1,
+                    ApplicationReason::NotInForce { .. } => statute_metrics.not_in_force += 1,
+                    _ => {}
+                }
             }
         }
     }
@@ -75,6 +83,19 @@ impl SimulationMetrics {
         }
     }
 
+    /// Returns the sum of a computed output across all statutes.
+    ///
+    /// Only outputs with the given unit (currency or duration unit) are summed,
+    /// so totals in different currencies are never mixed. Returns `None` if
+    /// the sum overflows.
+    pub fn output_total(&self, output: &str, unit: Option<&str>) -> Option<Decimal> {
+        self.statute_metrics
+            .values()
+            .filter_map(|m| m.output_totals.get(output))
+            .filter(|t| t.unit.as_deref() == unit)
+            .try_fold(Decimal::ZERO, |sum, t| sum.checked_add(&t.total))
+    }
+
     /// Generates a summary report.
     pub fn summary(&self) -> String {
         let mut report = String::new();
@@ -101,6 +122,15 @@ impl SimulationMetrics {
                 "{}: D={} / J={} / V={}\n",
                 statute_id, metrics.deterministic, metrics.discretion, metrics.void
             ));
+            if metrics.exceptions > 0 || metrics.not_in_force > 0 {
+                report.push_str(&format!(
+                    "  void by exception: {}, not in force: {}\n",
+                    metrics.exceptions, metrics.not_in_force
+                ));
+            }
+            for (name, total) in &metrics.output_totals {
+                report.push_str(&format!("  {}: {}\n", name, total));
+            }
         }
 
         report
@@ -118,6 +148,15 @@ pub struct StatuteMetrics {
     pub discretion: usize,
     /// Void outcomes
     pub void: usize,
+    /// Void outcomes caused by an exception
+    #[serde(default)]
+    pub exceptions: usize,
+    /// Void outcomes because the statute was not in force
+    #[serde(default)]
+    pub not_in_force: usize,
+    /// Totals of numeric computed outputs, keyed by output name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub output_totals: BTreeMap<String, OutputTotal>,
 }
 
 impl StatuteMetrics {
@@ -138,6 +177,68 @@ impl StatuteMetrics {
             self.discretion as f64 / self.total as f64
         }
     }
+
+    /// Adds a computed output value to the running totals.
+    ///
+    /// Values are summed exactly; values without a numeric form (dates, enum
+    /// variants) or that would overflow the total are ignored.
+    pub fn record_output(&mut self, name: &str, value: &OutputValue) {
+        if let Some(amount) = value.as_decimal() {
+            let total = self
+                .output_totals
+                .entry(name.to_string())
+                .or_insert_with(|| OutputTotal {
+                    unit: value.unit(),
+                    ..OutputTotal::default()
+                });
+            if let Some(sum) = total.total.checked_add(&amount) {
+                total.total = sum;
+                total.count += 1;
+            }
+        }
+    }
+}
+
+/// Running total of a computed effect output.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OutputTotal {
+    /// Currency code or duration unit, if the output has one
+    pub unit: Option<String>,
+    /// Exact sum of all computed values
+    pub total: Decimal,
+    /// Number of values summed
+    pub count: usize,
+}
+
+impl OutputTotal {
+    /// Returns the mean value per application.
+    pub fn mean(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.total.to_f64() / self.count as f64
+        }
+    }
+
+    /// Merges another total into this one.
+    pub fn merge(&mut self, other: &OutputTotal) {
+        if self.unit.is_none() {
+            self.unit.clone_from(&other.unit);
+        }
+        if let Some(sum) = self.total.checked_add(&other.total) {
+            self.total = sum;
+            self.count += other.count;
+        }
+    }
+}
+
+impl std::fmt::Display for OutputTotal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.unit {
+            Some(unit) => write!(f, "{} {} (n={})", self.total, unit, self.count),
+            None => write!(f, "{} (n={})", self.total, self.count),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -149,24 +250,21 @@ mod tests {
     fn test_metrics_recording() {
         let mut metrics = SimulationMetrics::new();
 
-        metrics.record_result(&LawApplicationResult {
-            agent_id: Uuid::new_v4(),
-            statute_id: "test-1".to_string(),
-            result: LegalResult::Deterministic(Effect::new(
-                legalis_core::EffectType::Grant,
-                "Test",
-            )),
-        });
-
-        metrics.record_result(&LawApplicationResult {
-            agent_id: Uuid::new_v4(),
-            statute_id: "test-1".to_string(),
-            result: LegalResult::JudicialDiscretion {
+        metrics.record_result(&LawApplicationResult::new(
+            Uuid::new_v4(),
+            "test-1".to_string(),
+            LegalResult::Deterministic(Effect::new(legalis_core::EffectType::Grant, "Test")),
+        ));
+
+        metrics.record_result(&LawApplicationResult::new(
+            Uuid::new_v4(),
+            "test-1".to_string(),
+            LegalResult::JudicialDiscretion {
                 issue: "Test issue".to_string(),
                 context_id: Uuid::new_v4(),
                 narrative_hint: None,
             },
-        });
+        ));
 
         assert_eq!(metrics.total_applications, 2);
         assert_eq!(metrics.deterministic_count, 1);
@@ -181,9 +279,47 @@ mod tests {
             deterministic: 80,
             discretion: 15,
             void: 5,
+            output_totals: BTreeMap::new(),
+            ..Default::default()
         };
 
         assert!((metrics.effectiveness() - 0.8).abs() < f64::EPSILON);
         assert!((metrics.ambiguity() - 0.15).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn test_output_totals() {
+        let mut metrics = SimulationMetrics::new();
+
+        for amount in ["1000.10", "2500.20"] {
+            let mut outputs = BTreeMap::new();
+            outputs.insert(
+                "amount".to_string(),
+                OutputValue::money(amount.parse().unwrap(), "JPY"),
+            );
+            metrics.record_result(&LawApplicationResult {
+                outputs,
+                ..LawApplicationResult::new(
+                    Uuid::new_v4(),
+                    "benefit",
+                    LegalResult::Deterministic(Effect::grant("Benefit")),
+                )
+            });
+        }
+
+        let total = &metrics.statute_metrics["benefit"].output_totals["amount"];
+        assert_eq!(total.count, 2);
+        // Exact: 1000.10 + 2500.20 is 3500.3000000000002 in binary floats
+        assert_eq!(total.total.to_string(), "3500.30");
+        assert!((total.mean() - 1750.15).abs() < f64::EPSILON);
+        assert_eq!(total.to_string(), "3500.30 JPY (n=2)");
+        assert_eq!(
+            metrics.output_total("amount", Some("JPY")),
+            "3500.30".parse().ok()
+        );
+        assert_eq!(
+            metrics.output_total("amount", Some("USD")),
+            Some(Decimal::ZERO)
+        );
+    }
 }
@@ -186,70 +186,85 @@ impl PopulationGenerator {
 
     /// Generates a population.
     pub fn generate(&self) -> Vec<BasicEntity> {
-        let mut population = Vec::with_capacity(self.size);
+        self.stream().collect()
+    }
 
-        for _ in 0..self.size {
-            let mut entity = BasicEntity::new();
+    /// Returns a lazy stream over the population.
+    ///
+    /// Entities are generated one at a time, so populations far larger than
+    /// available memory can be fed to [`crate::StreamingSimulation`].
+    pub fn stream(&self) -> PopulationStream<'_> {
+        PopulationStream {
+            generator: self,
+            remaining: self.size,
+        }
+    }
 
-            // Generate age
-            let mut age = self.profile.age_distribution.sample();
-            if self.enforce_constraints {
-                age = age.clamp(0.0, 120.0);
-            }
-            entity.set_attribute("age", (age as u32).to_string());
-
-            // Generate income with optional correlation to age
-            let base_income = self.profile.income_distribution.sample();
-            let income = if self.age_income_correlation > 0.0 {
-                // Adjust income based on age (peak earning years are 45-54)
-                let age_factor = if age < 25.0 {
-                    0.6 + (age - 18.0) / 35.0 // Ramp up from 18-25
-                } else if age < 45.0 {
-                    0.8 + (age - 25.0) / 50.0 // Continue ramping to peak
-                } else if age < 65.0 {
-                    1.0 // Peak earning years
-                } else {
-                    1.0 - (age - 65.0) / 100.0 // Decline after retirement
-                }
-                .max(0.3);
+    /// Returns the number of entities this generator produces.
+    pub fn size(&self) -> usize {
+        self.size
+    }
 
-                let correlation_factor =
-                    self.age_income_correlation * age_factor + (1.0 - self.age_income_correlation);
-                base_income * correlation_factor
-            } else {
-                base_income
-            };
+    /// Generates a single entity from the demographic profile.
+    pub fn generate_entity(&self) -> BasicEntity {
+        let mut entity = BasicEntity::new();
 
-            if self.enforce_constraints {
-                entity.set_attribute("income", (income.max(0.0) as u64).to_string());
+        // Generate age
+        let mut age = self.profile.age_distribution.sample();
+        if self.enforce_constraints {
+            age = age.clamp(0.0, 120.0);
+        }
+        entity.set_attribute("age", (age as u32).to_string());
+
+        // Generate income with optional correlation to age
+        let base_income = self.profile.income_distribution.sample();
+        let income = if self.age_income_correlation > 0.0 {
+            // Adjust income based on age (peak earning years are 45-54)
+            let age_factor = if age < 25.0 {
+                0.6 + (age - 18.0) / 35.0 // Ramp up from 18-25
+            } else if age < 45.0 {
+                0.8 + (age - 25.0) / 50.0 // Continue ramping to peak
+            } else if age < 65.0 {
+                1.0 // Peak earning years
             } else {
-                entity.set_attribute("income", (income as u64).to_string());
+                1.0 - (age - 65.0) / 100.0 // Decline after retirement
             }
+            .max(0.3);
 
-            // Assign region
-            if !self.profile.regions.is_empty() {
-                let region = self.select_region();
-                entity.set_attribute("region", region.id.clone());
-
-                // Adjust income based on region
-                if let Some(income_str) = entity.get_attribute("income")
-                    && let Ok(income) = income_str.parse::<u64>()
-                {
-                    let adjusted = ((income as f64) * region.income_multiplier).round() as u64;
-                    entity.set_attribute("income", adjusted.to_string());
-                }
-            }
+            let correlation_factor =
+                self.age_income_correlation * age_factor + (1.0 - self.age_income_correlation);
+            base_income * correlation_factor
+        } else {
+            base_income
+        };
 
-            // Generate custom attributes
-            for (attr_name, distribution) in &self.profile.custom_attributes {
-                let value = distribution.sample();
-                entity.set_attribute(attr_name, value.to_string());
+        if self.enforce_constraints {
+            entity.set_attribute("income", (income.max(0.0) as u64).to_string());
+        } else {
+            entity.set_attribute("income", (income as u64).to_string());
+        }
+
+        // Assign region
+        if !self.profile.regions.is_empty() {
+            let region = self.select_region();
+            entity.set_attribute("region", region.id.clone());
+
+            // Adjust income based on region
+            if let Some(income_str) = entity.get_attribute("income")
+                && let Ok(income) = income_str.parse::<u64>()
+            {
+                let adjusted = ((income as f64) * region.income_multiplier).round() as u64;
+                entity.set_attribute("income", adjusted.to_string());
             }
+        }
 
-            population.push(entity);
+        // Generate custom attributes
+        for (attr_name, distribution) in &self.profile.custom_attributes {
+            let value = distribution.sample();
+            entity.set_attribute(attr_name, value.to_string());
         }
 
-        population
+        entity
     }
 
     /// Selects a region based on proportions.
@@ -266,6 +281,43 @@ impl PopulationGenerator {
     }
 }
 
+/// Lazy iterator over a generated population.
+///
+/// Created by [`PopulationGenerator::stream`].
+pub struct PopulationStream<'a> {
+    generator: &'a PopulationGenerator,
+    remaining: usize,
+}
+
+impl PopulationStream<'_> {
+    /// Advances past up to `count` entities without generating them.
+    ///
+    /// Returns the number of entities skipped.
+    pub(crate) fn skip_ahead(&mut self, count: usize) -> usize {
+        let skipped = count.min(self.remaining);
+        self.remaining -= skipped;
+        skipped
+    }
+}
+
+impl Iterator for PopulationStream<'_> {
+    type Item = BasicEntity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(self.generator.generate_entity())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for PopulationStream<'_> {}
+
 /// Builder for creating populations with behavioral profiles.
 pub struct BehavioralPopulationBuilder {
     entities: Vec<BasicEntity>,
@@ -624,6 +676,29 @@ pub struct EntityRecord {
     pub attributes: HashMap<String, serde_json::Value>,
 }
 
+impl EntityRecord {
+    /// Converts the record into a [`BasicEntity`].
+    ///
+    /// A missing or unparsable ID results in a freshly generated one.
+    pub fn into_entity(self) -> BasicEntity {
+        let mut entity = match self.id.as_deref().map(uuid::Uuid::parse_str) {
+            Some(Ok(uuid)) => BasicEntity::with_id(uuid),
+            _ => BasicEntity::new(),
+        };
+
+        for (key, value) in self.attributes {
+            let string_value = match value {
+                serde_json::Value::String(s) => s,
+                serde_json::Value::Number(n) => n.to_string(),
+                serde_json::Value::Bool(b) => b.to_string(),
+                _ => value.to_string(),
+            };
+            entity.set_attribute(&key, string_value);
+        }
+        entity
+    }
+}
+
 /// Population importer for CSV and JSON files.
 pub struct PopulationImporter;
 
@@ -670,63 +745,47 @@ impl PopulationImporter {
         let mut records = Vec::new();
 
         for (line_num, line) in lines.enumerate() {
-            let values: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
-
-            if values.len() != headers.len() {
-                return Err(format!(
-                    "Line {}: expected {} columns, found {}",
-                    line_num + 2,
-                    headers.len(),
-                    values.len()
-                ));
-            }
+            records.push(Self::csv_record(&headers, line, line_num + 2)?);
+        }
 
-            let mut attributes = HashMap::new();
-            for (header, value) in headers.iter().zip(values.iter()) {
-                if !value.is_empty() {
-                    attributes.insert(header.clone(), serde_json::Value::String(value.to_string()));
-                }
-            }
+        Ok(Self::records_to_entities(records))
+    }
 
-            records.push(EntityRecord {
-                id: attributes
-                    .remove("id")
-                    .and_then(|v| v.as_str().map(String::from)),
-                attributes,
-            });
+    /// Parses one CSV data line into a record using the given headers.
+    pub(crate) fn csv_record(
+        headers: &[String],
+        line: &str,
+        line_number: usize,
+    ) -> Result<EntityRecord, String> {
+        let values: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+        if values.len() != headers.len() {
+            return Err(format!(
+                "Line {}: expected {} columns, found {}",
+                line_number,
+                headers.len(),
+                values.len()
+            ));
         }
 
-        Ok(Self::records_to_entities(records))
+        let mut attributes = HashMap::new();
+        for (header, value) in headers.iter().zip(values.iter()) {
+            if !value.is_empty() {
+                attributes.insert(header.clone(), serde_json::Value::String(value.to_string()));
+            }
+        }
+
+        Ok(EntityRecord {
+            id: attributes
+                .remove("id")
+                .and_then(|v| v.as_str().map(String::from)),
+            attributes,
+        })
     }
 
     /// Converts entity records to BasicEntity instances.
     fn records_to_entities(records: Vec<EntityRecord>) -> Vec<BasicEntity> {
-        records
-            .into_iter()
-            .map(|record| {
-                let entity = if let Some(id_str) = record.id {
-                    if let Ok(uuid) = uuid::Uuid::parse_str(&id_str) {
-                        BasicEntity::with_id(uuid)
-                    } else {
-                        BasicEntity::new()
-                    }
-                } else {
-                    BasicEntity::new()
-                };
-
-                let mut entity = entity;
-                for (key, value) in record.attributes {
-                    let string_value = match value {
-                        serde_json::Value::String(s) => s,
-                        serde_json::Value::Number(n) => n.to_string(),
-                        serde_json::Value::Bool(b) => b.to_string(),
-                        _ => value.to_string(),
-                    };
-                    entity.set_attribute(&key, string_value);
-                }
-                entity
-            })
-            .collect()
+        records.into_iter().map(EntityRecord::into_entity).collect()
     }
 
     /// Saves population to JSON string.
@@ -472,23 +472,23 @@ mod tests {
         let disc_count = 100 - det_count;
 
         for _ in 0..det_count {
-            metrics.record_result(&LawApplicationResult {
-                agent_id: Uuid::new_v4(),
-                statute_id: "test".to_string(),
-                result: LegalResult::Deterministic(Effect::new(EffectType::Grant, "Test")),
-            });
+            metrics.record_result(&LawApplicationResult::new(
+                Uuid::new_v4(),
+                "test".to_string(),
+                LegalResult::Deterministic(Effect::new(EffectType::Grant, "Test")),
+            ));
         }
 
         for _ in 0..disc_count {
-            metrics.record_result(&LawApplicationResult {
-                agent_id: Uuid::new_v4(),
-                statute_id: "test".to_string(),
-                result: LegalResult::JudicialDiscretion {
+            metrics.record_result(&LawApplicationResult::new(
+                Uuid::new_v4(),
+                "test".to_string(),
+                LegalResult::JudicialDiscretion {
                     issue: "Test".to_string(),
                     context_id: Uuid::new_v4(),
                     narrative_hint: None,
                 },
-            });
+            ));
         }
 
         metrics
@@ -41,6 +41,12 @@ pub enum RelationshipType {
     Creditor,
     /// Debtor-creditor relationship
     Debtor,
+    /// Member of the same household
+    HouseholdMember,
+    /// Company-director relationship
+    Director,
+    /// Director-company relationship
+    DirectorOf,
 }
 
 impl RelationshipType {
@@ -61,6 +67,9 @@ impl RelationshipType {
             RelationshipType::BusinessPartner => RelationshipType::BusinessPartner,
             RelationshipType::Creditor => RelationshipType::Debtor,
             RelationshipType::Debtor => RelationshipType::Creditor,
+            RelationshipType::HouseholdMember => RelationshipType::HouseholdMember,
+            RelationshipType::Director => RelationshipType::DirectorOf,
+            RelationshipType::DirectorOf => RelationshipType::Director,
         }
     }
 
@@ -72,10 +81,29 @@ impl RelationshipType {
                 | RelationshipType::Sibling
                 | RelationshipType::ContractParty
                 | RelationshipType::BusinessPartner
+                | RelationshipType::HouseholdMember
         )
     }
 }
 
+/// Maps the relationship types used in statute conditions onto graph edges,
+/// seen from the entity the condition is evaluated for (e.g. `ParentChild`
+/// selects the entity's children).
+impl From<legalis_core::RelationshipType> for RelationshipType {
+    fn from(relationship_type: legalis_core::RelationshipType) -> Self {
+        match relationship_type {
+            legalis_core::RelationshipType::ParentChild => RelationshipType::Parent,
+            legalis_core::RelationshipType::Spouse => RelationshipType::Spouse,
+            legalis_core::RelationshipType::Employment => RelationshipType::Employer,
+            legalis_core::RelationshipType::Guardian => RelationshipType::Guardian,
+            legalis_core::RelationshipType::BusinessOwner => RelationshipType::BusinessPartner,
+            legalis_core::RelationshipType::Contractual => RelationshipType::ContractParty,
+            legalis_core::RelationshipType::HouseholdMember => RelationshipType::HouseholdMember,
+            legalis_core::RelationshipType::Director => RelationshipType::Director,
+        }
+    }
+}
+
 /// A relationship between two entities.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Relationship {
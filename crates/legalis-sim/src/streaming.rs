@@ -0,0 +1,977 @@
+//! Streaming, bounded-memory simulation for very large populations.
+//!
+//! This module provides:
+//! - Pull-based entity sources (population generators, JSON-lines and CSV files)
+//! - Chunked execution over a fixed pool of worker threads
+//! - Incremental metrics reported as chunks complete
+//! - Periodic checkpoints and resume through [`CheckpointStore`]
+//!
+//! Memory use is bounded by `concurrency * chunk_size` entities regardless of
+//! population size: workers pull the next chunk from the shared source as soon
+//! as they finish the previous one, so faster workers naturally take on more of
+//! the population. At most `concurrency` chunks are in flight or waiting to be
+//! merged at any time, so a slow chunk holds the others back instead of letting
+//! finished results pile up.
+
+use crate::{
+    Checkpoint, CheckpointStore, EntityRecord, PopulationImporter, PopulationStream, SimEngine,
+    SimResult, SimulationError, SimulationMetrics,
+};
+use chrono::NaiveDate;
+use legalis_core::{LegalEntity, Statute};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, mpsc};
+
+/// Metadata key holding the number of entities covered by a checkpoint.
+const PROCESSED_KEY: &str = "stream.processed";
+/// Metadata key holding the number of chunks covered by a checkpoint, counted
+/// at the run's chunk size from the start of the source.
+const CHUNKS_KEY: &str = "stream.chunks";
+/// Metadata key holding the streaming run identifier.
+const RUN_KEY: &str = "stream.run";
+
+/// A pull-based source of entities for streaming simulation.
+pub trait EntitySource: Send {
+    /// Returns the next entity, or `None` when the source is exhausted.
+    fn next_entity(&mut self) -> Option<SimResult<Box<dyn LegalEntity>>>;
+
+    /// Skips up to `count` entities and returns how many were skipped.
+    ///
+    /// Used when resuming from a checkpoint; sources that can skip without
+    /// materialising entities should override it.
+    fn skip_entities(&mut self, count: u64) -> SimResult<u64> {
+        let mut skipped = 0;
+        while skipped < count {
+            match self.next_entity() {
+                Some(Ok(_)) => skipped += 1,
+                Some(Err(e)) => return Err(e),
+                None => break,
+            }
+        }
+        Ok(skipped)
+    }
+}
+
+/// Generated populations are not reproducible, so skipping simply reduces
+/// the number of entities left to draw.
+impl EntitySource for PopulationStream<'_> {
+    fn next_entity(&mut self) -> Option<SimResult<Box<dyn LegalEntity>>> {
+        self.next()
+            .map(|entity| Ok(Box::new(entity) as Box<dyn LegalEntity>))
+    }
+
+    fn skip_entities(&mut self, count: u64) -> SimResult<u64> {
+        let count = usize::try_from(count).unwrap_or(usize::MAX);
+        Ok(self.skip_ahead(count) as u64)
+    }
+}
+
+/// Adapts any iterator of entities into an [`EntitySource`].
+pub struct IterSource<I> {
+    iter: I,
+}
+
+impl<I> IterSource<I> {
+    /// Wraps an iterator.
+    pub fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<I, E> EntitySource for IterSource<I>
+where
+    I: Iterator<Item = E> + Send,
+    E: LegalEntity + 'static,
+{
+    fn next_entity(&mut self) -> Option<SimResult<Box<dyn LegalEntity>>> {
+        self.iter
+            .next()
+            .map(|entity| Ok(Box::new(entity) as Box<dyn LegalEntity>))
+    }
+}
+
+/// On-disk format of a population file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordFormat {
+    /// One JSON object per line, in the [`EntityRecord`] shape.
+    JsonLines,
+    /// Comma-separated values with a header row, as in [`PopulationImporter::from_csv`].
+    Csv,
+}
+
+/// Reads entity records one line at a time from a population file.
+///
+/// # Example
+///
+/// ```
+/// use legalis_sim::{EntitySource, RecordReader};
+///
+/// let data = "age,income\n30,45000\n67,12000\n";
+/// let mut reader = RecordReader::csv(data.as_bytes()).unwrap();
+///
+/// let first = reader.next_entity().unwrap().unwrap();
+/// assert_eq!(first.get_attribute("age"), Some("30".to_string()));
+/// assert_eq!(reader.skip_entities(5).unwrap(), 1);
+/// ```
+pub struct RecordReader<R> {
+    reader: R,
+    format: RecordFormat,
+    headers: Vec<String>,
+    line_number: usize,
+    line: String,
+}
+
+impl RecordReader<BufReader<File>> {
+    /// Opens a population file, choosing the format from its extension.
+    ///
+    /// Files ending in `.csv` are read as CSV; anything else as JSON lines.
+    pub fn open(path: impl AsRef<Path>) -> SimResult<Self> {
+        let path = path.as_ref();
+        let reader = BufReader::new(File::open(path)?);
+        let is_csv = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+
+        if is_csv {
+            Self::csv(reader)
+        } else {
+            Ok(Self::json_lines(reader))
+        }
+    }
+}
+
+impl<R: BufRead> RecordReader<R> {
+    /// Creates a reader over JSON-lines data.
+    pub fn json_lines(reader: R) -> Self {
+        Self {
+            reader,
+            format: RecordFormat::JsonLines,
+            headers: Vec::new(),
+            line_number: 0,
+            line: String::new(),
+        }
+    }
+
+    /// Creates a reader over CSV data, consuming the header row.
+    pub fn csv(reader: R) -> SimResult<Self> {
+        let mut this = Self {
+            reader,
+            format: RecordFormat::Csv,
+            headers: Vec::new(),
+            line_number: 0,
+            line: String::new(),
+        };
+
+        if !this.read_line()? {
+            return Err(SimulationError::InvalidPopulation(
+                "CSV is empty".to_string(),
+            ));
+        }
+        this.headers = this.line.split(',').map(|s| s.trim().to_string()).collect();
+
+        Ok(this)
+    }
+
+    /// Returns the format being read.
+    pub fn format(&self) -> RecordFormat {
+        self.format
+    }
+
+    /// Reads the next non-blank line into the buffer; `false` at end of input.
+    fn read_line(&mut self) -> SimResult<bool> {
+        loop {
+            self.line.clear();
+            if self.reader.read_line(&mut self.line)? == 0 {
+                return Ok(false);
+            }
+            self.line_number += 1;
+            if !self.line.trim().is_empty() {
+                return Ok(true);
+            }
+        }
+    }
+
+    /// Parses the buffered line into a record.
+    fn parse_record(&self) -> SimResult<EntityRecord> {
+        match self.format {
+            RecordFormat::JsonLines => serde_json::from_str(self.line.trim()).map_err(|e| {
+                SimulationError::InvalidPopulation(format!("Line {}: {}", self.line_number, e))
+            }),
+            RecordFormat::Csv => {
+                PopulationImporter::csv_record(&self.headers, self.line.trim(), self.line_number)
+                    .map_err(SimulationError::InvalidPopulation)
+            }
+        }
+    }
+}
+
+impl<R: BufRead + Send> EntitySource for RecordReader<R> {
+    fn next_entity(&mut self) -> Option<SimResult<Box<dyn LegalEntity>>> {
+        match self.read_line() {
+            Ok(true) => Some(
+                self.parse_record()
+                    .map(|record| Box::new(record.into_entity()) as Box<dyn LegalEntity>),
+            ),
+            Ok(false) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+
+    fn skip_entities(&mut self, count: u64) -> SimResult<u64> {
+        let mut skipped = 0;
+        while skipped < count && self.read_line()? {
+            skipped += 1;
+        }
+        Ok(skipped)
+    }
+}
+
+/// Snapshot passed to progress callbacks after each chunk is merged.
+#[derive(Debug)]
+pub struct StreamingProgress<'a> {
+    /// Entities processed so far, including those covered by a resumed checkpoint
+    pub processed: u64,
+    /// Chunks merged so far in this run
+    pub chunks: u64,
+    /// Metrics accumulated so far
+    pub metrics: &'a SimulationMetrics,
+}
+
+/// Result of a streaming simulation run.
+#[derive(Debug, Clone)]
+pub struct StreamingOutcome {
+    /// Metrics over every processed entity
+    pub metrics: SimulationMetrics,
+    /// Total entities processed, including those covered by a resumed checkpoint
+    pub processed: u64,
+    /// Chunks processed in this run
+    pub chunks: u64,
+    /// Entities skipped because a checkpoint already covered them
+    pub resumed_from: u64,
+    /// Checkpoints written during this run
+    pub checkpoints_written: usize,
+}
+
+/// Metrics for one completed chunk.
+struct ChunkResult {
+    index: u64,
+    len: u64,
+    metrics: SimulationMetrics,
+}
+
+/// Shared source state that workers pull chunks from.
+struct ChunkQueue<S> {
+    source: S,
+    next_index: u64,
+    exhausted: bool,
+}
+
+/// Limits how many chunks are dispatched but not yet merged.
+struct DispatchWindow {
+    free: Mutex<usize>,
+    released: Condvar,
+}
+
+impl DispatchWindow {
+    fn new(size: usize) -> Self {
+        Self {
+            free: Mutex::new(size),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Waits for a free slot; returns `false` if the run was cancelled.
+    fn acquire(&self, cancelled: &AtomicBool) -> bool {
+        let mut free = self.free.lock().unwrap_or_else(|e| e.into_inner());
+        loop {
+            if cancelled.load(Ordering::Relaxed) {
+                return false;
+            }
+            if *free > 0 {
+                *free -= 1;
+                return true;
+            }
+            free = self.released.wait(free).unwrap_or_else(|e| e.into_inner());
+        }
+    }
+
+    fn release(&self) {
+        *self.free.lock().unwrap_or_else(|e| e.into_inner()) += 1;
+        self.released.notify_one();
+    }
+
+    /// Wakes every waiting worker so that it can observe cancellation.
+    fn cancel(&self, cancelled: &AtomicBool) {
+        let _free = self.free.lock().unwrap_or_else(|e| e.into_inner());
+        cancelled.store(true, Ordering::Relaxed);
+        self.released.notify_all();
+    }
+}
+
+/// Chunked, bounded-concurrency simulation over a streamed population.
+///
+/// Unlike [`SimEngine::run_simulation`], the population is never held in
+/// memory: each worker pulls `chunk_size` entities at a time from an
+/// [`EntitySource`], evaluates every statute against them, and hands back
+/// per-chunk metrics that are merged in source order.
+///
+/// # Example
+///
+/// ```
+/// use legalis_core::{Condition, ComparisonOp, Effect, EffectType, Statute};
+/// use legalis_sim::{DemographicProfile, PopulationGenerator, StreamingSimulation};
+///
+/// let statute = Statute::new("pension", "Pension", Effect::new(EffectType::Grant, "Pension"))
+///     .with_precondition(Condition::Age { operator: ComparisonOp::GreaterOrEqual, value: 65 });
+/// let generator = PopulationGenerator::new(DemographicProfile::default(), 5_000);
+///
+/// let outcome = StreamingSimulation::new(vec![statute])
+///     .with_chunk_size(512)
+///     .with_concurrency(4)
+///     .run(generator.stream())
+///     .unwrap();
+///
+/// assert_eq!(outcome.processed, 5_000);
+/// assert_eq!(outcome.metrics.total_applications, 5_000);
+/// ```
+pub struct StreamingSimulation {
+    statutes: Arc<[Statute]>,
+    simulation_date: Option<NaiveDate>,
+    chunk_size: usize,
+    concurrency: usize,
+    max_discretion_agents: usize,
+    checkpoint_store: Option<CheckpointStore>,
+    checkpoint_every: u64,
+    run_id: String,
+}
+
+impl StreamingSimulation {
+    /// Creates a streaming simulation over the given statutes.
+    pub fn new(statutes: Vec<Statute>) -> Self {
+        Self {
+            statutes: statutes.into(),
+            simulation_date: None,
+            chunk_size: 10_000,
+            concurrency: num_cpus::get().max(1),
+            max_discretion_agents: 100_000,
+            checkpoint_store: None,
+            checkpoint_every: 100,
+            run_id: "stream".to_string(),
+        }
+    }
+
+    /// Evaluates every statute as of the given date.
+    pub fn with_simulation_date(mut self, date: NaiveDate) -> Self {
+        self.simulation_date = Some(date);
+        self
+    }
+
+    /// Sets the number of entities per chunk.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Sets the number of worker threads.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Caps how many agent IDs are kept in `discretion_agents`.
+    ///
+    /// Discretion counts remain exact; only the ID list is truncated.
+    pub fn with_max_discretion_agents(mut self, max: usize) -> Self {
+        self.max_discretion_agents = max;
+        self
+    }
+
+    /// Writes checkpoints to the given store.
+    pub fn with_checkpoint_store(mut self, store: CheckpointStore) -> Self {
+        self.checkpoint_store = Some(store);
+        self
+    }
+
+    /// Sets how many chunks are merged between checkpoints.
+    pub fn with_checkpoint_every(mut self, chunks: u64) -> Self {
+        self.checkpoint_every = chunks.max(1);
+        self
+    }
+
+    /// Sets the run identifier used to name and find checkpoints.
+    pub fn with_run_id(mut self, run_id: impl Into<String>) -> Self {
+        self.run_id = run_id.into();
+        self
+    }
+
+    /// Returns the chunk size.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Returns the number of worker threads.
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Runs the simulation over the whole source.
+    pub fn run<S: EntitySource>(&self, source: S) -> SimResult<StreamingOutcome> {
+        self.execute(source, None, |_| {})
+    }
+
+    /// Runs the simulation, calling `progress` after each chunk is merged.
+    pub fn run_with_progress<S, F>(&self, source: S, progress: F) -> SimResult<StreamingOutcome>
+    where
+        S: EntitySource,
+        F: FnMut(&StreamingProgress<'_>),
+    {
+        self.execute(source, None, progress)
+    }
+
+    /// Resumes from the latest checkpoint of this run.
+    ///
+    /// The source must yield the same population in the same order as the
+    /// interrupted run; entities covered by the checkpoint are skipped. Without
+    /// a checkpoint the whole source is processed.
+    pub fn resume<S: EntitySource>(&self, source: S) -> SimResult<StreamingOutcome> {
+        self.resume_with_progress(source, |_| {})
+    }
+
+    /// Resumes from the latest checkpoint, reporting progress as in
+    /// [`StreamingSimulation::run_with_progress`].
+    pub fn resume_with_progress<S, F>(&self, source: S, progress: F) -> SimResult<StreamingOutcome>
+    where
+        S: EntitySource,
+        F: FnMut(&StreamingProgress<'_>),
+    {
+        if self.checkpoint_store.is_none() {
+            return Err(SimulationError::Checkpoint(
+                "Resume requires a checkpoint store".to_string(),
+            ));
+        }
+        let checkpoint = self.latest_checkpoint()?;
+        self.execute(source, checkpoint, progress)
+    }
+
+    /// Returns the checkpoint covering the most entities for this run.
+    pub fn latest_checkpoint(&self) -> SimResult<Option<Checkpoint>> {
+        let Some(store) = &self.checkpoint_store else {
+            return Ok(None);
+        };
+        let prefix = format!("{}-", self.run_id);
+
+        let mut latest: Option<(u64, Checkpoint)> = None;
+        for id in store.list_checkpoints()? {
+            if !id.starts_with(&prefix) {
+                continue;
+            }
+            let checkpoint = store.load(&id)?;
+            if checkpoint.metadata.get(RUN_KEY) != Some(&self.run_id) {
+                continue;
+            }
+            let processed = processed_count(&checkpoint)?;
+            if latest.as_ref().is_none_or(|(best, _)| processed > *best) {
+                latest = Some((processed, checkpoint));
+            }
+        }
+
+        Ok(latest.map(|(_, checkpoint)| checkpoint))
+    }
+
+    fn execute<S, F>(
+        &self,
+        mut source: S,
+        checkpoint: Option<Checkpoint>,
+        mut progress: F,
+    ) -> SimResult<StreamingOutcome>
+    where
+        S: EntitySource,
+        F: FnMut(&StreamingProgress<'_>),
+    {
+        let (mut metrics, resumed_from) = match checkpoint {
+            Some(checkpoint) => {
+                let processed = processed_count(&checkpoint)?;
+                (checkpoint.metrics, processed)
+            }
+            None => (SimulationMetrics::new(), 0),
+        };
+
+        if resumed_from > 0 {
+            let skipped = source.skip_entities(resumed_from)?;
+            if skipped < resumed_from {
+                return Err(SimulationError::Checkpoint(format!(
+                    "Checkpoint covers {} entities but the source only has {}",
+                    resumed_from, skipped
+                )));
+            }
+        }
+
+        let queue = Mutex::new(ChunkQueue {
+            source,
+            next_index: 0,
+            exhausted: false,
+        });
+        let cancelled = AtomicBool::new(false);
+        let window = DispatchWindow::new(self.concurrency);
+        let (tx, rx) = mpsc::sync_channel::<SimResult<ChunkResult>>(self.concurrency);
+
+        let mut processed = resumed_from;
+        let mut chunks = 0;
+        let mut checkpoints_written = 0;
+        let mut last_checkpoint = resumed_from;
+        let mut error = None;
+
+        std::thread::scope(|scope| {
+            for _ in 0..self.concurrency {
+                let tx = tx.clone();
+                let queue = &queue;
+                let cancelled = &cancelled;
+                let window = &window;
+                scope.spawn(move || self.worker(queue, window, cancelled, tx));
+            }
+            drop(tx);
+
+            // Chunks can finish out of order; merge them in source order so
+            // that every checkpoint covers a contiguous prefix of the source.
+            let mut pending = BTreeMap::new();
+            for message in rx {
+                let chunk = match message {
+                    Ok(chunk) => chunk,
+                    Err(e) => {
+                        window.cancel(&cancelled);
+                        error.get_or_insert(e);
+                        continue;
+                    }
+                };
+                if error.is_some() {
+                    continue;
+                }
+                pending.insert(chunk.index, chunk);
+
+                while let Some(chunk) = pending.remove(&chunks) {
+                    self.merge_chunk(&mut metrics, chunk.metrics);
+                    processed += chunk.len;
+                    chunks += 1;
+                    window.release();
+                    progress(&StreamingProgress {
+                        processed,
+                        chunks,
+                        metrics: &metrics,
+                    });
+
+                    if chunks % self.checkpoint_every == 0 {
+                        match self.save_checkpoint(&metrics, processed) {
+                            Ok(true) => {
+                                checkpoints_written += 1;
+                                last_checkpoint = processed;
+                            }
+                            Ok(false) => {}
+                            Err(e) => {
+                                window.cancel(&cancelled);
+                                error = Some(e);
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        if let Some(e) = error {
+            return Err(e);
+        }
+
+        if processed > last_checkpoint && self.save_checkpoint(&metrics, processed)? {
+            checkpoints_written += 1;
+        }
+
+        Ok(StreamingOutcome {
+            metrics,
+            processed,
+            chunks,
+            resumed_from,
+            checkpoints_written,
+        })
+    }
+
+    /// Pulls chunks from the queue until it is exhausted or the run is cancelled.
+    fn worker<S: EntitySource>(
+        &self,
+        queue: &Mutex<ChunkQueue<S>>,
+        window: &DispatchWindow,
+        cancelled: &AtomicBool,
+        tx: mpsc::SyncSender<SimResult<ChunkResult>>,
+    ) {
+        let mut batch: Vec<Box<dyn LegalEntity>> = Vec::with_capacity(self.chunk_size);
+
+        while window.acquire(cancelled) {
+            batch.clear();
+            let mut failure = None;
+            let index = {
+                let mut queue = queue.lock().unwrap_or_else(|e| e.into_inner());
+                if queue.exhausted {
+                    window.release();
+                    break;
+                }
+                while batch.len() < self.chunk_size {
+                    match queue.source.next_entity() {
+                        Some(Ok(entity)) => batch.push(entity),
+                        Some(Err(e)) => {
+                            failure = Some(e);
+                            break;
+                        }
+                        None => {
+                            queue.exhausted = true;
+                            break;
+                        }
+                    }
+                }
+                if failure.is_some() {
+                    queue.exhausted = true;
+                }
+                let index = queue.next_index;
+                queue.next_index += 1;
+                index
+            };
+
+            if let Some(e) = failure {
+                let _ = tx.send(Err(e));
+                break;
+            }
+            if batch.is_empty() {
+                window.release();
+                break;
+            }
+
+            let mut metrics = SimulationMetrics::new();
+            for entity in &batch {
+                for statute in self.statutes.iter() {
+                    let result = match self.simulation_date {
+                        Some(date) => SimEngine::apply_law_at(entity.as_ref(), statute, date),
+                        None => SimEngine::apply_law_with_outputs(entity.as_ref(), statute),
+                    };
+                    metrics.record_result(&result);
+                }
+            }
+            metrics
+                .discretion_agents
+                .truncate(self.max_discretion_agents);
+
+            let chunk = ChunkResult {
+                index,
+                len: batch.len() as u64,
+                metrics,
+            };
+            if tx.send(Ok(chunk)).is_err() {
+                break;
+            }
+        }
+    }
+
+    /// Adds one chunk's metrics to the running totals.
+    fn merge_chunk(&self, total: &mut SimulationMetrics, chunk: SimulationMetrics) {
+        total.total_applications += chunk.total_applications;
+        total.deterministic_count += chunk.deterministic_count;
+        total.discretion_count += chunk.discretion_count;
+        total.void_count += chunk.void_count;
+
+        for (statute_id, statute_metrics) in &chunk.statute_metrics {
+            total
+                .statute_metrics
+                .entry(statute_id.clone())
+                .or_default()
+                .merge(statute_metrics);
+        }
+
+        let room = self
+            .max_discretion_agents
+            .saturating_sub(total.discretion_agents.len());
+        total
+            .discretion_agents
+            .extend(chunk.discretion_agents.into_iter().take(room));
+    }
+
+    /// Saves a checkpoint if a store is configured; returns whether one was written.
+    fn save_checkpoint(&self, metrics: &SimulationMetrics, processed: u64) -> SimResult<bool> {
+        let Some(store) = &self.checkpoint_store else {
+            return Ok(false);
+        };
+
+        let id = format!("{}-{:015}", self.run_id, processed);
+        let mut checkpoint = Checkpoint::new(id, metrics.clone());
+        checkpoint.add_metadata(RUN_KEY.to_string(), self.run_id.clone());
+        checkpoint.add_metadata(PROCESSED_KEY.to_string(), processed.to_string());
+        // Derived from the entity count so that it stays cumulative when a
+        // resumed run restarts its own chunk numbering.
+        let chunks = processed.div_ceil(self.chunk_size as u64);
+        checkpoint.add_metadata(CHUNKS_KEY.to_string(), chunks.to_string());
+        store.save(&checkpoint)?;
+
+        Ok(true)
+    }
+}
+
+/// Reads the processed-entity count recorded in a streaming checkpoint.
+fn processed_count(checkpoint: &Checkpoint) -> SimResult<u64> {
+    checkpoint
+        .metadata
+        .get(PROCESSED_KEY)
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| {
+            SimulationError::Checkpoint(format!(
+                "Checkpoint '{}' is not a streaming checkpoint",
+                checkpoint.id
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DemographicProfile, PersistenceConfig, PopulationGenerator};
+    use legalis_core::{BasicEntity, ComparisonOp, Condition, Effect, EffectType};
+    use std::path::PathBuf;
+    use uuid::Uuid;
+
+    fn pension_statute() -> Statute {
+        Statute::new(
+            "pension",
+            "Pension",
+            Effect::new(EffectType::Grant, "Pension"),
+        )
+        .with_precondition(Condition::Age {
+            operator: ComparisonOp::GreaterOrEqual,
+            value: 65,
+        })
+    }
+
+    fn discretion_statute() -> Statute {
+        Statute::new(
+            "hardship",
+            "Hardship relief",
+            Effect::new(EffectType::Grant, "Relief"),
+        )
+        .with_discretion("Assess hardship case by case")
+    }
+
+    fn aged_population(count: u32) -> Vec<BasicEntity> {
+        (0..count)
+            .map(|i| {
+                let mut entity = BasicEntity::new();
+                entity.set_attribute("age", (i % 100).to_string());
+                entity
+            })
+            .collect()
+    }
+
+    fn temp_dir() -> PathBuf {
+        PathBuf::from(format!("/tmp/legalis-sim-stream-{}", Uuid::new_v4()))
+    }
+
+    /// Yields a fixed population but fails once `fail_at` entities have been read.
+    struct FlakySource {
+        entities: std::vec::IntoIter<BasicEntity>,
+        read: usize,
+        fail_at: Option<usize>,
+    }
+
+    impl EntitySource for FlakySource {
+        fn next_entity(&mut self) -> Option<SimResult<Box<dyn LegalEntity>>> {
+            if self.fail_at == Some(self.read) {
+                return Some(Err(SimulationError::ExecutionError(
+                    "source interrupted".to_string(),
+                )));
+            }
+            self.read += 1;
+            self.entities
+                .next()
+                .map(|entity| Ok(Box::new(entity) as Box<dyn LegalEntity>))
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_in_memory_counts() {
+        let statutes = vec![pension_statute()];
+        let population = aged_population(1_000);
+
+        let mut expected = SimulationMetrics::new();
+        for entity in &population {
+            expected.record_result(&SimEngine::apply_law_with_outputs(entity, &statutes[0]));
+        }
+
+        let outcome = StreamingSimulation::new(statutes)
+            .with_chunk_size(37)
+            .with_concurrency(4)
+            .run(IterSource::new(population.into_iter()))
+            .unwrap();
+
+        assert_eq!(outcome.processed, 1_000);
+        assert_eq!(outcome.chunks, 28);
+        assert_eq!(outcome.metrics.total_applications, 1_000);
+        assert_eq!(
+            outcome.metrics.deterministic_count,
+            expected.deterministic_count
+        );
+        assert_eq!(outcome.metrics.void_count, expected.void_count);
+        assert_eq!(
+            outcome.metrics.statute_metrics["pension"].deterministic,
+            350
+        );
+        assert_eq!(outcome.checkpoints_written, 0);
+    }
+
+    #[test]
+    fn test_streaming_from_generator_with_progress() {
+        let generator = PopulationGenerator::new(DemographicProfile::default(), 2_500);
+        let mut seen = Vec::new();
+
+        let outcome = StreamingSimulation::new(vec![pension_statute(), discretion_statute()])
+            .with_chunk_size(500)
+            .with_concurrency(3)
+            .with_max_discretion_agents(100)
+            .run_with_progress(generator.stream(), |progress| {
+                seen.push((progress.processed, progress.metrics.total_applications));
+            })
+            .unwrap();
+
+        assert_eq!(outcome.processed, 2_500);
+        assert_eq!(outcome.metrics.total_applications, 5_000);
+        assert_eq!(outcome.metrics.discretion_count, 2_500);
+        assert_eq!(outcome.metrics.discretion_agents.len(), 100);
+        assert_eq!(
+            seen,
+            vec![
+                (500, 1_000),
+                (1_000, 2_000),
+                (1_500, 3_000),
+                (2_000, 4_000),
+                (2_500, 5_000)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_record_reader_formats() {
+        let jsonl = "{\"age\": 70, \"income\": \"12000\"}\n\n{\"age\": \"40\"}\n";
+        let outcome = StreamingSimulation::new(vec![pension_statute()])
+            .with_chunk_size(1)
+            .run(RecordReader::json_lines(jsonl.as_bytes()))
+            .unwrap();
+        assert_eq!(outcome.processed, 2);
+        assert_eq!(outcome.metrics.deterministic_count, 1);
+
+        let csv = "id,age\n,80\n,20\n,66\n";
+        let reader = RecordReader::csv(csv.as_bytes()).unwrap();
+        assert_eq!(reader.format(), RecordFormat::Csv);
+        let outcome = StreamingSimulation::new(vec![pension_statute()])
+            .run(reader)
+            .unwrap();
+        assert_eq!(outcome.metrics.deterministic_count, 2);
+
+        let bad = "{\"age\": 70}\nnot json\n";
+        let err = StreamingSimulation::new(vec![pension_statute()])
+            .run(RecordReader::json_lines(bad.as_bytes()))
+            .unwrap_err();
+        assert!(err.to_string().contains("Line 2"));
+
+        assert!(RecordReader::csv("".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_checkpoint_and_resume() {
+        let dir = temp_dir();
+        let store = || CheckpointStore::new(PersistenceConfig::new(dir.clone())).unwrap();
+        let population = aged_population(100);
+
+        let simulation = StreamingSimulation::new(vec![pension_statute()])
+            .with_chunk_size(10)
+            .with_concurrency(1)
+            .with_checkpoint_every(2)
+            .with_run_id("census")
+            .with_checkpoint_store(store());
+
+        let interrupted = simulation.run(FlakySource {
+            entities: population.clone().into_iter(),
+            read: 0,
+            fail_at: Some(55),
+        });
+        assert!(interrupted.is_err());
+
+        let latest = simulation.latest_checkpoint().unwrap().unwrap();
+        assert_eq!(latest.metadata[PROCESSED_KEY], "40");
+        assert_eq!(latest.metadata[CHUNKS_KEY], "4");
+        assert_eq!(latest.metrics.total_applications, 40);
+
+        let outcome = simulation
+            .resume(FlakySource {
+                entities: population.into_iter(),
+                read: 0,
+                fail_at: None,
+            })
+            .unwrap();
+        assert_eq!(outcome.resumed_from, 40);
+        assert_eq!(outcome.processed, 100);
+        assert_eq!(outcome.chunks, 6);
+        assert_eq!(outcome.metrics.total_applications, 100);
+        assert_eq!(outcome.metrics.statute_metrics["pension"].deterministic, 35);
+
+        // The final checkpoint covers the whole population.
+        let latest = simulation.latest_checkpoint().unwrap().unwrap();
+        assert_eq!(latest.metadata[PROCESSED_KEY], "100");
+        assert_eq!(latest.metadata[CHUNKS_KEY], "10");
+
+        // A different run ID does not pick up these checkpoints.
+        let other = StreamingSimulation::new(vec![pension_statute()])
+            .with_run_id("other")
+            .with_checkpoint_store(store());
+        assert!(other.latest_checkpoint().unwrap().is_none());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_resume_rejects_short_source() {
+        let dir = temp_dir();
+        let store = CheckpointStore::new(PersistenceConfig::new(dir.clone())).unwrap();
+        let simulation = StreamingSimulation::new(vec![pension_statute()])
+            .with_chunk_size(5)
+            .with_checkpoint_store(store);
+
+        simulation
+            .run(IterSource::new(aged_population(20).into_iter()))
+            .unwrap();
+        let err = simulation
+            .resume(IterSource::new(aged_population(10).into_iter()))
+            .unwrap_err();
+        assert!(err.to_string().contains("covers 20 entities"));
+
+        assert!(
+            StreamingSimulation::new(vec![])
+                .resume(IterSource::new(aged_population(1).into_iter()))
+                .is_err()
+        );
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_engine_streaming_uses_simulation_date() {
+        let date = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let statute = pension_statute().with_temporal_validity(
+            legalis_core::TemporalValidity::new()
+                .with_effective_date(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
+        );
+        let engine = SimEngine::new(vec![statute], Vec::new()).with_simulation_date(date);
+
+        let outcome = engine
+            .streaming()
+            .run(IterSource::new(aged_population(10).into_iter()))
+            .unwrap();
+        assert_eq!(outcome.metrics.void_count, 10);
+        assert_eq!(outcome.metrics.statute_metrics["pension"].not_in_force, 10);
+    }
+}
@@ -7,7 +7,7 @@
 //! - Temporal metrics collection
 //! - Event-driven state transitions
 
-use crate::engine::{LawApplicationResult, SimEngine};
+use crate::engine::SimEngine;
 use crate::metrics::SimulationMetrics;
 use chrono::{Duration, NaiveDate};
 use legalis_core::{BasicEntity, LegalEntity, Statute};
@@ -584,13 +584,7 @@ impl TemporalSimEngine {
                 // Add current date as an attribute for date-based conditions
                 temp_entity.set_attribute("current_date", date_str.clone());
 
-                let result = SimEngine::apply_law(&temp_entity, statute);
-
-                metrics.record_result(&LawApplicationResult {
-                    agent_id: agent.id,
-                    statute_id: statute.id.clone(),
-                    result,
-                });
+                metrics.record_result(&SimEngine::apply_law_with_outputs(&temp_entity, statute));
             }
         }
 
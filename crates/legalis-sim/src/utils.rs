@@ -165,6 +165,14 @@ impl StatuteMetrics {
         self.deterministic += other.deterministic;
         self.discretion += other.discretion;
         self.void += other.void;
+        self.exceptions += other.exceptions;
+        self.not_in_force += other.not_in_force;
+        for (name, total) in &other.output_totals {
+            self.output_totals
+                .entry(name.clone())
+                .or_default()
+                .merge(total);
+        }
     }
 }
 
@@ -481,18 +489,18 @@ mod tests {
     #[test]
     fn test_aggregate_metrics() {
         let mut metrics1 = SimulationMetrics::new();
-        metrics1.record_result(&LawApplicationResult {
-            agent_id: Uuid::new_v4(),
-            statute_id: "test".to_string(),
-            result: LegalResult::Deterministic(Effect::new(EffectType::Grant, "test")),
-        });
+        metrics1.record_result(&LawApplicationResult::new(
+            Uuid::new_v4(),
+            "test".to_string(),
+            LegalResult::Deterministic(Effect::new(EffectType::Grant, "test")),
+        ));
 
         let mut metrics2 = SimulationMetrics::new();
-        metrics2.record_result(&LawApplicationResult {
-            agent_id: Uuid::new_v4(),
-            statute_id: "test".to_string(),
-            result: LegalResult::Deterministic(Effect::new(EffectType::Grant, "test")),
-        });
+        metrics2.record_result(&LawApplicationResult::new(
+            Uuid::new_v4(),
+            "test".to_string(),
+            LegalResult::Deterministic(Effect::new(EffectType::Grant, "test")),
+        ));
 
         let aggregated = aggregate_metrics(&[metrics1, metrics2]);
 
@@ -573,11 +581,11 @@ mod tests {
         let mut metrics_list = Vec::new();
         for i in 0..200 {
             let mut metrics = SimulationMetrics::new();
-            metrics.record_result(&LawApplicationResult {
-                agent_id: Uuid::new_v4(),
-                statute_id: format!("test-{}", i % 5),
-                result: LegalResult::Deterministic(Effect::new(EffectType::Grant, "test")),
-            });
+            metrics.record_result(&LawApplicationResult::new(
+                Uuid::new_v4(),
+                format!("test-{}", i % 5),
+                LegalResult::Deterministic(Effect::new(EffectType::Grant, "test")),
+            ));
             metrics_list.push(metrics);
         }
 
@@ -601,11 +609,11 @@ mod tests {
         let mut metrics_list = Vec::new();
         for _ in 0..10 {
             let mut metrics = SimulationMetrics::new();
-            metrics.record_result(&LawApplicationResult {
-                agent_id: Uuid::new_v4(),
-                statute_id: "test".to_string(),
-                result: LegalResult::Deterministic(Effect::new(EffectType::Grant, "test")),
-            });
+            metrics.record_result(&LawApplicationResult::new(
+                Uuid::new_v4(),
+                "test".to_string(),
+                LegalResult::Deterministic(Effect::new(EffectType::Grant, "test")),
+            ));
             metrics_list.push(metrics);
         }
 
@@ -1028,6 +1028,7 @@ mod tests {
                 effect_type: EffectType::Grant,
                 description: description.to_string(),
                 parameters: Default::default(),
+                outputs: Default::default(),
             },
             preconditions: vec![],
             jurisdiction: Some("Test Jurisdiction".to_string()),
@@ -587,6 +587,7 @@ mod tests {
                 effect_type: EffectType::Grant,
                 description: "Test effect".to_string(),
                 parameters: Default::default(),
+                outputs: Default::default(),
             },
             preconditions: vec![],
             jurisdiction: Some("Test".to_string()),
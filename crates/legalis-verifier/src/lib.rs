@@ -660,7 +660,10 @@ impl StatuteVerifier {
 
         // Build dependency graph by extracting statute references from conditions
         for statute in statutes {
-            let deps = self.extract_statute_references(&statute.preconditions);
+            let mut deps = self.extract_statute_references(&statute.preconditions);
+            for exception in &statute.exceptions {
+                Self::extract_refs_from_condition(&exception.condition, &mut deps);
+            }
             graph.insert(&statute.id, deps);
         }
 
@@ -731,12 +734,26 @@ impl StatuteVerifier {
                     refs.insert(statute_ref.trim());
                 }
             }
+            Condition::StatuteApplies { statute_id }
+            | Condition::StatuteOutput { statute_id, .. } => {
+                refs.insert(statute_id);
+            }
             // Recursive cases
             Condition::And(left, right) | Condition::Or(left, right) => {
                 Self::extract_refs_from_condition(left, refs);
                 Self::extract_refs_from_condition(right, refs);
             }
-            Condition::Not(inner) => {
+            Condition::Not(inner)
+            | Condition::ForAll {
+                condition: inner, ..
+            }
+            | Condition::Exists {
+                condition: inner, ..
+            }
+            | Condition::Count {
+                condition: Some(inner),
+                ..
+            } => {
                 Self::extract_refs_from_condition(inner, refs);
             }
             // Other conditions don't contain statute references
@@ -3023,6 +3040,34 @@ fn analyze_condition(condition: &legalis_core::Condition) -> (usize, usize, Hash
         }
         Condition::Pattern { .. } => (1, 0, ["Pattern".to_string()].into_iter().collect()),
         Condition::Calculation { .. } => (1, 0, ["Calculation".to_string()].into_iter().collect()),
+        Condition::StatuteApplies { .. } => {
+            (1, 0, ["StatuteApplies".to_string()].into_iter().collect())
+        }
+        Condition::StatuteOutput { .. } => {
+            (1, 0, ["StatuteOutput".to_string()].into_iter().collect())
+        }
+        Condition::ForAll { condition, .. } => {
+            let (depth, ops, mut types) = analyze_condition(condition);
+            types.insert("ForAll".to_string());
+            (1 + depth, 1 + ops, types)
+        }
+        Condition::Exists { condition, .. } => {
+            let (depth, ops, mut types) = analyze_condition(condition);
+            types.insert("Exists".to_string());
+            (1 + depth, 1 + ops, types)
+        }
+        Condition::Count {
+            condition: Some(condition),
+            ..
+        } => {
+            let (depth, ops, mut types) = analyze_condition(condition);
+            types.insert("Count".to_string());
+            (1 + depth, 1 + ops, types)
+        }
+        Condition::Count {
+            condition: None, ..
+        } => (1, 0, ["Count".to_string()].into_iter().collect()),
+        Condition::Sum { .. } => (1, 0, ["Sum".to_string()].into_iter().collect()),
         Condition::Composite { conditions, .. } => {
             // For composite conditions, recursively analyze all sub-conditions
             let mut max_depth = 1;
@@ -4623,7 +4668,10 @@ pub fn validate_cross_references(statutes: &[Statute]) -> Vec<CrossReferenceErro
     let statute_ids: HashSet<&str> = statutes.iter().map(|s| s.id.as_str()).collect();
 
     for statute in statutes {
-        let references = extract_statute_references_from_conditions(&statute.preconditions);
+        let mut references = extract_statute_references_from_conditions(&statute.preconditions);
+        for exception in &statute.exceptions {
+            extract_refs_from_single_condition(&exception.condition, &mut references);
+        }
 
         for reference in references {
             // Check if the referenced statute exists
@@ -4662,11 +4710,24 @@ fn extract_refs_from_single_condition(condition: &legalis_core::Condition, refs:
                 refs.push(statute_ref.trim().to_string());
             }
         }
+        Condition::StatuteApplies { statute_id } | Condition::StatuteOutput { statute_id, .. } => {
+            refs.push(statute_id.clone());
+        }
         Condition::And(left, right) | Condition::Or(left, right) => {
             extract_refs_from_single_condition(left, refs);
             extract_refs_from_single_condition(right, refs);
         }
-        Condition::Not(inner) => {
+        Condition::Not(inner)
+        | Condition::ForAll {
+            condition: inner, ..
+        }
+        | Condition::Exists {
+            condition: inner, ..
+        }
+        | Condition::Count {
+            condition: Some(inner),
+            ..
+        } => {
             extract_refs_from_single_condition(inner, refs);
         }
         _ => {}
@@ -9065,6 +9126,9 @@ fn is_testable_condition(condition: &legalis_core::Condition) -> bool {
         | Condition::Threshold { .. }
         | Condition::Temporal { .. } => true,
 
+        // References are testable through the statute they point at
+        Condition::StatuteApplies { .. } | Condition::StatuteOutput { .. } => true,
+
         // Attribute checks are testable if well-defined
         Condition::HasAttribute { .. } | Condition::AttributeEquals { .. } => true,
 
@@ -9081,6 +9145,15 @@ fn is_testable_condition(condition: &legalis_core::Condition) -> bool {
         }
         Condition::Probabilistic { condition, .. } => is_testable_condition(condition),
 
+        // Quantifiers are testable when their per-entity condition is
+        Condition::ForAll { condition, .. } | Condition::Exists { condition, .. } => {
+            is_testable_condition(condition)
+        }
+        Condition::Count { condition, .. } => {
+            condition.as_deref().is_none_or(is_testable_condition)
+        }
+        Condition::Sum { .. } => true,
+
         // Fuzzy and Custom are less testable
         Condition::Fuzzy { .. } | Condition::Custom { .. } => false,
     }
@@ -18590,6 +18663,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_statute_reference_conditions_verified() {
+        let statute_a = Statute::new("welfare-a", "A", Effect::new(EffectType::Grant, "A"))
+            .with_precondition(Condition::statute_applies("welfare-b"));
+        let statute_b = Statute::new("welfare-b", "B", Effect::new(EffectType::Grant, "B"))
+            .with_precondition(Condition::statute_output(
+                "welfare-a",
+                "amount",
+                ComparisonOp::GreaterThan,
+                legalis_core::Decimal::ZERO,
+            ))
+            .with_precondition(Condition::statute_applies("welfare-missing"));
+
+        let verifier = StatuteVerifier::new();
+        let result = verifier.verify(&[statute_a.clone(), statute_b.clone()]);
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| matches!(e, VerificationError::CircularReference { .. }))
+        );
+
+        let errors = validate_cross_references(&[statute_a, statute_b]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].referenced_statute_id, "welfare-missing");
+        assert_eq!(errors[0].error_type, CrossReferenceErrorType::NotFound);
+    }
+
     #[test]
     fn test_no_circular_reference() {
         let statute1 = Statute::new(
@@ -1874,6 +1874,57 @@ fn format_condition(condition: &Condition) -> String {
         } => {
             format!("{} {} {}", formula, format_operator(operator), value)
         }
+        Condition::StatuteApplies { statute_id } => {
+            format!("Applies '{}'", statute_id)
+        }
+        Condition::StatuteOutput {
+            statute_id,
+            output,
+            operator,
+            value,
+            currency,
+        } => {
+            format!(
+                "{}.{} {} {}{}",
+                statute_id,
+                output,
+                format_operator(operator),
+                value,
+                currency
+                    .as_ref()
+                    .map(|c| format!(" {}", c))
+                    .unwrap_or_default()
+            )
+        }
+        Condition::ForAll { relationship, .. } => format!("For all {:?}", relationship),
+        Condition::Exists { relationship, .. } => format!("Exists {:?}", relationship),
+        Condition::Count {
+            relationship,
+            operator,
+            value,
+            ..
+        } => {
+            format!(
+                "count({:?}) {} {}",
+                relationship,
+                format_operator(operator),
+                value
+            )
+        }
+        Condition::Sum {
+            relationship,
+            attribute,
+            operator,
+            value,
+        } => {
+            format!(
+                "sum({:?}.{}) {} {}",
+                relationship,
+                attribute,
+                format_operator(operator),
+                value
+            )
+        }
         Condition::And(_, _) => "AND condition".to_string(),
         Condition::Or(_, _) => "OR condition".to_string(),
         Condition::Not(_) => "NOT condition".to_string(),
@@ -617,6 +617,50 @@ declaration scope VotingRights:
         }
     }
 
+    #[test]
+    fn test_cgi_converter_computes_decote() {
+        let mut converter = CodeImpotsConverter::new();
+
+        // Article 197, I-4 du CGI (décote), simplified to single taxpayers
+        let catala_source = "## Article 197
+
+```catala
+déclaration champ d'application Décote:
+  entrée impôt_brut contenu argent
+  résultat décote contenu argent
+
+champ d'application Décote:
+  définition décote égal à
+    si impôt_brut < 1 841 € alors 833 € - impôt_brut * 45,25% sinon 0 €
+```
+";
+
+        let (statutes, _) = converter.import_cgi(catala_source).unwrap();
+        let decote = &statutes[0];
+        assert_eq!(decote.jurisdiction.as_deref(), Some("FR"));
+        assert_eq!(
+            decote.effect.parameters.get("catala_article"),
+            Some(&"Article 197".to_string())
+        );
+
+        let output = &decote.effect.outputs[0];
+        let amount = |impot_brut: &str| {
+            let entity = std::collections::HashMap::from([(
+                "impôt_brut".to_string(),
+                impot_brut.to_string(),
+            )]);
+            output.evaluate(&entity).unwrap()
+        };
+        assert_eq!(
+            amount("1000"),
+            legalis_core::OutputValue::money("380.5".parse().unwrap(), "EUR")
+        );
+        assert_eq!(
+            amount("2000"),
+            legalis_core::OutputValue::money(legalis_core::Decimal::ZERO, "EUR")
+        );
+    }
+
     #[test]
     fn test_css_converter() {
         let mut converter = CodeSecuriteSocialeConverter::new();
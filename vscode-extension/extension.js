@@ -0,0 +1,46 @@
+// Starts the legalis-lsp language server for Legalis DSL files.
+
+const vscode = require("vscode");
+const { LanguageClient, TransportKind } = require("vscode-languageclient/node");
+
+let client;
+
+function activate(context) {
+  const config = vscode.workspace.getConfiguration("legalis-dsl");
+  const command = config.get("server.path") || "legalis-lsp";
+
+  const serverOptions = {
+    run: { command, transport: TransportKind.stdio },
+    debug: { command, transport: TransportKind.stdio },
+  };
+  const clientOptions = {
+    documentSelector: [{ scheme: "file", language: "legalis" }],
+    synchronize: {
+      fileEvents: vscode.workspace.createFileSystemWatcher("**/*.{legalis,legal}"),
+    },
+  };
+
+  client = new LanguageClient("legalis-dsl", "Legalis DSL", serverOptions, clientOptions);
+  client.start().catch((error) => {
+    vscode.window.showWarningMessage(
+      `Could not start the Legalis language server (${command}): ${error.message}. ` +
+        "Set legalis-dsl.server.path to the legalis-lsp binary."
+    );
+  });
+
+  context.subscriptions.push(
+    vscode.workspace.onDidChangeConfiguration((event) => {
+      if (event.affectsConfiguration("legalis-dsl.server.path")) {
+        vscode.window.showInformationMessage(
+          "Reload the window to restart the Legalis language server."
+        );
+      }
+    })
+  );
+}
+
+function deactivate() {
+  return client ? client.stop() : undefined;
+}
+
+module.exports = { activate, deactivate };